@@ -0,0 +1,342 @@
+//! Calibration module - comparing the system clock against an NTP server
+//!
+//! A measurement sends one SNTP request (RFC 4330) from a background thread
+//! and computes the clock offset and round-trip delay from the four packet
+//! timestamps. The session keeps a history of offsets, and the slope of that
+//! history is the system clock's drift rate.
+//!
+//! Offsets follow the NTP convention: server time minus system time, so a
+//! positive offset means the system clock is slow.
+
+use std::collections::VecDeque;
+use std::net::UdpSocket;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+/// Server used until the user picks another
+pub const DEFAULT_SERVER: &str = "pool.ntp.org";
+
+/// Offsets kept for the sparkline and drift fit; the oldest are dropped first
+pub const MAX_HISTORY: usize = 120;
+
+/// Time between automatic measurements while the calibration view is shown
+///
+/// 64 seconds is the shortest poll interval NTP servers expect from clients.
+pub const AUTO_INTERVAL: Duration = Duration::from_secs(64);
+
+/// Shortest span of samples a drift rate is quoted over
+const MIN_DRIFT_SPAN_SECS: f64 = 60.0;
+
+const NTP_PORT: u16 = 123;
+const PACKET_LEN: usize = 48;
+const TIMEOUT: Duration = Duration::from_secs(3);
+/// Seconds from the NTP epoch (1900) to the Unix epoch (1970)
+const NTP_UNIX_OFFSET_SECS: f64 = 2_208_988_800.0;
+
+/// Persisted calibration view settings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CalibrationSettings {
+    /// Show the calibration view on the ring and measure periodically
+    pub enabled: bool,
+    /// NTP server host name or address
+    pub server: String,
+}
+
+impl Default for CalibrationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            server: DEFAULT_SERVER.to_string(),
+        }
+    }
+}
+
+impl CalibrationSettings {
+    /// The server to query, falling back to the default when blank
+    pub fn server(&self) -> &str {
+        match self.server.trim() {
+            "" => DEFAULT_SERVER,
+            server => server,
+        }
+    }
+}
+
+/// One completed measurement
+#[derive(Debug, Clone, Copy)]
+pub struct NtpSample {
+    /// When the response arrived
+    pub measured_at: Instant,
+    /// Server time minus system time in milliseconds
+    pub offset_ms: f64,
+    /// Network round trip, excluding the server's processing time
+    pub round_trip_ms: f64,
+}
+
+/// Encode Unix seconds as a 64-bit NTP timestamp
+fn ntp_timestamp(unix_secs: f64) -> [u8; 8] {
+    let ntp = unix_secs + NTP_UNIX_OFFSET_SECS;
+    let secs = ntp.floor();
+    let fraction = ((ntp - secs) * 4_294_967_296.0) as u32;
+    let mut bytes = [0; 8];
+    bytes[..4].copy_from_slice(&(secs as u32).to_be_bytes());
+    bytes[4..].copy_from_slice(&fraction.to_be_bytes());
+    bytes
+}
+
+/// Decode a 64-bit NTP timestamp (era 0) as Unix seconds
+fn unix_seconds(bytes: &[u8]) -> f64 {
+    let secs = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64;
+    let fraction = u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as f64;
+    secs + fraction / 4_294_967_296.0 - NTP_UNIX_OFFSET_SECS
+}
+
+fn system_unix_seconds() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0.0, |d| d.as_secs_f64())
+}
+
+/// A client request sent at `transmit` (Unix seconds)
+pub fn build_request(transmit: f64) -> [u8; PACKET_LEN] {
+    let mut packet = [0; PACKET_LEN];
+    // Leap indicator 0, version 3, mode 3 (client)
+    packet[0] = 0x1B;
+    packet[40..48].copy_from_slice(&ntp_timestamp(transmit));
+    packet
+}
+
+/// Offset and round trip in milliseconds from a server response
+///
+/// `sent` and `received` are the system times the request left and the
+/// response arrived; the response must echo `sent` as its originate time.
+pub fn parse_response(response: &[u8], sent: f64, received: f64) -> Result<(f64, f64), String> {
+    if response.len() < PACKET_LEN {
+        return Err("Short NTP response".to_string());
+    }
+    if response[0] & 0x07 != 4 {
+        return Err("Not an NTP server response".to_string());
+    }
+    if response[1] == 0 {
+        return Err("NTP server is not synchronized".to_string());
+    }
+    if response[24..32] != ntp_timestamp(sent) {
+        return Err("NTP response does not match the request".to_string());
+    }
+
+    let server_received = unix_seconds(&response[32..40]);
+    let server_sent = unix_seconds(&response[40..48]);
+    let offset = ((server_received - sent) + (server_sent - received)) / 2.0;
+    let round_trip = (received - sent) - (server_sent - server_received);
+    Ok((offset * 1000.0, round_trip.max(0.0) * 1000.0))
+}
+
+/// Measure the system clock against `server` (blocks up to a few seconds)
+pub fn measure(server: &str) -> Result<NtpSample, String> {
+    let socket = UdpSocket::bind("0.0.0.0:0").map_err(|e| e.to_string())?;
+    socket
+        .set_read_timeout(Some(TIMEOUT))
+        .map_err(|e| e.to_string())?;
+    socket
+        .connect((server, NTP_PORT))
+        .map_err(|e| format!("{}: {}", server, e))?;
+
+    let sent = system_unix_seconds();
+    socket
+        .send(&build_request(sent))
+        .map_err(|e| format!("{}: {}", server, e))?;
+    let mut response = [0; PACKET_LEN];
+    let len = socket
+        .recv(&mut response)
+        .map_err(|e| format!("{}: {}", server, e))?;
+    let received = system_unix_seconds();
+    let measured_at = Instant::now();
+
+    let (offset_ms, round_trip_ms) = parse_response(&response[..len], sent, received)?;
+    Ok(NtpSample {
+        measured_at,
+        offset_ms,
+        round_trip_ms,
+    })
+}
+
+/// Offsets measured this session and any measurement in flight
+#[derive(Default)]
+pub struct Calibration {
+    /// Completed measurements, oldest first
+    history: VecDeque<NtpSample>,
+    /// Result of the measurement running in the background
+    pending: Option<Receiver<Result<NtpSample, String>>>,
+    /// When the last measurement was started
+    last_started: Option<Instant>,
+}
+
+impl Calibration {
+    pub fn is_measuring(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Start a background measurement unless one is already running
+    pub fn start(&mut self, server: &str, now: Instant) {
+        if self.is_measuring() {
+            return;
+        }
+        let (sender, receiver) = mpsc::channel();
+        let server = server.to_string();
+        thread::spawn(move || {
+            let _ = sender.send(measure(&server));
+        });
+        self.pending = Some(receiver);
+        self.last_started = Some(now);
+    }
+
+    /// Whether the next automatic measurement is due
+    pub fn is_due(&self, now: Instant) -> bool {
+        match self.last_started {
+            _ if self.is_measuring() => false,
+            Some(started) => now.duration_since(started) >= AUTO_INTERVAL,
+            None => true,
+        }
+    }
+
+    /// The finished measurement, if any; successful ones join the history
+    pub fn poll(&mut self) -> Option<Result<NtpSample, String>> {
+        let outcome = match self.pending.as_ref()?.try_recv() {
+            Ok(outcome) => outcome,
+            Err(mpsc::TryRecvError::Empty) => return None,
+            Err(mpsc::TryRecvError::Disconnected) => {
+                Err("NTP measurement ended unexpectedly".to_string())
+            }
+        };
+        self.pending = None;
+        if let Ok(sample) = outcome {
+            self.record(sample);
+        }
+        Some(outcome)
+    }
+
+    pub fn record(&mut self, sample: NtpSample) {
+        if self.history.len() == MAX_HISTORY {
+            self.history.pop_front();
+        }
+        self.history.push_back(sample);
+    }
+
+    pub fn latest(&self) -> Option<&NtpSample> {
+        self.history.back()
+    }
+
+    pub fn history(&self) -> &VecDeque<NtpSample> {
+        &self.history
+    }
+
+    /// How fast the system clock gains on the server, in parts per million
+    ///
+    /// The least-squares slope of the offset history, negated so a positive
+    /// rate means the system clock runs fast. `None` until the samples span
+    /// at least a minute.
+    pub fn drift_ppm(&self) -> Option<f64> {
+        let first = self.history.front()?.measured_at;
+        let points: Vec<(f64, f64)> = self
+            .history
+            .iter()
+            .map(|s| (s.measured_at.duration_since(first).as_secs_f64(), s.offset_ms))
+            .collect();
+        let span = points.last()?.0;
+        if points.len() < 2 || span < MIN_DRIFT_SPAN_SECS {
+            return None;
+        }
+
+        let n = points.len() as f64;
+        let mean_t = points.iter().map(|p| p.0).sum::<f64>() / n;
+        let mean_offset = points.iter().map(|p| p.1).sum::<f64>() / n;
+        let covariance: f64 = points
+            .iter()
+            .map(|(t, offset)| (t - mean_t) * (offset - mean_offset))
+            .sum();
+        let variance: f64 = points.iter().map(|(t, _)| (t - mean_t).powi(2)).sum();
+        // Milliseconds per second is parts per thousand
+        Some(-covariance / variance * 1000.0)
+    }
+}
+
+/// The smallest decade (1 ms, 10 ms, ...) that holds `ms`, used as the
+/// ring's full-scale deflection
+pub fn full_scale_ms(ms: f64) -> f64 {
+    let mut scale = 1.0;
+    while scale < ms.abs() && scale < 10_000.0 {
+        scale *= 10.0;
+    }
+    scale
+}
+
+/// "+1.234 ms" with precision suited to the magnitude
+pub fn format_ms(ms: f64) -> String {
+    if ms.abs() < 10.0 {
+        format!("{:+.3} ms", ms)
+    } else if ms.abs() < 1000.0 {
+        format!("{:+.1} ms", ms)
+    } else {
+        format!("{:+.2} s", ms / 1000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A server response to a request sent at `sent`, with the server's clock
+    /// `offset` seconds ahead and `delay` seconds each way
+    fn response(sent: f64, offset: f64, delay: f64) -> ([u8; PACKET_LEN], f64) {
+        let mut packet = [0; PACKET_LEN];
+        packet[0] = 0x1C; // version 3, mode 4 (server)
+        packet[1] = 2;
+        packet[24..32].copy_from_slice(&ntp_timestamp(sent));
+        packet[32..40].copy_from_slice(&ntp_timestamp(sent + delay + offset));
+        packet[40..48].copy_from_slice(&ntp_timestamp(sent + delay + offset + 0.001));
+        let received = sent + 2.0 * delay + 0.001;
+        (packet, received)
+    }
+
+    #[test]
+    fn test_offset_and_round_trip() {
+        let sent = 1_750_000_000.25;
+        let (packet, received) = response(sent, 0.015, 0.020);
+        let (offset_ms, round_trip_ms) = parse_response(&packet, sent, received).unwrap();
+        assert!((offset_ms - 15.0).abs() < 0.01, "offset {}", offset_ms);
+        assert!((round_trip_ms - 40.0).abs() < 0.01, "round trip {}", round_trip_ms);
+        assert_eq!(build_request(sent)[40..48], packet[24..32]);
+    }
+
+    #[test]
+    fn test_rejects_mismatched_or_unsynchronized_responses() {
+        let sent = 1_750_000_000.0;
+        let (mut packet, received) = response(sent, 0.0, 0.01);
+        assert!(parse_response(&packet, sent + 1.0, received).is_err());
+        packet[1] = 0;
+        assert!(parse_response(&packet, sent, received).is_err());
+        assert!(parse_response(&packet[..40], sent, received).is_err());
+    }
+
+    #[test]
+    fn test_drift_from_offset_history() {
+        let start = Instant::now();
+        let mut calibration = Calibration::default();
+        // The system clock gains 2 ms on the server every 100 s: 20 ppm fast
+        for i in 0..5u64 {
+            calibration.record(NtpSample {
+                measured_at: start + Duration::from_secs(i * 25),
+                offset_ms: -(i as f64) * 0.5,
+                round_trip_ms: 30.0,
+            });
+            if i == 1 {
+                assert_eq!(calibration.drift_ppm(), None);
+            }
+        }
+        let drift = calibration.drift_ppm().unwrap();
+        assert!((drift - 20.0).abs() < 1e-6, "drift {}", drift);
+    }
+}
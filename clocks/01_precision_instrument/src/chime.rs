@@ -0,0 +1,401 @@
+//! Chime module - hourly and half-hourly chimes, heard or seen
+//!
+//! On the hour and half hour the instrument can strike ship's bells (one to
+//! eight bells through each four-hour watch, struck in pairs) or simple
+//! tones (the hour's count on the hour, one on the half hour). Each chime can
+//! sound, flash the panel, or both; the flash serves silent rooms. The
+//! shared quiet-hours schedule silences the sound and holds back the flash.
+//!
+//! Chime selection and the flash envelope are always compiled; sound output
+//! uses rodio and is only built with the `audio` feature. Without it (or
+//! without an output device) the engine is simply absent and the chimes
+//! only flash.
+
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use shared::{tr_args, TimeData};
+
+/// How long one strike's flash takes to fade
+const FLASH_FADE: Duration = Duration::from_millis(350);
+/// The single, slower fade used instead of pulses in reduced motion
+const REDUCED_MOTION_FADE: Duration = Duration::from_millis(1500);
+
+/// What the chime sounds like
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ChimeSound {
+    /// Bells counting the half hours of the watch, struck in pairs
+    #[default]
+    ShipsBells,
+    /// Plain tones counting the hour
+    Tones,
+}
+
+impl ChimeSound {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChimeSound::ShipsBells => "Ship's bells",
+            ChimeSound::Tones => "Tones",
+        }
+    }
+
+    /// Tone partials in Hz, played together for each strike
+    #[cfg_attr(not(feature = "audio"), allow(dead_code))]
+    pub fn partials(&self, half_hour: bool) -> &'static [f32] {
+        match (self, half_hour) {
+            // A bell's inharmonic partials over a hum note
+            (ChimeSound::ShipsBells, _) => &[1046.5, 2093.0, 2516.0, 3140.0],
+            (ChimeSound::Tones, false) => &[659.25, 1318.5],
+            (ChimeSound::Tones, true) => &[880.0, 1760.0],
+        }
+    }
+
+    /// Ring length of one strike
+    #[cfg_attr(not(feature = "audio"), allow(dead_code))]
+    pub fn ring(&self) -> Duration {
+        match self {
+            ChimeSound::ShipsBells => Duration::from_millis(1400),
+            ChimeSound::Tones => Duration::from_millis(450),
+        }
+    }
+}
+
+/// How many strikes a chime has
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ChimeCount {
+    /// Bells of the watch, or the hour's count for tones
+    #[default]
+    Count,
+    /// A single strike
+    Single,
+}
+
+impl ChimeCount {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChimeCount::Count => "Count",
+            ChimeCount::Single => "Single",
+        }
+    }
+}
+
+/// Persisted chime preferences
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChimeSettings {
+    /// Chime on the hour
+    pub hourly: bool,
+    /// Chime on the half hour
+    pub half_hourly: bool,
+    pub sound: ChimeSound,
+    pub count: ChimeCount,
+    /// Play the chime through the speakers
+    pub audible: bool,
+    pub volume: f32,
+    /// Flash the panel with each strike
+    pub flash: bool,
+}
+
+impl Default for ChimeSettings {
+    fn default() -> Self {
+        Self {
+            hourly: false,
+            half_hourly: false,
+            sound: ChimeSound::default(),
+            count: ChimeCount::default(),
+            audible: true,
+            volume: 0.6,
+            flash: false,
+        }
+    }
+}
+
+impl ChimeSettings {
+    /// Whether any chime is switched on
+    pub fn is_enabled(&self) -> bool {
+        (self.hourly || self.half_hourly) && (self.audible || self.flash)
+    }
+}
+
+/// A chime to strike now
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Chime {
+    pub sound: ChimeSound,
+    pub strikes: u32,
+    /// Struck on the half hour rather than the hour
+    pub half_hour: bool,
+    #[cfg_attr(not(feature = "audio"), allow(dead_code))]
+    pub volume: f32,
+}
+
+impl Chime {
+    /// When each strike falls, from the first
+    pub fn strike_offsets(&self) -> Vec<Duration> {
+        (0..self.strikes)
+            .map(|i| match self.sound {
+                // Pairs a short beat apart, with a longer pause between pairs
+                ChimeSound::ShipsBells => {
+                    Duration::from_millis(u64::from(i / 2) * 1100 + u64::from(i % 2) * 350)
+                }
+                ChimeSound::Tones => Duration::from_millis(u64::from(i) * 700),
+            })
+            .collect()
+    }
+
+    /// How long the whole chime lasts, to its last strike's fade
+    pub fn duration(&self) -> Duration {
+        self.strike_offsets().last().copied().unwrap_or_default() + FLASH_FADE
+    }
+
+    /// "4 bells", "3 strikes", "1 strike"
+    pub fn describe(&self) -> String {
+        let text = match (self.sound, self.strikes) {
+            (ChimeSound::ShipsBells, 1) => "{0} bell",
+            (ChimeSound::ShipsBells, _) => "{0} bells",
+            (ChimeSound::Tones, 1) => "{0} strike",
+            (ChimeSound::Tones, _) => "{0} strikes",
+        };
+        tr_args(text, &[&self.strikes])
+    }
+
+    /// Brightness of the flash `elapsed` after the chime began, 0 to 1
+    ///
+    /// Each strike flashes and fades; in reduced motion the whole chime is
+    /// one slow fade instead of a train of pulses.
+    pub fn flash_alpha(&self, elapsed: Duration, reduced_motion: bool) -> f32 {
+        let fade = |since: Duration, length: Duration| {
+            1.0 - (since.as_secs_f32() / length.as_secs_f32()).min(1.0)
+        };
+        if reduced_motion {
+            return 0.6 * fade(elapsed, REDUCED_MOTION_FADE);
+        }
+        self.strike_offsets()
+            .into_iter()
+            .filter(|&offset| offset <= elapsed)
+            .map(|offset| fade(elapsed - offset, FLASH_FADE))
+            .fold(0.0, f32::max)
+    }
+}
+
+/// Bells struck at a half-hour mark: one at half past the start of a watch,
+/// up to eight at its end (watches change at 4, 8 and 12 o'clock)
+pub fn ships_bells(hour24: u32, minute: u32) -> u32 {
+    match (hour24 * 2 + minute / 30) % 8 {
+        0 => 8,
+        n => n,
+    }
+}
+
+/// The chime for a newly reached second, if it's a chiming half-hour mark
+pub fn select_chime(time_data: &TimeData, settings: &ChimeSettings) -> Option<Chime> {
+    if time_data.second != 0 || !settings.is_enabled() {
+        return None;
+    }
+    let half_hour = match time_data.minute {
+        0 if settings.hourly => false,
+        30 if settings.half_hourly => true,
+        _ => return None,
+    };
+    Some(chime_at(time_data, half_hour, settings))
+}
+
+/// The chime for the hour of `time_data`, or its half hour
+pub fn chime_at(time_data: &TimeData, half_hour: bool, settings: &ChimeSettings) -> Chime {
+    let strikes = match (settings.count, settings.sound) {
+        (ChimeCount::Single, _) => 1,
+        (ChimeCount::Count, ChimeSound::ShipsBells) => {
+            ships_bells(time_data.hour24, if half_hour { 30 } else { 0 })
+        }
+        (ChimeCount::Count, ChimeSound::Tones) if half_hour => 1,
+        (ChimeCount::Count, ChimeSound::Tones) => time_data.hour12,
+    };
+    Chime {
+        sound: settings.sound,
+        strikes,
+        half_hour,
+        volume: settings.volume,
+    }
+}
+
+/// A chime being flashed, and when it began
+#[derive(Debug, Clone, Copy)]
+pub struct ChimeFlash {
+    pub chime: Chime,
+    pub started: Instant,
+}
+
+impl ChimeFlash {
+    pub fn is_finished(&self, now: Instant) -> bool {
+        now.duration_since(self.started) >= self.chime.duration().max(REDUCED_MOTION_FADE)
+    }
+
+    pub fn alpha(&self, now: Instant, reduced_motion: bool) -> f32 {
+        self.chime
+            .flash_alpha(now.duration_since(self.started), reduced_motion)
+    }
+}
+
+#[cfg(feature = "audio")]
+mod output {
+    use rodio::source::{SineWave, Source};
+    use rodio::{OutputStream, OutputStreamHandle};
+
+    use super::Chime;
+
+    /// Loudness of a strike before the user's volume is applied
+    const GAIN: f32 = 0.35;
+
+    /// Shown when the engine could not be created
+    pub const UNAVAILABLE_REASON: &str = "No audio output device found";
+
+    /// Plays chimes on the default output device
+    pub struct ChimeEngine {
+        /// Keeps the output stream alive
+        _stream: OutputStream,
+        handle: OutputStreamHandle,
+    }
+
+    impl ChimeEngine {
+        pub fn new() -> Option<Self> {
+            let (stream, handle) = OutputStream::try_default().ok()?;
+            Some(Self {
+                _stream: stream,
+                handle,
+            })
+        }
+
+        /// Fire-and-forget: every strike is queued at its offset, each
+        /// partial ringing out with a linear decay
+        pub fn play(&self, chime: Chime) {
+            let partials = chime.sound.partials(chime.half_hour);
+            let gain = GAIN * chime.volume / partials.len() as f32;
+
+            for offset in chime.strike_offsets() {
+                for (i, &frequency) in partials.iter().enumerate() {
+                    let mut tone = SineWave::new(frequency).take_duration(chime.sound.ring());
+                    tone.set_filter_fadeout();
+                    // Higher partials are quieter
+                    let source = tone.amplify(gain / (1.0 + i as f32 * 0.7)).delay(offset);
+                    if let Err(e) = self.handle.play_raw(source) {
+                        eprintln!("Failed to play chime: {}", e);
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "audio"))]
+mod output {
+    use super::Chime;
+
+    /// Shown when the engine could not be created
+    pub const UNAVAILABLE_REASON: &str = "Built without audio (enable the `audio` feature)";
+
+    /// Silent stand-in when the `audio` feature is disabled
+    #[allow(dead_code)]
+    pub struct ChimeEngine;
+
+    impl ChimeEngine {
+        pub fn new() -> Option<Self> {
+            None
+        }
+
+        pub fn play(&self, _chime: Chime) {}
+    }
+}
+
+pub use output::{ChimeEngine, UNAVAILABLE_REASON};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Tz;
+    use shared::compute_time_data_at;
+
+    fn time_at(hour: u32, minute: u32, second: u32) -> TimeData {
+        let tz: Tz = "UTC".parse().unwrap();
+        compute_time_data_at(tz, Utc.with_ymd_and_hms(2025, 1, 15, hour, minute, second).unwrap())
+    }
+
+    fn enabled(sound: ChimeSound) -> ChimeSettings {
+        ChimeSettings {
+            hourly: true,
+            half_hourly: true,
+            sound,
+            ..ChimeSettings::default()
+        }
+    }
+
+    #[test]
+    fn test_ships_bells_count_the_watch() {
+        assert_eq!(ships_bells(0, 30), 1);
+        assert_eq!(ships_bells(1, 0), 2);
+        assert_eq!(ships_bells(4, 0), 8);
+        assert_eq!(ships_bells(4, 30), 1);
+        assert_eq!(ships_bells(12, 0), 8);
+        assert_eq!(ships_bells(19, 30), 7);
+    }
+
+    #[test]
+    fn test_select_chime() {
+        let bells = enabled(ChimeSound::ShipsBells);
+        let strikes = |settings: &ChimeSettings, h, m, s| {
+            select_chime(&time_at(h, m, s), settings).map(|c| c.strikes)
+        };
+        assert_eq!(strikes(&bells, 14, 0, 0), Some(4));
+        assert_eq!(strikes(&bells, 14, 30, 0), Some(5));
+        assert_eq!(strikes(&bells, 14, 30, 1), None);
+        assert_eq!(strikes(&bells, 14, 15, 0), None);
+
+        let tones = enabled(ChimeSound::Tones);
+        assert_eq!(strikes(&tones, 15, 0, 0), Some(3));
+        assert_eq!(strikes(&tones, 0, 0, 0), Some(12));
+        assert_eq!(strikes(&tones, 15, 30, 0), Some(1));
+
+        let single = ChimeSettings {
+            count: ChimeCount::Single,
+            ..tones.clone()
+        };
+        assert_eq!(strikes(&single, 15, 0, 0), Some(1));
+
+        // Per-chime enables, and nothing without sound or flash
+        let hourly_only = ChimeSettings {
+            half_hourly: false,
+            ..tones.clone()
+        };
+        assert_eq!(strikes(&hourly_only, 15, 30, 0), None);
+        let neither = ChimeSettings {
+            audible: false,
+            flash: false,
+            ..tones
+        };
+        assert_eq!(strikes(&neither, 15, 0, 0), None);
+    }
+
+    #[test]
+    fn test_strikes_and_flash() {
+        let chime = Chime {
+            sound: ChimeSound::ShipsBells,
+            strikes: 3,
+            half_hour: true,
+            volume: 1.0,
+        };
+        let ms = Duration::from_millis;
+        assert_eq!(chime.strike_offsets(), vec![ms(0), ms(350), ms(1100)]);
+        assert_eq!(chime.describe(), "3 bells");
+
+        // Full at each strike, dark between pairs and after the last fade
+        assert_eq!(chime.flash_alpha(ms(0), false), 1.0);
+        assert_eq!(chime.flash_alpha(ms(350), false), 1.0);
+        assert_eq!(chime.flash_alpha(ms(1000), false), 0.0);
+        assert_eq!(chime.flash_alpha(ms(1100), false), 1.0);
+        assert_eq!(chime.flash_alpha(chime.duration(), false), 0.0);
+
+        // Reduced motion: one steady fade, no pulses
+        assert!(chime.flash_alpha(ms(1000), true) > 0.0);
+        assert!(chime.flash_alpha(ms(200), true) < chime.flash_alpha(ms(0), true));
+    }
+}
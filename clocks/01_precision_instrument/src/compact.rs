@@ -0,0 +1,112 @@
+//! Compact module - a small always-on-top widget
+//!
+//! Pressing M shrinks the instrument to a frameless widget that floats above
+//! other windows, showing only the time, the zone and a DST badge. Dragging
+//! the readout moves the window; pressing M again restores the full layout at
+//! the size it had before.
+
+use nannou::window::Window;
+use shared::{tr_args, DstChange, TimeData};
+
+/// Size of the compact widget, in points
+pub const COMPACT_SIZE: (f32, f32) = (280.0, 110.0);
+
+/// Full layout size to restore when the clock was started compact
+const FULL_SIZE: (f32, f32) = (900.0, 600.0);
+
+/// Whether the window is the compact widget, and the size to restore
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompactWidget {
+    enabled: bool,
+    /// Window size in the full layout, saved on the way into compact mode
+    full_size: (f32, f32),
+}
+
+impl CompactWidget {
+    pub fn new() -> Self {
+        Self {
+            enabled: false,
+            full_size: FULL_SIZE,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Shrink `window` to the widget, or restore the full layout
+    pub fn set(&mut self, window: &Window, enabled: bool) {
+        if enabled == self.enabled {
+            return;
+        }
+        if enabled {
+            self.full_size = window.inner_size_points();
+        }
+        self.enabled = enabled;
+
+        let (width, height) = if enabled { COMPACT_SIZE } else { self.full_size };
+        window.set_decorations(!enabled);
+        window.set_always_on_top(enabled);
+        window.set_resizable(!enabled);
+        window.set_inner_size_points(width, height);
+    }
+}
+
+/// How a DST badge should stand out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BadgeTone {
+    /// Nothing changing soon
+    Quiet,
+    /// A change is coming within a day
+    Warning,
+    /// The clocks just changed
+    Accent,
+}
+
+/// The widget's DST badge: "DST" or "STD", with the change when one is near
+pub fn dst_badge(time_data: &TimeData) -> (String, BadgeTone) {
+    let state = if time_data.is_dst { "DST" } else { "STD" };
+    match time_data.dst_change {
+        DstChange::None => (state.to_string(), BadgeTone::Quiet),
+        DstChange::Upcoming { delta_minutes, .. } => {
+            (tr_args("{0} · {1} soon", &[&state, &shift_label(delta_minutes)]), BadgeTone::Warning)
+        }
+        DstChange::JustOccurred { delta_minutes, .. } => {
+            (format!("{} · {}", state, shift_label(delta_minutes)), BadgeTone::Accent)
+        }
+    }
+}
+
+/// "+1h", "−1h", "+30m"
+fn shift_label(delta_minutes: i32) -> String {
+    let sign = if delta_minutes > 0 { "+" } else { "−" };
+    let minutes = delta_minutes.abs();
+    if minutes % 60 == 0 {
+        format!("{}{}h", sign, minutes / 60)
+    } else {
+        format!("{}{}m", sign, minutes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Tz;
+    use shared::compute_time_data_at;
+
+    #[test]
+    fn test_dst_badge() {
+        let tz: Tz = "America/New_York".parse().unwrap();
+
+        let summer = compute_time_data_at(tz, Utc.with_ymd_and_hms(2025, 7, 1, 16, 0, 0).unwrap());
+        assert_eq!(dst_badge(&summer), ("DST".to_string(), BadgeTone::Quiet));
+
+        // Evening before the 2 November 2025 fall back
+        let eve = compute_time_data_at(tz, Utc.with_ymd_and_hms(2025, 11, 1, 22, 0, 0).unwrap());
+        assert_eq!(dst_badge(&eve), ("DST · −1h soon".to_string(), BadgeTone::Warning));
+
+        assert_eq!(shift_label(60), "+1h");
+        assert_eq!(shift_label(-30), "−30m");
+    }
+}
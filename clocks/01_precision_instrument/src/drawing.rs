@@ -5,7 +5,15 @@
 use std::time::Instant;
 
 use nannou::prelude::*;
-use shared::{DstChange, TimeData};
+use shared::{tr, tr_args, DstChange, TimeData};
+
+use crate::calibration::{format_ms, full_scale_ms, Calibration};
+use crate::compact::{dst_badge, BadgeTone};
+use crate::readout::{leap_notice, time_scales_text, ReadoutFormat, SecondaryReadout};
+use crate::stopwatch::{format_elapsed, Lap};
+
+/// How long the calibration needle takes to settle after a measurement
+const CALIBRATION_EASE_SECS: f32 = 1.5;
 
 /// A toast notification message
 pub struct ToastMessage {
@@ -17,6 +25,7 @@ pub struct ToastMessage {
 /// Color palette for the precision instrument theme
 pub mod colors {
     use nannou::prelude::*;
+    use shared::{Rgb, ThemeRole};
 
     pub const BACKGROUND: Srgb<u8> = Srgb {
         red: 26,
@@ -73,18 +82,61 @@ pub mod colors {
         blue: 50,
         standard: std::marker::PhantomData,
     };
+    pub const LAP_MARK: Srgb<u8> = Srgb {
+        red: 255,
+        green: 140,
+        blue: 90,
+        standard: std::marker::PhantomData,
+    };
+    pub const LAP_FASTEST: Srgb<u8> = Srgb {
+        red: 110,
+        green: 230,
+        blue: 140,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Look up one of this clock's role colors in the active theme
+    fn themed(role: ThemeRole, native: Srgb<u8>) -> Srgb<u8> {
+        let color = shared::themed(role, Rgb::new(native.red, native.green, native.blue));
+        Srgb::new(color.red, color.green, color.blue)
+    }
+
+    pub fn background() -> Srgb<u8> {
+        themed(ThemeRole::Background, BACKGROUND)
+    }
+
+    pub fn text_primary() -> Srgb<u8> {
+        themed(ThemeRole::PrimaryText, TEXT_PRIMARY)
+    }
+
+    pub fn text_secondary() -> Srgb<u8> {
+        themed(ThemeRole::SecondaryText, TEXT_SECONDARY)
+    }
+
+    pub fn accent() -> Srgb<u8> {
+        themed(ThemeRole::Accent, ACCENT)
+    }
+
+    pub fn warning() -> Srgb<u8> {
+        themed(ThemeRole::Warning, WARNING)
+    }
 }
 
 /// Draw the primary time readout (left panel)
-pub fn draw_primary_readout(draw: &Draw, time_data: &TimeData, rect: Rect) {
+pub fn draw_primary_readout(
+    draw: &Draw,
+    time_data: &TimeData,
+    readout: &ReadoutFormat,
+    rect: Rect,
+) {
     let center = rect.xy();
     
-    // Large time display: hh:mm:ss with AM/PM as superscript
-    let time_str = time_data.format_time();
-    let meridiem_str = time_data.meridiem.to_string();
+    // Large time display with AM/PM as superscript in 12-hour mode
+    let time_str = readout.time_text(time_data);
+    let show_meridiem = readout.shows_meridiem();
     
     // Offset time slightly left to make room for AM/PM
-    let time_x_offset = -20.0;
+    let time_x_offset = if show_meridiem { -20.0 } else { 0.0 };
     let time_y = 60.0;
     
     // Subtle glow effect behind time digits
@@ -97,48 +149,185 @@ pub fn draw_primary_readout(draw: &Draw, time_data: &TimeData, rect: Rect) {
     // Main time text
     draw.text(&time_str)
         .xy(center + vec2(time_x_offset, time_y))
-        .color(colors::TEXT_PRIMARY)
+        .color(colors::text_primary())
         .font_size(72)
         .w(rect.w());
     
-    // AM/PM indicator - positioned as superscript to the right of time
-    // Approximate time text width: 8 chars * ~40px = ~160px half-width
-    let time_half_width = 160.0;
-    let am_pm_x = time_x_offset + time_half_width + 8.0;
-    let am_pm_y = time_y + 18.0; // Align with upper portion of digits
-    
-    draw.text(&meridiem_str)
-        .xy(center + vec2(am_pm_x, am_pm_y))
-        .color(colors::ACCENT)
-        .font_size(24)
-        .w(100.0);
+    if show_meridiem {
+        // AM/PM indicator - positioned as superscript to the right of time
+        // Approximate time text width: ~40px per char, so 8 chars = ~160px half-width
+        let time_half_width = time_str.chars().count() as f32 * 20.0;
+        let am_pm_x = time_x_offset + time_half_width + 8.0;
+        let am_pm_y = time_y + 18.0; // Align with upper portion of digits
+
+        draw.text(&time_data.meridiem.to_string())
+            .xy(center + vec2(am_pm_x, am_pm_y))
+            .color(colors::accent())
+            .font_size(24)
+            .w(100.0);
+    }
     
     // Date line
-    let date_str = time_data.format_date();
+    let date_str = readout.date_text(time_data);
     draw.text(&date_str)
         .xy(center + vec2(0.0, 0.0))
-        .color(colors::TEXT_SECONDARY)
+        .color(colors::text_secondary())
         .font_size(20)
         .w(rect.w());
     
     // Timezone info line
+    let dst = if time_data.is_dst { "DST on" } else { "DST off" };
     let tz_str = format!(
-        "{} · {} · DST {}",
+        "{} · {} · {}",
         time_data.tz_abbrev,
         time_data.format_utc_offset(),
-        if time_data.is_dst { "On" } else { "Off" }
+        tr(dst)
     );
     draw.text(&tz_str)
         .xy(center + vec2(0.0, -40.0))
-        .color(colors::TEXT_SECONDARY)
+        .color(colors::text_secondary())
         .font_size(16)
         .w(rect.w());
 }
 
+/// Draw the compact widget: time, zone and DST badge filling the window
+pub fn draw_compact_readout(
+    draw: &Draw,
+    time_data: &TimeData,
+    readout: &ReadoutFormat,
+    zone_name: &str,
+    rect: Rect,
+) {
+    let mut time_str = readout.time_text(time_data);
+    if readout.shows_meridiem() {
+        time_str = format!("{} {}", time_str, time_data.meridiem);
+    }
+    draw.text(&time_str)
+        .xy(pt2(rect.x(), rect.y() + 14.0))
+        .color(colors::text_primary())
+        .font_size(34)
+        .w(rect.w() - 20.0);
+
+    let zone_str = format!("{} · {}", zone_name, time_data.tz_abbrev);
+    draw.text(&zone_str)
+        .xy(pt2(rect.x() - 30.0, rect.bottom() + 22.0))
+        .color(colors::text_secondary())
+        .font_size(12)
+        .left_justify()
+        .w(rect.w() - 80.0);
+
+    let (badge, tone) = dst_badge(time_data);
+    let badge_color = match tone {
+        BadgeTone::Quiet => colors::text_secondary(),
+        BadgeTone::Warning => colors::warning(),
+        BadgeTone::Accent => colors::accent(),
+    };
+    let badge_w = badge.chars().count() as f32 * 7.0 + 14.0;
+    let badge_xy = pt2(rect.right() - badge_w / 2.0 - 10.0, rect.bottom() + 22.0);
+    draw.rect()
+        .xy(badge_xy)
+        .w_h(badge_w, 18.0)
+        .no_fill()
+        .stroke(badge_color)
+        .stroke_weight(1.0);
+    draw.text(&badge)
+        .xy(badge_xy)
+        .color(badge_color)
+        .font_size(11)
+        .w(badge_w);
+
+    // Hairline frame, since the widget has no window decorations
+    draw.rect()
+        .xy(rect.xy())
+        .wh(rect.wh() - vec2(1.0, 1.0))
+        .no_fill()
+        .stroke(srgba(255u8, 255u8, 255u8, 40u8))
+        .stroke_weight(1.0);
+}
+
+/// Draw the secondary zone readout below the primary one (left panel)
+pub fn draw_secondary_readout(draw: &Draw, secondary: &TimeData, primary: &TimeData, rect: Rect) {
+    let center = rect.xy();
+
+    // Hairline separating it from the primary readout
+    draw.line()
+        .start(center + vec2(-120.0, -68.0))
+        .end(center + vec2(120.0, -68.0))
+        .color(srgba(255u8, 255u8, 255u8, 30u8))
+        .stroke_weight(1.0);
+
+    draw.text(&SecondaryReadout::time_text(secondary))
+        .xy(center + vec2(0.0, -96.0))
+        .color(colors::accent())
+        .font_size(28)
+        .w(rect.w());
+
+    draw.text(&SecondaryReadout::date_text(secondary, primary))
+        .xy(center + vec2(0.0, -126.0))
+        .color(colors::text_secondary())
+        .font_size(14)
+        .w(rect.w());
+}
+
+/// Draw the TAI/GPS line and any leap second notice under the readouts
+///
+/// Sits below the secondary readout when that is shown. The notice appears
+/// whenever a leap second is within a day, even with the line turned off.
+pub fn draw_time_scales(
+    draw: &Draw,
+    time_data: &TimeData,
+    readout: &ReadoutFormat,
+    below_secondary: bool,
+    rect: Rect,
+) {
+    let mut y = rect.y() + if below_secondary { -160.0 } else { -84.0 };
+
+    if readout.show_time_scales {
+        draw.text(&time_scales_text(time_data))
+            .xy(pt2(rect.x(), y))
+            .color(colors::text_secondary())
+            .font_size(14)
+            .w(rect.w());
+        y -= 24.0;
+    }
+
+    if let Some(notice) = leap_notice(time_data, readout.leap_display) {
+        draw.text(&notice)
+            .xy(pt2(rect.x(), y))
+            .color(colors::warning())
+            .font_size(14)
+            .w(rect.w());
+    }
+}
+
+/// Canvas y just below the last readout line, where the world clock strip starts
+///
+/// Follows the same stacking as [`draw_time_scales`].
+pub fn readouts_bottom(
+    time_data: &TimeData,
+    readout: &ReadoutFormat,
+    below_secondary: bool,
+    rect: Rect,
+) -> f32 {
+    let mut y = rect.y() + if below_secondary { -160.0 } else { -84.0 };
+    if readout.show_time_scales {
+        y -= 24.0;
+    }
+    if leap_notice(time_data, readout.leap_display).is_some() {
+        y -= 24.0;
+    }
+    // Half a line up: the next line would be centred on `y`
+    y + 10.0
+}
+
 /// Draw the calibration ring (right panel)
+///
+/// `second` and `second_fraction` are wall-clock seconds in clock mode and
+/// elapsed seconds in stopwatch mode.
 pub fn draw_calibration_ring(
     draw: &Draw,
-    time_data: &TimeData,
+    second: u32,
+    second_fraction: f64,
     center: Point2,
     radius: f32,
     reduced_motion: bool,
@@ -153,7 +342,7 @@ pub fn draw_calibration_ring(
         // Start at 12 o'clock (PI/2) and go clockwise (subtract angle)
         let angle = PI / 2.0 - (i as f32 / num_ticks as f32) * TAU;
         let is_major = i % 5 == 0;
-        let is_current = i == time_data.second as usize;
+        let is_current = i == second as usize;
         
         let tick_length = if is_major { tick_length_major } else { tick_length_minor };
         let inner_radius = radius - tick_length;
@@ -162,7 +351,7 @@ pub fn draw_calibration_ring(
         let end = center + vec2(angle.cos(), angle.sin()) * radius;
         
         let color = if is_current {
-            colors::ACCENT
+            colors::accent()
         } else if is_major {
             colors::TICK_MAJOR
         } else {
@@ -186,7 +375,7 @@ pub fn draw_calibration_ring(
     
     // Draw sweeping second indicator (smooth animation)
     if !reduced_motion {
-        let second_with_fraction = time_data.second as f64 + time_data.second_fraction;
+        let second_with_fraction = second as f64 + second_fraction;
         // Start at 12 o'clock (PI/2) and go clockwise (subtract angle)
         let sweep_angle = PI / 2.0 - (second_with_fraction / 60.0) as f32 * TAU;
         
@@ -202,7 +391,7 @@ pub fn draw_calibration_ring(
         draw.ellipse()
             .xy(indicator_pos)
             .radius(4.0)
-            .color(colors::ACCENT);
+            .color(colors::accent());
     }
     
     // Draw center dot
@@ -233,14 +422,301 @@ pub fn draw_calibration_ring(
             .w_h(tooltip_width, tooltip_height)
             .color(srgba(40u8, 40u8, 40u8, 220u8));
         
-        draw.text(tooltip_text)
+        draw.text(tr(tooltip_text))
             .xy(tooltip_pos)
-            .color(colors::TEXT_PRIMARY)
+            .color(colors::text_primary())
             .font_size(12)
             .w(tooltip_width);
     }
 }
 
+/// Draw the sub-second sweep inside the calibration ring
+///
+/// One turn per second, with ten tenth-of-a-second ticks. The marker glides
+/// with `second_fraction`, or steps a tenth at a time with reduced motion.
+pub fn draw_subsecond_sweep(
+    draw: &Draw,
+    second_fraction: f64,
+    center: Point2,
+    radius: f32,
+    reduced_motion: bool,
+) {
+    let sweep_radius = radius * 0.45;
+
+    for i in 0..10 {
+        // Start at 12 o'clock (PI/2) and go clockwise (subtract angle)
+        let angle = PI / 2.0 - (i as f32 / 10.0) * TAU;
+        let direction = vec2(angle.cos(), angle.sin());
+        draw.line()
+            .start(center + direction * (sweep_radius - 4.0))
+            .end(center + direction * sweep_radius)
+            .color(colors::TICK_NORMAL)
+            .weight(1.0);
+    }
+    draw_ring(draw, center, sweep_radius, 0.5, colors::TICK_NORMAL);
+
+    let phase = if reduced_motion {
+        (second_fraction * 10.0).floor() / 10.0
+    } else {
+        second_fraction
+    };
+    let angle = PI / 2.0 - phase as f32 * TAU;
+    let direction = vec2(angle.cos(), angle.sin());
+    draw.line()
+        .start(center)
+        .end(center + direction * sweep_radius)
+        .color(colors::ACCENT_DIM)
+        .weight(1.0);
+    draw.ellipse()
+        .xy(center + direction * sweep_radius)
+        .radius(3.0)
+        .color(colors::accent());
+}
+
+/// Draw lap tick marks just outside the calibration ring
+///
+/// Each lap sits at its elapsed position within the minute. The latest lap is
+/// drawn longest and the fastest lap (if known) is highlighted.
+pub fn draw_lap_marks(
+    draw: &Draw,
+    laps: &[Lap],
+    fastest_index: Option<usize>,
+    center: Point2,
+    radius: f32,
+) {
+    let last_index = laps.len().checked_sub(1);
+
+    for (i, lap) in laps.iter().enumerate() {
+        let seconds_in_minute = (lap.total_ms % 60_000) as f32 / 1000.0;
+        // Start at 12 o'clock (PI/2) and go clockwise (subtract angle)
+        let angle = PI / 2.0 - (seconds_in_minute / 60.0) * TAU;
+        let direction = vec2(angle.cos(), angle.sin());
+
+        let is_latest = Some(i) == last_index;
+        let length = if is_latest { radius * 0.12 } else { radius * 0.07 };
+        let color = if Some(i) == fastest_index {
+            colors::LAP_FASTEST
+        } else {
+            colors::LAP_MARK
+        };
+
+        let start = center + direction * (radius + 4.0);
+        let end = center + direction * (radius + 4.0 + length);
+        draw.line()
+            .start(start)
+            .end(end)
+            .color(color)
+            .weight(if is_latest { 3.0 } else { 2.0 });
+
+        if is_latest {
+            draw.text(&format!("L{}", lap.number))
+                .xy(end + direction * 12.0)
+                .color(color)
+                .font_size(11)
+                .w(40.0);
+        }
+    }
+}
+
+/// Draw the stopwatch readout (left panel)
+pub fn draw_stopwatch_readout(
+    draw: &Draw,
+    elapsed_ms: u64,
+    is_running: bool,
+    laps: &[Lap],
+    fastest_index: Option<usize>,
+    rect: Rect,
+) {
+    let center = rect.xy();
+    let elapsed_str = format_elapsed(elapsed_ms);
+
+    // Subtle glow effect behind digits
+    draw.text(&elapsed_str)
+        .xy(center + vec2(0.0, 80.0))
+        .color(srgba(0u8, 180u8, 220u8, 25u8))
+        .font_size(60)
+        .w(rect.w());
+
+    draw.text(&elapsed_str)
+        .xy(center + vec2(0.0, 80.0))
+        .color(colors::text_primary())
+        .font_size(56)
+        .w(rect.w());
+
+    let (status, status_color) = if is_running {
+        ("RUNNING", colors::accent())
+    } else if elapsed_ms > 0 {
+        ("STOPPED", colors::text_secondary())
+    } else {
+        ("READY", colors::text_secondary())
+    };
+    draw.text(tr(status))
+        .xy(center + vec2(0.0, 30.0))
+        .color(status_color)
+        .font_size(14)
+        .w(rect.w());
+
+    // Most recent laps, newest first
+    let row_height = 22.0;
+    let max_rows = 6;
+    for (row, (i, lap)) in laps.iter().enumerate().rev().take(max_rows).enumerate() {
+        let color = if Some(i) == fastest_index {
+            colors::LAP_FASTEST
+        } else {
+            colors::text_secondary()
+        };
+        let line = tr_args(
+            "Lap {0}   +{1}   {2}",
+            &[
+                &format!("{:>2}", lap.number),
+                &format_elapsed(lap.split_ms),
+                &format_elapsed(lap.total_ms),
+            ],
+        );
+        draw.text(&line)
+            .xy(center + vec2(0.0, -10.0 - row as f32 * row_height))
+            .color(color)
+            .font_size(14)
+            .w(rect.w());
+    }
+
+    if laps.len() > max_rows {
+        draw.text(&tr_args("+{0} earlier laps", &[&(laps.len() - max_rows)]))
+            .xy(center + vec2(0.0, -10.0 - max_rows as f32 * row_height))
+            .color(colors::TICK_MAJOR)
+            .font_size(12)
+            .w(rect.w());
+    }
+}
+
+/// Draw the NTP calibration view inside the calibration ring
+///
+/// The latest offset deflects a needle from 12 o'clock (clockwise when the
+/// system clock is fast) on a decade full scale, with the round-trip
+/// uncertainty as a band around it. The needle eases in after each new
+/// measurement. Below it sit the offset history sparkline and drift rate.
+pub fn draw_calibration_view(
+    draw: &Draw,
+    calibration: &Calibration,
+    center: Point2,
+    radius: f32,
+    reduced_motion: bool,
+) {
+    let text_width = radius * 1.4;
+    let Some(latest) = calibration.latest() else {
+        let status = if calibration.is_measuring() {
+            "MEASURING…"
+        } else {
+            "NO NTP MEASUREMENT"
+        };
+        draw.text(tr(status))
+            .xy(center + vec2(0.0, radius * 0.3))
+            .color(colors::text_secondary())
+            .font_size(12)
+            .w(text_width);
+        return;
+    };
+
+    let progress = if reduced_motion {
+        1.0
+    } else {
+        let t = (latest.measured_at.elapsed().as_secs_f32() / CALIBRATION_EASE_SECS).min(1.0);
+        1.0 - (1.0 - t).powi(3)
+    };
+
+    // System fast (negative offset) deflects clockwise; a quarter turn is
+    // full scale
+    let scale = full_scale_ms(latest.offset_ms.abs() + latest.round_trip_ms / 2.0);
+    let angle_for = |ms: f64| PI / 2.0 + (ms / scale) as f32 * (PI / 2.0);
+    let arc_radius = radius * 0.78;
+
+    // Round-trip uncertainty band: offset ± half the round trip
+    let half_band = latest.round_trip_ms / 2.0 * progress as f64;
+    let band_start = angle_for(latest.offset_ms * progress as f64 - half_band);
+    let band_end = angle_for(latest.offset_ms * progress as f64 + half_band);
+    let segments = 48;
+    let band: Vec<Point2> = (0..=segments)
+        .map(|i| {
+            let angle = band_start + (band_end - band_start) * i as f32 / segments as f32;
+            center + vec2(angle.cos(), angle.sin()) * arc_radius
+        })
+        .collect();
+    draw.polyline()
+        .weight(6.0)
+        .color(srgba(0u8, 106u8, 128u8, (160.0 * progress) as u8))
+        .points(band);
+
+    // Needle at the (easing) offset
+    let needle_angle = angle_for(latest.offset_ms * progress as f64);
+    let direction = vec2(needle_angle.cos(), needle_angle.sin());
+    draw.line()
+        .start(center + direction * (arc_radius - 10.0))
+        .end(center + direction * (arc_radius + 10.0))
+        .color(colors::accent())
+        .weight(3.0);
+    draw.text(&format!("±{}", format_ms(scale).trim_start_matches('+')))
+        .xy(center + vec2(0.0, arc_radius - 18.0))
+        .color(colors::TICK_MAJOR)
+        .font_size(10)
+        .w(text_width);
+
+    draw.text(&tr_args("OFFSET {0}", &[&format_ms(latest.offset_ms)]))
+        .xy(center + vec2(0.0, radius * 0.3))
+        .color(colors::text_primary())
+        .font_size(14)
+        .w(text_width);
+    let round_trip = format_ms(latest.round_trip_ms);
+    draw.text(&tr_args("RTT {0}", &[&round_trip.trim_start_matches('+')]))
+        .xy(center + vec2(0.0, radius * 0.18))
+        .color(colors::text_secondary())
+        .font_size(12)
+        .w(text_width);
+    let drift = match calibration.drift_ppm() {
+        Some(ppm) => tr_args("DRIFT {0} ppm", &[&format!("{:+.1}", ppm)]),
+        None => tr("DRIFT — (needs 1 min of samples)").to_string(),
+    };
+    draw.text(&drift)
+        .xy(center + vec2(0.0, -radius * 0.18))
+        .color(colors::text_secondary())
+        .font_size(12)
+        .w(text_width);
+
+    draw_offset_sparkline(draw, calibration, center + vec2(0.0, -radius * 0.4), radius);
+}
+
+/// Offset history as a sparkline centered on `center`, zero as a dim baseline
+fn draw_offset_sparkline(draw: &Draw, calibration: &Calibration, center: Point2, radius: f32) {
+    let history = calibration.history();
+    let width = radius * 0.9;
+    let height = radius * 0.18;
+    let left = center.x - width / 2.0;
+
+    draw.line()
+        .start(pt2(left, center.y))
+        .end(pt2(left + width, center.y))
+        .color(colors::TICK_NORMAL)
+        .weight(1.0);
+    if history.len() < 2 {
+        return;
+    }
+
+    let max_ms = history
+        .iter()
+        .map(|s| s.offset_ms.abs())
+        .fold(0.0, f64::max)
+        .max(0.001);
+    let step = width / (history.len() - 1) as f32;
+    let points: Vec<Point2> = history
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            let y = (s.offset_ms / max_ms) as f32 * height / 2.0;
+            pt2(left + i as f32 * step, center.y + y)
+        })
+        .collect();
+    draw.polyline().weight(1.5).color(colors::accent()).points(points);
+}
+
 /// Draw a ring (circle outline) using line segments
 fn draw_ring(draw: &Draw, center: Point2, radius: f32, weight: f32, color: Srgb<u8>) {
     let segments = 120;
@@ -291,7 +767,7 @@ pub fn draw_toasts(draw: &Draw, toasts: &[ToastMessage], window_rect: Rect) {
         // Text
         draw.text(&toast.text)
             .xy(pos)
-            .color(srgba(colors::TEXT_PRIMARY.red, colors::TEXT_PRIMARY.green, colors::TEXT_PRIMARY.blue, alpha))
+            .color(srgba(colors::text_primary().red, colors::text_primary().green, colors::text_primary().blue, alpha))
             .font_size(14)
             .w(toast_width - padding * 2.0);
     }
@@ -303,41 +779,33 @@ pub fn draw_dst_status(draw: &Draw, time_data: &TimeData, position: Point2) {
     let status_text = match &time_data.dst_change {
         DstChange::None => {
             if time_data.is_dst {
-                "DST Active".to_string()
+                tr("DST Active").to_string()
             } else {
-                "Standard Time".to_string()
+                tr("Standard Time").to_string()
             }
         }
         DstChange::Upcoming { delta_minutes, .. } => {
-            let direction = if *delta_minutes > 0 {
-                "forward"
+            let text = if *delta_minutes > 0 {
+                "DST change: forward {0}min in <24h"
             } else {
-                "back"
+                "DST change: back {0}min in <24h"
             };
-            format!(
-                "DST change: {} {}min in <24h",
-                direction,
-                delta_minutes.abs()
-            )
+            tr_args(text, &[&delta_minutes.abs()])
         }
         DstChange::JustOccurred { delta_minutes, .. } => {
-            let direction = if *delta_minutes > 0 {
-                "forward"
+            let text = if *delta_minutes > 0 {
+                "DST changed: forward {0}min"
             } else {
-                "back"
+                "DST changed: back {0}min"
             };
-            format!(
-                "DST changed: {} {}min",
-                direction,
-                delta_minutes.abs()
-            )
+            tr_args(text, &[&delta_minutes.abs()])
         }
     };
     
     let color = match &time_data.dst_change {
-        DstChange::None => colors::TEXT_SECONDARY,
-        DstChange::Upcoming { .. } => colors::WARNING,
-        DstChange::JustOccurred { .. } => colors::ACCENT,
+        DstChange::None => colors::text_secondary(),
+        DstChange::Upcoming { .. } => colors::warning(),
+        DstChange::JustOccurred { .. } => colors::accent(),
     };
     
     draw.text(&status_text)
@@ -366,11 +834,31 @@ pub fn draw_error_banner(draw: &Draw, message: &str, rect: Rect) {
     // Text
     draw.text(message)
         .xy(banner_rect.xy())
-        .color(colors::TEXT_PRIMARY)
+        .color(colors::text_primary())
         .font_size(14)
         .w(banner_rect.w() - 20.0);
 }
 
+/// Flash the window for a chime strike: an accent wash and a bright border,
+/// both at `alpha` (0 to 1)
+pub fn draw_chime_flash(draw: &Draw, rect: Rect, alpha: f32) {
+    if alpha <= 0.0 {
+        return;
+    }
+    let accent = colors::accent();
+    let color = |a: f32| srgba(accent.red, accent.green, accent.blue, (a * 255.0) as u8);
+    draw.rect()
+        .xy(rect.xy())
+        .wh(rect.wh())
+        .color(color(alpha * 0.18));
+    draw.rect()
+        .xy(rect.xy())
+        .wh(rect.wh() - vec2(8.0, 8.0))
+        .no_fill()
+        .stroke(color(alpha))
+        .stroke_weight(8.0);
+}
+
 /// Calculate layout rectangles for two-column layout
 pub struct Layout {
     pub left_panel: Rect,
@@ -0,0 +1,1435 @@
+//! Precision Instrument Clock
+//!
+//! A clock as a calibrated instrument panel: crisp typography, grid-aligned readouts,
+//! and a secondary "calibration ring" that visualizes seconds. Optional chimes
+//! strike the hour and half hour as ship's bells or tones, or flash the panel.
+
+mod calibration;
+mod chime;
+mod compact;
+mod drawing;
+mod readout;
+mod stopwatch;
+mod ui;
+
+use std::time::Instant;
+
+use chrono_tz::Tz;
+use nannou::event::ModifiersState;
+use nannou::prelude::*;
+use nannou_egui::{self, egui, Egui};
+use serde::{Deserialize, Serialize};
+use shared::{
+    compute_time_data_at, report_save, tr, tr_args, ClipRecorder, ClipSettings, ClipStep,
+    CommandPalette, CommandRegistry, ConfigRecovery, ConfigSaver, Favorites, FollowSystemZone,
+    FramePacer, KeyChord, Keymap, LanguageSetting, MotionPreference, NoFocusRegions, QuietHours,
+    RecoveryChoice, SafePaletteSetting, ScreenReader, SnapshotProgress, SnapshotStep,
+    SystemMotionWatcher, SystemZoneWatcher, ThemeLibrary, TickGranularity, Ticker, TimeData,
+    TimeReveal, Validity, VisualIntensity, WindowPlacement, WindowTitle, WindowTitleClock,
+};
+
+use crate::calibration::{Calibration, CalibrationSettings};
+use crate::chime::{chime_at, select_chime, Chime, ChimeEngine, ChimeFlash, ChimeSettings};
+use crate::compact::CompactWidget;
+use crate::drawing::{
+    colors, draw_calibration_ring, draw_calibration_view, draw_chime_flash, draw_compact_readout,
+    draw_error_banner, draw_lap_marks,
+    draw_primary_readout, draw_secondary_readout, draw_stopwatch_readout, draw_subsecond_sweep,
+    draw_time_scales, draw_toasts, readouts_bottom, Layout, ToastMessage,
+};
+use crate::readout::{HourCycle, ReadoutFormat, SecondaryReadout, StripEntry};
+use crate::stopwatch::{InstrumentMode, Lap, Stopwatch};
+use crate::ui::{
+    draw_dst_status_card, draw_favorites_chips, draw_settings_panel, draw_timezone_bar,
+    draw_timezone_picker, draw_world_strip, theme_visuals, PickerState,
+};
+
+const CLOCK_NAME: &str = "precision_instrument";
+const DEFAULT_TZ: &str = "America/Los_Angeles";
+const TOAST_DURATION_SECS: f32 = 3.0;
+const WINDOW_TITLE: &str = "Precision Instrument Clock";
+
+/// Run the clock; returns only when the window is closed
+pub fn run() {
+    nannou::app(model).update(update).run();
+}
+
+/// Launcher entry for this clock
+pub struct PrecisionInstrument;
+
+impl shared::ClockApp for PrecisionInstrument {
+    fn id(&self) -> &'static str {
+        CLOCK_NAME
+    }
+
+    fn title(&self) -> &'static str {
+        "Precision Instrument"
+    }
+
+    fn tagline(&self) -> &'static str {
+        "A calibrated instrument panel with a seconds calibration ring"
+    }
+
+    fn run(&self) {
+        run();
+    }
+}
+
+/// Persisted configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Config {
+    selected_tz_id: String,
+    favorites: Vec<String>,
+    /// Keep `favorites` instead of following the series-wide list
+    #[serde(default)]
+    own_favorites: bool,
+    reduced_motion: MotionPreference,
+    #[serde(default)]
+    mode: InstrumentMode,
+    /// Stopwatch elapsed time at last save (restored stopped)
+    #[serde(default)]
+    stopwatch_elapsed_ms: u64,
+    #[serde(default)]
+    laps: Vec<Lap>,
+    /// 12/24-hour cycle and custom readout patterns
+    #[serde(default)]
+    readout: ReadoutFormat,
+    /// Selected theme name; `None` uses the clock's own colors
+    #[serde(default)]
+    theme: Option<String>,
+    /// UTC (or other zone) readout under the primary one
+    #[serde(default)]
+    secondary: SecondaryReadout,
+    /// NTP calibration view and server
+    #[serde(default)]
+    calibration: CalibrationSettings,
+    /// Show every favorite's time in a strip under the readouts
+    #[serde(default)]
+    world_strip: bool,
+    /// Last shown as the compact always-on-top widget
+    #[serde(default)]
+    compact: bool,
+    /// Hourly and half-hourly chimes
+    #[serde(default)]
+    chimes: ChimeSettings,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            selected_tz_id: DEFAULT_TZ.to_string(),
+            favorites: vec![
+                "America/New_York".to_string(),
+                "America/Los_Angeles".to_string(),
+                "Europe/London".to_string(),
+                "Asia/Tokyo".to_string(),
+            ],
+            own_favorites: false,
+            reduced_motion: MotionPreference::Auto,
+            mode: InstrumentMode::default(),
+            stopwatch_elapsed_ms: 0,
+            laps: Vec::new(),
+            readout: ReadoutFormat::default(),
+            theme: None,
+            secondary: SecondaryReadout::default(),
+            calibration: CalibrationSettings::default(),
+            world_strip: false,
+            compact: false,
+            chimes: ChimeSettings::default(),
+        }
+    }
+}
+
+impl shared::VersionedConfig for Config {
+    const VERSION: u32 = 2;
+
+    /// Version 2 names the reduced-motion choice; earlier files hold a bool
+    fn migrate(_from_version: u32, payload: &mut toml::Table) {
+        shared::migrate_reduced_motion(payload);
+    }
+}
+
+/// Application state
+struct Model {
+    /// Current time data
+    time_data: TimeData,
+    /// Selected timezone
+    selected_tz: Tz,
+    /// Favorite timezones
+    favorites: Favorites,
+    /// Favorites chip under the keyboard cursor
+    favorite_cursor: Option<usize>,
+    /// Timezone picker state
+    picker_state: PickerState,
+    /// Ctrl+K command palette
+    command_palette: CommandPalette,
+    /// Actions listed in the command palette, and the chords they're bound to
+    keymap: Keymap,
+    /// Reduced motion setting: follow the system, or override it
+    motion_preference: MotionPreference,
+    /// Reports the system's reduce-motion preference
+    system_motion: SystemMotionWatcher,
+    /// Whether motion is reduced, from the setting and the system
+    reduced_motion: bool,
+    /// Shared quiet-hours schedule
+    quiet_hours: QuietHours,
+    /// Brightness and effects allowed right now by the schedule
+    intensity: VisualIntensity,
+    /// Shared setting for the time in the window title
+    window_title_clock: WindowTitleClock,
+    /// Shared settings for clips recorded with Ctrl+Shift+R
+    clip_settings: ClipSettings,
+    /// Clip being recorded or saved, if any
+    clip_recorder: ClipRecorder,
+    /// Shared language for labels, dates and toasts
+    language: LanguageSetting,
+    /// Shared high-contrast or color-blind safe palette
+    safe_palette: SafePaletteSetting,
+    /// Keeps the window title's time current
+    window_title: WindowTitle,
+    /// Remembers the window's size, position and monitor
+    window_placement: WindowPlacement,
+    /// Shared setting for switching to the system zone when it changes
+    follow_system_zone: FollowSystemZone,
+    /// Reports changes to the computer's time zone
+    system_zone: SystemZoneWatcher,
+    /// Shared system-wide shortcut that shows the time from the background
+    time_reveal: TimeReveal,
+    /// Drops the frame rate while the clock sits idle
+    frame_pacer: FramePacer,
+    /// Writes config changes once they settle
+    config_saver: ConfigSaver,
+    /// Shown when the saved config couldn't be read
+    config_recovery: Option<ConfigRecovery>,
+    /// Clock or stopwatch
+    mode: InstrumentMode,
+    /// Stopwatch state and lap history
+    stopwatch: Stopwatch,
+    /// How the clock readout and date line are formatted
+    readout: ReadoutFormat,
+    /// Secondary zone readout settings
+    secondary: SecondaryReadout,
+    /// Current time data in the secondary zone
+    secondary_time: TimeData,
+    /// Whether the world clock strip is shown under the readouts
+    world_strip: bool,
+    /// Each favorite's time for the world clock strip (refreshed every tick)
+    strip_entries: Vec<StripEntry>,
+    /// Compact always-on-top widget, or the full layout
+    compact: CompactWidget,
+    /// NTP calibration view settings
+    calibration_settings: CalibrationSettings,
+    /// NTP offsets measured this session
+    calibration: Calibration,
+    /// Hourly and half-hourly chime settings
+    chime_settings: ChimeSettings,
+    /// Audio output for chimes (None without the `audio` feature or a device)
+    chimes: Option<ChimeEngine>,
+    /// The chime currently flashing the panel, if any
+    chime_flash: Option<ChimeFlash>,
+    /// Whether a settings text field has keyboard focus
+    text_input_focused: bool,
+    /// Built-in and user themes
+    themes: ThemeLibrary,
+    /// Active theme name; `None` uses the clock's own colors
+    theme: Option<String>,
+    /// Error message to display (if any)
+    error_message: Option<String>,
+    /// Screen reader view of the time, DST status and focus
+    screen_reader: ScreenReader,
+
+    /// egui integration
+    egui: Egui,
+    /// Current mouse position
+    mouse_pos: Point2,
+    /// Active toast notifications
+    toasts: Vec<ToastMessage>,
+    /// Whether window is focused (for resync)
+    is_focused: bool,
+    /// Wall-clock ticker driving time recomputation
+    ticker: Ticker,
+    /// Frame counter for `--snapshot` runs
+    snapshot: SnapshotProgress,
+    /// Layout info for hover detection
+    #[allow(dead_code)]
+    ring_center: Point2,
+    #[allow(dead_code)]
+    ring_radius: f32,
+}
+
+fn save_config(model: &Model) {
+    let config = Config {
+        selected_tz_id: model.selected_tz.name().to_string(),
+        favorites: model.favorites.own_ids(),
+        own_favorites: model.favorites.uses_own(),
+        reduced_motion: model.motion_preference,
+        mode: model.mode,
+        stopwatch_elapsed_ms: model.stopwatch.elapsed_ms_at(Instant::now()),
+        laps: model.stopwatch.laps().to_vec(),
+        readout: model.readout.clone(),
+        theme: model.theme.clone(),
+        secondary: model.secondary.clone(),
+        calibration: model.calibration_settings.clone(),
+        world_strip: model.world_strip,
+        compact: model.compact.is_enabled(),
+        chimes: model.chime_settings.clone(),
+    };
+    model.config_saver.request(&config);
+}
+
+fn add_toast(model: &mut Model, message: String) {
+    model.toasts.push(ToastMessage {
+        text: message,
+        created_at: Instant::now(),
+        duration_secs: TOAST_DURATION_SECS,
+    });
+}
+
+/// Apply a reduced-motion setting against the current system preference
+fn set_motion_preference(model: &mut Model, preference: MotionPreference) {
+    model.motion_preference = preference;
+    model.reduced_motion = preference.resolve(model.system_motion.reduced());
+}
+
+fn set_mode(model: &mut Model, mode: InstrumentMode) {
+    model.mode = mode;
+    save_config(model);
+    let msg = match mode {
+        InstrumentMode::Clock => tr("Clock mode"),
+        InstrumentMode::Stopwatch => tr("Stopwatch mode"),
+    };
+    add_toast(model, msg.to_string());
+}
+
+/// Time data for `tz` as the readout shows it, smeared through leap seconds
+/// when set to
+fn clock_time(readout: &ReadoutFormat, tz: Tz) -> TimeData {
+    compute_time_data_at(tz, readout.leap_display.clock_instant(shared::now_utc()))
+}
+
+/// Each favorite's time for the world clock strip, against the primary readout
+fn strip_entries(readout: &ReadoutFormat, favorites: &[Tz], primary: &TimeData) -> Vec<StripEntry> {
+    favorites
+        .iter()
+        .map(|&tz| StripEntry::new(&clock_time(readout, tz), primary, readout.hour_cycle))
+        .collect()
+}
+
+/// Recompute the world clock strip, if it's shown
+fn refresh_world_strip(model: &mut Model) {
+    model.strip_entries = if model.world_strip {
+        strip_entries(&model.readout, &model.favorites, &model.time_data)
+    } else {
+        Vec::new()
+    };
+}
+
+fn toggle_world_strip(model: &mut Model) {
+    model.world_strip = !model.world_strip;
+    refresh_world_strip(model);
+    save_config(model);
+    let msg = if model.world_strip {
+        tr("World clock strip shown")
+    } else {
+        tr("World clock strip hidden")
+    };
+    add_toast(model, msg.to_string());
+}
+
+/// Shrink to the compact widget, or restore the full layout
+fn set_compact(app: &App, model: &mut Model, enabled: bool) {
+    model.compact.set(&app.main_window(), enabled);
+    model.time_reveal.set_pinned(enabled);
+    if enabled {
+        model.picker_state.close();
+        model.favorite_cursor = None;
+    }
+    save_config(model);
+}
+
+fn set_secondary(model: &mut Model, secondary: SecondaryReadout) {
+    model.secondary_time = clock_time(&model.readout, secondary.zone());
+    model.secondary = secondary;
+    save_config(model);
+}
+
+fn set_calibration(model: &mut Model, settings: CalibrationSettings) {
+    if settings.enabled && !model.calibration_settings.enabled {
+        model.calibration.start(settings.server(), Instant::now());
+    }
+    model.calibration_settings = settings;
+    save_config(model);
+}
+
+fn toggle_calibration(model: &mut Model) {
+    let mut settings = model.calibration_settings.clone();
+    settings.enabled = !settings.enabled;
+    let msg = if settings.enabled {
+        tr_args("NTP calibration: {0}", &[&settings.server()])
+    } else {
+        tr("NTP calibration hidden").to_string()
+    };
+    set_calibration(model, settings);
+    add_toast(model, msg);
+}
+
+/// Collect a finished NTP measurement and start the next one when due
+fn update_calibration(model: &mut Model) {
+    if let Some(Err(e)) = model.calibration.poll() {
+        add_toast(model, tr_args("NTP measurement failed: {0}", &[&e]));
+    }
+    let now = Instant::now();
+    if model.calibration_settings.enabled && model.calibration.is_due(now) {
+        model.calibration.start(model.calibration_settings.server(), now);
+    }
+}
+
+/// Sound and flash a chime as its settings, quiet hours and the audio
+/// device allow
+fn strike_chime(model: &mut Model, chime: Chime) {
+    if model.chime_settings.audible && model.intensity.sounds {
+        if let Some(engine) = &model.chimes {
+            engine.play(chime);
+        }
+    }
+    if model.chime_settings.flash && model.intensity.animations {
+        model.chime_flash = Some(ChimeFlash {
+            chime,
+            started: Instant::now(),
+        });
+    }
+}
+
+fn toggle_stopwatch(model: &mut Model) {
+    model.stopwatch.toggle(Instant::now());
+    save_config(model);
+}
+
+fn record_lap(model: &mut Model) {
+    if model.stopwatch.lap(Instant::now()).is_some() {
+        save_config(model);
+    }
+}
+
+fn reset_stopwatch(model: &mut Model) {
+    model.stopwatch.reset();
+    save_config(model);
+    add_toast(model, tr("Stopwatch reset").to_string());
+}
+
+fn model(app: &App) -> Model {
+    // Disable default escape-to-close behavior - we handle Escape ourselves
+    app.set_exit_on_escape(false);
+    
+    // Create window
+    let window_id = app
+        .new_window()
+        .title(WINDOW_TITLE)
+        .size(900, 600)
+        .view(view)
+        .key_pressed(key_pressed)
+        .mouse_moved(mouse_moved)
+        .mouse_pressed(mouse_pressed)
+        .focused(window_focused)
+        .unfocused(window_unfocused)
+        .raw_event(raw_window_event)
+        .visible(false)
+        .build()
+        .unwrap();
+
+    let window = app.window(window_id).unwrap();
+    let egui = Egui::from_window(&window);
+    shared::install_fallback_fonts(egui.ctx());
+
+    // Load configuration, noting an unreadable file for the recovery prompt
+    let (config, config_recovery) = shared::load_config_or_recover(CLOCK_NAME);
+    let config: Config = config.unwrap_or_default();
+
+    // Parse timezone from config
+    let selected_tz: Tz = config
+        .selected_tz_id
+        .parse()
+        .unwrap_or_else(|_| DEFAULT_TZ.parse().unwrap());
+
+    // The shared favorites, or this clock's own
+    let favorites = Favorites::load(&config.favorites, config.own_favorites);
+
+    let themes = ThemeLibrary::load();
+    let theme = themes.activate(config.theme.as_deref());
+    let system_motion = SystemMotionWatcher::spawn();
+
+    // Compute initial time data
+    let time_data = clock_time(&config.readout, selected_tz);
+    let secondary_time = clock_time(&config.readout, config.secondary.zone());
+    let strip = if config.world_strip {
+        strip_entries(&config.readout, &favorites, &time_data)
+    } else {
+        Vec::new()
+    };
+
+    // Screen reader tree, attached before the window is first shown
+    let screen_reader = ScreenReader::attach::<NoFocusRegions>(
+        window.winit_window(),
+        "Precision Instrument Clock",
+        &time_data,
+        None,
+    );
+
+    // Reopen where the window was left, or where the command line asks
+    let mut window_placement = WindowPlacement::load(CLOCK_NAME);
+    window_placement.restore(window.winit_window());
+    let mut compact = CompactWidget::new();
+    compact.set(&window, config.compact);
+    let mut time_reveal = TimeReveal::load();
+    time_reveal.set_pinned(config.compact);
+    window.set_visible(shared::snapshot_request().is_none());
+
+    let wake_proxy = app.create_proxy();
+    Model {
+        time_data,
+        selected_tz,
+        favorites,
+        favorite_cursor: None,
+        picker_state: PickerState::default(),
+        command_palette: CommandPalette::default(),
+        keymap: Keymap::load(CLOCK_NAME, commands()),
+        motion_preference: config.reduced_motion,
+        reduced_motion: config.reduced_motion.resolve(system_motion.reduced()),
+        system_motion,
+        quiet_hours: QuietHours::load(),
+        intensity: VisualIntensity::FULL,
+        window_title_clock: WindowTitleClock::load(),
+        clip_settings: ClipSettings::load(),
+        clip_recorder: ClipRecorder::new(CLOCK_NAME),
+        language: LanguageSetting::load(),
+        safe_palette: SafePaletteSetting::load(),
+        window_title: WindowTitle::new(WINDOW_TITLE),
+        window_placement,
+        follow_system_zone: FollowSystemZone::load(),
+        system_zone: SystemZoneWatcher::spawn(),
+        time_reveal,
+        frame_pacer: FramePacer::spawn(move || wake_proxy.wakeup().is_ok()),
+        config_saver: ConfigSaver::new(CLOCK_NAME),
+        config_recovery,
+        mode: config.mode,
+        stopwatch: Stopwatch::restore(config.stopwatch_elapsed_ms, config.laps),
+        readout: config.readout,
+        secondary: config.secondary,
+        secondary_time,
+        world_strip: config.world_strip,
+        strip_entries: strip,
+        compact,
+        calibration_settings: config.calibration,
+        calibration: Calibration::default(),
+        chime_settings: config.chimes,
+        chimes: ChimeEngine::new(),
+        chime_flash: None,
+        text_input_focused: false,
+        themes,
+        theme,
+        error_message: None,
+        screen_reader,
+        egui,
+        mouse_pos: pt2(0.0, 0.0),
+        toasts: Vec::new(),
+        is_focused: true,
+        ticker: Ticker::spawn(TickGranularity::Second),
+        snapshot: SnapshotProgress::default(),
+        ring_center: pt2(0.0, 0.0),
+        ring_radius: 0.0,
+    }
+}
+
+/// Drive a `--snapshot` run: capture once the frame has settled, then quit
+fn advance_snapshot(app: &App, progress: &mut SnapshotProgress) {
+    let Some(request) = shared::snapshot_request() else {
+        return;
+    };
+    match progress.advance() {
+        SnapshotStep::Settle => {}
+        SnapshotStep::Capture => app.main_window().capture_frame(&request.path),
+        SnapshotStep::Finish => {
+            if app.main_window().await_capture_frame_jobs().is_err() {
+                eprintln!("Timed out writing snapshot to {}", request.path.display());
+                std::process::exit(1);
+            }
+            println!("Saved snapshot to {}", request.path.display());
+            app.quit();
+        }
+    }
+}
+
+/// Capture frames for a clip being recorded, and report the clip once saved
+fn advance_clip(app: &App, model: &mut Model) {
+    match model.clip_recorder.advance(Instant::now()) {
+        ClipStep::Idle => {}
+        ClipStep::Capture(path) => app.main_window().capture_frame(path),
+        ClipStep::Finish => {
+            let written = app.main_window().await_capture_frame_jobs().is_ok();
+            model.clip_recorder.encode(written);
+        }
+    }
+    if let Some(message) = model.clip_recorder.poll() {
+        add_toast(model, message);
+    }
+}
+
+/// Whether something on screen moves faster than once a second: running
+/// milliseconds, a chime flash, a toast or a clip being captured
+fn is_animating(model: &Model, now: Instant) -> bool {
+    let running_digits = match model.mode {
+        InstrumentMode::Clock => model.readout.high_precision,
+        InstrumentMode::Stopwatch => model.stopwatch.is_running(),
+    };
+    running_digits
+        || model.chime_flash.is_some_and(|flash| !flash.is_finished(now))
+        || !model.toasts.is_empty()
+        || model.clip_recorder.is_recording()
+        || model.time_reveal.is_flashing()
+}
+
+/// Let the event loop wait between second boundaries while the clock is idle
+fn pace_frames(app: &App, model: &mut Model, now: Instant) {
+    let animating = is_animating(model, now);
+    let loop_mode = if model.frame_pacer.pace(animating, now) {
+        LoopMode::Wait
+    } else {
+        LoopMode::RefreshSync
+    };
+    app.set_loop_mode(loop_mode);
+}
+
+/// Act on the config recovery prompt: restart on the backup, or carry on
+/// with the defaults
+fn recover_config(app: &App, model: &mut Model, choice: RecoveryChoice) {
+    let Some(recovery) = model.config_recovery.take() else {
+        return;
+    };
+    if choice == RecoveryChoice::Restore {
+        match recovery.restore(&model.config_saver) {
+            Ok(()) => app.quit(),
+            Err(message) => add_toast(model, message),
+        }
+    }
+}
+
+fn update(app: &App, model: &mut Model, update: Update) {
+    advance_snapshot(app, &mut model.snapshot);
+    advance_clip(app, model);
+
+    // Recompute time data only when the ticker crosses a second boundary;
+    // between ticks just advance the fraction for the sweep indicator
+    let tick = model.ticker.poll();
+    if tick.is_some() {
+        model.time_data = clock_time(&model.readout, model.selected_tz);
+        model.secondary_time = clock_time(&model.readout, model.secondary.zone());
+        refresh_world_strip(model);
+    } else {
+        model.time_data.refresh_second_fraction(shared::now_utc());
+    }
+    model.intensity = model.quiet_hours.intensity(&model.time_data);
+
+    // Strike the hour or half hour as the new second reaches it
+    if tick.is_some() {
+        if let Some(chime) = select_chime(&model.time_data, &model.chime_settings) {
+            strike_chime(model, chime);
+        }
+    }
+    let now = Instant::now();
+    if model.chime_flash.is_some_and(|flash| flash.is_finished(now)) {
+        model.chime_flash = None;
+    }
+    model.time_reveal.update(app.main_window().winit_window(), now);
+    model.config_saver.poll(now);
+    pace_frames(app, model, now);
+
+    // Check for validity issues
+    if model.time_data.validity != Validity::Ok {
+        model.error_message = Some(match model.time_data.validity {
+            Validity::TzMissing => tr("Time zone data missing. Showing UTC.").to_string(),
+            Validity::TzDataStale => tr("Time zone data may be outdated.").to_string(),
+            Validity::Unknown => tr("Unknown time zone issue.").to_string(),
+            Validity::Ok => unreachable!(),
+        });
+    }
+
+    update_calibration(model);
+
+    // Remove expired toasts
+    model.toasts.retain(|toast| {
+        toast.created_at.elapsed().as_secs_f32() < toast.duration_secs
+    });
+
+    let focused_chip = focused_favorite(model);
+
+    // The world clock strip sits under the readouts in the left panel
+    let strip_placement = (model.world_strip && model.mode == InstrumentMode::Clock).then(|| {
+        let window_rect = app.window_rect();
+        let panel = Layout::calculate(window_rect).left_panel;
+        let top = readouts_bottom(&model.time_data, &model.readout, model.secondary.enabled, panel);
+        let top_left = egui::pos2(panel.left() - window_rect.left(), window_rect.top() - top);
+        (top_left, panel.w())
+    });
+
+    // Begin egui frame
+    model.egui.set_elapsed_time(update.since_start);
+    let ctx = model.egui.begin_frame();
+
+    let mut visuals = egui::Visuals::dark();
+    theme_visuals(&mut visuals);
+    ctx.set_visuals(visuals);
+
+    // The compact widget has no panels; only the command palette opens over it
+    if model.compact.is_enabled() {
+        let palette_choice = model.command_palette.show(&ctx, model.keymap.registry());
+        drop(ctx);
+        if let Some(chord) = palette_choice {
+            handle_key(app, model, chord.key, chord.mods);
+        }
+        follow_surroundings(app, model);
+        return;
+    }
+
+    // Collect UI state needed for drawing
+    let current_tz = model.selected_tz;
+    let favorites_clone = model.favorites.to_vec();
+    let time_data_clone = model.time_data.clone();
+    let mut motion_preference = model.motion_preference;
+    let system_reduced_motion = model.system_motion.reduced();
+    let mut mode = model.mode;
+    let mut readout = model.readout.clone();
+    let mut secondary = model.secondary.clone();
+    let mut world_strip = model.world_strip;
+    let mut calibration_settings = model.calibration_settings.clone();
+    let mut chime_settings = model.chime_settings.clone();
+    let audio_available = model.chimes.is_some();
+    let mut quiet_hours = model.quiet_hours.clone();
+    let mut window_title_clock = model.window_title_clock;
+    let mut clip_settings = model.clip_settings;
+    let mut language = model.language;
+    let mut safe_palette = model.safe_palette;
+    let mut follow_system_zone = model.follow_system_zone;
+    let mut reveal_hotkey = model.time_reveal.setting.clone();
+    let mut own_favorites = model.favorites.uses_own();
+
+    // Draw timezone bar (top)
+    let bar_clicked = draw_timezone_bar(&ctx, &time_data_clone);
+    if bar_clicked {
+        model.picker_state.open();
+    }
+
+    // Draw timezone picker (if open)
+    let picker_result = draw_timezone_picker(
+        &ctx,
+        &mut model.picker_state,
+        current_tz,
+        &favorites_clone,
+    );
+
+    // Draw DST status card
+    draw_dst_status_card(&ctx, &time_data_clone, current_tz);
+
+    // Draw settings panel
+    let settings_result = draw_settings_panel(
+        &ctx,
+        &mut motion_preference,
+        system_reduced_motion,
+        &mut mode,
+        model.stopwatch.is_running(),
+        &mut readout,
+        &mut secondary,
+        &mut world_strip,
+        &mut calibration_settings,
+        model.calibration.is_measuring(),
+        &mut chime_settings,
+        audio_available,
+        &time_data_clone,
+        &favorites_clone,
+        &mut quiet_hours,
+        &mut window_title_clock,
+        &mut clip_settings,
+        &mut language,
+        &mut safe_palette,
+        &mut follow_system_zone,
+        &mut reveal_hotkey,
+        model.time_reveal.status(),
+        &mut own_favorites,
+        &model.keymap,
+        &model.themes,
+        model.theme.as_deref(),
+    );
+
+    // Draw favorites chips (bottom)
+    let favorites_selection =
+        draw_favorites_chips(&ctx, &favorites_clone, current_tz, focused_chip);
+
+    // Draw the world clock strip under the readouts
+    let strip_selection = strip_placement.and_then(|(top_left, width)| {
+        draw_world_strip(&ctx, &model.strip_entries, current_tz, top_left, width)
+    });
+
+    // Draw the time card while the reveal shortcut flashes the window
+    model.time_reveal.show(&ctx, &time_data_clone);
+
+    // Offer the backup if the saved config couldn't be read
+    let recovery_choice = model.config_recovery.as_ref().and_then(|r| r.show(&ctx));
+
+    // Draw command palette (if open)
+    let palette_choice = model.command_palette.show(&ctx, model.keymap.registry());
+
+    model.text_input_focused = ctx.wants_keyboard_input();
+
+    // Now apply UI results after egui frame is done (ctx is dropped here)
+    drop(ctx);
+    if let Some(choice) = recovery_choice {
+        recover_config(app, model, choice);
+    }
+
+    // Handle picker result
+    if let Some(tz) = picker_result.selected_tz {
+        model.selected_tz = tz;
+        model.time_data = clock_time(&model.readout, tz);
+        model.error_message = None;
+        save_config(model);
+    }
+    if let Some(tz) = picker_result.toggle_favorite {
+        model.favorites.toggle(tz);
+        save_config(model);
+    }
+    if picker_result.close_picker {
+        model.picker_state.close();
+    }
+
+    // Handle settings change
+    if settings_result.reduced_motion_changed {
+        set_motion_preference(model, motion_preference);
+        save_config(model);
+    }
+    if settings_result.quiet_hours_changed {
+        model.quiet_hours = quiet_hours;
+        report_save("quiet hours", model.quiet_hours.save(), |message| add_toast(model, message));
+    }
+    if settings_result.window_title_changed {
+        model.window_title_clock = window_title_clock;
+        report_save(
+            "window title setting",
+            model.window_title_clock.save(),
+            |message| add_toast(model, message),
+        );
+    }
+    if settings_result.clip_settings_changed {
+        model.clip_settings = clip_settings;
+        report_save(
+            "recording settings",
+            model.clip_settings.save(),
+            |message| add_toast(model, message),
+        );
+    }
+    if settings_result.language_changed {
+        model.language = language;
+        report_save("language setting", model.language.save(), |message| add_toast(model, message));
+    }
+    if settings_result.safe_palette_changed {
+        model.safe_palette = safe_palette;
+        report_save(
+            "palette setting",
+            model.safe_palette.save(),
+            |message| add_toast(model, message),
+        );
+    }
+    if settings_result.system_zone_changed {
+        model.follow_system_zone = follow_system_zone;
+        report_save(
+            "system zone setting",
+            model.follow_system_zone.save(),
+            |message| add_toast(model, message),
+        );
+    }
+    if settings_result.reveal_hotkey_changed {
+        model.time_reveal.setting = reveal_hotkey;
+        model.time_reveal.apply();
+    }
+    if settings_result.favorites_source_changed {
+        model.favorites.set_uses_own(own_favorites);
+        save_config(model);
+    }
+    if let Some(edit) = settings_result.keymap_edit {
+        model.keymap.apply(edit);
+    }
+    if settings_result.mode_changed {
+        set_mode(model, mode);
+    }
+    if settings_result.compact {
+        set_compact(app, model, true);
+    }
+    if settings_result.readout_changed {
+        model.readout = readout;
+        model.time_data = clock_time(&model.readout, model.selected_tz);
+        model.secondary_time = clock_time(&model.readout, model.secondary.zone());
+        refresh_world_strip(model);
+        save_config(model);
+    }
+    if settings_result.secondary_changed {
+        set_secondary(model, secondary);
+    }
+    if settings_result.world_strip_changed && world_strip != model.world_strip {
+        toggle_world_strip(model);
+    }
+    if settings_result.calibration_changed {
+        set_calibration(model, calibration_settings);
+    }
+    if settings_result.measure_now {
+        model.calibration.start(model.calibration_settings.server(), Instant::now());
+    }
+    if settings_result.chimes_changed {
+        model.chime_settings = chime_settings;
+        save_config(model);
+    }
+    if settings_result.test_chime {
+        let chime = chime_at(&model.time_data, false, &model.chime_settings);
+        strike_chime(model, chime);
+        add_toast(model, tr_args("Chime: {0}", &[&chime.describe()]));
+    }
+    if settings_result.toggle_stopwatch {
+        toggle_stopwatch(model);
+    }
+    if settings_result.record_lap {
+        record_lap(model);
+    }
+    if settings_result.reset_stopwatch {
+        reset_stopwatch(model);
+    }
+    if let Some(theme) = settings_result.set_theme {
+        model.theme = model.themes.activate(theme.as_deref());
+        save_config(model);
+    }
+
+    // Handle favorites selection, from the chips or the world clock strip
+    if let Some(tz) = favorites_selection.or(strip_selection) {
+        select_zone(model, tz);
+    }
+
+    // Run the command chosen in the palette as if its key were pressed
+    if let Some(chord) = palette_choice {
+        handle_key(app, model, chord.key, chord.mods);
+    }
+
+    follow_surroundings(app, model);
+}
+
+/// Keep up with the world outside the clock: the screen reader, favorites
+/// starred elsewhere, the system zone and motion settings, and the window title
+fn follow_surroundings(app: &App, model: &mut Model) {
+    // Keep the screen reader's time and DST status current
+    model.screen_reader.update::<NoFocusRegions>(&model.time_data, None);
+
+    // Pick up favorites starred in another clock
+    model.favorites.poll();
+
+    // Follow the computer's time zone when it changes
+    if let Some(change) = model.system_zone.poll() {
+        let switch = model.follow_system_zone.enabled && change.current != model.selected_tz;
+        if switch {
+            select_zone(model, change.current);
+        }
+        add_toast(model, change.message(switch));
+    }
+
+    // Follow the system reduce-motion setting when set to Auto
+    if model.system_motion.poll() {
+        set_motion_preference(model, model.motion_preference);
+    }
+
+    // Show the current time in the taskbar/dock title, and any clip recording
+    let badge = model.clip_recorder.indicator(Instant::now());
+    model.window_title.set_badge(badge);
+    model.window_title.update(
+        app.main_window().winit_window(),
+        &model.window_title_clock,
+        model.selected_tz,
+        shared::now_utc(),
+    );
+
+    // Remember where the full layout was left; the compact widget isn't saved
+    if !model.compact.is_enabled() {
+        model.window_placement.track(app.main_window().winit_window(), Instant::now());
+    }
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    let window_rect = app.window_rect();
+
+    // Clear background
+    draw.background().color(colors::background());
+
+    if model.compact.is_enabled() {
+        draw_compact_readout(
+            &draw,
+            &model.time_data,
+            &model.readout,
+            model.selected_tz.name(),
+            window_rect,
+        );
+        if let Some(flash) = &model.chime_flash {
+            draw_chime_flash(&draw, window_rect, flash.alpha(Instant::now(), model.reduced_motion));
+        }
+        if model.intensity.is_quiet() {
+            draw.rect()
+                .xy(window_rect.xy())
+                .wh(window_rect.wh())
+                .color(srgba(0.0, 0.0, 0.0, model.intensity.dim_alpha()));
+        }
+        draw.to_frame(app, &frame).unwrap();
+        model.egui.draw_to_frame(&frame).unwrap();
+        return;
+    }
+
+    // Calculate layout
+    let layout = Layout::calculate(window_rect);
+
+    // Draw calibration ring (right panel)
+    let ring_radius = layout.right_panel.w().min(layout.right_panel.h()) * 0.4;
+    let ring_center = layout.right_panel.xy();
+
+    // Draw primary readout (left panel); the ring follows wall-clock seconds
+    // or stopwatch elapsed seconds depending on mode
+    let (ring_second, ring_fraction) = match model.mode {
+        InstrumentMode::Clock => {
+            draw_primary_readout(&draw, &model.time_data, &model.readout, layout.left_panel);
+            if model.secondary.enabled {
+                draw_secondary_readout(
+                    &draw,
+                    &model.secondary_time,
+                    &model.time_data,
+                    layout.left_panel,
+                );
+            }
+            draw_time_scales(
+                &draw,
+                &model.time_data,
+                &model.readout,
+                model.secondary.enabled,
+                layout.left_panel,
+            );
+            (model.time_data.second, model.time_data.second_fraction)
+        }
+        InstrumentMode::Stopwatch => {
+            let elapsed = model.stopwatch.elapsed_at(Instant::now());
+            draw_stopwatch_readout(
+                &draw,
+                elapsed.as_millis() as u64,
+                model.stopwatch.is_running(),
+                model.stopwatch.laps(),
+                model.stopwatch.fastest_lap_index(),
+                layout.left_panel,
+            );
+            (
+                (elapsed.as_secs() % 60) as u32,
+                elapsed.subsec_nanos() as f64 / 1_000_000_000.0,
+            )
+        }
+    };
+    
+    // Check if mouse is hovering over ring
+    let mouse_dist = model.mouse_pos.distance(ring_center);
+    let is_hovering_ring = mouse_dist < ring_radius * 1.1 && mouse_dist > ring_radius * 0.3;
+    
+    draw_calibration_ring(
+        &draw,
+        ring_second,
+        ring_fraction,
+        ring_center,
+        ring_radius,
+        model.reduced_motion || !model.intensity.animations,
+        is_hovering_ring,
+    );
+    if model.readout.high_precision {
+        draw_subsecond_sweep(
+            &draw,
+            ring_fraction,
+            ring_center,
+            ring_radius,
+            model.reduced_motion || !model.intensity.animations,
+        );
+    }
+    if model.calibration_settings.enabled {
+        draw_calibration_view(
+            &draw,
+            &model.calibration,
+            ring_center,
+            ring_radius,
+            model.reduced_motion || !model.intensity.animations,
+        );
+    }
+    if model.mode == InstrumentMode::Stopwatch {
+        draw_lap_marks(
+            &draw,
+            model.stopwatch.laps(),
+            model.stopwatch.fastest_lap_index(),
+            ring_center,
+            ring_radius,
+        );
+    }
+
+    // Flash for the chime, under the quiet-hours dimming
+    if let Some(flash) = &model.chime_flash {
+        draw_chime_flash(&draw, window_rect, flash.alpha(Instant::now(), model.reduced_motion));
+    }
+
+    // Dim everything under the banners during quiet hours
+    if model.intensity.is_quiet() {
+        draw.rect()
+            .xy(window_rect.xy())
+            .wh(window_rect.wh())
+            .color(srgba(0.0, 0.0, 0.0, model.intensity.dim_alpha()));
+    }
+
+    // Draw error banner if needed
+    if let Some(ref message) = model.error_message {
+        draw_error_banner(&draw, message, window_rect);
+    }
+
+    // Draw toast notifications
+    draw_toasts(&draw, &model.toasts, window_rect);
+
+    // Render to frame
+    draw.to_frame(app, &frame).unwrap();
+
+    // Render egui on top
+    model.egui.draw_to_frame(&frame).unwrap();
+}
+
+/// Every action in the command palette, with the key that performs it
+fn commands() -> CommandRegistry {
+    CommandRegistry::new()
+        .register("Open timezone picker", KeyChord::new(Key::Slash))
+        .register("Toggle reduced motion", KeyChord::new(Key::R))
+        .register("Toggle 12/24-hour readout", KeyChord::new(Key::H))
+        .register("Toggle millisecond readout", KeyChord::new(Key::P))
+        .register("Toggle secondary UTC readout", KeyChord::new(Key::U))
+        .register("Toggle world clock strip", KeyChord::new(Key::W))
+        .register("Toggle compact widget", KeyChord::new(Key::M))
+        .register("Switch clock/stopwatch mode", KeyChord::shift(Key::M))
+        .register("Start/stop stopwatch", KeyChord::new(Key::S))
+        .register("Record lap", KeyChord::new(Key::L))
+        .register("Reset stopwatch", KeyChord::new(Key::Back))
+        .register("Toggle NTP calibration view", KeyChord::new(Key::N))
+        .register("Focus previous favorite", KeyChord::new(Key::Left))
+        .register("Focus next favorite", KeyChord::new(Key::Right))
+        .register("Move favorite left", KeyChord::ctrl(Key::Left))
+        .register("Move favorite right", KeyChord::ctrl(Key::Right))
+        .register("Record clip", KeyChord::ctrl_shift(Key::R))
+}
+
+fn key_pressed(app: &App, model: &mut Model, key: Key) {
+    let mods = app.keys.mods;
+
+    // While a shortcut is being rebound, the next chord becomes its binding
+    if model.keymap.capture(key, mods) {
+        return;
+    }
+
+    // Ctrl+K opens the command palette from anywhere; while open it takes
+    // every other key
+    if KeyChord::is_palette_toggle(key, mods) {
+        model.command_palette.toggle();
+        return;
+    }
+    if model.command_palette.is_open {
+        return;
+    }
+
+    // Don't treat typing in a readout pattern field as shortcuts
+    if model.text_input_focused && !model.picker_state.is_open {
+        return;
+    }
+
+    // Remapped chords reach the handler as their action's default chord
+    if let Some(chord) = model.keymap.translate(key, mods) {
+        handle_key(app, model, chord.key, chord.mods);
+    }
+}
+
+fn handle_key(app: &App, model: &mut Model, key: Key, mods: ModifiersState) {
+    match key {
+        // Ctrl+Shift+R - start or stop recording a clip
+        _ if shared::is_clip_toggle(key, mods) => {
+            let message = model.clip_recorder.toggle(model.clip_settings, Instant::now());
+            add_toast(model, message);
+        }
+
+        // Escape closes picker (if open)
+        Key::Escape if model.picker_state.is_open => {
+            model.picker_state.close();
+        }
+        // Otherwise it leaves the favorites chips
+        Key::Escape => {
+            model.favorite_cursor = None;
+        }
+        // The compact widget has no picker to open
+        Key::Slash | Key::Space | Key::Return if model.compact.is_enabled() => {}
+        // Slash focuses search (opens picker if needed)
+        Key::Slash => {
+            if !model.picker_state.is_open {
+                model.picker_state.open();
+            } else {
+                model.picker_state.should_focus_search = true;
+            }
+        }
+        // Enter selects the favorite under the keyboard cursor
+        Key::Return if !model.picker_state.is_open && focused_favorite(model).is_some() => {
+            if let Some(tz) = focused_favorite(model).map(|index| model.favorites[index]) {
+                select_zone(model, tz);
+            }
+        }
+        // Space/Enter opens picker when closed
+        Key::Space | Key::Return if !model.picker_state.is_open => {
+            model.picker_state.open();
+        }
+        // R toggles reduced motion
+        Key::R => {
+            let preference = model.motion_preference.toggled(model.system_motion.reduced());
+            set_motion_preference(model, preference);
+            save_config(model);
+            let msg = if model.reduced_motion {
+                tr("Reduced motion enabled")
+            } else {
+                tr("Reduced motion disabled")
+            };
+            add_toast(model, msg.to_string());
+        }
+        // H switches between 12- and 24-hour readout
+        Key::H if !model.picker_state.is_open => {
+            model.readout.hour_cycle = model.readout.hour_cycle.toggled();
+            save_config(model);
+            let msg = match model.readout.hour_cycle {
+                HourCycle::H12 => tr("12-hour readout"),
+                HourCycle::H24 => tr("24-hour readout"),
+            };
+            add_toast(model, msg.to_string());
+        }
+        // P shows or hides milliseconds and the sub-second sweep
+        Key::P if !model.picker_state.is_open => {
+            model.readout.high_precision = !model.readout.high_precision;
+            save_config(model);
+            let msg = if model.readout.high_precision {
+                tr("Millisecond readout on")
+            } else {
+                tr("Millisecond readout off")
+            };
+            add_toast(model, msg.to_string());
+        }
+        // U shows or hides the secondary readout
+        Key::U if !model.picker_state.is_open => {
+            let mut secondary = model.secondary.clone();
+            secondary.enabled = !secondary.enabled;
+            let msg = if secondary.enabled {
+                tr_args("Secondary readout: {0}", &[&secondary.zone().name()])
+            } else {
+                tr("Secondary readout hidden").to_string()
+            };
+            set_secondary(model, secondary);
+            add_toast(model, msg);
+        }
+        // W shows or hides the world clock strip
+        Key::W if !model.picker_state.is_open => {
+            toggle_world_strip(model);
+        }
+        // M shrinks to the compact widget and back
+        Key::M if !model.picker_state.is_open && !mods.shift() => {
+            set_compact(app, model, !model.compact.is_enabled());
+        }
+        // Shift+M switches between clock and stopwatch
+        Key::M if !model.picker_state.is_open => {
+            let mode = match model.mode {
+                InstrumentMode::Clock => InstrumentMode::Stopwatch,
+                InstrumentMode::Stopwatch => InstrumentMode::Clock,
+            };
+            set_mode(model, mode);
+        }
+        // Stopwatch controls: S start/stop, L lap, Backspace reset
+        Key::S if !model.picker_state.is_open && model.mode == InstrumentMode::Stopwatch => {
+            toggle_stopwatch(model);
+        }
+        Key::L if !model.picker_state.is_open && model.mode == InstrumentMode::Stopwatch => {
+            record_lap(model);
+        }
+        Key::Back if !model.picker_state.is_open && model.mode == InstrumentMode::Stopwatch => {
+            reset_stopwatch(model);
+        }
+        // N shows or hides the NTP calibration view
+        Key::N if !model.picker_state.is_open => {
+            toggle_calibration(model);
+        }
+        // ←/→ move along the favorites chips, Ctrl+←/→ reorder them
+        Key::Left | Key::Right if !model.picker_state.is_open => {
+            let delta = if key == Key::Left { -1 } else { 1 };
+            if mods.ctrl() || mods.logo() {
+                move_focused_favorite(model, delta);
+            } else {
+                move_favorite_cursor(model, delta);
+            }
+        }
+        // Arrow keys for picker navigation
+        Key::Up if model.picker_state.is_open => {
+            model.picker_state.move_selection(-1);
+        }
+        Key::Down if model.picker_state.is_open => {
+            model.picker_state.move_selection(1);
+        }
+        _ => {}
+    }
+}
+
+/// Switch the clock to `tz`
+fn select_zone(model: &mut Model, tz: Tz) {
+    model.selected_tz = tz;
+    model.time_data = clock_time(&model.readout, tz);
+    model.error_message = None;
+    refresh_world_strip(model);
+    save_config(model);
+}
+
+/// The favorites chip under the keyboard cursor, if it's still there
+fn focused_favorite(model: &Model) -> Option<usize> {
+    focused_index(model.favorite_cursor, model.favorites.len())
+}
+
+/// `cursor`, unless the favorites have shrunk out from under it
+fn focused_index(cursor: Option<usize>, count: usize) -> Option<usize> {
+    cursor.filter(|&index| index < count)
+}
+
+/// Move the keyboard cursor along the favorites chips
+///
+/// The first press lands on the selected zone's chip (or the first one).
+fn move_favorite_cursor(model: &mut Model, delta: isize) {
+    if let Some(cursor) =
+        moved_cursor(model.favorite_cursor, &model.favorites, model.selected_tz, delta)
+    {
+        model.favorite_cursor = Some(cursor);
+    }
+}
+
+/// Where the cursor lands after moving `delta` chips, clamped to the row
+fn moved_cursor(cursor: Option<usize>, zones: &[Tz], selected: Tz, delta: isize) -> Option<usize> {
+    let count = zones.len();
+    if count == 0 {
+        return None;
+    }
+    Some(match focused_index(cursor, count) {
+        Some(index) => index.saturating_add_signed(delta).min(count - 1),
+        None => zones.iter().position(|&tz| tz == selected).unwrap_or(0),
+    })
+}
+
+/// Swap the focused favorite with its neighbour, keeping it focused
+fn move_focused_favorite(model: &mut Model, delta: isize) {
+    if focused_favorite(model).is_none() {
+        move_favorite_cursor(model, delta);
+        return;
+    }
+    let mut zones = model.favorites.to_vec();
+    let Some(cursor) = swap_focused(&mut zones, model.favorite_cursor, delta) else {
+        return;
+    };
+    model.favorites.set_zones(zones);
+    model.favorite_cursor = Some(cursor);
+    save_config(model);
+}
+
+/// Swap the zone under `cursor` with the one `delta` along, returning where
+/// it ended up; `None` at either end of the row
+fn swap_focused(zones: &mut [Tz], cursor: Option<usize>, delta: isize) -> Option<usize> {
+    let index = focused_index(cursor, zones.len())?;
+    let other = index
+        .checked_add_signed(delta)
+        .filter(|&other| other < zones.len())?;
+    zones.swap(index, other);
+    Some(other)
+}
+
+fn mouse_pressed(app: &App, model: &mut Model, button: MouseButton) {
+    // The compact widget has no title bar, so dragging the readout moves it
+    if button != MouseButton::Left || !model.compact.is_enabled() || model.command_palette.is_open {
+        return;
+    }
+    if let Err(e) = app.main_window().winit_window().drag_window() {
+        eprintln!("Failed to drag window: {}", e);
+    }
+}
+
+fn mouse_moved(_app: &App, model: &mut Model, pos: Point2) {
+    model.mouse_pos = pos;
+}
+
+fn window_focused(_app: &App, model: &mut Model) {
+    // Resync time immediately when window regains focus
+    model.time_data = clock_time(&model.readout, model.selected_tz);
+    model.secondary_time = clock_time(&model.readout, model.secondary.zone());
+    model.is_focused = true;
+}
+
+fn window_unfocused(_app: &App, model: &mut Model) {
+    model.is_focused = false;
+}
+
+fn raw_window_event(app: &App, model: &mut Model, event: &nannou::winit::event::WindowEvent) {
+    // Any window event brings an idle clock back to full frame rate
+    model.frame_pacer.note_input(Instant::now());
+
+    // Let egui handle raw events for keyboard and mouse input
+    model.egui.handle_raw_event(event);
+    model.screen_reader.process_event(app.main_window().winit_window(), event);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono_tz::{America, Asia, Europe};
+
+    const ZONES: [Tz; 3] = [Europe::London, Asia::Tokyo, America::New_York];
+
+    #[test]
+    fn test_first_move_lands_on_selected_zone() {
+        assert_eq!(moved_cursor(None, &ZONES, Asia::Tokyo, 1), Some(1));
+        assert_eq!(moved_cursor(None, &ZONES, Europe::Paris, -1), Some(0));
+        assert_eq!(moved_cursor(None, &[], Asia::Tokyo, 1), None);
+    }
+
+    #[test]
+    fn test_cursor_clamps_at_both_ends() {
+        assert_eq!(moved_cursor(Some(1), &ZONES, Asia::Tokyo, 1), Some(2));
+        assert_eq!(moved_cursor(Some(2), &ZONES, Asia::Tokyo, 1), Some(2));
+        assert_eq!(moved_cursor(Some(0), &ZONES, Asia::Tokyo, -1), Some(0));
+        assert_eq!(moved_cursor(Some(1), &ZONES, Asia::Tokyo, -5), Some(0));
+    }
+
+    #[test]
+    fn test_stale_cursor_after_favorites_shrink() {
+        // The cursor was on a fourth chip that has since been removed
+        assert_eq!(focused_index(Some(3), ZONES.len()), None);
+        assert_eq!(moved_cursor(Some(3), &ZONES, America::New_York, -1), Some(2));
+        let mut zones = ZONES;
+        assert_eq!(swap_focused(&mut zones, Some(3), -1), None);
+        assert_eq!(zones, ZONES);
+    }
+
+    #[test]
+    fn test_swap_focused_favorite() {
+        let mut zones = ZONES;
+        assert_eq!(swap_focused(&mut zones, Some(0), 1), Some(1));
+        assert_eq!(zones, [Asia::Tokyo, Europe::London, America::New_York]);
+        assert_eq!(swap_focused(&mut zones, Some(1), -1), Some(0));
+        assert_eq!(zones, ZONES);
+    }
+
+    #[test]
+    fn test_swap_stops_at_list_boundaries() {
+        let mut zones = ZONES;
+        assert_eq!(swap_focused(&mut zones, Some(0), -1), None);
+        assert_eq!(swap_focused(&mut zones, Some(2), 1), None);
+        assert_eq!(zones, ZONES);
+    }
+}
@@ -0,0 +1,414 @@
+//! Readout format - 12/24-hour display and custom time/date patterns
+//!
+//! Patterns use chrono's strftime syntax (`%H:%M:%S`, `%A %-d %B`, ...).
+//! An empty pattern uses the built-in readout, and so does a pattern that
+//! doesn't parse, so a half-typed pattern never blanks the display.
+//!
+//! A secondary readout of UTC (or another zone) can sit under the primary
+//! one; it is always 24-hour with an ISO date, as used for radio logs.
+//! A world clock strip can list every favorite zone's time beneath them,
+//! each marked with how many days it is ahead of or behind the primary.
+//!
+//! In high-precision mode the readout gains milliseconds. `TimeData`'s
+//! calendar fields stop at whole seconds, so the digits come from its
+//! `second_fraction`, which is refreshed from the wall clock every frame.
+//!
+//! Leap seconds are either stepped, reading 23:59:60 for the inserted
+//! second, or smeared over the surrounding day so the readout never shows
+//! it. The system clock never reports 23:59:60 itself, so a stepped leap
+//! second only shows for an instant pinned with `--at`.
+
+use std::fmt::Write;
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use shared::{gps_time, smeared_time, tai_time, tr, tr_args, LeapSecondStatus, TimeData};
+
+/// Whether hours run 1-12 with AM/PM or 0-23
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HourCycle {
+    #[default]
+    H12,
+    H24,
+}
+
+impl HourCycle {
+    pub fn label(self) -> &'static str {
+        match self {
+            HourCycle::H12 => "12-hour",
+            HourCycle::H24 => "24-hour",
+        }
+    }
+
+    pub fn toggled(self) -> Self {
+        match self {
+            HourCycle::H12 => HourCycle::H24,
+            HourCycle::H24 => HourCycle::H12,
+        }
+    }
+}
+
+/// How the readout handles an inserted leap second
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LeapDisplay {
+    /// Show 23:59:60, then step to midnight
+    #[default]
+    Step,
+    /// Run slow over the day around it, as smearing time servers do
+    Smear,
+}
+
+impl LeapDisplay {
+    pub fn label(self) -> &'static str {
+        match self {
+            LeapDisplay::Step => "Step",
+            LeapDisplay::Smear => "Smear",
+        }
+    }
+
+    /// The instant the readout shows for the true instant `now`
+    pub fn clock_instant(self, now: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            LeapDisplay::Step => now,
+            LeapDisplay::Smear => smeared_time(now),
+        }
+    }
+}
+
+/// How the primary readout and date line are formatted
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ReadoutFormat {
+    #[serde(default)]
+    pub hour_cycle: HourCycle,
+    /// Custom pattern for the time readout; empty for the built-in one
+    #[serde(default)]
+    pub time_pattern: String,
+    /// Custom pattern for the date line; empty for the built-in one
+    #[serde(default)]
+    pub date_pattern: String,
+    #[serde(default)]
+    pub leap_display: LeapDisplay,
+    /// Show the TAI and GPS time line
+    #[serde(default)]
+    pub show_time_scales: bool,
+    /// Show milliseconds and the sub-second sweep
+    #[serde(default)]
+    pub high_precision: bool,
+}
+
+impl ReadoutFormat {
+    /// Text for the large time readout
+    ///
+    /// High precision appends ".mmm" to custom patterns too; chrono's `%f`
+    /// would only read the whole second the time data was computed at.
+    pub fn time_text(&self, time_data: &TimeData) -> String {
+        let mut text = format_pattern(&self.time_pattern, time_data).unwrap_or_else(|| {
+            let hour = match self.hour_cycle {
+                HourCycle::H12 => time_data.hour12,
+                HourCycle::H24 => time_data.hour24,
+            };
+            // Custom patterns get :60 from chrono's %S
+            format!("{:02}:{:02}:{:02}", hour, time_data.minute, time_data.display_second())
+        });
+        if self.high_precision {
+            text.push_str(&format!(".{:03}", milliseconds(time_data)));
+        }
+        text
+    }
+
+    /// Text for the date line
+    pub fn date_text(&self, time_data: &TimeData) -> String {
+        format_pattern(&self.date_pattern, time_data).unwrap_or_else(|| time_data.format_date())
+    }
+
+    /// Whether the AM/PM superscript goes next to the readout
+    ///
+    /// Custom time patterns include `%p` themselves if they want it.
+    pub fn shows_meridiem(&self) -> bool {
+        self.hour_cycle == HourCycle::H12 && self.time_pattern.trim().is_empty()
+    }
+}
+
+/// Milliseconds into the current second, from `second_fraction`
+pub fn milliseconds(time_data: &TimeData) -> u32 {
+    ((time_data.second_fraction * 1000.0) as u32).min(999)
+}
+
+/// Line giving TAI and GPS time: "TAI 14:05:46 · GPS 14:05:27 · TAI−UTC 37 s"
+pub fn time_scales_text(time_data: &TimeData) -> String {
+    let instant = time_data.local_datetime.with_timezone(&Utc);
+    format!(
+        "TAI {} · GPS {} · TAI−UTC {} s",
+        tai_time(instant).format("%H:%M:%S"),
+        gps_time(instant).format("%H:%M:%S"),
+        time_data.tai_minus_utc
+    )
+}
+
+/// Notice for a leap second within a day either side of now
+pub fn leap_notice(time_data: &TimeData, display: LeapDisplay) -> Option<String> {
+    match time_data.leap_second {
+        LeapSecondStatus::None => None,
+        LeapSecondStatus::Upcoming { instant } => Some(tr_args(
+            "Leap second at {0} UTC ({1})",
+            &[
+                &instant.format("%Y-%m-%d %H:%M:%S"),
+                &tr(display.label()).to_lowercase(),
+            ],
+        )),
+        LeapSecondStatus::InProgress => Some(tr("Leap second in progress").to_string()),
+        LeapSecondStatus::JustOccurred { .. } => Some(tr_args(
+            "Leap second inserted · TAI−UTC now {0} s",
+            &[&time_data.tai_minus_utc],
+        )),
+    }
+}
+
+/// Second zone shown under the primary readout
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SecondaryReadout {
+    pub enabled: bool,
+    /// IANA id of the secondary zone
+    pub zone_id: String,
+}
+
+impl Default for SecondaryReadout {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            zone_id: "UTC".to_string(),
+        }
+    }
+}
+
+impl SecondaryReadout {
+    /// The secondary zone, falling back to UTC for an unknown id
+    pub fn zone(&self) -> Tz {
+        self.zone_id.parse().unwrap_or(Tz::UTC)
+    }
+
+    /// Time line: "14:05:09 UTC"
+    pub fn time_text(secondary: &TimeData) -> String {
+        format!(
+            "{:02}:{:02}:{:02} {}",
+            secondary.hour24,
+            secondary.minute,
+            secondary.display_second(),
+            secondary.tz_abbrev
+        )
+    }
+
+    /// Date line: "Mon 2024-01-15", noting when it isn't the primary's date
+    pub fn date_text(secondary: &TimeData, primary: &TimeData) -> String {
+        let date = secondary.local_datetime.date_naive();
+        let days = (date - primary.local_datetime.date_naive()).num_days();
+        let text = date.format("%a %Y-%m-%d").to_string();
+        match days {
+            0 => text,
+            1 => tr_args("{0} (next day)", &[&text]),
+            -1 => tr_args("{0} (previous day)", &[&text]),
+            _ => tr_args("{0} ({1} days)", &[&text, &format!("{:+}", days)]),
+        }
+    }
+}
+
+/// One favorite zone in the world clock strip under the readouts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StripEntry {
+    pub tz: Tz,
+    /// "14:05", or "02:05 PM" in 12-hour mode
+    pub time: String,
+    /// Days the zone's date is ahead of (or behind) the primary's
+    pub day_offset: i64,
+}
+
+impl StripEntry {
+    pub fn new(zone: &TimeData, primary: &TimeData, hour_cycle: HourCycle) -> Self {
+        let time = match hour_cycle {
+            HourCycle::H12 => format!("{:02}:{:02} {}", zone.hour12, zone.minute, zone.meridiem),
+            HourCycle::H24 => format!("{:02}:{:02}", zone.hour24, zone.minute),
+        };
+        let date = zone.local_datetime.date_naive();
+        Self {
+            tz: zone.local_datetime.timezone(),
+            time,
+            day_offset: (date - primary.local_datetime.date_naive()).num_days(),
+        }
+    }
+
+    /// "+1d" or "−1d", empty on the primary's date
+    pub fn day_offset_text(&self) -> String {
+        match self.day_offset {
+            0 => String::new(),
+            days if days > 0 => format!("+{}d", days),
+            days => format!("−{}d", -days),
+        }
+    }
+}
+
+/// Check a pattern, describing the first problem if there is one
+///
+/// An empty pattern is valid (it means the built-in format).
+pub fn validate_pattern(pattern: &str) -> Result<(), String> {
+    if StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error)) {
+        return Err("Unknown % specifier".to_string());
+    }
+    Ok(())
+}
+
+/// Format `time_data`'s local time with `pattern`
+///
+/// Returns `None` for an empty or invalid pattern.
+fn format_pattern(pattern: &str, time_data: &TimeData) -> Option<String> {
+    if pattern.trim().is_empty() || validate_pattern(pattern).is_err() {
+        return None;
+    }
+    let mut text = String::new();
+    write!(text, "{}", time_data.local_datetime.format(pattern)).ok()?;
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Tz;
+    use shared::compute_time_data_at;
+
+    fn afternoon() -> TimeData {
+        let tz: Tz = "Europe/London".parse().unwrap();
+        compute_time_data_at(tz, Utc.with_ymd_and_hms(2024, 1, 15, 14, 5, 9).unwrap())
+    }
+
+    #[test]
+    fn test_builtin_readout() {
+        let time_data = afternoon();
+        let mut format = ReadoutFormat::default();
+        assert_eq!(format.time_text(&time_data), "02:05:09");
+        assert!(format.shows_meridiem());
+
+        format.hour_cycle = HourCycle::H24;
+        assert_eq!(format.time_text(&time_data), "14:05:09");
+        assert!(!format.shows_meridiem());
+        assert_eq!(format.date_text(&time_data), time_data.format_date());
+    }
+
+    #[test]
+    fn test_high_precision_readout() {
+        let mut time_data = afternoon();
+        time_data.second_fraction = 0.4567;
+        let mut format = ReadoutFormat {
+            hour_cycle: HourCycle::H24,
+            high_precision: true,
+            ..Default::default()
+        };
+        assert_eq!(format.time_text(&time_data), "14:05:09.456");
+
+        format.time_pattern = "%H:%M:%S".to_string();
+        time_data.second_fraction = 0.999;
+        assert_eq!(format.time_text(&time_data), "14:05:09.999");
+    }
+
+    #[test]
+    fn test_custom_patterns() {
+        let time_data = afternoon();
+        let format = ReadoutFormat {
+            hour_cycle: HourCycle::H12,
+            time_pattern: "%-I.%M %p".to_string(),
+            date_pattern: "%Y-%m-%d (%a)".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(format.time_text(&time_data), "2.05 PM");
+        assert_eq!(format.date_text(&time_data), "2024-01-15 (Mon)");
+        assert!(!format.shows_meridiem());
+    }
+
+    #[test]
+    fn test_secondary_readout() {
+        let secondary = SecondaryReadout::default();
+        assert!(secondary.enabled);
+        assert_eq!(secondary.zone(), Tz::UTC);
+
+        // 23:30 in Los Angeles is already the next day in UTC
+        let instant = Utc.with_ymd_and_hms(2024, 1, 16, 7, 30, 0).unwrap();
+        let la: Tz = "America/Los_Angeles".parse().unwrap();
+        let primary = compute_time_data_at(la, instant);
+        let utc = compute_time_data_at(secondary.zone(), instant);
+        assert_eq!(SecondaryReadout::time_text(&utc), "07:30:00 UTC");
+        assert_eq!(
+            SecondaryReadout::date_text(&utc, &primary),
+            "Tue 2024-01-16 (next day)"
+        );
+        assert_eq!(SecondaryReadout::date_text(&primary, &primary), "Mon 2024-01-15");
+
+        let unknown = SecondaryReadout {
+            enabled: true,
+            zone_id: "Mars/Olympus".to_string(),
+        };
+        assert_eq!(unknown.zone(), Tz::UTC);
+    }
+
+    #[test]
+    fn test_strip_entries() {
+        // 23:30 in Los Angeles is already tomorrow in Tokyo and still today in Honolulu
+        let instant = Utc.with_ymd_and_hms(2024, 1, 16, 7, 30, 0).unwrap();
+        let la: Tz = "America/Los_Angeles".parse().unwrap();
+        let primary = compute_time_data_at(la, instant);
+
+        let tokyo = compute_time_data_at("Asia/Tokyo".parse().unwrap(), instant);
+        let entry = StripEntry::new(&tokyo, &primary, HourCycle::H24);
+        assert_eq!(entry.tz.name(), "Asia/Tokyo");
+        assert_eq!(entry.time, "16:30");
+        assert_eq!(entry.day_offset_text(), "+1d");
+
+        let honolulu = compute_time_data_at("Pacific/Honolulu".parse().unwrap(), instant);
+        let entry = StripEntry::new(&honolulu, &primary, HourCycle::H12);
+        assert_eq!(entry.time, "09:30 PM");
+        assert_eq!(entry.day_offset_text(), "");
+
+        // From Tokyo, Los Angeles is still yesterday
+        let entry = StripEntry::new(&primary, &tokyo, HourCycle::H24);
+        assert_eq!(entry.day_offset_text(), "−1d");
+    }
+
+    #[test]
+    fn test_leap_second_step_and_smear() {
+        let leap = shared::leap_seconds().last().unwrap();
+        let format = ReadoutFormat {
+            hour_cycle: HourCycle::H24,
+            ..Default::default()
+        };
+
+        let stepped = compute_time_data_at(Tz::UTC, LeapDisplay::Step.clock_instant(leap.instant));
+        assert_eq!(format.time_text(&stepped), "23:59:60");
+        assert_eq!(SecondaryReadout::time_text(&stepped), "23:59:60 UTC");
+        assert_eq!(
+            leap_notice(&stepped, LeapDisplay::Step),
+            Some("Leap second in progress".to_string())
+        );
+
+        let smeared = compute_time_data_at(Tz::UTC, LeapDisplay::Smear.clock_instant(leap.instant));
+        assert_eq!(format.time_text(&smeared), "23:59:59");
+
+        let after = compute_time_data_at(Tz::UTC, leap.ends());
+        assert_eq!(time_scales_text(&after), "TAI 00:00:37 · GPS 00:00:18 · TAI−UTC 37 s");
+        assert_eq!(leap_notice(&afternoon(), LeapDisplay::Step), None);
+    }
+
+    #[test]
+    fn test_invalid_pattern_falls_back() {
+        let time_data = afternoon();
+        assert!(validate_pattern("%H:%M").is_ok());
+        assert!(validate_pattern("").is_ok());
+        assert!(validate_pattern("%Q").is_err());
+
+        let format = ReadoutFormat {
+            time_pattern: "%H:%Q".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(format.time_text(&time_data), "02:05:09");
+    }
+}
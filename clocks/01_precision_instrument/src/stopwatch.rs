@@ -0,0 +1,201 @@
+//! Stopwatch module - elapsed time measurement and lap recording
+//!
+//! Pure state machine driven by explicit `Instant`s so it can be tested
+//! without sleeping. The main loop passes `Instant::now()`.
+
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+/// Maximum laps kept in history; the oldest are dropped first
+pub const MAX_LAPS: usize = 99;
+
+/// Which instrument the clock is showing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum InstrumentMode {
+    #[default]
+    Clock,
+    Stopwatch,
+}
+
+/// A recorded lap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lap {
+    /// 1-based lap number
+    pub number: u32,
+    /// Time since the previous lap (or start) in milliseconds
+    pub split_ms: u64,
+    /// Total elapsed time when the lap was recorded in milliseconds
+    pub total_ms: u64,
+}
+
+/// Start/stop stopwatch with lap history
+#[derive(Debug, Clone, Default)]
+pub struct Stopwatch {
+    /// Elapsed time from completed running spans
+    accumulated: Duration,
+    /// When the current running span began, if running
+    started_at: Option<Instant>,
+    /// Recorded laps, oldest first
+    laps: Vec<Lap>,
+}
+
+impl Stopwatch {
+    /// Restore a stopped stopwatch from persisted state
+    pub fn restore(elapsed_ms: u64, mut laps: Vec<Lap>) -> Self {
+        if laps.len() > MAX_LAPS {
+            laps.drain(..laps.len() - MAX_LAPS);
+        }
+        Self {
+            accumulated: Duration::from_millis(elapsed_ms),
+            started_at: None,
+            laps,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.started_at.is_some()
+    }
+
+    pub fn laps(&self) -> &[Lap] {
+        &self.laps
+    }
+
+    /// Total elapsed time at `now`
+    pub fn elapsed_at(&self, now: Instant) -> Duration {
+        match self.started_at {
+            Some(started_at) => self.accumulated + now.saturating_duration_since(started_at),
+            None => self.accumulated,
+        }
+    }
+
+    /// Total elapsed milliseconds at `now`
+    pub fn elapsed_ms_at(&self, now: Instant) -> u64 {
+        self.elapsed_at(now).as_millis() as u64
+    }
+
+    pub fn start(&mut self, now: Instant) {
+        if self.started_at.is_none() {
+            self.started_at = Some(now);
+        }
+    }
+
+    pub fn stop(&mut self, now: Instant) {
+        if self.started_at.is_some() {
+            self.accumulated = self.elapsed_at(now);
+            self.started_at = None;
+        }
+    }
+
+    /// Start if stopped, stop if running
+    pub fn toggle(&mut self, now: Instant) {
+        if self.is_running() {
+            self.stop(now);
+        } else {
+            self.start(now);
+        }
+    }
+
+    /// Record a lap; only possible while running
+    pub fn lap(&mut self, now: Instant) -> Option<Lap> {
+        if !self.is_running() {
+            return None;
+        }
+
+        let total_ms = self.elapsed_ms_at(now);
+        let previous_ms = self.laps.last().map(|lap| lap.total_ms).unwrap_or(0);
+        let lap = Lap {
+            number: self.laps.last().map(|lap| lap.number + 1).unwrap_or(1),
+            split_ms: total_ms.saturating_sub(previous_ms),
+            total_ms,
+        };
+
+        self.laps.push(lap);
+        if self.laps.len() > MAX_LAPS {
+            self.laps.remove(0);
+        }
+        Some(lap)
+    }
+
+    /// Stop and clear elapsed time and laps
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Index of the fastest lap (by split), if there are at least two
+    pub fn fastest_lap_index(&self) -> Option<usize> {
+        if self.laps.len() < 2 {
+            return None;
+        }
+        self.laps
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, lap)| lap.split_ms)
+            .map(|(i, _)| i)
+    }
+}
+
+/// Format milliseconds as "MM:SS.mmm", or "H:MM:SS.mmm" past the hour
+pub fn format_elapsed(ms: u64) -> String {
+    let millis = ms % 1000;
+    let total_secs = ms / 1000;
+    let secs = total_secs % 60;
+    let mins = (total_secs / 60) % 60;
+    let hours = total_secs / 3600;
+
+    if hours > 0 {
+        format!("{}:{:02}:{:02}.{:03}", hours, mins, secs, millis)
+    } else {
+        format!("{:02}:{:02}.{:03}", mins, secs, millis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_stop_accumulates() {
+        let t0 = Instant::now();
+        let mut sw = Stopwatch::default();
+
+        sw.start(t0);
+        sw.stop(t0 + Duration::from_millis(1500));
+        assert_eq!(sw.elapsed_ms_at(t0 + Duration::from_secs(10)), 1500);
+
+        // Paused time is not counted
+        sw.start(t0 + Duration::from_secs(5));
+        assert_eq!(sw.elapsed_ms_at(t0 + Duration::from_millis(5250)), 1750);
+        assert!(sw.is_running());
+    }
+
+    #[test]
+    fn test_laps_record_splits() {
+        let t0 = Instant::now();
+        let mut sw = Stopwatch::default();
+
+        assert!(sw.lap(t0).is_none(), "no laps while stopped");
+
+        sw.start(t0);
+        sw.lap(t0 + Duration::from_millis(1200));
+        sw.lap(t0 + Duration::from_millis(2000));
+        sw.lap(t0 + Duration::from_millis(3500));
+
+        let splits: Vec<u64> = sw.laps().iter().map(|l| l.split_ms).collect();
+        assert_eq!(splits, vec![1200, 800, 1500]);
+        assert_eq!(sw.laps()[2].number, 3);
+        assert_eq!(sw.laps()[2].total_ms, 3500);
+        assert_eq!(sw.fastest_lap_index(), Some(1));
+
+        sw.reset();
+        assert!(sw.laps().is_empty());
+        assert_eq!(sw.elapsed_ms_at(t0 + Duration::from_secs(4)), 0);
+    }
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_elapsed(0), "00:00.000");
+        assert_eq!(format_elapsed(83_456), "01:23.456");
+        assert_eq!(format_elapsed(3_723_004), "1:02:03.004");
+    }
+}
@@ -4,7 +4,22 @@
 
 use chrono_tz::Tz;
 use nannou_egui::egui;
-use shared::{all_timezones, search_timezones, DstChange, TimeData};
+use shared::{
+    active_palette, all_timezones, clip_recording_settings, favorites_settings, keymap_settings,
+    language_settings, order_by_region, quiet_hours_settings, reduced_motion_settings,
+    region_change, region_heading, reveal_hotkey_settings, safe_palette_settings,
+    search_timezones, system_zone_settings, tr, tr_args, window_title_settings,
+    zone_display_name, ClipSettings, DstChange, FollowSystemZone, Keymap, KeymapEdit,
+    LanguageSetting, MotionPreference, QuietHours, RevealHotkey, Rgb, SafePaletteSetting,
+    ThemeLibrary, TimeData, WindowTitleClock,
+};
+
+use crate::calibration::CalibrationSettings;
+use crate::chime::{ChimeCount, ChimeSettings, ChimeSound, UNAVAILABLE_REASON};
+use crate::readout::{
+    validate_pattern, HourCycle, LeapDisplay, ReadoutFormat, SecondaryReadout, StripEntry,
+};
+use crate::stopwatch::InstrumentMode;
 
 /// State for the timezone picker
 #[derive(Default)]
@@ -27,7 +42,7 @@ impl PickerState {
     pub fn open(&mut self) {
         self.is_open = true;
         self.search_query.clear();
-        self.search_results = search_timezones("");
+        self.search_results = order_by_region(search_timezones(""));
         self.total_count = all_timezones().len();
         self.should_focus_search = true;
         self.selected_index = None;
@@ -41,7 +56,7 @@ impl PickerState {
     }
 
     pub fn update_search(&mut self) {
-        self.search_results = search_timezones(&self.search_query);
+        self.search_results = order_by_region(search_timezones(&self.search_query));
         // Reset selection when search changes
         self.selected_index = if self.search_results.is_empty() {
             None
@@ -83,6 +98,7 @@ impl PickerState {
 }
 
 /// Result of UI interactions
+#[derive(Default)]
 pub struct UiResult {
     /// If Some, the user selected a new timezone
     pub selected_tz: Option<Tz>,
@@ -95,16 +111,6 @@ pub struct UiResult {
     pub toggle_reduced_motion: bool,
 }
 
-impl Default for UiResult {
-    fn default() -> Self {
-        Self {
-            selected_tz: None,
-            toggle_favorite: None,
-            close_picker: false,
-            toggle_reduced_motion: false,
-        }
-    }
-}
 
 /// Draw the timezone picker overlay
 pub fn draw_timezone_picker(
@@ -119,7 +125,8 @@ pub fn draw_timezone_picker(
         return result;
     }
 
-    egui::Window::new("Select Time Zone")
+    egui::Window::new(tr("Select Time Zone"))
+        .id(egui::Id::new("zone_picker"))
         .collapsible(false)
         .resizable(true)
         .default_width(400.0)
@@ -128,7 +135,7 @@ pub fn draw_timezone_picker(
         .show(ctx, |ui| {
             // Search field
             let _search_response = ui.horizontal(|ui| {
-                ui.label("Search:");
+                ui.label(tr("Search:"));
                 let response = ui.text_edit_singleline(&mut picker_state.search_query);
                 if picker_state.should_focus_search {
                     response.request_focus();
@@ -143,7 +150,7 @@ pub fn draw_timezone_picker(
             ui.separator();
 
             // System timezone button
-            if ui.button("Use System Time Zone").clicked() {
+            if ui.button(tr("Use System Time Zone")).clicked() {
                 if let Some(sys_tz) = shared::system_timezone() {
                     result.selected_tz = Some(sys_tz);
                     result.close_picker = true;
@@ -154,7 +161,7 @@ pub fn draw_timezone_picker(
 
             // Favorites section
             if !favorites.is_empty() {
-                ui.label("Favorites:");
+                ui.label(tr("Favorites:"));
                 ui.horizontal_wrapped(|ui| {
                     for &tz in favorites {
                         let is_current = tz == current_tz;
@@ -173,16 +180,20 @@ pub fn draw_timezone_picker(
             }
 
             // Results list with count
-            ui.label(format!(
-                "Showing {} of {} time zones",
-                picker_state.search_results.len(),
-                picker_state.total_count
+            ui.label(tr_args(
+                "Showing {0} of {1} time zones",
+                &[&picker_state.search_results.len(), &picker_state.total_count],
             ));
             
             egui::ScrollArea::vertical()
                 .max_height(300.0)
                 .show(ui, |ui| {
+                    let mut previous = None;
                     for (idx, &tz) in picker_state.search_results.iter().enumerate() {
+                        if let Some(region) = region_change(previous, tz) {
+                            region_heading(ui, region);
+                        }
+                        previous = Some(tz);
                         let is_current = tz == current_tz;
                         let is_favorite = favorites.contains(&tz);
                         let is_keyboard_selected = picker_state.selected_index == Some(idx);
@@ -196,9 +207,9 @@ pub fn draw_timezone_picker(
                             
                             // Timezone name - highlight if keyboard selected
                             let label = if is_current {
-                                format!("{} ◀", tz.name())
+                                format!("{} ◀", zone_display_name(tz))
                             } else {
-                                tz.name().to_string()
+                                zone_display_name(tz)
                             };
                             
                             let response = ui.selectable_label(
@@ -222,12 +233,12 @@ pub fn draw_timezone_picker(
             ui.separator();
 
             // Keyboard hint
-            ui.label("↑↓ Navigate · Enter Select · Esc Close");
+            ui.label(tr("↑↓ Navigate · Enter Select · Esc Close"));
 
             ui.separator();
 
             // Close button
-            if ui.button("Close").clicked() {
+            if ui.button(tr("Close")).clicked() {
                 result.close_picker = true;
             }
         });
@@ -247,7 +258,8 @@ pub fn draw_timezone_picker(
 
 /// Draw the DST status card
 pub fn draw_dst_status_card(ctx: &egui::Context, time_data: &TimeData, selected_tz: Tz) {
-    egui::Window::new("DST Status")
+    egui::Window::new(tr("DST Status"))
+        .id(egui::Id::new("dst_status"))
         .collapsible(true)
         .resizable(false)
         .default_width(280.0)
@@ -255,11 +267,12 @@ pub fn draw_dst_status_card(ctx: &egui::Context, time_data: &TimeData, selected_
         .show(ctx, |ui| {
             // Current DST status
             ui.horizontal(|ui| {
-                ui.label("Status:");
+                ui.label(tr("Status:"));
                 if time_data.is_dst {
-                    ui.colored_label(egui::Color32::from_rgb(0, 212, 255), "Daylight Saving Time");
+                    let color = egui::Color32::from_rgb(0, 212, 255);
+                    ui.colored_label(color, tr("Daylight Saving Time"));
                 } else {
-                    ui.label("Standard Time");
+                    ui.label(tr("Standard Time"));
                 }
             });
 
@@ -268,47 +281,67 @@ pub fn draw_dst_status_card(ctx: &egui::Context, time_data: &TimeData, selected_
             // DST transition info
             match &time_data.dst_change {
                 DstChange::None => {
-                    ui.label("No DST transitions within 24 hours.");
+                    ui.label(tr("No DST transitions within 24 hours."));
                 }
                 DstChange::Upcoming { instant, delta_minutes } => {
-                    let direction = if *delta_minutes > 0 { "forward" } else { "back" };
+                    let change = if *delta_minutes > 0 {
+                        "Clocks will move forward by {0} minutes"
+                    } else {
+                        "Clocks will move back by {0} minutes"
+                    };
                     ui.colored_label(
                         egui::Color32::from_rgb(255, 180, 0),
-                        "⚠ Upcoming DST Change".to_string(),
+                        tr("⚠ Upcoming DST Change"),
                     );
-                    ui.label(format!(
-                        "Clocks will move {} by {} minutes",
-                        direction,
-                        delta_minutes.abs()
-                    ));
+                    ui.label(tr_args(change, &[&delta_minutes.abs()]));
                     // Show in local time
                     let local_time = instant.with_timezone(&selected_tz);
-                    ui.label(format!("At: {}", local_time.format("%b %d, %Y %I:%M %p (local)")));
+                    let at = local_time.format("%b %d, %Y %I:%M %p");
+                    ui.label(tr_args("At: {0} (local)", &[&at]));
                 }
                 DstChange::JustOccurred { instant, delta_minutes } => {
-                    let direction = if *delta_minutes > 0 { "forward" } else { "back" };
+                    let change = if *delta_minutes > 0 {
+                        "Clocks moved forward by {0} minutes"
+                    } else {
+                        "Clocks moved back by {0} minutes"
+                    };
                     ui.colored_label(
                         egui::Color32::from_rgb(0, 212, 255),
-                        "ℹ Recent DST Change".to_string(),
+                        tr("ℹ Recent DST Change"),
                     );
-                    ui.label(format!(
-                        "Clocks moved {} by {} minutes",
-                        direction,
-                        delta_minutes.abs()
-                    ));
+                    ui.label(tr_args(change, &[&delta_minutes.abs()]));
                     // Show in local time
                     let local_time = instant.with_timezone(&selected_tz);
-                    ui.label(format!("At: {}", local_time.format("%b %d, %Y %I:%M %p (local)")));
+                    let at = local_time.format("%b %d, %Y %I:%M %p");
+                    ui.label(tr_args("At: {0} (local)", &[&at]));
                 }
             }
         });
 }
 
+/// Most favorites chips shown in the row; the rest go in an overflow menu
+pub const MAX_VISIBLE_FAVORITES: usize = 6;
+
+/// The favorites shown as chips: the first few, or a run sliding along to
+/// keep the keyboard-focused one in view
+fn visible_favorites(count: usize, focused: Option<usize>) -> std::ops::Range<usize> {
+    let shown = count.min(MAX_VISIBLE_FAVORITES);
+    let start = match focused {
+        Some(index) if index >= shown => (index + 1 - shown).min(count - shown),
+        _ => 0,
+    };
+    start..start + shown
+}
+
 /// Draw the favorites chips row
+///
+/// `focused` is the chip under the keyboard cursor, outlined so it can be
+/// told apart from the selected zone.
 pub fn draw_favorites_chips(
     ctx: &egui::Context,
     favorites: &[Tz],
     current_tz: Tz,
+    focused: Option<usize>,
 ) -> Option<Tz> {
     let mut selected = None;
 
@@ -316,52 +349,530 @@ pub fn draw_favorites_chips(
         return None;
     }
 
+    let visible = visible_favorites(favorites.len(), focused);
     egui::TopBottomPanel::bottom("favorites_panel")
         .resizable(false)
         .show(ctx, |ui| {
             ui.horizontal(|ui| {
-                ui.label("Quick Select:");
-                for &tz in favorites {
+                ui.label(tr("Quick Select:"))
+                    .on_hover_text(tr("←/→ focus a favorite, Enter selects, Ctrl+←/→ reorders"));
+                for (index, &tz) in favorites.iter().enumerate() {
+                    if !visible.contains(&index) {
+                        continue;
+                    }
                     let is_current = tz == current_tz;
-                    // Get just the city name from the timezone
-                    let display_name = tz
-                        .name()
-                        .rsplit('/')
-                        .next()
-                        .unwrap_or(tz.name())
-                        .replace('_', " ");
-                    
-                    if ui.selectable_label(is_current, &display_name).clicked() {
+                    let response = ui.selectable_label(is_current, chip_label(tz));
+                    if focused == Some(index) {
+                        ui.painter().rect_stroke(
+                            response.rect.expand(2.0),
+                            3.0,
+                            ui.visuals().selection.stroke,
+                        );
+                    }
+                    if response.clicked() {
                         selected = Some(tz);
                     }
                 }
+
+                let hidden = favorites.len() - visible.len();
+                if hidden > 0 {
+                    ui.menu_button(tr_args("+{0} more", &[&hidden]), |ui| {
+                        for (index, &tz) in favorites.iter().enumerate() {
+                            if visible.contains(&index) {
+                                continue;
+                            }
+                            if ui.selectable_label(tz == current_tz, chip_label(tz)).clicked() {
+                                selected = Some(tz);
+                                ui.close_menu();
+                            }
+                        }
+                    });
+                }
             });
         });
 
     selected
 }
 
-/// Draw the settings panel
-pub fn draw_settings_panel(ctx: &egui::Context, reduced_motion: &mut bool) -> bool {
-    let mut changed = false;
+/// Height of the world clock strip
+pub const WORLD_STRIP_HEIGHT: f32 = 52.0;
+
+/// Draw the world clock strip: each favorite's time and day offset, side by
+/// side, scrolling sideways when they don't fit
+///
+/// `top_left` and `width` place it under the readouts. Returns the zone
+/// whose card was clicked.
+pub fn draw_world_strip(
+    ctx: &egui::Context,
+    entries: &[StripEntry],
+    current_tz: Tz,
+    top_left: egui::Pos2,
+    width: f32,
+) -> Option<Tz> {
+    let mut selected = None;
+
+    if entries.is_empty() {
+        return None;
+    }
+
+    egui::Area::new("world_strip")
+        .fixed_pos(top_left)
+        .order(egui::Order::Background)
+        .show(ctx, |ui| {
+            // Themes set the hyperlink color to their accent
+            let accent = ui.visuals().hyperlink_color;
+            let muted = ui.visuals().weak_text_color();
+            ui.set_width(width);
+            egui::ScrollArea::horizontal()
+                .max_width(width)
+                .max_height(WORLD_STRIP_HEIGHT)
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        for entry in entries {
+                            let is_current = entry.tz == current_tz;
+                            let card = egui::Frame::group(ui.style())
+                                .inner_margin(egui::Margin::symmetric(8.0, 4.0))
+                                .show(ui, |ui| {
+                                    ui.vertical(|ui| {
+                                        let city = egui::RichText::new(chip_label(entry.tz))
+                                            .size(11.0)
+                                            .color(if is_current { accent } else { muted });
+                                        ui.label(city);
+                                        ui.horizontal(|ui| {
+                                            ui.label(
+                                                egui::RichText::new(&entry.time)
+                                                    .size(15.0)
+                                                    .monospace()
+                                                    .strong(),
+                                            );
+                                            let offset = entry.day_offset_text();
+                                            if !offset.is_empty() {
+                                                ui.label(
+                                                    egui::RichText::new(offset)
+                                                        .size(10.0)
+                                                        .color(accent),
+                                                );
+                                            }
+                                        });
+                                    });
+                                });
+                            let response = ui
+                                .interact(
+                                    card.response.rect,
+                                    egui::Id::new(("world_strip", entry.tz.name())),
+                                    egui::Sense::click(),
+                                )
+                                .on_hover_text(entry.tz.name());
+                            if response.hovered() {
+                                ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+                            }
+                            if response.clicked() {
+                                selected = Some(entry.tz);
+                            }
+                        }
+                    });
+                });
+        });
+
+    selected
+}
 
-    egui::Window::new("Settings")
+/// Just the city name from the timezone
+fn chip_label(tz: Tz) -> String {
+    tz.name().rsplit('/').next().unwrap_or(tz.name()).replace('_', " ")
+}
+
+/// Result of settings panel interactions
+#[derive(Default)]
+pub struct SettingsResult {
+    /// Reduced motion checkbox changed
+    pub reduced_motion_changed: bool,
+    /// Clock/stopwatch mode changed
+    pub mode_changed: bool,
+    /// Shrink to the compact widget
+    pub compact: bool,
+    /// Start or stop the stopwatch
+    pub toggle_stopwatch: bool,
+    /// Record a lap
+    pub record_lap: bool,
+    /// Reset the stopwatch and clear laps
+    pub reset_stopwatch: bool,
+    /// Hour cycle or a readout pattern was edited
+    pub readout_changed: bool,
+    /// Secondary readout toggled or its zone changed
+    pub secondary_changed: bool,
+    /// World clock strip toggled
+    pub world_strip_changed: bool,
+    /// Quiet-hours schedule edited
+    pub quiet_hours_changed: bool,
+    /// Window title clock setting edited
+    pub window_title_changed: bool,
+    /// Shared clip recording settings edited
+    pub clip_settings_changed: bool,
+    /// Shared language edited
+    pub language_changed: bool,
+    /// Shared safe palette edited
+    pub safe_palette_changed: bool,
+    /// Shared follow-system-zone setting edited
+    pub system_zone_changed: bool,
+    /// Shared reveal shortcut edited
+    pub reveal_hotkey_changed: bool,
+    /// Switched between the shared favorites and this clock's own
+    pub favorites_source_changed: bool,
+    /// Shortcut rebound, reset or waiting for its new chord
+    pub keymap_edit: Option<KeymapEdit>,
+    /// Calibration view toggled or its server edited
+    pub calibration_changed: bool,
+    /// Take an NTP measurement now
+    pub measure_now: bool,
+    /// Chime enables, sound, count, volume or flash edited
+    pub chimes_changed: bool,
+    /// Strike the current hour's chime now
+    pub test_chime: bool,
+    /// Switch theme (`Some(None)` for the clock's own colors)
+    pub set_theme: Option<Option<String>>,
+}
+
+/// Draw the settings panel
+#[allow(clippy::too_many_arguments)]
+pub fn draw_settings_panel(
+    ctx: &egui::Context,
+    motion_preference: &mut MotionPreference,
+    system_reduced_motion: bool,
+    mode: &mut InstrumentMode,
+    stopwatch_running: bool,
+    readout: &mut ReadoutFormat,
+    secondary: &mut SecondaryReadout,
+    world_strip: &mut bool,
+    calibration: &mut CalibrationSettings,
+    measuring: bool,
+    chimes: &mut ChimeSettings,
+    audio_available: bool,
+    time_data: &TimeData,
+    favorites: &[Tz],
+    quiet_hours: &mut QuietHours,
+    window_title: &mut WindowTitleClock,
+    clip_settings: &mut ClipSettings,
+    language: &mut LanguageSetting,
+    safe_palette: &mut SafePaletteSetting,
+    follow_system_zone: &mut FollowSystemZone,
+    reveal_hotkey: &mut RevealHotkey,
+    reveal_status: Option<&str>,
+    own_favorites: &mut bool,
+    keymap: &Keymap,
+    themes: &ThemeLibrary,
+    theme: Option<&str>,
+) -> SettingsResult {
+    let mut result = SettingsResult::default();
+
+    egui::Window::new(tr("Settings"))
+        .id(egui::Id::new("settings"))
         .collapsible(true)
         .resizable(false)
         .default_width(200.0)
         .anchor(egui::Align2::RIGHT_BOTTOM, [-10.0, -50.0])
         .show(ctx, |ui| {
-            if ui.checkbox(reduced_motion, "Reduced Motion").changed() {
-                changed = true;
+            ui.horizontal(|ui| {
+                if ui
+                    .radio_value(mode, InstrumentMode::Clock, tr("Clock"))
+                    .changed()
+                {
+                    result.mode_changed = true;
+                }
+                if ui
+                    .radio_value(mode, InstrumentMode::Stopwatch, tr("Stopwatch"))
+                    .changed()
+                {
+                    result.mode_changed = true;
+                }
+            });
+            ui.label(tr("Press Shift+M to switch"));
+            result.compact = ui
+                .button(tr("Compact widget"))
+                .on_hover_text(tr(
+                    "A small always-on-top readout; drag it to move, press M to restore",
+                ))
+                .clicked();
+
+            if *mode == InstrumentMode::Stopwatch {
+                ui.horizontal(|ui| {
+                    let start_label = if stopwatch_running { tr("Stop") } else { tr("Start") };
+                    if ui.button(start_label).clicked() {
+                        result.toggle_stopwatch = true;
+                    }
+                    if ui
+                        .add_enabled(stopwatch_running, egui::Button::new(tr("Lap")))
+                        .clicked()
+                    {
+                        result.record_lap = true;
+                    }
+                    if ui.button(tr("Reset")).clicked() {
+                        result.reset_stopwatch = true;
+                    }
+                });
+                ui.label(tr("S start/stop · L lap · Backspace reset"));
+            }
+
+            ui.separator();
+            if draw_readout_settings(ui, readout, time_data) {
+                result.readout_changed = true;
+            }
+
+            ui.separator();
+            if draw_secondary_settings(ui, secondary, favorites) {
+                result.secondary_changed = true;
             }
-            ui.label("Disables continuous animations");
+            result.world_strip_changed = ui
+                .checkbox(world_strip, tr("World clock strip"))
+                .on_hover_text(tr("Every favorite's time under the readout; click one to switch"))
+                .changed();
+            ui.label(tr("Press W to toggle"));
+
+            ui.separator();
+            let (calibration_changed, measure_now) =
+                draw_calibration_settings(ui, calibration, measuring);
+            result.calibration_changed = calibration_changed;
+            result.measure_now = measure_now;
+
+            ui.separator();
+            let (chimes_changed, test_chime) = draw_chime_settings(ui, chimes, audio_available);
+            result.chimes_changed = chimes_changed;
+            result.test_chime = test_chime;
+
             ui.separator();
-            ui.label("Press R to toggle");
+            result.reduced_motion_changed =
+                reduced_motion_settings(ui, motion_preference, system_reduced_motion);
+            ui.label(tr("Disables continuous animations"));
+            ui.separator();
+            ui.label(tr("Press R to toggle"));
+            ui.separator();
+            result.quiet_hours_changed = quiet_hours_settings(ui, quiet_hours);
+            result.window_title_changed = window_title_settings(ui, window_title);
+            result.clip_settings_changed = clip_recording_settings(ui, clip_settings);
+            result.language_changed = language_settings(ui, language);
+            result.system_zone_changed = system_zone_settings(ui, follow_system_zone);
+            result.reveal_hotkey_changed =
+                reveal_hotkey_settings(ui, reveal_hotkey, reveal_status);
+            result.favorites_source_changed = favorites_settings(ui, own_favorites);
+            result.keymap_edit = keymap_settings(ui, keymap);
+            ui.separator();
+            result.set_theme = theme_selector(ui, themes, theme);
+            result.safe_palette_changed = safe_palette_settings(ui, safe_palette);
+        });
+
+    result
+}
+
+/// Draw the hour cycle and pattern fields with a live preview
+///
+/// Returns true if anything was edited.
+fn draw_readout_settings(
+    ui: &mut egui::Ui,
+    readout: &mut ReadoutFormat,
+    time_data: &TimeData,
+) -> bool {
+    let mut changed = false;
+
+    ui.horizontal(|ui| {
+        for cycle in [HourCycle::H12, HourCycle::H24] {
+            if ui.radio_value(&mut readout.hour_cycle, cycle, tr(cycle.label())).changed() {
+                changed = true;
+            }
+        }
+    });
+    ui.label(tr("Press H to switch"));
+
+    for (label, pattern) in [
+        ("Time:", &mut readout.time_pattern),
+        ("Date:", &mut readout.date_pattern),
+    ] {
+        ui.horizontal(|ui| {
+            ui.label(tr(label));
+            let edit = egui::TextEdit::singleline(pattern)
+                .hint_text(tr("built-in"))
+                .desired_width(120.0);
+            if ui.add(edit).changed() {
+                changed = true;
+            }
         });
+        if let Err(e) = validate_pattern(pattern) {
+            ui.colored_label(egui::Color32::from_rgb(255, 100, 100), e);
+        }
+    }
+
+    // Live preview of exactly what the readout will draw
+    let meridiem = if readout.shows_meridiem() {
+        format!(" {}", time_data.meridiem)
+    } else {
+        String::new()
+    };
+    ui.label(
+        egui::RichText::new(format!("{}{}", readout.time_text(time_data), meridiem))
+            .monospace()
+            .color(egui::Color32::from_rgb(0, 212, 255)),
+    );
+    ui.label(egui::RichText::new(readout.date_text(time_data)).small());
+    ui.label(egui::RichText::new(tr("strftime syntax, e.g. %H:%M · %a %-d %b")).small());
+
+    ui.horizontal(|ui| {
+        ui.label(tr("Leap second:"));
+        for display in [LeapDisplay::Step, LeapDisplay::Smear] {
+            let hover = match display {
+                LeapDisplay::Step => "Show 23:59:60 for the inserted second",
+                LeapDisplay::Smear => "Run slow over the surrounding day instead",
+            };
+            if ui
+                .radio_value(&mut readout.leap_display, display, tr(display.label()))
+                .on_hover_text(tr(hover))
+                .changed()
+            {
+                changed = true;
+            }
+        }
+    });
+    if ui.checkbox(&mut readout.show_time_scales, tr("TAI and GPS time")).changed() {
+        changed = true;
+    }
+    if ui
+        .checkbox(&mut readout.high_precision, tr("Milliseconds (P)"))
+        .on_hover_text(tr("Add milliseconds and a once-per-second sweep inside the ring"))
+        .changed()
+    {
+        changed = true;
+    }
+
+    changed
+}
+
+/// Draw the secondary readout toggle and zone choice
+///
+/// UTC and favorites are listed first, then every zone. Returns true if
+/// anything was edited.
+fn draw_secondary_settings(
+    ui: &mut egui::Ui,
+    secondary: &mut SecondaryReadout,
+    favorites: &[Tz],
+) -> bool {
+    let mut changed = ui.checkbox(&mut secondary.enabled, tr("Secondary readout")).changed();
+
+    ui.add_enabled_ui(secondary.enabled, |ui| {
+        egui::ComboBox::from_id_source("secondary_zone")
+            .selected_text(secondary.zone().name())
+            .width(170.0)
+            .show_ui(ui, |ui| {
+                let mut pick = |ui: &mut egui::Ui, tz: Tz| {
+                    let is_current = secondary.zone() == tz;
+                    if ui.selectable_label(is_current, tz.name()).clicked() && !is_current {
+                        secondary.zone_id = tz.name().to_string();
+                        changed = true;
+                    }
+                };
+                pick(ui, Tz::UTC);
+                for &tz in favorites {
+                    pick(ui, tz);
+                }
+                ui.separator();
+                for tz in all_timezones() {
+                    pick(ui, tz);
+                }
+            });
+    });
+    ui.label(tr("Press U to toggle"));
 
     changed
 }
 
+/// NTP calibration controls; returns (settings changed, measure now)
+fn draw_calibration_settings(
+    ui: &mut egui::Ui,
+    calibration: &mut CalibrationSettings,
+    measuring: bool,
+) -> (bool, bool) {
+    let mut changed = ui
+        .checkbox(&mut calibration.enabled, tr("NTP calibration"))
+        .on_hover_text(tr("Compare the system clock against an NTP server"))
+        .changed();
+    let mut measure = false;
+
+    ui.add_enabled_ui(calibration.enabled, |ui| {
+        ui.horizontal(|ui| {
+            ui.label(tr("Server:"));
+            let edit = egui::TextEdit::singleline(&mut calibration.server)
+                .hint_text(crate::calibration::DEFAULT_SERVER)
+                .desired_width(120.0);
+            if ui.add(edit).changed() {
+                changed = true;
+            }
+        });
+        let label = if measuring { tr("Measuring…") } else { tr("Measure now") };
+        if ui.add_enabled(!measuring, egui::Button::new(label)).clicked() {
+            measure = true;
+        }
+    });
+    ui.label(tr("Press N to toggle"));
+
+    (changed, measure)
+}
+
+/// Hourly chime controls; returns (settings changed, test chime)
+fn draw_chime_settings(
+    ui: &mut egui::Ui,
+    chimes: &mut ChimeSettings,
+    audio_available: bool,
+) -> (bool, bool) {
+    let mut changed = false;
+    let mut test = false;
+
+    ui.horizontal(|ui| {
+        ui.label(tr("Chime:"));
+        changed |= ui.checkbox(&mut chimes.hourly, tr("Hour")).changed();
+        changed |= ui.checkbox(&mut chimes.half_hourly, tr("Half hour")).changed();
+    });
+
+    ui.add_enabled_ui(chimes.hourly || chimes.half_hourly, |ui| {
+        ui.horizontal(|ui| {
+            for sound in [ChimeSound::ShipsBells, ChimeSound::Tones] {
+                changed |= ui.radio_value(&mut chimes.sound, sound, tr(sound.label())).changed();
+            }
+        });
+        ui.horizontal(|ui| {
+            for count in [ChimeCount::Count, ChimeCount::Single] {
+                let hover = match (count, chimes.sound) {
+                    (ChimeCount::Count, ChimeSound::ShipsBells) => {
+                        "1 to 8 bells through each four-hour watch"
+                    }
+                    (ChimeCount::Count, ChimeSound::Tones) => {
+                        "The hour's count on the hour, one on the half hour"
+                    }
+                    (ChimeCount::Single, _) => "One strike each time",
+                };
+                changed |= ui
+                    .radio_value(&mut chimes.count, count, tr(count.label()))
+                    .on_hover_text(tr(hover))
+                    .changed();
+            }
+        });
+        ui.horizontal(|ui| {
+            changed |= ui.checkbox(&mut chimes.audible, tr("Sound")).changed();
+            ui.add_enabled_ui(chimes.audible, |ui| {
+                let slider = egui::Slider::new(&mut chimes.volume, 0.0..=1.0).show_value(false);
+                changed |= ui.add(slider).changed();
+            });
+        });
+        changed |= ui
+            .checkbox(&mut chimes.flash, tr("Flash"))
+            .on_hover_text(tr("Flash the panel with each strike, for silent rooms"))
+            .changed();
+        if chimes.audible && !audio_available {
+            ui.label(egui::RichText::new(tr(UNAVAILABLE_REASON)).small());
+        }
+        test = ui.button(tr("Test chime")).clicked();
+    });
+    ui.label(egui::RichText::new(tr("Quiet hours silence chimes and hold the flash")).small());
+
+    (changed, test)
+}
+
 /// Draw the main timezone info bar (clickable to open picker)
 pub fn draw_timezone_bar(ctx: &egui::Context, time_data: &TimeData) -> bool {
     let mut clicked = false;
@@ -371,16 +882,16 @@ pub fn draw_timezone_bar(ctx: &egui::Context, time_data: &TimeData) -> bool {
         .show(ctx, |ui| {
             ui.horizontal(|ui| {
                 let tz_text = format!(
-                    "{} ({}) · {} · DST {}",
+                    "{} ({}) · {} · {}",
                     time_data.local_datetime.timezone().name(),
                     time_data.tz_abbrev,
                     time_data.format_utc_offset(),
-                    if time_data.is_dst { "On" } else { "Off" }
+                    if time_data.is_dst { tr("DST on") } else { tr("DST off") }
                 );
                 
                 if ui
                     .add(egui::Label::new(&tz_text).sense(egui::Sense::click()))
-                    .on_hover_text("Click to change time zone (or press Space)")
+                    .on_hover_text(tr("Click to change time zone (or press Space)"))
                     .clicked()
                 {
                     clicked = true;
@@ -390,3 +901,82 @@ pub fn draw_timezone_bar(ctx: &egui::Context, time_data: &TimeData) -> bool {
 
     clicked
 }
+
+/// Restyle egui's chrome for the active theme
+///
+/// Leaves `visuals` alone while the clock uses its own colors.
+pub fn theme_visuals(visuals: &mut egui::Visuals) {
+    let Some(palette) = active_palette() else {
+        return;
+    };
+    let color = |c: Rgb| egui::Color32::from_rgb(c.red, c.green, c.blue);
+    *visuals = if palette.is_light() {
+        egui::Visuals::light()
+    } else {
+        egui::Visuals::dark()
+    };
+    visuals.panel_fill = color(palette.background);
+    visuals.window_fill = color(palette.background);
+    visuals.override_text_color = Some(color(palette.primary_text));
+    visuals.hyperlink_color = color(palette.accent);
+    visuals.warn_fg_color = color(palette.warning);
+}
+
+/// Draw the theme switcher
+///
+/// Returns the newly chosen theme, where `Some(None)` means the clock's own
+/// colors.
+pub fn theme_selector(
+    ui: &mut egui::Ui,
+    themes: &ThemeLibrary,
+    selected: Option<&str>,
+) -> Option<Option<String>> {
+    let mut choice = None;
+    egui::ComboBox::from_label(tr("Theme"))
+        .selected_text(selected.unwrap_or(tr("Clock colors")))
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(selected.is_none(), tr("Clock colors")).clicked() {
+                choice = Some(None);
+            }
+            for theme in &themes.themes {
+                let is_selected = selected == Some(theme.name.as_str());
+                if ui.selectable_label(is_selected, &theme.name).clicked() {
+                    choice = Some(Some(theme.name.clone()));
+                }
+            }
+        });
+    if let Some(error) = &themes.error {
+        ui.label(
+            egui::RichText::new(error)
+                .size(10.0)
+                .color(egui::Color32::from_rgb(255, 100, 100)),
+        );
+    }
+    choice
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visible_favorites_without_focus() {
+        assert_eq!(visible_favorites(0, None), 0..0);
+        assert_eq!(visible_favorites(4, None), 0..4);
+        assert_eq!(visible_favorites(10, None), 0..MAX_VISIBLE_FAVORITES);
+    }
+
+    #[test]
+    fn test_visible_favorites_slides_to_focus() {
+        // Focus inside the first window leaves it alone
+        assert_eq!(visible_favorites(10, Some(5)), 0..6);
+        // Past it, the window ends on the focused chip
+        assert_eq!(visible_favorites(10, Some(6)), 1..7);
+        assert_eq!(visible_favorites(10, Some(9)), 4..10);
+        // A stale cursor past the end clamps to the last window
+        assert_eq!(visible_favorites(10, Some(14)), 4..10);
+        // Fewer favorites than the row holds never slide
+        assert_eq!(visible_favorites(3, Some(2)), 0..3);
+        assert_eq!(visible_favorites(3, Some(7)), 0..3);
+    }
+}
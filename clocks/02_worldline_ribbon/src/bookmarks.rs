@@ -0,0 +1,233 @@
+//! Bookmarks module - named instants flagged on the ribbon
+//!
+//! Bookmarks are kept per timezone: each zone has its own list, and the
+//! ribbon shows the list of the zone it is displaying. Lists can be filled
+//! from a CSV file with one `label,instant` row per bookmark, where the
+//! instant is either RFC 3339 (`2024-03-10T09:30:00Z`) or a local wall time
+//! in the displayed zone (`2024-03-10 09:30`).
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, LocalResult, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// Local wall-time layouts accepted in CSV files, tried in order
+const LOCAL_FORMATS: [&str; 4] = [
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+];
+
+/// A named instant
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub label: String,
+    #[serde(with = "rfc3339")]
+    pub instant: DateTime<Utc>,
+}
+
+impl Bookmark {
+    pub fn new(label: &str, instant: DateTime<Utc>) -> Self {
+        Self {
+            label: label.trim().to_string(),
+            instant,
+        }
+    }
+}
+
+/// Bookmarks for every timezone, keyed by zone id
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct BookmarkBook {
+    zones: BTreeMap<String, Vec<Bookmark>>,
+}
+
+impl BookmarkBook {
+    /// Bookmarks for `tz`, earliest first
+    pub fn for_zone(&self, tz: Tz) -> &[Bookmark] {
+        self.zones.get(tz.name()).map_or(&[], Vec::as_slice)
+    }
+
+    /// Add a bookmark to `tz`, keeping the list in time order
+    ///
+    /// Returns false (and adds nothing) if the zone already has a bookmark
+    /// with the same label at the same instant.
+    pub fn add(&mut self, tz: Tz, bookmark: Bookmark) -> bool {
+        let list = self.zones.entry(tz.name().to_string()).or_default();
+        if list.contains(&bookmark) {
+            return false;
+        }
+        let index = list.partition_point(|b| b.instant <= bookmark.instant);
+        list.insert(index, bookmark);
+        true
+    }
+
+    /// Remove the bookmark at `index` in `tz`'s list
+    pub fn remove(&mut self, tz: Tz, index: usize) -> Option<Bookmark> {
+        let list = self.zones.get_mut(tz.name())?;
+        if index >= list.len() {
+            return None;
+        }
+        let removed = list.remove(index);
+        if list.is_empty() {
+            self.zones.remove(tz.name());
+        }
+        Some(removed)
+    }
+
+    /// Add every bookmark in `bookmarks` to `tz`, returning how many were new
+    pub fn import(&mut self, tz: Tz, bookmarks: Vec<Bookmark>) -> usize {
+        bookmarks
+            .into_iter()
+            .filter(|bookmark| self.add(tz, bookmark.clone()))
+            .count()
+    }
+}
+
+/// Parse bookmark CSV, reading local wall times in `tz`
+///
+/// Blank lines and lines starting with `#` are skipped, as is a leading
+/// `label,instant` header. Labels may be double-quoted to contain commas.
+pub fn parse_csv(contents: &str, tz: Tz) -> Result<Vec<Bookmark>, String> {
+    let mut bookmarks = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line_number = index + 1;
+        let Some((label, instant)) = line.rsplit_once(',') else {
+            return Err(format!("line {}: expected label,instant", line_number));
+        };
+        let label = unquote(label.trim());
+        let instant = instant.trim();
+        if bookmarks.is_empty() && label.eq_ignore_ascii_case("label") {
+            continue;
+        }
+        if label.is_empty() {
+            return Err(format!("line {}: missing label", line_number));
+        }
+        let instant = parse_instant(instant, tz)
+            .map_err(|e| format!("line {}: {}", line_number, e))?;
+        bookmarks.push(Bookmark::new(&label, instant));
+    }
+    Ok(bookmarks)
+}
+
+/// Read and parse a bookmark CSV file
+pub fn read_csv(path: &Path, tz: Tz) -> Result<Vec<Bookmark>, String> {
+    let contents =
+        fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    parse_csv(&contents, tz)
+}
+
+/// Parse an RFC 3339 instant, or a wall time in `tz`
+///
+/// Ambiguous wall times (in a DST fall-back hour) use the earlier instant;
+/// wall times skipped by a spring-forward are rejected.
+fn parse_instant(text: &str, tz: Tz) -> Result<DateTime<Utc>, String> {
+    if let Ok(instant) = DateTime::parse_from_rfc3339(text) {
+        return Ok(instant.with_timezone(&Utc));
+    }
+    let naive = LOCAL_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+        .ok_or_else(|| format!("unrecognised time {:?}", text))?;
+    match tz.from_local_datetime(&naive) {
+        LocalResult::Single(local) | LocalResult::Ambiguous(local, _) => {
+            Ok(local.with_timezone(&Utc))
+        }
+        LocalResult::None => Err(format!("{} does not exist in {}", text, tz.name())),
+    }
+}
+
+/// Strip surrounding double quotes, undoing `""` escapes
+fn unquote(field: &str) -> String {
+    match field.strip_prefix('"').and_then(|f| f.strip_suffix('"')) {
+        Some(inner) => inner.replace("\"\"", "\""),
+        None => field.to_string(),
+    }
+}
+
+/// Store instants as RFC 3339 strings in the config file
+mod rfc3339 {
+    use chrono::{DateTime, Utc};
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        instant: &DateTime<Utc>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&instant.to_rfc3339())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Utc>, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        DateTime::parse_from_rfc3339(&text)
+            .map(|instant| instant.with_timezone(&Utc))
+            .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn los_angeles() -> Tz {
+        "America/Los_Angeles".parse().unwrap()
+    }
+
+    #[test]
+    fn test_book_keeps_zones_apart_and_sorted() {
+        let la = los_angeles();
+        let tokyo: Tz = "Asia/Tokyo".parse().unwrap();
+        let early = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+        let late = Utc.with_ymd_and_hms(2024, 5, 2, 12, 0, 0).unwrap();
+
+        let mut book = BookmarkBook::default();
+        assert!(book.add(la, Bookmark::new("release freeze", late)));
+        assert!(book.add(la, Bookmark::new("flight departs", early)));
+        assert!(!book.add(la, Bookmark::new("flight departs", early)));
+        assert!(book.for_zone(tokyo).is_empty());
+
+        let labels: Vec<&str> = book.for_zone(la).iter().map(|b| b.label.as_str()).collect();
+        assert_eq!(labels, ["flight departs", "release freeze"]);
+
+        assert_eq!(book.remove(la, 0).unwrap().label, "flight departs");
+        assert!(book.remove(la, 5).is_none());
+        assert_eq!(book.for_zone(la).len(), 1);
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        let csv = "label,instant\n\
+                   # comment\n\
+                   flight departs,2024-03-10T09:30:00Z\n\
+                   \n\
+                   \"standup, daily\",2024-03-11 09:00\n";
+        let bookmarks = parse_csv(csv, los_angeles()).unwrap();
+        assert_eq!(bookmarks.len(), 2);
+        assert_eq!(bookmarks[0].instant, Utc.with_ymd_and_hms(2024, 3, 10, 9, 30, 0).unwrap());
+        // Local wall time in Los Angeles, after the spring-forward (PDT)
+        assert_eq!(bookmarks[1].label, "standup, daily");
+        assert_eq!(bookmarks[1].instant, Utc.with_ymd_and_hms(2024, 3, 11, 16, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_parse_csv_errors() {
+        let la = los_angeles();
+        assert!(parse_csv("no comma here", la).unwrap_err().starts_with("line 1"));
+        assert!(parse_csv("a,2024-03-10T09:30:00Z\nb,tomorrow", la)
+            .unwrap_err()
+            .starts_with("line 2"));
+        assert!(parse_csv(",2024-03-10T09:30:00Z", la).is_err());
+        // 02:30 is skipped by the spring-forward in Los Angeles
+        assert!(parse_csv("gap,2024-03-10 02:30", la).is_err());
+    }
+}
@@ -1,15 +1,24 @@
 //! Drawing module - ribbon rendering, DST seams, and visual effects
 //!
-//! Renders the worldline ribbon with its warm amber/sepia "paper scroll" aesthetic.
+//! Renders the worldline ribbon with its warm amber/sepia "paper scroll" aesthetic,
+//! shaded by day and night in the selected zone, with historical DST rule
+//! changes labeled above their seams, plus any extra timezone lanes stacked
+//! beneath it. Every band carries a tag with its UTC offset, flagged when
+//! the offset isn't a whole number of hours.
 
+use chrono::{DateTime, Utc};
 use nannou::prelude::*;
-use shared::DstTransition;
+use shared::{tr, tr_args, CommandRegistry, DaylightPhase, DstTransition, RuleEpoch};
 
-use crate::ribbon::{RibbonViewport, Tick, TickType};
+use crate::bookmarks::Bookmark;
+use crate::offsets::ZoneOffset;
+use crate::ribbon::{format_zoom, DaylightBand, RibbonViewport, Tick, TickType};
+use crate::selection::TimeRange;
 
 /// Color palette for the worldline ribbon theme - warm amber/sepia paper scroll aesthetic
 pub mod colors {
     use nannou::prelude::*;
+    use shared::{Rgb, ThemeRole};
 
     /// Deep warm brown background
     pub const BACKGROUND: Srgb<u8> = Srgb {
@@ -108,6 +117,22 @@ pub mod colors {
         standard: std::marker::PhantomData,
     };
 
+    /// Offset tag of a zone off the whole hour
+    pub const ODD_OFFSET: Srgb<u8> = Srgb {
+        red: 120,
+        green: 200,
+        blue: 255,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Inserted leap second
+    pub const LEAP_SECOND: Srgb<u8> = Srgb {
+        red: 100,
+        green: 210,
+        blue: 200,
+        standard: std::marker::PhantomData,
+    };
+
     /// Scrub mode indicator
     pub const SCRUB_MODE: Srgb<u8> = Srgb {
         red: 120,
@@ -115,6 +140,76 @@ pub mod colors {
         blue: 220,
         standard: std::marker::PhantomData,
     };
+
+    /// Deep indigo laid over the ribbon at night (half strength in twilight)
+    pub const NIGHT_SHADE: Srgb<u8> = Srgb {
+        red: 10,
+        green: 12,
+        blue: 34,
+        standard: std::marker::PhantomData,
+    };
+
+    /// DST rule change labels - pale gold, quieter than the seams
+    pub const EPOCH: Srgb<u8> = Srgb {
+        red: 230,
+        green: 200,
+        blue: 140,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Bookmark flags - pale green, distinct from the seams and cursor
+    pub const BOOKMARK: Srgb<u8> = Srgb {
+        red: 150,
+        green: 210,
+        blue: 140,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Selected range band - pale blue, shaded over the ticks
+    pub const SELECTION: Srgb<u8> = Srgb {
+        red: 140,
+        green: 190,
+        blue: 255,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Look up one of this clock's role colors in the active theme
+    fn themed(role: ThemeRole, native: Srgb<u8>) -> Srgb<u8> {
+        let color = shared::themed(role, Rgb::new(native.red, native.green, native.blue));
+        Srgb::new(color.red, color.green, color.blue)
+    }
+
+    pub fn background() -> Srgb<u8> {
+        themed(ThemeRole::Background, BACKGROUND)
+    }
+
+    pub fn text_primary() -> Srgb<u8> {
+        themed(ThemeRole::PrimaryText, TEXT_PRIMARY)
+    }
+
+    pub fn text_secondary() -> Srgb<u8> {
+        themed(ThemeRole::SecondaryText, TEXT_SECONDARY)
+    }
+
+    pub fn now_cursor() -> Srgb<u8> {
+        themed(ThemeRole::Accent, NOW_CURSOR)
+    }
+
+    pub fn dst_seam() -> Srgb<u8> {
+        themed(ThemeRole::Warning, DST_SEAM)
+    }
+
+    pub fn scrub_mode() -> Srgb<u8> {
+        themed(ThemeRole::Accent, SCRUB_MODE)
+    }
+
+    pub fn epoch() -> Srgb<u8> {
+        themed(ThemeRole::SecondaryText, EPOCH)
+    }
+
+    pub fn bookmark() -> Srgb<u8> {
+        themed(ThemeRole::Accent, BOOKMARK)
+    }
 }
 
 /// Layout configuration for the ribbon
@@ -131,10 +226,45 @@ pub struct RibbonLayout {
 }
 
 impl RibbonLayout {
-    pub fn calculate(window_rect: Rect) -> Self {
-        let ribbon_height = (window_rect.h() * 0.15).clamp(60.0, 120.0);
+    /// Layout for the primary ribbon with `extra_lanes` lanes stacked below it
+    ///
+    /// The primary ribbon gets slimmer when lanes are present so the stack
+    /// still fits above the help text.
+    pub fn calculate(window_rect: Rect, extra_lanes: usize) -> Self {
+        let ribbon_height = if extra_lanes == 0 {
+            (window_rect.h() * 0.15).clamp(60.0, 120.0)
+        } else {
+            (window_rect.h() * 0.09).clamp(40.0, 80.0)
+        };
+        Self::with_height(0.0, ribbon_height)
+    }
+
+    /// Top and bottom of the primary ribbon plus `extra_lanes` lanes
+    pub fn stack_extent(&self, extra_lanes: usize) -> (f32, f32) {
+        let top = self.ribbon_center_y + self.ribbon_height;
+        let bottom = match extra_lanes {
+            0 => self.ribbon_center_y - self.ribbon_height,
+            n => {
+                let last_lane = self.lane(n - 1);
+                last_lane.ribbon_center_y - last_lane.ribbon_height
+            }
+        };
+        (top, bottom)
+    }
+
+    /// Layout for extra lane `index` (0 = directly under the primary ribbon)
+    pub fn lane(&self, index: usize) -> Self {
+        let lane_height = self.ribbon_height * 0.6;
+        let gap = 24.0; // Room for the hour labels of the band above
+        let first_center_y =
+            self.ribbon_center_y - self.ribbon_height / 2.0 - gap - lane_height / 2.0;
+        let center_y = first_center_y - index as f32 * (lane_height + gap);
+        Self::with_height(center_y, lane_height)
+    }
+
+    fn with_height(ribbon_center_y: f32, ribbon_height: f32) -> Self {
         Self {
-            ribbon_center_y: 0.0,
+            ribbon_center_y,
             ribbon_height,
             tick_height_hour: ribbon_height * 0.6,
             tick_height_five_min: ribbon_height * 0.4,
@@ -145,11 +275,15 @@ impl RibbonLayout {
 }
 
 /// Draw the complete ribbon visualization
+///
+/// `daylight` shades the ribbon by the sun in the selected zone.
+#[allow(clippy::too_many_arguments)]
 pub fn draw_ribbon(
     draw: &Draw,
     viewport: &RibbonViewport,
     ticks: &[Tick],
     transitions: &[DstTransition],
+    daylight: &[DaylightBand],
     layout: &RibbonLayout,
     is_scrub_mode: bool,
     reduced_motion: bool,
@@ -157,6 +291,17 @@ pub fn draw_ribbon(
     // Draw ribbon background
     draw_ribbon_background(draw, viewport, layout);
 
+    // Darken nights and twilight, edges warped like the ticks
+    let warp = |instant| {
+        let x = viewport.instant_to_x(instant);
+        if reduced_motion {
+            x
+        } else {
+            viewport.apply_warp(x, instant, transitions)
+        }
+    };
+    draw_daylight_bands(draw, daylight, warp, layout);
+
     // Draw DST seams first (so ticks appear on top)
     for transition in transitions {
         draw_dst_seam(draw, viewport, transition, layout, reduced_motion);
@@ -176,6 +321,195 @@ pub fn draw_ribbon(
     draw_now_cursor(draw, layout, is_scrub_mode);
 }
 
+/// Draw a flag above the ribbon for every bookmark in the viewport
+pub fn draw_bookmarks(
+    draw: &Draw,
+    viewport: &RibbonViewport,
+    bookmarks: &[Bookmark],
+    layout: &RibbonLayout,
+) {
+    let half_width = viewport.viewport_width / 2.0;
+    let pole_bottom = layout.ribbon_center_y + layout.ribbon_height / 2.0;
+    let pole_top = pole_bottom + layout.ribbon_height * 0.6 + 20.0;
+    let flag_size = 10.0;
+    let color = colors::bookmark();
+
+    for bookmark in bookmarks {
+        let x = viewport.instant_to_x(bookmark.instant);
+        if x.abs() > half_width {
+            continue;
+        }
+
+        draw.line()
+            .start(pt2(x, pole_bottom))
+            .end(pt2(x, pole_top))
+            .color(color)
+            .weight(2.0);
+
+        // Pennant pointing right from the top of the pole
+        draw.polygon()
+            .points(vec![
+                pt2(x, pole_top),
+                pt2(x + flag_size * 1.4, pole_top - flag_size / 2.0),
+                pt2(x, pole_top - flag_size),
+            ])
+            .color(color);
+
+        draw.text(&bookmark.label)
+            .x_y(x + flag_size * 1.6 + 60.0, pole_top - flag_size / 2.0)
+            .left_justify()
+            .color(color)
+            .font_size(11)
+            .w(120.0);
+    }
+}
+
+/// Mark and label every DST rule change in the viewport, dated so scrubbed
+/// history reads at a glance; neighbouring labels alternate rows
+pub fn draw_rule_epochs(
+    draw: &Draw,
+    viewport: &RibbonViewport,
+    epochs: &[RuleEpoch],
+    layout: &RibbonLayout,
+) {
+    let half_width = viewport.viewport_width / 2.0;
+    let marker_bottom = layout.ribbon_center_y + layout.ribbon_height / 2.0;
+    let label_base = layout.ribbon_center_y + layout.ribbon_height * 0.75 + 42.0;
+    let color = colors::epoch();
+
+    for (index, epoch) in epochs.iter().enumerate() {
+        let x = viewport.instant_to_x(epoch.instant_utc);
+        if x.abs() > half_width {
+            continue;
+        }
+        let label_y = label_base + if index % 2 == 1 { 16.0 } else { 0.0 };
+
+        draw.line()
+            .start(pt2(x, marker_bottom))
+            .end(pt2(x, label_y - 7.0))
+            .color(color)
+            .weight(1.0);
+
+        let local = epoch.instant_utc.with_timezone(&viewport.timezone);
+        draw.text(&format!("{} · {}", epoch.label, local.format("%b %-d %Y")))
+            .x_y(x, label_y)
+            .color(color)
+            .font_size(11)
+            .w(240.0);
+    }
+}
+
+/// Shade a selected range across the ribbon and every lane
+pub fn draw_selection(
+    draw: &Draw,
+    viewport: &RibbonViewport,
+    range: &TimeRange,
+    layout: &RibbonLayout,
+    extra_lanes: usize,
+) {
+    let half_width = viewport.viewport_width / 2.0;
+    let left = viewport.instant_to_x(range.start).max(-half_width);
+    let right = viewport.instant_to_x(range.end).min(half_width);
+    if left > right {
+        return;
+    }
+    let (top, bottom) = layout.stack_extent(extra_lanes);
+    let c = colors::SELECTION;
+
+    draw.rect()
+        .x_y((left + right) / 2.0, (top + bottom) / 2.0)
+        .w_h((right - left).max(1.0), top - bottom)
+        .color(srgba(c.red, c.green, c.blue, 50u8));
+
+    // Edges, only where the range ends inside the viewport
+    for (instant, x) in [(range.start, left), (range.end, right)] {
+        if viewport.instant_to_x(instant).abs() <= half_width {
+            draw.line()
+                .start(pt2(x, bottom))
+                .end(pt2(x, top))
+                .color(c)
+                .weight(1.5);
+        }
+    }
+}
+
+/// Draw one extra timezone lane on the shared UTC axis
+pub fn draw_lane(
+    draw: &Draw,
+    viewport: &RibbonViewport,
+    ticks: &[Tick],
+    transitions: &[DstTransition],
+    layout: &RibbonLayout,
+    is_scrub_mode: bool,
+    reduced_motion: bool,
+) {
+    let half_width = viewport.viewport_width / 2.0;
+    let top = layout.ribbon_center_y + layout.ribbon_height / 2.0;
+    let bottom = layout.ribbon_center_y - layout.ribbon_height / 2.0;
+
+    // Lane band
+    draw.rect()
+        .x_y(0.0, layout.ribbon_center_y)
+        .w_h(viewport.viewport_width + 20.0, layout.ribbon_height)
+        .color(colors::RIBBON_DARK);
+
+    for y in [top, bottom] {
+        draw.line()
+            .start(pt2(-half_width, y))
+            .end(pt2(half_width, y))
+            .color(colors::TICK_MINUTE)
+            .weight(1.0);
+    }
+
+    // Compact DST seams - label sits inside the lane to keep the stack tight
+    for transition in transitions {
+        let x = viewport.instant_to_x(transition.instant_utc);
+        draw.line()
+            .start(pt2(x, top))
+            .end(pt2(x, bottom))
+            .color(colors::dst_seam())
+            .weight(2.5);
+
+        let sign = if transition.delta_minutes > 0 { "+" } else { "" };
+        let shift = format!("{}{}", sign, transition.delta_minutes);
+        draw.text(&tr_args("DST {0}m", &[&shift]))
+            .x_y(x + 36.0, top - 8.0)
+            .color(colors::dst_seam())
+            .font_size(10)
+            .w(64.0);
+    }
+
+    // Tick marks with warp effect
+    for tick in ticks {
+        let warped_x = if reduced_motion {
+            tick.x_position
+        } else {
+            viewport.apply_warp(tick.x_position, tick.instant, transitions)
+        };
+        draw_tick(draw, tick, warped_x, layout);
+    }
+
+    // Cursor segment so the shared instant reads across every lane
+    let cursor_color = if is_scrub_mode {
+        colors::SCRUB_MODE
+    } else {
+        colors::now_cursor()
+    };
+    draw.line()
+        .start(pt2(0.0, top))
+        .end(pt2(0.0, bottom))
+        .color(cursor_color)
+        .weight(2.0);
+
+    // Zone label at the left edge
+    draw.text(viewport.timezone.name())
+        .x_y(-half_width + 110.0, layout.ribbon_center_y)
+        .color(colors::text_primary())
+        .font_size(11)
+        .left_justify()
+        .w(200.0);
+}
+
 fn draw_ribbon_background(draw: &Draw, viewport: &RibbonViewport, layout: &RibbonLayout) {
     let half_width = viewport.viewport_width / 2.0;
 
@@ -225,6 +559,27 @@ fn draw_ribbon_background(draw: &Draw, viewport: &RibbonViewport, layout: &Ribbo
         .weight(1.0);
 }
 
+fn draw_daylight_bands(
+    draw: &Draw,
+    bands: &[DaylightBand],
+    x_for: impl Fn(DateTime<Utc>) -> f32,
+    layout: &RibbonLayout,
+) {
+    for band in bands {
+        let alpha = match band.phase {
+            DaylightPhase::Day => continue,
+            DaylightPhase::CivilTwilight => 85u8,
+            DaylightPhase::Night => 170u8,
+        };
+        let shade = colors::NIGHT_SHADE;
+        let (start, end) = (x_for(band.start), x_for(band.end));
+        draw.rect()
+            .x_y((start + end) / 2.0, layout.ribbon_center_y)
+            .w_h(end - start, layout.ribbon_height)
+            .color(srgba(shade.red, shade.green, shade.blue, alpha));
+    }
+}
+
 fn draw_tick(draw: &Draw, tick: &Tick, x: f32, layout: &RibbonLayout) {
     let (height, color, weight) = match tick.tick_type {
         TickType::Hour => (layout.tick_height_hour, colors::TICK_HOUR, 2.0),
@@ -232,6 +587,10 @@ fn draw_tick(draw: &Draw, tick: &Tick, x: f32, layout: &RibbonLayout) {
         TickType::Minute => (layout.tick_height_minute, colors::TICK_MINUTE, 1.0),
         TickType::Second => (layout.tick_height_second, colors::TICK_SECOND, 0.5),
         TickType::Midnight => (layout.tick_height_hour, colors::MIDNIGHT, 3.0),
+        TickType::Day => (layout.tick_height_five_min, colors::TICK_FIVE_MIN, 1.0),
+        TickType::Week => (layout.tick_height_hour, colors::TICK_HOUR, 2.0),
+        TickType::Month => (layout.tick_height_hour, colors::MIDNIGHT, 3.0),
+        TickType::LeapSecond => (layout.ribbon_height, colors::LEAP_SECOND, 2.5),
     };
 
     let top = layout.ribbon_center_y + height / 2.0;
@@ -243,16 +602,31 @@ fn draw_tick(draw: &Draw, tick: &Tick, x: f32, layout: &RibbonLayout) {
         .color(color)
         .weight(weight);
 
+    // A leap second's tick spans the ribbon, capped with a diamond
+    if tick.tick_type == TickType::LeapSecond {
+        draw.quad()
+            .points(
+                pt2(x, top + 5.0),
+                pt2(x + 4.0, top + 1.0),
+                pt2(x, top - 3.0),
+                pt2(x - 4.0, top + 1.0),
+            )
+            .color(color);
+    }
+
     // Draw label if present
     if let Some(ref label) = tick.label {
         let label_y = match tick.tick_type {
-            TickType::Midnight => layout.ribbon_center_y + layout.ribbon_height / 2.0 + 25.0,
+            TickType::Midnight | TickType::Month => {
+                layout.ribbon_center_y + layout.ribbon_height / 2.0 + 25.0
+            }
             _ => layout.ribbon_center_y - layout.ribbon_height / 2.0 - 15.0,
         };
 
         let label_color = match tick.tick_type {
-            TickType::Midnight => colors::MIDNIGHT,
-            _ => colors::TEXT_SECONDARY,
+            TickType::Midnight | TickType::Month => colors::MIDNIGHT,
+            TickType::LeapSecond => colors::LEAP_SECOND,
+            _ => colors::text_secondary(),
         };
 
         draw.text(label)
@@ -260,6 +634,15 @@ fn draw_tick(draw: &Draw, tick: &Tick, x: f32, layout: &RibbonLayout) {
             .color(label_color)
             .font_size(12)
             .w(120.0);
+
+        // ISO week and day of year, under the date
+        if let Some(ref sublabel) = tick.sublabel {
+            draw.text(sublabel)
+                .x_y(x, label_y - 13.0)
+                .color(colors::text_secondary())
+                .font_size(10)
+                .w(120.0);
+        }
     }
 }
 
@@ -277,11 +660,22 @@ fn draw_dst_seam(
     draw.line()
         .start(pt2(x, layout.ribbon_center_y + seam_height / 2.0))
         .end(pt2(x, layout.ribbon_center_y - seam_height / 2.0))
-        .color(colors::DST_SEAM)
+        .color(colors::dst_seam())
         .weight(3.0);
 
-    // Glow effect (unless reduced motion)
-    if !reduced_motion {
+    // Glow effect (unless reduced motion); a diamond marks the thin seam at macro zoom
+    if viewport.is_macro() {
+        let top = layout.ribbon_center_y + seam_height / 2.0;
+        let size = 6.0;
+        draw.polygon()
+            .points(vec![
+                pt2(x, top + size),
+                pt2(x + size, top),
+                pt2(x, top - size),
+                pt2(x - size, top),
+            ])
+            .color(colors::dst_seam());
+    } else if !reduced_motion {
         for i in 1..=5 {
             let alpha = (50 - i * 8) as u8;
             let offset = i as f32 * 2.0;
@@ -298,31 +692,35 @@ fn draw_dst_seam(
         }
     }
 
-    // DST label
+    // DST label, dated at macro zoom where days are only a few pixels wide
     let sign = if transition.delta_minutes > 0 { "+" } else { "" };
-    let label = format!("DST {}{}m", sign, transition.delta_minutes);
+    let mut label = tr_args("DST {0}m", &[&format!("{}{}", sign, transition.delta_minutes)]);
+    if viewport.is_macro() {
+        let local = transition.instant_utc.with_timezone(&viewport.timezone);
+        label.push_str(&local.format(" %b %d").to_string());
+    }
     let label_y = layout.ribbon_center_y + seam_height / 2.0 + 20.0;
 
     draw.text(&label)
         .x_y(x, label_y)
-        .color(colors::DST_SEAM)
+        .color(colors::dst_seam())
         .font_size(14)
-        .w(100.0);
+        .w(160.0);
 
-    // Wall time labels (if not reduced motion)
-    if !reduced_motion {
+    // Wall time labels (if not reduced motion, and the hours are visible)
+    if !reduced_motion && !viewport.is_macro() {
         let before_y = layout.ribbon_center_y - seam_height / 2.0 - 15.0;
         let after_y = layout.ribbon_center_y - seam_height / 2.0 - 30.0;
 
-        draw.text(&format!("Before: {}", transition.local_wall_time_before))
+        draw.text(&tr_args("Before: {0}", &[&transition.local_wall_time_before]))
             .x_y(x, before_y)
-            .color(colors::TEXT_SECONDARY)
+            .color(colors::text_secondary())
             .font_size(10)
             .w(180.0);
 
-        draw.text(&format!("After: {}", transition.local_wall_time_after))
+        draw.text(&tr_args("After: {0}", &[&transition.local_wall_time_after]))
             .x_y(x, after_y)
-            .color(colors::TEXT_SECONDARY)
+            .color(colors::text_secondary())
             .font_size(10)
             .w(180.0);
     }
@@ -336,7 +734,7 @@ fn draw_now_cursor(draw: &Draw, layout: &RibbonLayout, is_scrub_mode: bool) {
     let cursor_color = if is_scrub_mode {
         colors::SCRUB_MODE
     } else {
-        colors::NOW_CURSOR
+        colors::now_cursor()
     };
 
     // Main cursor line
@@ -368,6 +766,47 @@ fn draw_now_cursor(draw: &Draw, layout: &RibbonLayout, is_scrub_mode: bool) {
         .color(cursor_color);
 }
 
+/// Size of a band's offset tag
+const OFFSET_TAG_WIDTH: f32 = 116.0;
+const OFFSET_TAG_HEIGHT: f32 = 16.0;
+
+/// Where a band's offset tag sits: inside its top right corner
+pub fn offset_tag_rect(viewport_width: f32, layout: &RibbonLayout) -> Rect {
+    Rect::from_x_y_w_h(
+        viewport_width / 2.0 - OFFSET_TAG_WIDTH / 2.0 - 10.0,
+        layout.ribbon_center_y + layout.ribbon_height / 2.0 - OFFSET_TAG_HEIGHT / 2.0 - 3.0,
+        OFFSET_TAG_WIDTH,
+        OFFSET_TAG_HEIGHT,
+    )
+}
+
+/// Tag a band with its UTC offset, flagging offsets off the whole hour
+pub fn draw_offset_tag(draw: &Draw, offset: &ZoneOffset, rect: Rect) {
+    let (text, color) = if offset.is_unusual() {
+        (format!("◆ {}", offset.label()), colors::ODD_OFFSET)
+    } else {
+        (offset.label(), colors::text_secondary())
+    };
+
+    draw.rect()
+        .xy(rect.xy())
+        .wh(rect.wh())
+        .color(srgba(20u8, 16u8, 12u8, 210u8));
+    if offset.is_unusual() {
+        draw.rect()
+            .xy(rect.xy())
+            .wh(rect.wh())
+            .no_fill()
+            .stroke(color)
+            .stroke_weight(1.0);
+    }
+    draw.text(&text)
+        .xy(rect.xy())
+        .wh(rect.wh())
+        .color(color)
+        .font_size(10);
+}
+
 /// Draw the time display above the ribbon
 pub fn draw_time_display(
     draw: &Draw,
@@ -383,7 +822,7 @@ pub fn draw_time_display(
     let time_color = if is_scrub_mode {
         colors::SCRUB_MODE
     } else {
-        colors::TEXT_PRIMARY
+        colors::text_primary()
     };
 
     draw.text(time_text)
@@ -394,16 +833,16 @@ pub fn draw_time_display(
 
     draw.text(date_text)
         .x_y(0.0, date_y)
-        .color(colors::TEXT_SECONDARY)
+        .color(colors::text_secondary())
         .font_size(20)
         .w(400.0);
 
     // Scrub mode indicator - positioned above the time
     if is_scrub_mode {
         let indicator_y = time_y - 40.0;
-        draw.text("◆ SCRUB MODE ◆")
+        draw.text(tr("◆ SCRUB MODE ◆"))
             .x_y(0.0, indicator_y)
-            .color(colors::SCRUB_MODE)
+            .color(colors::scrub_mode())
             .font_size(12)
             .w(200.0);
     }
@@ -411,23 +850,40 @@ pub fn draw_time_display(
 
 /// Draw zoom level indicator
 pub fn draw_zoom_indicator(draw: &Draw, seconds_per_pixel: f32, window_rect: Rect) {
-    let text = format!("{:.0} sec/px", seconds_per_pixel);
+    let text = format_zoom(seconds_per_pixel);
     let x = window_rect.left() + 80.0;
     let y = window_rect.bottom() + 30.0;
 
     draw.text(&text)
         .x_y(x, y)
-        .color(colors::TEXT_SECONDARY)
+        .color(colors::text_secondary())
         .font_size(12)
         .w(100.0);
 }
 
-/// Draw keyboard shortcuts help
-pub fn draw_help_text(draw: &Draw, window_rect: Rect) {
+/// Draw keyboard shortcuts help, with the keys currently bound
+pub fn draw_help_text(draw: &Draw, window_rect: Rect, keys: &CommandRegistry) {
+    let pair = |first: &str, second: &str| {
+        format!("{}/{}", keys.key_label(first), keys.key_label(second))
+    };
+    let line = |hints: &[(String, &'static str)]| {
+        let hints: Vec<String> =
+            hints.iter().map(|(key, caption)| format!("{}: {}", key, tr(caption))).collect();
+        hints.join("  |  ")
+    };
     let help_lines = [
-        "Space: Toggle Live/Scrub",
-        "←/→: ±1 sec  |  Shift: ±1 min  |  Ctrl: ±1 hr",
-        "Ctrl+/Ctrl-: Zoom  |  /: Search TZ",
+        line(&[(keys.key_label("Toggle live/scrub"), "Toggle Live/Scrub")]),
+        line(&[
+            (pair("Step back one second", "Step forward one second"), "±1 sec"),
+            (pair("Step back one minute", "Step forward one minute"), "±1 min"),
+            (pair("Step back one hour", "Step forward one hour"), "±1 hr"),
+        ]),
+        line(&[
+            (pair("Zoom in", "Zoom out"), "Zoom"),
+            (keys.key_label("Open timezone picker"), "Search TZ"),
+            (tr("Shift+drag").to_string(), "Measure"),
+            ("Ctrl+K".to_string(), "Commands"),
+        ]),
     ];
 
     let x = 0.0;
@@ -456,7 +912,7 @@ pub fn draw_error_banner(draw: &Draw, message: &str, window_rect: Rect) {
     // Text
     draw.text(message)
         .x_y(0.0, banner_y)
-        .color(colors::TEXT_PRIMARY)
+        .color(colors::text_primary())
         .font_size(14)
         .w(window_rect.w() - 40.0);
 }
@@ -0,0 +1,286 @@
+//! Inertia module - momentum for mouse-drag scrubbing
+//!
+//! While the ribbon is dragged, the last tenth of a second of pointer samples
+//! gives a release velocity. Letting go mid-flick keeps the ribbon gliding
+//! until friction stops it; with snapping on it then eases onto the nearest
+//! local minute, hour or day, whichever suits the zoom level. Reduced motion skips
+//! the glide and the ease: the ribbon stops where it was released and jumps
+//! straight to the snap.
+
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Duration, Offset, Utc};
+use chrono_tz::Tz;
+
+use crate::ribbon::MACRO_ZOOM;
+
+/// Pointer samples older than this don't count toward the release velocity
+const SAMPLE_WINDOW_SECS: f32 = 0.1;
+
+/// Fastest release, in pixels per second, so a jittery flick can't fling days
+const MAX_RELEASE_SPEED: f32 = 4000.0;
+
+/// Fraction of the glide velocity left after one second
+const FRICTION_PER_SEC: f32 = 0.04;
+
+/// The glide ends below this speed, in pixels per second
+const STOP_SPEED: f32 = 15.0;
+
+/// Fraction of the distance to the snap target left after one second
+const SETTLE_PER_SEC: f32 = 0.0005;
+
+/// At this zoom (seconds per pixel) and coarser, snap to hours
+const HOUR_SNAP_ZOOM: f32 = 60.0;
+
+/// Recent horizontal pointer positions during a drag
+#[derive(Debug, Clone, Default)]
+pub struct VelocityTracker {
+    /// (time in seconds, x in pixels), oldest first
+    samples: VecDeque<(f32, f32)>,
+}
+
+impl VelocityTracker {
+    /// Forget every sample, for a new drag
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Record the pointer at `x` at `time` seconds
+    pub fn record(&mut self, time: f32, x: f32) {
+        self.samples.push_back((time, x));
+        while self
+            .samples
+            .front()
+            .is_some_and(|&(t, _)| time - t > SAMPLE_WINDOW_SECS)
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Pointer velocity in pixels per second at release time `time`
+    ///
+    /// Zero when the pointer rested before letting go.
+    pub fn release_velocity(&self, time: f32) -> f32 {
+        let (Some(&(t0, x0)), Some(&(t1, x1))) = (self.samples.front(), self.samples.back())
+        else {
+            return 0.0;
+        };
+        if time - t1 > SAMPLE_WINDOW_SECS || t1 <= t0 {
+            return 0.0;
+        }
+        ((x1 - x0) / (t1 - t0)).clamp(-MAX_RELEASE_SPEED, MAX_RELEASE_SPEED)
+    }
+}
+
+/// Where a scrub comes to rest: whole local minutes, hours or days
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Snap {
+    /// Snap interval in seconds
+    unit_secs: i64,
+    /// Zone whose wall clock the interval is counted in
+    tz: Tz,
+}
+
+impl Snap {
+    /// Minutes when zoomed in, hours when zoomed out, days at macro zoom levels
+    pub fn for_zoom(seconds_per_pixel: f32, tz: Tz) -> Self {
+        let unit_secs = if seconds_per_pixel >= MACRO_ZOOM {
+            86_400
+        } else if seconds_per_pixel >= HOUR_SNAP_ZOOM {
+            3600
+        } else {
+            60
+        };
+        Self { unit_secs, tz }
+    }
+
+    /// The nearest whole minute, hour or day on the zone's wall clock
+    pub fn apply(&self, instant: DateTime<Utc>) -> DateTime<Utc> {
+        // Round local time, so half-hour zones still land on their own hours
+        let offset = i64::from(instant.with_timezone(&self.tz).offset().fix().local_minus_utc());
+        let local_ms = instant.timestamp_millis() + offset * 1000;
+        let unit_ms = self.unit_secs * 1000;
+        let snapped = (local_ms + unit_ms / 2).div_euclid(unit_ms) * unit_ms;
+        DateTime::from_timestamp_millis(snapped - offset * 1000).unwrap_or(instant)
+    }
+}
+
+/// What the ribbon does after a drag is released
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum Glide {
+    /// At rest
+    #[default]
+    Idle,
+    /// Still moving, at this pointer velocity in pixels per second
+    Coasting { velocity: f32 },
+    /// Easing onto a snap target
+    Settling { target: DateTime<Utc> },
+}
+
+/// Momentum left over from a released drag
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Momentum {
+    glide: Glide,
+    /// Where to come to rest once the glide ends, if snapping
+    snap: Option<Snap>,
+}
+
+impl Momentum {
+    /// Start gliding from `ghost` at the pointer's release velocity
+    pub fn release(&mut self, ghost: DateTime<Utc>, velocity: f32, snap: Option<Snap>) {
+        self.snap = snap;
+        self.glide = if velocity.abs() >= STOP_SPEED {
+            Glide::Coasting { velocity }
+        } else {
+            self.settle(ghost)
+        };
+    }
+
+    /// Halt immediately, wherever the ribbon is
+    pub fn stop(&mut self) {
+        self.glide = Glide::Idle;
+    }
+
+    /// Whether the ribbon is still gliding or settling
+    pub fn is_active(&self) -> bool {
+        self.glide != Glide::Idle
+    }
+
+    /// Skip the rest of the motion; returns the snap target, if any
+    pub fn finish(&mut self, ghost: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let target = match self.glide {
+            Glide::Idle => None,
+            Glide::Settling { target } => Some(target),
+            Glide::Coasting { .. } => self.snap.map(|snap| snap.apply(ghost)),
+        };
+        self.glide = Glide::Idle;
+        target
+    }
+
+    /// Advance `dt` seconds; returns where the ghost moves, if it moves
+    pub fn step(
+        &mut self,
+        ghost: DateTime<Utc>,
+        dt: f32,
+        seconds_per_pixel: f32,
+    ) -> Option<DateTime<Utc>> {
+        match self.glide {
+            Glide::Idle => None,
+            Glide::Coasting { velocity } => {
+                // Moving the pointer right shows earlier time
+                let moved = ghost + seconds(-velocity * dt * seconds_per_pixel);
+                let velocity = velocity * FRICTION_PER_SEC.powf(dt);
+                self.glide = if velocity.abs() < STOP_SPEED {
+                    self.settle(moved)
+                } else {
+                    Glide::Coasting { velocity }
+                };
+                Some(moved)
+            }
+            Glide::Settling { target } => {
+                let remaining = (target - ghost).num_milliseconds() as f32 / 1000.0;
+                // Within half a pixel counts as there
+                if remaining.abs() <= seconds_per_pixel * 0.5 {
+                    self.glide = Glide::Idle;
+                    return Some(target);
+                }
+                Some(ghost + seconds(remaining * (1.0 - SETTLE_PER_SEC.powf(dt))))
+            }
+        }
+    }
+
+    /// Ease onto the snap target, or come to rest
+    fn settle(&self, ghost: DateTime<Utc>) -> Glide {
+        match self.snap {
+            Some(snap) => Glide::Settling {
+                target: snap.apply(ghost),
+            },
+            None => Glide::Idle,
+        }
+    }
+}
+
+/// A fractional number of seconds as a `Duration`
+fn seconds(secs: f32) -> Duration {
+    Duration::milliseconds((secs * 1000.0) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn utc() -> Tz {
+        "UTC".parse().unwrap()
+    }
+
+    #[test]
+    fn test_release_velocity() {
+        let mut tracker = VelocityTracker::default();
+        for i in 0..10 {
+            tracker.record(i as f32 * 0.02, i as f32 * 10.0);
+        }
+        // Only the last 0.1 s counts: 50 px in 0.1 s
+        assert!((tracker.release_velocity(0.19) - 500.0).abs() < 1.0);
+        // Resting before release throws the flick away
+        assert_eq!(tracker.release_velocity(0.5), 0.0);
+    }
+
+    #[test]
+    fn test_snap_by_zoom_and_zone() {
+        let at = Utc.with_ymd_and_hms(2025, 6, 1, 10, 31, 40).unwrap();
+        assert_eq!(
+            Snap::for_zoom(30.0, utc()).apply(at),
+            Utc.with_ymd_and_hms(2025, 6, 1, 10, 32, 0).unwrap()
+        );
+        assert_eq!(
+            Snap::for_zoom(60.0, utc()).apply(at),
+            Utc.with_ymd_and_hms(2025, 6, 1, 11, 0, 0).unwrap()
+        );
+        // Kolkata is UTC+5:30, so its hours fall on UTC half-hours
+        let kolkata: Tz = "Asia/Kolkata".parse().unwrap();
+        assert_eq!(
+            Snap::for_zoom(120.0, kolkata).apply(at),
+            Utc.with_ymd_and_hms(2025, 6, 1, 10, 30, 0).unwrap()
+        );
+        // Macro zoom levels land on the zone's midnight
+        assert_eq!(
+            Snap::for_zoom(1800.0, kolkata).apply(at),
+            Utc.with_ymd_and_hms(2025, 6, 1, 18, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_glide_slows_then_snaps() {
+        let start = Utc.with_ymd_and_hms(2025, 6, 1, 10, 0, 0).unwrap();
+        let mut momentum = Momentum::default();
+        momentum.release(start, -1000.0, Some(Snap::for_zoom(10.0, utc())));
+
+        let mut ghost = start;
+        let mut frames = 0;
+        while let Some(next) = momentum.step(ghost, 1.0 / 60.0, 10.0) {
+            ghost = next;
+            frames += 1;
+            assert!(frames < 1000, "glide never ended");
+        }
+        // Dragging left moves later in time, and it rests on a whole minute
+        assert!(ghost > start);
+        assert_eq!(ghost.timestamp() % 60, 0);
+        assert!(!momentum.is_active());
+    }
+
+    #[test]
+    fn test_finish_skips_the_glide() {
+        let start = Utc.with_ymd_and_hms(2025, 6, 1, 10, 0, 20).unwrap();
+        let mut momentum = Momentum::default();
+        momentum.release(start, 2000.0, None);
+        assert_eq!(momentum.finish(start), None);
+        assert!(!momentum.is_active());
+
+        momentum.release(start, 2000.0, Some(Snap::for_zoom(5.0, utc())));
+        assert_eq!(
+            momentum.finish(start),
+            Some(Utc.with_ymd_and_hms(2025, 6, 1, 10, 0, 0).unwrap())
+        );
+    }
+}
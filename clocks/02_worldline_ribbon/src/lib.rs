@@ -0,0 +1,1451 @@
+//! Worldline Ribbon Clock
+//!
+//! A clock as a scrolling ribbon of time: the present is a cursor;
+//! the ribbon moves beneath it. Users can scrub time to explore DST and offsets,
+//! and stack extra timezone lanes on the same UTC axis to compare transitions.
+//! Historical DST rule changes are labeled where they took effect.
+//! Named bookmarks flag instants on the ribbon, kept per timezone, and a
+//! shift-dragged selection measures a range in elapsed and wall-clock time.
+//! A flicked drag keeps gliding with momentum and can settle on a whole
+//! minute or hour.
+
+mod bookmarks;
+mod drawing;
+mod inertia;
+mod offsets;
+mod ribbon;
+mod selection;
+mod ui;
+
+use std::path::Path;
+use std::time::Instant;
+
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use nannou::event::ModifiersState;
+use nannou::prelude::*;
+use nannou_egui::{self, egui, Egui};
+use serde::{Deserialize, Serialize};
+use shared::{
+    compute_time_data, dst_rule_epochs, query_dst_transitions, report_save, tr, tr_args,
+    ClipRecorder, ClipSettings, ClipStep, CommandPalette, CommandRegistry, ConfigRecovery,
+    ConfigSaver, DstTransition, Favorites, FollowSystemZone, FramePacer, KeyChord, Keymap,
+    LanguageSetting, LinkListener, LinkMessage, MotionPreference, NoFocusRegions, QuietHours,
+    RecoveryChoice, RuleEpoch, SafePaletteSetting, ScreenReader, SnapshotProgress, SnapshotStep,
+    SystemMotionWatcher, SystemZoneWatcher, ThemeLibrary, TickGranularity, Ticker, TimeData,
+    TimeReveal, Validity, VisualIntensity, WindowPlacement, WindowTitle, WindowTitleClock,
+};
+
+use crate::bookmarks::{read_csv, Bookmark, BookmarkBook};
+use crate::drawing::{
+    colors, draw_bookmarks, draw_error_banner, draw_help_text, draw_lane, draw_offset_tag,
+    draw_ribbon, draw_rule_epochs, draw_selection, draw_time_display, draw_zoom_indicator,
+    offset_tag_rect, RibbonLayout,
+};
+use crate::inertia::{Momentum, Snap, VelocityTracker};
+use crate::offsets::ZoneOffset;
+use crate::ribbon::{
+    dst_range_days, format_cursor_time, CalendarLabels, LaneManager, RibbonViewport, Tick,
+    DEFAULT_ZOOM_INDEX, MACRO_ZOOM, ZOOM_LEVELS,
+};
+use crate::selection::TimeRange;
+use crate::ui::{
+    draw_bookmarks_panel, draw_dst_status, draw_offset_tooltip, draw_scrub_controls,
+    draw_selection_panel, draw_toast, draw_timezone_bar, draw_timezone_picker, theme_visuals,
+    BookmarkPanelState, PickerState, SelectionResult,
+};
+
+const CLOCK_NAME: &str = "worldline_ribbon";
+const DEFAULT_TZ: &str = "America/Los_Angeles";
+const WINDOW_TITLE: &str = "Worldline Ribbon";
+
+/// Run the clock; returns only when the window is closed
+pub fn run() {
+    nannou::app(model).update(update).run();
+}
+
+/// Launcher entry for this clock
+pub struct WorldlineRibbon;
+
+impl shared::ClockApp for WorldlineRibbon {
+    fn id(&self) -> &'static str {
+        CLOCK_NAME
+    }
+
+    fn title(&self) -> &'static str {
+        "Worldline Ribbon"
+    }
+
+    fn tagline(&self) -> &'static str {
+        "A scrolling ribbon of time you can scrub through DST seams"
+    }
+
+    fn run(&self) {
+        run();
+    }
+}
+
+/// Application mode - Live or Scrub
+#[derive(Debug, Clone, PartialEq)]
+enum Mode {
+    /// Live mode - ribbon scrolls with current time
+    Live,
+    /// Scrub mode - user is exploring a different instant
+    Scrub { ghost_instant: DateTime<Utc> },
+}
+
+impl Mode {
+    fn is_scrub(&self) -> bool {
+        matches!(self, Mode::Scrub { .. })
+    }
+
+    #[allow(dead_code)]
+    fn ghost_instant(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Mode::Scrub { ghost_instant } => Some(*ghost_instant),
+            Mode::Live => None,
+        }
+    }
+}
+
+/// Persisted configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Config {
+    selected_tz_id: String,
+    favorites: Vec<String>,
+    /// Keep `favorites` instead of following the series-wide list
+    #[serde(default)]
+    own_favorites: bool,
+    reduced_motion: MotionPreference,
+    zoom_index: usize,
+    /// Extra lanes stacked under the primary ribbon
+    #[serde(default)]
+    lane_zone_ids: Vec<String>,
+    /// Selected theme name; `None` uses the clock's own colors
+    #[serde(default)]
+    theme: Option<String>,
+    /// Bookmarks for each timezone, keyed by zone id
+    #[serde(default)]
+    bookmarks: BookmarkBook,
+    /// Settle scrubs on the nearest minute or hour
+    #[serde(default)]
+    snap_scrub: bool,
+    /// ISO week numbers under date ticks
+    #[serde(default)]
+    week_numbers: bool,
+    /// Day of the year under date ticks
+    #[serde(default)]
+    day_of_year: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            selected_tz_id: DEFAULT_TZ.to_string(),
+            favorites: vec![
+                "America/New_York".to_string(),
+                "America/Los_Angeles".to_string(),
+                "Europe/London".to_string(),
+                "Asia/Tokyo".to_string(),
+            ],
+            own_favorites: false,
+            reduced_motion: MotionPreference::Auto,
+            zoom_index: DEFAULT_ZOOM_INDEX,
+            lane_zone_ids: Vec::new(),
+            theme: None,
+            bookmarks: BookmarkBook::default(),
+            snap_scrub: false,
+            week_numbers: false,
+            day_of_year: false,
+        }
+    }
+}
+
+impl shared::VersionedConfig for Config {
+    const VERSION: u32 = 2;
+
+    /// Version 2 names the reduced-motion choice; earlier files hold a bool
+    fn migrate(_from_version: u32, payload: &mut toml::Table) {
+        shared::migrate_reduced_motion(payload);
+    }
+}
+
+/// Drag state for scrubbing
+#[derive(Debug, Clone, Default)]
+struct DragState {
+    is_dragging: bool,
+    start_x: f32,
+    start_instant: DateTime<Utc>,
+}
+
+/// Scroll axis lock state - prevents accidental axis switching mid-gesture
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum ScrollLock {
+    #[default]
+    None,
+    Horizontal,
+    Vertical,
+}
+
+/// Scroll state for trackpad gestures
+#[derive(Debug, Clone, Default)]
+struct ScrollState {
+    /// Which axis is currently locked
+    lock: ScrollLock,
+    /// Accumulated vertical scroll (for zoom)
+    vertical_accumulator: f32,
+    /// Accumulated horizontal scroll (for time scrub)
+    horizontal_accumulator: f32,
+}
+
+/// Application state
+struct Model {
+    /// Current mode (Live or Scrub)
+    mode: Mode,
+    /// Current time data
+    time_data: TimeData,
+    /// Selected timezone
+    selected_tz: Tz,
+    /// Favorite timezones
+    favorites: Favorites,
+    /// Timezone picker state
+    picker_state: PickerState,
+    /// Ctrl+K command palette
+    command_palette: CommandPalette,
+    /// Actions listed in the command palette, and the chords they're bound to
+    keymap: Keymap,
+    /// Reduced motion setting: follow the system, or override it
+    motion_preference: MotionPreference,
+    /// Reports the system's reduce-motion preference
+    system_motion: SystemMotionWatcher,
+    /// Whether motion is reduced, from the setting and the system
+    reduced_motion: bool,
+    /// Settle scrubs on the nearest minute or hour
+    snap_scrub: bool,
+    /// ISO week and day of year under date ticks
+    calendar_labels: CalendarLabels,
+    /// Shared quiet-hours schedule
+    quiet_hours: QuietHours,
+    /// Brightness and effects allowed right now by the schedule
+    intensity: VisualIntensity,
+    /// Shared setting for the time in the window title
+    window_title_clock: WindowTitleClock,
+    /// Shared settings for clips recorded with Ctrl+Shift+R
+    clip_settings: ClipSettings,
+    /// Clip being recorded or saved, if any
+    clip_recorder: ClipRecorder,
+    /// Shared language for labels, dates and toasts
+    language: LanguageSetting,
+    /// Shared high-contrast or color-blind safe palette
+    safe_palette: SafePaletteSetting,
+    /// Keeps the window title's time current
+    window_title: WindowTitle,
+    /// Remembers the window's size, position and monitor
+    window_placement: WindowPlacement,
+    /// Shared setting for switching to the system zone when it changes
+    follow_system_zone: FollowSystemZone,
+    /// Reports changes to the computer's time zone
+    system_zone: SystemZoneWatcher,
+    /// Shared system-wide shortcut that shows the time from the background
+    time_reveal: TimeReveal,
+    /// Drops the frame rate while the clock sits idle
+    frame_pacer: FramePacer,
+    /// Writes config changes once they settle
+    config_saver: ConfigSaver,
+    /// Shown when the saved config couldn't be read
+    config_recovery: Option<ConfigRecovery>,
+    /// Instants sent from other clocks, such as the audit ledger
+    link: LinkListener,
+    /// Current zoom level index
+    zoom_index: usize,
+    /// Built-in and user themes
+    themes: ThemeLibrary,
+    /// Active theme name; `None` uses the clock's own colors
+    theme: Option<String>,
+    /// Cached DST transitions
+    dst_transitions: Vec<DstTransition>,
+    /// Historical DST rule changes over the same range
+    rule_epochs: Vec<RuleEpoch>,
+    /// Extra timezone lanes stacked under the primary ribbon
+    lanes: LaneManager,
+    /// Bookmarks for every timezone
+    bookmarks: BookmarkBook,
+    /// Bookmarks sidebar text fields
+    bookmark_panel: BookmarkPanelState,
+    /// Whether an egui text field has keyboard focus (suppresses shortcuts)
+    text_input_focused: bool,
+    /// Last center instant used for DST query (to avoid re-querying every frame)
+    last_dst_query_instant: Option<DateTime<Utc>>,
+    /// Days either side of that center the cached transitions cover
+    dst_range_days: i64,
+    /// Drag state for mouse scrubbing
+    drag_state: DragState,
+    /// Pointer samples for the release velocity of a drag
+    velocity: VelocityTracker,
+    /// Glide left over from a released drag
+    momentum: Momentum,
+    /// Scroll state for trackpad gestures (axis locking)
+    scroll_state: ScrollState,
+    /// Measured range of the ribbon (shift-drag)
+    selection: Option<TimeRange>,
+    /// Instant where the current shift-drag started
+    selection_anchor: Option<DateTime<Utc>>,
+    /// Error message to display (if any)
+    error_message: Option<String>,
+    /// Toast message with display start time (auto-dismisses after timeout)
+    toast: Option<(String, std::time::Instant)>,
+    /// Last valid timezone (for reverting on invalid selection)
+    last_valid_tz: Tz,
+    /// Whether a DST transition is currently visible in the viewport
+    transition_visible: bool,
+    /// Wall-clock ticker driving time recomputation in live mode
+    ticker: Ticker,
+    /// Frame counter for `--snapshot` runs
+    snapshot: SnapshotProgress,
+    /// Screen reader view of the time, DST status and focus
+    screen_reader: ScreenReader,
+
+    /// egui integration
+    egui: Egui,
+}
+
+impl Model {
+    fn seconds_per_pixel(&self) -> f32 {
+        ZOOM_LEVELS[self.zoom_index]
+    }
+
+    /// Whether the warp and glides are off, by preference or quiet hours
+    fn motion_reduced(&self) -> bool {
+        self.reduced_motion || !self.intensity.animations
+    }
+
+    /// Whether something on screen is moving on its own: a glide, a toast or
+    /// a clip being captured
+    fn is_animating(&self) -> bool {
+        self.momentum.is_active()
+            || self.toast.is_some()
+            || self.clip_recorder.is_recording()
+            || self.time_reveal.is_flashing()
+    }
+
+    /// Apply a reduced-motion setting against the current system preference
+    fn set_motion_preference(&mut self, preference: MotionPreference) {
+        self.motion_preference = preference;
+        self.reduced_motion = preference.resolve(self.system_motion.reduced());
+    }
+
+    fn center_instant(&self) -> DateTime<Utc> {
+        match &self.mode {
+            Mode::Live => shared::now_utc(),
+            Mode::Scrub { ghost_instant } => *ghost_instant,
+        }
+    }
+
+    /// The instant under screen position `x` on the ribbon
+    fn instant_at_x(&self, x: f32) -> DateTime<Utc> {
+        self.center_instant() + Duration::seconds((x * self.seconds_per_pixel()) as i64)
+    }
+
+    /// UTC offset of the ribbon and then each lane, at the cursor
+    fn band_offsets(&self) -> Vec<ZoneOffset> {
+        let instant = self.center_instant();
+        std::iter::once(self.selected_tz)
+            .chain(self.lanes.zones())
+            .map(|zone| ZoneOffset::at(zone, instant))
+            .collect()
+    }
+
+    /// The offset whose ruler tag is under `pos`
+    fn offset_tag_at(&self, window_rect: Rect, pos: Point2) -> Option<ZoneOffset> {
+        let layout = RibbonLayout::calculate(window_rect, self.lanes.len());
+        let lanes = (0..self.lanes.len()).map(|index| layout.lane(index));
+        let tags = std::iter::once(offset_tag_rect(window_rect.w(), &layout))
+            .chain(lanes.map(|lane| offset_tag_rect(window_rect.w(), &lane)));
+        self.band_offsets()
+            .into_iter()
+            .zip(tags)
+            .find_map(|(offset, tag)| tag.contains(pos).then_some(offset))
+    }
+
+    fn enter_scrub(&mut self, instant: DateTime<Utc>) {
+        self.momentum.stop();
+        self.mode = Mode::Scrub {
+            ghost_instant: instant,
+        };
+    }
+
+    fn return_to_live(&mut self) {
+        self.momentum.stop();
+        self.mode = Mode::Live;
+        self.time_data = compute_time_data(self.selected_tz);
+    }
+
+    fn adjust_ghost(&mut self, delta_seconds: i64) {
+        self.momentum.stop();
+        match &mut self.mode {
+            Mode::Live => {
+                // Enter scrub mode with current time adjusted
+                let ghost = shared::now_utc() + Duration::seconds(delta_seconds);
+                self.mode = Mode::Scrub {
+                    ghost_instant: ghost,
+                };
+            }
+            Mode::Scrub { ghost_instant } => {
+                *ghost_instant += Duration::seconds(delta_seconds);
+            }
+        }
+    }
+
+    fn zoom_in(&mut self) {
+        if self.zoom_index > 0 {
+            self.zoom_index -= 1;
+        }
+    }
+
+    fn zoom_out(&mut self) {
+        if self.zoom_index < ZOOM_LEVELS.len() - 1 {
+            self.zoom_index += 1;
+        }
+    }
+}
+
+fn save_config(model: &Model) {
+    let config = Config {
+        selected_tz_id: model.selected_tz.name().to_string(),
+        favorites: model.favorites.own_ids(),
+        own_favorites: model.favorites.uses_own(),
+        reduced_motion: model.motion_preference,
+        zoom_index: model.zoom_index,
+        lane_zone_ids: model
+            .lanes
+            .zones()
+            .iter()
+            .map(|tz| tz.name().to_string())
+            .collect(),
+        theme: model.theme.clone(),
+        bookmarks: model.bookmarks.clone(),
+        snap_scrub: model.snap_scrub,
+        week_numbers: model.calendar_labels.week_numbers,
+        day_of_year: model.calendar_labels.day_of_year,
+    };
+    model.config_saver.request(&config);
+}
+
+fn model(app: &App) -> Model {
+    // Create window with minimum size to prevent layout issues
+    let window_id = app
+        .new_window()
+        .title(WINDOW_TITLE)
+        .size(1100, 600)
+        .min_size(800, 500)
+        .view(view)
+        .key_pressed(key_pressed)
+        .mouse_pressed(mouse_pressed)
+        .mouse_released(mouse_released)
+        .mouse_moved(mouse_moved)
+        .mouse_wheel(mouse_wheel)
+        .raw_event(raw_window_event)
+        .visible(false)
+        .build()
+        .unwrap();
+
+    let window = app.window(window_id).unwrap();
+    let egui = Egui::from_window(&window);
+    shared::install_fallback_fonts(egui.ctx());
+
+    // Load configuration, noting an unreadable file for the recovery prompt
+    let (config, config_recovery) = shared::load_config_or_recover(CLOCK_NAME);
+    let config: Config = config.unwrap_or_default();
+
+    // Parse timezone from config
+    let selected_tz: Tz = config
+        .selected_tz_id
+        .parse()
+        .unwrap_or_else(|_| DEFAULT_TZ.parse().unwrap());
+
+    // The shared favorites, or this clock's own
+    let favorites = Favorites::load(&config.favorites, config.own_favorites);
+
+    // Validate zoom index
+    let zoom_index = config.zoom_index.min(ZOOM_LEVELS.len() - 1);
+
+    // Compute initial time data
+    let themes = ThemeLibrary::load();
+    let theme = themes.activate(config.theme.as_deref());
+    let system_motion = SystemMotionWatcher::spawn();
+
+    let time_data = compute_time_data(selected_tz);
+
+    // DST transitions are queried on the first update, once the zoom is known
+    let now = shared::now_utc();
+
+    // Restore extra lanes
+    let lane_zones: Vec<Tz> = config
+        .lane_zone_ids
+        .iter()
+        .filter_map(|s| s.parse().ok())
+        .collect();
+    let lanes = LaneManager::new(&lane_zones, selected_tz, now);
+
+    // Screen reader tree, attached before the window is first shown
+    let screen_reader = ScreenReader::attach::<NoFocusRegions>(
+        window.winit_window(),
+        "Worldline Ribbon",
+        &time_data,
+        None,
+    );
+
+    // Reopen where the window was left, or where the command line asks
+    let mut window_placement = WindowPlacement::load(CLOCK_NAME);
+    window_placement.restore(window.winit_window());
+    window.set_visible(shared::snapshot_request().is_none());
+
+    let wake_proxy = app.create_proxy();
+    Model {
+        mode: Mode::Live,
+        time_data,
+        selected_tz,
+        favorites,
+        picker_state: PickerState::default(),
+        command_palette: CommandPalette::default(),
+        keymap: Keymap::load(CLOCK_NAME, commands()),
+        motion_preference: config.reduced_motion,
+        reduced_motion: config.reduced_motion.resolve(system_motion.reduced()),
+        system_motion,
+        snap_scrub: config.snap_scrub,
+        calendar_labels: CalendarLabels {
+            week_numbers: config.week_numbers,
+            day_of_year: config.day_of_year,
+        },
+        quiet_hours: QuietHours::load(),
+        intensity: VisualIntensity::FULL,
+        window_title_clock: WindowTitleClock::load(),
+        clip_settings: ClipSettings::load(),
+        clip_recorder: ClipRecorder::new(CLOCK_NAME),
+        language: LanguageSetting::load(),
+        safe_palette: SafePaletteSetting::load(),
+        window_title: WindowTitle::new(WINDOW_TITLE),
+        window_placement,
+        follow_system_zone: FollowSystemZone::load(),
+        system_zone: SystemZoneWatcher::spawn(),
+        time_reveal: TimeReveal::load(),
+        frame_pacer: FramePacer::spawn(move || wake_proxy.wakeup().is_ok()),
+        config_saver: ConfigSaver::new(CLOCK_NAME),
+        config_recovery,
+        link: LinkListener::listen(CLOCK_NAME),
+        zoom_index,
+        themes,
+        theme,
+        dst_transitions: Vec::new(),
+        rule_epochs: Vec::new(),
+        lanes,
+        bookmarks: config.bookmarks,
+        bookmark_panel: BookmarkPanelState::default(),
+        text_input_focused: false,
+        last_dst_query_instant: None,
+        dst_range_days: 0,
+        drag_state: DragState::default(),
+        velocity: VelocityTracker::default(),
+        momentum: Momentum::default(),
+        scroll_state: ScrollState::default(),
+        selection: None,
+        selection_anchor: None,
+        error_message: None,
+        toast: None,
+        last_valid_tz: selected_tz,
+        transition_visible: false,
+        ticker: Ticker::spawn(TickGranularity::Second),
+        snapshot: SnapshotProgress::default(),
+        screen_reader,
+        egui,
+    }
+}
+
+/// Drive a `--snapshot` run: capture once the frame has settled, then quit
+fn advance_snapshot(app: &App, progress: &mut SnapshotProgress) {
+    let Some(request) = shared::snapshot_request() else {
+        return;
+    };
+    match progress.advance() {
+        SnapshotStep::Settle => {}
+        SnapshotStep::Capture => app.main_window().capture_frame(&request.path),
+        SnapshotStep::Finish => {
+            if app.main_window().await_capture_frame_jobs().is_err() {
+                eprintln!("Timed out writing snapshot to {}", request.path.display());
+                std::process::exit(1);
+            }
+            println!("Saved snapshot to {}", request.path.display());
+            app.quit();
+        }
+    }
+}
+
+/// Capture frames for a clip being recorded, and report the clip once saved
+fn advance_clip(app: &App, model: &mut Model) {
+    match model.clip_recorder.advance(Instant::now()) {
+        ClipStep::Idle => {}
+        ClipStep::Capture(path) => app.main_window().capture_frame(path),
+        ClipStep::Finish => {
+            let written = app.main_window().await_capture_frame_jobs().is_ok();
+            model.clip_recorder.encode(written);
+        }
+    }
+    if let Some(message) = model.clip_recorder.poll() {
+        model.toast = Some((message, Instant::now()));
+    }
+}
+
+/// Let the event loop wait between second boundaries while the clock is idle
+fn pace_frames(app: &App, model: &mut Model, now: Instant) {
+    let animating = model.is_animating();
+    let loop_mode = if model.frame_pacer.pace(animating, now) {
+        LoopMode::Wait
+    } else {
+        LoopMode::RefreshSync
+    };
+    app.set_loop_mode(loop_mode);
+}
+
+/// Act on the config recovery prompt: restart on the backup, or carry on
+/// with the defaults
+fn recover_config(app: &App, model: &mut Model, choice: RecoveryChoice) {
+    let Some(recovery) = model.config_recovery.take() else {
+        return;
+    };
+    if choice == RecoveryChoice::Restore {
+        match recovery.restore(&model.config_saver) {
+            Ok(()) => app.quit(),
+            Err(message) => model.toast = Some((message, Instant::now())),
+        }
+    }
+}
+
+/// Make `tz` the primary zone
+fn select_zone(model: &mut Model, tz: Tz) {
+    model.selected_tz = tz;
+    model.last_valid_tz = tz; // Track last valid selection
+    model.time_data = compute_time_data(tz);
+    model.lanes.remove_lane(tz); // The primary ribbon already shows this zone
+    model.error_message = None; // Clear any error on successful selection
+    // Invalidate DST cache
+    model.last_dst_query_instant = None;
+    save_config(model);
+}
+
+fn update(app: &App, model: &mut Model, update: Update) {
+    advance_snapshot(app, &mut model.snapshot);
+    advance_clip(app, model);
+
+    // Carry on any glide left from a released drag
+    if model.momentum.is_active() {
+        if let Mode::Scrub { ghost_instant } = model.mode {
+            let moved = if model.motion_reduced() {
+                model.momentum.finish(ghost_instant)
+            } else {
+                let dt = update.since_last.as_secs_f32();
+                model.momentum.step(ghost_instant, dt, model.seconds_per_pixel())
+            };
+            if let Some(ghost_instant) = moved {
+                model.mode = Mode::Scrub { ghost_instant };
+            }
+        }
+    }
+
+    let center = model.center_instant();
+
+    // Update time data when live time ticks over, or when scrubbing moved the
+    // ghost to a different second; the ribbon itself still scrolls every frame
+    let tick = model.ticker.poll();
+    let ghost_moved = model.mode.is_scrub()
+        && center.timestamp() != model.time_data.local_datetime.timestamp();
+    if tick.is_some() || ghost_moved {
+        model.time_data = shared::compute_time_data_at(model.selected_tz, center);
+    }
+    // Quiet hours follow the real time, not the scrubbed ghost
+    model.intensity = model.quiet_hours.intensity_at(model.selected_tz, shared::now_utc());
+
+    // Check for validity issues
+    if model.time_data.validity != Validity::Ok {
+        model.error_message = Some(match model.time_data.validity {
+            Validity::TzMissing => tr("Time zone data missing. Showing UTC.").to_string(),
+            Validity::TzDataStale => tr("Time zone data may be outdated.").to_string(),
+            Validity::Unknown => tr("Unknown time zone issue.").to_string(),
+            Validity::Ok => unreachable!(),
+        });
+    }
+
+    // Re-query DST transitions if the zoom changed the range to cover, or the
+    // center has moved significantly (more than 1 hour, or a quarter of the
+    // range at macro zoom levels)
+    let range_days = dst_range_days(model.seconds_per_pixel(), app.window_rect().w());
+    let requery_hours = if model.seconds_per_pixel() >= MACRO_ZOOM { range_days * 6 } else { 1 };
+    let should_requery = match model.last_dst_query_instant {
+        Some(last) => {
+            range_days != model.dst_range_days || (center - last).num_hours().abs() > requery_hours
+        }
+        None => true,
+    };
+
+    if should_requery {
+        model.dst_transitions = query_dst_transitions(model.selected_tz, center, range_days);
+        let range = Duration::days(range_days);
+        model.rule_epochs = dst_rule_epochs(model.selected_tz, center - range, center + range);
+        model.lanes.refresh_transitions(center, range_days);
+        model.last_dst_query_instant = Some(center);
+        model.dst_range_days = range_days;
+    }
+
+    // Check if any DST transition is visible in the current viewport
+    // Viewport span is approximately window_width * seconds_per_pixel
+    let viewport_half_span = Duration::hours(6); // Conservative estimate
+    let lane_transitions = model.lanes.lanes().iter().flat_map(|l| l.dst_transitions.iter());
+    model.transition_visible = model.dst_transitions.iter().chain(lane_transitions).any(|t| {
+        let delta = (t.instant_utc - center).num_seconds().abs();
+        delta < viewport_half_span.num_seconds()
+    });
+
+    // Auto-dismiss toast after 3 seconds
+    if let Some((_, start_time)) = &model.toast {
+        if start_time.elapsed().as_secs_f32() > 3.0 {
+            model.toast = None;
+        }
+    }
+
+    // Offset ruler tag under the pointer, for its tooltip
+    let hovered_offset = model.offset_tag_at(app.window_rect(), app.mouse.position());
+
+    // Begin egui frame
+    model.egui.set_elapsed_time(update.since_start);
+    let ctx = model.egui.begin_frame();
+
+    let mut visuals = egui::Visuals::dark();
+    theme_visuals(&mut visuals);
+    ctx.set_visuals(visuals);
+
+    // Collect UI state
+    let current_tz = model.selected_tz;
+    let favorites_clone = model.favorites.to_vec();
+    let lane_zones = model.lanes.zones();
+    let time_data_clone = model.time_data.clone();
+    let is_scrub = model.mode.is_scrub();
+    let mut motion_preference = model.motion_preference;
+    let system_reduced_motion = model.system_motion.reduced();
+    let mut snap_scrub = model.snap_scrub;
+    let mut calendar_labels = model.calendar_labels;
+    let mut quiet_hours = model.quiet_hours.clone();
+    let mut window_title_clock = model.window_title_clock;
+    let mut clip_settings = model.clip_settings;
+    let mut language = model.language;
+    let mut safe_palette = model.safe_palette;
+    let mut follow_system_zone = model.follow_system_zone;
+    let mut reveal_hotkey = model.time_reveal.setting.clone();
+    let mut own_favorites = model.favorites.uses_own();
+
+    // Draw timezone bar (top)
+    let bar_clicked = draw_timezone_bar(&ctx, &time_data_clone);
+    if bar_clicked {
+        model.picker_state.open();
+    }
+
+    // Draw timezone picker (if open)
+    let picker_result = draw_timezone_picker(
+        &ctx,
+        &mut model.picker_state,
+        current_tz,
+        &favorites_clone,
+        &lane_zones,
+    );
+
+    // Draw scrub controls
+    let scrub_result = draw_scrub_controls(
+        &ctx,
+        is_scrub,
+        model.zoom_index,
+        &mut motion_preference,
+        system_reduced_motion,
+        &mut snap_scrub,
+        &mut calendar_labels,
+        &mut quiet_hours,
+        &mut window_title_clock,
+        &mut clip_settings,
+        &mut language,
+        &mut safe_palette,
+        &mut follow_system_zone,
+        &mut reveal_hotkey,
+        model.time_reveal.status(),
+        &mut own_favorites,
+        &model.keymap,
+        &model.themes,
+        model.theme.as_deref(),
+    );
+
+    // Draw bookmarks sidebar
+    let bookmark_result = draw_bookmarks_panel(
+        &ctx,
+        &mut model.bookmark_panel,
+        current_tz,
+        model.bookmarks.for_zone(current_tz),
+    );
+
+    // Draw the measurement panel for a selected range
+    let selection_result = match &model.selection {
+        Some(range) => draw_selection_panel(&ctx, range, current_tz),
+        None => SelectionResult::default(),
+    };
+
+    // Show DST status card when a transition is visible in viewport
+    if model.transition_visible {
+        draw_dst_status(&ctx, &time_data_clone);
+    }
+
+    // Draw toast notification if active
+    if let Some((ref message, start_time)) = model.toast {
+        draw_toast(&ctx, message, start_time.elapsed().as_secs_f32());
+    }
+
+    // Explain a flagged offset while its ruler tag is hovered
+    if let Some(explanation) = hovered_offset.and_then(|offset| offset.explanation()) {
+        draw_offset_tooltip(&ctx, &explanation);
+    }
+
+    // Draw the time card while the reveal shortcut flashes the window
+    model.time_reveal.show(&ctx, &time_data_clone);
+
+    // Offer the backup if the saved config couldn't be read
+    let recovery_choice = model.config_recovery.as_ref().and_then(|r| r.show(&ctx));
+
+    // Draw command palette (if open)
+    let palette_choice = model.command_palette.show(&ctx, model.keymap.registry());
+
+    model.text_input_focused = ctx.wants_keyboard_input();
+
+    // Now apply UI results
+    drop(ctx);
+    if let Some(choice) = recovery_choice {
+        recover_config(app, model, choice);
+    }
+
+    // Handle picker result
+    if let Some(tz) = picker_result.selected_tz {
+        select_zone(model, tz);
+    }
+    if let Some(tz) = picker_result.toggle_favorite {
+        model.favorites.toggle(tz);
+        save_config(model);
+    }
+    if let Some(tz) = picker_result.add_lane {
+        let center = model.center_instant();
+        if model.lanes.add_lane(tz, model.selected_tz, center) {
+            save_config(model);
+        }
+    }
+    if let Some(tz) = picker_result.remove_lane {
+        if model.lanes.remove_lane(tz) {
+            save_config(model);
+        }
+    }
+    if picker_result.close_picker {
+        model.picker_state.close();
+    }
+
+    // Handle scrub control results
+    if scrub_result.return_to_now {
+        model.return_to_live();
+    }
+    if scrub_result.zoom_in {
+        model.zoom_in();
+        save_config(model);
+    }
+    if scrub_result.zoom_out {
+        model.zoom_out();
+        save_config(model);
+    }
+    if let Some(delta) = scrub_result.step_time {
+        model.adjust_ghost(delta);
+    }
+    if scrub_result.reduced_motion_changed {
+        model.set_motion_preference(motion_preference);
+        save_config(model);
+    }
+    if scrub_result.snap_scrub_changed {
+        model.snap_scrub = snap_scrub;
+        save_config(model);
+    }
+    if scrub_result.calendar_labels_changed {
+        model.calendar_labels = calendar_labels;
+        save_config(model);
+    }
+    if scrub_result.quiet_hours_changed {
+        model.quiet_hours = quiet_hours;
+        report_save(
+            "quiet hours",
+            model.quiet_hours.save(),
+            |message| model.toast = Some((message, Instant::now())),
+        );
+    }
+    if scrub_result.window_title_changed {
+        model.window_title_clock = window_title_clock;
+        report_save(
+            "window title setting",
+            model.window_title_clock.save(),
+            |message| model.toast = Some((message, Instant::now())),
+        );
+    }
+    if scrub_result.clip_settings_changed {
+        model.clip_settings = clip_settings;
+        report_save(
+            "recording settings",
+            model.clip_settings.save(),
+            |message| model.toast = Some((message, Instant::now())),
+        );
+    }
+    if scrub_result.language_changed {
+        model.language = language;
+        report_save(
+            "language setting",
+            model.language.save(),
+            |message| model.toast = Some((message, Instant::now())),
+        );
+    }
+    if scrub_result.safe_palette_changed {
+        model.safe_palette = safe_palette;
+        report_save(
+            "palette setting",
+            model.safe_palette.save(),
+            |message| model.toast = Some((message, Instant::now())),
+        );
+    }
+    if scrub_result.system_zone_changed {
+        model.follow_system_zone = follow_system_zone;
+        report_save(
+            "system zone setting",
+            model.follow_system_zone.save(),
+            |message| model.toast = Some((message, Instant::now())),
+        );
+    }
+    if scrub_result.reveal_hotkey_changed {
+        model.time_reveal.setting = reveal_hotkey;
+        model.time_reveal.apply();
+    }
+    if scrub_result.favorites_source_changed {
+        model.favorites.set_uses_own(own_favorites);
+        save_config(model);
+    }
+    if let Some(edit) = scrub_result.keymap_edit {
+        model.keymap.apply(edit);
+    }
+    if let Some(theme) = scrub_result.set_theme {
+        model.theme = model.themes.activate(theme.as_deref());
+        save_config(model);
+    }
+
+    // Handle selection results
+    if selection_result.copied {
+        model.toast = Some((tr("Selection copied").to_string(), Instant::now()));
+    }
+    if selection_result.clear {
+        model.selection = None;
+    }
+
+    // Handle bookmark results
+    if let Some(instant) = bookmark_result.jump_to {
+        model.enter_scrub(instant);
+    }
+    if let Some(label) = bookmark_result.add_label {
+        let bookmark = Bookmark::new(&label, model.center_instant());
+        if model.bookmarks.add(model.selected_tz, bookmark) {
+            save_config(model);
+        }
+    }
+    if let Some(index) = bookmark_result.remove {
+        if model.bookmarks.remove(model.selected_tz, index).is_some() {
+            save_config(model);
+        }
+    }
+    if let Some(path) = bookmark_result.import_path {
+        import_bookmarks(model, Path::new(&path));
+    }
+
+    // Run the command chosen in the palette as if its keys were pressed
+    if let Some(chord) = palette_choice {
+        handle_key(model, chord.key, chord.mods);
+    }
+
+    // Keep the screen reader's time and DST status current
+    model.screen_reader.update::<NoFocusRegions>(&model.time_data, None);
+
+    // Pick up favorites starred in another clock
+    model.favorites.poll();
+
+    // Follow the computer's time zone when it changes
+    if let Some(change) = model.system_zone.poll() {
+        let switch = model.follow_system_zone.enabled && change.current != model.selected_tz;
+        if switch {
+            select_zone(model, change.current);
+        }
+        model.toast = Some((change.message(switch), Instant::now()));
+    }
+
+    // Follow the system reduce-motion setting when set to Auto
+    if model.system_motion.poll() {
+        model.set_motion_preference(model.motion_preference);
+    }
+
+    // Scrub to an instant another clock pointed at
+    if let Some(LinkMessage::Inspect(instant)) = model.link.poll() {
+        model.enter_scrub(instant);
+        let linked = format_cursor_time(instant, model.selected_tz);
+        let message = tr_args("Linked to {0}", &[&linked]);
+        model.toast = Some((message, Instant::now()));
+    }
+
+    // Show the current time in the taskbar/dock title, and any clip recording
+    let badge = model.clip_recorder.indicator(Instant::now());
+    model.window_title.set_badge(badge);
+    model.window_title.update(
+        app.main_window().winit_window(),
+        &model.window_title_clock,
+        model.selected_tz,
+        shared::now_utc(),
+    );
+
+    // Show or end the reveal shortcut's flash
+    model.time_reveal.update(app.main_window().winit_window(), Instant::now());
+
+    // Remember where the window was moved or resized to
+    model.window_placement.track(app.main_window().winit_window(), Instant::now());
+
+    // Write config changes once they have settled
+    model.config_saver.poll(Instant::now());
+
+    // Idle between second boundaries once nothing is moving
+    pace_frames(app, model, Instant::now());
+}
+
+/// Import bookmarks from a CSV file into the displayed zone
+fn import_bookmarks(model: &mut Model, path: &Path) {
+    let status = match read_csv(path, model.selected_tz) {
+        Ok(bookmarks) => {
+            let total = bookmarks.len();
+            let added = model.bookmarks.import(model.selected_tz, bookmarks);
+            if added > 0 {
+                save_config(model);
+            }
+            Ok(tr_args("Imported {0} of {1} bookmarks", &[&added, &total]))
+        }
+        Err(e) => Err(e),
+    };
+    model.bookmark_panel.import_status = Some(status);
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    let window_rect = app.window_rect();
+
+    // Clear background
+    draw.background().color(colors::background());
+
+    // Calculate layout
+    let layout = RibbonLayout::calculate(window_rect, model.lanes.len());
+    let reduced_motion = model.motion_reduced();
+
+    // Create viewport
+    let viewport = RibbonViewport::new(
+        model.center_instant(),
+        model.seconds_per_pixel(),
+        window_rect.w(),
+        model.selected_tz,
+    );
+
+    // Generate ticks, with week dates under the dates if enabled
+    let mut ticks: Vec<Tick> = viewport.generate_ticks();
+    viewport.annotate_week_dates(&mut ticks, model.calendar_labels);
+
+    // Day and night in the selected zone, at its reference city
+    let daylight = viewport.daylight_bands(shared::reference_coordinates(model.selected_tz));
+
+    // Draw the ribbon
+    draw_ribbon(
+        &draw,
+        &viewport,
+        &ticks,
+        &model.dst_transitions,
+        &daylight,
+        &layout,
+        model.mode.is_scrub(),
+        reduced_motion,
+    );
+
+    // Label rule changes (DST adopted, dropped, moved) above their seams
+    draw_rule_epochs(&draw, &viewport, &model.rule_epochs, &layout);
+
+    // Flag bookmarks for the displayed zone
+    draw_bookmarks(
+        &draw,
+        &viewport,
+        model.bookmarks.for_zone(model.selected_tz),
+        &layout,
+    );
+
+    // Draw extra lanes on the same UTC axis, each with its own local ticks
+    for (index, (lane, lane_viewport)) in model
+        .lanes
+        .lanes()
+        .iter()
+        .zip(model.lanes.viewports(&viewport))
+        .enumerate()
+    {
+        let lane_ticks = lane_viewport.generate_ticks();
+        draw_lane(
+            &draw,
+            &lane_viewport,
+            &lane_ticks,
+            &lane.dst_transitions,
+            &layout.lane(index),
+            model.mode.is_scrub(),
+            reduced_motion,
+        );
+    }
+
+    // Offset ruler: each band's UTC offset, odd ones flagged
+    let lanes = (0..model.lanes.len()).map(|index| layout.lane(index));
+    let tags = std::iter::once(offset_tag_rect(window_rect.w(), &layout))
+        .chain(lanes.map(|lane| offset_tag_rect(window_rect.w(), &lane)));
+    for (offset, tag) in model.band_offsets().iter().zip(tags) {
+        draw_offset_tag(&draw, offset, tag);
+    }
+
+    // Shade the measured range over the ribbon and lanes
+    if let Some(range) = &model.selection {
+        draw_selection(&draw, &viewport, range, &layout, model.lanes.len());
+    }
+
+    // Draw time display
+    let time_text = format_cursor_time(model.center_instant(), model.selected_tz);
+    let date_text = model.time_data.format_date();
+    draw_time_display(
+        &draw,
+        &time_text,
+        &date_text,
+        &layout,
+        model.mode.is_scrub(),
+    );
+
+    // Draw zoom indicator
+    draw_zoom_indicator(&draw, model.seconds_per_pixel(), window_rect);
+
+    // Draw help text
+    draw_help_text(&draw, window_rect, model.keymap.registry());
+
+    // Dim everything under the error banner during quiet hours
+    if model.intensity.is_quiet() {
+        draw.rect()
+            .xy(window_rect.xy())
+            .wh(window_rect.wh())
+            .color(srgba(0.0, 0.0, 0.0, model.intensity.dim_alpha()));
+    }
+
+    // Draw error banner if needed
+    if let Some(ref message) = model.error_message {
+        draw_error_banner(&draw, message, window_rect);
+    }
+
+    // Render to frame
+    draw.to_frame(app, &frame).unwrap();
+
+    // Render egui on top
+    model.egui.draw_to_frame(&frame).unwrap();
+}
+
+/// Every action in the command palette, with the keys that perform it
+fn commands() -> CommandRegistry {
+    CommandRegistry::new()
+        .register("Toggle live/scrub", KeyChord::new(Key::Space))
+        .register("Return to live", KeyChord::new(Key::Escape))
+        .register("Open timezone picker", KeyChord::new(Key::Slash))
+        .register("Step back one second", KeyChord::new(Key::Left))
+        .register("Step forward one second", KeyChord::new(Key::Right))
+        .register("Step back one minute", KeyChord::shift(Key::Left))
+        .register("Step forward one minute", KeyChord::shift(Key::Right))
+        .register("Step back one hour", KeyChord::ctrl(Key::Left))
+        .register("Step forward one hour", KeyChord::ctrl(Key::Right))
+        .register("Zoom in", KeyChord::ctrl(Key::Equals))
+        .register("Zoom out", KeyChord::ctrl(Key::Minus))
+        .register("Toggle reduced motion", KeyChord::new(Key::R))
+        .register("Record clip", KeyChord::ctrl_shift(Key::R))
+}
+
+fn key_pressed(app: &App, model: &mut Model, key: Key) {
+    // Check for modifier keys
+    let mods = app.keys.mods;
+
+    // While a shortcut is being rebound, the next chord becomes its binding
+    if model.keymap.capture(key, mods) {
+        return;
+    }
+
+    // Ctrl+K opens the command palette from anywhere; while open it takes
+    // every other key
+    if KeyChord::is_palette_toggle(key, mods) {
+        model.command_palette.toggle();
+        return;
+    }
+    if model.command_palette.is_open {
+        return;
+    }
+
+    // Don't treat typing a bookmark label or CSV path as shortcuts
+    if model.text_input_focused && !model.picker_state.is_open {
+        return;
+    }
+
+    // Remapped chords reach the handler as their action's default chord
+    if let Some(chord) = model.keymap.translate(key, mods) {
+        handle_key(model, chord.key, chord.mods);
+    }
+}
+
+fn handle_key(model: &mut Model, key: Key, mods: ModifiersState) {
+    match key {
+        // Ctrl+Shift+R - start or stop recording a clip
+        _ if shared::is_clip_toggle(key, mods) => {
+            let now = Instant::now();
+            let message = model.clip_recorder.toggle(model.clip_settings, now);
+            model.toast = Some((message, Instant::now()));
+        }
+
+        // Escape - close picker, clear selection or return to live
+        Key::Escape => {
+            if model.picker_state.is_open {
+                model.picker_state.close();
+            } else if model.selection.is_some() {
+                model.selection = None;
+            } else if model.mode.is_scrub() {
+                model.return_to_live();
+            }
+        }
+
+        // Space - toggle Live/Scrub
+        Key::Space if !model.picker_state.is_open => {
+            match &model.mode {
+                Mode::Live => {
+                    model.enter_scrub(shared::now_utc());
+                }
+                Mode::Scrub { .. } => {
+                    model.return_to_live();
+                }
+            }
+        }
+
+        // Slash - focus search
+        Key::Slash => {
+            if !model.picker_state.is_open {
+                model.picker_state.open();
+            } else {
+                model.picker_state.should_focus_search = true;
+            }
+        }
+
+        // Arrow keys - step time
+        Key::Left => {
+            if mods.ctrl() || mods.logo() {
+                model.adjust_ghost(-3600); // -1 hour
+            } else if mods.shift() {
+                model.adjust_ghost(-60); // -1 minute
+            } else {
+                model.adjust_ghost(-1); // -1 second
+            }
+        }
+        Key::Right => {
+            if mods.ctrl() || mods.logo() {
+                model.adjust_ghost(3600); // +1 hour
+            } else if mods.shift() {
+                model.adjust_ghost(60); // +1 minute
+            } else {
+                model.adjust_ghost(1); // +1 second
+            }
+        }
+
+        // Zoom controls
+        Key::Equals | Key::Plus if (mods.ctrl() || mods.logo()) => {
+            model.zoom_in();
+            save_config(model);
+        }
+        Key::Minus if (mods.ctrl() || mods.logo()) => {
+            model.zoom_out();
+            save_config(model);
+        }
+
+        // R - toggle reduced motion
+        Key::R => {
+            let preference = model.motion_preference.toggled(model.system_motion.reduced());
+            model.set_motion_preference(preference);
+            save_config(model);
+        }
+
+        _ => {}
+    }
+}
+
+fn mouse_pressed(app: &App, model: &mut Model, button: MouseButton) {
+    if button == MouseButton::Left && !model.picker_state.is_open {
+        let mouse_pos = app.mouse.position();
+        let window_rect = app.window_rect();
+        let layout = RibbonLayout::calculate(window_rect, model.lanes.len());
+
+        // Check if mouse is within ribbon area (including any stacked lanes)
+        let (ribbon_top, ribbon_bottom) = layout.stack_extent(model.lanes.len());
+
+        if mouse_pos.y >= ribbon_bottom && mouse_pos.y <= ribbon_top && app.keys.mods.shift() {
+            // Shift-drag selects a range instead of scrubbing
+            let anchor = model.instant_at_x(mouse_pos.x);
+            model.selection_anchor = Some(anchor);
+            model.selection = Some(TimeRange::new(anchor, anchor));
+        } else if mouse_pos.y >= ribbon_bottom && mouse_pos.y <= ribbon_top {
+            // Grabbing the ribbon catches any glide still under way
+            model.momentum.stop();
+            model.velocity.clear();
+            model.velocity.record(app.time, mouse_pos.x);
+            model.drag_state = DragState {
+                is_dragging: true,
+                start_x: mouse_pos.x,
+                start_instant: model.center_instant(),
+            };
+        }
+    }
+}
+
+fn mouse_released(app: &App, model: &mut Model, button: MouseButton) {
+    if button == MouseButton::Left {
+        // A flicked drag keeps going; the update loop carries the glide on
+        if model.drag_state.is_dragging {
+            if let Mode::Scrub { ghost_instant } = model.mode {
+                let velocity = model.velocity.release_velocity(app.time);
+                let snap = model
+                    .snap_scrub
+                    .then(|| Snap::for_zoom(model.seconds_per_pixel(), model.selected_tz));
+                model.momentum.release(ghost_instant, velocity, snap);
+            }
+        }
+        model.drag_state.is_dragging = false;
+        // A shift-click without dragging selects nothing
+        if model.selection_anchor.take().is_some()
+            && model.selection.is_some_and(|range| range.start == range.end)
+        {
+            model.selection = None;
+        }
+    }
+}
+
+fn mouse_moved(app: &App, model: &mut Model, pos: Point2) {
+    if let Some(anchor) = model.selection_anchor {
+        model.selection = Some(TimeRange::new(anchor, model.instant_at_x(pos.x)));
+    } else if model.drag_state.is_dragging {
+        model.velocity.record(app.time, pos.x);
+        let delta_x = pos.x - model.drag_state.start_x;
+        // Moving mouse right shows earlier time (ribbon scrolls left)
+        let delta_seconds = (-delta_x * model.seconds_per_pixel()) as i64;
+        let ghost_instant = model.drag_state.start_instant + Duration::seconds(delta_seconds);
+        model.mode = Mode::Scrub { ghost_instant };
+    }
+}
+
+fn mouse_wheel(_app: &App, model: &mut Model, delta: MouseScrollDelta, phase: TouchPhase) {
+    // Vertical scroll = zoom, Horizontal scroll = time scrub
+    // Uses axis locking to prevent accidental mode switching mid-gesture
+    const LOCK_THRESHOLD: f32 = 8.0; // Pixels needed to commit to an axis
+    const ZOOM_THRESHOLD: f32 = 30.0; // Accumulated pixels needed to trigger zoom
+
+    // Reset scroll state when gesture ends
+    if phase == TouchPhase::Ended || phase == TouchPhase::Cancelled {
+        model.scroll_state = ScrollState::default();
+        return;
+    }
+
+    match delta {
+        MouseScrollDelta::LineDelta(x, y) => {
+            // Discrete scroll (mouse wheel) - trigger immediately, no locking needed
+            // Vertical = zoom
+            if y > 0.0 {
+                model.zoom_in();
+                save_config(model);
+            } else if y < 0.0 {
+                model.zoom_out();
+                save_config(model);
+            }
+            // Horizontal = time scrub (if mouse has horizontal scroll)
+            if x != 0.0 {
+                let seconds = (x * -10.0) as i64;
+                model.adjust_ghost(seconds);
+            }
+        }
+        MouseScrollDelta::PixelDelta(pos) => {
+            let dx = pos.x as f32;
+            let dy = pos.y as f32;
+
+            // Accumulate in both directions
+            model.scroll_state.horizontal_accumulator += dx;
+            model.scroll_state.vertical_accumulator += dy;
+
+            // If not yet locked, check if we should lock to an axis
+            if model.scroll_state.lock == ScrollLock::None {
+                let abs_h = model.scroll_state.horizontal_accumulator.abs();
+                let abs_v = model.scroll_state.vertical_accumulator.abs();
+
+                if abs_h >= LOCK_THRESHOLD && abs_h > abs_v * 1.5 {
+                    // Lock to horizontal (time scrub)
+                    model.scroll_state.lock = ScrollLock::Horizontal;
+                } else if abs_v >= LOCK_THRESHOLD && abs_v > abs_h * 1.5 {
+                    // Lock to vertical (zoom)
+                    model.scroll_state.lock = ScrollLock::Vertical;
+                }
+            }
+
+            // Apply the appropriate action based on lock state
+            match model.scroll_state.lock {
+                ScrollLock::None => {
+                    // Not yet committed - don't do anything until we lock
+                }
+                ScrollLock::Vertical => {
+                    // Zoom mode
+                    if model.scroll_state.vertical_accumulator >= ZOOM_THRESHOLD {
+                        model.zoom_in();
+                        model.scroll_state.vertical_accumulator = 0.0;
+                        save_config(model);
+                    } else if model.scroll_state.vertical_accumulator <= -ZOOM_THRESHOLD {
+                        model.zoom_out();
+                        model.scroll_state.vertical_accumulator = 0.0;
+                        save_config(model);
+                    }
+                }
+                ScrollLock::Horizontal => {
+                    // Time scrub mode - apply horizontal scroll directly
+                    let delta_seconds =
+                        (model.scroll_state.horizontal_accumulator * model.seconds_per_pixel() * 0.02) as i64;
+                    if delta_seconds != 0 {
+                        model.adjust_ghost(delta_seconds);
+                        model.scroll_state.horizontal_accumulator = 0.0;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn raw_window_event(app: &App, model: &mut Model, event: &nannou::winit::event::WindowEvent) {
+    // Any window event brings an idle clock back to full frame rate
+    model.frame_pacer.note_input(Instant::now());
+
+    // Let egui handle raw events
+    model.egui.handle_raw_event(event);
+    model.screen_reader.process_event(app.main_window().winit_window(), event);
+
+    // Resync time data when window regains focus (in case app was backgrounded)
+    if let nannou::winit::event::WindowEvent::Focused(true) = event {
+        // Invalidate DST cache to force refresh
+        model.last_dst_query_instant = None;
+        // Refresh time data immediately
+        model.time_data = shared::compute_time_data_at(model.selected_tz, model.center_instant());
+    }
+}
+
@@ -0,0 +1,135 @@
+//! Offsets module - the UTC offset ruler beside the ribbon and lanes
+//!
+//! Each band of the stack is tagged at its right edge with its zone's UTC
+//! offset at the cursor. Most zones keep a whole number of hours from UTC;
+//! the others are flagged, since their hour ticks fall between the hour
+//! ticks of whole-hour bands, and hovering the tag explains why.
+
+use chrono::{DateTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// How a UTC offset divides the hour
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetKind {
+    WholeHour,
+    HalfHour,
+    QuarterHour,
+    /// Seconds off the quarter hour: local mean time from before standard
+    /// time, or an early standard set by an observatory
+    LocalMean,
+}
+
+impl OffsetKind {
+    fn of(seconds: i32) -> Self {
+        if seconds % 3600 == 0 {
+            OffsetKind::WholeHour
+        } else if seconds % 1800 == 0 {
+            OffsetKind::HalfHour
+        } else if seconds % 900 == 0 {
+            OffsetKind::QuarterHour
+        } else {
+            OffsetKind::LocalMean
+        }
+    }
+}
+
+/// A band's UTC offset at one instant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZoneOffset {
+    pub zone: Tz,
+    /// Seconds east of UTC
+    pub seconds: i32,
+}
+
+impl ZoneOffset {
+    /// `zone`'s offset at `instant`
+    pub fn at(zone: Tz, instant: DateTime<Utc>) -> Self {
+        let seconds = zone.offset_from_utc_datetime(&instant.naive_utc()).fix().local_minus_utc();
+        Self { zone, seconds }
+    }
+
+    pub fn kind(&self) -> OffsetKind {
+        OffsetKind::of(self.seconds)
+    }
+
+    /// Whether the ruler flags this offset
+    pub fn is_unusual(&self) -> bool {
+        self.kind() != OffsetKind::WholeHour
+    }
+
+    /// "UTC+05:45", with seconds when the offset has them ("UTC-04:56:02")
+    pub fn label(&self) -> String {
+        let sign = if self.seconds >= 0 { "+" } else { "-" };
+        let magnitude = self.seconds.abs();
+        let (hours, minutes, seconds) = (magnitude / 3600, magnitude / 60 % 60, magnitude % 60);
+        if seconds == 0 {
+            format!("UTC{}{:02}:{:02}", sign, hours, minutes)
+        } else {
+            format!("UTC{}{:02}:{:02}:{:02}", sign, hours, minutes, seconds)
+        }
+    }
+
+    /// Why a flagged offset is unusual and how it shows on the ribbon
+    pub fn explanation(&self) -> Option<String> {
+        let minutes_past = self.seconds.rem_euclid(3600) / 60;
+        let why = match self.kind() {
+            OffsetKind::WholeHour => return None,
+            OffsetKind::HalfHour => "a half-hour offset, as kept in India, Iran, central \
+                                     Australia and Newfoundland",
+            OffsetKind::QuarterHour => "a quarter-hour offset, as kept in Nepal, the Chatham \
+                                        Islands and Eucla",
+            OffsetKind::LocalMean => "local mean time, set by the sun at a city's meridian \
+                                      before standard time zones were adopted",
+        };
+        Some(format!(
+            "{} is {}: {}. Its hours begin {} minutes past each UTC hour, so its hour ticks \
+             fall between those of whole-hour zones.",
+            self.zone.name(),
+            self.label(),
+            why,
+            minutes_past,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offset(zone: &str, instant: DateTime<Utc>) -> ZoneOffset {
+        ZoneOffset::at(zone.parse().unwrap(), instant)
+    }
+
+    #[test]
+    fn test_offsets_are_classified_and_labeled() {
+        let instant = Utc.with_ymd_and_hms(2025, 1, 15, 12, 0, 0).unwrap();
+
+        let new_york = offset("America/New_York", instant);
+        assert_eq!(new_york.label(), "UTC-05:00");
+        assert!(!new_york.is_unusual());
+        assert_eq!(new_york.explanation(), None);
+
+        let kolkata = offset("Asia/Kolkata", instant);
+        assert_eq!(kolkata.label(), "UTC+05:30");
+        assert_eq!(kolkata.kind(), OffsetKind::HalfHour);
+
+        let kathmandu = offset("Asia/Kathmandu", instant);
+        assert_eq!(kathmandu.kind(), OffsetKind::QuarterHour);
+        assert!(kathmandu.explanation().unwrap().contains("45 minutes past"));
+
+        // St. John's is 3:30 behind UTC, so its hours begin at :30
+        let st_johns = offset("America/St_Johns", instant);
+        assert_eq!(st_johns.label(), "UTC-03:30");
+        assert!(st_johns.explanation().unwrap().contains("30 minutes past"));
+    }
+
+    #[test]
+    fn test_local_mean_time_keeps_its_seconds() {
+        // New York kept local mean time until the railroads' standard in 1883
+        let instant = Utc.with_ymd_and_hms(1880, 1, 1, 12, 0, 0).unwrap();
+        let new_york = offset("America/New_York", instant);
+        assert_eq!(new_york.label(), "UTC-04:56:02");
+        assert_eq!(new_york.kind(), OffsetKind::LocalMean);
+        assert!(new_york.explanation().unwrap().contains("local mean time"));
+    }
+}
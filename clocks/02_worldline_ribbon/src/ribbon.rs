@@ -2,13 +2,28 @@
 //!
 //! Handles the mapping between time instants and screen coordinates,
 //! generates tick marks at appropriate intervals, and computes DST warp effects.
+//! Also manages the stack of extra timezone lanes sharing the same UTC axis,
+//! and the day/night bands shading the ribbon. At macro zoom levels the
+//! ticks fall on days, weeks and months instead of hours and minutes. While
+//! days are labeled one by one, date ticks can also carry their ISO week
+//! number and day of year.
+//!
+//! The axis counts leap seconds: an inserted 23:59:60 gets a second of its
+//! own between 23:59:59 and midnight UTC, marked by its own tick, so the
+//! minute holding it runs to 61 seconds.
 
-use chrono::{DateTime, Duration, Timelike, Utc};
+use chrono::{DateTime, Datelike, Duration, TimeZone, Timelike, Utc, Weekday};
 use chrono_tz::Tz;
-use shared::DstTransition;
+use shared::{
+    leap_seconds_between, query_dst_transitions, solar_elevation, tai_minus_utc, tr, Coordinates,
+    DaylightPhase, DstTransition, WeekDate,
+};
+
+/// Available zoom levels in seconds per pixel, down to about a year across
+pub const ZOOM_LEVELS: [f32; 9] = [5.0, 10.0, 30.0, 60.0, 120.0, 600.0, 1800.0, 7200.0, 21600.0];
 
-/// Available zoom levels in seconds per pixel
-pub const ZOOM_LEVELS: [f32; 5] = [5.0, 10.0, 30.0, 60.0, 120.0];
+/// Finest zoom (seconds per pixel) where ticks mark days instead of hours
+pub const MACRO_ZOOM: f32 = 600.0;
 
 /// Default zoom level index (30 sec/px)
 pub const DEFAULT_ZOOM_INDEX: usize = 2;
@@ -16,6 +31,24 @@ pub const DEFAULT_ZOOM_INDEX: usize = 2;
 /// Warp effect half-width in seconds (30 minutes)
 const WARP_HALF_WIDTH: f32 = 1800.0;
 
+/// Maximum number of extra lanes stacked under the primary ribbon
+pub const MAX_EXTRA_LANES: usize = 3;
+
+/// DST transition query range (days either side of center) for every lane
+const LANE_DST_RANGE_DAYS: i64 = 7;
+
+/// Pixels between sun elevation samples when finding day/night bands
+const DAYLIGHT_SAMPLE_PX: f32 = 2.0;
+
+/// Narrowest day, in pixels, still shaded by day and night
+const DAYLIGHT_MIN_DAY_PX: f32 = 24.0;
+
+/// Narrowest gap, in pixels, between labeled macro ticks
+const MACRO_LABEL_PX: f32 = 60.0;
+
+/// Seconds in a day, for macro tick spacing
+const DAY_SECS: f32 = 86_400.0;
+
 /// Tick type for rendering different visual weights
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TickType {
@@ -29,6 +62,14 @@ pub enum TickType {
     Second,
     /// Midnight boundary - special, shows date
     Midnight,
+    /// Day boundary at macro zoom levels
+    Day,
+    /// Monday at macro zoom levels
+    Week,
+    /// First of the month at macro zoom levels
+    Month,
+    /// An inserted leap second, 23:59:60 UTC
+    LeapSecond,
 }
 
 /// A tick mark to be rendered on the ribbon
@@ -42,6 +83,39 @@ pub struct Tick {
     pub tick_type: TickType,
     /// Label text (if any)
     pub label: Option<String>,
+    /// Smaller second line under the label (if any)
+    pub sublabel: Option<String>,
+}
+
+/// Which calendar details date ticks show under their labels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CalendarLabels {
+    /// ISO-8601 week number ("W07")
+    pub week_numbers: bool,
+    /// Day of the year ("D045")
+    pub day_of_year: bool,
+}
+
+impl CalendarLabels {
+    /// The second line for a date tick, if any detail is on
+    fn sublabel(self, week_date: WeekDate) -> Option<String> {
+        let parts: Vec<String> = [
+            self.week_numbers.then(|| week_date.week_label()),
+            self.day_of_year.then(|| week_date.day_label()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        (!parts.is_empty()).then(|| parts.join(" · "))
+    }
+}
+
+/// A stretch of the ribbon spent in one daylight phase
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DaylightBand {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub phase: DaylightPhase,
 }
 
 /// Viewport state for the ribbon
@@ -68,6 +142,11 @@ impl RibbonViewport {
         }
     }
 
+    /// Whether ticks mark days, weeks and months rather than hours
+    pub fn is_macro(&self) -> bool {
+        self.seconds_per_pixel >= MACRO_ZOOM
+    }
+
     /// Get the time span visible in the viewport (in seconds)
     pub fn visible_span_seconds(&self) -> i64 {
         (self.viewport_width * self.seconds_per_pixel) as i64
@@ -84,9 +163,11 @@ impl RibbonViewport {
     }
 
     /// Convert an instant to an x position (relative to viewport center)
+    ///
+    /// Leap seconds between the two count, so each takes up its own second.
     pub fn instant_to_x(&self, instant: DateTime<Utc>) -> f32 {
-        let delta_seconds = (instant - self.center_instant).num_milliseconds() as f32 / 1000.0;
-        delta_seconds / self.seconds_per_pixel
+        let delta_seconds = axis_seconds(instant) - axis_seconds(self.center_instant);
+        delta_seconds as f32 / self.seconds_per_pixel
     }
 
     /// Convert an x position to an instant
@@ -122,6 +203,14 @@ impl RibbonViewport {
         let left = self.left_instant();
         let right = self.right_instant();
 
+        // Leap seconds are marked at every zoom
+        self.generate_leap_second_ticks(&mut ticks, left, right);
+
+        if self.is_macro() {
+            self.generate_day_ticks(&mut ticks, left, right);
+            return ticks;
+        }
+
         // Generate hour ticks
         self.generate_hour_ticks(&mut ticks, left, right);
 
@@ -165,7 +254,7 @@ impl RibbonViewport {
             .unwrap();
 
         if current_hour < left_local {
-            current_hour = current_hour + Duration::hours(1);
+            current_hour += Duration::hours(1);
         }
 
         while current_hour <= right_local {
@@ -181,7 +270,7 @@ impl RibbonViewport {
             };
 
             // Only generate label at the appropriate interval
-            let should_label = is_midnight || (current_hour.hour() % label_interval == 0);
+            let should_label = is_midnight || current_hour.hour().is_multiple_of(label_interval);
 
             let label = if should_label {
                 if is_midnight {
@@ -206,9 +295,30 @@ impl RibbonViewport {
                 x_position: x,
                 tick_type,
                 label,
+                sublabel: None,
             });
 
-            current_hour = current_hour + Duration::hours(1);
+            current_hour += Duration::hours(1);
+        }
+    }
+
+    /// A tick for each leap second in view, labeled with its local reading
+    /// ("00:59:60" an hour east of UTC)
+    fn generate_leap_second_ticks(
+        &self,
+        ticks: &mut Vec<Tick>,
+        left: DateTime<Utc>,
+        right: DateTime<Utc>,
+    ) {
+        for leap in leap_seconds_between(left, right) {
+            let local = leap.instant.with_timezone(&self.timezone);
+            ticks.push(Tick {
+                instant: leap.instant,
+                x_position: self.instant_to_x(leap.instant),
+                tick_type: TickType::LeapSecond,
+                label: Some(local.format("%H:%M:%S").to_string()),
+                sublabel: Some(tr("leap second").to_string()),
+            });
         }
     }
 
@@ -226,7 +336,7 @@ impl RibbonViewport {
             .unwrap();
 
         if current < left_local {
-            current = current + Duration::minutes(5);
+            current += Duration::minutes(5);
         }
 
         while current <= right_local {
@@ -240,10 +350,11 @@ impl RibbonViewport {
                     x_position: x,
                     tick_type: TickType::FiveMinute,
                     label: None,
+                    sublabel: None,
                 });
             }
 
-            current = current + Duration::minutes(5);
+            current += Duration::minutes(5);
         }
     }
 
@@ -257,12 +368,12 @@ impl RibbonViewport {
             .unwrap();
 
         if current < left_local {
-            current = current + Duration::minutes(1);
+            current += Duration::minutes(1);
         }
 
         while current <= right_local {
             // Skip if already a 5-minute or hour tick
-            if current.minute() % 5 != 0 {
+            if !current.minute().is_multiple_of(5) {
                 let instant = current.with_timezone(&Utc);
                 let x = self.instant_to_x(instant);
 
@@ -271,13 +382,120 @@ impl RibbonViewport {
                     x_position: x,
                     tick_type: TickType::Minute,
                     label: None,
+                    sublabel: None,
                 });
             }
 
-            current = current + Duration::minutes(1);
+            current += Duration::minutes(1);
+        }
+    }
+
+    /// Put the ISO week and day of year under each labeled date tick, while
+    /// the zoom is close enough that every day is labeled
+    pub fn annotate_week_dates(&self, ticks: &mut [Tick], calendar: CalendarLabels) {
+        if DAY_SECS / self.seconds_per_pixel < MACRO_LABEL_PX {
+            return;
+        }
+        for tick in ticks.iter_mut().filter(|tick| tick.label.is_some()) {
+            if matches!(
+                tick.tick_type,
+                TickType::Midnight | TickType::Day | TickType::Week | TickType::Month
+            ) {
+                tick.sublabel = calendar.sublabel(WeekDate::at(tick.instant, self.timezone));
+            }
         }
     }
 
+    /// Local midnights, labeled by day when there's room, else by week or month
+    fn generate_day_ticks(&self, ticks: &mut Vec<Tick>, left: DateTime<Utc>, right: DateTime<Utc>) {
+        let day_px = DAY_SECS / self.seconds_per_pixel;
+        let label_days = day_px >= MACRO_LABEL_PX;
+        let label_weeks = day_px * 7.0 >= MACRO_LABEL_PX;
+
+        let mut date = left.with_timezone(&self.timezone).date_naive();
+        let last = right.with_timezone(&self.timezone).date_naive();
+
+        while date <= last {
+            // A midnight skipped by DST starts the day at the first hour that exists
+            let start = (0..3).find_map(|hour| {
+                let local = date.and_hms_opt(hour, 0, 0)?;
+                self.timezone.from_local_datetime(&local).earliest()
+            });
+
+            if let Some(start) = start.map(|dt| dt.with_timezone(&Utc)) {
+                if start >= left && start <= right {
+                    let tick_type = if date.day() == 1 {
+                        TickType::Month
+                    } else if date.weekday() == Weekday::Mon {
+                        TickType::Week
+                    } else {
+                        TickType::Day
+                    };
+
+                    let label = match tick_type {
+                        TickType::Month if !label_weeks => Some(date.format("%b %Y").to_string()),
+                        TickType::Month | TickType::Week if label_weeks => {
+                            Some(date.format("%a %b %d").to_string())
+                        }
+                        _ if label_days => Some(date.format("%a %d").to_string()),
+                        _ => None,
+                    };
+
+                    ticks.push(Tick {
+                        instant: start,
+                        x_position: self.instant_to_x(start),
+                        tick_type,
+                        label,
+                        sublabel: None,
+                    });
+                }
+            }
+
+            date = match date.succ_opt() {
+                Some(next) => next,
+                None => break,
+            };
+        }
+    }
+
+    /// Day, twilight and night bands across the viewport at `coordinates`
+    ///
+    /// Samples the sun's elevation every couple of pixels, so polar day and
+    /// night need no special cases and band edges are pixel-accurate at any
+    /// zoom. Once days shrink to a few pixels the bands are left out.
+    pub fn daylight_bands(&self, coordinates: Coordinates) -> Vec<DaylightBand> {
+        if DAY_SECS / self.seconds_per_pixel < DAYLIGHT_MIN_DAY_PX {
+            return Vec::new();
+        }
+        let step_ms = DAYLIGHT_SAMPLE_PX * self.seconds_per_pixel * 1000.0;
+        let step = Duration::milliseconds(step_ms as i64);
+        let right = self.right_instant();
+        let mut bands: Vec<DaylightBand> = Vec::new();
+        let mut instant = self.left_instant();
+
+        loop {
+            let phase = DaylightPhase::from_elevation(solar_elevation(instant, coordinates));
+            match bands.last_mut() {
+                Some(band) if band.phase == phase => band.end = instant,
+                last => {
+                    if let Some(band) = last {
+                        band.end = instant;
+                    }
+                    bands.push(DaylightBand {
+                        start: instant,
+                        end: instant,
+                        phase,
+                    });
+                }
+            }
+            if instant >= right {
+                break;
+            }
+            instant = (instant + step).min(right);
+        }
+        bands
+    }
+
     fn generate_second_ticks(&self, ticks: &mut Vec<Tick>) {
         // Only generate seconds within ±90 seconds of center
         const SECOND_RANGE: i64 = 90;
@@ -295,12 +513,145 @@ impl RibbonViewport {
                     x_position: x,
                     tick_type: TickType::Second,
                     label: None,
+                    sublabel: None,
                 });
             }
         }
     }
 }
 
+/// Seconds since the Unix epoch counting leap seconds, so the axis runs
+/// through 23:59:60 instead of repeating 23:59:59
+fn axis_seconds(instant: DateTime<Utc>) -> f64 {
+    let nanos = f64::from(instant.timestamp_subsec_nanos()) / 1e9;
+    instant.timestamp() as f64 + nanos + f64::from(tai_minus_utc(instant))
+}
+
+/// An extra ribbon lane bound to one timezone
+#[derive(Debug, Clone)]
+pub struct Lane {
+    /// Timezone whose local time this lane's ticks follow
+    pub timezone: Tz,
+    /// Cached DST transitions for this lane's timezone
+    pub dst_transitions: Vec<DstTransition>,
+}
+
+/// Manages the extra lanes stacked under the primary ribbon
+///
+/// All lanes share the primary ribbon's UTC axis (same center instant and
+/// zoom), so a DST seam in one lane lines up against the same instant in
+/// every other lane.
+#[derive(Debug, Clone, Default)]
+pub struct LaneManager {
+    lanes: Vec<Lane>,
+    /// Days either side of center covered by the last transition query
+    range_days: i64,
+}
+
+impl LaneManager {
+    /// Create a lane manager from a list of timezones (duplicates and
+    /// overflow beyond `MAX_EXTRA_LANES` are dropped)
+    pub fn new(zones: &[Tz], primary: Tz, center: DateTime<Utc>) -> Self {
+        let mut manager = Self::default();
+        for &tz in zones {
+            manager.add_lane(tz, primary, center);
+        }
+        manager
+    }
+
+    /// All extra lanes, top to bottom
+    pub fn lanes(&self) -> &[Lane] {
+        &self.lanes
+    }
+
+    /// Timezones of all extra lanes, top to bottom
+    pub fn zones(&self) -> Vec<Tz> {
+        self.lanes.iter().map(|lane| lane.timezone).collect()
+    }
+
+    /// Number of extra lanes
+    pub fn len(&self) -> usize {
+        self.lanes.len()
+    }
+
+    /// Whether a lane for this timezone already exists
+    pub fn contains(&self, tz: Tz) -> bool {
+        self.lanes.iter().any(|lane| lane.timezone == tz)
+    }
+
+    /// Whether another lane can be added
+    pub fn is_full(&self) -> bool {
+        self.lanes.len() >= MAX_EXTRA_LANES
+    }
+
+    /// Add a lane for a timezone
+    ///
+    /// Returns false if the zone is the primary zone, already has a lane,
+    /// or the stack is full.
+    pub fn add_lane(&mut self, tz: Tz, primary: Tz, center: DateTime<Utc>) -> bool {
+        if tz == primary || self.contains(tz) || self.is_full() {
+            return false;
+        }
+        let range_days = self.range_days.max(LANE_DST_RANGE_DAYS);
+        self.lanes.push(Lane {
+            timezone: tz,
+            dst_transitions: query_dst_transitions(tz, center, range_days),
+        });
+        true
+    }
+
+    /// Remove the lane for a timezone, returning whether one was removed
+    pub fn remove_lane(&mut self, tz: Tz) -> bool {
+        let before = self.lanes.len();
+        self.lanes.retain(|lane| lane.timezone != tz);
+        self.lanes.len() != before
+    }
+
+    /// Re-query DST transitions for every lane around a new center instant
+    pub fn refresh_transitions(&mut self, center: DateTime<Utc>, range_days: i64) {
+        self.range_days = range_days;
+        for lane in self.lanes.iter_mut() {
+            lane.dst_transitions = query_dst_transitions(lane.timezone, center, range_days);
+        }
+    }
+
+    /// Build one viewport per lane, aligned to the primary viewport's UTC axis
+    pub fn viewports(&self, primary: &RibbonViewport) -> Vec<RibbonViewport> {
+        self.lanes
+            .iter()
+            .map(|lane| {
+                RibbonViewport::new(
+                    primary.center_instant,
+                    primary.seconds_per_pixel,
+                    primary.viewport_width,
+                    lane.timezone,
+                )
+            })
+            .collect()
+    }
+}
+
+/// Days either side of center to query DST transitions for
+///
+/// Covers a week at the hour scales and twice the half-width of the
+/// viewport at macro zoom levels, so the ribbon can move a little before
+/// the transitions need querying again.
+pub fn dst_range_days(seconds_per_pixel: f32, viewport_width: f32) -> i64 {
+    let half_span_days = (viewport_width * seconds_per_pixel / 2.0 / DAY_SECS).ceil() as i64;
+    (half_span_days * 2).max(LANE_DST_RANGE_DAYS)
+}
+
+/// Zoom level for display, in the largest whole unit ("10 min/px")
+pub fn format_zoom(seconds_per_pixel: f32) -> String {
+    if seconds_per_pixel >= 3600.0 {
+        format!("{:.0} hr/px", seconds_per_pixel / 3600.0)
+    } else if seconds_per_pixel >= 600.0 {
+        format!("{:.0} min/px", seconds_per_pixel / 60.0)
+    } else {
+        format!("{:.0} sec/px", seconds_per_pixel)
+    }
+}
+
 /// Format an instant for display at the cursor
 pub fn format_cursor_time(instant: DateTime<Utc>, tz: Tz) -> String {
     let local = instant.with_timezone(&tz);
@@ -322,7 +673,6 @@ pub fn format_cursor_time(instant: DateTime<Utc>, tz: Tz) -> String {
 }
 
 /// Format an instant with date for display
-#[allow(dead_code)]
 pub fn format_cursor_datetime(instant: DateTime<Utc>, tz: Tz) -> String {
     let local = instant.with_timezone(&tz);
     let hour24 = local.hour();
@@ -361,12 +711,123 @@ mod tests {
         assert!((viewport.instant_to_x(future) - 1.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_lane_manager_add_remove() {
+        let primary: Tz = "America/Los_Angeles".parse().unwrap();
+        let london: Tz = "Europe/London".parse().unwrap();
+        let center = Utc::now();
+        let mut lanes = LaneManager::default();
+
+        // Primary zone and duplicates are rejected
+        assert!(!lanes.add_lane(primary, primary, center));
+        assert!(lanes.add_lane(london, primary, center));
+        assert!(!lanes.add_lane(london, primary, center));
+
+        // Stack is capped
+        for name in ["Asia/Tokyo", "Australia/Sydney", "Asia/Kolkata"] {
+            lanes.add_lane(name.parse().unwrap(), primary, center);
+        }
+        assert_eq!(lanes.len(), MAX_EXTRA_LANES);
+
+        assert!(lanes.remove_lane(london));
+        assert!(!lanes.contains(london));
+    }
+
+    #[test]
+    fn test_lane_viewports_share_utc_axis() {
+        let primary_tz: Tz = "America/Los_Angeles".parse().unwrap();
+        let kolkata: Tz = "Asia/Kolkata".parse().unwrap();
+        let center = Utc::now();
+        let lanes = LaneManager::new(&[kolkata], primary_tz, center);
+        let primary = RibbonViewport::new(center, 30.0, 800.0, primary_tz);
+
+        let viewports = lanes.viewports(&primary);
+        assert_eq!(viewports.len(), 1);
+        assert_eq!(viewports[0].center_instant, primary.center_instant);
+        assert_eq!(viewports[0].timezone, kolkata);
+
+        // The same instant maps to the same x in every lane
+        let instant = center + Duration::hours(1);
+        assert_eq!(viewports[0].instant_to_x(instant), primary.instant_to_x(instant));
+    }
+
+    #[test]
+    fn test_macro_ticks_fall_on_days_weeks_and_months() {
+        use chrono::TimeZone;
+        // About ten days either side of Nov 1 2026 in New York, where DST ends
+        let new_york: Tz = "America/New_York".parse().unwrap();
+        let center = Utc.with_ymd_and_hms(2026, 11, 1, 12, 0, 0).unwrap();
+        let viewport = RibbonViewport::new(center, 1800.0, 1000.0, new_york);
+        let ticks = viewport.generate_ticks();
+
+        assert!(viewport.is_macro());
+        assert!(ticks.iter().all(|t| matches!(
+            t.tick_type,
+            TickType::Day | TickType::Week | TickType::Month
+        )));
+        // Every tick is a local midnight, one per day
+        assert!(ticks.iter().all(|t| t.instant.with_timezone(&new_york).hour() == 0));
+        assert!(ticks.windows(2).all(|w| w[1].instant - w[0].instant >= Duration::hours(23)));
+
+        let month = ticks.iter().find(|t| t.tick_type == TickType::Month).unwrap();
+        assert_eq!(month.label.as_deref(), Some("Sun Nov 01"));
+        let monday = ticks.iter().find(|t| t.tick_type == TickType::Week).unwrap();
+        assert_eq!(monday.label.as_deref(), Some("Mon Oct 26"));
+        // Days are too narrow at this zoom to label
+        assert!(ticks.iter().filter(|t| t.tick_type == TickType::Day).all(|t| t.label.is_none()));
+    }
+
+    #[test]
+    fn test_week_dates_under_day_ticks() {
+        use chrono::TimeZone;
+        // The turn of 2026, when week 1 starts on Monday 29 December
+        let center = Utc.with_ymd_and_hms(2025, 12, 31, 12, 0, 0).unwrap();
+        let calendar = CalendarLabels {
+            week_numbers: true,
+            day_of_year: true,
+        };
+        let viewport = RibbonViewport::new(center, 600.0, 1000.0, Tz::UTC);
+        let mut ticks = viewport.generate_ticks();
+        viewport.annotate_week_dates(&mut ticks, calendar);
+
+        let monday = ticks.iter().find(|t| t.tick_type == TickType::Week).unwrap();
+        assert_eq!(monday.sublabel.as_deref(), Some("2026-W01 · D363"));
+        let new_year = ticks.iter().find(|t| t.tick_type == TickType::Month).unwrap();
+        assert_eq!(new_year.sublabel.as_deref(), Some("W01 · D001"));
+
+        // Week numbers alone
+        let weeks_only = CalendarLabels {
+            week_numbers: true,
+            day_of_year: false,
+        };
+        viewport.annotate_week_dates(&mut ticks, weeks_only);
+        let new_year = ticks.iter().find(|t| t.tick_type == TickType::Month).unwrap();
+        assert_eq!(new_year.sublabel.as_deref(), Some("W01"));
+
+        // Zoomed out past one label per day, ticks stay as they were
+        let wide = RibbonViewport::new(center, 1800.0, 1000.0, Tz::UTC);
+        let mut ticks = wide.generate_ticks();
+        wide.annotate_week_dates(&mut ticks, calendar);
+        assert!(ticks.iter().all(|t| t.sublabel.is_none()));
+    }
+
+    #[test]
+    fn test_dst_range_covers_macro_viewport() {
+        assert_eq!(dst_range_days(30.0, 1400.0), LANE_DST_RANGE_DAYS);
+        // A year across needs transitions a year either side
+        let range = dst_range_days(21600.0, 1400.0);
+        assert!(range >= 350);
+        assert_eq!(format_zoom(30.0), "30 sec/px");
+        assert_eq!(format_zoom(600.0), "10 min/px");
+        assert_eq!(format_zoom(21600.0), "6 hr/px");
+    }
+
     #[test]
     fn test_warp_smoothstep() {
         // Verify smoothstep at boundaries
-        let u_start = 0.0;
-        let u_end = 1.0;
-        let u_mid = 0.5;
+        let u_start: f32 = 0.0;
+        let u_end: f32 = 1.0;
+        let u_mid: f32 = 0.5;
 
         let ss_start = u_start * u_start * (3.0 - 2.0 * u_start);
         let ss_end = u_end * u_end * (3.0 - 2.0 * u_end);
@@ -376,5 +837,58 @@ mod tests {
         assert!((ss_end - 1.0).abs() < 0.001);
         assert!((ss_mid - 0.5).abs() < 0.001);
     }
-}
 
+    #[test]
+    fn test_daylight_bands_cover_days_and_nights() {
+        use chrono::TimeZone;
+        // Two days around the June solstice in London, at the widest zoom
+        let london: Tz = "Europe/London".parse().unwrap();
+        let center = Utc.with_ymd_and_hms(2024, 6, 21, 12, 0, 0).unwrap();
+        let viewport = RibbonViewport::new(center, 120.0, 1440.0, london);
+        let bands = viewport.daylight_bands(Coordinates::new(51.5, -0.1));
+
+        assert_eq!(bands.first().unwrap().start, viewport.left_instant());
+        assert_eq!(bands.last().unwrap().end, viewport.right_instant());
+        assert!(bands.windows(2).all(|w| w[0].end == w[1].start && w[0].phase != w[1].phase));
+        // Noon falls in a long day band
+        let noon = bands.iter().find(|b| b.start <= center && center <= b.end).unwrap();
+        assert_eq!(noon.phase, DaylightPhase::Day);
+        assert!(noon.end - noon.start > Duration::hours(15));
+        assert!(bands.iter().any(|b| b.phase == DaylightPhase::Night));
+    }
+
+    #[test]
+    fn test_polar_day_is_one_band() {
+        use chrono::TimeZone;
+        let center = Utc.with_ymd_and_hms(2024, 6, 21, 0, 0, 0).unwrap();
+        let viewport = RibbonViewport::new(center, 120.0, 1440.0, Tz::UTC);
+        let bands = viewport.daylight_bands(Coordinates::new(78.2, 15.6));
+        assert_eq!(bands.len(), 1);
+        assert_eq!(bands[0].phase, DaylightPhase::Day);
+    }
+
+    #[test]
+    fn test_leap_second_gets_its_own_tick_and_second() {
+        use chrono::TimeZone;
+        let midnight = Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
+        let viewport = RibbonViewport::new(midnight, 5.0, 800.0, Tz::UTC);
+        let ticks = viewport.generate_ticks();
+
+        let leap = ticks.iter().find(|t| t.tick_type == TickType::LeapSecond).unwrap();
+        assert_eq!(leap.label.as_deref(), Some("23:59:60"));
+        // 23:59:59, the leap second and midnight are a second apart each
+        let before = viewport.instant_to_x(midnight - Duration::seconds(1));
+        assert!((leap.x_position - before - 0.2).abs() < 0.001);
+        assert!((viewport.instant_to_x(midnight) - leap.x_position - 0.2).abs() < 0.001);
+
+        // Local readings follow the zone
+        let berlin = RibbonViewport::new(midnight, 5.0, 800.0, "Europe/Berlin".parse().unwrap());
+        let leap = berlin.generate_ticks();
+        let leap = leap.iter().find(|t| t.tick_type == TickType::LeapSecond).unwrap();
+        assert_eq!(leap.label.as_deref(), Some("00:59:60"));
+
+        // No leap second in view, no leap tick
+        let later = RibbonViewport::new(midnight + Duration::days(2), 5.0, 800.0, Tz::UTC);
+        assert!(later.generate_ticks().iter().all(|t| t.tick_type != TickType::LeapSecond));
+    }
+}
@@ -0,0 +1,133 @@
+//! Selection module - a measured range of the ribbon
+//!
+//! Shift-dragging across the ribbon selects a span of time. Its duration is
+//! given two ways: elapsed (absolute) time, and the difference a wall clock
+//! in the selected zone would show. The two differ by any DST gap or overlap
+//! inside the range.
+
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use chrono_tz::Tz;
+use shared::tr_args;
+
+/// A span of the ribbon, `start` never after `end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeRange {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl TimeRange {
+    /// The range between two instants, in either order
+    pub fn new(a: DateTime<Utc>, b: DateTime<Utc>) -> Self {
+        Self {
+            start: a.min(b),
+            end: a.max(b),
+        }
+    }
+
+    /// Time actually elapsed across the range
+    pub fn absolute(&self) -> Duration {
+        self.end - self.start
+    }
+
+    /// Difference between the local clock readings at either end in `tz`
+    pub fn wall_clock(&self, tz: Tz) -> Duration {
+        self.end.with_timezone(&tz).naive_local() - self.start.with_timezone(&tz).naive_local()
+    }
+
+    /// How a DST transition inside the range skews the wall clock, if at all
+    pub fn dst_note(&self, tz: Tz) -> Option<String> {
+        let skew = self.wall_clock(tz) - self.absolute();
+        if skew > Duration::zero() {
+            Some(tr_args("Includes a {0} DST gap", &[&format_duration(skew)]))
+        } else if skew < Duration::zero() {
+            Some(tr_args("Includes a {0} DST overlap", &[&format_duration(-skew)]))
+        } else {
+            None
+        }
+    }
+
+    /// One-line summary for the clipboard, as an ISO 8601 interval
+    pub fn describe(&self, tz: Tz) -> String {
+        let stamp = |instant: DateTime<Utc>| {
+            instant
+                .with_timezone(&tz)
+                .to_rfc3339_opts(SecondsFormat::Secs, false)
+        };
+        format!(
+            "{}/{} ({}) · elapsed {} · wall clock {}",
+            stamp(self.start),
+            stamp(self.end),
+            tz.name(),
+            format_duration(self.absolute()),
+            format_duration(self.wall_clock(tz))
+        )
+    }
+}
+
+/// Format a duration as "2h 05m 30s", "1d 03h 00m" or "45s"
+pub fn format_duration(duration: Duration) -> String {
+    let sign = if duration < Duration::zero() { "-" } else { "" };
+    let total = duration.num_seconds().abs();
+    let (days, hours) = (total / 86_400, total % 86_400 / 3600);
+    let (minutes, seconds) = (total % 3600 / 60, total % 60);
+    if days > 0 {
+        format!("{}{}d {:02}h {:02}m", sign, days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}{}h {:02}m {:02}s", sign, hours, minutes, seconds)
+    } else if minutes > 0 {
+        format!("{}{}m {:02}s", sign, minutes, seconds)
+    } else {
+        format!("{}{}s", sign, seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn los_angeles() -> Tz {
+        "America/Los_Angeles".parse().unwrap()
+    }
+
+    #[test]
+    fn test_range_across_spring_forward() {
+        // 1:00 AM PST to 4:00 AM PDT on 2024-03-10: two hours pass, the
+        // wall clock moves three
+        let start = Utc.with_ymd_and_hms(2024, 3, 10, 9, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 3, 10, 11, 0, 0).unwrap();
+        let range = TimeRange::new(end, start);
+        assert_eq!(range.start, start);
+        assert_eq!(range.absolute(), Duration::hours(2));
+        assert_eq!(range.wall_clock(los_angeles()), Duration::hours(3));
+        assert_eq!(range.dst_note(los_angeles()).unwrap(), "Includes a 1h 00m 00s DST gap");
+        assert_eq!(
+            range.describe(los_angeles()),
+            "2024-03-10T01:00:00-08:00/2024-03-10T04:00:00-07:00 (America/Los_Angeles) \
+             · elapsed 2h 00m 00s · wall clock 3h 00m 00s"
+        );
+    }
+
+    #[test]
+    fn test_range_across_fall_back() {
+        // 1:30 AM PDT to 1:10 AM PST on 2024-11-03: 40 minutes pass, the
+        // wall clock reads 20 minutes earlier
+        let start = Utc.with_ymd_and_hms(2024, 11, 3, 8, 30, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 11, 3, 9, 10, 0).unwrap();
+        let range = TimeRange::new(start, end);
+        assert_eq!(range.absolute(), Duration::minutes(40));
+        assert_eq!(range.wall_clock(los_angeles()), Duration::minutes(-20));
+        assert_eq!(range.dst_note(los_angeles()).unwrap(), "Includes a 1h 00m 00s DST overlap");
+        assert!(range.dst_note("UTC".parse().unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::seconds(45)), "45s");
+        assert_eq!(format_duration(Duration::seconds(330)), "5m 30s");
+        assert_eq!(format_duration(Duration::seconds(7530)), "2h 05m 30s");
+        assert_eq!(format_duration(Duration::hours(27)), "1d 03h 00m");
+        assert_eq!(format_duration(Duration::minutes(-20)), "-20m 00s");
+    }
+}
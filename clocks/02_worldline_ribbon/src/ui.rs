@@ -2,11 +2,23 @@
 //!
 //! Provides the interactive UI components using nannou_egui.
 
+use chrono::{DateTime, Utc};
 use chrono_tz::Tz;
 use nannou_egui::egui;
-use shared::{search_timezones, DstChange, TimeData};
-
-use crate::ribbon::ZOOM_LEVELS;
+use shared::{
+    active_palette, clip_recording_settings, favorites_settings, keymap_settings, language_settings,
+    order_by_region, quiet_hours_settings, reduced_motion_settings, region_change, region_heading,
+    reveal_hotkey_settings, safe_palette_settings, search_timezones, system_zone_settings, tr,
+    tr_args, window_title_settings, zone_display_name, ClipSettings, DstChange, FollowSystemZone,
+    Keymap, KeymapEdit, LanguageSetting, MotionPreference, QuietHours, RevealHotkey, Rgb,
+    SafePaletteSetting, ThemeLibrary, TimeData, WindowTitleClock,
+};
+
+use crate::bookmarks::Bookmark;
+use crate::ribbon::{
+    format_cursor_datetime, format_zoom, CalendarLabels, MACRO_ZOOM, MAX_EXTRA_LANES, ZOOM_LEVELS,
+};
+use crate::selection::{format_duration, TimeRange};
 
 /// State for the timezone picker
 #[derive(Default)]
@@ -25,7 +37,7 @@ impl PickerState {
     pub fn open(&mut self) {
         self.is_open = true;
         self.search_query.clear();
-        self.search_results = search_timezones("");
+        self.search_results = order_by_region(search_timezones(""));
         self.should_focus_search = true;
     }
 
@@ -36,31 +48,28 @@ impl PickerState {
     }
 
     pub fn update_search(&mut self) {
-        self.search_results = search_timezones(&self.search_query);
+        self.search_results = order_by_region(search_timezones(&self.search_query));
     }
 }
 
 /// Result of timezone picker interactions
+#[derive(Default)]
 pub struct PickerResult {
     /// If Some, the user selected a new timezone
     pub selected_tz: Option<Tz>,
     /// If Some, toggle favorite status for this timezone
     pub toggle_favorite: Option<Tz>,
+    /// If Some, stack a new lane for this timezone
+    pub add_lane: Option<Tz>,
+    /// If Some, remove the lane for this timezone
+    pub remove_lane: Option<Tz>,
     /// If true, close the picker
     pub close_picker: bool,
 }
 
-impl Default for PickerResult {
-    fn default() -> Self {
-        Self {
-            selected_tz: None,
-            toggle_favorite: None,
-            close_picker: false,
-        }
-    }
-}
 
 /// Result of scrub control interactions
+#[derive(Default)]
 pub struct ScrubControlResult {
     /// Return to live mode
     pub return_to_now: bool,
@@ -72,18 +81,63 @@ pub struct ScrubControlResult {
     pub zoom_out: bool,
     /// Reduced motion setting changed
     pub reduced_motion_changed: bool,
+    /// Scrub snapping toggled
+    pub snap_scrub_changed: bool,
+    /// Week number or day-of-year labels toggled
+    pub calendar_labels_changed: bool,
+    /// Quiet-hours schedule edited
+    pub quiet_hours_changed: bool,
+    /// Window title clock setting edited
+    pub window_title_changed: bool,
+    /// Shared clip recording settings edited
+    pub clip_settings_changed: bool,
+    /// Shared language edited
+    pub language_changed: bool,
+    /// Shared safe palette edited
+    pub safe_palette_changed: bool,
+    /// Shared follow-system-zone setting edited
+    pub system_zone_changed: bool,
+    /// Shared reveal shortcut edited
+    pub reveal_hotkey_changed: bool,
+    /// Switched between the shared favorites and this clock's own
+    pub favorites_source_changed: bool,
+    /// Shortcut rebound, reset or waiting for its new chord
+    pub keymap_edit: Option<KeymapEdit>,
+    /// Switch theme (`Some(None)` for the clock's own colors)
+    pub set_theme: Option<Option<String>>,
 }
 
-impl Default for ScrubControlResult {
-    fn default() -> Self {
-        Self {
-            return_to_now: false,
-            step_time: None,
-            zoom_in: false,
-            zoom_out: false,
-            reduced_motion_changed: false,
-        }
-    }
+/// Result of selection panel interactions
+#[derive(Default)]
+pub struct SelectionResult {
+    /// The range summary was copied to the clipboard
+    pub copied: bool,
+    /// Clear the selection
+    pub clear: bool,
+}
+
+/// Text fields and last import outcome for the bookmarks sidebar
+#[derive(Default)]
+pub struct BookmarkPanelState {
+    /// Label for the next bookmark
+    pub new_label: String,
+    /// Path of the CSV file to import
+    pub import_path: String,
+    /// Outcome of the last import: `Ok` message or `Err` description
+    pub import_status: Option<Result<String, String>>,
+}
+
+/// Result of bookmarks sidebar interactions
+#[derive(Default)]
+pub struct BookmarkResult {
+    /// Scrub to this instant
+    pub jump_to: Option<DateTime<Utc>>,
+    /// Bookmark the cursor instant with this label
+    pub add_label: Option<String>,
+    /// Remove the bookmark at this index
+    pub remove: Option<usize>,
+    /// Import bookmarks from the CSV file at this path
+    pub import_path: Option<String>,
 }
 
 /// Draw the timezone picker overlay
@@ -92,6 +146,7 @@ pub fn draw_timezone_picker(
     picker_state: &mut PickerState,
     current_tz: Tz,
     favorites: &[Tz],
+    lane_zones: &[Tz],
 ) -> PickerResult {
     let mut result = PickerResult::default();
 
@@ -99,7 +154,8 @@ pub fn draw_timezone_picker(
         return result;
     }
 
-    egui::Window::new("Select Time Zone")
+    egui::Window::new(tr("Select Time Zone"))
+        .id(egui::Id::new("zone_picker"))
         .collapsible(false)
         .resizable(true)
         .default_width(400.0)
@@ -108,7 +164,7 @@ pub fn draw_timezone_picker(
         .show(ctx, |ui| {
             // Search field
             ui.horizontal(|ui| {
-                ui.label("Search:");
+                ui.label(tr("Search:"));
                 let response = ui.text_edit_singleline(&mut picker_state.search_query);
                 if picker_state.should_focus_search {
                     response.request_focus();
@@ -122,7 +178,7 @@ pub fn draw_timezone_picker(
             ui.separator();
 
             // System timezone button
-            if ui.button("Use System Time Zone").clicked() {
+            if ui.button(tr("Use System Time Zone")).clicked() {
                 if let Some(sys_tz) = shared::system_timezone() {
                     result.selected_tz = Some(sys_tz);
                     result.close_picker = true;
@@ -133,7 +189,7 @@ pub fn draw_timezone_picker(
 
             // Favorites section
             if !favorites.is_empty() {
-                ui.label("Favorites:");
+                ui.label(tr("Favorites:"));
                 ui.horizontal_wrapped(|ui| {
                     for &tz in favorites {
                         let is_current = tz == current_tz;
@@ -151,16 +207,37 @@ pub fn draw_timezone_picker(
                 ui.separator();
             }
 
+            // Lanes section
+            ui.label(tr_args("Lanes ({0}/{1}):", &[&lane_zones.len(), &MAX_EXTRA_LANES]));
+            if lane_zones.is_empty() {
+                ui.label(tr("Use \"+ Lane\" to stack another zone under the ribbon."));
+            } else {
+                for &tz in lane_zones {
+                    ui.horizontal(|ui| {
+                        if ui.small_button("✕").on_hover_text(tr("Remove lane")).clicked() {
+                            result.remove_lane = Some(tz);
+                        }
+                        ui.label(tz.name());
+                    });
+                }
+            }
+            ui.separator();
+
             // Results list
-            ui.label(format!(
-                "{} time zones found",
-                picker_state.search_results.len()
+            ui.label(tr_args(
+                "{0} time zones found",
+                &[&picker_state.search_results.len()],
             ));
 
             egui::ScrollArea::vertical()
                 .max_height(300.0)
                 .show(ui, |ui| {
+                    let mut previous = None;
                     for &tz in &picker_state.search_results {
+                        if let Some(region) = region_change(previous, tz) {
+                            region_heading(ui, region);
+                        }
+                        previous = Some(tz);
                         let is_current = tz == current_tz;
                         let is_favorite = favorites.contains(&tz);
 
@@ -171,11 +248,22 @@ pub fn draw_timezone_picker(
                                 result.toggle_favorite = Some(tz);
                             }
 
+                            // Lane button
+                            let can_add_lane = tz != current_tz
+                                && !lane_zones.contains(&tz)
+                                && lane_zones.len() < MAX_EXTRA_LANES;
+                            if ui
+                                .add_enabled(can_add_lane, egui::Button::new(tr("+ Lane")))
+                                .clicked()
+                            {
+                                result.add_lane = Some(tz);
+                            }
+
                             // Timezone name
                             let label = if is_current {
-                                format!("{} ◀", tz.name())
+                                format!("{} ◀", zone_display_name(tz))
                             } else {
-                                tz.name().to_string()
+                                zone_display_name(tz)
                             };
                             if ui.selectable_label(is_current, &label).clicked() {
                                 result.selected_tz = Some(tz);
@@ -188,7 +276,7 @@ pub fn draw_timezone_picker(
             ui.separator();
 
             // Close button
-            if ui.button("Close").clicked() {
+            if ui.button(tr("Close")).clicked() {
                 result.close_picker = true;
             }
         });
@@ -202,15 +290,32 @@ pub fn draw_timezone_picker(
 }
 
 /// Draw the scrub controls panel
+#[allow(clippy::too_many_arguments)]
 pub fn draw_scrub_controls(
     ctx: &egui::Context,
     is_scrub_mode: bool,
     current_zoom_index: usize,
-    reduced_motion: &mut bool,
+    motion_preference: &mut MotionPreference,
+    system_reduced_motion: bool,
+    snap_scrub: &mut bool,
+    calendar_labels: &mut CalendarLabels,
+    quiet_hours: &mut QuietHours,
+    window_title: &mut WindowTitleClock,
+    clip_settings: &mut ClipSettings,
+    language: &mut LanguageSetting,
+    safe_palette: &mut SafePaletteSetting,
+    follow_system_zone: &mut FollowSystemZone,
+    reveal_hotkey: &mut RevealHotkey,
+    reveal_status: Option<&str>,
+    own_favorites: &mut bool,
+    keymap: &Keymap,
+    themes: &ThemeLibrary,
+    theme: Option<&str>,
 ) -> ScrubControlResult {
     let mut result = ScrubControlResult::default();
 
-    egui::Window::new("Controls")
+    egui::Window::new(tr("Controls"))
+        .id(egui::Id::new("controls"))
         .collapsible(true)
         .resizable(false)
         .default_width(200.0)
@@ -221,7 +326,7 @@ pub fn draw_scrub_controls(
                 if ui
                     .add(
                         egui::Button::new(
-                            egui::RichText::new("⏱ Return to Now")
+                            egui::RichText::new(tr("⏱ Return to Now"))
                                 .color(egui::Color32::from_rgb(40, 30, 20)),
                         )
                         .fill(egui::Color32::from_rgb(255, 179, 71)),
@@ -234,7 +339,7 @@ pub fn draw_scrub_controls(
             }
 
             // Time step controls
-            ui.label("Step Time:");
+            ui.label(tr("Step Time:"));
             ui.horizontal(|ui| {
                 if ui.button("−1h").clicked() {
                     result.step_time = Some(-3600);
@@ -253,7 +358,7 @@ pub fn draw_scrub_controls(
             ui.separator();
 
             // Zoom controls
-            ui.label("Zoom:");
+            ui.label(tr("Zoom:"));
             ui.horizontal(|ui| {
                 let can_zoom_in = current_zoom_index > 0;
                 let can_zoom_out = current_zoom_index < ZOOM_LEVELS.len() - 1;
@@ -261,19 +366,207 @@ pub fn draw_scrub_controls(
                 if ui.add_enabled(can_zoom_in, egui::Button::new("🔍+")).clicked() {
                     result.zoom_in = true;
                 }
-                ui.label(format!("{:.0} sec/px", ZOOM_LEVELS[current_zoom_index]));
+                ui.label(format_zoom(ZOOM_LEVELS[current_zoom_index]));
                 if ui.add_enabled(can_zoom_out, egui::Button::new("🔍−")).clicked() {
                     result.zoom_out = true;
                 }
             });
 
+            // Where a dragged scrub comes to rest
+            let snap = match ZOOM_LEVELS[current_zoom_index] {
+                z if z >= MACRO_ZOOM => "Snap to nearest day",
+                z if z >= 60.0 => "Snap to nearest hour",
+                _ => "Snap to nearest minute",
+            };
+            if ui.checkbox(snap_scrub, tr(snap)).changed() {
+                result.snap_scrub_changed = true;
+            }
+
+            // Calendar details under the date ticks, shown while days are labeled
+            if ui
+                .checkbox(&mut calendar_labels.week_numbers, tr("ISO week numbers"))
+                .on_hover_text(tr("Shown under date ticks when zoomed in to days"))
+                .changed()
+            {
+                result.calendar_labels_changed = true;
+            }
+            if ui
+                .checkbox(&mut calendar_labels.day_of_year, tr("Day of year"))
+                .on_hover_text(tr("Shown under date ticks when zoomed in to days"))
+                .changed()
+            {
+                result.calendar_labels_changed = true;
+            }
+
             ui.separator();
 
             // Reduced motion toggle
-            if ui.checkbox(reduced_motion, "Reduced Motion").changed() {
-                result.reduced_motion_changed = true;
+            result.reduced_motion_changed =
+                reduced_motion_settings(ui, motion_preference, system_reduced_motion);
+            ui.label(tr("Disables warp effect and drag momentum"));
+
+            ui.separator();
+            result.quiet_hours_changed = quiet_hours_settings(ui, quiet_hours);
+            result.window_title_changed = window_title_settings(ui, window_title);
+            result.clip_settings_changed = clip_recording_settings(ui, clip_settings);
+            result.language_changed = language_settings(ui, language);
+            result.system_zone_changed = system_zone_settings(ui, follow_system_zone);
+            result.reveal_hotkey_changed =
+                reveal_hotkey_settings(ui, reveal_hotkey, reveal_status);
+            result.favorites_source_changed = favorites_settings(ui, own_favorites);
+            result.keymap_edit = keymap_settings(ui, keymap);
+
+            ui.separator();
+            result.set_theme = theme_selector(ui, themes, theme);
+            result.safe_palette_changed = safe_palette_settings(ui, safe_palette);
+        });
+
+    result
+}
+
+/// Draw the bookmarks sidebar for the displayed zone
+pub fn draw_bookmarks_panel(
+    ctx: &egui::Context,
+    state: &mut BookmarkPanelState,
+    tz: Tz,
+    bookmarks: &[Bookmark],
+) -> BookmarkResult {
+    let mut result = BookmarkResult::default();
+
+    egui::Window::new(tr("Bookmarks"))
+        .id(egui::Id::new("bookmarks"))
+        .collapsible(true)
+        .resizable(false)
+        .default_width(240.0)
+        .anchor(egui::Align2::LEFT_TOP, [10.0, 50.0])
+        .show(ctx, |ui| {
+            ui.label(egui::RichText::new(tz.name()).size(11.0).weak());
+
+            if bookmarks.is_empty() {
+                ui.label(tr("No bookmarks in this zone yet."));
             }
-            ui.label("Disables warp effect");
+            egui::ScrollArea::vertical()
+                .max_height(220.0)
+                .show(ui, |ui| {
+                    for (index, bookmark) in bookmarks.iter().enumerate() {
+                        ui.horizontal(|ui| {
+                            if ui.small_button("⏵").on_hover_text(tr("Jump to")).clicked() {
+                                result.jump_to = Some(bookmark.instant);
+                            }
+                            if ui.small_button("✕").on_hover_text(tr("Remove")).clicked() {
+                                result.remove = Some(index);
+                            }
+                            ui.vertical(|ui| {
+                                ui.label(&bookmark.label);
+                                ui.label(
+                                    egui::RichText::new(format_cursor_datetime(
+                                        bookmark.instant,
+                                        tz,
+                                    ))
+                                    .size(10.0)
+                                    .weak(),
+                                );
+                            });
+                        });
+                    }
+                });
+
+            ui.separator();
+
+            // Add at the cursor
+            ui.horizontal(|ui| {
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut state.new_label)
+                        .hint_text(tr("Label"))
+                        .desired_width(140.0),
+                );
+                let submitted =
+                    response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                let has_label = !state.new_label.trim().is_empty();
+                let clicked = ui
+                    .add_enabled(has_label, egui::Button::new(tr("Add at cursor")))
+                    .clicked();
+                if has_label && (clicked || submitted) {
+                    result.add_label = Some(std::mem::take(&mut state.new_label));
+                }
+            });
+
+            ui.separator();
+
+            // CSV import
+            ui.label(tr("Import CSV (label,instant):"));
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut state.import_path)
+                        .hint_text("path/to/markers.csv")
+                        .desired_width(140.0),
+                );
+                let has_path = !state.import_path.trim().is_empty();
+                if ui.add_enabled(has_path, egui::Button::new(tr("Import"))).clicked() {
+                    result.import_path = Some(state.import_path.trim().to_string());
+                }
+            });
+            match &state.import_status {
+                Some(Ok(message)) => {
+                    ui.label(egui::RichText::new(message).size(10.0));
+                }
+                Some(Err(error)) => {
+                    ui.label(
+                        egui::RichText::new(error)
+                            .size(10.0)
+                            .color(egui::Color32::from_rgb(255, 100, 100)),
+                    );
+                }
+                None => {}
+            }
+        });
+
+    result
+}
+
+/// Draw the measurement panel for a selected range of the ribbon
+pub fn draw_selection_panel(ctx: &egui::Context, range: &TimeRange, tz: Tz) -> SelectionResult {
+    let mut result = SelectionResult::default();
+
+    egui::Window::new(tr("Selection"))
+        .id(egui::Id::new("selection"))
+        .collapsible(true)
+        .resizable(false)
+        .default_width(260.0)
+        .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -60.0])
+        .show(ctx, |ui| {
+            egui::Grid::new("selection_grid")
+                .num_columns(2)
+                .spacing([12.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label(tr("From:"));
+                    ui.label(format_cursor_datetime(range.start, tz));
+                    ui.end_row();
+                    ui.label(tr("To:"));
+                    ui.label(format_cursor_datetime(range.end, tz));
+                    ui.end_row();
+                    ui.label(tr("Elapsed:"));
+                    ui.strong(format_duration(range.absolute()));
+                    ui.end_row();
+                    ui.label(tr("Wall clock:"));
+                    ui.strong(format_duration(range.wall_clock(tz)));
+                    ui.end_row();
+                });
+
+            if let Some(note) = range.dst_note(tz) {
+                ui.colored_label(egui::Color32::from_rgb(255, 107, 53), note);
+            }
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui.button(tr("📋 Copy")).clicked() {
+                    ui.output_mut(|o| o.copied_text = range.describe(tz));
+                    result.copied = true;
+                }
+                if ui.button(tr("Clear")).clicked() {
+                    result.clear = true;
+                }
+            });
         });
 
     result
@@ -281,7 +574,8 @@ pub fn draw_scrub_controls(
 
 /// Draw the DST status panel (shown when DST transition is in viewport)
 pub fn draw_dst_status(ctx: &egui::Context, time_data: &TimeData) {
-    egui::Window::new("DST Status")
+    egui::Window::new(tr("DST Status"))
+        .id(egui::Id::new("dst_status"))
         .collapsible(true)
         .resizable(false)
         .default_width(250.0)
@@ -289,14 +583,14 @@ pub fn draw_dst_status(ctx: &egui::Context, time_data: &TimeData) {
         .show(ctx, |ui| {
             // Current DST status
             ui.horizontal(|ui| {
-                ui.label("Status:");
+                ui.label(tr("Status:"));
                 if time_data.is_dst {
                     ui.colored_label(
                         egui::Color32::from_rgb(255, 179, 71),
-                        "Daylight Saving Time",
+                        tr("Daylight Saving Time"),
                     );
                 } else {
-                    ui.label("Standard Time");
+                    ui.label(tr("Standard Time"));
                 }
             });
 
@@ -305,47 +599,41 @@ pub fn draw_dst_status(ctx: &egui::Context, time_data: &TimeData) {
             // DST transition info
             match &time_data.dst_change {
                 DstChange::None => {
-                    ui.label("No DST transitions within 24 hours.");
+                    ui.label(tr("No DST transitions within 24 hours."));
                 }
                 DstChange::Upcoming {
                     instant,
                     delta_minutes,
                 } => {
-                    let direction = if *delta_minutes > 0 {
-                        "forward"
+                    let change = if *delta_minutes > 0 {
+                        "Clocks will move forward by {0} minutes"
                     } else {
-                        "back"
+                        "Clocks will move back by {0} minutes"
                     };
                     ui.colored_label(
                         egui::Color32::from_rgb(255, 107, 53),
-                        "⚠ Upcoming DST Change",
+                        tr("⚠ Upcoming DST Change"),
                     );
-                    ui.label(format!(
-                        "Clocks will move {} by {} minutes",
-                        direction,
-                        delta_minutes.abs()
-                    ));
-                    ui.label(format!("At: {}", instant.format("%Y-%m-%d %H:%M UTC")));
+                    ui.label(tr_args(change, &[&delta_minutes.abs()]));
+                    let at = instant.format("%Y-%m-%d %H:%M UTC");
+                    ui.label(tr_args("At: {0}", &[&at]));
                 }
                 DstChange::JustOccurred {
                     instant,
                     delta_minutes,
                 } => {
-                    let direction = if *delta_minutes > 0 {
-                        "forward"
+                    let change = if *delta_minutes > 0 {
+                        "Clocks moved forward by {0} minutes"
                     } else {
-                        "back"
+                        "Clocks moved back by {0} minutes"
                     };
                     ui.colored_label(
                         egui::Color32::from_rgb(255, 179, 71),
-                        "ℹ Recent DST Change",
+                        tr("ℹ Recent DST Change"),
                     );
-                    ui.label(format!(
-                        "Clocks moved {} by {} minutes",
-                        direction,
-                        delta_minutes.abs()
-                    ));
-                    ui.label(format!("At: {}", instant.format("%Y-%m-%d %H:%M UTC")));
+                    ui.label(tr_args(change, &[&delta_minutes.abs()]));
+                    let at = instant.format("%Y-%m-%d %H:%M UTC");
+                    ui.label(tr_args("At: {0}", &[&at]));
                 }
             }
         });
@@ -360,16 +648,16 @@ pub fn draw_timezone_bar(ctx: &egui::Context, time_data: &TimeData) -> bool {
         .show(ctx, |ui| {
             ui.horizontal(|ui| {
                 let tz_text = format!(
-                    "{} ({}) · {} · DST {}",
+                    "{} ({}) · {} · {}",
                     time_data.local_datetime.timezone().name(),
                     time_data.tz_abbrev,
                     time_data.format_utc_offset(),
-                    if time_data.is_dst { "On" } else { "Off" }
+                    if time_data.is_dst { tr("DST on") } else { tr("DST off") }
                 );
 
                 if ui
                     .add(egui::Label::new(&tz_text).sense(egui::Sense::click()))
-                    .on_hover_text("Click to change time zone")
+                    .on_hover_text(tr("Click to change time zone"))
                     .clicked()
                 {
                     clicked = true;
@@ -380,17 +668,17 @@ pub fn draw_timezone_bar(ctx: &egui::Context, time_data: &TimeData) -> bool {
                     match &time_data.dst_change {
                         DstChange::None => {}
                         DstChange::Upcoming { delta_minutes, .. } => {
-                            let sign = if *delta_minutes > 0 { "+" } else { "" };
+                            let delta = format!("{:+}", delta_minutes);
                             ui.colored_label(
                                 egui::Color32::from_rgb(255, 107, 53),
-                                format!("⚠ DST {}{}m soon", sign, delta_minutes),
+                                tr_args("⚠ DST {0}m soon", &[&delta]),
                             );
                         }
                         DstChange::JustOccurred { delta_minutes, .. } => {
-                            let sign = if *delta_minutes > 0 { "+" } else { "" };
+                            let delta = format!("{:+}", delta_minutes);
                             ui.colored_label(
                                 egui::Color32::from_rgb(255, 179, 71),
-                                format!("DST {}{}m occurred", sign, delta_minutes),
+                                tr_args("DST {0}m occurred", &[&delta]),
                             );
                         }
                     }
@@ -401,6 +689,14 @@ pub fn draw_timezone_bar(ctx: &egui::Context, time_data: &TimeData) -> bool {
     clicked
 }
 
+/// Explain a flagged UTC offset beside the pointer
+pub fn draw_offset_tooltip(ctx: &egui::Context, explanation: &str) {
+    egui::show_tooltip_at_pointer(ctx, egui::Id::new("offset_tooltip"), |ui| {
+        ui.set_max_width(280.0);
+        ui.label(explanation);
+    });
+}
+
 /// Draw a toast notification that auto-dismisses
 pub fn draw_toast(ctx: &egui::Context, message: &str, elapsed_secs: f32) {
     // Fade out during last 0.5 seconds
@@ -433,3 +729,56 @@ pub fn draw_toast(ctx: &egui::Context, message: &str, elapsed_secs: f32) {
         });
 }
 
+
+/// Restyle egui's chrome for the active theme
+///
+/// Leaves `visuals` alone while the clock uses its own colors.
+pub fn theme_visuals(visuals: &mut egui::Visuals) {
+    let Some(palette) = active_palette() else {
+        return;
+    };
+    let color = |c: Rgb| egui::Color32::from_rgb(c.red, c.green, c.blue);
+    *visuals = if palette.is_light() {
+        egui::Visuals::light()
+    } else {
+        egui::Visuals::dark()
+    };
+    visuals.panel_fill = color(palette.background);
+    visuals.window_fill = color(palette.background);
+    visuals.override_text_color = Some(color(palette.primary_text));
+    visuals.hyperlink_color = color(palette.accent);
+    visuals.warn_fg_color = color(palette.warning);
+}
+
+/// Draw the theme switcher
+///
+/// Returns the newly chosen theme, where `Some(None)` means the clock's own
+/// colors.
+pub fn theme_selector(
+    ui: &mut egui::Ui,
+    themes: &ThemeLibrary,
+    selected: Option<&str>,
+) -> Option<Option<String>> {
+    let mut choice = None;
+    egui::ComboBox::from_label(tr("Theme"))
+        .selected_text(selected.unwrap_or(tr("Clock colors")))
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(selected.is_none(), tr("Clock colors")).clicked() {
+                choice = Some(None);
+            }
+            for theme in &themes.themes {
+                let is_selected = selected == Some(theme.name.as_str());
+                if ui.selectable_label(is_selected, &theme.name).clicked() {
+                    choice = Some(Some(theme.name.clone()));
+                }
+            }
+        });
+    if let Some(error) = &themes.error {
+        ui.label(
+            egui::RichText::new(error)
+                .size(10.0)
+                .color(egui::Color32::from_rgb(255, 100, 100)),
+        );
+    }
+    choice
+}
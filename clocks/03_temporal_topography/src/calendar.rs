@@ -0,0 +1,392 @@
+//! Calendar module - ICS import and per-day busyness profiles
+//!
+//! Parses the VEVENT blocks of an iCalendar (.ics) file into UTC intervals and
+//! folds them into a busyness profile over the local day, which the terrain
+//! uses in "calendar" mode: meetings raise elevation, free time forms valleys.
+//!
+//! Recurrence rules (RRULE) are not expanded; only concrete event instances
+//! are imported.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::terrain::DayDomain;
+
+/// Number of buckets the day is divided into (15 minutes each on a normal day)
+pub const BUSYNESS_BUCKETS: usize = 96;
+
+/// Overlapping events beyond this count don't raise the terrain further
+const MAX_STACKED_EVENTS: f32 = 2.0;
+
+/// Error type for calendar import
+#[derive(Debug)]
+pub enum CalendarError {
+    /// IO error while reading the file
+    Io(io::Error),
+    /// The file contained no usable events
+    NoEvents,
+}
+
+impl fmt::Display for CalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalendarError::Io(e) => write!(f, "IO error: {}", e),
+            CalendarError::NoEvents => write!(f, "No events found in calendar"),
+        }
+    }
+}
+
+impl std::error::Error for CalendarError {}
+
+impl From<io::Error> for CalendarError {
+    fn from(e: io::Error) -> Self {
+        CalendarError::Io(e)
+    }
+}
+
+/// A single busy interval imported from the calendar
+#[derive(Debug, Clone, PartialEq)]
+#[allow(dead_code)]
+pub struct CalendarEvent {
+    pub summary: String,
+    pub start_utc: DateTime<Utc>,
+    pub end_utc: DateTime<Utc>,
+}
+
+/// Load and parse an ICS file
+///
+/// Floating times and all-day dates are interpreted in `local_tz`.
+pub fn load_ics(path: &Path, local_tz: Tz) -> Result<Vec<CalendarEvent>, CalendarError> {
+    let contents = fs::read_to_string(path)?;
+    let events = parse_ics(&contents, local_tz);
+    if events.is_empty() {
+        return Err(CalendarError::NoEvents);
+    }
+    Ok(events)
+}
+
+/// Parse the busy events out of ICS text
+///
+/// Cancelled events and events marked TRANSPARENT (free) are skipped.
+pub fn parse_ics(text: &str, local_tz: Tz) -> Vec<CalendarEvent> {
+    let mut events = Vec::new();
+    let mut current: Option<EventBuilder> = None;
+
+    for line in unfold_lines(text) {
+        let (name_and_params, value) = match line.split_once(':') {
+            Some(split) => split,
+            None => continue,
+        };
+        let mut parts = name_and_params.split(';');
+        let name = parts.next().unwrap_or("").to_ascii_uppercase();
+        let params: Vec<&str> = parts.collect();
+
+        if value.eq_ignore_ascii_case("VEVENT") {
+            if name == "BEGIN" {
+                current = Some(EventBuilder::default());
+            } else if name == "END" {
+                if let Some(event) = current.take().and_then(|b| b.build()) {
+                    events.push(event);
+                }
+            }
+            continue;
+        }
+
+        let builder = match current.as_mut() {
+            Some(builder) => builder,
+            None => continue,
+        };
+        match name.as_str() {
+            "SUMMARY" => builder.summary = value.to_string(),
+            "DTSTART" => builder.start = parse_ics_time(value, &params, local_tz),
+            "DTEND" => builder.end = parse_ics_time(value, &params, local_tz),
+            "DURATION" => builder.duration = parse_ics_duration(value),
+            "STATUS" if value.eq_ignore_ascii_case("CANCELLED") => builder.is_free = true,
+            "TRANSP" if value.eq_ignore_ascii_case("TRANSPARENT") => builder.is_free = true,
+            _ => {}
+        }
+    }
+
+    events.sort_by_key(|e| e.start_utc);
+    events
+}
+
+/// Accumulates the properties of one VEVENT
+#[derive(Default)]
+struct EventBuilder {
+    summary: String,
+    start: Option<IcsTime>,
+    end: Option<IcsTime>,
+    duration: Option<Duration>,
+    is_free: bool,
+}
+
+impl EventBuilder {
+    fn build(self) -> Option<CalendarEvent> {
+        if self.is_free {
+            return None;
+        }
+        let start = self.start?;
+        let end_utc = match (self.end, self.duration) {
+            (Some(end), _) => end.instant,
+            (None, Some(duration)) => start.instant + duration,
+            // All-day events without an end last one day
+            (None, None) if start.is_date => start.instant + Duration::days(1),
+            (None, None) => return None,
+        };
+        if end_utc <= start.instant {
+            return None;
+        }
+        Some(CalendarEvent {
+            summary: self.summary,
+            start_utc: start.instant,
+            end_utc,
+        })
+    }
+}
+
+/// A parsed DTSTART/DTEND value
+#[derive(Clone, Copy)]
+struct IcsTime {
+    instant: DateTime<Utc>,
+    /// VALUE=DATE (all-day) rather than a date-time
+    is_date: bool,
+}
+
+/// Join RFC 5545 folded lines (continuations start with a space or tab)
+fn unfold_lines(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in text.lines() {
+        if let (Some(continuation), Some(last)) = (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            last.push_str(continuation);
+            continue;
+        }
+        lines.push(raw.to_string());
+    }
+    lines
+}
+
+/// Parse a date or date-time value, honoring a TZID parameter when present
+fn parse_ics_time(value: &str, params: &[&str], local_tz: Tz) -> Option<IcsTime> {
+    let value = value.trim();
+    let tz = params
+        .iter()
+        .find_map(|p| p.strip_prefix("TZID="))
+        .and_then(|id| id.trim_matches('"').parse::<Tz>().ok())
+        .unwrap_or(local_tz);
+
+    if let Some(utc_value) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc_value, "%Y%m%dT%H%M%S").ok()?;
+        return Some(IcsTime {
+            instant: Utc.from_utc_datetime(&naive),
+            is_date: false,
+        });
+    }
+
+    let (naive, is_date) = match NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S") {
+        Ok(naive) => (naive, false),
+        Err(_) => {
+            let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+            (date.and_time(NaiveTime::from_hms_opt(0, 0, 0).unwrap()), true)
+        }
+    };
+
+    // Nonexistent local times (DST gap) resolve to the instant just after the gap
+    let local = tz
+        .from_local_datetime(&naive)
+        .earliest()
+        .or_else(|| tz.from_local_datetime(&(naive + Duration::hours(1))).earliest())?;
+
+    Some(IcsTime {
+        instant: local.with_timezone(&Utc),
+        is_date,
+    })
+}
+
+/// Parse an RFC 5545 duration such as "PT1H30M", "P1D" or "P2W"
+fn parse_ics_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let body = value.strip_prefix('P')?;
+
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    for c in body.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' => {}
+            'W' | 'D' | 'H' | 'M' | 'S' => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total += match c {
+                        'W' => Duration::weeks(n),
+                        'D' => Duration::days(n),
+                        'H' => Duration::hours(n),
+                        'M' => Duration::minutes(n),
+                        _ => Duration::seconds(n),
+                    };
+            }
+            _ => return None,
+        }
+    }
+
+    Some(if negative { -total } else { total })
+}
+
+/// How busy each part of one local day is, in [0..1]
+#[derive(Debug, Clone)]
+pub struct BusynessProfile {
+    buckets: Vec<f32>,
+}
+
+impl BusynessProfile {
+    /// Fold events into buckets spanning the day domain
+    pub fn compute(events: &[CalendarEvent], day_domain: &DayDomain) -> Self {
+        let day_start = day_domain.midnight_utc;
+        let day_end = day_domain.next_midnight_utc;
+        let bucket_seconds = day_domain.day_length_seconds as f32 / BUSYNESS_BUCKETS as f32;
+        let mut buckets = vec![0.0f32; BUSYNESS_BUCKETS];
+
+        for event in events {
+            let start = event.start_utc.max(day_start);
+            let end = event.end_utc.min(day_end);
+            if end <= start {
+                continue;
+            }
+
+            let start_s = (start - day_start).num_seconds() as f32;
+            let end_s = (end - day_start).num_seconds() as f32;
+            let first = (start_s / bucket_seconds) as usize;
+            let last = ((end_s / bucket_seconds).ceil() as usize).min(BUSYNESS_BUCKETS);
+
+            // Add the covered fraction of each bucket so stacked events add up
+            for (i, bucket) in buckets.iter_mut().enumerate().take(last).skip(first) {
+                let bucket_start = i as f32 * bucket_seconds;
+                let bucket_end = bucket_start + bucket_seconds;
+                let covered = end_s.min(bucket_end) - start_s.max(bucket_start);
+                *bucket += covered.max(0.0) / bucket_seconds;
+            }
+        }
+
+        for bucket in &mut buckets {
+            *bucket = (*bucket / MAX_STACKED_EVENTS).min(1.0);
+        }
+
+        Self { buckets }
+    }
+
+    /// Busyness at a normalized day position, interpolated between bucket centers
+    pub fn sample(&self, p: f32) -> f32 {
+        let x = (p.clamp(0.0, 1.0) * BUSYNESS_BUCKETS as f32 - 0.5).max(0.0);
+        let i = (x as usize).min(BUSYNESS_BUCKETS - 1);
+        let j = (i + 1).min(BUSYNESS_BUCKETS - 1);
+        let t = x - i as f32;
+        self.buckets[i] + (self.buckets[j] - self.buckets[i]) * t
+    }
+}
+
+/// Imported events plus busyness profiles cached per local day
+pub struct CalendarCache {
+    events: Vec<CalendarEvent>,
+    /// Keyed by the day's local midnight, which identifies both day and zone
+    profiles: HashMap<DateTime<Utc>, Arc<BusynessProfile>>,
+}
+
+impl CalendarCache {
+    pub fn new(events: Vec<CalendarEvent>) -> Self {
+        Self {
+            events,
+            profiles: HashMap::new(),
+        }
+    }
+
+    /// Number of imported events
+    pub fn event_count(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Busyness profile for a day, computed on first use
+    pub fn profile_for(&mut self, day_domain: &DayDomain) -> Arc<BusynessProfile> {
+        let events = &self.events;
+        self.profiles
+            .entry(day_domain.midnight_utc)
+            .or_insert_with(|| Arc::new(BusynessProfile::compute(events, day_domain)))
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_ICS: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+SUMMARY:Standup\r\n\
+DTSTART;TZID=America/New_York:20250115T090000\r\n\
+DURATION:PT30M\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+SUMMARY:Planning with a very long title that gets\r\n  \
+folded\r\n\
+DTSTART:20250115T180000Z\r\n\
+DTEND:20250115T200000Z\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+SUMMARY:Focus block\r\n\
+TRANSP:TRANSPARENT\r\n\
+DTSTART:20250115T210000Z\r\n\
+DTEND:20250115T220000Z\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    #[test]
+    fn test_parse_ics_events() {
+        let utc: Tz = "UTC".parse().unwrap();
+        let events = parse_ics(SAMPLE_ICS, utc);
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].summary, "Standup");
+        assert_eq!(events[0].start_utc, Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 0).unwrap());
+        assert_eq!(events[0].end_utc, Utc.with_ymd_and_hms(2025, 1, 15, 14, 30, 0).unwrap());
+        assert_eq!(events[1].summary, "Planning with a very long title that gets folded");
+    }
+
+    #[test]
+    fn test_all_day_event_uses_local_zone() {
+        let tokyo: Tz = "Asia/Tokyo".parse().unwrap();
+        let ics = "BEGIN:VEVENT\nDTSTART;VALUE=DATE:20250115\nEND:VEVENT\n";
+        let events = parse_ics(ics, tokyo);
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].start_utc, Utc.with_ymd_and_hms(2025, 1, 14, 15, 0, 0).unwrap());
+        assert_eq!((events[0].end_utc - events[0].start_utc).num_hours(), 24);
+    }
+
+    #[test]
+    fn test_busyness_profile_and_cache() {
+        let utc: Tz = "UTC".parse().unwrap();
+        let events = parse_ics(SAMPLE_ICS, utc);
+        let day = DayDomain::compute(Utc.with_ymd_and_hms(2025, 1, 15, 12, 0, 0).unwrap(), utc);
+
+        let profile = BusynessProfile::compute(&events, &day);
+        // 19:00 UTC is inside the planning meeting, 03:00 is free
+        assert!(profile.sample(19.0 / 24.0) > 0.4);
+        assert_eq!(profile.sample(3.0 / 24.0), 0.0);
+
+        let mut cache = CalendarCache::new(events);
+        let first = cache.profile_for(&day);
+        let second = cache.profile_for(&day);
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+}
@@ -0,0 +1,138 @@
+//! Contours module - iso-intensity levels over the terrain profile
+//!
+//! Terrain elevation [-1..1] reads as "temporal intensity" from 0% (deepest
+//! valley) to 100% (highest peak). The range is split into equal bands; the
+//! boundaries between them are the contour levels drawn across the terrain
+//! wherever it rises above them.
+
+/// A band of temporal intensity between two contour levels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntensityBand {
+    /// Lower elevation bound
+    pub low: f32,
+    /// Upper elevation bound
+    pub high: f32,
+    pub name: &'static str,
+}
+
+/// Bands from lowest to highest intensity
+pub const BANDS: [IntensityBand; 5] = [
+    IntensityBand { low: -1.0, high: -0.6, name: "Still" },
+    IntensityBand { low: -0.6, high: -0.2, name: "Calm" },
+    IntensityBand { low: -0.2, high: 0.2, name: "Steady" },
+    IntensityBand { low: 0.2, high: 0.6, name: "Busy" },
+    IntensityBand { low: 0.6, high: 1.0, name: "Peak" },
+];
+
+impl IntensityBand {
+    /// Elevation halfway through the band
+    pub fn mid(&self) -> f32 {
+        (self.low + self.high) / 2.0
+    }
+
+    /// "40–60%"
+    pub fn range_label(&self) -> String {
+        format!(
+            "{:.0}–{}",
+            intensity(self.low) * 100.0,
+            format_intensity(self.high)
+        )
+    }
+}
+
+/// Elevations of the contour lines: every boundary between two bands
+pub fn contour_levels() -> impl Iterator<Item = f32> {
+    BANDS[1..].iter().map(|band| band.low)
+}
+
+/// Temporal intensity in [0..1] for an elevation in [-1..1]
+pub fn intensity(elevation: f32) -> f32 {
+    ((elevation + 1.0) / 2.0).clamp(0.0, 1.0)
+}
+
+/// "60%"
+pub fn format_intensity(elevation: f32) -> String {
+    format!("{:.0}%", intensity(elevation) * 100.0)
+}
+
+/// Horizontal spans where the terrain is at or above `level`
+///
+/// `samples` pairs each position [0..1] with its elevation, or `None` inside
+/// a DST gap, which ends a span. Span ends are interpolated to where the
+/// profile crosses the level.
+pub fn contour_spans(samples: &[(f32, Option<f32>)], level: f32) -> Vec<(f32, f32)> {
+    let mut spans = Vec::new();
+    let mut start: Option<f32> = None;
+    let mut previous: Option<(f32, f32)> = None;
+
+    for &(p, elevation) in samples {
+        let Some(e) = elevation else {
+            if let (Some(s), Some((prev_p, _))) = (start.take(), previous) {
+                spans.push((s, prev_p));
+            }
+            previous = None;
+            continue;
+        };
+
+        let above = e >= level;
+        match previous {
+            Some((prev_p, prev_e)) if (prev_e >= level) != above => {
+                let crossing = prev_p + (p - prev_p) * (level - prev_e) / (e - prev_e);
+                if above {
+                    start = Some(crossing);
+                } else if let Some(s) = start.take() {
+                    spans.push((s, crossing));
+                }
+            }
+            None if above => start = Some(p),
+            _ => {}
+        }
+        previous = Some((p, e));
+    }
+
+    if let (Some(s), Some((last_p, _))) = (start, previous) {
+        spans.push((s, last_p));
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bands_cover_the_range() {
+        assert_eq!(BANDS[0].low, -1.0);
+        assert_eq!(BANDS[BANDS.len() - 1].high, 1.0);
+        assert!(BANDS.windows(2).all(|pair| pair[0].high == pair[1].low));
+        assert_eq!(contour_levels().collect::<Vec<_>>(), vec![-0.6, -0.2, 0.2, 0.6]);
+        assert_eq!(BANDS[3].range_label(), "60–80%");
+        assert_eq!(format_intensity(-1.0), "0%");
+        assert_eq!(format_intensity(0.0), "50%");
+    }
+
+    #[test]
+    fn test_spans_interpolate_crossings() {
+        // A single hill peaking at 0.5 in the middle
+        let samples = [(0.0, Some(-0.5)), (0.5, Some(0.5)), (1.0, Some(-0.5))];
+        let spans = contour_spans(&samples, 0.0);
+        assert_eq!(spans.len(), 1);
+        assert!((spans[0].0 - 0.25).abs() < 1e-6);
+        assert!((spans[0].1 - 0.75).abs() < 1e-6);
+
+        assert!(contour_spans(&samples, 0.6).is_empty());
+        assert_eq!(contour_spans(&samples, -0.6), vec![(0.0, 1.0)]);
+    }
+
+    #[test]
+    fn test_gaps_break_spans() {
+        let samples = [
+            (0.0, Some(0.4)),
+            (0.1, Some(0.4)),
+            (0.2, None),
+            (0.3, Some(0.4)),
+            (0.4, Some(0.4)),
+        ];
+        assert_eq!(contour_spans(&samples, 0.2), vec![(0.0, 0.1), (0.3, 0.4)]);
+    }
+}
@@ -3,13 +3,18 @@
 //! Renders the topographic day map with its cartographic aesthetic.
 
 use nannou::prelude::*;
+use shared::{tr, CommandRegistry};
 
-use crate::terrain::{DayDomain, HourBoundary, TerrainParams, terrain_elevation};
+use crate::contours::{contour_levels, contour_spans, format_intensity, BANDS};
+use crate::relief::{project, ReliefGrid};
+use crate::terrain::{ComparisonTerrain, DayDomain, HourBoundary, TerrainParams, terrain_elevation};
+use crate::waypoints::Waypoint;
 
 /// Color palette for the temporal topography theme - cartographic/topographic aesthetic
 #[allow(dead_code)]
 pub mod colors {
     use nannou::prelude::*;
+    use shared::{Rgb, ThemeRole};
 
     /// Deep slate background (paper tone)
     pub const BACKGROUND: Srgb<u8> = Srgb {
@@ -91,6 +96,22 @@ pub mod colors {
         standard: std::marker::PhantomData,
     };
 
+    /// Waypoint markers - pale violet, distinct from the inspect cursor
+    pub const WAYPOINT: Srgb<u8> = Srgb {
+        red: 190,
+        green: 160,
+        blue: 230,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Comparison zone's terrain overlay - teal, apart from the elevation ramp
+    pub const COMPARISON: Srgb<u8> = Srgb {
+        red: 80,
+        green: 210,
+        blue: 190,
+        standard: std::marker::PhantomData,
+    };
+
     /// DST fault line - warning orange
     pub const DST_FAULT: Srgb<u8> = Srgb {
         red: 255,
@@ -122,6 +143,32 @@ pub mod colors {
         blue: 220,
         standard: std::marker::PhantomData,
     };
+
+    /// Look up one of this clock's role colors in the active theme
+    fn themed(role: ThemeRole, native: Srgb<u8>) -> Srgb<u8> {
+        let color = shared::themed(role, Rgb::new(native.red, native.green, native.blue));
+        Srgb::new(color.red, color.green, color.blue)
+    }
+
+    pub fn background() -> Srgb<u8> {
+        themed(ThemeRole::Background, BACKGROUND)
+    }
+
+    pub fn text_primary() -> Srgb<u8> {
+        themed(ThemeRole::PrimaryText, TEXT_PRIMARY)
+    }
+
+    pub fn text_secondary() -> Srgb<u8> {
+        themed(ThemeRole::SecondaryText, TEXT_SECONDARY)
+    }
+
+    pub fn beacon() -> Srgb<u8> {
+        themed(ThemeRole::Accent, BEACON)
+    }
+
+    pub fn dst_fault() -> Srgb<u8> {
+        themed(ThemeRole::Warning, DST_FAULT)
+    }
 }
 
 /// Layout configuration for the day map canvas
@@ -196,29 +243,50 @@ impl MapLayout {
     pub fn contains(&self, x: f32, y: f32) -> bool {
         x >= self.left && x <= self.right && y >= self.bottom && y <= self.top
     }
+
+    /// A horizontal slice of the canvas for one day panel
+    ///
+    /// Keeps the vertical mapping, so every panel shares one elevation scale.
+    pub fn panel(&self, left: f32, right: f32) -> Self {
+        let width = right - left;
+        Self {
+            left,
+            right,
+            width,
+            sample_count: (width as usize).max(60),
+            ..*self
+        }
+    }
 }
 
 /// Draw the complete day map visualization
+#[allow(clippy::too_many_arguments)]
 pub fn draw_day_map(
     draw: &Draw,
     layout: &MapLayout,
     params: &TerrainParams,
     day_domain: &DayDomain,
     hour_boundaries: &[HourBoundary],
+    show_contours: bool,
+    show_relief: bool,
     reduced_motion: bool,
     time_fraction: f32,
+    beacon: (f32, &str),
 ) {
     // Draw canvas background
     draw_canvas_background(draw, layout);
 
     // Draw grid layer (behind terrain)
-    draw_grid_layer(draw, layout, hour_boundaries, day_domain);
+    draw_grid_layer(draw, layout, hour_boundaries, day_domain, 1);
 
     // Draw DST fault lines (behind terrain but on top of grid)
     draw_fault_lines(draw, layout, day_domain);
 
     // Draw terrain layer
-    draw_terrain_layer(draw, layout, params, day_domain);
+    draw_terrain_layer(draw, layout, params, day_domain, show_relief);
+    if show_contours {
+        draw_contour_lines(draw, layout, params, day_domain);
+    }
 
     // Draw dual traces for any DST fall-back overlaps
     for fault in &day_domain.dst_faults {
@@ -231,7 +299,109 @@ pub fn draw_day_map(
     }
 
     // Draw locator beacon
-    draw_locator_beacon(draw, layout, params, day_domain, reduced_motion, time_fraction);
+    let (beacon_position, beacon_label) = beacon;
+    draw_locator_beacon(
+        draw,
+        layout,
+        params,
+        beacon_position,
+        beacon_label,
+        reduced_motion,
+        time_fraction,
+    );
+}
+
+/// One day of the week view, ready to draw
+pub struct DayPanel<'a> {
+    pub domain: &'a DayDomain,
+    pub params: &'a TerrainParams,
+    pub hour_boundaries: &'a [HourBoundary],
+    /// Header text, e.g. "Sun 9"
+    pub label: String,
+    /// Whether the panel holds the current instant (gets the beacon)
+    pub is_today: bool,
+}
+
+/// Draw several days as adjacent panels sharing one elevation scale
+///
+/// `panels` pairs each day with its horizontal span from [`WeekView::panels`].
+///
+/// [`WeekView::panels`]: crate::week::WeekView::panels
+pub fn draw_week_map(
+    draw: &Draw,
+    layout: &MapLayout,
+    panels: &[(DayPanel, f32, f32)],
+    show_contours: bool,
+    show_relief: bool,
+    reduced_motion: bool,
+    time_fraction: f32,
+) {
+    draw_canvas_background(draw, layout);
+
+    for (panel, left, right) in panels {
+        let panel_layout = layout.panel(*left, *right);
+
+        // Label every nth hour so labels keep ~40px apart
+        let hour_width = panel_layout.width / 24.0;
+        let label_step = [1, 2, 3, 6, 12]
+            .into_iter()
+            .find(|step| *step as f32 * hour_width >= 40.0)
+            .unwrap_or(24);
+
+        // The next panel draws this panel's closing midnight
+        let boundaries = match panel.hour_boundaries.last() {
+            Some(last) if last.is_next_day => {
+                &panel.hour_boundaries[..panel.hour_boundaries.len() - 1]
+            }
+            _ => panel.hour_boundaries,
+        };
+
+        draw_grid_layer(draw, &panel_layout, boundaries, panel.domain, label_step);
+        draw_fault_lines(draw, &panel_layout, panel.domain);
+        draw_terrain_layer(draw, &panel_layout, panel.params, panel.domain, show_relief);
+        if show_contours {
+            draw_contour_lines(draw, &panel_layout, panel.params, panel.domain);
+        }
+
+        for fault in &panel.domain.dst_faults {
+            if fault.delta_minutes < 0 {
+                let fault_x = panel_layout.position_to_x(fault.position);
+                let fault_width_px = fault.width * panel_layout.width;
+                draw_overlap_dual_traces(draw, &panel_layout, panel.params, fault_x, fault_width_px);
+            }
+        }
+
+        // Day header; DST days are called out so the fault stands out across the week
+        let header = if panel.domain.dst_faults.is_empty() {
+            panel.label.clone()
+        } else {
+            format!("{} · {}", panel.label, tr("DST"))
+        };
+        let header_color = if !panel.domain.dst_faults.is_empty() {
+            colors::dst_fault()
+        } else if panel.is_today {
+            colors::beacon()
+        } else {
+            colors::text_secondary()
+        };
+        draw.text(&header)
+            .x_y((left + right) / 2.0, layout.top + 42.0)
+            .color(header_color)
+            .font_size(11)
+            .w(panel_layout.width);
+
+        if panel.is_today {
+            draw_locator_beacon(
+                draw,
+                &panel_layout,
+                panel.params,
+                panel.domain.normalized_position,
+                tr("NOW"),
+                reduced_motion,
+                time_fraction,
+            );
+        }
+    }
 }
 
 /// Draw the canvas background
@@ -260,14 +430,19 @@ fn draw_canvas_background(draw: &Draw, layout: &MapLayout) {
 }
 
 /// Draw the grid layer with hour and 15-minute marks
+///
+/// `label_step` labels every nth hour; narrow week panels use a step above 1,
+/// which also drops the 15-minute marks.
 fn draw_grid_layer(
     draw: &Draw,
     layout: &MapLayout,
     hour_boundaries: &[HourBoundary],
     day_domain: &DayDomain,
+    label_step: usize,
 ) {
     // Draw 15-minute marks first (behind hour lines)
-    for i in 0..96 {
+    let quarter_marks = if label_step == 1 { 96 } else { 0 };
+    for i in 0..quarter_marks {
         let ssm = i * 900; // 15 minutes = 900 seconds
         if ssm > day_domain.day_length_seconds {
             break;
@@ -289,7 +464,7 @@ fn draw_grid_layer(
     }
 
     // Draw hour boundaries
-    for boundary in hour_boundaries {
+    for (i, boundary) in hour_boundaries.iter().enumerate() {
         let x = layout.position_to_x(boundary.position);
 
         let (color, weight) = if boundary.is_midnight {
@@ -304,6 +479,10 @@ fn draw_grid_layer(
             .color(color)
             .weight(weight);
 
+        if i % label_step != 0 {
+            continue;
+        }
+
         // Draw label below the grid
         let label_y = layout.bottom - 15.0;
         let label = if let Some(ref suffix) = boundary.suffix {
@@ -315,7 +494,7 @@ fn draw_grid_layer(
         let label_color = if boundary.is_midnight {
             colors::MIDNIGHT
         } else {
-            colors::TEXT_SECONDARY
+            colors::text_secondary()
         };
 
         draw.text(&label)
@@ -326,7 +505,7 @@ fn draw_grid_layer(
         
         // Draw "(next)" below for next day's midnight
         if boundary.is_next_day {
-            draw.text("(next)")
+            draw.text(tr("(next)"))
                 .x_y(x, label_y - 12.0)
                 .color(label_color)
                 .font_size(9)
@@ -348,7 +527,7 @@ fn draw_fault_lines(draw: &Draw, layout: &MapLayout, day_domain: &DayDomain) {
                 draw,
                 pt2(x, layout.top),
                 pt2(x, layout.bottom),
-                colors::DST_FAULT,
+                colors::dst_fault(),
                 2.0,
                 8.0,
                 4.0,
@@ -358,7 +537,7 @@ fn draw_fault_lines(draw: &Draw, layout: &MapLayout, day_domain: &DayDomain) {
                 draw,
                 pt2(x + fault_width_px, layout.top),
                 pt2(x + fault_width_px, layout.bottom),
-                colors::DST_FAULT,
+                colors::dst_fault(),
                 2.0,
                 8.0,
                 4.0,
@@ -371,9 +550,9 @@ fn draw_fault_lines(draw: &Draw, layout: &MapLayout, day_domain: &DayDomain) {
                 .color(srgba(255u8, 107u8, 53u8, 30u8));
 
             // Label
-            draw.text("GAP")
+            draw.text(tr("GAP"))
                 .x_y(x + fault_width_px / 2.0, layout.top + 15.0)
-                .color(colors::DST_FAULT)
+                .color(colors::dst_fault())
                 .font_size(10)
                 .w(60.0);
         } else {
@@ -382,7 +561,7 @@ fn draw_fault_lines(draw: &Draw, layout: &MapLayout, day_domain: &DayDomain) {
             draw.line()
                 .start(pt2(x, layout.top))
                 .end(pt2(x, layout.bottom))
-                .color(colors::DST_FAULT)
+                .color(colors::dst_fault())
                 .weight(2.0);
 
             // Fill overlap region with subtle overlay
@@ -395,20 +574,20 @@ fn draw_fault_lines(draw: &Draw, layout: &MapLayout, day_domain: &DayDomain) {
             if let Some(ref label_a) = fault.label_a {
                 draw.text(label_a)
                     .x_y(x + fault_width_px * 0.25, layout.top + 15.0)
-                    .color(colors::DST_FAULT)
+                    .color(colors::dst_fault())
                     .font_size(10)
                     .w(30.0);
             }
             if let Some(ref label_b) = fault.label_b {
                 draw.text(label_b)
                     .x_y(x + fault_width_px * 0.75, layout.top + 15.0)
-                    .color(colors::DST_FAULT)
+                    .color(colors::dst_fault())
                     .font_size(10)
                     .w(30.0);
             }
             
             // Draw label for repeated hour
-            draw.text("Repeated Hour")
+            draw.text(tr("Repeated Hour"))
                 .x_y(x + fault_width_px / 2.0, layout.bottom - 30.0)
                 .color(srgba(255u8, 179u8, 71u8, 180u8))
                 .font_size(9)
@@ -502,13 +681,18 @@ fn draw_dashed_line(
     }
 }
 
-/// Draw the terrain layer
+/// Draw the terrain layer, filled flat or as a receding relief
 fn draw_terrain_layer(
     draw: &Draw,
     layout: &MapLayout,
     params: &TerrainParams,
     day_domain: &DayDomain,
+    relief: bool,
 ) {
+    if relief {
+        draw_relief(draw, layout, params, day_domain);
+    }
+
     let sample_count = layout.sample_count;
     let mut points: Vec<Point2> = Vec::with_capacity(sample_count + 2);
     let mut fill_points: Vec<Point2> = Vec::with_capacity(sample_count + 2);
@@ -549,14 +733,66 @@ fn draw_terrain_layer(
     // Close fill polygon at bottom-right
     fill_points.push(pt2(layout.right, layout.bottom));
 
-    // Draw fill (under the terrain curve)
-    if fill_points.len() > 2 {
+    // Draw fill (under the terrain curve); the relief already covers it
+    if !relief && fill_points.len() > 2 {
         draw.polygon()
             .points(fill_points)
             .color(srgba(50u8, 58u8, 55u8, 100u8));
     }
 }
 
+/// Draw the terrain extruded into a lit ridge behind its front profile
+///
+/// Rows further back fade toward the canvas color so the crest reads as nearest.
+fn draw_relief(draw: &Draw, layout: &MapLayout, params: &TerrainParams, day_domain: &DayDomain) {
+    let grid = ReliefGrid::for_size(layout.width, layout.height);
+    let fronts: Vec<Option<f32>> = (0..grid.columns)
+        .map(|column| {
+            let p = grid.position(column);
+            (!day_domain.is_in_gap(p)).then(|| terrain_elevation(p, params))
+        })
+        .collect();
+    let vertices = grid.vertices(&fronts);
+
+    let vanishing = ((layout.left + layout.right) / 2.0, layout.top + layout.height * 0.5);
+    let fog = colors::CANVAS_BG.into_format::<f32>();
+    let points = vertices.iter().map(|vertex| {
+        let elevation = vertex.elevation.unwrap_or(-1.0);
+        let (x, y) = project(
+            layout.position_to_x(vertex.position),
+            layout.elevation_to_y(elevation),
+            vertex.depth,
+            vanishing,
+        );
+        let lit = elevation_color(elevation).into_format::<f32, f32>();
+        let haze = vertex.depth * 0.6;
+        let tone = |channel: f32, fog: f32| lerp(channel * vertex.shade, fog, haze);
+        let color = srgba(
+            tone(lit.red, fog.red),
+            tone(lit.green, fog.green),
+            tone(lit.blue, fog.blue),
+            1.0,
+        );
+        (pt3(x, y, 0.0), color)
+    });
+
+    // Two triangles per cell, skipping cells that touch a DST gap
+    let mut indices = Vec::new();
+    for row in 0..grid.rows - 1 {
+        for column in 0..grid.columns - 1 {
+            let i = row * grid.columns + column;
+            let corners = [i, i + 1, i + grid.columns, i + grid.columns + 1];
+            if corners.iter().any(|&corner| vertices[corner].elevation.is_none()) {
+                continue;
+            }
+            indices.extend_from_slice(&[corners[0], corners[1], corners[2]]);
+            indices.extend_from_slice(&[corners[1], corners[3], corners[2]]);
+        }
+    }
+
+    draw.mesh().indexed_colored(points, indices);
+}
+
 /// Draw a terrain segment with color gradient based on elevation
 fn draw_terrain_segment(draw: &Draw, points: &[Point2], layout: &MapLayout) {
     if points.len() < 2 {
@@ -583,8 +819,69 @@ fn draw_terrain_segment(draw: &Draw, points: &[Point2], layout: &MapLayout) {
     }
 }
 
+/// Draw iso-intensity contour lines wherever the terrain rises above them
+fn draw_contour_lines(
+    draw: &Draw,
+    layout: &MapLayout,
+    params: &TerrainParams,
+    day_domain: &DayDomain,
+) {
+    let sample_count = layout.sample_count;
+    let samples: Vec<(f32, Option<f32>)> = (0..sample_count)
+        .map(|i| {
+            let p = i as f32 / (sample_count - 1) as f32;
+            let elevation = (!day_domain.is_in_gap(p)).then(|| terrain_elevation(p, params));
+            (p, elevation)
+        })
+        .collect();
+
+    for level in contour_levels() {
+        let y = layout.elevation_to_y(level);
+        let mut color = elevation_color(level);
+        color.alpha = 150;
+        for (start, end) in contour_spans(&samples, level) {
+            draw.line()
+                .start(pt2(layout.position_to_x(start), y))
+                .end(pt2(layout.position_to_x(end), y))
+                .color(color)
+                .weight(1.0);
+        }
+    }
+}
+
+/// Draw the elevation scale in the left margin: a color bar split into
+/// intensity bands, labelled at each contour level
+pub fn draw_intensity_scale(draw: &Draw, layout: &MapLayout) {
+    let bar_x = layout.left - 12.0;
+    let bar_width = 6.0;
+
+    for band in &BANDS {
+        let low = layout.elevation_to_y(band.low);
+        let high = layout.elevation_to_y(band.high);
+        draw.rect()
+            .x_y(bar_x, (low + high) / 2.0)
+            .w_h(bar_width, high - low)
+            .color(elevation_color(band.mid()));
+    }
+
+    for level in contour_levels().chain([-1.0, 1.0]) {
+        let y = layout.elevation_to_y(level);
+        draw.line()
+            .start(pt2(bar_x - bar_width / 2.0 - 3.0, y))
+            .end(pt2(bar_x + bar_width / 2.0, y))
+            .color(colors::text_secondary())
+            .weight(1.0);
+        draw.text(&format_intensity(level))
+            .x_y(bar_x - 27.0, y)
+            .color(colors::text_secondary())
+            .font_size(8)
+            .right_justify()
+            .w(40.0);
+    }
+}
+
 /// Get color for a given normalized elevation [-1..1]
-fn elevation_color(e: f32) -> Srgba<u8> {
+pub fn elevation_color(e: f32) -> Srgba<u8> {
     if e > 0.0 {
         // Peak colors (brown/tan)
         let t = e.clamp(0.0, 1.0);
@@ -607,16 +904,16 @@ fn lerp(a: f32, b: f32, t: f32) -> f32 {
     a + (b - a) * t
 }
 
-/// Draw the locator beacon at the current time position
+/// Draw the locator beacon at `p`, labeled above the map
 fn draw_locator_beacon(
     draw: &Draw,
     layout: &MapLayout,
     params: &TerrainParams,
-    day_domain: &DayDomain,
+    p: f32,
+    label: &str,
     reduced_motion: bool,
     time_fraction: f32,
 ) {
-    let p = day_domain.normalized_position;
     let x = layout.position_to_x(p);
 
     // Get terrain height at this position - beacon follows the terrain line
@@ -635,14 +932,14 @@ fn draw_locator_beacon(
                 .x_y(x, y)
                 .w_h(beacon_size, beacon_size)
                 .no_fill()
-                .stroke(colors::BEACON)
+                .stroke(colors::beacon())
                 .stroke_weight(2.0);
         } else {
             // Draw filled
             draw.ellipse()
                 .x_y(x, y)
                 .w_h(beacon_size, beacon_size)
-                .color(colors::BEACON);
+                .color(colors::beacon());
         }
     } else {
         // Pulse animation
@@ -665,7 +962,7 @@ fn draw_locator_beacon(
         draw.ellipse()
             .x_y(x, y)
             .w_h(animated_size, animated_size)
-            .color(colors::BEACON);
+            .color(colors::beacon());
     }
 
     // Vertical line through beacon
@@ -676,11 +973,83 @@ fn draw_locator_beacon(
         .weight(1.0);
 
     // Label above
-    draw.text("NOW")
+    draw.text(label)
         .x_y(x, layout.top + 25.0)
-        .color(colors::BEACON)
+        .color(colors::beacon())
+        .font_size(12)
+        .w(80.0);
+}
+
+/// Trace the terrain swept so far by a day replay in the beacon's color
+pub fn draw_replay_sweep(
+    draw: &Draw,
+    layout: &MapLayout,
+    params: &TerrainParams,
+    day_domain: &DayDomain,
+    position: f32,
+) {
+    let sample_count = ((layout.sample_count as f32 * position) as usize).max(2);
+    let mut points: Vec<Point2> = Vec::with_capacity(sample_count);
+    let flush = |points: &mut Vec<Point2>| {
+        if points.len() > 1 {
+            draw.polyline()
+                .weight(4.0)
+                .points(points.drain(..))
+                .color(srgba(255u8, 179u8, 71u8, 110u8));
+        }
+        points.clear();
+    };
+
+    for i in 0..sample_count {
+        let p = position * i as f32 / (sample_count - 1) as f32;
+        if day_domain.is_in_gap(p) {
+            flush(&mut points);
+        } else {
+            let y = layout.elevation_to_y(terrain_elevation(p, params));
+            points.push(pt2(layout.position_to_x(p), y));
+        }
+    }
+    flush(&mut points);
+
+    // Wash over the swept part of the day
+    let swept = position * layout.width;
+    draw.rect()
+        .x_y(layout.left + swept / 2.0, layout.center_y)
+        .w_h(swept, layout.height)
+        .color(srgba(255u8, 179u8, 71u8, 14u8));
+}
+
+/// Draw the callout explaining the DST fault a day replay is paused at
+pub fn draw_replay_callout(draw: &Draw, layout: &MapLayout, position: f32, text: &str) {
+    let padding = 10.0;
+    let text_width = 300.0_f32.min(layout.width - padding * 2.0);
+    let lines = (text.chars().count() as f32 * 6.5 / text_width).ceil().max(1.0);
+    let text_height = lines * 17.0;
+    let box_width = text_width + padding * 2.0;
+    let box_height = text_height + padding * 2.0;
+
+    // Below the top of the map, beside the fault and clear of the map's edges
+    let half = box_width / 2.0;
+    let x = layout.position_to_x(position).clamp(layout.left + half, layout.right - half);
+    let y = layout.top - 40.0 - box_height / 2.0;
+
+    draw.rect()
+        .x_y(x, y)
+        .w_h(box_width, box_height)
+        .color(srgba(40u8, 44u8, 50u8, 235u8));
+
+    draw.rect()
+        .x_y(x, y)
+        .w_h(box_width, box_height)
+        .no_fill()
+        .stroke(colors::dst_fault())
+        .stroke_weight(1.5);
+
+    draw.text(text)
+        .x_y(x, y)
+        .color(colors::text_primary())
         .font_size(12)
-        .w(40.0);
+        .w_h(text_width, text_height);
 }
 
 /// Draw the inspect cursor at a given position
@@ -725,24 +1094,196 @@ pub fn draw_inspect_cursor(
     }
 }
 
+/// Draw the note explaining which landmark the cursor snapped to
+pub fn draw_snap_tooltip(draw: &Draw, layout: &MapLayout, position: f32, note: &str) {
+    let padding = 8.0;
+    let text_width = (note.chars().count() as f32 * 6.5).min(layout.width - padding * 2.0);
+    let text_height = 16.0;
+    let box_width = text_width + padding * 2.0;
+
+    // Just inside the top of the map, kept clear of its edges
+    let half = box_width / 2.0;
+    let x = layout.position_to_x(position).clamp(layout.left + half, layout.right - half);
+    let y = layout.top - 24.0;
+
+    draw.rect()
+        .x_y(x, y)
+        .w_h(box_width, text_height + padding * 2.0)
+        .color(srgba(40u8, 44u8, 50u8, 230u8));
+
+    draw.rect()
+        .x_y(x, y)
+        .w_h(box_width, text_height + padding * 2.0)
+        .no_fill()
+        .stroke(srgba(120u8, 180u8, 220u8, 150u8))
+        .stroke_weight(1.0);
+
+    draw.text(note)
+        .x_y(x, y)
+        .color(colors::text_primary())
+        .font_size(12)
+        .w(text_width);
+}
+
+/// Draw a comparison zone's terrain as a translucent trace over the day map
+///
+/// The trace breaks where the comparison zone's own day starts, marked with
+/// a dashed line; `label` names the zone at the right end of the trace.
+pub fn draw_comparison_terrain(
+    draw: &Draw,
+    layout: &MapLayout,
+    day_domain: &DayDomain,
+    comparison: &ComparisonTerrain,
+    label: &str,
+) {
+    let tint = |alpha: u8| {
+        srgba(colors::COMPARISON.red, colors::COMPARISON.green, colors::COMPARISON.blue, alpha)
+    };
+    let midnights = comparison.midnights(day_domain);
+
+    let sample_count = layout.sample_count;
+    let mut segments: Vec<Vec<Point2>> = vec![Vec::with_capacity(sample_count)];
+    let mut next_midnight = midnights.iter().peekable();
+    for i in 0..sample_count {
+        let p = i as f32 / (sample_count - 1) as f32;
+        if next_midnight.next_if(|&&midnight| p >= midnight).is_some() {
+            segments.push(Vec::with_capacity(sample_count));
+        }
+        let elevation = comparison.elevation_at(day_domain, p);
+        let point = pt2(layout.position_to_x(p), layout.elevation_to_y(elevation));
+        if let Some(segment) = segments.last_mut() {
+            segment.push(point);
+        }
+    }
+
+    for segment in segments.iter().filter(|segment| segment.len() > 1) {
+        // Shade between the trace and sea level
+        let mut fill = segment.clone();
+        if let (Some(first), Some(last)) = (segment.first(), segment.last()) {
+            fill.push(pt2(last.x, layout.center_y));
+            fill.push(pt2(first.x, layout.center_y));
+        }
+        draw.polygon().points(fill).color(tint(35));
+        draw.polyline()
+            .weight(2.0)
+            .points(segment.iter().copied())
+            .color(tint(190));
+    }
+
+    for &midnight in &midnights {
+        let x = layout.position_to_x(midnight);
+        draw_dashed_line(
+            draw,
+            pt2(x, layout.top),
+            pt2(x, layout.bottom),
+            colors::COMPARISON,
+            1.0,
+            4.0,
+            6.0,
+        );
+        draw.text(&format!("00:00 {}", label))
+            .x_y(x, layout.bottom + 12.0)
+            .color(tint(200))
+            .font_size(10)
+            .w(140.0);
+    }
+
+    if let Some(end) = segments.last().and_then(|segment| segment.last()) {
+        draw.text(label)
+            .x_y(end.x - 60.0, end.y + 14.0)
+            .color(tint(230))
+            .font_size(11)
+            .w(120.0)
+            .right_justify();
+    }
+}
+
+/// Draw a marker on the map for every waypoint
+///
+/// The `active` waypoint (the one under the inspect cursor) is emphasised.
+pub fn draw_waypoints(
+    draw: &Draw,
+    layout: &MapLayout,
+    day_domain: &DayDomain,
+    waypoints: &[Waypoint],
+    active: Option<usize>,
+) {
+    let tint = |alpha: u8| {
+        srgba(colors::WAYPOINT.red, colors::WAYPOINT.green, colors::WAYPOINT.blue, alpha)
+    };
+
+    for (i, waypoint) in waypoints.iter().enumerate() {
+        let position = day_domain.ssm_to_position(waypoint.minute as i64 * 60);
+        let x = layout.position_to_x(position);
+        let is_active = active == Some(i);
+        let color = tint(if is_active { 255 } else { 170 });
+
+        draw.line()
+            .start(pt2(x, layout.top))
+            .end(pt2(x, layout.bottom))
+            .color(tint(60))
+            .weight(1.0);
+
+        // Diamond marker with the note just below it
+        let size = if is_active { 7.0 } else { 5.0 };
+        let marker_y = layout.top - 12.0;
+        draw.polygon()
+            .points(vec![
+                pt2(x, marker_y + size),
+                pt2(x + size, marker_y),
+                pt2(x, marker_y - size),
+                pt2(x - size, marker_y),
+            ])
+            .color(color);
+
+        draw.text(&waypoint.label())
+            .x_y(x, marker_y - 16.0)
+            .color(color)
+            .font_size(if is_active { 12 } else { 10 })
+            .w(110.0);
+    }
+}
+
 /// Draw the title and map summary
 pub fn draw_title(draw: &Draw, window_rect: Rect) {
     let title_y = window_rect.top() - 30.0;
 
-    draw.text("Temporal Topography")
+    draw.text(tr("Temporal Topography"))
         .x_y(window_rect.left() + 150.0, title_y)
-        .color(colors::TEXT_PRIMARY)
+        .color(colors::text_primary())
         .font_size(20)
         .w(300.0);
 }
 
-/// Draw keyboard help hints at the bottom
-pub fn draw_help_hints(draw: &Draw, layout: &MapLayout, window_rect: Rect) {
+/// Keys and what they do, for the hint line under the map
+const HELP_HINTS: [(&[&str], &str); 12] = [
+    (&["Step back one minute", "Step forward one minute"], "step minute"),
+    (&["Step back one hour", "Step forward one hour"], "step hour"),
+    (&["Pin waypoint"], "pin waypoint"),
+    (&["Previous waypoint", "Next waypoint"], "cycle waypoints"),
+    (&["Next landmark", "Previous landmark"], "snap to landmarks"),
+    (&["Toggle week view"], "week view"),
+    (&["Toggle contour lines"], "contours"),
+    (&["Toggle 3D relief"], "3D relief"),
+    (&["Show more days", "Show fewer days"], "zoom days"),
+    (&["Scroll to earlier days", "Scroll to later days"], "scroll days"),
+    (&["Return to now"], "return to now"),
+    (&["Open timezone picker"], "search timezone"),
+];
+
+/// Draw keyboard help hints at the bottom, with the keys currently bound
+pub fn draw_help_hints(draw: &Draw, layout: &MapLayout, window_rect: Rect, keys: &CommandRegistry) {
     let help_y = window_rect.bottom() + 15.0;
     // Center within the map canvas, not the whole window
     let center_x = (layout.left + layout.right) / 2.0;
 
-    draw.text("Click map to inspect  •  ←/→ step minute  •  Shift+←/→ step hour  •  Esc return to now  •  / search timezone")
+    let mut hints = vec![format!("{} {}", tr("Click"), tr("inspect"))];
+    hints.extend(HELP_HINTS.iter().map(|(commands, action)| {
+        let labels: Vec<String> = commands.iter().map(|name| keys.key_label(name)).collect();
+        format!("{} {}", labels.join("/"), tr(action))
+    }));
+    hints.push(format!("Ctrl+K {}", tr("commands")));
+    draw.text(&hints.join("  •  "))
         .x_y(center_x, help_y)
         .color(srgba(140u8, 135u8, 130u8, 150u8))
         .font_size(10)
@@ -787,7 +1328,7 @@ pub fn draw_hover_tooltip(
     // Time text
     draw.text(time_str)
         .x_y(tooltip_x, tooltip_y)
-        .color(colors::TEXT_PRIMARY)
+        .color(colors::text_primary())
         .font_size(12)
         .w(text_width);
 
@@ -0,0 +1,135 @@
+//! Export module - the day's terrain as a PNG heightmap and a CSV profile
+//!
+//! Both are sampled once per minute along the day map, so column N of the
+//! image and row N of the CSV describe the same minute. DST gaps stay empty
+//! in both, as they do on the map.
+
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use nannou::image::{DynamicImage, ImageOutputFormat, Rgb, RgbImage};
+
+use crate::contours::intensity;
+use crate::drawing::{colors, elevation_color};
+use crate::terrain::{terrain_elevation, DayDomain, TerrainParams};
+
+/// Height of the heightmap image in pixels (its width is one pixel per minute)
+pub const HEIGHTMAP_HEIGHT: u32 = 256;
+
+const CSV_HEADER: &str = "instant_utc,local_time,minute_of_day,elevation,intensity";
+
+/// The terrain at one minute of the day
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileSample {
+    pub instant_utc: DateTime<Utc>,
+    /// Minutes since local midnight (elapsed, so DST days run 1380 or 1500)
+    pub minute: i64,
+    /// Elevation [-1..1], or `None` inside a DST gap
+    pub elevation: Option<f32>,
+}
+
+/// Sample the terrain once per minute across the day
+pub fn profile(params: &TerrainParams, day_domain: &DayDomain) -> Vec<ProfileSample> {
+    (0..day_domain.day_length_seconds / 60)
+        .map(|minute| {
+            let p = day_domain.ssm_to_position(minute * 60);
+            ProfileSample {
+                instant_utc: day_domain.midnight_utc + Duration::minutes(minute),
+                minute,
+                elevation: (!day_domain.is_in_gap(p)).then(|| terrain_elevation(p, params)),
+            }
+        })
+        .collect()
+}
+
+/// Render the profile as CSV, with local times in `tz`
+pub fn render_csv(samples: &[ProfileSample], tz: Tz) -> String {
+    let mut out = String::from(CSV_HEADER);
+    out.push('\n');
+    for sample in samples {
+        let (elevation, level) = match sample.elevation {
+            Some(e) => (format!("{:.4}", e), format!("{:.4}", intensity(e))),
+            None => (String::new(), String::new()),
+        };
+        out.push_str(&format!(
+            "{},{},{},{},{}\n",
+            sample.instant_utc.format("%Y-%m-%dT%H:%M:%SZ"),
+            sample.instant_utc.with_timezone(&tz).format("%Y-%m-%dT%H:%M:%S%:z"),
+            sample.minute,
+            elevation,
+            level,
+        ));
+    }
+    out
+}
+
+/// Draw the profile as a filled terrain, one column per sample
+///
+/// Each column is filled from the bottom up to its elevation and tinted by
+/// the height of each pixel, like the map's elevation scale.
+pub fn render_heightmap(samples: &[ProfileSample]) -> RgbImage {
+    let width = samples.len().max(1) as u32;
+    let background = Rgb([colors::CANVAS_BG.red, colors::CANVAS_BG.green, colors::CANVAS_BG.blue]);
+    let mut image = RgbImage::from_pixel(width, HEIGHTMAP_HEIGHT, background);
+
+    let bottom = (HEIGHTMAP_HEIGHT - 1) as f32;
+    for (x, sample) in samples.iter().enumerate() {
+        let Some(elevation) = sample.elevation else {
+            continue;
+        };
+        let top = ((1.0 - elevation) / 2.0 * bottom).round() as u32;
+        for y in top..HEIGHTMAP_HEIGHT {
+            let color = elevation_color(1.0 - 2.0 * y as f32 / bottom);
+            image.put_pixel(x as u32, y, Rgb([color.red, color.green, color.blue]));
+        }
+    }
+    image
+}
+
+/// Encode an image as PNG
+pub fn encode_png(image: RgbImage) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    DynamicImage::ImageRgb8(image)
+        .write_to(&mut bytes, ImageOutputFormat::Png)
+        .map_err(|e| e.to_string())?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn day(tz: &str, date: (i32, u32, u32)) -> (TerrainParams, DayDomain) {
+        let tz: Tz = tz.parse().unwrap();
+        let noon = tz.with_ymd_and_hms(date.0, date.1, date.2, 12, 0, 0).unwrap();
+        let domain = DayDomain::compute(noon.with_timezone(&Utc), tz);
+        (TerrainParams::from_datetime(noon), domain)
+    }
+
+    #[test]
+    fn test_profile_covers_each_minute() {
+        let (params, domain) = day("America/Los_Angeles", (2025, 6, 1));
+        let samples = profile(&params, &domain);
+        assert_eq!(samples.len(), 1440);
+        assert!(samples.iter().all(|s| s.elevation.is_some()));
+
+        let csv = render_csv(&samples, "America/Los_Angeles".parse().unwrap());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert!(lines[1].starts_with("2025-06-01T07:00:00Z,2025-06-01T00:00:00-07:00,0,"));
+    }
+
+    #[test]
+    fn test_spring_forward_gap_stays_empty() {
+        let (params, domain) = day("America/Los_Angeles", (2025, 3, 9));
+        let samples = profile(&params, &domain);
+        assert_eq!(samples.len(), 1380);
+        let gap = samples.iter().position(|s| s.elevation.is_none()).unwrap();
+
+        let image = render_heightmap(&samples);
+        assert_eq!(image.dimensions(), (1380, HEIGHTMAP_HEIGHT));
+        let background = [colors::CANVAS_BG.red, colors::CANVAS_BG.green, colors::CANVAS_BG.blue];
+        assert_eq!(image.get_pixel(gap as u32, HEIGHTMAP_HEIGHT - 1).0, background);
+        assert_ne!(image.get_pixel(0, HEIGHTMAP_HEIGHT - 1).0, background);
+    }
+}
@@ -0,0 +1,231 @@
+//! Landmarks module - the points on the day map worth jumping between
+//!
+//! Hour boundaries, terrain peaks and valleys, and the edges of DST faults.
+//! Peaks and valleys are found on the same per-minute samples as the profile
+//! export, skipping DST gaps; a flat top or bottom counts once, at its first
+//! minute.
+
+use shared::{tr, tr_args};
+
+use crate::terrain::{terrain_elevation, DayDomain, HourBoundary, TerrainParams};
+
+/// A point on the day map and what makes it interesting
+#[derive(Debug, Clone, PartialEq)]
+pub struct Landmark {
+    /// Normalized position in the day [0..1]
+    pub position: f32,
+    /// "Peak (elevation 0.82)", "Hour boundary: 3 PM", ...
+    pub description: String,
+}
+
+/// Every landmark on the day, in order
+pub fn day_landmarks(
+    day_domain: &DayDomain,
+    hour_boundaries: &[HourBoundary],
+    params: &TerrainParams,
+) -> Vec<Landmark> {
+    let mut landmarks: Vec<Landmark> = hour_boundaries
+        .iter()
+        .map(|boundary| Landmark {
+            position: boundary.position,
+            description: hour_description(boundary),
+        })
+        .collect();
+
+    for fault in &day_domain.dst_faults {
+        let (start, end) = if fault.delta_minutes > 0 {
+            (
+                tr_args("DST gap begins: clocks skip ahead {0} min", &[&fault.delta_minutes]),
+                tr("DST gap ends").to_string(),
+            )
+        } else {
+            (
+                tr_args(
+                    "Repeated hour begins: clocks fall back {0} min",
+                    &[&-fault.delta_minutes],
+                ),
+                tr("Repeated hour ends").to_string(),
+            )
+        };
+        landmarks.push(Landmark {
+            position: fault.position,
+            description: start,
+        });
+        landmarks.push(Landmark {
+            position: fault.position + fault.width,
+            description: end,
+        });
+    }
+
+    let positions: Vec<f32> = (0..day_domain.day_length_seconds / 60)
+        .map(|minute| day_domain.ssm_to_position(minute * 60))
+        .collect();
+    let elevations: Vec<Option<f32>> = positions
+        .iter()
+        .map(|&p| (!day_domain.is_in_gap(p)).then(|| terrain_elevation(p, params)))
+        .collect();
+    for (index, is_peak) in extrema(&elevations) {
+        let elevation = elevations[index].unwrap_or_default();
+        landmarks.push(Landmark {
+            position: positions[index],
+            description: tr_args(
+                if is_peak { "Peak (elevation {0})" } else { "Valley (elevation {0})" },
+                &[&format!("{:.2}", elevation)],
+            ),
+        });
+    }
+
+    landmarks.sort_by(|a, b| a.position.total_cmp(&b.position));
+    landmarks
+}
+
+fn hour_description(boundary: &HourBoundary) -> String {
+    if boundary.is_next_day {
+        return tr("Next midnight").to_string();
+    }
+    if boundary.is_midnight {
+        return tr("Midnight").to_string();
+    }
+    match &boundary.suffix {
+        Some(suffix) if !suffix.is_empty() => {
+            let label = format!("{} {}", boundary.label, suffix);
+            tr_args("Hour boundary: {0}", &[&label])
+        }
+        _ => tr_args("Hour boundary: {0}", &[&boundary.label]),
+    }
+}
+
+/// Local maxima and minima of the samples as (index, is_peak)
+///
+/// `None` samples (DST gaps) split the day; the ends of each stretch are not
+/// extrema, since the terrain carries on past them.
+fn extrema(elevations: &[Option<f32>]) -> Vec<(usize, bool)> {
+    let mut found = Vec::new();
+    // (first index, value) of each run of equal samples in the current stretch
+    let mut runs: Vec<(usize, f32)> = Vec::new();
+
+    let mut flush = |runs: &mut Vec<(usize, f32)>| {
+        for window in runs.windows(3) {
+            let (before, (index, value), after) = (window[0].1, window[1], window[2].1);
+            if value > before && value > after {
+                found.push((index, true));
+            } else if value < before && value < after {
+                found.push((index, false));
+            }
+        }
+        runs.clear();
+    };
+
+    for (index, sample) in elevations.iter().enumerate() {
+        match sample {
+            Some(value) => {
+                if runs.last().map(|&(_, last)| last) != Some(*value) {
+                    runs.push((index, *value));
+                }
+            }
+            None => flush(&mut runs),
+        }
+    }
+    flush(&mut runs);
+    found
+}
+
+/// The landmark after (or before) `position`, wrapping around the day
+///
+/// Landmarks within `tolerance` of the cursor are skipped, and those within
+/// `tolerance` of the one found are reported together.
+pub fn snap_target(
+    landmarks: &[Landmark],
+    position: f32,
+    forward: bool,
+    tolerance: f32,
+) -> Option<(f32, String)> {
+    let target = if forward {
+        landmarks
+            .iter()
+            .find(|l| l.position > position + tolerance)
+            .or_else(|| landmarks.first())
+    } else {
+        landmarks
+            .iter()
+            .rev()
+            .find(|l| l.position < position - tolerance)
+            .or_else(|| landmarks.last())
+    }?;
+
+    let description = landmarks
+        .iter()
+        .filter(|l| (l.position - target.position).abs() <= tolerance)
+        .map(|l| l.description.as_str())
+        .collect::<Vec<_>>()
+        .join(" • ");
+    Some((target.position, description))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Tz;
+
+    use crate::terrain::generate_hour_boundaries;
+
+    fn landmark(position: f32, description: &str) -> Landmark {
+        Landmark {
+            position,
+            description: description.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_extrema_skip_plateaus_and_gaps() {
+        let samples = [
+            Some(0.0),
+            Some(0.5),
+            Some(0.5),
+            Some(0.2),
+            Some(-0.3),
+            Some(0.1),
+            None,
+            Some(0.4),
+            Some(0.9),
+        ];
+        assert_eq!(extrema(&samples), vec![(1, true), (4, false)]);
+    }
+
+    #[test]
+    fn test_snap_target_merges_and_wraps() {
+        let landmarks = vec![
+            landmark(0.25, "Hour boundary: 6 AM"),
+            landmark(0.5, "Hour boundary: 12 PM"),
+            landmark(0.5005, "Peak (elevation 0.90)"),
+            landmark(0.75, "Valley (elevation -0.40)"),
+        ];
+        let tolerance = 0.001;
+
+        let (position, description) = snap_target(&landmarks, 0.3, true, tolerance).unwrap();
+        assert_eq!(position, 0.5);
+        assert_eq!(description, "Hour boundary: 12 PM • Peak (elevation 0.90)");
+
+        // Sitting on a landmark moves past it
+        assert_eq!(snap_target(&landmarks, 0.75, true, tolerance).unwrap().0, 0.25);
+        assert_eq!(snap_target(&landmarks, 0.25, false, tolerance).unwrap().0, 0.75);
+        assert!(snap_target(&[], 0.5, true, tolerance).is_none());
+    }
+
+    #[test]
+    fn test_day_landmarks_include_dst_edges() {
+        // US spring forward: Sunday 9 March 2025, 2 AM local
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let noon = Utc.with_ymd_and_hms(2025, 3, 9, 16, 0, 0).unwrap();
+        let day = DayDomain::compute(noon, tz);
+        let boundaries = generate_hour_boundaries(tz, &day);
+        let params = TerrainParams::from_datetime(noon.with_timezone(&tz));
+        let landmarks = day_landmarks(&day, &boundaries, &params);
+
+        assert!(landmarks.iter().any(|l| l.description.starts_with("DST gap begins")));
+        assert!(landmarks.iter().any(|l| l.description.starts_with("Peak")));
+        assert!(landmarks.iter().any(|l| l.description.starts_with("Valley")));
+        assert!(landmarks.windows(2).all(|w| w[0].position <= w[1].position));
+    }
+}
@@ -0,0 +1,1886 @@
+//! Temporal Topography Clock
+//!
+//! A clock as a topographic map of the day where elevations represent
+//! "temporal intensity." You read time by locating yourself on the terrain.
+//!
+//! Zooming out lays the days of the week side by side on one elevation
+//! scale, so the weekly rhythm (and a DST Sunday's fault) can be compared.
+//! Noted waypoints ("standup", "kid pickup") can be pinned anywhere on the day.
+//! Optional contour lines split the terrain into intensity bands, read off
+//! the scale in the left margin. An optional 3D relief extrudes the profile
+//! into a lit ridge, still reading time left to right along its front edge.
+//! A second zone's terrain can be traced over
+//! the day to see where the two zones' intense hours line up. The day's
+//! terrain exports as a PNG heightmap and a per-minute CSV profile.
+//! A day replay sweeps the beacon from midnight to midnight in a few
+//! seconds, pausing to explain each DST fault it crosses.
+//! Instead of the synthetic waves, the terrain can follow an imported
+//! calendar or an intensity curve drawn by hand for each weekday.
+
+mod calendar;
+mod contours;
+mod drawing;
+mod export;
+mod landmarks;
+mod profile;
+mod relief;
+mod replay;
+mod terrain;
+mod ui;
+mod waypoints;
+mod week;
+
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::{DateTime, Datelike, Utc, Weekday};
+use chrono_tz::Tz;
+use nannou::event::ModifiersState;
+use nannou::prelude::*;
+use nannou_egui::{self, egui, Egui};
+use serde::{Deserialize, Serialize};
+use shared::{
+    compute_time_data, compute_time_data_at, report_save, tr, tr_args, ClipRecorder, ClipSettings,
+    ClipStep, CommandPalette, CommandRegistry, ConfigRecovery, ConfigSaver, Favorites,
+    FollowSystemZone, FramePacer, KeyChord, Keymap, LanguageSetting, LinkListener, LinkMessage,
+    MotionPreference, NoFocusRegions, QuietHours, RecoveryChoice, SafePaletteSetting, ScreenReader,
+    SnapshotProgress, SnapshotStep, SystemMotionWatcher, SystemZoneWatcher, ThemeLibrary,
+    TickGranularity, Ticker, TimeData, TimeReveal, VisualIntensity, WindowPlacement, WindowTitle,
+    WindowTitleClock,
+};
+
+use crate::drawing::{
+    colors, draw_day_map, draw_help_hints, draw_hover_tooltip, draw_inspect_cursor,
+    draw_comparison_terrain, draw_intensity_scale, draw_replay_callout, draw_replay_sweep,
+    draw_snap_tooltip, draw_title, draw_waypoints, draw_week_map, DayPanel, MapLayout,
+};
+use crate::calendar::{load_ics, CalendarCache};
+use crate::terrain::{
+    generate_hour_boundaries, week_domains, ComparisonTerrain, DayDomain, HourBoundary,
+    TerrainParams, TerrainSource,
+};
+use crate::ui::{
+    draw_side_panel, draw_timezone_picker, theme_visuals, PickerResult, PickerState,
+    PickerTarget, SidePanelResult,
+};
+use crate::landmarks::{day_landmarks, snap_target};
+use crate::profile::IntensityProfiles;
+use crate::replay::{replay_stops, DayReplay, DEFAULT_REPLAY_SECONDS};
+use crate::waypoints::{adjacent, find_at, WaypointBook};
+use crate::week::WeekView;
+
+const CLOCK_NAME: &str = "temporal_topography";
+const DEFAULT_TZ: &str = "America/Los_Angeles";
+const WINDOW_TITLE: &str = "Temporal Topography";
+const SIDE_PANEL_WIDTH: f32 = 280.0;
+
+/// Run the clock; returns only when the window is closed
+pub fn run() {
+    nannou::app(model).update(update).run();
+}
+
+/// Launcher entry for this clock
+pub struct TemporalTopography;
+
+impl shared::ClockApp for TemporalTopography {
+    fn id(&self) -> &'static str {
+        CLOCK_NAME
+    }
+
+    fn title(&self) -> &'static str {
+        "Temporal Topography"
+    }
+
+    fn tagline(&self) -> &'static str {
+        "A topographic map of the day; find yourself on the terrain"
+    }
+
+    fn run(&self) {
+        run();
+    }
+}
+
+/// Application mode
+#[derive(Debug, Clone, PartialEq)]
+enum Mode {
+    /// Live mode - beacon at current time
+    Live,
+    /// Inspecting mode - user is exploring a specific point on the map
+    Inspecting {
+        /// Normalized position [0..1] being inspected
+        inspect_position: f32,
+        /// Whether the inspection point is pinned
+        is_pinned: bool,
+    },
+    /// Replaying - the beacon sweeps across the whole day
+    Replaying(DayReplay),
+}
+
+impl Mode {
+    fn is_inspecting(&self) -> bool {
+        matches!(self, Mode::Inspecting { .. })
+    }
+
+    fn replay(&self) -> Option<&DayReplay> {
+        match self {
+            Mode::Replaying(replay) => Some(replay),
+            Mode::Live | Mode::Inspecting { .. } => None,
+        }
+    }
+
+    fn inspect_position(&self) -> Option<f32> {
+        match self {
+            Mode::Inspecting { inspect_position, .. } => Some(*inspect_position),
+            Mode::Live | Mode::Replaying(_) => None,
+        }
+    }
+
+    #[allow(dead_code)]
+    fn is_pinned(&self) -> bool {
+        match self {
+            Mode::Inspecting { is_pinned, .. } => *is_pinned,
+            Mode::Live | Mode::Replaying(_) => false,
+        }
+    }
+}
+
+/// Persisted configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Config {
+    selected_tz_id: String,
+    favorites: Vec<String>,
+    /// Keep `favorites` instead of following the series-wide list
+    #[serde(default)]
+    own_favorites: bool,
+    reduced_motion: MotionPreference,
+    show_legend: bool,
+    #[serde(default)]
+    show_contours: bool,
+    /// Draw the terrain as a 3D relief instead of a flat profile
+    #[serde(default)]
+    show_relief: bool,
+    #[serde(default)]
+    terrain_source: TerrainSource,
+    #[serde(default)]
+    calendar_path: String,
+    /// Hand-drawn intensity curves for the custom terrain source
+    #[serde(default)]
+    intensity_profiles: IntensityProfiles,
+    /// Selected theme name; `None` uses the clock's own colors
+    #[serde(default)]
+    theme: Option<String>,
+    /// Pinned waypoints for each timezone, keyed by zone id
+    #[serde(default)]
+    waypoints: WaypointBook,
+    /// Whether to overlay the comparison zone's terrain
+    #[serde(default)]
+    show_comparison: bool,
+    /// Zone whose terrain is overlaid
+    #[serde(default)]
+    comparison_tz_id: Option<String>,
+    /// Seconds a day replay takes, excluding pauses at DST faults
+    #[serde(default = "default_replay_seconds")]
+    replay_seconds: f32,
+}
+
+fn default_replay_seconds() -> f32 {
+    DEFAULT_REPLAY_SECONDS
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            selected_tz_id: DEFAULT_TZ.to_string(),
+            favorites: vec![
+                "America/New_York".to_string(),
+                "America/Los_Angeles".to_string(),
+                "Europe/London".to_string(),
+                "Asia/Tokyo".to_string(),
+            ],
+            own_favorites: false,
+            reduced_motion: MotionPreference::Auto,
+            show_legend: true,
+            show_contours: false,
+            show_relief: false,
+            terrain_source: TerrainSource::Synthetic,
+            calendar_path: String::new(),
+            intensity_profiles: IntensityProfiles::default(),
+            theme: None,
+            waypoints: WaypointBook::default(),
+            show_comparison: false,
+            comparison_tz_id: None,
+            replay_seconds: DEFAULT_REPLAY_SECONDS,
+        }
+    }
+}
+
+impl shared::VersionedConfig for Config {
+    const VERSION: u32 = 2;
+
+    /// Version 2 names the reduced-motion choice; earlier files hold a bool
+    fn migrate(_from_version: u32, payload: &mut toml::Table) {
+        shared::migrate_reduced_motion(payload);
+    }
+}
+
+/// Application state
+struct Model {
+    /// Current mode (Live, Inspecting or Replaying)
+    mode: Mode,
+    /// Current time data
+    time_data: TimeData,
+    /// Selected timezone
+    selected_tz: Tz,
+    /// Favorite timezones
+    favorites: Favorites,
+    /// Timezone picker state
+    picker_state: PickerState,
+    /// Ctrl+K command palette
+    command_palette: CommandPalette,
+    /// Actions listed in the command palette, and the chords they're bound to
+    keymap: Keymap,
+    /// Reduced motion setting: follow the system, or override it
+    motion_preference: MotionPreference,
+    /// Reports the system's reduce-motion preference
+    system_motion: SystemMotionWatcher,
+    /// Whether motion is reduced, from the setting and the system
+    reduced_motion: bool,
+    /// Shared quiet-hours schedule
+    quiet_hours: QuietHours,
+    /// Brightness and effects allowed right now by the schedule
+    intensity: VisualIntensity,
+    /// Shared setting for the time in the window title
+    window_title_clock: WindowTitleClock,
+    /// Shared settings for clips recorded with Ctrl+Shift+R
+    clip_settings: ClipSettings,
+    /// Clip being recorded or saved, if any
+    clip_recorder: ClipRecorder,
+    /// Shared language for labels, dates and toasts
+    language: LanguageSetting,
+    /// Shared high-contrast or color-blind safe palette
+    safe_palette: SafePaletteSetting,
+    /// Keeps the window title's time current
+    window_title: WindowTitle,
+    /// Remembers the window's size, position and monitor
+    window_placement: WindowPlacement,
+    /// Shared setting for switching to the system zone when it changes
+    follow_system_zone: FollowSystemZone,
+    /// Reports changes to the computer's time zone
+    system_zone: SystemZoneWatcher,
+    /// Shared system-wide shortcut that shows the time from the background
+    time_reveal: TimeReveal,
+    /// Drops the frame rate while the clock sits idle
+    frame_pacer: FramePacer,
+    /// Writes config changes once they settle
+    config_saver: ConfigSaver,
+    /// Shown when the saved config couldn't be read
+    config_recovery: Option<ConfigRecovery>,
+    /// Instants sent from other clocks, such as the audit ledger
+    link: LinkListener,
+    /// Whether to show the legend and elevation scale
+    show_legend: bool,
+    /// Whether to draw contour lines over the terrain
+    show_contours: bool,
+    /// Whether to draw the terrain as a 3D relief
+    show_relief: bool,
+    /// Whether to overlay the comparison zone's terrain
+    show_comparison: bool,
+    /// Zone whose terrain is overlaid
+    comparison_tz: Option<Tz>,
+    /// The comparison zone's terrain along today's axis (cached)
+    comparison: Option<ComparisonTerrain>,
+    /// Seconds a day replay takes, excluding pauses at DST faults
+    replay_seconds: f32,
+    /// Built-in and user themes
+    themes: ThemeLibrary,
+    /// Active theme name; `None` uses the clock's own colors
+    theme: Option<String>,
+    /// Current day domain (cached)
+    day_domain: DayDomain,
+    /// Hour boundaries for grid (cached)
+    hour_boundaries: Vec<HourBoundary>,
+    /// Terrain parameters (cached)
+    terrain_params: TerrainParams,
+    /// Which days of the week are on screen
+    week_view: WeekView,
+    /// Day domains for the current week, Monday first (cached)
+    week_days: Vec<DayDomain>,
+    /// Hour boundaries for each day of the week (cached)
+    week_boundaries: Vec<Vec<HourBoundary>>,
+    /// Terrain parameters for each day of the week (cached)
+    week_params: Vec<TerrainParams>,
+    /// Fractional mouse wheel movement not yet turned into a zoom/scroll step
+    wheel_accumulator: Vec2,
+    /// Whether the terrain is synthetic or shaped by the calendar
+    terrain_source: TerrainSource,
+    /// Path of the ICS file to import
+    calendar_path: String,
+    /// Imported calendar with per-day busyness cache
+    calendar: Option<CalendarCache>,
+    /// Hand-drawn intensity curves for the custom terrain source
+    intensity_profiles: IntensityProfiles,
+    /// Weekday whose curve the side panel is editing
+    profile_day: Weekday,
+    /// Last system time zone change, shown under its setting
+    system_zone_status: Option<String>,
+    /// Last instant sent from another clock, shown under the time readout
+    link_status: Option<String>,
+    /// Where the cursor last snapped to a landmark, and what it found there
+    snap_note: Option<(f32, String)>,
+    /// Result of the last calendar import, shown in the side panel
+    calendar_status: Option<String>,
+    /// Result of the last terrain export, shown in the side panel
+    export_status: Option<String>,
+    /// Pinned waypoints for every timezone
+    waypoints: WaypointBook,
+    /// Note for the next waypoint, as typed in the side panel
+    waypoint_note: String,
+    /// Whether an egui text field has keyboard focus
+    text_input_focused: bool,
+    /// Mouse position for hover inspection
+    mouse_position: Option<Point2>,
+    /// Last click time for double-click detection
+    last_click_time: Option<std::time::Instant>,
+    /// Wall-clock ticker driving time recomputation in live mode
+    ticker: Ticker,
+    /// Frame counter for `--snapshot` runs
+    snapshot: SnapshotProgress,
+    /// Screen reader view of the time, DST status and focus
+    screen_reader: ScreenReader,
+
+    /// egui integration
+    egui: Egui,
+}
+
+impl Model {
+    /// Whether something on screen is moving on its own: a day replay or a
+    /// clip being captured
+    fn is_animating(&self) -> bool {
+        self.mode.replay().is_some()
+            || self.clip_recorder.is_recording()
+            || self.time_reveal.is_flashing()
+    }
+
+    /// Apply a reduced-motion setting against the current system preference
+    fn set_motion_preference(&mut self, preference: MotionPreference) {
+        self.motion_preference = preference;
+        self.reduced_motion = preference.resolve(self.system_motion.reduced());
+    }
+
+    fn enter_inspect(&mut self, position: f32) {
+        // Snap to nearest minute boundary
+        let snapped = self.day_domain.snap_to_minute(position);
+        self.mode = Mode::Inspecting {
+            inspect_position: snapped.clamp(0.0, 1.0),
+            is_pinned: false,
+        };
+    }
+
+    /// Sweep the beacon across today's map, pausing at its DST faults
+    fn start_replay(&mut self) {
+        if !self.is_day_view() {
+            self.set_week_view(WeekView::day(self.today_index()));
+        }
+        let stops = replay_stops(&self.day_domain, self.selected_tz);
+        self.mode = Mode::Replaying(DayReplay::new(self.replay_seconds, stops));
+    }
+
+    fn return_to_live(&mut self) {
+        self.mode = Mode::Live;
+        self.link_status = None;
+        self.time_data = compute_time_data(self.selected_tz);
+        self.refresh_terrain_params();
+    }
+
+    /// Rebuild terrain params from the displayed time and the terrain source
+    fn refresh_terrain_params(&mut self) {
+        let params = TerrainParams::from_datetime(self.time_data.local_datetime);
+        let calendar = match self.terrain_source {
+            TerrainSource::Calendar => self.calendar.as_mut(),
+            TerrainSource::Synthetic | TerrainSource::Custom => None,
+        };
+
+        match calendar {
+            Some(calendar) => {
+                self.week_params = self
+                    .week_days
+                    .iter()
+                    .map(|day| {
+                        params
+                            .for_day_of_year(day.local_date(self.selected_tz).ordinal())
+                            .with_busyness(calendar.profile_for(day))
+                    })
+                    .collect();
+                self.terrain_params = params.with_busyness(calendar.profile_for(&self.day_domain));
+            }
+            None => {
+                let tz = self.selected_tz;
+                let profiles = match self.terrain_source {
+                    TerrainSource::Custom => Some(&self.intensity_profiles),
+                    TerrainSource::Synthetic | TerrainSource::Calendar => None,
+                };
+                let day_params = |day: &DayDomain| {
+                    let date = day.local_date(tz);
+                    let day_params = params.for_day_of_year(date.ordinal());
+                    match profiles {
+                        Some(profiles) => day_params.with_profile(
+                            Arc::new(profiles.day(date.weekday()).clone()),
+                            profiles.blend,
+                        ),
+                        None => day_params,
+                    }
+                };
+                self.week_params = self.week_days.iter().map(day_params).collect();
+                self.terrain_params = day_params(&self.day_domain);
+            }
+        }
+        self.refresh_comparison();
+    }
+
+    /// Rebuild the comparison trace for the displayed time, if it's shown
+    fn refresh_comparison(&mut self) {
+        self.comparison = match self.comparison_tz {
+            Some(tz) if self.show_comparison => {
+                let instant = self.time_data.local_datetime.with_timezone(&Utc);
+                Some(ComparisonTerrain::compute(tz, &self.day_domain, instant))
+            }
+            _ => None,
+        };
+    }
+
+    /// Show or hide the comparison overlay, asking for a zone if none is set
+    fn toggle_comparison(&mut self) {
+        self.show_comparison = !self.show_comparison;
+        if self.show_comparison && self.comparison_tz.is_none() {
+            self.picker_state.open_for(PickerTarget::Comparison);
+        }
+        self.refresh_comparison();
+        save_config(self);
+    }
+
+    /// Recompute the week's day domains around `now`
+    fn refresh_week(&mut self, now: DateTime<Utc>) {
+        let week_days = week_domains(now, self.selected_tz);
+
+        let week_changed = self.week_days.first().map(|day| day.midnight_utc)
+            != week_days.first().map(|day| day.midnight_utc);
+        if week_changed {
+            self.week_boundaries = week_days
+                .iter()
+                .map(|day| generate_hour_boundaries(self.selected_tz, day))
+                .collect();
+        }
+        self.week_days = week_days;
+    }
+
+    /// Index (0 = Monday) of today within the week
+    fn today_index(&self) -> usize {
+        self.day_domain.local_date(self.selected_tz).weekday().num_days_from_monday() as usize
+    }
+
+    /// Whether the map shows just today, where inspect mode lives
+    fn is_day_view(&self) -> bool {
+        self.week_view == WeekView::day(self.today_index())
+    }
+
+    /// Change the visible days; inspecting and replaying only apply to today's map
+    fn set_week_view(&mut self, view: WeekView) {
+        self.week_view = view;
+        if !self.is_day_view() && self.mode != Mode::Live {
+            self.return_to_live();
+        }
+    }
+
+    /// Show more (`delta > 0`) or fewer days, anchored on `anchor_day`
+    fn zoom_week(&mut self, delta: i32, anchor_day: usize) {
+        let mut view = self.week_view;
+        view.zoom(delta, anchor_day);
+        self.set_week_view(view);
+    }
+
+    /// Zoom around today if it's on screen, otherwise the leftmost day
+    fn zoom_week_from_keyboard(&mut self, delta: i32) {
+        let today = self.today_index();
+        let anchor = if self.week_view.shows(today) {
+            today
+        } else {
+            self.week_view.first_day
+        };
+        self.zoom_week(delta, anchor);
+    }
+
+    fn scroll_week(&mut self, delta: i32) {
+        let mut view = self.week_view;
+        view.scroll(delta);
+        self.set_week_view(view);
+    }
+
+    /// Toggle between today's map and the whole week
+    fn toggle_week_view(&mut self) {
+        if self.week_view.is_week() {
+            self.set_week_view(WeekView::day(self.today_index()));
+        } else {
+            self.set_week_view(WeekView::week());
+        }
+    }
+
+    /// Summary of the visible days, e.g. "Mon 3 – Sun 9"
+    fn week_view_range(&self) -> String {
+        let first = self.week_view.first_day;
+        let last = first + self.week_view.visible_days - 1;
+        let label = |idx: usize| day_label(&self.week_days[idx], self.selected_tz);
+        if first == last {
+            label(first)
+        } else {
+            format!("{} – {}", label(first), label(last))
+        }
+    }
+
+    /// Import the ICS file at `calendar_path`
+    fn load_calendar(&mut self) {
+        let path = self.calendar_path.trim();
+        if path.is_empty() {
+            self.calendar = None;
+            self.calendar_status = Some(tr("Enter the path to an .ics file").to_string());
+            return;
+        }
+
+        match load_ics(Path::new(path), self.selected_tz) {
+            Ok(events) => {
+                let calendar = CalendarCache::new(events);
+                let count = calendar.event_count();
+                self.calendar_status = Some(tr_args("{0} events loaded", &[&count]));
+                self.calendar = Some(calendar);
+            }
+            Err(e) => {
+                self.calendar = None;
+                self.calendar_status = Some(tr_args("Failed to load calendar: {0}", &[&e]));
+            }
+        }
+        self.refresh_terrain_params();
+    }
+
+    /// Write today's terrain as a PNG heightmap and a CSV profile
+    fn export_terrain(&mut self) {
+        let samples = export::profile(&self.terrain_params, &self.day_domain);
+        let label = self.time_data.local_datetime.format("%Y%m%dT%H%M%S").to_string();
+        let csv = export::render_csv(&samples, self.selected_tz);
+
+        let result = export::encode_png(export::render_heightmap(&samples)).and_then(|png| {
+            let png_path = shared::write_export(CLOCK_NAME, &label, "png", &png)
+                .map_err(|e| e.to_string())?;
+            shared::write_export(CLOCK_NAME, &label, "csv", csv.as_bytes())
+                .map_err(|e| e.to_string())?;
+            Ok(png_path)
+        });
+
+        self.export_status = Some(match result {
+            Ok(path) => tr_args("Exported {0} and its .csv profile", &[&path.display()]),
+            Err(e) => tr_args("Export failed: {0}", &[&e]),
+        });
+    }
+
+    fn toggle_pin(&mut self) {
+        if let Mode::Inspecting { inspect_position, is_pinned } = &self.mode {
+            self.mode = Mode::Inspecting {
+                inspect_position: *inspect_position,
+                is_pinned: !*is_pinned,
+            };
+        }
+    }
+
+    fn adjust_inspect(&mut self, delta_minutes: i64) {
+        match &self.mode {
+            Mode::Live | Mode::Replaying(_) => {
+                // Enter inspect mode at the beacon (snapped), then adjust
+                let beacon = self
+                    .mode
+                    .replay()
+                    .map_or(self.day_domain.normalized_position, |replay| replay.position());
+                let position = self.day_domain.snap_to_minute(beacon);
+                let ssm = self.day_domain.position_to_ssm(position);
+                let new_ssm = ssm + delta_minutes * 60;
+                let new_position = self.day_domain.ssm_to_position(new_ssm);
+                self.mode = Mode::Inspecting {
+                    inspect_position: new_position.clamp(0.0, 1.0),
+                    is_pinned: false,
+                };
+            }
+            Mode::Inspecting { inspect_position, is_pinned } => {
+                let ssm = self.day_domain.position_to_ssm(*inspect_position);
+                let new_ssm = ssm + delta_minutes * 60;
+                let new_position = self.day_domain.ssm_to_position(new_ssm);
+                self.mode = Mode::Inspecting {
+                    inspect_position: new_position.clamp(0.0, 1.0),
+                    is_pinned: *is_pinned,
+                };
+            }
+        }
+    }
+
+    /// Minute of the day under the inspect cursor
+    fn inspect_minute(&self) -> Option<u32> {
+        self.mode
+            .inspect_position()
+            .map(|p| (self.day_domain.position_to_ssm(p) / 60) as u32)
+    }
+
+    /// Index of the waypoint under the inspect cursor, if any
+    fn active_waypoint(&self) -> Option<usize> {
+        let minute = self.inspect_minute()?;
+        find_at(self.waypoints.for_zone(self.selected_tz), minute)
+    }
+
+    /// Pin the inspected minute as a waypoint and hold the cursor there
+    fn pin_waypoint(&mut self, note: &str) {
+        let Some(minute) = self.inspect_minute() else {
+            return;
+        };
+        self.waypoints.pin(self.selected_tz, minute, note);
+        if let Mode::Inspecting { is_pinned, .. } = &mut self.mode {
+            *is_pinned = true;
+        }
+        save_config(self);
+    }
+
+    /// Move the pinned inspect cursor onto waypoint `index`
+    fn inspect_waypoint(&mut self, index: usize) {
+        let Some(waypoint) = self.waypoints.for_zone(self.selected_tz).get(index) else {
+            return;
+        };
+        let position = self.day_domain.ssm_to_position(waypoint.minute as i64 * 60);
+        if !self.is_day_view() {
+            self.set_week_view(WeekView::day(self.today_index()));
+        }
+        self.mode = Mode::Inspecting {
+            inspect_position: position,
+            is_pinned: true,
+        };
+    }
+
+    /// Pin the inspect cursor on an instant sent from another clock
+    ///
+    /// Only today's map can be inspected, so other days are just reported.
+    fn inspect_linked(&mut self, instant: DateTime<Utc>) {
+        let local = instant.with_timezone(&self.selected_tz).format("%b %-d %H:%M:%S");
+        let day = &self.day_domain;
+        if instant < day.midnight_utc || instant >= day.next_midnight_utc {
+            self.link_status = Some(tr_args("Linked time {0} is not on today's map", &[&local]));
+            return;
+        }
+
+        // Aim for the middle of the second so the cursor reads back the same one
+        let ssm = (instant - day.midnight_utc).num_seconds();
+        let position = ((ssm as f32 + 0.5) / day.day_length_seconds as f32).clamp(0.0, 1.0);
+        if !self.is_day_view() {
+            self.set_week_view(WeekView::day(self.today_index()));
+        }
+        self.mode = Mode::Inspecting {
+            inspect_position: position,
+            is_pinned: true,
+        };
+        self.link_status = Some(tr_args("Linked from another clock: {0}", &[&local]));
+    }
+
+    /// Jump to the next (or previous) waypoint from the cursor or the current time
+    fn cycle_waypoint(&mut self, forward: bool) {
+        let minute = self
+            .inspect_minute()
+            .unwrap_or((self.day_domain.seconds_since_midnight / 60) as u32);
+        if let Some(index) = adjacent(self.waypoints.for_zone(self.selected_tz), minute, forward) {
+            self.inspect_waypoint(index);
+        }
+    }
+
+    /// Jump the pinned cursor to the next (or previous) landmark on today's map
+    fn snap_to_landmark(&mut self, forward: bool) {
+        let position = self
+            .mode
+            .inspect_position()
+            .unwrap_or(self.day_domain.normalized_position);
+        let landmarks =
+            day_landmarks(&self.day_domain, &self.hour_boundaries, &self.terrain_params);
+        // Half a minute either side counts as already there
+        let tolerance = 30.0 / self.day_domain.day_length_seconds as f32;
+        let Some((target, description)) = snap_target(&landmarks, position, forward, tolerance)
+        else {
+            return;
+        };
+        if !self.is_day_view() {
+            self.set_week_view(WeekView::day(self.today_index()));
+        }
+        self.mode = Mode::Inspecting {
+            inspect_position: target,
+            is_pinned: true,
+        };
+        self.snap_note = Some((target, description));
+    }
+
+    /// What the cursor snapped to, while it is still there
+    fn active_snap_note(&self) -> Option<&str> {
+        let (position, note) = self.snap_note.as_ref()?;
+        (self.mode.inspect_position() == Some(*position)).then_some(note.as_str())
+    }
+
+    /// Format time at a given normalized position
+    fn format_time_at_position(&self, position: f32) -> String {
+        format_time_in_day(&self.day_domain, position, true)
+    }
+
+    /// Check if a position is in a DST gap
+    fn is_position_in_gap(&self, position: f32) -> bool {
+        self.day_domain.is_in_gap(position)
+    }
+
+    /// Check if a position is in a DST overlap
+    fn is_position_in_overlap(&self, position: f32) -> bool {
+        self.day_domain.is_in_overlap(position).is_some()
+    }
+}
+
+/// Format the time at a normalized position within a day
+fn format_time_in_day(day_domain: &DayDomain, position: f32, with_seconds: bool) -> String {
+    let ssm = day_domain.position_to_ssm(position);
+    let hours = (ssm / 3600) % 24;
+    let minutes = (ssm % 3600) / 60;
+    let seconds = ssm % 60;
+
+    let hour12 = match hours {
+        0 => 12,
+        1..=12 => hours,
+        _ => hours - 12,
+    };
+    let meridiem = if hours < 12 { "AM" } else { "PM" };
+
+    if with_seconds {
+        format!("{}:{:02}:{:02} {}", hour12, minutes, seconds, meridiem)
+    } else {
+        format!("{}:{:02} {}", hour12, minutes, meridiem)
+    }
+}
+
+/// City part of a zone id, e.g. "Tokyo" for "Asia/Tokyo"
+fn zone_label(tz: Tz) -> String {
+    let name = tz.name();
+    name.rsplit('/').next().unwrap_or(name).replace('_', " ")
+}
+
+/// Short day label for week panels, e.g. "Sun 9"
+fn day_label(day_domain: &DayDomain, tz: Tz) -> String {
+    day_domain.local_date(tz).format("%a %-d").to_string()
+}
+
+fn save_config(model: &Model) {
+    let config = Config {
+        selected_tz_id: model.selected_tz.name().to_string(),
+        favorites: model.favorites.own_ids(),
+        own_favorites: model.favorites.uses_own(),
+        reduced_motion: model.motion_preference,
+        show_legend: model.show_legend,
+        show_contours: model.show_contours,
+        show_relief: model.show_relief,
+        terrain_source: model.terrain_source,
+        calendar_path: model.calendar_path.clone(),
+        intensity_profiles: model.intensity_profiles.clone(),
+        theme: model.theme.clone(),
+        waypoints: model.waypoints.clone(),
+        show_comparison: model.show_comparison,
+        comparison_tz_id: model.comparison_tz.map(|tz| tz.name().to_string()),
+        replay_seconds: model.replay_seconds,
+    };
+    model.config_saver.request(&config);
+}
+
+fn model(app: &App) -> Model {
+    // Create window
+    let window_id = app
+        .new_window()
+        .title(WINDOW_TITLE)
+        .size(1200, 700)
+        .min_size(900, 600)
+        .view(view)
+        .key_pressed(key_pressed)
+        .mouse_pressed(mouse_pressed)
+        .mouse_moved(mouse_moved)
+        .mouse_wheel(mouse_wheel)
+        .raw_event(raw_window_event)
+        .visible(false)
+        .build()
+        .unwrap();
+
+    let window = app.window(window_id).unwrap();
+    let egui = Egui::from_window(&window);
+    shared::install_fallback_fonts(egui.ctx());
+
+    // Load configuration, noting an unreadable file for the recovery prompt
+    let (config, config_recovery) = shared::load_config_or_recover(CLOCK_NAME);
+    let config: Config = config.unwrap_or_default();
+
+    // Parse timezone from config
+    let selected_tz: Tz = config
+        .selected_tz_id
+        .parse()
+        .unwrap_or_else(|_| DEFAULT_TZ.parse().unwrap());
+
+    // The shared favorites, or this clock's own
+    let favorites = Favorites::load(&config.favorites, config.own_favorites);
+
+    // Compute initial time data
+    let now = shared::now_utc();
+    let themes = ThemeLibrary::load();
+    let theme = themes.activate(config.theme.as_deref());
+    let system_motion = SystemMotionWatcher::spawn();
+
+    let time_data = compute_time_data(selected_tz);
+    let day_domain = DayDomain::compute(now, selected_tz);
+    let hour_boundaries = generate_hour_boundaries(selected_tz, &day_domain);
+    let terrain_params = TerrainParams::from_datetime(time_data.local_datetime);
+
+    // Screen reader tree, attached before the window is first shown
+    let screen_reader = ScreenReader::attach::<NoFocusRegions>(
+        window.winit_window(),
+        "Temporal Topography",
+        &time_data,
+        None,
+    );
+
+    // Reopen where the window was left, or where the command line asks
+    let mut window_placement = WindowPlacement::load(CLOCK_NAME);
+    window_placement.restore(window.winit_window());
+    window.set_visible(shared::snapshot_request().is_none());
+
+    let wake_proxy = app.create_proxy();
+    let mut model = Model {
+        mode: Mode::Live,
+        time_data,
+        selected_tz,
+        favorites,
+        picker_state: PickerState::default(),
+        command_palette: CommandPalette::default(),
+        keymap: Keymap::load(CLOCK_NAME, commands()),
+        motion_preference: config.reduced_motion,
+        reduced_motion: config.reduced_motion.resolve(system_motion.reduced()),
+        system_motion,
+        quiet_hours: QuietHours::load(),
+        intensity: VisualIntensity::FULL,
+        window_title_clock: WindowTitleClock::load(),
+        clip_settings: ClipSettings::load(),
+        clip_recorder: ClipRecorder::new(CLOCK_NAME),
+        language: LanguageSetting::load(),
+        safe_palette: SafePaletteSetting::load(),
+        window_title: WindowTitle::new(WINDOW_TITLE),
+        window_placement,
+        follow_system_zone: FollowSystemZone::load(),
+        system_zone: SystemZoneWatcher::spawn(),
+        time_reveal: TimeReveal::load(),
+        frame_pacer: FramePacer::spawn(move || wake_proxy.wakeup().is_ok()),
+        config_saver: ConfigSaver::new(CLOCK_NAME),
+        config_recovery,
+        link: LinkListener::listen(CLOCK_NAME),
+        show_legend: config.show_legend,
+        show_contours: config.show_contours,
+        show_relief: config.show_relief,
+        show_comparison: config.show_comparison,
+        comparison_tz: config.comparison_tz_id.and_then(|id| id.parse().ok()),
+        comparison: None,
+        replay_seconds: config.replay_seconds,
+        themes,
+        theme,
+        day_domain,
+        hour_boundaries,
+        terrain_params,
+        week_view: WeekView::day(0),
+        week_days: Vec::new(),
+        week_boundaries: Vec::new(),
+        week_params: Vec::new(),
+        wheel_accumulator: Vec2::ZERO,
+        terrain_source: config.terrain_source,
+        calendar_path: config.calendar_path,
+        calendar: None,
+        intensity_profiles: config.intensity_profiles.repaired(),
+        profile_day: Weekday::Mon,
+        calendar_status: None,
+        system_zone_status: None,
+        link_status: None,
+        snap_note: None,
+        export_status: None,
+        waypoints: config.waypoints,
+        waypoint_note: String::new(),
+        text_input_focused: false,
+        mouse_position: None,
+        last_click_time: None,
+        ticker: Ticker::spawn(TickGranularity::Second),
+        snapshot: SnapshotProgress::default(),
+        screen_reader,
+        egui,
+    };
+
+    model.refresh_week(now);
+    model.week_view = WeekView::day(model.today_index());
+    model.profile_day = model.day_domain.local_date(selected_tz).weekday();
+    model.refresh_terrain_params();
+
+    if model.terrain_source == TerrainSource::Calendar && !model.calendar_path.is_empty() {
+        model.load_calendar();
+    }
+
+    model
+}
+
+/// Drive a `--snapshot` run: capture once the frame has settled, then quit
+fn advance_snapshot(app: &App, progress: &mut SnapshotProgress) {
+    let Some(request) = shared::snapshot_request() else {
+        return;
+    };
+    match progress.advance() {
+        SnapshotStep::Settle => {}
+        SnapshotStep::Capture => app.main_window().capture_frame(&request.path),
+        SnapshotStep::Finish => {
+            if app.main_window().await_capture_frame_jobs().is_err() {
+                eprintln!("Timed out writing snapshot to {}", request.path.display());
+                std::process::exit(1);
+            }
+            println!("Saved snapshot to {}", request.path.display());
+            app.quit();
+        }
+    }
+}
+
+/// Capture frames for a clip being recorded, and report the clip once saved
+fn advance_clip(app: &App, model: &mut Model) {
+    match model.clip_recorder.advance(Instant::now()) {
+        ClipStep::Idle => {}
+        ClipStep::Capture(path) => app.main_window().capture_frame(path),
+        ClipStep::Finish => {
+            let written = app.main_window().await_capture_frame_jobs().is_ok();
+            model.clip_recorder.encode(written);
+        }
+    }
+    if let Some(message) = model.clip_recorder.poll() {
+        model.export_status = Some(message);
+    }
+}
+
+/// Let the event loop wait between second boundaries while the clock is idle
+fn pace_frames(app: &App, model: &mut Model, now: Instant) {
+    let animating = model.is_animating();
+    let loop_mode = if model.frame_pacer.pace(animating, now) {
+        LoopMode::Wait
+    } else {
+        LoopMode::RefreshSync
+    };
+    app.set_loop_mode(loop_mode);
+}
+
+/// Act on the config recovery prompt: restart on the backup, or carry on
+/// with the defaults
+fn recover_config(app: &App, model: &mut Model, choice: RecoveryChoice) {
+    let Some(recovery) = model.config_recovery.take() else {
+        return;
+    };
+    if choice == RecoveryChoice::Restore {
+        match recovery.restore(&model.config_saver) {
+            Ok(()) => app.quit(),
+            Err(message) => model.export_status = Some(message),
+        }
+    }
+}
+
+/// Make `tz` the primary zone, rebuilding the terrain for its day at `now`
+fn select_zone(model: &mut Model, tz: Tz, now: DateTime<Utc>) {
+    let was_day_view = model.is_day_view();
+    model.selected_tz = tz;
+    model.time_data = compute_time_data(tz);
+    // Regenerate day domain and hour boundaries
+    model.day_domain = DayDomain::compute(now, tz);
+    model.hour_boundaries = generate_hour_boundaries(tz, &model.day_domain);
+    model.week_days.clear();
+    model.refresh_week(now);
+    if was_day_view {
+        model.week_view = WeekView::day(model.today_index());
+    }
+    model.refresh_terrain_params();
+    save_config(model);
+}
+
+fn update(app: &App, model: &mut Model, update: Update) {
+    advance_snapshot(app, &mut model.snapshot);
+    advance_clip(app, model);
+
+    let now = shared::now_utc();
+    let tick = model.ticker.poll();
+
+    // Move a day replay along, and back to live once it reaches midnight
+    if let Mode::Replaying(replay) = &mut model.mode {
+        replay.advance(update.since_last.as_secs_f32());
+        if replay.is_finished() {
+            model.return_to_live();
+        }
+    }
+
+    // Update time data based on mode
+    let display_instant = match &model.mode {
+        Mode::Live => now,
+        Mode::Inspecting { inspect_position, .. } => {
+            // Calculate the instant for the inspected position
+            let ssm = model.day_domain.position_to_ssm(*inspect_position);
+            model.day_domain.midnight_utc + chrono::Duration::seconds(ssm)
+        }
+        Mode::Replaying(replay) => {
+            let ssm = model.day_domain.position_to_ssm(replay.position());
+            model.day_domain.midnight_utc + chrono::Duration::seconds(ssm)
+        }
+    };
+
+    // Recompute only when the displayed second changes: ticks drive live mode,
+    // while inspecting and replaying recompute when the cursor or beacon lands
+    // on a different second
+    let inspect_moved = model.mode != Mode::Live
+        && display_instant.timestamp() != model.time_data.local_datetime.timestamp();
+
+    if tick.is_some() || inspect_moved {
+        // Today's map follows the date across midnight
+        let was_day_view = model.is_day_view();
+
+        model.time_data = compute_time_data_at(model.selected_tz, display_instant);
+
+        // Always update day domain based on current time (for proper day boundaries)
+        let new_day_domain = DayDomain::compute(now, model.selected_tz);
+
+        // Check if day changed (regenerate hour boundaries)
+        if new_day_domain.midnight_utc != model.day_domain.midnight_utc {
+            model.day_domain = new_day_domain;
+            model.hour_boundaries = generate_hour_boundaries(model.selected_tz, &model.day_domain);
+        } else {
+            // Just update the normalized position
+            model.day_domain = new_day_domain;
+        }
+        model.refresh_week(now);
+        if was_day_view {
+            model.week_view = WeekView::day(model.today_index());
+        }
+
+        // Update terrain params
+        model.refresh_terrain_params();
+    } else if model.mode == Mode::Live {
+        // Keep the beacon pulse animating between ticks
+        model.time_data.refresh_second_fraction(now);
+    }
+    // Quiet hours follow the real time, not the inspected one
+    model.intensity = model.quiet_hours.intensity_at(model.selected_tz, now);
+
+    // Collect UI state before borrowing egui
+    let current_tz = model.selected_tz;
+    let favorites_clone = model.favorites.to_vec();
+    let time_data_clone = model.time_data.clone();
+    let is_inspecting = model.mode.is_inspecting();
+    let is_replaying = model.mode.replay().is_some();
+    let mut replay_seconds = model.replay_seconds;
+    let mut motion_preference = model.motion_preference;
+    let system_reduced_motion = model.system_motion.reduced();
+    let mut quiet_hours = model.quiet_hours.clone();
+    let mut window_title_clock = model.window_title_clock;
+    let mut clip_settings = model.clip_settings;
+    let mut language = model.language;
+    let mut safe_palette = model.safe_palette;
+    let mut follow_system_zone = model.follow_system_zone;
+    let mut reveal_hotkey = model.time_reveal.setting.clone();
+    let system_zone_status = model.system_zone_status.clone();
+    let link_status = model.link_status.clone();
+    let mut own_favorites = model.favorites.uses_own();
+    let mut show_legend = model.show_legend;
+    let mut show_contours = model.show_contours;
+    let mut show_relief = model.show_relief;
+    let mut show_comparison = model.show_comparison;
+    let comparison_tz = model.comparison_tz;
+    let mut terrain_source = model.terrain_source;
+    let mut calendar_path = model.calendar_path.clone();
+    let mut intensity_profiles = model.intensity_profiles.clone();
+    let mut profile_day = model.profile_day;
+    let calendar_status = model.calendar_status.clone();
+    let export_status = model.export_status.clone();
+    let is_day_view = model.is_day_view();
+    let is_week_view = model.week_view.is_week();
+    let view_range = model.week_view_range();
+    let mut waypoints = model.waypoints.for_zone(current_tz).to_vec();
+    let mut waypoint_note = model.waypoint_note.clone();
+    let active_waypoint = model.active_waypoint();
+
+    // Get inspect info if in inspect mode (before borrowing egui)
+    let inspect_time_str = model
+        .mode
+        .inspect_position()
+        .map(|p| model.format_time_at_position(p));
+    
+    let inspect_is_gap = model
+        .mode
+        .inspect_position()
+        .map(|p| model.is_position_in_gap(p))
+        .unwrap_or(false);
+    
+    let inspect_is_overlap = model
+        .mode
+        .inspect_position()
+        .map(|p| model.is_position_in_overlap(p))
+        .unwrap_or(false);
+
+    // Begin egui frame
+    model.egui.set_elapsed_time(update.since_start);
+    let ctx = model.egui.begin_frame();
+
+    let mut visuals = egui::Visuals::dark();
+    theme_visuals(&mut visuals);
+    ctx.set_visuals(visuals);
+
+    // Draw side panel
+    let panel_result: SidePanelResult = draw_side_panel(
+        &ctx,
+        &time_data_clone,
+        is_inspecting,
+        inspect_time_str.as_deref(),
+        inspect_is_gap,
+        inspect_is_overlap,
+        link_status.as_deref(),
+        &mut motion_preference,
+        system_reduced_motion,
+        &mut show_legend,
+        &mut show_contours,
+        &mut show_relief,
+        &mut terrain_source,
+        &mut calendar_path,
+        calendar_status.as_deref(),
+        &mut intensity_profiles,
+        &mut profile_day,
+        export_status.as_deref(),
+        &mut show_comparison,
+        comparison_tz,
+        is_day_view,
+        is_week_view,
+        &view_range,
+        &mut waypoints,
+        &mut waypoint_note,
+        active_waypoint,
+        is_replaying,
+        &mut replay_seconds,
+        &mut quiet_hours,
+        &mut window_title_clock,
+        &mut clip_settings,
+        &mut language,
+        &mut safe_palette,
+        &mut follow_system_zone,
+        &mut reveal_hotkey,
+        model.time_reveal.status(),
+        system_zone_status.as_deref(),
+        &mut own_favorites,
+        &model.keymap,
+        &model.themes,
+        model.theme.as_deref(),
+    );
+
+    // Draw timezone picker (if open)
+    let picker_tz = match model.picker_state.target {
+        PickerTarget::Primary => current_tz,
+        PickerTarget::Comparison => comparison_tz.unwrap_or(current_tz),
+    };
+    let picker_result: PickerResult = draw_timezone_picker(
+        &ctx,
+        &mut model.picker_state,
+        picker_tz,
+        &favorites_clone,
+    );
+
+    // Draw the time card while the reveal shortcut flashes the window
+    model.time_reveal.show(&ctx, &time_data_clone);
+
+    // Offer the backup if the saved config couldn't be read
+    let recovery_choice = model.config_recovery.as_ref().and_then(|r| r.show(&ctx));
+
+    // Draw command palette (if open)
+    let palette_choice = model.command_palette.show(&ctx, model.keymap.registry());
+
+    let text_input_focused = ctx.wants_keyboard_input();
+
+    // Apply results
+    drop(ctx);
+    if let Some(choice) = recovery_choice {
+        recover_config(app, model, choice);
+    }
+
+    model.text_input_focused = text_input_focused;
+    model.calendar_path = calendar_path;
+    model.waypoint_note = waypoint_note;
+
+    // Handle panel result
+    if panel_result.open_picker {
+        model.picker_state.open();
+    }
+    if panel_result.open_comparison_picker {
+        model.picker_state.open_for(PickerTarget::Comparison);
+    }
+    if panel_result.comparison_toggled && show_comparison != model.show_comparison {
+        model.toggle_comparison();
+    }
+    if panel_result.return_to_now {
+        model.return_to_live();
+    }
+    if panel_result.replay_seconds_changed {
+        model.replay_seconds = replay_seconds;
+        save_config(model);
+    }
+    if panel_result.start_replay {
+        model.start_replay();
+    }
+    if panel_result.reduced_motion_changed {
+        model.set_motion_preference(motion_preference);
+        save_config(model);
+    }
+    if panel_result.quiet_hours_changed {
+        model.quiet_hours = quiet_hours;
+        report_save(
+            "quiet hours",
+            model.quiet_hours.save(),
+            |message| model.export_status = Some(message),
+        );
+    }
+    if panel_result.window_title_changed {
+        model.window_title_clock = window_title_clock;
+        report_save(
+            "window title setting",
+            model.window_title_clock.save(),
+            |message| model.export_status = Some(message),
+        );
+    }
+    if panel_result.clip_settings_changed {
+        model.clip_settings = clip_settings;
+        report_save(
+            "recording settings",
+            model.clip_settings.save(),
+            |message| model.export_status = Some(message),
+        );
+    }
+    if panel_result.language_changed {
+        model.language = language;
+        report_save(
+            "language setting",
+            model.language.save(),
+            |message| model.export_status = Some(message),
+        );
+    }
+    if panel_result.safe_palette_changed {
+        model.safe_palette = safe_palette;
+        report_save(
+            "palette setting",
+            model.safe_palette.save(),
+            |message| model.export_status = Some(message),
+        );
+    }
+    if panel_result.system_zone_changed {
+        model.follow_system_zone = follow_system_zone;
+        report_save(
+            "system zone setting",
+            model.follow_system_zone.save(),
+            |message| model.export_status = Some(message),
+        );
+    }
+    if panel_result.reveal_hotkey_changed {
+        model.time_reveal.setting = reveal_hotkey;
+        model.time_reveal.apply();
+    }
+    if panel_result.favorites_source_changed {
+        model.favorites.set_uses_own(own_favorites);
+        save_config(model);
+    }
+    if let Some(edit) = panel_result.keymap_edit {
+        model.keymap.apply(edit);
+    }
+    if panel_result.legend_toggled {
+        model.show_legend = show_legend;
+        save_config(model);
+    }
+    if panel_result.contours_toggled {
+        model.show_contours = show_contours;
+        save_config(model);
+    }
+    if panel_result.relief_toggled {
+        model.show_relief = show_relief;
+        save_config(model);
+    }
+    if panel_result.load_calendar {
+        model.load_calendar();
+        save_config(model);
+    }
+    if panel_result.export_terrain {
+        model.export_terrain();
+    }
+    if panel_result.terrain_source_changed {
+        model.terrain_source = terrain_source;
+        if terrain_source == TerrainSource::Calendar && model.calendar.is_none() {
+            model.load_calendar();
+        }
+        model.refresh_terrain_params();
+        save_config(model);
+    }
+    model.profile_day = profile_day;
+    if panel_result.profile_edited {
+        model.intensity_profiles = intensity_profiles;
+        model.refresh_terrain_params();
+    }
+    if panel_result.save_profile {
+        save_config(model);
+    }
+
+    if panel_result.show_day {
+        let today = model.today_index();
+        model.set_week_view(WeekView::day(today));
+    }
+    if panel_result.show_week {
+        model.set_week_view(WeekView::week());
+    }
+    if let Some(theme) = panel_result.set_theme {
+        model.theme = model.themes.activate(theme.as_deref());
+        save_config(model);
+    }
+
+    // Waypoint edits apply first, so the indices below match the edited list
+    if panel_result.waypoints_edited {
+        model.waypoints.replace(current_tz, waypoints);
+        save_config(model);
+    }
+    if let Some(index) = panel_result.remove_waypoint {
+        if model.waypoints.remove(current_tz, index).is_some() {
+            save_config(model);
+        }
+    }
+    if let Some(index) = panel_result.jump_to_waypoint {
+        model.inspect_waypoint(index);
+    }
+    if panel_result.pin_waypoint {
+        let note = std::mem::take(&mut model.waypoint_note);
+        model.pin_waypoint(&note);
+    }
+
+    // Handle picker result
+    let picker_target = model.picker_state.target;
+    if let (Some(tz), PickerTarget::Comparison) = (picker_result.selected_tz, picker_target) {
+        model.comparison_tz = Some(tz);
+        model.show_comparison = true;
+        model.refresh_comparison();
+        save_config(model);
+    } else if let Some(tz) = picker_result.selected_tz {
+        select_zone(model, tz, now);
+    }
+    if let Some(tz) = picker_result.toggle_favorite {
+        model.favorites.toggle(tz);
+        save_config(model);
+    }
+    if picker_result.close_picker {
+        model.picker_state.close();
+    }
+
+    // Run the command chosen in the palette as if its keys were pressed
+    if let Some(chord) = palette_choice {
+        handle_key(model, chord.key, chord.mods);
+    }
+
+    // Keep the screen reader's time and DST status current
+    model.screen_reader.update::<NoFocusRegions>(&model.time_data, None);
+
+    // Pick up favorites starred in another clock
+    model.favorites.poll();
+
+    // Follow the computer's time zone when it changes
+    if let Some(change) = model.system_zone.poll() {
+        let switch = model.follow_system_zone.enabled && change.current != model.selected_tz;
+        if switch {
+            select_zone(model, change.current, now);
+        }
+        model.system_zone_status = Some(change.message(switch));
+    }
+
+    // Follow the system reduce-motion setting when set to Auto
+    if model.system_motion.poll() {
+        model.set_motion_preference(model.motion_preference);
+    }
+
+    // Inspect an instant another clock pointed at
+    if let Some(LinkMessage::Inspect(instant)) = model.link.poll() {
+        model.inspect_linked(instant);
+    }
+
+    // Show the current time in the taskbar/dock title, and any clip recording
+    let badge = model.clip_recorder.indicator(Instant::now());
+    model.window_title.set_badge(badge);
+    model.window_title.update(
+        app.main_window().winit_window(),
+        &model.window_title_clock,
+        model.selected_tz,
+        shared::now_utc(),
+    );
+
+    // Show or end the reveal shortcut's flash
+    model.time_reveal.update(app.main_window().winit_window(), Instant::now());
+
+    // Remember where the window was moved or resized to
+    model.window_placement.track(app.main_window().winit_window(), Instant::now());
+
+    // Write config changes once they have settled
+    model.config_saver.poll(Instant::now());
+
+    // Idle between second boundaries once nothing is moving
+    pace_frames(app, model, Instant::now());
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    let window_rect = app.window_rect();
+
+    // Clear background
+    draw.background().color(colors::background());
+
+    // Calculate layout
+    let layout = MapLayout::calculate(window_rect, SIDE_PANEL_WIDTH);
+
+    // Get time fraction for beacon pulse animation
+    let time_fraction = model.time_data.second_fraction as f32;
+    let reduced_motion = model.reduced_motion || !model.intensity.animations;
+
+    if model.is_day_view() {
+        let replay = model.mode.replay();
+        let beacon_label = match replay {
+            Some(_) => model.time_data.format_time(),
+            None => tr("NOW").to_string(),
+        };
+        let beacon_position = replay.map_or(model.day_domain.normalized_position, |r| r.position());
+
+        // Draw the day map
+        draw_day_map(
+            &draw,
+            &layout,
+            &model.terrain_params,
+            &model.day_domain,
+            &model.hour_boundaries,
+            model.show_contours,
+            model.show_relief,
+            reduced_motion,
+            time_fraction,
+            (beacon_position, &beacon_label),
+        );
+
+        if let Some(replay) = replay {
+            draw_replay_sweep(
+                &draw,
+                &layout,
+                &model.terrain_params,
+                &model.day_domain,
+                replay.position(),
+            );
+        }
+
+        draw_waypoints(
+            &draw,
+            &layout,
+            &model.day_domain,
+            model.waypoints.for_zone(model.selected_tz),
+            model.active_waypoint(),
+        );
+
+        if let Some(comparison) = &model.comparison {
+            draw_comparison_terrain(
+                &draw,
+                &layout,
+                &model.day_domain,
+                comparison,
+                &zone_label(comparison.tz),
+            );
+        }
+
+        // Draw inspect cursor if in inspect mode
+        if let Mode::Inspecting { inspect_position, is_pinned } = &model.mode {
+            draw_inspect_cursor(&draw, &layout, *inspect_position, *is_pinned);
+            if let Some(note) = model.active_snap_note() {
+                draw_snap_tooltip(&draw, &layout, *inspect_position, note);
+            }
+        }
+
+        // Explain the DST fault a replay is paused at
+        if let Some(stop) = replay.and_then(|replay| replay.callout()) {
+            draw_replay_callout(&draw, &layout, stop.position, &stop.callout);
+        }
+    } else {
+        // Draw the visible days side by side
+        let today = model.today_index();
+        let panels: Vec<(DayPanel, f32, f32)> = model
+            .week_view
+            .panels(layout.left, layout.width)
+            .into_iter()
+            .map(|(idx, left, right)| {
+                let panel = DayPanel {
+                    domain: &model.week_days[idx],
+                    params: if idx == today {
+                        &model.terrain_params
+                    } else {
+                        &model.week_params[idx]
+                    },
+                    hour_boundaries: &model.week_boundaries[idx],
+                    label: day_label(&model.week_days[idx], model.selected_tz),
+                    is_today: idx == today,
+                };
+                (panel, left, right)
+            })
+            .collect();
+        draw_week_map(
+            &draw,
+            &layout,
+            &panels,
+            model.show_contours,
+            model.show_relief,
+            reduced_motion,
+            time_fraction,
+        );
+    }
+
+    // Draw hover tooltip when mouse is over map (and not in pinned inspect mode)
+    let is_pinned = matches!(&model.mode, Mode::Inspecting { is_pinned: true, .. });
+    if !is_pinned {
+        if let Some(mouse_pos) = model.mouse_position {
+            if layout.contains(mouse_pos.x, mouse_pos.y) {
+                let hover_time_str = if model.is_day_view() {
+                    let hover_position = layout.x_to_position(mouse_pos.x);
+                    model.format_time_at_position(hover_position)
+                } else {
+                    let (idx, position) = model.week_view.locate(mouse_pos.x, layout.left, layout.width);
+                    let day = &model.week_days[idx];
+                    format!(
+                        "{} {}",
+                        day.local_date(model.selected_tz).format("%a"),
+                        format_time_in_day(day, position, false)
+                    )
+                };
+                draw_hover_tooltip(&draw, &layout, mouse_pos.x, mouse_pos.y, &hover_time_str);
+            }
+        }
+    }
+
+    // Elevation scale in the map's left margin
+    if model.show_legend {
+        draw_intensity_scale(&draw, &layout);
+    }
+
+    // Draw title
+    draw_title(&draw, window_rect);
+
+    // Draw help hints
+    draw_help_hints(&draw, &layout, window_rect, model.keymap.registry());
+
+    // Dim the map during quiet hours
+    if model.intensity.is_quiet() {
+        draw.rect()
+            .xy(window_rect.xy())
+            .wh(window_rect.wh())
+            .color(srgba(0.0, 0.0, 0.0, model.intensity.dim_alpha()));
+    }
+
+    // Render to frame
+    draw.to_frame(app, &frame).unwrap();
+
+    // Render egui on top
+    model.egui.draw_to_frame(&frame).unwrap();
+}
+
+/// Every action in the command palette, with the keys that perform it
+fn commands() -> CommandRegistry {
+    CommandRegistry::new()
+        .register("Return to now", KeyChord::new(Key::Escape))
+        .register("Open timezone picker", KeyChord::new(Key::Slash))
+        .register("Toggle pin", KeyChord::new(Key::Space))
+        .register("Pin waypoint", KeyChord::new(Key::P))
+        .register("Previous waypoint", KeyChord::new(Key::Up))
+        .register("Next waypoint", KeyChord::new(Key::Down))
+        .register("Next landmark", KeyChord::new(Key::N))
+        .register("Previous landmark", KeyChord::shift(Key::N))
+        .register("Step back one minute", KeyChord::new(Key::Left))
+        .register("Step forward one minute", KeyChord::new(Key::Right))
+        .register("Step back one hour", KeyChord::shift(Key::Left))
+        .register("Step forward one hour", KeyChord::shift(Key::Right))
+        .register("Toggle week view", KeyChord::new(Key::W))
+        .register("Show more days", KeyChord::new(Key::Minus))
+        .register("Show fewer days", KeyChord::new(Key::Equals))
+        .register("Scroll to earlier days", KeyChord::new(Key::Comma))
+        .register("Scroll to later days", KeyChord::new(Key::Period))
+        .register("Toggle contour lines", KeyChord::new(Key::C))
+        .register("Toggle 3D relief", KeyChord::new(Key::D))
+        .register("Export terrain heightmap and profile", KeyChord::new(Key::E))
+        .register("Toggle reduced motion", KeyChord::new(Key::R))
+        .register("Toggle comparison zone", KeyChord::new(Key::O))
+        .register("Record clip", KeyChord::ctrl_shift(Key::R))
+}
+
+fn key_pressed(app: &App, model: &mut Model, key: Key) {
+    let mods = app.keys.mods;
+
+    // While a shortcut is being rebound, the next chord becomes its binding
+    if model.keymap.capture(key, mods) {
+        return;
+    }
+
+    // Ctrl+K opens the command palette from anywhere; while open it takes
+    // every other key
+    if KeyChord::is_palette_toggle(key, mods) {
+        model.command_palette.toggle();
+        return;
+    }
+    if model.command_palette.is_open {
+        return;
+    }
+
+    // Typing into a side panel text field shouldn't trigger shortcuts
+    if model.text_input_focused && !model.picker_state.is_open {
+        return;
+    }
+
+    // Remapped chords reach the handler as their action's default chord
+    if let Some(chord) = model.keymap.translate(key, mods) {
+        handle_key(model, chord.key, chord.mods);
+    }
+}
+
+fn handle_key(model: &mut Model, key: Key, mods: ModifiersState) {
+    match key {
+        // Ctrl+Shift+R - start or stop recording a clip
+        _ if shared::is_clip_toggle(key, mods) => {
+            let now = Instant::now();
+            let message = model.clip_recorder.toggle(model.clip_settings, now);
+            model.export_status = Some(message);
+        }
+
+        // Escape - close picker or return to live
+        Key::Escape => {
+            if model.picker_state.is_open {
+                model.picker_state.close();
+            } else if model.mode != Mode::Live {
+                model.return_to_live();
+            } else if !model.is_day_view() {
+                let today = model.today_index();
+                model.set_week_view(WeekView::day(today));
+            }
+        }
+
+        // Enter/Space - toggle pin in inspect mode
+        Key::Return | Key::Space if !model.picker_state.is_open && model.mode.is_inspecting() => {
+            model.toggle_pin();
+        }
+
+        // Slash - focus search / open picker
+        Key::Slash => {
+            if !model.picker_state.is_open {
+                model.picker_state.open();
+            } else {
+                model.picker_state.should_focus_search = true;
+            }
+        }
+
+        // P - pin the inspected minute as a waypoint
+        Key::P if !model.picker_state.is_open && model.mode.is_inspecting() => {
+            model.pin_waypoint("");
+        }
+
+        // Up/Down - cycle between waypoints
+        Key::Up if !model.picker_state.is_open => {
+            model.cycle_waypoint(false);
+        }
+        Key::Down if !model.picker_state.is_open => {
+            model.cycle_waypoint(true);
+        }
+
+        // N / Shift+N - snap to the next or previous landmark
+        Key::N if !model.picker_state.is_open => {
+            model.snap_to_landmark(!mods.shift());
+        }
+
+        // Arrow keys - scroll days when several are shown
+        Key::Left if !model.picker_state.is_open && !model.is_day_view() => {
+            model.scroll_week(-1);
+        }
+        Key::Right if !model.picker_state.is_open && !model.is_day_view() => {
+            model.scroll_week(1);
+        }
+
+        // Arrow keys - step inspection cursor
+        Key::Left => {
+            if mods.shift() {
+                model.adjust_inspect(-60); // -1 hour
+            } else {
+                model.adjust_inspect(-1); // -1 minute
+            }
+        }
+        Key::Right => {
+            if mods.shift() {
+                model.adjust_inspect(60); // +1 hour
+            } else {
+                model.adjust_inspect(1); // +1 minute
+            }
+        }
+
+        // W - toggle between today and the whole week
+        Key::W if !model.picker_state.is_open => {
+            model.toggle_week_view();
+        }
+
+        // -/= - show more or fewer days
+        Key::Minus if !model.picker_state.is_open => {
+            model.zoom_week_from_keyboard(1);
+        }
+        Key::Equals if !model.picker_state.is_open => {
+            model.zoom_week_from_keyboard(-1);
+        }
+
+        // ,/. - scroll the visible days
+        Key::Comma if !model.picker_state.is_open => {
+            model.scroll_week(-1);
+        }
+        Key::Period if !model.picker_state.is_open => {
+            model.scroll_week(1);
+        }
+
+        // C - toggle contour lines
+        Key::C if !model.picker_state.is_open => {
+            model.show_contours = !model.show_contours;
+            save_config(model);
+        }
+
+        // D - toggle the 3D relief
+        Key::D if !model.picker_state.is_open => {
+            model.show_relief = !model.show_relief;
+            save_config(model);
+        }
+
+        // E - export the terrain as a heightmap and profile
+        Key::E if !model.picker_state.is_open => {
+            model.export_terrain();
+        }
+
+        // O - toggle the comparison zone overlay
+        Key::O if !model.picker_state.is_open => {
+            model.toggle_comparison();
+        }
+
+        // R - toggle reduced motion
+        Key::R if !model.picker_state.is_open => {
+            let preference = model.motion_preference.toggled(model.system_motion.reduced());
+            model.set_motion_preference(preference);
+            save_config(model);
+        }
+
+        _ => {}
+    }
+}
+
+fn mouse_pressed(app: &App, model: &mut Model, button: MouseButton) {
+    if button == MouseButton::Left && !model.picker_state.is_open {
+        let mouse_pos = app.mouse.position();
+        let window_rect = app.window_rect();
+        let layout = MapLayout::calculate(window_rect, SIDE_PANEL_WIDTH);
+
+        // In the multi-day view a click zooms into that day
+        if !model.is_day_view() {
+            if layout.contains(mouse_pos.x, mouse_pos.y) && !model.week_view.is_single_day() {
+                let (idx, _) = model.week_view.locate(mouse_pos.x, layout.left, layout.width);
+                model.set_week_view(WeekView::day(idx));
+            }
+            return;
+        }
+
+        // Check if click is within the map canvas
+        if layout.contains(mouse_pos.x, mouse_pos.y) {
+            let now = Instant::now();
+            
+            // Check for double-click (within 300ms)
+            if let Some(last_click) = model.last_click_time {
+                if now.duration_since(last_click).as_millis() < 300 {
+                    // Double-click detected - return to live mode
+                    model.return_to_live();
+                    model.last_click_time = None;
+                    return;
+                }
+            }
+            
+            model.last_click_time = Some(now);
+            
+            let position = layout.x_to_position(mouse_pos.x);
+
+            // If already inspecting this position, toggle pin
+            if let Mode::Inspecting { inspect_position, .. } = &model.mode {
+                if (*inspect_position - position).abs() < 0.01 {
+                    model.toggle_pin();
+                    return;
+                }
+            }
+
+            model.enter_inspect(position);
+        }
+    }
+}
+
+fn mouse_moved(app: &App, model: &mut Model, pos: Point2) {
+    model.mouse_position = Some(pos);
+
+    // If in unpinned inspect mode, follow the mouse (snapped to minute)
+    if let Mode::Inspecting { is_pinned: false, .. } = &model.mode {
+        let window_rect = app.window_rect();
+        let layout = MapLayout::calculate(window_rect, SIDE_PANEL_WIDTH);
+
+        if layout.contains(pos.x, pos.y) {
+            let position = layout.x_to_position(pos.x);
+            let snapped = model.day_domain.snap_to_minute(position);
+            model.mode = Mode::Inspecting {
+                inspect_position: snapped,
+                is_pinned: false,
+            };
+        }
+    }
+}
+
+fn mouse_wheel(app: &App, model: &mut Model, delta: MouseScrollDelta, _phase: TouchPhase) {
+    if model.picker_state.is_open {
+        return;
+    }
+
+    // Only the map canvas zooms; the side panel scrolls itself
+    let mouse_pos = app.mouse.position();
+    let layout = MapLayout::calculate(app.window_rect(), SIDE_PANEL_WIDTH);
+    if !layout.contains(mouse_pos.x, mouse_pos.y) {
+        return;
+    }
+
+    // Trackpads report pixels; treat ~40px as one wheel notch
+    model.wheel_accumulator += match delta {
+        MouseScrollDelta::LineDelta(x, y) => vec2(x, y),
+        MouseScrollDelta::PixelDelta(pos) => vec2(pos.x as f32, pos.y as f32) / 40.0,
+    };
+
+    // Vertical wheel zooms around the day under the mouse (up = fewer days)
+    let zoom_steps = model.wheel_accumulator.y.trunc();
+    if zoom_steps != 0.0 {
+        model.wheel_accumulator.y -= zoom_steps;
+        let (anchor, _) = model.week_view.locate(mouse_pos.x, layout.left, layout.width);
+        model.zoom_week(-zoom_steps as i32, anchor);
+    }
+
+    // Horizontal wheel scrolls between days
+    let scroll_steps = model.wheel_accumulator.x.trunc();
+    if scroll_steps != 0.0 {
+        model.wheel_accumulator.x -= scroll_steps;
+        model.scroll_week(scroll_steps as i32);
+    }
+}
+
+fn raw_window_event(app: &App, model: &mut Model, event: &nannou::winit::event::WindowEvent) {
+    // Any window event brings an idle clock back to full frame rate
+    model.frame_pacer.note_input(Instant::now());
+
+    // Let egui handle raw events
+    model.egui.handle_raw_event(event);
+    model.screen_reader.process_event(app.main_window().winit_window(), event);
+}
+
@@ -0,0 +1,280 @@
+//! Profile module - hand-drawn intensity profiles for each weekday
+//!
+//! An intensity curve runs through control points (hour of day, intensity)
+//! and says how intense each part of the day feels: "mornings are intense,
+//! evenings calm". Each weekday has its own curve. The custom terrain source
+//! raises the terrain along the curve, mixed with the synthetic waves by a
+//! blend amount from 0 (synthetic only) to 1 (the curve replaces them).
+
+use chrono::Weekday;
+use serde::{Deserialize, Serialize};
+
+/// Hours spanned by a curve; the end points sit at 0 and 24
+pub const DAY_HOURS: f32 = 24.0;
+
+/// Most control points a curve can have
+pub const MAX_POINTS: usize = 24;
+
+/// Closest two control points can get, in hours
+const MIN_GAP_HOURS: f32 = 0.25;
+
+/// A control point on an intensity curve
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ProfilePoint {
+    /// Hour of the day, 0 to 24
+    pub hour: f32,
+    /// Intensity, 0 (calm) to 1 (intense)
+    pub intensity: f32,
+}
+
+impl ProfilePoint {
+    pub const fn new(hour: f32, intensity: f32) -> Self {
+        Self { hour, intensity }
+    }
+}
+
+/// A day's intensity, eased between control points
+///
+/// Points are kept in hour order, with the first at midnight and the last at
+/// the next midnight.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IntensityCurve {
+    points: Vec<ProfilePoint>,
+}
+
+impl Default for IntensityCurve {
+    /// Quiet nights, a busy late morning, a lull after lunch and a calm evening
+    fn default() -> Self {
+        Self {
+            points: vec![
+                ProfilePoint::new(0.0, 0.1),
+                ProfilePoint::new(6.0, 0.2),
+                ProfilePoint::new(10.0, 0.8),
+                ProfilePoint::new(13.0, 0.55),
+                ProfilePoint::new(16.0, 0.7),
+                ProfilePoint::new(20.0, 0.35),
+                ProfilePoint::new(24.0, 0.1),
+            ],
+        }
+    }
+}
+
+impl IntensityCurve {
+    pub fn points(&self) -> &[ProfilePoint] {
+        &self.points
+    }
+
+    /// Intensity at position `p` along the day [0..1], in [0..1]
+    pub fn sample(&self, p: f32) -> f32 {
+        let hour = p.clamp(0.0, 1.0) * DAY_HOURS;
+        let next = self.points.partition_point(|point| point.hour < hour);
+        match (next.checked_sub(1).map(|i| self.points[i]), self.points.get(next).copied()) {
+            (Some(a), Some(b)) => {
+                let t = (hour - a.hour) / (b.hour - a.hour).max(f32::EPSILON);
+                // Smoothstep, so the terrain levels off at each control point
+                let eased = t * t * (3.0 - 2.0 * t);
+                a.intensity + (b.intensity - a.intensity) * eased
+            }
+            (None, Some(point)) | (Some(point), None) => point.intensity,
+            (None, None) => 0.5,
+        }
+    }
+
+    /// Move the point at `index`, keeping the hour order and the end points
+    /// at midnight
+    pub fn move_point(&mut self, index: usize, hour: f32, intensity: f32) {
+        let last = self.points.len() - 1;
+        let hour = if index == 0 {
+            0.0
+        } else if index == last {
+            DAY_HOURS
+        } else {
+            let min = self.points[index - 1].hour + MIN_GAP_HOURS;
+            let max = self.points[index + 1].hour - MIN_GAP_HOURS;
+            hour.clamp(min, max.max(min))
+        };
+        self.points[index] = ProfilePoint::new(hour, intensity.clamp(0.0, 1.0));
+    }
+
+    /// Add a point, returning its index; `None` when the curve is full or
+    /// the point would crowd a neighbor
+    pub fn insert_point(&mut self, hour: f32, intensity: f32) -> Option<usize> {
+        if self.points.len() >= MAX_POINTS || hour <= 0.0 || hour >= DAY_HOURS {
+            return None;
+        }
+        let index = self.points.partition_point(|point| point.hour < hour);
+        let crowded = [index.checked_sub(1), Some(index)]
+            .into_iter()
+            .flatten()
+            .filter_map(|i| self.points.get(i))
+            .any(|point| (point.hour - hour).abs() < MIN_GAP_HOURS);
+        if crowded {
+            return None;
+        }
+        self.points
+            .insert(index, ProfilePoint::new(hour, intensity.clamp(0.0, 1.0)));
+        Some(index)
+    }
+
+    /// Remove an inner point; the end points stay
+    pub fn remove_point(&mut self, index: usize) -> bool {
+        if index == 0 || index + 1 >= self.points.len() {
+            return false;
+        }
+        self.points.remove(index);
+        true
+    }
+
+    /// Put a hand-edited curve back in order: hours sorted and clamped,
+    /// intensities clamped, and end points at both midnights
+    fn repair(&mut self) {
+        self.points
+            .retain(|point| point.hour.is_finite() && point.intensity.is_finite());
+        for point in &mut self.points {
+            point.hour = point.hour.clamp(0.0, DAY_HOURS);
+            point.intensity = point.intensity.clamp(0.0, 1.0);
+        }
+        self.points.sort_by(|a, b| a.hour.total_cmp(&b.hour));
+        self.points.truncate(MAX_POINTS);
+
+        match (self.points.first().copied(), self.points.last().copied()) {
+            (Some(first), Some(last)) => {
+                if first.hour > 0.0 {
+                    self.points.insert(0, ProfilePoint::new(0.0, first.intensity));
+                }
+                if last.hour < DAY_HOURS || self.points.len() == 1 {
+                    self.points.push(ProfilePoint::new(DAY_HOURS, last.intensity));
+                }
+            }
+            _ => *self = Self::default(),
+        }
+    }
+}
+
+/// Intensity curves for the seven weekdays and how strongly they shape the
+/// custom terrain
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntensityProfiles {
+    /// 0 keeps the synthetic waves, 1 replaces them with the curve
+    #[serde(default = "default_blend")]
+    pub blend: f32,
+    /// Monday first
+    #[serde(default)]
+    days: Vec<IntensityCurve>,
+}
+
+fn default_blend() -> f32 {
+    1.0
+}
+
+impl Default for IntensityProfiles {
+    fn default() -> Self {
+        Self {
+            blend: default_blend(),
+            days: vec![IntensityCurve::default(); 7],
+        }
+    }
+}
+
+impl IntensityProfiles {
+    /// Seven valid curves and a blend in [0..1], whatever the config held
+    pub fn repaired(mut self) -> Self {
+        self.blend = if self.blend.is_finite() {
+            self.blend.clamp(0.0, 1.0)
+        } else {
+            default_blend()
+        };
+        self.days.resize_with(7, IntensityCurve::default);
+        for curve in &mut self.days {
+            curve.repair();
+        }
+        self
+    }
+
+    pub fn day(&self, weekday: Weekday) -> &IntensityCurve {
+        &self.days[weekday.num_days_from_monday() as usize]
+    }
+
+    pub fn day_mut(&mut self, weekday: Weekday) -> &mut IntensityCurve {
+        &mut self.days[weekday.num_days_from_monday() as usize]
+    }
+
+    /// Give every weekday `weekday`'s curve
+    pub fn copy_to_all(&mut self, weekday: Weekday) {
+        let curve = self.day(weekday).clone();
+        self.days.fill(curve);
+    }
+
+    /// Restore `weekday`'s default curve
+    pub fn reset_day(&mut self, weekday: Weekday) {
+        *self.day_mut(weekday) = IntensityCurve::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_eases_between_points() {
+        let curve = IntensityCurve::default();
+        assert!((curve.sample(0.0) - 0.1).abs() < 1e-6);
+        assert!((curve.sample(10.0 / 24.0) - 0.8).abs() < 1e-6);
+        assert!((curve.sample(1.0) - 0.1).abs() < 1e-6);
+        // Halfway between 06:00 (0.2) and 10:00 (0.8)
+        assert!((curve.sample(8.0 / 24.0) - 0.5).abs() < 1e-6);
+        for i in 0..=96 {
+            assert!((0.0..=1.0).contains(&curve.sample(i as f32 / 96.0)));
+        }
+    }
+
+    #[test]
+    fn test_editing_keeps_order_and_end_points() {
+        let mut curve = IntensityCurve::default();
+        // End points stay at midnight
+        curve.move_point(0, 5.0, 1.4);
+        assert_eq!(curve.points()[0], ProfilePoint::new(0.0, 1.0));
+        // Inner points can't pass their neighbors
+        curve.move_point(2, 20.0, 0.9);
+        assert_eq!(curve.points()[2].hour, 13.0 - MIN_GAP_HOURS);
+
+        let index = curve.insert_point(8.0, 0.6).unwrap();
+        assert_eq!(curve.points()[index].hour, 8.0);
+        assert!(curve.points().windows(2).all(|w| w[0].hour < w[1].hour));
+        assert_eq!(curve.insert_point(8.1, 0.6), None);
+        assert_eq!(curve.insert_point(24.0, 0.6), None);
+
+        assert!(curve.remove_point(index));
+        assert!(!curve.remove_point(0));
+        assert!(!curve.remove_point(curve.points().len() - 1));
+    }
+
+    #[test]
+    fn test_repair_hand_edited_config() {
+        // Out of order, out of range, missing end points and missing days
+        let profiles = IntensityProfiles {
+            blend: 3.0,
+            days: vec![
+                IntensityCurve {
+                    points: vec![ProfilePoint::new(18.0, 0.9), ProfilePoint::new(9.0, -1.0)],
+                },
+                IntensityCurve { points: Vec::new() },
+            ],
+        }
+        .repaired();
+
+        assert_eq!(profiles.blend, 1.0);
+        assert_eq!(
+            profiles.day(Weekday::Mon).points(),
+            [
+                ProfilePoint::new(0.0, 0.0),
+                ProfilePoint::new(9.0, 0.0),
+                ProfilePoint::new(18.0, 0.9),
+                ProfilePoint::new(24.0, 0.9),
+            ]
+        );
+        assert_eq!(profiles.day(Weekday::Tue), &IntensityCurve::default());
+        assert_eq!(profiles.day(Weekday::Sun), &IntensityCurve::default());
+    }
+}
@@ -0,0 +1,184 @@
+//! Relief module - pseudo-3D view of the terrain
+//!
+//! Extrudes the day's elevation profile into a ridge that recedes from the
+//! viewer. Time still runs left to right: the front edge of the ridge is the
+//! flat map's profile, so the beacon, contours and cursor stay on it, while
+//! rows further back shrink toward a vanishing point above the canvas and the
+//! ridge settles to the valley floor. The grid is sized from the canvas with
+//! a fixed cell budget, so full-screen windows stay smooth.
+
+/// How fast rows shrink with depth: the back row is drawn at 1 / (1 + PERSPECTIVE)
+const PERSPECTIVE: f32 = 1.5;
+
+/// Canvas pixels per column and per row at full detail
+const COLUMN_PX: f32 = 4.0;
+const ROW_PX: f32 = 14.0;
+
+const MIN_COLUMNS: usize = 48;
+const MIN_ROWS: usize = 6;
+const MAX_ROWS: usize = 40;
+
+/// Most grid cells drawn for one map, however large the canvas
+pub const MAX_CELLS: usize = 12_000;
+
+/// Brightness of a surface facing away from the light
+const AMBIENT: f32 = 0.45;
+
+/// Light from the upper left, slightly behind the ridge
+const LIGHT: [f32; 3] = [-0.5, 0.3, 0.8];
+
+/// Elevation change per unit of day position that reads as a 45° slope
+const SLOPE_SCALE: f32 = 8.0;
+
+/// Rows and columns of the relief mesh
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReliefGrid {
+    pub columns: usize,
+    pub rows: usize,
+}
+
+/// One mesh vertex, before projection
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReliefVertex {
+    /// Position [0..1] along the day
+    pub position: f32,
+    /// Depth from the front edge (0) to the back (1)
+    pub depth: f32,
+    /// Ridge elevation [-1..1], or `None` inside a DST gap
+    pub elevation: Option<f32>,
+    /// Lighting [AMBIENT..1]
+    pub shade: f32,
+}
+
+impl ReliefGrid {
+    /// Level of detail for a canvas of this size
+    pub fn for_size(width: f32, height: f32) -> Self {
+        let rows = ((height / ROW_PX) as usize).clamp(MIN_ROWS, MAX_ROWS);
+        let columns = ((width / COLUMN_PX) as usize).clamp(MIN_COLUMNS, MAX_CELLS / rows);
+        Self { columns, rows }
+    }
+
+    /// Position [0..1] along the day of a column
+    pub fn position(&self, column: usize) -> f32 {
+        column as f32 / (self.columns - 1) as f32
+    }
+
+    /// Depth [0..1] of a row, 0 being the front edge
+    pub fn depth(&self, row: usize) -> f32 {
+        row as f32 / (self.rows - 1) as f32
+    }
+
+    /// Vertices for the front profile `fronts` (one elevation per column),
+    /// back row first so nearer rows paint over it
+    pub fn vertices(&self, fronts: &[Option<f32>]) -> Vec<ReliefVertex> {
+        debug_assert_eq!(fronts.len(), self.columns);
+        let step = 1.0 / (self.columns - 1) as f32;
+
+        let mut vertices = Vec::with_capacity(self.columns * self.rows);
+        for row in (0..self.rows).rev() {
+            let depth = self.depth(row);
+            for (column, front) in fronts.iter().enumerate() {
+                let elevation = front.map(|front| ridge_elevation(front, depth));
+
+                // Slopes from the neighbours, falling back to one side at edges and gaps
+                let before = column.checked_sub(1).and_then(|c| fronts[c]).or(*front);
+                let after = fronts.get(column + 1).copied().flatten().or(*front);
+                let along = match (before, after) {
+                    (Some(before), Some(after)) => {
+                        let rise = ridge_elevation(after, depth) - ridge_elevation(before, depth);
+                        rise / (2.0 * step)
+                    }
+                    _ => 0.0,
+                };
+                let across = front.map_or(0.0, |front| -2.0 * depth * (front + 1.0));
+
+                vertices.push(ReliefVertex {
+                    position: self.position(column),
+                    depth,
+                    elevation,
+                    shade: shade(along, across),
+                });
+            }
+        }
+        vertices
+    }
+}
+
+/// Elevation `depth` behind the front edge: the ridge falls away smoothly to
+/// the lowest elevation at the back
+pub fn ridge_elevation(front: f32, depth: f32) -> f32 {
+    let falloff = 1.0 - depth * depth;
+    -1.0 + (front + 1.0) * falloff
+}
+
+/// Project a point of the flat map back to `depth`, shrinking it toward `vanishing`
+pub fn project(x: f32, y: f32, depth: f32, vanishing: (f32, f32)) -> (f32, f32) {
+    let scale = 1.0 / (1.0 + PERSPECTIVE * depth);
+    (
+        vanishing.0 + (x - vanishing.0) * scale,
+        vanishing.1 + (y - vanishing.1) * scale,
+    )
+}
+
+/// Brightness of the surface from its slopes along the day and into the
+/// distance (elevation change per unit position / depth)
+pub fn shade(along: f32, across: f32) -> f32 {
+    let normal = [-along / SLOPE_SCALE, -across, 1.0];
+    let length = normal.iter().map(|n| n * n).sum::<f32>().sqrt();
+    let light_length = LIGHT.iter().map(|l| l * l).sum::<f32>().sqrt();
+    let dot = normal.iter().zip(LIGHT).map(|(n, l)| n * l).sum::<f32>();
+    let facing = dot / (length * light_length);
+    AMBIENT + (1.0 - AMBIENT) * facing.max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_stays_within_budget() {
+        let small = ReliefGrid::for_size(400.0, 300.0);
+        assert_eq!(small, ReliefGrid { columns: 100, rows: 21 });
+
+        // A 4K canvas is capped rather than growing with the window
+        let large = ReliefGrid::for_size(3600.0, 2000.0);
+        assert_eq!(large.rows, MAX_ROWS);
+        assert!(large.columns * large.rows <= MAX_CELLS);
+
+        // Narrow week panels keep a usable minimum
+        assert_eq!(ReliefGrid::for_size(60.0, 40.0), ReliefGrid { columns: 48, rows: 6 });
+    }
+
+    #[test]
+    fn test_front_edge_matches_flat_map() {
+        assert!((ridge_elevation(0.4, 0.0) - 0.4).abs() < 1e-6);
+        assert_eq!(ridge_elevation(0.4, 1.0), -1.0);
+        assert_eq!(project(120.0, -40.0, 0.0, (0.0, 300.0)), (120.0, -40.0));
+
+        // The back row shrinks toward the vanishing point
+        let (x, y) = project(120.0, -40.0, 1.0, (0.0, 300.0));
+        assert!((x - 48.0).abs() < 1e-4);
+        assert!((y - 164.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_vertices_run_back_to_front() {
+        let grid = ReliefGrid { columns: 3, rows: 2 };
+        let vertices = grid.vertices(&[Some(0.0), None, Some(1.0)]);
+        assert_eq!(vertices.len(), 6);
+        assert_eq!(vertices[0].depth, 1.0);
+        assert_eq!(vertices[5].depth, 0.0);
+        assert_eq!(vertices[4].elevation, None);
+        assert_eq!(vertices[5].elevation, Some(1.0));
+    }
+
+    #[test]
+    fn test_slopes_facing_the_light_are_brighter() {
+        let flat = shade(0.0, 0.0);
+        // Rising to the right faces the light from the upper left
+        assert!(shade(4.0, 0.0) > flat);
+        assert!(shade(-4.0, 0.0) < flat);
+        assert!(shade(-100.0, 0.0) >= AMBIENT);
+        assert!(flat <= 1.0);
+    }
+}
@@ -0,0 +1,208 @@
+//! Replay module - sweep the beacon across the whole day
+//!
+//! A replay runs the day from midnight to midnight in a set number of
+//! seconds, pausing at each DST transition long enough to read a callout
+//! explaining what the fault on the map means. Stops are placed at the
+//! exact transition instants, so the beacon halts where the clocks change.
+
+use chrono::Duration;
+use chrono_tz::Tz;
+use shared::{query_transitions_between, tr_args};
+
+use crate::terrain::DayDomain;
+
+/// Shortest and longest replay the side panel offers, in seconds
+pub const REPLAY_SECONDS_RANGE: std::ops::RangeInclusive<f32> = 5.0..=120.0;
+
+/// Replay length until the user picks one
+pub const DEFAULT_REPLAY_SECONDS: f32 = 20.0;
+
+/// How long the replay holds at each DST transition
+const PAUSE_SECONDS: f32 = 4.0;
+
+/// A DST transition the replay pauses at
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayStop {
+    /// Normalized position in the day [0..1] of the transition
+    pub position: f32,
+    /// What happens there, in a sentence
+    pub callout: String,
+}
+
+/// An in-progress sweep across the day
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayReplay {
+    /// Seconds the whole day takes, excluding pauses
+    duration_secs: f32,
+    /// Normalized position of the beacon [0..1]
+    position: f32,
+    stops: Vec<ReplayStop>,
+    /// Index of the next stop not yet reached
+    next_stop: usize,
+    /// Seconds left to hold at the stop just reached
+    hold: f32,
+}
+
+impl DayReplay {
+    pub fn new(duration_secs: f32, stops: Vec<ReplayStop>) -> Self {
+        Self {
+            duration_secs: duration_secs.max(0.1),
+            position: 0.0,
+            stops,
+            next_stop: 0,
+            hold: 0.0,
+        }
+    }
+
+    /// Normalized position of the beacon
+    pub fn position(&self) -> f32 {
+        self.position
+    }
+
+    /// The stop being explained, while the replay holds there
+    pub fn callout(&self) -> Option<&ReplayStop> {
+        (self.hold > 0.0).then(|| &self.stops[self.next_stop - 1])
+    }
+
+    /// Whether the sweep has reached midnight
+    pub fn is_finished(&self) -> bool {
+        self.position >= 1.0 && self.hold <= 0.0
+    }
+
+    /// Move the sweep on by `dt` seconds of real time
+    pub fn advance(&mut self, mut dt: f32) {
+        while dt > 0.0 && !self.is_finished() {
+            if self.hold > 0.0 {
+                let held = dt.min(self.hold);
+                self.hold -= held;
+                dt -= held;
+                continue;
+            }
+
+            let target = self
+                .stops
+                .get(self.next_stop)
+                .map_or(1.0, |stop| stop.position);
+            let needed = (target - self.position) * self.duration_secs;
+            if dt < needed {
+                self.position += dt / self.duration_secs;
+                return;
+            }
+
+            dt -= needed.max(0.0);
+            self.position = target;
+            if self.next_stop < self.stops.len() {
+                self.next_stop += 1;
+                self.hold = PAUSE_SECONDS;
+            } else {
+                self.position = 1.0;
+            }
+        }
+    }
+}
+
+/// A stop for every DST transition during `day` in `tz`
+pub fn replay_stops(day: &DayDomain, tz: Tz) -> Vec<ReplayStop> {
+    query_transitions_between(tz, day.midnight_utc, day.next_midnight_utc)
+        .into_iter()
+        .filter(|t| t.instant_utc > day.midnight_utc && t.instant_utc < day.next_midnight_utc)
+        .map(|transition| {
+            let after = transition.instant_utc.with_timezone(&tz);
+            // The wall time the clocks read as they change, e.g. 2:00 AM
+            let before = after.naive_local() - Duration::minutes(transition.delta_minutes as i64);
+            let (from, to) = (
+                before.format("%-I:%M %p").to_string(),
+                after.format("%-I:%M %p").to_string(),
+            );
+            let amount = format_minutes(transition.delta_minutes.abs());
+            let day_length = format_minutes((day.day_length_seconds / 60) as i32);
+
+            let text = if transition.delta_minutes > 0 {
+                "At {0} clocks spring forward {1} to {2}. \
+                 The times between never happen, so today is {3} long."
+            } else {
+                "At {0} clocks fall back {1} to {2}. \
+                 The times between happen twice (A, then B), so today is {3} long."
+            };
+            let callout = tr_args(text, &[&from, &amount, &to, &day_length]);
+            let elapsed = (transition.instant_utc - day.midnight_utc).num_seconds();
+            ReplayStop {
+                position: day.ssm_to_position(elapsed),
+                callout,
+            }
+        })
+        .collect()
+}
+
+/// "1 hour", "30 minutes", "23 hours", "24 hours 30 minutes"
+fn format_minutes(minutes: i32) -> String {
+    let hours = |n: i32| tr_args(if n == 1 { "{0} hour" } else { "{0} hours" }, &[&n]);
+    let mins = |n: i32| tr_args(if n == 1 { "{0} minute" } else { "{0} minutes" }, &[&n]);
+    match (minutes / 60, minutes % 60) {
+        (0, m) => mins(m),
+        (h, 0) => hours(h),
+        (h, m) => format!("{} {}", hours(h), mins(m)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn stop(position: f32) -> ReplayStop {
+        ReplayStop {
+            position,
+            callout: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_replay_sweeps_and_holds_at_stops() {
+        let mut replay = DayReplay::new(10.0, vec![stop(0.25)]);
+        replay.advance(1.0);
+        assert!((replay.position() - 0.1).abs() < 1e-5);
+        assert!(replay.callout().is_none());
+
+        // Reaching the stop holds there; the rest of the step counts toward the hold
+        replay.advance(3.0);
+        assert_eq!(replay.position(), 0.25);
+        assert!(replay.callout().is_some());
+        replay.advance(PAUSE_SECONDS - 2.0);
+        assert_eq!(replay.position(), 0.25);
+
+        // After the hold the leftover time carries on sweeping
+        replay.advance(1.5);
+        assert!(replay.callout().is_none());
+        assert!((replay.position() - 0.35).abs() < 1e-5);
+
+        replay.advance(100.0);
+        assert!(replay.is_finished());
+        assert_eq!(replay.position(), 1.0);
+    }
+
+    #[test]
+    fn test_replay_stops_explain_transitions() {
+        // US spring forward: Sunday 9 March 2025, 2 AM local
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let noon = Utc.with_ymd_and_hms(2025, 3, 9, 16, 0, 0).unwrap();
+        let stops = replay_stops(&DayDomain::compute(noon, tz), tz);
+        assert_eq!(stops.len(), 1);
+        assert!((stops[0].position - 2.0 / 23.0).abs() < 1e-4);
+        assert_eq!(
+            stops[0].callout,
+            "At 2:00 AM clocks spring forward 1 hour to 3:00 AM. \
+             The times between never happen, so today is 23 hours long."
+        );
+
+        // Fall back: Sunday 2 November 2025
+        let noon = Utc.with_ymd_and_hms(2025, 11, 2, 17, 0, 0).unwrap();
+        let stops = replay_stops(&DayDomain::compute(noon, tz), tz);
+        assert!(stops[0].callout.starts_with("At 2:00 AM clocks fall back 1 hour to 1:00 AM."));
+        assert!(stops[0].callout.ends_with("today is 25 hours long."));
+
+        let ordinary = Utc.with_ymd_and_hms(2025, 6, 4, 16, 0, 0).unwrap();
+        assert!(replay_stops(&DayDomain::compute(ordinary, tz), tz).is_empty());
+        assert_eq!(format_minutes(30), "30 minutes");
+    }
+}
@@ -3,9 +3,14 @@
 //! Handles the mapping between time and topographic terrain coordinates,
 //! including special handling for DST transitions that create gaps or overlaps.
 
-use chrono::{DateTime, Datelike, Duration, NaiveTime, Offset, TimeZone, Timelike, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Offset, TimeZone, Timelike, Utc};
 use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
 use std::f32::consts::TAU;
+use std::sync::Arc;
+
+use crate::calendar::BusynessProfile;
+use crate::profile::IntensityCurve;
 
 /// Information about a DST fault line within the current day
 #[derive(Debug, Clone)]
@@ -43,10 +48,15 @@ pub struct DayDomain {
 impl DayDomain {
     /// Compute the day domain for a given instant and timezone
     pub fn compute(instant: DateTime<Utc>, tz: Tz) -> Self {
-        let local = instant.with_timezone(&tz);
-        
-        // Get local midnight (start of today)
-        let local_date = local.date_naive();
+        Self::compute_for_date(instant.with_timezone(&tz).date_naive(), tz, instant)
+    }
+
+    /// Compute the day domain for a local calendar date
+    ///
+    /// `instant` only places the position; for other days it clamps to the
+    /// start or end of the day.
+    pub fn compute_for_date(local_date: NaiveDate, tz: Tz, instant: DateTime<Utc>) -> Self {
+        // Get local midnight (start of the day)
         let midnight_local = tz
             .from_local_datetime(&local_date.and_time(NaiveTime::from_hms_opt(0, 0, 0).unwrap()))
             .single()
@@ -97,6 +107,11 @@ impl DayDomain {
         }
     }
     
+    /// The local calendar date this domain covers
+    pub fn local_date(&self, tz: Tz) -> NaiveDate {
+        self.midnight_utc.with_timezone(&tz).date_naive()
+    }
+
     /// Convert a normalized position [0..1] to seconds since midnight
     pub fn position_to_ssm(&self, p: f32) -> i64 {
         (p * self.day_length_seconds as f32) as i64
@@ -202,8 +217,30 @@ impl DayDomain {
     }
 }
 
+/// Day domains for the seven days (Monday first) of the week containing `instant`
+pub fn week_domains(instant: DateTime<Utc>, tz: Tz) -> Vec<DayDomain> {
+    let today = instant.with_timezone(&tz).date_naive();
+    let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+
+    (0..7)
+        .map(|i| DayDomain::compute_for_date(monday + Duration::days(i), tz, instant))
+        .collect()
+}
+
+/// What shapes the terrain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TerrainSource {
+    /// Deterministic waves driven by the current time
+    #[default]
+    Synthetic,
+    /// Busyness from an imported calendar
+    Calendar,
+    /// Hand-drawn intensity curve for each weekday
+    Custom,
+}
+
 /// Terrain parameters extracted from time data
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct TerrainParams {
     /// Hour in 12-hour format (1-12)
     pub hour12: u32,
@@ -213,6 +250,12 @@ pub struct TerrainParams {
     pub second: u32,
     /// Day of year (1-366)
     pub day_of_year: u32,
+    /// Calendar busyness for the day; when set, replaces the synthetic shape
+    pub busyness: Option<Arc<BusynessProfile>>,
+    /// Hand-drawn intensity curve for the day, mixed into the synthetic shape
+    pub profile: Option<Arc<IntensityCurve>>,
+    /// How much of the shape comes from `profile`, 0 to 1
+    pub profile_blend: f32,
 }
 
 impl TerrainParams {
@@ -230,8 +273,34 @@ impl TerrainParams {
             minute: dt.minute(),
             second: dt.second(),
             day_of_year: dt.ordinal(),
+            busyness: None,
+            profile: None,
+            profile_blend: 0.0,
+        }
+    }
+
+    /// Same shape parameters for another day of the year (week panels)
+    pub fn for_day_of_year(&self, day_of_year: u32) -> Self {
+        Self {
+            day_of_year,
+            busyness: None,
+            profile: None,
+            ..self.clone()
         }
     }
+
+    /// Shape the terrain by calendar busyness instead of synthetic waves
+    pub fn with_busyness(mut self, busyness: Arc<BusynessProfile>) -> Self {
+        self.busyness = Some(busyness);
+        self
+    }
+
+    /// Mix a hand-drawn intensity curve into the synthetic waves
+    pub fn with_profile(mut self, profile: Arc<IntensityCurve>, blend: f32) -> Self {
+        self.profile = Some(profile);
+        self.profile_blend = blend;
+        self
+    }
 }
 
 /// Compute the terrain elevation at a normalized position p in [0..1]
@@ -241,8 +310,28 @@ impl TerrainParams {
 /// - The current hour, minute, second (creates the terrain shape)
 /// - The day of year (adds daily variation)
 ///
+/// When the params carry a calendar busyness profile, the calendar shape is
+/// used instead (see [`calendar_elevation`]); an intensity curve is blended
+/// in (see [`profile_elevation`]).
+///
 /// Returns a value in [-1..1]
 pub fn terrain_elevation(p: f32, params: &TerrainParams) -> f32 {
+    if let Some(busyness) = &params.busyness {
+        return calendar_elevation(p, busyness, params);
+    }
+
+    let synthetic = synthetic_elevation(p, params);
+    match &params.profile {
+        Some(profile) => {
+            let blend = params.profile_blend.clamp(0.0, 1.0);
+            synthetic + (profile_elevation(p, profile, params) - synthetic) * blend
+        }
+        None => synthetic,
+    }
+}
+
+/// The deterministic waves driven by the time, in [-1..1]
+fn synthetic_elevation(p: f32, params: &TerrainParams) -> f32 {
     let h_norm = params.hour12 as f32 / 12.0;
     let m_norm = params.minute as f32 / 60.0;
     let s_norm = params.second as f32 / 60.0;
@@ -256,6 +345,40 @@ pub fn terrain_elevation(p: f32, params: &TerrainParams) -> f32 {
     elevation.clamp(-1.0, 1.0)
 }
 
+/// Compute the calendar-driven elevation at a normalized position p in [0..1]
+///
+/// Meetings raise the terrain toward the peaks and free time sinks into
+/// valleys; a small minute/second ripple keeps the surface alive.
+///
+/// Returns a value in [-1..1]
+pub fn calendar_elevation(p: f32, busyness: &BusynessProfile, params: &TerrainParams) -> f32 {
+    let m_norm = params.minute as f32 / 60.0;
+    let s_norm = params.second as f32 / 60.0;
+
+    let elevation = 1.6 * busyness.sample(p) - 0.8
+        + 0.08 * (TAU * (4.0 * p + m_norm)).sin()
+        + 0.06 * (TAU * (16.0 * p + s_norm)).sin();
+
+    elevation.clamp(-1.0, 1.0)
+}
+
+/// Compute the elevation of a hand-drawn intensity curve at position p
+///
+/// Intense hours rise toward the peaks and calm ones sink into valleys,
+/// with the same small ripple as the calendar terrain.
+///
+/// Returns a value in [-1..1]
+pub fn profile_elevation(p: f32, profile: &IntensityCurve, params: &TerrainParams) -> f32 {
+    let m_norm = params.minute as f32 / 60.0;
+    let s_norm = params.second as f32 / 60.0;
+
+    let elevation = 1.6 * profile.sample(p) - 0.8
+        + 0.08 * (TAU * (4.0 * p + m_norm)).sin()
+        + 0.06 * (TAU * (16.0 * p + s_norm)).sin();
+
+    elevation.clamp(-1.0, 1.0)
+}
+
 /// Generate terrain samples for rendering
 ///
 /// Returns a vector of (x_normalized, elevation) pairs
@@ -286,6 +409,68 @@ pub fn generate_terrain_samples(
     samples
 }
 
+/// A second zone's terrain laid along another zone's day axis
+///
+/// Each position on the day map is an instant; the comparison zone's own
+/// local day decides where on its terrain that instant falls, so its peaks
+/// and valleys line up with when they actually happen.
+#[derive(Debug, Clone)]
+pub struct ComparisonTerrain {
+    pub tz: Tz,
+    /// The comparison zone's local days overlapping the map's day, in order,
+    /// each with its terrain parameters
+    days: Vec<(DayDomain, TerrainParams)>,
+}
+
+impl ComparisonTerrain {
+    /// Terrain of `tz` over `day_domain`, shaped by the displayed `instant`
+    pub fn compute(tz: Tz, day_domain: &DayDomain, instant: DateTime<Utc>) -> Self {
+        let params = TerrainParams::from_datetime(instant.with_timezone(&tz));
+        let mut date = day_domain.midnight_utc.with_timezone(&tz).date_naive();
+        let mut days = Vec::new();
+        // Offsets differ by at most 26 hours, so three local days always cover it
+        for _ in 0..3 {
+            let day = DayDomain::compute_for_date(date, tz, instant);
+            if day.midnight_utc >= day_domain.next_midnight_utc {
+                break;
+            }
+            let day_params = params.for_day_of_year(date.ordinal());
+            days.push((day, day_params));
+            date += Duration::days(1);
+        }
+        Self { tz, days }
+    }
+
+    /// Elevation at position `p` of `day_domain`, in [-1..1]
+    pub fn elevation_at(&self, day_domain: &DayDomain, p: f32) -> f32 {
+        let instant = day_domain.midnight_utc + Duration::seconds(day_domain.position_to_ssm(p));
+        let Some((day, params)) = self
+            .days
+            .iter()
+            .find(|(day, _)| instant < day.next_midnight_utc)
+            .or(self.days.last())
+        else {
+            return 0.0;
+        };
+        let q = day.ssm_to_position((instant - day.midnight_utc).num_seconds());
+        terrain_elevation(q, params)
+    }
+
+    /// Positions on `day_domain` where the comparison zone's local day begins
+    pub fn midnights(&self, day_domain: &DayDomain) -> Vec<f32> {
+        self.days
+            .iter()
+            .map(|(day, _)| day.midnight_utc)
+            .filter(|&midnight| {
+                midnight > day_domain.midnight_utc && midnight < day_domain.next_midnight_utc
+            })
+            .map(|midnight| {
+                day_domain.ssm_to_position((midnight - day_domain.midnight_utc).num_seconds())
+            })
+            .collect()
+    }
+}
+
 /// Hour boundary information for grid rendering
 #[derive(Debug, Clone)]
 pub struct HourBoundary {
@@ -366,13 +551,82 @@ mod tests {
             minute: 30,
             second: 45,
             day_of_year: 180,
+            busyness: None,
+            profile: None,
+            profile_blend: 0.0,
         };
         
         for i in 0..100 {
             let p = i as f32 / 99.0;
             let e = terrain_elevation(p, &params);
-            assert!(e >= -1.0 && e <= 1.0, "Elevation {} out of bounds at p={}", e, p);
+            assert!((-1.0..=1.0).contains(&e), "Elevation {} out of bounds at p={}", e, p);
+        }
+    }
+
+    #[test]
+    fn test_profile_blends_with_synthetic_waves() {
+        let tz: Tz = "UTC".parse().unwrap();
+        let now = Utc.with_ymd_and_hms(2025, 6, 4, 9, 15, 0).unwrap();
+        let params = TerrainParams::from_datetime(now.with_timezone(&tz));
+        let curve = Arc::new(IntensityCurve::default());
+
+        let synthetic = params.clone().with_profile(curve.clone(), 0.0);
+        let custom = params.clone().with_profile(curve.clone(), 1.0);
+        let half = params.clone().with_profile(curve.clone(), 0.5);
+        for i in 0..=48 {
+            let p = i as f32 / 48.0;
+            let a = terrain_elevation(p, &synthetic);
+            let b = terrain_elevation(p, &custom);
+            assert!((a - terrain_elevation(p, &params)).abs() < 1e-6);
+            assert!((b - profile_elevation(p, &curve, &params)).abs() < 1e-6);
+            assert!((terrain_elevation(p, &half) - (a + b) / 2.0).abs() < 1e-5);
+        }
+
+        // The curve's intense late morning stands above its quiet night
+        assert!(terrain_elevation(10.0 / 24.0, &custom) > terrain_elevation(3.0 / 24.0, &custom));
+    }
+
+    #[test]
+    fn test_week_domains_show_dst_sunday() {
+        // US spring forward: Sunday 9 March 2025, 2 AM local
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let wednesday = Utc.with_ymd_and_hms(2025, 3, 5, 17, 0, 0).unwrap();
+        let week = week_domains(wednesday, tz);
+
+        assert_eq!(week.len(), 7);
+        assert_eq!(week[0].local_date(tz).weekday(), chrono::Weekday::Mon);
+        assert!(week[2].midnight_utc <= wednesday && wednesday < week[2].next_midnight_utc);
+        for pair in week.windows(2) {
+            assert_eq!(pair[0].next_midnight_utc, pair[1].midnight_utc);
         }
+
+        let sunday = &week[6];
+        assert_eq!(sunday.day_length_seconds, 23 * 3600);
+        assert_eq!(sunday.dst_faults.len(), 1);
+        assert!(week[..6].iter().all(|day| day.dst_faults.is_empty()));
+        assert_eq!(sunday.normalized_position, 0.0);
+    }
+
+    #[test]
+    fn test_comparison_follows_its_own_day() {
+        // Tokyo is 16 hours ahead of Los Angeles in June
+        let la: Tz = "America/Los_Angeles".parse().unwrap();
+        let tokyo: Tz = "Asia/Tokyo".parse().unwrap();
+        let now = Utc.with_ymd_and_hms(2025, 6, 4, 19, 0, 0).unwrap();
+        let day = DayDomain::compute(now, la);
+        let comparison = ComparisonTerrain::compute(tokyo, &day, now);
+
+        // Tokyo midnight is 08:00 in Los Angeles, a third of the way in
+        let midnights = comparison.midnights(&day);
+        assert_eq!(midnights.len(), 1);
+        assert!((midnights[0] - 8.0 / 24.0).abs() < 1e-4);
+
+        // LA 10:00 is Tokyo 02:00 on the next local day
+        let tokyo_day = DayDomain::compute(now, tokyo);
+        let params = TerrainParams::from_datetime(now.with_timezone(&tokyo));
+        let expected = terrain_elevation(2.0 / 24.0, &params.for_day_of_year(156));
+        assert_eq!(tokyo_day.local_date(tokyo).ordinal(), 156);
+        assert!((comparison.elevation_at(&day, 10.0 / 24.0) - expected).abs() < 1e-4);
     }
 
     #[test]
@@ -1,19 +1,50 @@
 //! UI module - egui side panel components
 //!
 //! Provides the interactive UI components using nannou_egui:
-//! - SidePanel with time readout, timezone picker, DST status, legend
+//! - SidePanel with time readout, timezone picker, comparison zone, DST
+//!   status, terrain source, day/week view, legend
+//! - Intensity profile editor for the custom terrain
 //! - Timezone picker overlay
 //! - Inspect mode controls
+//! - Waypoint list with notes
+//! - Day replay controls
 
+use chrono::Weekday;
 use chrono_tz::Tz;
 use nannou_egui::egui;
-use shared::{search_timezones, DstChange, TimeData, Validity};
+use shared::{
+    active_palette, clip_recording_settings, favorites_settings, keymap_settings, language_settings,
+    order_by_region, quiet_hours_settings, reduced_motion_settings, region_change, region_heading,
+    reveal_hotkey_settings, safe_palette_settings, search_timezones, system_zone_settings, tr,
+    tr_args, window_title_settings, zone_display_name, ClipSettings, DstChange, FollowSystemZone,
+    Keymap, KeymapEdit, LanguageSetting, MotionPreference, QuietHours, RevealHotkey, Rgb,
+    SafePaletteSetting, ThemeLibrary, TimeData, Validity, WindowTitleClock,
+};
+
+use crate::contours::BANDS;
+use crate::drawing::elevation_color;
+use crate::profile::{IntensityProfiles, ProfilePoint, DAY_HOURS};
+use crate::replay::REPLAY_SECONDS_RANGE;
+use crate::terrain::TerrainSource;
+use crate::waypoints::{format_minute, Waypoint};
+
+/// Which zone the picker is choosing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PickerTarget {
+    /// The map's own zone
+    #[default]
+    Primary,
+    /// The zone whose terrain is overlaid for comparison
+    Comparison,
+}
 
 /// State for the timezone picker
 #[derive(Default)]
 pub struct PickerState {
     /// Whether the picker is currently open
     pub is_open: bool,
+    /// Which zone a selection applies to
+    pub target: PickerTarget,
     /// Current search query
     pub search_query: String,
     /// Cached search results
@@ -24,9 +55,15 @@ pub struct PickerState {
 
 impl PickerState {
     pub fn open(&mut self) {
+        self.open_for(PickerTarget::Primary);
+    }
+
+    /// Open the picker to choose the zone for `target`
+    pub fn open_for(&mut self, target: PickerTarget) {
+        self.target = target;
         self.is_open = true;
         self.search_query.clear();
-        self.search_results = search_timezones("");
+        self.search_results = order_by_region(search_timezones(""));
         self.should_focus_search = true;
     }
 
@@ -37,7 +74,7 @@ impl PickerState {
     }
 
     pub fn update_search(&mut self) {
-        self.search_results = search_timezones(&self.search_query);
+        self.search_results = order_by_region(search_timezones(&self.search_query));
     }
 }
 
@@ -57,12 +94,319 @@ pub struct PickerResult {
 pub struct SidePanelResult {
     /// Open the timezone picker
     pub open_picker: bool,
+    /// Open the timezone picker to choose the comparison zone
+    pub open_comparison_picker: bool,
+    /// Comparison overlay toggled
+    pub comparison_toggled: bool,
     /// Return to live mode
     pub return_to_now: bool,
+    /// Start a day replay
+    pub start_replay: bool,
+    /// Day replay length edited
+    pub replay_seconds_changed: bool,
     /// Reduced motion setting changed
     pub reduced_motion_changed: bool,
+    /// Quiet-hours schedule edited
+    pub quiet_hours_changed: bool,
+    /// Window title clock setting edited
+    pub window_title_changed: bool,
+    /// Shared clip recording settings edited
+    pub clip_settings_changed: bool,
+    /// Shared language edited
+    pub language_changed: bool,
+    /// Shared safe palette edited
+    pub safe_palette_changed: bool,
+    /// Shared follow-system-zone setting edited
+    pub system_zone_changed: bool,
+    /// Shared reveal shortcut edited
+    pub reveal_hotkey_changed: bool,
+    /// Switched between the shared favorites and this clock's own
+    pub favorites_source_changed: bool,
+    /// Shortcut rebound, reset or waiting for its new chord
+    pub keymap_edit: Option<KeymapEdit>,
     /// Legend visibility changed
     pub legend_toggled: bool,
+    /// Contour lines toggled
+    pub contours_toggled: bool,
+    /// 3D relief toggled
+    pub relief_toggled: bool,
+    /// Terrain source (synthetic/calendar/custom) changed
+    pub terrain_source_changed: bool,
+    /// An intensity curve or the blend was edited
+    pub profile_edited: bool,
+    /// An intensity profile edit finished and should be saved
+    pub save_profile: bool,
+    /// Load (or reload) the calendar file at the entered path
+    pub load_calendar: bool,
+    /// Export the terrain as a PNG heightmap and CSV profile
+    pub export_terrain: bool,
+    /// Switch to today's single-day map
+    pub show_day: bool,
+    /// Switch to the whole-week map
+    pub show_week: bool,
+    /// Switch theme (`Some(None)` for the clock's own colors)
+    pub set_theme: Option<Option<String>>,
+    /// Pin the inspected minute as a waypoint with the entered note
+    pub pin_waypoint: bool,
+    /// Inspect the waypoint at this index
+    pub jump_to_waypoint: Option<usize>,
+    /// Remove the waypoint at this index
+    pub remove_waypoint: Option<usize>,
+    /// A waypoint note was edited
+    pub waypoints_edited: bool,
+}
+
+/// Draw the waypoint list, note editors and the pin form
+fn draw_waypoint_list(
+    ui: &mut egui::Ui,
+    waypoints: &mut [Waypoint],
+    new_note: &mut String,
+    can_pin: bool,
+    active: Option<usize>,
+    result: &mut SidePanelResult,
+) {
+    if waypoints.is_empty() {
+        ui.label(
+            egui::RichText::new(tr("No waypoints in this zone yet"))
+                .size(11.0)
+                .color(egui::Color32::from_rgb(140, 130, 120)),
+        );
+    }
+
+    for (i, waypoint) in waypoints.iter_mut().enumerate() {
+        ui.horizontal(|ui| {
+            let time = egui::RichText::new(format_minute(waypoint.minute)).monospace();
+            let time = if active == Some(i) {
+                time.color(egui::Color32::from_rgb(190, 160, 230))
+            } else {
+                time
+            };
+            if ui
+                .add(egui::Button::new(time).small())
+                .on_hover_text(tr("Inspect this waypoint"))
+                .clicked()
+            {
+                result.jump_to_waypoint = Some(i);
+            }
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut waypoint.note)
+                    .hint_text(tr("note"))
+                    .desired_width(130.0),
+            );
+            if response.changed() {
+                result.waypoints_edited = true;
+            }
+            if ui.small_button("✕").on_hover_text(tr("Remove")).clicked() {
+                result.remove_waypoint = Some(i);
+            }
+        });
+    }
+
+    ui.add_space(5.0);
+    ui.horizontal(|ui| {
+        let response = ui.add_enabled(
+            can_pin,
+            egui::TextEdit::singleline(new_note)
+                .hint_text(tr("note"))
+                .desired_width(130.0),
+        );
+        let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+        if ui.add_enabled(can_pin, egui::Button::new(tr("📌 Pin"))).clicked() || submitted {
+            result.pin_waypoint = true;
+        }
+    });
+    ui.label(
+        egui::RichText::new(tr(if can_pin {
+            "P pins the cursor  •  ↑/↓ cycle waypoints"
+        } else {
+            "Click the map to choose a time to pin"
+        }))
+        .size(11.0)
+        .color(egui::Color32::from_rgb(140, 130, 120)),
+    );
+}
+
+/// Weekdays in editor order with their button labels
+const PROFILE_WEEKDAYS: [(Weekday, &str); 7] = [
+    (Weekday::Mon, "Mo"),
+    (Weekday::Tue, "Tu"),
+    (Weekday::Wed, "We"),
+    (Weekday::Thu, "Th"),
+    (Weekday::Fri, "Fr"),
+    (Weekday::Sat, "Sa"),
+    (Weekday::Sun, "Su"),
+];
+
+/// Height of the intensity curve editor
+const PROFILE_EDITOR_HEIGHT: f32 = 110.0;
+
+/// How close (px) the pointer must be to grab a control point
+const PROFILE_GRAB_RADIUS: f32 = 9.0;
+
+/// Draw the weekday tabs, the draggable intensity curve and its controls
+fn draw_profile_editor(
+    ui: &mut egui::Ui,
+    profiles: &mut IntensityProfiles,
+    weekday: &mut Weekday,
+    result: &mut SidePanelResult,
+) {
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = 2.0;
+        for (day, label) in PROFILE_WEEKDAYS {
+            if ui.selectable_label(*weekday == day, tr(label)).clicked() {
+                *weekday = day;
+            }
+        }
+    });
+
+    let width = ui.available_width();
+    let (response, painter) = ui.allocate_painter(
+        egui::vec2(width, PROFILE_EDITOR_HEIGHT),
+        egui::Sense::click_and_drag(),
+    );
+    let rect = response.rect.shrink(6.0);
+    let to_screen = |hour: f32, intensity: f32| {
+        egui::pos2(
+            rect.left() + hour / DAY_HOURS * rect.width(),
+            rect.bottom() - intensity * rect.height(),
+        )
+    };
+    let from_screen = |pos: egui::Pos2| {
+        (
+            (pos.x - rect.left()) / rect.width() * DAY_HOURS,
+            (rect.bottom() - pos.y) / rect.height(),
+        )
+    };
+
+    // Grab the nearest point on press, and keep it until release
+    let drag_id = response.id.with("dragged_point");
+    let curve = profiles.day_mut(*weekday);
+    let nearest = |pos: egui::Pos2, points: &[ProfilePoint]| {
+        points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| (i, to_screen(point.hour, point.intensity).distance(pos)))
+            .filter(|(_, distance)| *distance <= PROFILE_GRAB_RADIUS)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+    };
+    let pointer = response.interact_pointer_pos();
+
+    if response.drag_started() {
+        if let Some(index) = pointer.and_then(|pos| nearest(pos, curve.points())) {
+            ui.data_mut(|data| data.insert_temp(drag_id, index));
+        }
+    }
+    let dragged = ui.data(|data| data.get_temp::<usize>(drag_id));
+    if let (Some(index), Some(pos)) = (dragged, pointer) {
+        if response.dragged() {
+            let (hour, intensity) = from_screen(pos);
+            curve.move_point(index, hour, intensity);
+            result.profile_edited = true;
+        }
+    }
+    if response.drag_released() && dragged.is_some() {
+        ui.data_mut(|data| data.remove::<usize>(drag_id));
+        result.save_profile = true;
+    }
+    if let Some(pos) = pointer {
+        if response.double_clicked() && nearest(pos, curve.points()).is_none() {
+            let (hour, intensity) = from_screen(pos);
+            if curve.insert_point(hour, intensity).is_some() {
+                result.profile_edited = true;
+                result.save_profile = true;
+            }
+        }
+        if response.secondary_clicked() {
+            if let Some(index) = nearest(pos, curve.points()) {
+                if curve.remove_point(index) {
+                    result.profile_edited = true;
+                    result.save_profile = true;
+                }
+            }
+        }
+    }
+
+    // Frame, six-hour grid and the curve shaded by elevation
+    let grid = egui::Stroke::new(1.0, egui::Color32::from_rgb(60, 56, 52));
+    painter.rect_stroke(response.rect, 3.0, grid);
+    for hour in [6.0, 12.0, 18.0] {
+        let x = to_screen(hour, 0.0).x;
+        painter.line_segment([egui::pos2(x, rect.top()), egui::pos2(x, rect.bottom())], grid);
+    }
+    let samples = 96;
+    let line: Vec<egui::Pos2> = (0..=samples)
+        .map(|i| {
+            let p = i as f32 / samples as f32;
+            to_screen(p * DAY_HOURS, curve.sample(p))
+        })
+        .collect();
+    for segment in line.windows(2) {
+        let intensity = (from_screen(segment[0]).1 + from_screen(segment[1]).1) / 2.0;
+        let color = elevation_color(intensity * 1.6 - 0.8);
+        painter.line_segment(
+            [segment[0], segment[1]],
+            egui::Stroke::new(2.0, egui::Color32::from_rgb(color.red, color.green, color.blue)),
+        );
+    }
+    for (i, point) in curve.points().iter().enumerate() {
+        let fill = if dragged == Some(i) {
+            egui::Color32::from_rgb(190, 160, 230)
+        } else {
+            egui::Color32::from_rgb(220, 210, 195)
+        };
+        painter.circle_filled(to_screen(point.hour, point.intensity), 4.0, fill);
+    }
+    if let Some(hover) = response.hover_pos() {
+        let (hour, intensity) = from_screen(hover);
+        let minute = (hour.clamp(0.0, DAY_HOURS) * 60.0).round() as u32;
+        response.on_hover_text_at_pointer(format!(
+            "{}  •  {:.0}%",
+            format_minute(minute),
+            intensity.clamp(0.0, 1.0) * 100.0
+        ));
+    }
+
+    let blend = ui.add(
+        egui::Slider::new(&mut profiles.blend, 0.0..=1.0)
+            .text(tr("Blend"))
+            .show_value(false),
+    );
+    if blend.changed() {
+        result.profile_edited = true;
+    }
+    if blend.drag_released() || (blend.changed() && !blend.dragged()) {
+        result.save_profile = true;
+    }
+
+    ui.horizontal(|ui| {
+        if ui
+            .button(tr("Copy to all days"))
+            .on_hover_text(tr("Give every weekday this curve"))
+            .clicked()
+        {
+            profiles.copy_to_all(*weekday);
+            result.profile_edited = true;
+            result.save_profile = true;
+        }
+        if ui
+            .button(tr("Reset"))
+            .on_hover_text(tr("Restore this weekday's default curve"))
+            .clicked()
+        {
+            profiles.reset_day(*weekday);
+            result.profile_edited = true;
+            result.save_profile = true;
+        }
+    });
+    ui.label(
+        egui::RichText::new(tr(
+            "Drag points  •  double-click adds  •  right-click removes\n\
+             Blend: synthetic waves ← → this curve",
+        ))
+        .size(11.0)
+        .color(egui::Color32::from_rgb(140, 130, 120)),
+    );
 }
 
 /// Result of inspect tooltip interactions
@@ -76,6 +420,7 @@ pub struct InspectResult {
 }
 
 /// Draw the main side panel with all UI elements
+#[allow(clippy::too_many_arguments)]
 pub fn draw_side_panel(
     ctx: &egui::Context,
     time_data: &TimeData,
@@ -83,8 +428,41 @@ pub fn draw_side_panel(
     inspect_time_str: Option<&str>,
     inspect_is_gap: bool,
     inspect_is_overlap: bool,
-    reduced_motion: &mut bool,
+    link_status: Option<&str>,
+    motion_preference: &mut MotionPreference,
+    system_reduced_motion: bool,
     show_legend: &mut bool,
+    show_contours: &mut bool,
+    show_relief: &mut bool,
+    terrain_source: &mut TerrainSource,
+    calendar_path: &mut String,
+    calendar_status: Option<&str>,
+    intensity_profiles: &mut IntensityProfiles,
+    profile_day: &mut Weekday,
+    export_status: Option<&str>,
+    show_comparison: &mut bool,
+    comparison_tz: Option<Tz>,
+    is_day_view: bool,
+    is_week_view: bool,
+    view_range: &str,
+    waypoints: &mut [Waypoint],
+    waypoint_note: &mut String,
+    active_waypoint: Option<usize>,
+    is_replaying: bool,
+    replay_seconds: &mut f32,
+    quiet_hours: &mut QuietHours,
+    window_title: &mut WindowTitleClock,
+    clip_settings: &mut ClipSettings,
+    language: &mut LanguageSetting,
+    safe_palette: &mut SafePaletteSetting,
+    follow_system_zone: &mut FollowSystemZone,
+    reveal_hotkey: &mut RevealHotkey,
+    reveal_status: Option<&str>,
+    system_zone_status: Option<&str>,
+    own_favorites: &mut bool,
+    keymap: &Keymap,
+    themes: &ThemeLibrary,
+    theme: Option<&str>,
 ) -> SidePanelResult {
     let mut result = SidePanelResult::default();
 
@@ -102,7 +480,7 @@ pub fn draw_side_panel(
             ui.add_space(10.0);
 
             // Explicit Time Readout
-            ui.heading("Current Time");
+            ui.heading(tr("Current Time"));
             ui.add_space(5.0);
 
             let time_str = if let Some(inspect) = inspect_time_str {
@@ -129,28 +507,43 @@ pub fn draw_side_panel(
 
             ui.add_space(5.0);
             ui.label(time_data.format_date());
+            if let Some(status) = link_status {
+                ui.label(
+                    egui::RichText::new(status)
+                        .size(11.0)
+                        .color(egui::Color32::from_rgb(180, 175, 170)),
+                );
+            }
 
             if is_inspecting {
                 ui.add_space(5.0);
                 ui.colored_label(
                     egui::Color32::from_rgb(120, 180, 220),
-                    "◆ INSPECT MODE",
+                    tr("◆ INSPECT MODE"),
                 );
                 
                 // Show DST gap/overlap warnings
                 if inspect_is_gap {
                     ui.colored_label(
                         egui::Color32::from_rgb(255, 107, 53),
-                        "⚠ Nonexistent time (DST gap)",
+                        tr("⚠ Nonexistent time (DST gap)"),
                     );
                 } else if inspect_is_overlap {
                     ui.colored_label(
                         egui::Color32::from_rgb(255, 179, 71),
-                        "⚠ Ambiguous time (DST overlap)",
+                        tr("⚠ Ambiguous time (DST overlap)"),
                     );
                 }
                 
-                if ui.button("Return to Now").clicked() {
+                if ui.button(tr("Return to Now")).clicked() {
+                    result.return_to_now = true;
+                }
+            }
+
+            if is_replaying {
+                ui.add_space(5.0);
+                ui.colored_label(egui::Color32::from_rgb(255, 179, 71), tr("▶ DAY REPLAY"));
+                if ui.button(tr("Stop Replay")).clicked() {
                     result.return_to_now = true;
                 }
             }
@@ -165,7 +558,7 @@ pub fn draw_side_panel(
             ui.add_space(10.0);
 
             // Timezone section
-            ui.heading("Time Zone");
+            ui.heading(tr("Time Zone"));
             ui.add_space(5.0);
 
             let tz_text = format!(
@@ -177,7 +570,7 @@ pub fn draw_side_panel(
 
             if ui
                 .add(egui::Label::new(&tz_text).sense(egui::Sense::click()))
-                .on_hover_text("Click to change time zone")
+                .on_hover_text(tr("Click to change time zone"))
                 .clicked()
             {
                 result.open_picker = true;
@@ -187,8 +580,78 @@ pub fn draw_side_panel(
             ui.separator();
             ui.add_space(10.0);
 
+            // Comparison zone section
+            ui.heading(tr("Compare"));
+            ui.add_space(5.0);
+
+            if ui.checkbox(show_comparison, tr("Overlay a second zone (O)")).changed() {
+                result.comparison_toggled = true;
+            }
+            let zone_text = comparison_tz.map_or(tr("Choose a zone…"), |tz| tz.name());
+            if ui
+                .add(egui::Label::new(zone_text).sense(egui::Sense::click()))
+                .on_hover_text(tr("Click to change the comparison zone"))
+                .clicked()
+            {
+                result.open_comparison_picker = true;
+            }
+            ui.label(
+                egui::RichText::new(tr("Its terrain is traced in teal on the day map"))
+                    .size(11.0)
+                    .color(egui::Color32::from_rgb(140, 130, 120)),
+            );
+
+            ui.add_space(15.0);
+            ui.separator();
+            ui.add_space(10.0);
+
+            // Waypoints section
+            ui.heading(tr("Waypoints"));
+            ui.add_space(5.0);
+
+            draw_waypoint_list(
+                ui,
+                waypoints,
+                waypoint_note,
+                is_inspecting,
+                active_waypoint,
+                &mut result,
+            );
+
+            ui.add_space(15.0);
+            ui.separator();
+            ui.add_space(10.0);
+
+            // Day replay section
+            ui.heading(tr("Day Replay"));
+            ui.add_space(5.0);
+
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(!is_replaying, egui::Button::new(tr("▶ Replay Day")))
+                    .clicked()
+                {
+                    result.start_replay = true;
+                }
+                result.replay_seconds_changed = ui
+                    .add(egui::Slider::new(replay_seconds, REPLAY_SECONDS_RANGE).suffix(" s"))
+                    .on_hover_text(tr("How long the sweep from midnight to midnight takes"))
+                    .changed();
+            });
+            ui.label(
+                egui::RichText::new(tr(
+                    "Sweeps the beacon across the day, pausing to explain each DST fault",
+                ))
+                    .size(11.0)
+                    .color(egui::Color32::from_rgb(140, 130, 120)),
+            );
+
+            ui.add_space(15.0);
+            ui.separator();
+            ui.add_space(10.0);
+
             // DST Status Card
-            ui.heading("DST Status");
+            ui.heading(tr("DST Status"));
             ui.add_space(5.0);
 
             draw_dst_status_card(ui, time_data);
@@ -197,14 +660,125 @@ pub fn draw_side_panel(
             ui.separator();
             ui.add_space(10.0);
 
+            // Terrain source section
+            ui.heading(tr("Terrain"));
+            ui.add_space(5.0);
+
+            ui.horizontal(|ui| {
+                if ui
+                    .radio_value(terrain_source, TerrainSource::Synthetic, tr("Synthetic"))
+                    .changed()
+                {
+                    result.terrain_source_changed = true;
+                }
+                if ui
+                    .radio_value(terrain_source, TerrainSource::Calendar, tr("Calendar"))
+                    .changed()
+                {
+                    result.terrain_source_changed = true;
+                }
+                if ui
+                    .radio_value(terrain_source, TerrainSource::Custom, tr("Custom"))
+                    .changed()
+                {
+                    result.terrain_source_changed = true;
+                }
+            });
+
+            if *terrain_source == TerrainSource::Calendar {
+                ui.add_space(5.0);
+                ui.label(
+                    egui::RichText::new(tr("ICS file path"))
+                        .size(11.0)
+                        .color(egui::Color32::from_rgb(140, 130, 120)),
+                );
+                let response = ui.text_edit_singleline(calendar_path);
+                let submitted =
+                    response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                if ui.button(tr("Load Calendar")).clicked() || submitted {
+                    result.load_calendar = true;
+                }
+
+                if let Some(status) = calendar_status {
+                    ui.label(
+                        egui::RichText::new(status)
+                            .size(11.0)
+                            .color(egui::Color32::from_rgb(180, 175, 170)),
+                    );
+                }
+                ui.label(
+                    egui::RichText::new(tr("Meetings raise the terrain; free time forms valleys"))
+                        .size(11.0)
+                        .color(egui::Color32::from_rgb(140, 130, 120)),
+                );
+            }
+
+            if *terrain_source == TerrainSource::Custom {
+                ui.add_space(5.0);
+                draw_profile_editor(ui, intensity_profiles, profile_day, &mut result);
+            }
+
+            ui.add_space(5.0);
+            if ui
+                .button(tr("Export Terrain (E)"))
+                .on_hover_text(tr("Save today's terrain as a PNG heightmap and a CSV profile"))
+                .clicked()
+            {
+                result.export_terrain = true;
+            }
+            if let Some(status) = export_status {
+                ui.label(
+                    egui::RichText::new(status)
+                        .size(11.0)
+                        .color(egui::Color32::from_rgb(180, 175, 170)),
+                );
+            }
+
+            ui.add_space(15.0);
+            ui.separator();
+            ui.add_space(10.0);
+
+            // Day / week view section
+            ui.heading(tr("View"));
+            ui.add_space(5.0);
+
+            ui.horizontal(|ui| {
+                if ui.selectable_label(is_day_view, tr("Day")).clicked() && !is_day_view {
+                    result.show_day = true;
+                }
+                if ui.selectable_label(is_week_view, tr("Week")).clicked() && !is_week_view {
+                    result.show_week = true;
+                }
+            });
+            ui.label(
+                egui::RichText::new(view_range)
+                    .size(11.0)
+                    .color(egui::Color32::from_rgb(180, 175, 170)),
+            );
+            ui.label(
+                egui::RichText::new(tr("W toggles week  •  -/= zoom  •  ,/. scroll"))
+                    .size(11.0)
+                    .color(egui::Color32::from_rgb(140, 130, 120)),
+            );
+
+            ui.add_space(15.0);
+            ui.separator();
+            ui.add_space(10.0);
+
             // Legend section
             ui.horizontal(|ui| {
-                ui.heading("Legend");
+                ui.heading(tr("Legend"));
                 if ui.small_button(if *show_legend { "▼" } else { "▶" }).clicked() {
                     *show_legend = !*show_legend;
                     result.legend_toggled = true;
                 }
             });
+            if ui.checkbox(show_contours, tr("Contour lines (C)")).changed() {
+                result.contours_toggled = true;
+            }
+            if ui.checkbox(show_relief, tr("3D relief (D)")).changed() {
+                result.relief_toggled = true;
+            }
 
             if *show_legend {
                 ui.add_space(5.0);
@@ -216,7 +790,7 @@ pub fn draw_side_panel(
             ui.add_space(10.0);
 
             // Accessibility - Map Summary
-            ui.heading("Map Summary");
+            ui.heading(tr("Map Summary"));
             ui.add_space(5.0);
             
             let summary = generate_map_summary(time_data, is_inspecting, inspect_time_str);
@@ -231,17 +805,36 @@ pub fn draw_side_panel(
             ui.add_space(10.0);
 
             // Settings
-            ui.heading("Settings");
+            ui.heading(tr("Settings"));
             ui.add_space(5.0);
 
-            if ui.checkbox(reduced_motion, "Reduced Motion").changed() {
-                result.reduced_motion_changed = true;
-            }
+            result.reduced_motion_changed =
+                reduced_motion_settings(ui, motion_preference, system_reduced_motion);
             ui.label(
-                egui::RichText::new("Disables beacon pulse animation")
+                egui::RichText::new(tr("Disables beacon pulse animation"))
                     .size(11.0)
                     .color(egui::Color32::from_rgb(140, 130, 120)),
             );
+            ui.add_space(5.0);
+            result.quiet_hours_changed = quiet_hours_settings(ui, quiet_hours);
+            result.window_title_changed = window_title_settings(ui, window_title);
+            result.clip_settings_changed = clip_recording_settings(ui, clip_settings);
+            result.language_changed = language_settings(ui, language);
+            result.system_zone_changed = system_zone_settings(ui, follow_system_zone);
+            result.reveal_hotkey_changed =
+                reveal_hotkey_settings(ui, reveal_hotkey, reveal_status);
+            if let Some(status) = system_zone_status {
+                ui.label(
+                    egui::RichText::new(status)
+                        .size(11.0)
+                        .color(egui::Color32::from_rgb(180, 175, 170)),
+                );
+            }
+            result.favorites_source_changed = favorites_settings(ui, own_favorites);
+            result.keymap_edit = keymap_settings(ui, keymap);
+            ui.add_space(5.0);
+            result.set_theme = theme_selector(ui, themes, theme);
+            result.safe_palette_changed = safe_palette_settings(ui, safe_palette);
             
             ui.add_space(10.0);
                 }); // End ScrollArea
@@ -254,14 +847,14 @@ pub fn draw_side_panel(
 fn draw_dst_status_card(ui: &mut egui::Ui, time_data: &TimeData) {
     // Current DST status
     ui.horizontal(|ui| {
-        ui.label("Status:");
+        ui.label(tr("Status:"));
         if time_data.is_dst {
             ui.colored_label(
                 egui::Color32::from_rgb(255, 179, 71),
-                "Daylight Saving Time",
+                tr("Daylight Saving Time"),
             );
         } else {
-            ui.label("Standard Time");
+            ui.label(tr("Standard Time"));
         }
     });
 
@@ -269,7 +862,7 @@ fn draw_dst_status_card(ui: &mut egui::Ui, time_data: &TimeData) {
     match &time_data.dst_change {
         DstChange::None => {
             ui.label(
-                egui::RichText::new("No transitions within 24 hours")
+                egui::RichText::new(tr("No transitions within 24 hours"))
                     .size(11.0)
                     .color(egui::Color32::from_rgb(140, 130, 120)),
             );
@@ -278,54 +871,42 @@ fn draw_dst_status_card(ui: &mut egui::Ui, time_data: &TimeData) {
             instant,
             delta_minutes,
         } => {
-            let direction = if *delta_minutes > 0 {
-                "forward"
+            let change = if *delta_minutes > 0 {
+                "Clocks will move forward by {0} min"
             } else {
-                "back"
+                "Clocks will move back by {0} min"
             };
             ui.colored_label(
                 egui::Color32::from_rgb(255, 107, 53),
-                "⚠ Upcoming DST Change",
-            );
-            ui.label(format!(
-                "Clocks will move {} by {} min",
-                direction,
-                delta_minutes.abs()
-            ));
-            ui.label(
-                egui::RichText::new(format!("At: {}", instant.format("%H:%M UTC")))
-                    .size(11.0),
+                tr("⚠ Upcoming DST Change"),
             );
+            ui.label(tr_args(change, &[&delta_minutes.abs()]));
+            let at = instant.format("%H:%M UTC");
+            ui.label(egui::RichText::new(tr_args("At: {0}", &[&at])).size(11.0));
         }
         DstChange::JustOccurred {
             instant,
             delta_minutes,
         } => {
-            let direction = if *delta_minutes > 0 {
-                "forward"
+            let change = if *delta_minutes > 0 {
+                "Clocks moved forward by {0} min"
             } else {
-                "back"
+                "Clocks moved back by {0} min"
             };
             ui.colored_label(
                 egui::Color32::from_rgb(255, 179, 71),
-                "ℹ Recent DST Change",
-            );
-            ui.label(format!(
-                "Clocks moved {} by {} min",
-                direction,
-                delta_minutes.abs()
-            ));
-            ui.label(
-                egui::RichText::new(format!("At: {}", instant.format("%H:%M UTC")))
-                    .size(11.0),
+                tr("ℹ Recent DST Change"),
             );
+            ui.label(tr_args(change, &[&delta_minutes.abs()]));
+            let at = instant.format("%H:%M UTC");
+            ui.label(egui::RichText::new(tr_args("At: {0}", &[&at])).size(11.0));
         }
     }
 }
 
 /// Generate an accessible map summary description
 fn generate_map_summary(time_data: &TimeData, is_inspecting: bool, inspect_time_str: Option<&str>) -> String {
-    let mode = if is_inspecting { "Inspecting" } else { "Live" };
+    let mode = if is_inspecting { tr("Inspecting mode") } else { tr("Live mode") };
     let default_time = time_data.format_time();
     let time_str = inspect_time_str.unwrap_or(&default_time);
     
@@ -333,7 +914,7 @@ fn generate_map_summary(time_data: &TimeData, is_inspecting: bool, inspect_time_
     let hour = time_data.hour24;
     let progress_in_hour = time_data.minute as f32 / 60.0;
     
-    let terrain_desc = if progress_in_hour < 0.25 {
+    let terrain_desc = tr(if progress_in_hour < 0.25 {
         "descending into valley"
     } else if progress_in_hour < 0.5 {
         "at valley floor"
@@ -341,27 +922,28 @@ fn generate_map_summary(time_data: &TimeData, is_inspecting: bool, inspect_time_
         "ascending toward peak"
     } else {
         "approaching peak"
-    };
+    });
     
     let day_progress = (hour as f32 + time_data.minute as f32 / 60.0) / 24.0 * 100.0;
     
     let dst_status = if time_data.is_dst {
-        "Daylight Saving Time active"
+        tr("Daylight Saving Time active")
     } else {
-        "Standard Time"
+        tr("Standard Time")
     };
     
-    format!(
-        "{} mode. Current position: {} {}. \
-         The beacon marks {} on the day map, {:.0}% through the day. \
-         Terrain is {}. {}.",
-        mode,
-        time_str,
-        time_data.meridiem,
-        time_str,
-        day_progress,
-        terrain_desc,
-        dst_status
+    tr_args(
+        "{0}. Current position: {1} {2}. \
+         The beacon marks {1} on the day map, {3}% through the day. \
+         Terrain is {4}. {5}.",
+        &[
+            &mode,
+            &time_str,
+            &time_data.meridiem,
+            &format!("{:.0}", day_progress),
+            &terrain_desc,
+            &dst_status,
+        ],
     )
 }
 
@@ -388,7 +970,7 @@ fn draw_error_banner(ui: &mut egui::Ui, validity: &Validity) {
         .inner_margin(8.0)
         .rounding(4.0)
         .show(ui, |ui| {
-            ui.colored_label(color, message);
+            ui.colored_label(color, tr(message));
         });
 }
 
@@ -397,7 +979,7 @@ fn draw_legend(ui: &mut egui::Ui) {
     ui.vertical(|ui| {
         // Terrain explanation
         ui.label(
-            egui::RichText::new("Reading the Map:")
+            egui::RichText::new(tr("Reading the Map:"))
                 .size(12.0)
                 .color(egui::Color32::from_rgb(200, 190, 180)),
         );
@@ -405,31 +987,53 @@ fn draw_legend(ui: &mut egui::Ui) {
         
         ui.horizontal(|ui| {
             ui.colored_label(egui::Color32::from_rgb(139, 119, 101), "●");
-            ui.label("Peaks = late in hour");
+            ui.label(tr("Peaks = late in hour"));
         });
         
         ui.horizontal(|ui| {
             ui.colored_label(egui::Color32::from_rgb(70, 100, 90), "●");
-            ui.label("Valleys = early in hour");
+            ui.label(tr("Valleys = early in hour"));
         });
         
+        ui.add_space(5.0);
+
+        // Elevation bands, highest first to match the scale beside the map
+        ui.label(
+            egui::RichText::new(tr("Temporal Intensity:"))
+                .size(12.0)
+                .color(egui::Color32::from_rgb(200, 190, 180)),
+        );
+        ui.add_space(3.0);
+        for band in BANDS.iter().rev() {
+            let color = elevation_color(band.mid());
+            let swatch = egui::Color32::from_rgb(color.red, color.green, color.blue);
+            ui.horizontal(|ui| {
+                ui.colored_label(swatch, "■");
+                ui.label(format!("{} · {}", band.range_label(), tr(band.name)));
+            });
+        }
+        ui.horizontal(|ui| {
+            ui.colored_label(egui::Color32::from_rgb(120, 125, 115), "─");
+            ui.label(tr("Contour at each band edge"));
+        });
+
         ui.add_space(5.0);
         
         // Beacon
         ui.horizontal(|ui| {
             ui.colored_label(egui::Color32::from_rgb(255, 179, 71), "◆");
-            ui.label("Locator Beacon (now)");
+            ui.label(tr("Locator Beacon (now)"));
         });
         
         // Grid lines
         ui.horizontal(|ui| {
             ui.colored_label(egui::Color32::from_rgb(100, 100, 100), "│");
-            ui.label("Hour boundaries");
+            ui.label(tr("Hour boundaries"));
         });
         
         ui.horizontal(|ui| {
             ui.colored_label(egui::Color32::from_rgb(60, 60, 60), "┆");
-            ui.label("15-minute marks");
+            ui.label(tr("15-minute marks"));
         });
         
         ui.add_space(5.0);
@@ -437,31 +1041,31 @@ fn draw_legend(ui: &mut egui::Ui) {
         // DST markers
         ui.horizontal(|ui| {
             ui.colored_label(egui::Color32::from_rgb(255, 107, 53), "║");
-            ui.label("DST fault line");
+            ui.label(tr("DST fault line"));
         });
         
         ui.add_space(5.0);
         
         // Interaction hints
         ui.label(
-            egui::RichText::new("Interactions:")
+            egui::RichText::new(tr("Interactions:"))
                 .size(12.0)
                 .color(egui::Color32::from_rgb(200, 190, 180)),
         );
         ui.add_space(3.0);
         
         ui.label(
-            egui::RichText::new("Click map to inspect time")
+            egui::RichText::new(tr("Click map to inspect time"))
                 .size(11.0)
                 .color(egui::Color32::from_rgb(140, 130, 120)),
         );
         ui.label(
-            egui::RichText::new("Arrow keys to navigate")
+            egui::RichText::new(tr("Arrow keys to navigate"))
                 .size(11.0)
                 .color(egui::Color32::from_rgb(140, 130, 120)),
         );
         ui.label(
-            egui::RichText::new("Esc to return to now")
+            egui::RichText::new(tr("Esc to return to now"))
                 .size(11.0)
                 .color(egui::Color32::from_rgb(140, 130, 120)),
         );
@@ -481,7 +1085,12 @@ pub fn draw_timezone_picker(
         return result;
     }
 
-    egui::Window::new("Select Time Zone")
+    let title = match picker_state.target {
+        PickerTarget::Primary => "Select Time Zone",
+        PickerTarget::Comparison => "Compare With Time Zone",
+    };
+    egui::Window::new(tr(title))
+        .id(egui::Id::new("zone_picker"))
         .collapsible(false)
         .resizable(true)
         .default_width(400.0)
@@ -490,7 +1099,7 @@ pub fn draw_timezone_picker(
         .show(ctx, |ui| {
             // Search field
             ui.horizontal(|ui| {
-                ui.label("Search:");
+                ui.label(tr("Search:"));
                 let response = ui.text_edit_singleline(&mut picker_state.search_query);
                 if picker_state.should_focus_search {
                     response.request_focus();
@@ -504,7 +1113,7 @@ pub fn draw_timezone_picker(
             ui.separator();
 
             // System timezone button
-            if ui.button("Use System Time Zone").clicked() {
+            if ui.button(tr("Use System Time Zone")).clicked() {
                 if let Some(sys_tz) = shared::system_timezone() {
                     result.selected_tz = Some(sys_tz);
                     result.close_picker = true;
@@ -515,7 +1124,7 @@ pub fn draw_timezone_picker(
 
             // Favorites section
             if !favorites.is_empty() {
-                ui.label("Favorites:");
+                ui.label(tr("Favorites:"));
                 ui.horizontal_wrapped(|ui| {
                     for &tz in favorites {
                         let is_current = tz == current_tz;
@@ -534,15 +1143,20 @@ pub fn draw_timezone_picker(
             }
 
             // Results list
-            ui.label(format!(
-                "{} time zones found",
-                picker_state.search_results.len()
+            ui.label(tr_args(
+                "{0} time zones found",
+                &[&picker_state.search_results.len()],
             ));
 
             egui::ScrollArea::vertical()
                 .max_height(300.0)
                 .show(ui, |ui| {
+                    let mut previous = None;
                     for &tz in &picker_state.search_results {
+                        if let Some(region) = region_change(previous, tz) {
+                            region_heading(ui, region);
+                        }
+                        previous = Some(tz);
                         let is_current = tz == current_tz;
                         let is_favorite = favorites.contains(&tz);
 
@@ -555,9 +1169,9 @@ pub fn draw_timezone_picker(
 
                             // Timezone name
                             let label = if is_current {
-                                format!("{} ◀", tz.name())
+                                format!("{} ◀", zone_display_name(tz))
                             } else {
-                                tz.name().to_string()
+                                zone_display_name(tz)
                             };
                             if ui.selectable_label(is_current, &label).clicked() {
                                 result.selected_tz = Some(tz);
@@ -570,7 +1184,7 @@ pub fn draw_timezone_picker(
             ui.separator();
 
             // Close button
-            if ui.button("Close").clicked() {
+            if ui.button(tr("Close")).clicked() {
                 result.close_picker = true;
             }
         });
@@ -615,20 +1229,20 @@ pub fn draw_inspect_tooltip(
             if is_gap {
                 ui.colored_label(
                     egui::Color32::from_rgb(255, 107, 53),
-                    "⚠ Nonexistent (DST gap)",
+                    tr("⚠ Nonexistent (DST gap)"),
                 );
             } else if is_overlap {
                 ui.colored_label(
                     egui::Color32::from_rgb(255, 179, 71),
-                    "⚠ Ambiguous (DST overlap)",
+                    tr("⚠ Ambiguous (DST overlap)"),
                 );
             }
 
             ui.horizontal(|ui| {
-                if ui.small_button(if is_pinned { "Unpin" } else { "Pin" }).clicked() {
+                if ui.small_button(if is_pinned { tr("Unpin") } else { tr("Pin") }).clicked() {
                     result.toggle_pin = true;
                 }
-                if ui.small_button("Return to Now").clicked() {
+                if ui.small_button(tr("Return to Now")).clicked() {
                     result.return_to_now = true;
                 }
             });
@@ -640,16 +1254,70 @@ pub fn draw_inspect_tooltip(
 /// Draw keyboard help overlay
 #[allow(dead_code)]
 pub fn draw_help_overlay(ctx: &egui::Context) {
-    egui::Window::new("Keyboard Shortcuts")
+    egui::Window::new(tr("Keyboard Shortcuts"))
+        .id(egui::Id::new("keyboard_shortcuts"))
         .collapsible(false)
         .resizable(false)
         .anchor(egui::Align2::LEFT_BOTTOM, [10.0, -10.0])
         .show(ctx, |ui| {
-            ui.label("← → : Move by minute");
-            ui.label("Shift+← → : Move by hour");
-            ui.label("Enter : Pin/unpin inspection");
-            ui.label("Esc : Return to now");
-            ui.label("/ : Search timezone");
+            ui.label(tr("← → : Move by minute"));
+            ui.label(tr("Shift+← → : Move by hour"));
+            ui.label(tr("Enter : Pin/unpin inspection"));
+            ui.label(tr("Esc : Return to now"));
+            ui.label(tr("/ : Search timezone"));
         });
 }
 
+
+/// Restyle egui's chrome for the active theme
+///
+/// Leaves `visuals` alone while the clock uses its own colors.
+pub fn theme_visuals(visuals: &mut egui::Visuals) {
+    let Some(palette) = active_palette() else {
+        return;
+    };
+    let color = |c: Rgb| egui::Color32::from_rgb(c.red, c.green, c.blue);
+    *visuals = if palette.is_light() {
+        egui::Visuals::light()
+    } else {
+        egui::Visuals::dark()
+    };
+    visuals.panel_fill = color(palette.background);
+    visuals.window_fill = color(palette.background);
+    visuals.override_text_color = Some(color(palette.primary_text));
+    visuals.hyperlink_color = color(palette.accent);
+    visuals.warn_fg_color = color(palette.warning);
+}
+
+/// Draw the theme switcher
+///
+/// Returns the newly chosen theme, where `Some(None)` means the clock's own
+/// colors.
+pub fn theme_selector(
+    ui: &mut egui::Ui,
+    themes: &ThemeLibrary,
+    selected: Option<&str>,
+) -> Option<Option<String>> {
+    let mut choice = None;
+    egui::ComboBox::from_label(tr("Theme"))
+        .selected_text(selected.unwrap_or(tr("Clock colors")))
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(selected.is_none(), tr("Clock colors")).clicked() {
+                choice = Some(None);
+            }
+            for theme in &themes.themes {
+                let is_selected = selected == Some(theme.name.as_str());
+                if ui.selectable_label(is_selected, &theme.name).clicked() {
+                    choice = Some(Some(theme.name.clone()));
+                }
+            }
+        });
+    if let Some(error) = &themes.error {
+        ui.label(
+            egui::RichText::new(error)
+                .size(10.0)
+                .color(egui::Color32::from_rgb(255, 100, 100)),
+        );
+    }
+    choice
+}
@@ -0,0 +1,189 @@
+//! Waypoints module - noted times of day pinned on the terrain
+//!
+//! A waypoint marks a minute of the day ("standup", "kid pickup") and shows
+//! on every day's map. Waypoints are kept per timezone, so switching zones
+//! swaps in that zone's set.
+
+use std::collections::BTreeMap;
+
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// A pinned minute of the day with a short note
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Waypoint {
+    /// Minutes since local midnight, as on the map's hour grid
+    pub minute: u32,
+    #[serde(default)]
+    pub note: String,
+}
+
+impl Waypoint {
+    /// The note, or the time itself for a waypoint without one
+    pub fn label(&self) -> String {
+        let note = self.note.trim();
+        if note.is_empty() {
+            format_minute(self.minute)
+        } else {
+            note.to_string()
+        }
+    }
+}
+
+/// Waypoints for every timezone, keyed by zone id
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct WaypointBook {
+    zones: BTreeMap<String, Vec<Waypoint>>,
+}
+
+impl WaypointBook {
+    /// Waypoints for `tz`, earliest first
+    pub fn for_zone(&self, tz: Tz) -> &[Waypoint] {
+        self.zones.get(tz.name()).map_or(&[], Vec::as_slice)
+    }
+
+    /// Pin `minute` in `tz`, returning the waypoint's index
+    ///
+    /// Re-pinning a minute keeps the existing waypoint, taking the new note
+    /// if one is given.
+    pub fn pin(&mut self, tz: Tz, minute: u32, note: &str) -> usize {
+        let list = self.zones.entry(tz.name().to_string()).or_default();
+        let note = note.trim();
+        if let Some(index) = find_at(list, minute) {
+            if !note.is_empty() {
+                list[index].note = note.to_string();
+            }
+            return index;
+        }
+        let index = list.partition_point(|w| w.minute < minute);
+        list.insert(
+            index,
+            Waypoint {
+                minute,
+                note: note.to_string(),
+            },
+        );
+        index
+    }
+
+    /// Remove the waypoint at `index` in `tz`'s list
+    pub fn remove(&mut self, tz: Tz, index: usize) -> Option<Waypoint> {
+        let list = self.zones.get_mut(tz.name())?;
+        if index >= list.len() {
+            return None;
+        }
+        let removed = list.remove(index);
+        if list.is_empty() {
+            self.zones.remove(tz.name());
+        }
+        Some(removed)
+    }
+
+    /// Replace `tz`'s waypoints (e.g. after editing notes)
+    pub fn replace(&mut self, tz: Tz, mut waypoints: Vec<Waypoint>) {
+        if waypoints.is_empty() {
+            self.zones.remove(tz.name());
+            return;
+        }
+        waypoints.sort_by_key(|w| w.minute);
+        self.zones.insert(tz.name().to_string(), waypoints);
+    }
+}
+
+/// Index of the waypoint at exactly `minute`
+pub fn find_at(waypoints: &[Waypoint], minute: u32) -> Option<usize> {
+    waypoints.iter().position(|w| w.minute == minute)
+}
+
+/// The waypoint after (or before) `minute`, wrapping around the day
+pub fn adjacent(waypoints: &[Waypoint], minute: u32, forward: bool) -> Option<usize> {
+    if waypoints.is_empty() {
+        return None;
+    }
+    let index = if forward {
+        waypoints
+            .iter()
+            .position(|w| w.minute > minute)
+            .unwrap_or(0)
+    } else {
+        waypoints
+            .iter()
+            .rposition(|w| w.minute < minute)
+            .unwrap_or(waypoints.len() - 1)
+    };
+    Some(index)
+}
+
+/// Format a minute of the day as "9:30 AM"
+pub fn format_minute(minute: u32) -> String {
+    let hours = (minute / 60) % 24;
+    let hour12 = match hours {
+        0 => 12,
+        1..=12 => hours,
+        _ => hours - 12,
+    };
+    let meridiem = if hours < 12 { "AM" } else { "PM" };
+    format!("{}:{:02} {}", hour12, minute % 60, meridiem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn los_angeles() -> Tz {
+        "America/Los_Angeles".parse().unwrap()
+    }
+
+    #[test]
+    fn test_pin_keeps_order_and_zones() {
+        let la = los_angeles();
+        let mut book = WaypointBook::default();
+        assert_eq!(book.pin(la, 15 * 60, "kid pickup"), 0);
+        assert_eq!(book.pin(la, 9 * 60 + 30, "standup"), 0);
+        // Re-pinning updates the note but not the position
+        assert_eq!(book.pin(la, 15 * 60, ""), 1);
+        assert_eq!(book.pin(la, 15 * 60, "school run"), 1);
+
+        let list = book.for_zone(la);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[1].note, "school run");
+        assert!(book.for_zone("Asia/Tokyo".parse().unwrap()).is_empty());
+
+        assert_eq!(book.remove(la, 0).unwrap().note, "standup");
+        assert!(book.remove(la, 3).is_none());
+        book.replace(la, Vec::new());
+        assert!(book.for_zone(la).is_empty());
+    }
+
+    #[test]
+    fn test_adjacent_wraps() {
+        let waypoints: Vec<Waypoint> = [540, 720, 900]
+            .iter()
+            .map(|&minute| Waypoint {
+                minute,
+                note: String::new(),
+            })
+            .collect();
+
+        assert_eq!(adjacent(&waypoints, 600, true), Some(1));
+        assert_eq!(adjacent(&waypoints, 720, true), Some(2));
+        assert_eq!(adjacent(&waypoints, 900, true), Some(0));
+        assert_eq!(adjacent(&waypoints, 720, false), Some(0));
+        assert_eq!(adjacent(&waypoints, 500, false), Some(2));
+        assert_eq!(adjacent(&[], 500, false), None);
+    }
+
+    #[test]
+    fn test_labels() {
+        assert_eq!(format_minute(0), "12:00 AM");
+        assert_eq!(format_minute(9 * 60 + 5), "9:05 AM");
+        assert_eq!(format_minute(15 * 60 + 30), "3:30 PM");
+
+        let waypoint = Waypoint {
+            minute: 9 * 60,
+            note: "  ".to_string(),
+        };
+        assert_eq!(waypoint.label(), "9:00 AM");
+    }
+}
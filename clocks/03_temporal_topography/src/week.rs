@@ -0,0 +1,122 @@
+//! Week module - which days of the week are on screen
+//!
+//! The map can show anywhere from one day to the full week as adjacent
+//! terrain panels. Zooming changes how many days are visible and scrolling
+//! moves the window a whole day at a time, so panels always line up with the
+//! edges of the canvas.
+
+/// Number of days in the week view (Monday first)
+pub const WEEK_DAYS: usize = 7;
+
+/// Visible window into the week
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekView {
+    /// Index (0 = Monday) of the leftmost visible day
+    pub first_day: usize,
+    /// Number of days shown side by side (1..=7)
+    pub visible_days: usize,
+}
+
+impl WeekView {
+    /// A single day
+    pub fn day(day_index: usize) -> Self {
+        Self {
+            first_day: day_index.min(WEEK_DAYS - 1),
+            visible_days: 1,
+        }
+    }
+
+    /// The whole week
+    pub fn week() -> Self {
+        Self {
+            first_day: 0,
+            visible_days: WEEK_DAYS,
+        }
+    }
+
+    pub fn is_single_day(&self) -> bool {
+        self.visible_days == 1
+    }
+
+    pub fn is_week(&self) -> bool {
+        self.visible_days == WEEK_DAYS
+    }
+
+    /// Whether a day index is on screen
+    pub fn shows(&self, day_index: usize) -> bool {
+        day_index >= self.first_day && day_index < self.first_day + self.visible_days
+    }
+
+    /// Show one more (`delta > 0`) or one fewer day, keeping `anchor_day` visible
+    pub fn zoom(&mut self, delta: i32, anchor_day: usize) {
+        let visible = (self.visible_days as i32 + delta).clamp(1, WEEK_DAYS as i32) as usize;
+        if visible == self.visible_days {
+            return;
+        }
+
+        // Keep the anchor at the same relative spot where possible
+        let anchor = anchor_day.min(WEEK_DAYS - 1);
+        let relative = anchor.saturating_sub(self.first_day).min(visible - 1);
+        self.visible_days = visible;
+        self.first_day = anchor.saturating_sub(relative).min(WEEK_DAYS - visible);
+    }
+
+    /// Move the window by whole days
+    pub fn scroll(&mut self, delta: i32) {
+        let max_first = (WEEK_DAYS - self.visible_days) as i32;
+        self.first_day = (self.first_day as i32 + delta).clamp(0, max_first) as usize;
+    }
+
+    /// Horizontal span of each visible day as `(day_index, left, right)`
+    pub fn panels(&self, left: f32, width: f32) -> Vec<(usize, f32, f32)> {
+        let panel_width = width / self.visible_days as f32;
+        (0..self.visible_days)
+            .map(|i| {
+                let x = left + i as f32 * panel_width;
+                (self.first_day + i, x, x + panel_width)
+            })
+            .collect()
+    }
+
+    /// Day index and normalized position within that day for an x coordinate
+    pub fn locate(&self, x: f32, left: f32, width: f32) -> (usize, f32) {
+        let t = ((x - left) / width).clamp(0.0, 1.0) * self.visible_days as f32;
+        let offset = (t.floor() as usize).min(self.visible_days - 1);
+        (self.first_day + offset, (t - offset as f32).clamp(0.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zoom_keeps_anchor_visible() {
+        let mut view = WeekView::day(4);
+        view.zoom(1, 4);
+        assert_eq!(view, WeekView { first_day: 4, visible_days: 2 });
+        view.zoom(10, 4);
+        assert!(view.is_week());
+        view.zoom(-6, 6);
+        assert_eq!(view, WeekView::day(6));
+
+        let mut view = WeekView::day(6);
+        view.zoom(2, 6);
+        assert_eq!(view.first_day + view.visible_days, WEEK_DAYS);
+        assert!(view.shows(6));
+    }
+
+    #[test]
+    fn test_scroll_and_locate() {
+        let mut view = WeekView { first_day: 1, visible_days: 3 };
+        view.scroll(-5);
+        assert_eq!(view.first_day, 0);
+        view.scroll(10);
+        assert_eq!(view.first_day, 4);
+
+        let panels = view.panels(0.0, 300.0);
+        assert_eq!(panels, vec![(4, 0.0, 100.0), (5, 100.0, 200.0), (6, 200.0, 300.0)]);
+        assert_eq!(view.locate(150.0, 0.0, 300.0), (5, 0.5));
+        assert_eq!(view.locate(300.0, 0.0, 300.0), (6, 1.0));
+    }
+}
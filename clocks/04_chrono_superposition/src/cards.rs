@@ -1,11 +1,13 @@
 //! Card module - Zone card data model, ordering logic, and geometry calculations
 //!
-//! Handles the deterministic ordering of zone cards and their visual geometry
-//! including stacking offsets, rotation, and parallax effects.
+//! Handles the ordering of zone cards (automatic, or arranged by dragging) and
+//! their visual geometry including stacking offsets, rotation, and parallax effects,
+//! or the rows and columns of the grid view.
 
 use chrono::Offset;
 use chrono_tz::Tz;
 use nannou::prelude::*;
+use shared::tr;
 
 use crate::drawing::CoreLayout;
 
@@ -24,6 +26,12 @@ const ROTATION_DEG: f32 = 0.6;
 const PARALLAX_BASE: f32 = 6.0;
 const PARALLAX_DEPTH_FACTOR: f32 = 0.15;
 
+/// Gap between cards in the grid view
+const GRID_GAP: f32 = 16.0;
+/// Grid cards shrink to fit the window but never grow past deck size
+const GRID_MAX_SCALE: f32 = 1.0;
+const GRID_MIN_SCALE: f32 = 0.3;
+
 /// Geometry for a single card in the deck
 #[derive(Debug, Clone)]
 pub struct CardGeometry {
@@ -79,7 +87,7 @@ impl CardGeometry {
         let rotation = if reduced_motion {
             0.0
         } else {
-            let sign = if index % 2 == 0 { 1.0 } else { -1.0 };
+            let sign = if index.is_multiple_of(2) { 1.0 } else { -1.0 };
             (i * ROTATION_DEG * sign * spread_factor).to_radians()
         };
 
@@ -109,6 +117,53 @@ impl CardGeometry {
     }
 }
 
+/// Columns, rows and card scale for `count` cards in a `width` x `height` area
+///
+/// Picks the column count that lets the cards be largest; among equally
+/// large fits, the squarest grid wins.
+pub fn grid_shape(count: usize, width: f32, height: f32) -> (usize, usize, f32) {
+    let count = count.max(1);
+    let (cols, rows, scale) = (1..=count)
+        .map(|cols| {
+            let rows = count.div_ceil(cols);
+            let fit_w = (width - (cols - 1) as f32 * GRID_GAP) / (cols as f32 * CARD_WIDTH);
+            let fit_h = (height - (rows - 1) as f32 * GRID_GAP) / (rows as f32 * CARD_HEIGHT);
+            (cols, rows, fit_w.min(fit_h).min(GRID_MAX_SCALE))
+        })
+        .max_by(|a, b| {
+            a.2.total_cmp(&b.2)
+                .then_with(|| b.0.abs_diff(b.1).cmp(&a.0.abs_diff(a.1)))
+        })
+        .unwrap_or((1, 1, GRID_MAX_SCALE));
+    (cols, rows, scale.max(GRID_MIN_SCALE))
+}
+
+/// Geometry for every card in the grid view, filled row by row in display
+/// order and centered in the core area (a short last row is centered too)
+pub fn grid_geometries(count: usize, layout: &CoreLayout) -> Vec<CardGeometry> {
+    let (cols, rows, scale) = grid_shape(count, layout.width, layout.height);
+    let cell_w = CARD_WIDTH * scale + GRID_GAP;
+    let cell_h = CARD_HEIGHT * scale + GRID_GAP;
+
+    (0..count)
+        .map(|index| {
+            let (row, col) = (index / cols, index % cols);
+            let in_row = (count - row * cols).min(cols);
+            let offset = pt2(
+                (col as f32 - (in_row as f32 - 1.0) / 2.0) * cell_w,
+                ((rows as f32 - 1.0) / 2.0 - row as f32) * cell_h,
+            );
+            CardGeometry {
+                index,
+                offset,
+                rotation: 0.0,
+                scale,
+                opacity: 1.0,
+            }
+        })
+        .collect()
+}
+
 /// Compute the deterministic display order for zones
 ///
 /// Order:
@@ -141,7 +196,7 @@ pub fn compute_display_order(
 
     remaining.sort_by(|a, b| {
         // Get current UTC offsets
-        let now = chrono::Utc::now();
+        let now = shared::now_utc();
         let offset_a = now.with_timezone(a).offset().fix().local_minus_utc();
         let offset_b = now.with_timezone(b).offset().fix().local_minus_utc();
 
@@ -155,6 +210,57 @@ pub fn compute_display_order(
     result
 }
 
+/// How the deck orders its cards
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum OrderingStrategy {
+    /// Dominant, favorites, then UTC offset (see `compute_display_order`)
+    #[default]
+    Automatic,
+    /// The order the user dragged the cards into
+    Manual(Vec<Tz>),
+}
+
+impl OrderingStrategy {
+    pub fn is_manual(&self) -> bool {
+        matches!(self, OrderingStrategy::Manual(_))
+    }
+
+    /// Display order of the selected zones under this strategy
+    ///
+    /// The dominant zone always leads. In a manual order, zones added since
+    /// the last drag follow the arranged ones in automatic order.
+    pub fn display_order(
+        &self,
+        selected_zones: &[Tz],
+        dominant_zone: Tz,
+        favorites: &[Tz],
+    ) -> Vec<Tz> {
+        let automatic = compute_display_order(selected_zones, dominant_zone, favorites);
+        let OrderingStrategy::Manual(order) = self else {
+            return automatic;
+        };
+
+        let mut result = Vec::with_capacity(automatic.len());
+        result.push(dominant_zone);
+        for &tz in order.iter().chain(&automatic) {
+            if selected_zones.contains(&tz) && !result.contains(&tz) {
+                result.push(tz);
+            }
+        }
+        result
+    }
+}
+
+/// Move the card at `from` into slot `to`, shifting the cards in between
+pub fn move_card(order: &[Tz], from: usize, to: usize) -> Vec<Tz> {
+    let mut result = order.to_vec();
+    if from < result.len() {
+        let tz = result.remove(from);
+        result.insert(to.min(result.len()), tz);
+    }
+    result
+}
+
 /// Data for comparing a zone to the dominant zone
 #[derive(Debug, Clone)]
 pub struct ZoneComparison {
@@ -210,6 +316,26 @@ impl ZoneComparison {
     }
 }
 
+/// How far ahead a zone's minute and second hands read compared with the
+/// dominant zone's, in seconds within the hour (0 when they line up)
+///
+/// Offsets that differ by whole hours keep the hands aligned; Asia/Kathmandu
+/// (+5:45) against Europe/London (+0:00) reads 45 minutes ahead. Historical
+/// local mean time offsets can drift by seconds as well.
+pub fn alignment_drift(zone_offset_seconds: i32, dominant_offset_seconds: i32) -> i32 {
+    (zone_offset_seconds - dominant_offset_seconds).rem_euclid(3600)
+}
+
+/// Format an alignment drift as "+45m", "+19m 32s" or "+12s"
+pub fn format_drift(drift_seconds: i32) -> String {
+    match (drift_seconds / 60, drift_seconds % 60) {
+        (0, 0) => tr("aligned").to_string(),
+        (minutes, 0) => format!("+{}m", minutes),
+        (0, seconds) => format!("+{}s", seconds),
+        (minutes, seconds) => format!("+{}m {:02}s", minutes, seconds),
+    }
+}
+
 /// Compute the "wall minutes" for a zone's local time
 ///
 /// Used for composite readout calculations:
@@ -242,6 +368,45 @@ mod tests {
         assert_eq!(order[1], "Asia/Tokyo".parse::<Tz>().unwrap());
     }
 
+    #[test]
+    fn test_alignment_drift() {
+        // Kathmandu +5:45 and Eucla +8:45 against London and each other
+        assert_eq!(alignment_drift(20_700, 0), 45 * 60);
+        assert_eq!(alignment_drift(31_500, 20_700), 0);
+        // New York (-4:00) keeps London's minutes; Newfoundland (-2:30) doesn't
+        assert_eq!(alignment_drift(-14_400, 3_600), 0);
+        assert_eq!(alignment_drift(-9_000, 3_600), 30 * 60);
+        // Amsterdam's 1900 local mean time, +0:19:32
+        assert_eq!(alignment_drift(1_172, 0), 1_172);
+
+        assert_eq!(format_drift(0), "aligned");
+        assert_eq!(format_drift(45 * 60), "+45m");
+        assert_eq!(format_drift(1_172), "+19m 32s");
+        assert_eq!(format_drift(12), "+12s");
+    }
+
+    #[test]
+    fn test_manual_order() {
+        let zones: Vec<Tz> = ["America/New_York", "Europe/London", "Asia/Tokyo", "UTC"]
+            .iter()
+            .map(|name| name.parse().unwrap())
+            .collect();
+        let (new_york, london, tokyo, utc) = (zones[0], zones[1], zones[2], zones[3]);
+
+        // Dropping New York into slot 2 shifts London and Tokyo up
+        let arranged = move_card(&[new_york, london, tokyo], 0, 2);
+        assert_eq!(arranged, [london, tokyo, new_york]);
+        assert_eq!(move_card(&arranged, 1, 9), [london, new_york, tokyo]);
+
+        // The dominant zone leads, unplaced zones follow, removed ones drop out
+        let manual = OrderingStrategy::Manual(vec![tokyo, "Asia/Dubai".parse().unwrap(), new_york]);
+        assert!(manual.is_manual());
+        let order = manual.display_order(&zones, london, &[]);
+        assert_eq!(order[..3], [london, tokyo, new_york]);
+        assert_eq!(order[3], utc);
+        assert_eq!(order.len(), zones.len());
+    }
+
     #[test]
     fn test_zone_comparison() {
         let comp = ZoneComparison::compute(
@@ -257,5 +422,30 @@ mod tests {
         assert_eq!(comp.delta_days, 0);
         assert!(!comp.dst_differs);
     }
-}
 
+    #[test]
+    fn test_grid_shape_fits_area() {
+        // Nine cards in a roomy area tile 3x3 at full size
+        assert_eq!(grid_shape(9, 1000.0, 600.0), (3, 3, 1.0));
+        // A wide, short area takes more columns, shrinking cards to fit
+        let (cols, rows, scale) = grid_shape(12, 1200.0, 300.0);
+        assert!(cols > rows);
+        assert!(scale < 1.0);
+        assert!(cols * rows >= 12);
+    }
+
+    #[test]
+    fn test_grid_cards_stay_inside_layout() {
+        let layout = CoreLayout::calculate(Rect::from_w_h(1200.0, 800.0), 200.0, 200.0);
+        let geometries = grid_geometries(7, &layout);
+        assert_eq!(geometries.len(), 7);
+        for (i, geom) in geometries.iter().enumerate() {
+            let rect = geom.card_rect(&layout);
+            assert!(rect.left() >= layout.left - 0.01 && rect.right() <= layout.right + 0.01);
+            assert!(rect.bottom() >= layout.bottom - 0.01 && rect.top() <= layout.top + 0.01);
+            for other in &geometries[i + 1..] {
+                assert!(rect.overlap(other.card_rect(&layout)).is_none());
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Details module - what the back of a flipped card shows
+//!
+//! With card flipping on, clicking a card that isn't dominant (or pressing
+//! Enter on it) turns it over instead of promoting it. The back names the
+//! zone in full with its country, gives the next DST transition as the last
+//! local second before it and the first after, and lists the offsets the
+//! zone keeps over the year on display. A button on the back still makes
+//! the zone dominant.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+use shared::{main_city, query_transitions_between, tr_args, zone_display_name, OffsetState};
+
+use crate::dst_notify::format_minutes;
+
+/// How far ahead to look for the next transition
+const TRANSITION_HORIZON_DAYS: i64 = 400;
+
+/// Format of the local instants either side of a transition
+const INSTANT_FORMAT: &str = "%a %b %-d %Y, %H:%M:%S %Z";
+
+/// The next change to a zone's offset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NextTransition {
+    /// When the clocks change (UTC)
+    pub instant_utc: DateTime<Utc>,
+    /// Offset change in minutes (positive = spring forward)
+    pub delta_minutes: i32,
+    /// The last local second before the change
+    pub last_before: String,
+    /// The first local second after it
+    pub first_after: String,
+}
+
+impl NextTransition {
+    /// "Clocks go forward 1h"
+    pub fn describe(&self) -> String {
+        let text = if self.delta_minutes > 0 {
+            "Clocks go forward {0}"
+        } else {
+            "Clocks go back {0}"
+        };
+        tr_args(text, &[&format_minutes(i64::from(self.delta_minutes.abs()))])
+    }
+}
+
+/// A stretch of the year spent on one offset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffsetPeriod {
+    /// Local dates the stretch starts and ends on
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub state: OffsetState,
+}
+
+impl OffsetPeriod {
+    /// "Mar 9 – Nov 2  UTC-04:00 EDT"
+    pub fn describe(&self) -> String {
+        let minutes = self.state.standard_minutes + self.state.saving_minutes;
+        let sign = if minutes >= 0 { "+" } else { "-" };
+        format!(
+            "{} – {}  UTC{}{:02}:{:02} {}",
+            self.from.format("%b %-d"),
+            self.to.format("%b %-d"),
+            sign,
+            minutes.abs() / 60,
+            minutes.abs() % 60,
+            self.state.abbreviation
+        )
+    }
+}
+
+/// The back of one zone's card
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneDetails {
+    pub tz: Tz,
+    /// "Tokyo, Japan (Asia/Tokyo)", or the zone id alone
+    pub full_name: String,
+    pub country: Option<&'static str>,
+    /// `None` when the offset doesn't change within the horizon
+    pub next_transition: Option<NextTransition>,
+    /// Local year the history covers
+    pub year: i32,
+    /// Offsets kept over `year`, in order
+    pub offset_history: Vec<OffsetPeriod>,
+}
+
+impl ZoneDetails {
+    /// Details for `tz` as of `instant`
+    pub fn at(tz: Tz, instant: DateTime<Utc>) -> Self {
+        let year = instant.with_timezone(&tz).year();
+        Self {
+            tz,
+            full_name: zone_display_name(tz),
+            country: main_city(tz).map(|city| city.country),
+            next_transition: next_transition(tz, instant),
+            year,
+            offset_history: offset_history(tz, year),
+        }
+    }
+}
+
+/// The first transition in `tz` after `instant`, within the horizon
+fn next_transition(tz: Tz, instant: DateTime<Utc>) -> Option<NextTransition> {
+    let horizon = instant + Duration::days(TRANSITION_HORIZON_DAYS);
+    let transition = query_transitions_between(tz, instant, horizon).into_iter().next()?;
+    let at = transition.instant_utc;
+    let local = |second: DateTime<Utc>| second.with_timezone(&tz).format(INSTANT_FORMAT);
+    Some(NextTransition {
+        instant_utc: at,
+        delta_minutes: transition.delta_minutes,
+        last_before: local(at - Duration::seconds(1)).to_string(),
+        first_after: local(at).to_string(),
+    })
+}
+
+/// The instant `tz`'s local `year` begins
+fn year_start(tz: Tz, year: i32) -> DateTime<Utc> {
+    tz.with_ymd_and_hms(year, 1, 1, 0, 0, 0)
+        .earliest()
+        .map(|start| start.with_timezone(&Utc))
+        .unwrap_or_else(|| Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap())
+}
+
+/// The offsets `tz` keeps over its local `year`, split at each transition
+fn offset_history(tz: Tz, year: i32) -> Vec<OffsetPeriod> {
+    let start = year_start(tz, year);
+    let end = year_start(tz, year + 1);
+    let mut bounds = vec![start];
+    bounds.extend(
+        query_transitions_between(tz, start, end)
+            .into_iter()
+            .map(|transition| transition.instant_utc)
+            .filter(|&at| at > start && at < end),
+    );
+    bounds.push(end);
+
+    bounds
+        .windows(2)
+        .map(|span| OffsetPeriod {
+            from: span[0].with_timezone(&tz).date_naive(),
+            to: (span[1] - Duration::seconds(1)).with_timezone(&tz).date_naive(),
+            state: OffsetState::at(tz, span[0]),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(details: &ZoneDetails) -> Vec<String> {
+        details.offset_history.iter().map(OffsetPeriod::describe).collect()
+    }
+
+    #[test]
+    fn test_new_york_details() {
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let instant = Utc.with_ymd_and_hms(2025, 1, 15, 12, 0, 0).unwrap();
+        let details = ZoneDetails::at(tz, instant);
+        assert_eq!(details.country, Some("United States"));
+        assert!(details.full_name.ends_with("(America/New_York)"));
+
+        // Spring forward at 2025-03-09 07:00 UTC
+        let next = details.next_transition.as_ref().unwrap();
+        assert_eq!(next.instant_utc, Utc.with_ymd_and_hms(2025, 3, 9, 7, 0, 0).unwrap());
+        assert_eq!(next.describe(), "Clocks go forward 1h");
+        assert_eq!(next.last_before, "Sun Mar 9 2025, 01:59:59 EST");
+        assert_eq!(next.first_after, "Sun Mar 9 2025, 03:00:00 EDT");
+
+        assert_eq!(
+            history(&details),
+            [
+                "Jan 1 – Mar 9  UTC-05:00 EST",
+                "Mar 9 – Nov 2  UTC-04:00 EDT",
+                "Nov 2 – Dec 31  UTC-05:00 EST",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_zone_without_dst() {
+        let tz: Tz = "Asia/Tokyo".parse().unwrap();
+        let instant = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        let details = ZoneDetails::at(tz, instant);
+        assert_eq!(details.next_transition, None);
+        assert_eq!(details.year, 2025);
+        assert_eq!(history(&details), ["Jan 1 – Dec 31  UTC+09:00 JST"]);
+    }
+}
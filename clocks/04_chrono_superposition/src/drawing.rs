@@ -2,19 +2,33 @@
 //!
 //! Handles all nannou-based rendering including the superposition deck,
 //! composite readout, list view, and DST warning effects.
+//!
+//! The composite readout can also show how far each zone's minute and second
+//! hands sit from the dominant zone's, for offsets like +5:45 that aren't
+//! whole hours apart.
+//!
+//! Under the deck and grid, an optional timeline strip lines every zone's
+//! hours up against the dominant zone's day.
 
 use std::collections::HashMap;
 
+use chrono::{DateTime, Offset, Utc};
 use chrono_tz::Tz;
 use nannou::prelude::*;
-use shared::{DstChange, TimeData};
+use shared::{tr, tr_args, DstChange, TimeData};
 
-use crate::cards::{CardGeometry, ZoneComparison, CARD_HEIGHT, CARD_WIDTH};
+use crate::cards::{
+    alignment_drift, format_drift, CardGeometry, ZoneComparison, CARD_HEIGHT, CARD_WIDTH,
+};
+use crate::labels::{short_zone_name, zone_color, zone_name, ZoneLabel};
+use crate::planner::{hours_for, BusinessHours};
+use crate::timeline::{Shade, TimelineAxis};
 
 /// Color palette for the chrono-superposition theme
 #[allow(dead_code)]
 pub mod colors {
     use nannou::prelude::*;
+    use shared::{Rgb, ThemeRole};
 
     /// Deep background
     pub const BACKGROUND: Srgb<u8> = Srgb {
@@ -143,6 +157,82 @@ pub mod colors {
         blue: 65,
         standard: std::marker::PhantomData,
     };
+
+    /// Within business hours / shared overlap (meeting planner)
+    pub const IN_HOURS: Srgb<u8> = Srgb {
+        red: 110,
+        green: 220,
+        blue: 150,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Deck is previewing an instant other than now
+    pub const SCRUB_ACTIVE: Srgb<u8> = Srgb {
+        red: 180,
+        green: 160,
+        blue: 255,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Timeline cell during the working day
+    pub const TIMELINE_DAY: Srgb<u8> = Srgb {
+        red: 62,
+        green: 78,
+        blue: 104,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Timeline cell in the early morning or evening
+    pub const TIMELINE_FRINGE: Srgb<u8> = Srgb {
+        red: 42,
+        green: 50,
+        blue: 68,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Timeline cell at night
+    pub const TIMELINE_NIGHT: Srgb<u8> = Srgb {
+        red: 24,
+        green: 28,
+        blue: 38,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Look up one of this clock's role colors in the active theme
+    fn themed(role: ThemeRole, native: Srgb<u8>) -> Srgb<u8> {
+        let color = shared::themed(role, Rgb::new(native.red, native.green, native.blue));
+        Srgb::new(color.red, color.green, color.blue)
+    }
+
+    pub fn background() -> Srgb<u8> {
+        themed(ThemeRole::Background, BACKGROUND)
+    }
+
+    pub fn text_primary() -> Srgb<u8> {
+        themed(ThemeRole::PrimaryText, TEXT_PRIMARY)
+    }
+
+    pub fn secondary_text() -> Srgb<u8> {
+        themed(ThemeRole::SecondaryText, SECONDARY_TEXT)
+    }
+
+    pub fn scrub_active() -> Srgb<u8> {
+        themed(ThemeRole::Accent, SCRUB_ACTIVE)
+    }
+
+    pub fn dst_warning() -> Srgb<u8> {
+        themed(ThemeRole::Warning, DST_WARNING)
+    }
+
+    pub fn dst_active() -> Srgb<u8> {
+        themed(ThemeRole::Accent, DST_ACTIVE)
+    }
+
+    /// The composite panel takes the theme's background, so the themed text
+    /// on it keeps the theme's contrast
+    pub fn composite_bg() -> Srgb<u8> {
+        themed(ThemeRole::Background, COMPOSITE_BG)
+    }
 }
 
 /// Layout configuration for the core (center) area
@@ -159,7 +249,6 @@ pub struct CoreLayout {
     /// Width of the core area
     pub width: f32,
     /// Height of the core area
-    #[allow(dead_code)]
     pub height: f32,
     /// Center X position
     pub center_x: f32,
@@ -197,20 +286,59 @@ impl CoreLayout {
     pub fn contains(&self, x: f32, y: f32) -> bool {
         x >= self.left && x <= self.right && y >= self.bottom && y <= self.top
     }
+
+    /// Split off a strip of `count` timeline bands at the bottom, returning
+    /// the area left above it and the strip
+    pub fn split_timeline(&self, count: usize) -> (Self, Rect) {
+        let band = timeline_band_height(self.height, count);
+        let strip_height = band * count as f32 + TIMELINE_GAP;
+        let strip = Rect::from_corners(
+            pt2(self.left, self.bottom),
+            pt2(self.right, self.bottom + strip_height - TIMELINE_GAP),
+        );
+        let bottom = self.bottom + strip_height;
+        let above = Self {
+            bottom,
+            height: self.top - bottom,
+            center_y: (self.top + bottom) / 2.0,
+            ..*self
+        };
+        (above, strip)
+    }
+}
+
+/// Tallest a zone's band in the timeline strip gets
+const TIMELINE_BAND_HEIGHT: f32 = 18.0;
+/// Space between the deck and the timeline strip
+const TIMELINE_GAP: f32 = 12.0;
+/// Width of the zone names left of the timeline's bands
+const TIMELINE_NAME_WIDTH: f32 = 80.0;
+/// Most of the core area's height the timeline may take
+const TIMELINE_MAX_SHARE: f32 = 0.35;
+
+/// Band height that fits `count` bands in the timeline's share of the core
+fn timeline_band_height(core_height: f32, count: usize) -> f32 {
+    let share = core_height * TIMELINE_MAX_SHARE / count.max(1) as f32;
+    share.min(TIMELINE_BAND_HEIGHT)
 }
 
 /// Draw the card deck view
+#[allow(clippy::too_many_arguments)]
 pub fn draw_card_deck(
     draw: &Draw,
     layout: &CoreLayout,
     display_order: &[Tz],
     zone_times: &HashMap<Tz, TimeData>,
     dominant_zone: Tz,
+    home_zone: Option<Tz>,
     geometries: &[CardGeometry],
     compare_mode: bool,
     hovered_index: Option<usize>,
+    flip: Option<(Tz, f32)>,
     animation_time: f32,
     reduced_motion: bool,
+    business_hours: Option<&HashMap<Tz, BusinessHours>>,
+    labels: &HashMap<Tz, ZoneLabel>,
 ) {
     // Get dominant zone data for comparison
     let dominant_data = zone_times.get(&dominant_zone);
@@ -221,42 +349,55 @@ pub fn draw_card_deck(
         let geom = &geometries[i];
         let is_dominant = tz == dominant_zone;
         let is_hovered = hovered_index == Some(i);
+        let turned = flip.filter(|&(flipped, _)| flipped == tz).map_or(0.0, |(_, turned)| turned);
 
         if let Some(time_data) = zone_times.get(&tz) {
             draw_zone_card(
                 draw,
                 layout,
-                tz,
                 time_data,
                 geom,
                 is_dominant,
+                home_zone == Some(tz),
                 is_hovered,
+                turned,
                 compare_mode,
                 dominant_data,
                 animation_time,
                 reduced_motion,
+                business_hours.map(|hours| hours_for(hours, tz)),
+                &zone_name(labels, tz),
+                zone_color(labels, tz),
             );
         }
     }
 }
 
 /// Draw a single zone card
+#[allow(clippy::too_many_arguments)]
 fn draw_zone_card(
     draw: &Draw,
     layout: &CoreLayout,
-    tz: Tz,
     time_data: &TimeData,
     geom: &CardGeometry,
     is_dominant: bool,
+    is_home: bool,
     is_hovered: bool,
+    turned: f32,
     compare_mode: bool,
     dominant_data: Option<&TimeData>,
     animation_time: f32,
     reduced_motion: bool,
+    business_hours: Option<BusinessHours>,
+    name: &str,
+    tag: Option<[u8; 3]>,
 ) {
     let card_x = layout.center_x + geom.offset.x;
     let card_y = layout.center_y + geom.offset.y;
-    let card_w = CARD_WIDTH * geom.scale;
+    // A card turning over narrows to an edge, its face fading, and widens
+    // again showing its plain back, which the details panel then covers
+    let face = (turned * PI).cos();
+    let card_w = CARD_WIDTH * geom.scale * face.abs();
     let card_h = CARD_HEIGHT * geom.scale;
 
     // Determine if DST warning should pulse
@@ -297,11 +438,11 @@ fn draw_zone_card(
     // Card border
     let border_color = if has_dst_warning {
         let r = colors::CARD_BORDER.red as f32
-            + (colors::DST_WARNING.red as f32 - colors::CARD_BORDER.red as f32) * pulse;
+            + (colors::dst_warning().red as f32 - colors::CARD_BORDER.red as f32) * pulse;
         let g = colors::CARD_BORDER.green as f32
-            + (colors::DST_WARNING.green as f32 - colors::CARD_BORDER.green as f32) * pulse;
+            + (colors::dst_warning().green as f32 - colors::CARD_BORDER.green as f32) * pulse;
         let b = colors::CARD_BORDER.blue as f32
-            + (colors::DST_WARNING.blue as f32 - colors::CARD_BORDER.blue as f32) * pulse;
+            + (colors::dst_warning().blue as f32 - colors::CARD_BORDER.blue as f32) * pulse;
         srgba(r as u8, g as u8, b as u8, (255.0 * geom.opacity) as u8)
     } else if is_dominant {
         srgba(
@@ -358,13 +499,26 @@ fn draw_zone_card(
             .stroke_weight(if is_dominant { 2.0 } else { 1.0 });
     }
 
+    if face < 0.0 {
+        return;
+    }
+
     // Content (no rotation for readability)
     let content_scale = geom.scale;
-    let text_opacity = (255.0 * geom.opacity) as u8;
+    let text_opacity = (255.0 * geom.opacity * face) as u8;
+
+    // Color tag
+    let name_y = card_y + card_h * 0.32;
+    if let Some([r, g, b]) = tag {
+        draw.ellipse()
+            .x_y(card_x - card_w * 0.42, name_y)
+            .radius(4.0 * content_scale)
+            .color(srgba(r, g, b, text_opacity));
+    }
 
-    // Zone name
-    draw.text(tz.name())
-        .x_y(card_x, card_y + card_h * 0.32)
+    // Zone name (or nickname)
+    draw.text(name)
+        .x_y(card_x, name_y)
         .w(card_w - 20.0)
         .color(srgba(
             colors::ZONE_TEXT.red,
@@ -394,26 +548,21 @@ fn draw_zone_card(
     draw.text(&time_data.meridiem.to_string())
         .x_y(card_x + card_w * 0.32, card_y + card_h * 0.08)
         .color(srgba(
-            colors::SECONDARY_TEXT.red,
-            colors::SECONDARY_TEXT.green,
-            colors::SECONDARY_TEXT.blue,
+            colors::secondary_text().red,
+            colors::secondary_text().green,
+            colors::secondary_text().blue,
             text_opacity,
         ))
         .font_size((14.0 * content_scale) as u32);
 
     // Date
-    let date_str = format!(
-        "{} {}, {}",
-        month_abbrev(time_data.month),
-        time_data.day,
-        time_data.year
-    );
+    let date_str = short_date(time_data.month, time_data.day, time_data.year);
     draw.text(&date_str)
         .x_y(card_x, card_y - card_h * 0.12)
         .color(srgba(
-            colors::SECONDARY_TEXT.red,
-            colors::SECONDARY_TEXT.green,
-            colors::SECONDARY_TEXT.blue,
+            colors::secondary_text().red,
+            colors::secondary_text().green,
+            colors::secondary_text().blue,
             text_opacity,
         ))
         .font_size((12.0 * content_scale) as u32);
@@ -427,17 +576,17 @@ fn draw_zone_card(
             if time_data.is_dst {
                 colors::DST_ACTIVE.red
             } else {
-                colors::SECONDARY_TEXT.red
+                colors::secondary_text().red
             },
             if time_data.is_dst {
                 colors::DST_ACTIVE.green
             } else {
-                colors::SECONDARY_TEXT.green
+                colors::secondary_text().green
             },
             if time_data.is_dst {
                 colors::DST_ACTIVE.blue
             } else {
-                colors::SECONDARY_TEXT.blue
+                colors::secondary_text().blue
             },
             text_opacity,
         ))
@@ -477,6 +626,33 @@ fn draw_zone_card(
             text_opacity,
         );
     }
+
+    // Home zone badge
+    if is_home {
+        draw.text(tr("HOME"))
+            .x_y(card_x + card_w * 0.35, card_y + card_h * 0.35)
+            .color(srgba(
+                colors::CARD_BORDER_DOMINANT.red,
+                colors::CARD_BORDER_DOMINANT.green,
+                colors::CARD_BORDER_DOMINANT.blue,
+                text_opacity,
+            ))
+            .font_size((9.0 * content_scale) as u32);
+    }
+
+    // Business hours badge (meeting planner mode)
+    if let Some(hours) = business_hours {
+        let in_hours = hours.contains_minute(time_data.hour24 * 60 + time_data.minute);
+        let (label, color) = if in_hours {
+            ("● In hours", colors::IN_HOURS)
+        } else {
+            ("○ Off hours", colors::secondary_text())
+        };
+        draw.text(tr(label))
+            .x_y(card_x - card_w * 0.35, card_y - card_h * 0.35)
+            .color(srgba(color.red, color.green, color.blue, text_opacity))
+            .font_size((9.0 * content_scale) as u32);
+    }
 }
 
 /// Draw comparison delta badge
@@ -503,12 +679,12 @@ fn draw_comparison_badge(
 
     // Day delta
     if let Some(day_str) = comparison.format_day() {
-        badges.push((day_str.to_string(), colors::SECONDARY_TEXT));
+        badges.push((tr(day_str).to_string(), colors::secondary_text()));
     }
 
     // DST differs
     if comparison.dst_differs {
-        badges.push(("DST differs".to_string(), colors::DST_WARNING));
+        badges.push((tr("DST differs").to_string(), colors::dst_warning()));
     }
 
     // Draw badges
@@ -531,17 +707,19 @@ fn draw_dst_warning_badge(
     scale: f32,
     opacity: u8,
 ) {
-    let now = chrono::Utc::now();
+    let now = shared::now_utc();
     let warning_text = match &time_data.dst_change {
         DstChange::Upcoming { instant, delta_minutes } => {
             let hours_remaining = (*instant - now).num_hours();
             let direction = if *delta_minutes > 0 { "+" } else { "" };
-            format!("DST in {}h ({}{}m)", hours_remaining, direction, delta_minutes)
+            let shift = format!("{}{}", direction, delta_minutes);
+            tr_args("DST in {0}h ({1}m)", &[&hours_remaining, &shift])
         }
         DstChange::JustOccurred { instant, delta_minutes } => {
             let hours_ago = (now - *instant).num_hours();
             let direction = if *delta_minutes > 0 { "+" } else { "" };
-            format!("DST {}h ago ({}{}m)", hours_ago, direction, delta_minutes)
+            let shift = format!("{}{}", direction, delta_minutes);
+            tr_args("DST {0}h ago ({1}m)", &[&hours_ago, &shift])
         }
         DstChange::None => return,
     };
@@ -549,15 +727,16 @@ fn draw_dst_warning_badge(
     draw.text(&warning_text)
         .x_y(x, y)
         .color(srgba(
-            colors::DST_WARNING.red,
-            colors::DST_WARNING.green,
-            colors::DST_WARNING.blue,
+            colors::dst_warning().red,
+            colors::dst_warning().green,
+            colors::dst_warning().blue,
             opacity,
         ))
         .font_size((9.0 * scale) as u32);
 }
 
 /// Draw the composite readout view (when focus_strength >= 0.8)
+#[allow(clippy::too_many_arguments)]
 pub fn draw_composite_readout(
     draw: &Draw,
     layout: &CoreLayout,
@@ -565,10 +744,13 @@ pub fn draw_composite_readout(
     zone_times: &HashMap<Tz, TimeData>,
     dominant_zone: Tz,
     compare_mode: bool,
+    show_drift: bool,
     animation_time: f32,
+    overlap_active: bool,
+    labels: &HashMap<Tz, ZoneLabel>,
 ) {
     // Compute composite data
-    let composite = compute_composite_data(display_order, zone_times, dominant_zone);
+    let composite = compute_composite_data(display_order, zone_times, dominant_zone, labels);
 
     // Background panel
     let panel_w = 400.0;
@@ -576,79 +758,96 @@ pub fn draw_composite_readout(
     draw.rect()
         .x_y(layout.center_x, layout.center_y)
         .w_h(panel_w, panel_h)
-        .color(colors::COMPOSITE_BG);
+        .color(colors::composite_bg());
 
     draw.rect()
         .x_y(layout.center_x, layout.center_y)
         .w_h(panel_w, panel_h)
         .no_fill()
-        .stroke(colors::CARD_BORDER_DOMINANT)
+        .stroke(if overlap_active {
+            colors::IN_HOURS
+        } else {
+            colors::CARD_BORDER_DOMINANT
+        })
         .stroke_weight(2.0);
 
     // Title
-    draw.text("SUPERPOSITION COLLAPSED")
+    draw.text(tr("SUPERPOSITION COLLAPSED"))
         .x_y(layout.center_x, layout.center_y + panel_h * 0.38)
-        .color(colors::ZONE_TEXT)
+        .color(colors::secondary_text())
         .font_size(11);
 
     // Time (with range if different hours)
     draw.text(&composite.time_display)
         .x_y(layout.center_x, layout.center_y + panel_h * 0.15)
-        .color(colors::TIME_TEXT)
+        .color(colors::text_primary())
         .font_size(42);
 
     // Meridiem
     draw.text(&composite.meridiem_display)
         .x_y(layout.center_x + 140.0, layout.center_y + panel_h * 0.15)
-        .color(colors::SECONDARY_TEXT)
+        .color(colors::secondary_text())
         .font_size(18);
 
     // Date display
     draw.text(&composite.date_display)
         .x_y(layout.center_x, layout.center_y - panel_h * 0.05)
-        .color(colors::SECONDARY_TEXT)
+        .color(colors::secondary_text())
         .font_size(14);
 
     // Date badges for zones with different dates
     if !composite.date_badges.is_empty() {
         let badge_y = layout.center_y - panel_h * 0.12;
         for (i, (zone_name, badge)) in composite.date_badges.iter().take(3).enumerate() {
-            let badge_text = format!("{}: {}", zone_name, badge);
+            let badge_text = format!("{}: {}", zone_name, tr(badge));
             draw.text(&badge_text)
                 .x_y(layout.center_x, badge_y - (i as f32 * 14.0))
-                .color(colors::DST_ACTIVE)
+                .color(colors::dst_active())
                 .font_size(10);
         }
         if composite.date_badges.len() > 3 {
-            draw.text(&format!("...and {} more", composite.date_badges.len() - 3))
+            draw.text(&tr_args("...and {0} more", &[&(composite.date_badges.len() - 3)]))
                 .x_y(layout.center_x, badge_y - 42.0)
-                .color(colors::ZONE_TEXT)
+                .color(colors::secondary_text())
                 .font_size(9);
         }
     }
 
     // Zone count
-    let zone_count_str = format!("{} zones superposed", display_order.len());
+    let zone_count_str = tr_args("{0} zones superposed", &[&display_order.len()]);
     draw.text(&zone_count_str)
         .x_y(layout.center_x, layout.center_y - panel_h * 0.28)
-        .color(colors::ZONE_TEXT)
+        .color(colors::secondary_text())
         .font_size(12);
 
     // DST warning if any zone has transition
     if composite.has_dst_warning {
         let pulse = (animation_time * 3.0).sin() * 0.5 + 0.5;
         let alpha = (180.0 + 75.0 * pulse) as u8;
-        draw.text("⚠ DST transition imminent in some zones")
+        draw.text(tr("⚠ DST transition imminent in some zones"))
             .x_y(layout.center_x, layout.center_y - panel_h * 0.35)
             .color(srgba(
-                colors::DST_WARNING.red,
-                colors::DST_WARNING.green,
-                colors::DST_WARNING.blue,
+                colors::dst_warning().red,
+                colors::dst_warning().green,
+                colors::dst_warning().blue,
                 alpha,
             ))
             .font_size(11);
     }
 
+    // Dials for zones whose minutes and seconds don't line up
+    if show_drift {
+        draw_alignment_drift(
+            draw,
+            layout.center_x,
+            layout.center_y + panel_h * 0.5 + 45.0,
+            display_order,
+            zone_times,
+            dominant_zone,
+            labels,
+        );
+    }
+
     // Compare mode: show all zones as small list
     if compare_mode {
         draw_composite_zone_list(
@@ -658,6 +857,7 @@ pub fn draw_composite_readout(
             display_order,
             zone_times,
             dominant_zone,
+            labels,
         );
     }
 }
@@ -677,6 +877,7 @@ fn compute_composite_data(
     display_order: &[Tz],
     zone_times: &HashMap<Tz, TimeData>,
     dominant_zone: Tz,
+    labels: &HashMap<Tz, ZoneLabel>,
 ) -> CompositeData {
     let dominant_data = zone_times.get(&dominant_zone);
 
@@ -690,15 +891,17 @@ fn compute_composite_data(
         return CompositeData {
             time_display: "--:--:--".to_string(),
             meridiem_display: "".to_string(),
-            date_display: "No data".to_string(),
+            date_display: tr("No data").to_string(),
             date_badges: Vec::new(),
             has_dst_warning: false,
         };
     }
 
-    // Use dominant zone's minute and second (they're aligned)
-    let minute = all_data[0].1.minute;
-    let second = all_data[0].1.second;
+    // Use dominant zone's minute and second; zones that differ show in the
+    // alignment drift dials
+    let anchor = dominant_data.unwrap_or(all_data[0].1);
+    let minute = anchor.minute;
+    let second = anchor.second;
 
     // Collect unique hours and meridiems
     let mut hours: Vec<u32> = all_data.iter().map(|(_, d)| d.hour12).collect();
@@ -734,9 +937,9 @@ fn compute_composite_data(
 
     let date_display = if dates_same {
         if let Some(d) = dominant_data {
-            format!("{} {}, {}", month_abbrev(d.month), d.day, d.year)
+            short_date(d.month, d.day, d.year)
         } else {
-            "Today".to_string()
+            tr("Today").to_string()
         }
     } else {
         // Compute date badges for zones with different dates
@@ -747,14 +950,7 @@ fn compute_composite_data(
                 }
                 let day_diff = compute_day_index(td, dom_data);
                 if day_diff != 0 {
-                    let short_name: String = tz
-                        .name()
-                        .split('/')
-                        .last()
-                        .unwrap_or(tz.name())
-                        .chars()
-                        .take(10)
-                        .collect();
+                    let short_name = short_zone_name(labels, *tz, 10);
                     let badge = match day_diff {
                         -1 => "Yesterday",
                         1 => "Tomorrow",
@@ -767,16 +963,16 @@ fn compute_composite_data(
 
         if date_badges.is_empty() {
             if let Some(d) = dominant_data {
-                format!("{} {}, {}", month_abbrev(d.month), d.day, d.year)
+                short_date(d.month, d.day, d.year)
             } else {
-                "Today".to_string()
+                tr("Today").to_string()
             }
         } else {
             // Show dominant date with indicator
             if let Some(d) = dominant_data {
-                format!("{} {}, {} (varies)", month_abbrev(d.month), d.day, d.year)
+                tr_args("{0} (varies)", &[&short_date(d.month, d.day, d.year)])
             } else {
-                "Multiple dates".to_string()
+                tr("Multiple dates").to_string()
             }
         }
     };
@@ -798,6 +994,105 @@ fn compute_composite_data(
     }
 }
 
+/// Radius of one dial in the alignment drift row
+const DRIFT_DIAL_RADIUS: f32 = 15.0;
+
+/// Dials in the alignment drift row before the rest are counted
+const MAX_DRIFT_DIALS: usize = 6;
+
+/// Offset from UTC in seconds, including any seconds of a historical offset
+fn offset_seconds(time_data: &TimeData) -> i32 {
+    time_data.local_datetime.offset().fix().local_minus_utc()
+}
+
+/// Draw a row of small dials, one per zone whose minute and second hands
+/// don't line up with the dominant zone's
+///
+/// The faint hand on each dial is the dominant zone's minute hand; the bright
+/// one is the zone's own, ahead by its drift.
+fn draw_alignment_drift(
+    draw: &Draw,
+    x: f32,
+    y: f32,
+    display_order: &[Tz],
+    zone_times: &HashMap<Tz, TimeData>,
+    dominant_zone: Tz,
+    labels: &HashMap<Tz, ZoneLabel>,
+) {
+    let Some(dominant) = zone_times.get(&dominant_zone) else {
+        return;
+    };
+    let dominant_offset = offset_seconds(dominant);
+    let drifts: Vec<(Tz, i32)> = display_order
+        .iter()
+        .filter(|&&tz| tz != dominant_zone)
+        .filter_map(|&tz| {
+            let time_data = zone_times.get(&tz)?;
+            Some((tz, alignment_drift(offset_seconds(time_data), dominant_offset)))
+        })
+        .filter(|&(_, drift)| drift != 0)
+        .collect();
+
+    if drifts.is_empty() {
+        draw.text(tr("Minutes and seconds aligned in every zone"))
+            .x_y(x, y)
+            .color(colors::ZONE_TEXT)
+            .font_size(10);
+        return;
+    }
+
+    let radius = DRIFT_DIAL_RADIUS;
+    let spacing = 90.0;
+    let shown = drifts.len().min(MAX_DRIFT_DIALS);
+    let start_x = x - spacing * (shown - 1) as f32 / 2.0;
+    // The dominant zone's minute hand, in turns from the top
+    let dominant_turns = (dominant.minute * 60 + dominant.second) as f32 / 3600.0;
+
+    for (i, &(tz, drift)) in drifts.iter().take(MAX_DRIFT_DIALS).enumerate() {
+        let center = pt2(start_x + spacing * i as f32, y);
+        let hand_tip = |turns: f32| {
+            let angle = turns * TAU;
+            center + vec2(angle.sin(), angle.cos()) * (radius - 3.0)
+        };
+
+        draw.ellipse()
+            .xy(center)
+            .radius(radius)
+            .color(colors::composite_bg())
+            .stroke(colors::CARD_BORDER)
+            .stroke_weight(1.0);
+        draw.line()
+            .start(center)
+            .end(hand_tip(dominant_turns))
+            .color(colors::ZONE_TEXT)
+            .weight(1.0);
+        let hand_color = zone_color(labels, tz)
+            .map(|[r, g, b]| srgb(r, g, b))
+            .unwrap_or(colors::TIME_TEXT);
+        draw.line()
+            .start(center)
+            .end(hand_tip(dominant_turns + drift as f32 / 3600.0))
+            .color(hand_color)
+            .weight(2.0);
+
+        draw.text(&short_zone_name(labels, tz, 10))
+            .xy(center - vec2(0.0, radius + 10.0))
+            .color(colors::secondary_text())
+            .font_size(9);
+        draw.text(&format_drift(drift))
+            .xy(center - vec2(0.0, radius + 22.0))
+            .color(colors::DELTA_POSITIVE)
+            .font_size(9);
+    }
+
+    if drifts.len() > MAX_DRIFT_DIALS {
+        draw.text(&tr_args("...and {0} more", &[&(drifts.len() - MAX_DRIFT_DIALS)]))
+            .x_y(x, y - radius - 36.0)
+            .color(colors::secondary_text())
+            .font_size(9);
+    }
+}
+
 /// Draw a compact list of zones for compare mode in composite view
 fn draw_composite_zone_list(
     draw: &Draw,
@@ -806,6 +1101,7 @@ fn draw_composite_zone_list(
     display_order: &[Tz],
     zone_times: &HashMap<Tz, TimeData>,
     dominant_zone: Tz,
+    labels: &HashMap<Tz, ZoneLabel>,
 ) {
     let dominant_data = zone_times.get(&dominant_zone);
     let item_height = 16.0;
@@ -823,15 +1119,14 @@ fn draw_composite_zone_list(
                 colors::ZONE_TEXT
             };
 
-            // Format short zone name
-            let short_name: String = tz
-                .name()
-                .split('/')
-                .last()
-                .unwrap_or(tz.name())
-                .chars()
-                .take(15)
-                .collect();
+            // Format short zone name, with its color tag
+            let short_name = short_zone_name(labels, tz, 15);
+            if let Some([r, g, b]) = zone_color(labels, tz) {
+                draw.ellipse()
+                    .x_y(x - 206.0, item_y)
+                    .radius(3.5)
+                    .color(srgb(r, g, b));
+            }
 
             draw.text(&short_name)
                 .x_y(x - 100.0, item_y)
@@ -843,7 +1138,7 @@ fn draw_composite_zone_list(
             let time_str = format!("{}:{:02} {}", time_data.hour12, time_data.minute, time_data.meridiem);
             draw.text(&time_str)
                 .x_y(x + 50.0, item_y)
-                .color(colors::SECONDARY_TEXT)
+                .color(colors::secondary_text())
                 .font_size(10);
 
             // Delta (if not dominant)
@@ -873,7 +1168,7 @@ fn draw_composite_zone_list(
     }
 
     if display_order.len() > max_display {
-        let more_str = format!("...and {} more", display_order.len() - max_display);
+        let more_str = tr_args("...and {0} more", &[&(display_order.len() - max_display)]);
         draw.text(&more_str)
             .x_y(x, y - (max_display as f32) * item_height)
             .color(colors::ZONE_TEXT)
@@ -881,23 +1176,108 @@ fn draw_composite_zone_list(
     }
 }
 
+/// Draw the meeting planner status line below the core area content
+pub fn draw_planner_banner(draw: &Draw, layout: &CoreLayout, text: &str, active: bool) {
+    let color = if active {
+        colors::IN_HOURS
+    } else {
+        colors::secondary_text()
+    };
+    draw.text(text)
+        .x_y(layout.center_x, layout.bottom + 24.0)
+        .w(layout.width - 40.0)
+        .color(color)
+        .font_size(12)
+        .center_justify();
+}
+
+/// Draw the preview banner shown while the deck is scrubbed away from now
+pub fn draw_scrub_banner(draw: &Draw, layout: &CoreLayout, text: &str) {
+    draw.text(text)
+        .x_y(layout.center_x, layout.top + 8.0)
+        .w(layout.width - 40.0)
+        .color(colors::scrub_active())
+        .font_size(12)
+        .center_justify();
+}
+
+/// Draw toast notifications stacked upward from above the planner banner
+pub fn draw_toasts(draw: &Draw, layout: &CoreLayout, toasts: &[crate::Toast]) {
+    let toast_width = (layout.width - 40.0).min(460.0);
+    let toast_height = 36.0;
+    let padding = 8.0;
+    let start_y = layout.bottom + 64.0;
+
+    for (i, toast) in toasts.iter().enumerate() {
+        let y = start_y + i as f32 * (toast_height + padding);
+        let alpha = toast.alpha();
+        let border = colors::dst_warning();
+        let text = colors::text_primary();
+
+        draw.rect()
+            .x_y(layout.center_x, y)
+            .w_h(toast_width, toast_height)
+            .color(srgba(30u8, 36u8, 44u8, (alpha * 230.0) as u8));
+        draw.rect()
+            .x_y(layout.center_x, y)
+            .w_h(toast_width, toast_height)
+            .no_fill()
+            .stroke(srgba(border.red, border.green, border.blue, (alpha * 255.0) as u8))
+            .stroke_weight(1.0);
+        draw.text(&toast.message)
+            .x_y(layout.center_x, y)
+            .w(toast_width - 20.0)
+            .color(srgba(text.red, text.green, text.blue, (alpha * 255.0) as u8))
+            .font_size(12)
+            .center_justify();
+    }
+}
+
+/// Height of one row in the list view
+const LIST_ITEM_HEIGHT: f32 = 50.0;
+
+/// Width of the list view rows
+fn list_item_width(layout: &CoreLayout) -> f32 {
+    layout.width.min(500.0)
+}
+
+/// Center Y of row `index` in a list of `count` rows
+fn list_item_y(layout: &CoreLayout, count: usize, index: usize) -> f32 {
+    let start_y = layout.center_y + ((count as f32 - 1.0) * LIST_ITEM_HEIGHT) / 2.0;
+    start_y - index as f32 * LIST_ITEM_HEIGHT
+}
+
+/// Index of the list view row under `pos`, if any
+pub fn list_row_at(layout: &CoreLayout, count: usize, pos: Point2) -> Option<usize> {
+    let half_width = list_item_width(layout) / 2.0;
+    if (pos.x - layout.center_x).abs() > half_width {
+        return None;
+    }
+    (0..count).find(|&i| (pos.y - list_item_y(layout, count, i)).abs() <= LIST_ITEM_HEIGHT / 2.0)
+}
+
 /// Draw the list view (accessibility mode)
+///
+/// `highlighted` outlines a row, marking where a dragged row will land.
+#[allow(clippy::too_many_arguments)]
 pub fn draw_list_view(
     draw: &Draw,
     layout: &CoreLayout,
     display_order: &[Tz],
     zone_times: &HashMap<Tz, TimeData>,
     dominant_zone: Tz,
+    home_zone: Option<Tz>,
     compare_mode: bool,
+    highlighted: Option<usize>,
+    labels: &HashMap<Tz, ZoneLabel>,
 ) {
-    let item_height = 50.0;
-    let item_width = layout.width.min(500.0);
-    let start_y = layout.center_y + ((display_order.len() as f32 - 1.0) * item_height) / 2.0;
+    let item_height = LIST_ITEM_HEIGHT;
+    let item_width = list_item_width(layout);
 
     let dominant_data = zone_times.get(&dominant_zone);
 
     for (i, &tz) in display_order.iter().enumerate() {
-        let item_y = start_y - (i as f32) * item_height;
+        let item_y = list_item_y(layout, display_order.len(), i);
         let is_dominant = tz == dominant_zone;
 
         if let Some(time_data) = zone_times.get(&tz) {
@@ -922,8 +1302,26 @@ pub fn draw_list_view(
                     .stroke_weight(1.5);
             }
 
-            // Zone name
-            draw.text(tz.name())
+            // Drop target while dragging a row
+            if highlighted == Some(i) {
+                draw.rect()
+                    .x_y(layout.center_x, item_y)
+                    .w_h(item_width, item_height - 4.0)
+                    .no_fill()
+                    .stroke(colors::scrub_active())
+                    .stroke_weight(2.0);
+            }
+
+            // Color tag
+            if let Some([r, g, b]) = zone_color(labels, tz) {
+                draw.rect()
+                    .x_y(layout.center_x - item_width / 2.0 + 3.0, item_y)
+                    .w_h(6.0, item_height - 4.0)
+                    .color(srgb(r, g, b));
+            }
+
+            // Zone name (or nickname)
+            draw.text(&zone_name(labels, tz))
                 .x_y(layout.center_x - item_width * 0.35, item_y + 8.0)
                 .color(if is_dominant {
                     colors::TIME_TEXT
@@ -946,7 +1344,7 @@ pub fn draw_list_view(
             // Offset
             draw.text(&time_data.format_utc_offset())
                 .x_y(layout.center_x + item_width * 0.35, item_y + 8.0)
-                .color(colors::SECONDARY_TEXT)
+                .color(colors::secondary_text())
                 .font_size(10)
                 .right_justify();
 
@@ -959,6 +1357,15 @@ pub fn draw_list_view(
                     .right_justify();
             }
 
+            // Home zone badge
+            if home_zone == Some(tz) {
+                draw.text(tr("HOME"))
+                    .x_y(layout.center_x - item_width * 0.35, item_y - 8.0)
+                    .color(colors::CARD_BORDER_DOMINANT)
+                    .font_size(9)
+                    .left_justify();
+            }
+
             // Compare mode delta
             if compare_mode && !is_dominant {
                 if let Some(dom_data) = dominant_data {
@@ -991,13 +1398,139 @@ pub fn draw_list_view(
             if has_dst_warning {
                 draw.text("⚠")
                     .x_y(layout.center_x - item_width * 0.45, item_y)
-                    .color(colors::DST_WARNING)
+                    .color(colors::dst_warning())
                     .font_size(14);
             }
         }
     }
 }
 
+/// Draw the name of the card or row being dragged next to the pointer
+pub fn draw_drag_label(draw: &Draw, name: &str, pos: Point2) {
+    let text = format!("↕ {}", name);
+    draw.rect()
+        .x_y(pos.x + 90.0, pos.y + 16.0)
+        .w_h(170.0, 22.0)
+        .color(colors::composite_bg());
+    draw.text(&text)
+        .x_y(pos.x + 90.0, pos.y + 16.0)
+        .w(160.0)
+        .color(colors::scrub_active())
+        .font_size(11);
+}
+
+/// Where along the timeline's axis (0..1) `pos` falls, if it's over a band
+pub fn timeline_fraction_at(strip: Rect, pos: Point2) -> Option<f32> {
+    let left = strip.left() + TIMELINE_NAME_WIDTH;
+    if !strip.contains(pos) || pos.x < left {
+        return None;
+    }
+    Some((pos.x - left) / (strip.right() - left))
+}
+
+/// Draw the timeline strip: a band of hour cells per zone against the
+/// dominant zone's day, DST changes, and a cursor at the instant the deck
+/// shows
+#[allow(clippy::too_many_arguments)]
+pub fn draw_timeline(
+    draw: &Draw,
+    strip: Rect,
+    axis: &TimelineAxis,
+    display_order: &[Tz],
+    dominant_zone: Tz,
+    display_instant: DateTime<Utc>,
+    live: bool,
+    labels: &HashMap<Tz, ZoneLabel>,
+) {
+    if display_order.is_empty() {
+        return;
+    }
+    let band_height = strip.h() / display_order.len() as f32;
+    let left = strip.left() + TIMELINE_NAME_WIDTH;
+    let cell_width = (strip.right() - left) / crate::timeline::HOURS as f32;
+    let font_size = (band_height * 0.55).clamp(6.0, 10.0) as u32;
+
+    for (i, &tz) in display_order.iter().enumerate() {
+        let y = strip.top() - (i as f32 + 0.5) * band_height;
+        let is_dominant = tz == dominant_zone;
+
+        draw.text(&short_zone_name(labels, tz, 12))
+            .x_y(strip.left() + TIMELINE_NAME_WIDTH / 2.0 - 4.0, y)
+            .w(TIMELINE_NAME_WIDTH - 8.0)
+            .color(if is_dominant {
+                colors::TIME_TEXT
+            } else {
+                colors::ZONE_TEXT
+            })
+            .font_size(font_size)
+            .left_justify();
+
+        for (hour, cell) in axis.cells(tz).iter().enumerate() {
+            let x = left + (hour as f32 + 0.5) * cell_width;
+            let fill = match cell.shade {
+                Shade::Day => colors::TIMELINE_DAY,
+                Shade::Fringe => colors::TIMELINE_FRINGE,
+                Shade::Night => colors::TIMELINE_NIGHT,
+            };
+            draw.rect()
+                .x_y(x, y)
+                .w_h(cell_width - 1.0, band_height - 2.0)
+                .color(fill);
+            // A new local day starts with its weekday and a rule
+            if cell.new_day {
+                draw.line()
+                    .start(pt2(x - cell_width / 2.0, y - band_height / 2.0))
+                    .end(pt2(x - cell_width / 2.0, y + band_height / 2.0))
+                    .color(colors::CARD_BORDER)
+                    .weight(1.0);
+            }
+            draw.text(&cell.label)
+                .x_y(x, y)
+                .w(cell_width)
+                .color(if cell.new_day {
+                    colors::CARD_BORDER_DOMINANT
+                } else {
+                    colors::secondary_text()
+                })
+                .font_size(font_size);
+        }
+
+        // Offset changes break the band where the local hours jump
+        for fraction in axis.offset_changes(tz) {
+            let x = left + fraction * (strip.right() - left);
+            draw.line()
+                .start(pt2(x, y - band_height / 2.0))
+                .end(pt2(x, y + band_height / 2.0))
+                .color(colors::dst_warning())
+                .weight(2.0);
+        }
+
+        if is_dominant {
+            draw.rect()
+                .x_y((left + strip.right()) / 2.0, y)
+                .w_h(strip.right() - left, band_height - 1.0)
+                .no_fill()
+                .stroke(colors::CARD_BORDER_DOMINANT)
+                .stroke_weight(1.0);
+        }
+    }
+
+    // The instant the deck shows
+    if let Some(fraction) = axis.fraction_of(display_instant) {
+        let x = left + fraction * (strip.right() - left);
+        let color = if live {
+            colors::text_primary()
+        } else {
+            colors::scrub_active()
+        };
+        draw.line()
+            .start(pt2(x, strip.bottom() - 2.0))
+            .end(pt2(x, strip.top() + 2.0))
+            .color(color)
+            .weight(2.0);
+    }
+}
+
 /// Helper: compute day index relative to dominant zone
 fn compute_day_index(zone_data: &TimeData, dominant_data: &TimeData) -> i32 {
     let zone_days = zone_data.year * 366 + zone_data.month as i32 * 31 + zone_data.day as i32;
@@ -1006,6 +1539,11 @@ fn compute_day_index(zone_data: &TimeData, dominant_data: &TimeData) -> i32 {
     (zone_days - dom_days).clamp(-1, 1)
 }
 
+/// "Jan 5, 2026", in the current language
+fn short_date(month: u32, day: u32, year: i32) -> String {
+    tr_args("{0} {1}, {2}", &[&tr(month_abbrev(month)), &day, &year])
+}
+
 /// Helper: get month abbreviation
 fn month_abbrev(month: u32) -> &'static str {
     match month {
@@ -0,0 +1,207 @@
+//! DST notifier - heads-up when any selected zone is about to change its clocks
+//!
+//! Opt-in: once enabled, every selected zone is checked against the real
+//! current time (never the scrubbed preview) for a DST transition within the
+//! lead time, or one in the last day. Each transition is announced once, by
+//! toast and optionally by a desktop notification.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use shared::{query_dst_transitions, tr_args};
+
+/// Shortest and longest lead time offered in the Collapse Controls panel
+pub const MIN_LEAD_HOURS: u32 = 1;
+pub const MAX_LEAD_HOURS: u32 = 72;
+
+/// How long after a transition it still counts as "just changed"
+const RECENT_HOURS: i64 = 24;
+
+/// Persisted notifier preferences
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DstNotifySettings {
+    /// Whether the notifier runs at all
+    pub enabled: bool,
+    /// Warn this many hours before a transition
+    pub lead_hours: u32,
+    /// Also raise a desktop notification (needs the `notifications` feature)
+    pub system_notifications: bool,
+}
+
+impl Default for DstNotifySettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            lead_hours: 24,
+            system_notifications: false,
+        }
+    }
+}
+
+/// A transition worth telling the user about
+#[derive(Debug, Clone, PartialEq)]
+pub struct DstAlert {
+    pub tz: Tz,
+    /// When the clocks change (UTC)
+    pub instant: DateTime<Utc>,
+    /// Offset change in minutes (positive = spring forward)
+    pub delta_minutes: i32,
+}
+
+impl DstAlert {
+    /// Whether the transition is still ahead of `now`
+    pub fn is_upcoming(&self, now: DateTime<Utc>) -> bool {
+        self.instant > now
+    }
+
+    /// One-line description, e.g. "Tokyo: clocks go forward 1h at 03:00 (in 5h 10m)"
+    pub fn message(&self, zone_name: &str, now: DateTime<Utc>) -> String {
+        let forward = self.delta_minutes > 0;
+        let amount = format_minutes(i64::from(self.delta_minutes.abs()));
+        let local = self.instant.with_timezone(&self.tz).format("%H:%M");
+        if self.is_upcoming(now) {
+            let until = format_minutes((self.instant - now).num_minutes().max(1));
+            let text = if forward {
+                "{0}: clocks go forward {1} at {2} (in {3})"
+            } else {
+                "{0}: clocks go back {1} at {2} (in {3})"
+            };
+            tr_args(text, &[&zone_name, &amount, &local, &until])
+        } else {
+            let text = if forward {
+                "{0}: clocks went forward {1} at {2}"
+            } else {
+                "{0}: clocks went back {1} at {2}"
+            };
+            tr_args(text, &[&zone_name, &amount, &local])
+        }
+    }
+}
+
+/// "1h", "30m" or "5h 10m"
+pub fn format_minutes(minutes: i64) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {:02}m", h, m),
+    }
+}
+
+/// Finds transitions near now and remembers which have been announced
+#[derive(Debug, Default)]
+pub struct DstNotifier {
+    /// Transitions already announced, per zone
+    announced: HashSet<(Tz, DateTime<Utc>)>,
+}
+
+impl DstNotifier {
+    /// Transitions in `zones` within `lead_hours` ahead of `now` or the last
+    /// day behind it that haven't been announced yet; marks them announced
+    pub fn check(&mut self, zones: &[Tz], lead_hours: u32, now: DateTime<Utc>) -> Vec<DstAlert> {
+        let lead = Duration::hours(i64::from(lead_hours));
+        let recent = Duration::hours(RECENT_HOURS);
+        // query_dst_transitions searches whole days either side of now
+        let range_days = (i64::from(lead_hours).max(RECENT_HOURS) + 23) / 24;
+
+        let mut alerts = Vec::new();
+        for &tz in zones {
+            for transition in query_dst_transitions(tz, now, range_days) {
+                let instant = transition.instant_utc;
+                let near = if instant > now {
+                    instant - now <= lead
+                } else {
+                    now - instant <= recent
+                };
+                if near && self.announced.insert((tz, instant)) {
+                    alerts.push(DstAlert {
+                        tz,
+                        instant,
+                        delta_minutes: transition.delta_minutes,
+                    });
+                }
+            }
+        }
+        alerts
+    }
+}
+
+#[cfg(feature = "notifications")]
+mod system {
+    use notify_rust::Notification;
+
+    /// Shown next to the desktop notification checkbox
+    pub const UNAVAILABLE_REASON: Option<&str> = None;
+
+    /// Raise a desktop notification; failures are logged and otherwise ignored
+    pub fn notify(message: &str) {
+        if let Err(e) = Notification::new()
+            .summary("Chrono-Superposition: DST change")
+            .body(message)
+            .show()
+        {
+            eprintln!("Failed to show desktop notification: {}", e);
+        }
+    }
+}
+
+#[cfg(not(feature = "notifications"))]
+mod system {
+    /// Shown next to the desktop notification checkbox
+    pub const UNAVAILABLE_REASON: Option<&str> =
+        Some("Built without desktop notifications (enable the `notifications` feature)");
+
+    pub fn notify(_message: &str) {}
+}
+
+pub use system::{notify as system_notify, UNAVAILABLE_REASON};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn new_york() -> Tz {
+        "America/New_York".parse().unwrap()
+    }
+
+    #[test]
+    fn test_upcoming_within_lead_only() {
+        // New York springs forward at 2025-03-09 07:00 UTC
+        let now = Utc.with_ymd_and_hms(2025, 3, 9, 2, 0, 0).unwrap();
+        let mut notifier = DstNotifier::default();
+        assert!(notifier.check(&[new_york()], 3, now).is_empty());
+
+        let alerts = notifier.check(&[new_york()], 6, now);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].instant, Utc.with_ymd_and_hms(2025, 3, 9, 7, 0, 0).unwrap());
+        assert_eq!(alerts[0].delta_minutes, 60);
+        assert_eq!(
+            alerts[0].message("New York", now),
+            "New York: clocks go forward 1h at 03:00 (in 5h)"
+        );
+    }
+
+    #[test]
+    fn test_each_transition_announced_once() {
+        let before = Utc.with_ymd_and_hms(2025, 3, 9, 6, 30, 0).unwrap();
+        let after = Utc.with_ymd_and_hms(2025, 3, 9, 8, 0, 0).unwrap();
+        let mut notifier = DstNotifier::default();
+        assert_eq!(notifier.check(&[new_york()], 1, before).len(), 1);
+        assert!(notifier.check(&[new_york()], 1, before).is_empty());
+        assert!(notifier.check(&[new_york()], 1, after).is_empty());
+    }
+
+    #[test]
+    fn test_just_transitioned() {
+        // Fall back at 2025-11-02 06:00 UTC, noticed two hours later
+        let now = Utc.with_ymd_and_hms(2025, 11, 2, 8, 0, 0).unwrap();
+        let london: Tz = "Europe/London".parse().unwrap();
+        let mut notifier = DstNotifier::default();
+        let alerts = notifier.check(&[new_york(), london], 1, now);
+        assert_eq!(alerts.len(), 1);
+        assert!(!alerts[0].is_upcoming(now));
+        assert_eq!(alerts[0].message("NYC", now), "NYC: clocks went back 1h at 01:00");
+    }
+}
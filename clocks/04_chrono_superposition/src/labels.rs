@@ -0,0 +1,100 @@
+//! Labels module - per-zone nicknames and color tags
+//!
+//! A zone can be renamed ("HQ" instead of "America/Los_Angeles") and tagged
+//! with one of a fixed set of swatches. Cards, the composite readout and the
+//! list view show the nickname wherever they would show the zone id.
+
+use std::collections::HashMap;
+
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// Color tags offered in the Zone Field panel
+pub const SWATCHES: [[u8; 3]; 8] = [
+    [239, 83, 80],
+    [255, 167, 38],
+    [255, 213, 79],
+    [102, 187, 106],
+    [38, 198, 218],
+    [66, 165, 245],
+    [171, 71, 188],
+    [236, 64, 122],
+];
+
+/// Nickname and color tag for one zone; empty fields fall back to defaults
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZoneLabel {
+    #[serde(default)]
+    pub nickname: String,
+    #[serde(default)]
+    pub color: Option<[u8; 3]>,
+}
+
+impl ZoneLabel {
+    /// Whether the label changes nothing and needn't be stored
+    pub fn is_empty(&self) -> bool {
+        self.nickname.trim().is_empty() && self.color.is_none()
+    }
+}
+
+/// Look up a zone's label, falling back to no nickname and no color
+pub fn label_for(labels: &HashMap<Tz, ZoneLabel>, tz: Tz) -> ZoneLabel {
+    labels.get(&tz).cloned().unwrap_or_default()
+}
+
+/// The nickname if set, otherwise the full zone id
+pub fn zone_name(labels: &HashMap<Tz, ZoneLabel>, tz: Tz) -> String {
+    match labels.get(&tz).map(|label| label.nickname.trim()) {
+        Some(nickname) if !nickname.is_empty() => nickname.to_string(),
+        _ => tz.name().to_string(),
+    }
+}
+
+/// The nickname if set, otherwise the city part of the zone id, cut to
+/// `max_chars`
+pub fn short_zone_name(labels: &HashMap<Tz, ZoneLabel>, tz: Tz, max_chars: usize) -> String {
+    let name = match labels.get(&tz).map(|label| label.nickname.trim()) {
+        Some(nickname) if !nickname.is_empty() => nickname,
+        _ => tz.name().split('/').next_back().unwrap_or(tz.name()),
+    };
+    name.chars().take(max_chars).collect()
+}
+
+/// The zone's color tag, if any
+pub fn zone_color(labels: &HashMap<Tz, ZoneLabel>, tz: Tz) -> Option<[u8; 3]> {
+    labels.get(&tz).and_then(|label| label.color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names_fall_back_to_zone_id() {
+        let la: Tz = "America/Los_Angeles".parse().unwrap();
+        let tokyo: Tz = "Asia/Tokyo".parse().unwrap();
+        let mut labels = HashMap::new();
+        labels.insert(
+            la,
+            ZoneLabel {
+                nickname: " HQ ".to_string(),
+                color: Some(SWATCHES[0]),
+            },
+        );
+        labels.insert(tokyo, ZoneLabel::default());
+
+        assert_eq!(zone_name(&labels, la), "HQ");
+        assert_eq!(short_zone_name(&labels, la, 10), "HQ");
+        assert_eq!(zone_color(&labels, la), Some(SWATCHES[0]));
+        assert_eq!(zone_name(&labels, tokyo), "Asia/Tokyo");
+        assert_eq!(short_zone_name(&labels, tokyo, 3), "Tok");
+        assert_eq!(zone_color(&labels, tokyo), None);
+    }
+
+    #[test]
+    fn test_empty_label() {
+        assert!(ZoneLabel::default().is_empty());
+        assert!(ZoneLabel { nickname: "  ".to_string(), color: None }.is_empty());
+        assert!(!ZoneLabel { nickname: String::new(), color: Some(SWATCHES[3]) }.is_empty());
+    }
+}
@@ -0,0 +1,2118 @@
+//! Chrono-Superposition Clock
+//!
+//! A clock that treats time zones as simultaneous realities. Multiple time zones
+//! are shown at once in a superposed "deck," which collapses into a composite
+//! readout when focused. A meeting planner mode highlights the windows where
+//! every selected zone is within business hours, and a time offset scrubber
+//! previews the whole deck at another instant. An opt-in notifier warns
+//! when any selected zone is about to change its clocks for DST. A 24-hour
+//! timeline strip under the deck lines the zones' hours up, meeting-planner
+//! style, and scrubs to the time clicked. Cards can optionally flip over to
+//! a zone's details instead of taking dominance when clicked.
+
+mod cards;
+mod details;
+mod drawing;
+mod dst_notify;
+mod labels;
+mod planner;
+mod scrub;
+mod table;
+mod timeline;
+mod ui;
+mod zone_sets;
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use nannou::event::ModifiersState;
+use nannou::prelude::*;
+use nannou_egui::{self, egui, Egui};
+use serde::{Deserialize, Serialize};
+use shared::{
+    compute_time_data, compute_time_data_at, report_save, tr, tr_args, ClipRecorder, ClipSettings,
+    ClipStep, CommandPalette, CommandRegistry, ConfigRecovery, ConfigSaver, Favorites, FocusRegions,
+    FollowSystemZone, FramePacer, KeyChord, Keymap, LanguageSetting, MotionPreference, QuietHours,
+    RecoveryChoice, SafePaletteSetting, ScreenReader, SnapshotProgress, SnapshotStep,
+    SystemMotionWatcher, SystemZoneWatcher, ThemeLibrary, TickGranularity, Ticker, TimeData,
+    TimeReveal, VisualIntensity, WindowPlacement, WindowTitle, WindowTitleClock,
+};
+
+use crate::cards::{grid_geometries, move_card, CardGeometry, OrderingStrategy};
+use crate::details::ZoneDetails;
+use crate::drawing::{
+    colors, draw_card_deck, draw_composite_readout, draw_drag_label, draw_list_view,
+    draw_planner_banner, draw_scrub_banner, draw_timeline, draw_toasts, list_row_at,
+    timeline_fraction_at, CoreLayout,
+};
+use crate::dst_notify::{system_notify, DstNotifier, DstNotifySettings};
+use crate::labels::{zone_name, ZoneLabel};
+use crate::planner::{
+    find_overlap_windows, BusinessHours, OverlapStatus, OverlapWindow, SEARCH_HORIZON_HOURS,
+};
+use crate::scrub::{clamp_offset, format_offset, virtual_instant};
+use crate::timeline::TimelineAxis;
+use crate::table::{comparison_rows, ComparisonTable, TableFormat};
+use crate::ui::{
+    draw_card_back, draw_collapse_controls, draw_meeting_planner, draw_zone_field, theme_visuals,
+    CardBackResult, CollapseControlsResult, MeetingPlannerResult, PickerState, ZoneFieldResult,
+};
+use crate::zone_sets::{upsert, ZoneSet, ZoneSetUndo};
+
+const CLOCK_NAME: &str = "chrono_superposition";
+const DEFAULT_TZ: &str = "America/Los_Angeles";
+const WINDOW_TITLE: &str = "Chrono-Superposition";
+const LEFT_PANEL_WIDTH: f32 = 240.0;
+const RIGHT_PANEL_WIDTH: f32 = 200.0;
+/// Pointer travel (pixels) before a press on a card becomes a drag
+const DRAG_THRESHOLD: f32 = 6.0;
+/// How long a card takes to turn over
+const FLIP_SECS: f32 = 0.35;
+
+/// Run the clock; returns only when the window is closed
+pub fn run() {
+    nannou::app(model).update(update).run();
+}
+
+/// Launcher entry for this clock
+pub struct ChronoSuperposition;
+
+impl shared::ClockApp for ChronoSuperposition {
+    fn id(&self) -> &'static str {
+        CLOCK_NAME
+    }
+
+    fn title(&self) -> &'static str {
+        "Chrono-Superposition"
+    }
+
+    fn tagline(&self) -> &'static str {
+        "Time zones as simultaneous realities in a collapsible deck"
+    }
+
+    fn run(&self) {
+        run();
+    }
+}
+
+/// View state machine
+#[derive(Debug, Clone, PartialEq)]
+pub enum ViewState {
+    /// Cards spread in deck formation
+    DeckView,
+    /// Cards collapsed into composite readout (focus_strength >= 0.8)
+    CompositeView,
+    /// Cards tiled in rows and columns sized to the window
+    GridView,
+    /// Accessible list view
+    ListView,
+    /// Timezone picker is open
+    PickerOpen,
+}
+
+/// Focus region for keyboard navigation
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FocusRegion {
+    /// Zone Field panel (left)
+    ZoneField,
+    /// Core deck area (center)
+    #[default]
+    CoreDeck,
+    /// Collapse Controls panel (right)
+    CollapseControls,
+}
+
+impl FocusRegions for FocusRegion {
+    const ALL: &'static [Self] = &[
+        FocusRegion::ZoneField,
+        FocusRegion::CoreDeck,
+        FocusRegion::CollapseControls,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            FocusRegion::ZoneField => "Zone field",
+            FocusRegion::CoreDeck => "Core deck",
+            FocusRegion::CollapseControls => "Collapse controls",
+        }
+    }
+}
+
+/// Toast notification for transient messages
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub created: Instant,
+    pub duration_secs: f32,
+}
+
+impl Toast {
+    pub fn new(message: String, duration_secs: f32) -> Self {
+        Self {
+            message,
+            created: Instant::now(),
+            duration_secs,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.created.elapsed().as_secs_f32() > self.duration_secs
+    }
+
+    pub fn alpha(&self) -> f32 {
+        let elapsed = self.created.elapsed().as_secs_f32();
+        if elapsed > self.duration_secs - 0.5 {
+            // Fade out in last 0.5s
+            ((self.duration_secs - elapsed) / 0.5).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Persisted configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Config {
+    selected_zone_ids: Vec<String>,
+    dominant_zone_id: String,
+    /// Zone detected from the OS as this machine's home
+    #[serde(default)]
+    home_zone_id: Option<String>,
+    favorites: Vec<String>,
+    /// Keep `favorites` instead of following the series-wide list
+    #[serde(default)]
+    own_favorites: bool,
+    focus_strength: f32,
+    compare_mode: bool,
+    /// Dials for minute and second misalignment in the composite view
+    #[serde(default)]
+    show_drift: bool,
+    list_mode: bool,
+    list_mode_override: bool,
+    /// Grid layout instead of the stacked deck
+    #[serde(default)]
+    grid_mode: bool,
+    /// 24-hour timeline strip under the deck
+    #[serde(default)]
+    show_timeline: bool,
+    reduced_motion: MotionPreference,
+    /// Meeting planner ("find overlap") mode
+    #[serde(default)]
+    planner_mode: bool,
+    /// Business hours per zone id (zones without an entry use 9-5)
+    #[serde(default)]
+    business_hours: HashMap<String, BusinessHours>,
+    /// Selected theme name; `None` uses the clock's own colors
+    #[serde(default)]
+    theme: Option<String>,
+    /// Dragged card order as zone ids; `None` orders cards automatically
+    #[serde(default)]
+    manual_order: Option<Vec<String>>,
+    /// Nickname and color tag per zone id
+    #[serde(default)]
+    zone_labels: HashMap<String, ZoneLabel>,
+    /// DST change notifier preferences
+    #[serde(default)]
+    dst_notify: DstNotifySettings,
+    /// Format of the exported comparison table
+    #[serde(default)]
+    table_format: TableFormat,
+    /// Named zone sets to switch between
+    #[serde(default)]
+    zone_sets: Vec<ZoneSet>,
+    /// Clicking a card that isn't dominant flips it to its details
+    #[serde(default)]
+    flip_cards: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            selected_zone_ids: vec![
+                "America/Los_Angeles".to_string(),
+                "America/New_York".to_string(),
+                "Europe/London".to_string(),
+                "Asia/Tokyo".to_string(),
+            ],
+            dominant_zone_id: DEFAULT_TZ.to_string(),
+            home_zone_id: None,
+            favorites: vec![
+                "America/New_York".to_string(),
+                "America/Los_Angeles".to_string(),
+                "Europe/London".to_string(),
+                "Asia/Tokyo".to_string(),
+            ],
+            own_favorites: false,
+            focus_strength: 0.0,
+            compare_mode: false,
+            show_drift: false,
+            list_mode: false,
+            list_mode_override: false,
+            grid_mode: false,
+            show_timeline: false,
+            reduced_motion: MotionPreference::Auto,
+            planner_mode: false,
+            business_hours: HashMap::new(),
+            theme: None,
+            manual_order: None,
+            zone_labels: HashMap::new(),
+            dst_notify: DstNotifySettings::default(),
+            table_format: TableFormat::default(),
+            zone_sets: Vec::new(),
+            flip_cards: false,
+        }
+    }
+}
+
+impl shared::VersionedConfig for Config {
+    const VERSION: u32 = 2;
+
+    /// Version 2 names the reduced-motion choice; earlier files hold a bool
+    fn migrate(_from_version: u32, payload: &mut toml::Table) {
+        shared::migrate_reduced_motion(payload);
+    }
+}
+
+/// A press on a card or list row that may turn into a drag
+#[derive(Debug, Clone, Copy)]
+struct CardDrag {
+    /// Display index of the pressed card
+    from: usize,
+    /// Where the press started
+    start: Point2,
+    /// Whether the pointer has moved far enough to count as a drag
+    moved: bool,
+}
+
+/// A card turned over to show its zone's details
+struct FlippedCard {
+    details: ZoneDetails,
+    /// When the card began turning
+    started: Instant,
+}
+
+/// Application state
+pub struct Model {
+    /// Selected time zones (1..N)
+    pub selected_zones: Vec<Tz>,
+    /// The dominant (top) zone
+    pub dominant_zone: Tz,
+    /// The machine's own zone, badged "home" on its card
+    pub home_zone: Option<Tz>,
+    /// Favorite time zones
+    pub favorites: Favorites,
+    /// Cached time data per zone
+    pub zone_times: HashMap<Tz, TimeData>,
+    /// Display order (computed each frame)
+    pub display_order: Vec<Tz>,
+    /// Whether cards are ordered automatically or as the user dragged them
+    pub ordering: OrderingStrategy,
+
+    /// Focus strength slider (0.0 = spread, 1.0 = collapsed)
+    pub focus_strength: f32,
+    /// Whether compare mode is active
+    pub compare_mode: bool,
+    /// Whether the composite view shows minute and second misalignment
+    pub show_drift: bool,
+    /// Whether list mode is active (accessibility)
+    pub list_mode: bool,
+    /// Whether list mode was manually overridden
+    pub list_mode_override: bool,
+    /// Whether cards are tiled in a grid instead of stacked
+    pub grid_mode: bool,
+    /// Whether the timeline strip is shown under the deck
+    pub show_timeline: bool,
+
+    /// Current view state
+    pub view_state: ViewState,
+
+    /// Whether meeting planner mode is active
+    pub planner_mode: bool,
+    /// Business hours per zone
+    pub business_hours: HashMap<Tz, BusinessHours>,
+    /// Upcoming windows where all selected zones are in business hours
+    pub overlap_windows: Vec<OverlapWindow>,
+
+    /// Nickname and color tag per zone
+    pub zone_labels: HashMap<Tz, ZoneLabel>,
+    /// Zone whose label is being edited in the Zone Field panel
+    pub editing_label: Option<Tz>,
+    /// Whether an egui text field has keyboard focus
+    pub text_input_focused: bool,
+
+    /// Named zone sets to switch between
+    pub zone_sets: Vec<ZoneSet>,
+    /// Name typed for the next saved zone set
+    pub zone_set_name: String,
+    /// Selection replaced by the last zone set switch, while it can be undone
+    zone_set_undo: Option<ZoneSetUndo>,
+
+    /// DST change notifier preferences
+    pub dst_notify: DstNotifySettings,
+    /// Transitions already announced
+    dst_notifier: DstNotifier,
+    /// Transient messages shown at the bottom of the deck
+    pub toasts: Vec<Toast>,
+
+    /// Scrub offset from now in minutes (0 = live)
+    pub time_offset_minutes: i64,
+    /// Format of the exported comparison table
+    pub table_format: TableFormat,
+
+    /// Mouse position for parallax
+    pub mouse_position: Option<Point2>,
+    /// Window center for parallax calculation
+    pub window_center: Point2,
+    /// Index of hovered card (if any)
+    pub hovered_card_index: Option<usize>,
+    /// Card or row currently pressed/dragged
+    card_drag: Option<CardDrag>,
+    /// Whether clicking a card that isn't dominant flips it instead of
+    /// promoting it
+    pub flip_cards: bool,
+    /// Card turned over to its details, if any
+    flipped_card: Option<FlippedCard>,
+    /// Whether the pointer is over an egui area, which takes its clicks
+    pointer_over_ui: bool,
+
+    /// Timezone picker state
+    pub picker_state: PickerState,
+    /// Ctrl+K command palette
+    command_palette: CommandPalette,
+    /// Actions listed in the command palette, and the chords they're bound to
+    keymap: Keymap,
+    /// Reduced motion setting: follow the system, or override it
+    pub motion_preference: MotionPreference,
+    /// Reports the system's reduce-motion preference
+    system_motion: SystemMotionWatcher,
+    /// Whether motion is reduced, from the setting and the system
+    pub reduced_motion: bool,
+    /// Shared quiet-hours schedule
+    pub quiet_hours: QuietHours,
+    /// Brightness and effects allowed right now by the schedule
+    pub intensity: VisualIntensity,
+    /// Shared setting for the time in the window title
+    pub window_title_clock: WindowTitleClock,
+    /// Shared settings for clips recorded with Ctrl+Shift+R
+    pub clip_settings: ClipSettings,
+    /// Clip being recorded or saved, if any
+    pub clip_recorder: ClipRecorder,
+    /// Shared language for labels, dates and toasts
+    pub language: LanguageSetting,
+    /// Shared high-contrast or color-blind safe palette
+    pub safe_palette: SafePaletteSetting,
+    /// Keeps the window title's time current
+    window_title: WindowTitle,
+    /// Remembers the window's size, position and monitor
+    window_placement: WindowPlacement,
+    /// Shared setting for switching to the system zone when it changes
+    follow_system_zone: FollowSystemZone,
+    /// Reports changes to the computer's time zone
+    system_zone: SystemZoneWatcher,
+    /// Shared system-wide shortcut that shows the time from the background
+    time_reveal: TimeReveal,
+    /// Drops the frame rate while the clock sits idle
+    frame_pacer: FramePacer,
+    /// Writes config changes once they settle
+    config_saver: ConfigSaver,
+    /// Shown when the saved config couldn't be read
+    config_recovery: Option<ConfigRecovery>,
+    /// Built-in and user themes
+    pub themes: ThemeLibrary,
+    /// Active theme name; `None` uses the clock's own colors
+    pub theme: Option<String>,
+    /// Animation time for pulsing effects
+    pub animation_time: f32,
+
+    /// Current focus region for keyboard navigation
+    pub focus_region: FocusRegion,
+
+    /// Wall-clock ticker driving zone time recomputation
+    ticker: Ticker,
+    /// Frame counter for `--snapshot` runs
+    snapshot: SnapshotProgress,
+
+    /// Screen reader view of the time, DST status and focus
+    screen_reader: ScreenReader,
+
+    /// egui integration
+    egui: Egui,
+}
+
+impl Model {
+    /// Whether something on screen is moving on its own: a toast fading, a
+    /// card turning over or a clip being captured
+    fn is_animating(&self) -> bool {
+        !self.toasts.is_empty()
+            || self.card_flip(Instant::now()).is_some_and(|(_, turned)| turned < 1.0)
+            || self.clip_recorder.is_recording()
+            || self.time_reveal.is_flashing()
+    }
+
+    /// Set a new dominant zone
+    pub fn set_dominant(&mut self, tz: Tz) {
+        if self.selected_zones.contains(&tz) {
+            self.dominant_zone = tz;
+            if self.flipped_zone() == Some(tz) {
+                self.flipped_card = None;
+            }
+            self.update_display_order();
+            save_config(self);
+        }
+    }
+
+    /// Add a zone to selected zones
+    pub fn add_zone(&mut self, tz: Tz) {
+        if !self.selected_zones.contains(&tz) {
+            self.selected_zones.push(tz);
+            self.zone_times.insert(tz, compute_time_data_at(tz, self.display_instant()));
+            self.update_display_order();
+            self.check_list_mode_threshold();
+            self.update_overlap_windows();
+            self.check_dst_transitions();
+            save_config(self);
+        }
+    }
+
+    /// A click or Enter on a card
+    ///
+    /// With card flipping on, a deck or grid card that isn't dominant turns
+    /// over to its details, or back again; otherwise the card becomes
+    /// dominant.
+    pub fn press_card(&mut self, tz: Tz) {
+        let flippable = self.flip_cards
+            && tz != self.dominant_zone
+            && matches!(self.view_state, ViewState::DeckView | ViewState::GridView);
+        if !flippable {
+            self.set_dominant(tz);
+        } else if self.flipped_zone() == Some(tz) {
+            self.flipped_card = None;
+        } else {
+            self.flipped_card = Some(FlippedCard {
+                details: ZoneDetails::at(tz, self.display_instant()),
+                started: Instant::now(),
+            });
+        }
+    }
+
+    /// Zone of the card turned over to its details
+    fn flipped_zone(&self) -> Option<Tz> {
+        self.flipped_card.as_ref().map(|card| card.details.tz)
+    }
+
+    /// The flipped card's zone and how far it has turned (0..1); reduced
+    /// motion turns it at once
+    fn card_flip(&self, now: Instant) -> Option<(Tz, f32)> {
+        self.flipped_card.as_ref().map(|card| {
+            let turned = if self.motion_reduced() {
+                1.0
+            } else {
+                now.saturating_duration_since(card.started).as_secs_f32() / FLIP_SECS
+            };
+            (card.details.tz, turned.min(1.0))
+        })
+    }
+
+    /// Add a zone if needed and make it dominant
+    pub fn follow_zone(&mut self, tz: Tz) {
+        self.add_zone(tz);
+        self.set_dominant(tz);
+    }
+
+    /// Detect the OS time zone and make it the dominant home zone
+    pub fn use_system_zone(&mut self) {
+        match shared::system_timezone() {
+            Some(tz) => {
+                self.home_zone = Some(tz);
+                self.follow_zone(tz);
+                self.show_toast(tr_args("Home zone: {0}", &[&zone_name(&self.zone_labels, tz)]));
+            }
+            None => self.show_toast(tr("Could not detect the system time zone").to_string()),
+        }
+        save_config(self);
+    }
+
+    /// Remove a zone from selected zones
+    pub fn remove_zone(&mut self, tz: Tz) {
+        if self.selected_zones.len() > 1 {
+            self.selected_zones.retain(|&z| z != tz);
+            self.zone_times.remove(&tz);
+            if self.flipped_zone() == Some(tz) {
+                self.flipped_card = None;
+            }
+            // If we removed the dominant zone, pick a new one
+            if self.dominant_zone == tz {
+                self.dominant_zone = self.selected_zones[0];
+            }
+            self.update_display_order();
+            self.check_list_mode_threshold();
+            self.update_overlap_windows();
+            save_config(self);
+        }
+    }
+
+    /// Toggle favorite status for a zone
+    pub fn toggle_favorite(&mut self, tz: Tz) {
+        self.favorites.toggle(tz);
+        self.update_display_order();
+        save_config(self);
+    }
+
+    /// Cycle dominance up/down in display order
+    pub fn cycle_dominance(&mut self, delta: i32) {
+        if self.display_order.len() <= 1 {
+            return;
+        }
+        let current_idx = self
+            .display_order
+            .iter()
+            .position(|&z| z == self.dominant_zone)
+            .unwrap_or(0);
+        let new_idx = (current_idx as i32 + delta)
+            .rem_euclid(self.display_order.len() as i32) as usize;
+        self.dominant_zone = self.display_order[new_idx];
+        self.update_display_order();
+        save_config(self);
+    }
+
+    /// Toggle compare mode
+    pub fn toggle_compare_mode(&mut self) {
+        self.compare_mode = !self.compare_mode;
+        save_config(self);
+    }
+
+    /// Show or hide the timeline strip
+    pub fn toggle_timeline(&mut self) {
+        self.show_timeline = !self.show_timeline;
+        save_config(self);
+    }
+
+    /// Toggle meeting planner mode
+    pub fn toggle_planner_mode(&mut self) {
+        self.planner_mode = !self.planner_mode;
+        self.update_overlap_windows();
+        save_config(self);
+    }
+
+    /// Set business hours for a zone
+    pub fn set_business_hours(&mut self, tz: Tz, hours: BusinessHours) {
+        self.business_hours.insert(tz, hours);
+        self.update_overlap_windows();
+        save_config(self);
+    }
+
+    /// Set a zone's nickname and color tag; an empty label clears it
+    pub fn set_zone_label(&mut self, tz: Tz, label: ZoneLabel) {
+        if label.is_empty() {
+            self.zone_labels.remove(&tz);
+        } else {
+            self.zone_labels.insert(tz, label);
+        }
+        save_config(self);
+    }
+
+    /// Save the current zones and dominant zone as a named set, replacing
+    /// any set with the same name
+    pub fn save_zone_set(&mut self, name: &str) {
+        let set = ZoneSet::capture(name, &self.selected_zones, self.dominant_zone);
+        if set.name.is_empty() {
+            return;
+        }
+        self.show_toast(tr_args("Saved zone set “{0}”", &[&set.name]));
+        upsert(&mut self.zone_sets, set);
+        self.zone_set_name.clear();
+        save_config(self);
+    }
+
+    /// Forget the zone set at `index`
+    pub fn delete_zone_set(&mut self, index: usize) {
+        if index < self.zone_sets.len() {
+            self.zone_sets.remove(index);
+            save_config(self);
+        }
+    }
+
+    /// Replace the selected zones and dominant zone with the set at `index`
+    ///
+    /// The replaced selection can be restored with Ctrl+Z for a few seconds.
+    pub fn switch_zone_set(&mut self, index: usize) {
+        let Some(set) = self.zone_sets.get(index) else {
+            return;
+        };
+        let name = set.name.clone();
+        let Some((zones, dominant)) = set.resolve() else {
+            self.show_toast(tr_args("Zone set “{0}” has no valid zones", &[&name]));
+            return;
+        };
+        self.zone_set_undo = Some(ZoneSetUndo::new(
+            self.selected_zones.clone(),
+            self.dominant_zone,
+            self.ordering.clone(),
+            Instant::now(),
+        ));
+        self.replace_zones(zones, dominant, OrderingStrategy::Automatic);
+        self.show_toast(tr_args("Switched to “{0}” — Ctrl+Z to undo", &[&name]));
+    }
+
+    /// Restore the selection replaced by the last zone set switch
+    pub fn undo_zone_set_switch(&mut self) {
+        match self.zone_set_undo.take() {
+            Some(undo) if !undo.is_expired(Instant::now()) => {
+                self.replace_zones(undo.zones, undo.dominant, undo.ordering);
+                self.show_toast(tr("Restored previous zones").to_string());
+            }
+            _ => {}
+        }
+    }
+
+    /// Whether the last zone set switch can still be undone
+    pub fn can_undo_zone_set_switch(&self) -> bool {
+        self.zone_set_undo
+            .as_ref()
+            .is_some_and(|undo| !undo.is_expired(Instant::now()))
+    }
+
+    /// Swap in a whole selection at once
+    fn replace_zones(&mut self, zones: Vec<Tz>, dominant: Tz, ordering: OrderingStrategy) {
+        self.selected_zones = zones;
+        self.dominant_zone = dominant;
+        self.ordering = ordering;
+        self.editing_label = None;
+        self.flipped_card = None;
+        self.update_zone_times();
+        self.update_display_order();
+        self.check_list_mode_threshold();
+        self.update_overlap_windows();
+        self.check_dst_transitions();
+        save_config(self);
+    }
+
+    /// Show a toast notification
+    pub fn show_toast(&mut self, message: String) {
+        self.toasts.push(Toast::new(message, 8.0));
+    }
+
+    /// Prune expired toasts
+    pub fn prune_toasts(&mut self) {
+        self.toasts.retain(|t| !t.is_expired());
+    }
+
+    /// Announce DST transitions near the real current time in any selected zone
+    fn check_dst_transitions(&mut self) {
+        if !self.dst_notify.enabled {
+            return;
+        }
+        let now = shared::now_utc();
+        let alerts = self
+            .dst_notifier
+            .check(&self.selected_zones, self.dst_notify.lead_hours, now);
+        for alert in alerts {
+            let message = alert.message(&zone_name(&self.zone_labels, alert.tz), now);
+            if self.dst_notify.system_notifications {
+                system_notify(&message);
+            }
+            self.show_toast(message);
+        }
+    }
+
+    /// Apply notifier preferences edited in the Collapse Controls panel
+    pub fn set_dst_notify(&mut self, settings: DstNotifySettings) {
+        self.dst_notify = settings;
+        self.check_dst_transitions();
+        save_config(self);
+    }
+
+    /// Recompute overlap windows from the displayed instant over the search horizon
+    fn update_overlap_windows(&mut self) {
+        self.overlap_windows = if self.planner_mode {
+            find_overlap_windows(
+                &self.selected_zones,
+                &self.business_hours,
+                self.display_instant(),
+                SEARCH_HORIZON_HOURS,
+            )
+        } else {
+            Vec::new()
+        };
+    }
+
+    /// Current overlap status (None when planner mode is off)
+    pub fn overlap_status(&self) -> Option<OverlapStatus> {
+        self.planner_mode
+            .then(|| OverlapStatus::at(&self.overlap_windows, self.display_instant()))
+    }
+
+    /// The instant the deck shows: now, shifted by the scrub offset
+    pub fn display_instant(&self) -> DateTime<Utc> {
+        virtual_instant(shared::now_utc(), self.time_offset_minutes)
+    }
+
+    /// Whether parallax and pulses are off, by preference or quiet hours
+    pub fn motion_reduced(&self) -> bool {
+        self.reduced_motion || !self.intensity.animations
+    }
+
+    /// Apply a reduced-motion setting against the current system preference
+    pub fn set_motion_preference(&mut self, preference: MotionPreference) {
+        self.motion_preference = preference;
+        self.reduced_motion = preference.resolve(self.system_motion.reduced());
+    }
+
+    /// Whether the deck shows the current time
+    pub fn is_live(&self) -> bool {
+        self.time_offset_minutes == 0
+    }
+
+    /// Set the scrub offset and recompute every zone at the new instant
+    pub fn set_time_offset(&mut self, offset_minutes: i64) {
+        self.time_offset_minutes = clamp_offset(offset_minutes);
+        self.update_zone_times();
+        self.update_overlap_windows();
+        // A flipped card's details follow the instant shown
+        let instant = self.display_instant();
+        if let Some(card) = &mut self.flipped_card {
+            card.details = ZoneDetails::at(card.details.tz, instant);
+        }
+    }
+
+    /// Step the scrub offset forward or backward
+    pub fn step_time_offset(&mut self, delta_minutes: i64) {
+        self.set_time_offset(self.time_offset_minutes + delta_minutes);
+    }
+
+    /// Scrub the deck to an instant, to the nearest minute
+    pub fn scrub_to(&mut self, instant: DateTime<Utc>) {
+        let seconds = (instant - shared::now_utc()).num_seconds();
+        self.set_time_offset((seconds as f64 / 60.0).round() as i64);
+    }
+
+    /// The deck's area and, when the strip is shown, the timeline under it
+    pub fn core_layout(&self, window_rect: Rect) -> (CoreLayout, Option<Rect>) {
+        let layout = CoreLayout::calculate(window_rect, LEFT_PANEL_WIDTH, RIGHT_PANEL_WIDTH);
+        let under_deck = matches!(
+            self.view_state,
+            ViewState::DeckView | ViewState::GridView | ViewState::PickerOpen
+        );
+        if self.show_timeline && under_deck && !self.display_order.is_empty() {
+            let (deck, strip) = layout.split_timeline(self.display_order.len());
+            (deck, Some(strip))
+        } else {
+            (layout, None)
+        }
+    }
+
+    /// The timeline's axis: the dominant zone's day at the displayed instant
+    pub fn timeline_axis(&self) -> TimelineAxis {
+        TimelineAxis::around(self.display_instant(), self.dominant_zone)
+    }
+
+    /// Return the deck to the current time
+    pub fn return_to_live(&mut self) {
+        self.set_time_offset(0);
+    }
+
+    /// Cycle deck -> grid -> list -> deck
+    pub fn cycle_view_mode(&mut self) {
+        (self.grid_mode, self.list_mode) = match (self.grid_mode, self.list_mode) {
+            (_, true) => (false, false),
+            (true, false) => (false, true),
+            (false, false) => (true, false),
+        };
+        self.list_mode_override = true;
+        self.update_view_state();
+        save_config(self);
+    }
+
+    /// Disable list and grid mode (Show Deck Anyway)
+    pub fn show_deck_anyway(&mut self) {
+        self.list_mode = false;
+        self.grid_mode = false;
+        self.list_mode_override = true;
+        self.update_view_state();
+        save_config(self);
+    }
+
+    /// Drag the card at display index `from` into slot `to`
+    ///
+    /// Switches to manual ordering; whichever card ends up first is dominant.
+    pub fn reorder_card(&mut self, from: usize, to: usize) {
+        let order = move_card(&self.display_order, from, to);
+        self.dominant_zone = order[0];
+        self.ordering = OrderingStrategy::Manual(order);
+        self.update_display_order();
+        save_config(self);
+    }
+
+    /// Go back to automatic card ordering
+    pub fn reset_ordering(&mut self) {
+        self.ordering = OrderingStrategy::Automatic;
+        self.update_display_order();
+        save_config(self);
+    }
+
+    /// Whether cards can be dragged in the current view
+    fn can_drag_cards(&self) -> bool {
+        matches!(
+            self.view_state,
+            ViewState::DeckView | ViewState::GridView | ViewState::ListView
+        )
+    }
+
+    /// Geometry of every card in display order: tiled in grid view,
+    /// stacked otherwise
+    /// Pointer position from the window center (-1..1 on each axis), which
+    /// drives the deck's parallax
+    fn pointer_delta(&self, window_rect: Rect) -> Option<Point2> {
+        self.mouse_position.map(|pos| {
+            let dx = (pos.x - self.window_center.x) / (window_rect.w() / 2.0);
+            let dy = (pos.y - self.window_center.y) / (window_rect.h() / 2.0);
+            pt2(dx.clamp(-1.0, 1.0), dy.clamp(-1.0, 1.0))
+        })
+    }
+
+    /// Where the flipped card sits, while the deck or grid shows it
+    fn flipped_card_rect(&self, window_rect: Rect) -> Option<Rect> {
+        let tz = self.flipped_zone()?;
+        if !matches!(self.view_state, ViewState::DeckView | ViewState::GridView) {
+            return None;
+        }
+        let index = self.display_order.iter().position(|&z| z == tz)?;
+        let (layout, _) = self.core_layout(window_rect);
+        let geometries = self.card_geometries(&layout, self.pointer_delta(window_rect));
+        Some(geometries.get(index)?.card_rect(&layout))
+    }
+
+    fn card_geometries(
+        &self,
+        layout: &CoreLayout,
+        pointer_delta: Option<Point2>,
+    ) -> Vec<CardGeometry> {
+        let count = self.display_order.len();
+        if self.view_state == ViewState::GridView {
+            return grid_geometries(count, layout);
+        }
+        (0..count)
+            .map(|i| {
+                CardGeometry::compute(
+                    i,
+                    count,
+                    self.focus_strength,
+                    pointer_delta,
+                    self.motion_reduced(),
+                )
+            })
+            .collect()
+    }
+
+    /// Cycle focus region (Tab navigation)
+    pub fn cycle_focus_region(&mut self, reverse: bool) {
+        self.focus_region = match (self.focus_region, reverse) {
+            (FocusRegion::ZoneField, false) => FocusRegion::CoreDeck,
+            (FocusRegion::CoreDeck, false) => FocusRegion::CollapseControls,
+            (FocusRegion::CollapseControls, false) => FocusRegion::ZoneField,
+            (FocusRegion::ZoneField, true) => FocusRegion::CollapseControls,
+            (FocusRegion::CoreDeck, true) => FocusRegion::ZoneField,
+            (FocusRegion::CollapseControls, true) => FocusRegion::CoreDeck,
+        };
+    }
+
+    /// Update display order based on current state
+    fn update_display_order(&mut self) {
+        self.display_order =
+            self.ordering
+                .display_order(&self.selected_zones, self.dominant_zone, &self.favorites);
+    }
+
+    /// Check if we should auto-enable list mode (N > 8)
+    fn check_list_mode_threshold(&mut self) {
+        if !self.list_mode_override {
+            self.list_mode = self.selected_zones.len() > 8;
+        }
+        self.update_view_state();
+    }
+
+    /// Update view state based on current settings
+    fn update_view_state(&mut self) {
+        self.view_state = if self.picker_state.is_open {
+            ViewState::PickerOpen
+        } else if self.list_mode {
+            ViewState::ListView
+        } else if self.grid_mode {
+            ViewState::GridView
+        } else if self.focus_strength >= 0.8 {
+            ViewState::CompositeView
+        } else {
+            ViewState::DeckView
+        };
+    }
+
+    /// Update time data for all selected zones at the displayed instant
+    fn update_zone_times(&mut self) {
+        let instant = self.display_instant();
+        self.zone_times.clear();
+        for &tz in &self.selected_zones {
+            self.zone_times.insert(tz, compute_time_data_at(tz, instant));
+        }
+    }
+
+    /// Get time data for the dominant zone
+    pub fn dominant_time(&self) -> Option<&TimeData> {
+        self.zone_times.get(&self.dominant_zone)
+    }
+
+    /// The zones as the deck shows them, scrub offset included
+    pub fn comparison_table(&self) -> ComparisonTable {
+        ComparisonTable {
+            rows: comparison_rows(
+                &self.display_order,
+                &self.zone_times,
+                &self.zone_labels,
+                self.dominant_zone,
+            ),
+            instant: self.display_instant(),
+            offset_minutes: self.time_offset_minutes,
+        }
+    }
+
+    /// Save the comparison table to the export directory
+    pub fn export_table(&mut self) {
+        let table = self.comparison_table();
+        let label = table.instant.format("%Y%m%dT%H%M").to_string();
+        let text = table.render(self.table_format);
+        let extension = self.table_format.extension();
+        match shared::write_export(CLOCK_NAME, &label, extension, text.as_bytes()) {
+            Ok(path) => self.show_toast(tr_args("Saved table to {0}", &[&path.display()])),
+            Err(e) => self.show_toast(tr_args("Failed to save table: {0}", &[&e])),
+        }
+    }
+}
+
+/// Whether the clock is starting for the first time
+///
+/// Only a missing config counts. When the saved one was unreadable the user
+/// has chosen zones before, so they aren't replaced with the system zone.
+fn is_first_run(saved: Option<&Config>, unreadable: bool) -> bool {
+    saved.is_none() && !unreadable
+}
+
+fn save_config(model: &Model) {
+    let config = Config {
+        selected_zone_ids: model
+            .selected_zones
+            .iter()
+            .map(|tz| tz.name().to_string())
+            .collect(),
+        dominant_zone_id: model.dominant_zone.name().to_string(),
+        home_zone_id: model.home_zone.map(|tz| tz.name().to_string()),
+        favorites: model.favorites.own_ids(),
+        own_favorites: model.favorites.uses_own(),
+        focus_strength: model.focus_strength,
+        compare_mode: model.compare_mode,
+        show_drift: model.show_drift,
+        list_mode: model.list_mode,
+        list_mode_override: model.list_mode_override,
+        grid_mode: model.grid_mode,
+        show_timeline: model.show_timeline,
+        reduced_motion: model.motion_preference,
+        planner_mode: model.planner_mode,
+        business_hours: model
+            .business_hours
+            .iter()
+            .map(|(tz, hours)| (tz.name().to_string(), *hours))
+            .collect(),
+        theme: model.theme.clone(),
+        manual_order: model
+            .ordering
+            .is_manual()
+            .then(|| model.display_order.iter().map(|tz| tz.name().to_string()).collect()),
+        zone_labels: model
+            .zone_labels
+            .iter()
+            .map(|(tz, label)| (tz.name().to_string(), label.clone()))
+            .collect(),
+        dst_notify: model.dst_notify,
+        table_format: model.table_format,
+        zone_sets: model.zone_sets.clone(),
+        flip_cards: model.flip_cards,
+    };
+    model.config_saver.request(&config);
+}
+
+fn model(app: &App) -> Model {
+    // Create window
+    let window_id = app
+        .new_window()
+        .title(WINDOW_TITLE)
+        .size(1400, 800)
+        .min_size(1100, 600)
+        .view(view)
+        .key_pressed(key_pressed)
+        .mouse_pressed(mouse_pressed)
+        .mouse_released(mouse_released)
+        .mouse_moved(mouse_moved)
+        .mouse_wheel(mouse_wheel)
+        .raw_event(raw_window_event)
+        .visible(false)
+        .build()
+        .unwrap();
+
+    let window = app.window(window_id).unwrap();
+    let egui = Egui::from_window(&window);
+    shared::install_fallback_fonts(egui.ctx());
+
+    // Load configuration; an unreadable one is noted for the recovery prompt.
+    let (saved, config_recovery) = shared::load_config_or_recover::<Config>(CLOCK_NAME);
+    let first_run = is_first_run(saved.as_ref(), config_recovery.is_some());
+    let config = saved.unwrap_or_default();
+
+    // Parse timezones from config
+    let selected_zones: Vec<Tz> = config
+        .selected_zone_ids
+        .iter()
+        .filter_map(|s| s.parse().ok())
+        .collect();
+    let selected_zones = if selected_zones.is_empty() {
+        vec![DEFAULT_TZ.parse().unwrap()]
+    } else {
+        selected_zones
+    };
+
+    let dominant_zone: Tz = config
+        .dominant_zone_id
+        .parse()
+        .unwrap_or_else(|_| selected_zones[0]);
+    let home_zone: Option<Tz> = config.home_zone_id.as_deref().and_then(|s| s.parse().ok());
+
+    // The shared favorites, or this clock's own
+    let favorites = Favorites::load(&config.favorites, config.own_favorites);
+
+    // Compute initial display order (unparseable zone ids are dropped)
+    let ordering = match &config.manual_order {
+        Some(ids) => OrderingStrategy::Manual(ids.iter().filter_map(|s| s.parse().ok()).collect()),
+        None => OrderingStrategy::Automatic,
+    };
+    let display_order = ordering.display_order(&selected_zones, dominant_zone, &favorites);
+
+    let themes = ThemeLibrary::load();
+    let theme = themes.activate(config.theme.as_deref());
+    let system_motion = SystemMotionWatcher::spawn();
+
+    // Compute initial time data
+    let now = shared::now_utc();
+    let mut zone_times = HashMap::new();
+    for &tz in &selected_zones {
+        zone_times.insert(tz, compute_time_data_at(tz, now));
+    }
+
+    // Determine initial view state
+    let list_mode_override = config.list_mode_override;
+    let list_mode = if list_mode_override {
+        config.list_mode
+    } else {
+        selected_zones.len() > 8
+    };
+    let view_state = if list_mode {
+        ViewState::ListView
+    } else if config.grid_mode {
+        ViewState::GridView
+    } else if config.focus_strength >= 0.8 {
+        ViewState::CompositeView
+    } else {
+        ViewState::DeckView
+    };
+
+    // Business hours (unparseable zone ids are dropped)
+    let business_hours: HashMap<Tz, BusinessHours> = config
+        .business_hours
+        .iter()
+        .filter_map(|(id, hours)| id.parse().ok().map(|tz| (tz, *hours)))
+        .collect();
+    let overlap_windows = if config.planner_mode {
+        find_overlap_windows(&selected_zones, &business_hours, now, SEARCH_HORIZON_HOURS)
+    } else {
+        Vec::new()
+    };
+
+    // Zone labels (unparseable zone ids are dropped)
+    let zone_labels: HashMap<Tz, ZoneLabel> = config
+        .zone_labels
+        .iter()
+        .filter_map(|(id, label)| id.parse().ok().map(|tz| (tz, label.clone())))
+        .collect();
+
+    let window_rect = app.window_rect();
+
+    // Screen reader tree, attached before the window is first shown
+    let screen_reader = ScreenReader::attach(
+        window.winit_window(),
+        "Chrono-Superposition",
+        &compute_time_data_at(dominant_zone, now),
+        Some(FocusRegion::default()),
+    );
+
+    // Reopen where the window was left, or where the command line asks
+    let mut window_placement = WindowPlacement::load(CLOCK_NAME);
+    window_placement.restore(window.winit_window());
+    window.set_visible(shared::snapshot_request().is_none());
+
+    let wake_proxy = app.create_proxy();
+    let mut model = Model {
+        selected_zones,
+        dominant_zone,
+        home_zone,
+        favorites,
+        zone_times,
+        display_order,
+        ordering,
+        focus_strength: config.focus_strength,
+        compare_mode: config.compare_mode,
+        show_drift: config.show_drift,
+        list_mode,
+        list_mode_override,
+        grid_mode: config.grid_mode,
+        show_timeline: config.show_timeline,
+        view_state,
+        planner_mode: config.planner_mode,
+        business_hours,
+        overlap_windows,
+        zone_labels,
+        dst_notify: config.dst_notify,
+        dst_notifier: DstNotifier::default(),
+        toasts: Vec::new(),
+        editing_label: None,
+        text_input_focused: false,
+        zone_sets: config.zone_sets,
+        zone_set_name: String::new(),
+        zone_set_undo: None,
+        time_offset_minutes: 0,
+        table_format: config.table_format,
+        mouse_position: None,
+        window_center: pt2(window_rect.x(), window_rect.y()),
+        hovered_card_index: None,
+        card_drag: None,
+        flip_cards: config.flip_cards,
+        flipped_card: None,
+        pointer_over_ui: false,
+        picker_state: PickerState::default(),
+        command_palette: CommandPalette::default(),
+        keymap: Keymap::load(CLOCK_NAME, commands()),
+        motion_preference: config.reduced_motion,
+        reduced_motion: config.reduced_motion.resolve(system_motion.reduced()),
+        system_motion,
+        quiet_hours: QuietHours::load(),
+        intensity: VisualIntensity::FULL,
+        window_title_clock: WindowTitleClock::load(),
+        clip_settings: ClipSettings::load(),
+        clip_recorder: ClipRecorder::new(CLOCK_NAME),
+        language: LanguageSetting::load(),
+        safe_palette: SafePaletteSetting::load(),
+        window_title: WindowTitle::new(WINDOW_TITLE),
+        window_placement,
+        follow_system_zone: FollowSystemZone::load(),
+        system_zone: SystemZoneWatcher::spawn(),
+        time_reveal: TimeReveal::load(),
+        frame_pacer: FramePacer::spawn(move || wake_proxy.wakeup().is_ok()),
+        config_saver: ConfigSaver::new(CLOCK_NAME),
+        config_recovery,
+        themes,
+        theme,
+        animation_time: 0.0,
+        focus_region: FocusRegion::default(),
+        ticker: Ticker::spawn(TickGranularity::Second),
+        snapshot: SnapshotProgress::default(),
+        screen_reader,
+        egui,
+    };
+    if first_run {
+        model.use_system_zone();
+    }
+    model.check_dst_transitions();
+    model
+}
+
+/// Drive a `--snapshot` run: capture once the frame has settled, then quit
+fn advance_snapshot(app: &App, progress: &mut SnapshotProgress) {
+    let Some(request) = shared::snapshot_request() else {
+        return;
+    };
+    match progress.advance() {
+        SnapshotStep::Settle => {}
+        SnapshotStep::Capture => app.main_window().capture_frame(&request.path),
+        SnapshotStep::Finish => {
+            if app.main_window().await_capture_frame_jobs().is_err() {
+                eprintln!("Timed out writing snapshot to {}", request.path.display());
+                std::process::exit(1);
+            }
+            println!("Saved snapshot to {}", request.path.display());
+            app.quit();
+        }
+    }
+}
+
+/// Capture frames for a clip being recorded, and report the clip once saved
+fn advance_clip(app: &App, model: &mut Model) {
+    match model.clip_recorder.advance(Instant::now()) {
+        ClipStep::Idle => {}
+        ClipStep::Capture(path) => app.main_window().capture_frame(path),
+        ClipStep::Finish => {
+            let written = app.main_window().await_capture_frame_jobs().is_ok();
+            model.clip_recorder.encode(written);
+        }
+    }
+    if let Some(message) = model.clip_recorder.poll() {
+        model.show_toast(message);
+    }
+}
+
+/// Let the event loop wait between second boundaries while the clock is idle
+fn pace_frames(app: &App, model: &mut Model, now: Instant) {
+    let animating = model.is_animating();
+    let loop_mode = if model.frame_pacer.pace(animating, now) {
+        LoopMode::Wait
+    } else {
+        LoopMode::RefreshSync
+    };
+    app.set_loop_mode(loop_mode);
+}
+
+/// Act on the config recovery prompt: restart on the backup, or carry on
+/// with the defaults
+fn recover_config(app: &App, model: &mut Model, choice: RecoveryChoice) {
+    let Some(recovery) = model.config_recovery.take() else {
+        return;
+    };
+    if choice == RecoveryChoice::Restore {
+        match recovery.restore(&model.config_saver) {
+            Ok(()) => app.quit(),
+            Err(message) => model.show_toast(message),
+        }
+    }
+}
+
+fn update(app: &App, model: &mut Model, update: Update) {
+    advance_snapshot(app, &mut model.snapshot);
+    advance_clip(app, model);
+
+    // Update animation time
+    model.animation_time = update.since_start.as_secs_f32();
+
+    // Update window center for parallax
+    let window_rect = app.window_rect();
+    model.window_center = pt2(window_rect.x(), window_rect.y());
+
+    // Update time data for all zones once per wall-clock second, and slide
+    // the overlap search forward on each new minute
+    if let Some(tick) = model.ticker.poll() {
+        model.update_zone_times();
+        if tick.is_minute_boundary {
+            model.update_overlap_windows();
+            model.check_dst_transitions();
+        }
+    }
+    model.prune_toasts();
+
+    // Quiet hours follow the dominant zone's real time, not the preview
+    model.intensity = model
+        .quiet_hours
+        .intensity_at(model.dominant_zone, shared::now_utc());
+
+    // Update view state
+    model.update_view_state();
+
+    // Collect state for UI (before borrowing egui)
+    let selected_zones = model.selected_zones.clone();
+    let dominant_zone = model.dominant_zone;
+    let favorites = model.favorites.to_vec();
+    let zone_times = model.zone_times.clone();
+    let zone_count = model.selected_zones.len();
+    let manual_order = model.ordering.is_manual();
+    let dominant_time_clone = model.dominant_time().cloned();
+    let mut focus_strength = model.focus_strength;
+    let mut compare_mode = model.compare_mode;
+    let mut show_drift = model.show_drift;
+    let mut list_mode = model.list_mode;
+    let mut grid_mode = model.grid_mode;
+    let mut show_timeline = model.show_timeline;
+    let mut motion_preference = model.motion_preference;
+    let system_reduced_motion = model.system_motion.reduced();
+    let mut quiet_hours = model.quiet_hours.clone();
+    let mut window_title_clock = model.window_title_clock;
+    let mut clip_settings = model.clip_settings;
+    let mut language = model.language;
+    let mut safe_palette = model.safe_palette;
+    let mut follow_system_zone = model.follow_system_zone;
+    let mut reveal_hotkey = model.time_reveal.setting.clone();
+    let mut own_favorites = model.favorites.uses_own();
+    let mut planner_mode = model.planner_mode;
+    let mut time_offset_hours = model.time_offset_minutes as f32 / 60.0;
+    let business_hours = model.business_hours.clone();
+    let overlap_windows = model.overlap_windows.clone();
+    let zone_labels = model.zone_labels.clone();
+    let zone_sets = model.zone_sets.clone();
+    let can_undo_switch = model.can_undo_zone_set_switch();
+    let mut dst_notify = model.dst_notify;
+    let comparison_table = model.comparison_table();
+    let mut table_format = model.table_format;
+    let mut flip_cards = model.flip_cards;
+
+    // Once a flipped card has turned over, its back is drawn centered on it
+    let card_back_center = model
+        .card_flip(Instant::now())
+        .filter(|&(_, turned)| turned >= 1.0)
+        .and(model.flipped_card_rect(window_rect))
+        .map(|rect| egui::pos2(rect.x() - window_rect.left(), window_rect.top() - rect.y()));
+
+    // Begin egui frame
+    model.egui.set_elapsed_time(update.since_start);
+    let ctx = model.egui.begin_frame();
+
+    let mut visuals = egui::Visuals::dark();
+    theme_visuals(&mut visuals);
+    ctx.set_visuals(visuals);
+
+    // Draw Zone Field (left panel)
+    let zone_field_result: ZoneFieldResult = draw_zone_field(
+        &ctx,
+        &mut model.picker_state,
+        &selected_zones,
+        dominant_zone,
+        model.home_zone,
+        &favorites,
+        &zone_times,
+        &zone_labels,
+        &mut model.editing_label,
+        &zone_sets,
+        &mut model.zone_set_name,
+        can_undo_switch,
+    );
+
+    // Draw Collapse Controls (right panel)
+    let controls_result: CollapseControlsResult = draw_collapse_controls(
+        &ctx,
+        &mut focus_strength,
+        &mut compare_mode,
+        &mut show_drift,
+        &mut list_mode,
+        &mut grid_mode,
+        &mut show_timeline,
+        &mut flip_cards,
+        &mut motion_preference,
+        system_reduced_motion,
+        &mut planner_mode,
+        &mut time_offset_hours,
+        zone_count,
+        manual_order,
+        dominant_time_clone.as_ref(),
+        &mut quiet_hours,
+        &mut window_title_clock,
+        &mut clip_settings,
+        &mut language,
+        &mut safe_palette,
+        &mut follow_system_zone,
+        &mut reveal_hotkey,
+        model.time_reveal.status(),
+        &mut own_favorites,
+        &model.keymap,
+        &mut dst_notify,
+        &comparison_table,
+        &mut table_format,
+        &model.themes,
+        model.theme.as_deref(),
+    );
+
+    // Draw Meeting Planner panel (planner mode only)
+    let planner_result = if planner_mode {
+        draw_meeting_planner(
+            &ctx,
+            &selected_zones,
+            dominant_zone,
+            &business_hours,
+            &overlap_windows,
+        )
+    } else {
+        MeetingPlannerResult::default()
+    };
+
+    // Draw the back of a flipped card
+    let card_back_result = match (&model.flipped_card, card_back_center) {
+        (Some(card), Some(center)) => {
+            let name = zone_name(&zone_labels, card.details.tz);
+            draw_card_back(&ctx, &card.details, &name, center)
+        }
+        _ => CardBackResult::default(),
+    };
+
+    // Draw the time card while the reveal shortcut flashes the window
+    if model.time_reveal.is_flashing() {
+        model.time_reveal.show(&ctx, &compute_time_data(model.dominant_zone));
+    }
+
+    // Offer the backup if the saved config couldn't be read
+    let recovery_choice = model.config_recovery.as_ref().and_then(|r| r.show(&ctx));
+
+    // Draw command palette (if open)
+    let palette_choice = model.command_palette.show(&ctx, model.keymap.registry());
+
+    let text_input_focused = ctx.wants_keyboard_input();
+    let pointer_over_ui = ctx.is_pointer_over_area();
+
+    drop(ctx);
+    if let Some(choice) = recovery_choice {
+        recover_config(app, model, choice);
+    }
+
+    model.text_input_focused = text_input_focused;
+    model.pointer_over_ui = pointer_over_ui;
+
+    // Apply zone field results
+    if let Some(tz) = zone_field_result.set_dominant {
+        model.set_dominant(tz);
+    }
+    if let Some(tz) = zone_field_result.remove_zone {
+        model.remove_zone(tz);
+    }
+    if let Some(tz) = zone_field_result.toggle_favorite {
+        model.toggle_favorite(tz);
+    }
+    if let Some(tz) = zone_field_result.add_zone {
+        model.add_zone(tz);
+    }
+    if let Some((tz, label)) = zone_field_result.set_label {
+        model.set_zone_label(tz, label);
+    }
+    if zone_field_result.use_system_zone {
+        model.use_system_zone();
+    }
+    if let Some(index) = zone_field_result.switch_zone_set {
+        model.switch_zone_set(index);
+    }
+    if let Some(index) = zone_field_result.delete_zone_set {
+        model.delete_zone_set(index);
+    }
+    if let Some(name) = zone_field_result.save_zone_set {
+        model.save_zone_set(&name);
+    }
+    if zone_field_result.undo_zone_set_switch {
+        model.undo_zone_set_switch();
+    }
+
+    // Apply controls results
+    if controls_result.focus_strength_changed {
+        model.focus_strength = focus_strength;
+        model.update_view_state();
+        save_config(model);
+    }
+    if controls_result.compare_mode_changed {
+        model.compare_mode = compare_mode;
+        save_config(model);
+    }
+    if controls_result.show_drift_changed {
+        model.show_drift = show_drift;
+        save_config(model);
+    }
+    if controls_result.list_mode_changed {
+        model.list_mode = list_mode;
+        model.list_mode_override = true;
+        model.update_view_state();
+        save_config(model);
+    }
+    if controls_result.show_timeline_changed {
+        model.show_timeline = show_timeline;
+        save_config(model);
+    }
+    if controls_result.flip_cards_changed {
+        model.flip_cards = flip_cards;
+        model.flipped_card = None;
+        save_config(model);
+    }
+    if controls_result.grid_mode_changed {
+        model.grid_mode = grid_mode;
+        model.update_view_state();
+        save_config(model);
+    }
+    if controls_result.reduced_motion_changed {
+        model.set_motion_preference(motion_preference);
+        save_config(model);
+    }
+    if controls_result.quiet_hours_changed {
+        model.quiet_hours = quiet_hours;
+        report_save("quiet hours", model.quiet_hours.save(), |message| model.show_toast(message));
+    }
+    if controls_result.window_title_changed {
+        model.window_title_clock = window_title_clock;
+        report_save(
+            "window title setting",
+            model.window_title_clock.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if controls_result.clip_settings_changed {
+        model.clip_settings = clip_settings;
+        report_save(
+            "recording settings",
+            model.clip_settings.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if controls_result.language_changed {
+        model.language = language;
+        report_save("language setting", model.language.save(), |message| model.show_toast(message));
+    }
+    if controls_result.safe_palette_changed {
+        model.safe_palette = safe_palette;
+        report_save(
+            "palette setting",
+            model.safe_palette.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if controls_result.system_zone_changed {
+        model.follow_system_zone = follow_system_zone;
+        report_save(
+            "system zone setting",
+            model.follow_system_zone.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if controls_result.reveal_hotkey_changed {
+        model.time_reveal.setting = reveal_hotkey;
+        model.time_reveal.apply();
+    }
+    if controls_result.favorites_source_changed {
+        model.favorites.set_uses_own(own_favorites);
+        model.update_display_order();
+        save_config(model);
+    }
+    if let Some(edit) = controls_result.keymap_edit {
+        model.keymap.apply(edit);
+    }
+    if controls_result.show_deck_anyway {
+        model.show_deck_anyway();
+    }
+    if controls_result.reset_order {
+        model.reset_ordering();
+    }
+    if controls_result.planner_mode_changed {
+        model.toggle_planner_mode();
+    }
+    if controls_result.time_offset_changed {
+        model.set_time_offset((time_offset_hours * 60.0).round() as i64);
+    }
+    if controls_result.return_to_live {
+        model.return_to_live();
+    }
+    if controls_result.dst_notify_changed {
+        model.set_dst_notify(dst_notify);
+    }
+    if controls_result.table_format_changed {
+        model.table_format = table_format;
+        save_config(model);
+    }
+    if controls_result.table_copied {
+        model.show_toast(tr_args("Copied {0} table", &[&table_format.label()]));
+    }
+    if controls_result.save_table {
+        model.export_table();
+    }
+    if let Some(theme) = controls_result.set_theme {
+        model.theme = model.themes.activate(theme.as_deref());
+        save_config(model);
+    }
+
+    // Apply the flipped card's buttons
+    if card_back_result.make_dominant {
+        if let Some(tz) = model.flipped_zone() {
+            model.set_dominant(tz);
+        }
+    }
+    if card_back_result.flip_back {
+        model.flipped_card = None;
+    }
+
+    // Apply meeting planner results
+    if let Some((tz, hours)) = planner_result.set_hours {
+        model.set_business_hours(tz, hours);
+    }
+    if planner_result.close {
+        model.toggle_planner_mode();
+    }
+
+    // Run the command chosen in the palette as if its keys were pressed
+    if let Some(chord) = palette_choice {
+        handle_key(model, chord.key, chord.mods);
+    }
+
+    // Keep the screen reader's time, DST status and focus current
+    if let Some(time_data) = model.zone_times.get(&model.dominant_zone) {
+        model.screen_reader.update(time_data, Some(model.focus_region));
+    }
+
+    // Pick up favorites starred in another clock
+    if model.favorites.poll() {
+        model.update_display_order();
+    }
+
+    // Follow the computer's time zone when it changes
+    if let Some(change) = model.system_zone.poll() {
+        let switch = model.follow_system_zone.enabled && change.current != model.dominant_zone;
+        if switch {
+            model.follow_zone(change.current);
+        }
+        model.show_toast(change.message(switch));
+    }
+
+    // Follow the system reduce-motion setting when set to Auto
+    if model.system_motion.poll() {
+        model.set_motion_preference(model.motion_preference);
+    }
+
+    // Show the current time in the taskbar/dock title, and any clip recording
+    let badge = model.clip_recorder.indicator(Instant::now());
+    model.window_title.set_badge(badge);
+    model.window_title.update(
+        app.main_window().winit_window(),
+        &model.window_title_clock,
+        model.dominant_zone,
+        shared::now_utc(),
+    );
+
+    // Show or end the reveal shortcut's flash
+    model.time_reveal.update(app.main_window().winit_window(), Instant::now());
+
+    // Remember where the window was moved or resized to
+    model.window_placement.track(app.main_window().winit_window(), Instant::now());
+
+    // Write config changes once they have settled
+    model.config_saver.poll(Instant::now());
+
+    // Idle between second boundaries once nothing is moving
+    pace_frames(app, model, Instant::now());
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    let window_rect = app.window_rect();
+
+    // Clear background
+    draw.background().color(colors::background());
+
+    // Calculate core layout (center area between panels), less the timeline
+    let (layout, timeline_strip) = model.core_layout(window_rect);
+
+    // Calculate card geometries
+    let geometries = model.card_geometries(&layout, model.pointer_delta(window_rect));
+
+    // Slot a dragged card or row would drop into
+    let drag = model.card_drag.filter(|drag| drag.moved);
+    let drop_target = drag.and(model.hovered_card_index);
+
+    // Meeting planner status (in dominant zone's wall time)
+    let overlap_status = model.overlap_status();
+    let overlap_active = overlap_status.map(|s| s.is_active()).unwrap_or(false);
+
+    // A frozen clock holds pulses steady during quiet hours
+    let animation_time = if model.intensity.animations {
+        model.animation_time
+    } else {
+        0.0
+    };
+
+    // Draw based on view state
+    match model.view_state {
+        ViewState::DeckView | ViewState::GridView | ViewState::PickerOpen => {
+            // Show deck view when picker is open (picker overlays on top);
+            // the grid is the same cards, laid out side by side
+            draw_card_deck(
+                &draw,
+                &layout,
+                &model.display_order,
+                &model.zone_times,
+                model.dominant_zone,
+                model.home_zone,
+                &geometries,
+                model.compare_mode,
+                model.hovered_card_index,
+                model.card_flip(Instant::now()),
+                animation_time,
+                model.motion_reduced(),
+                model.planner_mode.then_some(&model.business_hours),
+                &model.zone_labels,
+            );
+        }
+        ViewState::CompositeView => {
+            draw_composite_readout(
+                &draw,
+                &layout,
+                &model.display_order,
+                &model.zone_times,
+                model.dominant_zone,
+                model.compare_mode,
+                model.show_drift,
+                animation_time,
+                overlap_active,
+                &model.zone_labels,
+            );
+        }
+        ViewState::ListView => {
+            draw_list_view(
+                &draw,
+                &layout,
+                &model.display_order,
+                &model.zone_times,
+                model.dominant_zone,
+                model.home_zone,
+                model.compare_mode,
+                drop_target,
+                &model.zone_labels,
+            );
+        }
+    }
+
+    if let Some(strip) = timeline_strip {
+        draw_timeline(
+            &draw,
+            strip,
+            &model.timeline_axis(),
+            &model.display_order,
+            model.dominant_zone,
+            model.display_instant(),
+            model.is_live(),
+            &model.zone_labels,
+        );
+    }
+
+    if let (Some(drag), Some(pos)) = (drag, model.mouse_position) {
+        if let Some(&tz) = model.display_order.get(drag.from) {
+            draw_drag_label(&draw, &zone_name(&model.zone_labels, tz), pos);
+        }
+    }
+
+    if let Some(status) = overlap_status {
+        let text = status.describe(model.display_instant(), model.dominant_zone);
+        draw_planner_banner(&draw, &layout, &text, overlap_active);
+    }
+
+    if !model.is_live() {
+        let text = format!(
+            "PREVIEW {} · {} UTC · press 0 to return to live",
+            format_offset(model.time_offset_minutes),
+            model.display_instant().format("%a %H:%M")
+        );
+        draw_scrub_banner(&draw, &layout, &text);
+    }
+
+    draw_toasts(&draw, &layout, &model.toasts);
+
+    // Draw title (centered on window, not core area)
+    draw.text("CHRONO-SUPERPOSITION")
+        .x_y(0.0, window_rect.top() - 30.0)
+        .color(colors::text_primary())
+        .font_size(18)
+        .w(400.0);
+
+    // Dim the deck during quiet hours
+    if model.intensity.is_quiet() {
+        draw.rect()
+            .xy(window_rect.xy())
+            .wh(window_rect.wh())
+            .color(srgba(0.0, 0.0, 0.0, model.intensity.dim_alpha()));
+    }
+
+    // Render to frame
+    draw.to_frame(app, &frame).unwrap();
+
+    // Render egui on top
+    model.egui.draw_to_frame(&frame).unwrap();
+}
+
+/// Every action in the command palette, with the keys that perform it
+fn commands() -> CommandRegistry {
+    CommandRegistry::new()
+        .register("Open timezone picker", KeyChord::new(Key::F))
+        .register("Return to deck view", KeyChord::new(Key::Escape))
+        .register("Next focus region", KeyChord::new(Key::Tab))
+        .register("Previous focus region", KeyChord::shift(Key::Tab))
+        .register("Toggle compare mode", KeyChord::new(Key::C))
+        .register("Toggle meeting planner", KeyChord::new(Key::M))
+        .register("Cycle deck, grid and list views", KeyChord::new(Key::L))
+        .register("Toggle timeline strip", KeyChord::new(Key::T))
+        .register("Step back one hour", KeyChord::new(Key::LBracket))
+        .register("Step forward one hour", KeyChord::new(Key::RBracket))
+        .register("Step back 15 minutes", KeyChord::shift(Key::LBracket))
+        .register("Step forward 15 minutes", KeyChord::shift(Key::RBracket))
+        .register("Step back one day", KeyChord::ctrl(Key::LBracket))
+        .register("Step forward one day", KeyChord::ctrl(Key::RBracket))
+        .register("Return to live", KeyChord::new(Key::Key0))
+        .register("Undo zone set switch", KeyChord::ctrl(Key::Z))
+        .register("Record clip", KeyChord::ctrl_shift(Key::R))
+}
+
+fn key_pressed(app: &App, model: &mut Model, key: Key) {
+    let mods = app.keys.mods;
+
+    // While a shortcut is being rebound, the next chord becomes its binding
+    if model.keymap.capture(key, mods) {
+        return;
+    }
+
+    // Ctrl+K opens the command palette from anywhere; while open it takes
+    // every other key
+    if KeyChord::is_palette_toggle(key, mods) {
+        model.command_palette.toggle();
+        return;
+    }
+    if model.command_palette.is_open {
+        return;
+    }
+
+    // Typing a zone nickname shouldn't trigger shortcuts
+    if model.text_input_focused && !model.picker_state.is_open {
+        return;
+    }
+
+    // Remapped chords reach the handler as their action's default chord
+    if let Some(chord) = model.keymap.translate(key, mods) {
+        handle_key(model, chord.key, chord.mods);
+    }
+}
+
+fn handle_key(model: &mut Model, key: Key, mods: ModifiersState) {
+    match key {
+        // Ctrl+Shift+R - start or stop recording a clip
+        _ if shared::is_clip_toggle(key, mods) => {
+            let message = model.clip_recorder.toggle(model.clip_settings, Instant::now());
+            model.show_toast(message);
+        }
+
+        // Escape - close picker or return to deck view
+        Key::Escape => {
+            if model.picker_state.is_open {
+                model.picker_state.close();
+                model.update_view_state();
+            } else if model.flipped_card.is_some() {
+                model.flipped_card = None;
+            } else if !model.is_live() {
+                model.return_to_live();
+            } else if model.list_mode || model.grid_mode {
+                model.list_mode = false;
+                model.grid_mode = false;
+                model.list_mode_override = true;
+                model.update_view_state();
+                save_config(model);
+            }
+        }
+
+        // Tab - cycle focus regions
+        Key::Tab if !model.picker_state.is_open => {
+            model.cycle_focus_region(mods.shift());
+        }
+
+        // Enter - set hovered card as dominant, or flip it (when Core Deck is focused)
+        Key::Return
+            if !model.picker_state.is_open && model.focus_region == FocusRegion::CoreDeck =>
+        {
+            if let Some(idx) = model.hovered_card_index {
+                if idx < model.display_order.len() {
+                    let tz = model.display_order[idx];
+                    model.press_card(tz);
+                }
+            }
+        }
+
+        // Arrow keys - cycle dominance (when Core Deck is focused)
+        Key::Up if !model.picker_state.is_open && model.focus_region == FocusRegion::CoreDeck => {
+            model.cycle_dominance(-1);
+        }
+        Key::Down if !model.picker_state.is_open && model.focus_region == FocusRegion::CoreDeck => {
+            model.cycle_dominance(1);
+        }
+
+        // C - toggle compare mode
+        Key::C if !model.picker_state.is_open => {
+            model.toggle_compare_mode();
+        }
+
+        // M - toggle meeting planner mode
+        Key::M if !model.picker_state.is_open => {
+            model.toggle_planner_mode();
+        }
+
+        // L - cycle deck, grid and list views
+        Key::L if !model.picker_state.is_open => {
+            model.cycle_view_mode();
+        }
+
+        // T - show or hide the timeline strip
+        Key::T if !model.picker_state.is_open => {
+            model.toggle_timeline();
+        }
+
+        // [ / ] - scrub the deck backward/forward in time
+        Key::LBracket if !model.picker_state.is_open => {
+            if mods.ctrl() || mods.logo() {
+                model.step_time_offset(-24 * 60); // -1 day
+            } else if mods.shift() {
+                model.step_time_offset(-15); // -15 minutes
+            } else {
+                model.step_time_offset(-60); // -1 hour
+            }
+        }
+        Key::RBracket if !model.picker_state.is_open => {
+            if mods.ctrl() || mods.logo() {
+                model.step_time_offset(24 * 60); // +1 day
+            } else if mods.shift() {
+                model.step_time_offset(15); // +15 minutes
+            } else {
+                model.step_time_offset(60); // +1 hour
+            }
+        }
+
+        // 0 - return to live
+        Key::Key0 if !model.picker_state.is_open => {
+            model.return_to_live();
+        }
+
+        // Ctrl+Z - undo the last zone set switch
+        Key::Z if mods.ctrl() || mods.logo() => {
+            model.undo_zone_set_switch();
+        }
+
+        // F or / - focus search / open picker
+        Key::F | Key::Slash => {
+            if !model.picker_state.is_open {
+                model.picker_state.open();
+                model.update_view_state();
+            } else {
+                model.picker_state.should_focus_search = true;
+            }
+        }
+
+        _ => {}
+    }
+}
+
+fn mouse_pressed(app: &App, model: &mut Model, button: MouseButton) {
+    match button {
+        // Clicks on the back of a flipped card or another egui window are egui's
+        MouseButton::Left if !model.picker_state.is_open && !model.pointer_over_ui => {
+            // Clicking the timeline scrubs the deck to that time
+            let (_, strip) = model.core_layout(app.window_rect());
+            if let Some(fraction) = strip
+                .zip(model.mouse_position)
+                .and_then(|(strip, pos)| timeline_fraction_at(strip, pos))
+            {
+                let instant = model.timeline_axis().instant_at(fraction);
+                model.scrub_to(instant);
+                return;
+            }
+
+            // Pressing a card starts a possible drag; a release without
+            // moving is a click that makes it dominant or flips it.
+            // Clicking away from the cards turns a flipped card back
+            if let (Some(idx), Some(pos)) = (model.hovered_card_index, model.mouse_position) {
+                if idx < model.display_order.len() {
+                    model.card_drag = Some(CardDrag {
+                        from: idx,
+                        start: pos,
+                        moved: false,
+                    });
+                }
+            } else {
+                model.flipped_card = None;
+            }
+        }
+        // Rotary input: middle click toggles compare mode
+        MouseButton::Middle if !model.picker_state.is_open => {
+            model.toggle_compare_mode();
+        }
+        _ => {}
+    }
+}
+
+fn mouse_released(_app: &App, model: &mut Model, button: MouseButton) {
+    if button != MouseButton::Left {
+        return;
+    }
+    let Some(drag) = model.card_drag.take() else {
+        return;
+    };
+    if !drag.moved {
+        if let Some(&tz) = model.display_order.get(drag.from) {
+            model.press_card(tz);
+        }
+    } else if let Some(to) = model.hovered_card_index {
+        if to != drag.from {
+            model.reorder_card(drag.from, to);
+        }
+    }
+}
+
+fn mouse_wheel(_app: &App, model: &mut Model, delta: MouseScrollDelta, _phase: TouchPhase) {
+    // Rotary input: scroll wheel cycles dominance
+    if model.picker_state.is_open {
+        return;
+    }
+
+    let scroll_y = match delta {
+        MouseScrollDelta::LineDelta(_, y) => y,
+        MouseScrollDelta::PixelDelta(pos) => pos.y as f32 / 50.0,
+    };
+
+    if scroll_y > 0.5 {
+        model.cycle_dominance(-1); // Scroll up = previous
+    } else if scroll_y < -0.5 {
+        model.cycle_dominance(1); // Scroll down = next
+    }
+}
+
+fn mouse_moved(app: &App, model: &mut Model, pos: Point2) {
+    model.mouse_position = Some(pos);
+
+    // Promote a press to a drag once the pointer has travelled far enough
+    let can_drag = model.can_drag_cards();
+    if let Some(drag) = &mut model.card_drag {
+        if can_drag && drag.start.distance(pos) > DRAG_THRESHOLD {
+            drag.moved = true;
+        }
+    }
+
+    // Update hovered card index based on mouse position
+    let window_rect = app.window_rect();
+    let (layout, _) = model.core_layout(window_rect);
+
+    // Check if mouse is within core area
+    if model.view_state == ViewState::ListView {
+        model.hovered_card_index = list_row_at(&layout, model.display_order.len(), pos);
+    } else if layout.contains(pos.x, pos.y) {
+        // Simple hit testing - cards are stacked, so check from top (last) to bottom (first)
+        let geometries = model.card_geometries(&layout, model.pointer_delta(window_rect));
+        model.hovered_card_index = (0..geometries.len())
+            .rev()
+            .find(|&i| geometries[i].card_rect(&layout).contains(pos));
+    } else {
+        model.hovered_card_index = None;
+    }
+}
+
+fn raw_window_event(app: &App, model: &mut Model, event: &nannou::winit::event::WindowEvent) {
+    // Any window event brings an idle clock back to full frame rate
+    model.frame_pacer.note_input(Instant::now());
+
+    model.egui.handle_raw_event(event);
+    model.screen_reader.process_event(app.main_window().winit_window(), event);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_run_only_without_config() {
+        assert!(is_first_run(None, false));
+        assert!(!is_first_run(Some(&Config::default()), false));
+        // An unreadable config falls back to defaults but isn't a first run
+        assert!(!is_first_run(None, true));
+    }
+}
@@ -0,0 +1,268 @@
+//! Planner module - business hours and meeting overlap search
+//!
+//! Given per-zone business hours, finds the UTC windows in which every
+//! selected zone is inside its working day. Used by the "find overlap" mode to
+//! highlight cards and by the Meeting Planner panel.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, DurationRound, Timelike, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// Default working day start (09:00 local)
+pub const DEFAULT_START_MINUTE: u32 = 9 * 60;
+
+/// Default working day end (17:00 local)
+pub const DEFAULT_END_MINUTE: u32 = 17 * 60;
+
+/// How far ahead overlap windows are searched
+pub const SEARCH_HORIZON_HOURS: i64 = 24;
+
+/// Sampling step in minutes; every real-world UTC offset is a multiple of 15
+/// minutes, so quarter-hour business hours land exactly on a step
+const STEP_MINUTES: i64 = 5;
+
+/// Working hours for one zone, in local minutes since midnight
+///
+/// `end_minute < start_minute` describes an overnight shift.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BusinessHours {
+    pub start_minute: u32,
+    pub end_minute: u32,
+}
+
+impl Default for BusinessHours {
+    fn default() -> Self {
+        Self {
+            start_minute: DEFAULT_START_MINUTE,
+            end_minute: DEFAULT_END_MINUTE,
+        }
+    }
+}
+
+impl BusinessHours {
+    /// Whether a local minute-of-day falls inside these hours
+    pub fn contains_minute(&self, minute_of_day: u32) -> bool {
+        if self.start_minute <= self.end_minute {
+            minute_of_day >= self.start_minute && minute_of_day < self.end_minute
+        } else {
+            minute_of_day >= self.start_minute || minute_of_day < self.end_minute
+        }
+    }
+
+    /// Whether a UTC instant falls inside these hours in the given zone
+    pub fn contains_instant(&self, instant: DateTime<Utc>, tz: Tz) -> bool {
+        let local = instant.with_timezone(&tz);
+        self.contains_minute(local.hour() * 60 + local.minute())
+    }
+
+    /// Format as "9:00 AM – 5:00 PM"
+    pub fn format(&self) -> String {
+        format!(
+            "{} – {}",
+            format_minute_of_day(self.start_minute),
+            format_minute_of_day(self.end_minute)
+        )
+    }
+}
+
+/// Look up a zone's hours, falling back to the default working day
+pub fn hours_for(business_hours: &HashMap<Tz, BusinessHours>, tz: Tz) -> BusinessHours {
+    business_hours.get(&tz).copied().unwrap_or_default()
+}
+
+/// A UTC window where every selected zone is within business hours
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverlapWindow {
+    pub start_utc: DateTime<Utc>,
+    pub end_utc: DateTime<Utc>,
+}
+
+impl OverlapWindow {
+    /// Whether the instant lies inside this window
+    pub fn contains(&self, instant: DateTime<Utc>) -> bool {
+        instant >= self.start_utc && instant < self.end_utc
+    }
+
+    /// Window length in minutes
+    pub fn duration_minutes(&self) -> i64 {
+        (self.end_utc - self.start_utc).num_minutes()
+    }
+
+    /// Format as local start – end in the given zone
+    pub fn format_in(&self, tz: Tz) -> String {
+        format!(
+            "{} – {}",
+            format_local(self.start_utc, tz),
+            format_local(self.end_utc, tz)
+        )
+    }
+}
+
+/// Find every overlap window in the next `horizon_hours` starting at `from`
+pub fn find_overlap_windows(
+    zones: &[Tz],
+    business_hours: &HashMap<Tz, BusinessHours>,
+    from: DateTime<Utc>,
+    horizon_hours: i64,
+) -> Vec<OverlapWindow> {
+    let mut windows = Vec::new();
+    if zones.is_empty() {
+        return windows;
+    }
+
+    let start = from
+        .duration_trunc(Duration::minutes(STEP_MINUTES))
+        .unwrap_or(from);
+    let steps = horizon_hours * 60 / STEP_MINUTES;
+    let mut open_since: Option<DateTime<Utc>> = None;
+
+    // One step past the horizon so a window still open at the end gets closed
+    for step in 0..=steps {
+        let instant = start + Duration::minutes(step * STEP_MINUTES);
+        let all_in = step < steps
+            && zones
+                .iter()
+                .all(|&tz| hours_for(business_hours, tz).contains_instant(instant, tz));
+
+        match (all_in, open_since) {
+            (true, None) => open_since = Some(instant),
+            (false, Some(window_start)) => {
+                windows.push(OverlapWindow {
+                    start_utc: window_start,
+                    end_utc: instant,
+                });
+                open_since = None;
+            }
+            _ => {}
+        }
+    }
+
+    windows
+}
+
+/// Where "now" sits relative to the overlap windows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverlapStatus {
+    /// All zones are in business hours right now
+    Active(OverlapWindow),
+    /// The next shared window is later
+    Upcoming(OverlapWindow),
+    /// No shared window within the search horizon
+    None,
+}
+
+impl OverlapStatus {
+    /// Classify `now` against a list of windows (sorted by start)
+    pub fn at(windows: &[OverlapWindow], now: DateTime<Utc>) -> Self {
+        if let Some(window) = windows.iter().find(|w| w.contains(now)) {
+            return OverlapStatus::Active(*window);
+        }
+        match windows.iter().find(|w| w.start_utc > now) {
+            Some(window) => OverlapStatus::Upcoming(*window),
+            None => OverlapStatus::None,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        matches!(self, OverlapStatus::Active(_))
+    }
+
+    /// One-line description with times in the given zone
+    pub fn describe(&self, now: DateTime<Utc>, tz: Tz) -> String {
+        match self {
+            OverlapStatus::Active(window) => {
+                format!("◆ Overlap now · until {}", format_local(window.end_utc, tz))
+            }
+            OverlapStatus::Upcoming(window) => {
+                let minutes_until = (window.start_utc - now).num_minutes();
+                format!(
+                    "Next overlap {} (in {}h {:02}m)",
+                    window.format_in(tz),
+                    minutes_until / 60,
+                    minutes_until % 60
+                )
+            }
+            OverlapStatus::None => {
+                format!("No shared working hours in the next {}h", SEARCH_HORIZON_HOURS)
+            }
+        }
+    }
+}
+
+/// Format a minute-of-day as 12-hour wall time
+pub fn format_minute_of_day(minute_of_day: u32) -> String {
+    let minute_of_day = minute_of_day % (24 * 60);
+    let hour24 = minute_of_day / 60;
+    let hour12 = match hour24 {
+        0 => 12,
+        h if h <= 12 => h,
+        h => h - 12,
+    };
+    let meridiem = if hour24 < 12 { "AM" } else { "PM" };
+    format!("{}:{:02} {}", hour12, minute_of_day % 60, meridiem)
+}
+
+fn format_local(instant: DateTime<Utc>, tz: Tz) -> String {
+    let local = instant.with_timezone(&tz);
+    format_minute_of_day(local.hour() * 60 + local.minute())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_overlap_new_york_london() {
+        let new_york: Tz = "America/New_York".parse().unwrap();
+        let london: Tz = "Europe/London".parse().unwrap();
+        // Winter: New York is UTC-5, London is UTC+0
+        let from = Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap();
+
+        let windows = find_overlap_windows(&[new_york, london], &HashMap::new(), from, 24);
+
+        // 09:00-17:00 in both overlaps 14:00-17:00 UTC
+        assert_eq!(windows.len(), 1);
+        assert_eq!(windows[0].start_utc, Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 0).unwrap());
+        assert_eq!(windows[0].end_utc, Utc.with_ymd_and_hms(2025, 1, 15, 17, 0, 0).unwrap());
+        assert_eq!(windows[0].duration_minutes(), 180);
+    }
+
+    #[test]
+    fn test_custom_hours_remove_overlap() {
+        let los_angeles: Tz = "America/Los_Angeles".parse().unwrap();
+        let tokyo: Tz = "Asia/Tokyo".parse().unwrap();
+        let from = Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap();
+        let zones = [los_angeles, tokyo];
+
+        // LA 4-5 PM is Tokyo 9-10 AM (00:00-01:00 UTC)
+        let windows = find_overlap_windows(&zones, &HashMap::new(), from, 24);
+        assert_eq!(windows.len(), 1);
+        assert_eq!(windows[0].start_utc, from);
+        assert_eq!(windows[0].duration_minutes(), 60);
+
+        // Tokyo starting at 10:00 leaves no shared hour
+        let mut hours = HashMap::new();
+        hours.insert(tokyo, BusinessHours { start_minute: 10 * 60, end_minute: 17 * 60 });
+        assert!(find_overlap_windows(&zones, &hours, from, 24).is_empty());
+    }
+
+    #[test]
+    fn test_overnight_hours_and_status() {
+        let night_shift = BusinessHours { start_minute: 22 * 60, end_minute: 6 * 60 };
+        assert!(night_shift.contains_minute(23 * 60));
+        assert!(night_shift.contains_minute(60));
+        assert!(!night_shift.contains_minute(12 * 60));
+
+        let window = OverlapWindow {
+            start_utc: Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 0).unwrap(),
+            end_utc: Utc.with_ymd_and_hms(2025, 1, 15, 17, 0, 0).unwrap(),
+        };
+        let before = Utc.with_ymd_and_hms(2025, 1, 15, 12, 0, 0).unwrap();
+        let during = Utc.with_ymd_and_hms(2025, 1, 15, 15, 0, 0).unwrap();
+        assert_eq!(OverlapStatus::at(&[window], before), OverlapStatus::Upcoming(window));
+        assert!(OverlapStatus::at(&[window], during).is_active());
+    }
+}
@@ -0,0 +1,57 @@
+//! Scrub module - previewing the deck at an offset from now
+//!
+//! The whole deck can be shifted to a hypothetical instant ("what will these
+//! zones read in five hours?"). The shift is kept as an offset from the live
+//! clock, so a preview keeps ticking rather than freezing.
+
+use chrono::{DateTime, Duration, Utc};
+
+/// Furthest the scrubber reaches in either direction
+pub const SCRUB_RANGE_HOURS: i64 = 48;
+
+/// Clamp an offset in minutes to the scrubber's range
+pub fn clamp_offset(offset_minutes: i64) -> i64 {
+    let limit = SCRUB_RANGE_HOURS * 60;
+    offset_minutes.clamp(-limit, limit)
+}
+
+/// The instant the deck shows for a given offset
+pub fn virtual_instant(now: DateTime<Utc>, offset_minutes: i64) -> DateTime<Utc> {
+    now + Duration::minutes(offset_minutes)
+}
+
+/// Format an offset as "+5h 00m" / "−1h 30m" ("Live" at zero)
+pub fn format_offset(offset_minutes: i64) -> String {
+    if offset_minutes == 0 {
+        return "Live".to_string();
+    }
+
+    let sign = if offset_minutes > 0 { "+" } else { "−" };
+    let magnitude = offset_minutes.abs();
+    format!("{}{}h {:02}m", sign, magnitude / 60, magnitude % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_clamp_offset() {
+        assert_eq!(clamp_offset(90), 90);
+        assert_eq!(clamp_offset(10_000), SCRUB_RANGE_HOURS * 60);
+        assert_eq!(clamp_offset(-10_000), -SCRUB_RANGE_HOURS * 60);
+    }
+
+    #[test]
+    fn test_virtual_instant_and_format() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 9, 5, 0, 0).unwrap();
+        assert_eq!(
+            virtual_instant(now, 300),
+            Utc.with_ymd_and_hms(2025, 3, 9, 10, 0, 0).unwrap()
+        );
+        assert_eq!(format_offset(0), "Live");
+        assert_eq!(format_offset(300), "+5h 00m");
+        assert_eq!(format_offset(-90), "−1h 30m");
+    }
+}
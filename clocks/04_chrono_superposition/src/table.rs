@@ -0,0 +1,231 @@
+//! Table module - the deck as a comparison table for pasting elsewhere
+//!
+//! Each selected zone becomes one row: its name, local date and time, UTC
+//! offset, whether DST is in effect, and how far its clocks sit from the
+//! dominant zone's. Rows are taken from the zone times the deck is showing,
+//! so a scrubbed preview exports the previewed instant rather than now.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use shared::TimeData;
+
+use crate::labels::{zone_name, ZoneLabel};
+use crate::scrub::format_offset;
+
+/// Column headings, in order
+const HEADINGS: [&str; 6] = ["Zone", "Time zone", "Local time", "UTC offset", "DST", "Delta"];
+
+/// How the table is written out
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TableFormat {
+    #[default]
+    Csv,
+    Markdown,
+}
+
+impl TableFormat {
+    pub const ALL: [TableFormat; 2] = [TableFormat::Csv, TableFormat::Markdown];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TableFormat::Csv => "CSV",
+            TableFormat::Markdown => "Markdown",
+        }
+    }
+
+    /// File extension for saved tables
+    pub fn extension(&self) -> &'static str {
+        match self {
+            TableFormat::Csv => "csv",
+            TableFormat::Markdown => "md",
+        }
+    }
+}
+
+/// One zone's row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableRow {
+    /// Nickname, or the zone id when there is none
+    pub name: String,
+    pub zone_id: String,
+    /// "2025-03-09 10:00"
+    pub local_time: String,
+    /// "UTC+09:00"
+    pub utc_offset: String,
+    pub is_dst: bool,
+    /// Offset from the dominant zone in minutes; `None` for the dominant zone
+    pub delta_minutes: Option<i32>,
+}
+
+impl TableRow {
+    fn cells(&self) -> [String; 6] {
+        [
+            self.name.clone(),
+            self.zone_id.clone(),
+            self.local_time.clone(),
+            self.utc_offset.clone(),
+            if self.is_dst { "yes" } else { "no" }.to_string(),
+            self.delta_minutes
+                .map(format_delta)
+                .unwrap_or_else(|| "dominant".to_string()),
+        ]
+    }
+}
+
+/// Rows for the zones in `order` that have time data
+pub fn comparison_rows(
+    order: &[Tz],
+    zone_times: &HashMap<Tz, TimeData>,
+    zone_labels: &HashMap<Tz, ZoneLabel>,
+    dominant_zone: Tz,
+) -> Vec<TableRow> {
+    let dominant_offset = zone_times.get(&dominant_zone).map(|td| td.utc_offset_minutes);
+    order
+        .iter()
+        .filter_map(|&tz| {
+            let td = zone_times.get(&tz)?;
+            Some(TableRow {
+                name: zone_name(zone_labels, tz),
+                zone_id: tz.name().to_string(),
+                local_time: td.local_datetime.format("%Y-%m-%d %H:%M").to_string(),
+                utc_offset: td.format_utc_offset(),
+                is_dst: td.is_dst,
+                delta_minutes: match dominant_offset {
+                    Some(offset) if tz != dominant_zone => Some(td.utc_offset_minutes - offset),
+                    _ => None,
+                },
+            })
+        })
+        .collect()
+}
+
+/// Format an offset difference as "+5:30" / "-3:00" / "0:00"
+pub fn format_delta(minutes: i32) -> String {
+    let sign = match minutes.signum() {
+        1 => "+",
+        -1 => "-",
+        _ => "",
+    };
+    format!("{}{}:{:02}", sign, minutes.abs() / 60, minutes.abs() % 60)
+}
+
+/// The rows together with the instant they show
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComparisonTable {
+    pub rows: Vec<TableRow>,
+    /// The instant the deck shows
+    pub instant: DateTime<Utc>,
+    /// Scrub offset from now in minutes (0 = live)
+    pub offset_minutes: i64,
+}
+
+impl ComparisonTable {
+    /// Write the table out; Markdown tables get a caption naming the instant
+    pub fn render(&self, format: TableFormat) -> String {
+        match format {
+            TableFormat::Csv => render_csv(&self.rows),
+            TableFormat::Markdown => render_markdown(&self.rows, self.instant, self.offset_minutes),
+        }
+    }
+}
+
+fn render_csv(rows: &[TableRow]) -> String {
+    let mut out = HEADINGS.join(",");
+    out.push('\n');
+    for row in rows {
+        let cells: Vec<String> = row.cells().iter().map(|cell| csv_field(cell)).collect();
+        out.push_str(&cells.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+fn render_markdown(rows: &[TableRow], instant: DateTime<Utc>, offset_minutes: i64) -> String {
+    let mut out = format!("Zones at {}", instant.format("%Y-%m-%d %H:%M UTC"));
+    if offset_minutes != 0 {
+        out.push_str(&format!(" (preview {} from now)", format_offset(offset_minutes)));
+    }
+    out.push_str("\n\n");
+    out.push_str(&format!("| {} |\n", HEADINGS.join(" | ")));
+    out.push_str(&format!("|{}\n", "---|".repeat(HEADINGS.len())));
+    for row in rows {
+        let cells: Vec<String> = row.cells().iter().map(|cell| cell.replace('|', "\\|")).collect();
+        out.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    out
+}
+
+/// Quote a CSV field when it holds a comma, quote or newline
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use shared::compute_time_data_at;
+
+    fn table(offset_minutes: i64) -> ComparisonTable {
+        let la: Tz = "America/Los_Angeles".parse().unwrap();
+        let kolkata: Tz = "Asia/Kolkata".parse().unwrap();
+        let instant = Utc.with_ymd_and_hms(2025, 7, 1, 16, 0, 0).unwrap();
+        let zone_times: HashMap<Tz, TimeData> = [la, kolkata]
+            .iter()
+            .map(|&tz| (tz, compute_time_data_at(tz, instant)))
+            .collect();
+        let mut labels = HashMap::new();
+        labels.insert(
+            la,
+            ZoneLabel {
+                nickname: "HQ, West".to_string(),
+                color: None,
+            },
+        );
+        ComparisonTable {
+            rows: comparison_rows(&[la, kolkata], &zone_times, &labels, la),
+            instant,
+            offset_minutes,
+        }
+    }
+
+    #[test]
+    fn test_rows_follow_the_dominant_zone() {
+        let rows = table(0).rows;
+        assert_eq!(rows[0].delta_minutes, None);
+        assert!(rows[0].is_dst);
+        assert_eq!(rows[0].local_time, "2025-07-01 09:00");
+        assert_eq!(rows[1].delta_minutes, Some(12 * 60 + 30));
+        assert_eq!(rows[1].local_time, "2025-07-01 21:30");
+        assert_eq!(rows[1].utc_offset, "UTC+05:30");
+        assert_eq!(format_delta(-90), "-1:30");
+        assert_eq!(format_delta(0), "0:00");
+    }
+
+    #[test]
+    fn test_render_formats() {
+        let csv = table(0).render(TableFormat::Csv);
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("Zone,Time zone,Local time,UTC offset,DST,Delta"));
+        assert_eq!(
+            lines.next(),
+            Some("\"HQ, West\",America/Los_Angeles,2025-07-01 09:00,UTC-07:00,yes,dominant")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("Asia/Kolkata,Asia/Kolkata,2025-07-01 21:30,UTC+05:30,no,+12:30")
+        );
+
+        let markdown = table(300).render(TableFormat::Markdown);
+        assert!(markdown.starts_with("Zones at 2025-07-01 16:00 UTC (preview +5h 00m from now)"));
+        assert!(markdown.contains("| Asia/Kolkata | Asia/Kolkata | 2025-07-01 21:30 |"));
+        assert_eq!(markdown.lines().filter(|l| l.starts_with('|')).count(), 4);
+    }
+}
@@ -0,0 +1,217 @@
+//! Timeline module - the 24-hour offset strip under the deck
+//!
+//! Like a meeting planner, the strip lays every zone out as a band of hour
+//! cells against a shared axis: the dominant zone's local day containing the
+//! instant the deck shows. Each cell holds the zone's local time at that
+//! point on the axis, shaded for night, and a zone's band marks where a DST
+//! change shifts its offset. Clicking the strip scrubs the deck to the
+//! instant under the pointer.
+
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Offset, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
+
+/// Hour cells across the axis
+pub const HOURS: usize = 24;
+
+/// Clicks snap to this many minutes on the axis
+const SNAP_MINUTES: i64 = 15;
+
+/// How a cell's local hour is shaded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shade {
+    /// 08:00-18:00
+    Day,
+    /// Early morning and evening
+    Fringe,
+    /// 22:00-06:00
+    Night,
+}
+
+impl Shade {
+    /// Shade of a local hour (0..24)
+    pub fn of_hour(hour: u32) -> Self {
+        match hour {
+            8..=17 => Shade::Day,
+            6..=7 | 18..=21 => Shade::Fringe,
+            _ => Shade::Night,
+        }
+    }
+}
+
+/// One hour cell of a zone's band
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HourCell {
+    /// Local hour ("14", "14:30"), or the weekday where a local day begins
+    pub label: String,
+    pub shade: Shade,
+    /// The first cell of a new local day
+    pub new_day: bool,
+}
+
+/// The 24 hours the strip spans, from the dominant zone's local midnight
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimelineAxis {
+    pub start: DateTime<Utc>,
+}
+
+impl TimelineAxis {
+    /// The dominant zone's local day containing `instant`
+    pub fn around(instant: DateTime<Utc>, dominant: Tz) -> Self {
+        let day = instant.with_timezone(&dominant).date_naive();
+        // A DST change at midnight skips it; the day then starts at the
+        // first local time that exists
+        let start = (0..3)
+            .find_map(|hour| {
+                let local = day.and_time(NaiveTime::from_hms_opt(hour, 0, 0)?);
+                dominant.from_local_datetime(&local).earliest()
+            })
+            .map(|start| start.with_timezone(&Utc))
+            .unwrap_or(instant);
+        Self { start }
+    }
+
+    /// The last instant on the axis
+    pub fn end(&self) -> DateTime<Utc> {
+        self.start + Duration::hours(HOURS as i64)
+    }
+
+    /// Where `instant` sits along the axis (0..1), if it's on it
+    pub fn fraction_of(&self, instant: DateTime<Utc>) -> Option<f32> {
+        let span = (self.end() - self.start).num_seconds() as f32;
+        let fraction = (instant - self.start).num_seconds() as f32 / span;
+        (0.0..=1.0).contains(&fraction).then_some(fraction)
+    }
+
+    /// The instant at `fraction` along the axis, snapped to the quarter hour
+    pub fn instant_at(&self, fraction: f32) -> DateTime<Utc> {
+        let minutes = fraction.clamp(0.0, 1.0) * (HOURS as i64 * 60) as f32;
+        let snapped = (minutes / SNAP_MINUTES as f32).round() as i64 * SNAP_MINUTES;
+        self.start + Duration::minutes(snapped)
+    }
+
+    /// `tz`'s band: its local time at the start of each hour on the axis
+    pub fn cells(&self, tz: Tz) -> Vec<HourCell> {
+        (0..HOURS as i64)
+            .map(|hour| {
+                let local = (self.start + Duration::hours(hour)).with_timezone(&tz);
+                let previous = (self.start + Duration::hours(hour - 1)).with_timezone(&tz);
+                let new_day = local.date_naive() != previous.date_naive();
+                let label = if new_day {
+                    local.weekday().to_string()
+                } else if local.minute() == 0 {
+                    local.hour().to_string()
+                } else {
+                    format!("{}:{:02}", local.hour(), local.minute())
+                };
+                // Shade by the middle of the cell, so a half-hour zone's
+                // 7:30 cell counts as the eight o'clock hour
+                let middle = (self.start + Duration::minutes(hour * 60 + 30)).with_timezone(&tz);
+                HourCell {
+                    label,
+                    shade: Shade::of_hour(middle.hour()),
+                    new_day,
+                }
+            })
+            .collect()
+    }
+
+    /// Where `tz`'s UTC offset changes along the axis (0..1)
+    pub fn offset_changes(&self, tz: Tz) -> Vec<f32> {
+        let offset_at = |minute: i64| {
+            let instant = self.start + Duration::minutes(minute);
+            tz.offset_from_utc_datetime(&instant.naive_utc()).fix()
+        };
+        let total = HOURS as i64 * 60;
+        (0..HOURS as i64)
+            .filter(|hour| offset_at(hour * 60) != offset_at((hour + 1) * 60))
+            .map(|hour| {
+                // Narrow the hour down to the minute the offset changes
+                let (mut before, mut after) = (hour * 60, (hour + 1) * 60);
+                while after - before > 1 {
+                    let middle = (before + after) / 2;
+                    if offset_at(middle) == offset_at(before) {
+                        before = middle;
+                    } else {
+                        after = middle;
+                    }
+                }
+                after as f32 / total as f32
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zone(name: &str) -> Tz {
+        name.parse().unwrap()
+    }
+
+    #[test]
+    fn test_axis_starts_at_dominant_midnight() {
+        let new_york = zone("America/New_York");
+        // 15:00 EDT
+        let instant = Utc.with_ymd_and_hms(2025, 6, 2, 19, 0, 0).unwrap();
+        let axis = TimelineAxis::around(instant, new_york);
+        assert_eq!(axis.start, Utc.with_ymd_and_hms(2025, 6, 2, 4, 0, 0).unwrap());
+        assert_eq!(axis.fraction_of(instant), Some(15.0 / 24.0));
+        assert_eq!(axis.fraction_of(axis.end() + Duration::hours(1)), None);
+
+        let cells = axis.cells(new_york);
+        assert_eq!(cells.len(), HOURS);
+        assert_eq!(cells[0].label, "Mon");
+        assert!(cells[0].new_day);
+        assert_eq!(cells[15].label, "15");
+        assert_eq!(cells[0].shade, Shade::Night);
+        assert_eq!(cells[12].shade, Shade::Day);
+    }
+
+    #[test]
+    fn test_other_zones_align_to_the_axis() {
+        let axis = TimelineAxis::around(
+            Utc.with_ymd_and_hms(2025, 6, 2, 19, 0, 0).unwrap(),
+            zone("America/New_York"),
+        );
+        // New York midnight is 13:30 in Adelaide, whose next day starts
+        // in the cell after 23:30
+        let adelaide = axis.cells(zone("Australia/Adelaide"));
+        assert_eq!(adelaide[0].label, "13:30");
+        assert!(!adelaide[0].new_day);
+        assert_eq!(adelaide[10].label, "23:30");
+        assert_eq!(adelaide[11].label, "Tue");
+        assert!(adelaide[11].new_day);
+
+        let tokyo = axis.cells(zone("Asia/Tokyo"));
+        assert_eq!(tokyo[0].label, "13");
+        assert_eq!(tokyo[11].label, "Tue");
+    }
+
+    #[test]
+    fn test_dst_change_is_marked() {
+        let new_york = zone("America/New_York");
+        // Spring forward at 02:00 EST on 9 March 2025
+        let axis = TimelineAxis::around(
+            Utc.with_ymd_and_hms(2025, 3, 9, 17, 0, 0).unwrap(),
+            new_york,
+        );
+        assert_eq!(axis.offset_changes(new_york), vec![2.0 / 24.0]);
+        let cells = axis.cells(new_york);
+        assert_eq!(cells[1].label, "1");
+        assert_eq!(cells[2].label, "3");
+        assert!(axis.offset_changes(zone("Asia/Tokyo")).is_empty());
+    }
+
+    #[test]
+    fn test_clicks_snap_to_the_quarter_hour() {
+        let axis = TimelineAxis::around(
+            Utc.with_ymd_and_hms(2025, 6, 2, 19, 0, 0).unwrap(),
+            zone("Asia/Tokyo"),
+        );
+        let start = axis.start;
+        assert_eq!(axis.instant_at(0.5), start + Duration::hours(12));
+        assert_eq!(axis.instant_at(0.51), start + Duration::minutes(735));
+        assert_eq!(axis.instant_at(2.0), axis.end());
+    }
+}
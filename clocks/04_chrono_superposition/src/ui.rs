@@ -1,16 +1,34 @@
 //! UI module - egui panel components for the three-region layout
 //!
 //! Provides:
-//! - Zone Field (left panel): search, zone toggles, favorites
-//! - Collapse Controls (right panel): focus strength, compare mode, list mode
+//! - Zone Field (left panel): search, zone toggles, favorites, nicknames and color tags,
+//!   saved zone sets
+//! - Collapse Controls (right panel): time offset, focus strength, compare mode,
+//!   grid and list modes, DST change alerts
+//! - Meeting Planner panel: business hours per zone and overlap windows
+//! - Card back: a flipped card's zone details
 //! - Timezone picker overlay
 
 use std::collections::HashMap;
 
-use chrono::Utc;
 use chrono_tz::Tz;
 use nannou_egui::egui;
-use shared::{search_timezones, DstChange, TimeData, Validity};
+use shared::{
+    active_palette, clip_recording_settings, favorites_settings, keymap_settings, language_settings,
+    order_by_region, quiet_hours_settings, reduced_motion_settings, region_change, region_heading,
+    reveal_hotkey_settings, safe_palette_settings, search_timezones, system_zone_settings, tr,
+    tr_args, window_title_settings, zone_display_name, ClipSettings, DstChange, FollowSystemZone,
+    Keymap, KeymapEdit, LanguageSetting, MotionPreference, QuietHours, RevealHotkey, Rgb,
+    SafePaletteSetting, ThemeLibrary, TimeData, Validity, WindowTitleClock,
+};
+
+use crate::details::ZoneDetails;
+use crate::dst_notify::{DstNotifySettings, MAX_LEAD_HOURS, MIN_LEAD_HOURS, UNAVAILABLE_REASON};
+use crate::labels::{self, label_for, ZoneLabel, SWATCHES};
+use crate::planner::{format_minute_of_day, hours_for, BusinessHours, OverlapWindow};
+use crate::scrub::{format_offset, SCRUB_RANGE_HOURS};
+use crate::table::{ComparisonTable, TableFormat};
+use crate::zone_sets::ZoneSet;
 
 /// State for the timezone picker
 #[derive(Default)]
@@ -29,7 +47,7 @@ impl PickerState {
     pub fn open(&mut self) {
         self.is_open = true;
         self.search_query.clear();
-        self.search_results = search_timezones("");
+        self.search_results = order_by_region(search_timezones(""));
         self.should_focus_search = true;
     }
 
@@ -40,7 +58,7 @@ impl PickerState {
     }
 
     pub fn update_search(&mut self) {
-        self.search_results = search_timezones(&self.search_query);
+        self.search_results = order_by_region(search_timezones(&self.search_query));
     }
 }
 
@@ -51,6 +69,10 @@ pub struct PickerResult {
     pub add_zone: Option<Tz>,
     /// If Some, toggle favorite status for this timezone
     pub toggle_favorite: Option<Tz>,
+    /// Make the system time zone the dominant home zone
+    pub use_system_zone: bool,
+    /// Switch to the zone set at this index
+    pub switch_zone_set: Option<usize>,
     /// If true, close the picker
     pub close_picker: bool,
 }
@@ -66,6 +88,18 @@ pub struct ZoneFieldResult {
     pub toggle_favorite: Option<Tz>,
     /// Add a new zone
     pub add_zone: Option<Tz>,
+    /// New nickname and color tag for a zone
+    pub set_label: Option<(Tz, ZoneLabel)>,
+    /// Make the system time zone the dominant home zone
+    pub use_system_zone: bool,
+    /// Switch to the zone set at this index
+    pub switch_zone_set: Option<usize>,
+    /// Delete the zone set at this index
+    pub delete_zone_set: Option<usize>,
+    /// Save the current zones as a set with this name
+    pub save_zone_set: Option<String>,
+    /// Undo the last zone set switch
+    pub undo_zone_set_switch: bool,
 }
 
 /// Result of Collapse Controls panel interactions
@@ -75,22 +109,91 @@ pub struct CollapseControlsResult {
     pub focus_strength_changed: bool,
     /// Compare mode toggled
     pub compare_mode_changed: bool,
+    /// Alignment drift dials toggled
+    pub show_drift_changed: bool,
     /// List mode toggled
     pub list_mode_changed: bool,
+    /// Grid mode toggled
+    pub grid_mode_changed: bool,
+    /// Timeline strip shown or hidden
+    pub show_timeline_changed: bool,
+    /// Card flipping turned on or off
+    pub flip_cards_changed: bool,
     /// Reduced motion toggled
     pub reduced_motion_changed: bool,
+    /// Quiet-hours schedule edited
+    pub quiet_hours_changed: bool,
+    /// Window title clock setting edited
+    pub window_title_changed: bool,
+    /// Shared clip recording settings edited
+    pub clip_settings_changed: bool,
+    /// Shared language edited
+    pub language_changed: bool,
+    /// Shared safe palette edited
+    pub safe_palette_changed: bool,
+    /// Shared follow-system-zone setting edited
+    pub system_zone_changed: bool,
+    /// Shared reveal shortcut edited
+    pub reveal_hotkey_changed: bool,
+    /// Switched between the shared favorites and this clock's own
+    pub favorites_source_changed: bool,
+    /// Shortcut rebound, reset or waiting for its new chord
+    pub keymap_edit: Option<KeymapEdit>,
     /// Show Deck Anyway clicked
     pub show_deck_anyway: bool,
+    /// Reset to Automatic Order clicked
+    pub reset_order: bool,
+    /// Meeting planner mode toggled
+    pub planner_mode_changed: bool,
+    /// Time offset slider moved
+    pub time_offset_changed: bool,
+    /// Return to Live clicked
+    pub return_to_live: bool,
+    /// DST alert preferences edited
+    pub dst_notify_changed: bool,
+    /// Export format changed
+    pub table_format_changed: bool,
+    /// Comparison table copied to the clipboard
+    pub table_copied: bool,
+    /// Save Table clicked
+    pub save_table: bool,
+    /// Switch theme (`Some(None)` for the clock's own colors)
+    pub set_theme: Option<Option<String>>,
+}
+
+/// Result of Meeting Planner panel interactions
+#[derive(Default)]
+pub struct MeetingPlannerResult {
+    /// New business hours for a zone
+    pub set_hours: Option<(Tz, BusinessHours)>,
+    /// Close the planner (leave planner mode)
+    pub close: bool,
+}
+
+/// Result of the flipped card's buttons
+#[derive(Default)]
+pub struct CardBackResult {
+    /// Make Dominant clicked
+    pub make_dominant: bool,
+    /// Flip Back clicked
+    pub flip_back: bool,
 }
 
 /// Draw the Zone Field panel (left side)
+#[allow(clippy::too_many_arguments)]
 pub fn draw_zone_field(
     ctx: &egui::Context,
     picker_state: &mut PickerState,
     selected_zones: &[Tz],
     dominant_zone: Tz,
+    home_zone: Option<Tz>,
     favorites: &[Tz],
     zone_times: &HashMap<Tz, TimeData>,
+    zone_labels: &HashMap<Tz, ZoneLabel>,
+    editing_label: &mut Option<Tz>,
+    zone_sets: &[ZoneSet],
+    zone_set_name: &mut String,
+    can_undo_switch: bool,
 ) -> ZoneFieldResult {
     let mut result = ZoneFieldResult::default();
 
@@ -99,13 +202,20 @@ pub fn draw_zone_field(
         .default_width(240.0)
         .show(ctx, |ui| {
             ui.add_space(10.0);
-            ui.heading("Zone Field");
+            ui.heading(tr("Zone Field"));
             ui.add_space(10.0);
 
             // Add Zone button
-            if ui.button("+ Add Time Zone").clicked() {
+            if ui.button(tr("+ Add Time Zone")).clicked() {
                 picker_state.open();
             }
+            if ui
+                .button(tr("⌂ Use System Zone"))
+                .on_hover_text(tr("Make this machine's time zone the dominant home zone"))
+                .clicked()
+            {
+                result.use_system_zone = true;
+            }
 
             ui.add_space(10.0);
             ui.separator();
@@ -113,7 +223,7 @@ pub fn draw_zone_field(
 
             // Selected zones list
             ui.label(
-                egui::RichText::new(format!("Selected Zones ({})", selected_zones.len()))
+                egui::RichText::new(tr_args("Selected Zones ({0})", &[&selected_zones.len()]))
                     .size(12.0)
                     .color(egui::Color32::from_rgb(160, 165, 175)),
             );
@@ -132,7 +242,7 @@ pub fn draw_zone_field(
                             let dom_label = if is_dominant { "[*]" } else { "[ ]" };
                             if ui
                                 .selectable_label(is_dominant, dom_label)
-                                .on_hover_text("Click to set as dominant")
+                                .on_hover_text(tr("Click to set as dominant"))
                                 .clicked()
                             {
                                 result.set_dominant = Some(tz);
@@ -146,15 +256,8 @@ pub fn draw_zone_field(
 
                             // Zone info
                             ui.vertical(|ui| {
-                                // Zone name (shortened)
-                                let short_name: String = tz
-                                    .name()
-                                    .split('/')
-                                    .last()
-                                    .unwrap_or(tz.name())
-                                    .chars()
-                                    .take(18)
-                                    .collect();
+                                // Zone name (nickname or shortened id) with its color tag
+                                let short_name = labels::short_zone_name(zone_labels, tz, 18);
 
                                 let name_color = if is_dominant {
                                     egui::Color32::from_rgb(245, 240, 235)
@@ -162,7 +265,20 @@ pub fn draw_zone_field(
                                     egui::Color32::from_rgb(180, 185, 195)
                                 };
 
-                                ui.label(egui::RichText::new(&short_name).color(name_color));
+                                ui.horizontal(|ui| {
+                                    if let Some([r, g, b]) = label_for(zone_labels, tz).color {
+                                        ui.label(
+                                            egui::RichText::new("●")
+                                                .color(egui::Color32::from_rgb(r, g, b)),
+                                        );
+                                    }
+                                    ui.label(egui::RichText::new(&short_name).color(name_color))
+                                        .on_hover_text(tz.name());
+                                    if home_zone == Some(tz) {
+                                        ui.label(egui::RichText::new("⌂").color(name_color))
+                                            .on_hover_text(tr("Home zone"));
+                                    }
+                                });
 
                                 // Time preview
                                 if let Some(td) = time_data {
@@ -189,23 +305,38 @@ pub fn draw_zone_field(
                                 }
                             });
 
-                            // Remove button (only if more than 1 zone)
-                            if selected_zones.len() > 1 {
-                                ui.with_layout(
-                                    egui::Layout::right_to_left(egui::Align::Center),
-                                    |ui| {
-                                        if ui
+                            ui.with_layout(
+                                egui::Layout::right_to_left(egui::Align::Center),
+                                |ui| {
+                                    // Remove button (only if more than 1 zone)
+                                    if selected_zones.len() > 1
+                                        && ui
                                             .small_button("×")
-                                            .on_hover_text("Remove zone")
+                                            .on_hover_text(tr("Remove zone"))
                                             .clicked()
-                                        {
-                                            result.remove_zone = Some(tz);
-                                        }
-                                    },
-                                );
-                            }
+                                    {
+                                        result.remove_zone = Some(tz);
+                                    }
+
+                                    // Label editor toggle
+                                    let is_editing = *editing_label == Some(tz);
+                                    if ui
+                                        .selectable_label(is_editing, "✎")
+                                        .on_hover_text(tr("Nickname and color"))
+                                        .clicked()
+                                    {
+                                        *editing_label = if is_editing { None } else { Some(tz) };
+                                    }
+                                },
+                            );
                         });
 
+                        if *editing_label == Some(tz) {
+                            if let Some(label) = draw_label_editor(ui, tz, zone_labels) {
+                                result.set_label = Some((tz, label));
+                            }
+                        }
+
                         ui.add_space(4.0);
                     }
                 });
@@ -214,19 +345,80 @@ pub fn draw_zone_field(
             ui.separator();
             ui.add_space(10.0);
 
+            // Saved zone sets
+            ui.label(
+                egui::RichText::new(tr_args("Zone Sets ({0})", &[&zone_sets.len()]))
+                    .size(12.0)
+                    .color(egui::Color32::from_rgb(160, 165, 175)),
+            );
+            ui.add_space(5.0);
+
+            for (i, set) in zone_sets.iter().enumerate() {
+                let is_active = set.matches(selected_zones, dominant_zone);
+                ui.horizontal(|ui| {
+                    if ui
+                        .selectable_label(is_active, &set.name)
+                        .on_hover_text(set.zone_ids.join("\n"))
+                        .clicked()
+                        && !is_active
+                    {
+                        result.switch_zone_set = Some(i);
+                    }
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui
+                            .small_button("×")
+                            .on_hover_text(tr("Delete zone set"))
+                            .clicked()
+                        {
+                            result.delete_zone_set = Some(i);
+                        }
+                    });
+                });
+            }
+
+            ui.horizontal(|ui| {
+                let response = ui.add(
+                    egui::TextEdit::singleline(zone_set_name)
+                        .hint_text(tr("Set name"))
+                        .desired_width(130.0),
+                );
+                let submitted =
+                    response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                let has_name = !zone_set_name.trim().is_empty();
+                let save = ui
+                    .add_enabled(has_name, egui::Button::new(tr("Save current")))
+                    .on_hover_text(tr("Save the selected zones and dominant zone under this name"));
+                if has_name && (save.clicked() || submitted) {
+                    result.save_zone_set = Some(zone_set_name.clone());
+                }
+            });
+
+            if can_undo_switch
+                && ui
+                    .button(tr("↶ Undo switch"))
+                    .on_hover_text(tr("Restore the zones from before the switch (Ctrl+Z)"))
+                    .clicked()
+            {
+                result.undo_zone_set_switch = true;
+            }
+
+            ui.add_space(10.0);
+            ui.separator();
+            ui.add_space(10.0);
+
             // Keyboard hints
             ui.label(
-                egui::RichText::new("Keyboard:")
+                egui::RichText::new(tr("Keyboard:"))
                     .size(11.0)
                     .color(egui::Color32::from_rgb(140, 145, 155)),
             );
             ui.label(
-                egui::RichText::new("Up/Down: Cycle dominant")
+                egui::RichText::new(tr("Up/Down: Cycle dominant"))
                     .size(10.0)
                     .color(egui::Color32::from_rgb(120, 125, 135)),
             );
             ui.label(
-                egui::RichText::new("F  Search zones")
+                egui::RichText::new(tr("F  Search zones"))
                     .size(10.0)
                     .color(egui::Color32::from_rgb(120, 125, 135)),
             );
@@ -234,13 +426,24 @@ pub fn draw_zone_field(
 
     // Draw timezone picker if open
     if picker_state.is_open {
-        let picker_result = draw_timezone_picker(ctx, picker_state, selected_zones, favorites);
+        let picker_result = draw_timezone_picker(
+            ctx,
+            picker_state,
+            selected_zones,
+            dominant_zone,
+            favorites,
+            zone_sets,
+        );
         if let Some(tz) = picker_result.add_zone {
             result.add_zone = Some(tz);
         }
         if let Some(tz) = picker_result.toggle_favorite {
             result.toggle_favorite = Some(tz);
         }
+        result.use_system_zone |= picker_result.use_system_zone;
+        if let Some(index) = picker_result.switch_zone_set {
+            result.switch_zone_set = Some(index);
+        }
         if picker_result.close_picker {
             picker_state.close();
         }
@@ -249,15 +452,91 @@ pub fn draw_zone_field(
     result
 }
 
+/// Nickname field and color swatches for one zone; returns the edited label
+fn draw_label_editor(
+    ui: &mut egui::Ui,
+    tz: Tz,
+    zone_labels: &HashMap<Tz, ZoneLabel>,
+) -> Option<ZoneLabel> {
+    let mut label = label_for(zone_labels, tz);
+    let mut changed = false;
+
+    ui.indent(("zone_label", tz.name()), |ui| {
+        changed |= ui
+            .add(
+                egui::TextEdit::singleline(&mut label.nickname)
+                    .hint_text(tz.name())
+                    .desired_width(170.0),
+            )
+            .changed();
+
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing.x = 4.0;
+            for swatch in SWATCHES {
+                let [r, g, b] = swatch;
+                let is_selected = label.color == Some(swatch);
+                let stroke = if is_selected {
+                    egui::Stroke::new(2.0, egui::Color32::WHITE)
+                } else {
+                    egui::Stroke::NONE
+                };
+                let button = egui::Button::new("")
+                    .fill(egui::Color32::from_rgb(r, g, b))
+                    .stroke(stroke)
+                    .min_size(egui::vec2(16.0, 16.0));
+                if ui.add(button).clicked() {
+                    label.color = Some(swatch);
+                    changed = true;
+                }
+            }
+            if ui
+                .small_button("∅")
+                .on_hover_text(tr("No color"))
+                .clicked()
+            {
+                label.color = None;
+                changed = true;
+            }
+        });
+    });
+    ui.add_space(4.0);
+
+    changed.then_some(label)
+}
+
 /// Draw the Collapse Controls panel (right side)
+#[allow(clippy::too_many_arguments)]
 pub fn draw_collapse_controls(
     ctx: &egui::Context,
     focus_strength: &mut f32,
     compare_mode: &mut bool,
+    show_drift: &mut bool,
     list_mode: &mut bool,
-    reduced_motion: &mut bool,
+    grid_mode: &mut bool,
+    show_timeline: &mut bool,
+    flip_cards: &mut bool,
+    motion_preference: &mut MotionPreference,
+    system_reduced_motion: bool,
+    planner_mode: &mut bool,
+    time_offset_hours: &mut f32,
     zone_count: usize,
+    manual_order: bool,
     dominant_time: Option<&TimeData>,
+    quiet_hours: &mut QuietHours,
+    window_title: &mut WindowTitleClock,
+    clip_settings: &mut ClipSettings,
+    language: &mut LanguageSetting,
+    safe_palette: &mut SafePaletteSetting,
+    follow_system_zone: &mut FollowSystemZone,
+    reveal_hotkey: &mut RevealHotkey,
+    reveal_status: Option<&str>,
+    own_favorites: &mut bool,
+    keymap: &Keymap,
+    dst_notify: &mut DstNotifySettings,
+    comparison_table: &ComparisonTable,
+    table_format: &mut TableFormat,
+    themes: &ThemeLibrary,
+    theme: Option<&str>,
 ) -> CollapseControlsResult {
     let mut result = CollapseControlsResult::default();
 
@@ -266,13 +545,13 @@ pub fn draw_collapse_controls(
         .default_width(200.0)
         .show(ctx, |ui| {
             ui.add_space(10.0);
-            ui.heading("Controls");
+            ui.heading(tr("Controls"));
             ui.add_space(15.0);
 
             // Current dominant zone time display
             if let Some(td) = dominant_time {
                 ui.label(
-                    egui::RichText::new("Dominant Zone")
+                    egui::RichText::new(tr("Dominant Zone"))
                         .size(11.0)
                         .color(egui::Color32::from_rgb(160, 165, 175)),
                 );
@@ -289,7 +568,7 @@ pub fn draw_collapse_controls(
 
                 ui.label(td.format_date());
                 ui.label(
-                    egui::RichText::new(&td.format_utc_offset())
+                    egui::RichText::new(td.format_utc_offset())
                         .size(11.0)
                         .color(egui::Color32::from_rgb(140, 145, 155)),
                 );
@@ -297,9 +576,9 @@ pub fn draw_collapse_controls(
                 // Validity warning
                 if td.validity != Validity::Ok {
                     let warning = match td.validity {
-                        Validity::TzMissing => "⚠ TZ data missing",
-                        Validity::TzDataStale => "⚠ TZ data may be stale",
-                        Validity::Unknown => "⚠ Unknown validity",
+                        Validity::TzMissing => tr("⚠ TZ data missing"),
+                        Validity::TzDataStale => tr("⚠ TZ data may be stale"),
+                        Validity::Unknown => tr("⚠ Unknown validity"),
                         Validity::Ok => "",
                     };
                     ui.colored_label(egui::Color32::from_rgb(255, 179, 71), warning);
@@ -314,16 +593,48 @@ pub fn draw_collapse_controls(
                 ui.add_space(10.0);
             }
 
+            // Time offset scrubber
+            ui.label(tr("Time Offset"));
+            ui.add_space(3.0);
+
+            let range = SCRUB_RANGE_HOURS as f32;
+            let offset_label = format_offset((*time_offset_hours * 60.0).round() as i64);
+            let offset_response = ui.add(
+                egui::Slider::new(time_offset_hours, -range..=range)
+                    .step_by(0.25)
+                    .show_value(false)
+                    .text(offset_label),
+            );
+            if offset_response.changed() {
+                result.time_offset_changed = true;
+            }
+
+            if *time_offset_hours != 0.0 && ui.button(tr("Return to Live")).clicked() {
+                result.return_to_live = true;
+            }
+
+            ui.label(
+                egui::RichText::new(
+                    tr("Keyboard: [ / ] (Shift 15m, Ctrl 1d), 0 = live, Ctrl+K commands"),
+                )
+                .size(10.0)
+                .color(egui::Color32::from_rgb(120, 125, 135)),
+            );
+
+            ui.add_space(15.0);
+            ui.separator();
+            ui.add_space(10.0);
+
             // Focus Strength slider
-            ui.label("Focus Strength");
+            ui.label(tr("Focus Strength"));
             ui.add_space(3.0);
 
             let focus_label = if *focus_strength >= 0.8 {
-                "Collapsed"
+                tr("Collapsed")
             } else if *focus_strength >= 0.4 {
-                "Focused"
+                tr("Focused")
             } else {
-                "Spread"
+                tr("Spread")
             };
 
             let slider_response = ui.add(
@@ -336,7 +647,7 @@ pub fn draw_collapse_controls(
             }
 
             ui.label(
-                egui::RichText::new("Low = spread cards, High = collapse")
+                egui::RichText::new(tr("Low = spread cards, High = collapse"))
                     .size(10.0)
                     .color(egui::Color32::from_rgb(120, 125, 135)),
             );
@@ -346,56 +657,108 @@ pub fn draw_collapse_controls(
             ui.add_space(10.0);
 
             // Compare Mode toggle
-            ui.label("Compare Mode");
+            ui.label(tr("Compare Mode"));
             ui.add_space(3.0);
 
             if ui
-                .checkbox(compare_mode, "Show deltas from dominant")
+                .checkbox(compare_mode, tr("Show deltas from dominant"))
                 .changed()
             {
                 result.compare_mode_changed = true;
             }
 
             ui.label(
-                egui::RichText::new("Keyboard: C")
+                egui::RichText::new(tr("Keyboard: C"))
                     .size(10.0)
                     .color(egui::Color32::from_rgb(120, 125, 135)),
             );
 
+            if ui
+                .checkbox(show_drift, tr("Show minute/second drift"))
+                .on_hover_text(
+                    tr("In the composite view, a dial per zone whose minutes and seconds \
+                        don't line up with the dominant zone (e.g. +5:45)"),
+                )
+                .changed()
+            {
+                result.show_drift_changed = true;
+            }
+
             ui.add_space(15.0);
             ui.separator();
             ui.add_space(10.0);
 
             // List Mode toggle
-            ui.label("View Mode");
+            ui.label(tr("View Mode"));
             ui.add_space(3.0);
 
-            if ui.checkbox(list_mode, "List Mode (Accessible)").changed() {
+            if ui.checkbox(list_mode, tr("List Mode (Accessible)")).changed() {
                 result.list_mode_changed = true;
             }
+            if ui
+                .add_enabled(!*list_mode, egui::Checkbox::new(grid_mode, tr("Grid Mode")))
+                .on_hover_text(tr("Tile cards in rows and columns; suits 6-12 zones"))
+                .changed()
+            {
+                result.grid_mode_changed = true;
+            }
+            if ui
+                .add_enabled(!*list_mode, egui::Checkbox::new(show_timeline, tr("Timeline Strip")))
+                .on_hover_text(
+                    tr("Each zone's hours under the deck, lined up with the dominant zone's \
+                        day; click a time to preview it (T)"),
+                )
+                .changed()
+            {
+                result.show_timeline_changed = true;
+            }
+            if ui
+                .add_enabled(
+                    !*list_mode,
+                    egui::Checkbox::new(flip_cards, tr("Flip Cards for Details")),
+                )
+                .on_hover_text(
+                    tr("Clicking a card that isn't dominant, or pressing Enter on it, turns \
+                        it over to show the zone's details instead of making it dominant"),
+                )
+                .changed()
+            {
+                result.flip_cards_changed = true;
+            }
 
             if zone_count > 8 {
                 if *list_mode {
                     // Show "Show Deck Anyway" button when list mode is auto-triggered
                     ui.add_space(5.0);
-                    if ui.button("Show Deck Anyway").clicked() {
+                    if ui.button(tr("Show Deck Anyway")).clicked() {
                         result.show_deck_anyway = true;
                     }
                     ui.label(
-                        egui::RichText::new(format!("({} zones in deck)", zone_count))
+                        egui::RichText::new(tr_args("({0} zones in deck)", &[&zone_count]))
                             .size(10.0)
                             .color(egui::Color32::from_rgb(120, 125, 135)),
                     );
                 } else {
                     ui.colored_label(
                         egui::Color32::from_rgb(255, 179, 71),
-                        format!("⚠ {} zones - list recommended", zone_count),
+                        tr_args("⚠ {0} zones - list recommended", &[&zone_count]),
                     );
                 }
             }
 
             ui.label(
-                egui::RichText::new("Keyboard: L")
+                egui::RichText::new(tr("Keyboard: L cycles deck / grid / list"))
+                    .size(10.0)
+                    .color(egui::Color32::from_rgb(120, 125, 135)),
+            );
+
+            // Card order
+            ui.add_space(5.0);
+            if manual_order && ui.button(tr("Reset to Automatic Order")).clicked() {
+                result.reset_order = true;
+            }
+            ui.label(
+                egui::RichText::new(tr("Drag cards or rows to reorder"))
                     .size(10.0)
                     .color(egui::Color32::from_rgb(120, 125, 135)),
             );
@@ -404,25 +767,97 @@ pub fn draw_collapse_controls(
             ui.separator();
             ui.add_space(10.0);
 
-            // Settings
-            ui.label("Settings");
+            // Meeting Planner toggle
+            ui.label(tr("Meeting Planner"));
             ui.add_space(3.0);
 
-            if ui.checkbox(reduced_motion, "Reduced Motion").changed() {
-                result.reduced_motion_changed = true;
+            if ui.checkbox(planner_mode, tr("Find overlap")).changed() {
+                result.planner_mode_changed = true;
             }
 
             ui.label(
-                egui::RichText::new("Disables parallax and animations")
+                egui::RichText::new(tr("Keyboard: M"))
+                    .size(10.0)
+                    .color(egui::Color32::from_rgb(120, 125, 135)),
+            );
+
+            ui.add_space(15.0);
+            ui.separator();
+            ui.add_space(10.0);
+
+            // DST change alerts
+            ui.label(tr("DST Alerts"));
+            ui.add_space(3.0);
+            result.dst_notify_changed = dst_notify_settings(ui, dst_notify);
+
+            ui.add_space(15.0);
+            ui.separator();
+            ui.add_space(10.0);
+
+            // Comparison table export
+            ui.label(tr("Export Table"));
+            ui.add_space(3.0);
+
+            ui.horizontal(|ui| {
+                for format in TableFormat::ALL {
+                    if ui.radio_value(table_format, format, format.label()).changed() {
+                        result.table_format_changed = true;
+                    }
+                }
+            });
+            ui.horizontal(|ui| {
+                if ui.button(tr("📋 Copy")).clicked() {
+                    ui.output_mut(|o| o.copied_text = comparison_table.render(*table_format));
+                    result.table_copied = true;
+                }
+                if ui.button(tr("Save File")).clicked() {
+                    result.save_table = true;
+                }
+            });
+
+            ui.label(
+                egui::RichText::new(tr("Times, offsets, DST and deltas at the shown instant"))
                     .size(10.0)
                     .color(egui::Color32::from_rgb(120, 125, 135)),
             );
 
+            ui.add_space(15.0);
+            ui.separator();
+            ui.add_space(10.0);
+
+            // Settings
+            ui.label(tr("Settings"));
+            ui.add_space(3.0);
+
+            result.reduced_motion_changed =
+                reduced_motion_settings(ui, motion_preference, system_reduced_motion);
+
+            ui.label(
+                egui::RichText::new(tr("Disables parallax and animations"))
+                    .size(10.0)
+                    .color(egui::Color32::from_rgb(120, 125, 135)),
+            );
+
+            ui.add_space(10.0);
+            result.quiet_hours_changed = quiet_hours_settings(ui, quiet_hours);
+            result.window_title_changed = window_title_settings(ui, window_title);
+            result.clip_settings_changed = clip_recording_settings(ui, clip_settings);
+            result.language_changed = language_settings(ui, language);
+            result.system_zone_changed = system_zone_settings(ui, follow_system_zone);
+            result.reveal_hotkey_changed =
+                reveal_hotkey_settings(ui, reveal_hotkey, reveal_status);
+            result.favorites_source_changed = favorites_settings(ui, own_favorites);
+            result.keymap_edit = keymap_settings(ui, keymap);
+
+            ui.add_space(10.0);
+            result.set_theme = theme_selector(ui, themes, theme);
+            result.safe_palette_changed = safe_palette_settings(ui, safe_palette);
+
             ui.add_space(20.0);
 
             // Zone count
             ui.label(
-                egui::RichText::new(format!("{} zones in superposition", zone_count))
+                egui::RichText::new(tr_args("{0} zones in superposition", &[&zone_count]))
                     .size(10.0)
                     .color(egui::Color32::from_rgb(100, 105, 115)),
             );
@@ -431,12 +866,225 @@ pub fn draw_collapse_controls(
     result
 }
 
+/// Opt-in toggle, lead time and desktop notification choice; true when edited
+fn dst_notify_settings(ui: &mut egui::Ui, settings: &mut DstNotifySettings) -> bool {
+    let hint = egui::Color32::from_rgb(120, 125, 135);
+    let mut changed = ui.checkbox(&mut settings.enabled, tr("Warn before DST changes")).changed();
+
+    ui.add_enabled_ui(settings.enabled, |ui| {
+        changed |= ui
+            .add(
+                egui::Slider::new(&mut settings.lead_hours, MIN_LEAD_HOURS..=MAX_LEAD_HOURS)
+                    .suffix(tr(" h ahead")),
+            )
+            .changed();
+        ui.add_enabled_ui(UNAVAILABLE_REASON.is_none(), |ui| {
+            changed |= ui
+                .checkbox(&mut settings.system_notifications, tr("Desktop notification"))
+                .changed();
+        });
+        if let Some(reason) = UNAVAILABLE_REASON {
+            ui.label(egui::RichText::new(tr(reason)).size(10.0).color(hint));
+        }
+    });
+
+    ui.label(
+        egui::RichText::new(tr("Any selected zone, at the real time"))
+            .size(10.0)
+            .color(hint),
+    );
+    changed
+}
+
+/// Draw the Meeting Planner panel (bottom of the core area)
+pub fn draw_meeting_planner(
+    ctx: &egui::Context,
+    selected_zones: &[Tz],
+    dominant_zone: Tz,
+    business_hours: &HashMap<Tz, BusinessHours>,
+    overlap_windows: &[OverlapWindow],
+) -> MeetingPlannerResult {
+    let mut result = MeetingPlannerResult::default();
+
+    egui::Window::new(tr("Meeting Planner"))
+        .id(egui::Id::new("meeting_planner"))
+        .collapsible(true)
+        .resizable(false)
+        .default_width(420.0)
+        .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -50.0])
+        .show(ctx, |ui| {
+            ui.label(
+                egui::RichText::new(tr("Business hours (local time)"))
+                    .size(11.0)
+                    .color(egui::Color32::from_rgb(160, 165, 175)),
+            );
+            ui.add_space(4.0);
+
+            egui::Grid::new("business_hours_grid")
+                .num_columns(4)
+                .spacing([8.0, 4.0])
+                .show(ui, |ui| {
+                    for &tz in selected_zones {
+                        let hours = hours_for(business_hours, tz);
+                        let mut start = hours.start_minute;
+                        let mut end = hours.end_minute;
+
+                        ui.label(short_zone_name(tz)).on_hover_text(hours.format());
+                        let start_changed = ui
+                            .add(minute_of_day_drag(&mut start))
+                            .on_hover_text(tr("Start of working day"))
+                            .changed();
+                        ui.label("–");
+                        let end_changed = ui
+                            .add(minute_of_day_drag(&mut end))
+                            .on_hover_text(tr("End of working day"))
+                            .changed();
+                        ui.end_row();
+
+                        if start_changed || end_changed {
+                            result.set_hours = Some((
+                                tz,
+                                BusinessHours {
+                                    start_minute: start,
+                                    end_minute: end,
+                                },
+                            ));
+                        }
+                    }
+                });
+
+            ui.add_space(8.0);
+            ui.separator();
+            ui.add_space(4.0);
+
+            // Overlap windows, in the dominant zone's wall time
+            ui.label(
+                egui::RichText::new(tr_args(
+                    "Shared windows (next 24h, {0})",
+                    &[&short_zone_name(dominant_zone)],
+                ))
+                .size(11.0)
+                .color(egui::Color32::from_rgb(160, 165, 175)),
+            );
+
+            if overlap_windows.is_empty() {
+                ui.colored_label(
+                    egui::Color32::from_rgb(255, 179, 71),
+                    tr("No time when every zone is working"),
+                );
+            } else {
+                let now = shared::now_utc();
+                for window in overlap_windows {
+                    let duration = window.duration_minutes();
+                    let text = format!(
+                        "{}  ({}h {:02}m)",
+                        window.format_in(dominant_zone),
+                        duration / 60,
+                        duration % 60
+                    );
+                    if window.contains(now) {
+                        ui.colored_label(egui::Color32::from_rgb(110, 220, 150), format!("◆ {}", text));
+                    } else {
+                        ui.label(text);
+                    }
+                }
+            }
+
+            ui.add_space(6.0);
+            if ui.button(tr("Close Planner")).clicked() {
+                result.close = true;
+            }
+        });
+
+    result
+}
+
+/// Draw the back of a flipped card centered on `center`: the zone's full
+/// name and country, its next DST transition and its offsets over the year
+pub fn draw_card_back(
+    ctx: &egui::Context,
+    details: &ZoneDetails,
+    name: &str,
+    center: egui::Pos2,
+) -> CardBackResult {
+    let mut result = CardBackResult::default();
+    let heading = egui::Color32::from_rgb(160, 165, 175);
+    let hint = egui::Color32::from_rgb(120, 125, 135);
+
+    egui::Area::new("card_back")
+        .fixed_pos(center)
+        .pivot(egui::Align2::CENTER_CENTER)
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style())
+                .inner_margin(egui::Margin::symmetric(14.0, 10.0))
+                .show(ui, |ui| {
+                    ui.set_max_width(320.0);
+                    ui.label(
+                        egui::RichText::new(name)
+                            .size(15.0)
+                            .color(egui::Color32::from_rgb(245, 240, 235)),
+                    );
+                    ui.label(&details.full_name);
+                    if let Some(country) = details.country {
+                        ui.label(egui::RichText::new(country).size(11.0).color(heading));
+                    }
+
+                    ui.add_space(6.0);
+                    ui.label(egui::RichText::new(tr("Next DST change")).size(11.0).color(heading));
+                    match &details.next_transition {
+                        Some(transition) => {
+                            ui.label(transition.describe());
+                            let last_before = tr_args("Last: {0}", &[&transition.last_before]);
+                            ui.label(egui::RichText::new(last_before).monospace().size(11.0));
+                            let first_after = tr_args("Then: {0}", &[&transition.first_after]);
+                            ui.label(egui::RichText::new(first_after).monospace().size(11.0));
+                        }
+                        None => {
+                            let none = tr("No change within a year");
+                            ui.label(egui::RichText::new(none).color(hint));
+                        }
+                    }
+
+                    ui.add_space(6.0);
+                    ui.label(
+                        egui::RichText::new(tr_args("Offsets in {0}", &[&details.year]))
+                            .size(11.0)
+                            .color(heading),
+                    );
+                    for period in &details.offset_history {
+                        ui.label(egui::RichText::new(period.describe()).monospace().size(11.0));
+                    }
+
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        if ui.button(tr("Make Dominant")).clicked() {
+                            result.make_dominant = true;
+                        }
+                        if ui.button(tr("Flip Back")).on_hover_text("Esc").clicked() {
+                            result.flip_back = true;
+                        }
+                    });
+                });
+        });
+
+    result
+}
+
+/// Drag value editing a minute-of-day in 15-minute steps, shown as wall time
+fn minute_of_day_drag(value: &mut u32) -> egui::DragValue<'_> {
+    egui::DragValue::new(value)
+        .clamp_range(0..=(24 * 60 - 15))
+        .speed(15.0)
+        .custom_formatter(|n, _| format_minute_of_day(n as u32))
+}
+
 /// Draw DST status section
 fn draw_dst_status(ui: &mut egui::Ui, time_data: &TimeData) {
     let status_text = if time_data.is_dst {
-        "Daylight Saving Time"
+        tr("Daylight Saving Time")
     } else {
-        "Standard Time"
+        tr("Standard Time")
     };
 
     let status_color = if time_data.is_dst {
@@ -447,7 +1095,7 @@ fn draw_dst_status(ui: &mut egui::Ui, time_data: &TimeData) {
 
     ui.horizontal(|ui| {
         ui.label(
-            egui::RichText::new("DST:")
+            egui::RichText::new(tr("DST:"))
                 .size(11.0)
                 .color(egui::Color32::from_rgb(140, 145, 155)),
         );
@@ -455,7 +1103,7 @@ fn draw_dst_status(ui: &mut egui::Ui, time_data: &TimeData) {
     });
 
     // Transition warning with hours remaining
-    let now = Utc::now();
+    let now = shared::now_utc();
     match &time_data.dst_change {
         DstChange::Upcoming {
             instant,
@@ -465,15 +1113,13 @@ fn draw_dst_status(ui: &mut egui::Ui, time_data: &TimeData) {
             let direction_sign = if *delta_minutes > 0 { "+" } else { "" };
             ui.colored_label(
                 egui::Color32::from_rgb(255, 107, 53),
-                format!(
-                    "⚠ DST shift in {}h ({}{}m)",
-                    hours_remaining,
-                    direction_sign,
-                    delta_minutes
+                tr_args(
+                    "⚠ DST shift in {0}h ({1}{2}m)",
+                    &[&hours_remaining, &direction_sign, delta_minutes],
                 ),
             );
             ui.label(
-                egui::RichText::new(format!("At {}", instant.format("%H:%M UTC")))
+                egui::RichText::new(tr_args("At {0}", &[&instant.format("%H:%M UTC")]))
                     .size(10.0)
                     .color(egui::Color32::from_rgb(140, 145, 155)),
             );
@@ -486,15 +1132,13 @@ fn draw_dst_status(ui: &mut egui::Ui, time_data: &TimeData) {
             let direction_sign = if *delta_minutes > 0 { "+" } else { "" };
             ui.colored_label(
                 egui::Color32::from_rgb(255, 179, 71),
-                format!(
-                    "DST shift {}h ago ({}{}m)",
-                    hours_ago,
-                    direction_sign,
-                    delta_minutes
+                tr_args(
+                    "DST shift {0}h ago ({1}{2}m)",
+                    &[&hours_ago, &direction_sign, delta_minutes],
                 ),
             );
             ui.label(
-                egui::RichText::new(format!("At {}", instant.format("%H:%M UTC")))
+                egui::RichText::new(tr_args("At {0}", &[&instant.format("%H:%M UTC")]))
                     .size(10.0)
                     .color(egui::Color32::from_rgb(140, 145, 155)),
             );
@@ -508,11 +1152,14 @@ fn draw_timezone_picker(
     ctx: &egui::Context,
     picker_state: &mut PickerState,
     selected_zones: &[Tz],
+    dominant_zone: Tz,
     favorites: &[Tz],
+    zone_sets: &[ZoneSet],
 ) -> PickerResult {
     let mut result = PickerResult::default();
 
-    egui::Window::new("Add Time Zone")
+    egui::Window::new(tr("Add Time Zone"))
+        .id(egui::Id::new("add_time_zone"))
         .collapsible(false)
         .resizable(true)
         .default_width(450.0)
@@ -521,7 +1168,7 @@ fn draw_timezone_picker(
         .show(ctx, |ui| {
             // Search field
             ui.horizontal(|ui| {
-                ui.label("Search:");
+                ui.label(tr("Search:"));
                 let response = ui.text_edit_singleline(&mut picker_state.search_query);
                 if picker_state.should_focus_search {
                     response.request_focus();
@@ -535,20 +1182,34 @@ fn draw_timezone_picker(
             ui.separator();
 
             // System timezone button
-            if ui.button("Use System Time Zone").clicked() {
-                if let Some(sys_tz) = shared::system_timezone() {
-                    if !selected_zones.contains(&sys_tz) {
-                        result.add_zone = Some(sys_tz);
-                        result.close_picker = true;
-                    }
-                }
+            if ui.button(tr("Use System Time Zone")).clicked() {
+                result.use_system_zone = true;
+                result.close_picker = true;
             }
 
             ui.separator();
 
+            // Zone sets section
+            if !zone_sets.is_empty() {
+                ui.label(tr("Zone Sets:"));
+                ui.horizontal_wrapped(|ui| {
+                    for (i, set) in zone_sets.iter().enumerate() {
+                        let is_active = set.matches(selected_zones, dominant_zone);
+                        let response = ui
+                            .selectable_label(is_active, format!("▤ {}", set.name))
+                            .on_hover_text(tr("Replace the selected zones with this set"));
+                        if response.clicked() && !is_active {
+                            result.switch_zone_set = Some(i);
+                            result.close_picker = true;
+                        }
+                    }
+                });
+                ui.separator();
+            }
+
             // Favorites section
             if !favorites.is_empty() {
-                ui.label("Favorites:");
+                ui.label(tr("Favorites:"));
                 ui.horizontal_wrapped(|ui| {
                     for &tz in favorites {
                         let already_selected = selected_zones.contains(&tz);
@@ -569,15 +1230,17 @@ fn draw_timezone_picker(
             }
 
             // Results list
-            ui.label(format!(
-                "{} time zones found",
-                picker_state.search_results.len()
-            ));
+            ui.label(tr_args("{0} time zones found", &[&picker_state.search_results.len()]));
 
             egui::ScrollArea::vertical()
                 .max_height(300.0)
                 .show(ui, |ui| {
+                    let mut previous = None;
                     for &tz in &picker_state.search_results {
+                        if let Some(region) = region_change(previous, tz) {
+                            region_heading(ui, region);
+                        }
+                        previous = Some(tz);
                         let already_selected = selected_zones.contains(&tz);
                         let is_favorite = favorites.contains(&tz);
 
@@ -590,9 +1253,9 @@ fn draw_timezone_picker(
 
                             // Timezone name
                             let label = if already_selected {
-                                format!("{} ✓", tz.name())
+                                format!("{} ✓", zone_display_name(tz))
                             } else {
-                                tz.name().to_string()
+                                zone_display_name(tz)
                             };
 
                             let response = ui.selectable_label(already_selected, &label);
@@ -607,7 +1270,7 @@ fn draw_timezone_picker(
             ui.separator();
 
             // Close button
-            if ui.button("Close").clicked() {
+            if ui.button(tr("Close")).clicked() {
                 result.close_picker = true;
             }
         });
@@ -624,8 +1287,61 @@ fn draw_timezone_picker(
 fn short_zone_name(tz: Tz) -> String {
     tz.name()
         .split('/')
-        .last()
+        .next_back()
         .unwrap_or(tz.name())
         .to_string()
 }
 
+
+/// Restyle egui's chrome for the active theme
+///
+/// Leaves `visuals` alone while the clock uses its own colors.
+pub fn theme_visuals(visuals: &mut egui::Visuals) {
+    let Some(palette) = active_palette() else {
+        return;
+    };
+    let color = |c: Rgb| egui::Color32::from_rgb(c.red, c.green, c.blue);
+    *visuals = if palette.is_light() {
+        egui::Visuals::light()
+    } else {
+        egui::Visuals::dark()
+    };
+    visuals.panel_fill = color(palette.background);
+    visuals.window_fill = color(palette.background);
+    visuals.override_text_color = Some(color(palette.primary_text));
+    visuals.hyperlink_color = color(palette.accent);
+    visuals.warn_fg_color = color(palette.warning);
+}
+
+/// Draw the theme switcher
+///
+/// Returns the newly chosen theme, where `Some(None)` means the clock's own
+/// colors.
+pub fn theme_selector(
+    ui: &mut egui::Ui,
+    themes: &ThemeLibrary,
+    selected: Option<&str>,
+) -> Option<Option<String>> {
+    let mut choice = None;
+    egui::ComboBox::from_label(tr("Theme"))
+        .selected_text(selected.unwrap_or(tr("Clock colors")))
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(selected.is_none(), tr("Clock colors")).clicked() {
+                choice = Some(None);
+            }
+            for theme in &themes.themes {
+                let is_selected = selected == Some(theme.name.as_str());
+                if ui.selectable_label(is_selected, &theme.name).clicked() {
+                    choice = Some(Some(theme.name.clone()));
+                }
+            }
+        });
+    if let Some(error) = &themes.error {
+        ui.label(
+            egui::RichText::new(error)
+                .size(10.0)
+                .color(egui::Color32::from_rgb(255, 100, 100)),
+        );
+    }
+    choice
+}
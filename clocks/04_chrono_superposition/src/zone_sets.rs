@@ -0,0 +1,148 @@
+//! Zone sets module - named workspaces of zones
+//!
+//! A zone set ("EU team", "family", "APAC launch") remembers a list of zones
+//! and which one is dominant. Switching to a set replaces the selected zones
+//! and the dominant zone in one step; the previous selection is kept for a
+//! few seconds so the switch can be undone.
+
+use std::time::{Duration, Instant};
+
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::OrderingStrategy;
+
+/// How long a switch can be undone, matching the toast that offers it
+pub const UNDO_WINDOW: Duration = Duration::from_secs(8);
+
+/// A named list of zones and its dominant zone
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZoneSet {
+    pub name: String,
+    pub zone_ids: Vec<String>,
+    pub dominant_zone_id: String,
+}
+
+impl ZoneSet {
+    /// A set holding `zones` with `dominant` on top
+    pub fn capture(name: &str, zones: &[Tz], dominant: Tz) -> Self {
+        Self {
+            name: name.trim().to_string(),
+            zone_ids: zones.iter().map(|tz| tz.name().to_string()).collect(),
+            dominant_zone_id: dominant.name().to_string(),
+        }
+    }
+
+    /// The set's zones and dominant zone; unparseable zone ids are dropped,
+    /// and a dominant zone that isn't in the set falls back to the first
+    ///
+    /// `None` if no zone in the set parses.
+    pub fn resolve(&self) -> Option<(Vec<Tz>, Tz)> {
+        let mut zones: Vec<Tz> = Vec::new();
+        for tz in self.zone_ids.iter().filter_map(|id| id.parse::<Tz>().ok()) {
+            if !zones.contains(&tz) {
+                zones.push(tz);
+            }
+        }
+        let first = *zones.first()?;
+        let dominant = self
+            .dominant_zone_id
+            .parse()
+            .ok()
+            .filter(|tz| zones.contains(tz))
+            .unwrap_or(first);
+        Some((zones, dominant))
+    }
+
+    /// Whether the set holds exactly `zones` with `dominant` on top
+    pub fn matches(&self, zones: &[Tz], dominant: Tz) -> bool {
+        self.resolve()
+            .is_some_and(|(set_zones, set_dominant)| set_zones == zones && set_dominant == dominant)
+    }
+}
+
+/// Add `set`, replacing any set with the same name (ignoring case)
+pub fn upsert(sets: &mut Vec<ZoneSet>, set: ZoneSet) {
+    match sets.iter_mut().find(|s| s.name.eq_ignore_ascii_case(&set.name)) {
+        Some(existing) => *existing = set,
+        None => sets.push(set),
+    }
+}
+
+/// The selection a switch replaced, until the undo window closes
+#[derive(Debug, Clone)]
+pub struct ZoneSetUndo {
+    pub zones: Vec<Tz>,
+    pub dominant: Tz,
+    pub ordering: OrderingStrategy,
+    expires: Instant,
+}
+
+impl ZoneSetUndo {
+    pub fn new(zones: Vec<Tz>, dominant: Tz, ordering: OrderingStrategy, now: Instant) -> Self {
+        Self {
+            zones,
+            dominant,
+            ordering,
+            expires: now + UNDO_WINDOW,
+        }
+    }
+
+    pub fn is_expired(&self, now: Instant) -> bool {
+        now >= self.expires
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_and_resolve() {
+        let zones: Vec<Tz> = vec![Tz::Europe__Berlin, Tz::Europe__London, Tz::Europe__Madrid];
+        let set = ZoneSet::capture("  EU team ", &zones, Tz::Europe__London);
+        assert_eq!(set.name, "EU team");
+        assert_eq!(set.resolve(), Some((zones.clone(), Tz::Europe__London)));
+        assert!(set.matches(&zones, Tz::Europe__London));
+        assert!(!set.matches(&zones, Tz::Europe__Berlin));
+
+        // Hand-edited config: a bad id and a dominant zone not in the set
+        let edited = ZoneSet {
+            name: "family".to_string(),
+            zone_ids: vec!["Not/AZone".to_string(), "Asia/Tokyo".to_string()],
+            dominant_zone_id: "America/New_York".to_string(),
+        };
+        assert_eq!(edited.resolve(), Some((vec![Tz::Asia__Tokyo], Tz::Asia__Tokyo)));
+
+        let empty = ZoneSet {
+            zone_ids: vec!["Not/AZone".to_string()],
+            ..edited
+        };
+        assert_eq!(empty.resolve(), None);
+    }
+
+    #[test]
+    fn test_upsert_replaces_by_name() {
+        let mut sets = Vec::new();
+        upsert(&mut sets, ZoneSet::capture("Family", &[Tz::Asia__Tokyo], Tz::Asia__Tokyo));
+        upsert(&mut sets, ZoneSet::capture("APAC launch", &[Tz::Asia__Seoul], Tz::Asia__Seoul));
+        upsert(&mut sets, ZoneSet::capture("family", &[Tz::Europe__Paris], Tz::Europe__Paris));
+
+        assert_eq!(sets.len(), 2);
+        assert_eq!(sets[0].name, "family");
+        assert_eq!(sets[0].dominant_zone_id, "Europe/Paris");
+    }
+
+    #[test]
+    fn test_undo_expires() {
+        let now = Instant::now();
+        let undo = ZoneSetUndo::new(
+            vec![Tz::UTC],
+            Tz::UTC,
+            OrderingStrategy::Automatic,
+            now,
+        );
+        assert!(!undo.is_expired(now + UNDO_WINDOW / 2));
+        assert!(undo.is_expired(now + UNDO_WINDOW));
+    }
+}
@@ -0,0 +1,231 @@
+//! Audio module - chimes for beats, minute boundaries and the hour
+//!
+//! Chime selection and settings are always compiled; sound output uses rodio
+//! and is only built with the `audio` feature. Without it (or without an
+//! output device) the engine is simply absent and the clock stays silent.
+
+use serde::{Deserialize, Serialize};
+use shared::TimeData;
+
+/// Which event a chime marks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChimeKind {
+    /// Every second (beat node pulse)
+    Beat,
+    /// Minute boundary (hour node shimmer)
+    Minute,
+    /// Top of the hour
+    Hour,
+}
+
+impl ChimeKind {
+    /// The event a newly reached second marks
+    pub fn at(time_data: &TimeData) -> Self {
+        match (time_data.minute, time_data.second) {
+            (0, 0) => ChimeKind::Hour,
+            (_, 0) => ChimeKind::Minute,
+            _ => ChimeKind::Beat,
+        }
+    }
+
+    /// Tone partials in Hz, played together
+    #[cfg_attr(not(feature = "audio"), allow(dead_code))]
+    pub fn partials(&self) -> &'static [f32] {
+        match self {
+            ChimeKind::Beat => &[1760.0],
+            ChimeKind::Minute => &[880.0, 1320.0],
+            ChimeKind::Hour => &[440.0, 660.0, 880.0, 1108.73],
+        }
+    }
+
+    /// Ring length in milliseconds
+    #[cfg_attr(not(feature = "audio"), allow(dead_code))]
+    pub fn duration_ms(&self) -> u64 {
+        match self {
+            ChimeKind::Beat => 40,
+            ChimeKind::Minute => 400,
+            ChimeKind::Hour => 1500,
+        }
+    }
+
+    /// Base loudness before the user's volume is applied
+    #[cfg_attr(not(feature = "audio"), allow(dead_code))]
+    pub fn gain(&self) -> f32 {
+        match self {
+            ChimeKind::Beat => 0.15,
+            ChimeKind::Minute => 0.3,
+            ChimeKind::Hour => 0.35,
+        }
+    }
+}
+
+/// A chime to play at a given volume
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(not(feature = "audio"), allow(dead_code))]
+pub struct Chime {
+    pub kind: ChimeKind,
+    pub volume: f32,
+}
+
+/// Persisted chime preferences
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AudioSettings {
+    pub muted: bool,
+    pub beat_volume: f32,
+    pub minute_volume: f32,
+    pub hour_volume: f32,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            muted: true,
+            beat_volume: 0.3,
+            minute_volume: 0.6,
+            hour_volume: 0.8,
+        }
+    }
+}
+
+impl AudioSettings {
+    pub fn volume_for(&self, kind: ChimeKind) -> f32 {
+        match kind {
+            ChimeKind::Beat => self.beat_volume,
+            ChimeKind::Minute => self.minute_volume,
+            ChimeKind::Hour => self.hour_volume,
+        }
+    }
+}
+
+/// Pick the chime for a newly reached second, if any
+///
+/// The per-second beat is skipped in reduced motion; minute and hour chimes
+/// still sound. Nothing plays while muted; the shared quiet hours are left
+/// to the caller, through `VisualIntensity::sounds`.
+pub fn select_chime(time_data: &TimeData, settings: &AudioSettings, reduced_motion: bool) -> Option<Chime> {
+    if settings.muted {
+        return None;
+    }
+
+    let kind = ChimeKind::at(time_data);
+    if kind == ChimeKind::Beat && reduced_motion {
+        return None;
+    }
+
+    let volume = settings.volume_for(kind);
+    if volume <= 0.0 {
+        return None;
+    }
+    Some(Chime { kind, volume })
+}
+
+#[cfg(feature = "audio")]
+mod output {
+    use std::time::Duration;
+
+    use rodio::source::{SineWave, Source};
+    use rodio::{OutputStream, OutputStreamHandle};
+
+    use super::Chime;
+
+    /// Shown when the engine could not be created
+    pub const UNAVAILABLE_REASON: &str = "No audio output device found";
+
+    /// Plays chimes on the default output device
+    pub struct ChimeEngine {
+        /// Keeps the output stream alive
+        _stream: OutputStream,
+        handle: OutputStreamHandle,
+    }
+
+    impl ChimeEngine {
+        pub fn new() -> Option<Self> {
+            let (stream, handle) = OutputStream::try_default().ok()?;
+            Some(Self {
+                _stream: stream,
+                handle,
+            })
+        }
+
+        /// Fire-and-forget: each partial rings out with a linear decay
+        pub fn play(&self, chime: Chime) {
+            let duration = Duration::from_millis(chime.kind.duration_ms());
+            let partials = chime.kind.partials();
+            let gain = chime.kind.gain() * chime.volume / partials.len() as f32;
+
+            for (i, &frequency) in partials.iter().enumerate() {
+                let mut tone = SineWave::new(frequency).take_duration(duration);
+                tone.set_filter_fadeout();
+                // Higher partials are quieter
+                let source = tone.amplify(gain / (1.0 + i as f32 * 0.5));
+                if let Err(e) = self.handle.play_raw(source) {
+                    eprintln!("Failed to play chime: {}", e);
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "audio"))]
+mod output {
+    use super::Chime;
+
+    /// Shown when the engine could not be created
+    pub const UNAVAILABLE_REASON: &str = "Built without audio (enable the `audio` feature)";
+
+    /// Silent stand-in when the `audio` feature is disabled
+    #[allow(dead_code)]
+    pub struct ChimeEngine;
+
+    impl ChimeEngine {
+        pub fn new() -> Option<Self> {
+            None
+        }
+
+        pub fn play(&self, _chime: Chime) {}
+    }
+}
+
+pub use output::{ChimeEngine, UNAVAILABLE_REASON};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Tz;
+    use shared::compute_time_data_at;
+
+    fn time_at(hour: u32, minute: u32, second: u32) -> TimeData {
+        let tz: Tz = "UTC".parse().unwrap();
+        compute_time_data_at(tz, Utc.with_ymd_and_hms(2025, 1, 15, hour, minute, second).unwrap())
+    }
+
+    fn unmuted() -> AudioSettings {
+        AudioSettings {
+            muted: false,
+            ..AudioSettings::default()
+        }
+    }
+
+    #[test]
+    fn test_select_chime_kinds() {
+        let settings = unmuted();
+        let kind = |h, m, s| select_chime(&time_at(h, m, s), &settings, false).map(|c| c.kind);
+
+        assert_eq!(kind(12, 0, 0), Some(ChimeKind::Hour));
+        assert_eq!(kind(12, 30, 0), Some(ChimeKind::Minute));
+        assert_eq!(kind(12, 30, 15), Some(ChimeKind::Beat));
+    }
+
+    #[test]
+    fn test_reduced_motion_and_mute() {
+        let settings = unmuted();
+        // Reduced motion drops the per-second beat but keeps minute chimes
+        assert_eq!(select_chime(&time_at(12, 30, 15), &settings, true), None);
+        assert!(select_chime(&time_at(12, 30, 0), &settings, true).is_some());
+
+        let muted = AudioSettings::default();
+        assert_eq!(select_chime(&time_at(12, 0, 0), &muted, false), None);
+    }
+}
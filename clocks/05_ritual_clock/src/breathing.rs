@@ -0,0 +1,281 @@
+//! Breathing module - paced breathing sessions on the beat ring
+//!
+//! A breathing session walks through a repeating pattern of phases (box
+//! breathing by default: 4s in, 4s hold, 4s out, 4s hold). Each phase lasts
+//! whole seconds and the session starts on a beat, so phase changes land on
+//! the clock's own second boundaries. The beat ring fills while breathing in,
+//! stays full while holding, and empties while breathing out.
+//!
+//! Finished sessions add to persisted statistics: how many, how long in
+//! total, the longest, and how many full cycles.
+
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+use shared::{tr, tr_args};
+
+/// Longest a single phase can be set to
+pub const MAX_PHASE_SECS: u32 = 12;
+/// Longest session target in minutes
+pub const MAX_SESSION_MINUTES: u32 = 60;
+
+/// Persisted breathing pattern and session length
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreathingSettings {
+    pub inhale_secs: u32,
+    /// Hold with full lungs
+    pub hold_in_secs: u32,
+    pub exhale_secs: u32,
+    /// Hold with empty lungs
+    pub hold_out_secs: u32,
+    /// End the session after this many minutes; 0 runs until stopped
+    pub session_minutes: u32,
+}
+
+impl Default for BreathingSettings {
+    fn default() -> Self {
+        Self {
+            inhale_secs: 4,
+            hold_in_secs: 4,
+            exhale_secs: 4,
+            hold_out_secs: 4,
+            session_minutes: 5,
+        }
+    }
+}
+
+impl BreathingSettings {
+    /// Phases in order with their lengths, skipping any set to zero
+    fn phases(&self) -> impl Iterator<Item = (BreathPhase, u32)> {
+        [
+            (BreathPhase::Inhale, self.inhale_secs),
+            (BreathPhase::HoldIn, self.hold_in_secs),
+            (BreathPhase::Exhale, self.exhale_secs),
+            (BreathPhase::HoldOut, self.hold_out_secs),
+        ]
+        .into_iter()
+        .filter(|&(_, secs)| secs > 0)
+    }
+
+    /// Seconds in one full cycle
+    pub fn cycle_secs(&self) -> u32 {
+        self.phases().map(|(_, secs)| secs).sum()
+    }
+
+    /// Where in the pattern a session is, `elapsed` seconds after it started
+    pub fn state_at(&self, elapsed: f32) -> BreathState {
+        let cycle_secs = self.cycle_secs();
+        if cycle_secs == 0 {
+            return BreathState {
+                phase: BreathPhase::HoldOut,
+                progress: 0.0,
+                secs_left: 0,
+                cycle: 0,
+            };
+        }
+
+        let elapsed = elapsed.max(0.0);
+        let cycle = (elapsed / cycle_secs as f32) as u32;
+        let mut into_cycle = elapsed - (cycle * cycle_secs) as f32;
+        for (phase, secs) in self.phases() {
+            let length = secs as f32;
+            if into_cycle < length {
+                return BreathState {
+                    phase,
+                    progress: into_cycle / length,
+                    secs_left: (length - into_cycle).ceil() as u32,
+                    cycle,
+                };
+            }
+            into_cycle -= length;
+        }
+        // Float rounding at the very end of a cycle
+        BreathState {
+            phase: BreathPhase::Inhale,
+            progress: 0.0,
+            secs_left: self.inhale_secs,
+            cycle: cycle + 1,
+        }
+    }
+}
+
+/// One part of a breathing cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreathPhase {
+    Inhale,
+    HoldIn,
+    Exhale,
+    HoldOut,
+}
+
+impl BreathPhase {
+    pub fn label(self) -> &'static str {
+        match self {
+            BreathPhase::Inhale => "Breathe in",
+            BreathPhase::HoldIn | BreathPhase::HoldOut => "Hold",
+            BreathPhase::Exhale => "Breathe out",
+        }
+    }
+}
+
+/// Position within the breathing pattern
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BreathState {
+    pub phase: BreathPhase,
+    /// How far through the phase, 0 to 1
+    pub progress: f32,
+    /// Whole seconds left in the phase, for the countdown
+    pub secs_left: u32,
+    /// Full cycles completed before this one
+    pub cycle: u32,
+}
+
+impl BreathState {
+    /// How full the beat ring is, 0 (empty) to 1 (all 60 nodes lit)
+    pub fn fill(&self) -> f32 {
+        match self.phase {
+            BreathPhase::Inhale => self.progress,
+            BreathPhase::HoldIn => 1.0,
+            BreathPhase::Exhale => 1.0 - self.progress,
+            BreathPhase::HoldOut => 0.0,
+        }
+    }
+}
+
+/// A breathing session in progress
+#[derive(Debug, Clone, Copy)]
+pub struct BreathingSession {
+    started: Instant,
+    settings: BreathingSettings,
+}
+
+impl BreathingSession {
+    /// Start a session at `beat`, the instant of the latest second boundary
+    pub fn start(beat: Instant, settings: BreathingSettings) -> Self {
+        Self {
+            started: beat,
+            settings,
+        }
+    }
+
+    /// Seconds since the session started
+    pub fn elapsed_secs(&self, now: Instant) -> f32 {
+        now.saturating_duration_since(self.started).as_secs_f32()
+    }
+
+    pub fn state(&self, now: Instant) -> BreathState {
+        self.settings.state_at(self.elapsed_secs(now))
+    }
+
+    /// Whether the session has reached its target length
+    pub fn is_complete(&self, now: Instant) -> bool {
+        self.settings.session_minutes > 0
+            && self.elapsed_secs(now) >= (self.settings.session_minutes * 60) as f32
+    }
+}
+
+/// Persisted totals across finished sessions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreathingStats {
+    pub sessions: u32,
+    pub total_secs: u32,
+    pub longest_secs: u32,
+    /// Full breathing cycles across all sessions
+    pub cycles: u32,
+}
+
+impl BreathingStats {
+    /// Add a finished session
+    pub fn record(&mut self, secs: u32, cycles: u32) {
+        self.sessions += 1;
+        self.total_secs += secs;
+        self.longest_secs = self.longest_secs.max(secs);
+        self.cycles += cycles;
+    }
+
+    /// One-line summary for the conductor panel
+    pub fn summary(&self) -> String {
+        if self.sessions == 0 {
+            return tr("No sessions yet").to_string();
+        }
+        tr_args(
+            "{0} sessions · {1} total · longest {2} · {3} cycles",
+            &[
+                &self.sessions,
+                &format_duration(self.total_secs),
+                &format_duration(self.longest_secs),
+                &self.cycles,
+            ],
+        )
+    }
+}
+
+/// Format seconds as "4m 05s", or "1h 02m" past an hour
+pub fn format_duration(secs: u32) -> String {
+    if secs >= 3600 {
+        format!("{}h {:02}m", secs / 3600, secs / 60 % 60)
+    } else {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_box_breathing_phases() {
+        let settings = BreathingSettings::default();
+        assert_eq!(settings.cycle_secs(), 16);
+
+        let start = settings.state_at(0.0);
+        assert_eq!(start.phase, BreathPhase::Inhale);
+        assert_eq!(start.secs_left, 4);
+        assert_eq!(start.fill(), 0.0);
+
+        let held = settings.state_at(5.0);
+        assert_eq!(held.phase, BreathPhase::HoldIn);
+        assert_eq!(held.fill(), 1.0);
+
+        let out = settings.state_at(10.0);
+        assert_eq!(out.phase, BreathPhase::Exhale);
+        assert_eq!(out.fill(), 0.5);
+        assert_eq!(out.secs_left, 2);
+
+        let next = settings.state_at(33.0);
+        assert_eq!((next.phase, next.cycle), (BreathPhase::Inhale, 2));
+    }
+
+    #[test]
+    fn test_zero_length_phases_are_skipped() {
+        let settings = BreathingSettings {
+            inhale_secs: 4,
+            hold_in_secs: 0,
+            exhale_secs: 6,
+            hold_out_secs: 0,
+            session_minutes: 0,
+        };
+        assert_eq!(settings.cycle_secs(), 10);
+        assert_eq!(settings.state_at(4.0).phase, BreathPhase::Exhale);
+        assert_eq!(settings.state_at(10.0).phase, BreathPhase::Inhale);
+
+        let session = BreathingSession::start(Instant::now(), settings);
+        assert!(!session.is_complete(Instant::now()));
+    }
+
+    #[test]
+    fn test_stats_record_sessions() {
+        let mut stats = BreathingStats::default();
+        assert_eq!(stats.summary(), "No sessions yet");
+        stats.record(300, 18);
+        stats.record(125, 7);
+        assert_eq!(stats.longest_secs, 300);
+        assert_eq!(
+            stats.summary(),
+            "2 sessions · 7m 05s total · longest 5m 00s · 25 cycles"
+        );
+        assert_eq!(format_duration(3720), "1h 02m");
+    }
+}
@@ -0,0 +1,239 @@
+//! Brush module - how gesture trails are painted
+//!
+//! A brush pairs a trail style with the parameters that shape it: an ink
+//! ribbon thinning as it fades, a comet with a glowing head, a stipple of
+//! scattered dots, or a calligraphic nib that thins on fast strokes. Styles
+//! draw different amounts per trail point, so each paints at most its own
+//! number of the newest points. In reduced motion the comet falls back to an
+//! ink ribbon and the stipple's dots stay on the path.
+//!
+//! Everything here is geometry; `drawing.rs` does the painting.
+
+use nannou::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Range of the brush width slider, as a multiple of the stage's trail width
+pub const MIN_WIDTH: f32 = 0.5;
+pub const MAX_WIDTH: f32 = 2.5;
+
+/// Seconds a live trail point lasts
+pub const TRAIL_LIFETIME: f32 = 2.0;
+
+/// Most stipple dots painted in one frame
+pub const MAX_DOTS: usize = 480;
+
+/// Trail speed (px/s) at which the calligraphic nib is half as thin as it gets
+const NIB_HALF_SPEED: f32 = 900.0;
+/// Thinnest the nib gets, as a fraction of its width
+const MIN_NIB: f32 = 0.2;
+
+/// Trail rendering style
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TrailStyle {
+    #[default]
+    InkRibbon,
+    Comet,
+    Stipple,
+    Calligraphic,
+}
+
+impl TrailStyle {
+    pub const ALL: [TrailStyle; 4] = [
+        TrailStyle::InkRibbon,
+        TrailStyle::Comet,
+        TrailStyle::Stipple,
+        TrailStyle::Calligraphic,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TrailStyle::InkRibbon => "Ink ribbon",
+            TrailStyle::Comet => "Comet",
+            TrailStyle::Stipple => "Stipple",
+            TrailStyle::Calligraphic => "Calligraphic",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            TrailStyle::InkRibbon => "A ribbon thinning as it fades",
+            TrailStyle::Comet => "Widens toward a glowing head",
+            TrailStyle::Stipple => "Dots scattered along the path",
+            TrailStyle::Calligraphic => "A broad nib, thinner on fast strokes",
+        }
+    }
+
+    /// Most trail points painted in this style; the comet draws a glow under
+    /// each segment and the stipple several dots
+    pub fn point_cap(self) -> usize {
+        match self {
+            TrailStyle::InkRibbon => 256,
+            TrailStyle::Calligraphic => 192,
+            TrailStyle::Comet => 128,
+            TrailStyle::Stipple => 96,
+        }
+    }
+}
+
+/// Persisted trail brush preferences
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Brush {
+    pub style: TrailStyle,
+    /// Multiple of the stage's trail width
+    pub width: f32,
+    /// How much speed thins the calligraphic nib (0..1)
+    pub speed_response: f32,
+    /// How far stipple dots stray from the path, in dot widths (0..1)
+    pub scatter: f32,
+}
+
+impl Default for Brush {
+    fn default() -> Self {
+        Self {
+            style: TrailStyle::InkRibbon,
+            width: 1.0,
+            speed_response: 0.6,
+            scatter: 0.5,
+        }
+    }
+}
+
+impl Brush {
+    /// The brush actually painted: in reduced motion the comet becomes an ink
+    /// ribbon and stipple dots stop scattering
+    pub fn effective(self, reduced_motion: bool) -> Self {
+        if !reduced_motion {
+            return self;
+        }
+        let style = match self.style {
+            TrailStyle::Comet => TrailStyle::InkRibbon,
+            style => style,
+        };
+        Self {
+            style,
+            scatter: 0.0,
+            ..self
+        }
+    }
+
+    /// The newest points this brush's style paints
+    pub fn capped<'a, T>(&self, points: &'a [T]) -> &'a [T] {
+        let cap = self.style.point_cap();
+        &points[points.len().saturating_sub(cap)..]
+    }
+
+    /// Nib width for a segment drawn at `speed` px/s
+    pub fn nib_width(&self, base: f32, speed: f32) -> f32 {
+        let thinning = speed.max(0.0) / (speed.max(0.0) + NIB_HALF_SPEED);
+        base * (1.0 - self.speed_response.clamp(0.0, 1.0) * thinning).max(MIN_NIB)
+    }
+
+    /// Dot centers along `from`..`to`, about a dot width apart and pushed
+    /// off the path by the scatter; a segment's dots stay put between frames
+    pub fn stipple_dots(&self, from: Vec2, to: Vec2, dot_width: f32) -> Vec<Vec2> {
+        let delta = to - from;
+        let length = delta.length();
+        let spacing = dot_width.max(1.0) * 1.5;
+        let count = (length / spacing).ceil().max(1.0) as usize;
+        let normal = delta.perp().normalize_or_zero();
+        let seed = from.x.to_bits() ^ from.y.to_bits().rotate_left(16);
+        (0..count)
+            .map(|i| {
+                let offset = (unit_noise(seed.wrapping_add(i as u32)) - 0.5) * 2.0;
+                from + delta * (i as f32 / count as f32)
+                    + normal * offset * self.scatter * dot_width * 2.0
+            })
+            .collect()
+    }
+}
+
+/// Fade (0..1) of a trail point `age` seconds old, with squared falloff
+pub fn fade(age: f32, lifetime: f32) -> f32 {
+    (1.0 - age / lifetime).clamp(0.0, 1.0).powi(2)
+}
+
+/// Comet width at `t` along the trail (0 at the tail, 1 at the head)
+pub fn comet_width(base: f32, t: f32) -> f32 {
+    base * (0.15 + 0.85 * t.clamp(0.0, 1.0).powf(1.5))
+}
+
+/// Hash `n` to a value in 0..1
+fn unit_noise(n: u32) -> f32 {
+    let mut x = n.wrapping_mul(0x9E37_79B9);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x85EB_CA6B);
+    x ^= x >> 13;
+    (x & 0xFFFF) as f32 / 65535.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_styles_cap_painted_points() {
+        let points: Vec<usize> = (0..300).collect();
+        for style in TrailStyle::ALL {
+            let brush = Brush { style, ..Brush::default() };
+            let capped = brush.capped(&points);
+            assert_eq!(capped.len(), style.point_cap());
+            // The newest points are the ones kept
+            assert_eq!(capped.last(), Some(&299));
+        }
+        assert_eq!(Brush::default().capped(&points[..10]).len(), 10);
+    }
+
+    #[test]
+    fn test_reduced_motion_fallbacks() {
+        let comet = Brush {
+            style: TrailStyle::Comet,
+            ..Brush::default()
+        };
+        assert_eq!(comet.effective(false), comet);
+        assert_eq!(comet.effective(true).style, TrailStyle::InkRibbon);
+
+        let stipple = Brush {
+            style: TrailStyle::Stipple,
+            scatter: 1.0,
+            ..Brush::default()
+        };
+        let calm = stipple.effective(true);
+        assert_eq!(calm.style, TrailStyle::Stipple);
+        // Unscattered dots sit on the path
+        for dot in calm.stipple_dots(vec2(0.0, 0.0), vec2(100.0, 0.0), 4.0) {
+            assert_eq!(dot.y, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_nib_thins_with_speed() {
+        let brush = Brush::default();
+        let still = brush.nib_width(10.0, 0.0);
+        let slow = brush.nib_width(10.0, 200.0);
+        let fast = brush.nib_width(10.0, 3000.0);
+        assert_eq!(still, 10.0);
+        assert!(slow > fast);
+        assert!(fast >= 10.0 * MIN_NIB);
+
+        let rigid = Brush {
+            speed_response: 0.0,
+            ..Brush::default()
+        };
+        assert_eq!(rigid.nib_width(10.0, 3000.0), 10.0);
+    }
+
+    #[test]
+    fn test_stipple_dots_are_stable_and_scattered_within_bounds() {
+        let brush = Brush {
+            scatter: 1.0,
+            ..Brush::default()
+        };
+        let (from, to) = (vec2(10.0, 20.0), vec2(70.0, 20.0));
+        let dots = brush.stipple_dots(from, to, 4.0);
+        assert_eq!(dots, brush.stipple_dots(from, to, 4.0));
+        assert_eq!(dots.len(), 10);
+        assert!(dots.iter().all(|dot| (dot.y - 20.0).abs() <= 8.0));
+        assert!(dots.iter().any(|dot| dot.y != 20.0));
+    }
+}
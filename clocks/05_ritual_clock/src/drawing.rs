@@ -1,12 +1,20 @@
 //! Drawing module for the Ritual Clock
 //!
-//! Handles rendering of nodes, animations, trails, and the digital overlay.
+//! Handles rendering of nodes, animations, trails, session replays, the
+//! breathing guide, the duel scoreboard, hour lore, and the digital overlay.
 
 use std::time::Instant;
 
 use nannou::prelude::*;
-use shared::{DstChange, TimeData};
-
+use shared::{tr, tr_args, DstChange, TimeData};
+
+use crate::breathing::BreathState;
+use crate::brush::{comet_width, fade, Brush, TrailStyle, MAX_DOTS, TRAIL_LIFETIME};
+use crate::duel::{ConductorScore, Duel};
+use crate::lore::{HourLore, LoreBook};
+use crate::particles::Particle;
+use crate::pendulum::{Bob, REST_AMPLITUDE};
+use crate::session::{Replay, REPLAY_TRAIL_MS};
 use crate::stage::StageGeometry;
 use crate::Model;
 
@@ -14,6 +22,7 @@ use crate::Model;
 #[allow(dead_code)]
 pub mod colors {
     use nannou::prelude::*;
+    use shared::{Rgb, ThemeRole};
 
     /// Deep background
     pub const BACKGROUND: Srgb<u8> = Srgb {
@@ -71,6 +80,38 @@ pub mod colors {
         standard: std::marker::PhantomData,
     };
 
+    /// Replayed trail and highlight color
+    pub const REPLAY: Srgb<u8> = Srgb {
+        red: 255,
+        green: 140,
+        blue: 200,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Beat nodes lit by the breath
+    pub const BREATH: Srgb<u8> = Srgb {
+        red: 120,
+        green: 220,
+        blue: 190,
+        standard: std::marker::PhantomData,
+    };
+
+    /// The other conductor's score in a duel
+    pub const OPPONENT: Srgb<u8> = Srgb {
+        red: 255,
+        green: 190,
+        blue: 110,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Recording indicator color
+    pub const RECORDING: Srgb<u8> = Srgb {
+        red: 255,
+        green: 80,
+        blue: 80,
+        standard: std::marker::PhantomData,
+    };
+
     /// Overlay background (use overlay_bg() function)
     pub fn overlay_bg() -> Srgba<u8> {
         srgba(20, 25, 35, 230)
@@ -112,6 +153,48 @@ pub mod colors {
         blue: 255,
         standard: std::marker::PhantomData,
     };
+
+    /// Look up one of this clock's role colors in the active theme
+    fn themed(role: ThemeRole, native: Srgb<u8>) -> Srgb<u8> {
+        let color = shared::themed(role, Rgb::new(native.red, native.green, native.blue));
+        Srgb::new(color.red, color.green, color.blue)
+    }
+
+    pub fn background() -> Srgb<u8> {
+        themed(ThemeRole::Background, BACKGROUND)
+    }
+
+    pub fn text_primary() -> Srgb<u8> {
+        themed(ThemeRole::PrimaryText, TEXT_PRIMARY)
+    }
+
+    pub fn text_secondary() -> Srgb<u8> {
+        themed(ThemeRole::SecondaryText, TEXT_SECONDARY)
+    }
+
+    pub fn hour_node_active() -> Srgb<u8> {
+        themed(ThemeRole::Accent, HOUR_NODE_ACTIVE)
+    }
+
+    pub fn dst_warning() -> Srgb<u8> {
+        themed(ThemeRole::Warning, DST_WARNING)
+    }
+
+    pub fn breath() -> Srgb<u8> {
+        themed(ThemeRole::Accent, BREATH)
+    }
+
+    pub fn trail() -> Srgb<u8> {
+        themed(ThemeRole::Accent, TRAIL)
+    }
+
+    pub fn opponent() -> Srgb<u8> {
+        themed(ThemeRole::Warning, OPPONENT)
+    }
+
+    pub fn recording() -> Srgb<u8> {
+        themed(ThemeRole::Warning, RECORDING)
+    }
 }
 
 /// Draw the entire stage (nodes, trails, animations)
@@ -121,21 +204,61 @@ pub fn draw_stage(draw: &Draw, geometry: &StageGeometry, model: &Model) {
     // Calculate retune rotation if active
     let retune_rotation = calculate_retune_rotation(model, now);
 
-    // Draw beat nodes (outer ring)
-    draw_beat_nodes(draw, geometry, model, now, retune_rotation);
+    // Draw the pendulums behind everything else
+    if model.pendulum_settings.enabled {
+        draw_pendulums(draw, geometry, model);
+    }
+
+    // Draw beat nodes (outer ring), or the breath filling them
+    let breath = model.breathing.map(|session| session.state(now));
+    match breath {
+        Some(breath) => draw_breath_nodes(draw, geometry, breath),
+        None => draw_beat_nodes(draw, geometry, model, now, retune_rotation),
+    }
+
+    // Draw beat particle bursts
+    if model.particles_active() {
+        draw_particles(draw, model.particles.particles());
+    }
 
-    // Draw hour nodes (inner ring)
+    // Draw hour nodes (inner ring) and their sigils
     draw_hour_nodes(draw, geometry, model, now);
+    draw_hour_sigils(draw, geometry, &model.lore);
 
     // Draw gesture trails
     if model.should_draw_trails() {
         draw_trails(draw, geometry, model, now);
     }
 
+    // Draw the session replay over the live stage
+    if let Some(replay) = &model.replay {
+        draw_replay(draw, geometry, replay, model.gesture_sensitivity, model.brush);
+    }
+
+    // Draw both conductors' scores in a duel, otherwise the live sync
+    // score while the trail has a rhythm
+    if let Some(duel) = &model.duel {
+        draw_duel(draw, geometry, model, duel, now);
+    } else if model.gesture_reading.is_some() {
+        draw_sync_score(draw, geometry, model);
+    }
+
+    // Name the breathing phase above the overlay
+    if let Some(breath) = breath {
+        draw_breath_label(draw, geometry, breath);
+    }
+
     // Draw DST ghost beat if upcoming
     if matches!(model.time_data.dst_change, DstChange::Upcoming { .. }) {
         draw_ghost_beat(draw, geometry, model, now);
     }
+
+    // Name the hovered hour's ritual, or else the highlighted one's
+    if let Some(hour) = model.hovered_hour.or(model.highlighted_hour) {
+        if let Some(lore) = model.lore.hour(hour) {
+            draw_lore_tag(draw, geometry, hour, lore);
+        }
+    }
 }
 
 /// Calculate retune rotation angle
@@ -204,6 +327,39 @@ fn draw_beat_nodes(
     }
 }
 
+/// Draw the beat ring as a breath gauge: nodes light clockwise from the top
+/// while breathing in and go dark again while breathing out
+fn draw_breath_nodes(draw: &Draw, geometry: &StageGeometry, breath: BreathState) {
+    let lit = (breath.fill() * 60.0).round() as usize;
+    for (j, &(bx, by)) in geometry.beat_positions.iter().enumerate() {
+        let (radius, color) = if j < lit {
+            (geometry.beat_node_radius * 1.4, colors::BREATH)
+        } else {
+            (geometry.beat_node_radius, colors::BEAT_NODE)
+        };
+        draw.ellipse()
+            .x_y(bx, by)
+            .radius(radius)
+            .color(color);
+    }
+}
+
+/// Draw the breathing phase and its countdown above the center overlay
+fn draw_breath_label(draw: &Draw, geometry: &StageGeometry, breath: BreathState) {
+    let y = geometry.cy + geometry.r_hour * 0.6;
+
+    draw.text(tr(breath.phase.label()))
+        .x_y(geometry.cx, y)
+        .color(colors::breath())
+        .font_size(18)
+        .w(200.0);
+    draw.text(&tr_args("{0} · cycle {1}", &[&breath.secs_left, &(breath.cycle + 1)]))
+        .x_y(geometry.cx, y - 20.0)
+        .color(colors::text_secondary())
+        .font_size(11)
+        .w(200.0);
+}
+
 /// Calculate beat pulse scale, color, and ring outline flag
 /// Returns (scale, color, ring_outline)
 fn calculate_beat_pulse(model: &Model, beat_index: usize, now: Instant) -> (f32, Srgb<u8>, bool) {
@@ -212,14 +368,16 @@ fn calculate_beat_pulse(model: &Model, beat_index: usize, now: Instant) -> (f32,
             let elapsed_ms = now.duration_since(start).as_secs_f32() * 1000.0;
 
             if elapsed_ms < 360.0 {
-                if model.reduced_motion {
+                if model.motion_reduced() {
                     // Reduced motion: ring outline for 200ms (no scaling)
                     if elapsed_ms < 200.0 {
                         return (1.0, colors::BEAT_NODE, true); // Ring outline enabled
                     }
                 } else {
-                    // Normal animation
-                    let scale = if elapsed_ms < 120.0 {
+                    // Normal animation; particle bursts replace the swell
+                    let scale = if model.particles_active() {
+                        1.0
+                    } else if elapsed_ms < 120.0 {
                         // Phase 1: 0-120ms, scale 1.0 → 1.8
                         let t = elapsed_ms / 120.0;
                         1.0 + 0.8 * t
@@ -245,6 +403,72 @@ fn calculate_beat_pulse(model: &Model, beat_index: usize, now: Instant) -> (f32,
     (1.0, colors::BEAT_NODE, false)
 }
 
+/// Draw the pendulums hanging from above the beat ring
+///
+/// In reduced motion they hang still at the end of the swing the current
+/// second sits on, with the arc drawn faintly so the beat still reads.
+fn draw_pendulums(draw: &Draw, geometry: &StageGeometry, model: &Model) {
+    let rod = geometry.r_beat * 1.6;
+    let spacing = geometry.r_beat * 0.35;
+    let pivot_y = geometry.cy + geometry.r_beat * 0.9;
+    let bob_radius = geometry.hour_node_radius * 0.8;
+    let color = colors::BEAT_NODE;
+
+    let bobs: Vec<Bob> = if model.pendulum_active() {
+        model.pendulum.bobs().to_vec()
+    } else {
+        // Even seconds sit at the right end of the swing, odd at the left
+        let side = if model.time_data.second.is_multiple_of(2) { 1.0 } else { -1.0 };
+        let count = model.pendulum_settings.count.max(1);
+        vec![
+            Bob {
+                angle: side * REST_AMPLITUDE,
+                velocity: 0.0,
+            };
+            count
+        ]
+    };
+
+    let middle = (bobs.len() as f32 - 1.0) / 2.0;
+    for (index, bob) in bobs.iter().enumerate() {
+        let pivot = pt2(geometry.cx + (index as f32 - middle) * spacing, pivot_y);
+        let end = pivot + vec2(bob.angle.sin(), -bob.angle.cos()) * rod;
+
+        if !model.pendulum_active() {
+            let arc = (0..=24).map(|step| {
+                let angle = -REST_AMPLITUDE + 2.0 * REST_AMPLITUDE * step as f32 / 24.0;
+                pivot + vec2(angle.sin(), -angle.cos()) * rod
+            });
+            draw.polyline()
+                .weight(1.0)
+                .points(arc)
+                .color(srgba(color.red, color.green, color.blue, 40));
+        }
+
+        draw.line()
+            .start(pivot)
+            .end(end)
+            .weight(1.5)
+            .color(srgba(color.red, color.green, color.blue, 60));
+        draw.ellipse()
+            .xy(end)
+            .radius(bob_radius)
+            .color(srgba(color.red, color.green, color.blue, 90));
+    }
+}
+
+/// Draw beat particles, fading and shrinking as they age
+fn draw_particles(draw: &Draw, particles: &[Particle]) {
+    let pulse = colors::BEAT_NODE_PULSE;
+    for particle in particles {
+        let life = particle.life_left();
+        draw.ellipse()
+            .xy(particle.position)
+            .radius(particle.size * (0.4 + 0.6 * life))
+            .color(srgba(pulse.red, pulse.green, pulse.blue, (life * 220.0) as u8));
+    }
+}
+
 /// Draw hour nodes with shimmer animation
 fn draw_hour_nodes(draw: &Draw, geometry: &StageGeometry, model: &Model, now: Instant) {
     // Calculate minuteIntensity = minute / 59 for gradual buildup
@@ -257,9 +481,9 @@ fn draw_hour_nodes(draw: &Draw, geometry: &StageGeometry, model: &Model, now: In
         // Base color with minute intensity applied to current hour
         let mut color = if i == current_hour_index {
             // Gradually brighten current hour as minutes progress
-            let r = lerp_u8(colors::HOUR_NODE.red, colors::HOUR_NODE_ACTIVE.red, minute_intensity * 0.3);
-            let g = lerp_u8(colors::HOUR_NODE.green, colors::HOUR_NODE_ACTIVE.green, minute_intensity * 0.3);
-            let b = lerp_u8(colors::HOUR_NODE.blue, colors::HOUR_NODE_ACTIVE.blue, minute_intensity * 0.3);
+            let r = lerp_u8(colors::HOUR_NODE.red, colors::hour_node_active().red, minute_intensity * 0.3);
+            let g = lerp_u8(colors::HOUR_NODE.green, colors::hour_node_active().green, minute_intensity * 0.3);
+            let b = lerp_u8(colors::HOUR_NODE.blue, colors::hour_node_active().blue, minute_intensity * 0.3);
             Srgb::new(r, g, b)
         } else {
             colors::HOUR_NODE
@@ -277,10 +501,10 @@ fn draw_hour_nodes(draw: &Draw, geometry: &StageGeometry, model: &Model, now: In
                 let elapsed_ms = now.duration_since(start).as_secs_f32() * 1000.0;
 
                 if elapsed_ms < 600.0 {
-                    if model.reduced_motion {
+                    if model.motion_reduced() {
                         // Reduced motion: static highlight for 400ms
                         if elapsed_ms < 400.0 {
-                            color = colors::HOUR_NODE_ACTIVE;
+                            color = colors::hour_node_active();
                         }
                     } else {
                         // Normal: opacity 0 → 1 → 0 ease-in-out
@@ -294,7 +518,7 @@ fn draw_hour_nodes(draw: &Draw, geometry: &StageGeometry, model: &Model, now: In
                         };
                         // We'll draw an overlay with this alpha
                         extra_alpha = Some(1.0 - alpha);
-                        color = colors::HOUR_NODE_ACTIVE;
+                        color = colors::hour_node_active();
                     }
                 }
             }
@@ -310,9 +534,9 @@ fn draw_hour_nodes(draw: &Draw, geometry: &StageGeometry, model: &Model, now: In
                     .x_y(hx, hy)
                     .radius(geometry.hour_node_radius * 1.3)
                     .color(srgba(
-                        colors::HOUR_NODE_ACTIVE.red,
-                        colors::HOUR_NODE_ACTIVE.green,
-                        colors::HOUR_NODE_ACTIVE.blue,
+                        colors::hour_node_active().red,
+                        colors::hour_node_active().green,
+                        colors::hour_node_active().blue,
                         (echo_alpha * 255.0) as u8,
                     ));
             }
@@ -330,9 +554,9 @@ fn draw_hour_nodes(draw: &Draw, geometry: &StageGeometry, model: &Model, now: In
                 .x_y(hx, hy)
                 .radius(geometry.hour_node_radius * 1.2)
                 .color(srgba(
-                    colors::HOUR_NODE_ACTIVE.red,
-                    colors::HOUR_NODE_ACTIVE.green,
-                    colors::HOUR_NODE_ACTIVE.blue,
+                    colors::hour_node_active().red,
+                    colors::hour_node_active().green,
+                    colors::hour_node_active().blue,
                     ((1.0 - alpha) * 150.0) as u8,
                 ));
         }
@@ -349,51 +573,334 @@ fn draw_hour_nodes(draw: &Draw, geometry: &StageGeometry, model: &Model, now: In
     }
 }
 
+/// Unit vector from the stage center out through an hour node
+fn outward(geometry: &StageGeometry, hour: usize) -> Vec2 {
+    let (hx, hy) = geometry.hour_positions[hour];
+    let direction = vec2(hx - geometry.cx, hy - geometry.cy);
+    if direction.length() > f32::EPSILON {
+        direction.normalize()
+    } else {
+        vec2(0.0, 1.0)
+    }
+}
+
+/// Draw each hour's sigil just outside its node, a constellation of the
+/// day's rituals
+fn draw_hour_sigils(draw: &Draw, geometry: &StageGeometry, lore: &LoreBook) {
+    let font_size = (geometry.hour_node_radius * 0.9).max(10.0) as u32;
+    for hour in 0..12 {
+        let Some(sigil) = lore.hour(hour).map(|lore| lore.sigil.trim()) else {
+            continue;
+        };
+        if sigil.is_empty() {
+            continue;
+        }
+        let (hx, hy) = geometry.hour_positions[hour];
+        let at = pt2(hx, hy) + outward(geometry, hour) * geometry.hour_node_radius * 2.2;
+        draw.text(sigil)
+            .xy(at)
+            .color(colors::hour_node_active())
+            .font_size(font_size)
+            .w(60.0);
+    }
+}
+
+/// Draw an hour's label and note in a tag beside its node, on the side
+/// away from the stage center
+fn draw_lore_tag(draw: &Draw, geometry: &StageGeometry, hour: usize, lore: &HourLore) {
+    let width = 190.0;
+    let note = lore.note.trim();
+    let height = if note.is_empty() { 26.0 } else { 64.0 };
+    let (hx, hy) = geometry.hour_positions[hour];
+    let side = if hx >= geometry.cx { 1.0 } else { -1.0 };
+    let x = hx + side * (geometry.hour_node_radius + 12.0 + width / 2.0);
+
+    draw.rect()
+        .x_y(x, hy)
+        .w_h(width, height)
+        .color(colors::overlay_bg());
+    draw.rect()
+        .x_y(x, hy)
+        .w_h(width, height)
+        .no_fill()
+        .stroke(colors::FOCUS_RING)
+        .stroke_weight(1.0);
+
+    let title = lore
+        .title()
+        .unwrap_or_else(|| tr_args("Hour {0}", &[&hour_to_display(hour)]));
+    let title_y = if note.is_empty() { hy } else { hy + height / 2.0 - 13.0 };
+    draw.text(&title)
+        .x_y(x, title_y)
+        .color(colors::text_primary())
+        .font_size(13)
+        .w(width - 16.0);
+    if !note.is_empty() {
+        draw.text(note)
+            .x_y(x, hy - 9.0)
+            .color(colors::text_secondary())
+            .font_size(11)
+            .w_h(width - 16.0, height - 28.0);
+    }
+}
+
 /// Draw gesture trails
 fn draw_trails(draw: &Draw, geometry: &StageGeometry, model: &Model, now: Instant) {
-    if model.trail_points.len() < 2 {
-        return;
+    let base_width = geometry.trail_base_width() * (0.5 + model.gesture_sensitivity);
+    let points: Vec<StrokePoint> = model
+        .trail_points
+        .iter()
+        .map(|p| StrokePoint {
+            position: pt2(p.x, p.y),
+            age: now.duration_since(p.instant).as_secs_f32(),
+        })
+        .collect();
+
+    let brush = model.brush.effective(model.motion_reduced());
+    draw_stroke(draw, &points, TRAIL_LIFETIME, brush, base_width, colors::TRAIL);
+}
+
+/// Draw replayed strokes and the replayed hour highlight
+fn draw_replay(
+    draw: &Draw,
+    geometry: &StageGeometry,
+    replay: &Replay,
+    sensitivity: f32,
+    brush: Brush,
+) {
+    let base_width = geometry.trail_base_width() * (0.5 + sensitivity);
+    let lifetime = REPLAY_TRAIL_MS as f32 / 1000.0;
+
+    for stroke in replay.visible_strokes() {
+        let points: Vec<StrokePoint> = stroke
+            .iter()
+            .map(|p| StrokePoint {
+                position: pt2(p.x, p.y),
+                age: p.age_ms as f32 / 1000.0,
+            })
+            .collect();
+        draw_stroke(draw, &points, lifetime, brush, base_width, colors::REPLAY);
     }
 
-    let lifetime = 2.0; // seconds
-    let base_width = geometry.trail_base_width() * (0.5 + model.gesture_sensitivity);
+    if let Some(hour) = replay.highlighted_hour() {
+        let (hx, hy) = geometry.hour_positions[hour];
+        draw.ellipse()
+            .x_y(hx, hy)
+            .radius(geometry.hour_node_radius + 7.0)
+            .no_fill()
+            .stroke(colors::REPLAY)
+            .stroke_weight(1.5);
+    }
+}
 
-    // Draw trail segments
-    for i in 1..model.trail_points.len() {
-        let p0 = &model.trail_points[i - 1];
-        let p1 = &model.trail_points[i];
+/// A point on a stroke being painted, oldest first
+struct StrokePoint {
+    position: Point2,
+    /// Seconds since the point was drawn
+    age: f32,
+}
 
-        // Calculate alpha based on age
-        let age = now.duration_since(p1.instant).as_secs_f32();
-        let alpha_raw = (1.0 - age / lifetime).clamp(0.0, 1.0);
-        let alpha = alpha_raw * alpha_raw; // squared falloff
+/// Paint a stroke with the brush; points fade out over `lifetime` seconds
+fn draw_stroke(
+    draw: &Draw,
+    points: &[StrokePoint],
+    lifetime: f32,
+    brush: Brush,
+    base_width: f32,
+    color: Srgb<u8>,
+) {
+    let points = brush.capped(points);
+    if points.len() < 2 {
+        return;
+    }
+    let base_width = base_width * brush.width;
+    let tint = |alpha: f32| srgba(color.red, color.green, color.blue, (alpha * 255.0) as u8);
+
+    match brush.style {
+        TrailStyle::InkRibbon => {
+            for pair in points.windows(2) {
+                let alpha = fade(pair[1].age, lifetime);
+                if alpha < 0.01 {
+                    continue;
+                }
+                draw.line()
+                    .start(pair[0].position)
+                    .end(pair[1].position)
+                    .stroke_weight(base_width * alpha)
+                    .color(tint(alpha * 0.8));
+            }
+        }
+        TrailStyle::Comet => {
+            let last = (points.len() - 1) as f32;
+            for (i, pair) in points.windows(2).enumerate() {
+                let alpha = fade(pair[1].age, lifetime);
+                if alpha < 0.01 {
+                    continue;
+                }
+                let width = comet_width(base_width * 1.5, (i + 1) as f32 / last);
+                // Soft glow under the core
+                draw.line()
+                    .start(pair[0].position)
+                    .end(pair[1].position)
+                    .stroke_weight(width * 2.5)
+                    .color(tint(alpha * 0.15));
+                draw.line()
+                    .start(pair[0].position)
+                    .end(pair[1].position)
+                    .stroke_weight(width)
+                    .color(tint(alpha * 0.8));
+            }
 
-        if alpha < 0.01 {
-            continue;
+            // Glowing head on the newest point
+            let head = &points[points.len() - 1];
+            let alpha = fade(head.age, lifetime);
+            if alpha >= 0.01 {
+                for (scale, strength) in [(2.8, 0.12), (1.8, 0.25), (1.0, 0.9)] {
+                    draw.ellipse()
+                        .xy(head.position)
+                        .radius(base_width * scale)
+                        .color(tint(alpha * strength));
+                }
+            }
         }
+        TrailStyle::Stipple => {
+            // Newest segments first, so the dot budget drops the oldest
+            let dot_width = base_width * 0.8;
+            let mut budget = MAX_DOTS;
+            for pair in points.windows(2).rev() {
+                let alpha = fade(pair[1].age, lifetime);
+                if alpha < 0.01 || budget == 0 {
+                    break;
+                }
+                let dots = brush.stipple_dots(pair[0].position, pair[1].position, dot_width);
+                for dot in dots.into_iter().take(budget) {
+                    draw.ellipse()
+                        .xy(dot)
+                        .radius(dot_width * 0.5 * (0.4 + 0.6 * alpha))
+                        .color(tint(alpha * 0.85));
+                    budget -= 1;
+                }
+            }
+        }
+        TrailStyle::Calligraphic => {
+            for pair in points.windows(2) {
+                let alpha = fade(pair[1].age, lifetime);
+                if alpha < 0.01 {
+                    continue;
+                }
+                let distance = pair[0].position.distance(pair[1].position);
+                // Samples arrive at most 60 times a second
+                let elapsed = (pair[0].age - pair[1].age).max(1.0 / 60.0);
+                let width = brush.nib_width(base_width * 1.6, distance / elapsed);
+                draw.line()
+                    .start(pair[0].position)
+                    .end(pair[1].position)
+                    .stroke_weight(width * (0.5 + 0.5 * alpha))
+                    .caps_round()
+                    .color(tint(alpha * 0.8));
+            }
+        }
+    }
+}
 
-        let width = base_width * alpha;
+/// Draw a "REC" marker under the title while a session is recorded
+pub fn draw_recording_indicator(draw: &Draw, window_rect: Rect) {
+    draw.text(tr("● REC"))
+        .x_y(0.0, window_rect.top() - 45.0)
+        .color(colors::recording())
+        .font_size(12)
+        .w(100.0);
+}
 
-        draw.line()
-            .start(pt2(p0.x, p0.y))
-            .end(pt2(p1.x, p1.y))
-            .stroke_weight(width)
-            .color(srgba(
-                colors::TRAIL.red,
-                colors::TRAIL.green,
-                colors::TRAIL.blue,
-                (alpha * 200.0) as u8,
-            ));
+/// Draw the conducting sync score below the center of the stage
+fn draw_sync_score(draw: &Draw, geometry: &StageGeometry, model: &Model) {
+    let Some(reading) = model.gesture_reading else {
+        return;
+    };
+    let y = geometry.cy - geometry.r_hour * 0.6;
+
+    draw.text(&tr_args("SYNC {0}%", &[&format!("{:.0}", model.sync_score * 100.0)]))
+        .x_y(geometry.cx, y)
+        .color(colors::trail())
+        .font_size(16)
+        .w(160.0);
+    let period = format!("{:.2}", reading.period_secs);
+    draw.text(&tr_args("{0} · {1}s", &[&tr(reading.kind.label()), &period]))
+        .x_y(geometry.cx, y - 18.0)
+        .color(colors::text_secondary())
+        .font_size(11)
+        .w(160.0);
+}
+
+/// Draw the duel scoreboard: this conductor and the opponent side by side
+fn draw_duel(draw: &Draw, geometry: &StageGeometry, model: &Model, duel: &Duel, now: Instant) {
+    let y = geometry.cy - geometry.r_hour * 0.6;
+    let gap = 70.0;
+    let own = ConductorScore {
+        sync: model.gesture_reading.map(|_| model.sync_score),
+        best: model.best_sync_score,
+    };
+
+    let columns = [
+        ("YOU", Some(own), colors::trail(), -gap),
+        ("OPPONENT", duel.opponent(now), colors::opponent(), gap),
+    ];
+    for (label, score, color, dx) in columns {
+        let x = geometry.cx + dx;
+        draw.text(tr(label))
+            .x_y(x, y + 16.0)
+            .color(colors::text_secondary())
+            .font_size(10)
+            .w(120.0);
+        let sync = score.and_then(|score| score.sync);
+        let value = sync.map_or("–".to_string(), |sync| format!("{:.0}%", sync * 100.0));
+        draw.text(&value)
+            .x_y(x, y)
+            .color(color)
+            .font_size(18)
+            .w(120.0);
+
+        // Bar filling with the live score
+        let (bar_w, bar_y) = (100.0, y - 16.0);
+        draw.rect()
+            .x_y(x, bar_y)
+            .w_h(bar_w, 4.0)
+            .color(srgba(255u8, 255u8, 255u8, 25u8));
+        let fill = sync.unwrap_or(0.0) * bar_w;
+        if fill > 0.0 {
+            draw.rect()
+                .x_y(x - (bar_w - fill) / 2.0, bar_y)
+                .w_h(fill, 4.0)
+                .color(color);
+        }
+        if let Some(score) = score {
+            draw.text(&tr_args("best {0}%", &[&format!("{:.0}", score.best * 100.0)]))
+                .x_y(x, bar_y - 12.0)
+                .color(colors::text_secondary())
+                .font_size(10)
+                .w(120.0);
+        }
     }
+
+    draw.line()
+        .start(pt2(geometry.cx, y + 20.0))
+        .end(pt2(geometry.cx, y - 30.0))
+        .color(srgba(255u8, 255u8, 255u8, 40u8))
+        .stroke_weight(1.0);
+    draw.text(tr(duel.status(now).label()))
+        .x_y(geometry.cx, y - 44.0)
+        .color(colors::text_secondary())
+        .font_size(10)
+        .w(220.0);
 }
 
 /// Draw ghost beat for DST warning
 fn draw_ghost_beat(draw: &Draw, geometry: &StageGeometry, model: &Model, _now: Instant) {
-    if model.reduced_motion {
+    if model.motion_reduced() {
         // Static badge instead of animation - draw indicator near center
         draw.text("DST")
             .x_y(geometry.cx, geometry.cy - geometry.r_hour - 30.0)
-            .color(colors::DST_WARNING)
+            .color(colors::dst_warning())
             .font_size(14);
         return;
     }
@@ -408,9 +915,9 @@ fn draw_ghost_beat(draw: &Draw, geometry: &StageGeometry, model: &Model, _now: I
             .x_y(bx, by)
             .radius(geometry.beat_node_radius * 1.5)
             .color(srgba(
-                colors::DST_WARNING.red,
-                colors::DST_WARNING.green,
-                colors::DST_WARNING.blue,
+                colors::dst_warning().red,
+                colors::dst_warning().green,
+                colors::dst_warning().blue,
                 alpha,
             ));
     }
@@ -422,12 +929,23 @@ pub fn draw_overlay(
     geometry: &StageGeometry,
     time_data: &TimeData,
     highlighted_hour: Option<usize>,
+    lore: &LoreBook,
     always_on: bool,
 ) {
+    // The current hour's ritual gets a line of its own while no hour is
+    // highlighted
+    let current_hour = (time_data.hour12 % 12) as usize;
+    let ritual_line = match highlighted_hour {
+        Some(_) => None,
+        None => lore.hour(current_hour).and_then(HourLore::title),
+    };
+
     let overlay_width = 200.0;
-    let overlay_height = 80.0;
+    let overlay_height = if ritual_line.is_some() { 100.0 } else { 80.0 };
     let overlay_x = geometry.cx;
     let overlay_y = geometry.cy;
+    // Lines move up to make room for the ritual
+    let shift = (overlay_height - 80.0) / 2.0;
 
     // Draw background
     draw.rect()
@@ -441,28 +959,39 @@ pub fn draw_overlay(
         time_data.hour12, time_data.minute, time_data.second, time_data.meridiem
     );
     draw.text(&time_str)
-        .x_y(overlay_x, overlay_y + 15.0)
-        .color(colors::TEXT_PRIMARY)
+        .x_y(overlay_x, overlay_y + 15.0 + shift)
+        .color(colors::text_primary())
         .font_size(24)
         .w(overlay_width - 20.0);
 
     // Draw highlighted hour or date
     let secondary_text = if let Some(hour) = highlighted_hour {
-        format!("Hour {} highlighted", hour_to_display(hour))
+        match lore.hour(hour).and_then(HourLore::title) {
+            Some(title) => tr_args("Hour {0} · {1}", &[&hour_to_display(hour), &title]),
+            None => tr_args("Hour {0} highlighted", &[&hour_to_display(hour)]),
+        }
     } else {
         time_data.format_date()
     };
     draw.text(&secondary_text)
-        .x_y(overlay_x, overlay_y - 20.0)
-        .color(colors::TEXT_SECONDARY)
+        .x_y(overlay_x, overlay_y - 20.0 + shift)
+        .color(colors::text_secondary())
         .font_size(12)
         .w(overlay_width - 20.0);
 
+    if let Some(ritual) = ritual_line {
+        draw.text(&tr_args("Now: {0}", &[&ritual]))
+            .x_y(overlay_x, overlay_y - 20.0 - shift)
+            .color(colors::hour_node_active())
+            .font_size(12)
+            .w(overlay_width - 20.0);
+    }
+
     // Draw always-on indicator
     if always_on {
         draw.text("●")
             .x_y(overlay_x + overlay_width / 2.0 - 15.0, overlay_y + overlay_height / 2.0 - 10.0)
-            .color(colors::TEXT_SECONDARY)
+            .color(colors::text_secondary())
             .font_size(8);
     }
 }
@@ -495,9 +1024,9 @@ pub fn draw_error_banner(draw: &Draw, window_rect: Rect) {
         .color(srgba(120u8, 40u8, 40u8, 220u8));
 
     // Text
-    draw.text("⚠ Timezone data may be missing or stale. Showing UTC as fallback.")
+    draw.text(tr("⚠ Timezone data may be missing or stale. Showing UTC as fallback."))
         .x_y(0.0, banner_y)
-        .color(colors::TEXT_PRIMARY)
+        .color(colors::text_primary())
         .font_size(14)
         .w(window_rect.w() - 40.0);
 }
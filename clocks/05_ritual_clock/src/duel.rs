@@ -0,0 +1,341 @@
+//! Duel module - two conductors comparing sync over the local network
+//!
+//! One instance hosts a duel and shows a session code; the other types the
+//! code in to join. The code is the host's LAN address and port in Crockford
+//! base32 (e.g. `1Z3KC-8G2R0`), so joining needs no discovery service. Each
+//! side sends its live sync score a few times a second over UDP, and the
+//! stage shows both conductors side by side.
+//!
+//! The duel is best effort. Lost packets just leave the last score showing.
+//! When the other side goes quiet, the duel reports it as lost and the local
+//! conductor carries on alone until it comes back. With no network at all,
+//! the host falls back to loopback so a second instance on the same computer
+//! can still join.
+
+use std::io::ErrorKind;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4, UdpSocket};
+use std::time::{Duration, Instant};
+
+use shared::tr_args;
+
+/// First word of every packet, so stray traffic on the port is ignored
+const PACKET_TAG: &str = "ritual-duel/1";
+
+/// How often each side sends its score
+const SEND_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Silence after which the other conductor counts as lost
+const PEER_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Crockford base32, which leaves out I, L, O and U so codes read aloud well
+const CODE_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Characters in a session code, not counting the separating dash
+const CODE_LEN: usize = 10;
+
+/// What one conductor reports to the other
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConductorScore {
+    /// Live sync score (0..1), or `None` while not conducting
+    pub sync: Option<f32>,
+    /// Best sync score so far (0..1)
+    pub best: f32,
+}
+
+/// Where the duel stands with the other conductor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuelStatus {
+    /// Nothing heard yet
+    Waiting,
+    /// Scores arriving
+    Connected,
+    /// Heard before, silent for a while
+    Lost,
+}
+
+impl DuelStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            DuelStatus::Waiting => "Waiting for opponent",
+            DuelStatus::Connected => "Connected",
+            DuelStatus::Lost => "Opponent lost, conducting solo",
+        }
+    }
+}
+
+/// A hosted or joined duel
+pub struct Duel {
+    socket: UdpSocket,
+    /// Session code, formatted for display
+    code: String,
+    /// Where scores go: the host for a guest, the latest guest for a host
+    peer: Option<SocketAddr>,
+    last_heard: Option<Instant>,
+    last_sent: Option<Instant>,
+    /// Status as of the last `poll`, to report changes
+    last_status: DuelStatus,
+    opponent: Option<ConductorScore>,
+    /// The host has no LAN address, so only this computer can join
+    pub loopback_only: bool,
+    /// Why the last score couldn't be sent, if it couldn't
+    pub error: Option<String>,
+}
+
+impl Duel {
+    /// Host a duel on this computer's LAN address
+    pub fn host() -> Result<Self, String> {
+        let socket = bind()?;
+        let port = socket.local_addr().map_err(|e| e.to_string())?.port();
+        let lan = lan_address();
+        let ip = lan.unwrap_or(Ipv4Addr::LOCALHOST);
+        Ok(Self::new(
+            socket,
+            encode_code(SocketAddrV4::new(ip, port)),
+            None,
+            lan.is_none(),
+        ))
+    }
+
+    /// Join the duel hosted at `code`
+    pub fn join(code: &str) -> Result<Self, String> {
+        let host = decode_code(code)
+            .ok_or_else(|| tr_args("{0} is not a session code", &[&format!("{:?}", code)]))?;
+        let socket = bind()?;
+        Ok(Self::new(
+            socket,
+            encode_code(host),
+            Some(SocketAddr::V4(host)),
+            false,
+        ))
+    }
+
+    fn new(socket: UdpSocket, code: String, peer: Option<SocketAddr>, loopback_only: bool) -> Self {
+        Self {
+            socket,
+            code,
+            peer,
+            last_heard: None,
+            last_sent: None,
+            last_status: DuelStatus::Waiting,
+            opponent: None,
+            loopback_only,
+            error: None,
+        }
+    }
+
+    /// Session code the other conductor types in
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn status(&self, now: Instant) -> DuelStatus {
+        match self.last_heard {
+            None => DuelStatus::Waiting,
+            Some(heard) if now.saturating_duration_since(heard) > PEER_TIMEOUT => DuelStatus::Lost,
+            Some(_) => DuelStatus::Connected,
+        }
+    }
+
+    /// The other conductor's latest score, while connected
+    pub fn opponent(&self, now: Instant) -> Option<ConductorScore> {
+        self.opponent.filter(|_| self.status(now) == DuelStatus::Connected)
+    }
+
+    /// Read every waiting packet; returns the status if it changed
+    pub fn poll(&mut self, now: Instant) -> Option<DuelStatus> {
+        let mut buffer = [0u8; 256];
+        loop {
+            match self.socket.recv_from(&mut buffer) {
+                Ok((len, from)) => {
+                    let text = String::from_utf8_lossy(&buffer[..len]);
+                    if let Some(score) = parse_packet(&text, &self.code) {
+                        self.opponent = Some(score);
+                        self.last_heard = Some(now);
+                        self.peer = Some(from);
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                // Windows reports an earlier send the peer refused here
+                Err(_) => break,
+            }
+        }
+
+        let status = self.status(now);
+        (status != self.last_status).then(|| {
+            self.last_status = status;
+            status
+        })
+    }
+
+    /// Send our score, at most every `SEND_INTERVAL`
+    pub fn send(&mut self, score: ConductorScore, now: Instant) {
+        let Some(peer) = self.peer else {
+            return;
+        };
+        if self.last_sent.is_some_and(|sent| now.duration_since(sent) < SEND_INTERVAL) {
+            return;
+        }
+        self.last_sent = Some(now);
+        self.error = self
+            .socket
+            .send_to(encode_packet(&self.code, score).as_bytes(), peer)
+            .err()
+            .map(|e| e.to_string());
+    }
+}
+
+/// A non-blocking UDP socket on any free port
+fn bind() -> Result<UdpSocket, String> {
+    let socket_error = |e: std::io::Error| tr_args("Duel socket: {0}", &[&e]);
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).map_err(socket_error)?;
+    socket.set_nonblocking(true).map_err(socket_error)?;
+    Ok(socket)
+}
+
+/// This computer's address on the network, if it has one
+///
+/// Connecting a UDP socket sends nothing but picks the outgoing interface.
+fn lan_address() -> Option<Ipv4Addr> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).ok()?;
+    socket.connect((Ipv4Addr::new(192, 0, 2, 1), 9)).ok()?;
+    match socket.local_addr().ok()?.ip() {
+        IpAddr::V4(ip) if !ip.is_unspecified() && !ip.is_loopback() => Some(ip),
+        _ => None,
+    }
+}
+
+/// `XXXXX-XXXXX` for an IPv4 address and port
+pub fn encode_code(addr: SocketAddrV4) -> String {
+    let bits = (u64::from(u32::from(*addr.ip())) << 16) | u64::from(addr.port());
+    let chars: String = (0..CODE_LEN)
+        .rev()
+        .map(|i| CODE_ALPHABET[((bits >> (i * 5)) & 31) as usize] as char)
+        .collect();
+    format!("{}-{}", &chars[..CODE_LEN / 2], &chars[CODE_LEN / 2..])
+}
+
+/// The address in a session code; case, dashes, spaces and look-alike
+/// letters (O for 0, I and L for 1) are forgiven
+pub fn decode_code(code: &str) -> Option<SocketAddrV4> {
+    let mut bits = 0u64;
+    let mut len = 0;
+    for c in code.chars().filter(|c| *c != '-' && !c.is_whitespace()) {
+        let c = match c.to_ascii_uppercase() {
+            'O' => '0',
+            'I' | 'L' => '1',
+            c => c,
+        };
+        let value = CODE_ALPHABET.iter().position(|&a| a as char == c)?;
+        bits = (bits << 5) | value as u64;
+        len += 1;
+    }
+    if len != CODE_LEN || bits >> 48 != 0 {
+        return None;
+    }
+    let ip = Ipv4Addr::from((bits >> 16) as u32);
+    Some(SocketAddrV4::new(ip, (bits & 0xFFFF) as u16))
+}
+
+/// `ritual-duel/1 <code> <sync %, or -> <best %>`
+fn encode_packet(code: &str, score: ConductorScore) -> String {
+    let percent = |value: f32| (value.clamp(0.0, 1.0) * 100.0).round() as u32;
+    let sync = score.sync.map_or("-".to_string(), |sync| percent(sync).to_string());
+    format!("{} {} {} {}", PACKET_TAG, code, sync, percent(score.best))
+}
+
+/// The score in a packet for the duel `code`
+fn parse_packet(text: &str, code: &str) -> Option<ConductorScore> {
+    let mut fields = text.split_whitespace();
+    if fields.next()? != PACKET_TAG || fields.next()? != code {
+        return None;
+    }
+    let percent = |field: &str| field.parse::<u32>().ok().map(|p| p.min(100) as f32 / 100.0);
+    let sync = match fields.next()? {
+        "-" => None,
+        field => Some(percent(field)?),
+    };
+    let best = percent(fields.next()?)?;
+    Some(ConductorScore { sync, best })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_code_round_trip() {
+        let addr = SocketAddrV4::new(Ipv4Addr::new(192, 168, 1, 42), 51234);
+        let code = encode_code(addr);
+        assert_eq!(code.len(), CODE_LEN + 1);
+        assert_eq!(decode_code(&code), Some(addr));
+
+        // Typed sloppily: lowercase, no dash, O for 0
+        let sloppy = code.replace('-', " ").to_lowercase().replace('0', "o");
+        assert_eq!(decode_code(&sloppy), Some(addr));
+
+        assert_eq!(decode_code("12345"), None);
+        assert_eq!(decode_code("UUUUU-UUUUU"), None);
+        // 50 bits of code, but only 48 carry an address
+        assert_eq!(decode_code("ZZZZZ-ZZZZZ"), None);
+    }
+
+    #[test]
+    fn test_packets() {
+        let score = ConductorScore {
+            sync: Some(0.824),
+            best: 0.9,
+        };
+        let packet = encode_packet("ABCDE-12345", score);
+        assert_eq!(packet, "ritual-duel/1 ABCDE-12345 82 90");
+        assert_eq!(
+            parse_packet(&packet, "ABCDE-12345"),
+            Some(ConductorScore {
+                sync: Some(0.82),
+                best: 0.9
+            })
+        );
+
+        // Another duel's packets, and garbage, are ignored
+        assert_eq!(parse_packet(&packet, "ZZZZZ-00000"), None);
+        assert_eq!(parse_packet("hello", "ABCDE-12345"), None);
+
+        let idle = ConductorScore { sync: None, best: 0.5 };
+        assert_eq!(parse_packet(&encode_packet("X", idle), "X"), Some(idle));
+    }
+
+    #[test]
+    fn test_duel_over_loopback() {
+        let mut host = Duel::host().unwrap();
+        let port = host.socket.local_addr().unwrap().port();
+        let code = encode_code(SocketAddrV4::new(Ipv4Addr::LOCALHOST, port));
+        host.code = code.clone();
+        let mut guest = Duel::join(&code).unwrap();
+
+        let start = Instant::now();
+        assert_eq!(host.status(start), DuelStatus::Waiting);
+
+        let score = ConductorScore {
+            sync: Some(0.5),
+            best: 0.75,
+        };
+        guest.send(score, start);
+        let mut heard = None;
+        for _ in 0..100 {
+            if let Some(status) = host.poll(start) {
+                heard = Some(status);
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(heard, Some(DuelStatus::Connected));
+        assert_eq!(host.opponent(start), Some(score));
+
+        // The host now knows where to answer
+        assert!(host.peer.is_some());
+
+        // Silence eventually counts as lost
+        let later = start + PEER_TIMEOUT + Duration::from_secs(1);
+        assert_eq!(host.poll(later), Some(DuelStatus::Lost));
+        assert_eq!(host.opponent(later), None);
+    }
+}
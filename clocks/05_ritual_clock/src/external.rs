@@ -0,0 +1,422 @@
+//! External module - beats sent to external instruments
+//!
+//! Each second the ritual can drive synths and lighting rigs over OSC (UDP,
+//! always built) and MIDI (midir, only built with the `midi` feature). OSC
+//! receives `<prefix>/beat` every second plus `<prefix>/minute` and
+//! `<prefix>/hour` on those boundaries. MIDI receives a note per second (a
+//! different note on minute and hour boundaries) and, optionally, a 24 PPQN
+//! MIDI clock at one quarter note per second, phase-locked to the beats.
+
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+
+use serde::{Deserialize, Serialize};
+use shared::TimeData;
+
+use crate::audio::ChimeKind;
+
+/// MIDI timing clock pulse
+#[cfg_attr(not(feature = "midi"), allow(dead_code))]
+pub const MIDI_CLOCK: u8 = 0xF8;
+/// MIDI start (sent when the clock begins)
+#[cfg_attr(not(feature = "midi"), allow(dead_code))]
+pub const MIDI_START: u8 = 0xFA;
+/// MIDI stop (sent when the clock ends)
+#[cfg_attr(not(feature = "midi"), allow(dead_code))]
+pub const MIDI_STOP: u8 = 0xFC;
+/// Clock pulses per beat (quarter note), as the MIDI spec defines
+#[cfg_attr(not(feature = "midi"), allow(dead_code))]
+pub const CLOCKS_PER_BEAT: u32 = 24;
+
+/// Persisted external output preferences
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalSettings {
+    pub osc_enabled: bool,
+    /// Where OSC packets go, as `host:port`
+    pub osc_target: String,
+    /// Address prefix for OSC messages
+    pub osc_prefix: String,
+    pub midi_enabled: bool,
+    /// Name of the MIDI output port to use
+    pub midi_port: Option<String>,
+    /// MIDI channel, 1-16
+    pub midi_channel: u8,
+    /// Also send MIDI clock, one quarter note per second
+    pub midi_clock: bool,
+}
+
+impl Default for ExternalSettings {
+    fn default() -> Self {
+        Self {
+            osc_enabled: false,
+            osc_target: "127.0.0.1:9000".to_string(),
+            osc_prefix: "/ritual".to_string(),
+            midi_enabled: false,
+            midi_port: None,
+            // General MIDI percussion channel
+            midi_channel: 10,
+            midi_clock: false,
+        }
+    }
+}
+
+/// General MIDI drum note for an event
+pub fn midi_note(kind: ChimeKind) -> u8 {
+    match kind {
+        // Closed hi-hat
+        ChimeKind::Beat => 42,
+        // Acoustic snare
+        ChimeKind::Minute => 38,
+        // Crash cymbal
+        ChimeKind::Hour => 49,
+    }
+}
+
+/// Note velocity for an event
+pub fn midi_velocity(kind: ChimeKind) -> u8 {
+    match kind {
+        ChimeKind::Beat => 80,
+        ChimeKind::Minute => 100,
+        ChimeKind::Hour => 127,
+    }
+}
+
+/// Note On message; `channel` is 1-16
+pub fn note_on(channel: u8, note: u8, velocity: u8) -> [u8; 3] {
+    [0x90 | channel_bits(channel), note & 0x7F, velocity & 0x7F]
+}
+
+/// Note Off message; `channel` is 1-16
+pub fn note_off(channel: u8, note: u8) -> [u8; 3] {
+    [0x80 | channel_bits(channel), note & 0x7F, 0]
+}
+
+fn channel_bits(channel: u8) -> u8 {
+    channel.clamp(1, 16) - 1
+}
+
+/// Encode an OSC message with integer arguments
+pub fn encode_osc(address: &str, args: &[i32]) -> Vec<u8> {
+    let mut packet = Vec::new();
+    push_osc_string(&mut packet, address);
+    let type_tags: String = std::iter::once(',')
+        .chain(args.iter().map(|_| 'i'))
+        .collect();
+    push_osc_string(&mut packet, &type_tags);
+    for arg in args {
+        packet.extend_from_slice(&arg.to_be_bytes());
+    }
+    packet
+}
+
+/// OSC strings are null-terminated and padded to a multiple of four bytes
+fn push_osc_string(packet: &mut Vec<u8>, text: &str) {
+    packet.extend_from_slice(text.as_bytes());
+    let padding = 4 - text.len() % 4;
+    packet.resize(packet.len() + padding, 0);
+}
+
+/// OSC packets for a newly reached second
+///
+/// The beat carries the second; minute and hour boundaries add a message
+/// carrying the minute or the hour (0-23).
+pub fn osc_messages(prefix: &str, time_data: &TimeData) -> Vec<Vec<u8>> {
+    let prefix = prefix.trim_end_matches('/');
+    let address = |name: &str| format!("{}/{}", prefix, name);
+    let mut messages = vec![encode_osc(&address("beat"), &[time_data.second as i32])];
+    match ChimeKind::at(time_data) {
+        ChimeKind::Beat => {}
+        ChimeKind::Minute => {
+            messages.push(encode_osc(&address("minute"), &[time_data.minute as i32]));
+        }
+        ChimeKind::Hour => {
+            messages.push(encode_osc(&address("hour"), &[time_data.hour24 as i32]));
+        }
+    }
+    messages
+}
+
+/// Sends OSC packets over UDP
+struct OscSender {
+    socket: UdpSocket,
+    target: SocketAddr,
+}
+
+impl OscSender {
+    fn connect(target: &str) -> Result<Self, String> {
+        let target = target
+            .to_socket_addrs()
+            .map_err(|e| format!("OSC target {:?}: {}", target, e))?
+            .next()
+            .ok_or_else(|| format!("OSC target {:?} has no address", target))?;
+        let local = if target.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" };
+        let socket = UdpSocket::bind(local).map_err(|e| format!("OSC socket: {}", e))?;
+        Ok(Self { socket, target })
+    }
+
+    fn send(&self, packet: &[u8]) {
+        if let Err(e) = self.socket.send_to(packet, self.target) {
+            eprintln!("Failed to send OSC message: {}", e);
+        }
+    }
+}
+
+#[cfg(feature = "midi")]
+mod midi {
+    use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use midir::{MidiOutput, MidiOutputConnection};
+
+    use super::{CLOCKS_PER_BEAT, MIDI_CLOCK, MIDI_START, MIDI_STOP};
+
+    const CLIENT_NAME: &str = "Ritual Clock";
+
+    /// Shown when there is no port to choose
+    pub const UNAVAILABLE_REASON: &str = "No MIDI output ports found";
+
+    /// Names of the available MIDI output ports
+    pub fn port_names() -> Vec<String> {
+        let Ok(output) = MidiOutput::new(CLIENT_NAME) else {
+            return Vec::new();
+        };
+        output
+            .ports()
+            .iter()
+            .filter_map(|port| output.port_name(port).ok())
+            .collect()
+    }
+
+    /// An open MIDI output port
+    pub struct MidiOut {
+        connection: Arc<Mutex<MidiOutputConnection>>,
+        /// Wakes the clock thread on each beat; dropping it stops the clock
+        clock: Option<Sender<()>>,
+    }
+
+    impl MidiOut {
+        pub fn connect(port_name: &str, send_clock: bool) -> Result<Self, String> {
+            let output = MidiOutput::new(CLIENT_NAME).map_err(|e| e.to_string())?;
+            let port = output
+                .ports()
+                .into_iter()
+                .find(|port| output.port_name(port).is_ok_and(|name| name == port_name))
+                .ok_or_else(|| format!("MIDI port {:?} not found", port_name))?;
+            let connection = output
+                .connect(&port, "ritual-beats")
+                .map_err(|e| e.to_string())?;
+            let connection = Arc::new(Mutex::new(connection));
+            let clock = send_clock.then(|| spawn_clock(Arc::clone(&connection)));
+            Ok(Self { connection, clock })
+        }
+
+        pub fn send(&self, message: &[u8]) {
+            let Ok(mut connection) = self.connection.lock() else {
+                return;
+            };
+            if let Err(e) = connection.send(message) {
+                eprintln!("Failed to send MIDI message: {}", e);
+            }
+        }
+
+        /// Realign the clock (if running) to a beat happening now
+        pub fn beat(&self) {
+            if let Some(clock) = &self.clock {
+                let _ = clock.send(());
+            }
+        }
+    }
+
+    /// Send 24 clock pulses after each beat, evenly over the following second
+    ///
+    /// Restarting the run on every beat keeps the clock locked to the
+    /// wall-clock seconds rather than drifting with thread timing.
+    fn spawn_clock(connection: Arc<Mutex<MidiOutputConnection>>) -> Sender<()> {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let send = |message: u8| {
+                if let Ok(mut connection) = connection.lock() {
+                    let _ = connection.send(&[message]);
+                }
+            };
+            let interval = Duration::from_secs(1) / CLOCKS_PER_BEAT;
+            // When the next pulse is due, and how many this beat has had
+            let mut next: Option<(Instant, u32)> = None;
+
+            send(MIDI_START);
+            loop {
+                let event = match next {
+                    Some((due, _)) => {
+                        receiver.recv_timeout(due.saturating_duration_since(Instant::now()))
+                    }
+                    None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                };
+                match event {
+                    Ok(()) => {
+                        send(MIDI_CLOCK);
+                        next = Some((Instant::now() + interval, 1));
+                    }
+                    Err(RecvTimeoutError::Timeout) => {
+                        if let Some((due, sent)) = next {
+                            send(MIDI_CLOCK);
+                            next = (sent + 1 < CLOCKS_PER_BEAT).then(|| (due + interval, sent + 1));
+                        }
+                    }
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+            send(MIDI_STOP);
+        });
+        sender
+    }
+}
+
+#[cfg(not(feature = "midi"))]
+mod midi {
+    /// Shown when there is no port to choose
+    pub const UNAVAILABLE_REASON: &str = "Built without MIDI (enable the `midi` feature)";
+
+    pub fn port_names() -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Stand-in when the `midi` feature is disabled
+    #[allow(dead_code)]
+    pub struct MidiOut;
+
+    impl MidiOut {
+        pub fn connect(_port_name: &str, _send_clock: bool) -> Result<Self, String> {
+            Err(UNAVAILABLE_REASON.to_string())
+        }
+
+        pub fn send(&self, _message: &[u8]) {}
+
+        pub fn beat(&self) {}
+    }
+}
+
+pub use midi::{port_names as midi_port_names, UNAVAILABLE_REASON as MIDI_UNAVAILABLE_REASON};
+
+/// Open OSC and MIDI outputs, per the current settings
+#[derive(Default)]
+pub struct ExternalOutput {
+    osc: Option<OscSender>,
+    midi: Option<midi::MidiOut>,
+    /// Note left sounding by the last beat, released by the next
+    held_note: Option<(u8, u8)>,
+    /// Why an enabled output could not be opened
+    pub error: Option<String>,
+}
+
+impl ExternalOutput {
+    /// Reopen outputs after the settings change
+    pub fn configure(&mut self, settings: &ExternalSettings) {
+        self.release_note();
+        // Close the MIDI port before reopening it
+        self.midi = None;
+        self.osc = None;
+        self.error = None;
+
+        if settings.osc_enabled {
+            match OscSender::connect(settings.osc_target.trim()) {
+                Ok(sender) => self.osc = Some(sender),
+                Err(e) => self.error = Some(e),
+            }
+        }
+        if settings.midi_enabled {
+            match &settings.midi_port {
+                Some(port) => match midi::MidiOut::connect(port, settings.midi_clock) {
+                    Ok(output) => self.midi = Some(output),
+                    Err(e) => self.error = Some(e),
+                },
+                None => self.error = Some("Choose a MIDI port".to_string()),
+            }
+        }
+    }
+
+    /// Send the beat, minute or hour for a newly reached second
+    pub fn emit(&mut self, time_data: &TimeData, settings: &ExternalSettings) {
+        if let Some(osc) = &self.osc {
+            for packet in osc_messages(&settings.osc_prefix, time_data) {
+                osc.send(&packet);
+            }
+        }
+        if self.midi.is_some() {
+            self.release_note();
+        }
+        if let Some(midi) = &self.midi {
+            midi.beat();
+            let kind = ChimeKind::at(time_data);
+            let (channel, note) = (settings.midi_channel, midi_note(kind));
+            midi.send(&note_on(channel, note, midi_velocity(kind)));
+            self.held_note = Some((channel, note));
+        }
+    }
+
+    fn release_note(&mut self) {
+        if let (Some(midi), Some((channel, note))) = (&self.midi, self.held_note.take()) {
+            midi.send(&note_off(channel, note));
+        }
+    }
+}
+
+impl Drop for ExternalOutput {
+    fn drop(&mut self) {
+        self.release_note();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Tz;
+    use shared::compute_time_data_at;
+
+    fn time_at(hour: u32, minute: u32, second: u32) -> TimeData {
+        let tz: Tz = "UTC".parse().unwrap();
+        compute_time_data_at(tz, Utc.with_ymd_and_hms(2025, 1, 15, hour, minute, second).unwrap())
+    }
+
+    #[test]
+    fn test_encode_osc() {
+        let packet = encode_osc("/ritual/beat", &[7]);
+        let mut expected = b"/ritual/beat\0\0\0\0,i\0\0".to_vec();
+        expected.extend_from_slice(&[0, 0, 0, 7]);
+        assert_eq!(packet, expected);
+        assert_eq!(packet.len() % 4, 0);
+
+        // No arguments still carries an (empty) type tag string
+        assert_eq!(encode_osc("/a", &[]), b"/a\0\0,\0\0\0".to_vec());
+    }
+
+    #[test]
+    fn test_osc_messages_by_boundary() {
+        let addresses = |time: TimeData| -> Vec<String> {
+            osc_messages("/ritual/", &time)
+                .iter()
+                .map(|packet| {
+                    let end = packet.iter().position(|&b| b == 0).unwrap();
+                    String::from_utf8(packet[..end].to_vec()).unwrap()
+                })
+                .collect()
+        };
+
+        assert_eq!(addresses(time_at(12, 30, 15)), ["/ritual/beat"]);
+        assert_eq!(addresses(time_at(12, 30, 0)), ["/ritual/beat", "/ritual/minute"]);
+        assert_eq!(addresses(time_at(13, 0, 0)), ["/ritual/beat", "/ritual/hour"]);
+
+        let hour = osc_messages("/ritual", &time_at(13, 0, 0));
+        assert_eq!(hour[1][hour[1].len() - 4..], 13i32.to_be_bytes());
+    }
+
+    #[test]
+    fn test_midi_messages() {
+        assert_eq!(note_on(10, 42, 80), [0x99, 42, 80]);
+        assert_eq!(note_off(1, 49), [0x80, 49, 0]);
+        // Out-of-range channels are clamped rather than corrupting the status byte
+        assert_eq!(note_on(0, 38, 100)[0], 0x90);
+        assert_eq!(note_on(17, 38, 100)[0], 0x9F);
+    }
+}
@@ -0,0 +1,249 @@
+//! Gesture module - scoring how well the user conducts the seconds
+//!
+//! Looks at the recent trail for either a circular motion (stirring) or
+//! up-and-down beat strokes, estimates its period and compares it with the
+//! one-second beat. Beat strokes are also checked for phase: the bottom of
+//! each stroke (the ictus) should land on a second boundary.
+
+use std::f32::consts::{PI, TAU};
+
+/// One trail sample, with `t` in seconds from an arbitrary reference
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GestureSample {
+    pub x: f32,
+    pub y: f32,
+    pub t: f32,
+}
+
+/// The kind of rhythmic motion recognised
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GestureKind {
+    Circular,
+    Beat,
+}
+
+impl GestureKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            GestureKind::Circular => "Circle",
+            GestureKind::Beat => "Beat",
+        }
+    }
+}
+
+/// Result of analysing a trail
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GestureReading {
+    pub kind: GestureKind,
+    /// Estimated seconds per cycle
+    pub period_secs: f32,
+    /// Sync with the second beat, 0..1
+    pub score: f32,
+}
+
+/// Shortest stretch of trail worth analysing
+const MIN_DURATION_SECS: f32 = 0.8;
+const MIN_SAMPLES: usize = 8;
+
+/// Smallest stroke (pixels) that counts; sensitivity 0 → 1 lowers it
+fn min_stroke(sensitivity: f32) -> f32 {
+    60.0 - 48.0 * sensitivity.clamp(0.0, 1.0)
+}
+
+/// Relative period error at which the period score reaches zero;
+/// more sensitive settings are more forgiving
+fn period_tolerance(sensitivity: f32) -> f32 {
+    0.10 + 0.20 * sensitivity.clamp(0.0, 1.0)
+}
+
+/// Analyse a trail against a one-second beat
+///
+/// `beat_anchor` is the time (same reference as the samples) of any second
+/// boundary. Returns `None` when the trail holds no recognisable rhythm.
+pub fn analyze(samples: &[GestureSample], beat_anchor: f32, sensitivity: f32) -> Option<GestureReading> {
+    let (first, last) = (samples.first()?, samples.last()?);
+    if samples.len() < MIN_SAMPLES || last.t - first.t < MIN_DURATION_SECS {
+        return None;
+    }
+
+    let stroke = min_stroke(sensitivity);
+    let tolerance = period_tolerance(sensitivity);
+    let period_score = |period: f32| (1.0 - (period - 1.0).abs() / tolerance).clamp(0.0, 1.0);
+
+    if let Some(period) = circular_period(samples, stroke) {
+        return Some(GestureReading {
+            kind: GestureKind::Circular,
+            period_secs: period,
+            score: period_score(period),
+        });
+    }
+
+    let ictus = ictus_times(samples, stroke);
+    if ictus.len() < 2 {
+        return None;
+    }
+    let period = (ictus[ictus.len() - 1] - ictus[0]) / (ictus.len() - 1) as f32;
+
+    // Distance of each ictus from the nearest second boundary, 0..0.5s
+    let phase_error = ictus
+        .iter()
+        .map(|t| {
+            let d = (t - beat_anchor).rem_euclid(1.0);
+            d.min(1.0 - d)
+        })
+        .sum::<f32>()
+        / ictus.len() as f32;
+    let phase_score = (1.0 - phase_error / 0.25).clamp(0.0, 1.0);
+
+    Some(GestureReading {
+        kind: GestureKind::Beat,
+        period_secs: period,
+        score: 0.6 * period_score(period) + 0.4 * phase_score,
+    })
+}
+
+/// Seconds per revolution if the trail circles steadily around its centroid
+fn circular_period(samples: &[GestureSample], stroke: f32) -> Option<f32> {
+    let n = samples.len() as f32;
+    let cx = samples.iter().map(|s| s.x).sum::<f32>() / n;
+    let cy = samples.iter().map(|s| s.y).sum::<f32>() / n;
+
+    let mean_radius = samples
+        .iter()
+        .map(|s| ((s.x - cx).powi(2) + (s.y - cy).powi(2)).sqrt())
+        .sum::<f32>()
+        / n;
+    if mean_radius < stroke / 2.0 {
+        return None;
+    }
+
+    // Unwrapped angle swept around the centroid
+    let angles: Vec<f32> = samples.iter().map(|s| (s.y - cy).atan2(s.x - cx)).collect();
+    let deltas: Vec<f32> = angles
+        .windows(2)
+        .map(|w| (w[1] - w[0] + PI).rem_euclid(TAU) - PI)
+        .collect();
+    let swept: f32 = deltas.iter().sum();
+    if swept.abs() < 1.5 * PI {
+        return None;
+    }
+
+    // Mostly turning one way, not wobbling back and forth
+    let consistent = deltas.iter().filter(|d| d.signum() == swept.signum()).count();
+    if (consistent as f32) < 0.8 * deltas.len() as f32 {
+        return None;
+    }
+
+    let duration = samples[samples.len() - 1].t - samples[0].t;
+    Some(duration * TAU / swept.abs())
+}
+
+/// Times of the low points of down-up strokes at least `stroke` pixels deep
+fn ictus_times(samples: &[GestureSample], stroke: f32) -> Vec<f32> {
+    #[derive(PartialEq)]
+    enum Direction {
+        Unknown,
+        Down,
+        Up,
+    }
+
+    let mut ictus = Vec::new();
+    let mut direction = Direction::Unknown;
+    let mut low = samples[0];
+    let mut high = samples[0];
+
+    for &s in &samples[1..] {
+        match direction {
+            Direction::Unknown => {
+                if s.y < low.y {
+                    low = s;
+                }
+                if s.y > high.y {
+                    high = s;
+                }
+                if s.y - low.y >= stroke {
+                    direction = Direction::Up;
+                    high = s;
+                } else if high.y - s.y >= stroke {
+                    direction = Direction::Down;
+                    low = s;
+                }
+            }
+            Direction::Down => {
+                if s.y < low.y {
+                    low = s;
+                } else if s.y - low.y >= stroke {
+                    ictus.push(low.t);
+                    direction = Direction::Up;
+                    high = s;
+                }
+            }
+            Direction::Up => {
+                if s.y > high.y {
+                    high = s;
+                } else if high.y - s.y >= stroke {
+                    direction = Direction::Down;
+                    low = s;
+                }
+            }
+        }
+    }
+
+    ictus
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 60 Hz samples of `f(t)` over `secs`
+    fn trail(secs: f32, f: impl Fn(f32) -> (f32, f32)) -> Vec<GestureSample> {
+        (0..=(secs * 60.0) as usize)
+            .map(|i| {
+                let t = i as f32 / 60.0;
+                let (x, y) = f(t);
+                GestureSample { x, y, t }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_circle_at_one_hertz_scores_high() {
+        let samples = trail(2.0, |t| (80.0 * (TAU * t).cos(), 80.0 * (TAU * t).sin()));
+        let reading = analyze(&samples, 0.0, 0.5).unwrap();
+
+        assert_eq!(reading.kind, GestureKind::Circular);
+        assert!((reading.period_secs - 1.0).abs() < 0.02);
+        assert!(reading.score > 0.9);
+
+        // Twice as fast is well outside tolerance
+        let fast = trail(2.0, |t| (80.0 * (TAU * 2.0 * t).cos(), 80.0 * (TAU * 2.0 * t).sin()));
+        assert_eq!(analyze(&fast, 0.0, 0.5).unwrap().score, 0.0);
+    }
+
+    #[test]
+    fn test_beat_strokes_score_period_and_phase() {
+        // Ictus (lowest point) on every whole second
+        let on_beat = trail(2.5, |t| (0.0, -100.0 * (TAU * t).cos()));
+        let reading = analyze(&on_beat, 0.0, 0.5).unwrap();
+        assert_eq!(reading.kind, GestureKind::Beat);
+        assert!((reading.period_secs - 1.0).abs() < 0.05);
+        assert!(reading.score > 0.9);
+
+        // Same tempo, half a beat late: period is right, phase is not
+        let off_beat = analyze(&on_beat, 0.5, 0.5).unwrap();
+        assert!((off_beat.score - 0.6).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_sensitivity_and_noise() {
+        // Small strokes only register at high sensitivity
+        let small = trail(2.5, |t| (0.0, -15.0 * (TAU * t).cos()));
+        assert!(analyze(&small, 0.0, 0.0).is_none());
+        assert!(analyze(&small, 0.0, 1.0).is_some());
+
+        // Too short to judge
+        let short = trail(0.5, |t| (0.0, -100.0 * (TAU * t).cos()));
+        assert!(analyze(&short, 0.0, 0.5).is_none());
+    }
+}
@@ -0,0 +1,1793 @@
+//! Ritual Clock
+//!
+//! A clock as an interactive ritual: the user "conducts" the passing of seconds
+//! visually, while time remains authoritative. Features 12 "chorus nodes" (hours)
+//! and 60 "beat nodes" (seconds) with gesture trails and animations. Trails
+//! that circle or beat in time with the seconds earn a live sync score.
+//! Sessions can be recorded and replayed over the live stage. A breathing
+//! mode paces box breathing on the beat ring while the overlay keeps the time.
+//! A simulated pendulum can swing behind the nodes, phase-locked to the
+//! true second and swinging wider while the user conducts. Trails are painted
+//! with a selectable brush: ink ribbon, comet, stipple or calligraphic. Two
+//! instances on the same network can duel, each stage showing both
+//! conductors' sync. Hour nodes can carry the user's own sigils and labels,
+//! turning the stage into a map of their daily rituals.
+
+mod audio;
+mod breathing;
+mod brush;
+mod drawing;
+mod duel;
+mod external;
+mod gesture;
+mod lore;
+mod particles;
+mod pendulum;
+mod session;
+mod stage;
+mod ui;
+
+use std::time::{Duration, Instant};
+
+use chrono_tz::Tz;
+use nannou::event::ModifiersState;
+use nannou::prelude::*;
+use nannou_egui::{self, egui, Egui};
+use serde::{Deserialize, Serialize};
+use shared::{
+    compute_time_data, report_save, tr, tr_args, ClipRecorder, ClipSettings, ClipStep,
+    CommandPalette, CommandRegistry, ConfigRecovery, ConfigSaver, Favorites, FocusRegions,
+    FollowSystemZone, FramePacer, KeyChord, Keymap, LanguageSetting, MotionPreference, QuietHours,
+    RecoveryChoice, SafePaletteSetting, ScreenReader, SnapshotProgress, SnapshotStep,
+    SystemMotionWatcher, SystemZoneWatcher, ThemeLibrary, TickGranularity, Ticker, TimeData,
+    TimeReveal, VisualIntensity, WindowPlacement, WindowTitle, WindowTitleClock,
+};
+
+use crate::audio::{select_chime, AudioSettings, ChimeEngine};
+use crate::breathing::{format_duration, BreathingSession, BreathingSettings, BreathingStats};
+use crate::brush::{Brush, TRAIL_LIFETIME};
+use crate::duel::{ConductorScore, Duel, DuelStatus};
+use crate::external::{midi_port_names, ExternalOutput, ExternalSettings};
+use crate::gesture::{analyze, GestureReading, GestureSample};
+use crate::lore::LoreBook;
+use crate::particles::{ParticleSettings, ParticleSystem};
+use crate::pendulum::{gesture_activity, PendulumRig, PendulumSettings};
+use crate::session::{Recorder, Recording, Replay};
+use crate::stage::{StageGeometry, StageLayout};
+use crate::ui::PickerState;
+
+const CLOCK_NAME: &str = "ritual_clock";
+const DEFAULT_TZ: &str = "America/Los_Angeles";
+const WINDOW_TITLE: &str = "Ritual Clock";
+const CONDUCTOR_PANEL_HEIGHT: f32 = 120.0;
+/// Session files are saved and loaded as `<export dir>/ritual_clock_session.ritual`
+const SESSION_LABEL: &str = "session";
+const SESSION_EXTENSION: &str = "ritual";
+
+/// Run the clock; returns only when the window is closed
+pub fn run() {
+    nannou::app(model).update(update).run();
+}
+
+/// Launcher entry for this clock
+pub struct RitualClock;
+
+impl shared::ClockApp for RitualClock {
+    fn id(&self) -> &'static str {
+        CLOCK_NAME
+    }
+
+    fn title(&self) -> &'static str {
+        "Ritual Clock"
+    }
+
+    fn tagline(&self) -> &'static str {
+        "Conduct the passing of seconds across hour and beat nodes"
+    }
+
+    fn run(&self) {
+        run();
+    }
+}
+
+/// A point in the gesture trail
+#[derive(Debug, Clone)]
+pub struct TrailPoint {
+    pub x: f32,
+    pub y: f32,
+    pub instant: Instant,
+}
+
+/// Toast notification for error messages
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub created: Instant,
+    pub duration_secs: f32,
+}
+
+impl Toast {
+    pub fn new(message: String, duration_secs: f32) -> Self {
+        Self {
+            message,
+            created: Instant::now(),
+            duration_secs,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.created.elapsed().as_secs_f32() > self.duration_secs
+    }
+
+    pub fn alpha(&self) -> f32 {
+        let elapsed = self.created.elapsed().as_secs_f32();
+        if elapsed > self.duration_secs - 0.5 {
+            // Fade out in last 0.5s
+            ((self.duration_secs - elapsed) / 0.5).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Focus region for keyboard navigation
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FocusRegion {
+    /// Stage area (center)
+    #[default]
+    Stage,
+    /// Conductor panel (bottom)
+    ConductorPanel,
+}
+
+impl FocusRegions for FocusRegion {
+    const ALL: &'static [Self] = &[FocusRegion::Stage, FocusRegion::ConductorPanel];
+
+    fn label(self) -> &'static str {
+        match self {
+            FocusRegion::Stage => "Stage",
+            FocusRegion::ConductorPanel => "Conductor panel",
+        }
+    }
+}
+
+/// Persisted configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Config {
+    selected_zone_id: String,
+    favorites: Vec<String>,
+    /// Keep `favorites` instead of following the series-wide list
+    #[serde(default)]
+    own_favorites: bool,
+    gesture_sensitivity: f32,
+    overlay_always_on: bool,
+    /// How hour and beat nodes are arranged
+    #[serde(default)]
+    stage_layout: StageLayout,
+    reduced_motion: MotionPreference,
+    trails_enabled_in_reduced_motion: bool,
+    /// Trail style and brush parameters
+    #[serde(default)]
+    brush: Brush,
+    /// Particle bursts on the beat
+    #[serde(default)]
+    particles: ParticleSettings,
+    /// Pendulums swinging behind the nodes
+    #[serde(default)]
+    pendulum: PendulumSettings,
+    #[serde(default)]
+    audio: AudioSettings,
+    /// OSC/MIDI output to external instruments
+    #[serde(default)]
+    external: ExternalSettings,
+    /// Best sync score (0..1) reached while conducting
+    #[serde(default)]
+    best_sync_score: f32,
+    /// Selected theme name; `None` uses the clock's own colors
+    #[serde(default)]
+    theme: Option<String>,
+    /// Paced breathing pattern and session length
+    #[serde(default)]
+    breathing: BreathingSettings,
+    /// Totals across finished breathing sessions
+    #[serde(default)]
+    breathing_stats: BreathingStats,
+    /// Sigils, labels and notes for the hour nodes
+    #[serde(default)]
+    lore: LoreBook,
+    /// The chime panel's own quiet hours, set by `migrate` from a version 1
+    /// file until they are carried over to the shared schedule
+    #[serde(default, skip_serializing)]
+    legacy_quiet_hours: Option<LegacyQuietHours>,
+}
+
+/// Quiet hours as the chime panel kept them in version 1
+#[derive(Debug, Clone, Deserialize)]
+struct LegacyQuietHours {
+    enabled: bool,
+    start_hour: u32,
+    end_hour: u32,
+}
+
+impl LegacyQuietHours {
+    /// Carry these hours over to the shared schedule, returning whether it
+    /// changed
+    ///
+    /// Only hours that were actually silencing chimes (enabled, with chimes
+    /// unmuted) move over, and only while the shared schedule is off, so one
+    /// already set from another clock is kept.
+    fn adopt_into(&self, quiet_hours: &mut QuietHours, chimes_muted: bool) -> bool {
+        if quiet_hours.enabled || !self.enabled || chimes_muted || self.start_hour == self.end_hour
+        {
+            return false;
+        }
+        quiet_hours.enabled = true;
+        quiet_hours.start_minute = self.start_hour % 24 * 60;
+        quiet_hours.end_minute = self.end_hour % 24 * 60;
+        true
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            selected_zone_id: DEFAULT_TZ.to_string(),
+            favorites: vec![
+                "America/New_York".to_string(),
+                "America/Los_Angeles".to_string(),
+                "Europe/London".to_string(),
+                "Asia/Tokyo".to_string(),
+            ],
+            own_favorites: false,
+            gesture_sensitivity: 0.5,
+            overlay_always_on: false,
+            stage_layout: StageLayout::default(),
+            reduced_motion: MotionPreference::Auto,
+            trails_enabled_in_reduced_motion: false,
+            brush: Brush::default(),
+            particles: ParticleSettings::default(),
+            pendulum: PendulumSettings::default(),
+            audio: AudioSettings::default(),
+            external: ExternalSettings::default(),
+            best_sync_score: 0.0,
+            theme: None,
+            breathing: BreathingSettings::default(),
+            breathing_stats: BreathingStats::default(),
+            lore: LoreBook::default(),
+            legacy_quiet_hours: None,
+        }
+    }
+}
+
+impl shared::VersionedConfig for Config {
+    const VERSION: u32 = 3;
+
+    /// Version 2 dropped the chime panel's quiet hours for the shared
+    /// schedule; they are set aside in `legacy_quiet_hours` to carry over.
+    /// Version 3 names the reduced-motion choice; earlier files hold a bool.
+    fn migrate(from_version: u32, payload: &mut toml::Table) {
+        shared::migrate_reduced_motion(payload);
+        if from_version != 1 {
+            return;
+        }
+        let Some(audio) = payload.get_mut("audio").and_then(toml::Value::as_table_mut) else {
+            return;
+        };
+        let enabled = audio.remove("quiet_hours_enabled");
+        let start_hour = audio.remove("quiet_start_hour");
+        let end_hour = audio.remove("quiet_end_hour");
+        if let (Some(enabled), Some(start_hour), Some(end_hour)) = (enabled, start_hour, end_hour) {
+            let mut legacy = toml::Table::new();
+            legacy.insert("enabled".to_string(), enabled);
+            legacy.insert("start_hour".to_string(), start_hour);
+            legacy.insert("end_hour".to_string(), end_hour);
+            payload.insert("legacy_quiet_hours".to_string(), toml::Value::Table(legacy));
+        }
+    }
+}
+
+/// Application state
+pub struct Model {
+    /// Selected time zone
+    pub selected_zone: Tz,
+    /// Favorite time zones
+    pub favorites: Favorites,
+    /// Current time data
+    pub time_data: TimeData,
+    /// Previous time data (for detecting second/minute boundaries)
+    pub prev_second: u32,
+    pub prev_minute: u32,
+
+    /// Animation state
+    pub beat_pulse_start: Option<Instant>,
+    pub beat_pulse_index: usize,
+    pub hour_shimmer_start: Option<Instant>,
+    pub hour_shimmer_index: usize,
+    /// Particle burst preferences and the live particles
+    pub particle_settings: ParticleSettings,
+    pub particles: ParticleSystem,
+    /// Pendulum preferences and the simulated pendulums
+    pub pendulum_settings: PendulumSettings,
+    pub pendulum: PendulumRig,
+
+    /// Gesture trail points
+    pub trail_points: Vec<TrailPoint>,
+    /// How trails are painted
+    pub brush: Brush,
+    pub is_pointer_down: bool,
+    pub last_trail_sample: Option<Instant>,
+
+    /// Latest analysis of the trail's rhythm, if it has one
+    pub gesture_reading: Option<GestureReading>,
+    /// Smoothed sync score (0..1) shown while conducting
+    pub sync_score: f32,
+    /// Best smoothed sync score, persisted
+    pub best_sync_score: f32,
+    /// Best score improved since the last save
+    best_sync_dirty: bool,
+    /// Networked duel with another conductor, if one is hosted or joined
+    pub duel: Option<Duel>,
+    /// Session code being typed in to join a duel
+    duel_code_input: String,
+    /// Built-in and user themes
+    themes: ThemeLibrary,
+    /// Active theme name; `None` uses the clock's own colors
+    theme: Option<String>,
+
+    /// UI state
+    pub gesture_sensitivity: f32,
+    pub overlay_always_on: bool,
+    pub overlay_visible: bool,
+    /// How hour and beat nodes are arranged on the stage
+    pub stage_layout: StageLayout,
+    pub overlay_last_interaction: Option<Instant>,
+    pub highlighted_hour: Option<usize>,
+    /// Hour node under the pointer
+    pub hovered_hour: Option<usize>,
+    /// What each hour node stands for
+    pub lore: LoreBook,
+
+    /// Reduced motion setting: follow the system, or override it
+    pub motion_preference: MotionPreference,
+    /// Reports the system's reduce-motion preference
+    system_motion: SystemMotionWatcher,
+    /// Whether motion is reduced, from the setting and the system
+    pub reduced_motion: bool,
+    pub trails_enabled_in_reduced_motion: bool,
+    /// Shared quiet-hours schedule
+    pub quiet_hours: QuietHours,
+    /// Brightness and effects allowed right now by the schedule
+    pub intensity: VisualIntensity,
+    /// Shared setting for the time in the window title
+    pub window_title_clock: WindowTitleClock,
+    /// Shared settings for clips recorded with Ctrl+Shift+R
+    pub clip_settings: ClipSettings,
+    /// Clip being recorded or saved, if any
+    pub clip_recorder: ClipRecorder,
+    /// Shared language for labels, dates and toasts
+    pub language: LanguageSetting,
+    /// Shared high-contrast or color-blind safe palette
+    pub safe_palette: SafePaletteSetting,
+    /// Keeps the window title's time current
+    window_title: WindowTitle,
+    /// Remembers the window's size, position and monitor
+    window_placement: WindowPlacement,
+    /// Shared setting for switching to the system zone when it changes
+    follow_system_zone: FollowSystemZone,
+    /// Reports changes to the computer's time zone
+    system_zone: SystemZoneWatcher,
+    /// Shared system-wide shortcut that shows the time from the background
+    time_reveal: TimeReveal,
+    /// Drops the frame rate while the clock sits idle
+    frame_pacer: FramePacer,
+    /// Writes config changes once they settle
+    config_saver: ConfigSaver,
+    /// Shown when the saved config couldn't be read
+    config_recovery: Option<ConfigRecovery>,
+
+    /// Chime mute/volume preferences
+    pub audio_settings: AudioSettings,
+    /// OSC/MIDI output preferences
+    pub external_settings: ExternalSettings,
+    /// MIDI output ports offered in the conductor panel
+    midi_ports: Vec<String>,
+    /// Whether an egui text field has keyboard focus (suppresses shortcuts)
+    text_input_focused: bool,
+
+    /// Time zone switching animation
+    pub retune_start: Option<Instant>,
+    pub retune_delta_offset: i32,
+
+    /// Picker state
+    pub picker_state: PickerState,
+    /// Ctrl+K command palette
+    command_palette: CommandPalette,
+    /// Actions listed in the command palette, and the chords they're bound to
+    keymap: Keymap,
+
+    /// Focus region for keyboard navigation
+    pub focus_region: FocusRegion,
+
+    /// Window focus state
+    pub window_focused: bool,
+
+    /// Session being recorded, if any
+    recorder: Option<Recorder>,
+    /// Session loaded for replay, if any
+    pub replay: Option<Replay>,
+
+    /// Breathing pattern and session length preferences
+    pub breathing_settings: BreathingSettings,
+    /// Totals across finished breathing sessions, persisted
+    pub breathing_stats: BreathingStats,
+    /// Breathing session in progress, if any
+    pub breathing: Option<BreathingSession>,
+
+    /// Animation time (seconds since start)
+    pub animation_time: f32,
+
+    /// Toast notifications
+    pub toasts: Vec<Toast>,
+
+    /// Error state: TZ data validity
+    pub tz_error: bool,
+
+    /// Last valid timezone (for fallback)
+    pub last_valid_zone: Tz,
+
+    /// Wall-clock ticker driving beat and shimmer detection
+    ticker: Ticker,
+    /// Frame counter for `--snapshot` runs
+    snapshot: SnapshotProgress,
+
+    /// Audio output for chimes (None without the `audio` feature or a device)
+    chimes: Option<ChimeEngine>,
+    /// Open OSC/MIDI outputs for external instruments
+    external: ExternalOutput,
+
+    /// Screen reader view of the time, DST status and focus
+    screen_reader: ScreenReader,
+
+    /// egui integration
+    egui: Egui,
+}
+
+impl Model {
+    /// Whether something on screen is moving on its own: a beat pulse, hour
+    /// shimmer or retune still playing out, particles, pendulums or a trail,
+    /// a replay or breathing session, a toast, or a clip being captured
+    pub fn is_animating(&self, now: Instant) -> bool {
+        // Lengths of the pulse, shimmer and retune as drawn
+        let playing = |start: Option<Instant>, millis: u64| {
+            start.is_some_and(|start| now.duration_since(start) < Duration::from_millis(millis))
+        };
+        playing(self.beat_pulse_start, 360)
+            || playing(self.hour_shimmer_start, 600)
+            || playing(self.retune_start, 300)
+            || self.particles_active()
+            || self.pendulum_active()
+            || !self.trail_points.is_empty()
+            || self.replay.is_some()
+            || self.breathing.is_some()
+            || !self.toasts.is_empty()
+            || self.clip_recorder.is_recording()
+            || self.time_reveal.is_flashing()
+    }
+
+    /// Set a new timezone with retune animation
+    pub fn set_timezone(&mut self, tz: Tz) {
+        let old_offset = self.time_data.utc_offset_minutes;
+        self.selected_zone = tz;
+        self.time_data = compute_time_data(tz);
+        let new_offset = self.time_data.utc_offset_minutes;
+
+        // Check validity
+        if self.time_data.validity == shared::Validity::Ok {
+            self.last_valid_zone = tz;
+            self.tz_error = false;
+        } else {
+            // TZ data issue - show error
+            self.tz_error = true;
+            self.show_toast(tr("Timezone data may be stale or missing").to_string());
+        }
+
+        // Trigger retune animation
+        if !self.motion_reduced() && old_offset != new_offset {
+            self.retune_start = Some(Instant::now());
+            self.retune_delta_offset = new_offset - old_offset;
+        }
+
+        save_config(self);
+    }
+
+    /// Try to set a timezone from a string, with error handling
+    pub fn try_set_timezone(&mut self, tz_str: &str) {
+        match tz_str.parse::<Tz>() {
+            Ok(tz) => self.set_timezone(tz),
+            Err(_) => {
+                // Invalid TZ - revert to last valid and show toast
+                self.show_toast(tr_args("Invalid timezone: {0}. Reverting.", &[&tz_str]));
+                self.selected_zone = self.last_valid_zone;
+                self.time_data = compute_time_data(self.last_valid_zone);
+            }
+        }
+    }
+
+    /// Show a toast notification
+    pub fn show_toast(&mut self, message: String) {
+        self.toasts.push(Toast::new(message, 4.0));
+    }
+
+    /// Prune expired toasts
+    pub fn prune_toasts(&mut self) {
+        self.toasts.retain(|t| !t.is_expired());
+    }
+
+    /// Toggle favorite status for a zone
+    pub fn toggle_favorite(&mut self, tz: Tz) {
+        self.favorites.toggle(tz);
+        save_config(self);
+    }
+
+    /// Cycle hour highlight
+    pub fn cycle_hour_highlight(&mut self, delta: i32) {
+        let current = self.highlighted_hour.unwrap_or(0) as i32;
+        let new_hour = (current + delta).rem_euclid(12) as usize;
+        self.highlight_hour(new_hour);
+    }
+
+    /// Highlight an hour node and show the overlay
+    pub fn highlight_hour(&mut self, hour: usize) {
+        self.highlighted_hour = Some(hour);
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record_highlight(self.highlighted_hour);
+        }
+        self.trigger_overlay();
+    }
+
+    /// Stage geometry for the window in the chosen layout
+    pub fn stage_geometry(&self, window_rect: Rect) -> StageGeometry {
+        StageGeometry::calculate(window_rect, CONDUCTOR_PANEL_HEIGHT, self.stage_layout)
+    }
+
+    /// Toggle overlay always-on
+    pub fn toggle_overlay_always_on(&mut self) {
+        self.overlay_always_on = !self.overlay_always_on;
+        if self.overlay_always_on {
+            self.overlay_visible = true;
+        }
+        save_config(self);
+    }
+
+    /// Trigger overlay visibility (for interactions)
+    pub fn trigger_overlay(&mut self) {
+        self.overlay_visible = true;
+        self.overlay_last_interaction = Some(Instant::now());
+    }
+
+    /// Update overlay fade; it stays up through a breathing session
+    pub fn update_overlay(&mut self) {
+        if self.overlay_always_on || self.breathing.is_some() {
+            self.overlay_visible = true;
+            return;
+        }
+
+        if let Some(last) = self.overlay_last_interaction {
+            let elapsed = last.elapsed().as_secs_f32();
+            if elapsed > 3.0 {
+                self.overlay_visible = false;
+            }
+        }
+    }
+
+    /// Add a trail point (respecting sample rate limit)
+    pub fn add_trail_point(&mut self, x: f32, y: f32) {
+        let now = Instant::now();
+
+        // Sample rate limit: max 60 samples/sec
+        if let Some(last) = self.last_trail_sample {
+            if now.duration_since(last).as_secs_f32() < 1.0 / 60.0 {
+                return;
+            }
+        }
+
+        self.last_trail_sample = Some(now);
+
+        // Add point
+        self.trail_points.push(TrailPoint { x, y, instant: now });
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record_trail(x, y);
+        }
+
+        // Cap at 256 points
+        while self.trail_points.len() > 256 {
+            self.trail_points.remove(0);
+        }
+
+        self.trigger_overlay();
+    }
+
+    /// Prune expired trail points
+    pub fn prune_trail_points(&mut self) {
+        let now = Instant::now();
+        self.trail_points
+            .retain(|p| now.duration_since(p.instant).as_secs_f32() < TRAIL_LIFETIME);
+    }
+
+    /// Score the trail against the second beat and track the best
+    pub fn update_sync_score(&mut self) {
+        let Some(first) = self.trail_points.first() else {
+            self.gesture_reading = None;
+            self.sync_score = 0.0;
+            return;
+        };
+
+        let reference = first.instant;
+        let samples: Vec<GestureSample> = self
+            .trail_points
+            .iter()
+            .map(|p| GestureSample {
+                x: p.x,
+                y: p.y,
+                t: p.instant.duration_since(reference).as_secs_f32(),
+            })
+            .collect();
+
+        // Second boundaries happen where beat pulses start
+        let beat_anchor = match self.beat_pulse_start {
+            Some(pulse) if pulse >= reference => pulse.duration_since(reference).as_secs_f32(),
+            Some(pulse) => -reference.duration_since(pulse).as_secs_f32(),
+            None => 0.0,
+        };
+
+        self.gesture_reading = analyze(&samples, beat_anchor, self.gesture_sensitivity);
+        match self.gesture_reading {
+            Some(reading) => {
+                // Ease toward the latest reading so the score doesn't flicker
+                self.sync_score += (reading.score - self.sync_score) * 0.1;
+                if self.sync_score > self.best_sync_score {
+                    self.best_sync_score = self.sync_score;
+                    self.best_sync_dirty = true;
+                }
+            }
+            None => self.sync_score = 0.0,
+        }
+    }
+
+    /// Pointer lifted: persist a new best score
+    pub fn end_gesture(&mut self) {
+        self.is_pointer_down = false;
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record_lift();
+        }
+        if self.best_sync_dirty {
+            self.best_sync_dirty = false;
+            save_config(self);
+        }
+    }
+
+    /// Host a duel and show its session code
+    pub fn host_duel(&mut self) {
+        match Duel::host() {
+            Ok(duel) => {
+                let message = if duel.loopback_only {
+                    tr_args("Hosting duel {0} (offline: this computer only)", &[&duel.code()])
+                } else {
+                    tr_args("Hosting duel {0}", &[&duel.code()])
+                };
+                self.duel = Some(duel);
+                self.show_toast(message);
+            }
+            Err(e) => self.show_toast(tr_args("Couldn't host duel: {0}", &[&e])),
+        }
+    }
+
+    /// Join the duel whose code has been typed in
+    pub fn join_duel(&mut self) {
+        match Duel::join(&self.duel_code_input) {
+            Ok(duel) => {
+                self.show_toast(tr_args("Joining duel {0}", &[&duel.code()]));
+                self.duel = Some(duel);
+            }
+            Err(e) => self.show_toast(tr_args("Couldn't join duel: {0}", &[&e])),
+        }
+    }
+
+    /// Trade sync scores with the other conductor, reporting arrivals and losses
+    pub fn update_duel(&mut self) {
+        let now = Instant::now();
+        let score = ConductorScore {
+            sync: self.gesture_reading.map(|_| self.sync_score),
+            best: self.best_sync_score,
+        };
+        let Some(duel) = &mut self.duel else {
+            return;
+        };
+        duel.send(score, now);
+        match duel.poll(now) {
+            Some(DuelStatus::Connected) => {
+                self.show_toast(tr("Opponent joined the duel").to_string())
+            }
+            Some(DuelStatus::Lost) => {
+                self.show_toast(tr("Lost the opponent; conducting solo").to_string())
+            }
+            Some(DuelStatus::Waiting) | None => {}
+        }
+    }
+
+    /// Start recording a session, or stop and save the one in progress
+    pub fn toggle_recording(&mut self) {
+        let Some(recorder) = self.recorder.take() else {
+            self.recorder = Some(Recorder::start(self.highlighted_hour));
+            self.show_toast(tr("Recording session").to_string());
+            return;
+        };
+
+        let recording = recorder.finish();
+        let text = recording.to_text();
+        match shared::write_export(CLOCK_NAME, SESSION_LABEL, SESSION_EXTENSION, text.as_bytes()) {
+            Ok(path) => self.show_toast(tr_args("Saved session to {0}", &[&path.display()])),
+            Err(e) => self.show_toast(tr_args("Session save failed: {0}", &[&e])),
+        }
+        self.replay = Some(Replay::new(recording));
+    }
+
+    /// Play or pause the replay, loading the saved session first if needed
+    pub fn toggle_replay(&mut self) {
+        if self.replay.is_none() {
+            match load_session() {
+                Ok(recording) => self.replay = Some(Replay::new(recording)),
+                Err(e) => {
+                    self.show_toast(tr_args("Couldn't load session: {0}", &[&e]));
+                    return;
+                }
+            }
+        }
+        if let Some(replay) = &mut self.replay {
+            replay.toggle_playing();
+        }
+    }
+
+    /// Start a breathing session on the latest beat, or stop the one running
+    pub fn toggle_breathing(&mut self) {
+        if self.breathing.is_some() {
+            self.end_breathing();
+            return;
+        }
+        let beat = self.beat_pulse_start.unwrap_or_else(Instant::now);
+        self.breathing = Some(BreathingSession::start(beat, self.breathing_settings));
+        self.particles.clear();
+        self.show_toast(tr("Breathing session started").to_string());
+    }
+
+    /// Stop the breathing session and add it to the statistics
+    pub fn end_breathing(&mut self) {
+        let Some(session) = self.breathing.take() else {
+            return;
+        };
+        let now = Instant::now();
+        let secs = session.elapsed_secs(now) as u32;
+        let cycles = session.state(now).cycle;
+        self.breathing_stats.record(secs, cycles);
+        save_config(self);
+        self.show_toast(tr_args(
+            "Breathing session: {0}, {1} cycles",
+            &[&format_duration(secs), &cycles],
+        ));
+    }
+
+    /// Whether pulses and shimmer are off, by preference or quiet hours
+    pub fn motion_reduced(&self) -> bool {
+        self.reduced_motion || !self.intensity.animations
+    }
+
+    /// Apply a reduced-motion setting against the current system preference
+    pub fn set_motion_preference(&mut self, preference: MotionPreference) {
+        self.motion_preference = preference;
+        self.reduced_motion = preference.resolve(self.system_motion.reduced());
+    }
+
+    /// Whether beats emit particle bursts instead of the scale pulse; the
+    /// beat ring shows the breath instead during a breathing session
+    pub fn particles_active(&self) -> bool {
+        self.particle_settings.enabled && !self.motion_reduced() && self.breathing.is_none()
+    }
+
+    /// Whether the pendulums swing; in reduced motion they hang still
+    pub fn pendulum_active(&self) -> bool {
+        self.pendulum_settings.enabled && !self.motion_reduced()
+    }
+
+    /// Length of the live gesture trail, in pixels
+    pub fn trail_length(&self) -> f32 {
+        self.trail_points
+            .windows(2)
+            .map(|pair| vec2(pair[1].x - pair[0].x, pair[1].y - pair[0].y).length())
+            .sum()
+    }
+
+    /// Check if trails should be drawn
+    pub fn should_draw_trails(&self) -> bool {
+        if self.reduced_motion {
+            self.trails_enabled_in_reduced_motion
+        } else {
+            true
+        }
+    }
+
+    /// Cycle focus region
+    pub fn cycle_focus_region(&mut self, reverse: bool) {
+        self.focus_region = match (self.focus_region, reverse) {
+            (FocusRegion::Stage, false) => FocusRegion::ConductorPanel,
+            (FocusRegion::ConductorPanel, false) => FocusRegion::Stage,
+            (FocusRegion::Stage, true) => FocusRegion::ConductorPanel,
+            (FocusRegion::ConductorPanel, true) => FocusRegion::Stage,
+        };
+    }
+}
+
+/// Read the last saved session
+fn load_session() -> Result<Recording, String> {
+    let path = shared::export_path(CLOCK_NAME, SESSION_LABEL, SESSION_EXTENSION)
+        .ok_or_else(|| "no export directory".to_string())?;
+    let text = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    Recording::parse(&text)
+}
+
+fn save_config(model: &Model) {
+    let config = Config {
+        selected_zone_id: model.selected_zone.name().to_string(),
+        favorites: model.favorites.own_ids(),
+        own_favorites: model.favorites.uses_own(),
+        gesture_sensitivity: model.gesture_sensitivity,
+        overlay_always_on: model.overlay_always_on,
+        stage_layout: model.stage_layout,
+        reduced_motion: model.motion_preference,
+        trails_enabled_in_reduced_motion: model.trails_enabled_in_reduced_motion,
+        brush: model.brush,
+        particles: model.particle_settings,
+        pendulum: model.pendulum_settings,
+        audio: model.audio_settings.clone(),
+        external: model.external_settings.clone(),
+        best_sync_score: model.best_sync_score,
+        theme: model.theme.clone(),
+        breathing: model.breathing_settings,
+        breathing_stats: model.breathing_stats,
+        lore: model.lore.clone(),
+        legacy_quiet_hours: None,
+    };
+    model.config_saver.request(&config);
+}
+
+fn model(app: &App) -> Model {
+    // Disable default escape-to-exit behavior
+    app.set_exit_on_escape(false);
+
+    // Create window
+    let window_id = app
+        .new_window()
+        .title(WINDOW_TITLE)
+        .size(1000, 800)
+        .min_size(600, 500)
+        .view(view)
+        .key_pressed(key_pressed)
+        .mouse_pressed(mouse_pressed)
+        .mouse_released(mouse_released)
+        .mouse_moved(mouse_moved)
+        .mouse_wheel(mouse_wheel)
+        .raw_event(raw_window_event)
+        .visible(false)
+        .build()
+        .unwrap();
+
+    let window = app.window(window_id).unwrap();
+    let egui = Egui::from_window(&window);
+    shared::install_fallback_fonts(egui.ctx());
+
+    // Load configuration, noting an unreadable file for the recovery prompt
+    let (config, config_recovery) = shared::load_config_or_recover(CLOCK_NAME);
+    let config: Config = config.unwrap_or_default();
+
+    // Parse timezone from config
+    let selected_zone: Tz = config
+        .selected_zone_id
+        .parse()
+        .unwrap_or_else(|_| DEFAULT_TZ.parse().unwrap());
+
+    // The shared favorites, or this clock's own
+    let favorites = Favorites::load(&config.favorites, config.own_favorites);
+
+    let mut quiet_hours = QuietHours::load();
+    let mut toasts = Vec::new();
+    if let Some(legacy) = &config.legacy_quiet_hours {
+        if legacy.adopt_into(&mut quiet_hours, config.audio.muted) {
+            report_save("quiet hours", quiet_hours.save(), |message| {
+                toasts.push(Toast::new(message, 4.0));
+            });
+        }
+    }
+
+    // Get initial time data
+    let themes = ThemeLibrary::load();
+    let theme = themes.activate(config.theme.as_deref());
+    let system_motion = SystemMotionWatcher::spawn();
+
+    let time_data = compute_time_data(selected_zone);
+    let prev_second = time_data.second;
+    let prev_minute = time_data.minute;
+
+    let mut external = ExternalOutput::default();
+    external.configure(&config.external);
+
+    // Screen reader tree, attached before the window is first shown
+    let screen_reader = ScreenReader::attach(
+        window.winit_window(),
+        "Ritual Clock",
+        &time_data,
+        Some(FocusRegion::default()),
+    );
+
+    // Reopen where the window was left, or where the command line asks
+    let mut window_placement = WindowPlacement::load(CLOCK_NAME);
+    window_placement.restore(window.winit_window());
+    window.set_visible(shared::snapshot_request().is_none());
+
+    let wake_proxy = app.create_proxy();
+    Model {
+        selected_zone,
+        favorites,
+        time_data,
+        prev_second,
+        prev_minute,
+        beat_pulse_start: None,
+        beat_pulse_index: 0,
+        hour_shimmer_start: None,
+        hour_shimmer_index: 0,
+        particle_settings: config.particles,
+        particles: ParticleSystem::default(),
+        pendulum_settings: config.pendulum,
+        pendulum: PendulumRig::default(),
+        trail_points: Vec::new(),
+        brush: config.brush,
+        is_pointer_down: false,
+        last_trail_sample: None,
+        gesture_reading: None,
+        sync_score: 0.0,
+        best_sync_score: config.best_sync_score,
+        themes,
+        theme,
+        best_sync_dirty: false,
+        duel: None,
+        duel_code_input: String::new(),
+        gesture_sensitivity: config.gesture_sensitivity,
+        overlay_always_on: config.overlay_always_on,
+        overlay_visible: config.overlay_always_on,
+        stage_layout: config.stage_layout,
+        overlay_last_interaction: None,
+        highlighted_hour: None,
+        hovered_hour: None,
+        lore: config.lore.normalized(),
+        motion_preference: config.reduced_motion,
+        reduced_motion: config.reduced_motion.resolve(system_motion.reduced()),
+        system_motion,
+        trails_enabled_in_reduced_motion: config.trails_enabled_in_reduced_motion,
+        quiet_hours,
+        intensity: VisualIntensity::FULL,
+        window_title_clock: WindowTitleClock::load(),
+        clip_settings: ClipSettings::load(),
+        clip_recorder: ClipRecorder::new(CLOCK_NAME),
+        language: LanguageSetting::load(),
+        safe_palette: SafePaletteSetting::load(),
+        window_title: WindowTitle::new(WINDOW_TITLE),
+        window_placement,
+        follow_system_zone: FollowSystemZone::load(),
+        system_zone: SystemZoneWatcher::spawn(),
+        time_reveal: TimeReveal::load(),
+        frame_pacer: FramePacer::spawn(move || wake_proxy.wakeup().is_ok()),
+        config_saver: ConfigSaver::new(CLOCK_NAME),
+        config_recovery,
+        audio_settings: config.audio,
+        external_settings: config.external,
+        midi_ports: midi_port_names(),
+        text_input_focused: false,
+        retune_start: None,
+        retune_delta_offset: 0,
+        picker_state: PickerState::default(),
+        command_palette: CommandPalette::default(),
+        keymap: Keymap::load(CLOCK_NAME, commands()),
+        focus_region: FocusRegion::default(),
+        window_focused: true,
+        recorder: None,
+        replay: None,
+        breathing_settings: config.breathing,
+        breathing_stats: config.breathing_stats,
+        breathing: None,
+        animation_time: 0.0,
+        toasts,
+        tz_error: false,
+        last_valid_zone: selected_zone,
+        ticker: Ticker::spawn(TickGranularity::Second),
+        snapshot: SnapshotProgress::default(),
+        chimes: ChimeEngine::new(),
+        external,
+        screen_reader,
+        egui,
+    }
+}
+
+/// Drive a `--snapshot` run: capture once the frame has settled, then quit
+fn advance_snapshot(app: &App, progress: &mut SnapshotProgress) {
+    let Some(request) = shared::snapshot_request() else {
+        return;
+    };
+    match progress.advance() {
+        SnapshotStep::Settle => {}
+        SnapshotStep::Capture => app.main_window().capture_frame(&request.path),
+        SnapshotStep::Finish => {
+            if app.main_window().await_capture_frame_jobs().is_err() {
+                eprintln!("Timed out writing snapshot to {}", request.path.display());
+                std::process::exit(1);
+            }
+            println!("Saved snapshot to {}", request.path.display());
+            app.quit();
+        }
+    }
+}
+
+/// Capture frames for a clip being recorded, and report the clip once saved
+fn advance_clip(app: &App, model: &mut Model) {
+    match model.clip_recorder.advance(Instant::now()) {
+        ClipStep::Idle => {}
+        ClipStep::Capture(path) => app.main_window().capture_frame(path),
+        ClipStep::Finish => {
+            let written = app.main_window().await_capture_frame_jobs().is_ok();
+            model.clip_recorder.encode(written);
+        }
+    }
+    if let Some(message) = model.clip_recorder.poll() {
+        model.show_toast(message);
+    }
+}
+
+/// Let the event loop wait between second boundaries while the clock is idle
+fn pace_frames(app: &App, model: &mut Model, now: Instant) {
+    let animating = model.is_animating(now);
+    let loop_mode = if model.frame_pacer.pace(animating, now) {
+        LoopMode::Wait
+    } else {
+        LoopMode::RefreshSync
+    };
+    app.set_loop_mode(loop_mode);
+}
+
+/// Act on the config recovery prompt: restart on the backup, or carry on
+/// with the defaults
+fn recover_config(app: &App, model: &mut Model, choice: RecoveryChoice) {
+    let Some(recovery) = model.config_recovery.take() else {
+        return;
+    };
+    if choice == RecoveryChoice::Restore {
+        match recovery.restore(&model.config_saver) {
+            Ok(()) => app.quit(),
+            Err(message) => model.show_toast(message),
+        }
+    }
+}
+
+fn update(app: &App, model: &mut Model, update: Update) {
+    advance_snapshot(app, &mut model.snapshot);
+    advance_clip(app, model);
+
+    // Update animation time
+    model.animation_time = update.since_start.as_secs_f32();
+
+    // Update time data and detect boundaries once per wall-clock second
+    if model.ticker.poll().is_some() {
+        model.time_data = compute_time_data(model.selected_zone);
+        model.intensity = model.quiet_hours.intensity(&model.time_data);
+
+        // Detect second boundary for beat pulse
+        if model.time_data.second != model.prev_second {
+            model.beat_pulse_start = Some(Instant::now());
+            model.beat_pulse_index = model.time_data.second as usize;
+            model.prev_second = model.time_data.second;
+            if model.particles_active() {
+                let geometry = model.stage_geometry(app.window_rect());
+                let (bx, by) = geometry.beat_positions[model.beat_pulse_index];
+                model.particles.burst(
+                    vec2(bx, by),
+                    vec2(geometry.cx, geometry.cy),
+                    model.gesture_sensitivity,
+                    geometry.stage_size,
+                    model.particle_settings.budget,
+                    random_f32,
+                );
+            }
+
+            // Sound the beat, minute or hour chime unless quiet hours
+            // silence them
+            if let Some(engine) = model.chimes.as_ref().filter(|_| model.intensity.sounds) {
+                if let Some(chime) =
+                    select_chime(&model.time_data, &model.audio_settings, model.reduced_motion)
+                {
+                    engine.play(chime);
+                }
+            }
+            model.external.emit(&model.time_data, &model.external_settings);
+        }
+
+        // Detect minute boundary for hour shimmer
+        if model.time_data.minute != model.prev_minute && model.time_data.second == 0 {
+            model.hour_shimmer_start = Some(Instant::now());
+            // hIndex = (hour12 % 12) where 12 maps to 0
+            model.hour_shimmer_index = (model.time_data.hour12 % 12) as usize;
+            model.prev_minute = model.time_data.minute;
+        }
+    }
+
+    // End the breathing session once it reaches its length
+    if model.breathing.is_some_and(|session| session.is_complete(Instant::now())) {
+        model.end_breathing();
+    }
+
+    // Move particles along; none linger once bursts are off
+    if model.particles_active() {
+        model.particles.step(update.since_last.as_secs_f32());
+    } else {
+        model.particles.clear();
+    }
+
+    // Swing the pendulums on the true second, wider while conducting
+    if model.pendulum_active() {
+        let stage_size = model.stage_geometry(app.window_rect()).stage_size;
+        model.pendulum.step(
+            update.since_last.as_secs_f32(),
+            PendulumRig::clock_phase(shared::now_utc()),
+            model.pendulum_settings.count,
+            gesture_activity(model.trail_length(), stage_size),
+        );
+    } else {
+        model.pendulum.clear();
+    }
+
+    // Move the replay along
+    if let Some(replay) = &mut model.replay {
+        replay.advance(update.since_last.as_millis() as u32);
+    }
+
+    // Prune expired trail points and score what's left
+    model.prune_trail_points();
+    model.update_sync_score();
+    model.update_duel();
+
+    // Prune expired toasts
+    model.prune_toasts();
+
+    // Update overlay fade
+    model.update_overlay();
+
+    // Begin egui frame
+    model.egui.set_elapsed_time(update.since_start);
+    let ctx = model.egui.begin_frame();
+
+    let mut visuals = egui::Visuals::dark();
+    ui::theme_visuals(&mut visuals);
+    ctx.set_visuals(visuals);
+
+    // Draw conductor panel UI
+    let mut own_favorites = model.favorites.uses_own();
+    let mut reveal_hotkey = model.time_reveal.setting.clone();
+    let ui_result = ui::draw_conductor_panel(
+        &ctx,
+        &mut model.picker_state,
+        model.selected_zone,
+        &model.favorites,
+        &model.time_data,
+        &mut model.gesture_sensitivity,
+        &mut model.overlay_always_on,
+        &mut model.stage_layout,
+        &mut model.motion_preference,
+        model.system_motion.reduced(),
+        &mut model.trails_enabled_in_reduced_motion,
+        &mut model.brush,
+        &mut model.particle_settings,
+        &mut model.pendulum_settings,
+        &mut model.audio_settings,
+        model.chimes.is_some(),
+        &mut model.external_settings,
+        model.external.error.as_deref(),
+        &model.midi_ports,
+        model.gesture_reading.map(|_| model.sync_score),
+        model.best_sync_score,
+        &mut model.quiet_hours,
+        &mut model.window_title_clock,
+        &mut model.clip_settings,
+        &mut model.language,
+        &mut model.safe_palette,
+        &mut model.follow_system_zone,
+        &mut reveal_hotkey,
+        model.time_reveal.status(),
+        &mut own_favorites,
+        &model.keymap,
+        &model.themes,
+        model.theme.as_deref(),
+        model.recorder.is_some(),
+        model.replay.as_ref(),
+        &mut model.breathing_settings,
+        model.breathing.is_some(),
+        &model.breathing_stats,
+        &mut model.lore,
+        model.highlighted_hour,
+        model.duel.as_ref(),
+        &mut model.duel_code_input,
+    );
+
+    // Draw the time card while the reveal shortcut flashes the window
+    model.time_reveal.show(&ctx, &model.time_data);
+
+    // Offer the backup if the saved config couldn't be read
+    let recovery_choice = model.config_recovery.as_ref().and_then(|r| r.show(&ctx));
+
+    // Draw command palette (if open)
+    let palette_choice = model.command_palette.show(&ctx, model.keymap.registry());
+
+    model.text_input_focused = ctx.wants_keyboard_input();
+    drop(ctx);
+    if let Some(choice) = recovery_choice {
+        recover_config(app, model, choice);
+    }
+
+    // Apply UI results
+    if let Some(tz) = ui_result.set_timezone {
+        model.set_timezone(tz);
+    }
+    if let Some(tz) = ui_result.toggle_favorite {
+        model.toggle_favorite(tz);
+    }
+    if ui_result.sensitivity_changed {
+        save_config(model);
+    }
+    if ui_result.overlay_changed {
+        if model.overlay_always_on {
+            model.overlay_visible = true;
+        }
+        save_config(model);
+    }
+    if ui_result.stage_layout_changed {
+        save_config(model);
+    }
+    if ui_result.reduced_motion_changed {
+        model.set_motion_preference(model.motion_preference);
+        save_config(model);
+    }
+    if ui_result.audio_changed {
+        save_config(model);
+    }
+    if ui_result.brush_changed {
+        save_config(model);
+    }
+    if ui_result.particles_changed {
+        save_config(model);
+    }
+    if ui_result.pendulum_changed {
+        save_config(model);
+    }
+    if ui_result.quiet_hours_changed {
+        model.intensity = model.quiet_hours.intensity(&model.time_data);
+        report_save("quiet hours", model.quiet_hours.save(), |message| model.show_toast(message));
+    }
+    if ui_result.window_title_changed {
+        report_save(
+            "window title setting",
+            model.window_title_clock.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if ui_result.clip_settings_changed {
+        report_save(
+            "recording settings",
+            model.clip_settings.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if ui_result.language_changed {
+        report_save("language setting", model.language.save(), |message| model.show_toast(message));
+    }
+    if ui_result.safe_palette_changed {
+        report_save(
+            "palette setting",
+            model.safe_palette.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if ui_result.system_zone_changed {
+        report_save(
+            "system zone setting",
+            model.follow_system_zone.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if ui_result.reveal_hotkey_changed {
+        model.time_reveal.setting = reveal_hotkey;
+        model.time_reveal.apply();
+    }
+    if ui_result.favorites_source_changed {
+        model.favorites.set_uses_own(own_favorites);
+        save_config(model);
+    }
+    if let Some(edit) = ui_result.keymap_edit {
+        model.keymap.apply(edit);
+    }
+    if ui_result.refresh_midi_ports {
+        model.midi_ports = midi_port_names();
+    }
+    if ui_result.external_changed {
+        model.external.configure(&model.external_settings);
+        save_config(model);
+    }
+    if ui_result.reset_best_sync {
+        model.best_sync_score = 0.0;
+        model.best_sync_dirty = false;
+        save_config(model);
+    }
+    if let Some(theme) = ui_result.set_theme {
+        model.theme = model.themes.activate(theme.as_deref());
+        save_config(model);
+    }
+
+    if ui_result.toggle_recording {
+        model.toggle_recording();
+    }
+    if ui_result.toggle_replay {
+        model.toggle_replay();
+    }
+    if let (Some(fraction), Some(replay)) = (ui_result.seek_replay, &mut model.replay) {
+        replay.seek(fraction);
+    }
+    if ui_result.close_replay {
+        model.replay = None;
+    }
+    if ui_result.breathing_changed {
+        save_config(model);
+    }
+    if ui_result.toggle_breathing {
+        model.toggle_breathing();
+    }
+    if ui_result.reset_breathing_stats {
+        model.breathing_stats = BreathingStats::default();
+        save_config(model);
+    }
+    if let Some(hour) = ui_result.highlight_hour {
+        model.highlight_hour(hour);
+    }
+    if ui_result.lore_changed {
+        save_config(model);
+    }
+    if ui_result.host_duel {
+        model.host_duel();
+    }
+    if ui_result.join_duel {
+        model.join_duel();
+    }
+    if ui_result.leave_duel {
+        model.duel = None;
+        model.show_toast(tr("Left the duel").to_string());
+    }
+
+    // Run the command chosen in the palette as if its keys were pressed
+    if let Some(chord) = palette_choice {
+        handle_key(model, chord.key, chord.mods);
+    }
+
+    // Keep the screen reader's time, DST status and focus current
+    model.screen_reader.update(&model.time_data, Some(model.focus_region));
+
+    // Pick up favorites starred in another clock
+    model.favorites.poll();
+
+    // Follow the computer's time zone when it changes
+    if let Some(change) = model.system_zone.poll() {
+        let switch = model.follow_system_zone.enabled && change.current != model.selected_zone;
+        if switch {
+            model.set_timezone(change.current);
+        }
+        model.show_toast(change.message(switch));
+    }
+
+    // Follow the system reduce-motion setting when set to Auto
+    if model.system_motion.poll() {
+        model.set_motion_preference(model.motion_preference);
+    }
+
+    // Show the current time in the taskbar/dock title, and any clip recording
+    let badge = model.clip_recorder.indicator(Instant::now());
+    model.window_title.set_badge(badge);
+    model.window_title.update(
+        app.main_window().winit_window(),
+        &model.window_title_clock,
+        model.selected_zone,
+        shared::now_utc(),
+    );
+
+    // Show or end the reveal shortcut's flash
+    model.time_reveal.update(app.main_window().winit_window(), Instant::now());
+
+    // Remember where the window was moved or resized to
+    model.window_placement.track(app.main_window().winit_window(), Instant::now());
+
+    // Write config changes once they have settled
+    model.config_saver.poll(Instant::now());
+
+    // Idle between second boundaries once nothing is moving
+    pace_frames(app, model, Instant::now());
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    let window_rect = app.window_rect();
+
+    // Calculate stage geometry
+    let geometry = model.stage_geometry(window_rect);
+
+    // Draw background
+    draw.background().color(drawing::colors::background());
+
+    // Draw the stage (nodes and trails)
+    drawing::draw_stage(
+        &draw,
+        &geometry,
+        model,
+    );
+
+    // Draw digital overlay if visible
+    if model.overlay_visible {
+        drawing::draw_overlay(
+            &draw,
+            &geometry,
+            &model.time_data,
+            model.highlighted_hour,
+            &model.lore,
+            model.overlay_always_on,
+        );
+    }
+
+    // Draw title
+    draw.text("RITUAL CLOCK")
+        .x_y(0.0, window_rect.top() - 25.0)
+        .color(drawing::colors::text_primary())
+        .font_size(18)
+        .w(300.0);
+    if model.recorder.is_some() {
+        drawing::draw_recording_indicator(&draw, window_rect);
+    }
+
+    // Dim the stage during quiet hours
+    if model.intensity.is_quiet() {
+        draw.rect()
+            .xy(window_rect.xy())
+            .wh(window_rect.wh())
+            .color(srgba(0.0, 0.0, 0.0, model.intensity.dim_alpha()));
+    }
+
+    // Draw error banner if TZ data issue
+    if model.tz_error {
+        drawing::draw_error_banner(&draw, window_rect);
+    }
+
+    // Draw toast notifications
+    drawing::draw_toasts(&draw, window_rect, &model.toasts);
+
+    // Render to frame
+    draw.to_frame(app, &frame).unwrap();
+
+    // Render egui on top
+    model.egui.draw_to_frame(&frame).unwrap();
+}
+
+/// Every action in the command palette, with the keys that perform it
+fn commands() -> CommandRegistry {
+    CommandRegistry::new()
+        .register("Open timezone picker", KeyChord::new(Key::T))
+        .register("Next hour highlight", KeyChord::new(Key::H))
+        .register("Toggle time overlay always on", KeyChord::new(Key::S))
+        .register("Next focus region", KeyChord::new(Key::Tab))
+        .register("Previous focus region", KeyChord::shift(Key::Tab))
+        .register("Show highlighted hour", KeyChord::new(Key::Return))
+        .register("Hide time overlay", KeyChord::new(Key::Escape))
+        .register("Start/stop recording session", KeyChord::new(Key::R))
+        .register("Play/pause session replay", KeyChord::new(Key::P))
+        .register("Start/stop breathing session", KeyChord::new(Key::B))
+        .register("Record clip", KeyChord::ctrl_shift(Key::R))
+}
+
+fn key_pressed(app: &App, model: &mut Model, key: Key) {
+    let mods = app.keys.mods;
+
+    // While a shortcut is being rebound, the next chord becomes its binding
+    if model.keymap.capture(key, mods) {
+        return;
+    }
+
+    // Ctrl+K opens the command palette from anywhere; while open it takes
+    // every other key
+    if KeyChord::is_palette_toggle(key, mods) {
+        model.command_palette.toggle();
+        return;
+    }
+    if model.command_palette.is_open {
+        return;
+    }
+
+    // Don't treat typing an OSC target or prefix as shortcuts
+    if model.text_input_focused && !model.picker_state.is_open {
+        return;
+    }
+
+    // Remapped chords reach the handler as their action's default chord
+    if let Some(chord) = model.keymap.translate(key, mods) {
+        handle_key(model, chord.key, chord.mods);
+    }
+}
+
+fn handle_key(model: &mut Model, key: Key, mods: ModifiersState) {
+    match key {
+        // Ctrl+Shift+R - start or stop recording a clip
+        _ if shared::is_clip_toggle(key, mods) => {
+            let message = model.clip_recorder.toggle(model.clip_settings, Instant::now());
+            model.show_toast(message);
+        }
+
+        // T - open timezone picker
+        Key::T if !model.picker_state.is_open => {
+            model.picker_state.open();
+        }
+
+        // H - cycle hour highlights
+        Key::H => {
+            model.cycle_hour_highlight(1);
+        }
+
+        // S - toggle overlay always-on
+        Key::S => {
+            model.toggle_overlay_always_on();
+        }
+
+        // R - start/stop recording a session
+        Key::R => {
+            model.toggle_recording();
+        }
+
+        // P - play/pause the session replay
+        Key::P => {
+            model.toggle_replay();
+        }
+
+        // B - start/stop a breathing session
+        Key::B => {
+            model.toggle_breathing();
+        }
+
+        // Arrow keys - cycle hour highlight when stage focused
+        Key::Left if model.focus_region == FocusRegion::Stage => {
+            model.cycle_hour_highlight(-1);
+        }
+        Key::Right if model.focus_region == FocusRegion::Stage => {
+            model.cycle_hour_highlight(1);
+        }
+
+        // Tab - cycle focus regions
+        Key::Tab if !model.picker_state.is_open => {
+            model.cycle_focus_region(mods.shift());
+        }
+
+        // Escape - close picker or overlay
+        Key::Escape => {
+            if model.picker_state.is_open {
+                model.picker_state.close();
+            } else if model.overlay_visible && !model.overlay_always_on {
+                model.overlay_visible = false;
+            }
+        }
+
+        // / - focus search in picker
+        Key::Slash => {
+            if model.picker_state.is_open {
+                model.picker_state.should_focus_search = true;
+            } else {
+                model.picker_state.open();
+            }
+        }
+
+        // Enter/Space - activate (for accessibility)
+        Key::Return | Key::Space if model.focus_region == FocusRegion::Stage => {
+            if let Some(hour) = model.highlighted_hour {
+                model.highlighted_hour = Some(hour);
+                model.trigger_overlay();
+            }
+        }
+
+        _ => {}
+    }
+}
+
+fn mouse_pressed(app: &App, model: &mut Model, button: MouseButton) {
+    if button == MouseButton::Left {
+        model.is_pointer_down = true;
+
+        let pos = app.mouse.position();
+        let window_rect = app.window_rect();
+        let geometry = model.stage_geometry(window_rect);
+
+        // Check if clicking on an hour node
+        if let Some(hour_idx) = geometry.hit_test_hour_node(pos.x, pos.y) {
+            model.highlight_hour(hour_idx);
+        }
+
+        // Start trail
+        model.add_trail_point(pos.x, pos.y);
+    } else if button == MouseButton::Middle {
+        // Rotary press: toggle overlay
+        model.toggle_overlay_always_on();
+    }
+}
+
+fn mouse_released(_app: &App, model: &mut Model, button: MouseButton) {
+    if button == MouseButton::Left {
+        model.end_gesture();
+    }
+}
+
+fn mouse_moved(app: &App, model: &mut Model, pos: Point2) {
+    let geometry = model.stage_geometry(app.window_rect());
+    model.hovered_hour = geometry.hit_test_hour_node(pos.x, pos.y);
+
+    if model.is_pointer_down && model.should_draw_trails() {
+        model.add_trail_point(pos.x, pos.y);
+    }
+}
+
+fn mouse_wheel(_app: &App, model: &mut Model, delta: MouseScrollDelta, _phase: TouchPhase) {
+    // Rotary input: cycle hour highlights or adjust sensitivity
+    let scroll_y = match delta {
+        MouseScrollDelta::LineDelta(_, y) => y,
+        MouseScrollDelta::PixelDelta(pos) => pos.y as f32 / 50.0,
+    };
+
+    if model.focus_region == FocusRegion::ConductorPanel {
+        // Adjust sensitivity
+        model.gesture_sensitivity = (model.gesture_sensitivity + scroll_y * 0.1).clamp(0.0, 1.0);
+        save_config(model);
+    } else {
+        // Cycle hour highlight
+        if scroll_y > 0.5 {
+            model.cycle_hour_highlight(-1);
+        } else if scroll_y < -0.5 {
+            model.cycle_hour_highlight(1);
+        }
+    }
+}
+
+fn raw_window_event(app: &App, model: &mut Model, event: &nannou::winit::event::WindowEvent) {
+    // Any window event brings an idle clock back to full frame rate
+    model.frame_pacer.note_input(Instant::now());
+
+    model.egui.handle_raw_event(event);
+    model.screen_reader.process_event(app.main_window().winit_window(), event);
+
+    // Track window focus for resync
+    match event {
+        nannou::winit::event::WindowEvent::Focused(focused) => {
+            model.window_focused = *focused;
+            if *focused {
+                // Resync time on focus
+                model.time_data = compute_time_data(model.selected_zone);
+                model.prev_second = model.time_data.second;
+                model.prev_minute = model.time_data.minute;
+            }
+        }
+        // Handle touch events (map to mouse-like behavior)
+        nannou::winit::event::WindowEvent::Touch(touch) => {
+            let window_rect = app.window_rect();
+            let geometry = model.stage_geometry(window_rect);
+
+            // Convert touch position to nannou coordinates
+            let pos_x = touch.location.x as f32 - window_rect.w() / 2.0;
+            let pos_y = window_rect.h() / 2.0 - touch.location.y as f32;
+
+            match touch.phase {
+                nannou::winit::event::TouchPhase::Started => {
+                    model.is_pointer_down = true;
+
+                    // Check if touching an hour node
+                    if let Some(hour_idx) = geometry.hit_test_hour_node(pos_x, pos_y) {
+                        model.highlight_hour(hour_idx);
+                    }
+
+                    // Start trail
+                    if model.should_draw_trails() {
+                        model.add_trail_point(pos_x, pos_y);
+                    }
+                }
+                nannou::winit::event::TouchPhase::Moved => {
+                    if model.is_pointer_down && model.should_draw_trails() {
+                        model.add_trail_point(pos_x, pos_y);
+                    }
+                }
+                nannou::winit::event::TouchPhase::Ended | nannou::winit::event::TouchPhase::Cancelled => {
+                    model.end_gesture();
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A version 1 config with the chime panel's quiet hours set to 23–06 and
+    /// the legacy reduced-motion bool
+    const V1_CONFIG: &str = r#"
+version = 1
+
+[payload]
+selected_zone_id = "Europe/London"
+favorites = []
+gesture_sensitivity = 0.5
+overlay_always_on = false
+reduced_motion = true
+trails_enabled_in_reduced_motion = false
+
+[payload.audio]
+muted = false
+beat_volume = 0.3
+minute_volume = 0.6
+hour_volume = 0.8
+quiet_hours_enabled = true
+quiet_start_hour = 23
+quiet_end_hour = 6
+"#;
+
+    #[test]
+    fn test_v1_config_migrates() {
+        let config: Config = shared::decode_config(V1_CONFIG).unwrap();
+        assert_eq!(config.reduced_motion, MotionPreference::On);
+        let legacy = config.legacy_quiet_hours.as_ref().expect("quiet hours set aside");
+
+        let mut quiet_hours = QuietHours::default();
+        assert!(legacy.adopt_into(&mut quiet_hours, config.audio.muted));
+        assert!(quiet_hours.enabled);
+        assert_eq!(quiet_hours.window_label(), "23:00–06:00");
+
+        // Saved again, the old fields are gone for good
+        let encoded = shared::encode_config(&config).unwrap();
+        assert!(encoded.starts_with("version = 3"));
+        assert!(!encoded.contains("quiet"));
+    }
+
+    #[test]
+    fn test_legacy_quiet_hours_keep_shared_schedule() {
+        let legacy = LegacyQuietHours {
+            enabled: true,
+            start_hour: 22,
+            end_hour: 7,
+        };
+        let shared_schedule = QuietHours {
+            enabled: true,
+            start_minute: 21 * 60,
+            ..QuietHours::default()
+        };
+
+        // A schedule set from another clock wins
+        let mut quiet_hours = shared_schedule.clone();
+        assert!(!legacy.adopt_into(&mut quiet_hours, false));
+        assert_eq!(quiet_hours, shared_schedule);
+
+        // Muted chimes were never silenced by the old hours
+        let mut quiet_hours = QuietHours::default();
+        assert!(!legacy.adopt_into(&mut quiet_hours, true));
+        assert!(!quiet_hours.enabled);
+    }
+}
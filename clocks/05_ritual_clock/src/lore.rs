@@ -0,0 +1,135 @@
+//! Lore module - personal labels for the hour nodes
+//!
+//! Each of the twelve hour nodes can carry a sigil, a short label and a
+//! note: the ritual or household task that belongs to that hour. Sigils sit
+//! beside their nodes on the stage like the stars of a constellation; the
+//! label and note appear when a node is hovered or highlighted, and the
+//! overlay names the ritual of the current hour. A node stands for both its
+//! morning and its evening hour, as on a dial.
+
+use serde::{Deserialize, Serialize};
+
+/// Longest sigil, in characters; a glyph or two
+pub const MAX_SIGIL_CHARS: usize = 3;
+/// Longest label, in characters
+pub const MAX_LABEL_CHARS: usize = 32;
+/// Longest note, in characters
+pub const MAX_NOTE_CHARS: usize = 160;
+
+const HOURS: usize = 12;
+
+/// What one hour node stands for
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HourLore {
+    /// Glyph drawn beside the node
+    pub sigil: String,
+    /// Name of the ritual or task
+    pub label: String,
+    /// A line or two about it
+    pub note: String,
+}
+
+impl HourLore {
+    pub fn is_empty(&self) -> bool {
+        [&self.sigil, &self.label, &self.note].iter().all(|text| text.trim().is_empty())
+    }
+
+    /// Sigil and label together ("☕ Morning tea"), if either is set
+    pub fn title(&self) -> Option<String> {
+        let parts: Vec<&str> = [self.sigil.trim(), self.label.trim()]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect();
+        (!parts.is_empty()).then(|| parts.join(" "))
+    }
+
+    /// Keep each field within its length, whatever the config held
+    fn clamp_lengths(&mut self) {
+        for (text, max) in [
+            (&mut self.sigil, MAX_SIGIL_CHARS),
+            (&mut self.label, MAX_LABEL_CHARS),
+            (&mut self.note, MAX_NOTE_CHARS),
+        ] {
+            if let Some((end, _)) = text.char_indices().nth(max) {
+                text.truncate(end);
+            }
+        }
+    }
+}
+
+/// Lore for the twelve hour nodes, indexed as the nodes are: 0 is twelve
+/// o'clock
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct LoreBook {
+    hours: Vec<HourLore>,
+}
+
+impl LoreBook {
+    /// Lore for an hour node, if any was written for it
+    pub fn hour(&self, index: usize) -> Option<&HourLore> {
+        self.hours.get(index).filter(|lore| !lore.is_empty())
+    }
+
+    /// Lore for an hour node to edit
+    pub fn hour_mut(&mut self, index: usize) -> &mut HourLore {
+        let index = index % HOURS;
+        if self.hours.len() < HOURS {
+            self.hours.resize_with(HOURS, HourLore::default);
+        }
+        &mut self.hours[index]
+    }
+
+    /// Twelve nodes' worth, each within its lengths; for lore loaded from a
+    /// hand-edited config
+    pub fn normalized(mut self) -> Self {
+        self.hours.truncate(HOURS);
+        for lore in &mut self.hours {
+            lore.clamp_lengths();
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_joins_sigil_and_label() {
+        let mut book = LoreBook::default();
+        assert!(book.hour(7).is_none());
+
+        let tea = book.hour_mut(7);
+        tea.sigil = "☕".to_string();
+        tea.label = " Morning tea ".to_string();
+        assert_eq!(book.hour(7).and_then(HourLore::title), Some("☕ Morning tea".to_string()));
+
+        // A note alone is lore without a title
+        book.hour_mut(3).note = "Water the plants".to_string();
+        assert!(book.hour(3).is_some());
+        assert_eq!(book.hour(3).and_then(HourLore::title), None);
+
+        // Blanked fields leave the node unlabelled again
+        *book.hour_mut(7) = HourLore { sigil: " ".to_string(), ..HourLore::default() };
+        assert!(book.hour(7).is_none());
+    }
+
+    #[test]
+    fn test_loaded_lore_is_normalized() {
+        let mut hours = vec![HourLore::default(); 14];
+        hours[0].sigil = "✦✦✦✦✦".to_string();
+        hours[0].label = "x".repeat(40);
+        let book = LoreBook { hours }.normalized();
+        assert_eq!(book.hours.len(), HOURS);
+        let midnight = book.hour(0).unwrap();
+        assert_eq!(midnight.sigil, "✦✦✦");
+        assert_eq!(midnight.label.len(), MAX_LABEL_CHARS);
+
+        // Short lists from older configs grow when an hour is edited
+        let mut book = LoreBook { hours: vec![HourLore::default(); 2] }.normalized();
+        book.hour_mut(11).label = "Lights out".to_string();
+        assert_eq!(book.hour(11).unwrap().label, "Lights out");
+    }
+}
@@ -0,0 +1,164 @@
+//! Particles module - bursts emitted from the beat node on each second
+//!
+//! When enabled, every beat throws a spray of particles outward from the
+//! node that just lit, in place of the node's scale pulse. Gesture
+//! sensitivity sets how many particles a burst has and how fast they fly;
+//! the particle budget caps how many can be alive at once. Bursts are never
+//! emitted in reduced motion.
+//!
+//! Randomness is passed in as a closure returning values in 0..1, so bursts
+//! are deterministic under test.
+
+use nannou::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Range of the particle budget slider
+pub const MIN_BUDGET: usize = 20;
+pub const MAX_BUDGET: usize = 600;
+
+/// Particles per burst at sensitivity 0 and 1
+const BURST_MIN: f32 = 6.0;
+const BURST_MAX: f32 = 36.0;
+/// Seconds a particle lives, before jitter
+const LIFETIME: f32 = 0.9;
+/// Fraction of velocity kept after one second
+const DRAG_PER_SEC: f32 = 0.08;
+
+/// Persisted particle preferences
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ParticleSettings {
+    pub enabled: bool,
+    /// Most particles alive at once
+    pub budget: usize,
+}
+
+impl Default for ParticleSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            budget: 240,
+        }
+    }
+}
+
+/// One particle, in window coordinates
+#[derive(Debug, Clone, Copy)]
+pub struct Particle {
+    pub position: Vec2,
+    pub velocity: Vec2,
+    /// Seconds since emission
+    pub age: f32,
+    pub lifetime: f32,
+    /// Radius at emission; shrinks as the particle ages
+    pub size: f32,
+}
+
+impl Particle {
+    /// 1 when emitted, 0 at the end of its life
+    pub fn life_left(&self) -> f32 {
+        (1.0 - self.age / self.lifetime).clamp(0.0, 1.0)
+    }
+}
+
+/// All live particles
+#[derive(Debug, Default)]
+pub struct ParticleSystem {
+    particles: Vec<Particle>,
+}
+
+impl ParticleSystem {
+    pub fn particles(&self) -> &[Particle] {
+        &self.particles
+    }
+
+    /// Number of particles a burst emits at `sensitivity` (0..1)
+    pub fn burst_size(sensitivity: f32) -> usize {
+        (BURST_MIN + (BURST_MAX - BURST_MIN) * sensitivity.clamp(0.0, 1.0)).round() as usize
+    }
+
+    /// Emit a burst from `origin`, spraying away from `center`
+    ///
+    /// `scale` is the stage size, so speeds and sizes follow the window.
+    /// Only as many particles as the budget has room for are emitted.
+    pub fn burst(
+        &mut self,
+        origin: Vec2,
+        center: Vec2,
+        sensitivity: f32,
+        scale: f32,
+        budget: usize,
+        mut random: impl FnMut() -> f32,
+    ) {
+        let room = budget.saturating_sub(self.particles.len());
+        let count = Self::burst_size(sensitivity).min(room);
+        let outward = (origin - center).try_normalize().unwrap_or(Vec2::Y);
+        let outward_angle = outward.y.atan2(outward.x);
+        let speed_scale = scale * (0.15 + 0.35 * sensitivity.clamp(0.0, 1.0));
+
+        for _ in 0..count {
+            // Mostly outward, fanning up to 70 degrees either side
+            let spread = (random() - 0.5) * 2.0 * 70.0_f32.to_radians();
+            let angle = outward_angle + spread;
+            let direction = vec2(angle.cos(), angle.sin());
+            let speed = speed_scale * (0.4 + 0.6 * random());
+            self.particles.push(Particle {
+                position: origin,
+                velocity: direction * speed,
+                age: 0.0,
+                lifetime: LIFETIME * (0.6 + 0.8 * random()),
+                size: scale * (0.003 + 0.004 * random()),
+            });
+        }
+    }
+
+    /// Move particles on by `dt` seconds and drop the expired ones
+    pub fn step(&mut self, dt: f32) {
+        let drag = DRAG_PER_SEC.powf(dt);
+        for particle in &mut self.particles {
+            particle.position += particle.velocity * dt;
+            particle.velocity *= drag;
+            particle.age += dt;
+        }
+        self.particles.retain(|p| p.age < p.lifetime);
+    }
+
+    pub fn clear(&mut self) {
+        self.particles.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn half() -> f32 {
+        0.5
+    }
+
+    #[test]
+    fn test_sensitivity_sets_burst_size() {
+        assert_eq!(ParticleSystem::burst_size(0.0), 6);
+        assert_eq!(ParticleSystem::burst_size(1.0), 36);
+        assert!(ParticleSystem::burst_size(0.5) > ParticleSystem::burst_size(0.2));
+    }
+
+    #[test]
+    fn test_budget_caps_live_particles() {
+        let mut system = ParticleSystem::default();
+        for _ in 0..10 {
+            system.burst(vec2(100.0, 0.0), Vec2::ZERO, 1.0, 500.0, 50, half);
+        }
+        assert_eq!(system.particles().len(), 50);
+    }
+
+    #[test]
+    fn test_particles_fly_outward_and_expire() {
+        let mut system = ParticleSystem::default();
+        system.burst(vec2(0.0, 100.0), Vec2::ZERO, 0.5, 500.0, 100, half);
+        system.step(0.1);
+        assert!(system.particles().iter().all(|p| p.position.y > 100.0));
+        system.step(LIFETIME * 2.0);
+        assert!(system.particles().is_empty());
+    }
+}
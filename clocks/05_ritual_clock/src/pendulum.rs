@@ -0,0 +1,252 @@
+//! Pendulum module - a simulated seconds pendulum swinging behind the stage
+//!
+//! One pendulum, or a short row of coupled ones, is integrated as a damped
+//! physical system with a natural period of two seconds, so each swing ends
+//! on a whole second. Left alone the simulation would drift: wide swings run
+//! slow and frame times jitter. A phase-locked loop compares each bob's phase
+//! with the wall clock and trims its restoring frequency, keeping the ends of
+//! the swing on the true second. An energy pump holds the swing near a target
+//! amplitude, which grows while the user conducts and relaxes when the stage
+//! is still. In coupled rigs each bob follows a little behind its neighbour.
+
+use std::f32::consts::{PI, TAU};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Most pendulums in a coupled rig
+pub const MAX_PENDULUMS: usize = 3;
+
+/// Natural angular frequency in rad/s: a two-second period
+const OMEGA: f32 = PI;
+/// Swing, in radians, with no gestures and at full gesture activity
+pub const REST_AMPLITUDE: f32 = 0.18;
+const ACTIVE_AMPLITUDE: f32 = 0.55;
+/// How quickly the target swing follows gesture activity, per second
+const AMPLITUDE_EASE_PER_SEC: f32 = 1.5;
+/// Velocity damping, and the pump gain that makes up for it
+const DAMPING: f32 = 0.15;
+const PUMP: f32 = 2.0;
+/// Fractional frequency trim per radian of phase error, and its limit
+const LOCK_GAIN: f32 = 0.5;
+const MAX_TRIM: f32 = 0.2;
+/// Spring between neighbouring bobs, in rad/s² per radian of difference
+const COUPLING: f32 = 0.8;
+/// Phase, in radians, each bob trails the one before it
+const FOLLOWER_LAG: f32 = 0.35;
+/// Longest integration step; frames are split into steps no longer than this
+const MAX_STEP: f32 = 1.0 / 240.0;
+/// Frames longer than this (a stall or a paused window) restart the rig on the clock
+const MAX_FRAME: f32 = 0.5;
+/// Trail length, in stage sizes, that counts as full gesture activity
+const FULL_ACTIVITY_TRAIL: f32 = 3.0;
+
+/// Persisted pendulum preferences
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PendulumSettings {
+    pub enabled: bool,
+    /// Number of coupled pendulums (1..=MAX_PENDULUMS)
+    pub count: usize,
+}
+
+impl Default for PendulumSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            count: 1,
+        }
+    }
+}
+
+/// One pendulum's state
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bob {
+    /// Radians from vertical, positive to the right
+    pub angle: f32,
+    /// Radians per second
+    pub velocity: f32,
+}
+
+impl Bob {
+    /// Place a bob at `phase` of a swing of `amplitude`
+    fn at_phase(phase: f32, amplitude: f32) -> Self {
+        Self {
+            angle: amplitude * phase.cos(),
+            velocity: -amplitude * OMEGA * phase.sin(),
+        }
+    }
+
+    /// Phase in the swing: 0 at the right end, π at the left
+    pub fn phase(&self) -> f32 {
+        (-self.velocity / OMEGA).atan2(self.angle)
+    }
+
+    /// Current swing from the angle and velocity
+    pub fn amplitude(&self) -> f32 {
+        self.angle.hypot(self.velocity / OMEGA)
+    }
+}
+
+/// The simulated pendulums
+#[derive(Debug, Default)]
+pub struct PendulumRig {
+    bobs: Vec<Bob>,
+    /// Swing the pump is currently aiming for
+    target_amplitude: f32,
+}
+
+impl PendulumRig {
+    pub fn bobs(&self) -> &[Bob] {
+        &self.bobs
+    }
+
+    pub fn clear(&mut self) {
+        self.bobs.clear();
+    }
+
+    /// Where the wall clock is in the two-second swing: 0 on even seconds, π on odd
+    pub fn clock_phase(now: DateTime<Utc>) -> f32 {
+        let seconds = now.timestamp().rem_euclid(2) as f32;
+        let fraction = now.timestamp_subsec_nanos() as f32 / 1_000_000_000.0;
+        OMEGA * (seconds + fraction)
+    }
+
+    /// Phase the bob at `index` is locked to
+    fn target_phase(clock_phase: f32, index: usize) -> f32 {
+        clock_phase - index as f32 * FOLLOWER_LAG
+    }
+
+    /// Put `count` bobs exactly on the clock at the resting swing
+    fn restart(&mut self, count: usize, clock_phase: f32) {
+        self.target_amplitude = REST_AMPLITUDE;
+        self.bobs = (0..count)
+            .map(|index| Bob::at_phase(Self::target_phase(clock_phase, index), REST_AMPLITUDE))
+            .collect();
+    }
+
+    /// Advance by `dt` seconds, ending at `clock_phase`
+    ///
+    /// `activity` (0..1) is how busily the user is conducting; the swing
+    /// grows with it.
+    pub fn step(&mut self, dt: f32, clock_phase: f32, count: usize, activity: f32) {
+        let count = count.clamp(1, MAX_PENDULUMS);
+        if self.bobs.len() != count || !(0.0..=MAX_FRAME).contains(&dt) {
+            self.restart(count, clock_phase);
+            return;
+        }
+
+        let activity = activity.clamp(0.0, 1.0);
+        let goal = REST_AMPLITUDE + (ACTIVE_AMPLITUDE - REST_AMPLITUDE) * activity;
+        let ease = 1.0 - (-AMPLITUDE_EASE_PER_SEC * dt).exp();
+        self.target_amplitude += (goal - self.target_amplitude) * ease;
+
+        let steps = (dt / MAX_STEP).ceil().max(1.0) as usize;
+        let h = dt / steps as f32;
+        for step in 1..=steps {
+            // The clock's phase at the end of this step
+            let phase = clock_phase - OMEGA * (dt - h * step as f32);
+            let angles: Vec<f32> = self.bobs.iter().map(|bob| bob.angle).collect();
+
+            for (index, bob) in self.bobs.iter_mut().enumerate() {
+                let error = wrap_phase(Self::target_phase(phase, index) - bob.phase());
+                let trim = (LOCK_GAIN * error).clamp(-MAX_TRIM, MAX_TRIM);
+                let omega = OMEGA * (1.0 + trim);
+
+                let shortfall = (self.target_amplitude - bob.amplitude()) / self.target_amplitude;
+                let mut acceleration = -omega * omega * bob.angle.sin()
+                    + (PUMP * shortfall - DAMPING) * bob.velocity;
+
+                let neighbours = [index.checked_sub(1), Some(index + 1)];
+                for neighbour in neighbours.into_iter().flatten() {
+                    if let Some(angle) = angles.get(neighbour) {
+                        acceleration += COUPLING * (angle - bob.angle);
+                    }
+                }
+
+                // Semi-implicit Euler keeps the swing's energy steady
+                bob.velocity += acceleration * h;
+                bob.angle += bob.velocity * h;
+            }
+        }
+    }
+}
+
+/// Gesture activity (0..1) from the length of the live trail, in pixels
+pub fn gesture_activity(trail_length: f32, stage_size: f32) -> f32 {
+    if stage_size <= 0.0 {
+        return 0.0;
+    }
+    (trail_length / (stage_size * FULL_ACTIVITY_TRAIL)).clamp(0.0, 1.0)
+}
+
+/// Wrap a phase difference into -π..π
+fn wrap_phase(phase: f32) -> f32 {
+    (phase + PI).rem_euclid(TAU) - PI
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run the rig for `seconds` with uneven frame times, returning the clock phase reached
+    fn run(rig: &mut PendulumRig, seconds: f32, count: usize, activity: f32) -> f32 {
+        let mut clock = 0.0;
+        let frames = [1.0 / 60.0, 1.0 / 30.0, 1.0 / 144.0];
+        let mut elapsed = 0.0;
+        let mut frame = 0;
+        rig.step(0.0, clock, count, activity);
+        while elapsed < seconds {
+            let dt = frames[frame % frames.len()];
+            elapsed += dt;
+            clock += OMEGA * dt;
+            rig.step(dt, clock, count, activity);
+            frame += 1;
+        }
+        clock
+    }
+
+    #[test]
+    fn test_swing_stays_locked_to_the_second() {
+        let mut rig = PendulumRig::default();
+        let clock = run(&mut rig, 30.0, 1, 0.0);
+        let bob = rig.bobs()[0];
+        assert!(wrap_phase(clock - bob.phase()).abs() < 0.05);
+        assert!((bob.amplitude() - REST_AMPLITUDE).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_conducting_widens_the_swing_without_losing_lock() {
+        let mut rig = PendulumRig::default();
+        let clock = run(&mut rig, 20.0, 1, 1.0);
+        let bob = rig.bobs()[0];
+        // Wide swings run slow on their own; the lock keeps them on time
+        assert!(bob.amplitude() > (REST_AMPLITUDE + ACTIVE_AMPLITUDE) / 2.0);
+        assert!(wrap_phase(clock - bob.phase()).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_coupled_bobs_follow_in_turn() {
+        let mut rig = PendulumRig::default();
+        let clock = run(&mut rig, 20.0, 3, 0.0);
+        assert_eq!(rig.bobs().len(), 3);
+        for (index, bob) in rig.bobs().iter().enumerate() {
+            let target = PendulumRig::target_phase(clock, index);
+            assert!(wrap_phase(target - bob.phase()).abs() < 0.1);
+        }
+
+        // Changing the count, or a long stall, restarts on the clock
+        rig.step(0.01, 0.0, 2, 0.0);
+        assert_eq!(rig.bobs().len(), 2);
+        rig.step(5.0, PI, 2, 0.0);
+        assert!((rig.bobs()[0].angle + REST_AMPLITUDE).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_gesture_activity() {
+        assert_eq!(gesture_activity(0.0, 600.0), 0.0);
+        assert_eq!(gesture_activity(900.0, 600.0), 0.5);
+        assert_eq!(gesture_activity(9000.0, 600.0), 1.0);
+        assert_eq!(gesture_activity(100.0, 0.0), 0.0);
+    }
+}
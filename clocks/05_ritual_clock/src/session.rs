@@ -0,0 +1,358 @@
+//! Session module - recording and replaying conducting sessions
+//!
+//! A recording is the stream of trail samples, pointer lifts and hour
+//! highlights with millisecond offsets from the start of the session. It is
+//! saved as a small line-based text file:
+//!
+//! ```text
+//! ritual-session 1
+//! t 0 -120.5 48.0
+//! t 16 -118.0 52.5
+//! u 480
+//! h 900 3
+//! h 1500 -
+//! ```
+//!
+//! Positions are window coordinates (origin at the center), so a replay
+//! lines up with the live stage when the window keeps its size.
+
+use std::fmt::Write as _;
+use std::time::Instant;
+
+/// First line of every session file
+const HEADER: &str = "ritual-session 1";
+
+/// How long a replayed trail sample stays visible, matching live trails
+pub const REPLAY_TRAIL_MS: u32 = 2000;
+
+/// One recorded moment, `t_ms` from the start of the session
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SessionEvent {
+    /// A trail sample
+    Trail { t_ms: u32, x: f32, y: f32 },
+    /// The pointer lifted, ending the current stroke
+    Lift { t_ms: u32 },
+    /// The highlighted hour changed
+    Highlight { t_ms: u32, hour: Option<usize> },
+}
+
+impl SessionEvent {
+    pub fn t_ms(&self) -> u32 {
+        match *self {
+            SessionEvent::Trail { t_ms, .. }
+            | SessionEvent::Lift { t_ms }
+            | SessionEvent::Highlight { t_ms, .. } => t_ms,
+        }
+    }
+}
+
+/// A finished session, events in time order
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Recording {
+    pub events: Vec<SessionEvent>,
+}
+
+impl Recording {
+    /// Length of the session in milliseconds
+    pub fn duration_ms(&self) -> u32 {
+        self.events.last().map(SessionEvent::t_ms).unwrap_or(0)
+    }
+
+    /// Serialize to the session file format
+    pub fn to_text(&self) -> String {
+        let mut text = String::from(HEADER);
+        text.push('\n');
+        for event in &self.events {
+            // Writing to a String can't fail
+            let _ = match *event {
+                SessionEvent::Trail { t_ms, x, y } => {
+                    writeln!(text, "t {} {:.1} {:.1}", t_ms, x, y)
+                }
+                SessionEvent::Lift { t_ms } => writeln!(text, "u {}", t_ms),
+                SessionEvent::Highlight { t_ms, hour: Some(hour) } => {
+                    writeln!(text, "h {} {}", t_ms, hour)
+                }
+                SessionEvent::Highlight { t_ms, hour: None } => writeln!(text, "h {} -", t_ms),
+            };
+        }
+        text
+    }
+
+    /// Parse a session file; the error names the first bad line
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines().enumerate();
+        match lines.next() {
+            Some((_, line)) if line.trim() == HEADER => {}
+            _ => return Err("not a ritual session file".to_string()),
+        }
+
+        let mut events: Vec<SessionEvent> = Vec::new();
+        for (index, line) in lines {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let event = parse_event(line).ok_or_else(|| format!("bad line {}", index + 1))?;
+            if events.last().is_some_and(|last| last.t_ms() > event.t_ms()) {
+                return Err(format!("line {} is out of order", index + 1));
+            }
+            events.push(event);
+        }
+        Ok(Self { events })
+    }
+}
+
+fn parse_event(line: &str) -> Option<SessionEvent> {
+    let mut fields = line.split_whitespace();
+    let kind = fields.next()?;
+    let t_ms = fields.next()?.parse().ok()?;
+    let event = match kind {
+        "t" => SessionEvent::Trail {
+            t_ms,
+            x: fields.next()?.parse().ok()?,
+            y: fields.next()?.parse().ok()?,
+        },
+        "u" => SessionEvent::Lift { t_ms },
+        "h" => SessionEvent::Highlight {
+            t_ms,
+            hour: match fields.next()? {
+                "-" => None,
+                hour => Some(hour.parse().ok().filter(|&hour: &usize| hour < 12)?),
+            },
+        },
+        _ => return None,
+    };
+    fields.next().is_none().then_some(event)
+}
+
+/// Captures events while a session is being recorded
+pub struct Recorder {
+    started: Instant,
+    recording: Recording,
+}
+
+impl Recorder {
+    /// Start recording, noting the hour highlighted at the start
+    pub fn start(highlighted_hour: Option<usize>) -> Self {
+        let mut recorder = Self {
+            started: Instant::now(),
+            recording: Recording::default(),
+        };
+        recorder.record_highlight(highlighted_hour);
+        recorder
+    }
+
+    fn elapsed_ms(&self) -> u32 {
+        self.started.elapsed().as_millis().min(u32::MAX as u128) as u32
+    }
+
+    pub fn record_trail(&mut self, x: f32, y: f32) {
+        let t_ms = self.elapsed_ms();
+        self.recording.events.push(SessionEvent::Trail { t_ms, x, y });
+    }
+
+    pub fn record_lift(&mut self) {
+        let t_ms = self.elapsed_ms();
+        self.recording.events.push(SessionEvent::Lift { t_ms });
+    }
+
+    pub fn record_highlight(&mut self, hour: Option<usize>) {
+        let t_ms = self.elapsed_ms();
+        self.recording.events.push(SessionEvent::Highlight { t_ms, hour });
+    }
+
+    /// Stop recording; the session ends now rather than at the last event
+    pub fn finish(mut self) -> Recording {
+        let t_ms = self.elapsed_ms();
+        self.recording.events.push(SessionEvent::Lift { t_ms });
+        self.recording
+    }
+}
+
+/// A trail sample as it should be drawn at the replay position
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReplayPoint {
+    pub x: f32,
+    pub y: f32,
+    /// Milliseconds since the sample, for fading
+    pub age_ms: u32,
+}
+
+/// Playback of a recording with a scrubbable position
+pub struct Replay {
+    pub recording: Recording,
+    pub position_ms: u32,
+    pub playing: bool,
+}
+
+impl Replay {
+    /// Load a recording, paused at the start
+    pub fn new(recording: Recording) -> Self {
+        Self {
+            recording,
+            position_ms: 0,
+            playing: false,
+        }
+    }
+
+    /// Move forward while playing, pausing at the end
+    pub fn advance(&mut self, dt_ms: u32) {
+        if !self.playing {
+            return;
+        }
+        self.position_ms = self.position_ms.saturating_add(dt_ms);
+        if self.position_ms >= self.recording.duration_ms() {
+            self.position_ms = self.recording.duration_ms();
+            self.playing = false;
+        }
+    }
+
+    /// Play or pause; playing from the end starts over
+    pub fn toggle_playing(&mut self) {
+        if !self.playing && self.position_ms >= self.recording.duration_ms() {
+            self.position_ms = 0;
+        }
+        self.playing = !self.playing;
+    }
+
+    /// Position as a fraction of the session
+    pub fn progress(&self) -> f32 {
+        match self.recording.duration_ms() {
+            0 => 0.0,
+            duration => self.position_ms as f32 / duration as f32,
+        }
+    }
+
+    /// Jump to a fraction of the session
+    pub fn seek(&mut self, fraction: f32) {
+        let duration = self.recording.duration_ms() as f32;
+        self.position_ms = (fraction.clamp(0.0, 1.0) * duration).round() as u32;
+    }
+
+    /// Strokes visible at the current position, each split at pointer lifts
+    pub fn visible_strokes(&self) -> Vec<Vec<ReplayPoint>> {
+        let from = self.position_ms.saturating_sub(REPLAY_TRAIL_MS);
+        let mut strokes = Vec::new();
+        let mut stroke: Vec<ReplayPoint> = Vec::new();
+
+        let visible = self
+            .recording
+            .events
+            .iter()
+            .skip_while(|event| event.t_ms() < from)
+            .take_while(|event| event.t_ms() <= self.position_ms);
+        for event in visible {
+            match *event {
+                SessionEvent::Trail { t_ms, x, y } => stroke.push(ReplayPoint {
+                    x,
+                    y,
+                    age_ms: self.position_ms - t_ms,
+                }),
+                SessionEvent::Lift { .. } if !stroke.is_empty() => {
+                    strokes.push(std::mem::take(&mut stroke));
+                }
+                _ => {}
+            }
+        }
+        if !stroke.is_empty() {
+            strokes.push(stroke);
+        }
+        strokes
+    }
+
+    /// Hour highlighted at the current position
+    pub fn highlighted_hour(&self) -> Option<usize> {
+        self.recording
+            .events
+            .iter()
+            .take_while(|event| event.t_ms() <= self.position_ms)
+            .filter_map(|event| match *event {
+                SessionEvent::Highlight { hour, .. } => Some(hour),
+                _ => None,
+            })
+            .last()
+            .flatten()
+    }
+}
+
+/// "m:ss"
+pub fn format_position(ms: u32) -> String {
+    let secs = ms / 1000;
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_recording() -> Recording {
+        Recording {
+            events: vec![
+                SessionEvent::Highlight { t_ms: 0, hour: None },
+                SessionEvent::Trail { t_ms: 100, x: -10.0, y: 20.5 },
+                SessionEvent::Trail { t_ms: 200, x: -12.0, y: 22.0 },
+                SessionEvent::Lift { t_ms: 300 },
+                SessionEvent::Highlight { t_ms: 1000, hour: Some(3) },
+                SessionEvent::Trail { t_ms: 2500, x: 40.0, y: -5.0 },
+                SessionEvent::Lift { t_ms: 3000 },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        let recording = sample_recording();
+        let text = recording.to_text();
+        assert!(text.starts_with("ritual-session 1\n"));
+        assert!(text.contains("t 100 -10.0 20.5\n"));
+        assert!(text.contains("h 0 -\n"));
+        assert_eq!(Recording::parse(&text), Ok(recording));
+
+        assert!(Recording::parse("t 0 1 2").is_err());
+        assert_eq!(
+            Recording::parse("ritual-session 1\nt 0 1\n"),
+            Err("bad line 2".to_string())
+        );
+        assert!(Recording::parse("ritual-session 1\nh 5 12\n").is_err());
+        assert!(Recording::parse("ritual-session 1\nu 50\nu 10\n").is_err());
+    }
+
+    #[test]
+    fn test_replay_shows_recent_strokes_and_highlight() {
+        let mut replay = Replay::new(sample_recording());
+        replay.seek(0.1);
+        assert_eq!(replay.position_ms, 300);
+        let strokes = replay.visible_strokes();
+        assert_eq!(strokes.len(), 1);
+        assert_eq!(strokes[0].len(), 2);
+        assert_eq!(strokes[0][0].age_ms, 200);
+        assert_eq!(replay.highlighted_hour(), None);
+
+        // The first stroke has faded out; only the second remains
+        replay.seek(2600.0 / 3000.0);
+        let strokes = replay.visible_strokes();
+        assert_eq!(strokes.len(), 1);
+        assert_eq!(strokes[0][0].x, 40.0);
+        assert_eq!(replay.highlighted_hour(), Some(3));
+    }
+
+    #[test]
+    fn test_playback_stops_at_end() {
+        let mut replay = Replay::new(sample_recording());
+        replay.advance(500);
+        assert_eq!(replay.position_ms, 0);
+
+        replay.toggle_playing();
+        replay.advance(2000);
+        assert!(replay.playing);
+        replay.advance(2000);
+        assert_eq!(replay.position_ms, 3000);
+        assert!(!replay.playing);
+        assert_eq!(replay.progress(), 1.0);
+
+        // Playing again from the end starts over
+        replay.toggle_playing();
+        assert_eq!(replay.position_ms, 0);
+        assert_eq!(format_position(65_000), "1:05");
+    }
+}
@@ -1,11 +1,50 @@
 //! Stage geometry calculations for the Ritual Clock
 //!
-//! Handles positioning of hour "chorus" nodes and beat nodes in concentric circles,
-//! as well as hit testing for interaction.
+//! Handles positioning of hour "chorus" nodes and beat nodes for each stage
+//! layout, as well as hit testing for interaction. The classic layout puts
+//! them in concentric circles; the others wind them into a spiral, trace a
+//! 12-pointed star, or line them up in a procession across the stage.
+//!
+//! Hit areas grow to a comfortable minimum but never past half the gap to
+//! the nearest neighbouring node, so tightly packed layouts still pick the
+//! node under the pointer.
 
 use nannou::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::f32::consts::PI;
 
+/// How hour and beat nodes are arranged on the stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StageLayout {
+    /// Hours inside a ring of beats
+    #[default]
+    Circle,
+    /// Hours and beats each wind once outward
+    Spiral,
+    /// Beats trace a 12-pointed star with an hour at each point
+    Star,
+    /// Hours and beats in rows across the stage
+    Procession,
+}
+
+impl StageLayout {
+    pub const ALL: [StageLayout; 4] = [
+        StageLayout::Circle,
+        StageLayout::Spiral,
+        StageLayout::Star,
+        StageLayout::Procession,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            StageLayout::Circle => "Circle",
+            StageLayout::Spiral => "Spiral",
+            StageLayout::Star => "12-pointed star",
+            StageLayout::Procession => "Procession",
+        }
+    }
+}
+
 /// Stage geometry with all calculated positions
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -30,13 +69,17 @@ pub struct StageGeometry {
     pub hour_positions: [(f32, f32); 12],
     /// Positions of the 60 beat nodes
     pub beat_positions: [(f32, f32); 60],
+    /// Hit radius for hour nodes in this layout
+    pub hour_hit_radius: f32,
+    /// Hit radius for beat nodes in this layout
+    pub beat_hit_radius: f32,
 }
 
 impl StageGeometry {
     /// Calculate geometry from window dimensions
     ///
     /// Per spec: stageSize = min(containerWidth, containerHeight - controlsHeight)
-    pub fn calculate(window_rect: Rect, controls_height: f32) -> Self {
+    pub fn calculate(window_rect: Rect, controls_height: f32, layout: StageLayout) -> Self {
         let available_width = window_rect.w();
         let available_height = window_rect.h() - controls_height - 60.0; // Account for title
 
@@ -53,27 +96,73 @@ impl StageGeometry {
 
         // Node sizes per spec
         let hour_node_radius = 0.028 * stage_size;
-        let beat_node_radius = 0.012 * stage_size;
+        let mut beat_node_radius = 0.012 * stage_size;
 
-        // Calculate hour positions (12 nodes, 30° apart)
-        // Angle 0 at top: θ0 = 90° = π/2 (in standard math coords with y-up)
-        // Clockwise means subtracting angle as index increases
+        // Hours are 30° apart and beats 6° apart in every layout but the
+        // procession. Angle 0 at top: θ0 = 90° = π/2 (in standard math
+        // coords with y-up); clockwise means subtracting angle as index increases
         let theta_0 = PI / 2.0;
-        let mut hour_positions = [(0.0f32, 0.0f32); 12];
-        for i in 0..12 {
-            // Clockwise: subtract angle (negative direction in standard coords)
-            let theta = theta_0 - (i as f32) * (30.0 * PI / 180.0);
-            hour_positions[i] = (cx + r_hour * theta.cos(), cy + r_hour * theta.sin());
-        }
+        let hour_theta = |i: usize| theta_0 - (i as f32) * (30.0 * PI / 180.0);
+        let beat_theta = |j: usize| theta_0 - (j as f32) * (6.0 * PI / 180.0);
+        let polar = |r: f32, theta: f32| (cx + r * theta.cos(), cy + r * theta.sin());
 
-        // Calculate beat positions (60 nodes, 6° apart)
+        let mut hour_positions = [(0.0f32, 0.0f32); 12];
         let mut beat_positions = [(0.0f32, 0.0f32); 60];
-        for j in 0..60 {
-            // Clockwise: subtract angle
-            let theta = theta_0 - (j as f32) * (6.0 * PI / 180.0);
-            beat_positions[j] = (cx + r_beat * theta.cos(), cy + r_beat * theta.sin());
+        match layout {
+            StageLayout::Circle => {
+                for (i, position) in hour_positions.iter_mut().enumerate() {
+                    *position = polar(r_hour, hour_theta(i));
+                }
+                for (j, position) in beat_positions.iter_mut().enumerate() {
+                    *position = polar(r_beat, beat_theta(j));
+                }
+            }
+            StageLayout::Spiral => {
+                // Each winds outward over one turn, so midnight/noon and the
+                // top of the minute sit at the inner ends
+                for (i, position) in hour_positions.iter_mut().enumerate() {
+                    let r = 0.12 * stage_size + (i as f32 / 11.0) * 0.16 * stage_size;
+                    *position = polar(r, hour_theta(i));
+                }
+                for (j, position) in beat_positions.iter_mut().enumerate() {
+                    let r = 0.32 * stage_size + (j as f32 / 59.0) * 0.12 * stage_size;
+                    *position = polar(r, beat_theta(j));
+                }
+            }
+            StageLayout::Star => {
+                // Beats on the five-second marks are the star's points; the
+                // rest dip toward the notches halfway between them
+                for (i, position) in hour_positions.iter_mut().enumerate() {
+                    *position = polar(r_hour, hour_theta(i));
+                }
+                let (r_point, r_notch) = (0.44 * stage_size, 0.30 * stage_size);
+                for (j, position) in beat_positions.iter_mut().enumerate() {
+                    let from_point = ((j % 5) as f32 - 2.5).abs() / 2.5;
+                    let r = r_notch + (r_point - r_notch) * from_point;
+                    *position = polar(r, beat_theta(j));
+                }
+            }
+            StageLayout::Procession => {
+                // Beats march across the top with the hours in a row beneath,
+                // leaving the middle of the stage for labels and the overlay
+                let span = 0.9 * available_width;
+                let left = cx - span / 2.0;
+                for (i, position) in hour_positions.iter_mut().enumerate() {
+                    let x = left + span * (i as f32 + 0.5) / 12.0;
+                    *position = (x, cy + 0.24 * stage_size);
+                }
+                for (j, position) in beat_positions.iter_mut().enumerate() {
+                    let x = left + span * (j as f32 + 0.5) / 60.0;
+                    *position = (x, cy + 0.36 * stage_size);
+                }
+                beat_node_radius = beat_node_radius.min(span / 60.0 * 0.35);
+            }
         }
 
+        // Minimum hit radius for accessibility (~40px diameter for hours)
+        let hour_hit_radius = hit_radius(hour_node_radius.max(20.0), &hour_positions);
+        let beat_hit_radius = hit_radius(beat_node_radius.max(10.0), &beat_positions);
+
         Self {
             cx,
             cy,
@@ -85,6 +174,8 @@ impl StageGeometry {
             beat_node_radius,
             hour_positions,
             beat_positions,
+            hour_hit_radius,
+            beat_hit_radius,
         }
     }
 
@@ -93,19 +184,7 @@ impl StageGeometry {
     /// Returns the index of the hour node if hit, None otherwise.
     /// Uses a slightly larger hit area for accessibility (~40px minimum).
     pub fn hit_test_hour_node(&self, x: f32, y: f32) -> Option<usize> {
-        // Minimum hit radius for accessibility
-        let min_hit_radius = 20.0; // ~40px diameter
-        let hit_radius = self.hour_node_radius.max(min_hit_radius);
-
-        for (i, &(hx, hy)) in self.hour_positions.iter().enumerate() {
-            let dx = x - hx;
-            let dy = y - hy;
-            let dist_sq = dx * dx + dy * dy;
-            if dist_sq <= hit_radius * hit_radius {
-                return Some(i);
-            }
-        }
-        None
+        nearest_within(&self.hour_positions, x, y, self.hour_hit_radius)
     }
 
     /// Hit test for beat nodes
@@ -113,26 +192,14 @@ impl StageGeometry {
     /// Returns the index of the beat node if hit, None otherwise.
     #[allow(dead_code)]
     pub fn hit_test_beat_node(&self, x: f32, y: f32) -> Option<usize> {
-        // Minimum hit radius for accessibility
-        let min_hit_radius = 10.0;
-        let hit_radius = self.beat_node_radius.max(min_hit_radius);
-
-        for (j, &(bx, by)) in self.beat_positions.iter().enumerate() {
-            let dx = x - bx;
-            let dy = y - by;
-            let dist_sq = dx * dx + dy * dy;
-            if dist_sq <= hit_radius * hit_radius {
-                return Some(j);
-            }
-        }
-        None
+        nearest_within(&self.beat_positions, x, y, self.beat_hit_radius)
     }
 
-    /// Get the angle for a beat node index (for retune animation)
+    /// Get the angle of a beat node around the stage center (for retune animation)
     #[allow(dead_code)]
     pub fn beat_angle(&self, index: usize) -> f32 {
-        let theta_0 = PI / 2.0;
-        theta_0 - (index as f32) * (6.0 * PI / 180.0)
+        let (bx, by) = self.beat_positions[index];
+        (by - self.cy).atan2(bx - self.cx)
     }
 
     /// Get trail base width per spec
@@ -141,3 +208,62 @@ impl StageGeometry {
     }
 }
 
+/// `preferred` hit radius, shrunk to half the closest spacing between nodes
+fn hit_radius(preferred: f32, positions: &[(f32, f32)]) -> f32 {
+    let mut closest = f32::INFINITY;
+    for (i, &(ax, ay)) in positions.iter().enumerate() {
+        for &(bx, by) in &positions[i + 1..] {
+            closest = closest.min(((ax - bx).powi(2) + (ay - by).powi(2)).sqrt());
+        }
+    }
+    preferred.min(closest / 2.0)
+}
+
+/// Index of the node closest to (x, y), if it is within `radius`
+fn nearest_within(positions: &[(f32, f32)], x: f32, y: f32, radius: f32) -> Option<usize> {
+    positions
+        .iter()
+        .map(|&(px, py)| (x - px).powi(2) + (y - py).powi(2))
+        .enumerate()
+        .filter(|&(_, dist_sq)| dist_sq <= radius * radius)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stage(layout: StageLayout) -> StageGeometry {
+        StageGeometry::calculate(Rect::from_w_h(1200.0, 900.0), 120.0, layout)
+    }
+
+    #[test]
+    fn test_every_layout_hits_its_own_nodes() {
+        for layout in StageLayout::ALL {
+            let geometry = stage(layout);
+            for (i, &(x, y)) in geometry.hour_positions.iter().enumerate() {
+                assert_eq!(geometry.hit_test_hour_node(x + 3.0, y), Some(i), "{:?}", layout);
+            }
+            for (j, &(x, y)) in geometry.beat_positions.iter().enumerate() {
+                assert_eq!(geometry.hit_test_beat_node(x, y - 2.0), Some(j), "{:?}", layout);
+            }
+            let (cx, cy) = (geometry.cx, geometry.cy);
+            assert_eq!(geometry.hit_test_beat_node(cx, cy - geometry.stage_size), None);
+        }
+    }
+
+    #[test]
+    fn test_procession_shrinks_hit_areas_to_fit() {
+        let circle = stage(StageLayout::Circle);
+        let procession = stage(StageLayout::Procession);
+        assert_eq!(circle.beat_hit_radius, 10.0);
+
+        // Beats sit 18px apart across a 1200px window
+        let spacing = procession.beat_positions[1].0 - procession.beat_positions[0].0;
+        assert!((spacing - 18.0).abs() < 1e-3);
+        assert!(procession.beat_hit_radius <= spacing / 2.0);
+        assert_eq!(procession.hour_positions[0].1, procession.hour_positions[11].1);
+    }
+}
+
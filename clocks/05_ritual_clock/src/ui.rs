@@ -1,11 +1,30 @@
 //! UI module for the Ritual Clock
 //!
 //! Provides the conductor panel with timezone picker, DST indicator,
-//! and gesture sensitivity controls using egui.
+//! gesture sensitivity, trail brush, chime, external output, session, breathing, hour lore
+//! and duel controls using egui.
 
 use chrono_tz::Tz;
 use nannou_egui::egui;
-use shared::{search_timezones, system_timezone, DstChange, TimeData};
+use shared::{
+    active_palette, clip_recording_settings, favorites_settings, keymap_settings, language_settings,
+    order_by_region, quiet_hours_settings, reduced_motion_settings, region_change, region_heading,
+    reveal_hotkey_settings, safe_palette_settings, search_timezones, system_timezone,
+    system_zone_settings, tr, tr_args, window_title_settings, zone_display_name, ClipSettings,
+    DstChange, FollowSystemZone, Keymap, KeymapEdit, LanguageSetting, MotionPreference, QuietHours,
+    RevealHotkey, Rgb, SafePaletteSetting, ThemeLibrary, TimeData, WindowTitleClock,
+};
+
+use crate::audio::{AudioSettings, UNAVAILABLE_REASON};
+use crate::breathing::{BreathingSettings, BreathingStats, MAX_PHASE_SECS, MAX_SESSION_MINUTES};
+use crate::brush::{Brush, TrailStyle, MAX_WIDTH, MIN_WIDTH};
+use crate::duel::Duel;
+use crate::external::{ExternalSettings, MIDI_UNAVAILABLE_REASON};
+use crate::lore::{HourLore, LoreBook, MAX_LABEL_CHARS, MAX_NOTE_CHARS, MAX_SIGIL_CHARS};
+use crate::particles::{ParticleSettings, MAX_BUDGET, MIN_BUDGET};
+use crate::pendulum::{PendulumSettings, MAX_PENDULUMS};
+use crate::session::{format_position, Replay};
+use crate::stage::StageLayout;
 
 /// State for the timezone picker
 #[derive(Default)]
@@ -24,7 +43,7 @@ impl PickerState {
     pub fn open(&mut self) {
         self.is_open = true;
         self.search_query.clear();
-        self.search_results = search_timezones("");
+        self.search_results = order_by_region(search_timezones(""));
         self.should_focus_search = true;
     }
 
@@ -35,7 +54,7 @@ impl PickerState {
     }
 
     pub fn update_search(&mut self) {
-        self.search_results = search_timezones(&self.search_query);
+        self.search_results = order_by_region(search_timezones(&self.search_query));
     }
 }
 
@@ -50,11 +69,72 @@ pub struct ConductorPanelResult {
     pub sensitivity_changed: bool,
     /// Overlay toggle changed
     pub overlay_changed: bool,
+    /// Stage layout changed
+    pub stage_layout_changed: bool,
     /// Reduced motion changed
     pub reduced_motion_changed: bool,
+    /// Trail style or brush parameters changed
+    pub brush_changed: bool,
+    /// Beat particle toggle or budget changed
+    pub particles_changed: bool,
+    /// Pendulum toggle or count changed
+    pub pendulum_changed: bool,
+    /// Chime mute/volume settings changed
+    pub audio_changed: bool,
+    /// Shared quiet-hours schedule edited
+    pub quiet_hours_changed: bool,
+    /// Shared window title clock setting edited
+    pub window_title_changed: bool,
+    /// Shared clip recording settings edited
+    pub clip_settings_changed: bool,
+    /// Shared language edited
+    pub language_changed: bool,
+    /// Shared safe palette edited
+    pub safe_palette_changed: bool,
+    /// Shared follow-system-zone setting edited
+    pub system_zone_changed: bool,
+    /// Shared reveal shortcut edited
+    pub reveal_hotkey_changed: bool,
+    /// Switched between the shared favorites and this clock's own
+    pub favorites_source_changed: bool,
+    /// Shortcut rebound, reset or waiting for its new chord
+    pub keymap_edit: Option<KeymapEdit>,
+    /// OSC/MIDI output settings changed (reopen the outputs)
+    pub external_changed: bool,
+    /// Rescan MIDI output ports
+    pub refresh_midi_ports: bool,
+    /// Clear the best sync score
+    pub reset_best_sync: bool,
+    /// Switch theme (`Some(None)` for the clock's own colors)
+    pub set_theme: Option<Option<String>>,
+    /// Start or stop recording a session
+    pub toggle_recording: bool,
+    /// Play or pause the replay (loading the saved session if none)
+    pub toggle_replay: bool,
+    /// Scrub the replay to a fraction of the session
+    pub seek_replay: Option<f32>,
+    /// Unload the replay
+    pub close_replay: bool,
+    /// Breathing pattern or session length changed
+    pub breathing_changed: bool,
+    /// Start or stop a breathing session
+    pub toggle_breathing: bool,
+    /// Clear the breathing statistics
+    pub reset_breathing_stats: bool,
+    /// Highlight an hour node picked in the lore editor
+    pub highlight_hour: Option<usize>,
+    /// An hour's sigil, label or note edited
+    pub lore_changed: bool,
+    /// Host a duel
+    pub host_duel: bool,
+    /// Join the duel whose code was typed in
+    pub join_duel: bool,
+    /// Leave the duel
+    pub leave_duel: bool,
 }
 
 /// Draw the conductor panel (bottom)
+#[allow(clippy::too_many_arguments)]
 pub fn draw_conductor_panel(
     ctx: &egui::Context,
     picker_state: &mut PickerState,
@@ -63,8 +143,41 @@ pub fn draw_conductor_panel(
     time_data: &TimeData,
     gesture_sensitivity: &mut f32,
     overlay_always_on: &mut bool,
-    reduced_motion: &mut bool,
+    stage_layout: &mut StageLayout,
+    motion_preference: &mut MotionPreference,
+    system_reduced_motion: bool,
     trails_enabled_in_reduced_motion: &mut bool,
+    brush: &mut Brush,
+    particle_settings: &mut ParticleSettings,
+    pendulum_settings: &mut PendulumSettings,
+    audio_settings: &mut AudioSettings,
+    audio_available: bool,
+    external_settings: &mut ExternalSettings,
+    external_error: Option<&str>,
+    midi_ports: &[String],
+    sync_score: Option<f32>,
+    best_sync_score: f32,
+    quiet_hours: &mut QuietHours,
+    window_title: &mut WindowTitleClock,
+    clip_settings: &mut ClipSettings,
+    language: &mut LanguageSetting,
+    safe_palette: &mut SafePaletteSetting,
+    follow_system_zone: &mut FollowSystemZone,
+    reveal_hotkey: &mut RevealHotkey,
+    reveal_status: Option<&str>,
+    own_favorites: &mut bool,
+    keymap: &Keymap,
+    themes: &ThemeLibrary,
+    theme: Option<&str>,
+    recording: bool,
+    replay: Option<&Replay>,
+    breathing_settings: &mut BreathingSettings,
+    breathing: bool,
+    breathing_stats: &BreathingStats,
+    lore: &mut LoreBook,
+    highlighted_hour: Option<usize>,
+    duel: Option<&Duel>,
+    duel_code_input: &mut String,
 ) -> ConductorPanelResult {
     let mut result = ConductorPanelResult::default();
 
@@ -77,7 +190,7 @@ pub fn draw_conductor_panel(
             ui.horizontal(|ui| {
                 // Left section: Timezone info and picker
                 ui.vertical(|ui| {
-                    ui.heading("Ensemble");
+                    ui.heading(tr("Ensemble"));
 
                     // Current timezone display
                     ui.horizontal(|ui| {
@@ -93,16 +206,16 @@ pub fn draw_conductor_panel(
                     });
 
                     // Open picker button
-                    if ui.button("Change Ensemble (T)")
-                        .on_hover_text("Open timezone picker. Keyboard: T")
+                    if ui.button(tr("Change Ensemble (T)"))
+                        .on_hover_text(tr("Open timezone picker. Keyboard: T"))
                         .clicked()
                     {
                         picker_state.open();
                     }
 
                     // System timezone shortcut
-                    if ui.button("Use System Time")
-                        .on_hover_text("Set to your computer's local timezone")
+                    if ui.button(tr("Use System Time"))
+                        .on_hover_text(tr("Set to your computer's local timezone"))
                         .clicked()
                     {
                         if let Some(sys_tz) = system_timezone() {
@@ -115,14 +228,14 @@ pub fn draw_conductor_panel(
 
                 // Center section: DST indicator
                 ui.vertical(|ui| {
-                    ui.heading("DST Status");
+                    ui.heading(tr("DST Status"));
 
                     // DST active indicator
                     let dst_text = if time_data.is_dst {
-                        egui::RichText::new("● Daylight Saving Time Active")
+                        egui::RichText::new(tr("● Daylight Saving Time Active"))
                             .color(egui::Color32::from_rgb(255, 179, 71))
                     } else {
-                        egui::RichText::new("○ Standard Time")
+                        egui::RichText::new(tr("○ Standard Time"))
                             .color(egui::Color32::from_rgb(140, 150, 170))
                     };
                     ui.label(dst_text);
@@ -130,29 +243,26 @@ pub fn draw_conductor_panel(
                     // DST warning
                     match &time_data.dst_change {
                         DstChange::Upcoming { instant, delta_minutes } => {
-                            let hours_until = (*instant - chrono::Utc::now())
+                            let hours_until = (*instant - shared::now_utc())
                                 .num_hours();
-                            let direction = if *delta_minutes > 0 {
-                                "spring forward"
+                            let text = if *delta_minutes > 0 {
+                                "⚠ DST change in {0}h (spring forward)"
                             } else {
-                                "fall back"
+                                "⚠ DST change in {0}h (fall back)"
                             };
                             ui.label(
-                                egui::RichText::new(format!(
-                                    "⚠ DST change in {}h ({})",
-                                    hours_until, direction
-                                ))
+                                egui::RichText::new(tr_args(text, &[&hours_until]))
                                 .color(egui::Color32::from_rgb(255, 150, 80)),
                             );
                         }
                         DstChange::JustOccurred { delta_minutes, .. } => {
-                            let direction = if *delta_minutes > 0 {
-                                "sprang forward"
+                            let text = if *delta_minutes > 0 {
+                                "ℹ Clocks sprang forward recently"
                             } else {
-                                "fell back"
+                                "ℹ Clocks fell back recently"
                             };
                             ui.label(
-                                egui::RichText::new(format!("ℹ Clocks {} recently", direction))
+                                egui::RichText::new(tr(text))
                                     .color(egui::Color32::from_rgb(100, 180, 255)),
                             );
                         }
@@ -164,41 +274,244 @@ pub fn draw_conductor_panel(
 
                 // Right section: Sensitivity and settings
                 ui.vertical(|ui| {
-                    ui.heading("Controls");
+                    ui.heading(tr("Controls"));
 
                     // Gesture sensitivity slider
                     ui.horizontal(|ui| {
-                        ui.label("Sensitivity:");
+                        ui.label(tr("Sensitivity:"));
                         let old_sensitivity = *gesture_sensitivity;
                         let slider = egui::Slider::new(gesture_sensitivity, 0.0..=1.0)
                             .show_value(false)
-                            .text("Gesture trail sensitivity");
-                        ui.add(slider)
-                            .on_hover_text("Adjust the intensity of gesture trails");
+                            .text(tr("Gesture trail sensitivity"));
+                        ui.add(slider).on_hover_text(tr(
+                            "Trail intensity; higher also picks up smaller, looser conducting",
+                        ));
                         if (*gesture_sensitivity - old_sensitivity).abs() > 0.001 {
                             result.sensitivity_changed = true;
                         }
                     });
 
+                    // Conducting sync score
+                    ui.horizontal(|ui| {
+                        let live = match sync_score {
+                            Some(score) => {
+                                tr_args("Sync: {0}%", &[&format!("{:.0}", score * 100.0)])
+                            }
+                            None => tr("Sync: –").to_string(),
+                        };
+                        ui.label(live)
+                            .on_hover_text(tr("Conduct circles or beats in time with the seconds"));
+                        let best = format!("{:.0}", best_sync_score * 100.0);
+                        ui.label(
+                            egui::RichText::new(tr_args("best {0}%", &[&best]))
+                                .color(egui::Color32::from_rgb(140, 150, 170)),
+                        );
+                        if ui.small_button(tr("Reset"))
+                            .on_hover_text(tr("Clear the best sync score"))
+                            .clicked()
+                        {
+                            result.reset_best_sync = true;
+                        }
+                    });
+
                     // Overlay always-on toggle
-                    let overlay_response = ui.checkbox(overlay_always_on, "Always show time (S)")
-                        .on_hover_text("Keep digital time display visible. Keyboard: S");
+                    let overlay_response = ui
+                        .checkbox(overlay_always_on, tr("Always show time (S)"))
+                        .on_hover_text(tr("Keep digital time display visible. Keyboard: S"));
                     if overlay_response.changed() {
                         result.overlay_changed = true;
                     }
 
-                    // Reduced motion toggle
-                    let reduced_response = ui.checkbox(reduced_motion, "Reduced motion")
-                        .on_hover_text("Disable continuous animations for accessibility");
-                    if reduced_response.changed() {
-                        result.reduced_motion_changed = true;
-                    }
+                    // Stage layout
+                    ui.horizontal(|ui| {
+                        ui.label(tr("Stage:"));
+                        egui::ComboBox::from_id_source("stage_layout")
+                            .selected_text(tr(stage_layout.label()))
+                            .show_ui(ui, |ui| {
+                                for layout in StageLayout::ALL {
+                                    if ui
+                                        .selectable_value(stage_layout, layout, tr(layout.label()))
+                                        .changed()
+                                    {
+                                        result.stage_layout_changed = true;
+                                    }
+                                }
+                            });
+                    });
+
+                    // Reduced motion setting
+                    result.reduced_motion_changed =
+                        reduced_motion_settings(ui, motion_preference, system_reduced_motion);
+                    let reduced_motion = motion_preference.resolve(system_reduced_motion);
 
                     // Trails toggle (only visible in reduced motion)
-                    if *reduced_motion {
-                        let _ = ui.checkbox(trails_enabled_in_reduced_motion, "Enable trails anyway")
-                            .on_hover_text("Allow gesture trails even in reduced motion mode");
+                    if reduced_motion {
+                        let _ = ui
+                            .checkbox(trails_enabled_in_reduced_motion, tr("Enable trails anyway"))
+                            .on_hover_text(tr("Allow gesture trails even in reduced motion mode"));
                     }
+
+                    result.brush_changed = brush_controls(ui, brush, reduced_motion);
+
+                    // Beat particles (never in reduced motion)
+                    ui.add_enabled_ui(!reduced_motion, |ui| {
+                        ui.horizontal(|ui| {
+                            if ui.checkbox(&mut particle_settings.enabled, tr("Beat particles"))
+                                .on_hover_text(tr(
+                                    "Burst particles from each beat; sensitivity sets the size",
+                                ))
+                                .changed()
+                            {
+                                result.particles_changed = true;
+                            }
+                            ui.add_enabled_ui(particle_settings.enabled, |ui| {
+                                let slider = egui::Slider::new(
+                                    &mut particle_settings.budget,
+                                    MIN_BUDGET..=MAX_BUDGET,
+                                )
+                                .text(tr("max"));
+                                if ui.add(slider)
+                                    .on_hover_text(tr("Most particles on screen at once"))
+                                    .changed()
+                                {
+                                    result.particles_changed = true;
+                                }
+                            });
+                        });
+                    });
+
+                    // Pendulums (hang still in reduced motion)
+                    ui.horizontal(|ui| {
+                        if ui.checkbox(&mut pendulum_settings.enabled, tr("Pendulum"))
+                            .on_hover_text(tr("Swings on the true second, wider as you conduct"))
+                            .changed()
+                        {
+                            result.pendulum_changed = true;
+                        }
+                        ui.add_enabled_ui(pendulum_settings.enabled, |ui| {
+                            let slider =
+                                egui::Slider::new(&mut pendulum_settings.count, 1..=MAX_PENDULUMS)
+                                    .text(tr("coupled"));
+                            if ui.add(slider).changed() {
+                                result.pendulum_changed = true;
+                            }
+                        });
+                    });
+
+                    result.quiet_hours_changed = quiet_hours_settings(ui, quiet_hours);
+                    result.window_title_changed = window_title_settings(ui, window_title);
+                    result.clip_settings_changed = clip_recording_settings(ui, clip_settings);
+                    result.language_changed = language_settings(ui, language);
+                    result.system_zone_changed = system_zone_settings(ui, follow_system_zone);
+                    result.reveal_hotkey_changed =
+                        reveal_hotkey_settings(ui, reveal_hotkey, reveal_status);
+                    result.favorites_source_changed = favorites_settings(ui, own_favorites);
+                    result.keymap_edit = keymap_settings(ui, keymap);
+                    result.set_theme = theme_selector(ui, themes, theme);
+                    result.safe_palette_changed = safe_palette_settings(ui, safe_palette);
+                });
+
+                ui.separator();
+
+                // Far right section: Chimes
+                ui.vertical(|ui| {
+                    ui.horizontal(|ui| {
+                        ui.heading(tr("Chimes"));
+                        ui.add_enabled_ui(audio_available, |ui| {
+                            if ui.checkbox(&mut audio_settings.muted, tr("Mute"))
+                                .on_hover_text(tr("Silence all chimes"))
+                                .changed()
+                            {
+                                result.audio_changed = true;
+                            }
+                        });
+                    });
+
+                    if !audio_available {
+                        ui.label(
+                            egui::RichText::new(tr(UNAVAILABLE_REASON))
+                                .size(11.0)
+                                .color(egui::Color32::from_rgb(140, 150, 170)),
+                        );
+                    }
+
+                    ui.add_enabled_ui(audio_available && !audio_settings.muted, |ui| {
+                        let beat_hint = if motion_preference.resolve(system_reduced_motion) {
+                            "Per-second tick (off in reduced motion)"
+                        } else {
+                            "Per-second tick"
+                        };
+                        for (label, hint, volume) in [
+                            ("Beat:", beat_hint, &mut audio_settings.beat_volume),
+                            ("Minute:", "Minute boundary chime", &mut audio_settings.minute_volume),
+                            ("Hour:", "Top of the hour chime", &mut audio_settings.hour_volume),
+                        ] {
+                            ui.horizontal(|ui| {
+                                ui.label(tr(label));
+                                if ui.add(egui::Slider::new(volume, 0.0..=1.0).show_value(false))
+                                    .on_hover_text(tr(hint))
+                                    .changed()
+                                {
+                                    result.audio_changed = true;
+                                }
+                            });
+                        }
+                    });
+                });
+
+                ui.separator();
+
+                // External instruments: OSC and MIDI
+                ui.vertical(|ui| {
+                    ui.heading(tr("External"));
+                    if external_controls(ui, external_settings, midi_ports, &mut result) {
+                        result.external_changed = true;
+                    }
+                    if let Some(error) = external_error {
+                        ui.label(
+                            egui::RichText::new(error)
+                                .size(10.0)
+                                .color(egui::Color32::from_rgb(255, 100, 100)),
+                        );
+                    }
+                });
+
+                ui.separator();
+
+                // Session recording and replay
+                ui.vertical(|ui| {
+                    ui.heading(tr("Session"));
+                    session_controls(ui, recording, replay, &mut result);
+                });
+
+                ui.separator();
+
+                // Paced breathing on the beat ring
+                ui.vertical(|ui| {
+                    ui.heading(tr("Breathe"));
+                    breathing_controls(
+                        ui,
+                        breathing_settings,
+                        breathing,
+                        breathing_stats,
+                        &mut result,
+                    );
+                });
+
+                ui.separator();
+
+                // Sigils and labels for the hour nodes
+                ui.vertical(|ui| {
+                    ui.heading(tr("Lore"));
+                    lore_controls(ui, lore, highlighted_hour, &mut result);
+                });
+
+                ui.separator();
+
+                // Networked duel with a second conductor
+                ui.vertical(|ui| {
+                    ui.heading(tr("Duel"));
+                    duel_controls(ui, duel, duel_code_input, &mut result);
                 });
             });
         });
@@ -222,6 +535,385 @@ pub fn draw_conductor_panel(
     result
 }
 
+/// Trail style picker and the brush parameters for the chosen style
+fn brush_controls(ui: &mut egui::Ui, brush: &mut Brush, reduced_motion: bool) -> bool {
+    let mut changed = false;
+
+    ui.horizontal(|ui| {
+        ui.label(tr("Trail:"));
+        egui::ComboBox::from_id_source("trail_style")
+            .selected_text(tr(brush.style.label()))
+            .show_ui(ui, |ui| {
+                for style in TrailStyle::ALL {
+                    if ui
+                        .selectable_value(&mut brush.style, style, tr(style.label()))
+                        .on_hover_text(tr(style.description()))
+                        .changed()
+                    {
+                        changed = true;
+                    }
+                }
+            });
+        changed |= ui
+            .add(egui::Slider::new(&mut brush.width, MIN_WIDTH..=MAX_WIDTH).text(tr("width")))
+            .changed();
+    });
+
+    match brush.style {
+        TrailStyle::Calligraphic => {
+            let slider = egui::Slider::new(&mut brush.speed_response, 0.0..=1.0)
+                .show_value(false)
+                .text(tr("speed thinning"));
+            changed |= ui
+                .add(slider)
+                .on_hover_text(tr("How much fast strokes thin the nib"))
+                .changed();
+        }
+        TrailStyle::Stipple => {
+            ui.add_enabled_ui(!reduced_motion, |ui| {
+                let slider = egui::Slider::new(&mut brush.scatter, 0.0..=1.0)
+                    .show_value(false)
+                    .text(tr("scatter"));
+                changed |= ui
+                    .add(slider)
+                    .on_hover_text(tr("How far dots stray from the path (none in reduced motion)"))
+                    .changed();
+            });
+        }
+        TrailStyle::Comet if reduced_motion => {
+            ui.label(
+                egui::RichText::new(tr("Drawn as an ink ribbon in reduced motion"))
+                    .size(11.0)
+                    .color(egui::Color32::from_rgb(140, 150, 170)),
+            );
+        }
+        TrailStyle::Comet | TrailStyle::InkRibbon => {}
+    }
+
+    changed
+}
+
+/// Record/stop, play/pause and a scrubber for the loaded replay
+fn session_controls(
+    ui: &mut egui::Ui,
+    recording: bool,
+    replay: Option<&Replay>,
+    result: &mut ConductorPanelResult,
+) {
+    ui.horizontal(|ui| {
+        let record_label = if recording { tr("■ Stop (R)") } else { tr("● Record (R)") };
+        if ui.button(tr(record_label))
+            .on_hover_text(tr("Record trails and hour highlights; stopping saves the session"))
+            .clicked()
+        {
+            result.toggle_recording = true;
+        }
+
+        let playing = replay.is_some_and(|replay| replay.playing);
+        let play_label = if playing { tr("⏸ Pause (P)") } else { tr("▶ Play (P)") };
+        if ui.button(tr(play_label))
+            .on_hover_text(tr("Replay the last saved session over the stage"))
+            .clicked()
+        {
+            result.toggle_replay = true;
+        }
+    });
+
+    let Some(replay) = replay else {
+        return;
+    };
+    let mut progress = replay.progress();
+    ui.horizontal(|ui| {
+        if ui.add(egui::Slider::new(&mut progress, 0.0..=1.0).show_value(false))
+            .on_hover_text(tr("Scrub through the session"))
+            .changed()
+        {
+            result.seek_replay = Some(progress);
+        }
+        if ui.small_button("✕")
+            .on_hover_text(tr("Close the replay"))
+            .clicked()
+        {
+            result.close_replay = true;
+        }
+    });
+    ui.label(
+        egui::RichText::new(format!(
+            "{} / {}",
+            format_position(replay.position_ms),
+            format_position(replay.recording.duration_ms())
+        ))
+        .size(11.0)
+        .color(egui::Color32::from_rgb(140, 150, 170)),
+    );
+}
+
+/// Host or join a duel, or the running duel's code and status
+fn duel_controls(
+    ui: &mut egui::Ui,
+    duel: Option<&Duel>,
+    code_input: &mut String,
+    result: &mut ConductorPanelResult,
+) {
+    let hint = egui::Color32::from_rgb(140, 150, 170);
+
+    let Some(duel) = duel else {
+        if ui.button(tr("Host"))
+            .on_hover_text(tr("Start a duel and get a code for the other conductor"))
+            .clicked()
+        {
+            result.host_duel = true;
+        }
+        ui.horizontal(|ui| {
+            let field = ui.add(
+                egui::TextEdit::singleline(code_input)
+                    .desired_width(90.0)
+                    .hint_text("XXXXX-XXXXX"),
+            );
+            let entered = field.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            let join = ui.add_enabled(!code_input.trim().is_empty(), egui::Button::new(tr("Join")));
+            if entered || join.clicked() {
+                result.join_duel = true;
+            }
+        });
+        ui.label(egui::RichText::new(tr("Experimental, same network only")).size(11.0).color(hint));
+        return;
+    };
+
+    ui.label(egui::RichText::new(duel.code()).monospace().size(14.0))
+        .on_hover_text(tr("The other conductor joins with this code"));
+    ui.label(egui::RichText::new(tr(duel.status(std::time::Instant::now()).label())).size(11.0));
+    if duel.loopback_only {
+        ui.label(egui::RichText::new(tr("Offline: this computer only")).size(11.0).color(hint));
+    }
+    if let Some(error) = &duel.error {
+        ui.label(
+            egui::RichText::new(error)
+                .size(10.0)
+                .color(egui::Color32::from_rgb(255, 100, 100)),
+        );
+    }
+    if ui.button(tr("Leave")).clicked() {
+        result.leave_duel = true;
+    }
+}
+
+/// Hour picker, and the sigil, label and note of the highlighted hour
+fn lore_controls(
+    ui: &mut egui::Ui,
+    lore: &mut LoreBook,
+    highlighted_hour: Option<usize>,
+    result: &mut ConductorPanelResult,
+) {
+    let names: Vec<String> = (0..12)
+        .map(|index| {
+            let number = if index == 0 { 12 } else { index };
+            match lore.hour(index).and_then(HourLore::title) {
+                Some(title) => format!("{} {}", number, title),
+                None => number.to_string(),
+            }
+        })
+        .collect();
+    let selected = match highlighted_hour {
+        Some(hour) => names[hour].clone(),
+        None => tr("Pick an hour").to_string(),
+    };
+    egui::ComboBox::from_id_source("lore_hour")
+        .selected_text(selected)
+        .show_ui(ui, |ui| {
+            for (index, name) in names.iter().enumerate() {
+                if ui.selectable_label(highlighted_hour == Some(index), name).clicked() {
+                    result.highlight_hour = Some(index);
+                }
+            }
+        });
+
+    let Some(hour) = highlighted_hour else {
+        ui.label(
+            egui::RichText::new(tr("Click an hour node to give it a ritual"))
+                .size(11.0)
+                .color(egui::Color32::from_rgb(140, 150, 170)),
+        );
+        return;
+    };
+
+    let entry = lore.hour_mut(hour);
+    ui.horizontal(|ui| {
+        let sigil = egui::TextEdit::singleline(&mut entry.sigil)
+            .char_limit(MAX_SIGIL_CHARS)
+            .desired_width(32.0)
+            .hint_text("✦");
+        if ui.add(sigil).on_hover_text(tr("Glyph drawn beside the node")).changed() {
+            result.lore_changed = true;
+        }
+        let label = egui::TextEdit::singleline(&mut entry.label)
+            .char_limit(MAX_LABEL_CHARS)
+            .desired_width(140.0)
+            .hint_text(tr("Ritual or task"));
+        if ui.add(label).changed() {
+            result.lore_changed = true;
+        }
+    });
+    let note = egui::TextEdit::multiline(&mut entry.note)
+        .char_limit(MAX_NOTE_CHARS)
+        .desired_rows(2)
+        .desired_width(180.0)
+        .hint_text(tr("Notes, shown when the node is hovered"));
+    if ui.add(note).changed() {
+        result.lore_changed = true;
+    }
+    if !entry.is_empty()
+        && ui.small_button(tr("Clear"))
+            .on_hover_text(tr("Forget this hour's lore"))
+            .clicked()
+    {
+        *entry = HourLore::default();
+        result.lore_changed = true;
+    }
+}
+
+/// Start/stop, the in/hold/out/hold pattern, session length and statistics
+fn breathing_controls(
+    ui: &mut egui::Ui,
+    settings: &mut BreathingSettings,
+    breathing: bool,
+    stats: &BreathingStats,
+    result: &mut ConductorPanelResult,
+) {
+    let label = if breathing { tr("■ Stop (B)") } else { tr("◯ Start (B)") };
+    if ui.button(tr(label))
+        .on_hover_text(tr("Pace breathing on the beat ring; the time stays in the overlay"))
+        .clicked()
+    {
+        result.toggle_breathing = true;
+    }
+
+    // The pattern is fixed for a running session
+    ui.add_enabled_ui(!breathing, |ui| {
+        ui.horizontal(|ui| {
+            for (hint, secs) in [
+                ("Seconds breathing in", &mut settings.inhale_secs),
+                ("Seconds holding in", &mut settings.hold_in_secs),
+                ("Seconds breathing out", &mut settings.exhale_secs),
+                ("Seconds holding out", &mut settings.hold_out_secs),
+            ] {
+                if ui.add(egui::DragValue::new(secs).clamp_range(0..=MAX_PHASE_SECS).suffix("s"))
+                    .on_hover_text(tr(hint))
+                    .changed()
+                {
+                    result.breathing_changed = true;
+                }
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label(tr("Length:"));
+            let length = egui::DragValue::new(&mut settings.session_minutes)
+                .clamp_range(0..=MAX_SESSION_MINUTES)
+                .suffix(tr(" min"));
+            if ui.add(length)
+                .on_hover_text(tr("End the session after this long; 0 runs until stopped"))
+                .changed()
+            {
+                result.breathing_changed = true;
+            }
+        });
+    });
+
+    ui.horizontal(|ui| {
+        ui.label(
+            egui::RichText::new(stats.summary())
+                .size(11.0)
+                .color(egui::Color32::from_rgb(140, 150, 170)),
+        );
+        if stats.sessions > 0
+            && ui.small_button(tr("Reset"))
+                .on_hover_text(tr("Clear the breathing statistics"))
+                .clicked()
+        {
+            result.reset_breathing_stats = true;
+        }
+    });
+}
+
+/// OSC target/prefix and MIDI port/channel/clock controls
+///
+/// Returns true when a setting changed that needs the outputs reopened. Text
+/// fields only count once editing finishes, so typing doesn't reconnect.
+fn external_controls(
+    ui: &mut egui::Ui,
+    settings: &mut ExternalSettings,
+    midi_ports: &[String],
+    result: &mut ConductorPanelResult,
+) -> bool {
+    let mut changed = false;
+    let hint = egui::Color32::from_rgb(140, 150, 170);
+
+    ui.horizontal(|ui| {
+        changed |= ui
+            .checkbox(&mut settings.osc_enabled, "OSC")
+            .on_hover_text(tr("Send /beat, /minute and /hour messages over UDP"))
+            .changed();
+        let target = ui.add(
+            egui::TextEdit::singleline(&mut settings.osc_target)
+                .desired_width(110.0)
+                .hint_text("host:port"),
+        );
+        changed |= target.lost_focus();
+    });
+    ui.horizontal(|ui| {
+        ui.label(tr("Prefix:"));
+        let prefix = ui.add(
+            egui::TextEdit::singleline(&mut settings.osc_prefix).desired_width(80.0),
+        );
+        changed |= prefix.lost_focus();
+    });
+
+    ui.horizontal(|ui| {
+        ui.add_enabled_ui(!midi_ports.is_empty(), |ui| {
+            changed |= ui
+                .checkbox(&mut settings.midi_enabled, "MIDI")
+                .on_hover_text(tr("Play a drum note on every beat"))
+                .changed();
+            egui::ComboBox::from_id_source("midi_port")
+                .width(110.0)
+                .selected_text(settings.midi_port.as_deref().unwrap_or(tr("Choose port")))
+                .show_ui(ui, |ui| {
+                    for port in midi_ports {
+                        let selected = settings.midi_port.as_ref() == Some(port);
+                        if ui.selectable_label(selected, port).clicked() && !selected {
+                            settings.midi_port = Some(port.clone());
+                            changed = true;
+                        }
+                    }
+                });
+        });
+        if ui.small_button("⟳")
+            .on_hover_text(tr("Rescan MIDI ports"))
+            .clicked()
+        {
+            result.refresh_midi_ports = true;
+        }
+    });
+    if midi_ports.is_empty() {
+        ui.label(egui::RichText::new(tr(MIDI_UNAVAILABLE_REASON)).size(11.0).color(hint));
+    } else {
+        ui.add_enabled_ui(settings.midi_enabled, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(tr("Ch:"));
+                changed |= ui
+                    .add(egui::DragValue::new(&mut settings.midi_channel).clamp_range(1..=16))
+                    .changed();
+                changed |= ui
+                    .checkbox(&mut settings.midi_clock, tr("Clock"))
+                    .on_hover_text(tr("Send MIDI clock at one quarter note per second (60 BPM)"))
+                    .changed();
+            });
+        });
+    }
+
+    changed
+}
+
 /// Result of timezone picker interactions
 #[derive(Default)]
 struct PickerResult {
@@ -238,7 +930,8 @@ fn draw_timezone_picker(
 ) -> PickerResult {
     let mut result = PickerResult::default();
 
-    egui::Window::new("Select Ensemble")
+    egui::Window::new(tr("Select Ensemble"))
+        .id(egui::Id::new("select_ensemble"))
         .collapsible(false)
         .resizable(true)
         .default_width(400.0)
@@ -246,7 +939,7 @@ fn draw_timezone_picker(
         .show(ctx, |ui| {
             // Search field
             ui.horizontal(|ui| {
-                ui.label("Search:");
+                ui.label(tr("Search:"));
                 let search_response = ui.text_edit_singleline(&mut picker_state.search_query);
 
                 if picker_state.should_focus_search {
@@ -263,23 +956,25 @@ fn draw_timezone_picker(
 
             // Favorites section
             if !favorites.is_empty() {
-                ui.collapsing("★ Favorites", |ui| {
-                    for &tz in favorites {
-                        ui.horizontal(|ui| {
-                            if ui.button(format_zone_name(tz)).clicked() {
-                                result.select_zone = Some(tz);
-                            }
-                            if ui.small_button("★").clicked() {
-                                result.toggle_favorite = Some(tz);
-                            }
-                        });
-                    }
-                });
+                egui::CollapsingHeader::new(tr("★ Favorites"))
+                    .id_source("favorites")
+                    .show(ui, |ui| {
+                        for &tz in favorites {
+                            ui.horizontal(|ui| {
+                                if ui.button(format_zone_name(tz)).clicked() {
+                                    result.select_zone = Some(tz);
+                                }
+                                if ui.small_button("★").clicked() {
+                                    result.toggle_favorite = Some(tz);
+                                }
+                            });
+                        }
+                    });
                 ui.separator();
             }
 
             // System timezone shortcut
-            if ui.button("📍 Use System Timezone").clicked() {
+            if ui.button(tr("📍 Use System Timezone")).clicked() {
                 if let Some(sys_tz) = system_timezone() {
                     result.select_zone = Some(sys_tz);
                 }
@@ -288,15 +983,20 @@ fn draw_timezone_picker(
             ui.separator();
 
             // Search results
-            ui.label(format!("{} results", picker_state.search_results.len()));
+            ui.label(tr_args("{0} results", &[&picker_state.search_results.len()]));
 
             egui::ScrollArea::vertical()
                 .max_height(300.0)
                 .show(ui, |ui| {
+                    let mut previous = None;
                     for &tz in picker_state.search_results.iter().take(100) {
+                        if let Some(region) = region_change(previous, tz) {
+                            region_heading(ui, region);
+                        }
+                        previous = Some(tz);
                         let is_favorite = favorites.contains(&tz);
                         ui.horizontal(|ui| {
-                            if ui.button(format_zone_name(tz)).clicked() {
+                            if ui.button(zone_display_name(tz)).clicked() {
                                 result.select_zone = Some(tz);
                             }
                             let fav_label = if is_favorite { "★" } else { "☆" };
@@ -309,7 +1009,7 @@ fn draw_timezone_picker(
 
             ui.separator();
 
-            if ui.button("Close (Esc)").clicked() {
+            if ui.button(tr("Close (Esc)")).clicked() {
                 result.close = true;
             }
         });
@@ -328,3 +1028,56 @@ fn format_zone_name(tz: Tz) -> String {
     }
 }
 
+
+/// Restyle egui's chrome for the active theme
+///
+/// Leaves `visuals` alone while the clock uses its own colors.
+pub fn theme_visuals(visuals: &mut egui::Visuals) {
+    let Some(palette) = active_palette() else {
+        return;
+    };
+    let color = |c: Rgb| egui::Color32::from_rgb(c.red, c.green, c.blue);
+    *visuals = if palette.is_light() {
+        egui::Visuals::light()
+    } else {
+        egui::Visuals::dark()
+    };
+    visuals.panel_fill = color(palette.background);
+    visuals.window_fill = color(palette.background);
+    visuals.override_text_color = Some(color(palette.primary_text));
+    visuals.hyperlink_color = color(palette.accent);
+    visuals.warn_fg_color = color(palette.warning);
+}
+
+/// Draw the theme switcher
+///
+/// Returns the newly chosen theme, where `Some(None)` means the clock's own
+/// colors.
+pub fn theme_selector(
+    ui: &mut egui::Ui,
+    themes: &ThemeLibrary,
+    selected: Option<&str>,
+) -> Option<Option<String>> {
+    let mut choice = None;
+    egui::ComboBox::from_label(tr("Theme"))
+        .selected_text(selected.unwrap_or(tr("Clock colors")))
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(selected.is_none(), tr("Clock colors")).clicked() {
+                choice = Some(None);
+            }
+            for theme in &themes.themes {
+                let is_selected = selected == Some(theme.name.as_str());
+                if ui.selectable_label(is_selected, &theme.name).clicked() {
+                    choice = Some(Some(theme.name.clone()));
+                }
+            }
+        });
+    if let Some(error) = &themes.error {
+        ui.label(
+            egui::RichText::new(error)
+                .size(10.0)
+                .color(egui::Color32::from_rgb(255, 100, 100)),
+        );
+    }
+    choice
+}
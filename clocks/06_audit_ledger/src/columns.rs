@@ -0,0 +1,291 @@
+//! Columns module - which fields each ledger row shows
+//!
+//! Second entries always carry their block and chapter numbers; the other
+//! columns are switched on and off in the sidebar and saved with the clock's
+//! config. Each column is padded to a fixed width so the header lines up
+//! with the rows. DST and leap second markers and external events keep
+//! their own single-cell layouts.
+//!
+//! The comparison column repeats each entry's time in a second zone picked
+//! from the favorites, titled with that zone's city, so the ledger reads in
+//! two zones side by side.
+
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use shared::main_city;
+
+use crate::ledger::LedgerEntry;
+
+/// Characters of the chain hash shown in the HASH column
+const SHORT_HASH_LEN: usize = 12;
+
+/// An optional ledger column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    LocalTime,
+    Comparison,
+    Utc,
+    Epoch,
+    Offset,
+    Dst,
+    Hash,
+}
+
+impl Column {
+    pub const ALL: [Column; 7] = [
+        Column::LocalTime,
+        Column::Comparison,
+        Column::Utc,
+        Column::Epoch,
+        Column::Offset,
+        Column::Dst,
+        Column::Hash,
+    ];
+
+    /// Name shown in the sidebar
+    pub fn label(&self) -> &'static str {
+        match self {
+            Column::LocalTime => "Local time",
+            Column::Comparison => "Second zone",
+            Column::Utc => "UTC",
+            Column::Epoch => "Epoch",
+            Column::Offset => "Offset",
+            Column::Dst => "DST flag",
+            Column::Hash => "Hash",
+        }
+    }
+}
+
+/// The columns shown in ledger rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LedgerColumns {
+    pub local_time: bool,
+    /// The time in the comparison zone
+    pub comparison: bool,
+    pub utc: bool,
+    pub epoch: bool,
+    pub offset: bool,
+    pub dst: bool,
+    pub hash: bool,
+}
+
+impl Default for LedgerColumns {
+    /// The original layout: local time, offset and DST flag
+    fn default() -> Self {
+        Self {
+            local_time: true,
+            comparison: false,
+            utc: false,
+            epoch: false,
+            offset: true,
+            dst: true,
+            hash: false,
+        }
+    }
+}
+
+impl LedgerColumns {
+    pub fn shows_mut(&mut self, column: Column) -> &mut bool {
+        match column {
+            Column::LocalTime => &mut self.local_time,
+            Column::Comparison => &mut self.comparison,
+            Column::Utc => &mut self.utc,
+            Column::Epoch => &mut self.epoch,
+            Column::Offset => &mut self.offset,
+            Column::Dst => &mut self.dst,
+            Column::Hash => &mut self.hash,
+        }
+    }
+
+    /// The column titles, padded to match `row`; the comparison column is
+    /// titled with `comparison`'s city
+    pub fn header(&self, comparison: Tz) -> String {
+        self.line(|cell| match cell.field {
+            Field::Optional(Column::Comparison) => comparison_title(comparison, cell.width),
+            _ => cell.title.to_string(),
+        })
+    }
+
+    /// A second entry's cells; `dst` is the DST flag ("DST", "P1", ...)
+    pub fn row(&self, entry: &LedgerEntry, dst: &str, comparison: Tz) -> String {
+        self.line(|cell| match cell.field {
+            Field::Block => format!("{:02}", entry.block_id),
+            Field::Chapter => format!("{:02}", entry.chapter_id),
+            Field::Optional(Column::LocalTime) => entry.local_timestamp.clone(),
+            Field::Optional(Column::Comparison) => {
+                entry.instant_utc.with_timezone(&comparison).format("%I:%M:%S %p %Z").to_string()
+            }
+            Field::Optional(Column::Utc) => entry.instant_utc.format("%H:%M:%SZ").to_string(),
+            Field::Optional(Column::Epoch) => entry.instant_utc.timestamp().to_string(),
+            Field::Optional(Column::Offset) => entry.offset_str.clone(),
+            Field::Optional(Column::Dst) => dst.to_string(),
+            Field::Optional(Column::Hash) => entry.hash.chars().take(SHORT_HASH_LEN).collect(),
+        })
+    }
+
+    /// Join the shown cells as "│ a │ b │", each padded to its width
+    fn line(&self, text: impl Fn(&Cell) -> String) -> String {
+        let mut line = String::from("│");
+        for cell in CELLS.iter().filter(|cell| self.shows(cell.field)) {
+            line.push_str(&format!(" {:<width$} │", text(cell), width = cell.width));
+        }
+        line
+    }
+
+    fn shows(&self, field: Field) -> bool {
+        match field {
+            Field::Block | Field::Chapter => true,
+            Field::Optional(column) => {
+                let mut columns = *self;
+                *columns.shows_mut(column)
+            }
+        }
+    }
+}
+
+/// The comparison zone's city in capitals, cut to `width` characters
+fn comparison_title(zone: Tz, width: usize) -> String {
+    let city = match main_city(zone) {
+        Some(city) => city.name.to_string(),
+        None => zone.name().rsplit('/').next().unwrap_or_default().replace('_', " "),
+    };
+    city.to_uppercase().chars().take(width).collect()
+}
+
+/// What a cell of the layout holds
+#[derive(Clone, Copy)]
+enum Field {
+    Block,
+    Chapter,
+    Optional(Column),
+}
+
+/// One column of the layout
+struct Cell {
+    field: Field,
+    title: &'static str,
+    width: usize,
+}
+
+/// Every column in display order
+const CELLS: [Cell; 9] = [
+    Cell {
+        field: Field::Optional(Column::LocalTime),
+        title: "TIMESTAMP",
+        width: 11,
+    },
+    Cell {
+        field: Field::Optional(Column::Comparison),
+        title: "",
+        width: 16,
+    },
+    Cell {
+        field: Field::Optional(Column::Utc),
+        title: "UTC",
+        width: 9,
+    },
+    Cell {
+        field: Field::Optional(Column::Epoch),
+        title: "EPOCH",
+        width: 10,
+    },
+    Cell {
+        field: Field::Block,
+        title: "BLK",
+        width: 3,
+    },
+    Cell {
+        field: Field::Chapter,
+        title: "CH",
+        width: 2,
+    },
+    Cell {
+        field: Field::Optional(Column::Offset),
+        title: "OFFSET",
+        width: 9,
+    },
+    Cell {
+        field: Field::Optional(Column::Dst),
+        title: "DST",
+        width: 3,
+    },
+    Cell {
+        field: Field::Optional(Column::Hash),
+        title: "HASH",
+        width: SHORT_HASH_LEN,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Tz;
+
+    fn new_york() -> Tz {
+        "America/New_York".parse().unwrap()
+    }
+
+    fn entry() -> LedgerEntry {
+        let tz: Tz = "America/Los_Angeles".parse().unwrap();
+        // 05:34:56 PDT
+        let instant = Utc.with_ymd_and_hms(2025, 6, 1, 12, 34, 56).unwrap();
+        let mut entry = LedgerEntry::from_instant(instant, tz, true, false, false);
+        entry.seal("0");
+        entry
+    }
+
+    #[test]
+    fn test_default_columns_keep_the_original_layout() {
+        let columns = LedgerColumns::default();
+        assert_eq!(
+            columns.header(new_york()),
+            "│ TIMESTAMP   │ BLK │ CH │ OFFSET    │ DST │"
+        );
+        assert_eq!(
+            columns.row(&entry(), "DST", new_york()),
+            "│ 05:34:56 AM │ 34  │ 05 │ UTC-07:00 │ DST │"
+        );
+    }
+
+    #[test]
+    fn test_optional_columns() {
+        let entry = entry();
+        let mut columns = LedgerColumns::default();
+        *columns.shows_mut(Column::LocalTime) = false;
+        *columns.shows_mut(Column::Utc) = true;
+        *columns.shows_mut(Column::Epoch) = true;
+        *columns.shows_mut(Column::Hash) = true;
+
+        assert_eq!(
+            columns.header(new_york()),
+            "│ UTC       │ EPOCH      │ BLK │ CH │ OFFSET    │ DST │ HASH         │"
+        );
+        let row = columns.row(&entry, "   ", new_york());
+        assert!(row.starts_with("│ 12:34:56Z │ 1748781296 │ 34  │"));
+        assert!(row.ends_with(&format!("│ {} │", &entry.hash[..SHORT_HASH_LEN])));
+        // Every row lines up with the header
+        assert_eq!(row.chars().count(), columns.header(new_york()).chars().count());
+    }
+
+    #[test]
+    fn test_comparison_column_shows_the_second_zone() {
+        let entry = entry();
+        let mut columns = LedgerColumns::default();
+        *columns.shows_mut(Column::Comparison) = true;
+
+        assert_eq!(
+            columns.header(new_york()),
+            "│ TIMESTAMP   │ NEW YORK         │ BLK │ CH │ OFFSET    │ DST │"
+        );
+        let row = columns.row(&entry, "DST", new_york());
+        assert!(row.starts_with("│ 05:34:56 AM │ 08:34:56 AM EDT  │ 34  │"));
+        assert_eq!(row.chars().count(), columns.header(new_york()).chars().count());
+
+        // Zones without a city in the dataset are titled by their id
+        let utc: Tz = "Etc/UTC".parse().unwrap();
+        assert!(columns.header(utc).contains("│ UTC              │"));
+        assert!(columns.row(&entry, "DST", utc).contains("│ 12:34:56 PM UTC  │"));
+    }
+}
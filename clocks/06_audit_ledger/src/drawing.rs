@@ -3,16 +3,35 @@
 //! Handles rendering of the terminal-style ledger view, header with
 //! verification hash stamp, and all visual elements.
 
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use nannou::prelude::*;
-use shared::TimeData;
+use shared::{tr, tr_args, CommandRegistry, TimeData, WeekDate};
 
+use crate::columns::LedgerColumns;
 use crate::ledger::{BlockGroup, DstBadge, HourChapter};
+use crate::signing::block_start;
 use crate::Model;
 
+/// Height of an hour chapter header row
+pub const CHAPTER_HEADER_HEIGHT: f32 = 36.0;
+/// Height of a minute block header row
+pub const BLOCK_HEADER_HEIGHT: f32 = 26.0;
+
+/// How a row relates to the active search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchHit {
+    None,
+    Match,
+    /// The match last jumped to with N / Shift+N
+    Current,
+}
+
 /// Color palette for the terminal aesthetic
 #[allow(dead_code)]
 pub mod colors {
     use nannou::prelude::*;
+    use shared::{Rgb, ThemeRole};
 
     /// Deep background
     pub const BACKGROUND: Srgb<u8> = Srgb {
@@ -70,6 +89,14 @@ pub mod colors {
         standard: std::marker::PhantomData,
     };
 
+    /// Chapter header background when the chapter isn't focused
+    pub const CHAPTER_HEADER_BG_IDLE: Srgb<u8> = Srgb {
+        red: 22,
+        green: 35,
+        blue: 42,
+        standard: std::marker::PhantomData,
+    };
+
     /// Chapter header accent color
     pub const CHAPTER_ACCENT: Srgb<u8> = Srgb {
         red: 80,
@@ -102,6 +129,14 @@ pub mod colors {
         standard: std::marker::PhantomData,
     };
 
+    /// Seconds that went unrecorded (suspend or sleep)
+    pub const UNRECORDED: Srgb<u8> = Srgb {
+        red: 200,
+        green: 120,
+        blue: 255,
+        standard: std::marker::PhantomData,
+    };
+
     /// Focus ring color
     pub const FOCUS_RING: Srgb<u8> = Srgb {
         red: 100,
@@ -110,6 +145,22 @@ pub mod colors {
         standard: std::marker::PhantomData,
     };
 
+    /// Band behind rows matching the search
+    pub const SEARCH_HIT: Srgb<u8> = Srgb {
+        red: 25,
+        green: 55,
+        blue: 35,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Band behind the current search match
+    pub const SEARCH_CURRENT: Srgb<u8> = Srgb {
+        red: 40,
+        green: 85,
+        blue: 100,
+        standard: std::marker::PhantomData,
+    };
+
     /// Separator line color
     pub const SEPARATOR: Srgb<u8> = Srgb {
         red: 40,
@@ -134,10 +185,55 @@ pub mod colors {
         standard: std::marker::PhantomData,
     };
 
+    /// Row colors for followed event sources
+    const SOURCE_PALETTE: [(u8, u8, u8); 6] = [
+        (0, 220, 220),
+        (255, 120, 200),
+        (190, 150, 255),
+        (255, 230, 90),
+        (120, 200, 255),
+        (255, 140, 90),
+    ];
+
+    /// Color for an event source's rows, stable across runs (FNV-1a of the name)
+    pub fn source_color(source: &str) -> Srgb<u8> {
+        let hash = source.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+        let (red, green, blue) = SOURCE_PALETTE[(hash % SOURCE_PALETTE.len() as u64) as usize];
+        Srgb::new(red, green, blue)
+    }
+
     /// Overlay background with alpha
     pub fn overlay_bg() -> Srgba<u8> {
         srgba(10, 12, 16, 230)
     }
+
+    /// Look up one of this clock's role colors in the active theme
+    fn themed(role: ThemeRole, native: Srgb<u8>) -> Srgb<u8> {
+        let color = shared::themed(role, Rgb::new(native.red, native.green, native.blue));
+        Srgb::new(color.red, color.green, color.blue)
+    }
+
+    pub fn background() -> Srgb<u8> {
+        themed(ThemeRole::Background, BACKGROUND)
+    }
+
+    pub fn phosphor_green() -> Srgb<u8> {
+        themed(ThemeRole::PrimaryText, PHOSPHOR_GREEN)
+    }
+
+    pub fn dim_green() -> Srgb<u8> {
+        themed(ThemeRole::SecondaryText, DIM_GREEN)
+    }
+
+    pub fn amber() -> Srgb<u8> {
+        themed(ThemeRole::Accent, AMBER)
+    }
+
+    pub fn dst_warning() -> Srgb<u8> {
+        themed(ThemeRole::Warning, DST_WARNING)
+    }
 }
 
 /// Draw the header with time display and verification hash
@@ -155,14 +251,14 @@ pub fn draw_header(draw: &Draw, rect: &Rect, time_data: &TimeData, hash: &str) {
     let border_y = rect.top() - header_height;
     draw.text(&"═".repeat(60))
         .x_y(rect.x(), border_y)
-        .color(colors::DIM_GREEN)
+        .color(colors::dim_green())
         .font_size(14)
         .w(rect.w());
 
     // Title
-    draw.text("╔══ AUDIT LEDGER CLOCK ══╗")
+    draw.text(tr("╔══ AUDIT LEDGER CLOCK ══╗"))
         .x_y(rect.x(), rect.top() - 20.0)
-        .color(colors::PHOSPHOR_GREEN)
+        .color(colors::phosphor_green())
         .font_size(16)
         .w(400.0);
 
@@ -178,17 +274,25 @@ pub fn draw_header(draw: &Draw, rect: &Rect, time_data: &TimeData, hash: &str) {
     );
     draw.text(&time_str)
         .x_y(rect.x() - 100.0, rect.top() - 45.0)
-        .color(colors::PHOSPHOR_GREEN)
+        .color(colors::phosphor_green())
         .font_size(18)
         .w(400.0);
 
     // Verification hash stamp
-    draw.text(&format!("HASH: {}", hash))
+    draw.text(&tr_args("HASH: {0}", &[&hash]))
         .x_y(rect.x() + 180.0, rect.top() - 45.0)
         .color(colors::HASH_COLOR)
         .font_size(14)
         .w(200.0);
 
+    // ISO week date and day of year
+    let week_date = WeekDate::of(time_data.local_datetime.date_naive());
+    draw.text(&format!("{} │ {}", week_date.iso_string(), week_date.day_label()))
+        .x_y(rect.x() + 310.0, rect.top() - 20.0)
+        .color(colors::dim_green())
+        .font_size(12)
+        .w(200.0);
+
     // DST indicator in header
     if time_data.is_dst {
         draw.text("● DST")
@@ -200,15 +304,17 @@ pub fn draw_header(draw: &Draw, rect: &Rect, time_data: &TimeData, hash: &str) {
 
 /// Draw the ledger view with hierarchical chapter -> block structure
 pub fn draw_ledger(draw: &Draw, rect: &Rect, model: &Model) {
-    let chapters = model.ledger.get_chapter_grouped_entries();
-    let chapter_header_height = 36.0;
-    let block_header_height = 26.0;
+    let chapters = model.ledger_chapters();
+    let chapter_header_height = CHAPTER_HEADER_HEIGHT;
+    let block_header_height = BLOCK_HEADER_HEIGHT;
     let row_height = model.text_density.row_height();
     let font_size = model.text_density.font_size();
 
-    // Start below the main header (70px) + column headers (30px) + padding
+    // Start below the main header (70px) + column headers (30px) + padding;
+    // scrolling moves the content up to reveal older entries
     let content_top = rect.top() - 125.0;
-    let mut current_y = content_top - model.ledger.scroll_offset;
+    let mut current_y = content_top + model.ledger.scroll_offset;
+    let is_visible = |y: f32| y <= content_top + 5.0;
 
     // Relabel animation progress (for sweep effect)
     let relabel_progress = if model.relabel_start.is_some() {
@@ -235,7 +341,7 @@ pub fn draw_ledger(draw: &Draw, rect: &Rect, model: &Model) {
         };
 
         // Skip if completely above viewport
-        if current_y > content_top + 50.0 {
+        if current_y - chapter_header_height - chapter_content_height > content_top {
             current_y -= chapter_header_height + chapter_content_height;
             global_block_idx += chapter.blocks.len();
             continue;
@@ -247,10 +353,12 @@ pub fn draw_ledger(draw: &Draw, rect: &Rect, model: &Model) {
         }
 
         // Draw chapter header
-        let is_chapter_focused = model.focused_block_index.map_or(false, |idx| {
+        let is_chapter_focused = model.focused_block_index.is_some_and(|idx| {
             idx >= global_block_idx && idx < global_block_idx + chapter.blocks.len()
         });
-        draw_chapter_header(draw, rect.x(), current_y, rect.w() - 40.0, chapter, is_chapter_focused);
+        if is_visible(current_y) {
+            draw_chapter_header(draw, rect.x(), current_y, rect.w() - 40.0, chapter, is_chapter_focused);
+        }
         current_y -= chapter_header_height;
 
         // Draw blocks if chapter not collapsed
@@ -262,7 +370,19 @@ pub fn draw_ledger(draw: &Draw, rect: &Rect, model: &Model) {
 
                 // Draw block header (indented)
                 let is_block_focused = model.focused_block_index == Some(global_block_idx);
-                draw_block_header(draw, rect.x() + 20.0, current_y, rect.w() - 60.0, block, is_block_focused);
+                if is_visible(current_y) {
+                    let signed =
+                        block_start(block).is_some_and(|start| model.signatures.is_signed(start));
+                    draw_block_header(
+                        draw,
+                        rect.x() + 20.0,
+                        current_y,
+                        rect.w() - 60.0,
+                        block,
+                        is_block_focused,
+                        signed,
+                    );
+                }
                 current_y -= block_header_height;
 
                 // Draw entries if block not collapsed
@@ -284,7 +404,21 @@ pub fn draw_ledger(draw: &Draw, rect: &Rect, model: &Model) {
                             1.0
                         };
 
-                        draw_ledger_row(draw, rect.x() + 20.0, current_y, rect.w() - 60.0, entry, font_size, alpha);
+                        if is_visible(current_y) {
+                            let hit = model.search_highlight(entry);
+                            draw_ledger_row(
+                                draw,
+                                rect.x() + 20.0,
+                                current_y,
+                                rect.w() - 60.0,
+                                entry,
+                                font_size,
+                                alpha,
+                                hit,
+                                &model.columns,
+                                model.comparison_zone,
+                            );
+                        }
                         current_y -= row_height;
                     }
                 }
@@ -298,26 +432,40 @@ pub fn draw_ledger(draw: &Draw, rect: &Rect, model: &Model) {
 
     // Draw column headers at fixed position (below main header, above ledger content)
     let column_headers_y = rect.top() - 90.0;
-    draw_column_headers(draw, rect.x(), column_headers_y, rect.w() - 40.0);
+    draw_column_headers(
+        draw,
+        rect.x(),
+        column_headers_y,
+        rect.w() - 40.0,
+        &model.columns,
+        model.comparison_zone,
+    );
 }
 
-/// Draw column headers
-fn draw_column_headers(draw: &Draw, x: f32, y: f32, width: f32) {
-    let header_text = "│ TIMESTAMP    │ BLK │ CH │ OFFSET     │ DST │";
+/// Draw the titles of the shown columns
+fn draw_column_headers(
+    draw: &Draw,
+    x: f32,
+    y: f32,
+    width: f32,
+    columns: &LedgerColumns,
+    comparison_zone: Tz,
+) {
+    let header_text = columns.header(comparison_zone);
 
     draw.rect()
         .x_y(x, y)
         .w_h(width, 20.0)
         .color(colors::HEADER_BG);
 
-    draw.text(header_text)
+    draw.text(&header_text)
         .x_y(x, y)
-        .color(colors::DIM_GREEN)
+        .color(colors::dim_green())
         .font_size(12)
         .w(width);
 
     // Separator
-    draw.text(&"─".repeat(50))
+    draw.text(&"─".repeat(header_text.chars().count()))
         .x_y(x, y - 12.0)
         .color(colors::SEPARATOR)
         .font_size(12);
@@ -329,7 +477,7 @@ fn draw_chapter_header(draw: &Draw, x: f32, y: f32, width: f32, chapter: &HourCh
     let bg_color = if is_focused {
         colors::CHAPTER_HEADER_BG
     } else {
-        srgb(22, 35, 42)
+        colors::CHAPTER_HEADER_BG_IDLE
     };
 
     draw.rect()
@@ -351,14 +499,17 @@ fn draw_chapter_header(draw: &Draw, x: f32, y: f32, width: f32, chapter: &HourCh
     let total_entries: usize = chapter.blocks.iter().map(|b| b.entries.len()).sum();
 
     // Header text
-    let header_text = format!(
-        "{} ═══ CHAPTER {:02} ({:02} {}) ═══ {} blocks │ {} entries",
-        collapse_char,
-        chapter.hour,
-        hour_12,
-        meridiem,
-        chapter.blocks.len(),
-        total_entries
+    let header_text = tr_args(
+        "{0} ═══ CHAPTER {1} ({2} {3}) ═══ {4} blocks │ {5} entries │ ROOT {6}",
+        &[
+            &collapse_char,
+            &format!("{:02}", chapter.hour),
+            &format!("{:02}", hour_12),
+            &meridiem,
+            &chapter.blocks.len(),
+            &total_entries,
+            &&chapter.merkle_root[..8],
+        ],
     );
 
     draw.text(&header_text)
@@ -379,7 +530,15 @@ fn draw_chapter_header(draw: &Draw, x: f32, y: f32, width: f32, chapter: &HourCh
 }
 
 /// Draw a block header
-fn draw_block_header(draw: &Draw, x: f32, y: f32, width: f32, group: &BlockGroup, is_focused: bool) {
+fn draw_block_header(
+    draw: &Draw,
+    x: f32,
+    y: f32,
+    width: f32,
+    group: &BlockGroup,
+    is_focused: bool,
+    signed: bool,
+) {
     // Background
     let bg_color = if is_focused {
         colors::BLOCK_HEADER_BG
@@ -396,16 +555,22 @@ fn draw_block_header(draw: &Draw, x: f32, y: f32, width: f32, group: &BlockGroup
     let collapse_char = if group.collapsed { "▸" } else { "▾" };
 
     // Header text (simplified - chapter info is in parent)
-    let header_text = format!(
-        "{} BLOCK {:02} │ {} entries",
-        collapse_char,
-        group.minute,
-        group.entries.len()
+    let mut header_text = tr_args(
+        "{0} BLOCK {1} │ {2} entries │ ROOT {3}",
+        &[
+            &collapse_char,
+            &format!("{:02}", group.minute),
+            &group.entries.len(),
+            &&group.merkle_root[..8],
+        ],
     );
+    if signed {
+        header_text.push_str(&format!(" │ {}", tr("SIGNED")));
+    }
 
     draw.text(&header_text)
         .x_y(x, y)
-        .color(colors::PHOSPHOR_GREEN)
+        .color(colors::phosphor_green())
         .font_size(12)
         .w(width);
 
@@ -420,7 +585,66 @@ fn draw_block_header(draw: &Draw, x: f32, y: f32, width: f32, group: &BlockGroup
     }
 }
 
+/// Offset of `instant`'s row below the top of the ledger content, as laid
+/// out by `draw_ledger`; `None` if the row is hidden
+pub fn row_offset(
+    chapters: &[HourChapter],
+    instant: DateTime<Utc>,
+    row_height: f32,
+) -> Option<f32> {
+    let mut offset = 0.0;
+    for chapter in chapters {
+        offset += CHAPTER_HEADER_HEIGHT;
+        if chapter.collapsed {
+            continue;
+        }
+        for block in chapter.blocks.iter() {
+            offset += BLOCK_HEADER_HEIGHT;
+            if block.collapsed {
+                continue;
+            }
+            for entry in block.entries.iter() {
+                if entry.instant_utc == instant {
+                    return Some(offset);
+                }
+                offset += row_height;
+            }
+        }
+    }
+    None
+}
+
+/// The entry whose row covers `offset` below the top of the ledger content,
+/// as laid out by `draw_ledger`
+pub fn entry_at_offset(
+    chapters: &[HourChapter],
+    offset: f32,
+    row_height: f32,
+) -> Option<&crate::ledger::LedgerEntry> {
+    let mut row = 0.0;
+    for chapter in chapters {
+        row += CHAPTER_HEADER_HEIGHT;
+        if chapter.collapsed {
+            continue;
+        }
+        for block in chapter.blocks.iter() {
+            row += BLOCK_HEADER_HEIGHT;
+            if block.collapsed {
+                continue;
+            }
+            for entry in block.entries.iter() {
+                if (offset - row).abs() <= row_height / 2.0 {
+                    return Some(entry);
+                }
+                row += row_height;
+            }
+        }
+    }
+    None
+}
+
 /// Draw a single ledger row
+#[allow(clippy::too_many_arguments)]
 fn draw_ledger_row(
     draw: &Draw,
     x: f32,
@@ -429,12 +653,51 @@ fn draw_ledger_row(
     entry: &crate::ledger::LedgerEntry,
     font_size: u32,
     alpha: f32,
+    hit: SearchHit,
+    columns: &LedgerColumns,
+    comparison_zone: Tz,
 ) {
+    // Search hits get a band behind the text; the current one is brighter
+    // and outlined
+    match hit {
+        SearchHit::None => {}
+        SearchHit::Match => {
+            draw.rect()
+                .x_y(x, y)
+                .w_h(width, font_size as f32 + 6.0)
+                .color(colors::SEARCH_HIT);
+        }
+        SearchHit::Current => {
+            draw.rect()
+                .x_y(x, y)
+                .w_h(width, font_size as f32 + 6.0)
+                .color(colors::SEARCH_CURRENT)
+                .stroke(colors::FOCUS_RING)
+                .stroke_weight(1.0);
+        }
+    }
+
+    // External events are colored by source and span the data columns
+    if let Some(event) = entry.event() {
+        let color = colors::source_color(&event.source);
+        let row_text = format!(
+            "│ {} │ ▶ [{}] {}: {} │",
+            entry.local_timestamp, event.source, event.kind, event.message
+        );
+        draw.text(&row_text)
+            .x_y(x, y)
+            .color(srgba(color.red, color.green, color.blue, (255.0 * alpha) as u8))
+            .font_size(font_size)
+            .w(width);
+        return;
+    }
+
     // Determine row color based on entry type
     let (text_color, is_special) = match &entry.dst_badge {
-        DstBadge::GapMarker { .. } => (colors::GAP_MARKER, true),
-        DstBadge::OverlapPass1 | DstBadge::OverlapPass2 => (colors::DST_WARNING, true),
-        _ => (colors::PHOSPHOR_GREEN, false),
+        DstBadge::GapMarker { .. } | DstBadge::LeapSecond => (colors::GAP_MARKER, true),
+        DstBadge::OverlapPass1 | DstBadge::OverlapPass2 => (colors::dst_warning(), true),
+        DstBadge::Unrecorded { .. } => (colors::UNRECORDED, true),
+        _ => (colors::phosphor_green(), false),
     };
 
     // Apply alpha
@@ -449,7 +712,29 @@ fn draw_ledger_row(
     if is_special {
         match &entry.dst_badge {
             DstBadge::GapMarker { from, to } => {
-                let marker_text = format!("│ ══ DST GAP: {} → {} ══ │", from, to);
+                let marker_text = tr_args("│ ══ DST GAP: {0} → {1} ══ │", &[from, to]);
+                draw.text(&marker_text)
+                    .x_y(x, y)
+                    .color(text_color)
+                    .font_size(font_size)
+                    .w(width);
+                return;
+            }
+            DstBadge::LeapSecond => {
+                let marker_text =
+                    tr_args("│ ══ LEAP SECOND: {0} ══ │", &[&entry.local_timestamp]);
+                draw.text(&marker_text)
+                    .x_y(x, y)
+                    .color(text_color)
+                    .font_size(font_size)
+                    .w(width);
+                return;
+            }
+            DstBadge::Unrecorded { seconds } => {
+                let marker_text = tr_args(
+                    "│ ░░ GAP: {0} seconds unrecorded (suspend suspected) ░░ │",
+                    &[seconds],
+                );
                 draw.text(&marker_text)
                     .x_y(x, y)
                     .color(text_color)
@@ -458,11 +743,13 @@ fn draw_ledger_row(
                 return;
             }
             DstBadge::OverlapPass1 => {
-                draw_normal_row(draw, x, y, width, entry, font_size, text_color, "P1");
+                let row_text = columns.row(entry, "P1", comparison_zone);
+                draw_normal_row(draw, x, y, width, &row_text, font_size, text_color);
                 return;
             }
             DstBadge::OverlapPass2 => {
-                draw_normal_row(draw, x, y, width, entry, font_size, text_color, "P2");
+                let row_text = columns.row(entry, "P2", comparison_zone);
+                draw_normal_row(draw, x, y, width, &row_text, font_size, text_color);
                 return;
             }
             _ => {}
@@ -474,30 +761,21 @@ fn draw_ledger_row(
         DstBadge::Active => "DST",
         _ => "   ",
     };
-    draw_normal_row(draw, x, y, width, entry, font_size, text_color, dst_str);
+    let row_text = columns.row(entry, dst_str, comparison_zone);
+    draw_normal_row(draw, x, y, width, &row_text, font_size, text_color);
 }
 
-/// Draw a normal ledger row with all columns
+/// Draw a normal ledger row, laid out by `LedgerColumns::row`
 fn draw_normal_row(
     draw: &Draw,
     x: f32,
     y: f32,
     width: f32,
-    entry: &crate::ledger::LedgerEntry,
+    row_text: &str,
     font_size: u32,
     color: Srgba<u8>,
-    dst_str: &str,
 ) {
-    let row_text = format!(
-        "│ {} │ {:02}  │ {:02} │ {} │ {} │",
-        entry.local_timestamp,
-        entry.block_id,
-        entry.chapter_id,
-        entry.offset_str,
-        dst_str
-    );
-
-    draw.text(&row_text)
+    draw.text(row_text)
         .x_y(x, y)
         .color(color)
         .font_size(font_size)
@@ -505,7 +783,7 @@ fn draw_normal_row(
 }
 
 /// Draw the "Return to Live" button
-pub fn draw_return_to_live_button(draw: &Draw, rect: &Rect) {
+pub fn draw_return_to_live_button(draw: &Draw, rect: &Rect, keys: &CommandRegistry) {
     let button_x = rect.x();
     let button_y = rect.bottom() + 60.0;
     let button_w = 200.0;
@@ -526,7 +804,7 @@ pub fn draw_return_to_live_button(draw: &Draw, rect: &Rect) {
         .stroke_weight(2.0);
 
     // Button text
-    draw.text("▲ RETURN TO LIVE (L)")
+    draw.text(&tr_args("▲ RETURN TO LIVE ({0})", &[&keys.key_label("Return to live")]))
         .x_y(button_x, button_y)
         .color(srgb(255u8, 255u8, 255u8))
         .font_size(14);
@@ -544,7 +822,7 @@ pub fn draw_error_banner(draw: &Draw, window_rect: Rect) {
         .color(srgba(120u8, 40u8, 40u8, 220u8));
 
     // Text
-    draw.text("⚠ Timezone data may be missing or stale. Showing UTC as fallback.")
+    draw.text(tr("⚠ Timezone data may be missing or stale. Showing UTC as fallback."))
         .x_y(0.0, banner_y)
         .color(srgb(255u8, 255u8, 255u8))
         .font_size(14)
@@ -573,14 +851,14 @@ pub fn draw_toasts(draw: &Draw, window_rect: Rect, toasts: &[crate::Toast]) {
             .x_y(0.0, y)
             .w_h(toast_width, toast_height)
             .no_fill()
-            .stroke(srgba(colors::PHOSPHOR_GREEN.red, colors::PHOSPHOR_GREEN.green, colors::PHOSPHOR_GREEN.blue, alpha))
+            .stroke(srgba(colors::phosphor_green().red, colors::phosphor_green().green, colors::phosphor_green().blue, alpha))
             .stroke_weight(1.0);
 
         // Text
         let text_alpha = (toast.alpha() * 255.0) as u8;
         draw.text(&toast.message)
             .x_y(0.0, y)
-            .color(srgba(colors::PHOSPHOR_GREEN.red, colors::PHOSPHOR_GREEN.green, colors::PHOSPHOR_GREEN.blue, text_alpha))
+            .color(srgba(colors::phosphor_green().red, colors::phosphor_green().green, colors::phosphor_green().blue, text_alpha))
             .font_size(12)
             .w(toast_width - 20.0);
     }
@@ -596,3 +874,25 @@ pub fn draw_focus_indicator(draw: &Draw, rect: &Rect) {
         .stroke_weight(2.0);
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::{fixed_text_is_legible, Rgb};
+
+    fn rgb(color: Srgb<u8>) -> Rgb {
+        Rgb::new(color.red, color.green, color.blue)
+    }
+
+    #[test]
+    fn test_fixed_header_text_is_legible() {
+        for (text, surface) in [
+            (colors::HASH_COLOR, colors::HEADER_BG),
+            (colors::DST_ACTIVE, colors::HEADER_BG),
+            (colors::CHAPTER_ACCENT, colors::CHAPTER_HEADER_BG),
+            (colors::CHAPTER_ACCENT, colors::CHAPTER_HEADER_BG_IDLE),
+        ] {
+            assert!(fixed_text_is_legible(rgb(text), rgb(surface)), "{:?} on {:?}", text, surface);
+        }
+    }
+}
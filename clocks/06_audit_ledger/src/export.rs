@@ -0,0 +1,348 @@
+//! Export module - machine-readable dumps of the ledger
+//!
+//! Writes entries as JSON Lines or CSV, oldest first, with the UTC instant,
+//! the displayed local time and offset, DST flags and both chain hashes.
+//! Events from a followed stream add their source, type and message.
+//! With block signing on, a JSON Lines sidecar lists each signed block's
+//! root, the exact message signed, the signature and the public key, and
+//! every export carries a JSON sidecar with the session's stats.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ledger::{DstBadge, LedgerEntry};
+use crate::signing::{signed_message, BlockSignature};
+use crate::stats::{micros, SessionStats};
+
+/// Output format for an export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ExportFormat {
+    #[default]
+    JsonLines,
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::JsonLines => "jsonl",
+            ExportFormat::Csv => "csv",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExportFormat::JsonLines => "JSONL",
+            ExportFormat::Csv => "CSV",
+        }
+    }
+
+    pub fn all() -> &'static [ExportFormat] {
+        &[ExportFormat::JsonLines, ExportFormat::Csv]
+    }
+}
+
+/// Which entries an export includes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ExportScope {
+    /// Entries not hidden inside a collapsed block or chapter
+    #[default]
+    Visible,
+    /// Everything retained in the rolling window
+    All,
+}
+
+impl ExportScope {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExportScope::Visible => "Visible",
+            ExportScope::All => "All retained",
+        }
+    }
+
+    pub fn all() -> &'static [ExportScope] {
+        &[ExportScope::Visible, ExportScope::All]
+    }
+}
+
+const CSV_HEADER: &str =
+    "instant_utc,local_time,display_tz,utc_offset,utc_offset_minutes,dst_active,dst_badge,recorded_tz,prev_hash,hash,entry_type,source,event_type,message";
+
+/// Render entries (given newest first, as the ledger stores them)
+pub fn render<'a, I>(entries: I, display_tz: &str, format: ExportFormat) -> String
+where
+    I: DoubleEndedIterator<Item = &'a LedgerEntry>,
+{
+    let mut out = String::new();
+    if format == ExportFormat::Csv {
+        out.push_str(CSV_HEADER);
+        out.push('\n');
+    }
+
+    for entry in entries.rev() {
+        let row = ExportRow::new(entry, display_tz);
+        match format {
+            ExportFormat::JsonLines => out.push_str(&row.to_json()),
+            ExportFormat::Csv => out.push_str(&row.to_csv()),
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Render one entry as a JSON Lines row, without the newline
+pub fn jsonl_row(entry: &LedgerEntry, display_tz: &str) -> String {
+    ExportRow::new(entry, display_tz).to_json()
+}
+
+/// Render block signatures as JSON Lines, oldest block first
+pub fn render_signatures<'a, I>(signatures: I, public_key: &str) -> String
+where
+    I: Iterator<Item = &'a BlockSignature>,
+{
+    let mut out = String::new();
+    for signature in signatures {
+        out.push_str(&format!(
+            "{{\"block_start\":{},\"merkle_root\":{},\"message\":{},\"signature\":{},\"public_key\":{}}}\n",
+            json_string(&signature.block_start.format("%Y-%m-%dT%H:%MZ").to_string()),
+            json_string(&signature.merkle_root),
+            json_string(&signed_message(signature.block_start, &signature.merkle_root)),
+            json_string(&signature.signature),
+            json_string(public_key),
+        ));
+    }
+    out
+}
+
+/// Render the session stats as one JSON object
+///
+/// Figures that don't exist yet (no blocks, no verification run) are null.
+pub fn render_stats(stats: &SessionStats) -> String {
+    let number = |value: Option<f64>| value.map_or("null".to_string(), |v| format!("{:.3}", v));
+    let verification = match stats.verification {
+        Some(rate) => format!(
+            "{{\"entries\":{},\"elapsed_micros\":{:.3},\"entries_per_sec\":{:.3}}}",
+            rate.entries,
+            micros(rate.elapsed),
+            rate.per_second(),
+        ),
+        None => "null".to_string(),
+    };
+    format!(
+        "{{\"uptime_secs\":{},\"entries_sealed\":{},\"entries_retained\":{},\"blocks\":{},\"chapters\":{},\"entries_per_block\":{},\"gaps\":{},\"unrecorded_secs\":{},\"avg_hash_micros\":{},\"last_verification\":{}}}\n",
+        stats.uptime.as_secs(),
+        stats.totals.entries,
+        stats.retained,
+        stats.totals.blocks,
+        stats.totals.chapters,
+        number(stats.entries_per_block()),
+        stats.gaps.gaps,
+        stats.gaps.seconds,
+        number(stats.totals.average_hash_time().map(micros)),
+        verification,
+    )
+}
+
+/// One entry flattened to export fields
+struct ExportRow<'a> {
+    instant_utc: String,
+    entry: &'a LedgerEntry,
+    display_tz: &'a str,
+    dst_active: bool,
+    dst_badge: String,
+}
+
+impl<'a> ExportRow<'a> {
+    fn new(entry: &'a LedgerEntry, display_tz: &'a str) -> Self {
+        let dst_badge = match &entry.dst_badge {
+            DstBadge::None => "none".to_string(),
+            DstBadge::Active => "active".to_string(),
+            DstBadge::GapMarker { from, to } => format!("gap {}-{}", from, to),
+            DstBadge::OverlapPass1 => "overlap_pass1".to_string(),
+            DstBadge::OverlapPass2 => "overlap_pass2".to_string(),
+            DstBadge::LeapSecond => "leap_second".to_string(),
+            DstBadge::Unrecorded { seconds } => format!("unrecorded {}", seconds),
+        };
+
+        Self {
+            instant_utc: entry.instant_utc.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            entry,
+            display_tz,
+            // The first pass through a fall-back hour is still on DST
+            dst_active: matches!(entry.dst_badge, DstBadge::Active | DstBadge::OverlapPass1),
+            dst_badge,
+        }
+    }
+
+    fn to_json(&self) -> String {
+        let event = match self.entry.event() {
+            Some(event) => format!(
+                ",\"entry_type\":\"event\",\"source\":{},\"event_type\":{},\"message\":{}",
+                json_string(&event.source),
+                json_string(&event.kind),
+                json_string(&event.message),
+            ),
+            None => ",\"entry_type\":\"second\"".to_string(),
+        };
+        format!(
+            "{{\"instant_utc\":{},\"local_time\":{},\"display_tz\":{},\"utc_offset\":{},\"utc_offset_minutes\":{},\"dst_active\":{},\"dst_badge\":{},\"recorded_tz\":{},\"prev_hash\":{},\"hash\":{}{}}}",
+            json_string(&self.instant_utc),
+            json_string(&self.entry.local_timestamp),
+            json_string(self.display_tz),
+            json_string(&self.entry.offset_str),
+            self.entry.utc_offset_minutes,
+            self.dst_active,
+            json_string(&self.dst_badge),
+            json_string(&self.entry.tz_name),
+            json_string(&self.entry.prev_hash),
+            json_string(&self.entry.hash),
+            event,
+        )
+    }
+
+    fn to_csv(&self) -> String {
+        [
+            csv_field(&self.instant_utc),
+            csv_field(&self.entry.local_timestamp),
+            csv_field(self.display_tz),
+            csv_field(&self.entry.offset_str),
+            self.entry.utc_offset_minutes.to_string(),
+            self.dst_active.to_string(),
+            csv_field(&self.dst_badge),
+            csv_field(&self.entry.tz_name),
+            csv_field(&self.entry.prev_hash),
+            csv_field(&self.entry.hash),
+        ]
+        .into_iter()
+        .chain(match self.entry.event() {
+            Some(event) => [
+                "event".to_string(),
+                csv_field(&event.source),
+                csv_field(&event.kind),
+                csv_field(&event.message),
+            ],
+            None => ["second".to_string(), String::new(), String::new(), String::new()],
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+    }
+}
+
+/// Quote and escape a JSON string value
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Quote a CSV field when it contains a delimiter, quote or newline
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Tz;
+
+    fn sample_entries() -> Vec<LedgerEntry> {
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let mut older = LedgerEntry::from_instant(
+            Utc.with_ymd_and_hms(2025, 7, 1, 12, 0, 0).unwrap(),
+            tz,
+            true,
+            false,
+            false,
+        );
+        older.seal(crate::ledger::GENESIS_HASH);
+        let mut newer = LedgerEntry::from_instant(
+            Utc.with_ymd_and_hms(2025, 7, 1, 12, 0, 1).unwrap(),
+            tz,
+            true,
+            false,
+            false,
+        );
+        newer.seal(&older.hash);
+        // Newest first, as stored in the ledger
+        vec![newer, older]
+    }
+
+    #[test]
+    fn test_render_jsonl_oldest_first() {
+        let entries = sample_entries();
+        let out = render(entries.iter(), "America/New_York", ExportFormat::JsonLines);
+        let lines: Vec<&str> = out.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("{\"instant_utc\":\"2025-07-01T12:00:00Z\""));
+        assert!(lines[0].contains("\"dst_active\":true"));
+        assert!(lines[1].contains(&format!("\"prev_hash\":\"{}\"", entries[1].hash)));
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let entries = sample_entries();
+        let out = render(entries.iter(), "America/New_York", ExportFormat::Csv);
+        let lines: Vec<&str> = out.lines().collect();
+
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(lines.len(), 3);
+        let fields: Vec<&str> = lines[1].split(',').collect();
+        assert_eq!(fields.len(), CSV_HEADER.split(',').count());
+        assert_eq!(fields[0], "2025-07-01T12:00:00Z");
+        assert_eq!(fields[4], "-240");
+    }
+
+    #[test]
+    fn test_render_signatures() {
+        let signature = BlockSignature {
+            block_start: Utc.with_ymd_and_hms(2025, 7, 1, 12, 0, 0).unwrap(),
+            merkle_root: "ab".to_string(),
+            signature: "cd".to_string(),
+        };
+        let out = render_signatures([&signature].into_iter(), "ef");
+        assert_eq!(
+            out,
+            "{\"block_start\":\"2025-07-01T12:00Z\",\"merkle_root\":\"ab\",\"message\":\"audit-ledger-block|2025-07-01T12:00Z|ab\",\"signature\":\"cd\",\"public_key\":\"ef\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_render_stats() {
+        let stats = SessionStats {
+            uptime: std::time::Duration::from_secs(90),
+            totals: Default::default(),
+            retained: 0,
+            gaps: Default::default(),
+            verification: None,
+        };
+        let out = render_stats(&stats);
+        assert!(out.starts_with("{\"uptime_secs\":90,\"entries_sealed\":0,"));
+        assert!(out.contains("\"entries_per_block\":null"));
+        assert!(out.ends_with("\"last_verification\":null}\n"));
+    }
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(json_string("a\"b\\c"), "\"a\\\"b\\\\c\"");
+        assert_eq!(csv_field("12:00 → 13:00"), "12:00 → 13:00");
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+}
@@ -0,0 +1,310 @@
+//! Ingest module - following an external NDJSON event stream
+//!
+//! In follow mode the ledger tails one JSON object per line from a file,
+//! standard input or a local TCP port, and records each event as an entry
+//! between the second entries. A background thread reads and parses lines;
+//! the update loop drains them from a channel every frame.
+//!
+//! Recognized fields are `source`, `type` (or `event`/`kind`), `message` (or
+//! `msg`) and `ts` (or `timestamp`/`time`, RFC 3339). Any other object is
+//! still recorded, with its JSON as the message.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, ErrorKind, Seek, SeekFrom};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+/// How long a reader waits for more data before checking whether to stop
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Where follow mode reads events from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamSource {
+    /// Tail a file, starting at its current end
+    File(PathBuf),
+    /// Read standard input (a pipe) until it closes
+    Stdin,
+    /// Accept connections on 127.0.0.1 at this port
+    Tcp(u16),
+}
+
+impl StreamSource {
+    /// Parse a source as typed in the sidebar: `-` or `stdin`, `tcp:PORT`,
+    /// or a file path
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let spec = spec.trim();
+        if spec.is_empty() {
+            return Err("Enter a file path, - for stdin, or tcp:PORT".to_string());
+        }
+        if spec == "-" || spec.eq_ignore_ascii_case("stdin") {
+            return Ok(Self::Stdin);
+        }
+        if let Some(port) = spec.strip_prefix("tcp:") {
+            return port
+                .parse()
+                .map(Self::Tcp)
+                .map_err(|_| format!("Invalid TCP port: {}", port));
+        }
+        Ok(Self::File(PathBuf::from(spec)))
+    }
+
+    /// Short name, used as the source of events that don't name their own
+    pub fn label(&self) -> String {
+        match self {
+            Self::File(path) => path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.display().to_string()),
+            Self::Stdin => "stdin".to_string(),
+            Self::Tcp(port) => format!("tcp:{}", port),
+        }
+    }
+}
+
+/// One event read from the stream
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalEvent {
+    /// Who emitted the event; picks the row color
+    pub source: String,
+    /// Event type, "event" when the object has none
+    pub kind: String,
+    pub message: String,
+    /// The event's own timestamp, if it carried one
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+/// Parse one NDJSON line; `default_source` names events without a `source`
+pub fn parse_event(line: &str, default_source: &str) -> Result<ExternalEvent, String> {
+    let value: Value = serde_json::from_str(line).map_err(|e| e.to_string())?;
+    let Value::Object(fields) = &value else {
+        return Err("not a JSON object".to_string());
+    };
+    let text = |names: &[&str]| {
+        names.iter().find_map(|name| match fields.get(*name)? {
+            Value::String(s) => Some(s.clone()),
+            Value::Null => None,
+            other => Some(other.to_string()),
+        })
+    };
+
+    Ok(ExternalEvent {
+        source: text(&["source"]).unwrap_or_else(|| default_source.to_string()),
+        kind: text(&["type", "event", "kind"]).unwrap_or_else(|| "event".to_string()),
+        message: text(&["message", "msg"]).unwrap_or_else(|| value.to_string()),
+        timestamp: text(&["ts", "timestamp", "time"])
+            .and_then(|ts| DateTime::parse_from_rfc3339(&ts).ok())
+            .map(|ts| ts.with_timezone(&Utc)),
+    })
+}
+
+/// What the reader thread reports
+#[derive(Debug)]
+pub enum StreamItem {
+    Event(ExternalEvent),
+    /// A line that wasn't a JSON object
+    Malformed(String),
+    /// The stream failed or ended; no more items will follow
+    Closed(String),
+}
+
+/// A running follow: the reader thread and its channel
+///
+/// Dropping it stops the thread, except that a read from stdin only notices
+/// once the next line arrives or the pipe closes.
+pub struct EventStream {
+    pub source: StreamSource,
+    receiver: Receiver<StreamItem>,
+    stop: Arc<AtomicBool>,
+}
+
+impl EventStream {
+    /// Start reading `source` on a background thread
+    pub fn spawn(source: StreamSource) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let reader = Reader {
+            label: source.label(),
+            sender,
+            stop: Arc::clone(&stop),
+        };
+
+        let thread_source = source.clone();
+        thread::spawn(move || {
+            let outcome = match thread_source {
+                StreamSource::File(path) => reader.tail_file(&path),
+                StreamSource::Stdin => reader.read_lines(io::stdin().lock(), false),
+                StreamSource::Tcp(port) => reader.listen(port),
+            };
+            // Only stdin ends without being stopped or failing
+            if !reader.stopped() {
+                let reason = outcome.err().map_or("End of input".to_string(), |e| e.to_string());
+                reader.send(StreamItem::Closed(reason));
+            }
+        });
+
+        Self {
+            source,
+            receiver,
+            stop,
+        }
+    }
+
+    /// Everything read since the last poll
+    pub fn poll(&self) -> Vec<StreamItem> {
+        self.receiver.try_iter().collect()
+    }
+}
+
+impl Drop for EventStream {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// The reader thread's side of the channel
+#[derive(Clone)]
+struct Reader {
+    /// Source name for events that don't carry one
+    label: String,
+    sender: Sender<StreamItem>,
+    stop: Arc<AtomicBool>,
+}
+
+impl Reader {
+    fn stopped(&self) -> bool {
+        self.stop.load(Ordering::Relaxed)
+    }
+
+    fn send(&self, item: StreamItem) {
+        // The stream was dropped; the thread winds down on its next check
+        let _ = self.sender.send(item);
+    }
+
+    /// Follow a file from its current end, like `tail -f`
+    fn tail_file(&self, path: &Path) -> io::Result<()> {
+        let mut file = File::open(path)?;
+        file.seek(SeekFrom::End(0))?;
+        self.read_lines(BufReader::new(file), true)
+    }
+
+    /// Accept connections, reading each to its end on a thread of its own
+    fn listen(&self, port: u16) -> io::Result<()> {
+        let listener = TcpListener::bind(("127.0.0.1", port))?;
+        listener.set_nonblocking(true)?;
+        while !self.stopped() {
+            match listener.accept() {
+                Ok((stream, _)) => self.read_connection(stream),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => thread::sleep(POLL_INTERVAL),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    fn read_connection(&self, stream: TcpStream) {
+        let reader = self.clone();
+        thread::spawn(move || {
+            let setup = stream
+                .set_nonblocking(false)
+                .and_then(|_| stream.set_read_timeout(Some(POLL_INTERVAL)));
+            if let Err(e) = setup.and_then(|_| reader.read_lines(BufReader::new(stream), false)) {
+                eprintln!("Event stream connection dropped: {}", e);
+            }
+        });
+    }
+
+    /// Read lines until the stop flag is set or, unless `follow_eof`, the end
+    fn read_lines(&self, mut input: impl BufRead, follow_eof: bool) -> io::Result<()> {
+        let mut line = String::new();
+        while !self.stopped() {
+            // A timed-out read keeps what it got, so partial lines accumulate
+            match input.read_line(&mut line) {
+                Ok(0) if follow_eof => thread::sleep(POLL_INTERVAL),
+                Ok(0) => break,
+                Ok(_) if line.ends_with('\n') => {
+                    self.dispatch(line.trim());
+                    line.clear();
+                }
+                // The writer hasn't finished this line yet
+                Ok(_) => {}
+                Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        // A final line without a newline
+        if !follow_eof && !line.trim().is_empty() {
+            self.dispatch(line.trim());
+        }
+        Ok(())
+    }
+
+    fn dispatch(&self, line: &str) {
+        if line.is_empty() {
+            return;
+        }
+        self.send(match parse_event(line, &self.label) {
+            Ok(event) => StreamItem::Event(event),
+            Err(e) => StreamItem::Malformed(e),
+        });
+    }
+}
+
+/// Follow-mode counters for the sidebar
+#[derive(Debug, Clone, Default)]
+pub struct FollowStats {
+    /// Events recorded since following started
+    pub events: usize,
+    /// Lines skipped because they weren't JSON objects
+    pub malformed: usize,
+    /// Why the stream last stopped, if it failed
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_parse_source() {
+        assert_eq!(StreamSource::parse("-"), Ok(StreamSource::Stdin));
+        assert_eq!(StreamSource::parse("tcp:9000"), Ok(StreamSource::Tcp(9000)));
+        assert!(StreamSource::parse("tcp:http").is_err());
+        assert!(StreamSource::parse("  ").is_err());
+        let file = StreamSource::parse("/var/log/deploy.ndjson").unwrap();
+        assert_eq!(file.label(), "deploy.ndjson");
+    }
+
+    #[test]
+    fn test_parse_event_fields() {
+        let event = parse_event(
+            r#"{"source":"deploy","type":"release","msg":"v2.3 live","ts":"2025-06-01T12:00:00Z"}"#,
+            "stdin",
+        )
+        .unwrap();
+        assert_eq!(event.source, "deploy");
+        assert_eq!(event.kind, "release");
+        assert_eq!(event.message, "v2.3 live");
+        assert_eq!(event.timestamp, Some(Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_event_fallbacks() {
+        let event = parse_event(r#"{"status":503}"#, "tcp:9000").unwrap();
+        assert_eq!(event.source, "tcp:9000");
+        assert_eq!(event.kind, "event");
+        assert_eq!(event.message, r#"{"status":503}"#);
+        assert_eq!(event.timestamp, None);
+
+        assert!(parse_event("[1, 2]", "stdin").is_err());
+        assert!(parse_event("not json", "stdin").is_err());
+    }
+}
@@ -2,16 +2,84 @@
 //!
 //! Provides LedgerEntry, MinuteBlock, HourChapter, and LedgerState for managing
 //! the rolling window of time entries with hierarchical grouping.
+//!
+//! Entries form a hash chain: each entry stores the hash of the entry before it
+//! and its own SHA-256 over `(prev_hash, timestamp, tz)`. Blocks (minutes) and
+//! chapters (hours) carry Merkle roots over the hashes they contain.
+//!
+//! Besides one entry per second, the ledger records events from an external
+//! stream in follow mode; their source, type and message join the hash.
 
 use chrono::{DateTime, Utc};
 use chrono_tz::Tz;
-use shared::{compute_time_data_at, DstChange, TimeData};
+use sha2::{Digest, Sha256};
+use shared::{
+    compute_time_data_at, leap_seconds_between, tr, tr_args, DstChange, LeapSecond,
+    LeapSecondStatus, TimeData,
+};
 use std::collections::{HashSet, VecDeque};
+use std::time::Instant;
+
+use crate::hex;
+use crate::ingest::ExternalEvent;
+use crate::stats::SealTotals;
+
+/// Fewest missing seconds recorded as a gap; a single skipped second is a
+/// late frame rather than a suspend
+pub const MIN_GAP_SECONDS: i64 = 2;
+
+/// Previous-hash value for the first entry ever recorded
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Compute the chain hash for an entry: SHA-256 over `prev_hash|timestamp|payload`
+///
+/// The payload is the zone name, followed by the event fields for an
+/// external event.
+pub fn entry_hash(prev_hash: &str, instant_utc: DateTime<Utc>, payload: &str) -> String {
+    let input = format!(
+        "{}|{}|{}",
+        prev_hash,
+        instant_utc.format("%Y-%m-%dT%H:%M:%SZ"),
+        payload
+    );
+
+    let mut hasher = Sha256::new();
+    hasher.update(input.as_bytes());
+    hex::encode(&hasher.finalize())
+}
+
+/// Compute a Merkle root over a list of hex hashes (oldest first)
+///
+/// Odd nodes are paired with themselves. An empty list yields the genesis hash.
+pub fn merkle_root<S: AsRef<str>>(hashes: &[S]) -> String {
+    if hashes.is_empty() {
+        return GENESIS_HASH.to_string();
+    }
+
+    let mut level: Vec<String> = hashes.iter().map(|h| h.as_ref().to_string()).collect();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| {
+                let left = &pair[0];
+                let right = pair.get(1).unwrap_or(left);
+                let mut hasher = Sha256::new();
+                hasher.update(left.as_bytes());
+                hasher.update(right.as_bytes());
+                hex::encode(&hasher.finalize())
+            })
+            .collect();
+    }
+
+    level.remove(0)
+}
 
 /// Time range filter options (in minutes)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Default)]
 pub enum TimeRangeFilter {
     Minutes5,
+    #[default]
     Minutes10,
     Minutes30,
     Minutes60,
@@ -46,11 +114,6 @@ impl TimeRangeFilter {
     }
 }
 
-impl Default for TimeRangeFilter {
-    fn default() -> Self {
-        TimeRangeFilter::Minutes10
-    }
-}
 
 /// DST badge information for a ledger entry
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -65,11 +128,27 @@ pub enum DstBadge {
     OverlapPass1,
     /// Second pass through an overlapped hour (fall back)
     OverlapPass2,
+    /// An inserted leap second, 23:59:60 UTC
+    LeapSecond,
+    /// Seconds that passed unrecorded, e.g. while the computer slept
+    Unrecorded { seconds: i64 },
+}
+
+/// What a ledger entry records
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum EntryKind {
+    /// A second of the clock (or a DST or leap second marker)
+    #[default]
+    Second,
+    /// An event read from the followed stream
+    Event(ExternalEvent),
 }
 
 /// A single ledger entry representing one second
 #[derive(Debug, Clone)]
 pub struct LedgerEntry {
+    /// Whether this is a second or an external event
+    pub kind: EntryKind,
     /// The UTC instant this entry represents
     pub instant_utc: DateTime<Utc>,
     /// Formatted 12h time string (e.g., "12:34:56 PM")
@@ -82,10 +161,16 @@ pub struct LedgerEntry {
     pub offset_str: String,
     /// DST badge information
     pub dst_badge: DstBadge,
-    /// The second within the minute (0-59)
+    /// The second within the minute (0-59, or 60 for a leap second)
     pub second: u32,
     /// UTC offset in minutes (for overlap detection)
     pub utc_offset_minutes: i32,
+    /// Timezone the entry was recorded in (part of the hashed payload)
+    pub tz_name: String,
+    /// Hash of the previous entry in the chain
+    pub prev_hash: String,
+    /// This entry's own chain hash
+    pub hash: String,
 }
 
 impl LedgerEntry {
@@ -95,7 +180,10 @@ impl LedgerEntry {
 
         let local_timestamp = format!(
             "{:02}:{:02}:{:02} {}",
-            time_data.hour12, time_data.minute, time_data.second, time_data.meridiem
+            time_data.hour12,
+            time_data.minute,
+            time_data.display_second(),
+            time_data.meridiem.as_str()
         );
 
         // Determine DST badge
@@ -112,20 +200,25 @@ impl LedgerEntry {
         };
 
         Self {
+            kind: EntryKind::Second,
             instant_utc,
             local_timestamp,
             block_id: time_data.minute,
             chapter_id: time_data.hour24,
             offset_str: time_data.format_utc_offset(),
             dst_badge,
-            second: time_data.second,
+            second: time_data.display_second(),
             utc_offset_minutes: time_data.utc_offset_minutes,
+            tz_name: tz.name().to_string(),
+            prev_hash: String::new(),
+            hash: String::new(),
         }
     }
 
     /// Create a gap marker entry for DST spring forward
-    pub fn gap_marker(instant_utc: DateTime<Utc>, from: String, to: String) -> Self {
+    pub fn gap_marker(instant_utc: DateTime<Utc>, tz: Tz, from: String, to: String) -> Self {
         Self {
+            kind: EntryKind::Second,
             instant_utc,
             local_timestamp: format!("{} → {}", from, to),
             block_id: 0,
@@ -134,16 +227,88 @@ impl LedgerEntry {
             dst_badge: DstBadge::GapMarker { from, to },
             second: 0,
             utc_offset_minutes: 0,
+            tz_name: tz.name().to_string(),
+            prev_hash: String::new(),
+            hash: String::new(),
         }
     }
 
+    /// Create an entry for an inserted leap second
+    ///
+    /// The system clock never reads 23:59:60, so the ledger records the leap
+    /// second explicitly once it has passed. Its hash covers the instant as
+    /// "23:59:60Z".
+    pub fn leap_marker(leap: &LeapSecond, tz: Tz) -> Self {
+        let mut entry = Self::from_instant(leap.instant, tz, false, false, false);
+        entry.dst_badge = DstBadge::LeapSecond;
+        entry
+    }
+
+    /// Create a gap entry for `seconds` that went unrecorded, starting at
+    /// `first_missing`
+    ///
+    /// The count is part of the hashed payload, so the gap can't be shrunk
+    /// or removed without breaking the chain.
+    pub fn unrecorded(first_missing: DateTime<Utc>, seconds: i64, tz: Tz) -> Self {
+        let mut entry = Self::from_instant(first_missing, tz, false, false, false);
+        entry.dst_badge = DstBadge::Unrecorded { seconds };
+        entry
+    }
+
+    /// Create an entry for an event from the followed stream, received at
+    /// `instant_utc`
+    pub fn external(event: ExternalEvent, instant_utc: DateTime<Utc>, tz: Tz) -> Self {
+        let mut entry = Self::from_instant(instant_utc, tz, false, false, false);
+        entry.kind = EntryKind::Event(event);
+        entry
+    }
+
+    /// The event this entry records, if it isn't a second
+    pub fn event(&self) -> Option<&ExternalEvent> {
+        match &self.kind {
+            EntryKind::Event(event) => Some(event),
+            EntryKind::Second => None,
+        }
+    }
+
+    /// Everything the hash covers besides the previous hash and the instant
+    fn hashed_payload(&self) -> String {
+        match &self.kind {
+            EntryKind::Second => match self.dst_badge {
+                DstBadge::Unrecorded { seconds } => format!("{}|gap|{}", self.tz_name, seconds),
+                _ => self.tz_name.clone(),
+            },
+            EntryKind::Event(event) => format!(
+                "{}|{}|{}|{}",
+                self.tz_name, event.source, event.kind, event.message
+            ),
+        }
+    }
+
+    /// Link this entry to its predecessor and compute its own hash
+    pub fn seal(&mut self, prev_hash: &str) {
+        self.prev_hash = prev_hash.to_string();
+        self.hash = entry_hash(prev_hash, self.instant_utc, &self.hashed_payload());
+    }
+
+    /// Recompute the hash from the stored payload
+    pub fn recomputed_hash(&self) -> String {
+        entry_hash(&self.prev_hash, self.instant_utc, &self.hashed_payload())
+    }
+
     /// Recalculate local timestamp for a new timezone
+    ///
+    /// Only the display fields change; `tz_name` and the hashes keep
+    /// describing the zone the entry was originally recorded in.
     pub fn recalculate_for_tz(&mut self, tz: Tz) {
         let time_data = compute_time_data_at(tz, self.instant_utc);
 
         self.local_timestamp = format!(
             "{:02}:{:02}:{:02} {}",
-            time_data.hour12, time_data.minute, time_data.second, time_data.meridiem
+            time_data.hour12,
+            time_data.minute,
+            time_data.display_second(),
+            time_data.meridiem.as_str()
         );
         self.block_id = time_data.minute;
         self.chapter_id = time_data.hour24;
@@ -151,8 +316,9 @@ impl LedgerEntry {
         self.utc_offset_minutes = time_data.utc_offset_minutes;
 
         // Update DST badge based on new timezone
-        // Keep overlap markers as-is since they're based on UTC offset changes
-        if !matches!(self.dst_badge, DstBadge::GapMarker { .. } | DstBadge::OverlapPass1 | DstBadge::OverlapPass2) {
+        // Keep overlap markers as-is since they're based on UTC offset changes;
+        // events carry no DST flag
+        if !self.is_marker() && self.event().is_none() {
             if time_data.is_dst {
                 self.dst_badge = DstBadge::Active;
             } else {
@@ -161,15 +327,102 @@ impl LedgerEntry {
         }
     }
 
-    /// Check if this entry is a special marker (gap or overlap)
+    /// Check if this entry is a special marker (DST gap, overlap, leap
+    /// second or unrecorded seconds)
     pub fn is_marker(&self) -> bool {
         matches!(
             self.dst_badge,
-            DstBadge::GapMarker { .. } | DstBadge::OverlapPass1 | DstBadge::OverlapPass2
+            DstBadge::GapMarker { .. }
+                | DstBadge::OverlapPass1
+                | DstBadge::OverlapPass2
+                | DstBadge::LeapSecond
+                | DstBadge::Unrecorded { .. }
         )
     }
 }
 
+/// Why a chain verification failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+pub enum ChainBreak {
+    /// An entry's stored hash does not match its payload
+    HashMismatch,
+    /// An entry's `prev_hash` does not match the preceding entry's hash
+    LinkMismatch,
+    /// The newest entry's hash does not match the chain head
+    HeadMismatch,
+}
+
+/// Result of walking the hash chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChainVerification {
+    /// Every entry in the buffer verified
+    Intact { entries_checked: usize },
+    /// The chain is broken at the given entry
+    Broken {
+        instant_utc: DateTime<Utc>,
+        reason: ChainBreak,
+    },
+}
+
+impl ChainVerification {
+    /// Short human-readable summary for the sidebar
+    pub fn summary(&self) -> String {
+        match self {
+            ChainVerification::Intact { entries_checked } => {
+                tr_args("✓ Chain intact ({0} entries)", &[entries_checked])
+            }
+            ChainVerification::Broken { instant_utc, reason } => {
+                let text = match reason {
+                    ChainBreak::HashMismatch => "✗ Chain hash mismatch at {0}",
+                    ChainBreak::LinkMismatch => "✗ Chain broken link at {0}",
+                    ChainBreak::HeadMismatch => "✗ Chain head mismatch at {0}",
+                };
+                tr_args(text, &[&instant_utc.format("%H:%M:%SZ")])
+            }
+        }
+    }
+
+    pub fn is_intact(&self) -> bool {
+        matches!(self, ChainVerification::Intact { .. })
+    }
+}
+
+/// Gaps found since the clock started, for the integrity summary
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GapSummary {
+    /// Number of gap entries inserted
+    pub gaps: u32,
+    /// Seconds missing across all of them
+    pub seconds: i64,
+}
+
+impl GapSummary {
+    fn record(&mut self, seconds: i64) {
+        self.gaps += 1;
+        self.seconds += seconds;
+    }
+
+    /// Short human-readable summary for the sidebar
+    pub fn summary(&self) -> String {
+        let span = format_span(self.seconds);
+        match self.gaps {
+            0 => tr("No gaps since launch").to_string(),
+            1 => tr_args("1 gap, {0} unrecorded since launch", &[&span]),
+            n => tr_args("{0} gaps, {1} unrecorded since launch", &[&n, &span]),
+        }
+    }
+}
+
+/// "45s", "1m 31s", "8h 02m"
+fn format_span(seconds: i64) -> String {
+    match (seconds / 3600, seconds % 3600 / 60, seconds % 60) {
+        (0, 0, s) => format!("{}s", s),
+        (0, m, s) => format!("{}m {:02}s", m, s),
+        (h, m, _) => format!("{}h {:02}m", h, m),
+    }
+}
+
 /// State for tracking DST fall-back overlap
 #[derive(Debug, Clone, Default)]
 struct OverlapState {
@@ -204,8 +457,16 @@ pub struct LedgerState {
     last_minute: Option<u32>,
     /// Last recorded UTC offset (for overlap detection)
     last_offset: Option<i32>,
+    /// Instant of the last update (for leap second detection)
+    last_instant: Option<DateTime<Utc>>,
     /// Fall-back overlap tracking state
     overlap_state: OverlapState,
+    /// Hash of the most recently appended entry (survives pruning)
+    head_hash: String,
+    /// Unrecorded stretches found so far (survives pruning)
+    gap_summary: GapSummary,
+    /// Counts and hash timing of every entry sealed (survives pruning)
+    seal_totals: SealTotals,
 }
 
 impl Default for LedgerState {
@@ -220,7 +481,11 @@ impl Default for LedgerState {
             last_second: None,
             last_minute: None,
             last_offset: None,
+            last_instant: None,
             overlap_state: OverlapState::default(),
+            head_hash: GENESIS_HASH.to_string(),
+            gap_summary: GapSummary::default(),
+            seal_totals: SealTotals::default(),
         }
     }
 }
@@ -231,7 +496,76 @@ impl LedgerState {
         Self::default()
     }
 
-    /// Get the maximum number of entries based on current time range
+    /// Hash of the newest entry in the chain
+    pub fn head_hash(&self) -> &str {
+        &self.head_hash
+    }
+
+    /// Unrecorded stretches found since the clock started
+    pub fn gap_summary(&self) -> GapSummary {
+        self.gap_summary
+    }
+
+    /// Counts and hash timing of every entry sealed since the clock started
+    pub fn seal_totals(&self) -> SealTotals {
+        self.seal_totals
+    }
+
+    /// Seal an entry onto the chain and add it at the front (newest first)
+    fn append(&mut self, mut entry: LedgerEntry) {
+        let hash_started = Instant::now();
+        entry.seal(&self.head_hash);
+        self.seal_totals.record(&entry, hash_started.elapsed());
+        self.head_hash = entry.hash.clone();
+        self.entries.push_front(entry);
+    }
+
+    /// Walk the chain from oldest to newest and report the first break
+    ///
+    /// The oldest buffered entry's `prev_hash` is trusted as-is, since its
+    /// predecessor has already been pruned from the rolling window.
+    pub fn verify_chain(&self) -> ChainVerification {
+        let mut expected_prev: Option<&str> = None;
+
+        for entry in self.entries.iter().rev() {
+            if let Some(prev) = expected_prev {
+                if entry.prev_hash != prev {
+                    return ChainVerification::Broken {
+                        instant_utc: entry.instant_utc,
+                        reason: ChainBreak::LinkMismatch,
+                    };
+                }
+            }
+            if entry.recomputed_hash() != entry.hash {
+                return ChainVerification::Broken {
+                    instant_utc: entry.instant_utc,
+                    reason: ChainBreak::HashMismatch,
+                };
+            }
+            expected_prev = Some(entry.hash.as_str());
+        }
+
+        if let Some(newest) = self.entries.front() {
+            if newest.hash != self.head_hash {
+                return ChainVerification::Broken {
+                    instant_utc: newest.instant_utc,
+                    reason: ChainBreak::HeadMismatch,
+                };
+            }
+        }
+
+        ChainVerification::Intact {
+            entries_checked: self.entries.len(),
+        }
+    }
+
+    /// Record an event from the followed stream at `instant_utc`
+    pub fn ingest(&mut self, event: ExternalEvent, instant_utc: DateTime<Utc>, tz: Tz) {
+        self.append(LedgerEntry::external(event, instant_utc, tz));
+        self.prune_entries();
+    }
+
+    /// Get the maximum number of second entries based on current time range
     pub fn max_entries(&self) -> usize {
         self.time_range.as_seconds()
     }
@@ -240,15 +574,47 @@ impl LedgerState {
     ///
     /// Returns true if a new entry was added
     pub fn update(&mut self, time_data: &TimeData, tz: Tz) -> bool {
-        let current_second = time_data.second;
+        let instant_utc = time_data.local_datetime.with_timezone(&Utc);
+        let mut added = false;
+
+        // Seconds since the last update that never reached the ledger, as
+        // when the computer sleeps or the app is suspended
+        if let Some(last) = self.last_instant {
+            let missing = instant_utc.timestamp() - last.timestamp() - 1;
+            if missing >= MIN_GAP_SECONDS {
+                let first_missing = last + chrono::Duration::seconds(1);
+                self.append(LedgerEntry::unrecorded(first_missing, missing, tz));
+                self.gap_summary.record(missing);
+                added = true;
+            }
+        }
+
+        // Record any leap second inserted since the last update, including
+        // one that is happening now
+        let from = self
+            .last_instant
+            .unwrap_or(instant_utc - chrono::Duration::nanoseconds(1));
+        for leap in leap_seconds_between(from, instant_utc) {
+            self.append(LedgerEntry::leap_marker(&leap, tz));
+            added = true;
+        }
+        self.last_instant = Some(instant_utc);
+
+        let current_second = time_data.display_second();
 
         // Only add entry if second changed
         if self.last_second == Some(current_second) {
-            return false;
+            return added;
         }
 
         self.last_second = Some(current_second);
 
+        // The leap second's own entry is its marker
+        if time_data.leap_second == LeapSecondStatus::InProgress {
+            self.prune_entries();
+            return added;
+        }
+
         // Check for DST transitions
         self.check_for_dst_transitions(time_data);
 
@@ -271,7 +637,7 @@ impl LedgerState {
         self.last_offset = Some(time_data.utc_offset_minutes);
 
         // Add entry to front (newest first)
-        self.entries.push_front(entry);
+        self.append(entry);
 
         // Prune old entries
         self.prune_entries();
@@ -340,16 +706,15 @@ impl LedgerState {
     fn mark_overlap_pass1(&mut self, hour: u32, dst_offset: i32) {
         for entry in self.entries.iter_mut() {
             // Mark entries from the same hour that had the DST offset
-            if entry.chapter_id == hour && entry.utc_offset_minutes == dst_offset {
-                if !matches!(entry.dst_badge, DstBadge::GapMarker { .. }) {
+            if entry.chapter_id == hour && entry.utc_offset_minutes == dst_offset
+                && !matches!(entry.dst_badge, DstBadge::GapMarker { .. }) {
                     entry.dst_badge = DstBadge::OverlapPass1;
                 }
-            }
         }
     }
 
     /// Check for DST gaps when minute changes
-    fn check_for_dst_gap(&mut self, time_data: &TimeData, _tz: Tz) {
+    fn check_for_dst_gap(&mut self, time_data: &TimeData, tz: Tz) {
         // Check if we just had a DST spring forward
         if let DstChange::JustOccurred { delta_minutes, .. } = &time_data.dst_change {
             if *delta_minutes > 0 {
@@ -362,19 +727,27 @@ impl LedgerState {
                 };
                 let gap_entry = LedgerEntry::gap_marker(
                     time_data.local_datetime.with_timezone(&Utc),
+                    tz,
                     format!("{:02}:00", skipped_hour),
                     format!("{:02}:00", time_data.hour24),
                 );
-                self.entries.push_front(gap_entry);
+                self.append(gap_entry);
             }
         }
     }
 
     /// Prune entries older than the window size
+    ///
+    /// The window counts seconds; events go once the seconds around them do.
     fn prune_entries(&mut self) {
         let max = self.max_entries();
-        while self.entries.len() > max {
-            self.entries.pop_back();
+        let mut seconds = self.entries.iter().filter(|e| e.event().is_none()).count();
+        while seconds > max {
+            match self.entries.pop_back() {
+                Some(entry) if entry.event().is_none() => seconds -= 1,
+                Some(_) => {}
+                None => break,
+            }
         }
     }
 
@@ -414,6 +787,14 @@ impl LedgerState {
         self.collapsed_chapters.contains(&hour)
     }
 
+    /// Entries not hidden inside a collapsed block or chapter (newest first)
+    pub fn visible_entries(&self) -> impl DoubleEndedIterator<Item = &LedgerEntry> + '_ {
+        self.entries.iter().filter(|entry| {
+            !self.is_chapter_collapsed(entry.chapter_id)
+                && !self.is_block_collapsed(entry.chapter_id, entry.block_id)
+        })
+    }
+
     /// Collapse all visible blocks
     pub fn collapse_all(&mut self) {
         for entry in self.entries.iter() {
@@ -480,6 +861,7 @@ impl LedgerState {
                     hour: entry.chapter_id,
                     collapsed: self.is_chapter_collapsed(entry.chapter_id),
                     blocks: Vec::new(),
+                    merkle_root: String::new(),
                 });
                 chapters.last_mut().unwrap()
             };
@@ -496,10 +878,18 @@ impl LedgerState {
                     minute: entry.block_id,
                     collapsed: self.is_block_collapsed(entry.chapter_id, entry.block_id),
                     entries: vec![entry.clone()],
+                    merkle_root: String::new(),
                 });
             }
         }
 
+        for chapter in chapters.iter_mut() {
+            for block in chapter.blocks.iter_mut() {
+                block.compute_merkle_root();
+            }
+            chapter.compute_merkle_root();
+        }
+
         chapters
     }
 
@@ -521,9 +911,14 @@ impl LedgerState {
                 minute: entry.block_id,
                 collapsed: self.is_block_collapsed(entry.chapter_id, entry.block_id),
                 entries: vec![entry.clone()],
+                merkle_root: String::new(),
             });
         }
 
+        for group in groups.iter_mut() {
+            group.compute_merkle_root();
+        }
+
         groups
     }
 }
@@ -534,9 +929,17 @@ pub struct HourChapter {
     pub hour: u32,
     pub collapsed: bool,
     pub blocks: Vec<BlockGroup>,
+    /// Merkle root over the block roots (oldest block first)
+    pub merkle_root: String,
 }
 
 impl HourChapter {
+    /// Compute the chapter root from its (already computed) block roots
+    pub fn compute_merkle_root(&mut self) {
+        let roots: Vec<&str> = self.blocks.iter().rev().map(|b| b.merkle_root.as_str()).collect();
+        self.merkle_root = merkle_root(&roots);
+    }
+
     /// Get the chapter header text
     pub fn header_text(&self) -> String {
         let total_entries: usize = self.blocks.iter().map(|b| b.entries.len()).sum();
@@ -566,9 +969,17 @@ pub struct BlockGroup {
     pub minute: u32,
     pub collapsed: bool,
     pub entries: Vec<LedgerEntry>,
+    /// Merkle root over the entry hashes (oldest entry first)
+    pub merkle_root: String,
 }
 
 impl BlockGroup {
+    /// Compute the block root from its entry hashes
+    pub fn compute_merkle_root(&mut self) {
+        let hashes: Vec<&str> = self.entries.iter().rev().map(|e| e.hash.as_str()).collect();
+        self.merkle_root = merkle_root(&hashes);
+    }
+
     /// Get the block header text
     pub fn header_text(&self) -> String {
         format!(
@@ -578,3 +989,145 @@ impl BlockGroup {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn filled_ledger(seconds: i64) -> LedgerState {
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let start = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+        let mut ledger = LedgerState::new();
+        for s in 0..seconds {
+            let data = compute_time_data_at(tz, start + chrono::Duration::seconds(s));
+            ledger.update(&data, tz);
+        }
+        ledger
+    }
+
+    #[test]
+    fn test_chain_links_and_verifies() {
+        let ledger = filled_ledger(90);
+        assert_eq!(ledger.verify_chain(), ChainVerification::Intact { entries_checked: 90 });
+
+        let newest = ledger.entries.front().unwrap();
+        let previous = &ledger.entries[1];
+        assert_eq!(newest.prev_hash, previous.hash);
+        assert_eq!(ledger.head_hash(), newest.hash);
+
+        let chapters = ledger.get_chapter_grouped_entries();
+        assert_eq!(chapters[0].blocks.len(), 2);
+        assert_ne!(chapters[0].merkle_root, GENESIS_HASH);
+    }
+
+    #[test]
+    fn test_tampered_entry_breaks_chain() {
+        let mut ledger = filled_ledger(30);
+        let tampered_instant = ledger.entries[10].instant_utc;
+        ledger.entries[10].tz_name = "Europe/London".to_string();
+
+        assert_eq!(
+            ledger.verify_chain(),
+            ChainVerification::Broken {
+                instant_utc: tampered_instant,
+                reason: ChainBreak::HashMismatch,
+            }
+        );
+    }
+
+    #[test]
+    fn test_leap_second_recorded_explicitly() {
+        let tz: Tz = "Europe/London".parse().unwrap();
+        let leap = shared::leap_seconds().last().unwrap();
+        let mut ledger = LedgerState::new();
+        for s in -2..2 {
+            let instant = leap.ends() + chrono::Duration::seconds(s);
+            ledger.update(&compute_time_data_at(tz, instant), tz);
+        }
+
+        // 23:59:58, 23:59:59, the leap marker, then 00:00:00 and 00:00:01
+        assert_eq!(ledger.entries.len(), 5);
+        let marker = &ledger.entries[2];
+        assert_eq!(marker.dst_badge, DstBadge::LeapSecond);
+        assert_eq!(marker.local_timestamp, "11:59:60 PM");
+        assert_eq!(marker.second, 60);
+        assert!(ledger.verify_chain().is_intact());
+
+        // Replaying the leap second itself doesn't record it twice
+        let mut ledger = LedgerState::new();
+        ledger.update(&compute_time_data_at(tz, leap.instant), tz);
+        ledger.update(&compute_time_data_at(tz, leap.ends()), tz);
+        assert_eq!(ledger.entries.len(), 2);
+        assert_eq!(ledger.entries[1].dst_badge, DstBadge::LeapSecond);
+    }
+
+    #[test]
+    fn test_events_interleave_and_chain() {
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let start = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+        let mut ledger = LedgerState::new();
+        ledger.set_time_range(TimeRangeFilter::Minutes5);
+        for s in 0..400 {
+            let instant = start + chrono::Duration::seconds(s);
+            ledger.update(&compute_time_data_at(tz, instant), tz);
+            if s % 100 == 0 {
+                let event = ExternalEvent {
+                    source: "deploy".to_string(),
+                    kind: "release".to_string(),
+                    message: format!("build {}", s),
+                    timestamp: None,
+                };
+                ledger.ingest(event, instant, tz);
+            }
+        }
+
+        // The window still holds five minutes of seconds, plus the events in it
+        let events: Vec<_> = ledger.entries.iter().filter_map(|e| e.event()).collect();
+        let seconds = ledger.entries.len() - events.len();
+        assert_eq!(seconds, 300);
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].message, "build 300");
+        assert!(ledger.verify_chain().is_intact());
+
+        // The message is part of the hashed payload
+        let index = ledger.entries.iter().position(|e| e.event().is_some()).unwrap();
+        if let EntryKind::Event(event) = &mut ledger.entries[index].kind {
+            event.message = "build 301".to_string();
+        }
+        assert!(!ledger.verify_chain().is_intact());
+    }
+
+    #[test]
+    fn test_gap_recorded_after_suspend() {
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let start = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+        let mut ledger = LedgerState::new();
+        for s in [0, 1, 2, 3, 95, 96, 98] {
+            let instant = start + chrono::Duration::seconds(s);
+            ledger.update(&compute_time_data_at(tz, instant), tz);
+        }
+
+        // 4..=94 went missing; the one second skipped before 98 is a late frame
+        assert_eq!(ledger.entries.len(), 8);
+        let gap = &ledger.entries[3];
+        assert_eq!(gap.dst_badge, DstBadge::Unrecorded { seconds: 91 });
+        assert_eq!(gap.instant_utc, start + chrono::Duration::seconds(4));
+        assert_eq!(gap.local_timestamp, "08:00:04 AM");
+        assert_eq!(ledger.gap_summary(), GapSummary { gaps: 1, seconds: 91 });
+        assert_eq!(ledger.gap_summary().summary(), "1 gap, 1m 31s unrecorded since launch");
+        assert_eq!(format_span(8 * 3600 + 125), "8h 02m");
+        assert!(ledger.verify_chain().is_intact());
+
+        // The count is hashed, so the gap can't be quietly shrunk
+        ledger.entries[3].dst_badge = DstBadge::Unrecorded { seconds: 1 };
+        assert!(!ledger.verify_chain().is_intact());
+    }
+
+    #[test]
+    fn test_merkle_root_odd_count() {
+        let single = merkle_root(&["aa"]);
+        assert_eq!(single, "aa");
+        assert_eq!(merkle_root(&["aa", "bb", "cc"]), merkle_root(&["aa", "bb", "cc", "cc"]));
+    }
+}
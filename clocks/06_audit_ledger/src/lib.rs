@@ -0,0 +1,1860 @@
+//! Audit Ledger Clock
+//!
+//! A clock as an event ledger: each second is an entry; minutes are blocks;
+//! hours are chapters. Features terminal/console aesthetic with a SHA-256
+//! hash chain linking every entry to the one before it.
+//!
+//! Follow mode (F) tails an external NDJSON event stream from a file, stdin
+//! or a TCP port and interleaves its events with the second entries, turning
+//! the ledger into an audit console.
+//!
+//! Auto-rotation writes each completed hour or day to its own JSON Lines
+//! file, so a clock left running keeps a durable trail without exports.
+
+mod columns;
+mod drawing;
+mod export;
+mod ingest;
+mod ledger;
+mod pattern;
+mod rotation;
+mod search;
+mod signing;
+mod stats;
+mod ui;
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use nannou::event::ModifiersState;
+use nannou::prelude::*;
+use nannou_egui::{self, Egui};
+use serde::{Deserialize, Serialize};
+use shared::{
+    compute_time_data, report_save, tr, tr_args, ClipRecorder, ClipSettings, ClipStep,
+    CommandPalette, CommandRegistry, ConfigError, ConfigRecovery, ConfigSaver, Favorites,
+    FocusRegions, FollowSystemZone, FramePacer, KeyChord, Keymap, LanguageSetting, LinkMessage,
+    MotionPreference, QuietHours, RecoveryChoice, SafePaletteSetting, ScreenReader,
+    SnapshotProgress, SnapshotStep, SystemMotionWatcher, SystemZoneWatcher, ThemeLibrary,
+    TickGranularity, Ticker, TimeData, TimeReveal, Validity, VisualIntensity, WindowPlacement,
+    WindowTitle, WindowTitleClock,
+};
+
+use crate::columns::LedgerColumns;
+use crate::export::{ExportFormat, ExportScope};
+use crate::drawing::SearchHit;
+use crate::ingest::{EventStream, FollowStats, StreamItem, StreamSource};
+use crate::ledger::{ChainVerification, HourChapter, LedgerEntry, LedgerState, TimeRangeFilter};
+use crate::rotation::{LogRotator, RotationSettings};
+use crate::search::{EntryMatcher, LedgerSearch};
+use crate::signing::{BlockSigner, SignatureLog, SignatureVerification};
+use crate::stats::{SessionStats, VerificationRate};
+use crate::ui::PickerState;
+
+const CLOCK_NAME: &str = "audit_ledger";
+const DEFAULT_TZ: &str = "America/Los_Angeles";
+/// Zone of the comparison column until one is picked
+const DEFAULT_COMPARISON_TZ: &str = "America/New_York";
+const WINDOW_TITLE: &str = "Audit Ledger Clock";
+const SIDEBAR_WIDTH: f32 = 280.0;
+
+/// Run the clock; returns only when the window is closed
+pub fn run() {
+    nannou::app(model).update(update).run();
+}
+
+/// Launcher entry for this clock
+pub struct AuditLedger;
+
+impl shared::ClockApp for AuditLedger {
+    fn id(&self) -> &'static str {
+        CLOCK_NAME
+    }
+
+    fn title(&self) -> &'static str {
+        "Audit Ledger"
+    }
+
+    fn tagline(&self) -> &'static str {
+        "Every second is a hash-chained entry in a terminal ledger"
+    }
+
+    fn run(&self) {
+        run();
+    }
+}
+
+/// Toast notification for error messages
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub created: Instant,
+    pub duration_secs: f32,
+}
+
+impl Toast {
+    pub fn new(message: String, duration_secs: f32) -> Self {
+        Self {
+            message,
+            created: Instant::now(),
+            duration_secs,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.created.elapsed().as_secs_f32() > self.duration_secs
+    }
+
+    pub fn alpha(&self) -> f32 {
+        let elapsed = self.created.elapsed().as_secs_f32();
+        if elapsed > self.duration_secs - 0.5 {
+            // Fade out in last 0.5s
+            ((self.duration_secs - elapsed) / 0.5).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Focus region for keyboard navigation
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FocusRegion {
+    /// Ledger view (main area)
+    #[default]
+    Ledger,
+    /// Sidebar controls
+    Sidebar,
+}
+
+impl FocusRegions for FocusRegion {
+    const ALL: &'static [Self] = &[FocusRegion::Ledger, FocusRegion::Sidebar];
+
+    fn label(self) -> &'static str {
+        match self {
+            FocusRegion::Ledger => "Ledger",
+            FocusRegion::Sidebar => "Sidebar controls",
+        }
+    }
+}
+
+/// Text density for accessibility
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum TextDensity {
+    Compact,
+    #[default]
+    Normal,
+    Large,
+}
+
+impl TextDensity {
+    pub fn row_height(&self) -> f32 {
+        match self {
+            TextDensity::Compact => 18.0,
+            TextDensity::Normal => 24.0,
+            TextDensity::Large => 32.0,
+        }
+    }
+
+    pub fn font_size(&self) -> u32 {
+        match self {
+            TextDensity::Compact => 12,
+            TextDensity::Normal => 14,
+            TextDensity::Large => 18,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TextDensity::Compact => "Compact",
+            TextDensity::Normal => "Normal",
+            TextDensity::Large => "Large",
+        }
+    }
+
+    pub fn cycle(&self) -> Self {
+        match self {
+            TextDensity::Compact => TextDensity::Normal,
+            TextDensity::Normal => TextDensity::Large,
+            TextDensity::Large => TextDensity::Compact,
+        }
+    }
+}
+
+/// Persisted configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Config {
+    selected_zone_id: String,
+    favorites: Vec<String>,
+    /// Keep `favorites` instead of following the series-wide list
+    #[serde(default)]
+    own_favorites: bool,
+    time_range_minutes: u32,
+    text_density: TextDensity,
+    /// Columns shown in ledger rows
+    #[serde(default)]
+    columns: LedgerColumns,
+    /// Zone of the comparison column
+    #[serde(default)]
+    comparison_zone_id: String,
+    reduced_motion: MotionPreference,
+    #[serde(default)]
+    export_format: ExportFormat,
+    #[serde(default)]
+    export_scope: ExportScope,
+    /// Output directory for exports (empty = shared default)
+    #[serde(default)]
+    export_dir: String,
+    /// Automatic per-hour or per-day logs
+    #[serde(default)]
+    rotation: RotationSettings,
+    /// Selected theme name; `None` uses the clock's own colors
+    #[serde(default)]
+    theme: Option<String>,
+    /// Event stream to follow, as typed in the sidebar
+    #[serde(default)]
+    follow_source: String,
+    /// Whether follow mode was on when the clock last closed
+    #[serde(default)]
+    follow: bool,
+    /// Sign completed minute blocks
+    #[serde(default)]
+    signing: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            selected_zone_id: DEFAULT_TZ.to_string(),
+            favorites: vec![
+                "America/New_York".to_string(),
+                "America/Los_Angeles".to_string(),
+                "Europe/London".to_string(),
+                "Asia/Tokyo".to_string(),
+            ],
+            own_favorites: false,
+            time_range_minutes: 10,
+            text_density: TextDensity::Normal,
+            columns: LedgerColumns::default(),
+            comparison_zone_id: DEFAULT_COMPARISON_TZ.to_string(),
+            reduced_motion: MotionPreference::Auto,
+            export_format: ExportFormat::default(),
+            export_scope: ExportScope::default(),
+            export_dir: String::new(),
+            rotation: RotationSettings::default(),
+            theme: None,
+            follow_source: String::new(),
+            follow: false,
+            signing: false,
+        }
+    }
+}
+
+impl shared::VersionedConfig for Config {
+    const VERSION: u32 = 2;
+
+    /// Version 2 names the reduced-motion choice; earlier files hold a bool
+    fn migrate(_from_version: u32, payload: &mut toml::Table) {
+        shared::migrate_reduced_motion(payload);
+    }
+}
+
+/// Application state
+pub struct Model {
+    /// Selected time zone
+    pub selected_zone: Tz,
+    /// Favorite time zones
+    pub favorites: Favorites,
+    /// Current time data
+    pub time_data: TimeData,
+
+    /// Ledger state
+    pub ledger: LedgerState,
+
+    /// Current verification hash (truncated chain head)
+    pub verification_hash: String,
+    /// Result of the last "verify chain" command
+    pub chain_status: Option<ChainVerification>,
+    /// How fast the last chain verification ran
+    pub verification_rate: Option<VerificationRate>,
+    /// When the clock started, for the session uptime
+    pub session_started: Instant,
+    /// Sign completed minute blocks
+    pub signing: bool,
+    /// Local keypair; `None` until signing is first turned on
+    signer: Option<BlockSigner>,
+    /// Signatures for the blocks still in the ledger
+    pub signatures: SignatureLog,
+    /// Result of the last "verify signatures" command
+    pub signature_status: Option<SignatureVerification>,
+
+    /// Sidebar search query and options
+    pub search: LedgerSearch,
+    /// Compiled query; `None` when the query is empty or invalid
+    search_matcher: Option<EntryMatcher>,
+    /// Why the regex query failed to compile
+    search_error: Option<String>,
+    /// Instants of matching entries, newest first
+    search_hits: Vec<DateTime<Utc>>,
+    /// Match last jumped to with N / Shift+N
+    current_match: Option<DateTime<Utc>>,
+
+    /// UI state
+    pub text_density: TextDensity,
+    /// Columns shown in ledger rows
+    pub columns: LedgerColumns,
+    /// Zone whose time the comparison column shows
+    pub comparison_zone: Tz,
+    /// Entry whose detail popup is open
+    entry_detail: Option<LedgerEntry>,
+    /// Reduced motion setting: follow the system, or override it
+    pub motion_preference: MotionPreference,
+    /// Reports the system's reduce-motion preference
+    system_motion: SystemMotionWatcher,
+    /// Whether motion is reduced, from the setting and the system
+    pub reduced_motion: bool,
+    /// Shared quiet-hours schedule
+    pub quiet_hours: QuietHours,
+    /// Brightness and effects allowed right now by the schedule
+    pub intensity: VisualIntensity,
+    /// Shared setting for the time in the window title
+    pub window_title_clock: WindowTitleClock,
+    /// Shared settings for clips recorded with Ctrl+Shift+R
+    pub clip_settings: ClipSettings,
+    /// Clip being recorded or saved, if any
+    pub clip_recorder: ClipRecorder,
+    /// Shared language for labels, dates and toasts
+    pub language: LanguageSetting,
+    /// Shared high-contrast or color-blind safe palette
+    pub safe_palette: SafePaletteSetting,
+    /// Keeps the window title's time current
+    window_title: WindowTitle,
+    /// Remembers the window's size, position and monitor
+    window_placement: WindowPlacement,
+    /// Shared setting for switching to the system zone when it changes
+    follow_system_zone: FollowSystemZone,
+    /// Reports changes to the computer's time zone
+    system_zone: SystemZoneWatcher,
+    /// Shared system-wide shortcut that shows the time from the background
+    time_reveal: TimeReveal,
+    /// Drops the frame rate while the clock sits idle
+    frame_pacer: FramePacer,
+    /// Writes config changes once they settle
+    config_saver: ConfigSaver,
+    /// Shown when the saved config couldn't be read
+    config_recovery: Option<ConfigRecovery>,
+
+    /// Export settings
+    pub export_format: ExportFormat,
+    pub export_scope: ExportScope,
+    /// Output directory for exports (empty = shared default)
+    pub export_dir: String,
+    /// Automatic per-hour or per-day logs
+    pub rotation: RotationSettings,
+    /// Writes the logs while rotation is on
+    log_rotator: Option<LogRotator>,
+
+    /// Event stream source, as typed in the sidebar
+    pub follow_source: String,
+    /// Running follow-mode reader, if following
+    event_stream: Option<EventStream>,
+    /// Events and skipped lines since following started
+    follow_stats: FollowStats,
+
+    /// Built-in and user themes
+    themes: ThemeLibrary,
+    /// Active theme name; `None` uses the clock's own colors
+    theme: Option<String>,
+
+    /// Timezone switching animation
+    pub relabel_start: Option<Instant>,
+    pub relabel_progress: f32,
+
+    /// Picker state
+    pub picker_state: PickerState,
+    /// Ctrl+K command palette
+    command_palette: CommandPalette,
+    /// Actions listed in the command palette, and the chords they're bound to
+    keymap: Keymap,
+
+    /// Focus region for keyboard navigation
+    pub focus_region: FocusRegion,
+    /// Focused block index (for keyboard navigation)
+    pub focused_block_index: Option<usize>,
+
+    /// Window focus state
+    pub window_focused: bool,
+
+    /// Whether an egui text field has keyboard focus
+    pub text_input_focused: bool,
+    /// Whether the pointer is over an egui window, which takes its clicks
+    pointer_over_ui: bool,
+
+    /// Animation time (seconds since start)
+    pub animation_time: f32,
+
+    /// Toast notifications
+    pub toasts: Vec<Toast>,
+
+    /// Error state: TZ data validity
+    pub tz_error: bool,
+
+    /// Last valid timezone (for fallback)
+    pub last_valid_zone: Tz,
+
+    /// Wall-clock ticker driving ledger updates
+    ticker: Ticker,
+    /// Frame counter for `--snapshot` runs
+    snapshot: SnapshotProgress,
+
+    /// Screen reader view of the time, DST status and focus
+    screen_reader: ScreenReader,
+
+    /// egui integration
+    egui: Egui,
+}
+
+impl Model {
+    /// Whether something on screen is moving on its own: a relabel, a toast
+    /// or a clip being captured
+    fn is_animating(&self) -> bool {
+        self.relabel_start.is_some()
+            || !self.toasts.is_empty()
+            || self.clip_recorder.is_recording()
+            || self.time_reveal.is_flashing()
+    }
+
+    /// Set a new timezone with relabel animation
+    pub fn set_timezone(&mut self, tz: Tz) {
+        let old_zone = self.selected_zone;
+        self.selected_zone = tz;
+        self.time_data = compute_time_data(tz);
+
+        // Check validity
+        if self.time_data.validity == Validity::Ok {
+            self.last_valid_zone = tz;
+            self.tz_error = false;
+        } else {
+            // TZ data issue - show error
+            self.tz_error = true;
+            self.show_toast(tr("Timezone data may be stale or missing").to_string());
+        }
+
+        // Trigger relabel animation if timezone actually changed
+        if old_zone != tz {
+            if !self.reduced_motion && self.intensity.animations {
+                self.relabel_start = Some(Instant::now());
+                self.relabel_progress = 0.0;
+            }
+
+            // Recalculate all ledger entries for new timezone
+            self.ledger.recalculate_for_tz(tz);
+            self.refresh_search_hits();
+        }
+
+        self.compute_verification_hash();
+        save_config(self);
+    }
+
+    /// Try to set a timezone from a string, with error handling
+    pub fn try_set_timezone(&mut self, tz_str: &str) {
+        match tz_str.parse::<Tz>() {
+            Ok(tz) => self.set_timezone(tz),
+            Err(_) => {
+                // Invalid TZ - revert to last valid and show toast
+                self.show_toast(tr_args("Invalid timezone: {0}. Reverting.", &[&tz_str]));
+                self.selected_zone = self.last_valid_zone;
+                self.time_data = compute_time_data(self.last_valid_zone);
+            }
+        }
+    }
+
+    /// Point linked clocks (worldline ribbon, temporal topography) at an
+    /// entry's instant
+    pub fn link_entry(&mut self, instant: DateTime<Utc>, label: &str) {
+        let message = match shared::broadcast_link(&LinkMessage::Inspect(instant)) {
+            Ok(0) => tr("No linked clocks are open").to_string(),
+            Ok(1) => tr_args("Sent {0} to 1 clock", &[&label]),
+            Ok(count) => tr_args("Sent {0} to {1} clocks", &[&label, &count]),
+            Err(e) => tr_args("Could not reach linked clocks: {0}", &[&e]),
+        };
+        self.show_toast(message);
+    }
+
+    /// Show a toast notification
+    pub fn show_toast(&mut self, message: String) {
+        self.toasts.push(Toast::new(message, 4.0));
+    }
+
+    /// Prune expired toasts
+    pub fn prune_toasts(&mut self) {
+        self.toasts.retain(|t| !t.is_expired());
+    }
+
+    /// Toggle favorite status for a zone
+    pub fn toggle_favorite(&mut self, tz: Tz) {
+        self.favorites.toggle(tz);
+        save_config(self);
+    }
+
+    /// Update the verification stamp from the chain head
+    pub fn compute_verification_hash(&mut self) {
+        // Truncate to 16 hex chars
+        self.verification_hash = self.ledger.head_hash()[..16].to_uppercase();
+    }
+
+    /// Walk the hash chain and report the result
+    pub fn verify_chain(&mut self) {
+        let started = Instant::now();
+        let status = self.ledger.verify_chain();
+        self.verification_rate = Some(VerificationRate {
+            entries: self.ledger.entries.len(),
+            elapsed: started.elapsed(),
+        });
+        self.show_toast(status.summary());
+        self.chain_status = Some(status);
+    }
+
+    /// Turn block signing on or off, loading the keypair on first use
+    pub fn set_signing(&mut self, enabled: bool) {
+        if enabled && self.signer.is_none() {
+            match signing::load_signer() {
+                Ok(signer) => self.signer = Some(signer),
+                Err(e) => {
+                    self.show_toast(e);
+                    return;
+                }
+            }
+        }
+        self.signing = enabled;
+        self.sign_completed_blocks();
+        save_config(self);
+    }
+
+    /// Sign blocks the ledger has moved past and forget pruned ones
+    pub fn sign_completed_blocks(&mut self) {
+        let Some(signer) = self.signer.as_ref().filter(|_| self.signing) else {
+            return;
+        };
+        let groups = self.ledger.get_grouped_entries();
+        for (start, root) in self.signatures.unsigned_complete(&groups) {
+            self.signatures.insert(signer.sign_block(start, root));
+        }
+        if let Some(oldest) = self.ledger.entries.back() {
+            self.signatures.retain_from(signing::minute_start(oldest.instant_utc));
+        }
+    }
+
+    /// Re-check every block signature and report the result
+    pub fn verify_signatures(&mut self) {
+        let Some(signer) = self.signer.as_ref() else {
+            self.show_toast(tr("Block signing is off").to_string());
+            return;
+        };
+        let groups = self.ledger.get_grouped_entries();
+        let status = self
+            .signatures
+            .verify(&groups, |message, signature| signer.verify_hex(message, signature));
+        self.show_toast(status.summary());
+        self.signature_status = Some(status);
+    }
+
+    /// Recompile the search query and find its matches
+    pub fn set_search(&mut self, search: LedgerSearch) {
+        match EntryMatcher::new(&search) {
+            Ok(matcher) => {
+                self.search_matcher = matcher;
+                self.search_error = None;
+            }
+            Err(e) => {
+                self.search_matcher = None;
+                self.search_error = Some(e);
+            }
+        }
+        self.search = search;
+        self.current_match = None;
+        self.refresh_search_hits();
+    }
+
+    /// Re-run the search after the ledger changed
+    fn refresh_search_hits(&mut self) {
+        self.search_hits = match &self.search_matcher {
+            Some(matcher) => search::matching_instants(&self.ledger.entries, matcher),
+            None => Vec::new(),
+        };
+        if self
+            .current_match
+            .is_some_and(|instant| !search::is_hit(&self.search_hits, instant))
+        {
+            self.current_match = None;
+        }
+    }
+
+    /// Chapters as drawn: grouped entries with the search applied
+    pub fn ledger_chapters(&self) -> Vec<HourChapter> {
+        let chapters = self.ledger.get_chapter_grouped_entries();
+        if self.search_matcher.is_some() {
+            search::filter_chapters(chapters, &self.search_hits, self.search.only_matches)
+        } else {
+            chapters
+        }
+    }
+
+    /// How `entry` relates to the search, for highlighting
+    pub fn search_highlight(&self, entry: &LedgerEntry) -> SearchHit {
+        if self.current_match == Some(entry.instant_utc) {
+            SearchHit::Current
+        } else if search::is_hit(&self.search_hits, entry.instant_utc) {
+            SearchHit::Match
+        } else {
+            SearchHit::None
+        }
+    }
+
+    /// Position of the current match among the hits (0-based)
+    pub fn current_match_index(&self) -> Option<usize> {
+        let current = self.current_match?;
+        self.search_hits.iter().position(|&hit| hit == current)
+    }
+
+    /// Move to the next (older, `step > 0`) or previous match and scroll it
+    /// into view, wrapping at either end
+    pub fn jump_to_match(&mut self, step: isize) {
+        if self.search_hits.is_empty() {
+            if self.search_matcher.is_some() {
+                self.show_toast(tr("No matching entries").to_string());
+            }
+            return;
+        }
+
+        let len = self.search_hits.len() as isize;
+        let index = match self.current_match_index() {
+            Some(index) => (index as isize + step).rem_euclid(len),
+            None if step < 0 => len - 1,
+            None => 0,
+        };
+        let target = self.search_hits[index as usize];
+        self.current_match = Some(target);
+
+        // Leave a few rows of context above the match
+        let row_height = self.text_density.row_height();
+        if let Some(offset) = drawing::row_offset(&self.ledger_chapters(), target, row_height) {
+            let scroll_to = (offset - row_height * 3.0).max(0.0);
+            self.ledger.scroll(scroll_to - self.ledger.scroll_offset);
+        }
+    }
+
+    /// Cycle focus region
+    pub fn cycle_focus_region(&mut self, reverse: bool) {
+        self.focus_region = match (self.focus_region, reverse) {
+            (FocusRegion::Ledger, false) => FocusRegion::Sidebar,
+            (FocusRegion::Sidebar, false) => FocusRegion::Ledger,
+            (FocusRegion::Ledger, true) => FocusRegion::Sidebar,
+            (FocusRegion::Sidebar, true) => FocusRegion::Ledger,
+        };
+    }
+
+    /// Navigate to next/previous block
+    pub fn navigate_block(&mut self, delta: i32) {
+        let groups = self.ledger.get_grouped_entries();
+        if groups.is_empty() {
+            self.focused_block_index = None;
+            return;
+        }
+
+        let current = self.focused_block_index.unwrap_or(0) as i32;
+        let new_index = (current + delta).clamp(0, groups.len() as i32 - 1) as usize;
+        self.focused_block_index = Some(new_index);
+    }
+
+    /// Toggle collapse on focused block
+    pub fn toggle_focused_block(&mut self) {
+        if let Some(idx) = self.focused_block_index {
+            let groups = self.ledger.get_grouped_entries();
+            if let Some(group) = groups.get(idx) {
+                self.ledger.toggle_block_collapse(group.hour, group.minute);
+            }
+        }
+    }
+
+    /// Toggle collapse on the chapter containing the focused block
+    pub fn toggle_focused_chapter(&mut self) {
+        if let Some(idx) = self.focused_block_index {
+            let groups = self.ledger.get_grouped_entries();
+            if let Some(group) = groups.get(idx) {
+                self.ledger.toggle_chapter_collapse(group.hour);
+            }
+        }
+    }
+
+    /// Set text density
+    pub fn set_text_density(&mut self, density: TextDensity) {
+        self.text_density = density;
+        save_config(self);
+    }
+
+    /// Choose the columns shown in ledger rows
+    pub fn set_columns(&mut self, columns: LedgerColumns) {
+        self.columns = columns;
+        save_config(self);
+    }
+
+    /// Choose the zone shown in the comparison column
+    pub fn set_comparison_zone(&mut self, zone: Tz) {
+        self.comparison_zone = zone;
+        save_config(self);
+    }
+
+    /// Set the reduced motion preference
+    pub fn set_motion_preference(&mut self, preference: MotionPreference) {
+        self.apply_motion_preference(preference);
+        save_config(self);
+    }
+
+    /// Resolve a reduced-motion setting against the current system preference
+    fn apply_motion_preference(&mut self, preference: MotionPreference) {
+        self.motion_preference = preference;
+        self.reduced_motion = preference.resolve(self.system_motion.reduced());
+    }
+
+    /// Replace the shared quiet-hours schedule
+    pub fn set_quiet_hours(&mut self, quiet_hours: QuietHours) {
+        self.quiet_hours = quiet_hours;
+        self.intensity = self.quiet_hours.intensity(&self.time_data);
+        report_save("quiet hours", self.quiet_hours.save(), |message| self.show_toast(message));
+    }
+
+    /// Set time range filter
+    pub fn set_time_range(&mut self, range: TimeRangeFilter) {
+        self.ledger.set_time_range(range);
+        save_config(self);
+    }
+
+    /// Start following the event stream named by `follow_source`
+    pub fn start_follow(&mut self) {
+        match StreamSource::parse(&self.follow_source) {
+            Ok(source) => {
+                self.show_toast(tr_args("Following {0}", &[&source.label()]));
+                self.event_stream = Some(EventStream::spawn(source));
+                self.follow_stats = FollowStats::default();
+            }
+            Err(e) => self.show_toast(e),
+        }
+        save_config(self);
+    }
+
+    /// Stop following; events already recorded stay in the ledger
+    pub fn stop_follow(&mut self) {
+        if self.event_stream.take().is_some() {
+            self.show_toast(tr("Stopped following").to_string());
+        }
+        save_config(self);
+    }
+
+    pub fn toggle_follow(&mut self) {
+        if self.event_stream.is_some() {
+            self.stop_follow();
+        } else {
+            self.start_follow();
+        }
+    }
+
+    /// Record whatever the followed stream has delivered since last frame
+    fn drain_event_stream(&mut self) {
+        let Some(stream) = &self.event_stream else {
+            return;
+        };
+        let items = stream.poll();
+        if items.is_empty() {
+            return;
+        }
+
+        let mut recorded = false;
+        for item in items {
+            match item {
+                StreamItem::Event(event) => {
+                    self.ledger.ingest(event, shared::now_utc(), self.selected_zone);
+                    self.follow_stats.events += 1;
+                    recorded = true;
+                }
+                StreamItem::Malformed(e) => {
+                    eprintln!("Skipped event line: {}", e);
+                    self.follow_stats.malformed += 1;
+                }
+                StreamItem::Closed(reason) => {
+                    self.show_toast(tr_args("Event stream closed: {0}", &[&reason]));
+                    self.follow_stats.error = Some(reason);
+                    self.event_stream = None;
+                }
+            }
+        }
+        if recorded {
+            self.compute_verification_hash();
+            self.refresh_search_hits();
+        }
+    }
+
+    /// Write the ledger to a JSONL or CSV file and report where it went
+    pub fn export_ledger(&mut self) {
+        let display_tz = self.selected_zone.name();
+        let document = match self.export_scope {
+            ExportScope::Visible => {
+                export::render(self.ledger.visible_entries(), display_tz, self.export_format)
+            }
+            ExportScope::All => {
+                export::render(self.ledger.entries.iter(), display_tz, self.export_format)
+            }
+        };
+
+        let label = self.time_data.local_datetime.format("%Y%m%dT%H%M%S").to_string();
+        let result = self
+            .write_export(&label, self.export_format.extension(), &document)
+            .and_then(|path| {
+                self.export_signatures(&label)?;
+                self.export_stats(&label)?;
+                Ok(path)
+            });
+
+        match result {
+            Ok(path) => self.show_toast(tr_args(
+                "Exported {0} to {1}",
+                &[&self.export_format.label(), &path.display()],
+            )),
+            Err(e) => self.show_toast(tr_args("Export failed: {0}", &[&e])),
+        }
+    }
+
+    /// Replace the auto-rotation settings and restart the log writer
+    pub fn set_rotation(&mut self, rotation: RotationSettings) {
+        self.rotation = rotation;
+        self.restart_rotation();
+        save_config(self);
+    }
+
+    /// Start or stop writing logs to match the rotation settings and the
+    /// export directory
+    ///
+    /// Only entries sealed from now on are logged.
+    fn restart_rotation(&mut self) {
+        self.log_rotator = None;
+        if !self.rotation.enabled {
+            return;
+        }
+        let dir = if self.export_dir.trim().is_empty() {
+            shared::export_dir()
+        } else {
+            Some(PathBuf::from(self.export_dir.trim()))
+        };
+        match dir {
+            Some(dir) => {
+                self.log_rotator = Some(LogRotator::new(
+                    dir.join(rotation::LOG_DIR),
+                    self.rotation.clone(),
+                    self.ledger.head_hash(),
+                ));
+            }
+            None => {
+                self.rotation.enabled = false;
+                self.show_toast(tr("No directory to write logs to").to_string());
+            }
+        }
+    }
+
+    /// Append newly sealed entries to the rotated logs
+    fn rotate_logs(&mut self) {
+        let Some(rotator) = &mut self.log_rotator else {
+            return;
+        };
+        if let Err(e) = rotator.record(self.ledger.entries.iter(), self.selected_zone.name()) {
+            self.log_rotator = None;
+            self.rotation.enabled = false;
+            self.show_toast(tr_args("Log rotation stopped: {0}", &[&e]));
+            save_config(self);
+        }
+    }
+
+    /// The session's stats as of now
+    pub fn session_stats(&self) -> SessionStats {
+        SessionStats::collect(
+            &self.ledger,
+            self.session_started,
+            Instant::now(),
+            self.verification_rate,
+        )
+    }
+
+    /// Write the session stats next to an export
+    fn export_stats(&self, label: &str) -> Result<(), ConfigError> {
+        let document = export::render_stats(&self.session_stats());
+        self.write_export(&format!("{}_stats", label), "json", &document)?;
+        Ok(())
+    }
+
+    /// Write the block signatures and public key next to an export
+    fn export_signatures(&self, label: &str) -> Result<(), ConfigError> {
+        let Some(signer) = self.signer.as_ref().filter(|_| !self.signatures.is_empty()) else {
+            return Ok(());
+        };
+        let document = export::render_signatures(self.signatures.iter(), &signer.public_key_hex());
+        self.write_export(&format!("{}_signatures", label), "jsonl", &document)?;
+        Ok(())
+    }
+
+    /// Write an export file to the chosen directory, or the shared default
+    fn write_export(
+        &self,
+        label: &str,
+        extension: &str,
+        document: &str,
+    ) -> Result<PathBuf, ConfigError> {
+        if self.export_dir.trim().is_empty() {
+            shared::write_export(CLOCK_NAME, label, extension, document.as_bytes())
+        } else {
+            shared::write_export_to(
+                Path::new(self.export_dir.trim()),
+                CLOCK_NAME,
+                label,
+                extension,
+                document.as_bytes(),
+            )
+        }
+    }
+}
+
+fn save_config(model: &Model) {
+    let time_range_minutes = match model.ledger.time_range {
+        TimeRangeFilter::Minutes5 => 5,
+        TimeRangeFilter::Minutes10 => 10,
+        TimeRangeFilter::Minutes30 => 30,
+        TimeRangeFilter::Minutes60 => 60,
+    };
+
+    let config = Config {
+        selected_zone_id: model.selected_zone.name().to_string(),
+        favorites: model.favorites.own_ids(),
+        own_favorites: model.favorites.uses_own(),
+        time_range_minutes,
+        text_density: model.text_density,
+        columns: model.columns,
+        comparison_zone_id: model.comparison_zone.name().to_string(),
+        reduced_motion: model.motion_preference,
+        export_format: model.export_format,
+        export_scope: model.export_scope,
+        export_dir: model.export_dir.clone(),
+        rotation: model.rotation.clone(),
+        theme: model.theme.clone(),
+        follow_source: model.follow_source.clone(),
+        follow: model.event_stream.is_some(),
+        signing: model.signing,
+    };
+    model.config_saver.request(&config);
+}
+
+fn model(app: &App) -> Model {
+    // Disable default escape-to-exit behavior
+    app.set_exit_on_escape(false);
+
+    // Create window
+    let window_id = app
+        .new_window()
+        .title(WINDOW_TITLE)
+        .size(1100, 800)
+        .min_size(800, 600)
+        .view(view)
+        .key_pressed(key_pressed)
+        .mouse_pressed(mouse_pressed)
+        .mouse_wheel(mouse_wheel)
+        .raw_event(raw_window_event)
+        .visible(false)
+        .build()
+        .unwrap();
+
+    let window = app.window(window_id).unwrap();
+    let egui = Egui::from_window(&window);
+    shared::install_fallback_fonts(egui.ctx());
+
+    // Load configuration, noting an unreadable file for the recovery prompt
+    let (config, config_recovery) = shared::load_config_or_recover(CLOCK_NAME);
+    let config: Config = config.unwrap_or_default();
+
+    // Parse timezone from config
+    let selected_zone: Tz = config
+        .selected_zone_id
+        .parse()
+        .unwrap_or_else(|_| DEFAULT_TZ.parse().unwrap());
+    let comparison_zone: Tz = config
+        .comparison_zone_id
+        .parse()
+        .unwrap_or_else(|_| DEFAULT_COMPARISON_TZ.parse().unwrap());
+
+    // The shared favorites, or this clock's own
+    let favorites = Favorites::load(&config.favorites, config.own_favorites);
+
+    // Get initial time data
+    let themes = ThemeLibrary::load();
+    let theme = themes.activate(config.theme.as_deref());
+    let system_motion = SystemMotionWatcher::spawn();
+
+    let time_data = compute_time_data(selected_zone);
+
+    // Set up ledger with configured time range
+    let mut ledger = LedgerState::new();
+    let time_range = match config.time_range_minutes {
+        5 => TimeRangeFilter::Minutes5,
+        30 => TimeRangeFilter::Minutes30,
+        60 => TimeRangeFilter::Minutes60,
+        _ => TimeRangeFilter::Minutes10,
+    };
+    ledger.set_time_range(time_range);
+
+    // Initial stamp is the (empty) chain head
+    let verification_hash = ledger.head_hash()[..16].to_uppercase();
+
+    // Screen reader tree, attached before the window is first shown
+    let screen_reader = ScreenReader::attach(
+        window.winit_window(),
+        "Audit Ledger Clock",
+        &time_data,
+        Some(FocusRegion::default()),
+    );
+
+    // Reopen where the window was left, or where the command line asks
+    let mut window_placement = WindowPlacement::load(CLOCK_NAME);
+    window_placement.restore(window.winit_window());
+    window.set_visible(shared::snapshot_request().is_none());
+
+    let wake_proxy = app.create_proxy();
+    let mut model = Model {
+        selected_zone,
+        favorites,
+        time_data,
+        ledger,
+        verification_hash,
+        chain_status: None,
+        verification_rate: None,
+        session_started: Instant::now(),
+        signing: false,
+        signer: None,
+        signatures: SignatureLog::default(),
+        signature_status: None,
+        search: LedgerSearch::default(),
+        search_matcher: None,
+        search_error: None,
+        search_hits: Vec::new(),
+        current_match: None,
+        text_density: config.text_density,
+        columns: config.columns,
+        comparison_zone,
+        entry_detail: None,
+        motion_preference: config.reduced_motion,
+        reduced_motion: config.reduced_motion.resolve(system_motion.reduced()),
+        system_motion,
+        quiet_hours: QuietHours::load(),
+        intensity: VisualIntensity::FULL,
+        window_title_clock: WindowTitleClock::load(),
+        clip_settings: ClipSettings::load(),
+        clip_recorder: ClipRecorder::new(CLOCK_NAME),
+        language: LanguageSetting::load(),
+        safe_palette: SafePaletteSetting::load(),
+        window_title: WindowTitle::new(WINDOW_TITLE),
+        window_placement,
+        follow_system_zone: FollowSystemZone::load(),
+        system_zone: SystemZoneWatcher::spawn(),
+        time_reveal: TimeReveal::load(),
+        frame_pacer: FramePacer::spawn(move || wake_proxy.wakeup().is_ok()),
+        config_saver: ConfigSaver::new(CLOCK_NAME),
+        config_recovery,
+        themes,
+        theme,
+        export_format: config.export_format,
+        export_scope: config.export_scope,
+        export_dir: config.export_dir,
+        rotation: config.rotation,
+        log_rotator: None,
+        follow_source: config.follow_source,
+        event_stream: None,
+        follow_stats: FollowStats::default(),
+        relabel_start: None,
+        relabel_progress: 0.0,
+        picker_state: PickerState::default(),
+        command_palette: CommandPalette::default(),
+        keymap: Keymap::load(CLOCK_NAME, commands()),
+        focus_region: FocusRegion::default(),
+        focused_block_index: None,
+        window_focused: true,
+        text_input_focused: false,
+        pointer_over_ui: false,
+        animation_time: 0.0,
+        toasts: Vec::new(),
+        tz_error: false,
+        last_valid_zone: selected_zone,
+        ticker: Ticker::spawn(TickGranularity::Second),
+        snapshot: SnapshotProgress::default(),
+        screen_reader,
+        egui,
+    };
+
+    // Resume following where the last session left off
+    if config.follow {
+        model.start_follow();
+    }
+    // Keep signing blocks if it was on
+    if config.signing {
+        model.set_signing(true);
+    }
+    model.restart_rotation();
+    model
+}
+
+/// Drive a `--snapshot` run: capture once the frame has settled, then quit
+fn advance_snapshot(app: &App, progress: &mut SnapshotProgress) {
+    let Some(request) = shared::snapshot_request() else {
+        return;
+    };
+    match progress.advance() {
+        SnapshotStep::Settle => {}
+        SnapshotStep::Capture => app.main_window().capture_frame(&request.path),
+        SnapshotStep::Finish => {
+            if app.main_window().await_capture_frame_jobs().is_err() {
+                eprintln!("Timed out writing snapshot to {}", request.path.display());
+                std::process::exit(1);
+            }
+            println!("Saved snapshot to {}", request.path.display());
+            app.quit();
+        }
+    }
+}
+
+/// Capture frames for a clip being recorded, and report the clip once saved
+fn advance_clip(app: &App, model: &mut Model) {
+    match model.clip_recorder.advance(Instant::now()) {
+        ClipStep::Idle => {}
+        ClipStep::Capture(path) => app.main_window().capture_frame(path),
+        ClipStep::Finish => {
+            let written = app.main_window().await_capture_frame_jobs().is_ok();
+            model.clip_recorder.encode(written);
+        }
+    }
+    if let Some(message) = model.clip_recorder.poll() {
+        model.show_toast(message);
+    }
+}
+
+/// Let the event loop wait between second boundaries while the clock is idle
+fn pace_frames(app: &App, model: &mut Model, now: Instant) {
+    let animating = model.is_animating();
+    let loop_mode = if model.frame_pacer.pace(animating, now) {
+        LoopMode::Wait
+    } else {
+        LoopMode::RefreshSync
+    };
+    app.set_loop_mode(loop_mode);
+}
+
+/// Act on the config recovery prompt: restart on the backup, or carry on
+/// with the defaults
+fn recover_config(app: &App, model: &mut Model, choice: RecoveryChoice) {
+    let Some(recovery) = model.config_recovery.take() else {
+        return;
+    };
+    if choice == RecoveryChoice::Restore {
+        match recovery.restore(&model.config_saver) {
+            Ok(()) => app.quit(),
+            Err(message) => model.show_toast(message),
+        }
+    }
+}
+
+fn update(app: &App, model: &mut Model, update: Update) {
+    advance_snapshot(app, &mut model.snapshot);
+    advance_clip(app, model);
+
+    // Update animation time
+    model.animation_time = update.since_start.as_secs_f32();
+
+    // Update time data, ledger and verification hash once per wall-clock second
+    if model.ticker.poll().is_some() {
+        model.time_data = compute_time_data(model.selected_zone);
+        model.intensity = model.quiet_hours.intensity(&model.time_data);
+        model.ledger.update(&model.time_data, model.selected_zone);
+        model.compute_verification_hash();
+        model.sign_completed_blocks();
+        model.refresh_search_hits();
+    }
+
+    model.drain_event_stream();
+    model.rotate_logs();
+
+    // Update relabel animation
+    if let Some(start) = model.relabel_start {
+        let elapsed = start.elapsed().as_secs_f32();
+        let duration = 0.3; // 300ms
+
+        if elapsed < duration {
+            model.relabel_progress = elapsed / duration;
+        } else {
+            model.relabel_start = None;
+            model.relabel_progress = 1.0;
+        }
+    }
+
+    // Prune expired toasts
+    model.prune_toasts();
+
+    let current_match = model.current_match_index();
+    let session_stats = model.session_stats();
+
+    // Begin egui frame
+    model.egui.set_elapsed_time(update.since_start);
+    let ctx = model.egui.begin_frame();
+
+    // Draw sidebar UI
+    let mut export_format = model.export_format;
+    let mut export_scope = model.export_scope;
+    let mut export_dir = model.export_dir.clone();
+    let mut rotation = model.rotation.clone();
+    let mut search = model.search.clone();
+    let mut follow_source = model.follow_source.clone();
+    let ui_result = ui::draw_sidebar(
+        &ctx,
+        &mut model.picker_state,
+        model.selected_zone,
+        &model.favorites,
+        &model.time_data,
+        &model.ledger,
+        model.columns,
+        model.comparison_zone,
+        model.text_density,
+        model.motion_preference,
+        model.system_motion.reduced(),
+        model.chain_status.as_ref(),
+        &session_stats,
+        ui::SigningStatus {
+            enabled: model.signing,
+            public_key: model.signer.as_ref().map(BlockSigner::public_key_hex),
+            signed_blocks: model.signatures.len(),
+            verification: model.signature_status.as_ref(),
+        },
+        &mut export_format,
+        &mut export_scope,
+        &mut export_dir,
+        &mut rotation,
+        model.log_rotator.as_ref().map(LogRotator::status),
+        &mut search,
+        ui::SearchStatus {
+            matches: model.search_hits.len(),
+            current: current_match,
+            error: model.search_error.as_deref(),
+        },
+        &mut follow_source,
+        ui::FollowStatus {
+            following: model.event_stream.as_ref().map(|stream| stream.source.label()),
+            stats: &model.follow_stats,
+        },
+        &model.quiet_hours,
+        model.window_title_clock,
+        model.clip_settings,
+        model.language,
+        model.safe_palette,
+        model.follow_system_zone,
+        &model.time_reveal.setting,
+        model.time_reveal.status(),
+        model.favorites.uses_own(),
+        &model.keymap,
+        &model.themes,
+        model.theme.as_deref(),
+    );
+
+    // Draw the clicked entry's details (if open)
+    let detail_result = model
+        .entry_detail
+        .as_ref()
+        .map(|entry| ui::draw_entry_detail(&ctx, entry, &model.favorites));
+
+    // Draw the time card while the reveal shortcut flashes the window
+    model.time_reveal.show(&ctx, &model.time_data);
+
+    // Offer the backup if the saved config couldn't be read
+    let recovery_choice = model.config_recovery.as_ref().and_then(|r| r.show(&ctx));
+
+    // Draw command palette (if open)
+    let palette_choice = model.command_palette.show(&ctx, model.keymap.registry());
+
+    let text_input_focused = ctx.wants_keyboard_input();
+    let pointer_over_ui = ctx.is_pointer_over_area();
+
+    drop(ctx);
+    if let Some(choice) = recovery_choice {
+        recover_config(app, model, choice);
+    }
+
+    model.text_input_focused = text_input_focused;
+    model.pointer_over_ui = pointer_over_ui;
+    model.export_format = export_format;
+    model.export_scope = export_scope;
+    model.export_dir = export_dir;
+    model.follow_source = follow_source;
+
+    // Apply UI results
+    if let Some(tz) = ui_result.set_timezone {
+        model.set_timezone(tz);
+    }
+    if let Some(tz) = ui_result.toggle_favorite {
+        model.toggle_favorite(tz);
+    }
+    if let Some(range) = ui_result.set_time_range {
+        model.set_time_range(range);
+    }
+    if let Some(columns) = ui_result.set_columns {
+        model.set_columns(columns);
+    }
+    if let Some(zone) = ui_result.set_comparison_zone {
+        model.set_comparison_zone(zone);
+    }
+    if let Some(detail) = detail_result {
+        if let Some(label) = detail.copied {
+            model.show_toast(tr_args("Copied {0}", &[&label]));
+        }
+        if detail.link {
+            if let Some(entry) = &model.entry_detail {
+                let (instant, label) = (entry.instant_utc, entry.local_timestamp.clone());
+                model.link_entry(instant, &label);
+            }
+        }
+        if detail.close {
+            model.entry_detail = None;
+        }
+    }
+    if let Some(density) = ui_result.set_density {
+        model.set_text_density(density);
+    }
+    if let Some(preference) = ui_result.set_motion_preference {
+        model.set_motion_preference(preference);
+    }
+    if let Some(quiet_hours) = ui_result.set_quiet_hours {
+        model.set_quiet_hours(quiet_hours);
+    }
+    if let Some(setting) = ui_result.set_window_title {
+        model.window_title_clock = setting;
+        report_save(
+            "window title setting",
+            model.window_title_clock.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if let Some(settings) = ui_result.set_clip_settings {
+        model.clip_settings = settings;
+        report_save(
+            "recording settings",
+            model.clip_settings.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if let Some(setting) = ui_result.set_language {
+        model.language = setting;
+        report_save("language setting", model.language.save(), |message| model.show_toast(message));
+    }
+    if let Some(setting) = ui_result.set_safe_palette {
+        model.safe_palette = setting;
+        report_save(
+            "palette setting",
+            model.safe_palette.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if let Some(setting) = ui_result.set_reveal_hotkey {
+        model.time_reveal.setting = setting;
+        model.time_reveal.apply();
+    }
+    if let Some(setting) = ui_result.set_follow_system_zone {
+        model.follow_system_zone = setting;
+        report_save(
+            "system zone setting",
+            model.follow_system_zone.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if let Some(own) = ui_result.set_own_favorites {
+        model.favorites.set_uses_own(own);
+        save_config(model);
+    }
+    if let Some(edit) = ui_result.keymap_edit {
+        model.keymap.apply(edit);
+    }
+    if ui_result.verify_chain {
+        model.verify_chain();
+    }
+    if let Some(enabled) = ui_result.set_signing {
+        model.set_signing(enabled);
+    }
+    if ui_result.verify_signatures {
+        model.verify_signatures();
+    }
+    if ui_result.export_options_changed {
+        // Logs follow the export directory
+        model.restart_rotation();
+        save_config(model);
+    }
+    if ui_result.rotation_changed {
+        model.set_rotation(rotation);
+    }
+    if ui_result.export {
+        model.export_ledger();
+    }
+    if ui_result.follow_source_changed {
+        save_config(model);
+    }
+    if ui_result.toggle_follow {
+        model.toggle_follow();
+    }
+    if ui_result.search_changed {
+        model.set_search(search);
+    }
+    if let Some(step) = ui_result.jump_to_match {
+        model.jump_to_match(step);
+    }
+    if let Some(theme) = ui_result.set_theme {
+        model.theme = model.themes.activate(theme.as_deref());
+        save_config(model);
+    }
+
+    // Run the command chosen in the palette as if its keys were pressed
+    if let Some(chord) = palette_choice {
+        handle_key(model, chord.key, chord.mods);
+    }
+
+    // Keep the screen reader's time, DST status and focus current
+    model.screen_reader.update(&model.time_data, Some(model.focus_region));
+
+    // Pick up favorites starred in another clock
+    model.favorites.poll();
+
+    // Follow the computer's time zone when it changes
+    if let Some(change) = model.system_zone.poll() {
+        let switch = model.follow_system_zone.enabled && change.current != model.selected_zone;
+        if switch {
+            model.set_timezone(change.current);
+        }
+        model.show_toast(change.message(switch));
+    }
+
+    // Follow the system reduce-motion setting when set to Auto
+    if model.system_motion.poll() {
+        model.apply_motion_preference(model.motion_preference);
+    }
+
+    // Show the current time in the taskbar/dock title, and any clip recording
+    let badge = model.clip_recorder.indicator(Instant::now());
+    model.window_title.set_badge(badge);
+    model.window_title.update(
+        app.main_window().winit_window(),
+        &model.window_title_clock,
+        model.selected_zone,
+        shared::now_utc(),
+    );
+
+    // Show or end the reveal shortcut's flash
+    model.time_reveal.update(app.main_window().winit_window(), Instant::now());
+
+    // Remember where the window was moved or resized to
+    model.window_placement.track(app.main_window().winit_window(), Instant::now());
+
+    // Write config changes once they have settled
+    model.config_saver.poll(Instant::now());
+
+    // Idle between second boundaries once nothing is moving
+    pace_frames(app, model, Instant::now());
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    let window_rect = app.window_rect();
+
+    // Draw background
+    draw.background().color(drawing::colors::background());
+
+    // Calculate layout regions
+    let ledger_rect = Rect::from_x_y_w_h(
+        window_rect.left() + (window_rect.w() - SIDEBAR_WIDTH) / 2.0,
+        window_rect.y(),
+        window_rect.w() - SIDEBAR_WIDTH,
+        window_rect.h(),
+    );
+
+    // Draw header
+    drawing::draw_header(&draw, &ledger_rect, &model.time_data, &model.verification_hash);
+
+    // Draw ledger
+    drawing::draw_ledger(
+        &draw,
+        &ledger_rect,
+        model,
+    );
+
+    // Draw "Return to Live" button if not live
+    if !model.ledger.is_live {
+        drawing::draw_return_to_live_button(&draw, &ledger_rect, model.keymap.registry());
+    }
+
+    // Dim the ledger during quiet hours
+    if model.intensity.is_quiet() {
+        draw.rect()
+            .xy(window_rect.xy())
+            .wh(window_rect.wh())
+            .color(srgba(0.0, 0.0, 0.0, model.intensity.dim_alpha()));
+    }
+
+    // Draw error banner if TZ data issue
+    if model.tz_error {
+        drawing::draw_error_banner(&draw, window_rect);
+    }
+
+    // Draw toast notifications
+    drawing::draw_toasts(&draw, window_rect, &model.toasts);
+
+    // Draw focus indicator
+    if model.focus_region == FocusRegion::Ledger {
+        drawing::draw_focus_indicator(&draw, &ledger_rect);
+    }
+
+    // Render to frame
+    draw.to_frame(app, &frame).unwrap();
+
+    // Render egui on top
+    model.egui.draw_to_frame(&frame).unwrap();
+}
+
+/// Every action in the command palette, with the keys that perform it
+fn commands() -> CommandRegistry {
+    CommandRegistry::new()
+        .register("Open timezone picker", KeyChord::new(Key::T))
+        .register("Return to live", KeyChord::new(Key::L))
+        .register("Scroll down", KeyChord::new(Key::J))
+        .register("Scroll up", KeyChord::new(Key::K))
+        .register("Toggle focused block", KeyChord::new(Key::Return))
+        .register("Toggle focused chapter", KeyChord::new(Key::C))
+        .register("Collapse all blocks", KeyChord::ctrl(Key::LBracket))
+        .register("Expand all blocks", KeyChord::ctrl(Key::RBracket))
+        .register("Collapse all chapters", KeyChord::shift(Key::LBracket))
+        .register("Expand all chapters", KeyChord::shift(Key::RBracket))
+        .register("Verify hash chain", KeyChord::new(Key::V))
+        .register("Verify block signatures", KeyChord::shift(Key::V))
+        .register("Export ledger", KeyChord::new(Key::E))
+        .register("Toggle follow mode", KeyChord::new(Key::F))
+        .register("Next search match", KeyChord::new(Key::N))
+        .register("Previous search match", KeyChord::shift(Key::N))
+        .register("Next focus region", KeyChord::new(Key::Tab))
+        .register("Previous focus region", KeyChord::shift(Key::Tab))
+        .register("Record clip", KeyChord::ctrl_shift(Key::R))
+}
+
+fn key_pressed(app: &App, model: &mut Model, key: Key) {
+    let mods = app.keys.mods;
+
+    // While a shortcut is being rebound, the next chord becomes its binding
+    if model.keymap.capture(key, mods) {
+        return;
+    }
+
+    // Ctrl+K opens the command palette from anywhere; while open it takes
+    // every other key
+    if KeyChord::is_palette_toggle(key, mods) {
+        model.command_palette.toggle();
+        return;
+    }
+    if model.command_palette.is_open {
+        return;
+    }
+
+    // Typing into a sidebar text field shouldn't trigger shortcuts
+    if model.text_input_focused && !model.picker_state.is_open {
+        return;
+    }
+
+    // Remapped chords reach the handler as their action's default chord
+    if let Some(chord) = model.keymap.translate(key, mods) {
+        handle_key(model, chord.key, chord.mods);
+    }
+}
+
+fn handle_key(model: &mut Model, key: Key, mods: ModifiersState) {
+    match key {
+        // Ctrl+Shift+R - start or stop recording a clip
+        _ if shared::is_clip_toggle(key, mods) => {
+            let message = model.clip_recorder.toggle(model.clip_settings, Instant::now());
+            model.show_toast(message);
+        }
+
+        // T - open timezone picker
+        Key::T if !model.picker_state.is_open => {
+            model.picker_state.open();
+        }
+
+        // / - focus search in picker
+        Key::Slash => {
+            if model.picker_state.is_open {
+                model.picker_state.should_focus_search = true;
+            } else {
+                model.picker_state.open();
+            }
+        }
+
+        // L - return to live
+        Key::L => {
+            model.ledger.return_to_live();
+        }
+
+        // J/K or Down/Up - scroll ledger
+        Key::J | Key::Down if model.focus_region == FocusRegion::Ledger => {
+            model.ledger.scroll(model.text_density.row_height() * 3.0);
+            model.navigate_block(1);
+        }
+        Key::K | Key::Up if model.focus_region == FocusRegion::Ledger => {
+            model.ledger.scroll(-model.text_density.row_height() * 3.0);
+            model.navigate_block(-1);
+        }
+
+        // [ - collapse blocks/chapters
+        Key::LBracket => {
+            if mods.shift() {
+                // Shift+[ = collapse all chapters
+                model.ledger.collapse_all_chapters();
+            } else if mods.ctrl() || mods.logo() {
+                // Ctrl+[ = collapse all blocks
+                model.ledger.collapse_all();
+            } else {
+                // [ = toggle focused block
+                model.toggle_focused_block();
+            }
+        }
+
+        // ] - expand blocks/chapters
+        Key::RBracket => {
+            if mods.shift() {
+                // Shift+] = expand all chapters
+                model.ledger.expand_all_chapters();
+            } else if mods.ctrl() || mods.logo() {
+                // Ctrl+] = expand all blocks
+                model.ledger.expand_all();
+            } else {
+                // ] = toggle focused block
+                model.toggle_focused_block();
+            }
+        }
+
+        // V / Shift+V - verify hash chain / block signatures
+        Key::V => {
+            if mods.shift() {
+                model.verify_signatures();
+            } else {
+                model.verify_chain();
+            }
+        }
+
+        // E - export ledger
+        Key::E if !model.picker_state.is_open => {
+            model.export_ledger();
+        }
+
+        // F - start/stop following the event stream
+        Key::F if !model.picker_state.is_open => {
+            model.toggle_follow();
+        }
+
+        // N / Shift+N - next/previous search match
+        Key::N => {
+            model.jump_to_match(if mods.shift() { -1 } else { 1 });
+        }
+
+        // C - toggle focused chapter collapse
+        Key::C if model.focus_region == FocusRegion::Ledger => {
+            model.toggle_focused_chapter();
+        }
+
+        // Tab - cycle focus regions
+        Key::Tab if !model.picker_state.is_open => {
+            model.cycle_focus_region(mods.shift());
+        }
+
+        // Enter/Space - activate focused element
+        Key::Return | Key::Space if model.focus_region == FocusRegion::Ledger => {
+            model.toggle_focused_block();
+        }
+
+        // Escape - close picker or return to live
+        Key::Escape => {
+            if model.picker_state.is_open {
+                model.picker_state.close();
+            } else if model.entry_detail.is_some() {
+                model.entry_detail = None;
+            } else if !model.ledger.is_live {
+                model.ledger.return_to_live();
+            }
+        }
+
+        _ => {}
+    }
+}
+
+fn mouse_pressed(app: &App, model: &mut Model, button: MouseButton) {
+    // Clicks on the detail popup or palette are egui's
+    if model.pointer_over_ui {
+        return;
+    }
+    if button == MouseButton::Left {
+        let pos = app.mouse.position();
+        let window_rect = app.window_rect();
+
+        // Calculate ledger rect
+        let ledger_rect = Rect::from_x_y_w_h(
+            window_rect.left() + (window_rect.w() - SIDEBAR_WIDTH) / 2.0,
+            window_rect.y(),
+            window_rect.w() - SIDEBAR_WIDTH,
+            window_rect.h(),
+        );
+
+        // Check if clicking "Return to Live" button
+        if !model.ledger.is_live {
+            let button_rect = Rect::from_x_y_w_h(
+                ledger_rect.x(),
+                ledger_rect.bottom() + 60.0,
+                200.0,
+                40.0,
+            );
+            if button_rect.contains(pt2(pos.x, pos.y)) {
+                model.ledger.return_to_live();
+                return;
+            }
+        }
+
+        // Check if clicking in ledger area
+        if ledger_rect.contains(pt2(pos.x, pos.y)) {
+            model.focus_region = FocusRegion::Ledger;
+
+            // Clicking an entry opens its details; rows start below the
+            // header and column titles, as in `draw_ledger`
+            let content_top = ledger_rect.top() - 125.0;
+            if pos.y < content_top + 5.0 {
+                let offset = content_top + model.ledger.scroll_offset - pos.y;
+                let row_height = model.text_density.row_height();
+                let chapters = model.ledger_chapters();
+                if let Some(entry) = drawing::entry_at_offset(&chapters, offset, row_height) {
+                    model.entry_detail = Some(entry.clone());
+                    return;
+                }
+            }
+
+            // Hit test for block headers
+            let groups = model.ledger.get_grouped_entries();
+            let header_height = 30.0;
+            let row_height = model.text_density.row_height();
+            let start_y = ledger_rect.top() - 80.0 + model.ledger.scroll_offset;
+            let mut current_y = start_y;
+
+            for (idx, group) in groups.iter().enumerate() {
+                let header_rect = Rect::from_x_y_w_h(
+                    ledger_rect.x(),
+                    current_y - header_height / 2.0,
+                    ledger_rect.w() - 40.0,
+                    header_height,
+                );
+
+                if header_rect.contains(pt2(pos.x, pos.y)) {
+                    model.focused_block_index = Some(idx);
+                    model.ledger.toggle_block_collapse(group.hour, group.minute);
+                    return;
+                }
+
+                current_y -= header_height;
+
+                if !group.collapsed {
+                    current_y -= row_height * group.entries.len() as f32;
+                }
+            }
+        }
+    } else if button == MouseButton::Middle {
+        // Rotary press: toggle collapse on focused block
+        model.toggle_focused_block();
+    }
+}
+
+fn mouse_wheel(_app: &App, model: &mut Model, delta: MouseScrollDelta, _phase: TouchPhase) {
+    let scroll_amount = match delta {
+        MouseScrollDelta::LineDelta(_, y) => y * model.text_density.row_height() * 3.0,
+        MouseScrollDelta::PixelDelta(pos) => pos.y as f32,
+    };
+
+    model.ledger.scroll(-scroll_amount);
+}
+
+fn raw_window_event(app: &App, model: &mut Model, event: &nannou::winit::event::WindowEvent) {
+    // Any window event brings an idle clock back to full frame rate
+    model.frame_pacer.note_input(Instant::now());
+
+    model.egui.handle_raw_event(event);
+    model.screen_reader.process_event(app.main_window().winit_window(), event);
+
+    // Track window focus for resync
+    match event {
+        nannou::winit::event::WindowEvent::Focused(focused) => {
+            model.window_focused = *focused;
+            if *focused {
+                // Resync time on focus
+                model.time_data = compute_time_data(model.selected_zone);
+            }
+        }
+        // Handle touch events
+        nannou::winit::event::WindowEvent::Touch(touch) => {
+            let window_rect = app.window_rect();
+
+            // Convert touch position to nannou coordinates
+            let pos_x = touch.location.x as f32 - window_rect.w() / 2.0;
+            let pos_y = window_rect.h() / 2.0 - touch.location.y as f32;
+
+            match touch.phase {
+                // Check for "Return to Live" tap
+                nannou::winit::event::TouchPhase::Started if !model.ledger.is_live => {
+                    let ledger_rect = Rect::from_x_y_w_h(
+                        window_rect.left() + (window_rect.w() - SIDEBAR_WIDTH) / 2.0,
+                        window_rect.y(),
+                        window_rect.w() - SIDEBAR_WIDTH,
+                        window_rect.h(),
+                    );
+                    let button_rect = Rect::from_x_y_w_h(
+                        ledger_rect.x(),
+                        ledger_rect.bottom() + 60.0,
+                        200.0,
+                        40.0,
+                    );
+                    if button_rect.contains(pt2(pos_x, pos_y)) {
+                        model.ledger.return_to_live();
+                    }
+                }
+                nannou::winit::event::TouchPhase::Moved => {
+                    // Could implement swipe-to-scroll here
+                }
+                _ => {}
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Hex encoding helper (since we need it for the hash chain)
+mod hex {
+    pub fn encode(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    pub fn decode(text: &str) -> Option<Vec<u8>> {
+        if !text.len().is_multiple_of(2) {
+            return None;
+        }
+        (0..text.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+            .collect()
+    }
+}
+
@@ -0,0 +1,292 @@
+//! Pattern module - a small regular expression matcher for ledger search
+//!
+//! Supports the subset that is useful against ledger rows: literals, `.`,
+//! classes (`[0-9a-f]`, `[^ ]`), `\d \w \s` and their negations, the
+//! quantifiers `* + ?`, the anchors `^ $` and top-level alternation `|`.
+//! Groups and counted repetition are rejected with an error rather than
+//! silently treated as literals. Matching ignores ASCII case.
+
+/// What a single position of the pattern accepts
+#[derive(Debug, Clone, PartialEq)]
+enum Atom {
+    Char(char),
+    Any,
+    Class { ranges: Vec<(char, char)>, negated: bool },
+}
+
+impl Atom {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Atom::Char(expected) => expected.eq_ignore_ascii_case(&c),
+            Atom::Any => true,
+            Atom::Class { ranges, negated } => {
+                let folded = [c, c.to_ascii_lowercase(), c.to_ascii_uppercase()];
+                let hit = ranges
+                    .iter()
+                    .any(|&(lo, hi)| folded.iter().any(|&f| lo <= f && f <= hi));
+                hit != *negated
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Repeat {
+    One,
+    ZeroOrOne,
+    ZeroOrMore,
+    OneOrMore,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Piece {
+    atom: Atom,
+    repeat: Repeat,
+}
+
+/// One alternative of the pattern
+#[derive(Debug, Clone, PartialEq)]
+struct Branch {
+    anchored_start: bool,
+    anchored_end: bool,
+    pieces: Vec<Piece>,
+}
+
+/// A compiled search pattern
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    branches: Vec<Branch>,
+}
+
+impl Pattern {
+    /// Compile `source`, describing the first unsupported or malformed part
+    pub fn new(source: &str) -> Result<Self, String> {
+        let branches = split_alternatives(source)
+            .into_iter()
+            .map(|branch| parse_branch(&branch))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { branches })
+    }
+
+    /// Whether the pattern matches anywhere in `text`
+    pub fn is_match(&self, text: &str) -> bool {
+        let chars: Vec<char> = text.chars().collect();
+        self.branches.iter().any(|branch| {
+            let last_start = if branch.anchored_start { 0 } else { chars.len() };
+            (0..=last_start).any(|start| match_here(branch, &branch.pieces, &chars, start))
+        })
+    }
+}
+
+/// Split on `|` outside classes and escapes
+fn split_alternatives(source: &str) -> Vec<String> {
+    let mut branches = vec![String::new()];
+    let mut chars = source.chars();
+    let mut in_class = false;
+    while let Some(c) = chars.next() {
+        let current = branches.last_mut().unwrap();
+        match c {
+            '\\' => {
+                current.push(c);
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+            }
+            '[' => {
+                in_class = true;
+                current.push(c);
+            }
+            ']' => {
+                in_class = false;
+                current.push(c);
+            }
+            '|' if !in_class => branches.push(String::new()),
+            _ => current.push(c),
+        }
+    }
+    branches
+}
+
+fn parse_branch(source: &str) -> Result<Branch, String> {
+    let mut chars: Vec<char> = source.chars().collect();
+    let anchored_start = chars.first() == Some(&'^');
+    if anchored_start {
+        chars.remove(0);
+    }
+    // A trailing `$` anchors unless it is escaped
+    let trailing_backslashes = chars.iter().rev().skip(1).take_while(|&&c| c == '\\').count();
+    let anchored_end = chars.last() == Some(&'$') && trailing_backslashes % 2 == 0;
+    if anchored_end {
+        chars.pop();
+    }
+
+    let mut pieces: Vec<Piece> = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let atom = match chars[i] {
+            '.' => Atom::Any,
+            '[' => {
+                let (atom, end) = parse_class(&chars, i + 1)?;
+                i = end;
+                atom
+            }
+            '\\' => {
+                i += 1;
+                let escaped = *chars.get(i).ok_or("Trailing backslash")?;
+                escape_atom(escaped)
+            }
+            '(' | ')' => return Err("Groups are not supported".to_string()),
+            '{' => return Err("Counted repetition is not supported".to_string()),
+            '*' | '+' | '?' => return Err(format!("Nothing to repeat before '{}'", chars[i])),
+            c => Atom::Char(c),
+        };
+        i += 1;
+
+        let repeat = match chars.get(i) {
+            Some('*') => Repeat::ZeroOrMore,
+            Some('+') => Repeat::OneOrMore,
+            Some('?') => Repeat::ZeroOrOne,
+            _ => Repeat::One,
+        };
+        if repeat != Repeat::One {
+            i += 1;
+        }
+        pieces.push(Piece { atom, repeat });
+    }
+
+    Ok(Branch {
+        anchored_start,
+        anchored_end,
+        pieces,
+    })
+}
+
+/// Parse a class body starting after `[`; returns the atom and the index of `]`
+fn parse_class(chars: &[char], mut i: usize) -> Result<(Atom, usize), String> {
+    let negated = chars.get(i) == Some(&'^');
+    if negated {
+        i += 1;
+    }
+    let mut ranges = Vec::new();
+    let mut first = true;
+    loop {
+        let c = *chars.get(i).ok_or("Unclosed '['")?;
+        if c == ']' && !first {
+            return Ok((Atom::Class { ranges, negated }, i));
+        }
+        first = false;
+
+        let lo = if c == '\\' {
+            i += 1;
+            let escaped = *chars.get(i).ok_or("Unclosed '['")?;
+            match escape_atom(escaped) {
+                Atom::Char(literal) => literal,
+                Atom::Class { ranges: class, negated: false } => {
+                    ranges.extend(class);
+                    i += 1;
+                    continue;
+                }
+                _ => return Err(format!("'\\{}' is not supported inside a class", escaped)),
+            }
+        } else {
+            c
+        };
+
+        // `a-z` is a range unless the `-` is last in the class
+        if chars.get(i + 1) == Some(&'-') && chars.get(i + 2).is_some_and(|&hi| hi != ']') {
+            let hi = chars[i + 2];
+            if hi < lo {
+                return Err(format!("Range '{}-{}' is backwards", lo, hi));
+            }
+            ranges.push((lo, hi));
+            i += 3;
+        } else {
+            ranges.push((lo, lo));
+            i += 1;
+        }
+    }
+}
+
+fn escape_atom(c: char) -> Atom {
+    let class = |ranges: &[(char, char)], negated| Atom::Class {
+        ranges: ranges.to_vec(),
+        negated,
+    };
+    const DIGIT: &[(char, char)] = &[('0', '9')];
+    const WORD: &[(char, char)] = &[('a', 'z'), ('A', 'Z'), ('0', '9'), ('_', '_')];
+    const SPACE: &[(char, char)] = &[(' ', ' '), ('\t', '\t'), ('\n', '\n'), ('\r', '\r')];
+    match c {
+        'd' => class(DIGIT, false),
+        'D' => class(DIGIT, true),
+        'w' => class(WORD, false),
+        'W' => class(WORD, true),
+        's' => class(SPACE, false),
+        'S' => class(SPACE, true),
+        literal => Atom::Char(literal),
+    }
+}
+
+/// Backtracking match of `pieces` against `text` from position `i`
+fn match_here(branch: &Branch, pieces: &[Piece], text: &[char], i: usize) -> bool {
+    let Some((piece, rest)) = pieces.split_first() else {
+        return !branch.anchored_end || i == text.len();
+    };
+    let matches_at = |j: usize| j < text.len() && piece.atom.matches(text[j]);
+    match piece.repeat {
+        Repeat::One => matches_at(i) && match_here(branch, rest, text, i + 1),
+        Repeat::ZeroOrOne => {
+            (matches_at(i) && match_here(branch, rest, text, i + 1))
+                || match_here(branch, rest, text, i)
+        }
+        Repeat::ZeroOrMore | Repeat::OneOrMore => {
+            let min = if piece.repeat == Repeat::OneOrMore { 1 } else { 0 };
+            let max = (i..text.len()).take_while(|&j| matches_at(j)).count();
+            (min..=max)
+                .rev()
+                .any(|count| match_here(branch, rest, text, i + count))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_match(pattern: &str, text: &str) -> bool {
+        Pattern::new(pattern).unwrap().is_match(text)
+    }
+
+    #[test]
+    fn test_literals_classes_and_quantifiers() {
+        assert!(is_match("dst", "12:00:01 PM │ DST"));
+        assert!(is_match(r"\d\d:\d\d:0\d", "03:15:07 AM"));
+        assert!(!is_match(r"\d\d:\d\d:0\d", "03:15:17 AM"));
+        assert!(is_match("^[0-9a-f]+$", "00ab3f"));
+        assert!(!is_match("^[0-9a-f]+$", "00ab3g"));
+        assert!(is_match("colou?r", "color"));
+        assert!(is_match("a.*z", "a to z"));
+        assert!(is_match(r"utc-0[78]:00", "UTC-08:00"));
+        assert!(is_match(r"[^\d ]", "12 x"));
+        assert!(!is_match(r"[^\d ]", "12 34"));
+    }
+
+    #[test]
+    fn test_anchors_and_alternation() {
+        assert!(is_match("^p1|p2$", "P1 pass"));
+        assert!(is_match("^p1|p2$", "pass P2"));
+        assert!(!is_match("^p1|p2$", "P2 pass"));
+        assert!(is_match(r"cost \$5$", "cost $5"));
+        assert!(is_match(r"a\$", "a$"));
+        assert!(is_match("[|]", "a|b"));
+    }
+
+    #[test]
+    fn test_rejects_unsupported_syntax() {
+        assert!(Pattern::new("(ab)+").is_err());
+        assert!(Pattern::new("a{2}").is_err());
+        assert!(Pattern::new("*a").is_err());
+        assert!(Pattern::new("[abc").is_err());
+        assert!(Pattern::new("[z-a]").is_err());
+        assert!(Pattern::new("ab\\").is_err());
+    }
+}
@@ -0,0 +1,358 @@
+//! Rotation module - automatic logs of completed hours or days
+//!
+//! With auto-rotation on, every entry the ledger seals is appended as a JSON
+//! Lines row, in the export format, to an open log for its hour or day
+//! (`audit_ledger_<period>.jsonl.part`). When an entry from a later period
+//! arrives, the open log is closed: renamed to `.jsonl`, or gzipped to
+//! `.jsonl.gz`, after which only the newest closed logs are kept. Periods
+//! are UTC hours and days, so a DST change or zone switch never splits or
+//! merges a file.
+//!
+//! Logs live in their own folder under the export directory, so retention
+//! never deletes a manual export. A log left open when the clock quits is
+//! continued by the next run in the same period, or closed once a later
+//! period begins.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use shared::{tr, tr_args};
+
+use crate::export;
+use crate::ledger::LedgerEntry;
+
+/// Folder under the export directory that holds the logs
+pub const LOG_DIR: &str = "audit_ledger_logs";
+
+/// Most closed logs the retention setting offers to keep
+pub const MAX_KEEP: u32 = 720;
+
+/// File name prefix of every log
+const LOG_PREFIX: &str = "audit_ledger_";
+
+/// Extension of a log still being written
+const OPEN_EXTENSION: &str = "jsonl.part";
+
+/// How much of the ledger each log covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum RotationPeriod {
+    #[default]
+    Hourly,
+    Daily,
+}
+
+impl RotationPeriod {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RotationPeriod::Hourly => "Hourly",
+            RotationPeriod::Daily => "Daily",
+        }
+    }
+
+    pub fn all() -> &'static [RotationPeriod] {
+        &[RotationPeriod::Hourly, RotationPeriod::Daily]
+    }
+
+    /// Name of the UTC period containing `instant`, as used in file names
+    pub fn stamp(&self, instant: DateTime<Utc>) -> String {
+        match self {
+            RotationPeriod::Hourly => instant.format("%Y%m%dT%H00Z").to_string(),
+            RotationPeriod::Daily => instant.format("%Y%m%dZ").to_string(),
+        }
+    }
+}
+
+/// Auto-rotation options, saved with the clock's config
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RotationSettings {
+    pub enabled: bool,
+    pub period: RotationPeriod,
+    /// Closed logs to keep, newest first; 0 keeps every log
+    pub keep: u32,
+    /// Gzip each log as it closes
+    pub compress: bool,
+}
+
+impl Default for RotationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            period: RotationPeriod::default(),
+            keep: 48,
+            compress: false,
+        }
+    }
+}
+
+/// The log entries are currently appended to
+struct OpenLog {
+    stamp: String,
+    file: File,
+}
+
+/// Writes sealed entries to per-period logs and closes them as periods end
+pub struct LogRotator {
+    dir: PathBuf,
+    settings: RotationSettings,
+    /// Hash of the newest entry already written
+    written_through: String,
+    open: Option<OpenLog>,
+    /// Most recently closed log
+    last_closed: Option<PathBuf>,
+}
+
+impl LogRotator {
+    /// Log entries sealed after `head_hash` into `dir`
+    pub fn new(dir: PathBuf, settings: RotationSettings, head_hash: &str) -> Self {
+        Self {
+            dir,
+            settings,
+            written_through: head_hash.to_string(),
+            open: None,
+            last_closed: None,
+        }
+    }
+
+    /// Period of the log being written, if one is open
+    pub fn open_stamp(&self) -> Option<&str> {
+        self.open.as_ref().map(|log| log.stamp.as_str())
+    }
+
+    pub fn last_closed(&self) -> Option<&Path> {
+        self.last_closed.as_deref()
+    }
+
+    /// One-line state for the sidebar
+    pub fn status(&self) -> String {
+        let closed = self.last_closed().and_then(|path| path.file_name());
+        match (self.open_stamp(), closed) {
+            (_, Some(name)) => tr_args("Last closed {0}", &[&name.to_string_lossy()]),
+            (Some(stamp), None) => tr_args("Writing {0}", &[&stamp]),
+            (None, None) => tr("Waiting for the next entry").to_string(),
+        }
+    }
+
+    /// Write the entries sealed since the last call, closing the open log
+    /// when they reach a later period
+    ///
+    /// Entries are given newest first, as the ledger stores them. An entry
+    /// stamped slightly before the open period (an event received across the
+    /// boundary) stays in the open log rather than reopening a closed one.
+    pub fn record<'a, I>(&mut self, entries: I, display_tz: &str) -> io::Result<()>
+    where
+        I: Iterator<Item = &'a LedgerEntry>,
+    {
+        let fresh: Vec<&LedgerEntry> =
+            entries.take_while(|entry| entry.hash != self.written_through).collect();
+        let Some(newest) = fresh.first() else {
+            return Ok(());
+        };
+
+        for entry in fresh.iter().rev() {
+            let stamp = self.settings.period.stamp(entry.instant_utc);
+            if self.open_stamp().is_none_or(|open| stamp.as_str() > open) {
+                self.begin(stamp)?;
+            }
+            if let Some(log) = &mut self.open {
+                writeln!(log.file, "{}", export::jsonl_row(entry, display_tz))?;
+            }
+        }
+
+        self.written_through = newest.hash.clone();
+        Ok(())
+    }
+
+    /// Open the log for `stamp`, closing every other open log first,
+    /// including any a previous run left behind
+    fn begin(&mut self, stamp: String) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        self.open = None;
+
+        let path = self.path(&stamp, OPEN_EXTENSION);
+        for stale in self.logs(|name| name.ends_with(OPEN_EXTENSION))? {
+            if stale != path {
+                self.close(&stale)?;
+            }
+        }
+
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        self.open = Some(OpenLog { stamp, file });
+        Ok(())
+    }
+
+    /// Turn an open log into a closed one, then apply retention
+    fn close(&mut self, open: &Path) -> io::Result<()> {
+        // "<name>.jsonl.part" -> "<name>.jsonl"
+        let plain = open.with_extension("");
+        let closed = if self.settings.compress {
+            let gzipped = plain.with_extension("jsonl.gz");
+            let mut encoder = GzEncoder::new(File::create(&gzipped)?, Compression::default());
+            io::copy(&mut File::open(open)?, &mut encoder)?;
+            encoder.finish()?;
+            fs::remove_file(open)?;
+            gzipped
+        } else {
+            fs::rename(open, &plain)?;
+            plain
+        };
+        self.last_closed = Some(closed);
+        self.prune()
+    }
+
+    /// Delete the oldest closed logs beyond the retention limit
+    fn prune(&self) -> io::Result<()> {
+        if self.settings.keep == 0 {
+            return Ok(());
+        }
+        let closed = self.logs(|name| name.ends_with(".jsonl") || name.ends_with(".jsonl.gz"))?;
+        let excess = closed.len().saturating_sub(self.settings.keep as usize);
+        for path in &closed[..excess] {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    /// Logs in the folder whose file name passes `filter`, oldest first
+    fn logs(&self, filter: impl Fn(&str) -> bool) -> io::Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        for dir_entry in fs::read_dir(&self.dir)? {
+            let path = dir_entry?.path();
+            let matches = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(LOG_PREFIX) && filter(name));
+            if matches {
+                paths.push(path);
+            }
+        }
+        // Stamps sort chronologically
+        paths.sort();
+        Ok(paths)
+    }
+
+    fn path(&self, stamp: &str, extension: &str) -> PathBuf {
+        self.dir.join(format!("{}{}.{}", LOG_PREFIX, stamp, extension))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use chrono_tz::Tz;
+
+    use crate::ledger::GENESIS_HASH;
+
+    /// A sealed chain of one entry per `step`, newest first
+    fn chain(start: DateTime<Utc>, count: i64, step: Duration) -> Vec<LedgerEntry> {
+        let tz: Tz = "Europe/London".parse().unwrap();
+        let mut entries: Vec<LedgerEntry> = Vec::new();
+        for i in 0..count {
+            let instant = start + step * i as i32;
+            let mut entry = LedgerEntry::from_instant(instant, tz, false, false, false);
+            entry.seal(entries.first().map_or(GENESIS_HASH, |prev| prev.hash.as_str()));
+            entries.insert(0, entry);
+        }
+        entries
+    }
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join(format!("audit-rotation-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn file_names(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_stamp() {
+        let instant = Utc.with_ymd_and_hms(2025, 3, 30, 0, 59, 59).unwrap();
+        assert_eq!(RotationPeriod::Hourly.stamp(instant), "20250330T0000Z");
+        assert_eq!(RotationPeriod::Daily.stamp(instant), "20250330Z");
+    }
+
+    #[test]
+    fn test_closes_log_when_period_ends() {
+        let dir = test_dir("close");
+        let start = Utc.with_ymd_and_hms(2025, 7, 1, 11, 59, 58).unwrap();
+        let entries = chain(start, 4, Duration::seconds(1));
+        let mut rotator = LogRotator::new(dir.clone(), RotationSettings::default(), GENESIS_HASH);
+
+        // Two calls with the same entries write each row once
+        rotator.record(entries[2..].iter(), "UTC").unwrap();
+        rotator.record(entries[2..].iter(), "UTC").unwrap();
+        assert_eq!(file_names(&dir), ["audit_ledger_20250701T1100Z.jsonl.part"]);
+
+        rotator.record(entries.iter(), "UTC").unwrap();
+        assert_eq!(
+            file_names(&dir),
+            ["audit_ledger_20250701T1100Z.jsonl", "audit_ledger_20250701T1200Z.jsonl.part"]
+        );
+        assert_eq!(rotator.open_stamp(), Some("20250701T1200Z"));
+
+        let closed = fs::read_to_string(dir.join("audit_ledger_20250701T1100Z.jsonl")).unwrap();
+        let rows: Vec<&str> = closed.lines().collect();
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].contains("\"instant_utc\":\"2025-07-01T11:59:58Z\""));
+        assert!(rows[1].contains(&format!("\"hash\":\"{}\"", entries[2].hash)));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_compresses_and_keeps_newest() {
+        let dir = test_dir("keep");
+        let start = Utc.with_ymd_and_hms(2025, 7, 1, 9, 30, 0).unwrap();
+        let entries = chain(start, 4, Duration::hours(1));
+        let settings = RotationSettings {
+            enabled: true,
+            keep: 2,
+            compress: true,
+            ..RotationSettings::default()
+        };
+        let mut rotator = LogRotator::new(dir.clone(), settings, GENESIS_HASH);
+
+        rotator.record(entries.iter(), "UTC").unwrap();
+        assert_eq!(
+            file_names(&dir),
+            [
+                "audit_ledger_20250701T1000Z.jsonl.gz",
+                "audit_ledger_20250701T1100Z.jsonl.gz",
+                "audit_ledger_20250701T1200Z.jsonl.part",
+            ]
+        );
+        assert_eq!(
+            rotator.last_closed(),
+            Some(dir.join("audit_ledger_20250701T1100Z.jsonl.gz").as_path())
+        );
+
+        // A later run closes the log this one left open
+        let mut next = LogRotator::new(dir.clone(), rotator.settings.clone(), GENESIS_HASH);
+        let later = chain(start + Duration::hours(5), 1, Duration::seconds(1));
+        next.record(later.iter(), "UTC").unwrap();
+        assert_eq!(
+            file_names(&dir),
+            [
+                "audit_ledger_20250701T1100Z.jsonl.gz",
+                "audit_ledger_20250701T1200Z.jsonl.gz",
+                "audit_ledger_20250701T1400Z.jsonl.part",
+            ]
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
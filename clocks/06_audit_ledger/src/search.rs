@@ -0,0 +1,203 @@
+//! Search module - live filtering of ledger rows
+//!
+//! A query matches against a row's searchable text: its local timestamp,
+//! UTC offset, DST flags, chain hash and, for external events, the source,
+//! type and message. Plain queries match as a
+//! case-insensitive substring; regex queries use the `pattern` subset.
+
+use chrono::{DateTime, Utc};
+
+use crate::ledger::{DstBadge, HourChapter, LedgerEntry};
+use crate::pattern::Pattern;
+
+/// Sidebar search settings
+#[derive(Debug, Clone, Default)]
+pub struct LedgerSearch {
+    /// Current query text
+    pub query: String,
+    /// Treat the query as a regular expression
+    pub regex: bool,
+    /// Hide rows that don't match instead of only highlighting hits
+    pub only_matches: bool,
+}
+
+/// A compiled query
+#[derive(Debug, Clone)]
+pub enum EntryMatcher {
+    /// Lowercased substring
+    Text(String),
+    Regex(Pattern),
+}
+
+impl EntryMatcher {
+    /// Compile `search`; `Ok(None)` for an empty query
+    pub fn new(search: &LedgerSearch) -> Result<Option<Self>, String> {
+        let query = search.query.trim();
+        if query.is_empty() {
+            Ok(None)
+        } else if search.regex {
+            Pattern::new(query).map(|pattern| Some(Self::Regex(pattern)))
+        } else {
+            Ok(Some(Self::Text(query.to_lowercase())))
+        }
+    }
+
+    pub fn is_match(&self, entry: &LedgerEntry) -> bool {
+        let text = search_text(entry);
+        match self {
+            Self::Text(needle) => text.to_lowercase().contains(needle),
+            Self::Regex(pattern) => pattern.is_match(&text),
+        }
+    }
+}
+
+/// The text a query is matched against: "12:34:56 PM UTC-07:00 DST 3fa2…"
+pub fn search_text(entry: &LedgerEntry) -> String {
+    let flag = match &entry.dst_badge {
+        DstBadge::None => "",
+        DstBadge::Active => "DST",
+        DstBadge::GapMarker { .. } => "DST GAP",
+        DstBadge::OverlapPass1 => "P1",
+        DstBadge::OverlapPass2 => "P2",
+        DstBadge::LeapSecond => "LEAP",
+        DstBadge::Unrecorded { .. } => "GAP UNRECORDED",
+    };
+    let event = entry
+        .event()
+        .map(|event| format!("{} {} {}", event.source, event.kind, event.message))
+        .unwrap_or_default();
+    [
+        entry.local_timestamp.as_str(),
+        entry.offset_str.as_str(),
+        flag,
+        event.as_str(),
+        entry.hash.as_str(),
+    ]
+    .iter()
+    .filter(|part| !part.is_empty())
+    .copied()
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+/// Whether `instant` is among `hits` (newest first, as from `matching_instants`)
+pub fn is_hit(hits: &[DateTime<Utc>], instant: DateTime<Utc>) -> bool {
+    hits.binary_search_by(|hit| instant.cmp(hit)).is_ok()
+}
+
+/// Apply search `hits` to grouped chapters
+///
+/// Blocks and chapters holding a hit are expanded so the hit is visible.
+/// With `only_matches`, rows that miss are dropped along with any block or
+/// chapter left empty; merkle roots still describe the full blocks.
+pub fn filter_chapters(
+    chapters: Vec<HourChapter>,
+    hits: &[DateTime<Utc>],
+    only_matches: bool,
+) -> Vec<HourChapter> {
+    chapters
+        .into_iter()
+        .filter_map(|mut chapter| {
+            for block in chapter.blocks.iter_mut() {
+                if only_matches {
+                    block.entries.retain(|entry| is_hit(hits, entry.instant_utc));
+                }
+                if block.entries.iter().any(|entry| is_hit(hits, entry.instant_utc)) {
+                    block.collapsed = false;
+                    chapter.collapsed = false;
+                }
+            }
+            if only_matches {
+                chapter.blocks.retain(|block| !block.entries.is_empty());
+            }
+            (!chapter.blocks.is_empty()).then_some(chapter)
+        })
+        .collect()
+}
+
+/// Instants of every matching entry, newest first
+pub fn matching_instants<'a>(
+    entries: impl IntoIterator<Item = &'a LedgerEntry>,
+    matcher: &EntryMatcher,
+) -> Vec<DateTime<Utc>> {
+    entries
+        .into_iter()
+        .filter(|entry| matcher.is_match(entry))
+        .map(|entry| entry.instant_utc)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::LedgerState;
+    use chrono::TimeZone;
+    use chrono_tz::Tz;
+    use shared::compute_time_data_at;
+
+    fn filled_ledger(seconds: i64) -> LedgerState {
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let start = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+        let mut ledger = LedgerState::new();
+        for s in 0..seconds {
+            let data = compute_time_data_at(tz, start + chrono::Duration::seconds(s));
+            ledger.update(&data, tz);
+        }
+        ledger
+    }
+
+    fn matcher(query: &str, regex: bool) -> EntryMatcher {
+        let search = LedgerSearch {
+            query: query.to_string(),
+            regex,
+            only_matches: false,
+        };
+        EntryMatcher::new(&search).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_substring_and_regex_queries() {
+        let ledger = filled_ledger(90);
+        let entry = ledger.entries.back().unwrap();
+        assert_eq!(
+            search_text(entry),
+            format!("08:00:00 AM UTC-04:00 DST {}", entry.hash)
+        );
+
+        let hits = matching_instants(&ledger.entries, &matcher("08:00:3", false));
+        assert_eq!(hits.len(), 10);
+        assert!(hits.windows(2).all(|pair| pair[0] > pair[1]));
+
+        assert_eq!(matching_instants(&ledger.entries, &matcher("dst", false)).len(), 90);
+        assert_eq!(matching_instants(&ledger.entries, &matcher(r":0[05] AM", true)).len(), 4);
+        assert!(EntryMatcher::new(&LedgerSearch::default()).unwrap().is_none());
+        assert!(EntryMatcher::new(&LedgerSearch {
+            query: "(".to_string(),
+            regex: true,
+            only_matches: false,
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_filter_expands_and_hides() {
+        let mut ledger = filled_ledger(90);
+        ledger.collapse_all();
+        ledger.collapse_all_chapters();
+        let hits = matching_instants(&ledger.entries, &matcher(r"08:01:1\d", true));
+        assert!(is_hit(&hits, ledger.entries[15].instant_utc));
+        assert!(!is_hit(&hits, ledger.entries[75].instant_utc));
+
+        let highlighted = filter_chapters(ledger.get_chapter_grouped_entries(), &hits, false);
+        assert!(!highlighted[0].collapsed);
+        assert_eq!(highlighted[0].blocks.len(), 2);
+        // Newest block (minute 01) holds the hits; minute 00 stays collapsed
+        assert!(!highlighted[0].blocks[0].collapsed);
+        assert!(highlighted[0].blocks[1].collapsed);
+
+        let filtered = filter_chapters(ledger.get_chapter_grouped_entries(), &hits, true);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].blocks.len(), 1);
+        assert_eq!(filtered[0].blocks[0].entries.len(), 10);
+    }
+}
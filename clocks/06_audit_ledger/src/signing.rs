@@ -0,0 +1,452 @@
+//! Signing module - ed25519 signatures over minute-block Merkle roots
+//!
+//! With signing on, each minute block is signed once the ledger has moved
+//! past it: the signature covers the block's UTC start minute and its Merkle
+//! root. The keypair is generated on first use and its seed kept in a key
+//! file in the config directory, readable only by the user. The public key
+//! is shown in the sidebar and written alongside every export, so anyone
+//! holding an export can recompute the block roots from the entry hashes and
+//! check them against the signatures.
+//!
+//! The ed25519 implementation is behind the `signing` feature; without it the
+//! ledger stays unsigned and the sidebar says why.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use shared::tr_args;
+
+use crate::hex;
+use crate::ledger::BlockGroup;
+
+/// Key file holding the secret key
+const KEY_FILE: &str = "audit_ledger";
+
+/// Config earlier versions kept the secret key in
+const LEGACY_KEY_CONFIG: &str = "audit_ledger_key";
+
+/// Start of the UTC minute containing `instant`
+pub fn minute_start(instant: DateTime<Utc>) -> DateTime<Utc> {
+    Utc.timestamp_opt(instant.timestamp().div_euclid(60) * 60, 0)
+        .single()
+        .unwrap_or(instant)
+}
+
+/// Start of the minute a block covers
+pub fn block_start(group: &BlockGroup) -> Option<DateTime<Utc>> {
+    group.entries.first().map(|entry| minute_start(entry.instant_utc))
+}
+
+/// The text a block signature covers
+pub fn signed_message(block_start: DateTime<Utc>, merkle_root: &str) -> String {
+    format!(
+        "audit-ledger-block|{}|{}",
+        block_start.format("%Y-%m-%dT%H:%MZ"),
+        merkle_root
+    )
+}
+
+/// One signed minute block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockSignature {
+    pub block_start: DateTime<Utc>,
+    /// Merkle root at signing time
+    pub merkle_root: String,
+    /// Hex-encoded 64-byte ed25519 signature over `signed_message`
+    pub signature: String,
+}
+
+/// Why a signature check failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureBreak {
+    /// The signature does not match the signed root and public key
+    BadSignature,
+    /// The block's entries no longer hash to the signed root
+    RootChanged,
+}
+
+/// Result of re-checking the block signatures
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureVerification {
+    Valid { blocks_checked: usize },
+    Invalid {
+        block_start: DateTime<Utc>,
+        reason: SignatureBreak,
+    },
+}
+
+impl SignatureVerification {
+    /// Short human-readable summary for the sidebar
+    pub fn summary(&self) -> String {
+        match self {
+            SignatureVerification::Valid { blocks_checked } => {
+                tr_args("✓ {0} block signatures valid", &[blocks_checked])
+            }
+            SignatureVerification::Invalid {
+                block_start,
+                reason,
+            } => {
+                let text = match reason {
+                    SignatureBreak::BadSignature => "✗ Block {0} bad signature",
+                    SignatureBreak::RootChanged => "✗ Block {0} root changed",
+                };
+                tr_args(text, &[&block_start.format("%H:%MZ")])
+            }
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        matches!(self, SignatureVerification::Valid { .. })
+    }
+}
+
+/// Signatures for the blocks still in the ledger, oldest first
+#[derive(Debug, Default)]
+pub struct SignatureLog {
+    signatures: BTreeMap<DateTime<Utc>, BlockSignature>,
+}
+
+impl SignatureLog {
+    pub fn len(&self) -> usize {
+        self.signatures.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.signatures.is_empty()
+    }
+
+    pub fn is_signed(&self, block_start: DateTime<Utc>) -> bool {
+        self.signatures.contains_key(&block_start)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &BlockSignature> {
+        self.signatures.values()
+    }
+
+    /// Blocks ready to sign: every block but the newest, which is still
+    /// filling, that has no signature yet
+    ///
+    /// `groups` are newest first, as `LedgerState::get_grouped_entries`
+    /// returns them.
+    pub fn unsigned_complete<'a>(
+        &self,
+        groups: &'a [BlockGroup],
+    ) -> Vec<(DateTime<Utc>, &'a str)> {
+        groups
+            .iter()
+            .skip(1)
+            .filter_map(|group| Some((block_start(group)?, group.merkle_root.as_str())))
+            .filter(|(start, _)| !self.is_signed(*start))
+            .collect()
+    }
+
+    pub fn insert(&mut self, signature: BlockSignature) {
+        self.signatures.insert(signature.block_start, signature);
+    }
+
+    /// Forget signatures for blocks older than `oldest`, which the rolling
+    /// window has dropped
+    pub fn retain_from(&mut self, oldest: DateTime<Utc>) {
+        self.signatures = self.signatures.split_off(&oldest);
+    }
+
+    /// Re-check every signature with `check(message, signature_hex)`, and
+    /// that each signed block still hashes to the root that was signed
+    ///
+    /// The oldest block is only checked against its signature, since the
+    /// rolling window may already have pruned some of its entries.
+    pub fn verify(
+        &self,
+        groups: &[BlockGroup],
+        check: impl Fn(&str, &str) -> bool,
+    ) -> SignatureVerification {
+        let oldest = groups.last().and_then(block_start);
+        for signature in self.signatures.values() {
+            let message = signed_message(signature.block_start, &signature.merkle_root);
+            if !check(&message, &signature.signature) {
+                return SignatureVerification::Invalid {
+                    block_start: signature.block_start,
+                    reason: SignatureBreak::BadSignature,
+                };
+            }
+            let current = groups
+                .iter()
+                .find(|group| block_start(group) == Some(signature.block_start));
+            if let Some(group) = current {
+                if Some(signature.block_start) != oldest
+                    && group.merkle_root != signature.merkle_root
+                {
+                    return SignatureVerification::Invalid {
+                        block_start: signature.block_start,
+                        reason: SignatureBreak::RootChanged,
+                    };
+                }
+            }
+        }
+        SignatureVerification::Valid {
+            blocks_checked: self.signatures.len(),
+        }
+    }
+}
+
+/// The secret key as earlier versions stored it, in `LEGACY_KEY_CONFIG`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct StoredKey {
+    /// Hex-encoded 32-byte ed25519 seed
+    secret_key: String,
+}
+
+impl shared::VersionedConfig for StoredKey {
+    const VERSION: u32 = 1;
+}
+
+/// Load the signing key, generating and saving one on first use
+pub fn load_signer() -> Result<BlockSigner, String> {
+    let secret = match shared::load_secret(KEY_FILE) {
+        Ok(Some(secret)) => Some(secret),
+        Ok(None) => migrate_legacy_key()?,
+        Err(e) => return Err(format!("Failed to load signing key: {}", e)),
+    };
+    match secret {
+        Some(secret) => {
+            let seed =
+                hex::decode(&secret).ok_or_else(|| "Signing key file is damaged".to_string())?;
+            BlockSigner::from_seed(&seed)
+        }
+        None => {
+            let signer = BlockSigner::generate()?;
+            shared::save_secret(KEY_FILE, &hex::encode(&signer.seed()))
+                .map_err(|e| format!("Failed to save signing key: {}", e))?;
+            Ok(signer)
+        }
+    }
+}
+
+/// Move a key kept in `LEGACY_KEY_CONFIG` into the key file
+///
+/// The config, its backup generation and any corrupt copies all held the
+/// key in the clear, so every one of them is deleted once it has moved.
+fn migrate_legacy_key() -> Result<Option<String>, String> {
+    let legacy = shared::config_path(LEGACY_KEY_CONFIG)
+        .and_then(|path| std::fs::read_to_string(path).ok());
+    let secret = match legacy {
+        Some(contents) => {
+            let stored: StoredKey = shared::decode_config(&contents)
+                .map_err(|e| format!("Failed to load signing key: {}", e))?;
+            shared::save_secret(KEY_FILE, &stored.secret_key)
+                .map_err(|e| format!("Failed to save signing key: {}", e))?;
+            Some(stored.secret_key)
+        }
+        None => None,
+    };
+    shared::purge_config(LEGACY_KEY_CONFIG)
+        .map_err(|e| format!("Failed to remove old signing key copies: {}", e))?;
+    Ok(secret)
+}
+
+impl BlockSigner {
+    /// Sign a block's start minute and root
+    pub fn sign_block(&self, block_start: DateTime<Utc>, merkle_root: &str) -> BlockSignature {
+        BlockSignature {
+            block_start,
+            merkle_root: merkle_root.to_string(),
+            signature: hex::encode(&self.sign(signed_message(block_start, merkle_root).as_bytes())),
+        }
+    }
+
+    /// Hex-encoded public key
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(&self.public_key())
+    }
+
+    /// Check a hex signature over `message` against this signer's public key
+    pub fn verify_hex(&self, message: &str, signature_hex: &str) -> bool {
+        hex::decode(signature_hex)
+            .is_some_and(|signature| verify(&self.public_key(), message.as_bytes(), &signature))
+    }
+}
+
+#[cfg(feature = "signing")]
+mod keys {
+    use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+    use rand_core::{OsRng, RngCore};
+
+    /// Shown next to the signing checkbox
+    pub const UNAVAILABLE_REASON: Option<&str> = None;
+
+    /// Local ed25519 keypair
+    pub struct BlockSigner {
+        key: SigningKey,
+    }
+
+    impl BlockSigner {
+        pub fn generate() -> Result<Self, String> {
+            let mut seed = [0u8; 32];
+            OsRng
+                .try_fill_bytes(&mut seed)
+                .map_err(|e| format!("No system randomness for a signing key: {}", e))?;
+            Ok(Self {
+                key: SigningKey::from_bytes(&seed),
+            })
+        }
+
+        pub fn from_seed(seed: &[u8]) -> Result<Self, String> {
+            let seed: [u8; 32] = seed
+                .try_into()
+                .map_err(|_| "Signing key file is damaged".to_string())?;
+            Ok(Self {
+                key: SigningKey::from_bytes(&seed),
+            })
+        }
+
+        pub fn seed(&self) -> [u8; 32] {
+            self.key.to_bytes()
+        }
+
+        pub fn public_key(&self) -> [u8; 32] {
+            self.key.verifying_key().to_bytes()
+        }
+
+        pub fn sign(&self, message: &[u8]) -> [u8; 64] {
+            self.key.sign(message).to_bytes()
+        }
+    }
+
+    /// Check an ed25519 signature
+    pub fn verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        let Ok(public_key) = <[u8; 32]>::try_from(public_key) else {
+            return false;
+        };
+        let (Ok(key), Ok(signature)) = (
+            VerifyingKey::from_bytes(&public_key),
+            Signature::from_slice(signature),
+        ) else {
+            return false;
+        };
+        key.verify(message, &signature).is_ok()
+    }
+}
+
+#[cfg(not(feature = "signing"))]
+mod keys {
+    /// Shown next to the signing checkbox
+    pub const UNAVAILABLE_REASON: Option<&str> =
+        Some("Built without signing (enable the `signing` feature)");
+
+    /// Stand-in when the `signing` feature is disabled; never constructed
+    pub enum BlockSigner {}
+
+    impl BlockSigner {
+        pub fn generate() -> Result<Self, String> {
+            Err(UNAVAILABLE_REASON.unwrap_or_default().to_string())
+        }
+
+        pub fn from_seed(_seed: &[u8]) -> Result<Self, String> {
+            Self::generate()
+        }
+
+        pub fn seed(&self) -> [u8; 32] {
+            match *self {}
+        }
+
+        pub fn public_key(&self) -> [u8; 32] {
+            match *self {}
+        }
+
+        pub fn sign(&self, _message: &[u8]) -> [u8; 64] {
+            match *self {}
+        }
+    }
+
+    pub fn verify(_public_key: &[u8], _message: &[u8], _signature: &[u8]) -> bool {
+        false
+    }
+}
+
+pub use keys::{verify, BlockSigner, UNAVAILABLE_REASON};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::{LedgerState, GENESIS_HASH};
+    use chrono_tz::Tz;
+
+    /// One block per minute, newest first
+    fn groups(minutes: i64) -> Vec<BlockGroup> {
+        let tz: Tz = "UTC".parse().unwrap();
+        let start = Utc.with_ymd_and_hms(2025, 7, 1, 12, 0, 0).unwrap();
+        let mut ledger = LedgerState::new();
+        for s in 0..minutes * 60 {
+            let data = shared::compute_time_data_at(tz, start + chrono::Duration::seconds(s));
+            ledger.update(&data, tz);
+        }
+        ledger.get_grouped_entries()
+    }
+
+    /// Stand-in "signature": the message reversed
+    fn fake_sign(log: &mut SignatureLog, groups: &[BlockGroup]) {
+        for (start, root) in log.unsigned_complete(groups) {
+            log.insert(BlockSignature {
+                block_start: start,
+                merkle_root: root.to_string(),
+                signature: signed_message(start, root).chars().rev().collect(),
+            });
+        }
+    }
+
+    fn fake_check(message: &str, signature: &str) -> bool {
+        message.chars().rev().collect::<String>() == signature
+    }
+
+    #[test]
+    fn test_newest_block_waits_until_complete() {
+        let groups = groups(3);
+        let mut log = SignatureLog::default();
+        fake_sign(&mut log, &groups);
+        assert_eq!(log.len(), 2);
+        assert!(!log.is_signed(block_start(&groups[0]).unwrap()));
+        assert!(log.unsigned_complete(&groups).is_empty());
+
+        log.retain_from(block_start(&groups[1]).unwrap());
+        assert_eq!(log.len(), 1);
+    }
+
+    #[test]
+    fn test_verify_catches_tampering() {
+        let mut groups = groups(4);
+        let mut log = SignatureLog::default();
+        fake_sign(&mut log, &groups);
+        assert_eq!(
+            log.verify(&groups, fake_check),
+            SignatureVerification::Valid { blocks_checked: 3 }
+        );
+
+        // Rewriting an entry in a signed block changes its root
+        groups[1].entries[0].hash = GENESIS_HASH.to_string();
+        groups[1].compute_merkle_root();
+        let result = log.verify(&groups, fake_check);
+        assert!(matches!(
+            result,
+            SignatureVerification::Invalid {
+                reason: SignatureBreak::RootChanged,
+                ..
+            }
+        ));
+
+        // A signature that doesn't match is caught before the root
+        assert!(!log.verify(&groups, |_, _| false).is_valid());
+    }
+
+    #[cfg(feature = "signing")]
+    #[test]
+    fn test_ed25519_round_trip() {
+        let signer = BlockSigner::from_seed(&[7u8; 32]).unwrap();
+        let start = Utc.with_ymd_and_hms(2025, 7, 1, 12, 0, 0).unwrap();
+        let signature = signer.sign_block(start, GENESIS_HASH);
+        let message = signed_message(start, GENESIS_HASH);
+        assert!(signer.verify_hex(&message, &signature.signature));
+        assert!(!signer.verify_hex(&signed_message(start, "00"), &signature.signature));
+    }
+}
@@ -0,0 +1,187 @@
+//! Stats module - the session dashboard in the sidebar
+//!
+//! The ledger keeps running totals as it seals entries: how many, how many
+//! minute blocks and hour chapters they filled, and how long hashing took.
+//! The totals count everything since launch, so they keep growing after the
+//! rolling window starts pruning. The STATS section shows them with the
+//! session uptime, the gaps found and the speed of the last chain check, and
+//! an export writes the same figures beside the ledger file.
+
+use std::time::{Duration, Instant};
+
+use shared::tr_args;
+
+use crate::ledger::{GapSummary, LedgerEntry, LedgerState};
+
+/// Running totals over every entry sealed since launch
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SealTotals {
+    /// Entries sealed, of every kind
+    pub entries: u64,
+    /// Minute blocks the second entries filled
+    pub blocks: u64,
+    /// Hour chapters the second entries filled
+    pub chapters: u64,
+    /// Time spent computing entry hashes
+    hash_time: Duration,
+    /// Local minute and hour of the last second entry, counted from the epoch
+    last_block: Option<i64>,
+    last_chapter: Option<i64>,
+}
+
+impl SealTotals {
+    /// Count a sealed entry whose hash took `hash_time`
+    ///
+    /// Blocks and chapters are counted as the local minute and hour change,
+    /// so a block cut short by a zone switch or a gap still counts once.
+    /// Markers and events don't open blocks of their own.
+    pub fn record(&mut self, entry: &LedgerEntry, hash_time: Duration) {
+        self.entries += 1;
+        self.hash_time += hash_time;
+        if entry.is_marker() || entry.event().is_some() {
+            return;
+        }
+
+        let local_secs = entry.instant_utc.timestamp() + entry.utc_offset_minutes as i64 * 60;
+        let block = local_secs.div_euclid(60);
+        let chapter = local_secs.div_euclid(3600);
+        if self.last_block != Some(block) {
+            self.blocks += 1;
+            self.last_block = Some(block);
+        }
+        if self.last_chapter != Some(chapter) {
+            self.chapters += 1;
+            self.last_chapter = Some(chapter);
+        }
+    }
+
+    /// Mean time to hash one entry, once there is one
+    pub fn average_hash_time(&self) -> Option<Duration> {
+        (self.entries > 0)
+            .then(|| Duration::from_secs_f64(self.hash_time.as_secs_f64() / self.entries as f64))
+    }
+}
+
+/// How fast the last chain verification ran
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerificationRate {
+    pub entries: usize,
+    pub elapsed: Duration,
+}
+
+impl VerificationRate {
+    /// Entries checked per second
+    pub fn per_second(&self) -> f64 {
+        self.entries as f64 / self.elapsed.as_secs_f64().max(1e-9)
+    }
+}
+
+/// The figures the STATS section shows and a stats export writes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SessionStats {
+    pub uptime: Duration,
+    pub totals: SealTotals,
+    /// Entries still in the rolling window
+    pub retained: usize,
+    pub gaps: GapSummary,
+    pub verification: Option<VerificationRate>,
+}
+
+impl SessionStats {
+    /// Gather the stats for a session started at `started`
+    pub fn collect(
+        ledger: &LedgerState,
+        started: Instant,
+        now: Instant,
+        verification: Option<VerificationRate>,
+    ) -> Self {
+        Self {
+            uptime: now.saturating_duration_since(started),
+            totals: ledger.seal_totals(),
+            retained: ledger.entries.len(),
+            gaps: ledger.gap_summary(),
+            verification,
+        }
+    }
+
+    /// Mean entries per minute block
+    pub fn entries_per_block(&self) -> Option<f64> {
+        (self.totals.blocks > 0).then(|| self.totals.entries as f64 / self.totals.blocks as f64)
+    }
+
+    /// Label and value of each line in the STATS section
+    pub fn rows(&self) -> Vec<(&'static str, String)> {
+        let or_dash = |value: Option<String>| value.unwrap_or_else(|| "—".to_string());
+        vec![
+            ("Uptime", format_uptime(self.uptime)),
+            ("Entries", tr_args("{0} ({1} retained)", &[&self.totals.entries, &self.retained])),
+            ("Blocks", self.totals.blocks.to_string()),
+            ("Chapters", self.totals.chapters.to_string()),
+            ("Per block", or_dash(self.entries_per_block().map(|n| format!("{:.1}", n)))),
+            ("Gaps", tr_args("{0} ({1}s unrecorded)", &[&self.gaps.gaps, &self.gaps.seconds])),
+            (
+                "Avg hash",
+                or_dash(self.totals.average_hash_time().map(|t| format!("{:.1} µs", micros(t)))),
+            ),
+            (
+                "Verify rate",
+                or_dash(self.verification.map(|rate| format!("{:.0}/s", rate.per_second()))),
+            ),
+        ]
+    }
+}
+
+/// A duration in microseconds
+pub fn micros(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1e6
+}
+
+/// "42s", "12m 05s", "3h 07m 12s", "2d 04h 10m"
+pub fn format_uptime(uptime: Duration) -> String {
+    let secs = uptime.as_secs();
+    match (secs / 86_400, secs % 86_400 / 3600, secs % 3600 / 60, secs % 60) {
+        (0, 0, 0, s) => format!("{}s", s),
+        (0, 0, m, s) => format!("{}m {:02}s", m, s),
+        (0, h, m, s) => format!("{}h {:02}m {:02}s", h, m, s),
+        (d, h, m, _) => format!("{}d {:02}h {:02}m", d, h, m),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Tz;
+
+    #[test]
+    fn test_totals_count_blocks_and_chapters_by_local_time() {
+        // Kolkata is UTC+05:30, so its hours turn at :30 UTC
+        let tz: Tz = "Asia/Kolkata".parse().unwrap();
+        let mut totals = SealTotals::default();
+        let second = |h, m, s| {
+            let instant = Utc.with_ymd_and_hms(2025, 1, 15, h, m, s).unwrap();
+            LedgerEntry::from_instant(instant, tz, false, false, false)
+        };
+        for entry in [second(3, 29, 58), second(3, 29, 59), second(3, 30, 0), second(3, 31, 0)] {
+            totals.record(&entry, Duration::from_micros(4));
+        }
+        assert_eq!(totals.entries, 4);
+        assert_eq!(totals.blocks, 3);
+        assert_eq!(totals.chapters, 2);
+        assert_eq!(totals.average_hash_time().map(|t| micros(t).round()), Some(4.0));
+
+        // An unrecorded stretch is an entry but opens no block
+        let first_missing = Utc.with_ymd_and_hms(2025, 1, 15, 4, 0, 0).unwrap();
+        let gap = LedgerEntry::unrecorded(first_missing, 90, tz);
+        totals.record(&gap, Duration::ZERO);
+        assert_eq!((totals.entries, totals.blocks), (5, 3));
+    }
+
+    #[test]
+    fn test_uptime_format() {
+        assert_eq!(format_uptime(Duration::from_secs(42)), "42s");
+        assert_eq!(format_uptime(Duration::from_secs(725)), "12m 05s");
+        assert_eq!(format_uptime(Duration::from_secs(3 * 3600 + 7 * 60 + 12)), "3h 07m 12s");
+        assert_eq!(format_uptime(Duration::from_secs(2 * 86_400 + 4 * 3600 + 600)), "2d 04h 10m");
+    }
+}
@@ -1,13 +1,30 @@
 //! UI module for the Audit Ledger Clock
 //!
 //! Provides the sidebar with timezone picker, DST insights panel,
-//! time range filter, and density controls using egui.
+//! time range filter, column choice, session stats, chain integrity and block
+//! signing, entry search, event stream following, export and log rotation,
+//! and density controls using egui, plus the detail popup for a clicked entry.
 
+use chrono::SecondsFormat;
 use chrono_tz::Tz;
 use nannou_egui::egui;
-use shared::{search_timezones, system_timezone, DstChange, TimeData};
+use shared::{
+    active_palette, clip_recording_settings, favorites_settings, keymap_settings, language_settings,
+    order_by_region, quiet_hours_settings, reduced_motion_settings, region_change, region_heading,
+    reveal_hotkey_settings, safe_palette_settings, search_timezones, system_timezone,
+    system_zone_settings, tr, tr_args, window_title_settings, zone_display_name, ClipSettings,
+    DstChange, FollowSystemZone, Keymap, KeymapEdit, LanguageSetting, MotionPreference, QuietHours,
+    RevealHotkey, Rgb, SafePaletteSetting, ThemeLibrary, TimeData, WindowTitleClock,
+};
 
-use crate::ledger::{LedgerState, TimeRangeFilter};
+use crate::columns::{Column, LedgerColumns};
+use crate::export::{ExportFormat, ExportScope};
+use crate::ingest::FollowStats;
+use crate::ledger::{ChainVerification, LedgerEntry, LedgerState, TimeRangeFilter};
+use crate::rotation::{RotationPeriod, RotationSettings, MAX_KEEP};
+use crate::search::LedgerSearch;
+use crate::signing::{SignatureVerification, UNAVAILABLE_REASON};
+use crate::stats::SessionStats;
 use crate::TextDensity;
 
 /// State for the timezone picker
@@ -27,7 +44,7 @@ impl PickerState {
     pub fn open(&mut self) {
         self.is_open = true;
         self.search_query.clear();
-        self.search_results = search_timezones("");
+        self.search_results = order_by_region(search_timezones(""));
         self.should_focus_search = true;
     }
 
@@ -38,7 +55,7 @@ impl PickerState {
     }
 
     pub fn update_search(&mut self) {
-        self.search_results = search_timezones(&self.search_query);
+        self.search_results = order_by_region(search_timezones(&self.search_query));
     }
 }
 
@@ -51,13 +68,85 @@ pub struct SidebarResult {
     pub toggle_favorite: Option<Tz>,
     /// Set time range filter
     pub set_time_range: Option<TimeRangeFilter>,
+    /// Replace the columns shown in ledger rows
+    pub set_columns: Option<LedgerColumns>,
+    /// Zone shown in the comparison column
+    pub set_comparison_zone: Option<Tz>,
     /// Set text density
     pub set_density: Option<TextDensity>,
-    /// Set reduced motion
-    pub set_reduced_motion: Option<bool>,
+    /// Set the reduced motion preference
+    pub set_motion_preference: Option<MotionPreference>,
+    /// Replace the shared quiet-hours schedule
+    pub set_quiet_hours: Option<QuietHours>,
+    /// Replace the shared window title clock setting
+    pub set_window_title: Option<WindowTitleClock>,
+    /// Replace the shared clip recording settings
+    pub set_clip_settings: Option<ClipSettings>,
+    /// Replace the shared language
+    pub set_language: Option<LanguageSetting>,
+    /// Replace the shared safe palette
+    pub set_safe_palette: Option<SafePaletteSetting>,
+    /// Replace the shared follow-system-zone setting
+    pub set_follow_system_zone: Option<FollowSystemZone>,
+    /// Replace the shared reveal shortcut
+    pub set_reveal_hotkey: Option<RevealHotkey>,
+    /// Keep this clock's own favorites (`true`) or follow the shared list
+    pub set_own_favorites: Option<bool>,
+    /// Shortcut rebound, reset or waiting for its new chord
+    pub keymap_edit: Option<KeymapEdit>,
+    /// Walk the hash chain and report breaks
+    pub verify_chain: bool,
+    /// Turn block signing on or off
+    pub set_signing: Option<bool>,
+    /// Re-check the block signatures
+    pub verify_signatures: bool,
+    /// Export format, scope or directory was edited
+    pub export_options_changed: bool,
+    /// Export button was clicked
+    pub export: bool,
+    /// Auto-rotation turned on or off, or its options edited
+    pub rotation_changed: bool,
+    /// Search query or options were edited
+    pub search_changed: bool,
+    /// Jump to the next (`1`) or previous (`-1`) search match
+    pub jump_to_match: Option<isize>,
+    /// Switch theme (`Some(None)` for the clock's own colors)
+    pub set_theme: Option<Option<String>>,
+    /// Follow/Stop button was clicked
+    pub toggle_follow: bool,
+    /// Follow source field was edited
+    pub follow_source_changed: bool,
+}
+
+/// State of follow mode, shown in the FOLLOW section
+pub struct FollowStatus<'a> {
+    /// Label of the source being read, if following
+    pub following: Option<String>,
+    pub stats: &'a FollowStats,
+}
+
+/// Block signing state, shown in the CHAIN INTEGRITY section
+pub struct SigningStatus<'a> {
+    pub enabled: bool,
+    /// Hex public key, once a keypair is loaded
+    pub public_key: Option<String>,
+    pub signed_blocks: usize,
+    /// Result of the last signature check
+    pub verification: Option<&'a SignatureVerification>,
+}
+
+/// Outcome of the current search, shown under the search box
+pub struct SearchStatus<'a> {
+    /// Number of matching entries
+    pub matches: usize,
+    /// Index of the match last jumped to
+    pub current: Option<usize>,
+    /// Why the regex failed to compile
+    pub error: Option<&'a str>,
 }
 
 /// Draw the sidebar panel
+#[allow(clippy::too_many_arguments)]
 pub fn draw_sidebar(
     ctx: &egui::Context,
     picker_state: &mut PickerState,
@@ -65,13 +154,41 @@ pub fn draw_sidebar(
     favorites: &[Tz],
     time_data: &TimeData,
     ledger: &LedgerState,
+    columns: LedgerColumns,
+    comparison_zone: Tz,
     text_density: TextDensity,
-    reduced_motion: bool,
+    motion_preference: MotionPreference,
+    system_reduced_motion: bool,
+    chain_status: Option<&ChainVerification>,
+    stats: &SessionStats,
+    signing_status: SigningStatus,
+    export_format: &mut ExportFormat,
+    export_scope: &mut ExportScope,
+    export_dir: &mut String,
+    rotation: &mut RotationSettings,
+    rotation_status: Option<String>,
+    search: &mut LedgerSearch,
+    search_status: SearchStatus,
+    follow_source: &mut String,
+    follow_status: FollowStatus,
+    quiet_hours: &QuietHours,
+    window_title: WindowTitleClock,
+    clip_settings: ClipSettings,
+    language: LanguageSetting,
+    safe_palette: SafePaletteSetting,
+    follow_system_zone: FollowSystemZone,
+    reveal_hotkey: &RevealHotkey,
+    reveal_status: Option<&str>,
+    own_favorites: bool,
+    keymap: &Keymap,
+    themes: &ThemeLibrary,
+    theme: Option<&str>,
 ) -> SidebarResult {
     let mut result = SidebarResult::default();
 
     // Apply terminal-style theme
     let mut style = (*ctx.style()).clone();
+    style.visuals = egui::Visuals::dark();
     style.visuals.window_fill = egui::Color32::from_rgb(15, 20, 25);
     style.visuals.panel_fill = egui::Color32::from_rgb(15, 20, 25);
     style.visuals.widgets.noninteractive.bg_fill = egui::Color32::from_rgb(20, 28, 35);
@@ -79,6 +196,7 @@ pub fn draw_sidebar(
     style.visuals.widgets.hovered.bg_fill = egui::Color32::from_rgb(35, 50, 60);
     style.visuals.widgets.active.bg_fill = egui::Color32::from_rgb(45, 65, 80);
     style.visuals.override_text_color = Some(egui::Color32::from_rgb(51, 255, 102));
+    theme_visuals(&mut style.visuals);
     ctx.set_style(style);
 
     egui::SidePanel::right("sidebar")
@@ -88,12 +206,12 @@ pub fn draw_sidebar(
             ui.add_space(10.0);
 
             // Title
-            ui.heading(egui::RichText::new("╔══ CONTROLS ══╗").color(egui::Color32::from_rgb(51, 255, 102)));
+            ui.heading(egui::RichText::new(tr("╔══ CONTROLS ══╗")).color(egui::Color32::from_rgb(51, 255, 102)));
             ui.add_space(10.0);
 
             // Timezone section
             ui.group(|ui| {
-                ui.label(egui::RichText::new("▸ TIMEZONE").size(14.0).color(egui::Color32::from_rgb(51, 255, 102)));
+                ui.label(egui::RichText::new(tr("▸ TIMEZONE")).size(14.0).color(egui::Color32::from_rgb(51, 255, 102)));
                 ui.add_space(5.0);
 
                 // Current timezone display
@@ -113,12 +231,12 @@ pub fn draw_sidebar(
                 ui.add_space(5.0);
 
                 // Open picker button
-                if ui.button(egui::RichText::new("Change Zone (T)").size(12.0)).clicked() {
+                if ui.button(egui::RichText::new(tr("Change Zone (T)")).size(12.0)).clicked() {
                     picker_state.open();
                 }
 
                 // System timezone shortcut
-                if ui.button(egui::RichText::new("Use System TZ").size(12.0)).clicked() {
+                if ui.button(egui::RichText::new(tr("Use System TZ")).size(12.0)).clicked() {
                     if let Some(sys_tz) = system_timezone() {
                         result.set_timezone = Some(sys_tz);
                     }
@@ -129,15 +247,15 @@ pub fn draw_sidebar(
 
             // DST Insights section
             ui.group(|ui| {
-                ui.label(egui::RichText::new("▸ DST INSIGHTS").size(14.0).color(egui::Color32::from_rgb(51, 255, 102)));
+                ui.label(egui::RichText::new(tr("▸ DST INSIGHTS")).size(14.0).color(egui::Color32::from_rgb(51, 255, 102)));
                 ui.add_space(5.0);
 
                 // DST active indicator
                 let dst_text = if time_data.is_dst {
-                    egui::RichText::new("● Daylight Saving Time ACTIVE")
+                    egui::RichText::new(tr("● Daylight Saving Time ACTIVE"))
                         .color(egui::Color32::from_rgb(255, 200, 100))
                 } else {
-                    egui::RichText::new("○ Standard Time")
+                    egui::RichText::new(tr("○ Standard Time"))
                         .color(egui::Color32::from_rgb(100, 150, 100))
                 };
                 ui.label(dst_text);
@@ -155,27 +273,27 @@ pub fn draw_sidebar(
                 // DST warning
                 match &time_data.dst_change {
                     DstChange::Upcoming { instant, delta_minutes } => {
-                        let hours_until = (*instant - chrono::Utc::now()).num_hours();
-                        let direction = if *delta_minutes > 0 {
-                            "spring forward"
+                        let hours_until = (*instant - shared::now_utc()).num_hours();
+                        let text = if *delta_minutes > 0 {
+                            "⚠ DST change in {0}h (spring forward)"
                         } else {
-                            "fall back"
+                            "⚠ DST change in {0}h (fall back)"
                         };
                         ui.add_space(5.0);
                         ui.label(
-                            egui::RichText::new(format!("⚠ DST change in {}h ({})", hours_until, direction))
+                            egui::RichText::new(tr_args(text, &[&hours_until]))
                                 .color(egui::Color32::from_rgb(255, 150, 80)),
                         );
                     }
                     DstChange::JustOccurred { delta_minutes, .. } => {
-                        let direction = if *delta_minutes > 0 {
-                            "sprang forward"
+                        let text = if *delta_minutes > 0 {
+                            "ℹ Clocks sprang forward recently"
                         } else {
-                            "fell back"
+                            "ℹ Clocks fell back recently"
                         };
                         ui.add_space(5.0);
                         ui.label(
-                            egui::RichText::new(format!("ℹ Clocks {} recently", direction))
+                            egui::RichText::new(tr(text))
                                 .color(egui::Color32::from_rgb(100, 180, 255)),
                         );
                     }
@@ -187,17 +305,17 @@ pub fn draw_sidebar(
 
             // Time Range Filter section
             ui.group(|ui| {
-                ui.label(egui::RichText::new("▸ TIME RANGE").size(14.0).color(egui::Color32::from_rgb(51, 255, 102)));
+                ui.label(egui::RichText::new(tr("▸ TIME RANGE")).size(14.0).color(egui::Color32::from_rgb(51, 255, 102)));
                 ui.add_space(5.0);
 
                 ui.horizontal(|ui| {
                     for range in TimeRangeFilter::all() {
                         let is_selected = ledger.time_range == *range;
                         let text = if is_selected {
-                            egui::RichText::new(format!("[{}]", range.label()))
+                            egui::RichText::new(format!("[{}]", tr(range.label())))
                                 .color(egui::Color32::from_rgb(51, 255, 102))
                         } else {
-                            egui::RichText::new(range.label())
+                            egui::RichText::new(tr(range.label()))
                                 .color(egui::Color32::from_rgb(100, 150, 100))
                         };
 
@@ -209,7 +327,7 @@ pub fn draw_sidebar(
 
                 ui.add_space(3.0);
                 ui.label(
-                    egui::RichText::new(format!("{} entries in buffer", ledger.entries.len()))
+                    egui::RichText::new(tr_args("{0} entries in buffer", &[&ledger.entries.len()]))
                         .size(10.0)
                         .color(egui::Color32::from_rgb(80, 120, 80)),
                 );
@@ -217,23 +335,386 @@ pub fn draw_sidebar(
 
             ui.add_space(10.0);
 
+            // Columns section
+            ui.group(|ui| {
+                ui.label(egui::RichText::new(tr("▸ COLUMNS")).size(14.0).color(egui::Color32::from_rgb(51, 255, 102)));
+                ui.add_space(5.0);
+
+                let mut edited = columns;
+                ui.horizontal_wrapped(|ui| {
+                    for column in Column::ALL {
+                        let label = egui::RichText::new(tr(column.label())).size(12.0);
+                        if ui.checkbox(edited.shows_mut(column), label).changed() {
+                            result.set_columns = Some(edited);
+                        }
+                    }
+                });
+
+                // The second zone comes from the favorites
+                if edited.comparison {
+                    ui.add_space(5.0);
+                    if favorites.is_empty() {
+                        ui.label(
+                            egui::RichText::new(tr("Star a zone to compare against it"))
+                                .size(10.0)
+                                .color(egui::Color32::from_rgb(80, 120, 80)),
+                        );
+                    } else {
+                        egui::ComboBox::from_label(tr("Second zone"))
+                            .selected_text(format_zone_name(comparison_zone))
+                            .show_ui(ui, |ui| {
+                                for &tz in favorites {
+                                    let label = format_zone_name(tz);
+                                    if ui.selectable_label(tz == comparison_zone, label).clicked() {
+                                        result.set_comparison_zone = Some(tz);
+                                    }
+                                }
+                            });
+                    }
+                }
+            });
+
+            ui.add_space(10.0);
+
+            // Search section
+            ui.group(|ui| {
+                ui.label(egui::RichText::new(tr("▸ SEARCH")).size(14.0).color(egui::Color32::from_rgb(51, 255, 102)));
+                ui.add_space(5.0);
+
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut search.query)
+                        .hint_text(tr("time, offset, DST, hash…"))
+                        .font(egui::TextStyle::Monospace),
+                );
+                if response.changed() {
+                    result.search_changed = true;
+                }
+                // Enter in the box jumps to the next match
+                if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                    result.jump_to_match = Some(1);
+                }
+
+                ui.horizontal(|ui| {
+                    if ui.checkbox(&mut search.regex, egui::RichText::new(tr("Regex")).size(12.0)).changed() {
+                        result.search_changed = true;
+                    }
+                    if ui
+                        .checkbox(&mut search.only_matches, egui::RichText::new(tr("Only matches")).size(12.0))
+                        .changed()
+                    {
+                        result.search_changed = true;
+                    }
+                });
+
+                if let Some(error) = search_status.error {
+                    ui.label(
+                        egui::RichText::new(format!("✗ {}", error))
+                            .size(11.0)
+                            .color(egui::Color32::from_rgb(255, 90, 90)),
+                    );
+                } else if !search.query.trim().is_empty() {
+                    ui.horizontal(|ui| {
+                        let count = match search_status.current {
+                            Some(index) => tr_args("{0} / {1} matches", &[&(index + 1), &search_status.matches]),
+                            None => tr_args("{0} matches", &[&search_status.matches]),
+                        };
+                        ui.label(
+                            egui::RichText::new(count)
+                                .size(11.0)
+                                .color(egui::Color32::from_rgb(100, 150, 100)),
+                        );
+                        if ui.button(egui::RichText::new(tr("▲ Prev")).size(11.0)).clicked() {
+                            result.jump_to_match = Some(-1);
+                        }
+                        if ui.button(egui::RichText::new(tr("▼ Next (N)")).size(11.0)).clicked() {
+                            result.jump_to_match = Some(1);
+                        }
+                    });
+                }
+            });
+
+            ui.add_space(10.0);
+
+            // Session stats section
+            ui.group(|ui| {
+                ui.label(egui::RichText::new(tr("▸ STATS")).size(14.0).color(egui::Color32::from_rgb(51, 255, 102)));
+                ui.add_space(5.0);
+
+                egui::Grid::new("session_stats").num_columns(2).spacing([12.0, 2.0]).show(ui, |ui| {
+                    for (label, value) in stats.rows() {
+                        ui.label(
+                            egui::RichText::new(tr(label))
+                                .size(11.0)
+                                .color(egui::Color32::from_rgb(100, 150, 100)),
+                        );
+                        ui.label(egui::RichText::new(value).size(11.0).monospace());
+                        ui.end_row();
+                    }
+                });
+            });
+
+            ui.add_space(10.0);
+
+            // Chain integrity section
+            ui.group(|ui| {
+                ui.label(egui::RichText::new(tr("▸ CHAIN INTEGRITY")).size(14.0).color(egui::Color32::from_rgb(51, 255, 102)));
+                ui.add_space(5.0);
+
+                ui.label(
+                    egui::RichText::new(format!("HEAD {}", &ledger.head_hash()[..16]))
+                        .size(11.0)
+                        .color(egui::Color32::from_rgb(100, 150, 100))
+                        .monospace(),
+                );
+
+                if ui.button(egui::RichText::new(tr("Verify Chain (V)")).size(12.0)).clicked() {
+                    result.verify_chain = true;
+                }
+
+                if let Some(status) = chain_status {
+                    let color = if status.is_intact() {
+                        egui::Color32::from_rgb(51, 255, 102)
+                    } else {
+                        egui::Color32::from_rgb(255, 90, 90)
+                    };
+                    ui.label(egui::RichText::new(status.summary()).size(11.0).color(color));
+                }
+
+                // Seconds lost to sleep or suspend
+                let gaps = ledger.gap_summary();
+                let color = if gaps.gaps == 0 {
+                    egui::Color32::from_rgb(100, 150, 100)
+                } else {
+                    egui::Color32::from_rgb(200, 120, 255)
+                };
+                ui.label(egui::RichText::new(gaps.summary()).size(11.0).color(color));
+
+                ui.add_space(5.0);
+                let mut signing = signing_status.enabled;
+                ui.add_enabled_ui(UNAVAILABLE_REASON.is_none(), |ui| {
+                    if ui
+                        .checkbox(&mut signing, egui::RichText::new(tr("Sign minute blocks (ed25519)")).size(12.0))
+                        .changed()
+                    {
+                        result.set_signing = Some(signing);
+                    }
+                });
+                if let Some(reason) = UNAVAILABLE_REASON {
+                    ui.label(egui::RichText::new(tr(reason)).size(10.0).color(egui::Color32::from_rgb(100, 150, 100)));
+                }
+
+                if signing_status.enabled {
+                    if let Some(public_key) = &signing_status.public_key {
+                        ui.label(
+                            egui::RichText::new(format!("KEY {}", &public_key[..16]))
+                                .size(11.0)
+                                .color(egui::Color32::from_rgb(100, 150, 100))
+                                .monospace(),
+                        )
+                        .on_hover_text(public_key.as_str());
+                    }
+                    ui.label(
+                        egui::RichText::new(tr_args("{0} blocks signed", &[&signing_status.signed_blocks]))
+                            .size(11.0)
+                            .color(egui::Color32::from_rgb(100, 150, 100)),
+                    );
+
+                    if ui.button(egui::RichText::new(tr("Verify Signatures (Shift+V)")).size(12.0)).clicked() {
+                        result.verify_signatures = true;
+                    }
+
+                    if let Some(status) = signing_status.verification {
+                        let color = if status.is_valid() {
+                            egui::Color32::from_rgb(51, 255, 102)
+                        } else {
+                            egui::Color32::from_rgb(255, 90, 90)
+                        };
+                        ui.label(egui::RichText::new(status.summary()).size(11.0).color(color));
+                    }
+                }
+            });
+
+            ui.add_space(10.0);
+
+            // Follow section
+            ui.group(|ui| {
+                ui.label(egui::RichText::new(tr("▸ FOLLOW")).size(14.0).color(egui::Color32::from_rgb(51, 255, 102)));
+                ui.add_space(5.0);
+
+                let source_response = ui.add_enabled(
+                    follow_status.following.is_none(),
+                    egui::TextEdit::singleline(follow_source)
+                        .hint_text(tr("path, - for stdin, tcp:9000"))
+                        .font(egui::TextStyle::Monospace),
+                );
+                if source_response.lost_focus() {
+                    result.follow_source_changed = true;
+                }
+
+                let button = if follow_status.following.is_some() { "Stop (F)" } else { "Follow (F)" };
+                if ui.button(egui::RichText::new(tr(button)).size(12.0)).clicked() {
+                    result.toggle_follow = true;
+                }
+
+                let stats = follow_status.stats;
+                if let Some(label) = &follow_status.following {
+                    ui.label(
+                        egui::RichText::new(tr_args(
+                            "Following {0}: {1} events, {2} skipped",
+                            &[label, &stats.events, &stats.malformed],
+                        ))
+                        .size(11.0)
+                        .color(egui::Color32::from_rgb(100, 150, 100)),
+                    );
+                } else if let Some(error) = &stats.error {
+                    ui.label(
+                        egui::RichText::new(tr_args("Stopped: {0}", &[error]))
+                            .size(11.0)
+                            .color(egui::Color32::from_rgb(255, 90, 90)),
+                    );
+                }
+            });
+
+            ui.add_space(10.0);
+
+            // Export section
+            ui.group(|ui| {
+                ui.label(egui::RichText::new(tr("▸ EXPORT")).size(14.0).color(egui::Color32::from_rgb(51, 255, 102)));
+                ui.add_space(5.0);
+
+                ui.horizontal(|ui| {
+                    for format in ExportFormat::all() {
+                        if ui
+                            .radio_value(export_format, *format, egui::RichText::new(format.label()).size(12.0))
+                            .changed()
+                        {
+                            result.export_options_changed = true;
+                        }
+                    }
+                });
+                ui.horizontal(|ui| {
+                    for scope in ExportScope::all() {
+                        if ui
+                            .radio_value(export_scope, *scope, egui::RichText::new(tr(scope.label())).size(12.0))
+                            .changed()
+                        {
+                            result.export_options_changed = true;
+                        }
+                    }
+                });
+
+                ui.label(
+                    egui::RichText::new(tr("Output directory"))
+                        .size(10.0)
+                        .color(egui::Color32::from_rgb(80, 120, 80)),
+                );
+                let dir_response = ui.add(
+                    egui::TextEdit::singleline(export_dir)
+                        .hint_text("Documents/clock-series")
+                        .font(egui::TextStyle::Monospace),
+                );
+                if dir_response.lost_focus() {
+                    result.export_options_changed = true;
+                }
+
+                if ui.button(egui::RichText::new(tr("Export (E)")).size(12.0)).clicked() {
+                    result.export = true;
+                }
+
+                ui.add_space(5.0);
+                let mut rotation_changed = ui
+                    .checkbox(&mut rotation.enabled, egui::RichText::new(tr("Auto-rotate logs")).size(12.0))
+                    .on_hover_text(tr("Write each completed UTC hour or day to its own JSONL file"))
+                    .changed();
+                ui.add_enabled_ui(rotation.enabled, |ui| {
+                    ui.horizontal(|ui| {
+                        for period in RotationPeriod::all() {
+                            if ui
+                                .radio_value(&mut rotation.period, *period, egui::RichText::new(tr(period.label())).size(12.0))
+                                .changed()
+                            {
+                                rotation_changed = true;
+                            }
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new(tr("Keep")).size(12.0));
+                        rotation_changed |= ui
+                            .add(egui::DragValue::new(&mut rotation.keep).clamp_range(0..=MAX_KEEP))
+                            .on_hover_text(tr("0 keeps every log"))
+                            .changed();
+                        let unit = if rotation.keep == 0 { "logs (all)" } else { "logs" };
+                        ui.label(egui::RichText::new(tr(unit)).size(12.0));
+                    });
+                    rotation_changed |= ui
+                        .checkbox(&mut rotation.compress, egui::RichText::new(tr("Gzip closed logs")).size(12.0))
+                        .changed();
+                });
+                if let Some(status) = rotation_status {
+                    ui.label(
+                        egui::RichText::new(status)
+                            .size(11.0)
+                            .color(egui::Color32::from_rgb(100, 150, 100)),
+                    );
+                }
+                result.rotation_changed = rotation_changed;
+            });
+
+            ui.add_space(10.0);
+
             // Accessibility section
             ui.group(|ui| {
-                ui.label(egui::RichText::new("▸ ACCESSIBILITY").size(14.0).color(egui::Color32::from_rgb(51, 255, 102)));
+                ui.label(egui::RichText::new(tr("▸ ACCESSIBILITY")).size(14.0).color(egui::Color32::from_rgb(51, 255, 102)));
                 ui.add_space(5.0);
 
                 // Text density
                 ui.horizontal(|ui| {
-                    ui.label(egui::RichText::new("Density:").size(12.0));
-                    if ui.button(egui::RichText::new(text_density.label()).size(12.0)).clicked() {
+                    ui.label(egui::RichText::new(tr("Density:")).size(12.0));
+                    if ui.button(egui::RichText::new(tr(text_density.label())).size(12.0)).clicked() {
                         result.set_density = Some(text_density.cycle());
                     }
                 });
 
-                // Reduced motion toggle
-                let mut reduced = reduced_motion;
-                if ui.checkbox(&mut reduced, egui::RichText::new("Reduced motion").size(12.0)).changed() {
-                    result.set_reduced_motion = Some(reduced);
+                // Reduced motion setting
+                let mut preference = motion_preference;
+                if reduced_motion_settings(ui, &mut preference, system_reduced_motion) {
+                    result.set_motion_preference = Some(preference);
+                }
+
+                let mut edited = quiet_hours.clone();
+                if quiet_hours_settings(ui, &mut edited) {
+                    result.set_quiet_hours = Some(edited);
+                }
+                let mut edited = window_title;
+                if window_title_settings(ui, &mut edited) {
+                    result.set_window_title = Some(edited);
+                }
+                let mut edited = clip_settings;
+                if clip_recording_settings(ui, &mut edited) {
+                    result.set_clip_settings = Some(edited);
+                }
+                let mut edited = language;
+                if language_settings(ui, &mut edited) {
+                    result.set_language = Some(edited);
+                }
+                let mut edited = follow_system_zone;
+                if system_zone_settings(ui, &mut edited) {
+                    result.set_follow_system_zone = Some(edited);
+                }
+                let mut edited = reveal_hotkey.clone();
+                if reveal_hotkey_settings(ui, &mut edited, reveal_status) {
+                    result.set_reveal_hotkey = Some(edited);
+                }
+                let mut edited = own_favorites;
+                if favorites_settings(ui, &mut edited) {
+                    result.set_own_favorites = Some(edited);
+                }
+                result.keymap_edit = keymap_settings(ui, keymap);
+
+                result.set_theme = theme_selector(ui, themes, theme);
+                let mut edited = safe_palette;
+                if safe_palette_settings(ui, &mut edited) {
+                    result.set_safe_palette = Some(edited);
                 }
             });
 
@@ -241,7 +722,7 @@ pub fn draw_sidebar(
 
             // Keyboard shortcuts help
             ui.group(|ui| {
-                ui.label(egui::RichText::new("▸ SHORTCUTS").size(14.0).color(egui::Color32::from_rgb(51, 255, 102)));
+                ui.label(egui::RichText::new(tr("▸ SHORTCUTS")).size(14.0).color(egui::Color32::from_rgb(51, 255, 102)));
                 ui.add_space(5.0);
 
                 let shortcuts = [
@@ -249,7 +730,13 @@ pub fn draw_sidebar(
                     ("L", "Return to live"),
                     ("J/K", "Scroll down/up"),
                     ("[/]", "Collapse/expand"),
+                    ("V", "Verify chain"),
+                    ("E", "Export ledger"),
+                    ("N/⇧N", "Next/prev match"),
+                    ("F", "Follow event stream"),
+                    ("Click", "Entry details"),
                     ("Esc", "Close/return"),
+                    ("Ctrl+K", "All commands"),
                 ];
 
                 for (key, desc) in shortcuts {
@@ -261,7 +748,7 @@ pub fn draw_sidebar(
                                 .monospace(),
                         );
                         ui.label(
-                            egui::RichText::new(desc)
+                            egui::RichText::new(tr(desc))
                                 .size(10.0)
                                 .color(egui::Color32::from_rgb(100, 150, 100)),
                         );
@@ -274,10 +761,10 @@ pub fn draw_sidebar(
                 ui.add_space(10.0);
 
                 let status_text = if ledger.is_live {
-                    egui::RichText::new("● LIVE")
+                    egui::RichText::new(tr("● LIVE"))
                         .color(egui::Color32::from_rgb(51, 255, 102))
                 } else {
-                    egui::RichText::new("○ PAUSED")
+                    egui::RichText::new(tr("○ PAUSED"))
                         .color(egui::Color32::from_rgb(255, 150, 80))
                 };
                 ui.label(status_text);
@@ -319,7 +806,8 @@ fn draw_timezone_picker(
 ) -> PickerResult {
     let mut result = PickerResult::default();
 
-    egui::Window::new("╔══ SELECT TIMEZONE ══╗")
+    egui::Window::new(tr("╔══ SELECT TIMEZONE ══╗"))
+        .id(egui::Id::new("select_timezone"))
         .collapsible(false)
         .resizable(true)
         .default_width(400.0)
@@ -327,7 +815,7 @@ fn draw_timezone_picker(
         .show(ctx, |ui| {
             // Search field
             ui.horizontal(|ui| {
-                ui.label(egui::RichText::new("Search:").color(egui::Color32::from_rgb(51, 255, 102)));
+                ui.label(egui::RichText::new(tr("Search:")).color(egui::Color32::from_rgb(51, 255, 102)));
                 let search_response = ui.text_edit_singleline(&mut picker_state.search_query);
 
                 if picker_state.should_focus_search {
@@ -344,7 +832,8 @@ fn draw_timezone_picker(
 
             // Favorites section
             if !favorites.is_empty() {
-                ui.collapsing(egui::RichText::new("★ Favorites").color(egui::Color32::from_rgb(255, 200, 100)), |ui| {
+                let title = egui::RichText::new(tr("★ Favorites")).color(egui::Color32::from_rgb(255, 200, 100));
+                egui::CollapsingHeader::new(title).id_source("favorites").show(ui, |ui| {
                     for &tz in favorites {
                         ui.horizontal(|ui| {
                             if ui.button(egui::RichText::new(format_zone_name(tz)).size(12.0)).clicked() {
@@ -360,7 +849,7 @@ fn draw_timezone_picker(
             }
 
             // System timezone shortcut
-            if ui.button(egui::RichText::new("📍 Use System Timezone").size(12.0)).clicked() {
+            if ui.button(egui::RichText::new(tr("📍 Use System Timezone")).size(12.0)).clicked() {
                 if let Some(sys_tz) = system_timezone() {
                     result.select_zone = Some(sys_tz);
                 }
@@ -370,7 +859,7 @@ fn draw_timezone_picker(
 
             // Search results
             ui.label(
-                egui::RichText::new(format!("{} results", picker_state.search_results.len()))
+                egui::RichText::new(tr_args("{0} results", &[&picker_state.search_results.len()]))
                     .size(11.0)
                     .color(egui::Color32::from_rgb(100, 150, 100)),
             );
@@ -378,10 +867,15 @@ fn draw_timezone_picker(
             egui::ScrollArea::vertical()
                 .max_height(300.0)
                 .show(ui, |ui| {
+                    let mut previous = None;
                     for &tz in picker_state.search_results.iter().take(100) {
+                        if let Some(region) = region_change(previous, tz) {
+                            region_heading(ui, region);
+                        }
+                        previous = Some(tz);
                         let is_favorite = favorites.contains(&tz);
                         ui.horizontal(|ui| {
-                            if ui.button(egui::RichText::new(format_zone_name(tz)).size(12.0)).clicked() {
+                            if ui.button(egui::RichText::new(zone_display_name(tz)).size(12.0)).clicked() {
                                 result.select_zone = Some(tz);
                             }
                             let fav_label = if is_favorite { "★" } else { "☆" };
@@ -394,7 +888,7 @@ fn draw_timezone_picker(
 
             ui.separator();
 
-            if ui.button(egui::RichText::new("Close (Esc)").size(12.0)).clicked() {
+            if ui.button(egui::RichText::new(tr("Close (Esc)")).size(12.0)).clicked() {
                 result.close = true;
             }
         });
@@ -420,28 +914,160 @@ fn get_dst_explanation(time_data: &TimeData) -> String {
     let year = time_data.year;
 
     if time_data.is_dst {
-        format!(
+        tr_args(
             "DST is active. Clocks will fall back\n\
-             1 hour in autumn {}.",
-            year
+             1 hour in autumn {0}.",
+            &[&year],
         )
     } else {
         // Check if we're before or after spring DST
         let month = time_data.month;
-        if month < 3 || month > 11 {
-            format!(
+        if !(3..=11).contains(&month) {
+            tr_args(
                 "Standard time. Clocks will spring\n\
-                 forward 1 hour in March {}.",
-                if month > 11 { year + 1 } else { year }
+                 forward 1 hour in March {0}.",
+                &[&if month > 11 { year + 1 } else { year }],
             )
         } else {
-            format!(
-                "Standard time. DST ended for {}.\n\
-                 Next DST starts March {}.",
-                year,
-                year + 1
+            tr_args(
+                "Standard time. DST ended for {0}.\n\
+                 Next DST starts March {1}.",
+                &[&year, &(year + 1)],
             )
         }
     }
 }
 
+
+/// Result of entry detail popup interactions
+#[derive(Default)]
+pub struct DetailResult {
+    /// Name of the value just copied to the clipboard
+    pub copied: Option<String>,
+    /// Show the entry's instant in linked clocks
+    pub link: bool,
+    /// The popup was closed
+    pub close: bool,
+}
+
+/// Draw the detail popup for a clicked entry: its full hashes and the
+/// instant as ISO 8601 timestamps in UTC, its own zone and `zones`
+pub fn draw_entry_detail(ctx: &egui::Context, entry: &LedgerEntry, zones: &[Tz]) -> DetailResult {
+    let mut result = DetailResult::default();
+    let mut open = true;
+    let heading = |text: &str| {
+        egui::RichText::new(text)
+            .size(13.0)
+            .color(egui::Color32::from_rgb(51, 255, 102))
+    };
+
+    egui::Window::new(tr("╔══ ENTRY DETAIL ══╗"))
+        .id(egui::Id::new("entry_detail"))
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .default_width(460.0)
+        .show(ctx, |ui| {
+            ui.label(
+                egui::RichText::new(format!("{} │ {}", entry.local_timestamp, entry.tz_name))
+                    .size(14.0)
+                    .color(egui::Color32::from_rgb(200, 255, 200)),
+            );
+            if let Some(event) = entry.event() {
+                ui.label(format!("▶ [{}] {}: {}", event.source, event.kind, event.message));
+            }
+            ui.add_space(5.0);
+
+            ui.label(heading(tr("▸ HASH CHAIN")));
+            detail_row(ui, tr("Hash"), &entry.hash, &mut result);
+            detail_row(ui, tr("Previous"), &entry.prev_hash, &mut result);
+            ui.add_space(5.0);
+
+            ui.label(heading(tr("▸ TIMESTAMPS")));
+            let instant = entry.instant_utc;
+            detail_row(ui, "UTC", &instant.to_rfc3339_opts(SecondsFormat::Secs, true), &mut result);
+            let own_zone = entry.tz_name.parse::<Tz>().ok();
+            for tz in own_zone.iter().chain(zones.iter().filter(|&&tz| Some(tz) != own_zone)) {
+                let iso = instant.with_timezone(tz).to_rfc3339_opts(SecondsFormat::Secs, true);
+                detail_row(ui, tz.name(), &iso, &mut result);
+            }
+            detail_row(ui, tr("Epoch"), &instant.timestamp().to_string(), &mut result);
+            ui.add_space(5.0);
+
+            if ui.button(egui::RichText::new(tr("Show in linked clocks")).size(12.0)).clicked() {
+                result.link = true;
+            }
+        });
+
+    result.close = !open;
+    result
+}
+
+/// A labelled value in the detail popup, with a button to copy it
+fn detail_row(ui: &mut egui::Ui, label: &str, value: &str, result: &mut DetailResult) {
+    ui.horizontal(|ui| {
+        if ui.small_button(tr("Copy")).clicked() {
+            ui.output_mut(|o| o.copied_text = value.to_string());
+            result.copied = Some(label.to_string());
+        }
+        ui.label(
+            egui::RichText::new(label)
+                .size(11.0)
+                .color(egui::Color32::from_rgb(100, 150, 100)),
+        );
+        ui.add(egui::Label::new(egui::RichText::new(value).size(11.0).monospace()).wrap(true));
+    });
+}
+
+/// Restyle egui's chrome for the active theme
+///
+/// Leaves `visuals` alone while the clock uses its own colors.
+pub fn theme_visuals(visuals: &mut egui::Visuals) {
+    let Some(palette) = active_palette() else {
+        return;
+    };
+    let color = |c: Rgb| egui::Color32::from_rgb(c.red, c.green, c.blue);
+    *visuals = if palette.is_light() {
+        egui::Visuals::light()
+    } else {
+        egui::Visuals::dark()
+    };
+    visuals.panel_fill = color(palette.background);
+    visuals.window_fill = color(palette.background);
+    visuals.override_text_color = Some(color(palette.primary_text));
+    visuals.hyperlink_color = color(palette.accent);
+    visuals.warn_fg_color = color(palette.warning);
+}
+
+/// Draw the theme switcher
+///
+/// Returns the newly chosen theme, where `Some(None)` means the clock's own
+/// colors.
+pub fn theme_selector(
+    ui: &mut egui::Ui,
+    themes: &ThemeLibrary,
+    selected: Option<&str>,
+) -> Option<Option<String>> {
+    let mut choice = None;
+    egui::ComboBox::from_label(tr("Theme"))
+        .selected_text(selected.unwrap_or(tr("Clock colors")))
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(selected.is_none(), tr("Clock colors")).clicked() {
+                choice = Some(None);
+            }
+            for theme in &themes.themes {
+                let is_selected = selected == Some(theme.name.as_str());
+                if ui.selectable_label(is_selected, &theme.name).clicked() {
+                    choice = Some(Some(theme.name.clone()));
+                }
+            }
+        });
+    if let Some(error) = &themes.error {
+        ui.label(
+            egui::RichText::new(error)
+                .size(10.0)
+                .color(egui::Color32::from_rgb(255, 100, 100)),
+        );
+    }
+    choice
+}
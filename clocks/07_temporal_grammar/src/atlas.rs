@@ -0,0 +1,102 @@
+//! Atlas module - every favorite zone's diagram at once
+//!
+//! The atlas overlays the canvas with a grid of thumbnails, one per favorite
+//! zone, all drawn for the home diagram's instant. Since the hour, minute and
+//! second agree everywhere, the thumbnails differ only by how offset and DST
+//! twist the topology. Clicking a thumbnail switches the main diagram to its
+//! zone.
+
+use nannou::prelude::*;
+
+/// Space around and between thumbnails
+const GAP: f32 = 16.0;
+
+/// Height reserved under each thumbnail for its label
+pub const LABEL_HEIGHT: f32 = 36.0;
+
+/// Room above the grid for the atlas title
+const TITLE_HEIGHT: f32 = 50.0;
+
+/// Columns and rows for `count` thumbnails in `area`, keeping cells
+/// as close to square as the area allows
+fn grid_shape(count: usize, area: Rect) -> (usize, usize) {
+    (1..=count.max(1))
+        .map(|columns| (columns, count.max(1).div_ceil(columns)))
+        .max_by(|&(ac, ar), &(bc, br)| {
+            let side = |c: usize, r: usize| {
+                let w = area.w() / c as f32;
+                let h = area.h() / r as f32 - LABEL_HEIGHT;
+                w.min(h)
+            };
+            side(ac, ar).total_cmp(&side(bc, br))
+        })
+        .unwrap_or((1, 1))
+}
+
+/// The cell each of `count` thumbnails occupies in `canvas`, in reading
+/// order; each cell includes its label strip at the bottom
+pub fn atlas_cells(count: usize, canvas: Rect) -> Vec<Rect> {
+    let area = Rect::from_corners(
+        pt2(canvas.left() + GAP, canvas.bottom() + GAP),
+        pt2(canvas.right() - GAP, canvas.top() - TITLE_HEIGHT),
+    );
+    let (columns, rows) = grid_shape(count, area);
+    let (cell_w, cell_h) = (area.w() / columns as f32, area.h() / rows as f32);
+
+    (0..count)
+        .map(|i| {
+            let (column, row) = (i % columns, i / columns);
+            Rect::from_x_y_w_h(
+                area.left() + cell_w * (column as f32 + 0.5),
+                area.top() - cell_h * (row as f32 + 0.5),
+                cell_w - GAP,
+                cell_h - GAP,
+            )
+        })
+        .collect()
+}
+
+/// The square a thumbnail's diagram fits in, above its label
+pub fn diagram_area(cell: Rect) -> Rect {
+    let side = cell.w().min(cell.h() - LABEL_HEIGHT);
+    Rect::from_x_y_w_h(cell.x(), cell.top() - side / 2.0, side, side)
+}
+
+/// Index of the cell containing `point`
+pub fn cell_at(cells: &[Rect], point: Point2) -> Option<usize> {
+    cells.iter().position(|cell| cell.contains(point))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_fits_the_canvas() {
+        let canvas = Rect::from_w_h(740.0, 750.0);
+        assert_eq!(grid_shape(4, canvas), (2, 2));
+        assert_eq!(grid_shape(1, canvas), (1, 1));
+
+        // A wide canvas spreads a few thumbnails along one row
+        assert_eq!(grid_shape(3, Rect::from_w_h(1200.0, 300.0)), (3, 1));
+
+        let cells = atlas_cells(5, canvas);
+        assert_eq!(cells.len(), 5);
+        for cell in &cells {
+            assert!(canvas.contains(cell.top_left()) && canvas.contains(cell.bottom_right()));
+            let diagram = diagram_area(*cell);
+            assert!((diagram.w() - diagram.h()).abs() < 1e-3);
+            assert!(diagram.bottom() >= cell.bottom() + LABEL_HEIGHT - 1e-3);
+        }
+        // Reading order: left to right, then down
+        assert!(cells[1].x() > cells[0].x());
+        assert!(cells.last().unwrap().y() < cells[0].y());
+    }
+
+    #[test]
+    fn test_cell_at() {
+        let cells = atlas_cells(4, Rect::from_w_h(800.0, 800.0));
+        assert_eq!(cell_at(&cells, cells[2].xy()), Some(2));
+        assert_eq!(cell_at(&cells, pt2(0.0, 0.0)), None);
+    }
+}
@@ -4,13 +4,17 @@
 //! using nannou's Draw API.
 
 use nannou::prelude::*;
-use shared::{DstChange, TimeData};
+use shared::{tr, tr_args, CommandRegistry, DstChange, TimeData};
 
-use crate::geometry::{DstKnot, GeometryParams, PhaseRing};
+use crate::explicit::{Alignment, ExplicitLayout, ExplicitLine, LineRole};
+use crate::geometry::{self, DiagramShape, DstKnot, GeometryParams, PhaseRing};
+use crate::legend::{self, LegendLayer};
+use crate::quiz::Quiz;
 
 /// Color palette for the temporal grammar aesthetic
 pub mod colors {
     use nannou::prelude::*;
+    use shared::{Rgb, ThemeRole};
 
     /// Deep background - near black with subtle warmth
     pub const BACKGROUND: Srgb<u8> = Srgb {
@@ -92,6 +96,30 @@ pub mod colors {
         standard: std::marker::PhantomData,
     };
 
+    /// Poster paper - warm off-white
+    pub const PAPER: Srgb<u8> = Srgb {
+        red: 250,
+        green: 248,
+        blue: 243,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Poster ink for text and the needle
+    pub const INK: Srgb<u8> = Srgb {
+        red: 30,
+        green: 28,
+        blue: 40,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Poster ink for secondary text
+    pub const INK_SECONDARY: Srgb<u8> = Srgb {
+        red: 95,
+        green: 95,
+        blue: 110,
+        standard: std::marker::PhantomData,
+    };
+
     /// Truth anchor overlay background
     pub fn overlay_bg() -> Srgba<u8> {
         srgba(20, 18, 28, 240)
@@ -149,6 +177,32 @@ pub mod colors {
     pub fn help_bg() -> Srgba<u8> {
         srgba(25, 22, 35, 250)
     }
+
+    /// Look up one of this clock's role colors in the active theme
+    fn themed(role: ThemeRole, native: Srgb<u8>) -> Srgb<u8> {
+        let color = shared::themed(role, Rgb::new(native.red, native.green, native.blue));
+        Srgb::new(color.red, color.green, color.blue)
+    }
+
+    pub fn background() -> Srgb<u8> {
+        themed(ThemeRole::Background, BACKGROUND)
+    }
+
+    pub fn text_primary() -> Srgb<u8> {
+        themed(ThemeRole::PrimaryText, TEXT_PRIMARY)
+    }
+
+    pub fn text_secondary() -> Srgb<u8> {
+        themed(ThemeRole::SecondaryText, TEXT_SECONDARY)
+    }
+
+    pub fn hud_accent() -> Srgb<u8> {
+        themed(ThemeRole::Accent, HUD_ACCENT)
+    }
+
+    pub fn dst_knot() -> Srgb<u8> {
+        themed(ThemeRole::Warning, DST_KNOT)
+    }
 }
 
 /// Draw the foundation layer (hour polygon)
@@ -272,7 +326,7 @@ pub fn draw_phase_layer(draw: &Draw, ring: &PhaseRing, view_zoom: f32) {
 /// Draw the DST knot
 pub fn draw_dst_knot(draw: &Draw, knot: &DstKnot) {
     let color = if knot.is_upcoming {
-        colors::DST_KNOT
+        colors::dst_knot()
     } else {
         colors::DST_KNOT_PAST
     };
@@ -347,7 +401,7 @@ pub fn draw_truth_anchor_overlay(
         .xy(position)
         .w_h(overlay_width, overlay_height)
         .no_fill()
-        .stroke(colors::HUD_ACCENT)
+        .stroke(colors::hud_accent())
         .stroke_weight(2.0);
 
     // Time (large)
@@ -357,7 +411,7 @@ pub fn draw_truth_anchor_overlay(
     );
     draw.text(&time_str)
         .xy(position + vec2(0.0, overlay_height / 2.0 - 30.0))
-        .color(colors::TEXT_PRIMARY)
+        .color(colors::text_primary())
         .font_size(28)
         .w(overlay_width - padding * 2.0);
 
@@ -365,12 +419,12 @@ pub fn draw_truth_anchor_overlay(
     let date_str = time_data.format_date();
     draw.text(&date_str)
         .xy(position + vec2(0.0, overlay_height / 2.0 - 60.0))
-        .color(colors::TEXT_SECONDARY)
+        .color(colors::text_secondary())
         .font_size(14)
         .w(overlay_width - padding * 2.0);
 
     // Timezone info
-    let dst_str = if time_data.is_dst { "DST On" } else { "DST Off" };
+    let dst_str = tr(if time_data.is_dst { "DST On" } else { "DST Off" });
     let tz_str = format!(
         "{} · {} · {}",
         tz_name,
@@ -379,34 +433,33 @@ pub fn draw_truth_anchor_overlay(
     );
     draw.text(&tz_str)
         .xy(position + vec2(0.0, overlay_height / 2.0 - 85.0))
-        .color(colors::TEXT_SECONDARY)
+        .color(colors::text_secondary())
         .font_size(12)
         .w(overlay_width - padding * 2.0);
 
     // DST change warning if applicable
     match &time_data.dst_change {
         DstChange::Upcoming { instant, delta_minutes } => {
-            let hours_until = (*instant - chrono::Utc::now()).num_hours();
-            let direction = if *delta_minutes > 0 {
-                "spring forward"
+            let hours_until = (*instant - shared::now_utc()).num_hours();
+            let template = if *delta_minutes > 0 {
+                "⚠ DST change in {0}h (spring forward)"
             } else {
-                "fall back"
+                "⚠ DST change in {0}h (fall back)"
             };
-            let warning = format!("⚠ DST change in {}h ({})", hours_until, direction);
+            let warning = tr_args(template, &[&hours_until]);
             draw.text(&warning)
                 .xy(position + vec2(0.0, overlay_height / 2.0 - 110.0))
-                .color(colors::DST_KNOT)
+                .color(colors::dst_knot())
                 .font_size(11)
                 .w(overlay_width - padding * 2.0);
         }
         DstChange::JustOccurred { delta_minutes, .. } => {
-            let direction = if *delta_minutes > 0 {
-                "sprang forward"
+            let info = tr(if *delta_minutes > 0 {
+                "ℹ Clocks sprang forward recently"
             } else {
-                "fell back"
-            };
-            let info = format!("ℹ Clocks {} recently", direction);
-            draw.text(&info)
+                "ℹ Clocks fell back recently"
+            });
+            draw.text(info)
                 .xy(position + vec2(0.0, overlay_height / 2.0 - 110.0))
                 .color(colors::DST_KNOT_PAST)
                 .font_size(11)
@@ -416,7 +469,7 @@ pub fn draw_truth_anchor_overlay(
     }
 }
 
-/// Draw Decode Mode guides and labels
+/// Draw Decode Mode guides and the legend panel
 pub fn draw_decode_mode_guides(
     draw: &Draw,
     params: &GeometryParams,
@@ -424,63 +477,7 @@ pub fn draw_decode_mode_guides(
     center: Point2,
     canvas_rect: Rect,
 ) {
-    // Position labels relative to canvas edges with padding
-    let padding = 15.0;
-    let left_x = canvas_rect.left() + padding;
-    let right_x = canvas_rect.right() - padding - 120.0; // Leave room for label width
-
-    // Hour polygon label (top-left)
-    let hour_label = format!("V = 3 + {} = {}", params.hour, params.vertex_count);
-    draw.text(&hour_label)
-        .xy(pt2(left_x + 80.0, canvas_rect.top() - 60.0))
-        .color(colors::DECODE_LABEL)
-        .font_size(14)
-        .w(180.0)
-        .left_justify();
-
-    // Superellipse exponent label (right side, upper)
-    let exp_label = format!("e = {:.2}", params.exponent);
-    draw.text(&exp_label)
-        .xy(pt2(right_x, center.y + 80.0))
-        .color(colors::DECODE_LABEL)
-        .font_size(14)
-        .w(120.0)
-        .left_justify();
-
-    // Minute rotation label (right side, middle-upper)
-    let rot_label = format!("rot = {:.1}°", params.minute_rotation_deg);
-    draw.text(&rot_label)
-        .xy(pt2(right_x, center.y + 50.0))
-        .color(colors::DECODE_LABEL)
-        .font_size(14)
-        .w(120.0)
-        .left_justify();
-
-    // Phase angle label (bottom-left)
-    let phase_label = format!("φ = {:.1}°", params.phase_deg);
-    draw.text(&phase_label)
-        .xy(pt2(left_x + 80.0, canvas_rect.bottom() + 80.0))
-        .color(colors::DECODE_LABEL)
-        .font_size(14)
-        .w(120.0)
-        .left_justify();
-
-    // TZ transform labels (right side, lower)
-    let tz_rot_label = format!("tzRot = {:.1}°", params.tz_rotation_deg);
-    draw.text(&tz_rot_label)
-        .xy(pt2(right_x, center.y - 50.0))
-        .color(colors::DECODE_LABEL)
-        .font_size(14)
-        .w(130.0)
-        .left_justify();
-
-    let tz_skew_label = format!("tzSkewX = {:.3}", params.tz_skew_x);
-    draw.text(&tz_skew_label)
-        .xy(pt2(right_x, center.y - 80.0))
-        .color(colors::DECODE_LABEL)
-        .font_size(14)
-        .w(130.0)
-        .left_justify();
+    draw_decode_legend(draw, params, canvas_rect);
 
     // Draw guide line from center to highlighted second mark
     if ring.highlighted_index < ring.marks.len() {
@@ -511,89 +508,294 @@ pub fn draw_decode_mode_guides(
         .color(colors::DECODE_GUIDE);
 }
 
-/// Draw Explicit Mode (standard time readout replacing canvas)
-pub fn draw_explicit_mode(draw: &Draw, time_data: &TimeData, rect: Rect, tz_name: &str) {
-    let center = rect.xy();
+/// Draw the Decode Mode legend in the top-left corner of `canvas_rect`
+///
+/// One row per layer: a miniature of the layer as it stands now, its name,
+/// its current value and its formula. The panel shrinks to fit narrow
+/// canvases, such as one half of a comparison.
+fn draw_decode_legend(draw: &Draw, params: &GeometryParams, canvas_rect: Rect) {
+    let rows = legend::legend_rows(params);
+    let width = 300.0;
+    let row_height = 52.0;
+    let title_height = 26.0;
+    let padding = 10.0;
+    let height = title_height + row_height * rows.len() as f32 + padding;
+
+    // Below the HUD line, and never wider than half the canvas
+    let margin = 15.0;
+    let scale = (canvas_rect.w() * 0.5 / width)
+        .min((canvas_rect.h() - 80.0) * 0.6 / height)
+        .clamp(0.4, 1.0);
+    let top_left = pt2(canvas_rect.left() + margin, canvas_rect.top() - 40.0);
+    let draw = draw.xy(top_left).scale(scale);
 
-    // Large time display
-    let time_str = format!(
-        "{:02}:{:02}:{:02}",
-        time_data.hour12, time_data.minute, time_data.second
-    );
-    draw.text(&time_str)
-        .xy(center + vec2(0.0, 60.0))
-        .color(colors::TEXT_PRIMARY)
-        .font_size(72)
-        .w(rect.w());
-
-    // AM/PM
-    draw.text(&time_data.meridiem.to_string())
-        .xy(center + vec2(180.0, 75.0))
-        .color(colors::HUD_ACCENT)
-        .font_size(28)
-        .w(100.0);
+    draw.rect()
+        .x_y(width / 2.0, -height / 2.0)
+        .w_h(width, height)
+        .color(colors::overlay_bg());
+    draw.rect()
+        .x_y(width / 2.0, -height / 2.0)
+        .w_h(width, height)
+        .no_fill()
+        .stroke(colors::DECODE_GUIDE)
+        .stroke_weight(1.0);
+    draw.text(tr("DECODE LEGEND"))
+        .x_y(width / 2.0, -title_height / 2.0 - 2.0)
+        .w(width - padding * 2.0)
+        .color(colors::DECODE_GUIDE)
+        .font_size(11)
+        .left_justify();
 
-    // Date
-    let date_str = time_data.format_date();
-    draw.text(&date_str)
-        .xy(center + vec2(0.0, 0.0))
-        .color(colors::TEXT_SECONDARY)
-        .font_size(24)
-        .w(rect.w());
+    let shape = DiagramShape::from_params(params);
+    let mini_radius = 18.0;
+    let text_left = padding + mini_radius * 2.0 + 12.0;
+    let text_width = width - text_left - padding;
+    for (i, row) in rows.iter().enumerate() {
+        let y = -title_height - row_height * (i as f32 + 0.5);
+        let mini_center = pt2(padding + mini_radius, y);
+        draw_legend_miniature(&draw, row.layer, &shape, params, mini_center, mini_radius);
+
+        let lines = [
+            (tr(row.name), colors::text_secondary(), 10, 15.0),
+            (row.value.as_str(), colors::DECODE_LABEL, 13, 0.0),
+            (row.formula, colors::text_secondary(), 10, -15.0),
+        ];
+        for (text, color, size, dy) in lines {
+            draw.text(text)
+                .x_y(text_left + text_width / 2.0, y + dy)
+                .w_h(text_width, 16.0)
+                .color(color)
+                .font_size(size)
+                .left_justify()
+                .no_line_wrap();
+        }
+    }
+}
 
-    // Timezone info
-    let dst_str = if time_data.is_dst { "DST Active" } else { "Standard Time" };
-    let tz_str = format!("{} · {} · {}", tz_name, time_data.format_utc_offset(), dst_str);
-    draw.text(&tz_str)
-        .xy(center + vec2(0.0, -50.0))
-        .color(colors::TEXT_SECONDARY)
-        .font_size(18)
-        .w(rect.w());
+/// Draw one legend row's miniature of its layer, `radius` across from `center`
+fn draw_legend_miniature(
+    draw: &Draw,
+    layer: LegendLayer,
+    shape: &DiagramShape,
+    params: &GeometryParams,
+    center: Point2,
+    radius: f32,
+) {
+    let closed = |mut points: Vec<Point2>| {
+        if let Some(&first) = points.first() {
+            points.push(first);
+        }
+        points
+    };
 
-    // DST change info
-    match &time_data.dst_change {
-        DstChange::Upcoming { instant, delta_minutes } => {
-            let hours_until = (*instant - chrono::Utc::now()).num_hours();
-            let direction = if *delta_minutes > 0 {
-                "spring forward"
-            } else {
-                "fall back"
-            };
-            let warning = format!("DST change in {} hours ({})", hours_until, direction);
-            draw.text(&warning)
-                .xy(center + vec2(0.0, -100.0))
-                .color(colors::DST_KNOT)
-                .font_size(16)
-                .w(rect.w());
+    match layer {
+        LegendLayer::Foundation => {
+            // The polygon's radius is 0.28 of the size it is drawn for
+            let min_dim = radius / 0.28;
+            let points =
+                geometry::polygon_points(shape.vertex_count, shape.hour_rotation, min_dim, center);
+            draw_foundation_layer(draw, &points);
         }
-        DstChange::JustOccurred { delta_minutes, .. } => {
-            let direction = if *delta_minutes > 0 {
-                "sprang forward"
-            } else {
-                "fell back"
-            };
-            let info = format!("Clocks {} recently", direction);
-            draw.text(&info)
-                .xy(center + vec2(0.0, -100.0))
-                .color(colors::DST_KNOT_PAST)
-                .font_size(16)
-                .w(rect.w());
+        LegendLayer::Tension => {
+            // And the superellipse's long radius is 0.40 of it
+            let points = geometry::superellipse_points(
+                shape.exponent,
+                shape.minute_rotation,
+                radius / 0.40,
+                center,
+                64,
+            );
+            draw.polyline()
+                .weight(1.5)
+                .points(closed(points))
+                .color(colors::TENSION_STROKE);
+        }
+        LegendLayer::Phase => {
+            for i in 0..12 {
+                let angle = -PI / 2.0 - (i as f32 / 12.0) * TAU;
+                draw.ellipse()
+                    .xy(center + vec2(angle.cos(), angle.sin()) * radius)
+                    .radius(1.2)
+                    .color(colors::PHASE_MARK);
+            }
+            let angle = -PI / 2.0 - params.phase_deg.to_radians();
+            let tip = center + vec2(angle.cos(), angle.sin()) * radius;
+            draw.line().start(center).end(tip).weight(1.5).color(colors::PHASE_NEEDLE);
+            draw.ellipse().xy(tip).radius(3.0).color(colors::PHASE_HIGHLIGHT);
+        }
+        LegendLayer::Frame => {
+            // A square frame put through the timezone rotation and skew
+            let half = radius * 0.7;
+            let square = [
+                center + vec2(-half, -half),
+                center + vec2(half, -half),
+                center + vec2(half, half),
+                center + vec2(-half, half),
+            ];
+            draw.polyline()
+                .weight(1.0)
+                .points(closed(square.to_vec()))
+                .color(colors::DECODE_GUIDE);
+            let (rotation, skew) = (shape.tz_rotation, shape.tz_skew_x);
+            let framed = geometry::transform_points(&square, rotation, skew, 0.0, center);
+            draw.polyline()
+                .weight(1.5)
+                .points(closed(framed))
+                .color(colors::DECODE_LABEL);
         }
-        DstChange::None => {}
     }
+}
+
+/// Draw Explicit Mode (standard time readout replacing canvas)
+///
+/// The layout's lines are stacked around the middle of `rect`, each shrunk
+/// if needed so it fits the width.
+pub fn draw_explicit_mode(
+    draw: &Draw,
+    time_data: &TimeData,
+    rect: Rect,
+    tz_name: &str,
+    layout: &ExplicitLayout,
+) {
+    let margin = 24.0;
+    let width = (rect.w() - margin * 2.0).max(1.0);
+
+    let lines: Vec<(ExplicitLine, f32)> = layout
+        .lines(time_data, tz_name)
+        .into_iter()
+        .map(|line| {
+            // Glyphs run about 0.6 of the font size wide
+            let fit = width / (line.text.chars().count().max(1) as f32 * 0.6);
+            let size = (line.size * layout.scale()).min(fit).max(6.0);
+            (line, size)
+        })
+        .collect();
+
+    let line_height = |size: f32| size * 1.4;
+    let total: f32 = lines.iter().map(|(_, size)| line_height(*size)).sum();
+    let mut y = rect.y() + total / 2.0;
+
+    for (line, size) in &lines {
+        let height = line_height(*size);
+        let color = match line.role {
+            LineRole::Time => colors::text_primary(),
+            LineRole::Detail => colors::text_secondary(),
+            LineRole::Warning => colors::dst_knot(),
+            LineRole::Notice => colors::DST_KNOT_PAST,
+            LineRole::Caption => colors::hud_accent(),
+        };
+        let text = draw
+            .text(&line.text)
+            .x_y(rect.x(), y - height / 2.0)
+            .w_h(width, height)
+            .color(color)
+            .font_size(*size as u32)
+            .no_line_wrap();
+        match layout.alignment {
+            Alignment::Left => text.left_justify(),
+            Alignment::Center => text.center_justify(),
+            Alignment::Right => text.right_justify(),
+        };
+        y -= height;
+    }
+}
 
-    // Mode indicator
-    draw.text("EXPLICIT MODE")
-        .xy(center + vec2(0.0, -150.0))
-        .color(colors::HUD_ACCENT)
+/// Label each half of a side-by-side comparison
+///
+/// Names each zone under its diagram, divides the halves and captions the
+/// comparison side with how its offset differs from home.
+pub fn draw_comparison_labels(
+    draw: &Draw,
+    halves: [(Rect, &str, &TimeData); 2],
+    difference: &str,
+) {
+    let [(home_rect, _, _), (comparison_rect, _, _)] = halves;
+    let divider_x = (home_rect.right() + comparison_rect.left()) / 2.0;
+    draw.line()
+        .start(pt2(divider_x, home_rect.top() - 90.0))
+        .end(pt2(divider_x, home_rect.bottom() + 40.0))
+        .color(colors::text_secondary())
+        .weight(1.0);
+
+    for (rect, tz_name, time_data) in halves {
+        let label = format!("{} · {}", tz_name, time_data.format_utc_offset());
+        draw.text(&label)
+            .xy(pt2(rect.x(), rect.bottom() + 60.0))
+            .color(colors::text_primary())
+            .font_size(14)
+            .w(rect.w() - 20.0);
+    }
+
+    draw.text(difference)
+        .xy(pt2(comparison_rect.x(), comparison_rect.bottom() + 40.0))
+        .color(colors::hud_accent())
         .font_size(12)
-        .w(rect.w());
+        .w(comparison_rect.w() - 20.0);
+}
+
+/// Dim the canvas behind the atlas thumbnails and title it
+pub fn draw_atlas_backdrop(draw: &Draw, canvas_rect: Rect) {
+    draw.rect()
+        .xy(canvas_rect.xy())
+        .wh(canvas_rect.wh())
+        .color(colors::help_bg());
+
+    draw.text(tr("Favorites Atlas"))
+        .xy(pt2(canvas_rect.x(), canvas_rect.top() - 25.0))
+        .color(colors::text_primary())
+        .font_size(16)
+        .w(canvas_rect.w());
+}
+
+/// Label an atlas thumbnail with its zone, offset and DST state, outlining
+/// the home zone and the thumbnail under the pointer
+pub fn draw_atlas_label(
+    draw: &Draw,
+    cell: Rect,
+    tz_name: &str,
+    time_data: &TimeData,
+    is_home: bool,
+    hovered: bool,
+) {
+    if is_home || hovered {
+        let (color, weight) = if hovered {
+            (colors::hud_accent(), 2.0)
+        } else {
+            (colors::text_secondary(), 1.0)
+        };
+        draw.rect()
+            .xy(cell.xy())
+            .wh(cell.wh())
+            .no_fill()
+            .stroke(color)
+            .stroke_weight(weight);
+    }
+
+    let name = tz_name.rsplit('/').next().unwrap_or(tz_name).replace('_', " ");
+    draw.text(&name)
+        .xy(pt2(cell.x(), cell.bottom() + 24.0))
+        .color(colors::text_primary())
+        .font_size(12)
+        .w(cell.w() - 8.0);
+
+    let state = if time_data.is_dst { "DST" } else { "STD" };
+    let detail = format!("{} · {}", time_data.format_utc_offset(), state);
+    let detail_color = if matches!(time_data.dst_change, DstChange::None) {
+        colors::text_secondary()
+    } else {
+        colors::dst_knot()
+    };
+    draw.text(&detail)
+        .xy(pt2(cell.x(), cell.bottom() + 9.0))
+        .color(detail_color)
+        .font_size(10)
+        .w(cell.w() - 8.0);
 }
 
 /// Draw the "How to Read This Clock" help panel
-pub fn draw_help_panel(draw: &Draw, canvas_rect: Rect) {
+pub fn draw_help_panel(draw: &Draw, canvas_rect: Rect, keys: &CommandRegistry) {
     let panel_width = 500.0;
-    let panel_height = 400.0;
+    let panel_height = 620.0;
     let center = canvas_rect.xy();
 
     // Background
@@ -607,52 +809,66 @@ pub fn draw_help_panel(draw: &Draw, canvas_rect: Rect) {
         .xy(center)
         .w_h(panel_width, panel_height)
         .no_fill()
-        .stroke(colors::HUD_ACCENT)
+        .stroke(colors::hud_accent())
         .stroke_weight(2.0);
 
     // Title
-    draw.text("How to Read This Clock")
+    draw.text(tr("How to Read This Clock"))
         .xy(center + vec2(0.0, panel_height / 2.0 - 30.0))
-        .color(colors::TEXT_PRIMARY)
+        .color(colors::text_primary())
         .font_size(22)
         .w(panel_width - 40.0);
 
-    // Content - each item is (is_header, text)
+    // Content - each item is (is_header, text, command whose key follows it)
     let content = [
-        (true, "HOUR (Inner Polygon)"),
-        (false, "  Number of sides = 3 + hour"),
-        (false, "  4 sides = 1:00, 15 sides = 12:00"),
-        (true, "MINUTE (Middle Shape)"),
-        (false, "  Shape morphs from round to square"),
-        (false, "  Round (e≈1.2) = :00, Square (e≈4) = :59"),
-        (false, "  Rotates 360° clockwise through the hour"),
-        (true, "SECOND (Outer Ring)"),
-        (false, "  60 marks around the ring"),
-        (false, "  Highlighted mark = current second"),
-        (true, "TIMEZONE"),
-        (false, "  Offset rotates/skews all layers"),
-        (false, "  DST adds extra rotation + shear"),
-        (true, "DST KNOT"),
-        (false, "  Appears 24h before DST change"),
-        (false, "  Grows larger as transition approaches"),
+        (true, "HOUR (Inner Polygon)", None),
+        (false, "  Number of sides = 3 + hour", None),
+        (false, "  4 sides = 1:00, 15 sides = 12:00", None),
+        (true, "MINUTE (Middle Shape)", None),
+        (false, "  Shape morphs from round to square", None),
+        (false, "  Round (e≈1.2) = :00, Square (e≈4) = :59", None),
+        (false, "  Rotates 360° clockwise through the hour", None),
+        (true, "SECOND (Outer Ring)", None),
+        (false, "  60 marks around the ring", None),
+        (false, "  Highlighted mark = current second", None),
+        (true, "TIMEZONE", None),
+        (false, "  Offset rotates/skews all layers", None),
+        (false, "  DST adds extra rotation + shear", None),
+        (true, "DST KNOT", None),
+        (false, "  Appears 24h before DST change", None),
+        (false, "  Grows larger as transition approaches", None),
+        (true, "DECODE QUIZ", Some("Start/stop decode quiz")),
+        (false, "  Read a random instant, type h:mm to answer", None),
+        (true, "COMPARE", Some("Compare with another zone")),
+        (false, "  A favorite zone side by side; the shapes differ by offset/DST", None),
+        (true, "FAVORITES ATLAS", Some("Toggle favorites atlas")),
+        (false, "  Every favorite at the same instant; click one to switch to it", None),
+        (true, "GEOMETRY INSPECTOR", Some("Open geometry inspector")),
+        (false, "  Drag any parameter to see its effect; return to live to reset", None),
+        (true, "TIME POSTER", Some("Open time poster")),
+        (false, "  Any instant as a print-ready PNG or SVG, with caption and legend", None),
     ];
 
     let line_height = 20.0;
     let start_y = panel_height / 2.0 - 70.0;
     let left_edge = center.x - panel_width / 2.0 + 25.0;
 
-    for (i, (is_header, text)) in content.iter().enumerate() {
+    for (i, (is_header, text, command)) in content.iter().enumerate() {
         let y = center.y + start_y - (i as f32) * line_height;
+        let text = match command {
+            Some(command) => format!("{} ({})", tr(text), keys.key_label(command)),
+            None => tr(text).to_string(),
+        };
 
         let color = if *is_header {
-            colors::HUD_ACCENT
+            colors::hud_accent()
         } else {
-            colors::TEXT_SECONDARY
+            colors::text_secondary()
         };
 
         let font_size = if *is_header { 13 } else { 12 };
 
-        draw.text(text)
+        draw.text(&text)
             .xy(pt2(left_edge + (panel_width - 50.0) / 2.0, y))
             .color(color)
             .font_size(font_size)
@@ -661,9 +877,9 @@ pub fn draw_help_panel(draw: &Draw, canvas_rect: Rect) {
     }
 
     // Close hint
-    draw.text("Press ? or Escape to close")
+    draw.text(&tr_args("Press {0} or Esc to close", &[&keys.key_label("Toggle help")]))
         .xy(center + vec2(0.0, -panel_height / 2.0 + 20.0))
-        .color(colors::TEXT_SECONDARY)
+        .color(colors::text_secondary())
         .font_size(11)
         .w(panel_width);
 }
@@ -675,16 +891,17 @@ pub fn draw_hud(
     is_dst: bool,
     dst_change: &DstChange,
     truth_anchor_hint: bool,
+    keys: &CommandRegistry,
 ) {
     let margin = 20.0;
 
     // TZ icon hint (top-left)
-    draw.text("Z: Timezone")
+    draw.text(&keys.hint_line(&[("Open timezone picker", "Timezone")]))
         .xy(pt2(
             window_rect.left() + 60.0,
             window_rect.top() - margin,
         ))
-        .color(colors::TEXT_SECONDARY)
+        .color(colors::text_secondary())
         .font_size(11)
         .w(100.0)
         .left_justify();
@@ -692,13 +909,13 @@ pub fn draw_hud(
     // DST status label (top-left, next to TZ)
     let dst_label_pos = pt2(window_rect.left() + 160.0, window_rect.top() - margin);
     let (dst_label, dst_color) = match dst_change {
-        DstChange::Upcoming { .. } => ("DST Soon", colors::DST_KNOT),
-        DstChange::JustOccurred { .. } => ("DST Changed", colors::DST_KNOT_PAST),
+        DstChange::Upcoming { .. } => (tr("DST Soon"), colors::dst_knot()),
+        DstChange::JustOccurred { .. } => (tr("DST Changed"), colors::DST_KNOT_PAST),
         DstChange::None => {
             if is_dst {
-                ("DST", colors::DST_KNOT)
+                ("DST", colors::dst_knot())
             } else {
-                ("Standard", colors::TEXT_SECONDARY)
+                (tr("Standard"), colors::text_secondary())
             }
         }
     };
@@ -711,24 +928,30 @@ pub fn draw_hud(
 
     // Truth Anchor hint (top-right)
     if truth_anchor_hint {
-        draw.text("Hold Space: Reveal Time")
+        draw.text(tr("Hold Space: Reveal Time"))
             .xy(pt2(
                 window_rect.right() - 100.0,
                 window_rect.top() - margin,
             ))
-            .color(colors::TEXT_SECONDARY)
+            .color(colors::text_secondary())
             .font_size(11)
             .w(180.0)
             .right_justify();
     }
 
     // Keyboard shortcuts hint (bottom)
-    draw.text("D: Decode  |  ?: Help  |  Tab: Focus")
+    let hints = keys.hint_line(&[
+        ("Toggle decode mode", "Decode"),
+        ("Compare with another zone", "Compare"),
+        ("Toggle help", "Help"),
+        ("Next focus region", "Focus"),
+    ]);
+    draw.text(&format!("{}  |  Ctrl+K: {}", hints, tr("Commands")))
         .xy(pt2(window_rect.x(), window_rect.bottom() + margin))
         .color(srgba(
-            colors::TEXT_SECONDARY.red,
-            colors::TEXT_SECONDARY.green,
-            colors::TEXT_SECONDARY.blue,
+            colors::text_secondary().red,
+            colors::text_secondary().green,
+            colors::text_secondary().blue,
             120,
         ))
         .font_size(10)
@@ -765,9 +988,9 @@ pub fn draw_toast(draw: &Draw, message: &str, alpha: f32, window_rect: Rect) {
         .w_h(toast_width, toast_height)
         .no_fill()
         .stroke(srgba(
-            colors::HUD_ACCENT.red,
-            colors::HUD_ACCENT.green,
-            colors::HUD_ACCENT.blue,
+            colors::hud_accent().red,
+            colors::hud_accent().green,
+            colors::hud_accent().blue,
             alpha_u8,
         ))
         .stroke_weight(1.0);
@@ -776,15 +999,51 @@ pub fn draw_toast(draw: &Draw, message: &str, alpha: f32, window_rect: Rect) {
     draw.text(message)
         .xy(pos)
         .color(srgba(
-            colors::TEXT_PRIMARY.red,
-            colors::TEXT_PRIMARY.green,
-            colors::TEXT_PRIMARY.blue,
+            colors::text_primary().red,
+            colors::text_primary().green,
+            colors::text_primary().blue,
             alpha_u8,
         ))
         .font_size(13)
         .w(toast_width - 20.0);
 }
 
+/// Draw the decode quiz prompt (or the revealed answer) at the top of the canvas
+pub fn draw_quiz_banner(draw: &Draw, canvas_rect: Rect, quiz: &Quiz, keys: &CommandRegistry) {
+    let pos = pt2(canvas_rect.x(), canvas_rect.top() - 55.0);
+
+    let (text, color) = match &quiz.verdict {
+        None => (
+            tr("DECODE QUIZ · What time is it? Answer in the sidebar").to_string(),
+            colors::hud_accent(),
+        ),
+        Some(verdict) => {
+            let mark = if verdict.correct { "✓" } else { "✗" };
+            (
+                tr_args(
+                    "{0} It was {1} · {2} for the next question",
+                    &[&mark, &quiz.answer.format(), &keys.key_label("Next quiz question")],
+                ),
+                if verdict.correct {
+                    colors::text_primary()
+                } else {
+                    colors::dst_knot()
+                },
+            )
+        }
+    };
+
+    draw.rect()
+        .xy(pos)
+        .w_h(420.0, 30.0)
+        .color(srgba(30u8, 28u8, 40u8, 200u8));
+    draw.text(&text)
+        .xy(pos)
+        .color(color)
+        .font_size(13)
+        .w(400.0);
+}
+
 /// Draw error banner for TZ data issues
 pub fn draw_error_banner(draw: &Draw, window_rect: Rect) {
     let banner_height = 40.0;
@@ -797,9 +1056,9 @@ pub fn draw_error_banner(draw: &Draw, window_rect: Rect) {
         .color(srgba(120u8, 40u8, 40u8, 220u8));
 
     // Text
-    draw.text("⚠ Timezone data may be missing or stale. Showing UTC as fallback.")
+    draw.text(tr("⚠ Timezone data may be missing or stale. Showing UTC as fallback."))
         .x_y(window_rect.x(), banner_y)
-        .color(colors::TEXT_PRIMARY)
+        .color(colors::text_primary())
         .font_size(14)
         .w(window_rect.w() - 40.0);
 }
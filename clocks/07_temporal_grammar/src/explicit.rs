@@ -0,0 +1,212 @@
+//! Explicit module - layout of the plain time readout
+//!
+//! Explicit Mode swaps the diagram for an ordinary readout. Its layout
+//! chooses which elements appear under the time (seconds, date, UTC offset,
+//! DST note), how large the text is and how it is aligned, so the clock can
+//! stand in as a plain wall clock. The layout is laid out here as lines of
+//! text; `drawing.rs` places and paints them.
+
+use serde::{Deserialize, Serialize};
+use shared::{DstChange, TimeData};
+
+/// Range of the font scale slider
+pub const MIN_FONT_SCALE: f32 = 0.5;
+pub const MAX_FONT_SCALE: f32 = 3.0;
+
+/// Horizontal alignment of the readout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Alignment {
+    Left,
+    #[default]
+    Center,
+    Right,
+}
+
+impl Alignment {
+    pub const ALL: [Alignment; 3] = [Alignment::Left, Alignment::Center, Alignment::Right];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Alignment::Left => "Left",
+            Alignment::Center => "Center",
+            Alignment::Right => "Right",
+        }
+    }
+}
+
+/// Persisted Explicit Mode layout
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExplicitLayout {
+    pub seconds: bool,
+    pub date: bool,
+    /// UTC offset beside the zone name
+    pub offset: bool,
+    /// Standard or daylight time, and any upcoming or recent change
+    pub dst_note: bool,
+    /// Multiple of the default text sizes
+    pub font_scale: f32,
+    pub alignment: Alignment,
+}
+
+impl Default for ExplicitLayout {
+    fn default() -> Self {
+        Self {
+            seconds: true,
+            date: true,
+            offset: true,
+            dst_note: true,
+            font_scale: 1.0,
+            alignment: Alignment::Center,
+        }
+    }
+}
+
+/// What a line of the readout shows, which sets its color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineRole {
+    Time,
+    Detail,
+    /// A DST change coming up
+    Warning,
+    /// A DST change that just happened
+    Notice,
+    /// The "EXPLICIT MODE" caption
+    Caption,
+}
+
+/// One line of the readout
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExplicitLine {
+    pub text: String,
+    /// Font size before the layout's scale
+    pub size: f32,
+    pub role: LineRole,
+}
+
+impl ExplicitLine {
+    fn new(text: impl Into<String>, size: f32, role: LineRole) -> Self {
+        Self {
+            text: text.into(),
+            size,
+            role,
+        }
+    }
+}
+
+impl ExplicitLayout {
+    /// Font scale in range, whatever the config held
+    pub fn scale(&self) -> f32 {
+        if self.font_scale.is_finite() {
+            self.font_scale.clamp(MIN_FONT_SCALE, MAX_FONT_SCALE)
+        } else {
+            1.0
+        }
+    }
+
+    /// The readout's lines, top to bottom
+    pub fn lines(&self, time_data: &TimeData, tz_name: &str) -> Vec<ExplicitLine> {
+        let time = if self.seconds {
+            format!(
+                "{:02}:{:02}:{:02} {}",
+                time_data.hour12, time_data.minute, time_data.second, time_data.meridiem
+            )
+        } else {
+            format!(
+                "{:02}:{:02} {}",
+                time_data.hour12, time_data.minute, time_data.meridiem
+            )
+        };
+        let mut lines = vec![ExplicitLine::new(time, 72.0, LineRole::Time)];
+
+        if self.date {
+            lines.push(ExplicitLine::new(time_data.format_date(), 24.0, LineRole::Detail));
+        }
+
+        let mut zone = vec![tz_name.to_string()];
+        if self.offset {
+            zone.push(time_data.format_utc_offset());
+        }
+        if self.dst_note {
+            let dst = if time_data.is_dst { "DST Active" } else { "Standard Time" };
+            zone.push(dst.to_string());
+        }
+        lines.push(ExplicitLine::new(zone.join(" · "), 18.0, LineRole::Detail));
+
+        if self.dst_note {
+            match &time_data.dst_change {
+                DstChange::Upcoming { instant, delta_minutes } => {
+                    let hours_until = (*instant - shared::now_utc()).num_hours();
+                    let direction = if *delta_minutes > 0 {
+                        "spring forward"
+                    } else {
+                        "fall back"
+                    };
+                    let warning = format!("DST change in {} hours ({})", hours_until, direction);
+                    lines.push(ExplicitLine::new(warning, 16.0, LineRole::Warning));
+                }
+                DstChange::JustOccurred { delta_minutes, .. } => {
+                    let direction = if *delta_minutes > 0 {
+                        "sprang forward"
+                    } else {
+                        "fell back"
+                    };
+                    let notice = format!("Clocks {} recently", direction);
+                    lines.push(ExplicitLine::new(notice, 16.0, LineRole::Notice));
+                }
+                DstChange::None => {}
+            }
+        }
+
+        lines.push(ExplicitLine::new("EXPLICIT MODE", 12.0, LineRole::Caption));
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time_data() -> TimeData {
+        let tz: chrono_tz::Tz = "America/New_York".parse().unwrap();
+        shared::compute_time_data(tz)
+    }
+
+    #[test]
+    fn test_default_layout_shows_everything() {
+        let time_data = time_data();
+        let lines = ExplicitLayout::default().lines(&time_data, "America/New_York");
+        assert_eq!(lines[0].role, LineRole::Time);
+        assert_eq!(lines[0].text.matches(':').count(), 2);
+        assert_eq!(lines[1].text, time_data.format_date());
+        assert!(lines[2].text.contains(&time_data.format_utc_offset()));
+        assert!(lines[2].text.contains("Time") || lines[2].text.contains("DST"));
+        assert_eq!(lines.last().unwrap().role, LineRole::Caption);
+    }
+
+    #[test]
+    fn test_hidden_elements_are_left_out() {
+        let time_data = time_data();
+        let layout = ExplicitLayout {
+            seconds: false,
+            date: false,
+            offset: false,
+            dst_note: false,
+            ..ExplicitLayout::default()
+        };
+        let lines = layout.lines(&time_data, "America/New_York");
+        assert_eq!(lines[0].text.matches(':').count(), 1);
+        assert_eq!(lines[1].text, "America/New_York");
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|line| line.role != LineRole::Warning));
+    }
+
+    #[test]
+    fn test_scale_is_clamped() {
+        let scale = |font_scale| ExplicitLayout { font_scale, ..ExplicitLayout::default() }.scale();
+        assert_eq!(scale(10.0), MAX_FONT_SCALE);
+        assert_eq!(scale(0.1), MIN_FONT_SCALE);
+        assert_eq!(scale(f32::NAN), 1.0);
+        assert_eq!(scale(1.5), 1.5);
+    }
+}
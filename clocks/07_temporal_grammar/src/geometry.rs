@@ -2,6 +2,9 @@
 //!
 //! Provides pure functions for computing the three visual layers (hour polygon,
 //! minute superellipse, second phase ring) and applying timezone/DST transforms.
+//!
+//! `GeometryParams` can also be edited by hand in the geometry inspector;
+//! the diagram is then drawn from the edited values instead of the time.
 
 use nannou::prelude::*;
 use shared::DstChange;
@@ -35,8 +38,11 @@ pub struct DstKnot {
     pub is_upcoming: bool,
 }
 
+/// Extra rotation of both layers while DST is in effect, in degrees
+const DST_ROTATION_DEG: f32 = 5.0;
+
 /// Computed geometry parameters for decode mode display
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GeometryParams {
     /// Hour value (1-12)
     pub hour: u32,
@@ -60,6 +66,90 @@ pub struct GeometryParams {
     pub is_dst: bool,
 }
 
+impl GeometryParams {
+    /// Set the hour along with the vertex count it maps to
+    pub fn set_hour(&mut self, hour: u32) {
+        self.hour = hour;
+        self.vertex_count = 3 + hour;
+    }
+
+    /// Set the minute along with the exponent and rotation it maps to
+    pub fn set_minute(&mut self, minute: u32) {
+        self.minute = minute;
+        self.exponent = get_superellipse_exponent(minute);
+        self.minute_rotation_deg = get_minute_rotation_deg(minute);
+    }
+
+    /// Set the second along with the phase it maps to
+    pub fn set_second(&mut self, second: u32) {
+        self.second = second;
+        self.phase_deg = (second as f32 / 60.0) * 360.0;
+    }
+
+    /// Turn DST on or off, adding or removing its extra rotation
+    pub fn set_dst(&mut self, is_dst: bool) {
+        if is_dst != self.is_dst {
+            let sign = if is_dst { 1.0 } else { -1.0 };
+            self.tz_rotation_deg += sign * DST_ROTATION_DEG;
+            self.is_dst = is_dst;
+        }
+    }
+}
+
+/// The continuous parameters both shape layers are built from
+///
+/// Each time state maps to one shape; transitions interpolate between two
+/// shapes, so every field is a float even where the time value is discrete.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiagramShape {
+    /// Hour polygon vertices (fractional while morphing)
+    pub vertex_count: f32,
+    /// Hour polygon rotation in radians
+    pub hour_rotation: f32,
+    /// Minute superellipse exponent
+    pub exponent: f32,
+    /// Minute superellipse rotation in radians
+    pub minute_rotation: f32,
+    /// Timezone (and DST) rotation of both layers in radians
+    pub tz_rotation: f32,
+    /// Timezone skew X factor
+    pub tz_skew_x: f32,
+    /// DST shear Y factor (minute layer only)
+    pub dst_shear_y: f32,
+}
+
+impl DiagramShape {
+    pub fn for_time(hour12: u32, minute: u32, offset_minutes: i32, is_dst: bool) -> Self {
+        Self::from_params(&compute_geometry_params(hour12, minute, 0, offset_minutes, is_dst))
+    }
+
+    /// The shape drawn for a set of geometry parameters, live or hand-edited
+    pub fn from_params(params: &GeometryParams) -> Self {
+        Self {
+            vertex_count: params.vertex_count as f32,
+            hour_rotation: (params.hour as f32 / 12.0) * 30.0_f32.to_radians(),
+            exponent: params.exponent,
+            minute_rotation: params.minute_rotation_deg.to_radians(),
+            tz_rotation: params.tz_rotation_deg.to_radians(),
+            tz_skew_x: params.tz_skew_x,
+            dst_shear_y: if params.is_dst { 0.06 } else { 0.0 },
+        }
+    }
+
+    /// The hour polygon with the timezone transform applied
+    pub fn hour_polygon(&self, min_dim: f32, center: Point2) -> Vec<Point2> {
+        let points = polygon_points(self.vertex_count, self.hour_rotation, min_dim, center);
+        transform_points(&points, self.tz_rotation, self.tz_skew_x, 0.0, center)
+    }
+
+    /// The minute superellipse with the timezone and DST transforms applied
+    pub fn minute_superellipse(&self, min_dim: f32, center: Point2, samples: usize) -> Vec<Point2> {
+        let points =
+            superellipse_points(self.exponent, self.minute_rotation, min_dim, center, samples);
+        transform_points(&points, self.tz_rotation, self.tz_skew_x, self.dst_shear_y, center)
+    }
+}
+
 /// Compute the hour polygon (foundation layer)
 ///
 /// Creates a regular polygon with `3 + hour12` vertices.
@@ -67,23 +157,37 @@ pub struct GeometryParams {
 /// - `min_dim`: minimum of canvas width/height
 /// - `center`: center point of the polygon
 pub fn compute_hour_polygon(h: u32, min_dim: f32, center: Point2) -> Vec<Point2> {
-    let vertex_count = 3 + h;
-    let r1 = 0.28 * min_dim;
-    
     // Rotation based on hour: (h / 12) * 30 degrees
     let rot_hour = (h as f32 / 12.0) * 30.0_f32.to_radians();
-    
-    let mut points = Vec::with_capacity(vertex_count as usize);
-    
-    for k in 0..vertex_count {
-        // Start at -90 degrees (12 o'clock position) + hour rotation
-        let theta = -PI / 2.0 + rot_hour + (k as f32) * (TAU / vertex_count as f32);
-        let x = center.x + r1 * theta.cos();
-        let y = center.y + r1 * theta.sin();
-        points.push(pt2(x, y));
-    }
-    
-    points
+    polygon_points((3 + h) as f32, rot_hour, min_dim, center)
+}
+
+/// A regular polygon with a possibly fractional vertex count
+///
+/// Between `n` and `n + 1` vertices the extra vertex grows out of the first
+/// one while the others spread to their new angles, so the count morphs
+/// smoothly. A whole count gives exactly that many vertices.
+pub fn polygon_points(
+    vertex_count: f32,
+    rotation: f32,
+    min_dim: f32,
+    center: Point2,
+) -> Vec<Point2> {
+    let r1 = 0.28 * min_dim;
+    let base = vertex_count.floor().max(3.0);
+    let growth = (vertex_count - base).clamp(0.0, 1.0);
+    let n = base as u32;
+    let count = if growth > 0.0 { n + 1 } else { n };
+
+    (0..count)
+        .map(|k| {
+            let before = k as f32 * TAU / n as f32;
+            let after = k as f32 * TAU / (n + 1) as f32;
+            // Start at -90 degrees (12 o'clock position) + hour rotation
+            let theta = -PI / 2.0 + rotation + before + (after - before) * growth;
+            pt2(center.x + r1 * theta.cos(), center.y + r1 * theta.sin())
+        })
+        .collect()
 }
 
 /// Compute the minute superellipse (tension skin layer)
@@ -94,15 +198,26 @@ pub fn compute_hour_polygon(h: u32, min_dim: f32, center: Point2) -> Vec<Point2>
 /// - `center`: center point
 /// - `samples`: number of points to sample (default 256)
 pub fn compute_superellipse(m: u32, min_dim: f32, center: Point2, samples: usize) -> Vec<Point2> {
-    let r2x = 0.40 * min_dim;
-    let r2y = 0.22 * min_dim;
-    
     // Exponent varies from 1.2 (m=0) to 4.0 (m=59)
-    let e = 1.2 + 2.8 * (m as f32 / 59.0);
+    let e = get_superellipse_exponent(m);
     
     // Start with the shape pointing at 12 o'clock (-30°), then rotate 360° clockwise over the hour
     let rot_min = -PI / 6.0 - (m as f32 / 60.0) * TAU;
-    
+
+    superellipse_points(e, rot_min, min_dim, center, samples)
+}
+
+/// A superellipse with exponent `e` rotated by `rot_min` radians
+pub fn superellipse_points(
+    e: f32,
+    rot_min: f32,
+    min_dim: f32,
+    center: Point2,
+    samples: usize,
+) -> Vec<Point2> {
+    let r2x = 0.40 * min_dim;
+    let r2y = 0.22 * min_dim;
+
     let mut points = Vec::with_capacity(samples);
     
     for i in 0..samples {
@@ -179,6 +294,15 @@ pub fn compute_phase_ring(
     }
 }
 
+/// The phase ring for hand-edited geometry parameters
+///
+/// The needle points at `phase_deg`, which need not match the second.
+pub fn phase_ring_for_params(params: &GeometryParams, min_dim: f32, center: Point2) -> PhaseRing {
+    let mut ring = compute_phase_ring(params.second, 0.0, min_dim, center, true);
+    ring.needle_angle = -PI / 2.0 - params.phase_deg.to_radians();
+    ring
+}
+
 /// Apply timezone reframing transform to a set of points
 ///
 /// - `points`: input points
@@ -191,36 +315,10 @@ pub fn apply_tz_transform(
     is_dst: bool,
     center: Point2,
 ) -> Vec<Point2> {
-    // tzRot = (offset / 60) * 7.5 degrees
-    let tz_rot = (offset_minutes as f32 / 60.0) * 7.5_f32.to_radians();
-    
+    // tzRot = (offset / 60) * 7.5 degrees, plus 5 degrees under DST;
     // tzSkewX = clamp((offset % 60) / 60, -1..1) * 0.10
-    let remainder = (offset_minutes % 60) as f32 / 60.0;
-    let tz_skew_x = remainder.clamp(-1.0, 1.0) * 0.10;
-    
-    // Additional DST transform
-    let dst_extra_rot = if is_dst { 5.0_f32.to_radians() } else { 0.0 };
-    let total_rot = tz_rot + dst_extra_rot;
-    
-    points
-        .iter()
-        .map(|p| {
-            // Translate to origin
-            let x = p.x - center.x;
-            let y = p.y - center.y;
-            
-            // Apply skew
-            let skewed_x = x + y * tz_skew_x;
-            let skewed_y = y;
-            
-            // Apply rotation
-            let rotated_x = skewed_x * total_rot.cos() - skewed_y * total_rot.sin();
-            let rotated_y = skewed_x * total_rot.sin() + skewed_y * total_rot.cos();
-            
-            // Translate back
-            pt2(center.x + rotated_x, center.y + rotated_y)
-        })
-        .collect()
+    let total_rot = get_tz_rotation_deg(offset_minutes, is_dst).to_radians();
+    transform_points(points, total_rot, get_tz_skew_x(offset_minutes), 0.0, center)
 }
 
 /// Apply timezone transform specifically to the minute layer (with DST shear)
@@ -230,14 +328,19 @@ pub fn apply_tz_transform_minute_layer(
     is_dst: bool,
     center: Point2,
 ) -> Vec<Point2> {
-    let tz_rot = (offset_minutes as f32 / 60.0) * 7.5_f32.to_radians();
-    let remainder = (offset_minutes % 60) as f32 / 60.0;
-    let tz_skew_x = remainder.clamp(-1.0, 1.0) * 0.10;
-    
-    let dst_extra_rot = if is_dst { 5.0_f32.to_radians() } else { 0.0 };
+    let total_rot = get_tz_rotation_deg(offset_minutes, is_dst).to_radians();
     let dst_shear_y = if is_dst { 0.06 } else { 0.0 };
-    let total_rot = tz_rot + dst_extra_rot;
-    
+    transform_points(points, total_rot, get_tz_skew_x(offset_minutes), dst_shear_y, center)
+}
+
+/// Skew X, shear Y, then rotate `points` about `center`
+pub fn transform_points(
+    points: &[Point2],
+    total_rot: f32,
+    tz_skew_x: f32,
+    dst_shear_y: f32,
+    center: Point2,
+) -> Vec<Point2> {
     points
         .iter()
         .map(|p| {
@@ -261,7 +364,7 @@ pub fn apply_tz_transform_minute_layer(
 /// Get timezone rotation in degrees
 pub fn get_tz_rotation_deg(offset_minutes: i32, is_dst: bool) -> f32 {
     let base = (offset_minutes as f32 / 60.0) * 7.5;
-    if is_dst { base + 5.0 } else { base }
+    if is_dst { base + DST_ROTATION_DEG } else { base }
 }
 
 /// Get timezone skew X factor
@@ -454,6 +557,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_polygon_morph_grows_one_vertex() {
+        let center = pt2(0.0, 0.0);
+        let pentagon = polygon_points(5.0, 0.0, 100.0, center);
+        let halfway = polygon_points(5.5, 0.0, 100.0, center);
+        let hexagon = polygon_points(6.0, 0.0, 100.0, center);
+        assert_eq!(pentagon.len(), 5);
+        assert_eq!(halfway.len(), 6);
+        assert_eq!(hexagon.len(), 6);
+
+        // The sixth vertex grows out of the first on its way to its place
+        assert_eq!(halfway[0], pentagon[0]);
+        let gap = |p: Point2| p.distance(pentagon[0]);
+        assert!(gap(halfway[5]) > 0.0 && gap(halfway[5]) < gap(hexagon[5]));
+    }
+
     #[test]
     fn test_superellipse_exponent_range() {
         let e_min = get_superellipse_exponent(0);
@@ -462,6 +581,25 @@ mod tests {
         assert!((e_max - 4.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_edited_params_follow_the_mapping() {
+        let live = compute_geometry_params(3, 15, 20, 60, true);
+        let mut edited = compute_geometry_params(7, 0, 0, 60, false);
+        edited.set_hour(3);
+        edited.set_minute(15);
+        edited.set_second(20);
+        edited.set_dst(true);
+        assert_eq!(edited, live);
+        assert_eq!(DiagramShape::from_params(&live), DiagramShape::for_time(3, 15, 60, true));
+
+        // A hand-set vertex count is drawn as set
+        edited.vertex_count = 9;
+        assert_eq!(DiagramShape::from_params(&edited).vertex_count, 9.0);
+        let ring = phase_ring_for_params(&edited, 100.0, pt2(0.0, 0.0));
+        assert_eq!(ring.highlighted_index, 20);
+        assert!((ring.needle_angle + PI / 2.0 + 120.0_f32.to_radians()).abs() < 1e-5);
+    }
+
     #[test]
     fn test_phase_ring_marks() {
         let ring = compute_phase_ring(30, 0.0, 100.0, pt2(0.0, 0.0), false);
@@ -0,0 +1,282 @@
+//! Instance module - one zone's diagram
+//!
+//! A `GrammarInstance` holds a zone's time data and the geometry computed
+//! from it. The clock keeps one for the home zone and, while comparing, a
+//! second for another zone at the same instant, so the two diagrams differ
+//! only by their timezone/DST topology.
+//!
+//! When the time data changes, the instance can tween from the shape it is
+//! showing to the new one; `advance_transition` rebuilds the two shape
+//! layers each frame until the tween ends.
+//!
+//! The geometry inspector can draw hand-edited parameters instead. The time
+//! data stays live underneath, so setting it again tweens back from the
+//! edited shape to the live mapping.
+
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use nannou::prelude::*;
+use shared::{compute_time_data_at, tr, tr_args, TimeData};
+
+use crate::geometry::{
+    apply_tz_transform, apply_tz_transform_minute_layer, compute_dst_knot,
+    compute_geometry_params, compute_hour_polygon, compute_phase_ring, compute_superellipse,
+    generate_diagram_description, phase_ring_for_params, DiagramShape, DstKnot, GeometryParams,
+    PhaseRing,
+};
+use crate::tween::{TransitionSettings, Tween};
+
+/// Base diagram size before the view zoom is applied
+pub const BASE_DIM: f32 = 600.0;
+
+/// Points sampled around the minute superellipse
+const SUPERELLIPSE_SAMPLES: usize = 256;
+
+/// A zone's time data and diagram geometry
+pub struct GrammarInstance {
+    pub zone: Tz,
+    pub time_data: TimeData,
+    pub geometry_params: GeometryParams,
+    pub hour_polygon: Vec<Point2>,
+    pub minute_superellipse: Vec<Point2>,
+    pub phase_ring: PhaseRing,
+    pub diagram_description: String,
+    /// The shape the time data maps to, where any tween ends
+    shape: DiagramShape,
+    /// Transition from the previous shape, while one is running
+    tween: Option<Tween>,
+}
+
+impl GrammarInstance {
+    pub fn new(zone: Tz, time_data: TimeData, reduced_motion: bool) -> Self {
+        let geometry_params = params_for(&time_data);
+        let (hour_polygon, minute_superellipse) = layers_for(&time_data);
+        Self {
+            zone,
+            hour_polygon,
+            minute_superellipse,
+            phase_ring: ring_for(&time_data, reduced_motion),
+            diagram_description: generate_diagram_description(&geometry_params, zone.name()),
+            shape: shape_for(&time_data),
+            tween: None,
+            geometry_params,
+            time_data,
+        }
+    }
+
+    /// The instance for `zone` at `instant`
+    pub fn at(zone: Tz, instant: DateTime<Utc>, reduced_motion: bool) -> Self {
+        Self::new(zone, compute_time_data_at(zone, instant), reduced_motion)
+    }
+
+    /// The instant this diagram shows
+    pub fn instant(&self) -> DateTime<Utc> {
+        self.time_data.local_datetime.with_timezone(&Utc)
+    }
+
+    /// Replace the time data and recompute all geometry from it, tweening
+    /// the shape layers from where they are unless motion is reduced
+    pub fn set_time_data(
+        &mut self,
+        time_data: TimeData,
+        reduced_motion: bool,
+        transition: &TransitionSettings,
+        now: Instant,
+    ) {
+        self.show(self.zone, time_data, reduced_motion, transition, now);
+    }
+
+    /// Switch to `zone` showing `time_data`, tweening like `set_time_data`
+    pub fn show(
+        &mut self,
+        zone: Tz,
+        time_data: TimeData,
+        reduced_motion: bool,
+        transition: &TransitionSettings,
+        now: Instant,
+    ) {
+        let from = self.displayed_shape(now);
+        *self = Self::new(zone, time_data, reduced_motion);
+        if !reduced_motion && from != self.shape {
+            self.tween = Some(Tween::new(from, self.shape, now, transition));
+            self.build_shape_layers(&from);
+        }
+    }
+
+    /// Whether a shape tween is still playing
+    pub fn is_transitioning(&self) -> bool {
+        self.tween.is_some()
+    }
+
+    /// The shape on screen at `now`, part way through any tween
+    fn displayed_shape(&self, now: Instant) -> DiagramShape {
+        match &self.tween {
+            Some(tween) => tween.shape_at(now),
+            None => self.shape,
+        }
+    }
+
+    /// Move a running tween on to `now`, ending it early under reduced motion
+    pub fn advance_transition(&mut self, now: Instant, reduced_motion: bool) {
+        let Some(tween) = self.tween else {
+            return;
+        };
+        if reduced_motion || tween.is_finished(now) {
+            (self.hour_polygon, self.minute_superellipse) = layers_for(&self.time_data);
+            self.tween = None;
+        } else {
+            self.build_shape_layers(&tween.shape_at(now));
+        }
+    }
+
+    fn build_shape_layers(&mut self, shape: &DiagramShape) {
+        let center = pt2(0.0, 0.0);
+        self.hour_polygon = shape.hour_polygon(BASE_DIM, center);
+        self.minute_superellipse =
+            shape.minute_superellipse(BASE_DIM, center, SUPERELLIPSE_SAMPLES);
+    }
+
+    /// Draw `params` instead of the geometry the time data maps to
+    pub fn show_params(&mut self, params: &GeometryParams) {
+        self.shape = DiagramShape::from_params(params);
+        self.tween = None;
+        let shape = self.shape;
+        self.build_shape_layers(&shape);
+        self.phase_ring = phase_ring_for_params(params, BASE_DIM, pt2(0.0, 0.0));
+        self.diagram_description = generate_diagram_description(params, self.zone.name());
+        self.geometry_params = params.clone();
+    }
+
+    /// Recompute only the phase ring (smooth second sweep between ticks)
+    pub fn recompute_phase_ring(&mut self, reduced_motion: bool) {
+        self.phase_ring = ring_for(&self.time_data, reduced_motion);
+    }
+
+    /// Advance the sub-second sweep to `now` without a full recompute
+    pub fn refresh_second_fraction(&mut self, now: DateTime<Utc>, reduced_motion: bool) {
+        self.time_data.refresh_second_fraction(now);
+        self.recompute_phase_ring(reduced_motion);
+    }
+
+    /// The DST knot for this zone, if a transition is near
+    pub fn dst_knot(&self, min_dim: f32, center: Point2, now: DateTime<Utc>) -> Option<DstKnot> {
+        compute_dst_knot(
+            &self.time_data.dst_change,
+            self.time_data.utc_offset_minutes,
+            self.time_data.is_dst,
+            min_dim,
+            center,
+            now,
+        )
+    }
+}
+
+fn params_for(time_data: &TimeData) -> GeometryParams {
+    compute_geometry_params(
+        time_data.hour12,
+        time_data.minute,
+        time_data.second,
+        time_data.utc_offset_minutes,
+        time_data.is_dst,
+    )
+}
+
+/// The hour polygon and minute superellipse at rest
+fn layers_for(time_data: &TimeData) -> (Vec<Point2>, Vec<Point2>) {
+    let center = pt2(0.0, 0.0);
+    let raw_polygon = compute_hour_polygon(time_data.hour12, BASE_DIM, center);
+    let raw_superellipse =
+        compute_superellipse(time_data.minute, BASE_DIM, center, SUPERELLIPSE_SAMPLES);
+    (
+        apply_tz_transform(
+            &raw_polygon,
+            time_data.utc_offset_minutes,
+            time_data.is_dst,
+            center,
+        ),
+        apply_tz_transform_minute_layer(
+            &raw_superellipse,
+            time_data.utc_offset_minutes,
+            time_data.is_dst,
+            center,
+        ),
+    )
+}
+
+fn shape_for(time_data: &TimeData) -> DiagramShape {
+    DiagramShape::for_time(
+        time_data.hour12,
+        time_data.minute,
+        time_data.utc_offset_minutes,
+        time_data.is_dst,
+    )
+}
+
+fn ring_for(time_data: &TimeData, reduced_motion: bool) -> PhaseRing {
+    compute_phase_ring(
+        time_data.second,
+        time_data.second_fraction,
+        BASE_DIM,
+        pt2(0.0, 0.0),
+        reduced_motion,
+    )
+}
+
+/// How `other` differs from `home`: "+9h ahead · DST differs"
+pub fn describe_difference(home: &TimeData, other: &TimeData) -> String {
+    let delta = other.utc_offset_minutes - home.utc_offset_minutes;
+    let mut text = if delta == 0 {
+        tr("Same offset").to_string()
+    } else {
+        let sign = if delta > 0 { '+' } else { '-' };
+        let (hours, minutes) = (delta.abs() / 60, delta.abs() % 60);
+        let amount = if minutes == 0 {
+            format!("{}{}h", sign, hours)
+        } else {
+            format!("{}{}h{:02}m", sign, hours, minutes)
+        };
+        let template = if delta > 0 { "{0} ahead" } else { "{0} behind" };
+        tr_args(template, &[&amount])
+    };
+    if home.is_dst != other.is_dst {
+        text.push_str(&format!(" · {}", tr("DST differs")));
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn instance(zone: &str) -> GrammarInstance {
+        // A July instant: Los Angeles and London on DST, Tokyo and Kolkata not
+        let instant = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        GrammarInstance::at(zone.parse().unwrap(), instant, true)
+    }
+
+    #[test]
+    fn test_instances_share_the_instant() {
+        let home = instance("America/Los_Angeles");
+        let other = instance("Asia/Tokyo");
+        assert_eq!(home.instant(), other.instant());
+        assert_eq!(home.time_data.hour12, 5);
+        assert_eq!(other.time_data.hour12, 9);
+        assert_eq!(other.hour_polygon.len(), 3 + 9);
+    }
+
+    #[test]
+    fn test_describe_difference() {
+        let la = instance("America/Los_Angeles");
+        let tokyo = instance("Asia/Tokyo");
+        let london = instance("Europe/London");
+        let kolkata = instance("Asia/Kolkata");
+
+        assert_eq!(describe_difference(&la.time_data, &tokyo.time_data), "+16h ahead · DST differs");
+        assert_eq!(describe_difference(&tokyo.time_data, &la.time_data), "-16h behind · DST differs");
+        assert_eq!(describe_difference(&london.time_data, &kolkata.time_data), "+4h30m ahead · DST differs");
+        assert_eq!(describe_difference(&la.time_data, &la.time_data), "Same offset");
+    }
+}
@@ -0,0 +1,102 @@
+//! Legend module - the live key shown in Decode Mode
+//!
+//! Decode Mode pins a legend panel to the corner of the diagram with one row
+//! per layer: a miniature of the layer as it stands, the value it encodes
+//! and the formula that maps time to shape. The rows are rebuilt from the
+//! geometry parameters every frame, so the legend follows the clock, a
+//! transition or hand edits in the geometry inspector. The rows are laid out
+//! here as text; `drawing.rs` paints the panel and the miniatures.
+
+use shared::tr_args;
+
+use crate::geometry::GeometryParams;
+
+/// Which part of the diagram a legend row describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegendLayer {
+    /// Hour polygon
+    Foundation,
+    /// Minute superellipse
+    Tension,
+    /// Second phase ring
+    Phase,
+    /// Timezone rotation and skew of both shape layers
+    Frame,
+}
+
+/// One row of the legend
+#[derive(Debug, Clone, PartialEq)]
+pub struct LegendRow {
+    pub layer: LegendLayer,
+    /// Layer name ("Hour polygon")
+    pub name: &'static str,
+    /// The layer's current value, called out beside its miniature
+    pub value: String,
+    /// How the value follows from the time, in one line
+    pub formula: &'static str,
+}
+
+/// The legend's rows for the diagram `params` describe, top to bottom
+pub fn legend_rows(params: &GeometryParams) -> Vec<LegendRow> {
+    let dst = if params.is_dst { " · DST" } else { "" };
+    vec![
+        LegendRow {
+            layer: LegendLayer::Foundation,
+            name: "Hour polygon",
+            value: tr_args("{0} sides · hour {1}", &[&params.vertex_count, &params.hour]),
+            formula: "V = 3 + hour",
+        },
+        LegendRow {
+            layer: LegendLayer::Tension,
+            name: "Minute superellipse",
+            value: format!(
+                "e = {:.2} · rot {:.0}° · min {}",
+                params.exponent, params.minute_rotation_deg, params.minute
+            ),
+            formula: "e = 1.2 + 2.8·m/59, rot = −30° − 6°·m",
+        },
+        LegendRow {
+            layer: LegendLayer::Phase,
+            name: "Second ring",
+            value: format!("φ = {:.0}° · sec {}", params.phase_deg, params.second),
+            formula: "φ = 6°·s",
+        },
+        LegendRow {
+            layer: LegendLayer::Frame,
+            name: "Timezone frame",
+            value: format!(
+                "tzRot {:.1}° · skew {:.3}{}",
+                params.tz_rotation_deg, params.tz_skew_x, dst
+            ),
+            formula: "tzRot = 7.5°·offset h (+5° DST)",
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::compute_geometry_params;
+
+    #[test]
+    fn test_rows_follow_the_params() {
+        // 5:45:15 in Kathmandu, UTC+05:45
+        let mut params = compute_geometry_params(5, 45, 15, 345, false);
+        let rows = legend_rows(&params);
+        let layers: Vec<LegendLayer> = rows.iter().map(|row| row.layer).collect();
+        assert_eq!(
+            layers,
+            [LegendLayer::Foundation, LegendLayer::Tension, LegendLayer::Phase, LegendLayer::Frame]
+        );
+        assert_eq!(rows[0].value, "8 sides · hour 5");
+        assert_eq!(rows[2].value, "φ = 90° · sec 15");
+        assert_eq!(rows[3].value, "tzRot 43.1° · skew 0.075");
+
+        // Hand edits show up on the next frame's rows
+        params.set_hour(9);
+        params.set_dst(true);
+        let rows = legend_rows(&params);
+        assert_eq!(rows[0].value, "12 sides · hour 9");
+        assert!(rows[3].value.ends_with("· DST"));
+    }
+}
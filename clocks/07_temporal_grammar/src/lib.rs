@@ -0,0 +1,2017 @@
+//! Temporal Grammar Clock
+//!
+//! A clock as a semantic field: time is expressed as relationships and
+//! transformations rather than digits. The primary experience is a living
+//! diagram whose geometry encodes hour/minute/second and whose topology
+//! encodes time zone + DST. Views (zone, pan, zoom and modes) can be saved
+//! as named presets and recalled with the number keys. An optional
+//! sonification plays the same grammar as pitch, timbre and ticks. Any
+//! instant can be rendered as a print-ready poster at a chosen page size.
+//! Explicit Mode shows a plain readout whose elements, text size and
+//! alignment are configurable, for use as a wall clock.
+
+mod atlas;
+mod drawing;
+mod explicit;
+mod geometry;
+mod instance;
+mod legend;
+mod poster;
+mod presets;
+mod quiz;
+mod sonify;
+mod svg;
+mod tween;
+mod ui;
+
+use std::time::Instant;
+
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use nannou::event::ModifiersState;
+use nannou::prelude::*;
+use nannou_egui::{self, Egui};
+use serde::{Deserialize, Serialize};
+use shared::{
+    compute_time_data, compute_time_data_at, report_save, tr, tr_args, ClipRecorder, ClipSettings,
+    ClipStep, CommandPalette, CommandRegistry, ConfigRecovery, ConfigSaver, Favorites, FocusRegions,
+    FollowSystemZone, FramePacer, KeyChord, Keymap, LanguageSetting, MotionPreference, QuietHours,
+    RecoveryChoice, SafePaletteSetting, ScreenReader, SnapshotProgress, SnapshotStep,
+    SystemMotionWatcher, SystemZoneWatcher, ThemeLibrary, TickGranularity, Ticker, TimeData,
+    TimeReveal, Validity, VisualIntensity, WindowPlacement, WindowTitle, WindowTitleClock,
+};
+
+use crate::explicit::ExplicitLayout;
+use crate::geometry::{apply_view_transform_points, GeometryParams, PhaseRing};
+use crate::instance::{describe_difference, GrammarInstance, BASE_DIM};
+use crate::poster::{parse_local_instant, PosterFormat, PosterSettings};
+use crate::presets::{clean_name, ViewPreset, MAX_PRESETS};
+use crate::quiz::{parse_guess, question_instant, DialTime, Quiz, QuizStats};
+use crate::sonify::{SonificationSettings, Sonifier};
+use crate::tween::TransitionSettings;
+use crate::ui::{PickerState, PosterState};
+
+const CLOCK_NAME: &str = "temporal_grammar";
+const DEFAULT_TZ: &str = "America/Los_Angeles";
+const WINDOW_TITLE: &str = "Temporal Grammar Clock";
+const SIDEBAR_WIDTH: f32 = 260.0;
+const TOUCH_HOLD_THRESHOLD_MS: u128 = 350;
+/// Diagram scale while two zones share the canvas
+const COMPARE_SCALE: f32 = 0.55;
+/// Palette names and number keys of the view presets, in slot order
+const PRESET_COMMANDS: [(&str, Key); MAX_PRESETS] = [
+    ("Apply view preset 1", Key::Key1),
+    ("Apply view preset 2", Key::Key2),
+    ("Apply view preset 3", Key::Key3),
+    ("Apply view preset 4", Key::Key4),
+    ("Apply view preset 5", Key::Key5),
+    ("Apply view preset 6", Key::Key6),
+    ("Apply view preset 7", Key::Key7),
+    ("Apply view preset 8", Key::Key8),
+    ("Apply view preset 9", Key::Key9),
+];
+
+/// Run the clock; returns only when the window is closed
+pub fn run() {
+    nannou::app(model).update(update).run();
+}
+
+/// Launcher entry for this clock
+pub struct TemporalGrammar;
+
+impl shared::ClockApp for TemporalGrammar {
+    fn id(&self) -> &'static str {
+        CLOCK_NAME
+    }
+
+    fn title(&self) -> &'static str {
+        "Temporal Grammar"
+    }
+
+    fn tagline(&self) -> &'static str {
+        "Time as geometry: polygons, superellipses and phase rings"
+    }
+
+    fn run(&self) {
+        run();
+    }
+}
+
+/// Toast notification for transient messages
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub created: Instant,
+    pub duration_secs: f32,
+}
+
+impl Toast {
+    pub fn new(message: String, duration_secs: f32) -> Self {
+        Self {
+            message,
+            created: Instant::now(),
+            duration_secs,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.created.elapsed().as_secs_f32() > self.duration_secs
+    }
+
+    pub fn alpha(&self) -> f32 {
+        let elapsed = self.created.elapsed().as_secs_f32();
+        if elapsed > self.duration_secs - 0.5 {
+            ((self.duration_secs - elapsed) / 0.5).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Focus region for keyboard navigation
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FocusRegion {
+    /// No explicit focus (initial state)
+    #[default]
+    None,
+    /// TZ control
+    TzControl,
+    /// DST status
+    DstStatus,
+    /// Truth Anchor hint
+    TruthAnchorHint,
+    /// Canvas (main diagram area)
+    Canvas,
+    /// Sidebar
+    Sidebar,
+}
+
+impl FocusRegion {
+    fn next(self) -> Self {
+        match self {
+            FocusRegion::None => FocusRegion::TzControl,
+            FocusRegion::TzControl => FocusRegion::DstStatus,
+            FocusRegion::DstStatus => FocusRegion::TruthAnchorHint,
+            FocusRegion::TruthAnchorHint => FocusRegion::Canvas,
+            FocusRegion::Canvas => FocusRegion::Sidebar,
+            FocusRegion::Sidebar => FocusRegion::TzControl,
+        }
+    }
+
+    fn prev(self) -> Self {
+        match self {
+            FocusRegion::None => FocusRegion::Sidebar,
+            FocusRegion::TzControl => FocusRegion::Sidebar,
+            FocusRegion::DstStatus => FocusRegion::TzControl,
+            FocusRegion::TruthAnchorHint => FocusRegion::DstStatus,
+            FocusRegion::Canvas => FocusRegion::TruthAnchorHint,
+            FocusRegion::Sidebar => FocusRegion::Canvas,
+        }
+    }
+}
+
+impl FocusRegions for FocusRegion {
+    /// `None` is no region, so it is not registered
+    const ALL: &'static [Self] = &[
+        FocusRegion::TzControl,
+        FocusRegion::DstStatus,
+        FocusRegion::TruthAnchorHint,
+        FocusRegion::Canvas,
+        FocusRegion::Sidebar,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            FocusRegion::None => "",
+            FocusRegion::TzControl => "Timezone control",
+            FocusRegion::DstStatus => "DST status",
+            FocusRegion::TruthAnchorHint => "Truth Anchor hint",
+            FocusRegion::Canvas => "Diagram canvas",
+            FocusRegion::Sidebar => "Sidebar",
+        }
+    }
+}
+
+/// Persisted configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Config {
+    selected_zone_id: String,
+    favorites: Vec<String>,
+    /// Keep `favorites` instead of following the series-wide list
+    #[serde(default)]
+    own_favorites: bool,
+    decode_mode: bool,
+    explicit_mode: bool,
+    /// Elements, text size and alignment of the Explicit Mode readout
+    #[serde(default)]
+    explicit_layout: ExplicitLayout,
+    reduced_motion: MotionPreference,
+    view_zoom: f32,
+    #[serde(default)]
+    quiz_stats: QuizStats,
+    /// Selected theme name; `None` uses the clock's own colors
+    #[serde(default)]
+    theme: Option<String>,
+    /// Zone drawn beside the home zone, if comparing
+    #[serde(default)]
+    comparison_zone_id: Option<String>,
+    /// Named views recalled with the number keys
+    #[serde(default)]
+    view_presets: Vec<ViewPreset>,
+    /// Duration and easing of shape transitions
+    #[serde(default)]
+    transition: TransitionSettings,
+    /// Hour, minute and second played as sound
+    #[serde(default)]
+    sonification: SonificationSettings,
+    /// Page size, format and legend of time posters
+    #[serde(default)]
+    poster: PosterSettings,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            selected_zone_id: DEFAULT_TZ.to_string(),
+            favorites: vec![
+                "America/New_York".to_string(),
+                "America/Los_Angeles".to_string(),
+                "Europe/London".to_string(),
+                "Asia/Tokyo".to_string(),
+            ],
+            own_favorites: false,
+            decode_mode: false,
+            explicit_mode: false,
+            explicit_layout: ExplicitLayout::default(),
+            reduced_motion: MotionPreference::Auto,
+            view_zoom: 1.0,
+            quiz_stats: QuizStats::default(),
+            theme: None,
+            comparison_zone_id: None,
+            view_presets: Vec::new(),
+            transition: TransitionSettings::default(),
+            sonification: SonificationSettings::default(),
+            poster: PosterSettings::default(),
+        }
+    }
+}
+
+impl shared::VersionedConfig for Config {
+    const VERSION: u32 = 2;
+
+    /// Version 2 names the reduced-motion choice; earlier files hold a bool
+    fn migrate(_from_version: u32, payload: &mut toml::Table) {
+        shared::migrate_reduced_motion(payload);
+    }
+}
+
+/// Application state
+pub struct Model {
+    // Time state and geometry for the home zone, and the zone beside it
+    pub home: GrammarInstance,
+    pub comparison: Option<GrammarInstance>,
+    pub favorites: Favorites,
+    /// A thumbnail per favorite zone, while the atlas is open
+    pub atlas: Option<Vec<GrammarInstance>>,
+
+    // Time manipulation
+    pub is_live: bool,
+    pub manual_time: DateTime<Utc>,
+
+    // View state (pan/zoom)
+    pub view_offset: Vec2,
+    pub view_zoom: f32,
+    pub is_panning: bool,
+    pub last_mouse_pos: Point2,
+    pub view_presets: Vec<ViewPreset>,
+    /// Name typed for the next preset save
+    pub preset_name_input: String,
+
+    // Interaction state
+    pub truth_anchor_active: bool,
+    pub truth_anchor_latched: bool, // For rotary input
+    pub truth_anchor_position: Option<Point2>,
+    pub mouse_press_start: Option<Instant>,
+    pub touch_start_time: Option<Instant>,
+    pub space_held: bool,
+    pub decode_mode: bool,
+    pub explicit_mode: bool,
+    /// What the Explicit Mode readout shows and how
+    pub explicit_layout: ExplicitLayout,
+    pub help_panel_open: bool,
+    pub geometry_inspector_open: bool,
+    /// Hand-edited geometry drawn instead of the live mapping, not persisted
+    pub geometry_override: Option<GeometryParams>,
+
+    // Accessibility
+    /// Reduced motion setting: follow the system, or override it
+    pub motion_preference: MotionPreference,
+    /// Reports the system's reduce-motion preference
+    system_motion: SystemMotionWatcher,
+    /// Whether motion is reduced, from the setting and the system
+    pub reduced_motion: bool,
+    /// How the diagram moves between consecutive states
+    pub transition: TransitionSettings,
+    /// Hour, minute and second played as sound
+    pub sonification: SonificationSettings,
+    /// Audio output, opened once sonification is first turned on (None
+    /// without the `audio` feature or a device)
+    sonifier: Option<Sonifier>,
+    /// Instant last ticked, so each new second ticks once
+    last_sonified: Option<DateTime<Utc>>,
+
+    // Shared quiet-hours schedule and what it allows right now
+    pub quiet_hours: QuietHours,
+    pub intensity: VisualIntensity,
+    /// Shared setting for the time in the window title
+    pub window_title_clock: WindowTitleClock,
+    /// Shared settings for clips recorded with Ctrl+Shift+R
+    pub clip_settings: ClipSettings,
+    /// Clip being recorded or saved, if any
+    pub clip_recorder: ClipRecorder,
+    /// Shared language for labels, dates and toasts
+    pub language: LanguageSetting,
+    /// Shared high-contrast or color-blind safe palette
+    pub safe_palette: SafePaletteSetting,
+    /// Keeps the window title's time current
+    window_title: WindowTitle,
+    /// Remembers the window's size, position and monitor
+    window_placement: WindowPlacement,
+    /// Shared setting for switching to the system zone when it changes
+    follow_system_zone: FollowSystemZone,
+    /// Reports changes to the computer's time zone
+    system_zone: SystemZoneWatcher,
+    /// Shared system-wide shortcut that shows the time from the background
+    time_reveal: TimeReveal,
+    /// Drops the frame rate while the clock sits idle
+    frame_pacer: FramePacer,
+    /// Writes config changes once they settle
+    config_saver: ConfigSaver,
+    /// Shown when the saved config couldn't be read
+    config_recovery: Option<ConfigRecovery>,
+
+    // Built-in and user themes; `theme` is `None` for the clock's own colors
+    pub themes: ThemeLibrary,
+    pub theme: Option<String>,
+
+    // UI state
+    pub picker_state: PickerState,
+    /// Time poster window, and the page it renders
+    pub poster_state: PosterState,
+    pub poster_settings: PosterSettings,
+    /// Ctrl+K command palette
+    command_palette: CommandPalette,
+    /// Actions listed in the command palette, and the chords they're bound to
+    keymap: Keymap,
+    pub focus_region: FocusRegion,
+    pub window_focused: bool,
+
+    // Decode quiz
+    pub quiz: Option<Quiz>,
+    pub quiz_stats: QuizStats,
+    pub quiz_input: String,
+    pub text_input_focused: bool,
+
+    // Toast notifications
+    pub toasts: Vec<Toast>,
+
+    // Error state
+    pub tz_error: bool,
+    pub last_valid_zone: Tz,
+
+    // Wall-clock ticker driving live recomputation
+    ticker: Ticker,
+    // Frame counter for `--snapshot` runs
+    snapshot: SnapshotProgress,
+
+    /// Screen reader view of the time, DST status and focus
+    screen_reader: ScreenReader,
+
+    // egui integration
+    egui: Egui,
+}
+
+impl Model {
+    /// Whether something on screen is moving on its own: a shape transition,
+    /// a toast or a clip being captured
+    fn is_animating(&self) -> bool {
+        let atlas = self.atlas.iter().flatten();
+        std::iter::once(&self.home)
+            .chain(self.comparison.as_ref())
+            .chain(atlas)
+            .any(GrammarInstance::is_transitioning)
+            || !self.toasts.is_empty()
+            || self.clip_recorder.is_recording()
+            || self.time_reveal.is_flashing()
+    }
+
+    /// Set a new timezone
+    pub fn set_timezone(&mut self, tz: Tz) {
+        let old_zone = self.home.zone;
+        let time_data = match self.quiz {
+            Some(quiz) => compute_time_data_at(tz, quiz.instant),
+            None => compute_time_data(tz),
+        };
+        let reduced_motion = self.motion_reduced();
+        self.home.show(tz, time_data, reduced_motion, &self.transition, Instant::now());
+        self.sync_comparison();
+
+        if self.home.time_data.validity == Validity::Ok {
+            self.last_valid_zone = tz;
+            self.tz_error = false;
+        } else {
+            self.tz_error = true;
+            self.show_toast(tr("Timezone data may be stale or missing").to_string());
+        }
+
+        if old_zone != tz {
+            if let Some(quiz) = self.quiz {
+                self.ask(quiz.instant);
+            }
+        }
+
+        save_config(self);
+    }
+
+    /// Try to set a timezone from a string
+    pub fn try_set_timezone(&mut self, tz_str: &str) {
+        match tz_str.parse::<Tz>() {
+            Ok(tz) => self.set_timezone(tz),
+            Err(_) => {
+                self.show_toast(tr_args("Invalid timezone: {0}. Reverting.", &[&tz_str]));
+                let zone = self.last_valid_zone;
+                let time_data = compute_time_data(zone);
+                let reduced_motion = self.motion_reduced();
+                self.home.show(zone, time_data, reduced_motion, &self.transition, Instant::now());
+                self.sync_comparison();
+            }
+        }
+    }
+
+    /// Show a toast notification
+    pub fn show_toast(&mut self, message: String) {
+        self.toasts.push(Toast::new(message, 4.0));
+    }
+
+    /// Prune expired toasts
+    pub fn prune_toasts(&mut self) {
+        self.toasts.retain(|t| !t.is_expired());
+    }
+
+    /// Toggle favorite status for a zone
+    pub fn toggle_favorite(&mut self, tz: Tz) {
+        self.favorites.toggle(tz);
+        save_config(self);
+    }
+
+    /// Cycle focus region
+    pub fn cycle_focus(&mut self, reverse: bool) {
+        self.focus_region = if reverse {
+            self.focus_region.prev()
+        } else {
+            self.focus_region.next()
+        };
+    }
+
+    /// Whether a quiz question is waiting for an answer
+    pub fn quiz_asking(&self) -> bool {
+        self.quiz.is_some_and(|q| q.is_asking())
+    }
+
+    /// Activate the Truth Anchor (show time overlay)
+    pub fn activate_truth_anchor(&mut self, position: Option<Point2>) {
+        if self.quiz_asking() {
+            return;
+        }
+        self.truth_anchor_active = true;
+        self.truth_anchor_position = position;
+    }
+
+    /// Deactivate the Truth Anchor
+    pub fn deactivate_truth_anchor(&mut self) {
+        if !self.truth_anchor_latched {
+            self.truth_anchor_active = false;
+            self.truth_anchor_position = None;
+        }
+    }
+
+    /// Toggle Truth Anchor latch (for rotary input)
+    pub fn toggle_truth_anchor_latch(&mut self) {
+        self.truth_anchor_latched = !self.truth_anchor_latched;
+        self.truth_anchor_active = self.truth_anchor_latched;
+        if !self.truth_anchor_latched {
+            self.truth_anchor_position = None;
+        }
+    }
+
+    /// Step time forward or backward
+    pub fn step_time(&mut self, seconds: i64) {
+        if self.quiz.is_some() {
+            return;
+        }
+        if self.is_live {
+            // Switch to manual mode, starting from current time
+            self.is_live = false;
+            self.manual_time = shared::now_utc();
+        }
+        self.manual_time += Duration::seconds(seconds);
+        self.set_time_data(compute_time_data_at(self.home.zone, self.manual_time));
+    }
+
+    /// Return to live time
+    pub fn return_to_live(&mut self) {
+        self.quiz = None;
+        self.is_live = true;
+        self.set_time_data(compute_time_data(self.home.zone));
+    }
+
+    /// Start the decode quiz, or stop it and return to live time
+    pub fn toggle_quiz(&mut self) {
+        if self.quiz.is_some() {
+            self.return_to_live();
+        } else {
+            self.next_question();
+        }
+    }
+
+    /// Freeze the diagram on a fresh random instant and hide the answer
+    pub fn next_question(&mut self) {
+        self.ask(question_instant(shared::now_utc(), random_f64()));
+    }
+
+    fn ask(&mut self, instant: DateTime<Utc>) {
+        // Edited geometry would hide the question
+        self.geometry_override = None;
+        self.is_live = false;
+        self.manual_time = instant;
+        self.set_time_data(compute_time_data_at(self.home.zone, instant));
+
+        self.quiz = Some(Quiz::new(
+            instant,
+            DialTime {
+                hour12: self.home.time_data.hour12,
+                minute: self.home.time_data.minute,
+                second: Some(self.home.time_data.second),
+            },
+        ));
+        self.quiz_input.clear();
+        self.truth_anchor_latched = false;
+        self.truth_anchor_active = false;
+        self.truth_anchor_position = None;
+    }
+
+    /// Score the typed guess against the question
+    pub fn submit_guess(&mut self) {
+        let Some(quiz) = self.quiz.as_mut() else {
+            return;
+        };
+        let guess = match parse_guess(&self.quiz_input) {
+            Ok(guess) => guess,
+            Err(e) => {
+                self.show_toast(e);
+                return;
+            }
+        };
+        if quiz.submit(guess, &mut self.quiz_stats).is_some() {
+            save_config(self);
+        }
+    }
+
+    /// Clear the quiz score and streaks
+    pub fn reset_quiz_stats(&mut self) {
+        self.quiz_stats = QuizStats::default();
+        save_config(self);
+    }
+
+    /// Whether the phase sweep is off, by preference or quiet hours
+    pub fn motion_reduced(&self) -> bool {
+        self.reduced_motion || !self.intensity.animations
+    }
+
+    /// Apply a reduced-motion setting against the current system preference
+    pub fn set_motion_preference(&mut self, preference: MotionPreference) {
+        self.motion_preference = preference;
+        self.reduced_motion = preference.resolve(self.system_motion.reduced());
+    }
+
+    /// Show `time_data` for the home zone, moving the comparison along
+    pub fn set_time_data(&mut self, time_data: TimeData) {
+        let reduced_motion = self.motion_reduced();
+        self.home.set_time_data(time_data, reduced_motion, &self.transition, Instant::now());
+        self.sync_comparison();
+    }
+
+    /// Recompute the comparison and atlas diagrams for the home diagram's
+    /// instant
+    fn sync_comparison(&mut self) {
+        let reduced_motion = self.motion_reduced();
+        let instant = self.home.instant();
+        if let Some(comparison) = &mut self.comparison {
+            let time_data = compute_time_data_at(comparison.zone, instant);
+            comparison.set_time_data(time_data, reduced_motion, &self.transition, Instant::now());
+        }
+
+        // Favorites starred or unstarred meanwhile rebuild the atlas
+        let Some(atlas) = &mut self.atlas else {
+            return;
+        };
+        if !atlas.iter().map(|i| i.zone).eq(self.favorites.iter().copied()) {
+            *atlas = self
+                .favorites
+                .iter()
+                .map(|&tz| GrammarInstance::at(tz, instant, reduced_motion))
+                .collect();
+            return;
+        }
+        for instance in atlas {
+            let time_data = compute_time_data_at(instance.zone, instant);
+            instance.set_time_data(time_data, reduced_motion, &self.transition, Instant::now());
+        }
+    }
+
+    /// Open or close the geometry inspector; closing drops any edits
+    pub fn toggle_geometry_inspector(&mut self) {
+        self.geometry_inspector_open = !self.geometry_inspector_open;
+        if !self.geometry_inspector_open {
+            self.set_geometry_override(None);
+        }
+    }
+
+    /// Draw hand-edited geometry, or tween back to the live mapping with `None`
+    pub fn set_geometry_override(&mut self, params: Option<GeometryParams>) {
+        let was_detached = self.geometry_override.is_some();
+        self.geometry_override = params;
+        match &self.geometry_override {
+            Some(params) => self.home.show_params(params),
+            None if was_detached => self.set_time_data(self.home.time_data.clone()),
+            None => {}
+        }
+    }
+
+    /// Keep hand-edited geometry on screen over this frame's live updates
+    fn apply_geometry_override(&mut self) {
+        if let Some(params) = &self.geometry_override {
+            self.home.show_params(params);
+        }
+    }
+
+    /// Move running shape transitions on to this frame
+    pub fn advance_transitions(&mut self) {
+        let now = Instant::now();
+        let reduced_motion = self.motion_reduced();
+        let atlas = self.atlas.iter_mut().flatten();
+        for instance in std::iter::once(&mut self.home).chain(self.comparison.as_mut()).chain(atlas) {
+            instance.advance_transition(now, reduced_motion);
+        }
+    }
+
+    /// Advance the phase rings' smooth second sweep between ticks
+    pub fn refresh_second_fraction(&mut self) {
+        let now = shared::now_utc();
+        let reduced_motion = self.motion_reduced();
+        let atlas = self.atlas.iter_mut().flatten();
+        for instance in std::iter::once(&mut self.home).chain(self.comparison.as_mut()).chain(atlas) {
+            instance.refresh_second_fraction(now, reduced_motion);
+        }
+    }
+
+    /// Draw `tz` beside the home zone, or stop comparing with `None`
+    pub fn set_comparison(&mut self, tz: Option<Tz>) {
+        self.comparison =
+            tz.map(|tz| GrammarInstance::at(tz, self.home.instant(), self.motion_reduced()));
+        save_config(self);
+    }
+
+    /// Replace the shared quiet-hours schedule
+    pub fn set_quiet_hours(&mut self, quiet_hours: QuietHours) {
+        self.quiet_hours = quiet_hours;
+        self.intensity = self.quiet_hours.intensity_at(self.home.zone, shared::now_utc());
+        report_save("quiet hours", self.quiet_hours.save(), |message| self.show_toast(message));
+    }
+
+    /// Change the sonification settings, opening the audio output if needed
+    pub fn set_sonification(&mut self, settings: SonificationSettings) {
+        if settings.enabled && self.sonifier.is_none() {
+            self.sonifier = Sonifier::new();
+        }
+        self.sonification = settings;
+        save_config(self);
+    }
+
+    /// Turn sonification on or off
+    pub fn toggle_sonification(&mut self) {
+        let settings = SonificationSettings {
+            enabled: !self.sonification.enabled,
+            ..self.sonification
+        };
+        self.set_sonification(settings);
+        let message = match (settings.enabled, &self.sonifier) {
+            (false, _) => tr("Sonification off").to_string(),
+            (true, Some(_)) => tr("Sonification on").to_string(),
+            (true, None) => {
+                tr_args("Sonification unavailable: {0}", &[&tr(sonify::UNAVAILABLE_REASON)])
+            }
+        };
+        self.show_toast(message);
+    }
+
+    /// Play the home diagram's time, ticking once per new second; quiet
+    /// hours silence it
+    fn sonify(&mut self) {
+        let Some(sonifier) = &self.sonifier else {
+            return;
+        };
+        let voice = sonify::voice(&self.home.time_data, &self.sonification)
+            .filter(|_| self.intensity.sounds);
+        sonifier.set_voice(voice);
+
+        let instant = self.home.instant();
+        if voice.is_some() && self.sonification.ticks && self.last_sonified != Some(instant) {
+            sonifier.tick();
+        }
+        self.last_sonified = Some(instant);
+    }
+
+    /// Stop comparing, or compare with the first favorite that isn't home
+    pub fn toggle_comparison(&mut self) {
+        if self.comparison.is_some() {
+            self.set_comparison(None);
+            return;
+        }
+        match self.favorites.iter().find(|&&tz| tz != self.home.zone) {
+            Some(&tz) => self.set_comparison(Some(tz)),
+            None => self.show_toast(tr("Add a favorite zone to compare with").to_string()),
+        }
+    }
+
+    /// Open the atlas of every favorite zone, or close it
+    pub fn toggle_atlas(&mut self) {
+        if self.atlas.take().is_some() {
+            return;
+        }
+        if self.favorites.is_empty() {
+            self.show_toast(tr("Add a favorite zone to see the atlas").to_string());
+            return;
+        }
+        self.atlas = Some(Vec::new());
+        self.sync_comparison();
+    }
+
+    /// Export the diagram for the displayed instant as a standalone SVG
+    pub fn export_svg(&mut self) {
+        let now_utc = if self.is_live { shared::now_utc() } else { self.manual_time };
+        let home = &self.home;
+        let dst_knot = home.dst_knot(BASE_DIM, pt2(0.0, 0.0), now_utc);
+        let time_data = &home.time_data;
+        let caption = format!("{} · {}", time_data.format_time(), time_data.format_date());
+        let subcaption = format!("{} · {}", home.zone.name(), time_data.format_utc_offset());
+
+        let document = svg::render_svg(&svg::SvgDiagram {
+            hour_polygon: &home.hour_polygon,
+            minute_superellipse: &home.minute_superellipse,
+            phase_ring: &home.phase_ring,
+            dst_knot: dst_knot.as_ref(),
+            caption: &caption,
+            subcaption: &subcaption,
+            description: &home.diagram_description,
+        });
+
+        let label = time_data.local_datetime.format("%Y%m%dT%H%M%S").to_string();
+        match shared::write_export(CLOCK_NAME, &label, "svg", document.as_bytes()) {
+            Ok(path) => self.show_toast(tr_args("Exported SVG to {0}", &[&path.display()])),
+            Err(e) => self.show_toast(tr_args("SVG export failed: {0}", &[&e])),
+        }
+    }
+
+    /// Open the time poster window on the displayed instant
+    pub fn open_poster(&mut self) {
+        self.poster_state.open(&self.home.time_data);
+    }
+
+    /// Render the poster window's instant in the home zone and write it
+    /// to the export folder
+    pub fn render_poster(&mut self) {
+        let zone = self.home.zone;
+        let instant = match parse_local_instant(&self.poster_state.instant_input, zone) {
+            Ok(instant) => instant,
+            Err(e) => {
+                self.poster_state.error = Some(e);
+                return;
+            }
+        };
+        self.poster_state.error = None;
+
+        // Reduced motion rests the needle on the second, as print should
+        let instance = GrammarInstance::at(zone, instant, true);
+        let dst_knot = instance.dst_knot(BASE_DIM, pt2(0.0, 0.0), instant);
+        let settings = self.poster_settings;
+        let poster =
+            poster::compose(&instance, dst_knot.as_ref(), &self.poster_state.caption, &settings);
+        let contents = match settings.format {
+            PosterFormat::Png => poster.to_png(),
+            PosterFormat::Svg => Ok(poster.to_svg().into_bytes()),
+        };
+
+        let label = instance.time_data.local_datetime.format("poster_%Y%m%dT%H%M%S").to_string();
+        let format = settings.format;
+        let written = contents.and_then(|contents| {
+            shared::write_export(CLOCK_NAME, &label, format.extension(), &contents)
+                .map_err(|e| e.to_string())
+        });
+        match written {
+            Ok(path) => {
+                let message = "Saved {0} poster to {1}";
+                self.show_toast(tr_args(message, &[&format.label(), &path.display()]))
+            }
+            Err(e) => self.show_toast(tr_args("Poster failed: {0}", &[&e])),
+        }
+    }
+
+    /// Apply pan delta
+    pub fn pan(&mut self, delta: Vec2) {
+        self.view_offset += delta;
+    }
+
+    /// Apply zoom
+    pub fn zoom(&mut self, factor: f32) {
+        self.view_zoom = (self.view_zoom * factor).clamp(0.3, 3.0);
+        save_config(self);
+    }
+
+    /// Reset view to default
+    pub fn reset_view(&mut self) {
+        self.view_offset = vec2(0.0, 0.0);
+        self.view_zoom = 1.0;
+        save_config(self);
+    }
+
+    /// Save the current view under the typed name, replacing a preset
+    /// with the same name
+    pub fn save_view_preset(&mut self) {
+        let Some(name) = clean_name(&self.preset_name_input) else {
+            self.show_toast(tr("Name the preset first").to_string());
+            return;
+        };
+        let preset = ViewPreset {
+            name: name.clone(),
+            zone_id: self.home.zone.name().to_string(),
+            view_zoom: self.view_zoom,
+            view_offset: [self.view_offset.x, self.view_offset.y],
+            decode_mode: self.decode_mode,
+            explicit_mode: self.explicit_mode,
+        };
+        match presets::upsert(&mut self.view_presets, preset) {
+            Ok(index) => {
+                self.preset_name_input.clear();
+                save_config(self);
+                self.show_toast(tr_args("Saved view \"{0}\" to key {1}", &[&name, &(index + 1)]));
+            }
+            Err(e) => self.show_toast(e),
+        }
+    }
+
+    /// Switch to the preset in slot `index`, if there is one
+    pub fn apply_view_preset(&mut self, index: usize) {
+        let Some(preset) = self.view_presets.get(index).cloned() else {
+            self.show_toast(tr_args("No view preset on key {0}", &[&(index + 1)]));
+            return;
+        };
+        self.view_zoom = preset.view_zoom.clamp(0.3, 3.0);
+        self.view_offset = vec2(preset.view_offset[0], preset.view_offset[1]);
+        self.decode_mode = preset.decode_mode;
+        self.explicit_mode = preset.explicit_mode;
+
+        match preset.zone_id.parse::<Tz>() {
+            Ok(tz) if tz != self.home.zone => self.set_timezone(tz),
+            Ok(_) => save_config(self),
+            Err(_) => {
+                self.show_toast(tr_args("Unknown zone {0} in preset", &[&preset.zone_id]));
+                save_config(self);
+                return;
+            }
+        }
+        self.show_toast(tr_args("View: {0}", &[&preset.name]));
+    }
+
+    /// Forget the preset in slot `index`; later presets move up a key
+    pub fn delete_view_preset(&mut self, index: usize) {
+        if index < self.view_presets.len() {
+            self.view_presets.remove(index);
+            save_config(self);
+        }
+    }
+}
+
+fn save_config(model: &Model) {
+    let config = Config {
+        selected_zone_id: model.home.zone.name().to_string(),
+        favorites: model.favorites.own_ids(),
+        own_favorites: model.favorites.uses_own(),
+        decode_mode: model.decode_mode,
+        explicit_mode: model.explicit_mode,
+        explicit_layout: model.explicit_layout,
+        reduced_motion: model.motion_preference,
+        view_zoom: model.view_zoom,
+        quiz_stats: model.quiz_stats,
+        theme: model.theme.clone(),
+        comparison_zone_id: model.comparison.as_ref().map(|c| c.zone.name().to_string()),
+        view_presets: model.view_presets.clone(),
+        transition: model.transition,
+        sonification: model.sonification,
+        poster: model.poster_settings,
+    };
+    model.config_saver.request(&config);
+}
+
+fn model(app: &App) -> Model {
+    app.set_exit_on_escape(false);
+
+    let window_id = app
+        .new_window()
+        .title(WINDOW_TITLE)
+        .size(1000, 750)
+        .min_size(700, 500)
+        .view(view)
+        .key_pressed(key_pressed)
+        .key_released(key_released)
+        .mouse_pressed(mouse_pressed)
+        .mouse_released(mouse_released)
+        .mouse_moved(mouse_moved)
+        .mouse_wheel(mouse_wheel)
+        .raw_event(raw_window_event)
+        .visible(false)
+        .build()
+        .unwrap();
+
+    let window = app.window(window_id).unwrap();
+    let egui = Egui::from_window(&window);
+    shared::install_fallback_fonts(egui.ctx());
+
+    // Load configuration, noting an unreadable file for the recovery prompt
+    let (config, config_recovery) = shared::load_config_or_recover(CLOCK_NAME);
+    let config: Config = config.unwrap_or_default();
+
+    // Parse timezone from config
+    let selected_zone: Tz = config
+        .selected_zone_id
+        .parse()
+        .unwrap_or_else(|_| DEFAULT_TZ.parse().unwrap());
+
+    // The shared favorites, or this clock's own
+    let favorites = Favorites::load(&config.favorites, config.own_favorites);
+
+    let themes = ThemeLibrary::load();
+    let theme = themes.activate(config.theme.as_deref());
+    let system_motion = SystemMotionWatcher::spawn();
+    let reduced_motion = config.reduced_motion.resolve(system_motion.reduced());
+
+    // Initial diagrams (recomputed on the first tick)
+    let home =
+        GrammarInstance::new(selected_zone, compute_time_data(selected_zone), reduced_motion);
+    let comparison = config
+        .comparison_zone_id
+        .and_then(|id| id.parse::<Tz>().ok())
+        .map(|tz| GrammarInstance::at(tz, home.instant(), reduced_motion));
+
+    // Screen reader tree, attached before the window is first shown
+    let screen_reader = ScreenReader::attach(
+        window.winit_window(),
+        "Temporal Grammar Clock",
+        &home.time_data,
+        Some(FocusRegion::default()),
+    );
+
+    // Reopen where the window was left, or where the command line asks
+    let mut window_placement = WindowPlacement::load(CLOCK_NAME);
+    window_placement.restore(window.winit_window());
+    window.set_visible(shared::snapshot_request().is_none());
+
+    let wake_proxy = app.create_proxy();
+    Model {
+        home,
+        comparison,
+        favorites,
+        atlas: None,
+        is_live: true,
+        manual_time: shared::now_utc(),
+        view_offset: vec2(0.0, 0.0),
+        view_zoom: config.view_zoom,
+        is_panning: false,
+        last_mouse_pos: pt2(0.0, 0.0),
+        view_presets: config.view_presets,
+        preset_name_input: String::new(),
+        truth_anchor_active: false,
+        truth_anchor_latched: false,
+        truth_anchor_position: None,
+        mouse_press_start: None,
+        touch_start_time: None,
+        space_held: false,
+        decode_mode: config.decode_mode,
+        explicit_mode: config.explicit_mode,
+        explicit_layout: config.explicit_layout,
+        help_panel_open: false,
+        geometry_inspector_open: false,
+        geometry_override: None,
+        motion_preference: config.reduced_motion,
+        reduced_motion,
+        system_motion,
+        transition: config.transition,
+        sonification: config.sonification,
+        sonifier: config.sonification.enabled.then(Sonifier::new).flatten(),
+        last_sonified: None,
+        quiet_hours: QuietHours::load(),
+        intensity: VisualIntensity::FULL,
+        window_title_clock: WindowTitleClock::load(),
+        clip_settings: ClipSettings::load(),
+        clip_recorder: ClipRecorder::new(CLOCK_NAME),
+        language: LanguageSetting::load(),
+        safe_palette: SafePaletteSetting::load(),
+        window_title: WindowTitle::new(WINDOW_TITLE),
+        window_placement,
+        follow_system_zone: FollowSystemZone::load(),
+        system_zone: SystemZoneWatcher::spawn(),
+        time_reveal: TimeReveal::load(),
+        frame_pacer: FramePacer::spawn(move || wake_proxy.wakeup().is_ok()),
+        config_saver: ConfigSaver::new(CLOCK_NAME),
+        config_recovery,
+        themes,
+        theme,
+        picker_state: PickerState::default(),
+        poster_state: PosterState::default(),
+        poster_settings: config.poster,
+        command_palette: CommandPalette::default(),
+        keymap: Keymap::load(CLOCK_NAME, commands()),
+        focus_region: FocusRegion::default(),
+        window_focused: true,
+        quiz: None,
+        quiz_stats: config.quiz_stats,
+        quiz_input: String::new(),
+        text_input_focused: false,
+        toasts: Vec::new(),
+        tz_error: false,
+        last_valid_zone: selected_zone,
+        ticker: Ticker::spawn(TickGranularity::Second),
+        snapshot: SnapshotProgress::default(),
+        screen_reader,
+        egui,
+    }
+}
+
+/// Why sonification is silent while turned on, if it is
+fn sonification_status(model: &Model) -> Option<&'static str> {
+    if !model.sonification.enabled {
+        None
+    } else if model.sonifier.is_none() {
+        Some(sonify::UNAVAILABLE_REASON)
+    } else if !model.intensity.sounds {
+        Some("Silent during quiet hours")
+    } else {
+        None
+    }
+}
+
+/// Drive a `--snapshot` run: capture once the frame has settled, then quit
+fn advance_snapshot(app: &App, progress: &mut SnapshotProgress) {
+    let Some(request) = shared::snapshot_request() else {
+        return;
+    };
+    match progress.advance() {
+        SnapshotStep::Settle => {}
+        SnapshotStep::Capture => app.main_window().capture_frame(&request.path),
+        SnapshotStep::Finish => {
+            if app.main_window().await_capture_frame_jobs().is_err() {
+                eprintln!("Timed out writing snapshot to {}", request.path.display());
+                std::process::exit(1);
+            }
+            println!("Saved snapshot to {}", request.path.display());
+            app.quit();
+        }
+    }
+}
+
+/// Capture frames for a clip being recorded, and report the clip once saved
+fn advance_clip(app: &App, model: &mut Model) {
+    match model.clip_recorder.advance(Instant::now()) {
+        ClipStep::Idle => {}
+        ClipStep::Capture(path) => app.main_window().capture_frame(path),
+        ClipStep::Finish => {
+            let written = app.main_window().await_capture_frame_jobs().is_ok();
+            model.clip_recorder.encode(written);
+        }
+    }
+    if let Some(message) = model.clip_recorder.poll() {
+        model.show_toast(message);
+    }
+}
+
+/// Let the event loop wait between second boundaries while the clock is idle
+fn pace_frames(app: &App, model: &mut Model, now: Instant) {
+    let animating = model.is_animating();
+    let loop_mode = if model.frame_pacer.pace(animating, now) {
+        LoopMode::Wait
+    } else {
+        LoopMode::RefreshSync
+    };
+    app.set_loop_mode(loop_mode);
+}
+
+/// Act on the config recovery prompt: restart on the backup, or carry on
+/// with the defaults
+fn recover_config(app: &App, model: &mut Model, choice: RecoveryChoice) {
+    let Some(recovery) = model.config_recovery.take() else {
+        return;
+    };
+    if choice == RecoveryChoice::Restore {
+        match recovery.restore(&model.config_saver) {
+            Ok(()) => app.quit(),
+            Err(message) => model.show_toast(message),
+        }
+    }
+}
+
+fn update(app: &App, model: &mut Model, update: Update) {
+    advance_snapshot(app, &mut model.snapshot);
+    advance_clip(app, model);
+
+    // Update time data only when in live mode: full geometry on each
+    // wall-clock tick, phase ring sweep in between
+    let tick = model.ticker.poll();
+    if tick.is_some() {
+        // Quiet hours follow the real time, also while stepping manually
+        model.intensity = model.quiet_hours.intensity_at(model.home.zone, shared::now_utc());
+    }
+    if model.is_live {
+        if tick.is_some() {
+            model.set_time_data(compute_time_data(model.home.zone));
+        } else if !model.motion_reduced() {
+            model.refresh_second_fraction();
+        }
+    }
+    model.advance_transitions();
+    model.apply_geometry_override();
+    model.sonify();
+
+    // Prune expired toasts
+    model.prune_toasts();
+
+    let sonification_status = sonification_status(model);
+    // The geometry inspector would give a quiz answer away
+    let inspector_open = model.geometry_inspector_open;
+    let show_inspector = inspector_open && !model.quiz_asking();
+    // The poster window opens on the displayed instant, so it waits too
+    let show_poster = model.poster_state.is_open && !model.quiz_asking();
+
+    // Begin egui frame
+    model.egui.set_elapsed_time(update.since_start);
+    let ctx = model.egui.begin_frame();
+
+    // Draw sidebar UI
+    let ui_result = ui::draw_sidebar(
+        &ctx,
+        &mut model.picker_state,
+        model.home.zone,
+        &model.favorites,
+        model.comparison.as_ref().map(|c| c.zone),
+        model.atlas.is_some(),
+        &model.home.time_data,
+        model.decode_mode,
+        model.explicit_mode,
+        model.explicit_layout,
+        model.motion_preference,
+        model.system_motion.reduced(),
+        model.transition,
+        model.sonification,
+        sonification_status,
+        &model.home.diagram_description,
+        model.is_live,
+        model.quiz.as_ref(),
+        &model.quiz_stats,
+        &mut model.quiz_input,
+        &model.quiet_hours,
+        model.window_title_clock,
+        model.clip_settings,
+        model.language,
+        model.safe_palette,
+        model.follow_system_zone,
+        &model.time_reveal.setting,
+        model.time_reveal.status(),
+        model.favorites.uses_own(),
+        &model.keymap,
+        &model.themes,
+        model.theme.as_deref(),
+        &model.view_presets,
+        &mut model.preset_name_input,
+    );
+
+    // Draw the geometry inspector (if open)
+    let inspector_result = if show_inspector {
+        ui::draw_geometry_inspector(
+            &ctx,
+            &mut model.geometry_inspector_open,
+            &model.home.geometry_params,
+            model.geometry_override.is_some(),
+        )
+    } else {
+        ui::InspectorResult::default()
+    };
+
+    // Draw the time poster window (if open)
+    let poster_result = if show_poster {
+        ui::draw_poster_window(
+            &ctx,
+            &mut model.poster_state,
+            model.poster_settings,
+            model.home.zone,
+        )
+    } else {
+        ui::PosterResult::default()
+    };
+
+    // Draw the time card while the reveal shortcut flashes the window
+    model.time_reveal.show(&ctx, &model.home.time_data);
+
+    // Offer the backup if the saved config couldn't be read
+    let recovery_choice = model.config_recovery.as_ref().and_then(|r| r.show(&ctx));
+
+    // Draw command palette (if open)
+    let palette_choice = model.command_palette.show(&ctx, model.keymap.registry());
+
+    model.text_input_focused = ctx.wants_keyboard_input();
+    drop(ctx);
+    if let Some(choice) = recovery_choice {
+        recover_config(app, model, choice);
+    }
+
+    // Apply UI results
+    if let Some(tz) = ui_result.set_timezone {
+        model.set_timezone(tz);
+    }
+    if let Some(tz) = ui_result.toggle_favorite {
+        model.toggle_favorite(tz);
+    }
+    if ui_result.toggle_geometry_inspector {
+        model.toggle_geometry_inspector();
+    }
+    if inspector_open && !model.geometry_inspector_open {
+        // Closed with the window's own button
+        model.set_geometry_override(None);
+    }
+    if let Some(params) = inspector_result.set_override {
+        model.set_geometry_override(Some(params));
+    }
+    if inspector_result.return_to_live_mapping {
+        model.set_geometry_override(None);
+    }
+    if ui_result.toggle_decode_mode {
+        model.decode_mode = !model.decode_mode;
+        save_config(model);
+    }
+    if ui_result.toggle_explicit_mode {
+        model.explicit_mode = !model.explicit_mode;
+        save_config(model);
+    }
+    if let Some(layout) = ui_result.set_explicit_layout {
+        model.explicit_layout = layout;
+        save_config(model);
+    }
+    if let Some(preference) = ui_result.set_motion_preference {
+        model.set_motion_preference(preference);
+        save_config(model);
+    }
+    if let Some(transition) = ui_result.set_transition {
+        model.transition = transition;
+        save_config(model);
+    }
+    if let Some(settings) = ui_result.set_sonification {
+        model.set_sonification(settings);
+    }
+    if let Some(quiet_hours) = ui_result.set_quiet_hours {
+        model.set_quiet_hours(quiet_hours);
+    }
+    if let Some(setting) = ui_result.set_window_title {
+        model.window_title_clock = setting;
+        report_save(
+            "window title setting",
+            model.window_title_clock.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if let Some(settings) = ui_result.set_clip_settings {
+        model.clip_settings = settings;
+        report_save(
+            "recording settings",
+            model.clip_settings.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if let Some(setting) = ui_result.set_language {
+        model.language = setting;
+        report_save("language setting", model.language.save(), |message| model.show_toast(message));
+    }
+    if let Some(setting) = ui_result.set_safe_palette {
+        model.safe_palette = setting;
+        report_save(
+            "palette setting",
+            model.safe_palette.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if let Some(setting) = ui_result.set_reveal_hotkey {
+        model.time_reveal.setting = setting;
+        model.time_reveal.apply();
+    }
+    if let Some(setting) = ui_result.set_follow_system_zone {
+        model.follow_system_zone = setting;
+        report_save(
+            "system zone setting",
+            model.follow_system_zone.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if let Some(own) = ui_result.set_own_favorites {
+        model.favorites.set_uses_own(own);
+        save_config(model);
+    }
+    if let Some(edit) = ui_result.keymap_edit {
+        model.keymap.apply(edit);
+    }
+    if ui_result.open_help {
+        model.help_panel_open = true;
+    }
+    if let Some(seconds) = ui_result.step_time {
+        model.step_time(seconds);
+    }
+    if ui_result.return_to_live {
+        model.return_to_live();
+    }
+    if ui_result.export_svg {
+        model.export_svg();
+    }
+    if ui_result.open_poster {
+        model.open_poster();
+    }
+    if let Some(settings) = poster_result.set_settings {
+        model.poster_settings = settings;
+        save_config(model);
+    }
+    if poster_result.render {
+        model.render_poster();
+    }
+    if ui_result.toggle_quiz {
+        model.toggle_quiz();
+    }
+    if ui_result.submit_guess {
+        model.submit_guess();
+    }
+    if ui_result.next_question {
+        model.next_question();
+    }
+    if ui_result.reset_quiz_stats {
+        model.reset_quiz_stats();
+    }
+    if let Some(theme) = ui_result.set_theme {
+        model.theme = model.themes.activate(theme.as_deref());
+        save_config(model);
+    }
+    if let Some(tz) = ui_result.set_comparison {
+        model.set_comparison(tz);
+    }
+    if ui_result.toggle_atlas {
+        model.toggle_atlas();
+    }
+    if ui_result.save_view_preset {
+        model.save_view_preset();
+    }
+    if let Some(index) = ui_result.apply_view_preset {
+        model.apply_view_preset(index);
+    }
+    if let Some(index) = ui_result.delete_view_preset {
+        model.delete_view_preset(index);
+    }
+
+    // Run the command chosen in the palette as if its keys were pressed
+    if let Some(chord) = palette_choice {
+        handle_key(model, chord.key, chord.mods);
+    }
+
+    // Keep the screen reader's time, DST status and focus current
+    model.screen_reader.update(&model.home.time_data, Some(model.focus_region));
+
+    // Pick up favorites starred in another clock
+    model.favorites.poll();
+
+    // Follow the computer's time zone when it changes
+    if let Some(change) = model.system_zone.poll() {
+        let switch = model.follow_system_zone.enabled && change.current != model.home.zone;
+        if switch {
+            model.set_timezone(change.current);
+        }
+        model.show_toast(change.message(switch));
+    }
+
+    // Follow the system reduce-motion setting when set to Auto
+    if model.system_motion.poll() {
+        model.set_motion_preference(model.motion_preference);
+    }
+
+    // Show the current time in the taskbar/dock title, and any clip recording
+    let badge = model.clip_recorder.indicator(Instant::now());
+    model.window_title.set_badge(badge);
+    model.window_title.update(
+        app.main_window().winit_window(),
+        &model.window_title_clock,
+        model.home.zone,
+        shared::now_utc(),
+    );
+
+    // Show or end the reveal shortcut's flash
+    model.time_reveal.update(app.main_window().winit_window(), Instant::now());
+
+    // Remember where the window was moved or resized to
+    model.window_placement.track(app.main_window().winit_window(), Instant::now());
+
+    // Write config changes once they have settled
+    model.config_saver.poll(Instant::now());
+
+    // Idle between second boundaries once nothing is moving
+    pace_frames(app, model, Instant::now());
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    let window_rect = app.window_rect();
+
+    // Draw background
+    draw.background().color(drawing::colors::background());
+
+    let canvas_rect = canvas_rect(window_rect);
+
+    // Explicit mode and decode guides would give the quiz answer away
+    let quiz_asking = model.quiz_asking();
+
+    // Side by side while comparing, both halves sharing the pan and zoom
+    match model.comparison.as_ref().filter(|_| !quiz_asking) {
+        Some(comparison) => {
+            let middle = canvas_rect.x();
+            let home_rect =
+                Rect::from_corners(canvas_rect.bottom_left(), pt2(middle, canvas_rect.top()));
+            let comparison_rect =
+                Rect::from_corners(pt2(middle, canvas_rect.bottom()), canvas_rect.top_right());
+            let zoom = model.view_zoom * COMPARE_SCALE;
+            draw_instance(&draw, model, &model.home, home_rect, zoom, quiz_asking);
+            draw_instance(&draw, model, comparison, comparison_rect, zoom, quiz_asking);
+            drawing::draw_comparison_labels(
+                &draw,
+                [
+                    (home_rect, model.home.zone.name(), &model.home.time_data),
+                    (comparison_rect, comparison.zone.name(), &comparison.time_data),
+                ],
+                &describe_difference(&model.home.time_data, &comparison.time_data),
+            );
+        }
+        None => draw_instance(&draw, model, &model.home, canvas_rect, model.view_zoom, quiz_asking),
+    }
+
+    // Draw HUD elements
+    drawing::draw_hud(
+        &draw,
+        window_rect,
+        model.home.time_data.is_dst,
+        &model.home.time_data.dst_change,
+        !model.truth_anchor_active && !quiz_asking,
+        model.keymap.registry(),
+    );
+
+    if let Some(quiz) = &model.quiz {
+        drawing::draw_quiz_banner(&draw, canvas_rect, quiz, model.keymap.registry());
+    }
+
+    // Draw focus indicator if canvas is focused
+    if model.focus_region == FocusRegion::Canvas {
+        drawing::draw_focus_indicator(&draw, canvas_rect);
+    }
+
+    // Draw Truth Anchor overlay
+    if model.truth_anchor_active {
+        let overlay_pos = model
+            .truth_anchor_position
+            .unwrap_or(canvas_rect.xy());
+        drawing::draw_truth_anchor_overlay(
+            &draw,
+            &model.home.time_data,
+            overlay_pos,
+            model.home.zone.name(),
+        );
+    }
+
+    // Draw the favorites atlas over the diagram; a quiz hides it, since
+    // each thumbnail shows the answer
+    if let Some(atlas) = model.atlas.as_ref().filter(|_| !quiz_asking) {
+        draw_atlas(&draw, model, atlas, canvas_rect);
+    }
+
+    // Draw help panel (centered on canvas area, not whole window)
+    if model.help_panel_open {
+        drawing::draw_help_panel(&draw, canvas_rect, model.keymap.registry());
+    }
+
+    // Dim the diagram during quiet hours
+    if model.intensity.is_quiet() {
+        draw.rect()
+            .xy(window_rect.xy())
+            .wh(window_rect.wh())
+            .color(srgba(0.0, 0.0, 0.0, model.intensity.dim_alpha()));
+    }
+
+    // Draw error banner if TZ data issue
+    if model.tz_error {
+        drawing::draw_error_banner(&draw, window_rect);
+    }
+
+    // Draw toast notifications
+    for toast in &model.toasts {
+        drawing::draw_toast(&draw, &toast.message, toast.alpha(), window_rect);
+    }
+
+    // Render to frame
+    draw.to_frame(app, &frame).unwrap();
+
+    // Render egui on top
+    model.egui.draw_to_frame(&frame).unwrap();
+}
+
+/// The diagram canvas: the window, less the sidebar on the right
+fn canvas_rect(window_rect: Rect) -> Rect {
+    let canvas_width = window_rect.w() - SIDEBAR_WIDTH;
+    Rect::from_x_y_w_h(
+        window_rect.left() + canvas_width / 2.0,
+        window_rect.y(),
+        canvas_width,
+        window_rect.h(),
+    )
+}
+
+/// Draw one zone's diagram (or its explicit readout) centered in `rect`
+fn draw_instance(
+    draw: &Draw,
+    model: &Model,
+    instance: &GrammarInstance,
+    rect: Rect,
+    zoom: f32,
+    quiz_asking: bool,
+) {
+    if model.explicit_mode && !quiz_asking {
+        // Draw explicit mode (standard time readout)
+        drawing::draw_explicit_mode(
+            draw,
+            &instance.time_data,
+            rect,
+            instance.zone.name(),
+            &model.explicit_layout,
+        );
+        return;
+    }
+
+    let center = rect.xy();
+    let transformed_ring = draw_diagram(draw, instance, center, model.view_offset, zoom);
+
+    // Draw decode mode guides
+    if model.decode_mode && !quiz_asking {
+        drawing::draw_decode_mode_guides(
+            draw,
+            &instance.geometry_params,
+            &transformed_ring,
+            center + model.view_offset,
+            rect,
+        );
+    }
+}
+
+/// Draw one zone's foundation, tension and phase layers and its DST knot,
+/// panned by `offset` from `center`; returns the phase ring as drawn
+fn draw_diagram(
+    draw: &Draw,
+    instance: &GrammarInstance,
+    center: Point2,
+    offset: Vec2,
+    zoom: f32,
+) -> PhaseRing {
+    // Apply view transform to geometry
+    let transformed_polygon =
+        apply_view_transform_points(&instance.hour_polygon, offset, zoom, pt2(0.0, 0.0));
+    let transformed_superellipse =
+        apply_view_transform_points(&instance.minute_superellipse, offset, zoom, pt2(0.0, 0.0));
+
+    // Create transformed phase ring
+    let transformed_marks =
+        apply_view_transform_points(&instance.phase_ring.marks, offset, zoom, pt2(0.0, 0.0));
+    let transformed_center =
+        geometry::apply_view_transform(instance.phase_ring.center, offset, zoom, pt2(0.0, 0.0));
+    let transformed_ring = PhaseRing {
+        center: transformed_center + center,
+        radius: instance.phase_ring.radius * zoom,
+        marks: transformed_marks.iter().map(|p| *p + center).collect(),
+        highlighted_index: instance.phase_ring.highlighted_index,
+        needle_angle: instance.phase_ring.needle_angle,
+    };
+
+    // Offset all geometry by the diagram center
+    let polygon_centered: Vec<Point2> = transformed_polygon.iter().map(|p| *p + center).collect();
+    let superellipse_centered: Vec<Point2> = transformed_superellipse.iter().map(|p| *p + center).collect();
+
+    // Draw layers in order: foundation, tension, phase
+    drawing::draw_foundation_layer(draw, &polygon_centered);
+    drawing::draw_tension_layer(draw, &superellipse_centered);
+    drawing::draw_phase_layer(draw, &transformed_ring, zoom);
+
+    // Draw DST knot if applicable
+    if let Some(knot) = instance.dst_knot(BASE_DIM * zoom, center + offset, shared::now_utc()) {
+        drawing::draw_dst_knot(draw, &knot);
+    }
+
+    transformed_ring
+}
+
+/// Draw every favorite zone's thumbnail over the canvas
+fn draw_atlas(draw: &Draw, model: &Model, atlas: &[GrammarInstance], canvas_rect: Rect) {
+    drawing::draw_atlas_backdrop(draw, canvas_rect);
+
+    let cells = atlas::atlas_cells(atlas.len(), canvas_rect);
+    let hovered = atlas::cell_at(&cells, model.last_mouse_pos);
+    for (i, (instance, cell)) in atlas.iter().zip(&cells).enumerate() {
+        let area = atlas::diagram_area(*cell);
+        // The phase ring's marks reach just past the base dimension
+        let zoom = area.w() / (BASE_DIM * 1.05);
+        draw_diagram(draw, instance, area.xy(), vec2(0.0, 0.0), zoom);
+        drawing::draw_atlas_label(
+            draw,
+            *cell,
+            instance.zone.name(),
+            &instance.time_data,
+            instance.zone == model.home.zone,
+            hovered == Some(i),
+        );
+    }
+}
+
+/// Every action in the command palette, with the keys that perform it
+///
+/// The Truth Anchor is left out: it is held with Space, not triggered.
+fn commands() -> CommandRegistry {
+    let registry = CommandRegistry::new()
+        .register("Open timezone picker", KeyChord::new(Key::Z))
+        .register("Toggle decode mode", KeyChord::new(Key::D))
+        .register("Toggle sonification", KeyChord::new(Key::S))
+        .register("Toggle help", KeyChord::shift(Key::Slash))
+        .register("Open geometry inspector", KeyChord::new(Key::G))
+        .register("Start/stop decode quiz", KeyChord::new(Key::Q))
+        .register("Next quiz question", KeyChord::new(Key::N))
+        .register("Compare with another zone", KeyChord::new(Key::C))
+        .register("Toggle favorites atlas", KeyChord::new(Key::A))
+        .register("Export diagram as SVG", KeyChord::new(Key::E))
+        .register("Open time poster", KeyChord::shift(Key::E))
+        .register("Return to live", KeyChord::new(Key::L))
+        .register("Step back one second", KeyChord::new(Key::LBracket))
+        .register("Step forward one second", KeyChord::new(Key::RBracket))
+        .register("Step back one minute", KeyChord::shift(Key::LBracket))
+        .register("Step forward one minute", KeyChord::shift(Key::RBracket))
+        .register("Step back one hour", KeyChord::ctrl(Key::LBracket))
+        .register("Step forward one hour", KeyChord::ctrl(Key::RBracket))
+        .register("Zoom in", KeyChord::new(Key::Equals))
+        .register("Zoom out", KeyChord::new(Key::Minus))
+        .register("Reset view", KeyChord::new(Key::R))
+        .register("Next focus region", KeyChord::new(Key::Tab))
+        .register("Previous focus region", KeyChord::shift(Key::Tab))
+        .register("Record clip", KeyChord::ctrl_shift(Key::R));
+    PRESET_COMMANDS
+        .iter()
+        .fold(registry, |registry, &(name, key)| registry.register(name, KeyChord::new(key)))
+}
+
+fn key_pressed(app: &App, model: &mut Model, key: Key) {
+    let mods = app.keys.mods;
+
+    // While a shortcut is being rebound, the next chord becomes its binding
+    if model.keymap.capture(key, mods) {
+        return;
+    }
+
+    // Ctrl+K opens the command palette from anywhere; while open it takes
+    // every other key
+    if KeyChord::is_palette_toggle(key, mods) {
+        model.command_palette.toggle();
+        return;
+    }
+    if model.command_palette.is_open {
+        return;
+    }
+
+    // Don't treat typing in the quiz answer field as shortcuts
+    if model.text_input_focused {
+        return;
+    }
+
+    // Remapped chords reach the handler as their action's default chord
+    if let Some(chord) = model.keymap.translate(key, mods) {
+        handle_key(model, chord.key, chord.mods);
+    }
+}
+
+fn handle_key(model: &mut Model, key: Key, mods: ModifiersState) {
+    match key {
+        // Ctrl+Shift+R - start or stop recording a clip
+        _ if shared::is_clip_toggle(key, mods) => {
+            let message = model.clip_recorder.toggle(model.clip_settings, Instant::now());
+            model.show_toast(message);
+        }
+
+        // Space - activate Truth Anchor (hold)
+        Key::Space if !model.space_held => {
+            model.space_held = true;
+            model.activate_truth_anchor(None);
+        }
+
+        // D - toggle Decode Mode
+        Key::D if !model.picker_state.is_open && !model.help_panel_open => {
+            model.decode_mode = !model.decode_mode;
+            save_config(model);
+        }
+
+        // S - toggle sonification
+        Key::S if !model.picker_state.is_open && !model.help_panel_open => {
+            model.toggle_sonification();
+        }
+
+        // Z - open timezone picker
+        Key::Z if !model.help_panel_open => {
+            model.picker_state.open();
+        }
+
+        // ? (Shift + /) - toggle help panel
+        Key::Slash if mods.shift() => {
+            model.help_panel_open = !model.help_panel_open;
+        }
+
+        // Tab - cycle focus
+        Key::Tab if !model.picker_state.is_open && !model.help_panel_open => {
+            model.cycle_focus(mods.shift());
+        }
+
+        // Escape - close panels
+        Key::Escape => {
+            if model.help_panel_open {
+                model.help_panel_open = false;
+            } else if model.picker_state.is_open {
+                model.picker_state.close();
+            } else if model.atlas.is_some() {
+                model.atlas = None;
+            } else if model.truth_anchor_latched {
+                model.truth_anchor_latched = false;
+                model.deactivate_truth_anchor();
+            }
+        }
+
+        // Arrow keys - pan when canvas focused
+        Key::Up if model.focus_region == FocusRegion::Canvas => {
+            model.pan(vec2(0.0, 20.0));
+        }
+        Key::Down if model.focus_region == FocusRegion::Canvas => {
+            model.pan(vec2(0.0, -20.0));
+        }
+        Key::Left if model.focus_region == FocusRegion::Canvas => {
+            model.pan(vec2(-20.0, 0.0));
+        }
+        Key::Right if model.focus_region == FocusRegion::Canvas => {
+            model.pan(vec2(20.0, 0.0));
+        }
+
+        // R - reset view
+        Key::R if model.focus_region == FocusRegion::Canvas => {
+            model.reset_view();
+        }
+
+        // + / = - zoom in
+        Key::Equals | Key::Plus if model.focus_region == FocusRegion::Canvas => {
+            model.zoom(1.1);
+        }
+
+        // - - zoom out
+        Key::Minus if model.focus_region == FocusRegion::Canvas => {
+            model.zoom(0.9);
+        }
+
+        // L - return to live time
+        Key::L if !model.picker_state.is_open && !model.help_panel_open => {
+            model.return_to_live();
+        }
+
+        // G - open/close the geometry inspector
+        Key::G if !model.picker_state.is_open && !model.help_panel_open => {
+            model.toggle_geometry_inspector();
+        }
+
+        // Q - start/stop the decode quiz
+        Key::Q if !model.picker_state.is_open && !model.help_panel_open => {
+            model.toggle_quiz();
+        }
+
+        // N / Enter - next quiz question once the answer is revealed
+        Key::N | Key::Return if model.quiz.is_some_and(|q| !q.is_asking()) => {
+            model.next_question();
+        }
+
+        // C - compare with a favorite zone side by side
+        Key::C if !model.picker_state.is_open && !model.help_panel_open => {
+            model.toggle_comparison();
+        }
+
+        // A - open/close the atlas of favorite zones
+        Key::A if !model.picker_state.is_open && !model.help_panel_open => {
+            model.toggle_atlas();
+        }
+
+        // Shift+E - open the time poster window
+        Key::E if mods.shift() && !model.picker_state.is_open && !model.help_panel_open => {
+            model.open_poster();
+        }
+
+        // E - export diagram as SVG
+        Key::E if !model.picker_state.is_open && !model.help_panel_open => {
+            model.export_svg();
+        }
+
+        // 1-9 - switch to a view preset
+        Key::Key1
+        | Key::Key2
+        | Key::Key3
+        | Key::Key4
+        | Key::Key5
+        | Key::Key6
+        | Key::Key7
+        | Key::Key8
+        | Key::Key9
+            if !model.picker_state.is_open && !model.help_panel_open =>
+        {
+            if let Some(index) = PRESET_COMMANDS.iter().position(|&(_, k)| k == key) {
+                model.apply_view_preset(index);
+            }
+        }
+
+        // [ - step backward in time
+        Key::LBracket if !model.picker_state.is_open && !model.help_panel_open => {
+            if mods.ctrl() || mods.logo() {
+                model.step_time(-3600); // -1 hour
+            } else if mods.shift() {
+                model.step_time(-60); // -1 minute
+            } else {
+                model.step_time(-1); // -1 second
+            }
+        }
+
+        // ] - step forward in time
+        Key::RBracket if !model.picker_state.is_open && !model.help_panel_open => {
+            if mods.ctrl() || mods.logo() {
+                model.step_time(3600); // +1 hour
+            } else if mods.shift() {
+                model.step_time(60); // +1 minute
+            } else {
+                model.step_time(1); // +1 second
+            }
+        }
+
+        _ => {}
+    }
+}
+
+fn key_released(_app: &App, model: &mut Model, key: Key) {
+    if key == Key::Space {
+        model.space_held = false;
+        model.deactivate_truth_anchor();
+    }
+}
+
+fn mouse_pressed(app: &App, model: &mut Model, button: MouseButton) {
+    let pos = app.mouse.position();
+    let canvas_rect = canvas_rect(app.window_rect());
+
+    // A click in the atlas switches to that zone instead of panning
+    if button == MouseButton::Left && !model.quiz_asking() {
+        if let Some(atlas) = &model.atlas {
+            if canvas_rect.contains(pos) {
+                let cells = atlas::atlas_cells(atlas.len(), canvas_rect);
+                if let Some(i) = atlas::cell_at(&cells, pos) {
+                    let tz = atlas[i].zone;
+                    model.atlas = None;
+                    model.set_timezone(tz);
+                }
+                return;
+            }
+        }
+    }
+
+    match button {
+        MouseButton::Left if canvas_rect.contains(pt2(pos.x, pos.y)) => {
+            // Start Truth Anchor on press
+            model.mouse_press_start = Some(Instant::now());
+            model.activate_truth_anchor(Some(pos));
+
+            // Also start panning
+            model.is_panning = true;
+            model.last_mouse_pos = pos;
+        }
+        MouseButton::Middle => {
+            // Rotary press equivalent - toggle latch
+            model.toggle_truth_anchor_latch();
+        }
+        _ => {}
+    }
+}
+
+fn mouse_released(_app: &App, model: &mut Model, button: MouseButton) {
+    if button == MouseButton::Left {
+        model.is_panning = false;
+        model.mouse_press_start = None;
+        model.deactivate_truth_anchor();
+    }
+}
+
+fn mouse_moved(_app: &App, model: &mut Model, pos: Point2) {
+    if model.is_panning {
+        let delta = pos - model.last_mouse_pos;
+        model.pan(delta);
+        model.last_mouse_pos = pos;
+
+        // Update Truth Anchor position while dragging
+        if model.truth_anchor_active {
+            model.truth_anchor_position = Some(pos);
+        }
+    }
+
+    // Update last mouse position for future reference
+    model.last_mouse_pos = pos;
+}
+
+fn mouse_wheel(_app: &App, model: &mut Model, delta: MouseScrollDelta, _phase: TouchPhase) {
+    let scroll_amount = match delta {
+        MouseScrollDelta::LineDelta(_, y) => y,
+        MouseScrollDelta::PixelDelta(pos) => pos.y as f32 / 50.0,
+    };
+
+    if scroll_amount > 0.0 {
+        model.zoom(1.1);
+    } else if scroll_amount < 0.0 {
+        model.zoom(0.9);
+    }
+}
+
+fn raw_window_event(app: &App, model: &mut Model, event: &nannou::winit::event::WindowEvent) {
+    // Any window event brings an idle clock back to full frame rate
+    model.frame_pacer.note_input(Instant::now());
+
+    model.egui.handle_raw_event(event);
+    model.screen_reader.process_event(app.main_window().winit_window(), event);
+
+    match event {
+        nannou::winit::event::WindowEvent::Focused(focused) => {
+            model.window_focused = *focused;
+            if *focused && model.is_live {
+                // Resync time on focus
+                model.set_time_data(compute_time_data(model.home.zone));
+            }
+        }
+        nannou::winit::event::WindowEvent::Touch(touch) => {
+            let window_rect = app.window_rect();
+
+            // Convert touch position to nannou coordinates
+            let pos_x = touch.location.x as f32 - window_rect.w() / 2.0;
+            let pos_y = window_rect.h() / 2.0 - touch.location.y as f32;
+            let pos = pt2(pos_x, pos_y);
+
+            match touch.phase {
+                nannou::winit::event::TouchPhase::Started => {
+                    model.touch_start_time = Some(Instant::now());
+                    model.last_mouse_pos = pos;
+                }
+                nannou::winit::event::TouchPhase::Moved => {
+                    // Check if this is a long press (350ms)
+                    if let Some(start) = model.touch_start_time {
+                        if start.elapsed().as_millis() >= TOUCH_HOLD_THRESHOLD_MS {
+                            model.activate_truth_anchor(Some(pos));
+                        }
+                    }
+
+                    // Pan
+                    let delta = pos - model.last_mouse_pos;
+                    model.pan(delta);
+                    model.last_mouse_pos = pos;
+                }
+                nannou::winit::event::TouchPhase::Ended | nannou::winit::event::TouchPhase::Cancelled => {
+                    model.touch_start_time = None;
+                    model.deactivate_truth_anchor();
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
@@ -0,0 +1,740 @@
+//! Poster module - print layouts of the diagram at any size
+//!
+//! A poster lays one instant's diagram out on a page: the diagram in a
+//! square above, then an optional caption, the time and zone, and an
+//! optional legend naming what each layer encodes. It uses the same geometry
+//! as the live diagram, scaled from the `BASE_DIM` layout onto the page.
+//!
+//! The page is composed once as a list of shapes in page pixels, measured
+//! from the top left with y down. The same list is written as an SVG
+//! document or rasterized to a PNG, so both formats share one layout at any
+//! page size. Posters use paper and ink colors rather than the screen theme.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, LocalResult, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use nannou::prelude::*;
+use nannou::text::rt::point;
+use nannou::text::{font, Font, Scale};
+use serde::{Deserialize, Serialize};
+use tiny_skia::{
+    FillRule, LineCap, LineJoin, Paint, PathBuilder, Pixmap, PremultipliedColorU8, Stroke,
+    Transform,
+};
+
+use crate::drawing::colors;
+use crate::geometry::{DstKnot, PhaseRing};
+use crate::instance::GrammarInstance;
+use crate::svg::{escape_xml, opacity, rgb, HALF_EXTENT};
+
+/// Smallest and largest page side, in pixels
+pub const MIN_SIDE: u32 = 256;
+pub const MAX_SIDE: u32 = 8000;
+
+/// Page sizes offered in the poster window, in pixels at 300 dpi
+pub const PAGE_SIZES: [(&str, u32, u32); 4] = [
+    ("A4", 2480, 3508),
+    ("A3", 3508, 4961),
+    ("US Letter", 2550, 3300),
+    ("Square", 3000, 3000),
+];
+
+/// Formats the local time is typed in, most specific first
+const INSTANT_FORMATS: [&str; 4] = [
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M",
+];
+
+/// Page margin, as a fraction of the page's shorter side
+const MARGIN: f32 = 0.07;
+
+/// Text unit, as a fraction of the page's shorter side; sizes below are in units
+const UNIT: f32 = 1.0 / 60.0;
+const CAPTION_SIZE: f32 = 2.4;
+const TIME_SIZE: f32 = 1.6;
+const ZONE_SIZE: f32 = 1.1;
+const LEGEND_SIZE: f32 = 1.0;
+/// Line height, as a multiple of the text size
+const LINE_HEIGHT: f32 = 1.5;
+/// Height of one legend row, in units
+const LEGEND_ROW: f32 = 1.8;
+
+/// File format of a rendered poster
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PosterFormat {
+    #[default]
+    Png,
+    Svg,
+}
+
+impl PosterFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            PosterFormat::Png => "png",
+            PosterFormat::Svg => "svg",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PosterFormat::Png => "PNG",
+            PosterFormat::Svg => "SVG",
+        }
+    }
+}
+
+/// Persisted poster preferences
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PosterSettings {
+    /// Page size in pixels
+    pub width: u32,
+    pub height: u32,
+    pub format: PosterFormat,
+    /// Explain each layer under the diagram
+    pub legend: bool,
+}
+
+impl Default for PosterSettings {
+    fn default() -> Self {
+        let (_, width, height) = PAGE_SIZES[0];
+        Self {
+            width,
+            height,
+            format: PosterFormat::Png,
+            legend: true,
+        }
+    }
+}
+
+/// A color with its opacity
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ink {
+    pub color: Srgb<u8>,
+    pub alpha: u8,
+}
+
+impl Ink {
+    fn solid(color: Srgb<u8>) -> Self {
+        Self { color, alpha: 255 }
+    }
+
+    fn faded(color: Srgb<u8>, alpha: u8) -> Self {
+        Self { color, alpha }
+    }
+}
+
+/// Where a line of text hangs from its position
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anchor {
+    Start,
+    Middle,
+}
+
+/// One element of the page, in page pixels with y down
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    /// Closed outline, filled and/or stroked
+    Polygon {
+        points: Vec<(f32, f32)>,
+        fill: Option<Ink>,
+        stroke: Option<(Ink, f32)>,
+    },
+    Circle {
+        center: (f32, f32),
+        radius: f32,
+        fill: Ink,
+    },
+    Line {
+        from: (f32, f32),
+        to: (f32, f32),
+        ink: Ink,
+        width: f32,
+    },
+    /// A line of text; `position` is on its baseline
+    Text {
+        position: (f32, f32),
+        size: f32,
+        ink: Ink,
+        anchor: Anchor,
+        text: String,
+    },
+}
+
+/// A composed page, ready to write in either format
+#[derive(Debug, Clone)]
+pub struct Poster {
+    pub width: u32,
+    pub height: u32,
+    pub title: String,
+    pub description: String,
+    pub shapes: Vec<Shape>,
+}
+
+/// Where the parts of the page go
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageLayout {
+    /// Center of the diagram square
+    pub diagram_center: (f32, f32),
+    /// Page pixels per pixel of the `BASE_DIM` diagram
+    pub diagram_scale: f32,
+    /// Top of the text block under the diagram
+    pub text_top: f32,
+    /// Text unit in page pixels
+    pub unit: f32,
+    pub margin: f32,
+}
+
+impl PageLayout {
+    /// Fit the diagram above a text block of `caption`, time, zone and
+    /// `legend_rows` legend rows
+    pub fn new(width: f32, height: f32, caption: bool, legend_rows: usize) -> Self {
+        let short = width.min(height);
+        let margin = short * MARGIN;
+        let unit = short * UNIT;
+
+        let mut text_height = (TIME_SIZE + ZONE_SIZE) * LINE_HEIGHT * unit;
+        if caption {
+            text_height += CAPTION_SIZE * LINE_HEIGHT * unit;
+        }
+        if legend_rows > 0 {
+            text_height += (1.0 + legend_rows as f32 * LEGEND_ROW) * unit;
+        }
+
+        let diagram_height = (height - 2.0 * margin - text_height - 2.0 * unit).max(unit);
+        let side = (width - 2.0 * margin).min(diagram_height);
+        Self {
+            diagram_center: (width / 2.0, margin + diagram_height / 2.0),
+            diagram_scale: side / (2.0 * HALF_EXTENT),
+            text_top: margin + diagram_height + 2.0 * unit,
+            unit,
+            margin,
+        }
+    }
+
+    /// A point of the `BASE_DIM` diagram (y up, centered) on the page
+    pub fn place(&self, p: Point2) -> (f32, f32) {
+        (
+            self.diagram_center.0 + p.x * self.diagram_scale,
+            self.diagram_center.1 - p.y * self.diagram_scale,
+        )
+    }
+}
+
+/// Read a local date and time in `zone`, as "2025-01-15 09:30" or with seconds
+///
+/// A time a clock passes twice when it falls back means the first pass; a
+/// time skipped when it springs forward is an error.
+pub fn parse_local_instant(text: &str, zone: Tz) -> Result<DateTime<Utc>, String> {
+    let text = text.trim();
+    let naive = INSTANT_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+        .ok_or_else(|| "Use YYYY-MM-DD HH:MM or YYYY-MM-DD HH:MM:SS".to_string())?;
+    match zone.from_local_datetime(&naive) {
+        LocalResult::Single(local) | LocalResult::Ambiguous(local, _) => {
+            Ok(local.with_timezone(&Utc))
+        }
+        LocalResult::None => Err(format!("{} is skipped by a clock change in {}", text, zone)),
+    }
+}
+
+/// Lay out the poster for `instance`
+///
+/// `instance` should be built with reduced motion so the needle rests on
+/// the second rather than between marks.
+pub fn compose(
+    instance: &GrammarInstance,
+    dst_knot: Option<&DstKnot>,
+    caption: &str,
+    settings: &PosterSettings,
+) -> Poster {
+    let width = settings.width.clamp(MIN_SIDE, MAX_SIDE);
+    let height = settings.height.clamp(MIN_SIDE, MAX_SIDE);
+    let caption = caption.trim();
+    let legend = if settings.legend { legend_rows(dst_knot) } else { Vec::new() };
+    let layout = PageLayout::new(width as f32, height as f32, !caption.is_empty(), legend.len());
+    let scale = layout.diagram_scale;
+
+    let mut shapes = vec![
+        Shape::Polygon {
+            points: instance.hour_polygon.iter().map(|&p| layout.place(p)).collect(),
+            fill: Some(Ink::faded(colors::FOUNDATION, 180)),
+            stroke: Some((Ink::solid(colors::FOUNDATION_STROKE), 2.0 * scale)),
+        },
+        Shape::Polygon {
+            points: instance.minute_superellipse.iter().map(|&p| layout.place(p)).collect(),
+            fill: Some(Ink::faded(colors::TENSION, 100)),
+            stroke: Some((Ink::solid(colors::TENSION_STROKE), 2.5 * scale)),
+        },
+    ];
+    phase_shapes(&mut shapes, &instance.phase_ring, &layout);
+    if let Some(knot) = dst_knot {
+        knot_shapes(&mut shapes, knot, &layout);
+    }
+
+    // Text block, centered under the diagram
+    let time_data = &instance.time_data;
+    let time = format!(
+        "{} {} · {}",
+        time_data.format_time(),
+        time_data.meridiem,
+        time_data.format_date()
+    );
+    let zone = format!("{} · {}", instance.zone.name(), time_data.format_utc_offset());
+    let center_x = width as f32 / 2.0;
+    let unit = layout.unit;
+    let mut y = layout.text_top;
+    let mut lines = Vec::new();
+    if !caption.is_empty() {
+        lines.push((caption, CAPTION_SIZE, colors::INK));
+    }
+    lines.push((&time, TIME_SIZE, colors::INK));
+    lines.push((&zone, ZONE_SIZE, colors::INK_SECONDARY));
+    for (text, size, color) in lines {
+        y += size * unit;
+        shapes.push(Shape::Text {
+            position: (center_x, y),
+            size: size * unit,
+            ink: Ink::solid(color),
+            anchor: Anchor::Middle,
+            text: text.to_string(),
+        });
+        y += (LINE_HEIGHT - 1.0) * size * unit;
+    }
+
+    // Legend, one swatch and label per row
+    let legend_x = (center_x - 14.0 * unit).max(layout.margin);
+    y += unit;
+    for (ink, label) in legend {
+        let row_center = y + LEGEND_ROW * unit / 2.0;
+        shapes.push(Shape::Circle {
+            center: (legend_x + 0.5 * unit, row_center),
+            radius: 0.5 * unit,
+            fill: ink,
+        });
+        shapes.push(Shape::Text {
+            position: (legend_x + 1.7 * unit, row_center + 0.35 * LEGEND_SIZE * unit),
+            size: LEGEND_SIZE * unit,
+            ink: Ink::solid(colors::INK_SECONDARY),
+            anchor: Anchor::Start,
+            text: label.to_string(),
+        });
+        y += LEGEND_ROW * unit;
+    }
+
+    Poster {
+        width,
+        height,
+        title: if caption.is_empty() { time } else { caption.to_string() },
+        description: instance.diagram_description.clone(),
+        shapes,
+    }
+}
+
+/// What each layer encodes, with the color it is drawn in
+fn legend_rows(dst_knot: Option<&DstKnot>) -> Vec<(Ink, &'static str)> {
+    let mut rows = vec![
+        (
+            Ink::solid(colors::FOUNDATION_STROKE),
+            "Hour: a polygon with three more sides than the hour",
+        ),
+        (
+            Ink::solid(colors::TENSION_STROKE),
+            "Minute: the superellipse's squareness and turn",
+        ),
+        (
+            Ink::solid(colors::PHASE_HIGHLIGHT),
+            "Second: the lit mark and needle on the ring",
+        ),
+        (
+            Ink::solid(colors::PHASE_MARK),
+            "Zone: both shapes turn with the UTC offset; DST adds a twist",
+        ),
+    ];
+    if let Some(knot) = dst_knot {
+        let color = if knot.is_upcoming { colors::DST_KNOT } else { colors::DST_KNOT_PAST };
+        rows.push((Ink::solid(color), "Knot: a clock change is near"));
+    }
+    rows
+}
+
+/// Ring marks, highlighted mark with glow, and needle
+fn phase_shapes(shapes: &mut Vec<Shape>, ring: &PhaseRing, layout: &PageLayout) {
+    let scale = layout.diagram_scale;
+    for (i, mark) in ring.marks.iter().enumerate() {
+        let center = layout.place(*mark);
+        if i == ring.highlighted_index {
+            shapes.push(Shape::Circle {
+                center,
+                radius: 12.0 * scale,
+                fill: Ink::faded(colors::PHASE_HIGHLIGHT, 60),
+            });
+            shapes.push(Shape::Circle {
+                center,
+                radius: 8.0 * scale,
+                fill: Ink::solid(colors::PHASE_HIGHLIGHT),
+            });
+        } else if i % 5 == 0 {
+            shapes.push(Shape::Circle {
+                center,
+                radius: 4.8 * scale,
+                fill: Ink::solid(colors::PHASE_MARK),
+            });
+        } else {
+            shapes.push(Shape::Circle {
+                center,
+                radius: 2.4 * scale,
+                fill: Ink::faded(colors::PHASE_MARK, 150),
+            });
+        }
+    }
+
+    // The screen's white needle would vanish on paper
+    let tip = pt2(
+        ring.center.x + ring.radius * 0.85 * ring.needle_angle.cos(),
+        ring.center.y + ring.radius * 0.85 * ring.needle_angle.sin(),
+    );
+    shapes.push(Shape::Line {
+        from: layout.place(ring.center),
+        to: layout.place(tip),
+        ink: Ink::solid(colors::INK),
+        width: 2.0 * scale,
+    });
+    shapes.push(Shape::Circle {
+        center: layout.place(tip),
+        radius: 4.0 * scale,
+        fill: Ink::solid(colors::INK),
+    });
+}
+
+/// DST knot loop, anchor and control point glow
+fn knot_shapes(shapes: &mut Vec<Shape>, knot: &DstKnot, layout: &PageLayout) {
+    let scale = layout.diagram_scale;
+    let color = if knot.is_upcoming { colors::DST_KNOT } else { colors::DST_KNOT_PAST };
+    let mut points = vec![layout.place(knot.anchor)];
+    points.extend(knot.control_points.iter().map(|&cp| layout.place(cp)));
+    shapes.push(Shape::Polygon {
+        points,
+        fill: None,
+        stroke: Some((Ink::solid(color), 2.25 * scale)),
+    });
+    shapes.push(Shape::Circle {
+        center: layout.place(knot.anchor),
+        radius: 5.0 * scale,
+        fill: Ink::solid(color),
+    });
+    for &cp in &knot.control_points {
+        shapes.push(Shape::Circle {
+            center: layout.place(cp),
+            radius: knot.amplitude * 0.3 * scale,
+            fill: Ink::faded(color, 40),
+        });
+    }
+}
+
+impl Poster {
+    /// Write the poster as a standalone SVG document sized in pixels
+    pub fn to_svg(&self) -> String {
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+            w = self.width,
+            h = self.height,
+        );
+        let _ = writeln!(svg, "  <title>{}</title>", escape_xml(&self.title));
+        let _ = writeln!(svg, "  <desc>{}</desc>", escape_xml(&self.description));
+        let _ = writeln!(
+            svg,
+            r#"  <rect width="{}" height="{}" fill="{}"/>"#,
+            self.width,
+            self.height,
+            rgb(colors::PAPER),
+        );
+
+        for shape in &self.shapes {
+            match shape {
+                Shape::Polygon { points, fill, stroke } => {
+                    let points = points
+                        .iter()
+                        .map(|(x, y)| format!("{:.2},{:.2}", x, y))
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    let _ = write!(svg, r#"  <polygon points="{}""#, points);
+                    match fill {
+                        Some(ink) => {
+                            let opacity = fill_opacity(ink);
+                            let _ = write!(svg, r#" fill="{}"{}"#, rgb(ink.color), opacity);
+                        }
+                        None => svg.push_str(r#" fill="none""#),
+                    }
+                    if let Some((ink, width)) = stroke {
+                        let _ = write!(
+                            svg,
+                            r#" stroke="{}" stroke-width="{:.2}" stroke-linejoin="round""#,
+                            rgb(ink.color),
+                            width,
+                        );
+                    }
+                    svg.push_str("/>\n");
+                }
+                Shape::Circle { center, radius, fill } => {
+                    let _ = writeln!(
+                        svg,
+                        r#"  <circle cx="{:.2}" cy="{:.2}" r="{:.2}" fill="{}"{}/>"#,
+                        center.0,
+                        center.1,
+                        radius,
+                        rgb(fill.color),
+                        fill_opacity(fill),
+                    );
+                }
+                Shape::Line { from, to, ink, width } => {
+                    let _ = writeln!(
+                        svg,
+                        r#"  <line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="{}" stroke-width="{:.2}" stroke-linecap="round"/>"#,
+                        from.0,
+                        from.1,
+                        to.0,
+                        to.1,
+                        rgb(ink.color),
+                        width,
+                    );
+                }
+                Shape::Text { position, size, ink, anchor, text } => {
+                    let anchor = match anchor {
+                        Anchor::Start => "start",
+                        Anchor::Middle => "middle",
+                    };
+                    let _ = writeln!(
+                        svg,
+                        r#"  <text x="{:.2}" y="{:.2}" text-anchor="{}" font-family="sans-serif" font-size="{:.2}" fill="{}">{}</text>"#,
+                        position.0,
+                        position.1,
+                        anchor,
+                        size,
+                        rgb(ink.color),
+                        escape_xml(text),
+                    );
+                }
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Rasterize the poster and encode it as a PNG
+    pub fn to_png(&self) -> Result<Vec<u8>, String> {
+        let mut pixmap = Pixmap::new(self.width, self.height)
+            .ok_or_else(|| format!("cannot allocate a {}×{} image", self.width, self.height))?;
+        let paper = colors::PAPER;
+        pixmap.fill(tiny_skia::Color::from_rgba8(paper.red, paper.green, paper.blue, 255));
+        let font = font::default_notosans();
+        let identity = Transform::identity();
+
+        for shape in &self.shapes {
+            match shape {
+                Shape::Polygon { points, fill, stroke } => {
+                    let mut builder = PathBuilder::new();
+                    for (i, &(x, y)) in points.iter().enumerate() {
+                        if i == 0 {
+                            builder.move_to(x, y);
+                        } else {
+                            builder.line_to(x, y);
+                        }
+                    }
+                    builder.close();
+                    let Some(path) = builder.finish() else {
+                        continue;
+                    };
+                    if let Some(ink) = fill {
+                        let paint = paint(ink);
+                        pixmap.fill_path(&path, &paint, FillRule::Winding, identity, None);
+                    }
+                    if let Some((ink, width)) = stroke {
+                        let stroke = Stroke {
+                            width: *width,
+                            line_join: LineJoin::Round,
+                            ..Stroke::default()
+                        };
+                        pixmap.stroke_path(&path, &paint(ink), &stroke, identity, None);
+                    }
+                }
+                Shape::Circle { center, radius, fill } => {
+                    if let Some(path) = PathBuilder::from_circle(center.0, center.1, *radius) {
+                        let paint = paint(fill);
+                        pixmap.fill_path(&path, &paint, FillRule::Winding, identity, None);
+                    }
+                }
+                Shape::Line { from, to, ink, width } => {
+                    let mut builder = PathBuilder::new();
+                    builder.move_to(from.0, from.1);
+                    builder.line_to(to.0, to.1);
+                    if let Some(path) = builder.finish() {
+                        let stroke = Stroke {
+                            width: *width,
+                            line_cap: LineCap::Round,
+                            ..Stroke::default()
+                        };
+                        pixmap.stroke_path(&path, &paint(ink), &stroke, identity, None);
+                    }
+                }
+                Shape::Text { position, size, ink, anchor, text } => {
+                    draw_text(&mut pixmap, &font, *position, *size, *ink, *anchor, text);
+                }
+            }
+        }
+
+        pixmap.encode_png().map_err(|e| e.to_string())
+    }
+}
+
+fn fill_opacity(ink: &Ink) -> String {
+    if ink.alpha == 255 {
+        String::new()
+    } else {
+        format!(r#" fill-opacity="{}""#, opacity(ink.alpha))
+    }
+}
+
+fn paint(ink: &Ink) -> Paint<'static> {
+    let mut paint = Paint::default();
+    paint.set_color_rgba8(ink.color.red, ink.color.green, ink.color.blue, ink.alpha);
+    paint.anti_alias = true;
+    paint
+}
+
+/// Blend a line of text into the pixmap, sized like an SVG font-size
+fn draw_text(
+    pixmap: &mut Pixmap,
+    font: &Font,
+    position: (f32, f32),
+    size: f32,
+    ink: Ink,
+    anchor: Anchor,
+    text: &str,
+) {
+    // rusttype scales by ascent to descent; SVG sizes by the em
+    let metrics = font.v_metrics_unscaled();
+    let em = (metrics.ascent - metrics.descent) / font.units_per_em() as f32;
+    let scale = Scale::uniform(size * em);
+    let width = font
+        .layout(text, scale, point(0.0, 0.0))
+        .last()
+        .map_or(0.0, |glyph| glyph.position().x + glyph.unpositioned().h_metrics().advance_width);
+    let x = match anchor {
+        Anchor::Start => position.0,
+        Anchor::Middle => position.0 - width / 2.0,
+    };
+
+    let (page_width, page_height) = (pixmap.width() as i32, pixmap.height() as i32);
+    let pixels = pixmap.pixels_mut();
+    for glyph in font.layout(text, scale, point(x, position.1)) {
+        let Some(bounds) = glyph.pixel_bounding_box() else {
+            continue;
+        };
+        glyph.draw(|gx, gy, coverage| {
+            let px = bounds.min.x + gx as i32;
+            let py = bounds.min.y + gy as i32;
+            if px < 0 || py < 0 || px >= page_width || py >= page_height {
+                return;
+            }
+            let pixel = &mut pixels[(py * page_width + px) as usize];
+            let a = coverage * ink.alpha as f32 / 255.0;
+            let alpha = (255.0 * a + pixel.alpha() as f32 * (1.0 - a)).round() as u8;
+            let channel = |source: u8, dest: u8| {
+                let blended = (source as f32 * a + dest as f32 * (1.0 - a)).round() as u8;
+                blended.min(alpha)
+            };
+            let blended = PremultipliedColorU8::from_rgba(
+                channel(ink.color.red, pixel.red()),
+                channel(ink.color.green, pixel.green()),
+                channel(ink.color.blue, pixel.blue()),
+                alpha,
+            );
+            if let Some(blended) = blended {
+                *pixel = blended;
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instance_at(text: &str, zone: Tz) -> GrammarInstance {
+        GrammarInstance::at(zone, parse_local_instant(text, zone).unwrap(), true)
+    }
+
+    #[test]
+    fn test_parse_local_instant() {
+        let zone: Tz = "America/New_York".parse().unwrap();
+        let instant = parse_local_instant("2025-01-15 09:30", zone).unwrap();
+        assert_eq!(instant.to_rfc3339(), "2025-01-15T14:30:00+00:00");
+        assert!(parse_local_instant(" 2025-01-15T09:30:12 ", zone).is_ok());
+        assert!(parse_local_instant("09:30", zone).is_err());
+
+        // Skipped when clocks spring forward; the first pass when they fall back
+        assert!(parse_local_instant("2025-03-09 02:30", zone).is_err());
+        let repeated = parse_local_instant("2025-11-02 01:30", zone).unwrap();
+        assert_eq!(repeated.to_rfc3339(), "2025-11-02T05:30:00+00:00");
+    }
+
+    #[test]
+    fn test_layout_keeps_diagram_inside_margins() {
+        for (_, width, height) in PAGE_SIZES {
+            let layout = PageLayout::new(width as f32, height as f32, true, 5);
+            let (left, top) = layout.place(pt2(-HALF_EXTENT, HALF_EXTENT));
+            let (right, bottom) = layout.place(pt2(HALF_EXTENT, -HALF_EXTENT));
+            assert!(left >= layout.margin - 0.5 && right <= width as f32 - layout.margin + 0.5);
+            assert!(top >= layout.margin - 0.5 && bottom <= layout.text_top);
+        }
+
+        // A legend leaves less room for the diagram on a landscape page
+        let plain = PageLayout::new(3000.0, 2000.0, false, 0);
+        let legend = PageLayout::new(3000.0, 2000.0, true, 5);
+        assert!(legend.diagram_scale < plain.diagram_scale);
+    }
+
+    #[test]
+    fn test_compose_scales_with_the_page() {
+        let zone: Tz = "Europe/London".parse().unwrap();
+        let instance = instance_at("2025-06-01 09:30:12", zone);
+        let small = PosterSettings { width: 600, height: 800, ..PosterSettings::default() };
+        let large = PosterSettings { width: 6000, height: 8000, ..PosterSettings::default() };
+
+        let small = compose(&instance, None, "", &small);
+        let large = compose(&instance, None, "", &large);
+        assert_eq!(small.shapes.len(), large.shapes.len());
+        let radius = |poster: &Poster| match &poster.shapes[2] {
+            Shape::Circle { radius, .. } => *radius,
+            shape => panic!("expected a ring mark, got {:?}", shape),
+        };
+        assert!((radius(&large) / radius(&small) - 10.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_svg_and_png_share_the_page() {
+        let zone: Tz = "Asia/Tokyo".parse().unwrap();
+        let instance = instance_at("2025-01-15 21:05:40", zone);
+        let settings = PosterSettings { width: 400, height: 500, ..PosterSettings::default() };
+        let poster = compose(&instance, None, "First light <& last>", &settings);
+
+        let svg = poster.to_svg();
+        assert!(svg.contains(r#"width="400" height="500""#));
+        assert!(svg.contains("<title>First light &lt;&amp; last&gt;</title>"));
+        // Caption, time, zone and four legend rows
+        assert_eq!(svg.matches("<text ").count(), 7);
+
+        let png = poster.to_png().unwrap();
+        assert_eq!(&png[1..4], b"PNG");
+        assert_eq!(u32::from_be_bytes(png[16..20].try_into().unwrap()), 400);
+        assert_eq!(u32::from_be_bytes(png[20..24].try_into().unwrap()), 500);
+    }
+}
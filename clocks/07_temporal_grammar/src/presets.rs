@@ -0,0 +1,90 @@
+//! Presets module - named view setups
+//!
+//! A preset captures everything that frames the diagram: the home zone, pan
+//! and zoom, and the decode/explicit flags. Up to nine are kept, in order,
+//! so each can be recalled with its number key.
+
+use serde::{Deserialize, Serialize};
+use shared::tr_args;
+
+/// Presets beyond this have no number key, so aren't accepted
+pub const MAX_PRESETS: usize = 9;
+
+/// Longest preset name kept, in characters
+const MAX_NAME_CHARS: usize = 32;
+
+/// A saved view
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ViewPreset {
+    pub name: String,
+    pub zone_id: String,
+    pub view_zoom: f32,
+    /// Pan offset, x then y
+    pub view_offset: [f32; 2],
+    pub decode_mode: bool,
+    pub explicit_mode: bool,
+}
+
+/// Tidy a typed preset name; `None` if nothing is left
+pub fn clean_name(name: &str) -> Option<String> {
+    let name: String = name.trim().chars().take(MAX_NAME_CHARS).collect();
+    let name = name.trim_end();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// Store `preset`, replacing one with the same name (ignoring case)
+///
+/// Returns the preset's index, or an error when all slots are taken.
+pub fn upsert(presets: &mut Vec<ViewPreset>, preset: ViewPreset) -> Result<usize, String> {
+    if let Some(index) = presets
+        .iter()
+        .position(|p| p.name.to_lowercase() == preset.name.to_lowercase())
+    {
+        presets[index] = preset;
+        return Ok(index);
+    }
+    if presets.len() >= MAX_PRESETS {
+        return Err(tr_args("All {0} presets are in use; delete one first", &[&MAX_PRESETS]));
+    }
+    presets.push(preset);
+    Ok(presets.len() - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preset(name: &str, view_zoom: f32) -> ViewPreset {
+        ViewPreset {
+            name: name.to_string(),
+            zone_id: "Europe/London".to_string(),
+            view_zoom,
+            view_offset: [0.0, 0.0],
+            decode_mode: false,
+            explicit_mode: false,
+        }
+    }
+
+    #[test]
+    fn test_clean_name() {
+        assert_eq!(clean_name("  desk widget "), Some("desk widget".to_string()));
+        assert_eq!(clean_name("   "), None);
+        assert_eq!(clean_name(&"x".repeat(40)).unwrap().len(), MAX_NAME_CHARS);
+    }
+
+    #[test]
+    fn test_upsert_replaces_by_name_and_caps_slots() {
+        let mut presets = Vec::new();
+        assert_eq!(upsert(&mut presets, preset("Presentation", 1.0)), Ok(0));
+        assert_eq!(upsert(&mut presets, preset("Desk widget", 0.5)), Ok(1));
+        assert_eq!(upsert(&mut presets, preset("presentation", 2.0)), Ok(0));
+        assert_eq!(presets.len(), 2);
+        assert_eq!(presets[0].view_zoom, 2.0);
+
+        for n in presets.len()..MAX_PRESETS {
+            upsert(&mut presets, preset(&format!("Preset {}", n), 1.0)).unwrap();
+        }
+        assert!(upsert(&mut presets, preset("One more", 1.0)).is_err());
+        assert_eq!(upsert(&mut presets, preset("Desk widget", 0.7)), Ok(1));
+    }
+}
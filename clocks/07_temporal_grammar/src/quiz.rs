@@ -0,0 +1,222 @@
+//! Quiz module - practice decoding the diagram
+//!
+//! The quiz freezes the diagram on a random instant with the Truth Anchor
+//! hidden and asks for the time it encodes. The geometry carries the
+//! 12-hour hour, minute and second but not AM/PM, so answers are read on a
+//! 12-hour dial and count as correct within a minute either way.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use shared::{tr, tr_args};
+
+/// How far from now (either direction) a question may be drawn
+const QUESTION_RANGE_DAYS: i64 = 180;
+/// Questions are at least this far from now so they never show live time
+const MIN_OFFSET_SECS: i64 = 3600;
+/// A guess within this many seconds of the answer counts as correct
+pub const TOLERANCE_SECS: i64 = 60;
+
+const DIAL_SECS: i64 = 12 * 3600;
+
+/// Persisted running totals
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuizStats {
+    pub attempts: u32,
+    pub correct: u32,
+    pub streak: u32,
+    pub best_streak: u32,
+}
+
+impl QuizStats {
+    fn record(&mut self, correct: bool) {
+        self.attempts += 1;
+        if correct {
+            self.correct += 1;
+            self.streak += 1;
+            self.best_streak = self.best_streak.max(self.streak);
+        } else {
+            self.streak = 0;
+        }
+    }
+
+    /// Share of correct answers, 0..1
+    pub fn accuracy(&self) -> f32 {
+        if self.attempts == 0 {
+            0.0
+        } else {
+            self.correct as f32 / self.attempts as f32
+        }
+    }
+}
+
+/// A time on the 12-hour dial; seconds are optional when guessing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DialTime {
+    /// 1..=12
+    pub hour12: u32,
+    pub minute: u32,
+    pub second: Option<u32>,
+}
+
+impl DialTime {
+    fn dial_secs(&self) -> i64 {
+        (self.hour12 % 12) as i64 * 3600 + self.minute as i64 * 60 + self.second.unwrap_or(0) as i64
+    }
+
+    /// Signed seconds from `other` to `self`, wrapped to the nearest way round the dial
+    pub fn offset_from(&self, other: &DialTime) -> i64 {
+        let d = (self.dial_secs() - other.dial_secs()).rem_euclid(DIAL_SECS);
+        if d > DIAL_SECS / 2 {
+            d - DIAL_SECS
+        } else {
+            d
+        }
+    }
+
+    pub fn format(&self) -> String {
+        match self.second {
+            Some(s) => format!("{}:{:02}:{:02}", self.hour12, self.minute, s),
+            None => format!("{}:{:02}", self.hour12, self.minute),
+        }
+    }
+}
+
+/// Parse a guess written as `h:mm` or `h:mm:ss` (hour 0-23 is accepted and folded onto the dial)
+pub fn parse_guess(input: &str) -> Result<DialTime, String> {
+    let parts: Vec<&str> = input.trim().split(':').map(str::trim).collect();
+    if parts.len() < 2 || parts.len() > 3 {
+        return Err(tr("Enter the time as h:mm or h:mm:ss").to_string());
+    }
+
+    let field = |s: &str, error: &'static str, max: u32| -> Result<u32, String> {
+        match s.parse::<u32>() {
+            Ok(v) if v <= max => Ok(v),
+            _ => Err(tr_args(error, &[&max])),
+        }
+    };
+
+    let hour = field(parts[0], "Hour must be 0-{0}", 23)?;
+    let minute = field(parts[1], "Minute must be 0-{0}", 59)?;
+    let second = parts.get(2).map(|s| field(s, "Second must be 0-{0}", 59)).transpose()?;
+
+    let hour12 = match hour % 12 {
+        0 => 12,
+        h => h,
+    };
+    Ok(DialTime { hour12, minute, second })
+}
+
+/// Pick a question instant from a uniform random `unit` in 0..1
+///
+/// The first half of the range maps to the past, the second half to the
+/// future, always at least an hour away from `now`.
+pub fn question_instant(now: DateTime<Utc>, unit: f64) -> DateTime<Utc> {
+    let unit = unit.clamp(0.0, 1.0);
+    let (sign, magnitude) = if unit < 0.5 { (-1, unit * 2.0) } else { (1, unit * 2.0 - 1.0) };
+    let span = QUESTION_RANGE_DAYS * 86_400 - MIN_OFFSET_SECS;
+    let offset = MIN_OFFSET_SECS + (magnitude * span as f64) as i64;
+    now + Duration::seconds(sign * offset)
+}
+
+/// Outcome of a submitted guess
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Verdict {
+    pub guess: DialTime,
+    pub correct: bool,
+    /// Seconds the guess was off by (positive = too late)
+    pub error_secs: i64,
+}
+
+/// One question: asking until a guess comes in, then revealed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quiz {
+    pub instant: DateTime<Utc>,
+    pub answer: DialTime,
+    pub verdict: Option<Verdict>,
+}
+
+impl Quiz {
+    pub fn new(instant: DateTime<Utc>, answer: DialTime) -> Self {
+        Self {
+            instant,
+            answer,
+            verdict: None,
+        }
+    }
+
+    pub fn is_asking(&self) -> bool {
+        self.verdict.is_none()
+    }
+
+    /// Score a guess and record it; ignored once the answer is revealed
+    pub fn submit(&mut self, guess: DialTime, stats: &mut QuizStats) -> Option<Verdict> {
+        if !self.is_asking() {
+            return None;
+        }
+        let error_secs = guess.offset_from(&self.answer);
+        let verdict = Verdict {
+            guess,
+            correct: error_secs.abs() <= TOLERANCE_SECS,
+            error_secs,
+        };
+        stats.record(verdict.correct);
+        self.verdict = Some(verdict);
+        Some(verdict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn answer() -> DialTime {
+        DialTime { hour12: 12, minute: 59, second: Some(40) }
+    }
+
+    #[test]
+    fn test_parse_guess() {
+        assert_eq!(parse_guess("3:07").unwrap(), DialTime { hour12: 3, minute: 7, second: None });
+        assert_eq!(parse_guess(" 15:07:09 ").unwrap(), DialTime { hour12: 3, minute: 7, second: Some(9) });
+        assert_eq!(parse_guess("0:30").unwrap().hour12, 12);
+        assert!(parse_guess("3").is_err());
+        assert!(parse_guess("3:60").is_err());
+        assert!(parse_guess("24:00").is_err());
+        assert!(parse_guess("a:00").is_err());
+    }
+
+    #[test]
+    fn test_scoring_wraps_dial_and_tracks_streak() {
+        let mut stats = QuizStats::default();
+        let instant = Utc.with_ymd_and_hms(2024, 6, 1, 0, 59, 40).unwrap();
+
+        // 1:00 is twenty seconds after 12:59:40, across the top of the dial
+        let mut quiz = Quiz::new(instant, answer());
+        let verdict = quiz.submit(parse_guess("1:00").unwrap(), &mut stats).unwrap();
+        assert!(verdict.correct);
+        assert_eq!(verdict.error_secs, 20);
+        assert!(quiz.submit(parse_guess("1:00").unwrap(), &mut stats).is_none());
+
+        let mut quiz = Quiz::new(instant, answer());
+        quiz.submit(parse_guess("12:58:45").unwrap(), &mut stats);
+        assert_eq!(stats, QuizStats { attempts: 2, correct: 2, streak: 2, best_streak: 2 });
+
+        let mut quiz = Quiz::new(instant, answer());
+        let verdict = quiz.submit(parse_guess("11:59").unwrap(), &mut stats).unwrap();
+        assert!(!verdict.correct);
+        assert_eq!(verdict.error_secs, -3640);
+        assert_eq!(stats, QuizStats { attempts: 3, correct: 2, streak: 0, best_streak: 2 });
+    }
+
+    #[test]
+    fn test_question_instant_avoids_now() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+        for unit in [0.0, 0.25, 0.4999, 0.5, 0.75, 1.0] {
+            let offset = (question_instant(now, unit) - now).num_seconds().abs();
+            assert!(offset >= MIN_OFFSET_SECS);
+            assert!(offset <= QUESTION_RANGE_DAYS * 86_400);
+        }
+        assert!(question_instant(now, 0.1) < now);
+        assert!(question_instant(now, 0.9) > now);
+    }
+}
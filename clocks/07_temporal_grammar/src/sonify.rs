@@ -0,0 +1,327 @@
+//! Sonify module - the grammar as sound, for reading the time without looking
+//!
+//! The hour sets the pitch of a sawtooth drone (a semitone per hour, two
+//! octaves over the day), the minute sweeps a low-pass filter across it from
+//! dark to bright over the hour, and each second adds a soft tick.
+//!
+//! The voice mapping and the synth are always compiled; sound output uses
+//! rodio and is only built with the `audio` feature. Without it (or without
+//! an output device) the clock stays silent.
+
+use std::f32::consts::TAU;
+
+use serde::{Deserialize, Serialize};
+use shared::TimeData;
+
+/// Output sample rate of the synth
+#[cfg_attr(not(feature = "audio"), allow(dead_code))]
+pub const SAMPLE_RATE: u32 = 44_100;
+
+/// Drone pitch at midnight (A2); each hour adds a semitone
+const BASE_PITCH_HZ: f32 = 110.0;
+/// Filter cutoff at the top of the hour and just before the next one
+const CUTOFF_MIN_HZ: f32 = 200.0;
+const CUTOFF_MAX_HZ: f32 = 4000.0;
+/// Loudness of the drone and tick before the user's volume is applied
+const DRONE_GAIN: f32 = 0.2;
+const TICK_GAIN: f32 = 0.25;
+const TICK_HZ: f32 = 1800.0;
+/// Per-sample tick decay (about 25 ms to fade)
+const TICK_DECAY: f32 = 0.996;
+/// Per-sample glide toward new pitch, cutoff and volume (about 50 ms)
+const GLIDE: f32 = 0.0005;
+
+/// Persisted sonification preferences
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SonificationSettings {
+    pub enabled: bool,
+    pub volume: f32,
+    /// Tick on every second
+    pub ticks: bool,
+}
+
+impl Default for SonificationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            volume: 0.5,
+            ticks: true,
+        }
+    }
+}
+
+/// What the drone plays for one moment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Voice {
+    pub pitch_hz: f32,
+    /// Filter position across the hour, 0 (dark) to 1 (bright)
+    pub brightness: f32,
+    pub volume: f32,
+}
+
+/// Drone pitch for a local hour (0-23)
+pub fn hour_pitch(hour24: u32) -> f32 {
+    BASE_PITCH_HZ * 2f32.powf(hour24 as f32 / 12.0)
+}
+
+/// How far through the hour a moment is, 0 to 1
+pub fn hour_progress(minute: u32, second: u32) -> f32 {
+    (minute * 60 + second) as f32 / 3600.0
+}
+
+/// Low-pass cutoff for a brightness, swept exponentially so each minute
+/// sounds like an even step
+#[cfg_attr(not(feature = "audio"), allow(dead_code))]
+pub fn cutoff_hz(brightness: f32) -> f32 {
+    CUTOFF_MIN_HZ * (CUTOFF_MAX_HZ / CUTOFF_MIN_HZ).powf(brightness.clamp(0.0, 1.0))
+}
+
+/// The voice for `time_data`, or `None` when sonification is off or silent
+pub fn voice(time_data: &TimeData, settings: &SonificationSettings) -> Option<Voice> {
+    if !settings.enabled || settings.volume <= 0.0 {
+        return None;
+    }
+    Some(Voice {
+        pitch_hz: hour_pitch(time_data.hour24),
+        brightness: hour_progress(time_data.minute, time_data.second),
+        volume: settings.volume,
+    })
+}
+
+/// Sawtooth drone through a one-pole low-pass, plus a decaying sine tick
+///
+/// Pitch, cutoff and volume glide toward each new voice so changes don't
+/// click; with no voice the drone fades out.
+#[derive(Debug, Default)]
+#[cfg_attr(not(feature = "audio"), allow(dead_code))]
+pub struct Synth {
+    pitch_hz: f32,
+    cutoff_hz: f32,
+    gain: f32,
+    phase: f32,
+    filtered: f32,
+    tick_phase: f32,
+    tick_level: f32,
+}
+
+#[cfg_attr(not(feature = "audio"), allow(dead_code))]
+impl Synth {
+    /// Start a tick at `volume`
+    pub fn trigger_tick(&mut self, volume: f32) {
+        self.tick_level = TICK_GAIN * volume;
+        self.tick_phase = 0.0;
+    }
+
+    /// The next mono sample
+    pub fn next_sample(&mut self, voice: Option<&Voice>) -> f32 {
+        let target_gain = match voice {
+            Some(voice) => {
+                // Jump straight to the first pitch instead of sliding up from 0 Hz
+                if self.gain <= f32::EPSILON {
+                    self.pitch_hz = voice.pitch_hz;
+                    self.cutoff_hz = cutoff_hz(voice.brightness);
+                }
+                self.pitch_hz += (voice.pitch_hz - self.pitch_hz) * GLIDE;
+                self.cutoff_hz += (cutoff_hz(voice.brightness) - self.cutoff_hz) * GLIDE;
+                DRONE_GAIN * voice.volume
+            }
+            None => 0.0,
+        };
+        self.gain += (target_gain - self.gain) * GLIDE;
+
+        let rate = SAMPLE_RATE as f32;
+        self.phase = (self.phase + self.pitch_hz / rate).fract();
+        let saw = 2.0 * self.phase - 1.0;
+        let alpha = 1.0 - (-TAU * self.cutoff_hz / rate).exp();
+        self.filtered += alpha * (saw - self.filtered);
+
+        let tick = self.tick_level * (TAU * self.tick_phase).sin();
+        self.tick_phase = (self.tick_phase + TICK_HZ / rate).fract();
+        self.tick_level *= TICK_DECAY;
+
+        self.filtered * self.gain + tick
+    }
+}
+
+#[cfg(feature = "audio")]
+mod output {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use rodio::{OutputStream, Source};
+
+    use super::{Synth, Voice, SAMPLE_RATE};
+
+    /// Shown when the output could not be opened
+    pub const UNAVAILABLE_REASON: &str = "No audio output device found";
+
+    /// Samples between looks at the latest voice
+    const CONTROL_BLOCK: usize = 256;
+
+    /// Latest voice and pending tick, handed from the UI to the audio thread
+    #[derive(Default)]
+    struct Control {
+        voice: Option<Voice>,
+        tick: bool,
+    }
+
+    /// Endless source driving the synth from `Control`
+    struct SynthSource {
+        synth: Synth,
+        control: Arc<Mutex<Control>>,
+        voice: Option<Voice>,
+        until_control: usize,
+    }
+
+    impl Iterator for SynthSource {
+        type Item = f32;
+
+        fn next(&mut self) -> Option<f32> {
+            if self.until_control == 0 {
+                self.until_control = CONTROL_BLOCK;
+                if let Ok(mut control) = self.control.lock() {
+                    self.voice = control.voice;
+                    if std::mem::take(&mut control.tick) {
+                        if let Some(voice) = self.voice {
+                            self.synth.trigger_tick(voice.volume);
+                        }
+                    }
+                }
+            }
+            self.until_control -= 1;
+            Some(self.synth.next_sample(self.voice.as_ref()))
+        }
+    }
+
+    impl Source for SynthSource {
+        fn current_frame_len(&self) -> Option<usize> {
+            None
+        }
+
+        fn channels(&self) -> u16 {
+            1
+        }
+
+        fn sample_rate(&self) -> u32 {
+            SAMPLE_RATE
+        }
+
+        fn total_duration(&self) -> Option<Duration> {
+            None
+        }
+    }
+
+    /// Plays the synth on the default output device
+    pub struct Sonifier {
+        /// Keeps the output stream alive
+        _stream: OutputStream,
+        control: Arc<Mutex<Control>>,
+    }
+
+    impl Sonifier {
+        pub fn new() -> Option<Self> {
+            let (stream, handle) = OutputStream::try_default().ok()?;
+            let control = Arc::new(Mutex::new(Control::default()));
+            let source = SynthSource {
+                synth: Synth::default(),
+                control: Arc::clone(&control),
+                voice: None,
+                until_control: 0,
+            };
+            handle.play_raw(source).ok()?;
+            Some(Self {
+                _stream: stream,
+                control,
+            })
+        }
+
+        /// Play `voice` from now on; `None` fades the drone out
+        pub fn set_voice(&self, voice: Option<Voice>) {
+            if let Ok(mut control) = self.control.lock() {
+                control.voice = voice;
+            }
+        }
+
+        /// Sound one tick at the current voice's volume
+        pub fn tick(&self) {
+            if let Ok(mut control) = self.control.lock() {
+                control.tick = true;
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "audio"))]
+mod output {
+    use super::Voice;
+
+    /// Shown when the output could not be opened
+    pub const UNAVAILABLE_REASON: &str = "Built without audio (enable the `audio` feature)";
+
+    /// Silent stand-in when the `audio` feature is disabled
+    #[allow(dead_code)]
+    pub struct Sonifier;
+
+    impl Sonifier {
+        pub fn new() -> Option<Self> {
+            None
+        }
+
+        pub fn set_voice(&self, _voice: Option<Voice>) {}
+
+        pub fn tick(&self) {}
+    }
+}
+
+pub use output::{Sonifier, UNAVAILABLE_REASON};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Tz;
+    use shared::compute_time_data_at;
+
+    fn time_at(hour: u32, minute: u32, second: u32) -> TimeData {
+        let tz: Tz = "UTC".parse().unwrap();
+        compute_time_data_at(tz, Utc.with_ymd_and_hms(2025, 1, 15, hour, minute, second).unwrap())
+    }
+
+    #[test]
+    fn test_voice_mapping() {
+        let settings = SonificationSettings {
+            enabled: true,
+            ..SonificationSettings::default()
+        };
+        let noon = voice(&time_at(12, 0, 0), &settings).unwrap();
+        assert!((noon.pitch_hz - 220.0).abs() < 0.01);
+        assert_eq!(noon.brightness, 0.0);
+
+        let half_past = voice(&time_at(12, 30, 0), &settings).unwrap();
+        assert_eq!(half_past.brightness, 0.5);
+        assert!(hour_pitch(23) > hour_pitch(22));
+
+        assert_eq!(voice(&time_at(12, 0, 0), &SonificationSettings::default()), None);
+    }
+
+    #[test]
+    fn test_synth_fades_out_without_voice() {
+        let mut synth = Synth::default();
+        let voice = Voice {
+            pitch_hz: 220.0,
+            brightness: 0.5,
+            volume: 1.0,
+        };
+        let playing = (0..SAMPLE_RATE)
+            .map(|_| synth.next_sample(Some(&voice)).abs())
+            .fold(0.0, f32::max);
+        assert!(playing > 0.05);
+
+        let silent = (0..SAMPLE_RATE)
+            .map(|_| synth.next_sample(None).abs())
+            .last()
+            .unwrap();
+        assert!(silent < 0.001);
+    }
+}
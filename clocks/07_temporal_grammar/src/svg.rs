@@ -0,0 +1,299 @@
+//! SVG module - Standalone SVG rendering of the diagram
+//!
+//! Serializes the same layers the nannou renderer draws (hour polygon, minute
+//! superellipse, phase ring, DST knot) into SVG paths using the current
+//! palette, so a "sentence" for a given instant can be printed or shared.
+
+use std::fmt::Write;
+
+use nannou::prelude::*;
+
+use crate::drawing::colors;
+use crate::geometry::{DstKnot, PhaseRing};
+
+/// Half-size of the square drawing area (geometry is laid out for min_dim 600)
+pub const HALF_EXTENT: f32 = 350.0;
+
+/// Height of the caption band under the diagram
+const CAPTION_HEIGHT: f32 = 70.0;
+
+/// Everything the SVG needs for one instant
+pub struct SvgDiagram<'a> {
+    pub hour_polygon: &'a [Point2],
+    pub minute_superellipse: &'a [Point2],
+    pub phase_ring: &'a PhaseRing,
+    pub dst_knot: Option<&'a DstKnot>,
+    /// Caption line, e.g. "09:30:12 AM · Mon, Jan 15, 2025"
+    pub caption: &'a str,
+    /// Secondary caption line, e.g. "America/New_York · UTC-05:00"
+    pub subcaption: &'a str,
+    /// Accessible description of the diagram
+    pub description: &'a str,
+}
+
+/// Render the diagram as a standalone SVG document
+///
+/// Geometry is centered on the origin in nannou's y-up space and is flipped
+/// into SVG's y-down space.
+pub fn render_svg(diagram: &SvgDiagram) -> String {
+    let width = HALF_EXTENT * 2.0;
+    let height = width + CAPTION_HEIGHT;
+    let mut svg = String::new();
+
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="{x} {y} {w} {h}">"#,
+        w = width,
+        h = height,
+        x = -HALF_EXTENT,
+        y = -HALF_EXTENT,
+    );
+    let _ = writeln!(svg, "  <title>{}</title>", escape_xml(diagram.caption));
+    let _ = writeln!(svg, "  <desc>{}</desc>", escape_xml(diagram.description));
+    let _ = writeln!(
+        svg,
+        r#"  <rect x="{x}" y="{y}" width="{w}" height="{h}" fill="{fill}"/>"#,
+        x = -HALF_EXTENT,
+        y = -HALF_EXTENT,
+        w = width,
+        h = height,
+        fill = rgb(colors::background()),
+    );
+
+    // Foundation layer (hour polygon)
+    if !diagram.hour_polygon.is_empty() {
+        let _ = writeln!(
+            svg,
+            r#"  <polygon points="{}" fill="{}" fill-opacity="{}" stroke="{}" stroke-width="2"/>"#,
+            points_attr(diagram.hour_polygon),
+            rgb(colors::FOUNDATION),
+            opacity(180),
+            rgb(colors::FOUNDATION_STROKE),
+        );
+    }
+
+    // Tension layer (minute superellipse)
+    if !diagram.minute_superellipse.is_empty() {
+        let _ = writeln!(
+            svg,
+            r#"  <polygon points="{}" fill="{}" fill-opacity="{}" stroke="{}" stroke-width="2.5"/>"#,
+            points_attr(diagram.minute_superellipse),
+            rgb(colors::TENSION),
+            opacity(100),
+            rgb(colors::TENSION_STROKE),
+        );
+    }
+
+    write_phase_layer(&mut svg, diagram.phase_ring);
+
+    if let Some(knot) = diagram.dst_knot {
+        write_dst_knot(&mut svg, knot);
+    }
+
+    // Caption
+    let caption_y = HALF_EXTENT + CAPTION_HEIGHT * 0.45;
+    let _ = writeln!(
+        svg,
+        r#"  <text x="0" y="{:.1}" text-anchor="middle" font-family="sans-serif" font-size="20" fill="{}">{}</text>"#,
+        caption_y,
+        rgb(colors::text_primary()),
+        escape_xml(diagram.caption),
+    );
+    let _ = writeln!(
+        svg,
+        r#"  <text x="0" y="{:.1}" text-anchor="middle" font-family="sans-serif" font-size="13" fill="{}">{}</text>"#,
+        caption_y + 22.0,
+        rgb(colors::text_secondary()),
+        escape_xml(diagram.subcaption),
+    );
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Phase ring marks, highlighted mark with glow, and needle
+fn write_phase_layer(svg: &mut String, ring: &PhaseRing) {
+    let mark_size = 4.0;
+    let highlight_size = 8.0;
+
+    svg.push_str("  <g>\n");
+    for (i, mark) in ring.marks.iter().enumerate() {
+        let (x, y) = flip(*mark);
+        if i == ring.highlighted_index {
+            let _ = writeln!(
+                svg,
+                r#"    <circle cx="{:.2}" cy="{:.2}" r="{:.1}" fill="{}" fill-opacity="{}"/>"#,
+                x,
+                y,
+                highlight_size * 1.5,
+                rgb(colors::PHASE_HIGHLIGHT),
+                opacity(60),
+            );
+            let _ = writeln!(
+                svg,
+                r#"    <circle cx="{:.2}" cy="{:.2}" r="{:.1}" fill="{}"/>"#,
+                x,
+                y,
+                highlight_size,
+                rgb(colors::PHASE_HIGHLIGHT),
+            );
+        } else if i % 5 == 0 {
+            let _ = writeln!(
+                svg,
+                r#"    <circle cx="{:.2}" cy="{:.2}" r="{:.1}" fill="{}"/>"#,
+                x,
+                y,
+                mark_size * 1.2,
+                rgb(colors::PHASE_MARK),
+            );
+        } else {
+            let _ = writeln!(
+                svg,
+                r#"    <circle cx="{:.2}" cy="{:.2}" r="{:.1}" fill="{}" fill-opacity="{}"/>"#,
+                x,
+                y,
+                mark_size * 0.6,
+                rgb(colors::PHASE_MARK),
+                opacity(150),
+            );
+        }
+    }
+    svg.push_str("  </g>\n");
+
+    // Needle
+    let needle_end = pt2(
+        ring.center.x + ring.radius * 0.85 * ring.needle_angle.cos(),
+        ring.center.y + ring.radius * 0.85 * ring.needle_angle.sin(),
+    );
+    let (cx, cy) = flip(ring.center);
+    let (nx, ny) = flip(needle_end);
+    let _ = writeln!(
+        svg,
+        r#"  <line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="{}" stroke-width="2"/>"#,
+        cx,
+        cy,
+        nx,
+        ny,
+        rgb(colors::PHASE_NEEDLE),
+    );
+    let _ = writeln!(
+        svg,
+        r#"  <circle cx="{:.2}" cy="{:.2}" r="4" fill="{}"/>"#,
+        nx,
+        ny,
+        rgb(colors::PHASE_NEEDLE),
+    );
+}
+
+/// DST knot loop, anchor and control point glow
+fn write_dst_knot(svg: &mut String, knot: &DstKnot) {
+    let color = if knot.is_upcoming {
+        colors::dst_knot()
+    } else {
+        colors::DST_KNOT_PAST
+    };
+
+    let (ax, ay) = flip(knot.anchor);
+    let mut path = format!("M {:.2} {:.2}", ax, ay);
+    for cp in &knot.control_points {
+        let (x, y) = flip(*cp);
+        let _ = write!(path, " L {:.2} {:.2}", x, y);
+    }
+    path.push_str(" Z");
+
+    let _ = writeln!(
+        svg,
+        r#"  <path d="{}" fill="none" stroke="{}" stroke-width="2.25" stroke-linejoin="round"/>"#,
+        path,
+        rgb(color),
+    );
+    let _ = writeln!(
+        svg,
+        r#"  <circle cx="{:.2}" cy="{:.2}" r="5" fill="{}"/>"#,
+        ax,
+        ay,
+        rgb(color),
+    );
+    for cp in &knot.control_points {
+        let (x, y) = flip(*cp);
+        let _ = writeln!(
+            svg,
+            r#"  <circle cx="{:.2}" cy="{:.2}" r="{:.2}" fill="{}" fill-opacity="{}"/>"#,
+            x,
+            y,
+            knot.amplitude * 0.3,
+            rgb(color),
+            opacity(40),
+        );
+    }
+}
+
+/// nannou (y-up) to SVG (y-down) coordinates
+fn flip(p: Point2) -> (f32, f32) {
+    (p.x, -p.y)
+}
+
+fn points_attr(points: &[Point2]) -> String {
+    points
+        .iter()
+        .map(|p| {
+            let (x, y) = flip(*p);
+            format!("{:.2},{:.2}", x, y)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn rgb(color: Srgb<u8>) -> String {
+    format!("rgb({},{},{})", color.red, color.green, color.blue)
+}
+
+/// 8-bit alpha as an SVG opacity value
+pub fn opacity(alpha: u8) -> String {
+    format!("{:.3}", alpha as f32 / 255.0)
+}
+
+pub fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{compute_hour_polygon, compute_phase_ring, compute_superellipse};
+
+    #[test]
+    fn test_render_svg_layers() {
+        let center = pt2(0.0, 0.0);
+        let polygon = compute_hour_polygon(9, 600.0, center);
+        let superellipse = compute_superellipse(30, 600.0, center, 64);
+        let ring = compute_phase_ring(12, 0.0, 600.0, center, true);
+
+        let svg = render_svg(&SvgDiagram {
+            hour_polygon: &polygon,
+            minute_superellipse: &superellipse,
+            phase_ring: &ring,
+            dst_knot: None,
+            caption: "09:30:12 AM",
+            subcaption: "Europe/London · UTC+00:00",
+            description: "Hour: 9 <12-sided polygon> & more",
+        });
+
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches("<polygon ").count(), 2);
+        // 60 marks plus the highlight glow and the needle tip
+        assert_eq!(svg.matches("<circle ").count(), 62);
+        assert!(svg.contains("&lt;12-sided polygon&gt; &amp; more"));
+        assert!(!svg.contains("<path "));
+    }
+
+    #[test]
+    fn test_flip_y_axis() {
+        let polygon = vec![pt2(10.0, 20.0)];
+        assert_eq!(points_attr(&polygon), "10.00,-20.00");
+    }
+}
@@ -0,0 +1,182 @@
+//! Tween module - animated transitions between consecutive diagram states
+//!
+//! When the hour, minute or zone changes, the diagram moves from the shape
+//! it is showing to the new one instead of jumping: the hour polygon morphs
+//! its vertex count, the superellipse exponent eases, and the rotations
+//! follow a lightly damped spring. Reduced motion turns all of it off.
+
+use std::f32::consts::{PI, TAU};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::geometry::DiagramShape;
+
+/// Shortest and longest transition the sidebar slider offers
+pub const MIN_DURATION_MS: u32 = 100;
+pub const MAX_DURATION_MS: u32 = 2000;
+
+/// Easing curve for the vertex count, exponent and skew
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Easing {
+    Linear,
+    #[default]
+    EaseInOut,
+    EaseOut,
+}
+
+impl Easing {
+    pub const ALL: [Easing; 3] = [Easing::Linear, Easing::EaseInOut, Easing::EaseOut];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Easing::Linear => "Linear",
+            Easing::EaseInOut => "Ease in-out",
+            Easing::EaseOut => "Ease out",
+        }
+    }
+
+    /// Progress along the curve for linear progress `t` in 0..=1
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseInOut if t < 0.5 => 4.0 * t * t * t,
+            Easing::EaseInOut => 1.0 - (-2.0 * t + 2.0).powi(3) / 2.0,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+        }
+    }
+}
+
+/// Damped spring response for `t` in 0..=1: overshoots a little, then
+/// settles exactly on 1 at the end of the transition
+pub fn spring(t: f32) -> f32 {
+    if t >= 1.0 {
+        return 1.0;
+    }
+    let t = t.max(0.0);
+    let decay = 5.0;
+    let frequency = 2.5 * PI;
+    1.0 - (-decay * t).exp()
+        * ((frequency * t).cos() + decay / frequency * (frequency * t).sin())
+}
+
+/// Persisted transition duration and easing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransitionSettings {
+    pub duration_ms: u32,
+    pub easing: Easing,
+}
+
+impl Default for TransitionSettings {
+    fn default() -> Self {
+        Self {
+            duration_ms: 450,
+            easing: Easing::default(),
+        }
+    }
+}
+
+impl TransitionSettings {
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(self.duration_ms.clamp(MIN_DURATION_MS, MAX_DURATION_MS) as u64)
+    }
+}
+
+/// `to` moved by whole turns so it is within half a turn of `from`
+fn nearest_angle(from: f32, to: f32) -> f32 {
+    from + (to - from + PI).rem_euclid(TAU) - PI
+}
+
+fn lerp(from: f32, to: f32, t: f32) -> f32 {
+    from + (to - from) * t
+}
+
+/// One transition between two diagram shapes
+#[derive(Debug, Clone, Copy)]
+pub struct Tween {
+    from: DiagramShape,
+    /// The target, with rotations unwrapped to take the short way round
+    to: DiagramShape,
+    started: Instant,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl Tween {
+    pub fn new(
+        from: DiagramShape,
+        to: DiagramShape,
+        now: Instant,
+        settings: &TransitionSettings,
+    ) -> Self {
+        let to = DiagramShape {
+            hour_rotation: nearest_angle(from.hour_rotation, to.hour_rotation),
+            minute_rotation: nearest_angle(from.minute_rotation, to.minute_rotation),
+            ..to
+        };
+        Self {
+            from,
+            to,
+            started: now,
+            duration: settings.duration(),
+            easing: settings.easing,
+        }
+    }
+
+    fn progress(&self, now: Instant) -> f32 {
+        now.saturating_duration_since(self.started).as_secs_f32() / self.duration.as_secs_f32()
+    }
+
+    pub fn is_finished(&self, now: Instant) -> bool {
+        self.progress(now) >= 1.0
+    }
+
+    /// The in-between shape at `now`
+    pub fn shape_at(&self, now: Instant) -> DiagramShape {
+        let t = self.progress(now);
+        let eased = self.easing.apply(t);
+        let sprung = spring(t);
+        let (from, to) = (&self.from, &self.to);
+        DiagramShape {
+            vertex_count: lerp(from.vertex_count, to.vertex_count, eased),
+            hour_rotation: lerp(from.hour_rotation, to.hour_rotation, sprung),
+            exponent: lerp(from.exponent, to.exponent, eased),
+            minute_rotation: lerp(from.minute_rotation, to.minute_rotation, sprung),
+            tz_rotation: lerp(from.tz_rotation, to.tz_rotation, sprung),
+            tz_skew_x: lerp(from.tz_skew_x, to.tz_skew_x, eased),
+            dst_shear_y: lerp(from.dst_shear_y, to.dst_shear_y, eased),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_easings_start_and_end_in_place() {
+        for easing in Easing::ALL {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-6, "{:?}", easing);
+        }
+        assert_eq!(spring(0.0), 0.0);
+        assert_eq!(spring(1.0), 1.0);
+        // The spring overshoots before settling
+        assert!((0..100).any(|i| spring(i as f32 / 100.0) > 1.0));
+    }
+
+    #[test]
+    fn test_minute_wrap_takes_the_short_way() {
+        // 10:59 -> 11:00 turns the superellipse 6 degrees, not back 354
+        let from = DiagramShape::for_time(10, 59, 0, false);
+        let to = DiagramShape::for_time(11, 0, 0, false);
+        let start = Instant::now();
+        let tween = Tween::new(from, to, start, &TransitionSettings::default());
+        let end = tween.shape_at(start + Duration::from_secs(5));
+        assert!((end.minute_rotation - from.minute_rotation).abs() < 7.0_f32.to_radians());
+        assert!((end.vertex_count - 14.0).abs() < 1e-6);
+        assert!(tween.is_finished(start + Duration::from_secs(5)));
+    }
+}
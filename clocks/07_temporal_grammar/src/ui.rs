@@ -1,11 +1,27 @@
 //! UI module for the Temporal Grammar Clock
 //!
-//! Provides the sidebar panel with timezone picker, mode toggles,
-//! diagram description, and accessibility controls using egui.
+//! Provides the sidebar panel with timezone picker, mode toggles, view
+//! presets, diagram description, and accessibility controls using egui,
+//! the geometry inspector window and the time poster window.
 
 use chrono_tz::Tz;
 use nannou_egui::egui;
-use shared::{search_timezones, system_timezone, DstChange, TimeData};
+use shared::{
+    active_palette, clip_recording_settings, favorites_settings, keymap_settings, language_settings,
+    order_by_region, quiet_hours_settings, reduced_motion_settings, region_change, region_heading,
+    reveal_hotkey_settings, safe_palette_settings, search_timezones, system_timezone,
+    system_zone_settings, tr, tr_args, window_title_settings, zone_display_name, ClipSettings,
+    DstChange, FollowSystemZone, Keymap, KeymapEdit, LanguageSetting, MotionPreference, QuietHours,
+    RevealHotkey, Rgb, SafePaletteSetting, ThemeLibrary, TimeData, WindowTitleClock,
+};
+
+use crate::explicit::{Alignment, ExplicitLayout, MAX_FONT_SCALE, MIN_FONT_SCALE};
+use crate::geometry::GeometryParams;
+use crate::poster::{PosterFormat, PosterSettings, MAX_SIDE, MIN_SIDE, PAGE_SIZES};
+use crate::presets::ViewPreset;
+use crate::quiz::{Quiz, QuizStats, TOLERANCE_SECS};
+use crate::sonify::SonificationSettings;
+use crate::tween::{Easing, TransitionSettings, MAX_DURATION_MS, MIN_DURATION_MS};
 
 /// State for the timezone picker
 #[derive(Default)]
@@ -24,7 +40,7 @@ impl PickerState {
     pub fn open(&mut self) {
         self.is_open = true;
         self.search_query.clear();
-        self.search_results = search_timezones("");
+        self.search_results = order_by_region(search_timezones(""));
         self.should_focus_search = true;
     }
 
@@ -35,7 +51,28 @@ impl PickerState {
     }
 
     pub fn update_search(&mut self) {
-        self.search_results = search_timezones(&self.search_query);
+        self.search_results = order_by_region(search_timezones(&self.search_query));
+    }
+}
+
+/// State for the time poster window; the instant and caption are typed
+/// fresh for each poster rather than saved
+#[derive(Default)]
+pub struct PosterState {
+    pub is_open: bool,
+    /// Local date and time in the home zone, "YYYY-MM-DD HH:MM:SS"
+    pub instant_input: String,
+    pub caption: String,
+    /// Why the last render was refused
+    pub error: Option<String>,
+}
+
+impl PosterState {
+    /// Open the window on the instant the diagram shows
+    pub fn open(&mut self, time_data: &TimeData) {
+        self.is_open = true;
+        self.instant_input = time_data.local_datetime.format("%Y-%m-%d %H:%M:%S").to_string();
+        self.error = None;
     }
 }
 
@@ -50,33 +87,110 @@ pub struct SidebarResult {
     pub toggle_decode_mode: bool,
     /// Toggle explicit mode
     pub toggle_explicit_mode: bool,
-    /// Toggle reduced motion
-    pub toggle_reduced_motion: bool,
+    /// Change what the explicit readout shows and how
+    pub set_explicit_layout: Option<ExplicitLayout>,
+    /// Open or close the geometry inspector
+    pub toggle_geometry_inspector: bool,
+    /// Set the reduced motion preference
+    pub set_motion_preference: Option<MotionPreference>,
+    /// Change the transition duration or easing
+    pub set_transition: Option<TransitionSettings>,
+    /// Change the sonification settings
+    pub set_sonification: Option<SonificationSettings>,
+    /// Replace the shared quiet-hours schedule
+    pub set_quiet_hours: Option<QuietHours>,
+    /// Replace the shared window title clock setting
+    pub set_window_title: Option<WindowTitleClock>,
+    /// Replace the shared clip recording settings
+    pub set_clip_settings: Option<ClipSettings>,
+    /// Replace the shared language
+    pub set_language: Option<LanguageSetting>,
+    /// Replace the shared safe palette
+    pub set_safe_palette: Option<SafePaletteSetting>,
+    /// Replace the shared follow-system-zone setting
+    pub set_follow_system_zone: Option<FollowSystemZone>,
+    /// Replace the shared reveal shortcut
+    pub set_reveal_hotkey: Option<RevealHotkey>,
+    /// Keep this clock's own favorites (`true`) or follow the shared list
+    pub set_own_favorites: Option<bool>,
+    /// Shortcut rebound, reset or waiting for its new chord
+    pub keymap_edit: Option<KeymapEdit>,
     /// Open help panel
     pub open_help: bool,
     /// Step time by seconds (positive = forward, negative = backward)
     pub step_time: Option<i64>,
     /// Return to live time
     pub return_to_live: bool,
+    /// Export the diagram as SVG
+    pub export_svg: bool,
+    /// Open the time poster window
+    pub open_poster: bool,
+    /// Start or stop the decode quiz
+    pub toggle_quiz: bool,
+    /// Score the typed answer
+    pub submit_guess: bool,
+    /// Move on to a new question
+    pub next_question: bool,
+    /// Clear the quiz score and streaks
+    pub reset_quiz_stats: bool,
+    /// Switch theme (`Some(None)` for the clock's own colors)
+    pub set_theme: Option<Option<String>>,
+    /// Compare with another zone (`Some(None)` to stop comparing)
+    pub set_comparison: Option<Option<Tz>>,
+    /// Open or close the favorites atlas
+    pub toggle_atlas: bool,
+    /// Save the current view under the typed name
+    pub save_view_preset: bool,
+    /// Switch to the view preset at this index
+    pub apply_view_preset: Option<usize>,
+    /// Delete the view preset at this index
+    pub delete_view_preset: Option<usize>,
 }
 
 /// Draw the sidebar panel
+#[allow(clippy::too_many_arguments)]
 pub fn draw_sidebar(
     ctx: &egui::Context,
     picker_state: &mut PickerState,
     selected_zone: Tz,
     favorites: &[Tz],
+    comparison_zone: Option<Tz>,
+    atlas_open: bool,
     time_data: &TimeData,
     decode_mode: bool,
     explicit_mode: bool,
-    reduced_motion: bool,
+    explicit_layout: ExplicitLayout,
+    motion_preference: MotionPreference,
+    system_reduced_motion: bool,
+    transition: TransitionSettings,
+    sonification: SonificationSettings,
+    sonification_status: Option<&'static str>,
     diagram_description: &str,
     is_live: bool,
+    quiz: Option<&Quiz>,
+    quiz_stats: &QuizStats,
+    quiz_input: &mut String,
+    quiet_hours: &QuietHours,
+    window_title: WindowTitleClock,
+    clip_settings: ClipSettings,
+    language: LanguageSetting,
+    safe_palette: SafePaletteSetting,
+    follow_system_zone: FollowSystemZone,
+    reveal_hotkey: &RevealHotkey,
+    reveal_status: Option<&str>,
+    own_favorites: bool,
+    keymap: &Keymap,
+    themes: &ThemeLibrary,
+    theme: Option<&str>,
+    view_presets: &[ViewPreset],
+    preset_name_input: &mut String,
 ) -> SidebarResult {
     let mut result = SidebarResult::default();
+    let quiz_asking = quiz.is_some_and(|q| q.is_asking());
 
     // Apply temporal grammar theme
     let mut style = (*ctx.style()).clone();
+    style.visuals = egui::Visuals::dark();
     style.visuals.window_fill = egui::Color32::from_rgb(18, 16, 26);
     style.visuals.panel_fill = egui::Color32::from_rgb(18, 16, 26);
     style.visuals.widgets.noninteractive.bg_fill = egui::Color32::from_rgb(28, 24, 40);
@@ -84,6 +198,7 @@ pub fn draw_sidebar(
     style.visuals.widgets.hovered.bg_fill = egui::Color32::from_rgb(50, 45, 70);
     style.visuals.widgets.active.bg_fill = egui::Color32::from_rgb(65, 55, 90);
     style.visuals.override_text_color = Some(egui::Color32::from_rgb(200, 200, 210));
+    theme_visuals(&mut style.visuals);
     ctx.set_style(style);
 
     egui::SidePanel::right("sidebar")
@@ -94,7 +209,7 @@ pub fn draw_sidebar(
 
             // Title
             ui.heading(
-                egui::RichText::new("Temporal Grammar")
+                egui::RichText::new(tr("Temporal Grammar"))
                     .color(egui::Color32::from_rgb(100, 200, 255))
                     .size(18.0),
             );
@@ -103,7 +218,7 @@ pub fn draw_sidebar(
             // Timezone section
             ui.group(|ui| {
                 ui.label(
-                    egui::RichText::new("▸ TIMEZONE")
+                    egui::RichText::new(tr("▸ TIMEZONE"))
                         .size(13.0)
                         .color(egui::Color32::from_rgb(100, 200, 255)),
                 );
@@ -127,7 +242,7 @@ pub fn draw_sidebar(
 
                 // Open picker button
                 if ui
-                    .button(egui::RichText::new("Change Zone (Z)").size(12.0))
+                    .button(egui::RichText::new(tr("Change Zone (Z)")).size(12.0))
                     .clicked()
                 {
                     picker_state.open();
@@ -135,13 +250,41 @@ pub fn draw_sidebar(
 
                 // System timezone shortcut
                 if ui
-                    .button(egui::RichText::new("Use System TZ").size(12.0))
+                    .button(egui::RichText::new(tr("Use System TZ")).size(12.0))
                     .clicked()
                 {
                     if let Some(sys_tz) = system_timezone() {
                         result.set_timezone = Some(sys_tz);
                     }
                 }
+
+                // Side-by-side comparison with a favorite
+                let compare_text = comparison_zone.map_or(tr("None").to_string(), format_zone_name);
+                egui::ComboBox::from_label(tr("Compare (C)"))
+                    .selected_text(compare_text)
+                    .show_ui(ui, |ui| {
+                        if ui.selectable_label(comparison_zone.is_none(), tr("None")).clicked() {
+                            result.set_comparison = Some(None);
+                        }
+                        for &tz in favorites.iter().filter(|&&tz| tz != selected_zone) {
+                            let selected = comparison_zone == Some(tz);
+                            if ui.selectable_label(selected, format_zone_name(tz)).clicked() {
+                                result.set_comparison = Some(Some(tz));
+                            }
+                        }
+                    });
+
+                // Every favorite's diagram at once
+                let atlas_text = if atlas_open {
+                    egui::RichText::new(tr("[A] Favorites Atlas: ON"))
+                        .color(egui::Color32::from_rgb(180, 255, 180))
+                } else {
+                    egui::RichText::new(tr("[A] Favorites Atlas: OFF"))
+                        .color(egui::Color32::from_rgb(140, 140, 150))
+                };
+                if ui.button(atlas_text).clicked() {
+                    result.toggle_atlas = true;
+                }
             });
 
             ui.add_space(10.0);
@@ -149,7 +292,7 @@ pub fn draw_sidebar(
             // DST section
             ui.group(|ui| {
                 ui.label(
-                    egui::RichText::new("▸ DST STATUS")
+                    egui::RichText::new(tr("▸ DST STATUS"))
                         .size(13.0)
                         .color(egui::Color32::from_rgb(100, 200, 255)),
                 );
@@ -159,24 +302,24 @@ pub fn draw_sidebar(
                 if time_data.is_dst {
                     ui.horizontal(|ui| {
                         ui.label(
-                            egui::RichText::new("Status:")
+                            egui::RichText::new(tr("Status:"))
                                 .size(11.0)
                                 .color(egui::Color32::from_rgb(140, 140, 150)),
                         );
                         ui.label(
-                            egui::RichText::new("DST Active")
+                            egui::RichText::new(tr("DST Active"))
                                 .color(egui::Color32::from_rgb(255, 180, 100)),
                         );
                     });
                 } else {
                     ui.horizontal(|ui| {
                         ui.label(
-                            egui::RichText::new("Status:")
+                            egui::RichText::new(tr("Status:"))
                                 .size(11.0)
                                 .color(egui::Color32::from_rgb(140, 140, 150)),
                         );
                         ui.label(
-                            egui::RichText::new("Standard Time")
+                            egui::RichText::new(tr("Standard Time"))
                                 .color(egui::Color32::from_rgb(140, 140, 150)),
                         );
                     });
@@ -185,27 +328,27 @@ pub fn draw_sidebar(
                 // DST change warning
                 match &time_data.dst_change {
                     DstChange::Upcoming { instant, delta_minutes } => {
-                        let hours_until = (*instant - chrono::Utc::now()).num_hours();
-                        let direction = if *delta_minutes > 0 {
-                            "spring forward"
+                        let hours_until = (*instant - shared::now_utc()).num_hours();
+                        let text = if *delta_minutes > 0 {
+                            "⚠ Change in {0}h (spring forward)"
                         } else {
-                            "fall back"
+                            "⚠ Change in {0}h (fall back)"
                         };
                         ui.add_space(3.0);
                         ui.label(
-                            egui::RichText::new(format!("⚠ Change in {}h ({})", hours_until, direction))
+                            egui::RichText::new(tr_args(text, &[&hours_until]))
                                 .color(egui::Color32::from_rgb(255, 140, 60)),
                         );
                     }
                     DstChange::JustOccurred { delta_minutes, .. } => {
-                        let direction = if *delta_minutes > 0 {
-                            "sprang forward"
+                        let text = if *delta_minutes > 0 {
+                            "ℹ Clocks sprang forward recently"
                         } else {
-                            "fell back"
+                            "ℹ Clocks fell back recently"
                         };
                         ui.add_space(3.0);
                         ui.label(
-                            egui::RichText::new(format!("ℹ Clocks {} recently", direction))
+                            egui::RichText::new(tr(text))
                                 .color(egui::Color32::from_rgb(100, 180, 255)),
                         );
                     }
@@ -215,83 +358,201 @@ pub fn draw_sidebar(
 
             ui.add_space(10.0);
 
-            // Time control section
+            // Decode quiz section
             ui.group(|ui| {
                 ui.label(
-                    egui::RichText::new("▸ TIME CONTROL")
+                    egui::RichText::new(tr("▸ DECODE QUIZ"))
                         .size(13.0)
                         .color(egui::Color32::from_rgb(100, 200, 255)),
                 );
                 ui.add_space(5.0);
 
-                // Live/Paused indicator
-                if is_live {
-                    ui.label(
-                        egui::RichText::new("● LIVE")
-                            .color(egui::Color32::from_rgb(100, 255, 150)),
-                    );
-                } else {
-                    ui.label(
-                        egui::RichText::new("⏸ PAUSED")
-                            .color(egui::Color32::from_rgb(255, 180, 100)),
-                    );
-                    // Show the frozen time
-                    let time_str = format!(
-                        "{:02}:{:02}:{:02} {}",
-                        time_data.hour12, time_data.minute, time_data.second, time_data.meridiem
-                    );
-                    ui.label(
-                        egui::RichText::new(time_str)
-                            .size(11.0)
-                            .color(egui::Color32::from_rgb(180, 180, 190)),
-                    );
-                }
+                match quiz {
+                    None => {
+                        if ui
+                            .button(egui::RichText::new(tr("[Q] Start Quiz")).size(12.0))
+                            .clicked()
+                        {
+                            result.toggle_quiz = true;
+                        }
+                    }
+                    Some(quiz) => {
+                        match &quiz.verdict {
+                            None => {
+                                ui.label(
+                                    egui::RichText::new(tr("What time does the diagram show?"))
+                                        .size(11.0)
+                                        .color(egui::Color32::from_rgb(180, 180, 190)),
+                                );
+                                ui.horizontal(|ui| {
+                                    let response = ui.add(
+                                        egui::TextEdit::singleline(quiz_input)
+                                            .hint_text(tr("h:mm or h:mm:ss"))
+                                            .desired_width(120.0),
+                                    );
+                                    if response.lost_focus()
+                                        && ui.input(|i| i.key_pressed(egui::Key::Enter))
+                                    {
+                                        result.submit_guess = true;
+                                    }
+                                    if ui.button(egui::RichText::new(tr("Submit")).size(12.0)).clicked() {
+                                        result.submit_guess = true;
+                                    }
+                                });
+                                ui.label(
+                                    egui::RichText::new(tr_args(
+                                        "12-hour dial, within {0}s counts",
+                                        &[&TOLERANCE_SECS],
+                                    ))
+                                    .size(9.0)
+                                    .color(egui::Color32::from_rgb(100, 100, 110)),
+                                );
+                            }
+                            Some(verdict) => {
+                                let (text, color) = if verdict.correct {
+                                    ("✓ Correct", egui::Color32::from_rgb(100, 255, 150))
+                                } else {
+                                    ("✗ Not quite", egui::Color32::from_rgb(255, 120, 100))
+                                };
+                                ui.label(egui::RichText::new(tr(text)).size(13.0).color(color));
+                                ui.label(
+                                    egui::RichText::new(tr_args(
+                                        "Answer {0} · you said {1} ({2})",
+                                        &[
+                                            &quiz.answer.format(),
+                                            &verdict.guess.format(),
+                                            &format_error(verdict.error_secs),
+                                        ],
+                                    ))
+                                    .size(11.0)
+                                    .color(egui::Color32::from_rgb(180, 180, 190)),
+                                );
+                                if ui
+                                    .button(egui::RichText::new(tr("Next Question (N)")).size(12.0))
+                                    .clicked()
+                                {
+                                    result.next_question = true;
+                                }
+                            }
+                        }
 
-                ui.add_space(5.0);
+                        ui.add_space(3.0);
+                        if ui
+                            .button(egui::RichText::new(tr("[Q] Stop Quiz")).size(12.0))
+                            .clicked()
+                        {
+                            result.toggle_quiz = true;
+                        }
+                    }
+                }
 
-                // Step controls
+                ui.add_space(3.0);
                 ui.horizontal(|ui| {
-                    if ui.button(egui::RichText::new("◀◀").size(12.0)).clicked() {
-                        result.step_time = Some(-3600); // -1 hour
-                    }
-                    if ui.button(egui::RichText::new("◀").size(12.0)).clicked() {
-                        result.step_time = Some(-60); // -1 minute
-                    }
-                    if ui.button(egui::RichText::new("‹").size(12.0)).clicked() {
-                        result.step_time = Some(-1); // -1 second
-                    }
-                    if ui.button(egui::RichText::new("›").size(12.0)).clicked() {
-                        result.step_time = Some(1); // +1 second
-                    }
-                    if ui.button(egui::RichText::new("▶").size(12.0)).clicked() {
-                        result.step_time = Some(60); // +1 minute
-                    }
-                    if ui.button(egui::RichText::new("▶▶").size(12.0)).clicked() {
-                        result.step_time = Some(3600); // +1 hour
+                    ui.label(
+                        egui::RichText::new(tr_args(
+                            "Score {0}/{1} ({2}%) · streak {3} · best {4}",
+                            &[
+                                &quiz_stats.correct,
+                                &quiz_stats.attempts,
+                                &format!("{:.0}", quiz_stats.accuracy() * 100.0),
+                                &quiz_stats.streak,
+                                &quiz_stats.best_streak,
+                            ],
+                        ))
+                        .size(10.0)
+                        .color(egui::Color32::from_rgb(140, 140, 150)),
+                    );
+                    if quiz_stats.attempts > 0 && ui.small_button(tr("Reset")).clicked() {
+                        result.reset_quiz_stats = true;
                     }
                 });
+            });
 
-                ui.add_space(3.0);
+            ui.add_space(10.0);
 
-                // Return to live button
-                if !is_live {
-                    if ui
-                        .button(
-                            egui::RichText::new("Return to Live (L)")
-                                .size(12.0)
+            // Time control section (the quiz holds its own instant)
+            ui.add_enabled_ui(quiz.is_none(), |ui| {
+                ui.group(|ui| {
+                    ui.label(
+                        egui::RichText::new(tr("▸ TIME CONTROL"))
+                            .size(13.0)
+                            .color(egui::Color32::from_rgb(100, 200, 255)),
+                    );
+                    ui.add_space(5.0);
+
+                    // Live/Paused indicator
+                    if is_live {
+                        ui.label(
+                            egui::RichText::new(tr("● LIVE"))
                                 .color(egui::Color32::from_rgb(100, 255, 150)),
-                        )
-                        .clicked()
-                    {
-                        result.return_to_live = true;
+                        );
+                    } else if quiz_asking {
+                        ui.label(
+                            egui::RichText::new(tr("? QUIZ"))
+                                .color(egui::Color32::from_rgb(255, 180, 100)),
+                        );
+                    } else {
+                        ui.label(
+                            egui::RichText::new(tr("⏸ PAUSED"))
+                                .color(egui::Color32::from_rgb(255, 180, 100)),
+                        );
+                        // Show the frozen time
+                        let time_str = format!(
+                            "{:02}:{:02}:{:02} {}",
+                            time_data.hour12, time_data.minute, time_data.second, time_data.meridiem
+                        );
+                        ui.label(
+                            egui::RichText::new(time_str)
+                                .size(11.0)
+                                .color(egui::Color32::from_rgb(180, 180, 190)),
+                        );
                     }
-                }
 
-                ui.label(
-                    egui::RichText::new("[ ] step sec  |  Shift: min  |  Ctrl: hr")
-                        .size(9.0)
-                        .color(egui::Color32::from_rgb(100, 100, 110)),
-                );
+                    ui.add_space(5.0);
+
+                    // Step controls
+                    ui.horizontal(|ui| {
+                        if ui.button(egui::RichText::new("◀◀").size(12.0)).clicked() {
+                            result.step_time = Some(-3600); // -1 hour
+                        }
+                        if ui.button(egui::RichText::new("◀").size(12.0)).clicked() {
+                            result.step_time = Some(-60); // -1 minute
+                        }
+                        if ui.button(egui::RichText::new("‹").size(12.0)).clicked() {
+                            result.step_time = Some(-1); // -1 second
+                        }
+                        if ui.button(egui::RichText::new("›").size(12.0)).clicked() {
+                            result.step_time = Some(1); // +1 second
+                        }
+                        if ui.button(egui::RichText::new("▶").size(12.0)).clicked() {
+                            result.step_time = Some(60); // +1 minute
+                        }
+                        if ui.button(egui::RichText::new("▶▶").size(12.0)).clicked() {
+                            result.step_time = Some(3600); // +1 hour
+                        }
+                    });
+
+                    ui.add_space(3.0);
+
+                    // Return to live button
+                    if !is_live
+                        && ui
+                            .button(
+                                egui::RichText::new(tr("Return to Live (L)"))
+                                    .size(12.0)
+                                    .color(egui::Color32::from_rgb(100, 255, 150)),
+                            )
+                            .clicked()
+                        {
+                            result.return_to_live = true;
+                        }
+
+                    ui.label(
+                        egui::RichText::new(tr("[ ] step sec  |  Shift: min  |  Ctrl: hr"))
+                            .size(9.0)
+                            .color(egui::Color32::from_rgb(100, 100, 110)),
+                    );
+                });
             });
 
             ui.add_space(10.0);
@@ -299,7 +560,7 @@ pub fn draw_sidebar(
             // Mode toggles section
             ui.group(|ui| {
                 ui.label(
-                    egui::RichText::new("▸ VIEW MODES")
+                    egui::RichText::new(tr("▸ VIEW MODES"))
                         .size(13.0)
                         .color(egui::Color32::from_rgb(100, 200, 255)),
                 );
@@ -307,10 +568,10 @@ pub fn draw_sidebar(
 
                 // Decode mode toggle
                 let decode_text = if decode_mode {
-                    egui::RichText::new("[D] Decode Mode: ON")
+                    egui::RichText::new(tr("[D] Decode Mode: ON"))
                         .color(egui::Color32::from_rgb(180, 255, 180))
                 } else {
-                    egui::RichText::new("[D] Decode Mode: OFF")
+                    egui::RichText::new(tr("[D] Decode Mode: OFF"))
                         .color(egui::Color32::from_rgb(140, 140, 150))
                 };
                 if ui.button(decode_text).clicked() {
@@ -321,20 +582,97 @@ pub fn draw_sidebar(
 
                 // Explicit mode toggle
                 let explicit_text = if explicit_mode {
-                    egui::RichText::new("Explicit Mode: ON")
+                    egui::RichText::new(tr("Explicit Mode: ON"))
                         .color(egui::Color32::from_rgb(180, 255, 180))
                 } else {
-                    egui::RichText::new("Explicit Mode: OFF")
+                    egui::RichText::new(tr("Explicit Mode: OFF"))
                         .color(egui::Color32::from_rgb(140, 140, 150))
                 };
                 if ui.button(explicit_text).clicked() {
                     result.toggle_explicit_mode = true;
                 }
                 ui.label(
-                    egui::RichText::new("(Standard time display)")
+                    egui::RichText::new(tr("(Standard time display)"))
                         .size(10.0)
                         .color(egui::Color32::from_rgb(100, 100, 110)),
                 );
+                if explicit_mode {
+                    result.set_explicit_layout = explicit_layout_settings(ui, explicit_layout);
+                }
+
+                ui.add_space(3.0);
+
+                if ui.button(tr("[G] Geometry Inspector"))
+                    .on_hover_text(tr("Every geometry parameter as a slider, to try the mapping"))
+                    .clicked()
+                {
+                    result.toggle_geometry_inspector = true;
+                }
+
+                ui.add_space(3.0);
+
+                if ui.button(tr("[E] Export SVG")).clicked() {
+                    result.export_svg = true;
+                }
+                if ui.button(tr("[Shift+E] Time Poster"))
+                    .on_hover_text(tr("Render any instant as a print-ready PNG or SVG"))
+                    .clicked()
+                {
+                    result.open_poster = true;
+                }
+            });
+
+            ui.add_space(10.0);
+
+            // Named views section
+            ui.group(|ui| {
+                ui.label(
+                    egui::RichText::new(tr("▸ VIEW PRESETS"))
+                        .size(13.0)
+                        .color(egui::Color32::from_rgb(100, 200, 255)),
+                );
+                ui.add_space(5.0);
+
+                for (index, preset) in view_presets.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        let label = format!("[{}] {}", index + 1, preset.name);
+                        if ui
+                            .button(egui::RichText::new(label).size(12.0))
+                            .on_hover_text(describe_preset(preset))
+                            .clicked()
+                        {
+                            result.apply_view_preset = Some(index);
+                        }
+                        if ui.small_button("✕").on_hover_text(tr("Delete preset")).clicked() {
+                            result.delete_view_preset = Some(index);
+                        }
+                    });
+                }
+                if view_presets.is_empty() {
+                    ui.label(
+                        egui::RichText::new(tr("Save a view to recall it with 1–9"))
+                            .size(10.0)
+                            .color(egui::Color32::from_rgb(100, 100, 110)),
+                    );
+                }
+
+                ui.horizontal(|ui| {
+                    let response = ui.add(
+                        egui::TextEdit::singleline(preset_name_input)
+                            .hint_text(tr("e.g. presentation"))
+                            .desired_width(130.0),
+                    );
+                    if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                        result.save_view_preset = true;
+                    }
+                    if ui
+                        .button(egui::RichText::new(tr("Save View")).size(12.0))
+                        .on_hover_text(tr("Save zone, pan, zoom and modes under this name"))
+                        .clicked()
+                    {
+                        result.save_view_preset = true;
+                    }
+                });
             });
 
             ui.add_space(10.0);
@@ -342,26 +680,68 @@ pub fn draw_sidebar(
             // Accessibility section
             ui.group(|ui| {
                 ui.label(
-                    egui::RichText::new("▸ ACCESSIBILITY")
+                    egui::RichText::new(tr("▸ ACCESSIBILITY"))
                         .size(13.0)
                         .color(egui::Color32::from_rgb(100, 200, 255)),
                 );
                 ui.add_space(5.0);
 
-                // Reduced motion toggle
-                let mut reduced = reduced_motion;
-                if ui
-                    .checkbox(&mut reduced, egui::RichText::new("Reduced motion").size(12.0))
-                    .changed()
+                // Reduced motion setting
+                let mut preference = motion_preference;
+                if reduced_motion_settings(ui, &mut preference, system_reduced_motion) {
+                    result.set_motion_preference = Some(preference);
+                }
+                let reduced_motion = preference.resolve(system_reduced_motion);
+                if let Some(edited) = transition_settings(ui, transition, reduced_motion) {
+                    result.set_transition = Some(edited);
+                }
+                if let Some(edited) =
+                    sonification_settings(ui, sonification, sonification_status)
                 {
-                    result.toggle_reduced_motion = true;
+                    result.set_sonification = Some(edited);
+                }
+
+                let mut edited = quiet_hours.clone();
+                if quiet_hours_settings(ui, &mut edited) {
+                    result.set_quiet_hours = Some(edited);
+                }
+                let mut edited = window_title;
+                if window_title_settings(ui, &mut edited) {
+                    result.set_window_title = Some(edited);
+                }
+                let mut edited = clip_settings;
+                if clip_recording_settings(ui, &mut edited) {
+                    result.set_clip_settings = Some(edited);
+                }
+                let mut edited = language;
+                if language_settings(ui, &mut edited) {
+                    result.set_language = Some(edited);
+                }
+                let mut edited = follow_system_zone;
+                if system_zone_settings(ui, &mut edited) {
+                    result.set_follow_system_zone = Some(edited);
+                }
+                let mut edited = reveal_hotkey.clone();
+                if reveal_hotkey_settings(ui, &mut edited, reveal_status) {
+                    result.set_reveal_hotkey = Some(edited);
+                }
+                let mut edited = own_favorites;
+                if favorites_settings(ui, &mut edited) {
+                    result.set_own_favorites = Some(edited);
+                }
+                result.keymap_edit = keymap_settings(ui, keymap);
+
+                result.set_theme = theme_selector(ui, themes, theme);
+                let mut edited = safe_palette;
+                if safe_palette_settings(ui, &mut edited) {
+                    result.set_safe_palette = Some(edited);
                 }
 
                 ui.add_space(5.0);
 
                 // Help button
                 if ui
-                    .button(egui::RichText::new("[?] How to read this clock").size(12.0))
+                    .button(egui::RichText::new(tr("[?] How to read this clock")).size(12.0))
                     .clicked()
                 {
                     result.open_help = true;
@@ -373,15 +753,20 @@ pub fn draw_sidebar(
             // Diagram description section (for accessibility)
             ui.group(|ui| {
                 ui.label(
-                    egui::RichText::new("▸ DIAGRAM STATE")
+                    egui::RichText::new(tr("▸ DIAGRAM STATE"))
                         .size(13.0)
                         .color(egui::Color32::from_rgb(100, 200, 255)),
                 );
                 ui.add_space(5.0);
 
                 // Wrap the description text
+                let description = if quiz_asking {
+                    tr("Hidden until the quiz answer is in.")
+                } else {
+                    diagram_description
+                };
                 ui.label(
-                    egui::RichText::new(diagram_description)
+                    egui::RichText::new(description)
                         .size(10.0)
                         .color(egui::Color32::from_rgb(160, 160, 170)),
                 );
@@ -392,7 +777,7 @@ pub fn draw_sidebar(
             // Keyboard shortcuts
             ui.group(|ui| {
                 ui.label(
-                    egui::RichText::new("▸ SHORTCUTS")
+                    egui::RichText::new(tr("▸ SHORTCUTS"))
                         .size(13.0)
                         .color(egui::Color32::from_rgb(100, 200, 255)),
                 );
@@ -401,10 +786,15 @@ pub fn draw_sidebar(
                 let shortcuts = [
                     ("Space", "Hold to reveal time"),
                     ("D", "Toggle decode mode"),
+                    ("S", "Toggle sonification"),
                     ("Z", "Open timezone picker"),
                     ("?", "Help panel"),
                     ("[ / ]", "Step time back/fwd"),
                     ("L", "Return to live"),
+                    ("E", "Export SVG"),
+                    ("Q", "Decode quiz"),
+                    ("1–9", "View presets"),
+                    ("N", "Next quiz question"),
                     ("Tab", "Cycle focus"),
                     ("Esc", "Close panels"),
                 ];
@@ -418,7 +808,7 @@ pub fn draw_sidebar(
                                 .monospace(),
                         );
                         ui.label(
-                            egui::RichText::new(desc)
+                            egui::RichText::new(tr(desc))
                                 .size(10.0)
                                 .color(egui::Color32::from_rgb(120, 120, 130)),
                         );
@@ -430,7 +820,7 @@ pub fn draw_sidebar(
             ui.with_layout(egui::Layout::bottom_up(egui::Align::Center), |ui| {
                 ui.add_space(10.0);
                 ui.label(
-                    egui::RichText::new("Hold Space or click to reveal exact time")
+                    egui::RichText::new(tr("Hold Space or click to reveal exact time"))
                         .size(9.0)
                         .color(egui::Color32::from_rgb(80, 80, 90)),
                 );
@@ -456,6 +846,20 @@ pub fn draw_sidebar(
     result
 }
 
+/// "Europe/London · 150% · decode"
+fn describe_preset(preset: &ViewPreset) -> String {
+    let mut description = format!("{} · {:.0}%", preset.zone_id, preset.view_zoom * 100.0);
+    if preset.decode_mode {
+        description.push_str(" · ");
+        description.push_str(tr("decode"));
+    }
+    if preset.explicit_mode {
+        description.push_str(" · ");
+        description.push_str(tr("explicit"));
+    }
+    description
+}
+
 /// Result of timezone picker interactions
 #[derive(Default)]
 struct PickerResult {
@@ -472,7 +876,8 @@ fn draw_timezone_picker(
 ) -> PickerResult {
     let mut result = PickerResult::default();
 
-    egui::Window::new("Select Timezone")
+    egui::Window::new(tr("Select Timezone"))
+        .id(egui::Id::new("select_timezone"))
         .collapsible(false)
         .resizable(true)
         .default_width(380.0)
@@ -481,7 +886,7 @@ fn draw_timezone_picker(
             // Search field
             ui.horizontal(|ui| {
                 ui.label(
-                    egui::RichText::new("Search:")
+                    egui::RichText::new(tr("Search:"))
                         .color(egui::Color32::from_rgb(100, 200, 255)),
                 );
                 let search_response = ui.text_edit_singleline(&mut picker_state.search_query);
@@ -500,31 +905,32 @@ fn draw_timezone_picker(
 
             // Favorites section
             if !favorites.is_empty() {
-                ui.collapsing(
-                    egui::RichText::new("★ Favorites")
+                egui::CollapsingHeader::new(
+                    egui::RichText::new(tr("★ Favorites"))
                         .color(egui::Color32::from_rgb(255, 200, 100)),
-                    |ui| {
-                        for &tz in favorites {
-                            ui.horizontal(|ui| {
-                                if ui
-                                    .button(egui::RichText::new(format_zone_name(tz)).size(12.0))
-                                    .clicked()
-                                {
-                                    result.select_zone = Some(tz);
-                                }
-                                if ui.small_button("★").clicked() {
-                                    result.toggle_favorite = Some(tz);
-                                }
-                            });
-                        }
-                    },
-                );
+                )
+                .id_source("favorites")
+                .show(ui, |ui| {
+                    for &tz in favorites {
+                        ui.horizontal(|ui| {
+                            if ui
+                                .button(egui::RichText::new(format_zone_name(tz)).size(12.0))
+                                .clicked()
+                            {
+                                result.select_zone = Some(tz);
+                            }
+                            if ui.small_button("★").clicked() {
+                                result.toggle_favorite = Some(tz);
+                            }
+                        });
+                    }
+                });
                 ui.separator();
             }
 
             // System timezone shortcut
             if ui
-                .button(egui::RichText::new("📍 Use System Timezone").size(12.0))
+                .button(egui::RichText::new(tr("📍 Use System Timezone")).size(12.0))
                 .clicked()
             {
                 if let Some(sys_tz) = system_timezone() {
@@ -536,7 +942,7 @@ fn draw_timezone_picker(
 
             // Search results count
             ui.label(
-                egui::RichText::new(format!("{} results", picker_state.search_results.len()))
+                egui::RichText::new(tr_args("{0} results", &[&picker_state.search_results.len()]))
                     .size(11.0)
                     .color(egui::Color32::from_rgb(120, 120, 130)),
             );
@@ -544,11 +950,16 @@ fn draw_timezone_picker(
             egui::ScrollArea::vertical()
                 .max_height(280.0)
                 .show(ui, |ui| {
+                    let mut previous = None;
                     for &tz in picker_state.search_results.iter().take(100) {
+                        if let Some(region) = region_change(previous, tz) {
+                            region_heading(ui, region);
+                        }
+                        previous = Some(tz);
                         let is_favorite = favorites.contains(&tz);
                         ui.horizontal(|ui| {
                             if ui
-                                .button(egui::RichText::new(format_zone_name(tz)).size(12.0))
+                                .button(egui::RichText::new(zone_display_name(tz)).size(12.0))
                                 .clicked()
                             {
                                 result.select_zone = Some(tz);
@@ -564,7 +975,7 @@ fn draw_timezone_picker(
             ui.separator();
 
             if ui
-                .button(egui::RichText::new("Close (Esc)").size(12.0))
+                .button(egui::RichText::new(tr("Close (Esc)")).size(12.0))
                 .clicked()
             {
                 result.close = true;
@@ -574,6 +985,291 @@ fn draw_timezone_picker(
     result
 }
 
+/// Elements, font scale and alignment of the explicit readout; returns the
+/// edited layout if anything changed
+fn explicit_layout_settings(
+    ui: &mut egui::Ui,
+    layout: ExplicitLayout,
+) -> Option<ExplicitLayout> {
+    let mut edited = layout;
+    ui.horizontal_wrapped(|ui| {
+        ui.checkbox(&mut edited.seconds, tr("Seconds"));
+        ui.checkbox(&mut edited.date, tr("Date"));
+        ui.checkbox(&mut edited.offset, tr("Offset"));
+        ui.checkbox(&mut edited.dst_note, tr("DST note"));
+    });
+    ui.add(
+        egui::Slider::new(&mut edited.font_scale, MIN_FONT_SCALE..=MAX_FONT_SCALE)
+            .text(tr("text size"))
+            .suffix("×"),
+    )
+    .on_hover_text(tr("Larger for a wall display; lines shrink to fit the window"));
+    ui.horizontal(|ui| {
+        for alignment in Alignment::ALL {
+            ui.radio_value(&mut edited.alignment, alignment, tr(alignment.label()));
+        }
+    });
+    (edited != layout).then_some(edited)
+}
+
+/// Transition duration and easing, disabled under reduced motion; returns
+/// the edited settings if either changed
+fn transition_settings(
+    ui: &mut egui::Ui,
+    transition: TransitionSettings,
+    reduced_motion: bool,
+) -> Option<TransitionSettings> {
+    let mut edited = transition;
+    ui.add_enabled_ui(!reduced_motion, |ui| {
+        ui.add(
+            egui::Slider::new(&mut edited.duration_ms, MIN_DURATION_MS..=MAX_DURATION_MS)
+                .text(tr("ms transition")),
+        );
+        egui::ComboBox::from_label(tr("Easing"))
+            .selected_text(tr(edited.easing.label()))
+            .show_ui(ui, |ui| {
+                for easing in Easing::ALL {
+                    ui.selectable_value(&mut edited.easing, easing, tr(easing.label()));
+                }
+            });
+    });
+    (edited != transition).then_some(edited)
+}
+
+/// Sonification toggle, volume and ticks, with why it's silent if it is;
+/// returns the edited settings if anything changed
+fn sonification_settings(
+    ui: &mut egui::Ui,
+    sonification: SonificationSettings,
+    status: Option<&'static str>,
+) -> Option<SonificationSettings> {
+    let mut edited = sonification;
+    ui.checkbox(&mut edited.enabled, egui::RichText::new(tr("Sonification")).size(12.0))
+        .on_hover_text(tr("Hour sets the pitch, minute sweeps the timbre, seconds tick"));
+    ui.add_enabled_ui(edited.enabled, |ui| {
+        ui.add(egui::Slider::new(&mut edited.volume, 0.0..=1.0).text(tr("volume")));
+        ui.checkbox(&mut edited.ticks, tr("Second ticks"));
+    });
+    if let Some(status) = status {
+        ui.label(
+            egui::RichText::new(tr(status))
+                .size(10.0)
+                .color(egui::Color32::from_rgb(120, 120, 130)),
+        );
+    }
+    (edited != sonification).then_some(edited)
+}
+
+/// What the geometry inspector asks for
+#[derive(Default)]
+pub struct InspectorResult {
+    /// Draw these parameters instead of the live mapping
+    pub set_override: Option<GeometryParams>,
+    /// Drop the edits and go back to the live mapping
+    pub return_to_live_mapping: bool,
+}
+
+/// Draw the geometry inspector window
+///
+/// Moving the hour, minute or second slider also moves what it maps to, as
+/// the live clock does; the other sliders set their value alone.
+pub fn draw_geometry_inspector(
+    ctx: &egui::Context,
+    open: &mut bool,
+    params: &GeometryParams,
+    detached: bool,
+) -> InspectorResult {
+    let mut result = InspectorResult::default();
+    let mut edited = params.clone();
+
+    egui::Window::new(tr("Geometry Inspector"))
+        .id(egui::Id::new("geometry_inspector"))
+        .open(open)
+        .resizable(false)
+        .default_width(300.0)
+        .show(ctx, |ui| {
+            let (status, color) = if detached {
+                ("Detached from live time", egui::Color32::from_rgb(255, 190, 110))
+            } else {
+                ("Following live time", egui::Color32::from_rgb(180, 255, 180))
+            };
+            ui.label(egui::RichText::new(tr(status)).color(color));
+            ui.add_space(5.0);
+
+            let mut hour = edited.hour;
+            if ui
+                .add(egui::Slider::new(&mut hour, 1..=12).text(tr("hour")))
+                .on_hover_text(tr("Sets vertices = 3 + hour"))
+                .changed()
+            {
+                edited.set_hour(hour);
+            }
+            ui.add(egui::Slider::new(&mut edited.vertex_count, 3..=15).text(tr("vertices")));
+            ui.separator();
+
+            let mut minute = edited.minute;
+            if ui
+                .add(egui::Slider::new(&mut minute, 0..=59).text(tr("minute")))
+                .on_hover_text(tr("Sets e = 1.2 + 2.8 × m/59 and rotation = -30° - 6° × m"))
+                .changed()
+            {
+                edited.set_minute(minute);
+            }
+            ui.add(egui::Slider::new(&mut edited.exponent, 0.5..=6.0).text(tr("exponent e")));
+            ui.add(
+                egui::Slider::new(&mut edited.minute_rotation_deg, -390.0..=-30.0)
+                    .text(tr("minute rotation °")),
+            );
+            ui.separator();
+
+            let mut second = edited.second;
+            if ui
+                .add(egui::Slider::new(&mut second, 0..=59).text(tr("second")))
+                .on_hover_text(tr("Sets phase = 6° × second"))
+                .changed()
+            {
+                edited.set_second(second);
+            }
+            ui.add(egui::Slider::new(&mut edited.phase_deg, 0.0..=360.0).text(tr("phase °")));
+            ui.separator();
+
+            ui.add(
+                egui::Slider::new(&mut edited.tz_rotation_deg, -120.0..=120.0)
+                    .text(tr("zone rotation °")),
+            )
+            .on_hover_text(tr("Live: 7.5° per hour of UTC offset, +5° under DST"));
+            ui.add(egui::Slider::new(&mut edited.tz_skew_x, -0.1..=0.1).text(tr("zone skew x")))
+                .on_hover_text(tr("Live: 0.10 × the offset's leftover minutes / 60"));
+            let mut is_dst = edited.is_dst;
+            if ui
+                .checkbox(&mut is_dst, tr("DST"))
+                .on_hover_text(tr("Adds 5° of rotation and shears the minute layer"))
+                .changed()
+            {
+                edited.set_dst(is_dst);
+            }
+
+            ui.add_space(5.0);
+            if ui
+                .add_enabled(detached, egui::Button::new(tr("Return to live mapping")))
+                .clicked()
+            {
+                result.return_to_live_mapping = true;
+            }
+        });
+
+    if edited != *params {
+        result.set_override = Some(edited);
+    }
+    result
+}
+
+/// What the time poster window asks for
+#[derive(Default)]
+pub struct PosterResult {
+    /// Replace the saved page size, format or legend choice
+    pub set_settings: Option<PosterSettings>,
+    /// Render the poster and write it to the export folder
+    pub render: bool,
+}
+
+/// Draw the time poster window
+pub fn draw_poster_window(
+    ctx: &egui::Context,
+    state: &mut PosterState,
+    settings: PosterSettings,
+    zone: Tz,
+) -> PosterResult {
+    let mut result = PosterResult::default();
+    let mut edited = settings;
+
+    egui::Window::new(tr("Time Poster"))
+        .id(egui::Id::new("time_poster"))
+        .open(&mut state.is_open)
+        .resizable(false)
+        .default_width(300.0)
+        .show(ctx, |ui| {
+            ui.label(tr_args("Instant ({0})", &[&zone.name()]));
+            ui.add(
+                egui::TextEdit::singleline(&mut state.instant_input)
+                    .hint_text("YYYY-MM-DD HH:MM:SS"),
+            );
+            ui.label(tr("Caption"));
+            ui.add(egui::TextEdit::singleline(&mut state.caption).hint_text(tr("Optional")));
+            ui.separator();
+
+            ui.horizontal_wrapped(|ui| {
+                for (name, width, height) in PAGE_SIZES {
+                    let selected = edited.width == width && edited.height == height;
+                    if ui
+                        .selectable_label(selected, name)
+                        .on_hover_text(tr_args("{0} × {1} px at 300 dpi", &[&width, &height]))
+                        .clicked()
+                    {
+                        edited.width = width;
+                        edited.height = height;
+                    }
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::DragValue::new(&mut edited.width)
+                        .clamp_range(MIN_SIDE..=MAX_SIDE)
+                        .suffix(" px"),
+                );
+                ui.label("×");
+                ui.add(
+                    egui::DragValue::new(&mut edited.height)
+                        .clamp_range(MIN_SIDE..=MAX_SIDE)
+                        .suffix(" px"),
+                );
+                if ui.small_button("⇄").on_hover_text(tr("Swap portrait and landscape")).clicked() {
+                    std::mem::swap(&mut edited.width, &mut edited.height);
+                }
+            });
+            ui.horizontal(|ui| {
+                for format in [PosterFormat::Png, PosterFormat::Svg] {
+                    ui.radio_value(&mut edited.format, format, format.label());
+                }
+            });
+            ui.checkbox(&mut edited.legend, tr("Legend"))
+                .on_hover_text(tr("Name what each layer encodes under the diagram"));
+
+            ui.add_space(5.0);
+            if ui.button(tr_args("Render {0}", &[&edited.format.label()])).clicked() {
+                result.render = true;
+            }
+            if let Some(error) = &state.error {
+                ui.label(
+                    egui::RichText::new(error)
+                        .size(11.0)
+                        .color(egui::Color32::from_rgb(255, 100, 100)),
+                );
+            }
+        });
+
+    if edited != settings {
+        result.set_settings = Some(edited);
+    }
+    result
+}
+
+/// Describe how far off a quiz guess was
+fn format_error(error_secs: i64) -> String {
+    let secs = error_secs.abs();
+    if secs == 0 {
+        return tr("exact").to_string();
+    }
+    let late = error_secs > 0;
+    if secs < 60 {
+        let text = if late { "{0}s late" } else { "{0}s early" };
+        tr_args(text, &[&secs])
+    } else {
+        let text = if late { "{0}m {1}s late" } else { "{0}m {1}s early" };
+        tr_args(text, &[&(secs / 60), &(secs % 60)])
+    }
+}
+
 /// Format timezone name for display
 fn format_zone_name(tz: Tz) -> String {
     let name = tz.name();
@@ -585,3 +1281,56 @@ fn format_zone_name(tz: Tz) -> String {
     }
 }
 
+
+/// Restyle egui's chrome for the active theme
+///
+/// Leaves `visuals` alone while the clock uses its own colors.
+pub fn theme_visuals(visuals: &mut egui::Visuals) {
+    let Some(palette) = active_palette() else {
+        return;
+    };
+    let color = |c: Rgb| egui::Color32::from_rgb(c.red, c.green, c.blue);
+    *visuals = if palette.is_light() {
+        egui::Visuals::light()
+    } else {
+        egui::Visuals::dark()
+    };
+    visuals.panel_fill = color(palette.background);
+    visuals.window_fill = color(palette.background);
+    visuals.override_text_color = Some(color(palette.primary_text));
+    visuals.hyperlink_color = color(palette.accent);
+    visuals.warn_fg_color = color(palette.warning);
+}
+
+/// Draw the theme switcher
+///
+/// Returns the newly chosen theme, where `Some(None)` means the clock's own
+/// colors.
+pub fn theme_selector(
+    ui: &mut egui::Ui,
+    themes: &ThemeLibrary,
+    selected: Option<&str>,
+) -> Option<Option<String>> {
+    let mut choice = None;
+    egui::ComboBox::from_label(tr("Theme"))
+        .selected_text(selected.unwrap_or(tr("Clock colors")))
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(selected.is_none(), tr("Clock colors")).clicked() {
+                choice = Some(None);
+            }
+            for theme in &themes.themes {
+                let is_selected = selected == Some(theme.name.as_str());
+                if ui.selectable_label(is_selected, &theme.name).clicked() {
+                    choice = Some(Some(theme.name.clone()));
+                }
+            }
+        });
+    if let Some(error) = &themes.error {
+        ui.label(
+            egui::RichText::new(error)
+                .size(10.0)
+                .color(egui::Color32::from_rgb(255, 100, 100)),
+        );
+    }
+    choice
+}
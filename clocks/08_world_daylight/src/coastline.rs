@@ -0,0 +1,195 @@
+//! Coastline module - coarse land outlines for the world map
+//!
+//! Hand-simplified to a few dozen points per landmass: enough to recognise
+//! the continents under the terminator, not for navigation. Points are
+//! `(longitude, latitude)` in degrees, each outline closed implicitly.
+
+/// Landmasses, drawn first
+pub const LAND: &[&[(f32, f32)]] = &[
+    // North America
+    &[
+        (-166.0, 68.0), (-156.0, 71.3), (-141.0, 69.6), (-125.0, 70.0), (-110.0, 68.5),
+        (-95.0, 68.0), (-82.0, 69.5), (-78.0, 62.0), (-64.0, 60.0), (-56.0, 52.0),
+        (-66.0, 45.0), (-70.0, 42.0), (-74.0, 40.5), (-76.0, 35.0), (-81.0, 31.0),
+        (-80.0, 25.5), (-82.0, 27.5), (-84.0, 30.0), (-89.0, 30.0), (-94.0, 29.5),
+        (-97.0, 27.0), (-97.5, 22.0), (-95.0, 18.5), (-91.0, 19.0), (-87.0, 21.5),
+        (-88.0, 16.0), (-84.0, 15.0), (-83.5, 11.0), (-79.5, 9.0), (-77.5, 8.5),
+        (-80.0, 7.3), (-85.0, 10.0), (-87.5, 13.0), (-92.0, 14.5), (-96.0, 15.7),
+        (-105.0, 19.5), (-106.0, 23.0), (-110.0, 27.0), (-114.0, 31.0), (-117.0, 32.5),
+        (-120.5, 34.5), (-122.5, 37.5), (-124.0, 40.5), (-124.0, 46.0), (-123.0, 49.0),
+        (-127.0, 51.0), (-131.0, 55.0), (-136.0, 58.0), (-140.0, 60.0), (-146.0, 61.0),
+        (-152.0, 59.0), (-158.0, 57.0), (-164.0, 55.0), (-162.0, 58.5), (-165.0, 60.5),
+        (-165.0, 63.0), (-168.0, 65.5),
+    ],
+    // Greenland
+    &[
+        (-73.0, 78.0), (-60.0, 82.0), (-35.0, 83.5), (-20.0, 82.0), (-18.0, 77.0),
+        (-22.0, 70.0), (-32.0, 68.0), (-40.0, 65.0), (-43.0, 60.0), (-48.0, 61.0),
+        (-52.0, 65.0), (-54.0, 69.0), (-58.0, 75.0), (-66.0, 76.5),
+    ],
+    // Baffin Island
+    &[
+        (-80.0, 73.5), (-70.0, 70.5), (-62.0, 66.5), (-66.0, 63.0), (-71.0, 62.5),
+        (-78.0, 64.5), (-73.5, 68.0), (-80.0, 69.5),
+    ],
+    // Cuba
+    &[(-85.0, 21.8), (-80.0, 23.2), (-74.2, 20.2), (-77.7, 19.9), (-81.0, 21.6)],
+    // South America
+    &[
+        (-77.5, 8.5), (-72.0, 12.0), (-63.0, 10.7), (-60.0, 8.5), (-52.0, 5.0),
+        (-50.0, 0.0), (-44.0, -2.5), (-35.0, -5.5), (-35.0, -9.0), (-39.0, -14.0),
+        (-40.0, -20.0), (-44.0, -23.0), (-48.5, -27.0), (-53.0, -33.5), (-57.0, -35.5),
+        (-58.0, -38.5), (-62.0, -39.0), (-65.0, -42.0), (-67.5, -46.0), (-69.0, -51.0),
+        (-68.5, -54.5), (-71.5, -53.5), (-74.5, -51.0), (-75.5, -46.0), (-73.5, -40.0),
+        (-73.5, -36.5), (-71.5, -30.0), (-70.3, -20.0), (-71.5, -17.0), (-76.0, -14.0),
+        (-79.5, -7.5), (-81.0, -4.5), (-80.0, -1.0), (-80.0, 1.0), (-78.5, 2.5),
+    ],
+    // Eurasia
+    &[
+        (-9.0, 38.7), (-9.3, 43.0), (-1.5, 43.4), (-4.5, 47.8), (-4.7, 48.5),
+        (-1.5, 49.7), (1.5, 50.5), (4.0, 51.8), (5.0, 53.2), (8.5, 53.8),
+        (8.6, 57.1), (10.6, 57.7), (10.5, 55.0), (12.0, 54.2), (14.2, 53.9),
+        (18.5, 54.7), (21.2, 55.2), (21.0, 57.0), (24.2, 59.4), (28.0, 59.5),
+        (30.3, 59.9), (27.0, 60.5), (22.8, 59.9), (21.5, 61.0), (21.5, 63.0),
+        (25.0, 65.0), (22.0, 65.7), (21.0, 64.5), (17.5, 62.5), (18.8, 60.2),
+        (16.5, 57.5), (14.2, 55.4), (12.8, 55.8), (11.0, 58.9), (8.0, 58.1),
+        (5.5, 58.9), (5.0, 61.5), (8.0, 63.5), (12.5, 66.0), (15.0, 68.5),
+        (19.0, 70.0), (25.8, 71.1), (31.0, 70.3), (34.0, 69.3), (40.0, 67.8),
+        (44.0, 68.5), (53.0, 68.5), (60.0, 69.8), (68.0, 69.5), (73.0, 72.5),
+        (80.0, 73.5), (87.0, 74.0), (100.0, 76.0), (104.0, 77.7), (113.0, 73.7),
+        (130.0, 71.0), (140.0, 72.5), (150.0, 71.5), (160.0, 69.5), (170.0, 70.0),
+        (180.0, 69.0), (180.0, 65.0), (178.0, 64.5), (177.0, 62.5), (170.0, 60.0),
+        (163.0, 59.8), (163.0, 57.5), (160.0, 54.0), (156.5, 51.0), (156.0, 57.0),
+        (155.0, 59.2), (143.0, 59.3), (137.0, 54.0), (141.0, 52.0), (140.0, 48.0),
+        (135.0, 43.5), (131.0, 42.7), (129.5, 41.0), (129.5, 36.0), (128.0, 35.0),
+        (126.3, 34.6), (126.6, 37.5), (124.0, 39.8), (121.5, 40.9), (118.0, 39.0),
+        (119.0, 37.2), (122.5, 37.0), (119.3, 34.9), (121.5, 32.0), (122.0, 30.0),
+        (119.5, 26.0), (116.5, 23.0), (113.5, 22.2), (110.0, 21.0), (108.0, 21.5),
+        (106.6, 20.5), (105.6, 19.0), (108.0, 16.0), (109.4, 12.0), (107.0, 10.4),
+        (104.8, 8.6), (104.8, 10.5), (102.5, 12.2), (100.8, 13.4), (99.2, 10.5),
+        (100.3, 6.5), (103.5, 4.0), (104.2, 1.4), (101.3, 2.7), (98.5, 8.0),
+        (98.3, 10.0), (97.8, 16.5), (94.3, 16.0), (94.5, 19.0), (92.4, 21.0),
+        (90.5, 22.0), (88.0, 21.6), (86.8, 20.0), (80.2, 15.0), (80.2, 13.0),
+        (79.8, 10.3), (77.5, 8.1), (76.0, 10.0), (73.5, 16.0), (72.8, 19.0),
+        (72.5, 21.5), (70.0, 22.5), (68.5, 23.5), (66.5, 25.4), (61.5, 25.2),
+        (57.3, 25.7), (56.5, 27.1), (54.0, 26.6), (51.5, 27.9), (50.3, 30.0),
+        (48.5, 30.0), (48.0, 29.5), (50.0, 26.5), (51.6, 24.2), (54.0, 24.2),
+        (56.0, 24.8), (56.3, 26.2), (57.0, 23.9), (58.8, 23.5), (59.8, 22.4),
+        (57.8, 18.9), (55.0, 17.0), (52.0, 15.5), (45.0, 13.0), (43.4, 12.7),
+        (42.7, 16.0), (39.0, 21.5), (35.0, 28.0), (34.9, 29.5), (34.3, 27.8),
+        (32.6, 29.9), (32.3, 31.3), (34.3, 31.3), (35.0, 33.0), (36.0, 35.0),
+        (36.0, 36.8), (32.0, 36.2), (29.0, 36.6), (27.3, 37.5), (26.5, 40.0),
+        (29.0, 41.0), (35.0, 42.0), (41.6, 41.6), (39.7, 44.0), (38.0, 46.5),
+        (33.0, 46.2), (30.7, 46.5), (29.7, 45.3), (28.6, 43.8), (28.0, 42.0),
+        (26.0, 40.8), (23.5, 40.3), (24.0, 38.0), (22.5, 36.5), (21.1, 38.3),
+        (19.5, 40.5), (19.4, 42.0), (15.5, 44.0), (13.6, 45.7), (12.3, 45.3),
+        (12.4, 44.2), (14.0, 42.5), (16.2, 41.3), (18.5, 40.2), (16.6, 38.5),
+        (15.6, 38.0), (16.0, 39.9), (15.6, 40.0), (13.7, 41.2), (12.0, 42.0),
+        (10.5, 43.0), (8.8, 44.4), (6.2, 43.1), (3.2, 43.2), (3.2, 41.9),
+        (0.5, 40.5), (-0.3, 39.5), (0.0, 38.6), (-2.0, 36.7), (-5.4, 36.1),
+        (-6.3, 36.8), (-8.9, 37.0),
+    ],
+    // Great Britain
+    &[
+        (-5.7, 50.0), (1.4, 51.2), (1.7, 52.7), (0.0, 53.5), (-1.6, 55.5),
+        (-2.0, 57.7), (-3.0, 58.6), (-5.0, 58.6), (-6.2, 56.5), (-4.8, 54.8),
+        (-3.1, 53.5), (-4.7, 52.8), (-5.3, 51.7), (-3.5, 51.4),
+    ],
+    // Ireland
+    &[(-6.0, 52.2), (-6.0, 54.0), (-7.5, 55.3), (-10.0, 54.2), (-10.2, 51.6), (-8.0, 51.6)],
+    // Iceland
+    &[
+        (-24.0, 65.5), (-22.0, 66.4), (-16.0, 66.5), (-13.5, 65.0), (-15.0, 64.2),
+        (-20.0, 63.4), (-22.7, 63.9),
+    ],
+    // Africa
+    &[
+        (-5.9, 35.8), (-1.0, 35.2), (3.0, 36.8), (10.0, 37.3), (11.0, 35.0),
+        (10.5, 33.7), (11.5, 33.0), (15.3, 32.3), (20.0, 31.0), (20.0, 32.5),
+        (23.0, 32.6), (25.0, 31.7), (29.5, 31.0), (32.3, 31.3), (32.6, 29.9),
+        (33.6, 27.3), (35.6, 23.9), (37.3, 21.0), (38.8, 17.5), (41.5, 14.5),
+        (43.2, 12.0), (45.0, 10.4), (51.3, 11.8), (51.0, 10.4), (48.5, 5.0),
+        (46.0, 2.0), (42.0, -1.0), (40.2, -2.8), (39.3, -6.5), (40.5, -11.0),
+        (40.5, -15.0), (37.0, -18.0), (35.3, -22.0), (32.8, -26.0), (32.4, -28.8),
+        (30.5, -31.3), (27.5, -33.5), (25.5, -34.0), (20.0, -34.8), (18.4, -34.0),
+        (17.9, -31.8), (15.2, -27.0), (14.4, -23.0), (11.8, -17.5), (13.6, -12.0),
+        (12.0, -5.0), (9.3, -1.0), (9.5, 3.0), (8.5, 4.5), (6.0, 4.3),
+        (3.4, 6.4), (-2.0, 4.7), (-7.5, 4.3), (-11.5, 6.9), (-13.3, 9.0),
+        (-16.7, 12.5), (-17.5, 14.7), (-16.3, 19.5), (-17.0, 21.0), (-14.9, 25.5),
+        (-13.0, 27.7), (-9.8, 30.0), (-9.6, 32.5), (-6.8, 34.0),
+    ],
+    // Madagascar
+    &[
+        (49.3, -12.0), (50.5, -15.5), (49.5, -17.5), (47.0, -25.0), (44.0, -25.0),
+        (43.3, -22.0), (44.3, -17.0), (46.3, -15.7),
+    ],
+    // Japan: Honshu, Kyushu and Shikoku as one
+    &[
+        (130.2, 33.6), (131.0, 34.4), (133.0, 35.5), (136.0, 35.8), (137.3, 36.8),
+        (139.8, 38.2), (140.0, 40.0), (141.5, 41.3), (142.0, 39.5), (141.0, 37.0),
+        (140.8, 35.7), (139.8, 34.9), (137.0, 34.6), (135.0, 33.5), (132.4, 32.8),
+        (130.8, 31.3),
+    ],
+    // Hokkaido
+    &[(140.0, 41.5), (141.5, 42.5), (145.5, 43.3), (142.0, 45.5), (140.0, 43.5)],
+    // Luzon
+    &[(120.6, 18.5), (122.2, 18.5), (122.0, 16.0), (124.0, 13.0), (120.6, 14.2), (120.0, 16.0)],
+    // Sumatra
+    &[(95.3, 5.6), (98.0, 4.0), (103.0, -1.0), (106.0, -5.9), (104.5, -5.9), (101.0, -2.5), (97.5, 2.0)],
+    // Java
+    &[(105.2, -6.8), (108.5, -6.6), (112.6, -6.9), (114.5, -7.8), (110.5, -8.2), (106.5, -7.4)],
+    // Borneo
+    &[
+        (109.0, 1.5), (111.0, -3.0), (114.5, -4.0), (116.2, -3.5), (117.5, 0.5),
+        (119.0, 5.0), (117.0, 7.0), (115.5, 5.0), (113.0, 3.2), (111.0, 1.8),
+    ],
+    // New Guinea
+    &[
+        (131.0, -1.3), (135.0, -3.3), (138.0, -1.6), (141.0, -2.6), (145.8, -5.0),
+        (147.5, -6.2), (150.5, -10.5), (147.0, -10.2), (144.0, -7.8), (141.0, -9.0),
+        (138.0, -8.3), (137.7, -5.0), (133.0, -4.0),
+    ],
+    // Australia
+    &[
+        (113.5, -22.0), (114.0, -26.5), (115.0, -34.0), (118.0, -35.0), (123.5, -33.9),
+        (129.0, -31.6), (131.0, -31.5), (135.0, -34.5), (138.0, -35.6), (140.0, -38.0),
+        (144.0, -38.3), (146.3, -39.0), (150.0, -37.5), (151.3, -33.8), (153.0, -31.0),
+        (153.5, -28.0), (153.0, -25.0), (150.8, -22.5), (149.0, -20.3), (146.0, -18.5),
+        (145.3, -15.0), (143.5, -14.0), (142.5, -10.7), (141.6, -13.0), (141.5, -17.0),
+        (140.8, -17.5), (139.5, -17.5), (135.5, -15.0), (136.7, -12.2), (132.5, -11.3),
+        (130.3, -12.7), (129.4, -14.9), (126.2, -14.1), (122.2, -17.5), (121.0, -19.5),
+        (117.0, -20.6),
+    ],
+    // New Zealand: North Island
+    &[
+        (172.7, -34.4), (174.4, -36.3), (178.5, -37.7), (177.0, -39.5), (175.3, -41.6),
+        (174.6, -39.8), (173.8, -39.0), (174.0, -36.8),
+    ],
+    // New Zealand: South Island
+    &[
+        (172.7, -40.5), (174.3, -41.7), (173.0, -43.6), (171.2, -44.5), (169.0, -46.6),
+        (166.5, -46.0), (168.0, -44.0), (170.5, -43.0),
+    ],
+    // Antarctica
+    &[
+        (-180.0, -90.0), (-180.0, -84.0), (-150.0, -77.0), (-120.0, -73.5), (-90.0, -72.5),
+        (-70.0, -69.0), (-60.0, -63.0), (-65.0, -67.0), (-60.0, -74.0), (-40.0, -78.0),
+        (-20.0, -73.0), (0.0, -70.0), (30.0, -69.5), (60.0, -67.0), (90.0, -66.0),
+        (120.0, -66.5), (150.0, -68.5), (170.0, -72.0), (180.0, -78.0), (180.0, -90.0),
+    ],
+];
+
+/// Inland seas and bays, drawn over the land in the ocean color
+pub const WATER: &[&[(f32, f32)]] = &[
+    // Hudson Bay
+    &[
+        (-94.5, 59.0), (-93.0, 61.5), (-88.0, 64.0), (-82.0, 64.5), (-78.0, 62.5),
+        (-77.0, 60.0), (-77.0, 56.0), (-79.5, 54.5), (-82.3, 52.9), (-85.0, 55.3),
+        (-90.0, 57.0),
+    ],
+    // Caspian Sea
+    &[
+        (47.0, 44.5), (51.0, 47.0), (53.0, 45.0), (51.0, 43.0), (53.0, 41.5),
+        (54.0, 37.4), (50.5, 37.0), (49.0, 38.5), (49.5, 40.5),
+    ],
+];
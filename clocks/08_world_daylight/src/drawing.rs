@@ -0,0 +1,481 @@
+//! Drawing module - Render functions for the World Daylight clock
+//!
+//! Draws the map in layers: ocean and land, graticule, the night shade
+//! mesh, the terminator and subsolar point, then zone markers and overlays.
+
+use nannou::prelude::*;
+use shared::{tr, tr_args, CommandRegistry, Coordinates, DaylightPhase};
+
+use crate::coastline::{LAND, WATER};
+use crate::map::{project, ShadeGrid};
+use crate::ZoneMarker;
+
+/// Color palette for the world daylight aesthetic
+pub mod colors {
+    use nannou::prelude::*;
+    use shared::{Rgb, ThemeRole};
+
+    /// Window background around the map
+    pub const BACKGROUND: Srgb<u8> = Srgb {
+        red: 10,
+        green: 14,
+        blue: 22,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Open ocean
+    pub const OCEAN: Srgb<u8> = Srgb {
+        red: 28,
+        green: 56,
+        blue: 88,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Land
+    pub const LAND: Srgb<u8> = Srgb {
+        red: 92,
+        green: 120,
+        blue: 82,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Coastline stroke
+    pub const COAST: Srgb<u8> = Srgb {
+        red: 130,
+        green: 160,
+        blue: 115,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Night shade at full darkness
+    pub const NIGHT: Srgb<u8> = Srgb {
+        red: 4,
+        green: 6,
+        blue: 20,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Terminator line and subsolar sun
+    pub const SUN: Srgb<u8> = Srgb {
+        red: 255,
+        green: 210,
+        blue: 90,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Markers in civil twilight
+    pub const TWILIGHT: Srgb<u8> = Srgb {
+        red: 240,
+        green: 140,
+        blue: 90,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Markers at night
+    pub const MOON: Srgb<u8> = Srgb {
+        red: 150,
+        green: 170,
+        blue: 230,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Selected zone ring and accents
+    pub const ACCENT: Srgb<u8> = Srgb {
+        red: 90,
+        green: 220,
+        blue: 255,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Primary text
+    pub const TEXT_PRIMARY: Srgb<u8> = Srgb {
+        red: 230,
+        green: 232,
+        blue: 240,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Secondary text
+    pub const TEXT_SECONDARY: Srgb<u8> = Srgb {
+        red: 140,
+        green: 150,
+        blue: 165,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Panel background
+    pub fn panel_bg() -> Srgba<u8> {
+        srgba(16, 20, 30, 235)
+    }
+
+    /// Graticule lines
+    pub fn grid() -> Srgba<u8> {
+        srgba(255, 255, 255, 28)
+    }
+
+    /// Look up one of this clock's role colors in the active theme
+    fn themed(role: ThemeRole, native: Srgb<u8>) -> Srgb<u8> {
+        let color = shared::themed(role, Rgb::new(native.red, native.green, native.blue));
+        Srgb::new(color.red, color.green, color.blue)
+    }
+
+    pub fn background() -> Srgb<u8> {
+        themed(ThemeRole::Background, BACKGROUND)
+    }
+
+    pub fn text_primary() -> Srgb<u8> {
+        themed(ThemeRole::PrimaryText, TEXT_PRIMARY)
+    }
+
+    pub fn text_secondary() -> Srgb<u8> {
+        themed(ThemeRole::SecondaryText, TEXT_SECONDARY)
+    }
+
+    pub fn accent() -> Srgb<u8> {
+        themed(ThemeRole::Accent, ACCENT)
+    }
+}
+
+/// Darkest the night shade gets, so land stays faintly visible
+const NIGHT_MAX_ALPHA: f32 = 0.78;
+
+/// Marker color for a daylight phase
+pub fn phase_color(phase: DaylightPhase) -> Srgb<u8> {
+    match phase {
+        DaylightPhase::Day => colors::SUN,
+        DaylightPhase::CivilTwilight => colors::TWILIGHT,
+        DaylightPhase::Night => colors::MOON,
+    }
+}
+
+fn outline_points(outline: &[(f32, f32)], rect: Rect) -> Vec<Point2> {
+    outline
+        .iter()
+        .map(|&(lon, lat)| project(Coordinates::new(lat as f64, lon as f64), rect))
+        .collect()
+}
+
+/// Draw the ocean, landmasses and inland seas
+pub fn draw_base_map(draw: &Draw, rect: Rect) {
+    draw.rect().xy(rect.xy()).wh(rect.wh()).color(colors::OCEAN);
+
+    for outline in LAND {
+        let points = outline_points(outline, rect);
+        draw.polygon().points(points.iter().copied()).color(colors::LAND);
+        draw.polyline()
+            .weight(1.0)
+            .points_closed(points)
+            .color(colors::COAST);
+    }
+    for outline in WATER {
+        draw.polygon()
+            .points(outline_points(outline, rect))
+            .color(colors::OCEAN);
+    }
+}
+
+/// Draw meridians and parallels every 30°, with the equator and tropics
+pub fn draw_graticule(draw: &Draw, rect: Rect) {
+    for i in 0..=12 {
+        let x = rect.left() + rect.w() * i as f32 / 12.0;
+        draw.line()
+            .start(pt2(x, rect.bottom()))
+            .end(pt2(x, rect.top()))
+            .color(colors::grid())
+            .weight(1.0);
+    }
+    for i in 0..=6 {
+        let y = rect.bottom() + rect.h() * i as f32 / 6.0;
+        draw.line()
+            .start(pt2(rect.left(), y))
+            .end(pt2(rect.right(), y))
+            .color(colors::grid())
+            .weight(1.0);
+    }
+
+    // Tropics, where the subsolar point turns back at the solstices
+    for latitude in [23.44, -23.44] {
+        let y = project(Coordinates::new(latitude, 0.0), rect).y;
+        let dashes = 72;
+        for i in (0..dashes).step_by(2) {
+            let x0 = rect.left() + rect.w() * i as f32 / dashes as f32;
+            let x1 = rect.left() + rect.w() * (i + 1) as f32 / dashes as f32;
+            draw.line()
+                .start(pt2(x0, y))
+                .end(pt2(x1, y))
+                .color(colors::grid())
+                .weight(1.0);
+        }
+    }
+}
+
+/// Draw the night side as a shaded mesh, fading through twilight
+pub fn draw_night(draw: &Draw, grid: &ShadeGrid, rect: Rect) {
+    let night = colors::NIGHT.into_format::<f32>();
+
+    let points = (0..grid.rows).flat_map(|row| {
+        (0..grid.columns).map(move |column| {
+            let p = project(grid.coordinates(column, row), rect);
+            let alpha = grid.shade(column, row) * NIGHT_MAX_ALPHA;
+            (pt3(p.x, p.y, 0.0), srgba(night.red, night.green, night.blue, alpha))
+        })
+    });
+
+    // Two triangles per cell, skipping cells in full daylight
+    let mut indices = Vec::new();
+    for row in 0..grid.rows - 1 {
+        for column in 0..grid.columns - 1 {
+            let i = row * grid.columns + column;
+            let corners = [i, i + 1, i + grid.columns, i + grid.columns + 1];
+            let lit = [(column, row), (column + 1, row), (column, row + 1), (column + 1, row + 1)]
+                .iter()
+                .all(|&(c, r)| grid.shade(c, r) == 0.0);
+            if lit {
+                continue;
+            }
+            indices.extend_from_slice(&[corners[0], corners[1], corners[2]]);
+            indices.extend_from_slice(&[corners[1], corners[3], corners[2]]);
+        }
+    }
+
+    draw.mesh().indexed_colored(points, indices);
+}
+
+/// Draw the terminator line and the sun over the subsolar point
+pub fn draw_terminator(draw: &Draw, terminator: &[Coordinates], subsolar: Coordinates, rect: Rect) {
+    let line_color = srgba(colors::SUN.red, colors::SUN.green, colors::SUN.blue, 150u8);
+    draw.polyline()
+        .weight(1.5)
+        .points(terminator.iter().map(|&c| project(c, rect)))
+        .color(line_color);
+
+    let sun = project(subsolar, rect);
+    draw.ellipse()
+        .xy(sun)
+        .radius(11.0)
+        .color(srgba(colors::SUN.red, colors::SUN.green, colors::SUN.blue, 60u8));
+    draw.ellipse().xy(sun).radius(6.0).color(colors::SUN);
+}
+
+/// Draw a marker for each zone; the selected zone gets a ring
+pub fn draw_markers(draw: &Draw, markers: &[ZoneMarker], rect: Rect, show_labels: bool) {
+    for marker in markers {
+        let pos = project(marker.coordinates, rect);
+        let color = phase_color(marker.time_data.solar.phase());
+
+        if marker.is_selected {
+            draw.ellipse()
+                .xy(pos)
+                .radius(10.0)
+                .no_fill()
+                .stroke(colors::accent())
+                .stroke_weight(2.0);
+        }
+        draw.ellipse()
+            .xy(pos)
+            .radius(5.0)
+            .color(color)
+            .stroke(colors::background())
+            .stroke_weight(1.5);
+
+        if show_labels || marker.is_selected {
+            let label = format!("{}  {}", marker.city(), marker.time_data.format_time());
+            let text_color = if marker.is_selected {
+                colors::text_primary()
+            } else {
+                colors::text_secondary()
+            };
+            draw.text(&label)
+                .xy(pos + vec2(78.0, 0.0))
+                .w(140.0)
+                .left_justify()
+                .font_size(11)
+                .color(text_color);
+        }
+    }
+}
+
+/// Draw the readout for the point under the mouse
+pub fn draw_hover_tooltip(draw: &Draw, pos: Point2, lines: &[String]) {
+    let width = 230.0;
+    let height = 16.0 * lines.len() as f32 + 12.0;
+    let center = pos + vec2(width / 2.0 + 14.0, -height / 2.0 - 14.0);
+
+    draw.rect()
+        .xy(center)
+        .w_h(width, height)
+        .color(colors::panel_bg());
+    for (i, line) in lines.iter().enumerate() {
+        draw.text(line)
+            .xy(pt2(center.x, center.y + height / 2.0 - 14.0 - i as f32 * 16.0))
+            .w(width - 16.0)
+            .left_justify()
+            .font_size(11)
+            .color(if i == 0 {
+                colors::text_primary()
+            } else {
+                colors::text_secondary()
+            });
+    }
+}
+
+/// Draw the header above the map: UTC time and where the sun is overhead
+pub fn draw_header(
+    draw: &Draw,
+    canvas_rect: Rect,
+    map_rect: Rect,
+    utc_time: &str,
+    subsolar: Coordinates,
+    keys: &CommandRegistry,
+) {
+    let y = map_rect.top() + (canvas_rect.top() - map_rect.top()) / 2.0;
+    draw.text(&format!("UTC {}", utc_time))
+        .xy(pt2(map_rect.left() + 100.0, y))
+        .w(200.0)
+        .left_justify()
+        .font_size(14)
+        .color(colors::text_primary());
+    draw.text(&tr_args("Sun overhead at {0}", &[&format_coordinates(subsolar)]))
+        .xy(pt2(map_rect.right() - 150.0, y))
+        .w(300.0)
+        .right_justify()
+        .font_size(12)
+        .color(colors::SUN);
+
+    let hint = format!(
+        "{}  |  {}  |  Ctrl+K: {}",
+        tr("Click the map to pick a zone"),
+        keys.hint_line(&[
+            ("Open timezone picker", "Zone"),
+            ("Toggle favorite", "Favorite"),
+            ("Toggle help", "Help"),
+        ]),
+        tr("Commands")
+    );
+    draw.text(&hint)
+        .xy(pt2(map_rect.x(), map_rect.bottom() - (map_rect.bottom() - canvas_rect.bottom()) / 2.0))
+        .w(map_rect.w())
+        .font_size(10)
+        .color(colors::text_secondary());
+}
+
+/// Format a coordinate as e.g. "23.4°N 12.3°W"
+pub fn format_coordinates(coords: Coordinates) -> String {
+    let ns = if coords.latitude >= 0.0 { 'N' } else { 'S' };
+    let ew = if coords.longitude >= 0.0 { 'E' } else { 'W' };
+    format!(
+        "{:.1}°{} {:.1}°{}",
+        coords.latitude.abs(),
+        ns,
+        coords.longitude.abs(),
+        ew
+    )
+}
+
+/// Draw the help panel
+pub fn draw_help_panel(draw: &Draw, canvas_rect: Rect, keys: &CommandRegistry) {
+    let panel_width = 460.0;
+    let panel_height = 360.0;
+    let center = canvas_rect.xy();
+
+    draw.rect()
+        .xy(center)
+        .w_h(panel_width, panel_height)
+        .color(colors::panel_bg());
+    draw.rect()
+        .xy(center)
+        .w_h(panel_width, panel_height)
+        .no_fill()
+        .stroke(colors::accent())
+        .stroke_weight(2.0);
+
+    draw.text(tr("Reading the Daylight Map"))
+        .xy(center + vec2(0.0, panel_height / 2.0 - 30.0))
+        .color(colors::text_primary())
+        .font_size(20)
+        .w(panel_width - 40.0);
+
+    let content = [
+        (true, "SHADING"),
+        (false, "  Bright: sun above the horizon"),
+        (false, "  Fading: twilight, down to 18° below"),
+        (true, "TERMINATOR"),
+        (false, "  Yellow line: sunrise or sunset right now"),
+        (false, "  Sun marker: where it is directly overhead"),
+        (true, "MARKERS"),
+        (false, "  Yellow day, orange twilight, blue night"),
+        (false, "  Ringed marker: the selected zone"),
+        (true, "SELECTING"),
+        (false, "  Click a marker or anywhere on the map"),
+        (false, "  Open ocean picks the nautical zone"),
+    ];
+
+    let line_height = 20.0;
+    let start_y = panel_height / 2.0 - 70.0;
+    let left_edge = center.x - panel_width / 2.0 + 25.0;
+
+    for (i, (is_header, text)) in content.iter().enumerate() {
+        let y = center.y + start_y - i as f32 * line_height;
+        draw.text(tr(text))
+            .xy(pt2(left_edge + (panel_width - 50.0) / 2.0, y))
+            .color(if *is_header {
+                colors::accent()
+            } else {
+                colors::text_secondary()
+            })
+            .font_size(if *is_header { 13 } else { 12 })
+            .w(panel_width - 50.0)
+            .left_justify();
+    }
+
+    draw.text(&tr_args("Press {0} or Esc to close", &[&keys.key_label("Toggle help")]))
+        .xy(center + vec2(0.0, -panel_height / 2.0 + 20.0))
+        .color(colors::text_secondary())
+        .font_size(11)
+        .w(panel_width);
+}
+
+/// Draw a toast notification
+pub fn draw_toast(draw: &Draw, message: &str, alpha: f32, canvas_rect: Rect) {
+    let toast_width = 320.0;
+    let toast_height = 40.0;
+    let pos = pt2(canvas_rect.x(), canvas_rect.bottom() + 70.0);
+    let alpha_u8 = (alpha * 255.0) as u8;
+
+    draw.rect()
+        .xy(pos)
+        .w_h(toast_width, toast_height)
+        .color(srgba(16, 20, 30, alpha_u8));
+    draw.rect()
+        .xy(pos)
+        .w_h(toast_width, toast_height)
+        .no_fill()
+        .stroke(srgba(colors::accent().red, colors::accent().green, colors::accent().blue, alpha_u8))
+        .stroke_weight(1.0);
+    draw.text(message)
+        .xy(pos)
+        .color(srgba(
+            colors::text_primary().red,
+            colors::text_primary().green,
+            colors::text_primary().blue,
+            alpha_u8,
+        ))
+        .font_size(13)
+        .w(toast_width - 20.0);
+}
+
+/// Draw error banner for TZ data issues
+pub fn draw_error_banner(draw: &Draw, window_rect: Rect) {
+    let banner_height = 40.0;
+    let banner_y = window_rect.top() - banner_height / 2.0;
+
+    draw.rect()
+        .x_y(window_rect.x(), banner_y)
+        .w_h(window_rect.w(), banner_height)
+        .color(srgba(120u8, 40u8, 40u8, 220u8));
+    draw.text(tr("⚠ Timezone data may be missing or stale. Showing UTC as fallback."))
+        .x_y(window_rect.x(), banner_y)
+        .color(colors::text_primary())
+        .font_size(14)
+        .w(window_rect.w() - 40.0);
+}
@@ -0,0 +1,866 @@
+//! World Daylight Clock
+//!
+//! A clock as a map of the planet: an equirectangular world with the live
+//! day/night terminator sweeping across it. Favorite zones are marked where
+//! their cities are, colored by whether the sun is up there, and clicking
+//! anywhere on the map selects the zone for that place.
+
+mod coastline;
+mod drawing;
+mod map;
+mod ui;
+
+use std::time::Instant;
+
+use chrono_tz::Tz;
+use nannou::event::ModifiersState;
+use nannou::prelude::*;
+use nannou_egui::{self, Egui};
+use serde::{Deserialize, Serialize};
+use shared::{
+    compute_time_data, report_save, tr, tr_args, ClipRecorder, ClipSettings, ClipStep,
+    CommandPalette, CommandRegistry, ConfigRecovery, ConfigSaver, Coordinates, Favorites,
+    FollowSystemZone, FramePacer, KeyChord, Keymap, LanguageSetting, NoFocusRegions, QuietHours,
+    RecoveryChoice, SafePaletteSetting, ScreenReader, SnapshotProgress, SnapshotStep,
+    SystemZoneWatcher, ThemeLibrary, TickGranularity, Ticker, TimeData, TimeReveal, Validity,
+    VisualIntensity, WindowPlacement, WindowTitle, WindowTitleClock,
+};
+
+use crate::map::{
+    elevation_from_subsolar, map_rect, project, terminator, unproject, zone_at, ShadeGrid,
+};
+use crate::ui::{format_zone_name, PickerState};
+
+const CLOCK_NAME: &str = "world_daylight";
+const DEFAULT_TZ: &str = "America/Los_Angeles";
+const WINDOW_TITLE: &str = "World Daylight Clock";
+const SIDEBAR_WIDTH: f32 = 260.0;
+/// Space around the map for the header and hint line
+const MAP_MARGIN: f32 = 40.0;
+/// Clicks within this many pixels of a marker select its zone
+const MARKER_HIT_RADIUS: f32 = 10.0;
+const TERMINATOR_SAMPLES: usize = 181;
+
+/// Run the clock; returns only when the window is closed
+pub fn run() {
+    nannou::app(model).update(update).run();
+}
+
+/// Launcher entry for this clock
+pub struct WorldDaylight;
+
+impl shared::ClockApp for WorldDaylight {
+    fn id(&self) -> &'static str {
+        CLOCK_NAME
+    }
+
+    fn title(&self) -> &'static str {
+        "World Daylight"
+    }
+
+    fn tagline(&self) -> &'static str {
+        "The day/night terminator sweeping over a world map"
+    }
+
+    fn run(&self) {
+        run();
+    }
+}
+
+/// Toast notification for transient messages
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub created: Instant,
+    pub duration_secs: f32,
+}
+
+impl Toast {
+    pub fn new(message: String, duration_secs: f32) -> Self {
+        Self {
+            message,
+            created: Instant::now(),
+            duration_secs,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.created.elapsed().as_secs_f32() > self.duration_secs
+    }
+
+    pub fn alpha(&self) -> f32 {
+        let elapsed = self.created.elapsed().as_secs_f32();
+        if elapsed > self.duration_secs - 0.5 {
+            ((self.duration_secs - elapsed) / 0.5).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+/// A zone shown on the map at its reference city
+#[derive(Debug, Clone)]
+pub struct ZoneMarker {
+    pub tz: Tz,
+    pub coordinates: Coordinates,
+    pub time_data: TimeData,
+    pub is_selected: bool,
+    pub is_favorite: bool,
+}
+
+impl ZoneMarker {
+    fn new(tz: Tz, selected: Tz, favorites: &[Tz]) -> Self {
+        let time_data = compute_time_data(tz);
+        Self {
+            tz,
+            coordinates: time_data.solar.coordinates,
+            time_data,
+            is_selected: tz == selected,
+            is_favorite: favorites.contains(&tz),
+        }
+    }
+
+    pub fn city(&self) -> String {
+        format_zone_name(self.tz)
+    }
+}
+
+/// Persisted configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Config {
+    selected_zone_id: String,
+    favorites: Vec<String>,
+    /// Keep `favorites` instead of following the series-wide list
+    #[serde(default)]
+    own_favorites: bool,
+    show_grid: bool,
+    show_labels: bool,
+    /// Selected theme name; `None` uses the clock's own colors
+    #[serde(default)]
+    theme: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            selected_zone_id: DEFAULT_TZ.to_string(),
+            favorites: vec![
+                "America/New_York".to_string(),
+                "America/Los_Angeles".to_string(),
+                "Europe/London".to_string(),
+                "Asia/Tokyo".to_string(),
+                "Australia/Sydney".to_string(),
+            ],
+            own_favorites: false,
+            show_grid: true,
+            show_labels: true,
+            theme: None,
+        }
+    }
+}
+
+impl shared::VersionedConfig for Config {
+    const VERSION: u32 = 1;
+}
+
+/// Application state
+pub struct Model {
+    // Time state
+    pub selected_zone: Tz,
+    pub favorites: Favorites,
+    pub time_data: TimeData,
+
+    // Map state, refreshed on each tick
+    pub markers: Vec<ZoneMarker>,
+    pub shade: ShadeGrid,
+    pub terminator: Vec<Coordinates>,
+
+    // Display options
+    pub show_grid: bool,
+    pub show_labels: bool,
+
+    // Shared quiet-hours schedule and what it allows right now
+    pub quiet_hours: QuietHours,
+    pub intensity: VisualIntensity,
+    /// Shared setting for the time in the window title
+    pub window_title_clock: WindowTitleClock,
+    /// Shared settings for clips recorded with Ctrl+Shift+R
+    pub clip_settings: ClipSettings,
+    /// Clip being recorded or saved, if any
+    pub clip_recorder: ClipRecorder,
+    /// Shared language for labels, dates and toasts
+    pub language: LanguageSetting,
+    /// Shared high-contrast or color-blind safe palette
+    pub safe_palette: SafePaletteSetting,
+    /// Keeps the window title's time current
+    window_title: WindowTitle,
+    /// Remembers the window's size, position and monitor
+    window_placement: WindowPlacement,
+    /// Shared setting for switching to the system zone when it changes
+    follow_system_zone: FollowSystemZone,
+    /// Reports changes to the computer's time zone
+    system_zone: SystemZoneWatcher,
+    /// Shared system-wide shortcut that shows the time from the background
+    time_reveal: TimeReveal,
+    /// Drops the frame rate while the clock sits idle
+    frame_pacer: FramePacer,
+    /// Writes config changes once they settle
+    config_saver: ConfigSaver,
+    /// Shown when the saved config couldn't be read
+    config_recovery: Option<ConfigRecovery>,
+
+    // Built-in and user themes; `theme` is `None` for the clock's own colors
+    pub themes: ThemeLibrary,
+    pub theme: Option<String>,
+
+    // Interaction state
+    pub mouse_pos: Point2,
+    pub help_panel_open: bool,
+
+    // UI state
+    pub picker_state: PickerState,
+    /// Ctrl+K command palette
+    command_palette: CommandPalette,
+    /// Actions listed in the command palette, and the chords they're bound to
+    keymap: Keymap,
+
+    // Toast notifications
+    pub toasts: Vec<Toast>,
+
+    // Error state
+    pub tz_error: bool,
+    pub last_valid_zone: Tz,
+
+    // Wall-clock ticker driving live recomputation
+    ticker: Ticker,
+    // Frame counter for `--snapshot` runs
+    snapshot: SnapshotProgress,
+
+    /// Screen reader view of the time, DST status and focus
+    screen_reader: ScreenReader,
+
+    // egui integration
+    egui: Egui,
+}
+
+impl Model {
+    /// Whether something on screen is moving on its own: a toast or a clip
+    /// being captured
+    fn is_animating(&self) -> bool {
+        !self.toasts.is_empty()
+            || self.clip_recorder.is_recording()
+            || self.time_reveal.is_flashing()
+    }
+
+    /// Recompute time data, markers and the night shade for now
+    pub fn refresh(&mut self) {
+        self.time_data = compute_time_data(self.selected_zone);
+        self.intensity = self.quiet_hours.intensity(&self.time_data);
+        self.shade = ShadeGrid::compute(shared::now_utc());
+        self.terminator = terminator(self.shade.subsolar, TERMINATOR_SAMPLES);
+        self.rebuild_markers();
+    }
+
+    /// Favorites plus the selected zone, each at its reference city
+    fn rebuild_markers(&mut self) {
+        let mut zones = self.favorites.to_vec();
+        if !zones.contains(&self.selected_zone) {
+            zones.push(self.selected_zone);
+        }
+        self.markers = zones
+            .into_iter()
+            .map(|tz| ZoneMarker::new(tz, self.selected_zone, &self.favorites))
+            .collect();
+    }
+
+    /// Set a new timezone
+    pub fn set_timezone(&mut self, tz: Tz) {
+        self.selected_zone = tz;
+        self.time_data = compute_time_data(tz);
+
+        if self.time_data.validity == Validity::Ok {
+            self.last_valid_zone = tz;
+            self.tz_error = false;
+        } else {
+            self.tz_error = true;
+            self.show_toast(tr("Timezone data may be stale or missing").to_string());
+        }
+
+        self.rebuild_markers();
+        save_config(self);
+    }
+
+    /// Show a toast notification
+    pub fn show_toast(&mut self, message: String) {
+        self.toasts.push(Toast::new(message, 4.0));
+    }
+
+    /// Prune expired toasts
+    pub fn prune_toasts(&mut self) {
+        self.toasts.retain(|t| !t.is_expired());
+    }
+
+    /// Toggle favorite status for a zone
+    pub fn toggle_favorite(&mut self, tz: Tz) {
+        self.favorites.toggle(tz);
+        self.rebuild_markers();
+        save_config(self);
+    }
+
+    /// Select the zone under a point on the map: a marker if one was hit,
+    /// otherwise the zone for that place
+    pub fn select_at(&mut self, pos: Point2, map: Rect) {
+        let hit = self
+            .markers
+            .iter()
+            .map(|m| (m.tz, project(m.coordinates, map).distance(pos)))
+            .filter(|(_, d)| *d <= MARKER_HIT_RADIUS)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(tz, _)| tz);
+
+        let tz = match (hit, unproject(pos, map)) {
+            (Some(tz), _) => tz,
+            (None, Some(coords)) => zone_at(coords),
+            (None, None) => return,
+        };
+        if tz != self.selected_zone {
+            self.set_timezone(tz);
+            self.show_toast(tr_args("Selected {0}", &[&tz.name()]));
+        }
+    }
+
+    /// Lines describing the point under the mouse, if it is on the map
+    pub fn hover_lines(&self, map: Rect) -> Option<Vec<String>> {
+        let coords = unproject(self.mouse_pos, map)?;
+        let elevation = elevation_from_subsolar(coords, self.shade.subsolar);
+        let phase = shared::DaylightPhase::from_elevation(elevation);
+        Some(vec![
+            zone_at(coords).name().to_string(),
+            drawing::format_coordinates(coords),
+            tr_args("{0} · sun {1}°", &[&tr(phase.label()), &format!("{:+.0}", elevation)]),
+        ])
+    }
+}
+
+fn save_config(model: &Model) {
+    let config = Config {
+        selected_zone_id: model.selected_zone.name().to_string(),
+        favorites: model.favorites.own_ids(),
+        own_favorites: model.favorites.uses_own(),
+        show_grid: model.show_grid,
+        show_labels: model.show_labels,
+        theme: model.theme.clone(),
+    };
+    model.config_saver.request(&config);
+}
+
+/// Canvas area left of the sidebar, and the map inside it
+fn layout(window_rect: Rect) -> (Rect, Rect) {
+    let canvas_width = window_rect.w() - SIDEBAR_WIDTH;
+    let canvas_rect = Rect::from_x_y_w_h(
+        window_rect.left() + canvas_width / 2.0,
+        window_rect.y(),
+        canvas_width,
+        window_rect.h(),
+    );
+    (canvas_rect, map_rect(canvas_rect, MAP_MARGIN))
+}
+
+fn model(app: &App) -> Model {
+    app.set_exit_on_escape(false);
+
+    let window_id = app
+        .new_window()
+        .title(WINDOW_TITLE)
+        .size(1200, 700)
+        .min_size(800, 480)
+        .view(view)
+        .key_pressed(key_pressed)
+        .mouse_pressed(mouse_pressed)
+        .mouse_moved(mouse_moved)
+        .raw_event(raw_window_event)
+        .visible(false)
+        .build()
+        .unwrap();
+
+    let window = app.window(window_id).unwrap();
+    let egui = Egui::from_window(&window);
+    shared::install_fallback_fonts(egui.ctx());
+
+    // Load configuration, noting an unreadable file for the recovery prompt
+    let (config, config_recovery) = shared::load_config_or_recover(CLOCK_NAME);
+    let config: Config = config.unwrap_or_default();
+
+    let selected_zone: Tz = config
+        .selected_zone_id
+        .parse()
+        .unwrap_or_else(|_| DEFAULT_TZ.parse().unwrap());
+
+    // The shared favorites, or this clock's own
+    let favorites = Favorites::load(&config.favorites, config.own_favorites);
+
+    let themes = ThemeLibrary::load();
+    let theme = themes.activate(config.theme.as_deref());
+
+    let time_data = compute_time_data(selected_zone);
+    let shade = ShadeGrid::compute(shared::now_utc());
+    // Screen reader tree, attached before the window is first shown
+    let screen_reader = ScreenReader::attach::<NoFocusRegions>(
+        window.winit_window(),
+        "World Daylight Clock",
+        &time_data,
+        None,
+    );
+
+    // Reopen where the window was left, or where the command line asks
+    let mut window_placement = WindowPlacement::load(CLOCK_NAME);
+    window_placement.restore(window.winit_window());
+    window.set_visible(shared::snapshot_request().is_none());
+
+    let wake_proxy = app.create_proxy();
+    let mut model = Model {
+        selected_zone,
+        favorites,
+        time_data,
+        markers: Vec::new(),
+        terminator: terminator(shade.subsolar, TERMINATOR_SAMPLES),
+        shade,
+        show_grid: config.show_grid,
+        show_labels: config.show_labels,
+        quiet_hours: QuietHours::load(),
+        intensity: VisualIntensity::FULL,
+        window_title_clock: WindowTitleClock::load(),
+        clip_settings: ClipSettings::load(),
+        clip_recorder: ClipRecorder::new(CLOCK_NAME),
+        language: LanguageSetting::load(),
+        safe_palette: SafePaletteSetting::load(),
+        window_title: WindowTitle::new(WINDOW_TITLE),
+        window_placement,
+        follow_system_zone: FollowSystemZone::load(),
+        system_zone: SystemZoneWatcher::spawn(),
+        time_reveal: TimeReveal::load(),
+        frame_pacer: FramePacer::spawn(move || wake_proxy.wakeup().is_ok()),
+        config_saver: ConfigSaver::new(CLOCK_NAME),
+        config_recovery,
+        themes,
+        theme,
+        mouse_pos: pt2(0.0, 0.0),
+        help_panel_open: false,
+        picker_state: PickerState::default(),
+        command_palette: CommandPalette::default(),
+        keymap: Keymap::load(CLOCK_NAME, commands()),
+        toasts: Vec::new(),
+        tz_error: false,
+        last_valid_zone: selected_zone,
+        ticker: Ticker::spawn(TickGranularity::Second),
+        snapshot: SnapshotProgress::default(),
+        screen_reader,
+        egui,
+    };
+    model.rebuild_markers();
+    model
+}
+
+/// Drive a `--snapshot` run: capture once the frame has settled, then quit
+fn advance_snapshot(app: &App, progress: &mut SnapshotProgress) {
+    let Some(request) = shared::snapshot_request() else {
+        return;
+    };
+    match progress.advance() {
+        SnapshotStep::Settle => {}
+        SnapshotStep::Capture => app.main_window().capture_frame(&request.path),
+        SnapshotStep::Finish => {
+            if app.main_window().await_capture_frame_jobs().is_err() {
+                eprintln!("Timed out writing snapshot to {}", request.path.display());
+                std::process::exit(1);
+            }
+            println!("Saved snapshot to {}", request.path.display());
+            app.quit();
+        }
+    }
+}
+
+/// Capture frames for a clip being recorded, and report the clip once saved
+fn advance_clip(app: &App, model: &mut Model) {
+    match model.clip_recorder.advance(Instant::now()) {
+        ClipStep::Idle => {}
+        ClipStep::Capture(path) => app.main_window().capture_frame(path),
+        ClipStep::Finish => {
+            let written = app.main_window().await_capture_frame_jobs().is_ok();
+            model.clip_recorder.encode(written);
+        }
+    }
+    if let Some(message) = model.clip_recorder.poll() {
+        model.show_toast(message);
+    }
+}
+
+/// Let the event loop wait between second boundaries while the clock is idle
+fn pace_frames(app: &App, model: &mut Model, now: Instant) {
+    let animating = model.is_animating();
+    let loop_mode = if model.frame_pacer.pace(animating, now) {
+        LoopMode::Wait
+    } else {
+        LoopMode::RefreshSync
+    };
+    app.set_loop_mode(loop_mode);
+}
+
+/// Act on the config recovery prompt: restart on the backup, or carry on
+/// with the defaults
+fn recover_config(app: &App, model: &mut Model, choice: RecoveryChoice) {
+    let Some(recovery) = model.config_recovery.take() else {
+        return;
+    };
+    if choice == RecoveryChoice::Restore {
+        match recovery.restore(&model.config_saver) {
+            Ok(()) => app.quit(),
+            Err(message) => model.show_toast(message),
+        }
+    }
+}
+
+fn update(app: &App, model: &mut Model, update: Update) {
+    advance_snapshot(app, &mut model.snapshot);
+    advance_clip(app, model);
+
+    if model.ticker.poll().is_some() {
+        model.refresh();
+    }
+
+    model.prune_toasts();
+
+    // Begin egui frame
+    model.egui.set_elapsed_time(update.since_start);
+    let ctx = model.egui.begin_frame();
+
+    let ui_result = ui::draw_sidebar(
+        &ctx,
+        &mut model.picker_state,
+        model.selected_zone,
+        &model.favorites,
+        &model.time_data,
+        &model.markers,
+        model.show_grid,
+        model.show_labels,
+        &model.quiet_hours,
+        model.window_title_clock,
+        model.clip_settings,
+        model.language,
+        model.safe_palette,
+        model.follow_system_zone,
+        &model.time_reveal.setting,
+        model.time_reveal.status(),
+        model.favorites.uses_own(),
+        &model.keymap,
+        &model.themes,
+        model.theme.as_deref(),
+    );
+
+    // Draw the time card while the reveal shortcut flashes the window
+    model.time_reveal.show(&ctx, &model.time_data);
+
+    // Offer the backup if the saved config couldn't be read
+    let recovery_choice = model.config_recovery.as_ref().and_then(|r| r.show(&ctx));
+
+    // Draw command palette (if open)
+    let palette_choice = model.command_palette.show(&ctx, model.keymap.registry());
+
+    drop(ctx);
+    if let Some(choice) = recovery_choice {
+        recover_config(app, model, choice);
+    }
+
+    // Apply UI results
+    if let Some(tz) = ui_result.set_timezone {
+        model.set_timezone(tz);
+    }
+    if let Some(tz) = ui_result.toggle_favorite {
+        model.toggle_favorite(tz);
+    }
+    if ui_result.toggle_grid {
+        model.show_grid = !model.show_grid;
+        save_config(model);
+    }
+    if ui_result.toggle_labels {
+        model.show_labels = !model.show_labels;
+        save_config(model);
+    }
+    if let Some(quiet_hours) = ui_result.set_quiet_hours {
+        model.quiet_hours = quiet_hours;
+        model.intensity = model.quiet_hours.intensity(&model.time_data);
+        report_save("quiet hours", model.quiet_hours.save(), |message| model.show_toast(message));
+    }
+    if let Some(setting) = ui_result.set_window_title {
+        model.window_title_clock = setting;
+        report_save(
+            "window title setting",
+            model.window_title_clock.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if let Some(settings) = ui_result.set_clip_settings {
+        model.clip_settings = settings;
+        report_save(
+            "recording settings",
+            model.clip_settings.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if let Some(setting) = ui_result.set_language {
+        model.language = setting;
+        report_save("language setting", model.language.save(), |message| model.show_toast(message));
+    }
+    if let Some(setting) = ui_result.set_safe_palette {
+        model.safe_palette = setting;
+        report_save(
+            "palette setting",
+            model.safe_palette.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if let Some(setting) = ui_result.set_reveal_hotkey {
+        model.time_reveal.setting = setting;
+        model.time_reveal.apply();
+    }
+    if let Some(setting) = ui_result.set_follow_system_zone {
+        model.follow_system_zone = setting;
+        report_save(
+            "system zone setting",
+            model.follow_system_zone.save(),
+            |message| model.show_toast(message),
+        );
+    }
+    if let Some(own) = ui_result.set_own_favorites {
+        model.favorites.set_uses_own(own);
+        model.rebuild_markers();
+        save_config(model);
+    }
+    if let Some(edit) = ui_result.keymap_edit {
+        model.keymap.apply(edit);
+    }
+    if ui_result.open_help {
+        model.help_panel_open = true;
+    }
+    if let Some(theme) = ui_result.set_theme {
+        model.theme = model.themes.activate(theme.as_deref());
+        save_config(model);
+    }
+
+    // Run the command chosen in the palette as if its keys were pressed
+    if let Some(chord) = palette_choice {
+        handle_key(model, chord.key, chord.mods);
+    }
+
+    // Keep the screen reader's time and DST status current
+    model.screen_reader.update::<NoFocusRegions>(&model.time_data, None);
+
+    // Pick up favorites starred in another clock
+    if model.favorites.poll() {
+        model.rebuild_markers();
+    }
+
+    // Follow the computer's time zone when it changes
+    if let Some(change) = model.system_zone.poll() {
+        let switch = model.follow_system_zone.enabled && change.current != model.selected_zone;
+        if switch {
+            model.set_timezone(change.current);
+        }
+        model.show_toast(change.message(switch));
+    }
+
+    // Show the current time in the taskbar/dock title, and any clip recording
+    let badge = model.clip_recorder.indicator(Instant::now());
+    model.window_title.set_badge(badge);
+    model.window_title.update(
+        app.main_window().winit_window(),
+        &model.window_title_clock,
+        model.selected_zone,
+        shared::now_utc(),
+    );
+
+    // Show or end the reveal shortcut's flash
+    model.time_reveal.update(app.main_window().winit_window(), Instant::now());
+
+    // Remember where the window was moved or resized to
+    model.window_placement.track(app.main_window().winit_window(), Instant::now());
+
+    // Write config changes once they have settled
+    model.config_saver.poll(Instant::now());
+
+    // Idle between second boundaries once nothing is moving
+    pace_frames(app, model, Instant::now());
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    let window_rect = app.window_rect();
+    let (canvas_rect, map) = layout(window_rect);
+
+    draw.background().color(drawing::colors::background());
+
+    // Map layers: base, graticule, night, terminator, markers
+    drawing::draw_base_map(&draw, map);
+    if model.show_grid {
+        drawing::draw_graticule(&draw, map);
+    }
+    drawing::draw_night(&draw, &model.shade, map);
+    drawing::draw_terminator(&draw, &model.terminator, model.shade.subsolar, map);
+    drawing::draw_markers(&draw, &model.markers, map, model.show_labels);
+
+    let utc_time = shared::now_utc().format("%H:%M:%S").to_string();
+    drawing::draw_header(
+        &draw,
+        canvas_rect,
+        map,
+        &utc_time,
+        model.shade.subsolar,
+        model.keymap.registry(),
+    );
+
+    if !model.help_panel_open && !model.picker_state.is_open {
+        if let Some(lines) = model.hover_lines(map) {
+            drawing::draw_hover_tooltip(&draw, model.mouse_pos, &lines);
+        }
+    }
+
+    // Dim the map during quiet hours
+    if model.intensity.is_quiet() {
+        draw.rect()
+            .xy(window_rect.xy())
+            .wh(window_rect.wh())
+            .color(srgba(0.0, 0.0, 0.0, model.intensity.dim_alpha()));
+    }
+
+    if model.help_panel_open {
+        drawing::draw_help_panel(&draw, canvas_rect, model.keymap.registry());
+    }
+
+    if model.tz_error {
+        drawing::draw_error_banner(&draw, window_rect);
+    }
+
+    for toast in &model.toasts {
+        drawing::draw_toast(&draw, &toast.message, toast.alpha(), canvas_rect);
+    }
+
+    // Render to frame
+    draw.to_frame(app, &frame).unwrap();
+
+    // Render egui on top
+    model.egui.draw_to_frame(&frame).unwrap();
+}
+
+/// Every action in the command palette, with the keys that perform it
+fn commands() -> CommandRegistry {
+    CommandRegistry::new()
+        .register("Open timezone picker", KeyChord::new(Key::Z))
+        .register("Toggle favorite", KeyChord::new(Key::F))
+        .register("Toggle graticule", KeyChord::new(Key::G))
+        .register("Toggle marker labels", KeyChord::new(Key::L))
+        .register("Toggle help", KeyChord::shift(Key::Slash))
+        .register("Record clip", KeyChord::ctrl_shift(Key::R))
+}
+
+fn key_pressed(app: &App, model: &mut Model, key: Key) {
+    let mods = app.keys.mods;
+
+    // While a shortcut is being rebound, the next chord becomes its binding
+    if model.keymap.capture(key, mods) {
+        return;
+    }
+
+    // Ctrl+K opens the command palette from anywhere; while open it takes
+    // every other key
+    if KeyChord::is_palette_toggle(key, mods) {
+        model.command_palette.toggle();
+        return;
+    }
+    if model.command_palette.is_open {
+        return;
+    }
+
+    // Remapped chords reach the handler as their action's default chord
+    if let Some(chord) = model.keymap.translate(key, mods) {
+        handle_key(model, chord.key, chord.mods);
+    }
+}
+
+fn handle_key(model: &mut Model, key: Key, mods: ModifiersState) {
+    match key {
+        // Ctrl+Shift+R - start or stop recording a clip
+        _ if shared::is_clip_toggle(key, mods) => {
+            let message = model.clip_recorder.toggle(model.clip_settings, Instant::now());
+            model.show_toast(message);
+        }
+
+        // Z - open timezone picker
+        Key::Z if !model.picker_state.is_open && !model.help_panel_open => {
+            model.picker_state.open();
+        }
+
+        // F - toggle favorite for the selected zone
+        Key::F if !model.picker_state.is_open && !model.help_panel_open => {
+            let tz = model.selected_zone;
+            model.toggle_favorite(tz);
+        }
+
+        // G - toggle graticule
+        Key::G if !model.picker_state.is_open && !model.help_panel_open => {
+            model.show_grid = !model.show_grid;
+            save_config(model);
+        }
+
+        // L - toggle labels on every marker
+        Key::L if !model.picker_state.is_open && !model.help_panel_open => {
+            model.show_labels = !model.show_labels;
+            save_config(model);
+        }
+
+        // ? (Shift + /) - toggle help panel
+        Key::Slash if mods.shift() && !model.picker_state.is_open => {
+            model.help_panel_open = !model.help_panel_open;
+        }
+
+        // Escape - close panels
+        Key::Escape => {
+            if model.help_panel_open {
+                model.help_panel_open = false;
+            } else if model.picker_state.is_open {
+                model.picker_state.close();
+            }
+        }
+
+        _ => {}
+    }
+}
+
+fn mouse_pressed(app: &App, model: &mut Model, button: MouseButton) {
+    if button != MouseButton::Left || model.picker_state.is_open {
+        return;
+    }
+    if model.help_panel_open {
+        model.help_panel_open = false;
+        return;
+    }
+    let (_, map) = layout(app.window_rect());
+    let pos = model.mouse_pos;
+    model.select_at(pos, map);
+}
+
+fn mouse_moved(_app: &App, model: &mut Model, pos: Point2) {
+    model.mouse_pos = pos;
+}
+
+fn raw_window_event(app: &App, model: &mut Model, event: &nannou::winit::event::WindowEvent) {
+    // Any window event brings an idle clock back to full frame rate
+    model.frame_pacer.note_input(Instant::now());
+
+    model.egui.handle_raw_event(event);
+    model.screen_reader.process_event(app.main_window().winit_window(), event);
+
+    if let nannou::winit::event::WindowEvent::Focused(true) = event {
+        // Resync on focus
+        model.refresh();
+    }
+}
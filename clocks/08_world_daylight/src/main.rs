@@ -0,0 +1,3 @@
+fn main() {
+    world_daylight::run();
+}
@@ -0,0 +1,208 @@
+//! Map module - equirectangular projection, day/night shading and picking
+//!
+//! The whole world is laid out on a 2:1 rectangle, longitude along x and
+//! latitude along y. Shading comes from the sun's elevation at each point,
+//! computed from the subsolar point, so civil, nautical and astronomical
+//! twilight fade smoothly into night.
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use nannou::prelude::*;
+use shared::{nearest_city, subsolar_point, Coordinates, CIVIL_TWILIGHT_ALTITUDE};
+
+/// Sun altitude at the end of astronomical twilight
+const ASTRONOMICAL_TWILIGHT_ALTITUDE: f64 = -18.0;
+
+/// Clicks farther than this from every known city pick a nautical zone
+pub const MAX_PICK_DISTANCE_KM: f64 = 1200.0;
+
+/// Largest 2:1 rectangle centred in `area`, inset by `margin`
+pub fn map_rect(area: Rect, margin: f32) -> Rect {
+    let w = (area.w() - margin * 2.0).max(1.0);
+    let h = (area.h() - margin * 2.0).max(1.0);
+    let (w, h) = if w > h * 2.0 { (h * 2.0, h) } else { (w, w / 2.0) };
+    Rect::from_x_y_w_h(area.x(), area.y(), w, h)
+}
+
+/// Screen position of a coordinate on the map
+pub fn project(coords: Coordinates, rect: Rect) -> Point2 {
+    pt2(
+        rect.left() + ((coords.longitude + 180.0) / 360.0) as f32 * rect.w(),
+        rect.bottom() + ((coords.latitude + 90.0) / 180.0) as f32 * rect.h(),
+    )
+}
+
+/// Coordinate under a screen position, if it is on the map
+pub fn unproject(point: Point2, rect: Rect) -> Option<Coordinates> {
+    if !rect.contains(point) {
+        return None;
+    }
+    let longitude = ((point.x - rect.left()) / rect.w()) as f64 * 360.0 - 180.0;
+    let latitude = ((point.y - rect.bottom()) / rect.h()) as f64 * 180.0 - 90.0;
+    Some(Coordinates::new(latitude, longitude))
+}
+
+/// Sun elevation in degrees at `coords`, given where the sun is overhead
+pub fn elevation_from_subsolar(coords: Coordinates, subsolar: Coordinates) -> f64 {
+    let lat = coords.latitude.to_radians();
+    let dec = subsolar.latitude.to_radians();
+    let h = (coords.longitude - subsolar.longitude).to_radians();
+    let sin_elevation = lat.sin() * dec.sin() + lat.cos() * dec.cos() * h.cos();
+    sin_elevation.clamp(-1.0, 1.0).asin().to_degrees()
+}
+
+/// How dark a place is: 0 in daylight, 0.5 at the end of civil twilight,
+/// 1 once astronomical twilight is over
+pub fn night_shade(elevation_degrees: f64) -> f32 {
+    if elevation_degrees >= 0.0 {
+        0.0
+    } else if elevation_degrees >= CIVIL_TWILIGHT_ALTITUDE {
+        (0.5 * elevation_degrees / CIVIL_TWILIGHT_ALTITUDE) as f32
+    } else if elevation_degrees > ASTRONOMICAL_TWILIGHT_ALTITUDE {
+        let t = (elevation_degrees - CIVIL_TWILIGHT_ALTITUDE)
+            / (ASTRONOMICAL_TWILIGHT_ALTITUDE - CIVIL_TWILIGHT_ALTITUDE);
+        (0.5 + 0.5 * t) as f32
+    } else {
+        1.0
+    }
+}
+
+/// Night shade sampled on a regular latitude/longitude grid
+#[derive(Debug, Clone)]
+pub struct ShadeGrid {
+    /// Grid points along a parallel, from 180°W to 180°E inclusive
+    pub columns: usize,
+    /// Grid points along a meridian, from 90°N to 90°S inclusive
+    pub rows: usize,
+    /// Where the sun is overhead
+    pub subsolar: Coordinates,
+    values: Vec<f32>,
+}
+
+impl ShadeGrid {
+    /// Spacing between grid points
+    pub const STEP_DEGREES: f64 = 2.0;
+
+    pub fn compute(instant: DateTime<Utc>) -> Self {
+        let columns = (360.0 / Self::STEP_DEGREES) as usize + 1;
+        let rows = (180.0 / Self::STEP_DEGREES) as usize + 1;
+        let subsolar = subsolar_point(instant);
+
+        let mut grid = Self {
+            columns,
+            rows,
+            subsolar,
+            values: Vec::with_capacity(columns * rows),
+        };
+        for row in 0..rows {
+            for column in 0..columns {
+                let elevation = elevation_from_subsolar(grid.coordinates(column, row), subsolar);
+                grid.values.push(night_shade(elevation));
+            }
+        }
+        grid
+    }
+
+    pub fn coordinates(&self, column: usize, row: usize) -> Coordinates {
+        Coordinates::new(
+            90.0 - row as f64 * Self::STEP_DEGREES,
+            -180.0 + column as f64 * Self::STEP_DEGREES,
+        )
+    }
+
+    pub fn shade(&self, column: usize, row: usize) -> f32 {
+        self.values[row * self.columns + column]
+    }
+}
+
+/// The terminator (sun on the horizon) sampled west to east
+pub fn terminator(subsolar: Coordinates, samples: usize) -> Vec<Coordinates> {
+    // Avoid dividing by zero at the equinox, when the terminator runs along
+    // a pair of meridians
+    let mut dec = subsolar.latitude.to_radians();
+    if dec.abs() < 1e-6 {
+        dec = 1e-6;
+    }
+
+    (0..samples.max(2))
+        .map(|i| {
+            let longitude = -180.0 + 360.0 * i as f64 / (samples.max(2) - 1) as f64;
+            let h = (longitude - subsolar.longitude).to_radians();
+            let latitude = (-h.cos() / dec.tan()).atan().to_degrees();
+            Coordinates::new(latitude, longitude)
+        })
+        .collect()
+}
+
+/// Fixed-offset `Etc/GMT` zone for the 15° band a longitude falls in
+///
+/// Note the POSIX sign convention: `Etc/GMT+5` is five hours *behind* UTC.
+pub fn nautical_zone(longitude: f64) -> Tz {
+    let hours = (longitude / 15.0).round().clamp(-12.0, 12.0) as i32;
+    let name = if hours == 0 {
+        "Etc/GMT".to_string()
+    } else {
+        format!("Etc/GMT{:+}", -hours)
+    };
+    name.parse().unwrap_or(Tz::UTC)
+}
+
+/// Zone to select for a click on the map: the nearest city's zone, or the
+/// nautical zone when the click is far from any city
+pub fn zone_at(coords: Coordinates) -> Tz {
+    nearest_city(coords, MAX_PICK_DISTANCE_KM)
+        .and_then(|city| city.timezone())
+        .unwrap_or_else(|| nautical_zone(coords.longitude))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_projection_round_trip() {
+        let rect = map_rect(Rect::from_x_y_w_h(0.0, 0.0, 1000.0, 800.0), 20.0);
+        assert_eq!((rect.w(), rect.h()), (960.0, 480.0));
+
+        let tokyo = Coordinates::new(35.68, 139.69);
+        let back = unproject(project(tokyo, rect), rect).unwrap();
+        assert!((back.latitude - tokyo.latitude).abs() < 1e-3);
+        assert!((back.longitude - tokyo.longitude).abs() < 1e-3);
+
+        assert_eq!(project(Coordinates::new(90.0, -180.0), rect), pt2(rect.left(), rect.top()));
+        assert!(unproject(pt2(rect.right() + 1.0, 0.0), rect).is_none());
+    }
+
+    #[test]
+    fn test_shading_at_solstice() {
+        let instant = Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap();
+        let grid = ShadeGrid::compute(instant);
+        assert_eq!((grid.columns, grid.rows), (181, 91));
+
+        // Midnight sun at the north pole, polar night at the south
+        assert_eq!(grid.shade(0, 0), 0.0);
+        assert_eq!(grid.shade(0, grid.rows - 1), 1.0);
+        // Noon over Greenwich, night over the date line
+        assert_eq!(grid.shade(90, 34), 0.0);
+        assert_eq!(grid.shade(0, 45), 1.0);
+
+        assert_eq!(night_shade(-6.0), 0.5);
+        assert!(night_shade(-3.0) > 0.0 && night_shade(-3.0) < 0.5);
+
+        for point in terminator(grid.subsolar, 37) {
+            assert!(elevation_from_subsolar(point, grid.subsolar).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_zone_picking() {
+        assert_eq!(zone_at(Coordinates::new(35.0, 139.0)).name(), "Asia/Tokyo");
+        assert_eq!(zone_at(Coordinates::new(51.0, 0.5)).name(), "Europe/London");
+
+        // Open ocean falls back to the nautical zone
+        assert_eq!(zone_at(Coordinates::new(0.0, -150.0)).name(), "Etc/GMT+10");
+        assert_eq!(nautical_zone(3.0).name(), "Etc/GMT");
+        assert_eq!(nautical_zone(180.0).name(), "Etc/GMT-12");
+    }
+}
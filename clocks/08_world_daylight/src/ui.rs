@@ -0,0 +1,538 @@
+//! UI module for the World Daylight clock
+//!
+//! Provides the sidebar with the selected zone's time and sun, the favorites
+//! list, display toggles and the timezone picker, using egui.
+
+use chrono_tz::Tz;
+use nannou_egui::egui;
+use shared::{
+    active_palette, clip_recording_settings, favorites_settings, keymap_settings, language_settings,
+    order_by_region, quiet_hours_settings, region_change, region_heading, reveal_hotkey_settings,
+    safe_palette_settings, search_timezones, system_timezone, system_zone_settings, tr, tr_args,
+    window_title_settings, zone_display_name, ClipSettings, DaylightPhase, FollowSystemZone, Keymap,
+    KeymapEdit, LanguageSetting, QuietHours, RevealHotkey, Rgb, SafePaletteSetting, ThemeLibrary,
+    TimeData, WindowTitleClock,
+};
+
+use crate::ZoneMarker;
+
+/// State for the timezone picker
+#[derive(Default)]
+pub struct PickerState {
+    /// Whether the picker is currently open
+    pub is_open: bool,
+    /// Current search query
+    pub search_query: String,
+    /// Cached search results
+    pub search_results: Vec<Tz>,
+    /// Whether the search field should be focused
+    pub should_focus_search: bool,
+}
+
+impl PickerState {
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.search_query.clear();
+        self.search_results = order_by_region(search_timezones(""));
+        self.should_focus_search = true;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.search_query.clear();
+        self.search_results.clear();
+    }
+
+    pub fn update_search(&mut self) {
+        self.search_results = order_by_region(search_timezones(&self.search_query));
+    }
+}
+
+/// Result of sidebar interactions
+#[derive(Default)]
+pub struct SidebarResult {
+    /// Set a new timezone
+    pub set_timezone: Option<Tz>,
+    /// Toggle favorite status
+    pub toggle_favorite: Option<Tz>,
+    /// Toggle the graticule
+    pub toggle_grid: bool,
+    /// Toggle labels on every marker
+    pub toggle_labels: bool,
+    /// Replace the shared quiet-hours schedule
+    pub set_quiet_hours: Option<QuietHours>,
+    /// Replace the shared window title clock setting
+    pub set_window_title: Option<WindowTitleClock>,
+    /// Replace the shared clip recording settings
+    pub set_clip_settings: Option<ClipSettings>,
+    /// Replace the shared language
+    pub set_language: Option<LanguageSetting>,
+    /// Replace the shared safe palette
+    pub set_safe_palette: Option<SafePaletteSetting>,
+    /// Replace the shared follow-system-zone setting
+    pub set_follow_system_zone: Option<FollowSystemZone>,
+    /// Replace the shared reveal shortcut
+    pub set_reveal_hotkey: Option<RevealHotkey>,
+    /// Keep this clock's own favorites (`true`) or follow the shared list
+    pub set_own_favorites: Option<bool>,
+    /// Shortcut rebound, reset or waiting for its new chord
+    pub keymap_edit: Option<KeymapEdit>,
+    /// Open help panel
+    pub open_help: bool,
+    /// Switch theme (`Some(None)` for the clock's own colors)
+    pub set_theme: Option<Option<String>>,
+}
+
+const ACCENT: egui::Color32 = egui::Color32::from_rgb(90, 220, 255);
+const MUTED: egui::Color32 = egui::Color32::from_rgb(140, 150, 165);
+
+/// Draw the sidebar panel
+#[allow(clippy::too_many_arguments)]
+pub fn draw_sidebar(
+    ctx: &egui::Context,
+    picker_state: &mut PickerState,
+    selected_zone: Tz,
+    favorites: &[Tz],
+    time_data: &TimeData,
+    markers: &[ZoneMarker],
+    show_grid: bool,
+    show_labels: bool,
+    quiet_hours: &QuietHours,
+    window_title: WindowTitleClock,
+    clip_settings: ClipSettings,
+    language: LanguageSetting,
+    safe_palette: SafePaletteSetting,
+    follow_system_zone: FollowSystemZone,
+    reveal_hotkey: &RevealHotkey,
+    reveal_status: Option<&str>,
+    own_favorites: bool,
+    keymap: &Keymap,
+    themes: &ThemeLibrary,
+    theme: Option<&str>,
+) -> SidebarResult {
+    let mut result = SidebarResult::default();
+
+    let mut style = (*ctx.style()).clone();
+    style.visuals = egui::Visuals::dark();
+    style.visuals.window_fill = egui::Color32::from_rgb(14, 18, 28);
+    style.visuals.panel_fill = egui::Color32::from_rgb(14, 18, 28);
+    style.visuals.widgets.noninteractive.bg_fill = egui::Color32::from_rgb(22, 28, 40);
+    style.visuals.widgets.inactive.bg_fill = egui::Color32::from_rgb(28, 36, 52);
+    style.visuals.widgets.hovered.bg_fill = egui::Color32::from_rgb(40, 52, 72);
+    style.visuals.widgets.active.bg_fill = egui::Color32::from_rgb(52, 68, 92);
+    style.visuals.override_text_color = Some(egui::Color32::from_rgb(205, 210, 220));
+    theme_visuals(&mut style.visuals);
+    ctx.set_style(style);
+
+    egui::SidePanel::right("sidebar")
+        .resizable(false)
+        .min_width(260.0)
+        .show(ctx, |ui| {
+            ui.add_space(10.0);
+            ui.heading(egui::RichText::new(tr("World Daylight")).color(ACCENT).size(18.0));
+            ui.add_space(10.0);
+
+            // Selected zone
+            ui.group(|ui| {
+                section_label(ui, tr("▸ SELECTED ZONE"));
+
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(format_zone_name(selected_zone)).size(13.0));
+                    ui.label(
+                        egui::RichText::new(format!("({})", time_data.tz_abbrev))
+                            .size(11.0)
+                            .color(MUTED),
+                    );
+                });
+                ui.label(
+                    egui::RichText::new(time_data.format_time())
+                        .size(22.0)
+                        .color(egui::Color32::from_rgb(230, 232, 240)),
+                );
+                let dst = if time_data.is_dst {
+                    format!("  ·  {}", tr("DST"))
+                } else {
+                    String::new()
+                };
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{}  ·  {}{}",
+                        time_data.format_date(),
+                        time_data.format_utc_offset(),
+                        dst
+                    ))
+                    .size(11.0)
+                    .color(MUTED),
+                );
+
+                ui.add_space(5.0);
+                ui.horizontal(|ui| {
+                    if ui.button(egui::RichText::new(tr("Change (Z)")).size(12.0)).clicked() {
+                        picker_state.open();
+                    }
+                    if ui.button(egui::RichText::new(tr("System")).size(12.0)).clicked() {
+                        if let Some(sys_tz) = system_timezone() {
+                            result.set_timezone = Some(sys_tz);
+                        }
+                    }
+                    let star = if favorites.contains(&selected_zone) { "★ (F)" } else { "☆ (F)" };
+                    if ui.button(egui::RichText::new(star).size(12.0)).clicked() {
+                        result.toggle_favorite = Some(selected_zone);
+                    }
+                });
+            });
+
+            ui.add_space(10.0);
+
+            // Sun at the selected zone's reference city
+            ui.group(|ui| {
+                section_label(ui, tr("▸ SUN"));
+
+                let solar = &time_data.solar;
+                let phase = solar.phase();
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(format!("{} {}", phase_glyph(phase), tr(phase.label())))
+                            .color(phase_color(phase)),
+                    );
+                    ui.label(
+                        egui::RichText::new(format!("{:+.1}°", solar.elevation_degrees))
+                            .size(11.0)
+                            .color(MUTED),
+                    );
+                });
+
+                let events = &solar.events;
+                let tz = selected_zone;
+                let local = |instant: Option<chrono::DateTime<chrono::Utc>>| {
+                    instant
+                        .map(|t| t.with_timezone(&tz).format("%-I:%M %p").to_string())
+                        .unwrap_or_else(|| "—".to_string())
+                };
+                if events.is_polar_day() {
+                    ui.label(egui::RichText::new(tr("Midnight sun: no sunset today")).size(11.0));
+                } else if events.is_polar_night() {
+                    ui.label(egui::RichText::new(tr("Polar night: no sunrise today")).size(11.0));
+                } else {
+                    ui.label(
+                        egui::RichText::new(tr_args(
+                            "Sunrise {0}  ·  Sunset {1}",
+                            &[&local(events.sunrise), &local(events.sunset)],
+                        ))
+                        .size(11.0),
+                    );
+                }
+                let day_length = events.day_length();
+                ui.label(
+                    egui::RichText::new(tr_args(
+                        "Day length {0}h {1}m",
+                        &[
+                            &day_length.num_hours(),
+                            &format!("{:02}", day_length.num_minutes() % 60),
+                        ],
+                    ))
+                    .size(11.0)
+                    .color(MUTED),
+                );
+            });
+
+            ui.add_space(10.0);
+
+            // Favorites with their local time and daylight
+            ui.group(|ui| {
+                section_label(ui, tr("▸ FAVORITES"));
+
+                if favorites.is_empty() {
+                    ui.label(
+                        egui::RichText::new(tr("Press F to add the selected zone"))
+                            .size(11.0)
+                            .color(MUTED),
+                    );
+                }
+                for marker in markers.iter().filter(|m| m.is_favorite) {
+                    let phase = marker.time_data.solar.phase();
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new(phase_glyph(phase)).color(phase_color(phase)));
+                        let text = egui::RichText::new(format!(
+                            "{}  {}",
+                            marker.city(),
+                            marker.time_data.format_time()
+                        ))
+                        .size(12.0);
+                        if ui.selectable_label(marker.is_selected, text).clicked() {
+                            result.set_timezone = Some(marker.tz);
+                        }
+                    });
+                }
+            });
+
+            ui.add_space(10.0);
+
+            // Display toggles
+            ui.group(|ui| {
+                section_label(ui, tr("▸ DISPLAY"));
+
+                let mut grid = show_grid;
+                if ui.checkbox(&mut grid, tr("Graticule (G)")).changed() {
+                    result.toggle_grid = true;
+                }
+                let mut labels = show_labels;
+                if ui.checkbox(&mut labels, tr("Label every marker (L)")).changed() {
+                    result.toggle_labels = true;
+                }
+                let mut edited = quiet_hours.clone();
+                if quiet_hours_settings(ui, &mut edited) {
+                    result.set_quiet_hours = Some(edited);
+                }
+                let mut edited = window_title;
+                if window_title_settings(ui, &mut edited) {
+                    result.set_window_title = Some(edited);
+                }
+                let mut edited = clip_settings;
+                if clip_recording_settings(ui, &mut edited) {
+                    result.set_clip_settings = Some(edited);
+                }
+                let mut edited = language;
+                if language_settings(ui, &mut edited) {
+                    result.set_language = Some(edited);
+                }
+                let mut edited = follow_system_zone;
+                if system_zone_settings(ui, &mut edited) {
+                    result.set_follow_system_zone = Some(edited);
+                }
+                let mut edited = reveal_hotkey.clone();
+                if reveal_hotkey_settings(ui, &mut edited, reveal_status) {
+                    result.set_reveal_hotkey = Some(edited);
+                }
+                let mut edited = own_favorites;
+                if favorites_settings(ui, &mut edited) {
+                    result.set_own_favorites = Some(edited);
+                }
+                result.keymap_edit = keymap_settings(ui, keymap);
+                result.set_theme = theme_selector(ui, themes, theme);
+                let mut edited = safe_palette;
+                if safe_palette_settings(ui, &mut edited) {
+                    result.set_safe_palette = Some(edited);
+                }
+
+                ui.add_space(5.0);
+                if ui
+                    .button(egui::RichText::new(tr("[?] Reading the map")).size(12.0))
+                    .clicked()
+                {
+                    result.open_help = true;
+                }
+            });
+
+            ui.add_space(10.0);
+
+            // Keyboard shortcuts
+            ui.group(|ui| {
+                section_label(ui, tr("▸ SHORTCUTS"));
+
+                let shortcuts = [
+                    ("Click", "Select zone on map"),
+                    ("Z", "Open timezone picker"),
+                    ("F", "Toggle favorite"),
+                    ("G", "Toggle graticule"),
+                    ("L", "Toggle labels"),
+                    ("?", "Help panel"),
+                    ("Esc", "Close panels"),
+                ];
+                for (key, desc) in shortcuts {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new(format!("{:>6}", key))
+                                .size(10.0)
+                                .color(ACCENT)
+                                .monospace(),
+                        );
+                        ui.label(egui::RichText::new(tr(desc)).size(10.0).color(MUTED));
+                    });
+                }
+            });
+        });
+
+    // Draw picker overlay if open
+    if picker_state.is_open {
+        let picker_result = draw_timezone_picker(ctx, picker_state, favorites);
+
+        if let Some(tz) = picker_result.select_zone {
+            result.set_timezone = Some(tz);
+            picker_state.close();
+        }
+        if let Some(tz) = picker_result.toggle_favorite {
+            result.toggle_favorite = Some(tz);
+        }
+        if picker_result.close {
+            picker_state.close();
+        }
+    }
+
+    result
+}
+
+fn section_label(ui: &mut egui::Ui, text: &str) {
+    ui.label(egui::RichText::new(text).size(13.0).color(ACCENT));
+    ui.add_space(5.0);
+}
+
+fn phase_glyph(phase: DaylightPhase) -> &'static str {
+    match phase {
+        DaylightPhase::Day => "☀",
+        DaylightPhase::CivilTwilight => "◐",
+        DaylightPhase::Night => "☾",
+    }
+}
+
+fn phase_color(phase: DaylightPhase) -> egui::Color32 {
+    let c = crate::drawing::phase_color(phase);
+    egui::Color32::from_rgb(c.red, c.green, c.blue)
+}
+
+/// Result of timezone picker interactions
+#[derive(Default)]
+struct PickerResult {
+    select_zone: Option<Tz>,
+    toggle_favorite: Option<Tz>,
+    close: bool,
+}
+
+/// Draw the timezone picker overlay
+fn draw_timezone_picker(
+    ctx: &egui::Context,
+    picker_state: &mut PickerState,
+    favorites: &[Tz],
+) -> PickerResult {
+    let mut result = PickerResult::default();
+
+    egui::Window::new(tr("Select Timezone"))
+        .id(egui::Id::new("select_timezone"))
+        .collapsible(false)
+        .resizable(true)
+        .default_width(380.0)
+        .default_height(450.0)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(tr("Search:")).color(ACCENT));
+                let search_response = ui.text_edit_singleline(&mut picker_state.search_query);
+
+                if picker_state.should_focus_search {
+                    search_response.request_focus();
+                    picker_state.should_focus_search = false;
+                }
+
+                if search_response.changed() {
+                    picker_state.update_search();
+                }
+            });
+
+            ui.separator();
+
+            ui.label(
+                egui::RichText::new(tr_args("{0} results", &[&picker_state.search_results.len()]))
+                    .size(11.0)
+                    .color(MUTED),
+            );
+
+            egui::ScrollArea::vertical()
+                .max_height(320.0)
+                .show(ui, |ui| {
+                    let mut previous = None;
+                    for &tz in picker_state.search_results.iter().take(100) {
+                        if let Some(region) = region_change(previous, tz) {
+                            region_heading(ui, region);
+                        }
+                        previous = Some(tz);
+                        let is_favorite = favorites.contains(&tz);
+                        ui.horizontal(|ui| {
+                            if ui
+                                .button(egui::RichText::new(zone_display_name(tz)).size(12.0))
+                                .clicked()
+                            {
+                                result.select_zone = Some(tz);
+                            }
+                            let fav_label = if is_favorite { "★" } else { "☆" };
+                            if ui.small_button(fav_label).clicked() {
+                                result.toggle_favorite = Some(tz);
+                            }
+                        });
+                    }
+                });
+
+            ui.separator();
+
+            if ui
+                .button(egui::RichText::new(tr("Close (Esc)")).size(12.0))
+                .clicked()
+            {
+                result.close = true;
+            }
+        });
+
+    result
+}
+
+/// Format timezone name for display
+pub fn format_zone_name(tz: Tz) -> String {
+    let name = tz.name();
+    // Extract city name from "Continent/City" format
+    if let Some(idx) = name.rfind('/') {
+        name[idx + 1..].replace('_', " ")
+    } else {
+        name.to_string()
+    }
+}
+
+/// Restyle egui's chrome for the active theme
+///
+/// Leaves `visuals` alone while the clock uses its own colors.
+pub fn theme_visuals(visuals: &mut egui::Visuals) {
+    let Some(palette) = active_palette() else {
+        return;
+    };
+    let color = |c: Rgb| egui::Color32::from_rgb(c.red, c.green, c.blue);
+    *visuals = if palette.is_light() {
+        egui::Visuals::light()
+    } else {
+        egui::Visuals::dark()
+    };
+    visuals.panel_fill = color(palette.background);
+    visuals.window_fill = color(palette.background);
+    visuals.override_text_color = Some(color(palette.primary_text));
+    visuals.hyperlink_color = color(palette.accent);
+    visuals.warn_fg_color = color(palette.warning);
+}
+
+/// Draw the theme switcher
+///
+/// Returns the newly chosen theme, where `Some(None)` means the clock's own
+/// colors.
+pub fn theme_selector(
+    ui: &mut egui::Ui,
+    themes: &ThemeLibrary,
+    selected: Option<&str>,
+) -> Option<Option<String>> {
+    let mut choice = None;
+    egui::ComboBox::from_label(tr("Theme"))
+        .selected_text(selected.unwrap_or(tr("Clock colors")))
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(selected.is_none(), tr("Clock colors")).clicked() {
+                choice = Some(None);
+            }
+            for theme in &themes.themes {
+                let is_selected = selected == Some(theme.name.as_str());
+                if ui.selectable_label(is_selected, &theme.name).clicked() {
+                    choice = Some(Some(theme.name.clone()));
+                }
+            }
+        });
+    if let Some(error) = &themes.error {
+        ui.label(
+            egui::RichText::new(error)
+                .size(10.0)
+                .color(egui::Color32::from_rgb(255, 100, 100)),
+        );
+    }
+    choice
+}
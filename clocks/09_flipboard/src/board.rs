@@ -0,0 +1,189 @@
+//! Board module - what each row says and where its cells sit
+//!
+//! A row is one zone, laid out like a departure: destination, local time,
+//! UTC offset and a remark. Remarks compare the row's date with the home
+//! zone's ("+1 DAY") or flag daylight saving. Kept free of drawing so the
+//! text and the grid can be tested.
+
+use nannou::prelude::*;
+use shared::{DstChange, TimeData};
+
+use crate::flap::{DIGIT_DRUM, TEXT_DRUM};
+
+/// One column of the board
+#[derive(Debug, Clone, Copy)]
+pub struct Column {
+    pub title: &'static str,
+    /// Width in cells
+    pub width: usize,
+    pub drum: &'static [char],
+}
+
+pub const COLUMNS: [Column; 4] = [
+    Column { title: "DESTINATION", width: 13, drum: TEXT_DRUM },
+    Column { title: "TIME", width: 8, drum: DIGIT_DRUM },
+    Column { title: "UTC", width: 6, drum: DIGIT_DRUM },
+    Column { title: "REMARKS", width: 8, drum: TEXT_DRUM },
+];
+
+/// Rows beyond this don't fit a readable board
+pub const MAX_ROWS: usize = 10;
+
+/// Gap between cells, as a fraction of the cell width
+const CELL_GAP: f32 = 0.12;
+/// Gap between columns, in cell widths
+const COLUMN_GAP: f32 = 0.8;
+/// Cell height for a cell width
+const CELL_ASPECT: f32 = 1.45;
+/// Row pitch, in cell heights
+const ROW_PITCH: f32 = 1.3;
+/// Space for the column titles, in cell heights
+const TITLE_HEIGHT: f32 = 0.9;
+const MAX_CELL_WIDTH: f32 = 44.0;
+
+/// Text for each column of a zone's row
+pub fn row_texts(
+    city: &str,
+    time_data: &TimeData,
+    home: &TimeData,
+    show_seconds: bool,
+) -> [String; 4] {
+    let time = if show_seconds {
+        format!("{:02}:{:02}:{:02}", time_data.hour24, time_data.minute, time_data.second)
+    } else {
+        format!("{:02}:{:02}", time_data.hour24, time_data.minute)
+    };
+    [
+        city.to_uppercase(),
+        time,
+        format_offset(time_data.utc_offset_minutes),
+        remark(time_data, home),
+    ]
+}
+
+/// "+05:30"
+pub fn format_offset(offset_minutes: i32) -> String {
+    let sign = if offset_minutes >= 0 { '+' } else { '-' };
+    let minutes = offset_minutes.abs();
+    format!("{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+}
+
+/// Day difference from home, else the DST state
+fn remark(time_data: &TimeData, home: &TimeData) -> String {
+    let days =
+        (time_data.local_datetime.date_naive() - home.local_datetime.date_naive()).num_days();
+    if days != 0 {
+        return format!("{:+} DAY", days);
+    }
+    match time_data.dst_change {
+        DstChange::Upcoming { .. } => "DST SOON".to_string(),
+        _ if time_data.is_dst => "DST".to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Cell size and placement for a board in `area`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoardLayout {
+    pub cell_w: f32,
+    pub cell_h: f32,
+    /// Left edge of the first cell
+    pub left: f32,
+    /// Top edge of the column titles
+    pub top: f32,
+}
+
+impl BoardLayout {
+    /// Largest cells that fit `rows` rows in `area`, centered across it
+    pub fn fit(area: Rect, rows: usize) -> Self {
+        let width_units = COLUMNS.iter().map(|c| c.width).sum::<usize>() as f32 * (1.0 + CELL_GAP)
+            + COLUMN_GAP * (COLUMNS.len() - 1) as f32;
+        let height_units = (TITLE_HEIGHT + ROW_PITCH * rows.max(1) as f32) * CELL_ASPECT;
+
+        let cell_w = (area.w() / width_units)
+            .min(area.h() / height_units)
+            .min(MAX_CELL_WIDTH);
+        let board_w = cell_w * width_units;
+        Self {
+            cell_w,
+            cell_h: cell_w * CELL_ASPECT,
+            left: area.x() - board_w / 2.0,
+            top: area.top(),
+        }
+    }
+
+    /// Left edge of a column
+    pub fn column_left(&self, column: usize) -> f32 {
+        let before: usize = COLUMNS[..column].iter().map(|c| c.width).sum();
+        self.left + self.cell_w * (before as f32 * (1.0 + CELL_GAP) + COLUMN_GAP * column as f32)
+    }
+
+    /// Center of the column titles' line
+    pub fn title_y(&self) -> f32 {
+        self.top - self.cell_h * TITLE_HEIGHT / 2.0
+    }
+
+    /// Vertical center of a row
+    pub fn row_y(&self, row: usize) -> f32 {
+        self.top - self.cell_h * (TITLE_HEIGHT + ROW_PITCH * row as f32 + 0.5)
+    }
+
+    /// Rectangle of one cell
+    pub fn cell_rect(&self, row: usize, column: usize, index: usize) -> Rect {
+        let x = self.column_left(column) + self.cell_w * (index as f32 * (1.0 + CELL_GAP) + 0.5);
+        Rect::from_x_y_w_h(x, self.row_y(row), self.cell_w, self.cell_h)
+    }
+
+    /// Row under `pos`, if any of the first `rows`
+    pub fn row_at(&self, pos: Point2, rows: usize) -> Option<usize> {
+        let right = self.column_left(COLUMNS.len() - 1)
+            + self.cell_w * COLUMNS[COLUMNS.len() - 1].width as f32 * (1.0 + CELL_GAP);
+        if pos.x < self.left || pos.x > right {
+            return None;
+        }
+        (0..rows).find(|&row| (pos.y - self.row_y(row)).abs() <= self.cell_h / 2.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Tz;
+    use shared::compute_time_data_at;
+
+    #[test]
+    fn test_row_texts_compare_with_home() {
+        // 23:30 in New York is already the next day in Tokyo
+        let instant = Utc.with_ymd_and_hms(2024, 1, 16, 4, 30, 5).unwrap();
+        let new_york: Tz = "America/New_York".parse().unwrap();
+        let tokyo: Tz = "Asia/Tokyo".parse().unwrap();
+        let home = compute_time_data_at(new_york, instant);
+        let away = compute_time_data_at(tokyo, instant);
+
+        assert_eq!(
+            row_texts("Tokyo", &away, &home, true),
+            ["TOKYO", "13:30:05", "+09:00", "+1 DAY"].map(String::from)
+        );
+        assert_eq!(row_texts("New York", &home, &home, false)[1..], ["23:30", "-05:00", ""]);
+        assert_eq!(format_offset(330), "+05:30");
+        assert_eq!(format_offset(-210), "-03:30");
+    }
+
+    #[test]
+    fn test_layout_fits_and_hits_rows() {
+        let area = Rect::from_x_y_w_h(0.0, 0.0, 900.0, 500.0);
+        let layout = BoardLayout::fit(area, 4);
+        assert!(layout.cell_w <= MAX_CELL_WIDTH);
+
+        let last = COLUMNS.len() - 1;
+        let right = layout.cell_rect(3, last, COLUMNS[last].width - 1).right();
+        // This area is width-limited, so the board spans it edge to edge
+        assert!(layout.left >= area.left() - 0.01 && right <= area.right() + 0.01);
+        assert!(layout.cell_rect(3, 0, 0).bottom() >= area.bottom());
+
+        assert_eq!(layout.row_at(pt2(0.0, layout.row_y(2)), 4), Some(2));
+        assert_eq!(layout.row_at(pt2(0.0, layout.title_y()), 4), None);
+        assert_eq!(layout.row_at(pt2(area.left() - 1.0, layout.row_y(0)), 4), None);
+    }
+}
@@ -0,0 +1,372 @@
+//! Drawing module - Render functions for the Flipboard clock
+//!
+//! Draws the board frame, the column titles and every split-flap cell. A
+//! turning cell is drawn in layers: the next character's top half and the
+//! current character's bottom half behind, then the falling flap, squashed
+//! toward the hinge, over whichever half it is crossing.
+
+use nannou::prelude::*;
+use shared::{tr, tr_args, CommandRegistry};
+
+use crate::board::{BoardLayout, COLUMNS};
+use crate::flap::FlapCell;
+use crate::BoardRow;
+
+/// Color palette for the station board aesthetic
+pub mod colors {
+    use nannou::prelude::*;
+    use shared::{Rgb, ThemeRole};
+
+    /// Station wall behind the board
+    pub const BACKGROUND: Srgb<u8> = Srgb {
+        red: 12,
+        green: 12,
+        blue: 14,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Board housing
+    pub const BOARD: Srgb<u8> = Srgb {
+        red: 24,
+        green: 24,
+        blue: 28,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Flap face
+    pub const FLAP: Srgb<u8> = Srgb {
+        red: 38,
+        green: 38,
+        blue: 42,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Flap face while falling, catching less light
+    pub const FLAP_SHADOW: Srgb<u8> = Srgb {
+        red: 28,
+        green: 28,
+        blue: 32,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Characters printed on the flaps
+    pub const FLAP_TEXT: Srgb<u8> = Srgb {
+        red: 240,
+        green: 236,
+        blue: 220,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Gap between the two halves of a cell
+    pub const HINGE: Srgb<u8> = Srgb {
+        red: 8,
+        green: 8,
+        blue: 10,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Column titles, home marker and accents
+    pub const ACCENT: Srgb<u8> = Srgb {
+        red: 255,
+        green: 200,
+        blue: 40,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Primary text
+    pub const TEXT_PRIMARY: Srgb<u8> = Srgb {
+        red: 235,
+        green: 235,
+        blue: 240,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Secondary text
+    pub const TEXT_SECONDARY: Srgb<u8> = Srgb {
+        red: 140,
+        green: 140,
+        blue: 150,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Panel background
+    pub fn panel_bg() -> Srgba<u8> {
+        srgba(20, 20, 24, 235)
+    }
+
+    /// Look up one of this clock's role colors in the active theme
+    fn themed(role: ThemeRole, native: Srgb<u8>) -> Srgb<u8> {
+        let color = shared::themed(role, Rgb::new(native.red, native.green, native.blue));
+        Srgb::new(color.red, color.green, color.blue)
+    }
+
+    pub fn background() -> Srgb<u8> {
+        themed(ThemeRole::Background, BACKGROUND)
+    }
+
+    pub fn text_primary() -> Srgb<u8> {
+        themed(ThemeRole::PrimaryText, TEXT_PRIMARY)
+    }
+
+    pub fn text_secondary() -> Srgb<u8> {
+        themed(ThemeRole::SecondaryText, TEXT_SECONDARY)
+    }
+
+    pub fn accent() -> Srgb<u8> {
+        themed(ThemeRole::Accent, ACCENT)
+    }
+}
+
+/// Draw the header above the board: title and the home zone's date
+pub fn draw_header(
+    draw: &Draw,
+    canvas_rect: Rect,
+    home_city: &str,
+    date: &str,
+    keys: &CommandRegistry,
+) {
+    let y = canvas_rect.top() - 36.0;
+    draw.text(tr("WORLD DEPARTURES"))
+        .xy(pt2(canvas_rect.left() + 160.0, y))
+        .w(280.0)
+        .left_justify()
+        .font_size(20)
+        .color(colors::accent());
+    draw.text(&format!("{} · {}", home_city, date))
+        .xy(pt2(canvas_rect.right() - 180.0, y))
+        .w(320.0)
+        .right_justify()
+        .font_size(13)
+        .color(colors::text_secondary());
+
+    let hints = keys.hint_line(&[
+        ("Open timezone picker", "Zone"),
+        ("Toggle favorite", "Favorite"),
+        ("Toggle seconds", "Seconds"),
+        ("Toggle help", "Help"),
+    ]);
+    draw.text(&format!("{}  |  {}", tr("Click a row to make it home"), hints))
+        .xy(pt2(canvas_rect.x(), canvas_rect.bottom() + 24.0))
+        .w(canvas_rect.w())
+        .font_size(10)
+        .color(colors::text_secondary());
+}
+
+/// Draw the board housing, column titles and every row
+pub fn draw_board(draw: &Draw, layout: &BoardLayout, rows: &[BoardRow]) {
+    let last = COLUMNS.len() - 1;
+    let bottom_row = rows.len().max(1) - 1;
+    let bottom_right = layout.cell_rect(bottom_row, last, COLUMNS[last].width - 1).bottom_right();
+    let housing = Rect::from_corners(pt2(layout.left, layout.top), bottom_right)
+        .pad(-layout.cell_w * 0.6);
+    draw.rect()
+        .xy(housing.xy())
+        .wh(housing.wh())
+        .color(colors::BOARD);
+
+    let title_size = (layout.cell_h * 0.32).max(8.0) as u32;
+    for (column, spec) in COLUMNS.iter().enumerate() {
+        let left = layout.column_left(column);
+        draw.text(tr(spec.title))
+            .xy(pt2(left + 100.0, layout.title_y()))
+            .w(200.0)
+            .left_justify()
+            .font_size(title_size)
+            .color(colors::accent());
+    }
+
+    for (row_index, row) in rows.iter().enumerate() {
+        for (column, text) in row.columns.iter().enumerate() {
+            for (index, cell) in text.cells.iter().enumerate() {
+                draw_cell(draw, layout.cell_rect(row_index, column, index), cell);
+            }
+        }
+
+        // Home is always the first row
+        if row_index == 0 {
+            let y = layout.row_y(0);
+            let x = layout.left - layout.cell_w * 0.35;
+            draw.tri()
+                .points(
+                    pt2(x - layout.cell_w * 0.2, y + layout.cell_h * 0.2),
+                    pt2(x - layout.cell_w * 0.2, y - layout.cell_h * 0.2),
+                    pt2(x + layout.cell_w * 0.1, y),
+                )
+                .color(colors::accent());
+        }
+    }
+}
+
+/// Draw one split-flap cell, mid-turn if it hasn't settled
+fn draw_cell(draw: &Draw, rect: Rect, cell: &FlapCell) {
+    let font_size = (rect.h() * 0.62).max(6.0) as u32;
+
+    if cell.is_settled() {
+        draw_face(draw, rect, cell.shown, colors::FLAP, font_size);
+    } else {
+        let hinge = rect.y();
+        let top = Rect::from_corners(pt2(rect.left(), hinge), rect.top_right());
+        let bottom = Rect::from_corners(rect.bottom_left(), pt2(rect.right(), hinge));
+        let next = cell.next_flap();
+
+        // Behind the falling flap: the next character above the hinge, the
+        // current one still below it
+        draw_face(&draw.scissor(top), rect, next, colors::FLAP, font_size);
+        draw_face(&draw.scissor(bottom), rect, cell.shown, colors::FLAP, font_size);
+
+        // The falling flap: the current top half folding down to the hinge,
+        // then the next bottom half unfolding below it
+        let (half, face, squash) = if cell.progress < 0.5 {
+            (top, cell.shown, 1.0 - cell.progress * 2.0)
+        } else {
+            (bottom, next, cell.progress * 2.0 - 1.0)
+        };
+        if squash > 0.02 {
+            let flap = draw.scissor(half).x_y(rect.x(), hinge).scale_y(squash);
+            let local = Rect::from_w_h(rect.w(), rect.h());
+            draw_face(&flap, local, face, colors::FLAP_SHADOW, font_size);
+        }
+    }
+
+    draw.line()
+        .start(pt2(rect.left(), rect.y()))
+        .end(pt2(rect.right(), rect.y()))
+        .weight((rect.h() * 0.03).max(1.0))
+        .color(colors::HINGE);
+}
+
+/// Draw a full flap face with its character
+fn draw_face(draw: &Draw, rect: Rect, c: char, face: Srgb<u8>, font_size: u32) {
+    draw.rect().xy(rect.xy()).wh(rect.wh()).color(face);
+    if c != ' ' {
+        draw.text(&c.to_string())
+            .xy(rect.xy())
+            .w(rect.w() * 2.0)
+            .font_size(font_size)
+            .color(colors::FLAP_TEXT);
+    }
+}
+
+/// Draw the help panel
+pub fn draw_help_panel(draw: &Draw, canvas_rect: Rect, keys: &CommandRegistry) {
+    let panel_width = 460.0;
+    let panel_height = 340.0;
+    let center = canvas_rect.xy();
+
+    draw.rect()
+        .xy(center)
+        .w_h(panel_width, panel_height)
+        .color(colors::panel_bg());
+    draw.rect()
+        .xy(center)
+        .w_h(panel_width, panel_height)
+        .no_fill()
+        .stroke(colors::accent())
+        .stroke_weight(2.0);
+
+    draw.text(tr("Reading the Board"))
+        .xy(center + vec2(0.0, panel_height / 2.0 - 30.0))
+        .color(colors::text_primary())
+        .font_size(20)
+        .w(panel_width - 40.0);
+
+    let content = [
+        (true, "ROWS"),
+        (false, "  First row (marked): the home zone"),
+        (false, "  Below it: your favorite zones"),
+        (true, "COLUMNS"),
+        (false, "  Local time (24-hour) and offset from UTC"),
+        (true, "REMARKS"),
+        (false, "  +1 DAY / -1 DAY: a different date from home"),
+        (false, "  DST: daylight saving in effect"),
+        (false, "  DST SOON: clocks change within 24 hours"),
+        (true, "FLAPS"),
+        (false, "  Each cell turns through its drum in order"),
+    ];
+
+    let line_height = 20.0;
+    let start_y = panel_height / 2.0 - 70.0;
+    let left_edge = center.x - panel_width / 2.0 + 25.0;
+
+    for (i, (is_header, text)) in content.iter().enumerate() {
+        let y = center.y + start_y - i as f32 * line_height;
+        draw.text(tr(text))
+            .xy(pt2(left_edge + (panel_width - 50.0) / 2.0, y))
+            .color(if *is_header {
+                colors::accent()
+            } else {
+                colors::text_secondary()
+            })
+            .font_size(if *is_header { 13 } else { 12 })
+            .w(panel_width - 50.0)
+            .left_justify();
+    }
+
+    draw.text(&tr_args("Press {0} or Esc to close", &[&keys.key_label("Toggle help")]))
+        .xy(center + vec2(0.0, -panel_height / 2.0 + 20.0))
+        .color(colors::text_secondary())
+        .font_size(11)
+        .w(panel_width);
+}
+
+/// Draw a toast notification
+pub fn draw_toast(draw: &Draw, message: &str, alpha: f32, canvas_rect: Rect) {
+    let toast_width = 320.0;
+    let toast_height = 40.0;
+    let pos = pt2(canvas_rect.x(), canvas_rect.bottom() + 70.0);
+    let alpha_u8 = (alpha * 255.0) as u8;
+
+    draw.rect()
+        .xy(pos)
+        .w_h(toast_width, toast_height)
+        .color(srgba(20, 20, 24, alpha_u8));
+    draw.rect()
+        .xy(pos)
+        .w_h(toast_width, toast_height)
+        .no_fill()
+        .stroke(srgba(colors::accent().red, colors::accent().green, colors::accent().blue, alpha_u8))
+        .stroke_weight(1.0);
+    draw.text(message)
+        .xy(pos)
+        .color(srgba(
+            colors::text_primary().red,
+            colors::text_primary().green,
+            colors::text_primary().blue,
+            alpha_u8,
+        ))
+        .font_size(13)
+        .w(toast_width - 20.0);
+}
+
+/// Draw error banner for TZ data issues
+pub fn draw_error_banner(draw: &Draw, window_rect: Rect) {
+    let banner_height = 40.0;
+    let banner_y = window_rect.top() - banner_height / 2.0;
+
+    draw.rect()
+        .x_y(window_rect.x(), banner_y)
+        .w_h(window_rect.w(), banner_height)
+        .color(srgba(120u8, 40u8, 40u8, 220u8));
+    draw.text(tr("⚠ Timezone data may be missing or stale. Showing UTC as fallback."))
+        .x_y(window_rect.x(), banner_y)
+        .color(colors::text_primary())
+        .font_size(14)
+        .w(window_rect.w() - 40.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::{fixed_text_is_legible, Rgb};
+
+    fn rgb(color: Srgb<u8>) -> Rgb {
+        Rgb::new(color.red, color.green, color.blue)
+    }
+
+    #[test]
+    fn test_flap_text_is_legible() {
+        for face in [colors::FLAP, colors::FLAP_SHADOW] {
+            assert!(fixed_text_is_legible(rgb(colors::FLAP_TEXT), rgb(face)), "{:?}", face);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Flap module - the split-flap mechanism
+//!
+//! Each character cell is a drum of flaps. Changing a cell never jumps: the
+//! drum turns one flap at a time, in drum order, until the wanted character
+//! is showing. Cells on the digit drum only carry digits, so a time going
+//! from 9 to 0 takes a single flap rather than a trip through the alphabet.
+
+/// Flaps on a time or offset cell
+pub const DIGIT_DRUM: &[char] = &[' ', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+/// Flaps on a text cell
+pub const TEXT_DRUM: &[char] = &[
+    ' ', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
+    'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '0', '1', '2', '3', '4', '5', '6', '7', '8',
+    '9', '+', '-', ':', '.', '\'', '/',
+];
+
+/// One character cell
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlapCell {
+    drum: &'static [char],
+    /// Character on the flaps at rest
+    pub shown: char,
+    /// Character the drum is turning toward
+    pub target: char,
+    /// How far the current flap has fallen, 0..1
+    pub progress: f32,
+}
+
+impl FlapCell {
+    /// A blank cell on `drum`
+    pub fn blank(drum: &'static [char]) -> Self {
+        Self {
+            drum,
+            shown: ' ',
+            target: ' ',
+            progress: 0.0,
+        }
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.shown == self.target
+    }
+
+    /// Start turning toward `c`
+    pub fn set_target(&mut self, c: char) {
+        self.target = c;
+        if self.is_settled() {
+            self.progress = 0.0;
+        }
+    }
+
+    /// Show `c` at once, for reduced motion
+    pub fn snap_to(&mut self, c: char) {
+        self.shown = c;
+        self.target = c;
+        self.progress = 0.0;
+    }
+
+    /// The flap behind the one showing
+    ///
+    /// A target that isn't on the drum (an accented letter, say) is printed
+    /// on the very next flap so the cell still settles.
+    pub fn next_flap(&self) -> char {
+        if !self.drum.contains(&self.target) {
+            return self.target;
+        }
+        let index = self.drum.iter().position(|&c| c == self.shown);
+        match index {
+            Some(index) => self.drum[(index + 1) % self.drum.len()],
+            None => self.drum[0],
+        }
+    }
+
+    /// Let `dt_secs` pass with each flap taking `flap_secs`
+    pub fn advance(&mut self, dt_secs: f32, flap_secs: f32) {
+        if self.is_settled() {
+            return;
+        }
+        self.progress += dt_secs / flap_secs.max(f32::EPSILON);
+        while self.progress >= 1.0 && !self.is_settled() {
+            self.shown = self.next_flap();
+            self.progress -= 1.0;
+        }
+        if self.is_settled() {
+            self.progress = 0.0;
+        }
+    }
+}
+
+/// A fixed-width run of cells sharing a drum
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlapText {
+    pub cells: Vec<FlapCell>,
+}
+
+impl FlapText {
+    pub fn blank(width: usize, drum: &'static [char]) -> Self {
+        Self {
+            cells: vec![FlapCell::blank(drum); width],
+        }
+    }
+
+    /// Turn toward `text`, cut or padded with spaces to the width
+    pub fn set_text(&mut self, text: &str, animate: bool) {
+        let mut chars = text.chars().chain(std::iter::repeat(' '));
+        for cell in &mut self.cells {
+            let c = chars.next().unwrap_or(' ');
+            if animate {
+                cell.set_target(c);
+            } else {
+                cell.snap_to(c);
+            }
+        }
+    }
+
+    pub fn advance(&mut self, dt_secs: f32, flap_secs: f32) {
+        for cell in &mut self.cells {
+            cell.advance(dt_secs, flap_secs);
+        }
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.cells.iter().all(FlapCell::is_settled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digits_turn_in_drum_order() {
+        let mut cell = FlapCell::blank(DIGIT_DRUM);
+        cell.snap_to('9');
+        cell.set_target('0');
+        // 9 → blank → 0
+        assert_eq!(cell.next_flap(), ' ');
+        cell.advance(0.1, 0.1);
+        assert_eq!(cell.shown, ' ');
+        assert!(!cell.is_settled());
+        cell.advance(0.1, 0.1);
+        assert_eq!(cell.shown, '0');
+        assert!(cell.is_settled());
+        assert_eq!(cell.progress, 0.0);
+    }
+
+    #[test]
+    fn test_long_step_settles_without_overshooting() {
+        let mut cell = FlapCell::blank(TEXT_DRUM);
+        cell.set_target('C');
+        cell.advance(10.0, 0.05);
+        assert_eq!(cell.shown, 'C');
+        assert!(cell.is_settled());
+
+        // Characters off the drum arrive on the next flap
+        cell.set_target('É');
+        assert_eq!(cell.next_flap(), 'É');
+        cell.advance(0.05, 0.05);
+        assert_eq!(cell.shown, 'É');
+    }
+
+    #[test]
+    fn test_text_pads_and_snaps() {
+        let mut text = FlapText::blank(4, TEXT_DRUM);
+        text.set_text("OSLO BERGEN", true);
+        assert!(!text.is_settled());
+        let targets: String = text.cells.iter().map(|c| c.target).collect();
+        assert_eq!(targets, "OSLO");
+
+        text.set_text("RIO", false);
+        assert!(text.is_settled());
+        let shown: String = text.cells.iter().map(|c| c.shown).collect();
+        assert_eq!(shown, "RIO ");
+    }
+}
@@ -0,0 +1,86 @@
+//! Dial module - hand positions on the ten-hour dial
+//!
+//! The hour hand turns once a day past ten numerals, the minute hand once a
+//! decimal hour (2h 24m) past 100 marks, and the second hand once a decimal
+//! minute (86.4 s). The second hand ticks on each decimal second, easing into
+//! place over the first part of the tick; with reduced motion every hand
+//! jumps straight to the current decimal second instead.
+
+use std::f32::consts::TAU;
+
+use shared::{DecimalTime, DECIMAL_HOURS, DECIMAL_MINUTES, DECIMAL_SECONDS_PER_DAY};
+
+/// Part of each decimal second the second hand spends moving
+const TICK_FRACTION: f64 = 0.2;
+
+/// Hand angles in radians, clockwise from the top of the dial
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hands {
+    pub hour: f32,
+    pub minute: f32,
+    pub second: f32,
+}
+
+/// Where the hands point at `day_fraction` of the day
+pub fn hands(day_fraction: f64, reduced_motion: bool) -> Hands {
+    let decimal_seconds = day_fraction.clamp(0.0, 1.0) * DECIMAL_SECONDS_PER_DAY as f64;
+    let whole = decimal_seconds.floor();
+    let seconds = if reduced_motion {
+        whole
+    } else {
+        whole + ease_out(((decimal_seconds - whole) / TICK_FRACTION).min(1.0))
+    };
+
+    let per_hour = (DECIMAL_MINUTES * DECIMAL_MINUTES) as f64;
+    let turns = |seconds: f64, per_turn: f64| ((seconds / per_turn).fract() * TAU as f64) as f32;
+    Hands {
+        hour: turns(seconds, per_hour * DECIMAL_HOURS as f64),
+        minute: turns(seconds, per_hour),
+        second: turns(seconds, DECIMAL_MINUTES as f64),
+    }
+}
+
+/// Angles pointing exactly at a decimal time, for the conversion preview
+pub fn hands_at(time: DecimalTime) -> Hands {
+    hands(time.day_fraction(), true)
+}
+
+/// Cubic ease-out: fast start, gentle landing
+fn ease_out(t: f64) -> f64 {
+    1.0 - (1.0 - t).powi(3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn test_landmark_positions() {
+        // Noon is 5:00:00: hour hand straight down, the others at the top
+        let noon = hands(0.5, true);
+        assert!(close(noon.hour, TAU / 2.0));
+        assert!(close(noon.minute, 0.0));
+        assert!(close(noon.second, 0.0));
+
+        let evening = hands_at(DecimalTime::parse("7:50:25").unwrap());
+        assert!(close(evening.minute, TAU / 2.0 + TAU * 0.0025));
+        assert!(close(evening.second, TAU / 4.0));
+    }
+
+    #[test]
+    fn test_second_hand_ticks() {
+        let second = 1.0 / DECIMAL_SECONDS_PER_DAY as f64;
+        let step = TAU / DECIMAL_MINUTES as f32;
+        // Halfway through a decimal second the tick has already landed...
+        assert!(close(hands(second * 10.5, false).second, step * 11.0));
+        // ...but reduced motion stays on the current second
+        assert!(close(hands(second * 10.5, true).second, step * 10.0));
+        // Early in the tick the hand is on its way
+        let moving = hands(second * 10.05, false).second;
+        assert!(moving > step * 10.0 && moving < step * 11.0);
+    }
+}
@@ -0,0 +1,328 @@
+//! Drawing module - Render functions for the Decimal Time clock
+//!
+//! Draws the ten-hour dial in the spirit of the enamel faces made in 1794:
+//! ten numerals with 10 at the top, a hundred minute marks, three hands,
+//! and the decimal and conventional readings below.
+
+use nannou::prelude::*;
+use shared::{tr, tr_args, CommandRegistry};
+
+use crate::dial::Hands;
+
+/// Color palette for the enamel dial aesthetic
+pub mod colors {
+    use nannou::prelude::*;
+    use shared::{Rgb, ThemeRole};
+
+    /// Room behind the dial
+    pub const BACKGROUND: Srgb<u8> = Srgb {
+        red: 20,
+        green: 17,
+        blue: 15,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Enamel face
+    pub const FACE: Srgb<u8> = Srgb {
+        red: 238,
+        green: 230,
+        blue: 212,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Numerals, marks and hour and minute hands
+    pub const INK: Srgb<u8> = Srgb {
+        red: 36,
+        green: 30,
+        blue: 28,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Bezel, second hand and accents
+    pub const ACCENT: Srgb<u8> = Srgb {
+        red: 212,
+        green: 175,
+        blue: 55,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Second hand
+    pub const SECOND_HAND: Srgb<u8> = Srgb {
+        red: 178,
+        green: 34,
+        blue: 34,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Primary text
+    pub const TEXT_PRIMARY: Srgb<u8> = Srgb {
+        red: 240,
+        green: 232,
+        blue: 215,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Secondary text
+    pub const TEXT_SECONDARY: Srgb<u8> = Srgb {
+        red: 150,
+        green: 140,
+        blue: 125,
+        standard: std::marker::PhantomData,
+    };
+
+    /// Panel background
+    pub fn panel_bg() -> Srgba<u8> {
+        srgba(26, 22, 20, 235)
+    }
+
+    /// Conversion preview hand
+    pub fn preview() -> Srgba<u8> {
+        srgba(SECOND_HAND.red, SECOND_HAND.green, SECOND_HAND.blue, 90)
+    }
+
+    /// Look up one of this clock's role colors in the active theme
+    fn themed(role: ThemeRole, native: Srgb<u8>) -> Srgb<u8> {
+        let color = shared::themed(role, Rgb::new(native.red, native.green, native.blue));
+        Srgb::new(color.red, color.green, color.blue)
+    }
+
+    pub fn background() -> Srgb<u8> {
+        themed(ThemeRole::Background, BACKGROUND)
+    }
+
+    pub fn text_primary() -> Srgb<u8> {
+        themed(ThemeRole::PrimaryText, TEXT_PRIMARY)
+    }
+
+    pub fn text_secondary() -> Srgb<u8> {
+        themed(ThemeRole::SecondaryText, TEXT_SECONDARY)
+    }
+
+    pub fn accent() -> Srgb<u8> {
+        themed(ThemeRole::Accent, ACCENT)
+    }
+}
+
+/// Point at `angle` (clockwise from the top) and `distance` from `center`
+fn polar(center: Point2, angle: f32, distance: f32) -> Point2 {
+    center + vec2(angle.sin(), angle.cos()) * distance
+}
+
+/// Draw the face: bezel, minute marks and the ten numerals
+pub fn draw_dial(draw: &Draw, center: Point2, radius: f32) {
+    draw.ellipse()
+        .xy(center)
+        .radius(radius * 1.04)
+        .color(colors::accent());
+    draw.ellipse().xy(center).radius(radius).color(colors::FACE);
+
+    for mark in 0..100 {
+        let angle = mark as f32 / 100.0 * TAU;
+        let (length, weight) = if mark % 10 == 0 {
+            (radius * 0.09, 3.0)
+        } else if mark % 5 == 0 {
+            (radius * 0.06, 1.5)
+        } else {
+            (radius * 0.035, 1.0)
+        };
+        draw.line()
+            .start(polar(center, angle, radius * 0.96 - length))
+            .end(polar(center, angle, radius * 0.96))
+            .weight(weight)
+            .color(colors::INK);
+    }
+
+    let font_size = (radius * 0.14).max(10.0) as u32;
+    for hour in 0..10 {
+        let label = if hour == 0 { 10 } else { hour };
+        let angle = hour as f32 / 10.0 * TAU;
+        draw.text(&label.to_string())
+            .xy(polar(center, angle, radius * 0.74))
+            .w(radius * 0.4)
+            .font_size(font_size)
+            .color(colors::INK);
+    }
+}
+
+/// Draw the three hands and the center cap
+pub fn draw_hands(draw: &Draw, center: Point2, radius: f32, hands: &Hands) {
+    draw.line()
+        .start(polar(center, hands.hour + PI, radius * 0.08))
+        .end(polar(center, hands.hour, radius * 0.5))
+        .weight((radius * 0.035).max(3.0))
+        .caps_round()
+        .color(colors::INK);
+    draw.line()
+        .start(polar(center, hands.minute + PI, radius * 0.1))
+        .end(polar(center, hands.minute, radius * 0.8))
+        .weight((radius * 0.022).max(2.0))
+        .caps_round()
+        .color(colors::INK);
+    draw.line()
+        .start(polar(center, hands.second + PI, radius * 0.15))
+        .end(polar(center, hands.second, radius * 0.88))
+        .weight(1.5)
+        .color(colors::SECOND_HAND);
+    draw.ellipse()
+        .xy(center)
+        .radius((radius * 0.03).max(3.0))
+        .color(colors::SECOND_HAND);
+}
+
+/// Draw a faint hour hand at the conversion panel's decimal time
+pub fn draw_preview_hand(draw: &Draw, center: Point2, radius: f32, angle: f32) {
+    draw.line()
+        .start(center)
+        .end(polar(center, angle, radius * 0.6))
+        .weight((radius * 0.035).max(3.0))
+        .caps_round()
+        .color(colors::preview());
+}
+
+/// Draw the decimal reading, conventional time if shown, and the zone line
+pub fn draw_readout(
+    draw: &Draw,
+    canvas_rect: Rect,
+    decimal: &str,
+    conventional: Option<&str>,
+    zone_line: &str,
+    keys: &CommandRegistry,
+) {
+    let y = canvas_rect.bottom() + 130.0;
+    draw.text(decimal)
+        .x_y(canvas_rect.x(), y)
+        .w(canvas_rect.w())
+        .font_size(40)
+        .color(colors::accent());
+    if let Some(conventional) = conventional {
+        draw.text(&tr_args("{0}  standard", &[&conventional]))
+            .x_y(canvas_rect.x(), y - 40.0)
+            .w(canvas_rect.w())
+            .font_size(16)
+            .color(colors::text_primary());
+    }
+    draw.text(zone_line)
+        .x_y(canvas_rect.x(), y - 66.0)
+        .w(canvas_rect.w())
+        .font_size(12)
+        .color(colors::text_secondary());
+
+    let hints = keys.hint_line(&[
+        ("Open timezone picker", "Zone"),
+        ("Toggle favorite", "Favorite"),
+        ("Toggle conventional time", "Conventional time"),
+        ("Toggle help", "Help"),
+    ]);
+    draw.text(&hints)
+        .xy(pt2(canvas_rect.x(), canvas_rect.bottom() + 24.0))
+        .w(canvas_rect.w())
+        .font_size(10)
+        .color(colors::text_secondary());
+}
+
+/// Draw the help panel
+pub fn draw_help_panel(draw: &Draw, canvas_rect: Rect, keys: &CommandRegistry) {
+    let panel_width = 460.0;
+    let panel_height = 340.0;
+    let center = canvas_rect.xy();
+
+    draw.rect()
+        .xy(center)
+        .w_h(panel_width, panel_height)
+        .color(colors::panel_bg());
+    draw.rect()
+        .xy(center)
+        .w_h(panel_width, panel_height)
+        .no_fill()
+        .stroke(colors::accent())
+        .stroke_weight(2.0);
+
+    draw.text(tr("Reading Decimal Time"))
+        .xy(center + vec2(0.0, panel_height / 2.0 - 30.0))
+        .color(colors::text_primary())
+        .font_size(20)
+        .w(panel_width - 40.0);
+
+    let content = [
+        (true, "THE DAY"),
+        (false, "  10 hours of 100 minutes of 100 seconds"),
+        (false, "  A decimal second is 0.864 standard seconds"),
+        (true, "THE DIAL"),
+        (false, "  Short hand: hours, once around a day"),
+        (false, "  Long hand: minutes, once around a decimal hour"),
+        (false, "  Thin hand: seconds, once around 86.4 seconds"),
+        (true, "LANDMARKS"),
+        (false, "  5:00:00 is noon, 7:50:00 is 18:00"),
+        (false, "  The digits read as a fraction of the day"),
+        (false, "  Shown in local wall-clock time"),
+    ];
+
+    let line_height = 20.0;
+    let start_y = panel_height / 2.0 - 70.0;
+    let left_edge = center.x - panel_width / 2.0 + 25.0;
+
+    for (i, (is_header, text)) in content.iter().enumerate() {
+        let y = center.y + start_y - i as f32 * line_height;
+        draw.text(tr(text))
+            .xy(pt2(left_edge + (panel_width - 50.0) / 2.0, y))
+            .color(if *is_header {
+                colors::accent()
+            } else {
+                colors::text_secondary()
+            })
+            .font_size(if *is_header { 13 } else { 12 })
+            .w(panel_width - 50.0)
+            .left_justify();
+    }
+
+    draw.text(&tr_args("Press {0} or Esc to close", &[&keys.key_label("Toggle help")]))
+        .xy(center + vec2(0.0, -panel_height / 2.0 + 20.0))
+        .color(colors::text_secondary())
+        .font_size(11)
+        .w(panel_width);
+}
+
+/// Draw a toast notification
+pub fn draw_toast(draw: &Draw, message: &str, alpha: f32, canvas_rect: Rect) {
+    let toast_width = 320.0;
+    let toast_height = 40.0;
+    let pos = pt2(canvas_rect.x(), canvas_rect.top() - 40.0);
+    let alpha_u8 = (alpha * 255.0) as u8;
+
+    draw.rect()
+        .xy(pos)
+        .w_h(toast_width, toast_height)
+        .color(srgba(20, 20, 24, alpha_u8));
+    draw.rect()
+        .xy(pos)
+        .w_h(toast_width, toast_height)
+        .no_fill()
+        .stroke(srgba(colors::accent().red, colors::accent().green, colors::accent().blue, alpha_u8))
+        .stroke_weight(1.0);
+    draw.text(message)
+        .xy(pos)
+        .color(srgba(
+            colors::text_primary().red,
+            colors::text_primary().green,
+            colors::text_primary().blue,
+            alpha_u8,
+        ))
+        .font_size(13)
+        .w(toast_width - 20.0);
+}
+
+/// Draw error banner for TZ data issues
+pub fn draw_error_banner(draw: &Draw, window_rect: Rect) {
+    let banner_height = 40.0;
+    let banner_y = window_rect.top() - banner_height / 2.0;
+
+    draw.rect()
+        .x_y(window_rect.x(), banner_y)
+        .w_h(window_rect.w(), banner_height)
+        .color(srgba(120u8, 40u8, 40u8, 220u8));
+    draw.text(tr("⚠ Timezone data may be missing or stale. Showing UTC as fallback."))
+        .x_y(window_rect.x(), banner_y)
+        .color(colors::text_primary())
+        .font_size(14)
+        .w(window_rect.w() - 40.0);
+}
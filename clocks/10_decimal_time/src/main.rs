@@ -0,0 +1,3 @@
+fn main() {
+    decimal_time::run();
+}
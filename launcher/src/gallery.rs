@@ -0,0 +1,134 @@
+//! Gallery module - card layout and keyboard selection
+//!
+//! Lays the clocks out as a grid of cards and moves the selection around it.
+//! Kept free of drawing so the grid math can be tested.
+
+use nannou::prelude::*;
+
+/// Height reserved above the grid for the title
+pub const HEADER_HEIGHT: f32 = 90.0;
+
+/// Height reserved below the grid for the hint line
+pub const FOOTER_HEIGHT: f32 = 50.0;
+
+const CARD_GAP: f32 = 20.0;
+const PADDING: f32 = 30.0;
+
+/// Number of grid columns for a window width
+pub fn column_count(width: f32) -> usize {
+    if width >= 1000.0 {
+        4
+    } else if width >= 640.0 {
+        3
+    } else {
+        2
+    }
+}
+
+/// Card rectangles in reading order (left to right, top to bottom)
+pub fn card_rects(window_rect: Rect, count: usize) -> Vec<Rect> {
+    if count == 0 {
+        return Vec::new();
+    }
+
+    let columns = column_count(window_rect.w()).min(count);
+    let rows = count.div_ceil(columns);
+
+    let area_w = window_rect.w() - PADDING * 2.0;
+    let area_h = window_rect.h() - HEADER_HEIGHT - FOOTER_HEIGHT;
+    let card_w = (area_w - CARD_GAP * (columns - 1) as f32) / columns as f32;
+    let card_h = ((area_h - CARD_GAP * (rows - 1) as f32) / rows as f32).min(card_w * 1.1);
+
+    // Center the grid vertically in the area between header and footer
+    let grid_h = card_h * rows as f32 + CARD_GAP * (rows - 1) as f32;
+    let area_top = window_rect.top() - HEADER_HEIGHT;
+    let grid_top = area_top - (area_h - grid_h).max(0.0) / 2.0;
+
+    (0..count)
+        .map(|i| {
+            let column = i % columns;
+            let row = i / columns;
+            // Center a partial last row
+            let in_row = if row == rows - 1 {
+                count - row * columns
+            } else {
+                columns
+            };
+            let row_w = card_w * in_row as f32 + CARD_GAP * (in_row - 1) as f32;
+            let left = window_rect.x() - row_w / 2.0 + column as f32 * (card_w + CARD_GAP);
+            let top = grid_top - row as f32 * (card_h + CARD_GAP);
+            Rect::from_x_y_w_h(left + card_w / 2.0, top - card_h / 2.0, card_w, card_h)
+        })
+        .collect()
+}
+
+/// Index of the card under a point, if any
+pub fn card_at(rects: &[Rect], point: Point2) -> Option<usize> {
+    rects.iter().position(|rect| rect.contains(point))
+}
+
+/// Direction for keyboard navigation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// Move the selection within a grid of `count` cards, clamping at the edges
+pub fn move_selection(selected: usize, count: usize, columns: usize, direction: Move) -> usize {
+    if count == 0 {
+        return 0;
+    }
+
+    let selected = selected.min(count - 1);
+    match direction {
+        Move::Left => selected.saturating_sub(1),
+        Move::Right => (selected + 1).min(count - 1),
+        Move::Up => selected.checked_sub(columns).unwrap_or(selected),
+        Move::Down => {
+            let below = selected + columns;
+            if below < count {
+                below
+            } else {
+                selected
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_card_rects_fit_window() {
+        let window = Rect::from_w_h(1200.0, 700.0);
+        let rects = card_rects(window, 7);
+
+        assert_eq!(rects.len(), 7);
+        for rect in &rects {
+            assert!(rect.left() >= window.left() && rect.right() <= window.right());
+            assert!(rect.top() <= window.top() - HEADER_HEIGHT + 0.01);
+            assert!(rect.bottom() >= window.bottom() + FOOTER_HEIGHT - 0.01);
+        }
+
+        // Four across, then a centered row of three
+        assert!((rects[0].y() - rects[3].y()).abs() < 0.01);
+        assert!(rects[4].y() < rects[0].y());
+        assert!((rects[5].x() - window.x()).abs() < 0.01);
+        assert_eq!(card_at(&rects, rects[2].xy()), Some(2));
+    }
+
+    #[test]
+    fn test_move_selection() {
+        // 7 cards in 4 columns
+        assert_eq!(move_selection(0, 7, 4, Move::Left), 0);
+        assert_eq!(move_selection(3, 7, 4, Move::Right), 4);
+        assert_eq!(move_selection(6, 7, 4, Move::Right), 6);
+        assert_eq!(move_selection(1, 7, 4, Move::Down), 5);
+        assert_eq!(move_selection(3, 7, 4, Move::Down), 3);
+        assert_eq!(move_selection(5, 7, 4, Move::Up), 1);
+    }
+}
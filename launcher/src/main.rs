@@ -0,0 +1,361 @@
+//! Clock Launcher
+//!
+//! A gallery of every clock in the series. Picking a card starts that clock.
+//!
+//! The windowing event loop can only be created once per process, so the
+//! gallery does not run a clock itself: it relaunches this binary with
+//! `--clock <id>` and quits, and that process runs the clock in-process via
+//! its `ClockApp` entry point.
+//!
+//! Usage:
+//!   clock-launcher               open the gallery
+//!   clock-launcher --clock <id>  run a clock directly
+//!   clock-launcher --last        run the last-used clock
+//!   clock-launcher --list        print the clock ids
+//!
+//! Any clock can also render a single frame to disk and exit:
+//!   clock-launcher --clock <id> --snapshot <path.png> [--at <RFC3339>]
+//!
+//! Or open on a chosen monitor, optionally filling it, for kiosk displays:
+//!   clock-launcher --clock <id> --monitor <N> [--fullscreen]
+
+mod gallery;
+mod thumbnails;
+
+use std::process::Command;
+
+use nannou::prelude::*;
+use serde::{Deserialize, Serialize};
+use shared::{find_clock, ClockApp};
+
+use crate::gallery::{card_at, card_rects, column_count, move_selection, Move, FOOTER_HEIGHT};
+use crate::thumbnails::draw_thumbnail;
+
+const CLOCK_NAME: &str = "launcher";
+
+/// Persisted configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct Config {
+    /// Id of the clock launched most recently
+    #[serde(default)]
+    last_clock: Option<String>,
+}
+
+impl shared::VersionedConfig for Config {
+    const VERSION: u32 = 1;
+}
+
+/// Every clock in the series, in gallery order
+fn registry() -> [&'static dyn ClockApp; 10] {
+    [
+        &precision_instrument::PrecisionInstrument,
+        &worldline_ribbon::WorldlineRibbon,
+        &temporal_topography::TemporalTopography,
+        &chrono_superposition::ChronoSuperposition,
+        &ritual_clock::RitualClock,
+        &audit_ledger::AuditLedger,
+        &temporal_grammar::TemporalGrammar,
+        &world_daylight::WorldDaylight,
+        &flipboard::Flipboard,
+        &decimal_time::DecimalClock,
+    ]
+}
+
+fn load_config() -> Config {
+    shared::load_config(CLOCK_NAME)
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+fn save_last_clock(id: &str) {
+    let config = Config {
+        last_clock: Some(id.to_string()),
+    };
+    if let Err(e) = shared::save_config(CLOCK_NAME, &config) {
+        eprintln!("Failed to save config: {}", e);
+    }
+}
+
+fn main() {
+    let clocks = registry();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("--clock") => {
+            let Some(id) = args.get(1) else {
+                eprintln!("--clock needs a clock id (see --list)");
+                std::process::exit(2);
+            };
+            match find_clock(&clocks, id) {
+                Some(clock) => {
+                    // Snapshot runs shouldn't change which clock --last opens
+                    if shared::snapshot_request().is_none() {
+                        save_last_clock(clock.id());
+                    }
+                    clock.run();
+                }
+                None => {
+                    eprintln!("Unknown clock: {} (see --list)", id);
+                    std::process::exit(2);
+                }
+            }
+        }
+        Some("--last") => {
+            let last = load_config().last_clock;
+            match last.as_deref().and_then(|id| find_clock(&clocks, id)) {
+                Some(clock) => clock.run(),
+                None => nannou::app(model).run(),
+            }
+        }
+        Some("--list") => {
+            for clock in clocks {
+                println!("{:<22} {}", clock.id(), clock.title());
+            }
+        }
+        Some(other) => {
+            eprintln!("Unknown argument: {}", other);
+            eprintln!("Usage: clock-launcher [--clock <id> | --last | --list]");
+            eprintln!("       clock-launcher --clock <id> --snapshot <path.png> [--at <RFC3339>]");
+            eprintln!("       clock-launcher --clock <id> [--monitor <N>] [--fullscreen]");
+            std::process::exit(2);
+        }
+        None => nannou::app(model).run(),
+    }
+}
+
+/// Colors for the gallery chrome
+mod colors {
+    use nannou::prelude::*;
+
+    pub const BACKGROUND: Srgb<u8> = Srgb {
+        red: 14,
+        green: 14,
+        blue: 18,
+        standard: std::marker::PhantomData,
+    };
+    pub const TITLE: Srgb<u8> = Srgb {
+        red: 235,
+        green: 235,
+        blue: 240,
+        standard: std::marker::PhantomData,
+    };
+    pub const MUTED: Srgb<u8> = Srgb {
+        red: 130,
+        green: 130,
+        blue: 145,
+        standard: std::marker::PhantomData,
+    };
+    pub const CARD_BORDER: Srgb<u8> = Srgb {
+        red: 50,
+        green: 50,
+        blue: 60,
+        standard: std::marker::PhantomData,
+    };
+    pub const SELECTED: Srgb<u8> = Srgb {
+        red: 0,
+        green: 212,
+        blue: 255,
+        standard: std::marker::PhantomData,
+    };
+    pub const ERROR: Srgb<u8> = Srgb {
+        red: 255,
+        green: 100,
+        blue: 100,
+        standard: std::marker::PhantomData,
+    };
+}
+
+struct Model {
+    clocks: [&'static dyn ClockApp; 10],
+    /// Index of the highlighted card
+    selected: usize,
+    /// Card index under the mouse, if any
+    hovered: Option<usize>,
+    mouse_pos: Point2,
+    /// Id of the clock launched most recently, marked on its card
+    last_clock: Option<String>,
+    error_message: Option<String>,
+}
+
+fn model(app: &App) -> Model {
+    app.set_exit_on_escape(false);
+
+    app.new_window()
+        .title("Clock Series")
+        .size(1100, 720)
+        .view(view)
+        .key_pressed(key_pressed)
+        .mouse_moved(mouse_moved)
+        .mouse_pressed(mouse_pressed)
+        .build()
+        .unwrap();
+
+    let clocks = registry();
+    let config = load_config();
+
+    // Preselect the last-used clock
+    let selected = config
+        .last_clock
+        .as_deref()
+        .and_then(|id| clocks.iter().position(|clock| clock.id() == id))
+        .unwrap_or(0);
+
+    Model {
+        clocks,
+        selected,
+        hovered: None,
+        mouse_pos: pt2(0.0, 0.0),
+        last_clock: config.last_clock,
+        error_message: None,
+    }
+}
+
+/// Remember the clock, start it in a fresh process and close the gallery
+fn launch(app: &App, model: &mut Model, index: usize) {
+    let Some(clock) = model.clocks.get(index) else {
+        return;
+    };
+    model.selected = index;
+
+    let result = std::env::current_exe()
+        .and_then(|exe| Command::new(exe).arg("--clock").arg(clock.id()).spawn());
+
+    match result {
+        Ok(_) => {
+            save_last_clock(clock.id());
+            app.quit();
+        }
+        Err(e) => {
+            model.error_message = Some(format!("Could not start {}: {}", clock.title(), e));
+        }
+    }
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    let window_rect = app.window_rect();
+
+    draw.background().color(colors::BACKGROUND);
+
+    // Header
+    draw.text("CLOCK SERIES")
+        .x_y(window_rect.x(), window_rect.top() - 40.0)
+        .w(window_rect.w())
+        .font_size(28)
+        .color(colors::TITLE);
+    draw.text("Ten ways of looking at the same moment")
+        .x_y(window_rect.x(), window_rect.top() - 68.0)
+        .w(window_rect.w())
+        .font_size(14)
+        .color(colors::MUTED);
+
+    // Cards
+    let rects = card_rects(window_rect, model.clocks.len());
+    for (i, (clock, rect)) in model.clocks.iter().zip(&rects).enumerate() {
+        let is_selected = i == model.selected;
+        let is_hovered = model.hovered == Some(i);
+
+        let caption_h = 52.0;
+        let thumb_rect = Rect::from_corners(
+            pt2(rect.left() + 8.0, rect.bottom() + caption_h),
+            pt2(rect.right() - 8.0, rect.top() - 8.0),
+        );
+        draw_thumbnail(&draw, clock.id(), thumb_rect);
+
+        let border = if is_selected {
+            colors::SELECTED
+        } else if is_hovered {
+            colors::MUTED
+        } else {
+            colors::CARD_BORDER
+        };
+        draw.rect()
+            .xy(rect.xy())
+            .wh(rect.wh())
+            .no_fill()
+            .stroke(border)
+            .stroke_weight(if is_selected { 3.0 } else { 1.5 });
+
+        let title = if model.last_clock.as_deref() == Some(clock.id()) {
+            format!("{}. {}  (last)", i + 1, clock.title())
+        } else {
+            format!("{}. {}", i + 1, clock.title())
+        };
+        draw.text(&title)
+            .x_y(rect.x(), rect.bottom() + caption_h - 16.0)
+            .w(rect.w() - 16.0)
+            .font_size(14)
+            .color(if is_selected { colors::SELECTED } else { colors::TITLE });
+        draw.text(clock.tagline())
+            .x_y(rect.x(), rect.bottom() + 18.0)
+            .w(rect.w() - 16.0)
+            .font_size(10)
+            .color(colors::MUTED);
+    }
+
+    // Footer: error if launching failed, otherwise key hints
+    let footer_y = window_rect.bottom() + FOOTER_HEIGHT / 2.0;
+    match &model.error_message {
+        Some(message) => {
+            draw.text(message)
+                .x_y(window_rect.x(), footer_y)
+                .w(window_rect.w())
+                .font_size(13)
+                .color(colors::ERROR);
+        }
+        None => {
+            draw.text("Arrows select   Enter launch   1-9, 0 launch directly   Esc quit")
+                .x_y(window_rect.x(), footer_y)
+                .w(window_rect.w())
+                .font_size(12)
+                .color(colors::MUTED);
+        }
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
+
+fn key_pressed(app: &App, model: &mut Model, key: Key) {
+    let count = model.clocks.len();
+    let columns = column_count(app.window_rect().w()).min(count);
+
+    match key {
+        Key::Left => model.selected = move_selection(model.selected, count, columns, Move::Left),
+        Key::Right => model.selected = move_selection(model.selected, count, columns, Move::Right),
+        Key::Up => model.selected = move_selection(model.selected, count, columns, Move::Up),
+        Key::Down => model.selected = move_selection(model.selected, count, columns, Move::Down),
+        Key::Return | Key::Space => {
+            let index = model.selected;
+            launch(app, model, index);
+        }
+        Key::Key1 => launch(app, model, 0),
+        Key::Key2 => launch(app, model, 1),
+        Key::Key3 => launch(app, model, 2),
+        Key::Key4 => launch(app, model, 3),
+        Key::Key5 => launch(app, model, 4),
+        Key::Key6 => launch(app, model, 5),
+        Key::Key7 => launch(app, model, 6),
+        Key::Key8 => launch(app, model, 7),
+        Key::Key9 => launch(app, model, 8),
+        Key::Key0 => launch(app, model, 9),
+        Key::Escape => app.quit(),
+        _ => {}
+    }
+}
+
+fn mouse_moved(app: &App, model: &mut Model, pos: Point2) {
+    model.mouse_pos = pos;
+    let rects = card_rects(app.window_rect(), model.clocks.len());
+    model.hovered = card_at(&rects, pos);
+}
+
+fn mouse_pressed(app: &App, model: &mut Model, button: MouseButton) {
+    if button != MouseButton::Left {
+        return;
+    }
+    let rects = card_rects(app.window_rect(), model.clocks.len());
+    if let Some(index) = card_at(&rects, model.mouse_pos) {
+        launch(app, model, index);
+    }
+}
@@ -0,0 +1,360 @@
+//! Thumbnails module - miniature motif for each clock
+//!
+//! Each clock gets a small static drawing in its own palette so the gallery
+//! reads at a glance. Unknown ids fall back to a plain ring.
+
+use nannou::prelude::*;
+
+/// Draw the thumbnail for a clock id inside `rect`
+pub fn draw_thumbnail(draw: &Draw, id: &str, rect: Rect) {
+    match id {
+        "precision_instrument" => draw_precision_instrument(draw, rect),
+        "worldline_ribbon" => draw_worldline_ribbon(draw, rect),
+        "temporal_topography" => draw_temporal_topography(draw, rect),
+        "chrono_superposition" => draw_chrono_superposition(draw, rect),
+        "ritual_clock" => draw_ritual_clock(draw, rect),
+        "audit_ledger" => draw_audit_ledger(draw, rect),
+        "temporal_grammar" => draw_temporal_grammar(draw, rect),
+        "world_daylight" => draw_world_daylight(draw, rect),
+        "flipboard" => draw_flipboard(draw, rect),
+        "decimal_time" => draw_decimal_time(draw, rect),
+        _ => draw_ring(draw, rect.xy(), rect.w().min(rect.h()) * 0.4, srgb(120u8, 120u8, 120u8)),
+    }
+}
+
+/// Point on a clock face: index `i` of `n`, starting at 12 o'clock, clockwise
+fn clock_point(center: Point2, radius: f32, i: usize, n: usize) -> Point2 {
+    let angle = PI / 2.0 - (i as f32 / n as f32) * TAU;
+    center + vec2(angle.cos(), angle.sin()) * radius
+}
+
+fn draw_ring(draw: &Draw, center: Point2, radius: f32, color: Srgb<u8>) {
+    draw.ellipse()
+        .xy(center)
+        .radius(radius)
+        .no_fill()
+        .stroke(color)
+        .stroke_weight(1.5);
+}
+
+fn draw_precision_instrument(draw: &Draw, rect: Rect) {
+    draw.rect().xy(rect.xy()).wh(rect.wh()).color(srgb(26u8, 26u8, 26u8));
+
+    let center = rect.xy();
+    let radius = rect.w().min(rect.h()) * 0.4;
+    for i in 0..60 {
+        let is_major = i % 5 == 0;
+        let length = if is_major { radius * 0.15 } else { radius * 0.08 };
+        let color = if i == 10 {
+            srgb(0u8, 212u8, 255u8)
+        } else if is_major {
+            srgb(120u8, 120u8, 120u8)
+        } else {
+            srgb(80u8, 80u8, 80u8)
+        };
+        draw.line()
+            .start(clock_point(center, radius - length, i, 60))
+            .end(clock_point(center, radius, i, 60))
+            .color(color)
+            .weight(if is_major { 2.0 } else { 1.0 });
+    }
+    draw.ellipse()
+        .xy(clock_point(center, radius * 0.7, 10, 60))
+        .radius(3.0)
+        .color(srgb(0u8, 212u8, 255u8));
+}
+
+fn draw_worldline_ribbon(draw: &Draw, rect: Rect) {
+    draw.rect().xy(rect.xy()).wh(rect.wh()).color(srgb(26u8, 20u8, 16u8));
+
+    let lane_h = rect.h() * 0.22;
+    for (lane, y) in [rect.y() + lane_h * 0.6, rect.y() - lane_h * 0.6].into_iter().enumerate() {
+        let color = if lane == 0 {
+            srgb(42u8, 34u8, 24u8)
+        } else {
+            srgb(30u8, 26u8, 20u8)
+        };
+        draw.rect().x_y(rect.x(), y).w_h(rect.w(), lane_h).color(color);
+
+        // Hour ticks scrolling beneath the cursor
+        let spacing = rect.w() / 8.0;
+        let offset = if lane == 0 { 0.0 } else { spacing * 0.4 };
+        let mut x = rect.left() + offset;
+        while x < rect.right() {
+            draw.line()
+                .start(pt2(x, y - lane_h / 2.0))
+                .end(pt2(x, y - lane_h / 2.0 + lane_h * 0.35))
+                .color(srgb(201u8, 168u8, 108u8))
+                .weight(1.5);
+            x += spacing;
+        }
+    }
+
+    // DST seam on the lower lane and the "now" cursor
+    let seam_x = rect.x() + rect.w() * 0.28;
+    draw.line()
+        .start(pt2(seam_x, rect.y() - lane_h * 1.1))
+        .end(pt2(seam_x, rect.y() - lane_h * 0.1))
+        .color(srgb(255u8, 107u8, 53u8))
+        .weight(2.0);
+    draw.line()
+        .start(pt2(rect.x(), rect.top() - 8.0))
+        .end(pt2(rect.x(), rect.bottom() + 8.0))
+        .color(srgb(255u8, 179u8, 71u8))
+        .weight(2.0);
+}
+
+fn draw_temporal_topography(draw: &Draw, rect: Rect) {
+    draw.rect().xy(rect.xy()).wh(rect.wh()).color(srgb(38u8, 42u8, 48u8));
+
+    let center = rect.xy() + vec2(rect.w() * 0.08, -rect.h() * 0.05);
+    let base = rect.w().min(rect.h()) * 0.42;
+    let levels = 6;
+    for level in 0..levels {
+        let t = level as f32 / levels as f32;
+        let radius = base * (1.0 - t * 0.85);
+        let color = if level % 2 == 0 {
+            srgb(139u8, 119u8, 101u8)
+        } else {
+            srgb(70u8, 100u8, 90u8)
+        };
+        let points: Vec<Point2> = (0..=48)
+            .map(|i| {
+                let angle = i as f32 / 48.0 * TAU;
+                let wobble = 1.0 + 0.12 * (angle * 3.0 + level as f32).sin();
+                center + vec2(angle.cos() * 1.25, angle.sin()) * radius * wobble
+            })
+            .collect();
+        draw.polyline().weight(1.2).color(color).points(points);
+    }
+
+    // "You are here" beacon
+    draw.ellipse()
+        .xy(center + vec2(-base * 0.3, base * 0.2))
+        .radius(4.0)
+        .color(srgb(255u8, 179u8, 71u8));
+}
+
+fn draw_chrono_superposition(draw: &Draw, rect: Rect) {
+    draw.rect().xy(rect.xy()).wh(rect.wh()).color(srgb(18u8, 22u8, 28u8));
+
+    let card_w = rect.w() * 0.55;
+    let card_h = rect.h() * 0.42;
+    for i in 0..3 {
+        let offset = vec2(-14.0, 12.0) * (1.0 - i as f32);
+        let is_dominant = i == 2;
+        let (fill, border) = if is_dominant {
+            (srgb(45u8, 52u8, 65u8), srgb(120u8, 140u8, 180u8))
+        } else {
+            (srgb(35u8, 40u8, 50u8), srgb(80u8, 90u8, 110u8))
+        };
+        draw.rect()
+            .xy(rect.xy() + offset)
+            .w_h(card_w, card_h)
+            .color(fill)
+            .stroke(border)
+            .stroke_weight(1.5);
+    }
+
+    // Time line on the dominant card
+    let line_y = rect.y() - 12.0;
+    draw.line()
+        .start(pt2(rect.x() - card_w * 0.3 - 14.0, line_y))
+        .end(pt2(rect.x() + card_w * 0.2 - 14.0, line_y))
+        .color(srgb(245u8, 240u8, 235u8))
+        .weight(4.0);
+}
+
+fn draw_ritual_clock(draw: &Draw, rect: Rect) {
+    draw.rect().xy(rect.xy()).wh(rect.wh()).color(srgb(15u8, 18u8, 25u8));
+
+    let center = rect.xy();
+    let size = rect.w().min(rect.h());
+    for i in 0..60 {
+        let color = if i < 23 {
+            srgb(150u8, 180u8, 255u8)
+        } else {
+            srgb(60u8, 70u8, 90u8)
+        };
+        draw.ellipse()
+            .xy(clock_point(center, size * 0.28, i, 60))
+            .radius(1.5)
+            .color(color);
+    }
+    for i in 0..12 {
+        let color = if i == 4 {
+            srgb(255u8, 220u8, 100u8)
+        } else {
+            srgb(80u8, 100u8, 140u8)
+        };
+        draw.ellipse()
+            .xy(clock_point(center, size * 0.4, i, 12))
+            .radius(size * 0.03)
+            .color(color);
+    }
+}
+
+fn draw_audit_ledger(draw: &Draw, rect: Rect) {
+    draw.rect().xy(rect.xy()).wh(rect.wh()).color(srgb(10u8, 12u8, 16u8));
+
+    let rows = 7;
+    let row_h = rect.h() / (rows as f32 + 1.0);
+    let left = rect.left() + 12.0;
+    for row in 0..rows {
+        let y = rect.top() - row_h * (row as f32 + 1.0);
+        let is_latest = row == rows - 1;
+        let color = if is_latest {
+            srgb(51u8, 255u8, 102u8)
+        } else {
+            srgb(30u8, 120u8, 60u8)
+        };
+        // Timestamp column, then a hash of varying length
+        draw.rect()
+            .x_y(left + rect.w() * 0.12, y)
+            .w_h(rect.w() * 0.24, row_h * 0.35)
+            .color(color);
+        let hash_w = rect.w() * (0.3 + 0.1 * ((row * 7) % 4) as f32);
+        draw.rect()
+            .x_y(left + rect.w() * 0.3 + hash_w / 2.0, y)
+            .w_h(hash_w, row_h * 0.2)
+            .color(color);
+    }
+}
+
+fn draw_temporal_grammar(draw: &Draw, rect: Rect) {
+    draw.rect().xy(rect.xy()).wh(rect.wh()).color(srgb(12u8, 10u8, 18u8));
+
+    let center = rect.xy();
+    let size = rect.w().min(rect.h());
+
+    // Hour polygon (hexagon)
+    let polygon: Vec<Point2> = (0..6).map(|i| clock_point(center, size * 0.3, i, 6)).collect();
+    draw.polygon()
+        .color(srgba(45u8, 40u8, 90u8, 200u8))
+        .stroke(srgb(80u8, 70u8, 140u8))
+        .stroke_weight(1.5)
+        .points(polygon);
+
+    // Minute superellipse
+    let exponent = 4.0_f32;
+    let superellipse: Vec<Point2> = (0..64)
+        .map(|i| {
+            let angle = i as f32 / 64.0 * TAU;
+            let (cos, sin) = (angle.cos(), angle.sin());
+            let x = cos.abs().powf(2.0 / exponent) * cos.signum();
+            let y = sin.abs().powf(2.0 / exponent) * sin.signum();
+            center + vec2(x, y) * size * 0.2
+        })
+        .collect();
+    draw.polygon()
+        .color(srgba(180u8, 120u8, 60u8, 110u8))
+        .stroke(srgb(220u8, 160u8, 80u8))
+        .stroke_weight(1.5)
+        .points(superellipse);
+
+    // Phase ring
+    for i in 0..60 {
+        let (radius, color) = if i == 17 {
+            (3.5, srgb(100u8, 240u8, 255u8))
+        } else {
+            (1.2, srgb(60u8, 140u8, 160u8))
+        };
+        draw.ellipse()
+            .xy(clock_point(center, size * 0.42, i, 60))
+            .radius(radius)
+            .color(color);
+    }
+}
+
+fn draw_world_daylight(draw: &Draw, rect: Rect) {
+    draw.rect().xy(rect.xy()).wh(rect.wh()).color(srgb(10u8, 14u8, 22u8));
+
+    // 2:1 map with a few blocky landmasses
+    let map_w = rect.w().min(rect.h() * 2.0) * 0.9;
+    let map = Rect::from_x_y_w_h(rect.x(), rect.y(), map_w, map_w / 2.0);
+    draw.rect().xy(map.xy()).wh(map.wh()).color(srgb(28u8, 56u8, 88u8));
+    let at = |u: f32, v: f32| pt2(map.left() + u * map.w(), map.bottom() + v * map.h());
+    for (u, v, w, h) in [
+        (0.2, 0.7, 0.22, 0.3),
+        (0.3, 0.32, 0.1, 0.3),
+        (0.52, 0.45, 0.1, 0.35),
+        (0.68, 0.72, 0.35, 0.28),
+        (0.86, 0.3, 0.1, 0.12),
+    ] {
+        draw.rect()
+            .xy(at(u, v))
+            .w_h(w * map.w(), h * map.h())
+            .color(srgb(92u8, 120u8, 82u8));
+    }
+
+    // Night side bounded by a sinusoidal terminator
+    let terminator: Vec<Point2> = (0..=32)
+        .map(|i| {
+            let u = i as f32 / 32.0;
+            at(u, 0.5 + 0.35 * (u * TAU).cos())
+        })
+        .collect();
+    let mut night = terminator.clone();
+    night.push(at(1.0, 0.0));
+    night.push(at(0.0, 0.0));
+    draw.polygon().color(srgba(4u8, 6u8, 20u8, 180u8)).points(night);
+    draw.polyline()
+        .weight(1.5)
+        .points(terminator)
+        .color(srgb(255u8, 210u8, 90u8));
+
+    draw.ellipse().xy(at(0.5, 0.65)).radius(4.0).color(srgb(255u8, 210u8, 90u8));
+    for (u, v) in [(0.2, 0.68), (0.52, 0.75), (0.87, 0.68)] {
+        draw.ellipse().xy(at(u, v)).radius(2.5).color(srgb(90u8, 220u8, 255u8));
+    }
+}
+
+fn draw_flipboard(draw: &Draw, rect: Rect) {
+    draw.rect().xy(rect.xy()).wh(rect.wh()).color(srgb(12u8, 12u8, 14u8));
+
+    // Four rows of split-flap cells, a few of them lit in amber
+    let cell_w = rect.w() / 14.0;
+    let cell_h = cell_w * 1.4;
+    for row in 0..4 {
+        let y = rect.y() + (1.5 - row as f32) * cell_h * 1.3;
+        for col in 0..10 {
+            let x = rect.x() + (col as f32 - 4.5) * cell_w * 1.15;
+            let lit = (row * 3 + col) % 4 == 0;
+            let face = if lit { srgb(255u8, 200u8, 40u8) } else { srgb(38u8, 38u8, 42u8) };
+            draw.rect().x_y(x, y).w_h(cell_w, cell_h).color(face);
+            draw.line()
+                .start(pt2(x - cell_w / 2.0, y))
+                .end(pt2(x + cell_w / 2.0, y))
+                .weight(1.0)
+                .color(srgb(8u8, 8u8, 10u8));
+        }
+    }
+}
+
+fn draw_decimal_time(draw: &Draw, rect: Rect) {
+    draw.rect().xy(rect.xy()).wh(rect.wh()).color(srgb(20u8, 17u8, 15u8));
+
+    // Enamel face with ten hour marks, hands at 7:50 (six in the evening)
+    let center = rect.xy();
+    let radius = rect.w().min(rect.h()) * 0.4;
+    draw.ellipse().xy(center).radius(radius * 1.05).color(srgb(212u8, 175u8, 55u8));
+    draw.ellipse().xy(center).radius(radius).color(srgb(238u8, 230u8, 212u8));
+    let ink = srgb(36u8, 30u8, 28u8);
+    for i in 0..10 {
+        draw.line()
+            .start(clock_point(center, radius * 0.78, i, 10))
+            .end(clock_point(center, radius * 0.92, i, 10))
+            .weight(2.0)
+            .color(ink);
+    }
+    draw.line()
+        .start(center)
+        .end(clock_point(center, radius * 0.5, 78, 100))
+        .weight(3.0)
+        .color(ink);
+    draw.line()
+        .start(center)
+        .end(clock_point(center, radius * 0.8, 50, 100))
+        .weight(2.0)
+        .color(ink);
+}
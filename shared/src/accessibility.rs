@@ -0,0 +1,338 @@
+//! Accessibility module - screen reader tree for every clock
+//!
+//! Each clock describes itself as a small tree: a window holding the current
+//! time, the DST status and one node per keyboard focus region, with the
+//! focused region marked. The tree is always built; it only reaches screen
+//! readers through AccessKit when the `accesskit` feature is enabled, and is
+//! only sent when it changes (the time is spoken to the minute).
+
+use chrono::Utc;
+use winit::event::WindowEvent;
+use winit::window::Window;
+
+use crate::{DstChange, TimeData};
+
+/// A clock's keyboard focus regions, as registered with the screen reader
+pub trait FocusRegions: Copy + PartialEq + 'static {
+    /// Every region, in Tab order
+    const ALL: &'static [Self];
+
+    /// Name read out when the region is focused
+    fn label(self) -> &'static str;
+}
+
+/// Focus regions of a clock that has no keyboard regions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoFocusRegions {}
+
+impl FocusRegions for NoFocusRegions {
+    const ALL: &'static [Self] = &[];
+
+    fn label(self) -> &'static str {
+        match self {}
+    }
+}
+
+/// What a node is to assistive technology
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessRole {
+    /// The clock window (root)
+    Window,
+    /// The current time, announced as it changes
+    Time,
+    /// DST status, announced as it changes
+    Status,
+    /// A keyboard focus region
+    Region,
+}
+
+/// A node in the accessibility tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessNode {
+    pub role: AccessRole,
+    pub name: String,
+}
+
+/// A clock's accessibility tree
+///
+/// `nodes[0]` is the window and every other node is its child, in order:
+/// time, DST status, then the focus regions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessTree {
+    pub nodes: Vec<AccessNode>,
+    /// Index of the focused node (the window when no region is focused)
+    pub focus: usize,
+}
+
+impl AccessTree {
+    /// Index of the first focus region node
+    pub const FIRST_REGION: usize = 3;
+
+    pub fn new<R: FocusRegions>(title: &str, time_data: &TimeData, focused: Option<R>) -> Self {
+        let node = |role, name: String| AccessNode { role, name };
+        let mut nodes = vec![
+            node(AccessRole::Window, title.to_string()),
+            node(AccessRole::Time, spoken_time(time_data)),
+            node(AccessRole::Status, dst_status(time_data)),
+        ];
+        nodes.extend(
+            R::ALL
+                .iter()
+                .map(|region| node(AccessRole::Region, region.label().to_string())),
+        );
+
+        let focus = focused
+            .and_then(|region| R::ALL.iter().position(|&r| r == region))
+            .map_or(0, |index| Self::FIRST_REGION + index);
+        Self { nodes, focus }
+    }
+}
+
+/// The time to the minute, as read aloud: "It is 3:42 PM PDT"
+pub fn spoken_time(time_data: &TimeData) -> String {
+    format!(
+        "It is {}:{:02} {} {}",
+        time_data.hour12,
+        time_data.minute,
+        time_data.meridiem.as_str(),
+        time_data.tz_abbrev
+    )
+}
+
+/// DST status with any nearby transition: "Standard time; clocks spring
+/// forward in 5 hours"
+pub fn dst_status(time_data: &TimeData) -> String {
+    let mut status = if time_data.is_dst {
+        "Daylight saving time".to_string()
+    } else {
+        "Standard time".to_string()
+    };
+    match &time_data.dst_change {
+        DstChange::Upcoming { instant, delta_minutes } => {
+            let now = time_data.local_datetime.with_timezone(&Utc);
+            let hours = (*instant - now).num_hours();
+            let direction = if *delta_minutes > 0 { "spring forward" } else { "fall back" };
+            status.push_str(&format!("; clocks {} in {} hours", direction, hours));
+        }
+        DstChange::JustOccurred { delta_minutes, .. } => {
+            let direction = if *delta_minutes > 0 { "sprang forward" } else { "fell back" };
+            status.push_str(&format!("; clocks {} recently", direction));
+        }
+        DstChange::None => {}
+    }
+    status
+}
+
+#[cfg(feature = "accesskit")]
+mod adapter {
+    use std::num::NonZeroU128;
+
+    use accesskit::{
+        Action, ActionHandler, ActionRequest, Live, NodeBuilder, NodeClassSet, NodeId, Role, Tree,
+        TreeUpdate,
+    };
+    use accesskit_winit::Adapter;
+    use winit::event::WindowEvent;
+    use winit::window::Window;
+
+    use super::{AccessRole, AccessTree};
+
+    /// The tree is read-only; focus moves with the clock's own keys
+    struct IgnoreActions;
+
+    impl ActionHandler for IgnoreActions {
+        fn do_action(&self, _request: ActionRequest) {}
+    }
+
+    pub struct Backend {
+        adapter: Adapter,
+    }
+
+    impl Backend {
+        pub fn attach(window: &Window, initial: AccessTree) -> Self {
+            let adapter = Adapter::with_action_handler(
+                window,
+                move || tree_update(&initial),
+                Box::new(IgnoreActions),
+            );
+            Self { adapter }
+        }
+
+        pub fn update(&self, tree: &AccessTree) {
+            self.adapter.update_if_active(|| tree_update(tree));
+        }
+
+        pub fn process_event(&self, window: &Window, event: &WindowEvent<'_>) {
+            self.adapter.on_event(window, event);
+        }
+    }
+
+    fn node_id(index: usize) -> NodeId {
+        NodeId(NonZeroU128::MIN.saturating_add(index as u128))
+    }
+
+    fn tree_update(tree: &AccessTree) -> TreeUpdate {
+        let mut classes = NodeClassSet::new();
+        let nodes = tree
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| {
+                let mut builder = NodeBuilder::new(match node.role {
+                    AccessRole::Window => Role::Window,
+                    AccessRole::Time => Role::Timer,
+                    AccessRole::Status => Role::Status,
+                    AccessRole::Region => Role::Group,
+                });
+                builder.set_name(node.name.clone());
+                match node.role {
+                    AccessRole::Window => {
+                        let children: Vec<NodeId> = (1..tree.nodes.len()).map(node_id).collect();
+                        builder.set_children(children);
+                    }
+                    AccessRole::Time | AccessRole::Status => builder.set_live(Live::Polite),
+                    AccessRole::Region => builder.add_action(Action::Focus),
+                }
+                (node_id(index), builder.build(&mut classes))
+            })
+            .collect();
+        TreeUpdate {
+            nodes,
+            tree: Some(Tree::new(node_id(0))),
+            focus: Some(node_id(tree.focus)),
+        }
+    }
+}
+
+#[cfg(not(feature = "accesskit"))]
+mod adapter {
+    use winit::event::WindowEvent;
+    use winit::window::Window;
+
+    use super::AccessTree;
+
+    /// Stand-in when the `accesskit` feature is disabled
+    pub struct Backend;
+
+    impl Backend {
+        pub fn attach(_window: &Window, _initial: AccessTree) -> Self {
+            Backend
+        }
+
+        pub fn update(&self, _tree: &AccessTree) {}
+
+        pub fn process_event(&self, _window: &Window, _event: &WindowEvent<'_>) {}
+    }
+}
+
+/// A clock window's connection to screen readers
+pub struct ScreenReader {
+    title: &'static str,
+    backend: adapter::Backend,
+    /// Last tree sent, so unchanged frames send nothing
+    last: AccessTree,
+}
+
+impl ScreenReader {
+    /// Attach to `window`, which must not have been shown yet
+    ///
+    /// Build the window invisible, attach, then make it visible.
+    pub fn attach<R: FocusRegions>(
+        window: &Window,
+        title: &'static str,
+        time_data: &TimeData,
+        focused: Option<R>,
+    ) -> Self {
+        let tree = AccessTree::new(title, time_data, focused);
+        Self {
+            title,
+            backend: adapter::Backend::attach(window, tree.clone()),
+            last: tree,
+        }
+    }
+
+    /// Expose the current time, DST status and focused region
+    pub fn update<R: FocusRegions>(&mut self, time_data: &TimeData, focused: Option<R>) {
+        let tree = AccessTree::new(self.title, time_data, focused);
+        if tree != self.last {
+            self.backend.update(&tree);
+            self.last = tree;
+        }
+    }
+
+    /// Forward a window event (focus changes, etc.) to the adapter
+    pub fn process_event(&self, window: &Window, event: &WindowEvent<'_>) {
+        self.backend.process_event(window, event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute_time_data_at;
+    use chrono::TimeZone;
+    use chrono_tz::Tz;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Region {
+        Canvas,
+        Sidebar,
+    }
+
+    impl FocusRegions for Region {
+        const ALL: &'static [Self] = &[Region::Canvas, Region::Sidebar];
+
+        fn label(self) -> &'static str {
+            match self {
+                Region::Canvas => "Canvas",
+                Region::Sidebar => "Sidebar",
+            }
+        }
+    }
+
+    fn time_at(zone: &str, hour: u32, minute: u32, second: u32) -> TimeData {
+        let tz: Tz = zone.parse().unwrap();
+        compute_time_data_at(tz, Utc.with_ymd_and_hms(2024, 3, 9, hour, minute, second).unwrap())
+    }
+
+    #[test]
+    fn test_tree_layout_and_focus() {
+        let time_data = time_at("UTC", 15, 42, 10);
+        let tree = AccessTree::new("Test Clock", &time_data, Some(Region::Sidebar));
+        let roles: Vec<AccessRole> = tree.nodes.iter().map(|n| n.role).collect();
+        assert_eq!(
+            roles,
+            [
+                AccessRole::Window,
+                AccessRole::Time,
+                AccessRole::Status,
+                AccessRole::Region,
+                AccessRole::Region
+            ]
+        );
+        assert_eq!(tree.nodes[tree.focus].name, "Sidebar");
+
+        let unfocused = AccessTree::new::<NoFocusRegions>("Test Clock", &time_data, None);
+        assert_eq!(unfocused.nodes.len(), AccessTree::FIRST_REGION);
+        assert_eq!(unfocused.focus, 0);
+    }
+
+    #[test]
+    fn test_tree_changes_only_by_the_minute() {
+        let early = time_at("UTC", 15, 42, 10);
+        let later = time_at("UTC", 15, 42, 50);
+        let next = time_at("UTC", 15, 43, 0);
+        let tree = |t: &TimeData| AccessTree::new("Clock", t, Some(Region::Canvas));
+        assert_eq!(tree(&early), tree(&later));
+        assert_ne!(tree(&later), tree(&next));
+        assert_eq!(spoken_time(&early), "It is 3:42 PM UTC");
+    }
+
+    #[test]
+    fn test_dst_status() {
+        // Los Angeles springs forward at 2024-03-10 10:00 UTC
+        let before = time_at("America/Los_Angeles", 20, 0, 0);
+        assert_eq!(dst_status(&before), "Standard time; clocks spring forward in 14 hours");
+        assert_eq!(dst_status(&time_at("UTC", 12, 0, 0)), "Standard time");
+    }
+}
@@ -0,0 +1,524 @@
+//! Cities module - a compact world cities dataset
+//!
+//! A few hundred of the world's larger cities, each with its country, metro
+//! population, coordinates and IANA zone. Pickers use it to show
+//! "Tokyo, Japan (Asia/Tokyo)" instead of a bare zone id and to group results
+//! by region; search uses it to find zones by city or country; and map
+//! clicks use it to find the nearest city's zone.
+//!
+//! Populations are approximate metro figures in thousands. They only rank
+//! and label zones, so they're rounded and not kept precisely current.
+
+use chrono_tz::Tz;
+use nannou_egui::egui;
+
+use crate::i18n::tr;
+use crate::solar::Coordinates;
+
+/// A city and the zone it keeps time in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct City {
+    pub name: &'static str,
+    pub country: &'static str,
+    /// Metro population in thousands
+    pub population: u32,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// IANA zone id
+    pub zone_id: &'static str,
+}
+
+impl City {
+    pub fn coordinates(&self) -> Coordinates {
+        Coordinates::new(self.latitude, self.longitude)
+    }
+
+    pub fn timezone(&self) -> Option<Tz> {
+        self.zone_id.parse().ok()
+    }
+
+    /// "Tokyo, Japan"; city-states are named once ("Singapore")
+    pub fn label(&self) -> String {
+        if self.name == self.country {
+            self.name.to_string()
+        } else {
+            format!("{}, {}", self.name, self.country)
+        }
+    }
+}
+
+const fn city(
+    name: &'static str,
+    country: &'static str,
+    population: u32,
+    latitude: f64,
+    longitude: f64,
+    zone_id: &'static str,
+) -> City {
+    City {
+        name,
+        country,
+        population,
+        latitude,
+        longitude,
+        zone_id,
+    }
+}
+
+/// The dataset, grouped by region
+pub const CITIES: &[City] = &[
+    // North America
+    city("New York", "United States", 20140, 40.71, -74.01, "America/New_York"),
+    city("Washington", "United States", 6385, 38.91, -77.04, "America/New_York"),
+    city("Philadelphia", "United States", 6245, 39.95, -75.17, "America/New_York"),
+    city("Boston", "United States", 4941, 42.36, -71.06, "America/New_York"),
+    city("Atlanta", "United States", 6307, 33.75, -84.39, "America/New_York"),
+    city("Miami", "United States", 6139, 25.76, -80.19, "America/New_York"),
+    city("Detroit", "United States", 4392, 42.33, -83.05, "America/Detroit"),
+    city("Indianapolis", "United States", 2111, 39.77, -86.16, "America/Indiana/Indianapolis"),
+    city("Chicago", "United States", 9618, 41.88, -87.63, "America/Chicago"),
+    city("Houston", "United States", 7122, 29.76, -95.37, "America/Chicago"),
+    city("Dallas", "United States", 7637, 32.78, -96.80, "America/Chicago"),
+    city("Minneapolis", "United States", 3690, 44.98, -93.27, "America/Chicago"),
+    city("New Orleans", "United States", 1271, 29.95, -90.07, "America/Chicago"),
+    city("Denver", "United States", 2963, 39.74, -104.99, "America/Denver"),
+    city("Salt Lake City", "United States", 1257, 40.76, -111.89, "America/Denver"),
+    city("Phoenix", "United States", 4845, 33.45, -112.07, "America/Phoenix"),
+    city("Los Angeles", "United States", 13200, 34.05, -118.24, "America/Los_Angeles"),
+    city("San Francisco", "United States", 4749, 37.77, -122.42, "America/Los_Angeles"),
+    city("Seattle", "United States", 4018, 47.61, -122.33, "America/Los_Angeles"),
+    city("San Diego", "United States", 3298, 32.72, -117.16, "America/Los_Angeles"),
+    city("Portland", "United States", 2512, 45.52, -122.68, "America/Los_Angeles"),
+    city("Las Vegas", "United States", 2228, 36.17, -115.14, "America/Los_Angeles"),
+    city("Anchorage", "United States", 292, 61.22, -149.90, "America/Anchorage"),
+    city("Honolulu", "United States", 1016, 21.31, -157.86, "Pacific/Honolulu"),
+    city("Toronto", "Canada", 6202, 43.65, -79.38, "America/Toronto"),
+    city("Montreal", "Canada", 4292, 45.50, -73.57, "America/Toronto"),
+    city("Ottawa", "Canada", 1488, 45.42, -75.70, "America/Toronto"),
+    city("Vancouver", "Canada", 2643, 49.28, -123.12, "America/Vancouver"),
+    city("Calgary", "Canada", 1481, 51.05, -114.07, "America/Edmonton"),
+    city("Edmonton", "Canada", 1418, 53.55, -113.49, "America/Edmonton"),
+    city("Winnipeg", "Canada", 834, 49.90, -97.14, "America/Winnipeg"),
+    city("Regina", "Canada", 249, 50.45, -104.62, "America/Regina"),
+    city("Halifax", "Canada", 465, 44.65, -63.57, "America/Halifax"),
+    city("St. John's", "Canada", 212, 47.56, -52.71, "America/St_Johns"),
+    city("Nuuk", "Greenland", 19, 64.18, -51.72, "America/Nuuk"),
+    // Latin America and the Caribbean
+    city("Mexico City", "Mexico", 21804, 19.43, -99.13, "America/Mexico_City"),
+    city("Guadalajara", "Mexico", 5269, 20.66, -103.35, "America/Mexico_City"),
+    city("Monterrey", "Mexico", 5341, 25.69, -100.32, "America/Monterrey"),
+    city("Tijuana", "Mexico", 2158, 32.51, -117.04, "America/Tijuana"),
+    city("Cancún", "Mexico", 934, 21.16, -86.85, "America/Cancun"),
+    city("Guatemala City", "Guatemala", 3014, 14.63, -90.51, "America/Guatemala"),
+    city("San Salvador", "El Salvador", 1107, 13.69, -89.22, "America/El_Salvador"),
+    city("Tegucigalpa", "Honduras", 1403, 14.07, -87.19, "America/Tegucigalpa"),
+    city("Managua", "Nicaragua", 1055, 12.11, -86.24, "America/Managua"),
+    city("San José", "Costa Rica", 1420, 9.93, -84.08, "America/Costa_Rica"),
+    city("Panama City", "Panama", 1860, 8.98, -79.52, "America/Panama"),
+    city("Havana", "Cuba", 2143, 23.11, -82.37, "America/Havana"),
+    city("Kingston", "Jamaica", 1200, 17.97, -76.79, "America/Jamaica"),
+    city("Port-au-Prince", "Haiti", 2844, 18.59, -72.31, "America/Port-au-Prince"),
+    city("Santo Domingo", "Dominican Republic", 3524, 18.49, -69.93, "America/Santo_Domingo"),
+    city("San Juan", "Puerto Rico", 2036, 18.47, -66.11, "America/Puerto_Rico"),
+    city("Bogotá", "Colombia", 11344, 4.71, -74.07, "America/Bogota"),
+    city("Medellín", "Colombia", 4055, 6.24, -75.58, "America/Bogota"),
+    city("Caracas", "Venezuela", 2946, 10.48, -66.90, "America/Caracas"),
+    city("Quito", "Ecuador", 1928, -0.18, -78.47, "America/Guayaquil"),
+    city("Guayaquil", "Ecuador", 3092, -2.17, -79.92, "America/Guayaquil"),
+    city("Lima", "Peru", 10883, -12.05, -77.04, "America/Lima"),
+    city("La Paz", "Bolivia", 1908, -16.49, -68.12, "America/La_Paz"),
+    city("Santiago", "Chile", 6857, -33.45, -70.67, "America/Santiago"),
+    city("São Paulo", "Brazil", 22430, -23.55, -46.63, "America/Sao_Paulo"),
+    city("Rio de Janeiro", "Brazil", 13634, -22.91, -43.17, "America/Sao_Paulo"),
+    city("Belo Horizonte", "Brazil", 6194, -19.92, -43.94, "America/Sao_Paulo"),
+    city("Brasília", "Brazil", 4804, -15.79, -47.88, "America/Sao_Paulo"),
+    city("Salvador", "Brazil", 3957, -12.97, -38.50, "America/Bahia"),
+    city("Recife", "Brazil", 4208, -8.05, -34.88, "America/Recife"),
+    city("Fortaleza", "Brazil", 4167, -3.72, -38.54, "America/Fortaleza"),
+    city("Manaus", "Brazil", 2279, -3.12, -60.02, "America/Manaus"),
+    city("Buenos Aires", "Argentina", 15490, -34.60, -58.38, "America/Argentina/Buenos_Aires"),
+    city("Córdoba", "Argentina", 1612, -31.42, -64.18, "America/Argentina/Cordoba"),
+    city("Montevideo", "Uruguay", 1760, -34.90, -56.16, "America/Montevideo"),
+    city("Asunción", "Paraguay", 3452, -25.26, -57.58, "America/Asuncion"),
+    // Europe
+    city("London", "United Kingdom", 9648, 51.51, -0.13, "Europe/London"),
+    city("Manchester", "United Kingdom", 2791, 53.48, -2.24, "Europe/London"),
+    city("Birmingham", "United Kingdom", 2650, 52.49, -1.89, "Europe/London"),
+    city("Edinburgh", "United Kingdom", 548, 55.95, -3.19, "Europe/London"),
+    city("Dublin", "Ireland", 1263, 53.35, -6.26, "Europe/Dublin"),
+    city("Lisbon", "Portugal", 2972, 38.72, -9.14, "Europe/Lisbon"),
+    city("Madrid", "Spain", 6751, 40.42, -3.70, "Europe/Madrid"),
+    city("Barcelona", "Spain", 5687, 41.39, 2.17, "Europe/Madrid"),
+    city("Paris", "France", 11208, 48.86, 2.35, "Europe/Paris"),
+    city("Lyon", "France", 1748, 45.76, 4.84, "Europe/Paris"),
+    city("Marseille", "France", 1620, 43.30, 5.37, "Europe/Paris"),
+    city("Brussels", "Belgium", 2111, 50.85, 4.35, "Europe/Brussels"),
+    city("Amsterdam", "Netherlands", 2480, 52.37, 4.90, "Europe/Amsterdam"),
+    city("Luxembourg", "Luxembourg", 128, 49.61, 6.13, "Europe/Luxembourg"),
+    city("Berlin", "Germany", 4473, 52.52, 13.40, "Europe/Berlin"),
+    city("Hamburg", "Germany", 1906, 53.55, 9.99, "Europe/Berlin"),
+    city("Munich", "Germany", 2932, 48.14, 11.58, "Europe/Berlin"),
+    city("Frankfurt", "Germany", 2302, 50.11, 8.68, "Europe/Berlin"),
+    city("Cologne", "Germany", 1996, 50.94, 6.96, "Europe/Berlin"),
+    city("Zurich", "Switzerland", 1439, 47.38, 8.54, "Europe/Zurich"),
+    city("Geneva", "Switzerland", 628, 46.20, 6.14, "Europe/Zurich"),
+    city("Vienna", "Austria", 1975, 48.21, 16.37, "Europe/Vienna"),
+    city("Rome", "Italy", 4316, 41.90, 12.50, "Europe/Rome"),
+    city("Milan", "Italy", 3155, 45.46, 9.19, "Europe/Rome"),
+    city("Naples", "Italy", 3085, 40.85, 14.27, "Europe/Rome"),
+    city("Copenhagen", "Denmark", 1391, 55.68, 12.57, "Europe/Copenhagen"),
+    city("Oslo", "Norway", 1082, 59.91, 10.75, "Europe/Oslo"),
+    city("Stockholm", "Sweden", 1679, 59.33, 18.07, "Europe/Stockholm"),
+    city("Helsinki", "Finland", 1328, 60.17, 24.94, "Europe/Helsinki"),
+    city("Reykjavík", "Iceland", 233, 64.15, -21.94, "Atlantic/Reykjavik"),
+    city("Tallinn", "Estonia", 454, 59.44, 24.75, "Europe/Tallinn"),
+    city("Riga", "Latvia", 605, 56.95, 24.11, "Europe/Riga"),
+    city("Vilnius", "Lithuania", 592, 54.69, 25.28, "Europe/Vilnius"),
+    city("Warsaw", "Poland", 1800, 52.23, 21.01, "Europe/Warsaw"),
+    city("Prague", "Czechia", 1357, 50.08, 14.44, "Europe/Prague"),
+    city("Budapest", "Hungary", 1778, 47.50, 19.04, "Europe/Budapest"),
+    city("Zagreb", "Croatia", 686, 45.81, 15.98, "Europe/Zagreb"),
+    city("Belgrade", "Serbia", 1408, 44.79, 20.45, "Europe/Belgrade"),
+    city("Bucharest", "Romania", 1776, 44.43, 26.10, "Europe/Bucharest"),
+    city("Sofia", "Bulgaria", 1287, 42.70, 23.32, "Europe/Sofia"),
+    city("Athens", "Greece", 3154, 37.98, 23.73, "Europe/Athens"),
+    city("Istanbul", "Turkey", 15636, 41.01, 28.98, "Europe/Istanbul"),
+    city("Ankara", "Turkey", 5310, 39.93, 32.86, "Europe/Istanbul"),
+    city("Kyiv", "Ukraine", 3017, 50.45, 30.52, "Europe/Kyiv"),
+    city("Minsk", "Belarus", 2009, 53.90, 27.56, "Europe/Minsk"),
+    city("Moscow", "Russia", 12655, 55.76, 37.62, "Europe/Moscow"),
+    city("Saint Petersburg", "Russia", 5384, 59.93, 30.34, "Europe/Moscow"),
+    // Africa
+    city("Cairo", "Egypt", 22183, 30.04, 31.24, "Africa/Cairo"),
+    city("Alexandria", "Egypt", 5588, 31.20, 29.92, "Africa/Cairo"),
+    city("Casablanca", "Morocco", 3840, 33.57, -7.59, "Africa/Casablanca"),
+    city("Algiers", "Algeria", 2902, 36.75, 3.06, "Africa/Algiers"),
+    city("Tunis", "Tunisia", 2475, 36.81, 10.18, "Africa/Tunis"),
+    city("Tripoli", "Libya", 1176, 32.89, 13.19, "Africa/Tripoli"),
+    city("Khartoum", "Sudan", 6344, 15.50, 32.56, "Africa/Khartoum"),
+    city("Dakar", "Senegal", 3340, 14.72, -17.47, "Africa/Dakar"),
+    city("Abidjan", "Côte d'Ivoire", 5686, 5.36, -4.01, "Africa/Abidjan"),
+    city("Accra", "Ghana", 2660, 5.60, -0.19, "Africa/Accra"),
+    city("Lagos", "Nigeria", 15388, 6.52, 3.38, "Africa/Lagos"),
+    city("Abuja", "Nigeria", 3840, 9.08, 7.40, "Africa/Lagos"),
+    city("Kinshasa", "DR Congo", 16316, -4.44, 15.27, "Africa/Kinshasa"),
+    city("Luanda", "Angola", 9292, -8.84, 13.23, "Africa/Luanda"),
+    city("Addis Ababa", "Ethiopia", 5461, 9.03, 38.74, "Africa/Addis_Ababa"),
+    city("Nairobi", "Kenya", 5325, -1.29, 36.82, "Africa/Nairobi"),
+    city("Kampala", "Uganda", 3846, 0.35, 32.58, "Africa/Kampala"),
+    city("Dar es Salaam", "Tanzania", 7962, -6.79, 39.21, "Africa/Dar_es_Salaam"),
+    city("Harare", "Zimbabwe", 1578, -17.83, 31.05, "Africa/Harare"),
+    city("Maputo", "Mozambique", 1139, -25.97, 32.57, "Africa/Maputo"),
+    city("Johannesburg", "South Africa", 6198, -26.20, 28.05, "Africa/Johannesburg"),
+    city("Cape Town", "South Africa", 4890, -33.92, 18.42, "Africa/Johannesburg"),
+    city("Durban", "South Africa", 3228, -29.86, 31.02, "Africa/Johannesburg"),
+    // Middle East and the Caucasus
+    city("Dubai", "United Arab Emirates", 3604, 25.20, 55.27, "Asia/Dubai"),
+    city("Abu Dhabi", "United Arab Emirates", 1567, 24.45, 54.38, "Asia/Dubai"),
+    city("Muscat", "Oman", 1650, 23.59, 58.41, "Asia/Muscat"),
+    city("Doha", "Qatar", 1450, 25.29, 51.53, "Asia/Qatar"),
+    city("Manama", "Bahrain", 688, 26.23, 50.59, "Asia/Bahrain"),
+    city("Riyadh", "Saudi Arabia", 7682, 24.71, 46.68, "Asia/Riyadh"),
+    city("Jeddah", "Saudi Arabia", 4781, 21.49, 39.19, "Asia/Riyadh"),
+    city("Kuwait City", "Kuwait", 3298, 29.38, 47.98, "Asia/Kuwait"),
+    city("Baghdad", "Iraq", 7711, 33.31, 44.36, "Asia/Baghdad"),
+    city("Tehran", "Iran", 9500, 35.69, 51.39, "Asia/Tehran"),
+    city("Jerusalem", "Israel", 1000, 31.77, 35.21, "Asia/Jerusalem"),
+    city("Tel Aviv", "Israel", 4181, 32.09, 34.78, "Asia/Jerusalem"),
+    city("Amman", "Jordan", 2232, 31.95, 35.93, "Asia/Amman"),
+    city("Beirut", "Lebanon", 2421, 33.89, 35.50, "Asia/Beirut"),
+    city("Damascus", "Syria", 2503, 33.51, 36.29, "Asia/Damascus"),
+    city("Tbilisi", "Georgia", 1118, 41.72, 44.78, "Asia/Tbilisi"),
+    city("Yerevan", "Armenia", 1093, 40.18, 44.51, "Asia/Yerevan"),
+    city("Baku", "Azerbaijan", 2432, 40.41, 49.87, "Asia/Baku"),
+    // Central and South Asia
+    city("Kabul", "Afghanistan", 4458, 34.53, 69.17, "Asia/Kabul"),
+    city("Tashkent", "Uzbekistan", 2571, 41.30, 69.24, "Asia/Tashkent"),
+    city("Almaty", "Kazakhstan", 2000, 43.24, 76.89, "Asia/Almaty"),
+    city("Astana", "Kazakhstan", 1354, 51.17, 71.45, "Asia/Almaty"),
+    city("Yekaterinburg", "Russia", 1539, 56.84, 60.60, "Asia/Yekaterinburg"),
+    city("Novosibirsk", "Russia", 1634, 55.01, 82.93, "Asia/Novosibirsk"),
+    city("Vladivostok", "Russia", 603, 43.12, 131.89, "Asia/Vladivostok"),
+    city("Karachi", "Pakistan", 17236, 24.86, 67.01, "Asia/Karachi"),
+    city("Lahore", "Pakistan", 13979, 31.55, 74.34, "Asia/Karachi"),
+    city("Delhi", "India", 32941, 28.61, 77.21, "Asia/Kolkata"),
+    city("Mumbai", "India", 21297, 19.08, 72.88, "Asia/Kolkata"),
+    city("Kolkata", "India", 15134, 22.57, 88.36, "Asia/Kolkata"),
+    city("Bengaluru", "India", 13608, 12.97, 77.59, "Asia/Kolkata"),
+    city("Chennai", "India", 11776, 13.08, 80.27, "Asia/Kolkata"),
+    city("Hyderabad", "India", 10801, 17.39, 78.49, "Asia/Kolkata"),
+    city("Colombo", "Sri Lanka", 752, 6.93, 79.85, "Asia/Colombo"),
+    city("Kathmandu", "Nepal", 1521, 27.72, 85.32, "Asia/Kathmandu"),
+    city("Dhaka", "Bangladesh", 23210, 23.81, 90.41, "Asia/Dhaka"),
+    // Southeast Asia
+    city("Yangon", "Myanmar", 5610, 16.87, 96.20, "Asia/Yangon"),
+    city("Bangkok", "Thailand", 11234, 13.76, 100.50, "Asia/Bangkok"),
+    city("Hanoi", "Vietnam", 5253, 21.03, 105.85, "Asia/Bangkok"),
+    city("Ho Chi Minh City", "Vietnam", 9321, 10.82, 106.63, "Asia/Ho_Chi_Minh"),
+    city("Phnom Penh", "Cambodia", 2281, 11.56, 104.92, "Asia/Phnom_Penh"),
+    city("Vientiane", "Laos", 721, 17.98, 102.63, "Asia/Vientiane"),
+    city("Kuala Lumpur", "Malaysia", 8622, 3.139, 101.687, "Asia/Kuala_Lumpur"),
+    city("Singapore", "Singapore", 5918, 1.35, 103.82, "Asia/Singapore"),
+    city("Jakarta", "Indonesia", 11249, -6.21, 106.85, "Asia/Jakarta"),
+    city("Surabaya", "Indonesia", 3057, -7.25, 112.75, "Asia/Jakarta"),
+    city("Makassar", "Indonesia", 1571, -5.15, 119.43, "Asia/Makassar"),
+    city("Manila", "Philippines", 14667, 14.60, 120.98, "Asia/Manila"),
+    // East Asia
+    city("Shanghai", "China", 29211, 31.23, 121.47, "Asia/Shanghai"),
+    city("Beijing", "China", 21766, 39.90, 116.41, "Asia/Shanghai"),
+    city("Chongqing", "China", 17341, 29.56, 106.55, "Asia/Shanghai"),
+    city("Guangzhou", "China", 14284, 23.13, 113.26, "Asia/Shanghai"),
+    city("Shenzhen", "China", 13073, 22.54, 114.06, "Asia/Shanghai"),
+    city("Chengdu", "China", 9478, 30.57, 104.07, "Asia/Shanghai"),
+    city("Ürümqi", "China", 2045, 43.83, 87.62, "Asia/Urumqi"),
+    city("Hong Kong", "Hong Kong", 7685, 22.32, 114.17, "Asia/Hong_Kong"),
+    city("Macau", "Macau", 704, 22.20, 113.54, "Asia/Macau"),
+    city("Taipei", "Taiwan", 7048, 25.03, 121.57, "Asia/Taipei"),
+    city("Ulaanbaatar", "Mongolia", 1672, 47.89, 106.91, "Asia/Ulaanbaatar"),
+    city("Pyongyang", "North Korea", 3083, 39.04, 125.76, "Asia/Pyongyang"),
+    city("Seoul", "South Korea", 9976, 37.57, 126.98, "Asia/Seoul"),
+    city("Busan", "South Korea", 3453, 35.18, 129.08, "Asia/Seoul"),
+    city("Tokyo", "Japan", 37194, 35.68, 139.69, "Asia/Tokyo"),
+    city("Osaka", "Japan", 19013, 34.69, 135.50, "Asia/Tokyo"),
+    city("Nagoya", "Japan", 9522, 35.18, 136.91, "Asia/Tokyo"),
+    city("Sapporo", "Japan", 2665, 43.06, 141.35, "Asia/Tokyo"),
+    // Australia and the Pacific
+    city("Sydney", "Australia", 5297, -33.87, 151.21, "Australia/Sydney"),
+    city("Canberra", "Australia", 467, -35.28, 149.13, "Australia/Sydney"),
+    city("Melbourne", "Australia", 5207, -37.81, 144.96, "Australia/Melbourne"),
+    city("Brisbane", "Australia", 2706, -27.47, 153.03, "Australia/Brisbane"),
+    city("Perth", "Australia", 2310, -31.95, 115.86, "Australia/Perth"),
+    city("Adelaide", "Australia", 1432, -34.93, 138.60, "Australia/Adelaide"),
+    city("Hobart", "Australia", 253, -42.88, 147.33, "Australia/Hobart"),
+    city("Darwin", "Australia", 148, -12.46, 130.84, "Australia/Darwin"),
+    city("Auckland", "New Zealand", 1693, -36.85, 174.76, "Pacific/Auckland"),
+    city("Wellington", "New Zealand", 216, -41.29, 174.78, "Pacific/Auckland"),
+    city("Port Moresby", "Papua New Guinea", 410, -9.44, 147.18, "Pacific/Port_Moresby"),
+    city("Hagåtña", "Guam", 147, 13.47, 144.75, "Pacific/Guam"),
+    city("Nouméa", "New Caledonia", 182, -22.28, 166.46, "Pacific/Noumea"),
+    city("Suva", "Fiji", 185, -18.14, 178.44, "Pacific/Fiji"),
+    city("Apia", "Samoa", 37, -13.83, -171.76, "Pacific/Apia"),
+    city("Papeete", "French Polynesia", 137, -17.54, -149.57, "Pacific/Tahiti"),
+    // Atlantic and Indian Ocean islands
+    city("Hamilton", "Bermuda", 64, 32.29, -64.78, "Atlantic/Bermuda"),
+    city("Ponta Delgada", "Portugal", 68, 37.74, -25.67, "Atlantic/Azores"),
+    city("Las Palmas", "Spain", 379, 28.12, -15.43, "Atlantic/Canary"),
+    city("Praia", "Cape Verde", 159, 14.93, -23.51, "Atlantic/Cape_Verde"),
+    city("Antananarivo", "Madagascar", 3699, -18.88, 47.51, "Indian/Antananarivo"),
+    city("Port Louis", "Mauritius", 149, -20.16, 57.50, "Indian/Mauritius"),
+    city("Malé", "Maldives", 211, 4.18, 73.51, "Indian/Maldives"),
+];
+
+/// Cities keeping time in `tz`, largest first
+pub fn cities_in(tz: Tz) -> Vec<&'static City> {
+    let mut cities: Vec<&City> = CITIES.iter().filter(|c| c.zone_id == tz.name()).collect();
+    cities.sort_by_key(|c| std::cmp::Reverse(c.population));
+    cities
+}
+
+/// The city a zone is best known by: the one it's named after if the
+/// dataset has it, otherwise its largest city
+pub fn main_city(tz: Tz) -> Option<&'static City> {
+    let cities = cities_in(tz);
+    let namesake = fold_accents(&tz.name().rsplit('/').next()?.replace('_', " "));
+    cities
+        .iter()
+        .find(|c| fold_accents(c.name) == namesake)
+        .or(cities.first())
+        .copied()
+}
+
+/// Combined population of a zone's cities, in thousands
+pub fn zone_population(tz: Tz) -> u32 {
+    cities_in(tz).iter().map(|c| c.population).sum()
+}
+
+/// "Tokyo, Japan (Asia/Tokyo)", or the bare zone id for zones without a
+/// city in the dataset
+pub fn zone_display_name(tz: Tz) -> String {
+    match main_city(tz) {
+        Some(city) => format!("{} ({})", city.label(), tz.name()),
+        None => tz.name().to_string(),
+    }
+}
+
+/// The city nearest to `coords`, if any is within `max_km`
+pub fn nearest_city(coords: Coordinates, max_km: f64) -> Option<&'static City> {
+    CITIES
+        .iter()
+        .map(|c| (c, coords.distance_km(c.coordinates())))
+        .filter(|(_, d)| *d <= max_km)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(c, _)| c)
+}
+
+/// Lowercase, with the accents used in the dataset's names dropped, so
+/// "São Paulo" matches "Sao_Paulo"
+pub(crate) fn fold_accents(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            'ñ' => 'n',
+            c => c,
+        })
+        .collect()
+}
+
+/// Part of the world a zone belongs to, for grouping picker results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Region {
+    Africa,
+    Americas,
+    Antarctica,
+    Asia,
+    Atlantic,
+    AustraliaPacific,
+    Europe,
+    IndianOcean,
+    /// UTC, `Etc/` offsets and legacy abbreviations
+    Other,
+}
+
+impl Region {
+    /// Region from the zone id's area ("Asia/Tokyo" → Asia)
+    pub fn of(tz: Tz) -> Self {
+        let name = tz.name();
+        let area = match name.split_once('/') {
+            Some((area, _)) => area,
+            None => return Region::Other,
+        };
+        match area {
+            "Africa" => Region::Africa,
+            "America" | "US" | "Canada" | "Brazil" | "Chile" | "Mexico" => Region::Americas,
+            "Antarctica" => Region::Antarctica,
+            "Asia" => Region::Asia,
+            "Atlantic" => Region::Atlantic,
+            "Australia" | "Pacific" => Region::AustraliaPacific,
+            "Europe" | "Arctic" => Region::Europe,
+            "Indian" => Region::IndianOcean,
+            _ => Region::Other,
+        }
+    }
+
+    /// Name shown over the region's picker results
+    pub fn label(self) -> &'static str {
+        tr(match self {
+            Region::Africa => "Africa",
+            Region::Americas => "Americas",
+            Region::Antarctica => "Antarctica",
+            Region::Asia => "Asia",
+            Region::Atlantic => "Atlantic",
+            Region::AustraliaPacific => "Australia & Pacific",
+            Region::Europe => "Europe",
+            Region::IndianOcean => "Indian Ocean",
+            Region::Other => "Other zones",
+        })
+    }
+}
+
+/// `zones` with each region's zones together, keeping their order within a
+/// region; regions come in the order of their first zone, so the best search
+/// match stays on top
+pub fn order_by_region(zones: Vec<Tz>) -> Vec<Tz> {
+    let mut regions: Vec<Region> = Vec::new();
+    for &tz in &zones {
+        let region = Region::of(tz);
+        if !regions.contains(&region) {
+            regions.push(region);
+        }
+    }
+    let mut ordered = Vec::with_capacity(zones.len());
+    for region in regions {
+        ordered.extend(zones.iter().filter(|&&tz| Region::of(tz) == region));
+    }
+    ordered
+}
+
+/// The region heading to draw before `tz` in a list ordered by region,
+/// if `tz` starts a new region
+pub fn region_change(previous: Option<Tz>, tz: Tz) -> Option<Region> {
+    let region = Region::of(tz);
+    (previous.map(Region::of) != Some(region)).then_some(region)
+}
+
+/// Small heading over a region's zones in a picker list
+pub fn region_heading(ui: &mut egui::Ui, region: Region) {
+    ui.add_space(4.0);
+    ui.label(egui::RichText::new(region.label()).size(11.0).strong());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tz(id: &str) -> Tz {
+        id.parse().unwrap()
+    }
+
+    #[test]
+    fn test_dataset_is_consistent() {
+        for (index, city) in CITIES.iter().enumerate() {
+            assert!(city.timezone().is_some(), "{} has unknown zone {}", city.name, city.zone_id);
+            assert!(city.latitude.abs() <= 90.0 && city.longitude.abs() <= 180.0);
+            assert!(
+                CITIES[..index]
+                    .iter()
+                    .all(|other| (other.name, other.country) != (city.name, city.country)),
+                "duplicate city {}",
+                city.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_display_names() {
+        assert_eq!(zone_display_name(tz("Asia/Tokyo")), "Tokyo, Japan (Asia/Tokyo)");
+        // Named after a city that isn't its largest
+        assert_eq!(zone_display_name(tz("Asia/Kolkata")), "Kolkata, India (Asia/Kolkata)");
+        assert_eq!(
+            zone_display_name(tz("America/Sao_Paulo")),
+            "São Paulo, Brazil (America/Sao_Paulo)"
+        );
+        assert_eq!(zone_display_name(tz("Asia/Singapore")), "Singapore (Asia/Singapore)");
+        // No city for it: the bare id
+        assert_eq!(zone_display_name(tz("America/Araguaina")), "America/Araguaina");
+
+        assert_eq!(cities_in(tz("Asia/Kolkata"))[0].name, "Delhi");
+        assert!(zone_population(tz("Europe/Paris")) > zone_population(tz("Europe/Berlin")));
+    }
+
+    #[test]
+    fn test_nearest_city() {
+        let near_osaka = Coordinates::new(34.7, 135.4);
+        assert_eq!(nearest_city(near_osaka, 500.0).map(|c| c.name), Some("Osaka"));
+        // Mid-Pacific is far from everything
+        assert_eq!(nearest_city(Coordinates::new(0.0, -150.0), 1200.0), None);
+    }
+
+    #[test]
+    fn test_order_by_region() {
+        let ranked = vec![
+            tz("Europe/London"),
+            tz("America/New_York"),
+            tz("Europe/Paris"),
+            tz("UTC"),
+            tz("US/Eastern"),
+        ];
+        let ordered = order_by_region(ranked);
+        let names: Vec<&str> = ordered.iter().map(|tz| tz.name()).collect();
+        assert_eq!(
+            names,
+            ["Europe/London", "Europe/Paris", "America/New_York", "US/Eastern", "UTC"]
+        );
+
+        assert_eq!(region_change(None, ordered[0]), Some(Region::Europe));
+        assert_eq!(region_change(Some(ordered[0]), ordered[1]), None);
+        assert_eq!(region_change(Some(ordered[1]), ordered[2]), Some(Region::Americas));
+    }
+}
@@ -0,0 +1,255 @@
+//! Command-line options shared by every clock
+//!
+//! Each clock accepts `--snapshot <path> [--at <RFC3339>]`, which renders a
+//! single frame into a hidden window, saves it as a PNG and exits. `--at`
+//! pins the clock's notion of "now" so snapshots are reproducible.
+//!
+//! `--monitor <N>` opens the window on the Nth monitor (counting from 1) and
+//! `--fullscreen` makes it fill its monitor, for kiosk displays.
+//!
+//! Arguments a parser doesn't recognise are ignored, so the launcher's own
+//! `--clock <id>` can sit in front of them.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Utc};
+
+/// Frames rendered before capturing, so egui layout and fonts have settled
+pub const SNAPSHOT_SETTLE_FRAMES: u32 = 3;
+
+/// A request to render one frame to disk and exit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotRequest {
+    /// Where to write the PNG
+    pub path: PathBuf,
+    /// Instant to render instead of the current time
+    pub at: Option<DateTime<Utc>>,
+}
+
+impl SnapshotRequest {
+    /// Create the directory the snapshot will be written into
+    pub fn ensure_parent_dir(&self) -> io::Result<()> {
+        match self.path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => fs::create_dir_all(parent),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Parse `--snapshot <path>` and `--at <RFC3339>` out of an argument list
+///
+/// Returns `Ok(None)` when no snapshot was requested.
+pub fn parse_snapshot_args<I, S>(args: I) -> Result<Option<SnapshotRequest>, String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut path = None;
+    let mut at = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_ref() {
+            "--snapshot" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "--snapshot needs an output path".to_string())?;
+                path = Some(PathBuf::from(value.as_ref()));
+            }
+            "--at" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "--at needs an RFC 3339 timestamp".to_string())?;
+                let instant = DateTime::parse_from_rfc3339(value.as_ref())
+                    .map_err(|e| format!("Invalid --at timestamp {}: {}", value.as_ref(), e))?;
+                at = Some(instant.with_timezone(&Utc));
+            }
+            _ => {}
+        }
+    }
+
+    match (path, at) {
+        (Some(path), at) => Ok(Some(SnapshotRequest { path, at })),
+        (None, Some(_)) => Err("--at is only supported together with --snapshot".to_string()),
+        (None, None) => Ok(None),
+    }
+}
+
+/// The snapshot requested on this process's command line, if any
+///
+/// Parsed once on first use. Invalid arguments print an error and exit.
+pub fn snapshot_request() -> Option<&'static SnapshotRequest> {
+    static REQUEST: OnceLock<Option<SnapshotRequest>> = OnceLock::new();
+    REQUEST
+        .get_or_init(|| {
+            let request = match parse_snapshot_args(std::env::args().skip(1)) {
+                Ok(request) => request,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    eprintln!("Usage: --snapshot <path.png> [--at <RFC3339>]");
+                    std::process::exit(2);
+                }
+            };
+            if let Some(request) = &request {
+                if let Err(e) = request.ensure_parent_dir() {
+                    eprintln!("Could not create {}: {}", request.path.display(), e);
+                    std::process::exit(2);
+                }
+            }
+            request
+        })
+        .as_ref()
+}
+
+/// The current instant, or the instant pinned with `--at`
+pub fn now_utc() -> DateTime<Utc> {
+    snapshot_request()
+        .and_then(|request| request.at)
+        .unwrap_or_else(Utc::now)
+}
+
+/// Where a clock's window should open, overriding its remembered place
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PlacementRequest {
+    /// Monitor to open on, counting from 1
+    pub monitor: Option<usize>,
+    /// Fill the monitor
+    pub fullscreen: bool,
+}
+
+/// Parse `--monitor <N>` and `--fullscreen` out of an argument list
+pub fn parse_placement_args<I, S>(args: I) -> Result<PlacementRequest, String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut request = PlacementRequest::default();
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_ref() {
+            "--monitor" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "--monitor needs a monitor number".to_string())?;
+                let monitor = value
+                    .as_ref()
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&n| n >= 1)
+                    .ok_or_else(|| format!("Invalid --monitor {}: monitors count from 1", value.as_ref()))?;
+                request.monitor = Some(monitor);
+            }
+            "--fullscreen" => request.fullscreen = true,
+            _ => {}
+        }
+    }
+    Ok(request)
+}
+
+/// The window placement requested on this process's command line
+///
+/// Parsed once on first use. Invalid arguments print an error and exit.
+pub fn placement_request() -> PlacementRequest {
+    static REQUEST: OnceLock<PlacementRequest> = OnceLock::new();
+    *REQUEST.get_or_init(|| match parse_placement_args(std::env::args().skip(1)) {
+        Ok(request) => request,
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("Usage: [--monitor <N>] [--fullscreen]");
+            std::process::exit(2);
+        }
+    })
+}
+
+/// What a clock should do on this frame of a snapshot run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotStep {
+    /// Keep rendering normally
+    Settle,
+    /// Capture the frame about to be drawn
+    Capture,
+    /// Wait for the capture to be written and quit
+    Finish,
+}
+
+/// Frame counter driving a snapshot run
+#[derive(Debug, Default)]
+pub struct SnapshotProgress {
+    frames: u32,
+}
+
+impl SnapshotProgress {
+    /// Advance by one frame and return what to do on it
+    pub fn advance(&mut self) -> SnapshotStep {
+        self.frames = self.frames.saturating_add(1);
+        match self.frames {
+            n if n <= SNAPSHOT_SETTLE_FRAMES => SnapshotStep::Settle,
+            n if n == SNAPSHOT_SETTLE_FRAMES + 1 => SnapshotStep::Capture,
+            _ => SnapshotStep::Finish,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_parse_snapshot_args() {
+        assert_eq!(parse_snapshot_args(Vec::<String>::new()), Ok(None));
+        assert_eq!(parse_snapshot_args(["--clock", "ritual_clock"]), Ok(None));
+
+        let request = parse_snapshot_args(["--clock", "x", "--snapshot", "out/a.png"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(request.path, PathBuf::from("out/a.png"));
+        assert_eq!(request.at, None);
+
+        let request = parse_snapshot_args(["--at", "2024-03-10T09:30:00+01:00", "--snapshot", "a.png"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(request.at, Some(Utc.with_ymd_and_hms(2024, 3, 10, 8, 30, 0).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_snapshot_args_errors() {
+        assert!(parse_snapshot_args(["--snapshot"]).is_err());
+        assert!(parse_snapshot_args(["--snapshot", "a.png", "--at"]).is_err());
+        assert!(parse_snapshot_args(["--snapshot", "a.png", "--at", "yesterday"]).is_err());
+        assert!(parse_snapshot_args(["--at", "2024-03-10T09:30:00Z"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_placement_args() {
+        assert_eq!(
+            parse_placement_args(["--clock", "flipboard"]),
+            Ok(PlacementRequest::default())
+        );
+        assert_eq!(
+            parse_placement_args(["--clock", "flipboard", "--monitor", "2", "--fullscreen"]),
+            Ok(PlacementRequest {
+                monitor: Some(2),
+                fullscreen: true,
+            })
+        );
+        assert!(parse_placement_args(["--monitor"]).is_err());
+        assert!(parse_placement_args(["--monitor", "0"]).is_err());
+        assert!(parse_placement_args(["--monitor", "left"]).is_err());
+    }
+
+    #[test]
+    fn test_snapshot_progress() {
+        let mut progress = SnapshotProgress::default();
+        for _ in 0..SNAPSHOT_SETTLE_FRAMES {
+            assert_eq!(progress.advance(), SnapshotStep::Settle);
+        }
+        assert_eq!(progress.advance(), SnapshotStep::Capture);
+        assert_eq!(progress.advance(), SnapshotStep::Finish);
+        assert_eq!(progress.advance(), SnapshotStep::Finish);
+    }
+}
@@ -0,0 +1,57 @@
+//! Clock app module - common entry point for launching clocks
+//!
+//! Every clock crate exposes a unit struct implementing `ClockApp` so the
+//! launcher can list and start clocks without knowing their internals.
+
+/// A clock that can be listed in the gallery and launched
+pub trait ClockApp: Sync {
+    /// Stable identifier; matches the clock's config file name
+    fn id(&self) -> &'static str;
+
+    /// Display name shown in the gallery
+    fn title(&self) -> &'static str;
+
+    /// One-line description shown under the title
+    fn tagline(&self) -> &'static str;
+
+    /// Run the clock's event loop
+    ///
+    /// Does not return in practice: the windowing event loop exits the
+    /// process when the clock's window is closed.
+    fn run(&self);
+}
+
+/// Find a clock by id in a list of clocks
+pub fn find_clock<'a>(clocks: &[&'a dyn ClockApp], id: &str) -> Option<&'a dyn ClockApp> {
+    clocks.iter().copied().find(|clock| clock.id() == id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestClock;
+
+    impl ClockApp for TestClock {
+        fn id(&self) -> &'static str {
+            "test_clock"
+        }
+
+        fn title(&self) -> &'static str {
+            "Test Clock"
+        }
+
+        fn tagline(&self) -> &'static str {
+            "For tests"
+        }
+
+        fn run(&self) {}
+    }
+
+    #[test]
+    fn test_find_clock() {
+        let clocks: [&dyn ClockApp; 1] = [&TestClock];
+        assert_eq!(find_clock(&clocks, "test_clock").map(|c| c.title()), Some("Test Clock"));
+        assert!(find_clock(&clocks, "missing").is_none());
+    }
+}
@@ -0,0 +1,416 @@
+//! Command palette module - searchable list of a clock's actions (Ctrl+K)
+//!
+//! Each clock registers its actions in a `CommandRegistry` as a name plus the
+//! key chord that performs it. The palette lists them, filtered by a search
+//! query, and hands the chosen chord back to the clock, which replays it
+//! through its own key handler. Showing the chord next to each name is what
+//! teaches the single-key shortcuts; it is the user's binding when the
+//! action has been remapped (see `keymap`).
+
+use nannou_egui::egui;
+use winit::event::{ModifiersState, VirtualKeyCode};
+
+use crate::i18n::tr;
+
+/// A key plus the modifiers held with it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyChord {
+    pub key: VirtualKeyCode,
+    pub mods: ModifiersState,
+}
+
+impl KeyChord {
+    pub const fn new(key: VirtualKeyCode) -> Self {
+        Self {
+            key,
+            mods: ModifiersState::empty(),
+        }
+    }
+
+    pub const fn shift(key: VirtualKeyCode) -> Self {
+        Self {
+            key,
+            mods: ModifiersState::SHIFT,
+        }
+    }
+
+    pub const fn ctrl(key: VirtualKeyCode) -> Self {
+        Self {
+            key,
+            mods: ModifiersState::CTRL,
+        }
+    }
+
+    pub const fn ctrl_shift(key: VirtualKeyCode) -> Self {
+        Self {
+            key,
+            mods: ModifiersState::CTRL.union(ModifiersState::SHIFT),
+        }
+    }
+
+    /// Whether `mods` hold Ctrl (or Cmd on macOS) and `key` is K
+    pub fn is_palette_toggle(key: VirtualKeyCode, mods: ModifiersState) -> bool {
+        key == VirtualKeyCode::K && (mods.ctrl() || mods.logo())
+    }
+
+    /// The chord as shown to the user: "Ctrl+Shift+←"
+    pub fn label(&self) -> String {
+        let mut label = String::new();
+        if self.mods.ctrl() {
+            label.push_str("Ctrl+");
+        }
+        if self.mods.alt() {
+            label.push_str("Alt+");
+        }
+        if self.mods.shift() {
+            label.push_str("Shift+");
+        }
+        label.push_str(&key_name(self.key));
+        label
+    }
+}
+
+/// How `key` is written in chord labels
+pub(crate) fn key_name(key: VirtualKeyCode) -> String {
+    use VirtualKeyCode as K;
+    let name = match key {
+        K::Key0 => "0",
+        K::Key1 => "1",
+        K::Key2 => "2",
+        K::Key3 => "3",
+        K::Key4 => "4",
+        K::Key5 => "5",
+        K::Key6 => "6",
+        K::Key7 => "7",
+        K::Key8 => "8",
+        K::Key9 => "9",
+        K::Left => "←",
+        K::Right => "→",
+        K::Up => "↑",
+        K::Down => "↓",
+        K::Return => "Enter",
+        K::Back => "Backspace",
+        K::Escape => "Esc",
+        K::Slash => "/",
+        K::Equals => "=",
+        K::Plus => "+",
+        K::Minus => "-",
+        K::Comma => ",",
+        K::Period => ".",
+        K::LBracket => "[",
+        K::RBracket => "]",
+        K::Apostrophe => "'",
+        K::Semicolon => ";",
+        K::Grave => "`",
+        K::PageUp => "Page Up",
+        K::PageDown => "Page Down",
+        _ => return format!("{:?}", key),
+    };
+    name.to_string()
+}
+
+/// A named action in the palette
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Command {
+    pub name: &'static str,
+    /// The chord the clock's key handler performs the action for
+    pub chord: KeyChord,
+    /// The chord the user presses for it, the same unless remapped
+    pub binding: KeyChord,
+}
+
+/// Every action a clock supports, in the order they are listed
+#[derive(Debug, Clone, Default)]
+pub struct CommandRegistry {
+    commands: Vec<Command>,
+}
+
+impl CommandRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a command performed by `chord`
+    pub fn register(mut self, name: &'static str, chord: KeyChord) -> Self {
+        self.commands.push(Command {
+            name,
+            chord,
+            binding: chord,
+        });
+        self
+    }
+
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    /// The command called `name`
+    pub fn find(&self, name: &str) -> Option<&Command> {
+        self.commands.iter().find(|command| command.name == name)
+    }
+
+    /// The chord the user presses for `name`, as shown to them
+    pub fn key_label(&self, name: &str) -> String {
+        self.find(name).map(|command| command.binding.label()).unwrap_or_default()
+    }
+
+    /// A line of key hints, "Z: Zone  |  F: Favorite", pairing the current
+    /// binding of each named command with a caption, translated here
+    ///
+    /// Commands that aren't registered are left out.
+    pub fn hint_line(&self, hints: &[(&str, &'static str)]) -> String {
+        hints
+            .iter()
+            .filter_map(|&(name, caption)| {
+                let command = self.find(name)?;
+                Some(format!("{}: {}", command.binding.label(), tr(caption)))
+            })
+            .collect::<Vec<_>>()
+            .join("  |  ")
+    }
+
+    /// Have `name` pressed with `binding`; false if there is no such command
+    pub fn bind(&mut self, name: &str, binding: KeyChord) -> bool {
+        match self.commands.iter_mut().find(|command| command.name == name) {
+            Some(command) => {
+                command.binding = binding;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Put every command back on its registered chord
+    pub fn reset_bindings(&mut self) {
+        for command in &mut self.commands {
+            command.binding = command.chord;
+        }
+    }
+
+    /// Commands matching `query`, best matches first
+    ///
+    /// A name starting with the query ranks above one with a word starting
+    /// with it, then one containing it, then one containing its letters in
+    /// order. Ties keep registration order.
+    pub fn search(&self, query: &str) -> Vec<&Command> {
+        let query = query.trim().to_lowercase();
+        let mut ranked: Vec<(u8, &Command)> = self
+            .commands
+            .iter()
+            .filter_map(|command| {
+                match_rank(&tr(command.name).to_lowercase(), &query).map(|rank| (rank, command))
+            })
+            .collect();
+        ranked.sort_by_key(|&(rank, _)| rank);
+        ranked.into_iter().map(|(_, command)| command).collect()
+    }
+}
+
+/// Lower is better; `None` when `name` does not match at all
+fn match_rank(name: &str, query: &str) -> Option<u8> {
+    if name.starts_with(query) {
+        Some(0)
+    } else if name.split(' ').any(|word| word.starts_with(query)) {
+        Some(1)
+    } else if name.contains(query) {
+        Some(2)
+    } else {
+        let mut letters = name.chars();
+        query
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .all(|c| letters.any(|n| n == c))
+            .then_some(3)
+    }
+}
+
+/// State for the command palette overlay
+#[derive(Default)]
+pub struct CommandPalette {
+    /// Whether the palette is currently open
+    pub is_open: bool,
+    /// Current search query
+    query: String,
+    /// Index of the highlighted result
+    selected: usize,
+    /// Whether the search field should be focused
+    should_focus_search: bool,
+}
+
+impl CommandPalette {
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.query.clear();
+        self.selected = 0;
+        self.should_focus_search = true;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.query.clear();
+    }
+
+    pub fn toggle(&mut self) {
+        if self.is_open {
+            self.close();
+        } else {
+            self.open();
+        }
+    }
+
+    /// Draw the palette; returns the chord of the chosen command
+    ///
+    /// ↑/↓ move the highlight, Enter runs it and Esc closes the palette.
+    pub fn show(&mut self, ctx: &egui::Context, registry: &CommandRegistry) -> Option<KeyChord> {
+        if !self.is_open {
+            return None;
+        }
+
+        let results = registry.search(&self.query);
+        let (up, down, enter, escape) = ctx.input(|i| {
+            (
+                i.key_pressed(egui::Key::ArrowUp),
+                i.key_pressed(egui::Key::ArrowDown),
+                i.key_pressed(egui::Key::Enter),
+                i.key_pressed(egui::Key::Escape),
+            )
+        });
+        if !results.is_empty() {
+            let len = results.len();
+            if up {
+                self.selected = (self.selected + len - 1) % len;
+            }
+            if down {
+                self.selected = (self.selected + 1) % len;
+            }
+        }
+        self.selected = self.selected.min(results.len().saturating_sub(1));
+
+        let mut chosen = if enter {
+            results.get(self.selected).map(|command| command.chord)
+        } else {
+            None
+        };
+        let mut close = escape;
+
+        egui::Window::new("Commands")
+            .collapsible(false)
+            .resizable(false)
+            .title_bar(false)
+            .default_width(380.0)
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 80.0])
+            .show(ctx, |ui| {
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut self.query)
+                        .hint_text(tr("Type a command…"))
+                        .desired_width(f32::INFINITY),
+                );
+                if self.should_focus_search {
+                    response.request_focus();
+                    self.should_focus_search = false;
+                }
+                if response.changed() {
+                    self.selected = 0;
+                }
+
+                ui.separator();
+
+                egui::ScrollArea::vertical()
+                    .max_height(320.0)
+                    .show(ui, |ui| {
+                        if results.is_empty() {
+                            ui.weak(tr("No matching commands"));
+                        }
+                        for (index, command) in results.iter().enumerate() {
+                            let is_selected = index == self.selected;
+                            let row = ui.horizontal(|ui| {
+                                let label = ui.selectable_label(is_selected, tr(command.name));
+                                ui.with_layout(
+                                    egui::Layout::right_to_left(egui::Align::Center),
+                                    |ui| ui.weak(command.binding.label()),
+                                );
+                                label
+                            });
+                            if is_selected && (up || down) {
+                                row.inner.scroll_to_me(Some(egui::Align::Center));
+                            }
+                            if row.inner.clicked() {
+                                chosen = Some(command.chord);
+                            }
+                        }
+                    });
+
+                ui.separator();
+                ui.label(tr("↑↓ Navigate · Enter Run · Esc Close"));
+            });
+
+        if chosen.is_some() {
+            close = true;
+        }
+        if close {
+            self.close();
+        }
+        chosen
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> CommandRegistry {
+        CommandRegistry::new()
+            .register("Open timezone picker", KeyChord::new(VirtualKeyCode::Slash))
+            .register("Toggle reduced motion", KeyChord::new(VirtualKeyCode::R))
+            .register("Step back one hour", KeyChord::shift(VirtualKeyCode::Left))
+            .register("Export ledger", KeyChord::ctrl(VirtualKeyCode::E))
+    }
+
+    fn names(results: Vec<&Command>) -> Vec<&'static str> {
+        results.into_iter().map(|command| command.name).collect()
+    }
+
+    #[test]
+    fn test_search_ranks_matches() {
+        let registry = registry();
+        assert_eq!(registry.search("").len(), 4);
+        assert_eq!(
+            names(registry.search("t")),
+            ["Toggle reduced motion", "Open timezone picker", "Step back one hour", "Export ledger"]
+        );
+        assert_eq!(names(registry.search("MOTION")), ["Toggle reduced motion"]);
+        assert_eq!(names(registry.search("ex led")), ["Export ledger"]);
+        assert!(registry.search("zzz").is_empty());
+    }
+
+    #[test]
+    fn test_chord_labels() {
+        assert_eq!(KeyChord::new(VirtualKeyCode::Slash).label(), "/");
+        assert_eq!(KeyChord::shift(VirtualKeyCode::Left).label(), "Shift+←");
+        assert_eq!(KeyChord::ctrl(VirtualKeyCode::E).label(), "Ctrl+E");
+        assert_eq!(KeyChord::new(VirtualKeyCode::Key3).label(), "3");
+    }
+
+    #[test]
+    fn test_hint_line_follows_bindings() {
+        let mut registry = registry();
+        let hints = [
+            ("Open timezone picker", "Zone"),
+            ("Not registered", "Ignored"),
+            ("Toggle reduced motion", "Motion"),
+        ];
+        assert_eq!(registry.hint_line(&hints), "/: Zone  |  R: Motion");
+
+        registry.bind("Open timezone picker", KeyChord::shift(VirtualKeyCode::Z));
+        assert_eq!(registry.hint_line(&hints), "Shift+Z: Zone  |  R: Motion");
+        assert_eq!(registry.key_label("Open timezone picker"), "Shift+Z");
+        assert_eq!(registry.key_label("Not registered"), "");
+    }
+
+    #[test]
+    fn test_palette_toggle_chord() {
+        assert!(KeyChord::is_palette_toggle(VirtualKeyCode::K, ModifiersState::CTRL));
+        assert!(KeyChord::is_palette_toggle(VirtualKeyCode::K, ModifiersState::LOGO));
+        assert!(!KeyChord::is_palette_toggle(VirtualKeyCode::K, ModifiersState::empty()));
+    }
+}
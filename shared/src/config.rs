@@ -1,12 +1,39 @@
 //! Configuration persistence utilities
 //!
 //! Provides functions for loading and saving clock configuration to disk.
+//!
+//! Configs are stored in a versioned envelope:
+//!
+//! ```toml
+//! version = 2
+//!
+//! [payload]
+//! selected_tz_id = "Europe/London"
+//! ```
+//!
+//! Files written before versioning (a bare payload) are treated as version 0.
+//! On load, each clock's `VersionedConfig::migrate` hook upgrades the payload
+//! one version at a time before it is deserialized. A file that still can't be
+//! read is copied aside rather than being overwritten by defaults on next save.
+//!
+//! Saves are atomic: the new contents go to a temporary file that is then
+//! renamed over the config, so a crash mid-write leaves the old file whole.
+//! Each successful load also keeps a backup generation, a copy of the config
+//! as it last loaded cleanly, which a clock can offer to restore when the
+//! config itself turns out to be unreadable.
+//!
+//! Secrets such as signing keys don't go through any of that: they live in
+//! their own `.key` files, readable only by the user, and are never copied.
 
-use directories::ProjectDirs;
-use serde::{de::DeserializeOwned, Serialize};
+use directories::{ProjectDirs, UserDirs};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::fs;
-use std::io;
-use std::path::PathBuf;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use toml::Table;
+
+use crate::i18n::{tr, tr_args};
 
 /// Error type for configuration operations
 #[derive(Debug)]
@@ -19,6 +46,11 @@ pub enum ConfigError {
     Parse(toml::de::Error),
     /// Failed to serialize config
     Serialize(toml::ser::Error),
+    /// Config file was unreadable and has been copied to `backup`
+    Corrupt {
+        error: toml::de::Error,
+        backup: PathBuf,
+    },
 }
 
 impl std::fmt::Display for ConfigError {
@@ -28,6 +60,12 @@ impl std::fmt::Display for ConfigError {
             ConfigError::Io(e) => write!(f, "IO error: {}", e),
             ConfigError::Parse(e) => write!(f, "Parse error: {}", e),
             ConfigError::Serialize(e) => write!(f, "Serialize error: {}", e),
+            ConfigError::Corrupt { error, backup } => write!(
+                f,
+                "Unreadable config backed up to {}: {}",
+                backup.display(),
+                error
+            ),
         }
     }
 }
@@ -52,6 +90,68 @@ impl From<toml::ser::Error> for ConfigError {
     }
 }
 
+/// A clock config that is saved with a schema version
+///
+/// Bump `VERSION` whenever the struct changes in a way `#[serde(default)]`
+/// can't absorb (a renamed, retyped or newly required field), and teach
+/// `migrate` how to upgrade the previous version's payload.
+pub trait VersionedConfig: Serialize + DeserializeOwned {
+    /// Schema version written to disk
+    const VERSION: u32;
+
+    /// Upgrade a payload from `from_version` to `from_version + 1`
+    ///
+    /// Called once per step, oldest first. Unversioned files start at 0.
+    /// The default does nothing, which suits purely additive changes.
+    fn migrate(from_version: u32, payload: &mut Table) {
+        let _ = (from_version, payload);
+    }
+}
+
+/// On-disk wrapper around a config payload
+#[derive(Serialize, Deserialize)]
+struct Envelope<P> {
+    version: u32,
+    payload: P,
+}
+
+/// Serialize a config into its versioned envelope
+pub fn encode_config<T: VersionedConfig>(config: &T) -> Result<String, ConfigError> {
+    let envelope = Envelope {
+        version: T::VERSION,
+        payload: config,
+    };
+    Ok(toml::to_string_pretty(&envelope)?)
+}
+
+/// Parse a config file's contents, migrating older versions forward
+///
+/// Payloads from a newer version are deserialized as-is; unknown fields are
+/// ignored, so a downgrade keeps whatever the older struct understands.
+pub fn decode_config<T: VersionedConfig>(contents: &str) -> Result<T, ConfigError> {
+    let mut table: Table = toml::from_str(contents)?;
+
+    let version = table.get("version").and_then(toml::Value::as_integer);
+    let (version, mut payload) = match (version, table.remove("payload")) {
+        (Some(version), Some(toml::Value::Table(payload))) => {
+            (u32::try_from(version).unwrap_or(0), payload)
+        }
+        // Written before versioning: the whole file is the payload
+        (_, payload) => {
+            if let Some(payload) = payload {
+                table.insert("payload".to_string(), payload);
+            }
+            (0, table)
+        }
+    };
+
+    for from_version in version..T::VERSION {
+        T::migrate(from_version, &mut payload);
+    }
+
+    Ok(toml::Value::Table(payload).try_into()?)
+}
+
 /// Get the base configuration directory for all clocks
 pub fn config_dir() -> Option<PathBuf> {
     ProjectDirs::from("com", "clock-series", "clocks")
@@ -63,11 +163,112 @@ pub fn config_path(clock_name: &str) -> Option<PathBuf> {
     config_dir().map(|dir| dir.join(format!("{}.toml", clock_name)))
 }
 
+/// Get the directory exported files (SVG, CSV, ...) are written to
+///
+/// Prefers `<Documents>/clock-series`, falling back to the app data directory.
+pub fn export_dir() -> Option<PathBuf> {
+    UserDirs::new()
+        .and_then(|dirs| dirs.document_dir().map(|dir| dir.join("clock-series")))
+        .or_else(|| {
+            ProjectDirs::from("com", "clock-series", "clocks")
+                .map(|dirs| dirs.data_dir().join("exports"))
+        })
+}
+
+/// Get the path for an exported file, named `<clock_name>_<label>.<extension>`
+pub fn export_path(clock_name: &str, label: &str, extension: &str) -> Option<PathBuf> {
+    export_dir().map(|dir| dir.join(format!("{}_{}.{}", clock_name, label, extension)))
+}
+
+/// Write an exported file, returning the path it was written to
+pub fn write_export(
+    clock_name: &str,
+    label: &str,
+    extension: &str,
+    contents: &[u8],
+) -> Result<PathBuf, ConfigError> {
+    let dir = export_dir().ok_or(ConfigError::NoConfigDir)?;
+    write_export_to(&dir, clock_name, label, extension, contents)
+}
+
+/// Write an exported file into a chosen directory instead of `export_dir`
+pub fn write_export_to(
+    dir: &Path,
+    clock_name: &str,
+    label: &str,
+    extension: &str,
+    contents: &[u8],
+) -> Result<PathBuf, ConfigError> {
+    fs::create_dir_all(dir)?;
+
+    let path = dir.join(format!("{}_{}.{}", clock_name, label, extension));
+    fs::write(&path, contents)?;
+    Ok(path)
+}
+
+/// Get the path an unreadable config is copied to, stamped with `unix_secs`
+pub fn backup_path(clock_name: &str, unix_secs: u64) -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(format!("{}.corrupt-{}.toml", clock_name, unix_secs)))
+}
+
+/// Get the path of a config's backup generation: the config as it last
+/// loaded cleanly
+pub fn backup_generation_path(clock_name: &str) -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(format!("{}.backup.toml", clock_name)))
+}
+
+/// Get the path of a secret, e.g. a signing key
+pub fn secret_path(name: &str) -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(format!("{}.key", name)))
+}
+
+/// Write `contents` to `path` atomically
+///
+/// The contents are written and synced to a temporary file beside `path`,
+/// which is then renamed over it; readers see the old file or the new one,
+/// never a partial write.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    write_atomic_with(path, contents, false)
+}
+
+/// `write_atomic`, but the file is readable and writable only by its owner
+/// (mode 0600 on Unix)
+pub fn write_private(path: &Path, contents: &[u8]) -> io::Result<()> {
+    write_atomic_with(path, contents, true)
+}
+
+#[cfg_attr(not(unix), allow(unused_variables))]
+fn write_atomic_with(path: &Path, contents: &[u8], private: bool) -> io::Result<()> {
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp = path.with_file_name(temp_name);
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    if private {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&temp)?;
+    // The mode only applies to a new file; tighten one left by a crash too
+    #[cfg(unix)]
+    if private {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    }
+    file.write_all(contents)?;
+    file.sync_all()?;
+    drop(file);
+    fs::rename(&temp, path)
+}
+
 /// Load configuration for a specific clock
 ///
 /// Returns `None` if the config file doesn't exist yet.
-/// Returns an error if the file exists but can't be parsed.
-pub fn load_config<T: DeserializeOwned>(clock_name: &str) -> Result<Option<T>, ConfigError> {
+/// Returns an error if the file exists but can't be parsed; in that case the
+/// file is first copied to `backup_path` so the next save can't lose it.
+pub fn load_config<T: VersionedConfig>(clock_name: &str) -> Result<Option<T>, ConfigError> {
     let path = config_path(clock_name).ok_or(ConfigError::NoConfigDir)?;
     
     if !path.exists() {
@@ -75,12 +276,53 @@ pub fn load_config<T: DeserializeOwned>(clock_name: &str) -> Result<Option<T>, C
     }
     
     let contents = fs::read_to_string(&path)?;
-    let config: T = toml::from_str(&contents)?;
-    Ok(Some(config))
+    match decode_config(&contents) {
+        Ok(config) => {
+            // Keep this clean load as the backup generation. A failed copy
+            // only costs the backup, so it doesn't fail the load.
+            if let Some(backup) = backup_generation_path(clock_name) {
+                if let Err(e) = write_atomic(&backup, contents.as_bytes()) {
+                    eprintln!("Failed to back up {} config: {}", clock_name, e);
+                }
+            }
+            Ok(Some(config))
+        }
+        Err(ConfigError::Parse(error)) => {
+            let unix_secs = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            let backup = backup_path(clock_name, unix_secs).ok_or(ConfigError::NoConfigDir)?;
+            fs::copy(&path, &backup)?;
+            Err(ConfigError::Corrupt { error, backup })
+        }
+        Err(e) => Err(e),
+    }
 }
 
 /// Save configuration for a specific clock
-pub fn save_config<T: Serialize>(clock_name: &str, config: &T) -> Result<(), ConfigError> {
+pub fn save_config<T: VersionedConfig>(clock_name: &str, config: &T) -> Result<(), ConfigError> {
+    save_encoded_config(clock_name, &encode_config(config)?)
+}
+
+/// Report a shared setting that couldn't be saved: on stderr, and to the user
+/// through `toast`
+///
+/// `what` names the setting, e.g. "quiet hours". Pass the result of its
+/// `save`, so the sink can borrow the clock mutably.
+pub fn report_save(
+    what: &'static str,
+    result: Result<(), ConfigError>,
+    toast: impl FnOnce(String),
+) {
+    if let Err(e) = result {
+        eprintln!("Failed to save {}: {}", what, e);
+        toast(tr_args("Failed to save {0}: {1}", &[&tr(what), &e]));
+    }
+}
+
+/// Save a config already encoded with `encode_config`
+pub fn save_encoded_config(clock_name: &str, contents: &str) -> Result<(), ConfigError> {
     let path = config_path(clock_name).ok_or(ConfigError::NoConfigDir)?;
     
     // Ensure parent directory exists
@@ -88,11 +330,24 @@ pub fn save_config<T: Serialize>(clock_name: &str, config: &T) -> Result<(), Con
         fs::create_dir_all(parent)?;
     }
     
-    let contents = toml::to_string_pretty(config)?;
-    fs::write(&path, contents)?;
+    write_atomic(&path, contents.as_bytes())?;
     Ok(())
 }
 
+/// Whether a clock has a backup generation that can be restored
+pub fn has_config_backup<T: VersionedConfig>(clock_name: &str) -> bool {
+    backup_generation_path(clock_name)
+        .and_then(|path| fs::read_to_string(path).ok())
+        .is_some_and(|contents| decode_config::<T>(&contents).is_ok())
+}
+
+/// Put a clock's backup generation back in place of its config
+pub fn restore_config_backup(clock_name: &str) -> Result<(), ConfigError> {
+    let backup = backup_generation_path(clock_name).ok_or(ConfigError::NoConfigDir)?;
+    let contents = fs::read_to_string(backup)?;
+    save_encoded_config(clock_name, &contents)
+}
+
 /// Delete configuration for a specific clock
 pub fn delete_config(clock_name: &str) -> Result<(), ConfigError> {
     let path = config_path(clock_name).ok_or(ConfigError::NoConfigDir)?;
@@ -103,6 +358,51 @@ pub fn delete_config(clock_name: &str) -> Result<(), ConfigError> {
     Ok(())
 }
 
+/// Delete a config along with its backup generation and every copy of it
+/// set aside as unreadable
+pub fn purge_config(clock_name: &str) -> Result<(), ConfigError> {
+    delete_config(clock_name)?;
+    let dir = config_dir().ok_or(ConfigError::NoConfigDir)?;
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Ok(());
+    };
+    let backup = format!("{}.backup.toml", clock_name);
+    let corrupt = format!("{}.corrupt-", clock_name);
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name == backup || (name.starts_with(&corrupt) && name.ends_with(".toml")) {
+            fs::remove_file(entry.path())?;
+        }
+    }
+    Ok(())
+}
+
+/// Load a secret saved with `save_secret`
+///
+/// Returns `None` if none has been saved yet.
+pub fn load_secret(name: &str) -> Result<Option<String>, ConfigError> {
+    let path = secret_path(name).ok_or(ConfigError::NoConfigDir)?;
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents.trim().to_string())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Save a secret to its own file, readable only by the user
+///
+/// Unlike a config, a secret gets no backup generation and is never copied
+/// aside, so the file is its only copy on disk.
+pub fn save_secret(name: &str, secret: &str) -> Result<(), ConfigError> {
+    let path = secret_path(name).ok_or(ConfigError::NoConfigDir)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    write_private(&path, format!("{}\n", secret).as_bytes())?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,6 +414,19 @@ mod tests {
         value: i32,
     }
 
+    /// Version 1 renamed `label` to `name`
+    impl VersionedConfig for TestConfig {
+        const VERSION: u32 = 1;
+
+        fn migrate(from_version: u32, payload: &mut Table) {
+            if from_version == 0 {
+                if let Some(label) = payload.remove("label") {
+                    payload.insert("name".to_string(), label);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_config_dir() {
         let dir = config_dir();
@@ -127,5 +440,96 @@ mod tests {
         let path = path.unwrap();
         assert!(path.to_string_lossy().contains("test_clock.toml"));
     }
-}
 
+    #[test]
+    fn test_export_path() {
+        let path = export_path("test_clock", "20250115T093000", "svg");
+        assert!(path.is_some());
+        let path = path.unwrap();
+        assert!(path.to_string_lossy().ends_with("test_clock_20250115T093000.svg"));
+    }
+
+    #[test]
+    fn test_config_round_trip() {
+        let config = TestConfig {
+            name: "ribbon".to_string(),
+            value: 3,
+        };
+        let encoded = encode_config(&config).unwrap();
+        assert!(encoded.starts_with("version = 1"));
+        assert_eq!(decode_config::<TestConfig>(&encoded).unwrap(), config);
+    }
+
+    #[test]
+    fn test_config_migrates_unversioned_file() {
+        let legacy = "label = \"ribbon\"\nvalue = 3\n";
+        let config: TestConfig = decode_config(legacy).unwrap();
+        assert_eq!(config.name, "ribbon");
+        assert_eq!(config.value, 3);
+    }
+
+    #[test]
+    fn test_atomic_write_replaces_whole_file() {
+        let dir = std::env::temp_dir().join(format!("clock-series-config-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("atomic.toml");
+
+        write_atomic(&path, b"version = 1\nlong = \"first contents\"\n").unwrap();
+        write_atomic(&path, b"version = 2\n").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "version = 2\n");
+        // The temporary file was renamed away, not left behind
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_private_write_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("clock-series-secret-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("signing.key");
+
+        // Even over a world-readable file left behind by an earlier write
+        fs::write(&path, b"old").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+        write_private(&path, b"secret\n").unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "secret\n");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_secret_path() {
+        let path = secret_path("test_clock").unwrap();
+        assert!(path.to_string_lossy().ends_with("test_clock.key"));
+    }
+
+    #[test]
+    fn test_config_rejects_garbage() {
+        assert!(matches!(
+            decode_config::<TestConfig>("version = 1\n[payload]\nvalue = \"x\"\n"),
+            Err(ConfigError::Parse(_))
+        ));
+        assert!(decode_config::<TestConfig>("not toml at all [").is_err());
+    }
+
+    #[test]
+    fn test_report_save_toasts_failures_only() {
+        let mut toasts = Vec::new();
+        report_save("quiet hours", Ok(()), |message| toasts.push(message));
+        assert!(toasts.is_empty());
+
+        report_save("quiet hours", Err(ConfigError::NoConfigDir), |message| toasts.push(message));
+        assert_eq!(
+            toasts,
+            ["Failed to save quiet hours: Could not determine config directory"]
+        );
+    }
+}
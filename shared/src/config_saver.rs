@@ -0,0 +1,206 @@
+//! Config saver module - debounced saves and recovery from unreadable configs
+//!
+//! Clocks save their config on every change, and some changes arrive many
+//! times a second (a zoom gesture, a dragged slider). `ConfigSaver` keeps only
+//! the latest request and writes it once changes have settled for
+//! `SAVE_DEBOUNCE`, or when the clock closes and drops it.
+//!
+//! When a clock's config can't be read at startup, the clock runs on defaults
+//! and `ConfigRecovery` says so instead of resetting silently. If the config
+//! left a backup generation (see `config.rs`), the prompt offers to restore
+//! it; the clock is then restarted so the restored settings take effect.
+
+use std::cell::RefCell;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use nannou_egui::egui;
+
+use crate::config::{
+    encode_config, has_config_backup, load_config, restore_config_backup, save_encoded_config,
+    ConfigError, VersionedConfig,
+};
+use crate::i18n::{tr, tr_args};
+
+/// How long changes must settle before a requested save is written
+pub const SAVE_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Whether a save requested at `requested` is due at `now`
+pub fn save_due(requested: Instant, now: Instant) -> bool {
+    now.saturating_duration_since(requested) >= SAVE_DEBOUNCE
+}
+
+/// The latest config a clock asked to save, not yet written
+struct PendingSave {
+    contents: String,
+    requested: Instant,
+}
+
+/// Debounced writer for one clock's config
+///
+/// Requests take `&self`, so a clock can keep saving from code that only
+/// holds its model immutably. Anything still pending is written on drop.
+pub struct ConfigSaver {
+    clock_name: &'static str,
+    pending: RefCell<Option<PendingSave>>,
+}
+
+impl ConfigSaver {
+    pub fn new(clock_name: &'static str) -> Self {
+        Self {
+            clock_name,
+            pending: RefCell::new(None),
+        }
+    }
+
+    /// Ask for `config` to be saved, replacing any save still pending
+    pub fn request<T: VersionedConfig>(&self, config: &T) {
+        match encode_config(config) {
+            Ok(contents) => {
+                *self.pending.borrow_mut() = Some(PendingSave {
+                    contents,
+                    requested: Instant::now(),
+                });
+            }
+            Err(e) => eprintln!("Failed to save config: {}", e),
+        }
+    }
+
+    /// Write the pending save once it is due; call every update
+    pub fn poll(&self, now: Instant) {
+        let due = self
+            .pending
+            .borrow()
+            .as_ref()
+            .is_some_and(|pending| save_due(pending.requested, now));
+        if due {
+            self.flush();
+        }
+    }
+
+    /// Write the pending save now, if there is one
+    pub fn flush(&self) {
+        if let Some(pending) = self.pending.borrow_mut().take() {
+            if let Err(e) = save_encoded_config(self.clock_name, &pending.contents) {
+                eprintln!("Failed to save config: {}", e);
+            }
+        }
+    }
+
+    /// Drop the pending save without writing it
+    pub fn discard(&self) {
+        self.pending.borrow_mut().take();
+    }
+}
+
+impl Drop for ConfigSaver {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+/// Load a clock's config, noting a failure the user should hear about
+///
+/// Returns the config (`None` if there is none yet, or it couldn't be read)
+/// and, when the file was unreadable, the prompt to show.
+pub fn load_config_or_recover<T: VersionedConfig>(
+    clock_name: &'static str,
+) -> (Option<T>, Option<ConfigRecovery>) {
+    match load_config(clock_name) {
+        Ok(config) => (config, None),
+        Err(error @ ConfigError::Corrupt { .. }) => {
+            eprintln!("Failed to load config: {}", error);
+            let recovery = ConfigRecovery {
+                clock_name,
+                problem: error.to_string(),
+                can_restore: has_config_backup::<T>(clock_name),
+            };
+            (None, Some(recovery))
+        }
+        Err(e) => {
+            eprintln!("Failed to load config: {}", e);
+            (None, None)
+        }
+    }
+}
+
+/// What the user chose in the recovery prompt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryChoice {
+    /// Restore the backup generation and restart the clock
+    Restore,
+    /// Carry on with the defaults
+    KeepDefaults,
+}
+
+/// Prompt shown after a clock's config failed to load
+pub struct ConfigRecovery {
+    clock_name: &'static str,
+    /// What went wrong, including where the unreadable file was copied
+    problem: String,
+    can_restore: bool,
+}
+
+impl ConfigRecovery {
+    /// Draw the prompt; call inside the clock's egui frame
+    pub fn show(&self, ctx: &egui::Context) -> Option<RecoveryChoice> {
+        let mut choice = None;
+        egui::Area::new("config_recovery")
+            .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -48.0])
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style())
+                    .inner_margin(egui::Margin::symmetric(16.0, 12.0))
+                    .show(ui, |ui| {
+                        ui.set_max_width(420.0);
+                        ui.label(egui::RichText::new(tr("Settings couldn't be read")).strong());
+                        ui.label(tr("This clock started with its default settings."));
+                        ui.label(egui::RichText::new(&self.problem).small().weak());
+                        ui.horizontal(|ui| {
+                            if self.can_restore
+                                && ui
+                                    .button(tr("Restore backup"))
+                                    .on_hover_text(tr("Restart with the settings last loaded"))
+                                    .clicked()
+                            {
+                                choice = Some(RecoveryChoice::Restore);
+                            }
+                            if ui.button(tr("Keep defaults")).clicked() {
+                                choice = Some(RecoveryChoice::KeepDefaults);
+                            }
+                        });
+                    });
+            });
+        choice
+    }
+
+    /// Put the backup back and start a fresh copy of this clock on it; the
+    /// caller then quits
+    ///
+    /// Saves still pending in `saver` hold the defaults and are discarded so
+    /// they can't overwrite the restored file on the way out.
+    pub fn restore(&self, saver: &ConfigSaver) -> Result<(), String> {
+        saver.discard();
+        restore_config_backup(self.clock_name)
+            .map_err(|e| tr_args("Failed to restore backup: {0}", &[&e]))?;
+        std::env::current_exe()
+            .and_then(|exe| Command::new(exe).args(std::env::args().skip(1)).spawn())
+            .map(|_| ())
+            .map_err(|e| tr_args("Backup restored; restart to apply it ({0})", &[&e]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saves_wait_for_changes_to_settle() {
+        let requested = Instant::now();
+        assert!(!save_due(requested, requested));
+        assert!(!save_due(requested, requested + SAVE_DEBOUNCE / 2));
+        assert!(save_due(requested, requested + SAVE_DEBOUNCE));
+        // A request stamped after `now` isn't due yet
+        assert!(!save_due(requested + SAVE_DEBOUNCE, requested));
+    }
+}
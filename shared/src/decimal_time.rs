@@ -0,0 +1,180 @@
+//! Decimal time module - French Revolutionary decimal time
+//!
+//! The decree of 1793 divided the day into 10 decimal hours of 100 decimal
+//! minutes of 100 decimal seconds, so a decimal second lasts 0.864 standard
+//! seconds and the digits of a decimal time read straight off as a fraction
+//! of the day: 5:00:00 is noon, 7:50:00 is 18:00.
+//!
+//! Conversions here work on local wall-clock time, the way the decimal
+//! clocks of the time were read. On DST days the wall clock skips or repeats
+//! an hour, and so does decimal time.
+
+use std::fmt;
+
+use chrono::{NaiveTime, Timelike};
+
+use crate::time_engine::TimeData;
+
+/// Decimal hours in a day
+pub const DECIMAL_HOURS: u32 = 10;
+/// Decimal minutes in a decimal hour, and decimal seconds in a decimal minute
+pub const DECIMAL_MINUTES: u32 = 100;
+/// Decimal seconds in a day
+pub const DECIMAL_SECONDS_PER_DAY: u32 = DECIMAL_HOURS * DECIMAL_MINUTES * DECIMAL_MINUTES;
+/// Standard seconds in a day
+const STANDARD_SECONDS_PER_DAY: f64 = 86_400.0;
+/// Length of a decimal second in standard seconds
+pub const STANDARD_SECONDS_PER_DECIMAL: f64 = STANDARD_SECONDS_PER_DAY / 100_000.0;
+
+/// A time of day in decimal hours, minutes and seconds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DecimalTime {
+    /// 0-9
+    pub hour: u32,
+    /// 0-99
+    pub minute: u32,
+    /// 0-99
+    pub second: u32,
+}
+
+impl DecimalTime {
+    /// The decimal time at a fraction of the day (0 is midnight), rounded
+    /// down to the decimal second
+    pub fn from_day_fraction(fraction: f64) -> Self {
+        let total = (fraction.clamp(0.0, 1.0) * DECIMAL_SECONDS_PER_DAY as f64).floor() as u32;
+        Self::from_decimal_seconds(total.min(DECIMAL_SECONDS_PER_DAY - 1))
+    }
+
+    /// The decimal time a number of decimal seconds after midnight
+    fn from_decimal_seconds(total: u32) -> Self {
+        Self {
+            hour: total / (DECIMAL_MINUTES * DECIMAL_MINUTES),
+            minute: total / DECIMAL_MINUTES % DECIMAL_MINUTES,
+            second: total % DECIMAL_MINUTES,
+        }
+    }
+
+    /// The decimal time matching a standard wall-clock time
+    pub fn from_standard(time: NaiveTime) -> Self {
+        let seconds = time.num_seconds_from_midnight() as f64 + time.nanosecond() as f64 / 1e9;
+        Self::from_day_fraction(seconds / STANDARD_SECONDS_PER_DAY)
+    }
+
+    /// Decimal seconds since midnight
+    pub fn total_seconds(&self) -> u32 {
+        (self.hour * DECIMAL_MINUTES + self.minute) * DECIMAL_MINUTES + self.second
+    }
+
+    /// Fraction of the day this time marks
+    pub fn day_fraction(&self) -> f64 {
+        self.total_seconds() as f64 / DECIMAL_SECONDS_PER_DAY as f64
+    }
+
+    /// The standard wall-clock time this decimal time marks, to the
+    /// nearest standard second
+    pub fn to_standard(&self) -> NaiveTime {
+        let seconds = (self.day_fraction() * STANDARD_SECONDS_PER_DAY).round() as u32;
+        NaiveTime::from_num_seconds_from_midnight_opt(seconds % 86_400, 0)
+            .unwrap_or(NaiveTime::MIN)
+    }
+
+    /// Parse "h:mm:ss" or "h:mm"
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut fields = text.trim().split(':');
+        let hour: u32 = fields.next()?.trim().parse().ok()?;
+        let minute: u32 = fields.next()?.trim().parse().ok()?;
+        let second: u32 = match fields.next() {
+            Some(second) => second.trim().parse().ok()?,
+            None => 0,
+        };
+        if fields.next().is_some()
+            || hour >= DECIMAL_HOURS
+            || minute >= DECIMAL_MINUTES
+            || second >= DECIMAL_MINUTES
+        {
+            return None;
+        }
+        Some(Self {
+            hour,
+            minute,
+            second,
+        })
+    }
+}
+
+impl fmt::Display for DecimalTime {
+    /// "h:mm:ss", e.g. "7:50:00"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+/// Fraction of the local day shown by a clock, including the fractional
+/// second for smooth animation
+pub fn day_fraction(time_data: &TimeData) -> f64 {
+    let seconds = (time_data.hour24 * 3600 + time_data.minute * 60 + time_data.second) as f64
+        + time_data.second_fraction;
+    (seconds / STANDARD_SECONDS_PER_DAY).clamp(0.0, 1.0)
+}
+
+/// The decimal time a clock is showing
+pub fn decimal_time(time_data: &TimeData) -> DecimalTime {
+    DecimalTime::from_day_fraction(day_fraction(time_data))
+}
+
+/// Parse a standard time as "hh:mm:ss" or "hh:mm" (24-hour)
+pub fn parse_standard_time(text: &str) -> Option<NaiveTime> {
+    let text = text.trim();
+    NaiveTime::parse_from_str(text, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(text, "%H:%M"))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time_engine::compute_time_data_at;
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Tz;
+
+    fn standard(h: u32, m: u32, s: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, s).unwrap()
+    }
+
+    #[test]
+    fn test_landmarks() {
+        let noon = DecimalTime::from_standard(standard(12, 0, 0));
+        assert_eq!(noon.to_string(), "5:00:00");
+        assert_eq!(DecimalTime::from_standard(standard(18, 0, 0)).to_string(), "7:50:00");
+        assert_eq!(DecimalTime::from_standard(standard(0, 0, 0)).to_string(), "0:00:00");
+        // The last standard second of the day starts in the second-to-last decimal one
+        assert_eq!(DecimalTime::from_standard(standard(23, 59, 59)).to_string(), "9:99:98");
+        assert!((STANDARD_SECONDS_PER_DECIMAL - 0.864).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_round_trip_and_parse() {
+        let time = DecimalTime::parse("3:45:12").unwrap();
+        assert_eq!(time.total_seconds(), 34_512);
+        assert_eq!(time.to_standard(), standard(8, 16, 58));
+        // Decimal seconds are shorter, so the trip back rounds down a second
+        assert_eq!(DecimalTime::from_standard(standard(8, 16, 58)).to_string(), "3:45:11");
+
+        assert_eq!(DecimalTime::parse("7:50"), DecimalTime::parse("7:50:00"));
+        assert_eq!(DecimalTime::parse("10:00:00"), None);
+        assert_eq!(DecimalTime::parse("1:100:00"), None);
+        assert_eq!(DecimalTime::parse("1:2:3:4"), None);
+        assert_eq!(parse_standard_time("18:00"), Some(standard(18, 0, 0)));
+        assert_eq!(parse_standard_time("25:00"), None);
+    }
+
+    #[test]
+    fn test_clock_reading_uses_wall_time() {
+        let tz: Tz = "America/New_York".parse().unwrap();
+        // 18:00 EDT
+        let instant = Utc.with_ymd_and_hms(2025, 7, 1, 22, 0, 0).unwrap();
+        let time_data = compute_time_data_at(tz, instant);
+        assert_eq!(decimal_time(&time_data).to_string(), "7:50:00");
+        assert!((day_fraction(&time_data) - 0.75).abs() < 1e-9);
+    }
+}
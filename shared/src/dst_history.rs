@@ -0,0 +1,217 @@
+//! DST history - labeled epochs in a zone's offset rules
+//!
+//! Walks a zone's past (or future) offset changes and picks out the ones
+//! that changed the rules rather than following them: standard time being
+//! adopted or moved, DST being adopted or dropped, year-round DST, and DST
+//! seasons whose start or end dates differ from the year before. Each
+//! becomes a short label ("PDT adopted", "Year-round EWT") that a timeline
+//! can pin to the instant it took effect.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use chrono_tz::Tz;
+
+use crate::time_engine::{query_transitions_between, DstTransition};
+
+/// How far back a DST season must not have started for DST to count as newly
+/// adopted, and how far ahead one must not follow for it to count as dropped
+const SEASON_GAP_DAYS: i64 = 400;
+
+/// DST lasting longer than this is year-round rather than seasonal
+const YEAR_ROUND_DAYS: i64 = 365;
+
+/// Seasons longer than this are labeled as extended
+const EXTENDED_SEASON_DAYS: i64 = 240;
+
+/// A change in a zone's offset rules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleEpoch {
+    /// When the change took effect
+    pub instant_utc: DateTime<Utc>,
+    /// Short description, e.g. "PDT adopted" or "DST Mar 11 – Nov 4"
+    pub label: String,
+}
+
+/// Rule changes that took effect in `tz` between `from` and `to`, oldest first
+///
+/// Transitions up to `SEASON_GAP_DAYS` either side of the range are read as
+/// context, so an epoch near the edge of the range is labeled the same as
+/// one in the middle.
+pub fn dst_rule_epochs(tz: Tz, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<RuleEpoch> {
+    let context = Duration::days(SEASON_GAP_DAYS);
+    let transitions = query_transitions_between(tz, from - context, to + context);
+    rule_epochs(tz, &transitions, from, to)
+}
+
+/// Classify `transitions` (oldest first) into rule epochs within `from..=to`
+fn rule_epochs(
+    tz: Tz,
+    transitions: &[DstTransition],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<RuleEpoch> {
+    let mut epochs = Vec::new();
+
+    for (i, transition) in transitions.iter().enumerate() {
+        let instant = transition.instant_utc;
+        if instant < from || instant > to {
+            continue;
+        }
+        let (before, after) = (&transition.before, &transition.after);
+        let mut push = |label: String| epochs.push(RuleEpoch { instant_utc: instant, label });
+
+        if before.standard_minutes != after.standard_minutes {
+            push(if before.abbreviation == "LMT" {
+                format!("{} adopted", after.abbreviation)
+            } else {
+                format!("Standard time {}", format_offset(after.standard_minutes))
+            });
+        }
+
+        if !before.is_dst() && after.is_dst() {
+            let previous_start = transitions[..i].iter().rev().find(|t| starts_dst(t));
+            let end = transitions[i + 1..].iter().find(|t| ends_dst(t));
+            let season = end.map(|end| end.instant_utc - instant);
+
+            if season.is_none_or(|days| days.num_days() >= YEAR_ROUND_DAYS) {
+                push(format!("Year-round {}", after.abbreviation));
+            } else if previous_start
+                .is_none_or(|start| (instant - start.instant_utc).num_days() > SEASON_GAP_DAYS)
+            {
+                push(format!("{} adopted", after.abbreviation));
+            } else if let (Some(previous_start), Some(end)) = (previous_start, end) {
+                let previous_end = transitions[..i].iter().rev().find(|t| ends_dst(t));
+                let same_rules = same_day_rule(tz, previous_start, transition)
+                    && previous_end.is_some_and(|previous| same_day_rule(tz, previous, end));
+                if !same_rules {
+                    let extended =
+                        season.is_some_and(|days| days.num_days() > EXTENDED_SEASON_DAYS);
+                    push(format!(
+                        "{}DST {} – {}",
+                        if extended { "Extended " } else { "" },
+                        local_date(tz, transition).format("%b %-d"),
+                        local_date(tz, end).format("%b %-d"),
+                    ));
+                }
+            }
+        }
+
+        if before.is_dst() && !after.is_dst() {
+            let last_start = transitions[..i].iter().rev().find(|t| starts_dst(t));
+            let next_start = transitions[i + 1..].iter().find(|t| starts_dst(t));
+            // No start within the context means DST ran for longer than it
+            if last_start
+                .is_none_or(|start| (instant - start.instant_utc).num_days() >= YEAR_ROUND_DAYS)
+            {
+                push(format!("{} restored", after.abbreviation));
+            } else if next_start
+                .is_none_or(|start| (start.instant_utc - instant).num_days() > SEASON_GAP_DAYS)
+            {
+                push("DST dropped".to_string());
+            }
+        }
+    }
+
+    epochs
+}
+
+fn starts_dst(transition: &DstTransition) -> bool {
+    !transition.before.is_dst() && transition.after.is_dst()
+}
+
+fn ends_dst(transition: &DstTransition) -> bool {
+    transition.before.is_dst() && !transition.after.is_dst()
+}
+
+/// Local date a transition happened on
+fn local_date(tz: Tz, transition: &DstTransition) -> NaiveDate {
+    transition.instant_utc.with_timezone(&tz).date_naive()
+}
+
+/// Whether two transitions a year apart follow the same day rule: the same
+/// date ("Mar 22"), or the same weekday in the same week of the month
+/// ("second Sunday of March", "last Sunday of October")
+fn same_day_rule(tz: Tz, earlier: &DstTransition, later: &DstTransition) -> bool {
+    let (a, b) = (local_date(tz, earlier), local_date(tz, later));
+    let same_weekday = a.weekday() == b.weekday() && week_of_month(a) == week_of_month(b);
+    a.month() == b.month() && (a.day() == b.day() || same_weekday)
+}
+
+/// 0-3 for the first four weeks of the month, or 4 for the last seven days
+fn week_of_month(date: NaiveDate) -> u32 {
+    let next_month = if date.month() == 12 {
+        NaiveDate::from_ymd_opt(date.year() + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(date.year(), date.month() + 1, 1)
+    };
+    let days_in_month = next_month
+        .and_then(|first| first.pred_opt())
+        .map_or(31, |last| last.day());
+    if date.day() + 7 > days_in_month {
+        4
+    } else {
+        (date.day() - 1) / 7
+    }
+}
+
+/// "UTC+5:30" style offset
+fn format_offset(minutes: i32) -> String {
+    let sign = if minutes < 0 { '-' } else { '+' };
+    let minutes = minutes.abs();
+    if minutes % 60 == 0 {
+        format!("UTC{}{}", sign, minutes / 60)
+    } else {
+        format!("UTC{}{}:{:02}", sign, minutes / 60, minutes % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn year(tz: Tz, year: i32) -> Vec<RuleEpoch> {
+        let from = Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(year + 1, 1, 1, 0, 0, 0).unwrap();
+        dst_rule_epochs(tz, from, to)
+    }
+
+    fn labels(epochs: &[RuleEpoch]) -> Vec<&str> {
+        epochs.iter().map(|epoch| epoch.label.as_str()).collect()
+    }
+
+    #[test]
+    fn test_adoption_and_war_time() {
+        let los_angeles: Tz = "America/Los_Angeles".parse().unwrap();
+        assert_eq!(labels(&year(los_angeles, 1883)), ["PST adopted"]);
+        assert_eq!(labels(&year(los_angeles, 1918)), ["PDT adopted"]);
+        assert_eq!(labels(&year(los_angeles, 1942)), ["Year-round PWT"]);
+        assert_eq!(labels(&year(los_angeles, 1945)), ["PST restored"]);
+    }
+
+    #[test]
+    fn test_season_changes() {
+        let new_york: Tz = "America/New_York".parse().unwrap();
+        // The 1974 energy-crisis trial, then the 2007 extension
+        assert_eq!(labels(&year(new_york, 1974)), ["Extended DST Jan 6 – Oct 27"]);
+        assert_eq!(labels(&year(new_york, 2007)), ["DST Mar 11 – Nov 4"]);
+        // Seasons following the same rules as the year before are quiet
+        assert!(year(new_york, 2010).is_empty());
+    }
+
+    #[test]
+    fn test_dropped_and_standard_moves() {
+        let phoenix: Tz = "America/Phoenix".parse().unwrap();
+        // Arizona tried a single season of DST in 1967
+        assert_eq!(labels(&year(phoenix, 1967)), ["MDT adopted", "DST dropped"]);
+
+        let kolkata: Tz = "Asia/Kolkata".parse().unwrap();
+        assert_eq!(labels(&year(kolkata, 1941)), ["+0630 adopted"]);
+        assert_eq!(labels(&year(kolkata, 1945)), ["IST restored"]);
+        let moscow: Tz = "Europe/Moscow".parse().unwrap();
+        assert!(labels(&year(moscow, 2014)).contains(&"Standard time UTC+3"));
+
+        assert_eq!(format_offset(-210), "UTC-3:30");
+        assert_eq!(week_of_month(NaiveDate::from_ymd_opt(2025, 3, 30).unwrap()), 4);
+        assert_eq!(week_of_month(NaiveDate::from_ymd_opt(2025, 3, 9).unwrap()), 1);
+    }
+}
@@ -0,0 +1,263 @@
+//! Favorites module - favorite zones shared by every clock
+//!
+//! The series keeps one favorites list in a shared config file, so a zone
+//! starred in one clock shows up in all of them. A clock can opt out and
+//! keep its own list instead; that list stays in the clock's config and is
+//! kept while the clock follows the shared one.
+//!
+//! Clocks call `poll` every update. It checks the shared file's modification
+//! time about once a second and reloads the list when another clock has
+//! changed it, returning whether the favorites on screen changed.
+
+use std::fs;
+use std::ops::Deref;
+use std::time::{Duration, Instant, SystemTime};
+
+use chrono_tz::Tz;
+use nannou_egui::egui;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{config_path, load_config, save_config, VersionedConfig};
+use crate::i18n::tr;
+
+/// Config file shared by all clocks
+pub const FAVORITES_CONFIG: &str = "favorites";
+
+/// How often `poll` looks at the shared file
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The shared list as stored on disk
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct SharedFavorites {
+    zones: Vec<String>,
+}
+
+impl VersionedConfig for SharedFavorites {
+    const VERSION: u32 = 1;
+}
+
+/// Parse zone ids, skipping unknown ones
+pub fn parse_zones(ids: &[String]) -> Vec<Tz> {
+    ids.iter().filter_map(|id| id.parse().ok()).collect()
+}
+
+/// Zone ids for saving
+pub fn zone_ids(zones: &[Tz]) -> Vec<String> {
+    zones.iter().map(|tz| tz.name().to_string()).collect()
+}
+
+/// Remove `tz` if present, otherwise append it
+fn toggle_zone(zones: &mut Vec<Tz>, tz: Tz) {
+    if let Some(pos) = zones.iter().position(|&t| t == tz) {
+        zones.remove(pos);
+    } else {
+        zones.push(tz);
+    }
+}
+
+/// Modification time of the shared file, if it exists
+fn shared_modified() -> Option<SystemTime> {
+    let path = config_path(FAVORITES_CONFIG)?;
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// A clock's favorites: the shared list, or its own
+///
+/// Derefs to the list the clock shows.
+#[derive(Debug, Clone)]
+pub struct Favorites {
+    /// Keep this clock's own list instead of following the shared one
+    use_own: bool,
+    /// The clock's own list
+    own: Vec<Tz>,
+    /// The series-wide list as last read or written
+    shared: Vec<Tz>,
+    /// Shared file modification time when last read or written
+    seen: Option<SystemTime>,
+    /// When `poll` last looked at the shared file
+    last_poll: Option<Instant>,
+}
+
+impl Favorites {
+    /// Favorites from a clock's own list and the shared list
+    pub fn from_lists(own: Vec<Tz>, shared: Vec<Tz>, use_own: bool) -> Self {
+        Self {
+            use_own,
+            own,
+            shared,
+            seen: None,
+            last_poll: None,
+        }
+    }
+
+    /// Load the shared list alongside the clock's own `own_ids`
+    ///
+    /// The first clock to run after upgrading seeds the shared list with
+    /// its own favorites, so nobody's list is lost.
+    pub fn load(own_ids: &[String], use_own: bool) -> Self {
+        let own = parse_zones(own_ids);
+        let (shared, seed) = match load_config::<SharedFavorites>(FAVORITES_CONFIG) {
+            Ok(Some(stored)) => (parse_zones(&stored.zones), false),
+            Ok(None) => (own.clone(), true),
+            Err(e) => {
+                eprintln!("Failed to load shared favorites: {}", e);
+                (own.clone(), false)
+            }
+        };
+        let mut favorites = Self::from_lists(own, shared, use_own);
+        if seed {
+            favorites.save_shared();
+        }
+        favorites.seen = shared_modified();
+        favorites
+    }
+
+    /// Whether this clock keeps its own list
+    pub fn uses_own(&self) -> bool {
+        self.use_own
+    }
+
+    /// Switch between the clock's own list and the shared one
+    pub fn set_uses_own(&mut self, use_own: bool) {
+        self.use_own = use_own;
+    }
+
+    /// The clock's own list, for its config file
+    pub fn own_ids(&self) -> Vec<String> {
+        zone_ids(&self.own)
+    }
+
+    fn active_mut(&mut self) -> &mut Vec<Tz> {
+        if self.use_own {
+            &mut self.own
+        } else {
+            &mut self.shared
+        }
+    }
+
+    /// Star or unstar `tz` in the list this clock shows
+    ///
+    /// Shared changes are written straight away; the caller saves its own
+    /// config for changes to its own list.
+    pub fn toggle(&mut self, tz: Tz) {
+        toggle_zone(self.active_mut(), tz);
+        self.after_edit();
+    }
+
+    /// Replace the list this clock shows, e.g. after reordering
+    pub fn set_zones(&mut self, zones: Vec<Tz>) {
+        *self.active_mut() = zones;
+        self.after_edit();
+    }
+
+    fn after_edit(&mut self) {
+        if !self.use_own {
+            self.save_shared();
+            self.seen = shared_modified();
+        }
+    }
+
+    fn save_shared(&self) {
+        let stored = SharedFavorites {
+            zones: zone_ids(&self.shared),
+        };
+        if let Err(e) = save_config(FAVORITES_CONFIG, &stored) {
+            eprintln!("Failed to save shared favorites: {}", e);
+        }
+    }
+
+    /// Take a newer shared list; returns whether the shown list changed
+    pub fn apply_shared(&mut self, zones: Vec<Tz>) -> bool {
+        let changed = !self.use_own && zones != self.shared;
+        self.shared = zones;
+        changed
+    }
+
+    /// Reload the shared list if another clock changed it
+    ///
+    /// Returns whether the favorites this clock shows changed.
+    pub fn poll(&mut self) -> bool {
+        let now = Instant::now();
+        if self.last_poll.is_some_and(|last| now.duration_since(last) < POLL_INTERVAL) {
+            return false;
+        }
+        self.last_poll = Some(now);
+
+        let modified = shared_modified();
+        if modified == self.seen {
+            return false;
+        }
+        self.seen = modified;
+        match load_config::<SharedFavorites>(FAVORITES_CONFIG) {
+            Ok(Some(stored)) => self.apply_shared(parse_zones(&stored.zones)),
+            Ok(None) => false,
+            Err(e) => {
+                eprintln!("Failed to reload shared favorites: {}", e);
+                false
+            }
+        }
+    }
+}
+
+impl Deref for Favorites {
+    type Target = [Tz];
+
+    fn deref(&self) -> &[Tz] {
+        if self.use_own {
+            &self.own
+        } else {
+            &self.shared
+        }
+    }
+}
+
+/// Settings control for the favorites source; returns whether it changed
+pub fn favorites_settings(ui: &mut egui::Ui, use_own: &mut bool) -> bool {
+    ui.checkbox(use_own, tr("Own favorites for this clock"))
+        .on_hover_text(tr("Off: share favorites with every clock in the series"))
+        .changed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zones(ids: &[&str]) -> Vec<Tz> {
+        ids.iter().map(|id| id.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_own_list_survives_following_shared() {
+        let own = zones(&["Asia/Tokyo"]);
+        let shared = zones(&["Europe/London", "America/New_York"]);
+        let mut favorites = Favorites::from_lists(own.clone(), shared.clone(), true);
+        assert_eq!(&*favorites, own.as_slice());
+
+        favorites.toggle("Europe/Paris".parse().unwrap());
+        assert_eq!(favorites.len(), 2);
+        favorites.set_uses_own(false);
+        assert_eq!(&*favorites, shared.as_slice());
+        favorites.set_uses_own(true);
+        assert_eq!(favorites.own_ids(), ["Asia/Tokyo", "Europe/Paris"]);
+    }
+
+    #[test]
+    fn test_shared_changes_only_matter_when_shown() {
+        let mut favorites = Favorites::from_lists(Vec::new(), zones(&["UTC"]), true);
+        assert!(!favorites.apply_shared(zones(&["UTC", "Asia/Tokyo"])));
+
+        favorites.set_uses_own(false);
+        assert_eq!(favorites.len(), 2);
+        assert!(!favorites.apply_shared(zones(&["UTC", "Asia/Tokyo"])));
+        assert!(favorites.apply_shared(zones(&["Asia/Tokyo"])));
+        assert_eq!(&*favorites, zones(&["Asia/Tokyo"]).as_slice());
+    }
+
+    #[test]
+    fn test_parse_zones_skips_unknown() {
+        let ids = vec!["Asia/Tokyo".to_string(), "Mars/Olympus".to_string()];
+        assert_eq!(parse_zones(&ids), zones(&["Asia/Tokyo"]));
+        assert_eq!(zone_ids(&parse_zones(&ids)), ["Asia/Tokyo"]);
+    }
+}
@@ -0,0 +1,114 @@
+//! Frame pacing module - letting an idle clock stop drawing at full rate
+//!
+//! A clock redraws at the display's refresh rate while someone is using it
+//! or something on screen is moving. Once input has stopped for a moment and
+//! the clock reports no animation in progress, the pacer tells it to let the
+//! event loop wait, and a background thread wakes the loop just after each
+//! second boundary so the time still advances. Any window event brings the
+//! full rate straight back.
+//!
+//! Each clock decides what counts as an animation in progress: transitions,
+//! toasts fading out, replays, recordings. Motion that never ends, such as a
+//! sweeping second hand, steps once a second while the clock is idle.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+
+use crate::cli::snapshot_request;
+use crate::ticker::{duration_until_next_boundary, TickGranularity};
+
+/// How long after the last input the clock keeps drawing at full rate
+pub const IDLE_AFTER: Duration = Duration::from_secs(2);
+
+/// How long after a second boundary an idle clock is woken, so the ticker's
+/// tick for that second has arrived by the time it updates
+const WAKE_MARGIN: Duration = Duration::from_millis(15);
+
+/// Whether a clock can drop to idle pacing
+///
+/// It can once `IDLE_AFTER` has passed since the last input and nothing is
+/// animating.
+pub fn can_idle(last_input: Instant, now: Instant, animating: bool) -> bool {
+    !animating && now.saturating_duration_since(last_input) >= IDLE_AFTER
+}
+
+/// Handle to a clock's frame pacing and its wake-up thread
+///
+/// The thread is stopped when the handle is dropped.
+pub struct FramePacer {
+    last_input: Instant,
+    /// Shared with the thread, which only wakes the loop while this is set
+    idle: Arc<AtomicBool>,
+    stop: Arc<AtomicBool>,
+}
+
+impl FramePacer {
+    /// Start pacing; `wake` wakes the clock's event loop and returns false
+    /// once the loop has closed
+    pub fn spawn(wake: impl Fn() -> bool + Send + 'static) -> Self {
+        let idle = Arc::new(AtomicBool::new(false));
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_idle = Arc::clone(&idle);
+        let thread_stop = Arc::clone(&stop);
+
+        thread::spawn(move || {
+            while !thread_stop.load(Ordering::Relaxed) {
+                let wait = duration_until_next_boundary(Utc::now(), TickGranularity::Second);
+                thread::sleep(wait + WAKE_MARGIN);
+                // Event loop closed - nothing left to wake
+                if thread_idle.load(Ordering::Relaxed) && !wake() {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            last_input: Instant::now(),
+            idle,
+            stop,
+        }
+    }
+
+    /// Record user input; call for every raw window event
+    pub fn note_input(&mut self, now: Instant) {
+        self.last_input = now;
+    }
+
+    /// Decide this frame's pacing, given whether the clock has an animation
+    /// in progress; returns whether the event loop should wait for events
+    /// instead of redrawing continuously
+    ///
+    /// `--snapshot` runs never idle, so they reach their capture frame.
+    pub fn pace(&mut self, animating: bool, now: Instant) -> bool {
+        let animating = animating || snapshot_request().is_some();
+        let idle = can_idle(self.last_input, now, animating);
+        self.idle.store(idle, Ordering::Relaxed);
+        idle
+    }
+}
+
+impl Drop for FramePacer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idles_only_after_input_stops_and_nothing_animates() {
+        let input = Instant::now();
+        assert!(!can_idle(input, input, false));
+        assert!(!can_idle(input, input + IDLE_AFTER / 2, false));
+        assert!(can_idle(input, input + IDLE_AFTER, false));
+        assert!(!can_idle(input, input + IDLE_AFTER * 10, true));
+        // Input stamped after `now` (events handled mid-frame) isn't idle
+        assert!(!can_idle(input + IDLE_AFTER, input, false));
+    }
+}
@@ -0,0 +1,4186 @@
+//! i18n module - translated strings and date conventions for the series
+//!
+//! Strings are looked up by their English text with `tr`, so anything not
+//! in the catalog yet simply stays in English. The language is one setting
+//! for the whole series, saved like quiet hours and switchable while a
+//! clock runs; month and weekday names, AM/PM and the order of the long
+//! date follow it too.
+//!
+//! egui's bundled fonts have no Japanese glyphs, so `install_fallback_fonts`
+//! adds a system CJK font when one can be found.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::{OnceLock, RwLock};
+
+use chrono::Weekday;
+use nannou_egui::egui;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{load_config, save_config, ConfigError, VersionedConfig};
+use crate::time_engine::Meridiem;
+
+/// Config file shared by all clocks
+pub const LANGUAGE_CONFIG: &str = "language";
+
+/// System fonts with Japanese glyphs, tried in order
+const CJK_FONT_PATHS: [&str; 7] = [
+    "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/google-noto-cjk/NotoSansCJK-Regular.ttc",
+    "/System/Library/Fonts/ヒラギノ角ゴシック W3.ttc",
+    "/System/Library/Fonts/Hiragino Sans GB.ttc",
+    r"C:\Windows\Fonts\YuGothM.ttc",
+    r"C:\Windows\Fonts\msgothic.ttc",
+];
+
+/// A language the clocks can be shown in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    En,
+    Es,
+    De,
+    Ja,
+}
+
+impl Language {
+    pub const ALL: [Language; 4] = [Language::En, Language::Es, Language::De, Language::Ja];
+
+    /// The language's name for itself
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::En => "English",
+            Language::Es => "Español",
+            Language::De => "Deutsch",
+            Language::Ja => "日本語",
+        }
+    }
+
+    /// `text` in this language, or `text` itself when there's no translation
+    pub fn translate(&self, text: &'static str) -> &'static str {
+        let column = match self {
+            Language::En => return text,
+            Language::Es => 0,
+            Language::De => 1,
+            Language::Ja => 2,
+        };
+        catalog_index()
+            .get(text)
+            .map_or(text, |translations| translations[column])
+    }
+
+    /// Full name of `month` (1-12)
+    pub fn month_name(&self, month: u32) -> &'static str {
+        let names = match self {
+            Language::En => &MONTHS_EN,
+            Language::Es => &MONTHS_ES,
+            Language::De => &MONTHS_DE,
+            Language::Ja => &MONTHS_JA,
+        };
+        month
+            .checked_sub(1)
+            .and_then(|index| names.get(index as usize))
+            .copied()
+            .unwrap_or("?")
+    }
+
+    /// Short weekday name ("Mon", "lun", "Mo", "月")
+    pub fn weekday_abbrev(&self, weekday: Weekday) -> &'static str {
+        let names = match self {
+            Language::En => ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+            Language::Es => ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+            Language::De => ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+            Language::Ja => ["月", "火", "水", "木", "金", "土", "日"],
+        };
+        names[weekday.num_days_from_monday() as usize]
+    }
+
+    pub fn meridiem(&self, meridiem: Meridiem) -> &'static str {
+        match (self, meridiem) {
+            (Language::En, Meridiem::AM) => "AM",
+            (Language::En, Meridiem::PM) => "PM",
+            (Language::Es, Meridiem::AM) => "a. m.",
+            (Language::Es, Meridiem::PM) => "p. m.",
+            (Language::De, Meridiem::AM) => "vorm.",
+            (Language::De, Meridiem::PM) => "nachm.",
+            (Language::Ja, Meridiem::AM) => "午前",
+            (Language::Ja, Meridiem::PM) => "午後",
+        }
+    }
+
+    /// The date in this language's long form: "Mon, January 5, 2025",
+    /// "lun, 5 de enero de 2025", "Mo, 5. Januar 2025", "2025年1月5日(月)"
+    pub fn long_date(&self, weekday: Weekday, day: u32, month: u32, year: i32) -> String {
+        let weekday = self.weekday_abbrev(weekday);
+        let month_name = self.month_name(month);
+        match self {
+            Language::En => format!("{}, {} {}, {}", weekday, month_name, day, year),
+            Language::Es => format!("{}, {} de {} de {}", weekday, day, month_name, year),
+            Language::De => format!("{}, {}. {} {}", weekday, day, month_name, year),
+            Language::Ja => format!("{}年{}月{}日({})", year, month, day, weekday),
+        }
+    }
+}
+
+const MONTHS_EN: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June", "July", "August", "September",
+    "October", "November", "December",
+];
+
+const MONTHS_ES: [&str; 12] = [
+    "enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre",
+    "octubre", "noviembre", "diciembre",
+];
+
+const MONTHS_DE: [&str; 12] = [
+    "Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September",
+    "Oktober", "November", "Dezember",
+];
+
+const MONTHS_JA: [&str; 12] = [
+    "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+];
+
+static ACTIVE_LANGUAGE: RwLock<Language> = RwLock::new(Language::En);
+
+/// `CATALOG` keyed by its English text, built on the first lookup
+fn catalog_index() -> &'static HashMap<&'static str, [&'static str; 3]> {
+    static INDEX: OnceLock<HashMap<&'static str, [&'static str; 3]>> = OnceLock::new();
+    INDEX.get_or_init(|| CATALOG.iter().copied().collect())
+}
+
+/// Set the language every `tr` lookup uses
+pub fn set_language(language: Language) {
+    if let Ok(mut active) = ACTIVE_LANGUAGE.write() {
+        *active = language;
+    }
+}
+
+/// The language clocks are currently shown in
+pub fn language() -> Language {
+    ACTIVE_LANGUAGE.read().map_or(Language::En, |active| *active)
+}
+
+/// `text` in the current language
+pub fn tr(text: &'static str) -> &'static str {
+    language().translate(text)
+}
+
+/// `text` in the current language, with `{0}`, `{1}`, ... filled from `args`
+pub fn tr_args(text: &'static str, args: &[&dyn Display]) -> String {
+    fill(tr(text), args)
+}
+
+fn fill(template: &str, args: &[&dyn Display]) -> String {
+    args.iter()
+        .enumerate()
+        .fold(template.to_string(), |text, (index, arg)| {
+            text.replace(&format!("{{{}}}", index), &arg.to_string())
+        })
+}
+
+/// The series-wide language choice
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LanguageSetting {
+    pub language: Language,
+}
+
+impl VersionedConfig for LanguageSetting {
+    const VERSION: u32 = 1;
+}
+
+impl LanguageSetting {
+    /// Load the shared setting and make it the current language
+    pub fn load() -> Self {
+        let setting = match load_config(LANGUAGE_CONFIG) {
+            Ok(setting) => setting.unwrap_or_default(),
+            Err(e) => {
+                eprintln!("Failed to load language setting: {}", e);
+                Self::default()
+            }
+        };
+        set_language(setting.language);
+        setting
+    }
+
+    pub fn save(&self) -> Result<(), ConfigError> {
+        save_config(LANGUAGE_CONFIG, self)
+    }
+}
+
+/// Add a system CJK font behind egui's own, so Japanese text has glyphs
+pub fn install_fallback_fonts(ctx: &egui::Context) {
+    let Some(bytes) = CJK_FONT_PATHS.iter().find_map(|path| std::fs::read(path).ok()) else {
+        return;
+    };
+    let mut fonts = egui::FontDefinitions::default();
+    fonts
+        .font_data
+        .insert("cjk".to_string(), egui::FontData::from_owned(bytes));
+    for family in [egui::FontFamily::Proportional, egui::FontFamily::Monospace] {
+        fonts.families.entry(family).or_default().push("cjk".to_string());
+    }
+    ctx.set_fonts(fonts);
+}
+
+/// Settings control for the language; returns whether it changed
+///
+/// A change applies at once; the caller saves it.
+pub fn language_settings(ui: &mut egui::Ui, setting: &mut LanguageSetting) -> bool {
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.label(tr("Language"));
+        egui::ComboBox::from_id_source("language")
+            .selected_text(setting.language.native_name())
+            .show_ui(ui, |ui| {
+                for language in Language::ALL {
+                    changed |= ui
+                        .selectable_value(&mut setting.language, language, language.native_name())
+                        .changed();
+                }
+            });
+    });
+    if changed {
+        set_language(setting.language);
+    }
+    changed
+}
+
+/// Translations by English text: Spanish, German, Japanese
+const CATALOG: &[(&str, [&str; 3])] = &[
+    // Shared settings
+    ("Language", ["Idioma", "Sprache", "言語"]),
+    ("Quiet hours", ["Horas de silencio", "Ruhezeiten", "おやすみ時間"]),
+    (
+        "Dim, stop animations and mute chimes overnight",
+        [
+            "Atenúa la pantalla, detiene las animaciones y silencia las campanadas de noche",
+            "Nachts abdunkeln, Animationen anhalten und Glockenschläge stummschalten",
+            "夜間は画面を暗くし、アニメーションとチャイムを止めます",
+        ],
+    ),
+    ("Brightness", ["Brillo", "Helligkeit", "明るさ"]),
+    (
+        "Time in window title",
+        ["Hora en el título de la ventana", "Uhrzeit im Fenstertitel", "ウィンドウタイトルに時刻"],
+    ),
+    (
+        "Keep the time visible in the taskbar or dock",
+        [
+            "Mantiene la hora visible en la barra de tareas o el dock",
+            "Hält die Uhrzeit in der Taskleiste oder im Dock sichtbar",
+            "タスクバーやDockで時刻が見えるようにします",
+        ],
+    ),
+    ("24-hour", ["24 horas", "24 Stunden", "24時間表示"]),
+    (
+        "Clip Recording (Ctrl+Shift+R)",
+        [
+            "Grabación de clips (Ctrl+Shift+R)",
+            "Clip-Aufnahme (Strg+Umschalt+R)",
+            "クリップ録画 (Ctrl+Shift+R)",
+        ],
+    ),
+    (
+        "Recording a {0} s {1} clip (Ctrl+Shift+R to stop)",
+        [
+            "Grabando un clip {1} de {0} s (Ctrl+Shift+R para detener)",
+            "Nehme einen {1}-Clip von {0} s auf (Strg+Umschalt+R zum Beenden)",
+            "{0}秒の{1}クリップを録画中 (Ctrl+Shift+Rで停止)",
+        ],
+    ),
+    ("Stopping recording", ["Deteniendo la grabación", "Aufnahme wird beendet", "録画を停止しています"]),
+    (
+        "Still saving the last clip",
+        [
+            "Todavía se está guardando el último clip",
+            "Der letzte Clip wird noch gespeichert",
+            "前のクリップを保存しています",
+        ],
+    ),
+    (
+        "Saved clip to {0}",
+        ["Clip guardado en {0}", "Clip gespeichert unter {0}", "クリップを{0}に保存しました"],
+    ),
+    (
+        "Couldn't save clip: {0}",
+        [
+            "No se pudo guardar el clip: {0}",
+            "Clip konnte nicht gespeichert werden: {0}",
+            "クリップを保存できませんでした: {0}",
+        ],
+    ),
+    (
+        "Couldn't start recording: {0}",
+        [
+            "No se pudo iniciar la grabación: {0}",
+            "Aufnahme konnte nicht gestartet werden: {0}",
+            "録画を開始できませんでした: {0}",
+        ],
+    ),
+    ("Saving clip…", ["Guardando clip…", "Clip wird gespeichert…", "クリップを保存中…"]),
+    ("Reduced Motion", ["Movimiento reducido", "Reduzierte Bewegung", "モーション軽減"]),
+    ("Auto", ["Auto", "Auto", "自動"]),
+    ("On", ["Sí", "An", "オン"]),
+    ("Off", ["No", "Aus", "オフ"]),
+    ("on", ["activado", "an", "オン"]),
+    ("off", ["desactivado", "aus", "オフ"]),
+    (
+        "Auto follows the system setting (now {0})",
+        [
+            "Auto sigue la configuración del sistema (ahora {0})",
+            "Auto folgt der Systemeinstellung (derzeit {0})",
+            "自動はシステム設定に従います (現在{0})",
+        ],
+    ),
+    (
+        "Follow system time zone",
+        [
+            "Seguir la zona horaria del sistema",
+            "Systemzeitzone folgen",
+            "システムのタイムゾーンに従う",
+        ],
+    ),
+    (
+        "Switch to the computer's zone when it changes, e.g. after travel",
+        [
+            "Cambia a la zona del equipo cuando cambie, p. ej., tras un viaje",
+            "Wechselt zur Zeitzone des Computers, wenn sie sich ändert, z. B. nach einer Reise",
+            "旅行後など、コンピューターのタイムゾーンが変わったら切り替えます",
+        ],
+    ),
+    (
+        "Global shortcut to show the time (X11)",
+        [
+            "Atajo global para ver la hora (X11)",
+            "Globales Tastenkürzel für die Uhrzeit (X11)",
+            "時刻を表示するグローバルショートカット (X11)",
+        ],
+    ),
+    (
+        "Reach this clock from any app, even when it's hidden",
+        [
+            "Accede a este reloj desde cualquier aplicación, aunque esté oculto",
+            "Diese Uhr aus jeder App erreichen, auch wenn sie verdeckt ist",
+            "隠れていても、どのアプリからでもこの時計を表示します",
+        ],
+    ),
+    ("Flash on top", ["Mostrar encima", "Kurz im Vordergrund", "最前面に一時表示"]),
+    ("Raise window", ["Traer la ventana", "Fenster nach vorn", "ウィンドウを前面へ"]),
+    ("seconds", ["segundos", "Sekunden", "秒"]),
+    ("Unknown shortcut", ["Atajo desconocido", "Unbekanntes Tastenkürzel", "不明なショートカット"]),
+    (
+        "Add Ctrl or Alt, or pick a function key",
+        [
+            "Añade Ctrl o Alt, o elige una tecla de función",
+            "Strg oder Alt hinzufügen oder eine Funktionstaste wählen",
+            "Ctrl か Alt を加えるか、ファンクションキーを選んでください",
+        ],
+    ),
+    (
+        "No X11 display; the shortcut needs an X11 session",
+        [
+            "No hay pantalla X11; el atajo necesita una sesión X11",
+            "Keine X11-Anzeige; das Tastenkürzel braucht eine X11-Sitzung",
+            "X11 ディスプレイがありません。ショートカットには X11 セッションが必要です",
+        ],
+    ),
+    (
+        "Xlib can't be used from another thread",
+        [
+            "Xlib no se puede usar desde otro hilo",
+            "Xlib kann nicht aus einem anderen Thread genutzt werden",
+            "Xlib を別のスレッドから使えません",
+        ],
+    ),
+    (
+        "Global shortcuts aren't available under Wayland",
+        [
+            "Los atajos globales no están disponibles en Wayland",
+            "Globale Tastenkürzel gibt es unter Wayland nicht",
+            "Wayland ではグローバルショートカットを使えません",
+        ],
+    ),
+    (
+        "Another app or clock already uses this shortcut",
+        [
+            "Otra aplicación o reloj ya usa este atajo",
+            "Eine andere App oder Uhr nutzt dieses Tastenkürzel bereits",
+            "別のアプリか時計がこのショートカットを使用中です",
+        ],
+    ),
+    (
+        "Global shortcuts are only available on Linux with X11",
+        [
+            "Los atajos globales solo están disponibles en Linux con X11",
+            "Globale Tastenkürzel gibt es nur unter Linux mit X11",
+            "グローバルショートカットは X11 の Linux でのみ使えます",
+        ],
+    ),
+    (
+        "System time zone changed ({0}); switched",
+        [
+            "La zona horaria del sistema cambió ({0}); se ha cambiado",
+            "Systemzeitzone geändert ({0}); gewechselt",
+            "システムのタイムゾーンが変わりました ({0})。切り替えました",
+        ],
+    ),
+    (
+        "System time zone changed ({0})",
+        [
+            "La zona horaria del sistema cambió ({0})",
+            "Systemzeitzone geändert ({0})",
+            "システムのタイムゾーンが変わりました ({0})",
+        ],
+    ),
+    (
+        "Own favorites for this clock",
+        ["Favoritos propios para este reloj", "Eigene Favoriten für diese Uhr", "この時計専用のお気に入り"],
+    ),
+    (
+        "Off: share favorites with every clock in the series",
+        [
+            "Desactivado: comparte los favoritos con todos los relojes de la serie",
+            "Aus: Favoriten mit allen Uhren der Reihe teilen",
+            "オフ: シリーズのすべての時計でお気に入りを共有",
+        ],
+    ),
+    (
+        "Keyboard shortcuts",
+        ["Atajos de teclado", "Tastenkürzel", "キーボードショートカット"],
+    ),
+    (
+        "Click a shortcut, then press the new keys; Esc cancels",
+        [
+            "Haz clic en un atajo y pulsa las nuevas teclas; Esc cancela",
+            "Kürzel anklicken, dann die neuen Tasten drücken; Esc bricht ab",
+            "ショートカットをクリックして新しいキーを押します。Escで取り消し",
+        ],
+    ),
+    ("Press keys…", ["Pulsa las teclas…", "Tasten drücken…", "キーを押してください…"]),
+    ("Default: {0}", ["Predeterminado: {0}", "Standard: {0}", "既定: {0}"]),
+    ("Reset all", ["Restablecer todo", "Alle zurücksetzen", "すべてリセット"]),
+    (
+        "{0} and {1} share a shortcut",
+        [
+            "{0} y {1} comparten un atajo",
+            "{0} und {1} teilen sich ein Kürzel",
+            "{0} と {1} のショートカットが重複しています",
+        ],
+    ),
+    (
+        "{0} is already used by {1}",
+        ["{0} ya lo usa {1}", "{0} wird bereits von {1} verwendet", "{0} は {1} で使用中です"],
+    ),
+    (
+        "{0} opens the command palette",
+        ["{0} abre la paleta de comandos", "{0} öffnet die Befehlspalette", "{0} はコマンドパレットを開きます"],
+    ),
+    (
+        "{0} can't be used as a shortcut",
+        [
+            "{0} no se puede usar como atajo",
+            "{0} kann nicht als Kürzel verwendet werden",
+            "{0} はショートカットに使えません",
+        ],
+    ),
+    ("Colors", ["Colores", "Farben", "配色"]),
+    ("Clock theme", ["Tema del reloj", "Uhr-Design", "時計のテーマ"]),
+    ("High contrast", ["Alto contraste", "Hoher Kontrast", "ハイコントラスト"]),
+    ("Deuteranopia", ["Deuteranopía", "Deuteranopie", "2型色覚"]),
+    ("Protanopia", ["Protanopía", "Protanopie", "1型色覚"]),
+    ("Tritanopia", ["Tritanopía", "Tritanopie", "3型色覚"]),
+    ("Text contrast", ["Contraste del texto", "Textkontrast", "文字のコントラスト"]),
+    (
+        "High-contrast and color-blind safe colors for every clock",
+        [
+            "Colores de alto contraste y aptos para daltónicos en todos los relojes",
+            "Kontrastreiche, farbenblindsichere Farben für alle Uhren",
+            "すべての時計で高コントラスト・色覚多様性に配慮した配色",
+        ],
+    ),
+    // Zone regions
+    ("Africa", ["África", "Afrika", "アフリカ"]),
+    ("Americas", ["América", "Amerika", "南北アメリカ"]),
+    ("Antarctica", ["Antártida", "Antarktis", "南極"]),
+    ("Asia", ["Asia", "Asien", "アジア"]),
+    ("Atlantic", ["Atlántico", "Atlantik", "大西洋"]),
+    ("Australia & Pacific", ["Australia y Pacífico", "Australien & Pazifik", "オーストラリア・太平洋"]),
+    ("Europe", ["Europa", "Europa", "ヨーロッパ"]),
+    ("Indian Ocean", ["Océano Índico", "Indischer Ozean", "インド洋"]),
+    ("Other zones", ["Otras zonas", "Weitere Zonen", "その他のタイムゾーン"]),
+    // Command palette
+    ("Type a command…", ["Escribe un comando…", "Befehl eingeben…", "コマンドを入力…"]),
+    (
+        "No matching commands",
+        ["No hay comandos que coincidan", "Keine passenden Befehle", "一致するコマンドはありません"],
+    ),
+    (
+        "↑↓ Navigate · Enter Run · Esc Close",
+        [
+            "↑↓ Navegar · Enter Ejecutar · Esc Cerrar",
+            "↑↓ Navigieren · Enter Ausführen · Esc Schließen",
+            "↑↓ 移動 · Enter 実行 · Esc 閉じる",
+        ],
+    ),
+    // Config recovery
+    (
+        "Settings couldn't be read",
+        ["No se pudieron leer los ajustes", "Einstellungen konnten nicht gelesen werden", "設定を読み込めませんでした"],
+    ),
+    (
+        "This clock started with its default settings.",
+        [
+            "Este reloj se inició con los ajustes predeterminados.",
+            "Diese Uhr wurde mit den Standardeinstellungen gestartet.",
+            "この時計は既定の設定で起動しました。",
+        ],
+    ),
+    ("Restore backup", ["Restaurar copia", "Sicherung wiederherstellen", "バックアップを復元"]),
+    (
+        "Restart with the settings last loaded",
+        [
+            "Reiniciar con los últimos ajustes cargados",
+            "Mit den zuletzt geladenen Einstellungen neu starten",
+            "最後に読み込んだ設定で再起動",
+        ],
+    ),
+    ("Keep defaults", ["Mantener predeterminados", "Standard beibehalten", "既定のままにする"]),
+    (
+        "Failed to restore backup: {0}",
+        [
+            "No se pudo restaurar la copia: {0}",
+            "Sicherung konnte nicht wiederhergestellt werden: {0}",
+            "バックアップを復元できませんでした: {0}",
+        ],
+    ),
+    (
+        "Backup restored; restart to apply it ({0})",
+        [
+            "Copia restaurada; reinicia para aplicarla ({0})",
+            "Sicherung wiederhergestellt; zum Übernehmen neu starten ({0})",
+            "バックアップを復元しました。再起動すると反映されます ({0})",
+        ],
+    ),
+    (
+        "Failed to save {0}: {1}",
+        [
+            "No se pudo guardar {0}: {1}",
+            "{0} konnte nicht gespeichert werden: {1}",
+            "{0}を保存できませんでした: {1}",
+        ],
+    ),
+    ("quiet hours", ["las horas de silencio", "Ruhezeiten", "おやすみ時間"]),
+    ("window title setting", ["el título de la ventana", "Fenstertitel", "ウィンドウタイトルの設定"]),
+    ("recording settings", ["los ajustes de grabación", "Aufnahmeeinstellungen", "録画の設定"]),
+    ("language setting", ["el idioma", "Spracheinstellung", "言語の設定"]),
+    ("palette setting", ["la paleta", "Farbpalette", "配色の設定"]),
+    ("system zone setting", ["la zona del sistema", "Systemzeitzone", "システムのタイムゾーン設定"]),
+    // Commands and shortcuts
+    ("All commands", ["Todos los comandos", "Alle Befehle", "すべてのコマンド"]),
+    ("Close panels", ["Cerrar paneles", "Bereiche schließen", "パネルを閉じる"]),
+    ("Close/return", ["Cerrar/volver", "Schließen/zurück", "閉じる/戻る"]),
+    ("Collapse/expand", ["Contraer/expandir", "Ein-/ausklappen", "折りたたむ/展開"]),
+    (
+        "Collapse all blocks",
+        ["Contraer todos los bloques", "Alle Blöcke einklappen", "すべてのブロックを折りたたむ"],
+    ),
+    (
+        "Collapse all chapters",
+        ["Contraer todos los capítulos", "Alle Kapitel einklappen", "すべての章を折りたたむ"],
+    ),
+    ("Commands", ["Comandos", "Befehle", "コマンド"]),
+    (
+        "Compare with another zone",
+        ["Comparar con otra zona", "Mit einer anderen Zone vergleichen", "別のゾーンと比較"],
+    ),
+    ("Cycle focus", ["Cambiar el foco", "Fokus wechseln", "フォーカスを切り替え"]),
+    (
+        "Cycle deck, grid and list views",
+        [
+            "Alternar vistas de baraja, cuadrícula y lista",
+            "Zwischen Stapel-, Raster- und Listenansicht wechseln",
+            "デッキ・グリッド・リスト表示を切り替え",
+        ],
+    ),
+    ("Decode quiz", ["Prueba de lectura", "Entschlüsselungsquiz", "解読クイズ"]),
+    ("Entry details", ["Detalles de la entrada", "Eintragsdetails", "エントリーの詳細"]),
+    ("Expand all blocks", ["Expandir todos los bloques", "Alle Blöcke ausklappen", "すべてのブロックを展開"]),
+    (
+        "Expand all chapters",
+        ["Expandir todos los capítulos", "Alle Kapitel ausklappen", "すべての章を展開"],
+    ),
+    ("Export SVG", ["Exportar SVG", "SVG exportieren", "SVGを書き出す"]),
+    (
+        "Export diagram as SVG",
+        ["Exportar diagrama como SVG", "Diagramm als SVG exportieren", "図をSVGで書き出す"],
+    ),
+    ("Export ledger", ["Exportar registro", "Journal exportieren", "台帳を書き出す"]),
+    (
+        "Export terrain heightmap and profile",
+        [
+            "Exportar mapa de alturas y perfil del terreno",
+            "Höhenkarte und Geländeprofil exportieren",
+            "地形の高さマップと断面を書き出す",
+        ],
+    ),
+    ("Favorite", ["Favorito", "Favorit", "お気に入り"]),
+    (
+        "Focus next favorite",
+        ["Enfocar el siguiente favorito", "Nächsten Favoriten fokussieren", "次のお気に入りへ"],
+    ),
+    (
+        "Focus previous favorite",
+        ["Enfocar el favorito anterior", "Vorherigen Favoriten fokussieren", "前のお気に入りへ"],
+    ),
+    ("Follow event stream", ["Seguir el flujo de eventos", "Ereignisstrom folgen", "イベントストリームを追跡"]),
+    ("Help", ["Ayuda", "Hilfe", "ヘルプ"]),
+    ("Help panel", ["Panel de ayuda", "Hilfebereich", "ヘルプパネル"]),
+    (
+        "Hide time overlay",
+        ["Ocultar la hora superpuesta", "Zeiteinblendung ausblenden", "時刻オーバーレイを隠す"],
+    ),
+    (
+        "Hold to reveal time",
+        ["Mantén pulsado para ver la hora", "Gedrückt halten zeigt die Uhrzeit", "長押しで時刻を表示"],
+    ),
+    ("Make a row home", ["Hacer de una fila la principal", "Zeile zur Heimat machen", "行をホームに設定"]),
+    (
+        "Move favorite left",
+        ["Mover favorito a la izquierda", "Favorit nach links verschieben", "お気に入りを左へ移動"],
+    ),
+    (
+        "Move favorite right",
+        ["Mover favorito a la derecha", "Favorit nach rechts verschieben", "お気に入りを右へ移動"],
+    ),
+    ("Next focus region", ["Siguiente región de foco", "Nächster Fokusbereich", "次のフォーカス領域"]),
+    (
+        "Next hour highlight",
+        ["Siguiente hora resaltada", "Nächste hervorgehobene Stunde", "次の強調時間"],
+    ),
+    ("Next landmark", ["Siguiente hito", "Nächste Landmarke", "次の目印"]),
+    ("Next quiz question", ["Siguiente pregunta", "Nächste Quizfrage", "次のクイズ問題"]),
+    ("Next search match", ["Siguiente coincidencia", "Nächster Suchtreffer", "次の検索結果"]),
+    ("Next waypoint", ["Siguiente punto de ruta", "Nächster Wegpunkt", "次のウェイポイント"]),
+    (
+        "Next/prev match",
+        ["Coincidencia siguiente/anterior", "Nächster/vorheriger Treffer", "次/前の一致"],
+    ),
+    (
+        "Open geometry inspector",
+        ["Abrir inspector de geometría", "Geometrie-Inspektor öffnen", "ジオメトリインスペクターを開く"],
+    ),
+    ("Open time poster", ["Abrir póster horario", "Zeitposter öffnen", "時刻ポスターを開く"]),
+    (
+        "Open timezone picker",
+        ["Abrir selector de zona horaria", "Zeitzonenauswahl öffnen", "タイムゾーン選択を開く"],
+    ),
+    ("Pin waypoint", ["Fijar punto de ruta", "Wegpunkt anheften", "ウェイポイントを固定"]),
+    (
+        "Play/pause session replay",
+        [
+            "Reproducir/pausar la repetición de la sesión",
+            "Sitzungswiedergabe abspielen/pausieren",
+            "セッションの再生/一時停止",
+        ],
+    ),
+    (
+        "Press {0} or Esc to close",
+        ["Pulsa {0} o Esc para cerrar", "{0} oder Esc zum Schließen drücken", "{0} または Esc で閉じる"],
+    ),
+    ("Previous focus region", ["Región de foco anterior", "Vorheriger Fokusbereich", "前のフォーカス領域"]),
+    ("Previous landmark", ["Hito anterior", "Vorherige Landmarke", "前の目印"]),
+    ("Previous search match", ["Coincidencia anterior", "Vorheriger Suchtreffer", "前の検索結果"]),
+    ("Previous waypoint", ["Punto de ruta anterior", "Vorheriger Wegpunkt", "前のウェイポイント"]),
+    ("Record clip", ["Grabar clip", "Clip aufnehmen", "クリップを録画"]),
+    ("Record lap", ["Registrar vuelta", "Runde erfassen", "ラップを記録"]),
+    ("Reset stopwatch", ["Reiniciar cronómetro", "Stoppuhr zurücksetzen", "ストップウォッチをリセット"]),
+    ("Reset view", ["Restablecer vista", "Ansicht zurücksetzen", "表示をリセット"]),
+    (
+        "Return to deck view",
+        ["Volver a la vista de baraja", "Zurück zur Stapelansicht", "デッキ表示に戻る"],
+    ),
+    ("Return to live", ["Volver a en directo", "Zurück zu live", "ライブに戻る"]),
+    ("Return to now", ["Volver a ahora", "Zurück zu jetzt", "現在時刻に戻る"]),
+    ("Scroll down", ["Desplazar hacia abajo", "Nach unten scrollen", "下にスクロール"]),
+    ("Scroll down/up", ["Desplazar abajo/arriba", "Nach unten/oben scrollen", "下/上にスクロール"]),
+    ("Scroll to earlier days", ["Ir a días anteriores", "Zu früheren Tagen scrollen", "前の日へスクロール"]),
+    ("Scroll to later days", ["Ir a días posteriores", "Zu späteren Tagen scrollen", "後の日へスクロール"]),
+    ("Scroll up", ["Desplazar hacia arriba", "Nach oben scrollen", "上にスクロール"]),
+    ("Select zone on map", ["Elegir zona en el mapa", "Zone auf der Karte wählen", "地図でゾーンを選択"]),
+    ("Show fewer days", ["Mostrar menos días", "Weniger Tage zeigen", "表示日数を減らす"]),
+    (
+        "Show highlighted hour",
+        ["Mostrar la hora resaltada", "Hervorgehobene Stunde zeigen", "強調時間を表示"],
+    ),
+    ("Show more days", ["Mostrar más días", "Mehr Tage zeigen", "表示日数を増やす"]),
+    (
+        "Start/stop breathing session",
+        ["Iniciar/detener sesión de respiración", "Atemübung starten/beenden", "呼吸セッションを開始/停止"],
+    ),
+    (
+        "Start/stop decode quiz",
+        [
+            "Iniciar/detener prueba de lectura",
+            "Entschlüsselungsquiz starten/beenden",
+            "解読クイズを開始/停止",
+        ],
+    ),
+    (
+        "Start/stop recording session",
+        [
+            "Iniciar/detener sesión de grabación",
+            "Aufzeichnungssitzung starten/beenden",
+            "記録セッションを開始/停止",
+        ],
+    ),
+    (
+        "Start/stop stopwatch",
+        ["Iniciar/detener cronómetro", "Stoppuhr starten/anhalten", "ストップウォッチを開始/停止"],
+    ),
+    ("Step back 15 minutes", ["Retroceder 15 minutos", "15 Minuten zurück", "15分戻す"]),
+    ("Step back one day", ["Retroceder un día", "Einen Tag zurück", "1日戻す"]),
+    ("Step back one hour", ["Retroceder una hora", "Eine Stunde zurück", "1時間戻す"]),
+    ("Step back one minute", ["Retroceder un minuto", "Eine Minute zurück", "1分戻す"]),
+    ("Step back one second", ["Retroceder un segundo", "Eine Sekunde zurück", "1秒戻す"]),
+    ("Step forward 15 minutes", ["Avanzar 15 minutos", "15 Minuten vor", "15分進める"]),
+    ("Step forward one day", ["Avanzar un día", "Einen Tag vor", "1日進める"]),
+    ("Step forward one hour", ["Avanzar una hora", "Eine Stunde vor", "1時間進める"]),
+    ("Step forward one minute", ["Avanzar un minuto", "Eine Minute vor", "1分進める"]),
+    ("Step forward one second", ["Avanzar un segundo", "Eine Sekunde vor", "1秒進める"]),
+    ("Step time back/fwd", ["Retroceder/avanzar el tiempo", "Zeit zurück/vor", "時間を戻す/進める"]),
+    (
+        "Switch clock/stopwatch mode",
+        [
+            "Cambiar entre reloj y cronómetro",
+            "Zwischen Uhr und Stoppuhr wechseln",
+            "時計/ストップウォッチを切り替え",
+        ],
+    ),
+    (
+        "Toggle 12/24-hour readout",
+        ["Alternar formato de 12/24 horas", "12/24-Stunden-Anzeige umschalten", "12/24時間表示を切り替え"],
+    ),
+    ("Toggle 3D relief", ["Alternar relieve 3D", "3D-Relief umschalten", "3D起伏を切り替え"]),
+    (
+        "Toggle NTP calibration view",
+        [
+            "Alternar vista de calibración NTP",
+            "NTP-Kalibrierungsansicht umschalten",
+            "NTP較正ビューを切り替え",
+        ],
+    ),
+    (
+        "Toggle compare mode",
+        ["Alternar modo de comparación", "Vergleichsmodus umschalten", "比較モードを切り替え"],
+    ),
+    (
+        "Toggle comparison zone",
+        ["Alternar zona de comparación", "Vergleichszone umschalten", "比較ゾーンを切り替え"],
+    ),
+    ("Toggle contour lines", ["Alternar curvas de nivel", "Höhenlinien umschalten", "等高線を切り替え"]),
+    (
+        "Toggle conventional time",
+        ["Alternar hora convencional", "Herkömmliche Zeit umschalten", "通常の時刻表示を切り替え"],
+    ),
+    (
+        "Toggle decode mode",
+        ["Alternar modo de lectura", "Entschlüsselungsmodus umschalten", "解読モードを切り替え"],
+    ),
+    ("Toggle favorite", ["Alternar favorito", "Favorit umschalten", "お気に入りを切り替え"]),
+    (
+        "Toggle focused block",
+        ["Alternar bloque enfocado", "Fokussierten Block umschalten", "選択中のブロックを切り替え"],
+    ),
+    (
+        "Toggle focused chapter",
+        ["Alternar capítulo enfocado", "Fokussiertes Kapitel umschalten", "選択中の章を切り替え"],
+    ),
+    ("Toggle follow mode", ["Alternar modo de seguimiento", "Folgemodus umschalten", "追従モードを切り替え"]),
+    ("Toggle graticule", ["Alternar retícula", "Gradnetz umschalten", "経緯線を切り替え"]),
+    ("Toggle help", ["Alternar ayuda", "Hilfe umschalten", "ヘルプを切り替え"]),
+    ("Toggle labels", ["Alternar etiquetas", "Beschriftungen umschalten", "ラベルを切り替え"]),
+    (
+        "Toggle live/scrub",
+        ["Alternar en directo/desplazamiento", "Live/Scrubben umschalten", "ライブ/スクラブを切り替え"],
+    ),
+    (
+        "Toggle marker labels",
+        [
+            "Alternar etiquetas de marcadores",
+            "Markierungsbeschriftungen umschalten",
+            "マーカーのラベルを切り替え",
+        ],
+    ),
+    (
+        "Toggle meeting planner",
+        ["Alternar planificador de reuniones", "Besprechungsplaner umschalten", "会議プランナーを切り替え"],
+    ),
+    (
+        "Toggle millisecond readout",
+        ["Alternar milisegundos", "Millisekundenanzeige umschalten", "ミリ秒表示を切り替え"],
+    ),
+    ("Toggle pin", ["Alternar fijado", "Anheften umschalten", "固定を切り替え"]),
+    (
+        "Toggle reduced motion",
+        ["Alternar movimiento reducido", "Reduzierte Bewegung umschalten", "モーション軽減を切り替え"],
+    ),
+    (
+        "Toggle secondary UTC readout",
+        ["Alternar lectura UTC secundaria", "Zweite UTC-Anzeige umschalten", "UTC副表示を切り替え"],
+    ),
+    ("Toggle seconds", ["Alternar segundos", "Sekunden umschalten", "秒表示を切り替え"]),
+    ("Toggle sonification", ["Alternar sonificación", "Sonifikation umschalten", "音響化を切り替え"]),
+    (
+        "Toggle time overlay always on",
+        [
+            "Alternar hora superpuesta siempre visible",
+            "Dauerhafte Zeiteinblendung umschalten",
+            "時刻オーバーレイの常時表示を切り替え",
+        ],
+    ),
+    ("Toggle week view", ["Alternar vista semanal", "Wochenansicht umschalten", "週表示を切り替え"]),
+    (
+        "Toggle world clock strip",
+        ["Alternar franja de relojes mundiales", "Weltuhrleiste umschalten", "世界時計バーを切り替え"],
+    ),
+    (
+        "Verify block signatures",
+        ["Verificar firmas de bloques", "Blocksignaturen prüfen", "ブロック署名を検証"],
+    ),
+    ("Verify chain", ["Verificar cadena", "Kette prüfen", "チェーンを検証"]),
+    ("Verify hash chain", ["Verificar cadena de hashes", "Hash-Kette prüfen", "ハッシュチェーンを検証"]),
+    ("View presets", ["Vistas predefinidas", "Ansichtsvorlagen", "表示プリセット"]),
+    ("Zone", ["Zona", "Zone", "ゾーン"]),
+    ("Zoom in", ["Acercar", "Vergrößern", "ズームイン"]),
+    ("Zoom out", ["Alejar", "Verkleinern", "ズームアウト"]),
+    // Clock panels
+    ("Select Time Zone", ["Seleccionar zona horaria", "Zeitzone auswählen", "タイムゾーンを選択"]),
+    ("Search:", ["Buscar:", "Suchen:", "検索:"]),
+    (
+        "Use System Time Zone",
+        ["Usar la zona horaria del sistema", "Systemzeitzone verwenden", "システムのタイムゾーンを使用"],
+    ),
+    ("Favorites:", ["Favoritos:", "Favoriten:", "お気に入り:"]),
+    (
+        "Showing {0} of {1} time zones",
+        [
+            "Mostrando {0} de {1} zonas horarias",
+            "{0} von {1} Zeitzonen angezeigt",
+            "{1}件中{0}件のタイムゾーンを表示",
+        ],
+    ),
+    (
+        "↑↓ Navigate · Enter Select · Esc Close",
+        [
+            "↑↓ Navegar · Enter Seleccionar · Esc Cerrar",
+            "↑↓ Navigieren · Enter Auswählen · Esc Schließen",
+            "↑↓ 移動 · Enter 選択 · Esc 閉じる",
+        ],
+    ),
+    ("Close", ["Cerrar", "Schließen", "閉じる"]),
+    ("Settings", ["Ajustes", "Einstellungen", "設定"]),
+    ("DST Status", ["Estado del horario de verano", "Sommerzeitstatus", "夏時間の状態"]),
+    ("Status:", ["Estado:", "Status:", "状態:"]),
+    ("Daylight Saving Time", ["Horario de verano", "Sommerzeit", "夏時間"]),
+    ("Standard Time", ["Horario estándar", "Normalzeit", "標準時"]),
+    (
+        "No DST transitions within 24 hours.",
+        [
+            "No hay cambios de horario en las próximas 24 horas.",
+            "Keine Zeitumstellung innerhalb von 24 Stunden.",
+            "24時間以内に夏時間の切り替えはありません。",
+        ],
+    ),
+    (
+        "⚠ Upcoming DST Change",
+        ["⚠ Próximo cambio de horario", "⚠ Bevorstehende Zeitumstellung", "⚠ 夏時間の切り替えが近づいています"],
+    ),
+    (
+        "ℹ Recent DST Change",
+        ["ℹ Cambio de horario reciente", "ℹ Kürzliche Zeitumstellung", "ℹ 最近の夏時間の切り替え"],
+    ),
+    (
+        "Clocks will move forward by {0} minutes",
+        [
+            "Los relojes se adelantarán {0} minutos",
+            "Die Uhren werden um {0} Minuten vorgestellt",
+            "時計が{0}分進みます",
+        ],
+    ),
+    (
+        "Clocks will move back by {0} minutes",
+        [
+            "Los relojes se atrasarán {0} minutos",
+            "Die Uhren werden um {0} Minuten zurückgestellt",
+            "時計が{0}分戻ります",
+        ],
+    ),
+    (
+        "Clocks moved forward by {0} minutes",
+        [
+            "Los relojes se adelantaron {0} minutos",
+            "Die Uhren wurden um {0} Minuten vorgestellt",
+            "時計が{0}分進みました",
+        ],
+    ),
+    (
+        "Clocks moved back by {0} minutes",
+        [
+            "Los relojes se atrasaron {0} minutos",
+            "Die Uhren wurden um {0} Minuten zurückgestellt",
+            "時計が{0}分戻りました",
+        ],
+    ),
+    ("At: {0} (local)", ["A las: {0} (hora local)", "Um: {0} (Ortszeit)", "日時: {0} (現地時刻)"]),
+    ("Quick Select:", ["Selección rápida:", "Schnellauswahl:", "クイック選択:"]),
+    ("+{0} more", ["+{0} más", "+{0} weitere", "他{0}件"]),
+    (
+        "Disables continuous animations",
+        [
+            "Desactiva las animaciones continuas",
+            "Deaktiviert fortlaufende Animationen",
+            "連続アニメーションを無効にします",
+        ],
+    ),
+    ("Press R to toggle", ["Pulsa R para alternar", "R zum Umschalten", "Rキーで切り替え"]),
+    (
+        "Reduced motion enabled",
+        ["Movimiento reducido activado", "Reduzierte Bewegung aktiviert", "モーション軽減をオンにしました"],
+    ),
+    (
+        "Reduced motion disabled",
+        ["Movimiento reducido desactivado", "Reduzierte Bewegung deaktiviert", "モーション軽減をオフにしました"],
+    ),
+    ("Theme", ["Tema", "Design", "テーマ"]),
+    ("Clock colors", ["Colores del reloj", "Uhrfarben", "時計の配色"]),
+    ("DST on", ["Horario de verano activo", "Sommerzeit aktiv", "夏時間中"]),
+    ("DST off", ["Sin horario de verano", "Keine Sommerzeit", "夏時間外"]),
+    (
+        "Click to change time zone (or press Space)",
+        [
+            "Haz clic para cambiar de zona horaria (o pulsa Espacio)",
+            "Klicken, um die Zeitzone zu ändern (oder Leertaste drücken)",
+            "クリックしてタイムゾーンを変更 (またはSpaceキー)",
+        ],
+    ),
+    (
+        "Time zone data missing. Showing UTC.",
+        [
+            "Faltan datos de zonas horarias. Se muestra UTC.",
+            "Zeitzonendaten fehlen. UTC wird angezeigt.",
+            "タイムゾーンデータがありません。UTCを表示しています。",
+        ],
+    ),
+    (
+        "Time zone data may be outdated.",
+        [
+            "Los datos de zonas horarias pueden estar desactualizados.",
+            "Die Zeitzonendaten sind möglicherweise veraltet.",
+            "タイムゾーンデータが古い可能性があります。",
+        ],
+    ),
+    (
+        "Unknown time zone issue.",
+        [
+            "Problema desconocido con la zona horaria.",
+            "Unbekanntes Zeitzonenproblem.",
+            "タイムゾーンに不明な問題があります。",
+        ],
+    ),
+    // Precision instrument
+    ("Clock", ["Reloj", "Uhr", "時計"]),
+    ("Stopwatch", ["Cronómetro", "Stoppuhr", "ストップウォッチ"]),
+    (
+        "Press Shift+M to switch",
+        ["Pulsa Shift+M para cambiar", "Umschalt+M zum Wechseln", "Shift+Mで切り替え"],
+    ),
+    ("Compact widget", ["Widget compacto", "Kompaktes Widget", "コンパクトウィジェット"]),
+    (
+        "A small always-on-top readout; drag it to move, press M to restore",
+        [
+            "Una pequeña lectura siempre visible; arrástrala para moverla, pulsa M para restaurar",
+            "Eine kleine Anzeige im Vordergrund; zum Verschieben ziehen, M stellt wieder her",
+            "常に最前面の小さな表示。ドラッグで移動、Mキーで元に戻します",
+        ],
+    ),
+    ("Start", ["Iniciar", "Start", "開始"]),
+    ("Stop", ["Detener", "Stopp", "停止"]),
+    ("Lap", ["Vuelta", "Runde", "ラップ"]),
+    ("Reset", ["Reiniciar", "Zurücksetzen", "リセット"]),
+    (
+        "S start/stop · L lap · Backspace reset",
+        [
+            "S iniciar/detener · L vuelta · Retroceso reiniciar",
+            "S Start/Stopp · L Runde · Rücktaste zurücksetzen",
+            "S 開始/停止 · L ラップ · Backspace リセット",
+        ],
+    ),
+    ("World clock strip", ["Franja de relojes mundiales", "Weltuhrleiste", "世界時計バー"]),
+    (
+        "Every favorite's time under the readout; click one to switch",
+        [
+            "La hora de cada favorito bajo la lectura; haz clic en uno para cambiar",
+            "Die Uhrzeit jedes Favoriten unter der Anzeige; zum Wechseln anklicken",
+            "お気に入りの時刻を表示の下に並べます。クリックで切り替え",
+        ],
+    ),
+    ("Press W to toggle", ["Pulsa W para alternar", "W zum Umschalten", "Wキーで切り替え"]),
+    ("Press H to switch", ["Pulsa H para cambiar", "H zum Wechseln", "Hキーで切り替え"]),
+    ("12-hour", ["12 horas", "12 Stunden", "12時間表示"]),
+    ("Time:", ["Hora:", "Uhrzeit:", "時刻:"]),
+    ("Date:", ["Fecha:", "Datum:", "日付:"]),
+    ("built-in", ["predeterminado", "eingebaut", "既定"]),
+    (
+        "strftime syntax, e.g. %H:%M · %a %-d %b",
+        [
+            "Sintaxis de strftime, p. ej. %H:%M · %a %-d %b",
+            "strftime-Syntax, z. B. %H:%M · %a %-d %b",
+            "strftime形式 (例: %H:%M · %a %-d %b)",
+        ],
+    ),
+    ("Leap second:", ["Segundo intercalar:", "Schaltsekunde:", "うるう秒:"]),
+    ("Step", ["Salto", "Sprung", "ステップ"]),
+    ("Smear", ["Difuminado", "Verschmieren", "スミア"]),
+    (
+        "Show 23:59:60 for the inserted second",
+        [
+            "Muestra 23:59:60 durante el segundo insertado",
+            "Zeigt 23:59:60 für die eingefügte Sekunde",
+            "挿入された秒に23:59:60を表示します",
+        ],
+    ),
+    (
+        "Run slow over the surrounding day instead",
+        [
+            "En su lugar, avanza más despacio durante el día",
+            "Läuft stattdessen über den umgebenden Tag langsamer",
+            "代わりに前後一日をかけてゆっくり進めます",
+        ],
+    ),
+    ("TAI and GPS time", ["Hora TAI y GPS", "TAI- und GPS-Zeit", "TAI時刻とGPS時刻"]),
+    ("Milliseconds (P)", ["Milisegundos (P)", "Millisekunden (P)", "ミリ秒 (P)"]),
+    (
+        "Add milliseconds and a once-per-second sweep inside the ring",
+        [
+            "Añade milisegundos y un barrido por segundo dentro del anillo",
+            "Fügt Millisekunden und einen sekündlichen Schwenk im Ring hinzu",
+            "リング内にミリ秒と1秒ごとのスイープを追加します",
+        ],
+    ),
+    ("Secondary readout", ["Lectura secundaria", "Zweite Anzeige", "副表示"]),
+    ("Press U to toggle", ["Pulsa U para alternar", "U zum Umschalten", "Uキーで切り替え"]),
+    ("NTP calibration", ["Calibración NTP", "NTP-Kalibrierung", "NTPキャリブレーション"]),
+    (
+        "Compare the system clock against an NTP server",
+        [
+            "Compara el reloj del sistema con un servidor NTP",
+            "Vergleicht die Systemuhr mit einem NTP-Server",
+            "システム時計をNTPサーバーと比較します",
+        ],
+    ),
+    ("Server:", ["Servidor:", "Server:", "サーバー:"]),
+    ("Measuring…", ["Midiendo…", "Messung läuft…", "測定中…"]),
+    ("Measure now", ["Medir ahora", "Jetzt messen", "今すぐ測定"]),
+    ("Press N to toggle", ["Pulsa N para alternar", "N zum Umschalten", "Nキーで切り替え"]),
+    ("Chime:", ["Campanada:", "Glockenschlag:", "チャイム:"]),
+    ("Hour", ["Hora", "Stunde", "正時"]),
+    ("Half hour", ["Media hora", "Halbe Stunde", "半時"]),
+    ("Ship's bells", ["Campanas de barco", "Schiffsglocken", "船鐘"]),
+    ("Tones", ["Tonos", "Töne", "トーン"]),
+    ("Count", ["Contar", "Zählen", "回数"]),
+    ("Single", ["Único", "Einzeln", "1回"]),
+    (
+        "1 to 8 bells through each four-hour watch",
+        [
+            "De 1 a 8 campanadas en cada guardia de cuatro horas",
+            "1 bis 8 Glasen in jeder vierstündigen Wache",
+            "4時間の当直ごとに1〜8点鐘",
+        ],
+    ),
+    (
+        "The hour's count on the hour, one on the half hour",
+        [
+            "Las horas en punto, una a la media",
+            "Zur vollen Stunde die Stundenzahl, zur halben einmal",
+            "正時は時の数だけ、半時は1回",
+        ],
+    ),
+    ("One strike each time", ["Una campanada cada vez", "Jedes Mal ein Schlag", "毎回1回"]),
+    ("Sound", ["Sonido", "Ton", "音"]),
+    ("Flash", ["Destello", "Blitz", "フラッシュ"]),
+    (
+        "Flash the panel with each strike, for silent rooms",
+        [
+            "Hace destellar el panel con cada campanada, para salas en silencio",
+            "Lässt die Fläche bei jedem Schlag aufblitzen, für stille Räume",
+            "打つたびにパネルを光らせます (静かな部屋向け)",
+        ],
+    ),
+    (
+        "No audio output device found",
+        [
+            "No se encontró ningún dispositivo de salida de audio",
+            "Kein Audioausgabegerät gefunden",
+            "音声出力デバイスが見つかりません",
+        ],
+    ),
+    (
+        "Built without audio (enable the `audio` feature)",
+        [
+            "Compilado sin audio (activa la función `audio`)",
+            "Ohne Audio gebaut (Feature `audio` aktivieren)",
+            "音声なしでビルドされています (`audio`機能を有効にしてください)",
+        ],
+    ),
+    ("Test chime", ["Probar campanada", "Glockenschlag testen", "チャイムをテスト"]),
+    (
+        "Quiet hours silence chimes and hold the flash",
+        [
+            "Las horas de silencio silencian las campanadas y detienen el destello",
+            "Ruhezeiten schalten Glockenschläge stumm und halten den Blitz an",
+            "おやすみ時間はチャイムとフラッシュを止めます",
+        ],
+    ),
+    ("Clock mode", ["Modo reloj", "Uhrmodus", "時計モード"]),
+    ("Stopwatch mode", ["Modo cronómetro", "Stoppuhrmodus", "ストップウォッチモード"]),
+    (
+        "World clock strip shown",
+        ["Franja de relojes mundiales visible", "Weltuhrleiste eingeblendet", "世界時計バーを表示しました"],
+    ),
+    (
+        "World clock strip hidden",
+        ["Franja de relojes mundiales oculta", "Weltuhrleiste ausgeblendet", "世界時計バーを非表示にしました"],
+    ),
+    (
+        "NTP calibration: {0}",
+        ["Calibración NTP: {0}", "NTP-Kalibrierung: {0}", "NTPキャリブレーション: {0}"],
+    ),
+    (
+        "NTP calibration hidden",
+        ["Calibración NTP oculta", "NTP-Kalibrierung ausgeblendet", "NTPキャリブレーションを非表示にしました"],
+    ),
+    (
+        "NTP measurement failed: {0}",
+        ["La medición NTP falló: {0}", "NTP-Messung fehlgeschlagen: {0}", "NTP測定に失敗しました: {0}"],
+    ),
+    ("Stopwatch reset", ["Cronómetro reiniciado", "Stoppuhr zurückgesetzt", "ストップウォッチをリセットしました"]),
+    ("Chime: {0}", ["Campanada: {0}", "Glockenschlag: {0}", "チャイム: {0}"]),
+    ("{0} bell", ["{0} campanada", "{0} Glas", "{0}点鐘"]),
+    ("{0} bells", ["{0} campanadas", "{0} Glasen", "{0}点鐘"]),
+    ("{0} strike", ["{0} golpe", "{0} Schlag", "{0}回"]),
+    ("{0} strikes", ["{0} golpes", "{0} Schläge", "{0}回"]),
+    ("12-hour readout", ["Lectura de 12 horas", "12-Stunden-Anzeige", "12時間表示に切り替えました"]),
+    ("24-hour readout", ["Lectura de 24 horas", "24-Stunden-Anzeige", "24時間表示に切り替えました"]),
+    (
+        "Millisecond readout on",
+        ["Milisegundos activados", "Millisekundenanzeige an", "ミリ秒表示をオンにしました"],
+    ),
+    (
+        "Millisecond readout off",
+        ["Milisegundos desactivados", "Millisekundenanzeige aus", "ミリ秒表示をオフにしました"],
+    ),
+    ("Secondary readout: {0}", ["Lectura secundaria: {0}", "Zweite Anzeige: {0}", "副表示: {0}"]),
+    (
+        "Secondary readout hidden",
+        ["Lectura secundaria oculta", "Zweite Anzeige ausgeblendet", "副表示を非表示にしました"],
+    ),
+    (
+        "←/→ focus a favorite, Enter selects, Ctrl+←/→ reorders",
+        [
+            "←/→ enfocan un favorito, Enter lo selecciona, Ctrl+←/→ lo reordena",
+            "←/→ Favorit fokussieren, Enter wählt aus, Strg+←/→ sortiert um",
+            "←/→ でお気に入りを選び、Enterで決定、Ctrl+←/→ で並べ替え",
+        ],
+    ),
+    ("Display: 1s tick", ["Pantalla: tic de 1 s", "Anzeige: 1-s-Takt", "表示: 1秒刻み"]),
+    (
+        "System tick: 60fps / Display: 1s",
+        [
+            "Sistema: 60 fps / Pantalla: 1 s",
+            "Systemtakt: 60 fps / Anzeige: 1 s",
+            "システム: 60fps / 表示: 1秒",
+        ],
+    ),
+    ("RUNNING", ["EN MARCHA", "LÄUFT", "計測中"]),
+    ("STOPPED", ["DETENIDO", "GESTOPPT", "停止"]),
+    ("READY", ["LISTO", "BEREIT", "準備完了"]),
+    (
+        "Lap {0}   +{1}   {2}",
+        ["Vuelta {0}   +{1}   {2}", "Runde {0}   +{1}   {2}", "ラップ {0}   +{1}   {2}"],
+    ),
+    ("+{0} earlier laps", ["+{0} vueltas anteriores", "+{0} frühere Runden", "ほか {0} 件の前のラップ"]),
+    ("MEASURING…", ["MIDIENDO…", "MESSUNG…", "測定中…"]),
+    ("NO NTP MEASUREMENT", ["SIN MEDICIÓN NTP", "KEINE NTP-MESSUNG", "NTP 測定なし"]),
+    ("OFFSET {0}", ["DESFASE {0}", "ABWEICHUNG {0}", "オフセット {0}"]),
+    ("RTT {0}", ["RTT {0}", "RTT {0}", "往復 {0}"]),
+    ("DRIFT {0} ppm", ["DERIVA {0} ppm", "DRIFT {0} ppm", "ドリフト {0} ppm"]),
+    (
+        "DRIFT — (needs 1 min of samples)",
+        [
+            "DERIVA — (necesita 1 min de muestras)",
+            "DRIFT — (braucht 1 min Messwerte)",
+            "ドリフト — (1分間の測定が必要)",
+        ],
+    ),
+    (
+        "DST change: forward {0}min in <24h",
+        [
+            "Cambio de horario: adelanto de {0} min en <24 h",
+            "Zeitumstellung: {0} min vor in <24 h",
+            "夏時間切替: 24時間以内に {0}分進む",
+        ],
+    ),
+    (
+        "DST change: back {0}min in <24h",
+        [
+            "Cambio de horario: retraso de {0} min en <24 h",
+            "Zeitumstellung: {0} min zurück in <24 h",
+            "夏時間切替: 24時間以内に {0}分戻る",
+        ],
+    ),
+    (
+        "DST changed: forward {0}min",
+        [
+            "Cambio de horario: adelanto de {0} min",
+            "Zeit umgestellt: {0} min vor",
+            "夏時間切替済み: {0}分進んだ",
+        ],
+    ),
+    (
+        "DST changed: back {0}min",
+        [
+            "Cambio de horario: retraso de {0} min",
+            "Zeit umgestellt: {0} min zurück",
+            "夏時間切替済み: {0}分戻った",
+        ],
+    ),
+    ("{0} · {1} soon", ["{0} · {1} pronto", "{0} · bald {1}", "{0} · まもなく {1}"]),
+    (
+        "Leap second at {0} UTC ({1})",
+        [
+            "Segundo intercalar a las {0} UTC ({1})",
+            "Schaltsekunde um {0} UTC ({1})",
+            "うるう秒 {0} UTC ({1})",
+        ],
+    ),
+    ("Leap second in progress", ["Segundo intercalar en curso", "Schaltsekunde läuft", "うるう秒の挿入中"]),
+    (
+        "Leap second inserted · TAI−UTC now {0} s",
+        [
+            "Segundo intercalar insertado · TAI−UTC ahora {0} s",
+            "Schaltsekunde eingefügt · TAI−UTC jetzt {0} s",
+            "うるう秒を挿入 · TAI−UTC は {0} 秒に",
+        ],
+    ),
+    ("{0} (next day)", ["{0} (día siguiente)", "{0} (nächster Tag)", "{0} (翌日)"]),
+    ("{0} (previous day)", ["{0} (día anterior)", "{0} (Vortag)", "{0} (前日)"]),
+    ("{0} ({1} days)", ["{0} ({1} días)", "{0} ({1} Tage)", "{0} ({1}日)"]),
+    // Worldline ribbon
+    ("Lanes ({0}/{1}):", ["Carriles ({0}/{1}):", "Spuren ({0}/{1}):", "レーン ({0}/{1}):"]),
+    (
+        "Use \"+ Lane\" to stack another zone under the ribbon.",
+        [
+            "Usa \"+ Carril\" para apilar otra zona bajo la cinta.",
+            "Mit \"+ Spur\" eine weitere Zone unter dem Band stapeln.",
+            "「+ レーン」でリボンの下に別のゾーンを重ねます。",
+        ],
+    ),
+    ("Remove lane", ["Quitar carril", "Spur entfernen", "レーンを削除"]),
+    (
+        "{0} time zones found",
+        ["{0} zonas horarias encontradas", "{0} Zeitzonen gefunden", "{0}件のタイムゾーンが見つかりました"],
+    ),
+    ("+ Lane", ["+ Carril", "+ Spur", "+ レーン"]),
+    ("Controls", ["Controles", "Steuerung", "操作"]),
+    ("⏱ Return to Now", ["⏱ Volver al presente", "⏱ Zurück zu jetzt", "⏱ 現在に戻る"]),
+    ("Step Time:", ["Avanzar tiempo:", "Zeit schrittweise:", "時間を送る:"]),
+    ("Zoom:", ["Zoom:", "Zoom:", "ズーム:"]),
+    (
+        "Snap to nearest day",
+        ["Ajustar al día más cercano", "Am nächsten Tag einrasten", "最も近い日に合わせる"],
+    ),
+    (
+        "Snap to nearest hour",
+        ["Ajustar a la hora más cercana", "An der nächsten Stunde einrasten", "最も近い時に合わせる"],
+    ),
+    (
+        "Snap to nearest minute",
+        ["Ajustar al minuto más cercano", "An der nächsten Minute einrasten", "最も近い分に合わせる"],
+    ),
+    ("ISO week numbers", ["Números de semana ISO", "ISO-Kalenderwochen", "ISO週番号"]),
+    ("Day of year", ["Día del año", "Tag des Jahres", "年間通算日"]),
+    (
+        "Shown under date ticks when zoomed in to days",
+        [
+            "Se muestra bajo las marcas de fecha al ampliar a días",
+            "Wird unter den Datumsmarken angezeigt, wenn auf Tage gezoomt ist",
+            "日単位まで拡大すると日付目盛りの下に表示します",
+        ],
+    ),
+    (
+        "Disables warp effect and drag momentum",
+        [
+            "Desactiva el efecto de distorsión y la inercia al arrastrar",
+            "Deaktiviert den Warp-Effekt und den Schwung beim Ziehen",
+            "ワープ効果とドラッグの慣性を無効にします",
+        ],
+    ),
+    ("Bookmarks", ["Marcadores", "Lesezeichen", "ブックマーク"]),
+    (
+        "No bookmarks in this zone yet.",
+        [
+            "Todavía no hay marcadores en esta zona.",
+            "Noch keine Lesezeichen in dieser Zone.",
+            "このゾーンにはまだブックマークがありません。",
+        ],
+    ),
+    ("Jump to", ["Ir a", "Springen zu", "ジャンプ"]),
+    ("Remove", ["Quitar", "Entfernen", "削除"]),
+    ("Label", ["Etiqueta", "Bezeichnung", "ラベル"]),
+    ("Add at cursor", ["Añadir en el cursor", "Am Cursor hinzufügen", "カーソル位置に追加"]),
+    (
+        "Import CSV (label,instant):",
+        [
+            "Importar CSV (etiqueta,instante):",
+            "CSV importieren (Bezeichnung,Zeitpunkt):",
+            "CSVをインポート (ラベル,日時):",
+        ],
+    ),
+    ("Import", ["Importar", "Importieren", "インポート"]),
+    (
+        "Imported {0} of {1} bookmarks",
+        [
+            "Se importaron {0} de {1} marcadores",
+            "{0} von {1} Lesezeichen importiert",
+            "{1}件中{0}件のブックマークをインポートしました",
+        ],
+    ),
+    ("Selection", ["Selección", "Auswahl", "選択範囲"]),
+    ("From:", ["Desde:", "Von:", "開始:"]),
+    ("To:", ["Hasta:", "Bis:", "終了:"]),
+    ("Elapsed:", ["Transcurrido:", "Verstrichen:", "経過時間:"]),
+    ("Wall clock:", ["Reloj de pared:", "Wanduhr:", "壁時計:"]),
+    (
+        "Includes a {0} DST gap",
+        [
+            "Incluye un salto de horario de verano de {0}",
+            "Enthält eine Sommerzeitlücke von {0}",
+            "{0}の夏時間の欠落を含みます",
+        ],
+    ),
+    (
+        "Includes a {0} DST overlap",
+        [
+            "Incluye una superposición de horario de verano de {0}",
+            "Enthält eine Sommerzeitüberlappung von {0}",
+            "{0}の夏時間の重複を含みます",
+        ],
+    ),
+    ("📋 Copy", ["📋 Copiar", "📋 Kopieren", "📋 コピー"]),
+    ("Clear", ["Borrar", "Löschen", "クリア"]),
+    ("Selection copied", ["Selección copiada", "Auswahl kopiert", "選択範囲をコピーしました"]),
+    ("Linked to {0}", ["Vinculado a {0}", "Verknüpft mit {0}", "{0}にリンクしました"]),
+    ("At: {0}", ["A las: {0}", "Um: {0}", "日時: {0}"]),
+    (
+        "Click to change time zone",
+        [
+            "Haz clic para cambiar de zona horaria",
+            "Klicken, um die Zeitzone zu ändern",
+            "クリックしてタイムゾーンを変更",
+        ],
+    ),
+    (
+        "⚠ DST {0}m soon",
+        ["⚠ Horario de verano {0} min pronto", "⚠ Sommerzeit {0} Min. bald", "⚠ まもなく夏時間 {0}分"],
+    ),
+    (
+        "DST {0}m occurred",
+        ["Horario de verano {0} min aplicado", "Sommerzeit {0} Min. erfolgt", "夏時間 {0}分 切り替え済み"],
+    ),
+    ("DST {0}m", ["Cambio {0} min", "Umstellung {0} min", "夏時間 {0}分"]),
+    ("Before: {0}", ["Antes: {0}", "Vorher: {0}", "前: {0}"]),
+    ("After: {0}", ["Después: {0}", "Nachher: {0}", "後: {0}"]),
+    ("◆ SCRUB MODE ◆", ["◆ MODO EXPLORAR ◆", "◆ SCRUB-MODUS ◆", "◆ スクラブモード ◆"]),
+    ("Toggle Live/Scrub", ["En directo/explorar", "Live/Scrub umschalten", "ライブ/スクラブ切替"]),
+    ("±1 sec", ["±1 s", "±1 s", "±1秒"]),
+    ("±1 min", ["±1 min", "±1 Min.", "±1分"]),
+    ("±1 hr", ["±1 h", "±1 Std.", "±1時間"]),
+    ("Zoom", ["Zoom", "Zoomen", "ズーム"]),
+    ("Search TZ", ["Buscar zona", "Zone suchen", "ゾーン検索"]),
+    ("Shift+drag", ["Mayús+arrastrar", "Umschalt+Ziehen", "Shift+ドラッグ"]),
+    ("Measure", ["Medir", "Messen", "計測"]),
+    ("leap second", ["segundo intercalar", "Schaltsekunde", "うるう秒"]),
+    // Temporal topography
+    (
+        "No waypoints in this zone yet",
+        [
+            "Todavía no hay puntos de referencia en esta zona",
+            "Noch keine Wegpunkte in dieser Zone",
+            "このゾーンにはまだウェイポイントがありません",
+        ],
+    ),
+    (
+        "Inspect this waypoint",
+        ["Inspeccionar este punto de referencia", "Diesen Wegpunkt untersuchen", "このウェイポイントを調べる"],
+    ),
+    ("note", ["nota", "Notiz", "メモ"]),
+    ("📌 Pin", ["📌 Fijar", "📌 Anheften", "📌 ピン留め"]),
+    (
+        "P pins the cursor  •  ↑/↓ cycle waypoints",
+        [
+            "P fija el cursor  •  ↑/↓ recorren los puntos de referencia",
+            "P heftet den Cursor an  •  ↑/↓ wechseln Wegpunkte",
+            "Pでカーソル位置をピン留め  •  ↑/↓ でウェイポイントを巡回",
+        ],
+    ),
+    (
+        "Click the map to choose a time to pin",
+        [
+            "Haz clic en el mapa para elegir una hora que fijar",
+            "Auf die Karte klicken, um eine Zeit zum Anheften zu wählen",
+            "地図をクリックしてピン留めする時刻を選びます",
+        ],
+    ),
+    ("Mo", ["Lu", "Mo", "月"]),
+    ("Tu", ["Ma", "Di", "火"]),
+    ("We", ["Mi", "Mi", "水"]),
+    ("Th", ["Ju", "Do", "木"]),
+    ("Fr", ["Vi", "Fr", "金"]),
+    ("Sa", ["Sá", "Sa", "土"]),
+    ("Su", ["Do", "So", "日"]),
+    ("Blend", ["Mezcla", "Mischung", "ブレンド"]),
+    ("Copy to all days", ["Copiar a todos los días", "Auf alle Tage kopieren", "すべての曜日にコピー"]),
+    (
+        "Give every weekday this curve",
+        [
+            "Aplica esta curva a todos los días de la semana",
+            "Diese Kurve für jeden Wochentag übernehmen",
+            "すべての曜日にこの曲線を適用します",
+        ],
+    ),
+    (
+        "Restore this weekday's default curve",
+        [
+            "Restaura la curva predeterminada de este día",
+            "Standardkurve dieses Wochentags wiederherstellen",
+            "この曜日の既定の曲線に戻します",
+        ],
+    ),
+    (
+        "Drag points  •  double-click adds  •  right-click removes\n\
+         Blend: synthetic waves ← → this curve",
+        [
+            "Arrastra los puntos  •  doble clic añade  •  clic derecho quita\n\
+             Mezcla: ondas sintéticas ← → esta curva",
+            "Punkte ziehen  •  Doppelklick fügt hinzu  •  Rechtsklick entfernt\n\
+             Mischung: synthetische Wellen ← → diese Kurve",
+            "点をドラッグ  •  ダブルクリックで追加  •  右クリックで削除\n\
+             ブレンド: 合成の波 ← → この曲線",
+        ],
+    ),
+    ("Current Time", ["Hora actual", "Aktuelle Uhrzeit", "現在時刻"]),
+    ("◆ INSPECT MODE", ["◆ MODO INSPECCIÓN", "◆ UNTERSUCHUNGSMODUS", "◆ 調査モード"]),
+    (
+        "⚠ Nonexistent time (DST gap)",
+        [
+            "⚠ Hora inexistente (salto de horario de verano)",
+            "⚠ Nicht existierende Zeit (Sommerzeitlücke)",
+            "⚠ 存在しない時刻 (夏時間の欠落)",
+        ],
+    ),
+    (
+        "⚠ Ambiguous time (DST overlap)",
+        [
+            "⚠ Hora ambigua (superposición de horario de verano)",
+            "⚠ Mehrdeutige Zeit (Sommerzeitüberlappung)",
+            "⚠ あいまいな時刻 (夏時間の重複)",
+        ],
+    ),
+    ("Return to Now", ["Volver al presente", "Zurück zu jetzt", "現在に戻る"]),
+    ("▶ DAY REPLAY", ["▶ REPETICIÓN DEL DÍA", "▶ TAGESWIEDERGABE", "▶ 一日のリプレイ"]),
+    ("Stop Replay", ["Detener repetición", "Wiedergabe stoppen", "リプレイを停止"]),
+    ("Time Zone", ["Zona horaria", "Zeitzone", "タイムゾーン"]),
+    ("Compare", ["Comparar", "Vergleichen", "比較"]),
+    (
+        "Overlay a second zone (O)",
+        ["Superponer una segunda zona (O)", "Zweite Zone überlagern (O)", "2つ目のゾーンを重ねる (O)"],
+    ),
+    ("Choose a zone…", ["Elige una zona…", "Zone wählen…", "ゾーンを選択…"]),
+    (
+        "Click to change the comparison zone",
+        [
+            "Haz clic para cambiar la zona de comparación",
+            "Klicken, um die Vergleichszone zu ändern",
+            "クリックして比較ゾーンを変更",
+        ],
+    ),
+    (
+        "Its terrain is traced in teal on the day map",
+        [
+            "Su terreno se traza en verde azulado en el mapa del día",
+            "Ihr Gelände wird auf der Tageskarte türkis nachgezeichnet",
+            "その地形は日の地図に青緑色で描かれます",
+        ],
+    ),
+    ("Waypoints", ["Puntos de referencia", "Wegpunkte", "ウェイポイント"]),
+    ("Day Replay", ["Repetición del día", "Tageswiedergabe", "一日のリプレイ"]),
+    ("▶ Replay Day", ["▶ Repetir el día", "▶ Tag wiedergeben", "▶ 一日をリプレイ"]),
+    (
+        "How long the sweep from midnight to midnight takes",
+        [
+            "Cuánto dura el recorrido de medianoche a medianoche",
+            "Wie lange der Durchlauf von Mitternacht bis Mitternacht dauert",
+            "真夜中から真夜中までの再生にかける時間",
+        ],
+    ),
+    (
+        "Sweeps the beacon across the day, pausing to explain each DST fault",
+        [
+            "Recorre el día con la baliza y se detiene a explicar cada falla de horario de verano",
+            "Führt die Bake durch den Tag und hält an jeder Sommerzeitverwerfung zur Erklärung an",
+            "ビーコンで一日をたどり、夏時間の断層ごとに止まって説明します",
+        ],
+    ),
+    ("Terrain", ["Terreno", "Gelände", "地形"]),
+    ("Synthetic", ["Sintético", "Synthetisch", "合成"]),
+    ("Calendar", ["Calendario", "Kalender", "カレンダー"]),
+    ("Custom", ["Personalizado", "Eigene", "カスタム"]),
+    ("ICS file path", ["Ruta del archivo ICS", "Pfad zur ICS-Datei", "ICSファイルのパス"]),
+    ("Load Calendar", ["Cargar calendario", "Kalender laden", "カレンダーを読み込む"]),
+    (
+        "Meetings raise the terrain; free time forms valleys",
+        [
+            "Las reuniones elevan el terreno; el tiempo libre forma valles",
+            "Besprechungen heben das Gelände an; freie Zeit bildet Täler",
+            "会議で地形が盛り上がり、空き時間が谷になります",
+        ],
+    ),
+    ("Export Terrain (E)", ["Exportar terreno (E)", "Gelände exportieren (E)", "地形をエクスポート (E)"]),
+    (
+        "Save today's terrain as a PNG heightmap and a CSV profile",
+        [
+            "Guarda el terreno de hoy como mapa de alturas PNG y perfil CSV",
+            "Speichert das heutige Gelände als PNG-Höhenkarte und CSV-Profil",
+            "今日の地形をPNGの高さマップとCSVプロファイルとして保存します",
+        ],
+    ),
+    ("View", ["Vista", "Ansicht", "表示"]),
+    ("Day", ["Día", "Tag", "日"]),
+    ("Week", ["Semana", "Woche", "週"]),
+    (
+        "W toggles week  •  -/= zoom  •  ,/. scroll",
+        [
+            "W alterna la semana  •  -/= zoom  •  ,/. desplazar",
+            "W schaltet die Woche um  •  -/= Zoom  •  ,/. Blättern",
+            "W 週表示の切り替え  •  -/= ズーム  •  ,/. スクロール",
+        ],
+    ),
+    ("Legend", ["Leyenda", "Legende", "凡例"]),
+    ("Contour lines (C)", ["Curvas de nivel (C)", "Höhenlinien (C)", "等高線 (C)"]),
+    ("3D relief (D)", ["Relieve 3D (D)", "3D-Relief (D)", "3Dレリーフ (D)"]),
+    ("Map Summary", ["Resumen del mapa", "Kartenübersicht", "地図の概要"]),
+    (
+        "Disables beacon pulse animation",
+        [
+            "Desactiva la animación de pulso de la baliza",
+            "Deaktiviert die pulsierende Bake",
+            "ビーコンの点滅アニメーションを無効にします",
+        ],
+    ),
+    (
+        "No transitions within 24 hours",
+        [
+            "Sin cambios de horario en 24 horas",
+            "Keine Umstellung innerhalb von 24 Stunden",
+            "24時間以内に切り替えはありません",
+        ],
+    ),
+    (
+        "Clocks will move forward by {0} min",
+        [
+            "Los relojes se adelantarán {0} min",
+            "Die Uhren werden um {0} Min. vorgestellt",
+            "時計が{0}分進みます",
+        ],
+    ),
+    (
+        "Clocks will move back by {0} min",
+        [
+            "Los relojes se atrasarán {0} min",
+            "Die Uhren werden um {0} Min. zurückgestellt",
+            "時計が{0}分戻ります",
+        ],
+    ),
+    (
+        "Clocks moved forward by {0} min",
+        [
+            "Los relojes se adelantaron {0} min",
+            "Die Uhren wurden um {0} Min. vorgestellt",
+            "時計が{0}分進みました",
+        ],
+    ),
+    (
+        "Clocks moved back by {0} min",
+        [
+            "Los relojes se atrasaron {0} min",
+            "Die Uhren wurden um {0} Min. zurückgestellt",
+            "時計が{0}分戻りました",
+        ],
+    ),
+    ("Inspecting mode", ["Modo de inspección", "Untersuchungsmodus", "調査モード"]),
+    ("Live mode", ["Modo en directo", "Live-Modus", "ライブモード"]),
+    ("descending into valley", ["descendiendo hacia el valle", "absteigend ins Tal", "谷へ下っています"]),
+    ("at valley floor", ["en el fondo del valle", "am Talboden", "谷底にいます"]),
+    ("ascending toward peak", ["ascendiendo hacia la cima", "aufsteigend zum Gipfel", "頂上へ登っています"]),
+    ("approaching peak", ["acercándose a la cima", "kurz vor dem Gipfel", "頂上に近づいています"]),
+    ("Daylight Saving Time active", ["Horario de verano activo", "Sommerzeit aktiv", "夏時間が有効です"]),
+    (
+        "{0}. Current position: {1} {2}. \
+         The beacon marks {1} on the day map, {3}% through the day. \
+         Terrain is {4}. {5}.",
+        [
+            "{0}. Posición actual: {1} {2}. \
+             La baliza marca {1} en el mapa del día, al {3}% del día. \
+             El terreno está {4}. {5}.",
+            "{0}. Aktuelle Position: {1} {2}. \
+             Die Bake markiert {1} auf der Tageskarte, {3} % des Tages. \
+             Das Gelände ist {4}. {5}.",
+            "{0}。現在位置: {1} {2}。\
+             ビーコンは日の地図上の{1}を示し、一日の{3}%の地点です。\
+             地形は{4}。{5}。",
+        ],
+    ),
+    (
+        "⚠ Time zone data missing. Showing UTC.",
+        [
+            "⚠ Faltan datos de zonas horarias. Se muestra UTC.",
+            "⚠ Zeitzonendaten fehlen. UTC wird angezeigt.",
+            "⚠ タイムゾーンデータがありません。UTCを表示しています。",
+        ],
+    ),
+    (
+        "⚠ Time zone data may be outdated.",
+        [
+            "⚠ Los datos de zonas horarias pueden estar desactualizados.",
+            "⚠ Die Zeitzonendaten sind möglicherweise veraltet.",
+            "⚠ タイムゾーンデータが古い可能性があります。",
+        ],
+    ),
+    (
+        "⚠ Time zone validity unknown.",
+        [
+            "⚠ Validez de la zona horaria desconocida.",
+            "⚠ Gültigkeit der Zeitzone unbekannt.",
+            "⚠ タイムゾーンの有効性は不明です。",
+        ],
+    ),
+    ("Reading the Map:", ["Cómo leer el mapa:", "Die Karte lesen:", "地図の読み方:"]),
+    (
+        "Peaks = late in hour",
+        ["Cimas = final de la hora", "Gipfel = spät in der Stunde", "山 = 時の終わり"],
+    ),
+    (
+        "Valleys = early in hour",
+        ["Valles = principio de la hora", "Täler = früh in der Stunde", "谷 = 時の始め"],
+    ),
+    ("Temporal Intensity:", ["Intensidad temporal:", "Zeitliche Intensität:", "時間の強度:"]),
+    ("Still", ["Quieto", "Still", "静止"]),
+    ("Calm", ["Calmado", "Ruhig", "穏やか"]),
+    ("Steady", ["Estable", "Stetig", "安定"]),
+    ("Busy", ["Ocupado", "Beschäftigt", "多忙"]),
+    ("Peak", ["Cima", "Gipfel", "ピーク"]),
+    (
+        "Contour at each band edge",
+        ["Curva de nivel en cada límite de banda", "Höhenlinie an jeder Bandgrenze", "各帯の境界に等高線"],
+    ),
+    ("Locator Beacon (now)", ["Baliza de ubicación (ahora)", "Ortungsbake (jetzt)", "位置ビーコン (現在)"]),
+    ("Hour boundaries", ["Límites de hora", "Stundengrenzen", "時の境界"]),
+    ("15-minute marks", ["Marcas de 15 minutos", "15-Minuten-Markierungen", "15分目盛り"]),
+    ("DST fault line", ["Falla de horario de verano", "Sommerzeit-Verwerfung", "夏時間の断層線"]),
+    ("Interactions:", ["Interacciones:", "Bedienung:", "操作:"]),
+    (
+        "Click map to inspect time",
+        [
+            "Haz clic en el mapa para inspeccionar una hora",
+            "Karte anklicken, um eine Zeit zu untersuchen",
+            "地図をクリックして時刻を調べる",
+        ],
+    ),
+    (
+        "Arrow keys to navigate",
+        ["Flechas para desplazarse", "Pfeiltasten zum Navigieren", "矢印キーで移動"],
+    ),
+    (
+        "Esc to return to now",
+        ["Esc para volver al presente", "Esc kehrt zu jetzt zurück", "Escで現在に戻る"],
+    ),
+    (
+        "Compare With Time Zone",
+        ["Comparar con la zona horaria", "Mit Zeitzone vergleichen", "比較するタイムゾーン"],
+    ),
+    (
+        "⚠ Nonexistent (DST gap)",
+        [
+            "⚠ Inexistente (salto de horario de verano)",
+            "⚠ Nicht existent (Sommerzeitlücke)",
+            "⚠ 存在しない (夏時間の欠落)",
+        ],
+    ),
+    (
+        "⚠ Ambiguous (DST overlap)",
+        [
+            "⚠ Ambigua (superposición de horario de verano)",
+            "⚠ Mehrdeutig (Sommerzeitüberlappung)",
+            "⚠ あいまい (夏時間の重複)",
+        ],
+    ),
+    ("Pin", ["Fijar", "Anheften", "ピン留め"]),
+    ("Unpin", ["Soltar", "Lösen", "ピン留めを解除"]),
+    ("Keyboard Shortcuts", ["Atajos de teclado", "Tastenkürzel", "キーボードショートカット"]),
+    (
+        "← → : Move by minute",
+        ["← → : Mover por minutos", "← → : Minutenweise bewegen", "← → : 1分ずつ移動"],
+    ),
+    (
+        "Shift+← → : Move by hour",
+        [
+            "Shift+← → : Mover por horas",
+            "Umschalt+← → : Stundenweise bewegen",
+            "Shift+← → : 1時間ずつ移動",
+        ],
+    ),
+    (
+        "Enter : Pin/unpin inspection",
+        [
+            "Enter : Fijar/soltar la inspección",
+            "Enter : Untersuchung anheften/lösen",
+            "Enter : 調査のピン留め/解除",
+        ],
+    ),
+    ("Esc : Return to now", ["Esc : Volver al presente", "Esc : Zurück zu jetzt", "Esc : 現在に戻る"]),
+    ("/ : Search timezone", ["/ : Buscar zona horaria", "/ : Zeitzone suchen", "/ : タイムゾーンを検索"]),
+    (
+        "Enter the path to an .ics file",
+        [
+            "Introduce la ruta de un archivo .ics",
+            "Pfad zu einer .ics-Datei eingeben",
+            ".icsファイルのパスを入力してください",
+        ],
+    ),
+    ("{0} events loaded", ["{0} eventos cargados", "{0} Termine geladen", "{0}件の予定を読み込みました"]),
+    (
+        "Failed to load calendar: {0}",
+        [
+            "No se pudo cargar el calendario: {0}",
+            "Kalender konnte nicht geladen werden: {0}",
+            "カレンダーを読み込めませんでした: {0}",
+        ],
+    ),
+    (
+        "Exported {0} and its .csv profile",
+        [
+            "Se exportó {0} y su perfil .csv",
+            "{0} und das .csv-Profil exportiert",
+            "{0}と.csvプロファイルをエクスポートしました",
+        ],
+    ),
+    (
+        "Export failed: {0}",
+        ["Error al exportar: {0}", "Export fehlgeschlagen: {0}", "エクスポートに失敗しました: {0}"],
+    ),
+    (
+        "Linked time {0} is not on today's map",
+        [
+            "La hora vinculada {0} no está en el mapa de hoy",
+            "Die verknüpfte Zeit {0} liegt nicht auf der heutigen Karte",
+            "リンクされた時刻{0}は今日の地図にありません",
+        ],
+    ),
+    (
+        "Linked from another clock: {0}",
+        [
+            "Vinculado desde otro reloj: {0}",
+            "Von einer anderen Uhr verknüpft: {0}",
+            "別の時計からリンク: {0}",
+        ],
+    ),
+    ("NOW", ["AHORA", "JETZT", "現在"]),
+    ("(next)", ["(siguiente)", "(nächster)", "(翌日)"]),
+    ("GAP", ["HUECO", "LÜCKE", "欠落"]),
+    ("Repeated Hour", ["Hora repetida", "Doppelte Stunde", "繰り返す時間"]),
+    ("Temporal Topography", ["Topografía temporal", "Zeittopografie", "時間の地形図"]),
+    ("Click", ["Clic", "Klick", "クリック"]),
+    ("inspect", ["inspeccionar", "untersuchen", "調べる"]),
+    ("step minute", ["avanzar minuto", "Minutenschritt", "1分移動"]),
+    ("step hour", ["avanzar hora", "Stundenschritt", "1時間移動"]),
+    ("pin waypoint", ["fijar punto", "Wegpunkt setzen", "地点を固定"]),
+    ("cycle waypoints", ["recorrer puntos", "Wegpunkte durchgehen", "地点を順に表示"]),
+    ("snap to landmarks", ["saltar a hitos", "zu Marken springen", "目印へ移動"]),
+    ("week view", ["vista semanal", "Wochenansicht", "週表示"]),
+    ("contours", ["curvas de nivel", "Höhenlinien", "等高線"]),
+    ("3D relief", ["relieve 3D", "3D-Relief", "3D 地形"]),
+    ("zoom days", ["zoom de días", "Tage zoomen", "日数を拡大縮小"]),
+    ("scroll days", ["desplazar días", "Tage blättern", "日をスクロール"]),
+    ("return to now", ["volver a ahora", "zurück zu jetzt", "現在に戻る"]),
+    ("search timezone", ["buscar zona horaria", "Zeitzone suchen", "タイムゾーン検索"]),
+    ("commands", ["comandos", "Befehle", "コマンド"]),
+    (
+        "DST gap begins: clocks skip ahead {0} min",
+        [
+            "Empieza el hueco de horario: los relojes saltan {0} min",
+            "Zeitlücke beginnt: Uhren springen {0} min vor",
+            "夏時間の欠落開始: 時計が {0}分進みます",
+        ],
+    ),
+    ("DST gap ends", ["Termina el hueco de horario", "Zeitlücke endet", "夏時間の欠落終了"]),
+    (
+        "Repeated hour begins: clocks fall back {0} min",
+        [
+            "Empieza la hora repetida: los relojes retroceden {0} min",
+            "Doppelte Stunde beginnt: Uhren gehen {0} min zurück",
+            "繰り返し開始: 時計が {0}分戻ります",
+        ],
+    ),
+    ("Repeated hour ends", ["Termina la hora repetida", "Doppelte Stunde endet", "繰り返し終了"]),
+    ("Peak (elevation {0})", ["Cima (elevación {0})", "Gipfel (Höhe {0})", "山頂 (標高 {0})"]),
+    ("Valley (elevation {0})", ["Valle (elevación {0})", "Tal (Höhe {0})", "谷 (標高 {0})"]),
+    ("Next midnight", ["Próxima medianoche", "Nächste Mitternacht", "次の真夜中"]),
+    ("Midnight", ["Medianoche", "Mitternacht", "真夜中"]),
+    ("Hour boundary: {0}", ["Cambio de hora: {0}", "Stundengrenze: {0}", "時の境目: {0}"]),
+    (
+        "At {0} clocks spring forward {1} to {2}. \
+         The times between never happen, so today is {3} long.",
+        [
+            "A las {0} los relojes se adelantan {1} hasta las {2}. \
+             Las horas intermedias no existen, así que hoy dura {3}.",
+            "Um {0} springen die Uhren {1} vor auf {2}. \
+             Die Zeiten dazwischen gibt es nicht, daher dauert heute {3}.",
+            "{0} に時計が {1} 進んで {2} になります。\
+             その間の時刻は存在しないので、今日は {3} です。",
+        ],
+    ),
+    (
+        "At {0} clocks fall back {1} to {2}. \
+         The times between happen twice (A, then B), so today is {3} long.",
+        [
+            "A las {0} los relojes se atrasan {1} hasta las {2}. \
+             Las horas intermedias ocurren dos veces (A y luego B), así que hoy dura {3}.",
+            "Um {0} gehen die Uhren {1} zurück auf {2}. \
+             Die Zeiten dazwischen gibt es zweimal (A, dann B), daher dauert heute {3}.",
+            "{0} に時計が {1} 戻って {2} になります。\
+             その間の時刻は2回訪れる (A、次に B) ので、今日は {3} です。",
+        ],
+    ),
+    ("{0} hour", ["{0} hora", "{0} Stunde", "{0}時間"]),
+    ("{0} hours", ["{0} horas", "{0} Stunden", "{0}時間"]),
+    ("{0} minute", ["{0} minuto", "{0} Minute", "{0}分"]),
+    ("{0} minutes", ["{0} minutos", "{0} Minuten", "{0}分"]),
+    // Chrono superposition
+    ("Zone Field", ["Campo de zonas", "Zonenfeld", "ゾーンフィールド"]),
+    ("+ Add Time Zone", ["+ Añadir zona horaria", "+ Zeitzone hinzufügen", "+ タイムゾーンを追加"]),
+    ("⌂ Use System Zone", ["⌂ Usar zona del sistema", "⌂ Systemzone verwenden", "⌂ システムのゾーンを使用"]),
+    (
+        "Make this machine's time zone the dominant home zone",
+        [
+            "Hacer de la zona horaria de este equipo la zona de origen dominante",
+            "Die Zeitzone dieses Rechners zur dominanten Heimatzone machen",
+            "このマシンのタイムゾーンを主要なホームゾーンにする",
+        ],
+    ),
+    (
+        "Selected Zones ({0})",
+        ["Zonas seleccionadas ({0})", "Ausgewählte Zonen ({0})", "選択したゾーン ({0})"],
+    ),
+    (
+        "Click to set as dominant",
+        ["Haz clic para hacerla dominante", "Klicken, um sie dominant zu machen", "クリックして主要ゾーンに設定"],
+    ),
+    ("Home zone", ["Zona de origen", "Heimatzone", "ホームゾーン"]),
+    ("Remove zone", ["Quitar zona", "Zone entfernen", "ゾーンを削除"]),
+    ("Nickname and color", ["Apodo y color", "Spitzname und Farbe", "ニックネームと色"]),
+    ("Zone Sets ({0})", ["Conjuntos de zonas ({0})", "Zonensätze ({0})", "ゾーンセット ({0})"]),
+    ("Delete zone set", ["Eliminar conjunto de zonas", "Zonensatz löschen", "ゾーンセットを削除"]),
+    ("Set name", ["Nombre del conjunto", "Name des Satzes", "セット名"]),
+    ("Save current", ["Guardar actual", "Aktuelle speichern", "現在の内容を保存"]),
+    (
+        "Save the selected zones and dominant zone under this name",
+        [
+            "Guardar las zonas seleccionadas y la zona dominante con este nombre",
+            "Die ausgewählten Zonen und die dominante Zone unter diesem Namen speichern",
+            "選択したゾーンと主要ゾーンをこの名前で保存",
+        ],
+    ),
+    ("↶ Undo switch", ["↶ Deshacer cambio", "↶ Wechsel rückgängig", "↶ 切り替えを元に戻す"]),
+    (
+        "Restore the zones from before the switch (Ctrl+Z)",
+        [
+            "Restaurar las zonas de antes del cambio (Ctrl+Z)",
+            "Die Zonen von vor dem Wechsel wiederherstellen (Strg+Z)",
+            "切り替え前のゾーンに戻す (Ctrl+Z)",
+        ],
+    ),
+    ("Keyboard:", ["Teclado:", "Tastatur:", "キーボード:"]),
+    (
+        "Up/Down: Cycle dominant",
+        [
+            "Arriba/Abajo: cambiar zona dominante",
+            "Auf/Ab: dominante Zone wechseln",
+            "上/下: 主要ゾーンを切り替え",
+        ],
+    ),
+    ("F  Search zones", ["F  Buscar zonas", "F  Zonen suchen", "F  ゾーンを検索"]),
+    ("No color", ["Sin color", "Keine Farbe", "色なし"]),
+    ("Dominant Zone", ["Zona dominante", "Dominante Zone", "主要ゾーン"]),
+    (
+        "⚠ TZ data missing",
+        ["⚠ Faltan datos de zona horaria", "⚠ Zeitzonendaten fehlen", "⚠ タイムゾーンデータがありません"],
+    ),
+    (
+        "⚠ TZ data may be stale",
+        [
+            "⚠ Los datos de zona horaria pueden estar desactualizados",
+            "⚠ Zeitzonendaten sind evtl. veraltet",
+            "⚠ タイムゾーンデータが古い可能性があります",
+        ],
+    ),
+    ("⚠ Unknown validity", ["⚠ Validez desconocida", "⚠ Gültigkeit unbekannt", "⚠ 有効性が不明です"]),
+    ("Time Offset", ["Desfase horario", "Zeitversatz", "時刻オフセット"]),
+    ("Return to Live", ["Volver al tiempo real", "Zurück zur Echtzeit", "ライブに戻る"]),
+    (
+        "Keyboard: [ / ] (Shift 15m, Ctrl 1d), 0 = live, Ctrl+K commands",
+        [
+            "Teclado: [ / ] (Mayús 15m, Ctrl 1d), 0 = en vivo, Ctrl+K comandos",
+            "Tastatur: [ / ] (Umschalt 15m, Strg 1T), 0 = live, Strg+K Befehle",
+            "キーボード: [ / ] (Shift 15分, Ctrl 1日), 0 = ライブ, Ctrl+K コマンド",
+        ],
+    ),
+    ("Focus Strength", ["Intensidad del foco", "Fokusstärke", "フォーカスの強さ"]),
+    ("Collapsed", ["Contraído", "Zusammengeklappt", "折りたたみ"]),
+    ("Focused", ["Enfocado", "Fokussiert", "フォーカス"]),
+    ("Spread", ["Extendido", "Aufgefächert", "展開"]),
+    (
+        "Low = spread cards, High = collapse",
+        [
+            "Bajo = tarjetas extendidas, Alto = contraer",
+            "Niedrig = Karten auffächern, Hoch = zusammenklappen",
+            "低 = カードを広げる、高 = 折りたたむ",
+        ],
+    ),
+    ("Compare Mode", ["Modo comparación", "Vergleichsmodus", "比較モード"]),
+    (
+        "Show deltas from dominant",
+        [
+            "Mostrar diferencias con la dominante",
+            "Abweichungen zur dominanten Zone zeigen",
+            "主要ゾーンとの差を表示",
+        ],
+    ),
+    ("Keyboard: C", ["Teclado: C", "Tastatur: C", "キーボード: C"]),
+    (
+        "Show minute/second drift",
+        ["Mostrar desfase de minutos/segundos", "Minuten-/Sekundenversatz zeigen", "分/秒のずれを表示"],
+    ),
+    ("View Mode", ["Modo de vista", "Ansichtsmodus", "表示モード"]),
+    (
+        "List Mode (Accessible)",
+        ["Modo lista (accesible)", "Listenmodus (barrierefrei)", "リストモード (アクセシブル)"],
+    ),
+    ("Grid Mode", ["Modo cuadrícula", "Rastermodus", "グリッドモード"]),
+    (
+        "Tile cards in rows and columns; suits 6-12 zones",
+        [
+            "Coloca las tarjetas en filas y columnas; ideal para 6-12 zonas",
+            "Karten in Zeilen und Spalten anordnen; passend für 6-12 Zonen",
+            "カードを行と列に並べます。6〜12 ゾーン向け",
+        ],
+    ),
+    ("Timeline Strip", ["Franja de línea temporal", "Zeitleistenstreifen", "タイムラインストリップ"]),
+    (
+        "Flip Cards for Details",
+        ["Girar tarjetas para ver detalles", "Karten für Details umdrehen", "カードを裏返して詳細を表示"],
+    ),
+    (
+        "Show Deck Anyway",
+        ["Mostrar la baraja de todos modos", "Stapel trotzdem zeigen", "それでもデッキを表示"],
+    ),
+    (
+        "({0} zones in deck)",
+        ["({0} zonas en la baraja)", "({0} Zonen im Stapel)", "(デッキに {0} ゾーン)"],
+    ),
+    (
+        "⚠ {0} zones - list recommended",
+        [
+            "⚠ {0} zonas: se recomienda la lista",
+            "⚠ {0} Zonen – Liste empfohlen",
+            "⚠ {0} ゾーン - リスト表示を推奨",
+        ],
+    ),
+    (
+        "Keyboard: L cycles deck / grid / list",
+        [
+            "Teclado: L alterna baraja / cuadrícula / lista",
+            "Tastatur: L wechselt Stapel / Raster / Liste",
+            "キーボード: L でデッキ / グリッド / リストを切り替え",
+        ],
+    ),
+    (
+        "Reset to Automatic Order",
+        ["Restablecer orden automático", "Automatische Reihenfolge wiederherstellen", "自動の並び順に戻す"],
+    ),
+    (
+        "Drag cards or rows to reorder",
+        [
+            "Arrastra tarjetas o filas para reordenar",
+            "Karten oder Zeilen zum Umordnen ziehen",
+            "カードや行をドラッグして並べ替え",
+        ],
+    ),
+    ("Meeting Planner", ["Planificador de reuniones", "Besprechungsplaner", "会議プランナー"]),
+    ("Find overlap", ["Buscar coincidencias", "Überschneidung finden", "重なりを探す"]),
+    ("Keyboard: M", ["Teclado: M", "Tastatur: M", "キーボード: M"]),
+    ("DST Alerts", ["Avisos de horario de verano", "Sommerzeit-Hinweise", "夏時間の通知"]),
+    ("Export Table", ["Exportar tabla", "Tabelle exportieren", "表をエクスポート"]),
+    ("Save File", ["Guardar archivo", "Datei speichern", "ファイルに保存"]),
+    (
+        "Times, offsets, DST and deltas at the shown instant",
+        [
+            "Horas, desfases, horario de verano y diferencias en el instante mostrado",
+            "Zeiten, Versätze, Sommerzeit und Abweichungen zum angezeigten Zeitpunkt",
+            "表示中の時点の時刻、オフセット、夏時間、差",
+        ],
+    ),
+    (
+        "Disables parallax and animations",
+        [
+            "Desactiva el paralaje y las animaciones",
+            "Deaktiviert Parallaxe und Animationen",
+            "視差とアニメーションを無効にします",
+        ],
+    ),
+    (
+        "{0} zones in superposition",
+        ["{0} zonas en superposición", "{0} Zonen in Überlagerung", "{0} ゾーンが重ね合わせ中"],
+    ),
+    (
+        "Warn before DST changes",
+        ["Avisar antes de los cambios de horario", "Vor Zeitumstellungen warnen", "夏時間の切り替え前に通知"],
+    ),
+    (" h ahead", [" h antes", " h vorher", " 時間前"]),
+    (
+        "Desktop notification",
+        ["Notificación de escritorio", "Desktop-Benachrichtigung", "デスクトップ通知"],
+    ),
+    (
+        "Built without desktop notifications (enable the `notifications` feature)",
+        [
+            "Compilado sin notificaciones de escritorio (activa la función `notifications`)",
+            "Ohne Desktop-Benachrichtigungen gebaut (Feature `notifications` aktivieren)",
+            "デスクトップ通知なしでビルドされています (`notifications` 機能を有効にしてください)",
+        ],
+    ),
+    (
+        "Any selected zone, at the real time",
+        [
+            "Cualquier zona seleccionada, a la hora real",
+            "Jede ausgewählte Zone, zur echten Uhrzeit",
+            "選択中のどのゾーンも、実際の時刻で",
+        ],
+    ),
+    (
+        "Business hours (local time)",
+        ["Horario laboral (hora local)", "Geschäftszeiten (Ortszeit)", "営業時間 (現地時刻)"],
+    ),
+    ("Start of working day", ["Inicio de la jornada", "Beginn des Arbeitstags", "始業時刻"]),
+    ("End of working day", ["Fin de la jornada", "Ende des Arbeitstags", "終業時刻"]),
+    (
+        "Shared windows (next 24h, {0})",
+        [
+            "Franjas comunes (próximas 24 h, {0})",
+            "Gemeinsame Zeitfenster (nächste 24 h, {0})",
+            "共通の時間帯 (今後 24 時間, {0})",
+        ],
+    ),
+    (
+        "No time when every zone is working",
+        [
+            "No hay ninguna hora en que todas las zonas trabajen",
+            "Keine Zeit, zu der alle Zonen arbeiten",
+            "すべてのゾーンが勤務中の時間はありません",
+        ],
+    ),
+    ("Close Planner", ["Cerrar planificador", "Planer schließen", "プランナーを閉じる"]),
+    ("Next DST change", ["Próximo cambio de horario", "Nächste Zeitumstellung", "次の夏時間の切り替え"]),
+    (
+        "Clocks go forward {0}",
+        ["Los relojes se adelantan {0}", "Die Uhren werden {0} vorgestellt", "時計が {0} 進みます"],
+    ),
+    (
+        "Clocks go back {0}",
+        ["Los relojes se atrasan {0}", "Die Uhren werden {0} zurückgestellt", "時計が {0} 戻ります"],
+    ),
+    ("Last: {0}", ["Último: {0}", "Zuletzt: {0}", "直前: {0}"]),
+    ("Then: {0}", ["Después: {0}", "Danach: {0}", "直後: {0}"]),
+    (
+        "No change within a year",
+        ["Sin cambios en un año", "Keine Umstellung innerhalb eines Jahres", "1 年以内に切り替えはありません"],
+    ),
+    ("Offsets in {0}", ["Desfases en {0}", "Versätze in {0}", "{0} 年のオフセット"]),
+    ("Make Dominant", ["Hacer dominante", "Dominant machen", "主要ゾーンにする"]),
+    ("Flip Back", ["Dar la vuelta", "Zurückdrehen", "裏返す"]),
+    ("DST:", ["Horario de verano:", "Sommerzeit:", "夏時間:"]),
+    (
+        "⚠ DST shift in {0}h ({1}{2}m)",
+        [
+            "⚠ Cambio de horario en {0} h ({1}{2} min)",
+            "⚠ Zeitumstellung in {0} h ({1}{2} min)",
+            "⚠ {0} 時間後に夏時間の切り替え ({1}{2} 分)",
+        ],
+    ),
+    (
+        "DST shift {0}h ago ({1}{2}m)",
+        [
+            "Cambio de horario hace {0} h ({1}{2} min)",
+            "Zeitumstellung vor {0} h ({1}{2} min)",
+            "{0} 時間前に夏時間の切り替え ({1}{2} 分)",
+        ],
+    ),
+    ("At {0}", ["A las {0}", "Um {0}", "{0} に"]),
+    ("Add Time Zone", ["Añadir zona horaria", "Zeitzone hinzufügen", "タイムゾーンを追加"]),
+    ("Zone Sets:", ["Conjuntos de zonas:", "Zonensätze:", "ゾーンセット:"]),
+    (
+        "Replace the selected zones with this set",
+        [
+            "Sustituir las zonas seleccionadas por este conjunto",
+            "Die ausgewählten Zonen durch diesen Satz ersetzen",
+            "選択したゾーンをこのセットで置き換える",
+        ],
+    ),
+    ("Home zone: {0}", ["Zona de origen: {0}", "Heimatzone: {0}", "ホームゾーン: {0}"]),
+    (
+        "Could not detect the system time zone",
+        [
+            "No se pudo detectar la zona horaria del sistema",
+            "Die Systemzeitzone konnte nicht erkannt werden",
+            "システムのタイムゾーンを検出できませんでした",
+        ],
+    ),
+    (
+        "Saved zone set “{0}”",
+        ["Conjunto de zonas “{0}” guardado", "Zonensatz „{0}“ gespeichert", "ゾーンセット「{0}」を保存しました"],
+    ),
+    (
+        "Zone set “{0}” has no valid zones",
+        [
+            "El conjunto de zonas “{0}” no tiene zonas válidas",
+            "Zonensatz „{0}“ enthält keine gültigen Zonen",
+            "ゾーンセット「{0}」に有効なゾーンがありません",
+        ],
+    ),
+    (
+        "Switched to “{0}” — Ctrl+Z to undo",
+        [
+            "Cambiado a “{0}”: Ctrl+Z para deshacer",
+            "Zu „{0}“ gewechselt – Strg+Z macht es rückgängig",
+            "「{0}」に切り替えました — Ctrl+Z で元に戻す",
+        ],
+    ),
+    (
+        "Restored previous zones",
+        ["Zonas anteriores restauradas", "Vorherige Zonen wiederhergestellt", "以前のゾーンに戻しました"],
+    ),
+    (
+        "Saved table to {0}",
+        ["Tabla guardada en {0}", "Tabelle unter {0} gespeichert", "表を {0} に保存しました"],
+    ),
+    (
+        "Failed to save table: {0}",
+        [
+            "No se pudo guardar la tabla: {0}",
+            "Tabelle konnte nicht gespeichert werden: {0}",
+            "表を保存できませんでした: {0}",
+        ],
+    ),
+    ("Copied {0} table", ["Tabla {0} copiada", "{0}-Tabelle kopiert", "{0} の表をコピーしました"]),
+    (
+        "{0}: clocks go forward {1} at {2} (in {3})",
+        [
+            "{0}: los relojes se adelantan {1} a las {2} (en {3})",
+            "{0}: Die Uhren werden um {2} {1} vorgestellt (in {3})",
+            "{0}: {2} に時計が {1} 進みます ({3} 後)",
+        ],
+    ),
+    (
+        "{0}: clocks go back {1} at {2} (in {3})",
+        [
+            "{0}: los relojes se atrasan {1} a las {2} (en {3})",
+            "{0}: Die Uhren werden um {2} {1} zurückgestellt (in {3})",
+            "{0}: {2} に時計が {1} 戻ります ({3} 後)",
+        ],
+    ),
+    (
+        "{0}: clocks went forward {1} at {2}",
+        [
+            "{0}: los relojes se adelantaron {1} a las {2}",
+            "{0}: Die Uhren wurden um {2} {1} vorgestellt",
+            "{0}: {2} に時計が {1} 進みました",
+        ],
+    ),
+    (
+        "{0}: clocks went back {1} at {2}",
+        [
+            "{0}: los relojes se atrasaron {1} a las {2}",
+            "{0}: Die Uhren wurden um {2} {1} zurückgestellt",
+            "{0}: {2} に時計が {1} 戻りました",
+        ],
+    ),
+    (
+        "In the composite view, a dial per zone whose minutes and seconds \
+         don't line up with the dominant zone (e.g. +5:45)",
+        [
+            "En la vista compuesta, un dial por cada zona cuyos minutos y segundos \
+             no coinciden con los de la zona dominante (p. ej. +5:45)",
+            "In der Gesamtansicht ein Zifferblatt je Zone, deren Minuten und Sekunden \
+             nicht mit denen der dominanten Zone übereinstimmen (z. B. +5:45)",
+            "合成ビューで、分と秒が主要ゾーンとずれているゾーンごとに \
+             文字盤を表示します (例: +5:45)",
+        ],
+    ),
+    (
+        "Each zone's hours under the deck, lined up with the dominant zone's \
+         day; click a time to preview it (T)",
+        [
+            "Las horas de cada zona bajo la baraja, alineadas con el día de la zona \
+             dominante; haz clic en una hora para previsualizarla (T)",
+            "Die Stunden jeder Zone unter dem Stapel, am Tag der dominanten Zone \
+             ausgerichtet; eine Uhrzeit anklicken für die Vorschau (T)",
+            "デッキの下に各ゾーンの時間を主要ゾーンの一日に合わせて並べます。\
+             時刻をクリックでプレビュー (T)",
+        ],
+    ),
+    (
+        "Clicking a card that isn't dominant, or pressing Enter on it, turns \
+         it over to show the zone's details instead of making it dominant",
+        [
+            "Al hacer clic en una tarjeta no dominante, o pulsar Intro sobre ella, \
+             se da la vuelta y muestra los detalles de la zona en vez de hacerla dominante",
+            "Ein Klick auf eine nicht dominante Karte oder Enter darauf dreht sie um \
+             und zeigt die Details der Zone, statt sie dominant zu machen",
+            "主要でないカードをクリックするか Enter を押すと、主要ゾーンにする代わりに \
+             カードを裏返してゾーンの詳細を表示します",
+        ],
+    ),
+    ("HOME", ["CASA", "HEIMAT", "ホーム"]),
+    ("● In hours", ["● En horario", "● In der Arbeitszeit", "● 業務時間内"]),
+    ("○ Off hours", ["○ Fuera de horario", "○ Außerhalb der Arbeitszeit", "○ 業務時間外"]),
+    ("DST differs", ["Horario de verano distinto", "Sommerzeit abweichend", "夏時間が異なる"]),
+    ("Yesterday", ["Ayer", "Gestern", "昨日"]),
+    ("Tomorrow", ["Mañana", "Morgen", "明日"]),
+    ("Different day", ["Otro día", "Anderer Tag", "別の日"]),
+    (
+        "DST in {0}h ({1}m)",
+        [
+            "Cambio de hora en {0} h ({1} min)",
+            "Umstellung in {0} h ({1} min)",
+            "夏時間切替まで {0}時間 ({1}分)",
+        ],
+    ),
+    (
+        "DST {0}h ago ({1}m)",
+        [
+            "Cambio de hora hace {0} h ({1} min)",
+            "Umstellung vor {0} h ({1} min)",
+            "夏時間切替から {0}時間 ({1}分)",
+        ],
+    ),
+    (
+        "SUPERPOSITION COLLAPSED",
+        ["SUPERPOSICIÓN COLAPSADA", "SUPERPOSITION KOLLABIERT", "重ね合わせの収縮"],
+    ),
+    ("...and {0} more", ["...y {0} más", "...und {0} weitere", "...ほか {0} 件"]),
+    ("{0} zones superposed", ["{0} zonas superpuestas", "{0} Zonen überlagert", "{0} ゾーンを重ね合わせ"]),
+    (
+        "⚠ DST transition imminent in some zones",
+        [
+            "⚠ Cambio de hora inminente en algunas zonas",
+            "⚠ Zeitumstellung in einigen Zonen steht bevor",
+            "⚠ 一部のゾーンで夏時間切替が間近です",
+        ],
+    ),
+    ("No data", ["Sin datos", "Keine Daten", "データなし"]),
+    ("Today", ["Hoy", "Heute", "今日"]),
+    ("{0} (varies)", ["{0} (varía)", "{0} (unterschiedlich)", "{0} (ゾーンにより異なる)"]),
+    ("Multiple dates", ["Varias fechas", "Mehrere Daten", "複数の日付"]),
+    ("{0} {1}, {2}", ["{1} {0} {2}", "{1}. {0} {2}", "{2}年{0}{1}日"]),
+    ("Jan", ["ene", "Jan.", "1月"]),
+    ("Feb", ["feb", "Feb.", "2月"]),
+    ("Mar", ["mar", "März", "3月"]),
+    ("Apr", ["abr", "Apr.", "4月"]),
+    ("May", ["may", "Mai", "5月"]),
+    ("Jun", ["jun", "Juni", "6月"]),
+    ("Jul", ["jul", "Juli", "7月"]),
+    ("Aug", ["ago", "Aug.", "8月"]),
+    ("Sep", ["sept", "Sept.", "9月"]),
+    ("Oct", ["oct", "Okt.", "10月"]),
+    ("Nov", ["nov", "Nov.", "11月"]),
+    ("Dec", ["dic", "Dez.", "12月"]),
+    (
+        "Minutes and seconds aligned in every zone",
+        [
+            "Minutos y segundos alineados en todas las zonas",
+            "Minuten und Sekunden in allen Zonen gleich",
+            "すべてのゾーンで分と秒がそろっています",
+        ],
+    ),
+    ("aligned", ["alineado", "gleich", "そろっている"]),
+    // Ritual clock
+    ("Ensemble", ["Conjunto", "Ensemble", "アンサンブル"]),
+    ("Change Ensemble (T)", ["Cambiar conjunto (T)", "Ensemble wechseln (T)", "アンサンブルを変更 (T)"]),
+    (
+        "Open timezone picker. Keyboard: T",
+        [
+            "Abrir el selector de zona horaria. Teclado: T",
+            "Zeitzonenauswahl öffnen. Tastatur: T",
+            "タイムゾーン選択を開く。キーボード: T",
+        ],
+    ),
+    ("Use System Time", ["Usar hora del sistema", "Systemzeit verwenden", "システム時刻を使用"]),
+    (
+        "Set to your computer's local timezone",
+        [
+            "Usar la zona horaria local de tu equipo",
+            "Auf die lokale Zeitzone deines Computers setzen",
+            "コンピューターのローカルタイムゾーンに設定",
+        ],
+    ),
+    (
+        "● Daylight Saving Time Active",
+        ["● Horario de verano activo", "● Sommerzeit aktiv", "● 夏時間が有効"],
+    ),
+    ("○ Standard Time", ["○ Horario estándar", "○ Normalzeit", "○ 標準時"]),
+    (
+        "⚠ DST change in {0}h (spring forward)",
+        [
+            "⚠ Cambio de horario en {0} h (se adelanta)",
+            "⚠ Zeitumstellung in {0} h (vorstellen)",
+            "⚠ {0} 時間後に夏時間の切り替え (進む)",
+        ],
+    ),
+    (
+        "⚠ DST change in {0}h (fall back)",
+        [
+            "⚠ Cambio de horario en {0} h (se atrasa)",
+            "⚠ Zeitumstellung in {0} h (zurückstellen)",
+            "⚠ {0} 時間後に夏時間の切り替え (戻る)",
+        ],
+    ),
+    (
+        "ℹ Clocks sprang forward recently",
+        [
+            "ℹ Los relojes se adelantaron hace poco",
+            "ℹ Die Uhren wurden kürzlich vorgestellt",
+            "ℹ 最近、時計が進みました",
+        ],
+    ),
+    (
+        "ℹ Clocks fell back recently",
+        [
+            "ℹ Los relojes se atrasaron hace poco",
+            "ℹ Die Uhren wurden kürzlich zurückgestellt",
+            "ℹ 最近、時計が戻りました",
+        ],
+    ),
+    ("Sensitivity:", ["Sensibilidad:", "Empfindlichkeit:", "感度:"]),
+    (
+        "Gesture trail sensitivity",
+        ["Sensibilidad del trazo del gesto", "Empfindlichkeit der Gestenspur", "ジェスチャー軌跡の感度"],
+    ),
+    (
+        "Trail intensity; higher also picks up smaller, looser conducting",
+        [
+            "Intensidad del trazo; más alta también capta una dirección más pequeña y suelta",
+            "Spurintensität; höher erfasst auch kleineres, lockereres Dirigieren",
+            "軌跡の強さ。高くすると小さくゆるい指揮も拾います",
+        ],
+    ),
+    ("Sync: {0}%", ["Sincronía: {0} %", "Synchron: {0} %", "同期: {0}%"]),
+    ("Sync: –", ["Sincronía: –", "Synchron: –", "同期: –"]),
+    (
+        "Conduct circles or beats in time with the seconds",
+        [
+            "Dirige círculos o golpes al ritmo de los segundos",
+            "Dirigiere Kreise oder Schläge im Takt der Sekunden",
+            "秒に合わせて円や拍を指揮します",
+        ],
+    ),
+    ("best {0}%", ["mejor {0} %", "beste {0} %", "最高 {0}%"]),
+    (
+        "Clear the best sync score",
+        ["Borrar la mejor puntuación de sincronía", "Beste Synchronwertung löschen", "最高同期スコアを消去"],
+    ),
+    (
+        "Always show time (S)",
+        ["Mostrar siempre la hora (S)", "Zeit immer anzeigen (S)", "常に時刻を表示 (S)"],
+    ),
+    (
+        "Keep digital time display visible. Keyboard: S",
+        [
+            "Mantener visible la hora digital. Teclado: S",
+            "Digitale Zeitanzeige sichtbar lassen. Tastatur: S",
+            "デジタル時刻表示を常に表示。キーボード: S",
+        ],
+    ),
+    ("Stage:", ["Escenario:", "Bühne:", "ステージ:"]),
+    ("Circle", ["Círculo", "Kreis", "円"]),
+    ("Spiral", ["Espiral", "Spirale", "らせん"]),
+    ("12-pointed star", ["Estrella de 12 puntas", "Zwölfzackiger Stern", "十二芒星"]),
+    ("Procession", ["Procesión", "Prozession", "行列"]),
+    (
+        "Enable trails anyway",
+        ["Activar trazos de todos modos", "Spuren trotzdem aktivieren", "それでも軌跡を有効にする"],
+    ),
+    (
+        "Allow gesture trails even in reduced motion mode",
+        [
+            "Permitir trazos de gestos incluso con movimiento reducido",
+            "Gestenspuren auch bei reduzierter Bewegung erlauben",
+            "動きを減らすモードでもジェスチャー軌跡を許可",
+        ],
+    ),
+    ("Beat particles", ["Partículas de pulso", "Schlagpartikel", "拍のパーティクル"]),
+    (
+        "Burst particles from each beat; sensitivity sets the size",
+        [
+            "Partículas que estallan en cada pulso; la sensibilidad fija el tamaño",
+            "Partikel bei jedem Schlag; die Empfindlichkeit bestimmt die Größe",
+            "拍ごとにパーティクルを放出。感度で大きさが決まります",
+        ],
+    ),
+    ("max", ["máx.", "max.", "最大"]),
+    (
+        "Most particles on screen at once",
+        [
+            "Máximo de partículas en pantalla a la vez",
+            "Höchstzahl gleichzeitiger Partikel",
+            "同時に表示するパーティクルの上限",
+        ],
+    ),
+    ("Pendulum", ["Péndulo", "Pendel", "振り子"]),
+    (
+        "Swings on the true second, wider as you conduct",
+        [
+            "Oscila con el segundo exacto, más amplio al dirigir",
+            "Schwingt im genauen Sekundentakt, weiter beim Dirigieren",
+            "正確な秒で揺れ、指揮すると大きくなります",
+        ],
+    ),
+    ("coupled", ["acoplados", "gekoppelt", "連成"]),
+    ("Chimes", ["Campanadas", "Glockenschläge", "チャイム"]),
+    ("Mute", ["Silenciar", "Stumm", "ミュート"]),
+    (
+        "Silence all chimes",
+        ["Silenciar todas las campanadas", "Alle Glockenschläge stummschalten", "すべてのチャイムを消音"],
+    ),
+    (
+        "Per-second tick (off in reduced motion)",
+        [
+            "Tic por segundo (desactivado con movimiento reducido)",
+            "Tick pro Sekunde (aus bei reduzierter Bewegung)",
+            "毎秒のティック (動きを減らすモードではオフ)",
+        ],
+    ),
+    ("Per-second tick", ["Tic por segundo", "Tick pro Sekunde", "毎秒のティック"]),
+    ("Beat:", ["Pulso:", "Schlag:", "拍:"]),
+    ("Minute:", ["Minuto:", "Minute:", "分:"]),
+    ("Hour:", ["Hora:", "Stunde:", "時:"]),
+    (
+        "Minute boundary chime",
+        ["Campanada al cambiar de minuto", "Glockenschlag zur vollen Minute", "毎分のチャイム"],
+    ),
+    (
+        "Top of the hour chime",
+        ["Campanada a la hora en punto", "Glockenschlag zur vollen Stunde", "毎正時のチャイム"],
+    ),
+    ("External", ["Externo", "Extern", "外部"]),
+    ("Session", ["Sesión", "Sitzung", "セッション"]),
+    ("Breathe", ["Respirar", "Atmen", "呼吸"]),
+    ("Lore", ["Saber", "Überlieferung", "伝承"]),
+    ("Duel", ["Duelo", "Duell", "対決"]),
+    ("Trail:", ["Trazo:", "Spur:", "軌跡:"]),
+    ("Ink ribbon", ["Cinta de tinta", "Tintenband", "インクのリボン"]),
+    ("Comet", ["Cometa", "Komet", "彗星"]),
+    ("Stipple", ["Punteado", "Punktiert", "点描"]),
+    ("Calligraphic", ["Caligráfico", "Kalligrafisch", "書道"]),
+    (
+        "A ribbon thinning as it fades",
+        [
+            "Una cinta que se adelgaza al desvanecerse",
+            "Ein Band, das beim Verblassen dünner wird",
+            "消えるにつれて細くなるリボン",
+        ],
+    ),
+    (
+        "Widens toward a glowing head",
+        [
+            "Se ensancha hacia una cabeza brillante",
+            "Wird zum leuchtenden Kopf hin breiter",
+            "光る先端に向かって太くなります",
+        ],
+    ),
+    (
+        "Dots scattered along the path",
+        [
+            "Puntos dispersos a lo largo del trazo",
+            "Entlang des Pfads verstreute Punkte",
+            "軌跡に沿って散らばる点",
+        ],
+    ),
+    (
+        "A broad nib, thinner on fast strokes",
+        [
+            "Una plumilla ancha, más fina en trazos rápidos",
+            "Eine breite Feder, dünner bei schnellen Strichen",
+            "太いペン先。速い筆運びでは細くなります",
+        ],
+    ),
+    ("width", ["ancho", "Breite", "幅"]),
+    ("speed thinning", ["adelgazamiento por velocidad", "Verdünnung bei Tempo", "速度による細り"]),
+    (
+        "How much fast strokes thin the nib",
+        [
+            "Cuánto adelgazan la plumilla los trazos rápidos",
+            "Wie stark schnelle Striche die Feder verdünnen",
+            "速い筆運びでペン先がどれだけ細くなるか",
+        ],
+    ),
+    ("scatter", ["dispersión", "Streuung", "散らばり"]),
+    (
+        "How far dots stray from the path (none in reduced motion)",
+        [
+            "Cuánto se alejan los puntos del trazo (nada con movimiento reducido)",
+            "Wie weit Punkte vom Pfad abweichen (keine bei reduzierter Bewegung)",
+            "点が軌跡からどれだけ離れるか (動きを減らすモードではなし)",
+        ],
+    ),
+    (
+        "Drawn as an ink ribbon in reduced motion",
+        [
+            "Se dibuja como cinta de tinta con movimiento reducido",
+            "Bei reduzierter Bewegung als Tintenband gezeichnet",
+            "動きを減らすモードではインクのリボンとして描画",
+        ],
+    ),
+    ("■ Stop (R)", ["■ Detener (R)", "■ Stopp (R)", "■ 停止 (R)"]),
+    ("● Record (R)", ["● Grabar (R)", "● Aufnehmen (R)", "● 録画 (R)"]),
+    (
+        "Record trails and hour highlights; stopping saves the session",
+        [
+            "Grabar trazos y horas resaltadas; al detener se guarda la sesión",
+            "Spuren und Stundenmarkierungen aufnehmen; Stoppen speichert die Sitzung",
+            "軌跡と時の強調を記録します。停止するとセッションを保存します",
+        ],
+    ),
+    ("⏸ Pause (P)", ["⏸ Pausa (P)", "⏸ Pause (P)", "⏸ 一時停止 (P)"]),
+    ("▶ Play (P)", ["▶ Reproducir (P)", "▶ Abspielen (P)", "▶ 再生 (P)"]),
+    (
+        "Replay the last saved session over the stage",
+        [
+            "Reproducir la última sesión guardada sobre el escenario",
+            "Die zuletzt gespeicherte Sitzung auf der Bühne abspielen",
+            "最後に保存したセッションをステージ上で再生",
+        ],
+    ),
+    ("Scrub through the session", ["Recorrer la sesión", "Durch die Sitzung spulen", "セッションをスクラブ"]),
+    ("Close the replay", ["Cerrar la reproducción", "Wiedergabe schließen", "再生を閉じる"]),
+    ("Host", ["Anfitrión", "Gastgeber", "ホスト"]),
+    (
+        "Start a duel and get a code for the other conductor",
+        [
+            "Iniciar un duelo y obtener un código para el otro director",
+            "Ein Duell starten und einen Code für den anderen Dirigenten erhalten",
+            "対決を始めて相手の指揮者用のコードを取得",
+        ],
+    ),
+    ("Join", ["Unirse", "Beitreten", "参加"]),
+    (
+        "Experimental, same network only",
+        [
+            "Experimental, solo en la misma red",
+            "Experimentell, nur im selben Netzwerk",
+            "実験的機能、同じネットワークのみ",
+        ],
+    ),
+    (
+        "The other conductor joins with this code",
+        [
+            "El otro director se une con este código",
+            "Der andere Dirigent tritt mit diesem Code bei",
+            "相手の指揮者はこのコードで参加します",
+        ],
+    ),
+    ("Waiting for opponent", ["Esperando al rival", "Warte auf Gegner", "相手を待っています"]),
+    ("Connected", ["Conectado", "Verbunden", "接続済み"]),
+    (
+        "Opponent lost, conducting solo",
+        [
+            "Rival perdido, dirigiendo en solitario",
+            "Gegner verloren, dirigiere allein",
+            "相手を見失いました。一人で指揮中",
+        ],
+    ),
+    (
+        "Offline: this computer only",
+        ["Sin conexión: solo este equipo", "Offline: nur dieser Computer", "オフライン: このコンピューターのみ"],
+    ),
+    ("Leave", ["Salir", "Verlassen", "退出"]),
+    ("Pick an hour", ["Elige una hora", "Stunde wählen", "時を選ぶ"]),
+    (
+        "Click an hour node to give it a ritual",
+        [
+            "Haz clic en el nodo de una hora para darle un ritual",
+            "Klicke auf einen Stundenknoten, um ihm ein Ritual zu geben",
+            "時のノードをクリックして儀式を設定",
+        ],
+    ),
+    (
+        "Glyph drawn beside the node",
+        ["Glifo dibujado junto al nodo", "Neben dem Knoten gezeichnetes Zeichen", "ノードの横に描かれる記号"],
+    ),
+    ("Ritual or task", ["Ritual o tarea", "Ritual oder Aufgabe", "儀式またはタスク"]),
+    (
+        "Notes, shown when the node is hovered",
+        [
+            "Notas, mostradas al pasar sobre el nodo",
+            "Notizen, beim Überfahren des Knotens angezeigt",
+            "ノードにカーソルを合わせると表示されるメモ",
+        ],
+    ),
+    (
+        "Forget this hour's lore",
+        ["Olvidar el saber de esta hora", "Überlieferung dieser Stunde vergessen", "この時の伝承を消去"],
+    ),
+    ("■ Stop (B)", ["■ Detener (B)", "■ Stopp (B)", "■ 停止 (B)"]),
+    ("◯ Start (B)", ["◯ Iniciar (B)", "◯ Start (B)", "◯ 開始 (B)"]),
+    (
+        "Pace breathing on the beat ring; the time stays in the overlay",
+        [
+            "Marca la respiración en el anillo de pulsos; la hora sigue en la superposición",
+            "Atmung am Schlagring takten; die Uhrzeit bleibt im Overlay",
+            "拍のリングで呼吸のペースを取ります。時刻はオーバーレイに表示されたまま",
+        ],
+    ),
+    ("Seconds breathing in", ["Segundos inspirando", "Sekunden einatmen", "吸う秒数"]),
+    ("Seconds holding in", ["Segundos reteniendo el aire", "Sekunden Luft anhalten", "吸って止める秒数"]),
+    ("Seconds breathing out", ["Segundos espirando", "Sekunden ausatmen", "吐く秒数"]),
+    ("Seconds holding out", ["Segundos sin aire", "Sekunden Pause nach dem Ausatmen", "吐いて止める秒数"]),
+    ("Length:", ["Duración:", "Dauer:", "長さ:"]),
+    (" min", [" min", " Min.", " 分"]),
+    (
+        "End the session after this long; 0 runs until stopped",
+        [
+            "Termina la sesión tras este tiempo; 0 sigue hasta detenerla",
+            "Sitzung nach dieser Dauer beenden; 0 läuft bis zum Stopp",
+            "この時間でセッションを終了。0 は停止するまで続けます",
+        ],
+    ),
+    ("No sessions yet", ["Aún no hay sesiones", "Noch keine Sitzungen", "まだセッションはありません"]),
+    (
+        "{0} sessions · {1} total · longest {2} · {3} cycles",
+        [
+            "{0} sesiones · {1} en total · la más larga {2} · {3} ciclos",
+            "{0} Sitzungen · {1} gesamt · längste {2} · {3} Zyklen",
+            "{0} セッション · 合計 {1} · 最長 {2} · {3} サイクル",
+        ],
+    ),
+    (
+        "Clear the breathing statistics",
+        ["Borrar las estadísticas de respiración", "Atemstatistik löschen", "呼吸の統計を消去"],
+    ),
+    (
+        "Send /beat, /minute and /hour messages over UDP",
+        [
+            "Enviar mensajes /beat, /minute y /hour por UDP",
+            "/beat-, /minute- und /hour-Nachrichten über UDP senden",
+            "/beat、/minute、/hour メッセージを UDP で送信",
+        ],
+    ),
+    ("Prefix:", ["Prefijo:", "Präfix:", "プレフィックス:"]),
+    (
+        "Play a drum note on every beat",
+        [
+            "Tocar una nota de percusión en cada pulso",
+            "Bei jedem Schlag eine Trommelnote spielen",
+            "拍ごとにドラムの音を鳴らす",
+        ],
+    ),
+    ("Choose port", ["Elegir puerto", "Port wählen", "ポートを選択"]),
+    (
+        "Rescan MIDI ports",
+        ["Volver a buscar puertos MIDI", "MIDI-Ports neu suchen", "MIDI ポートを再検索"],
+    ),
+    (
+        "No MIDI output ports found",
+        [
+            "No se encontraron puertos de salida MIDI",
+            "Keine MIDI-Ausgänge gefunden",
+            "MIDI 出力ポートが見つかりません",
+        ],
+    ),
+    (
+        "Built without MIDI (enable the `midi` feature)",
+        [
+            "Compilado sin MIDI (activa la función `midi`)",
+            "Ohne MIDI gebaut (Feature `midi` aktivieren)",
+            "MIDI なしでビルドされています (`midi` 機能を有効にしてください)",
+        ],
+    ),
+    ("Ch:", ["Canal:", "Kanal:", "チャンネル:"]),
+    (
+        "Send MIDI clock at one quarter note per second (60 BPM)",
+        [
+            "Enviar reloj MIDI a una negra por segundo (60 BPM)",
+            "MIDI-Clock mit einer Viertelnote pro Sekunde senden (60 BPM)",
+            "1 秒に四分音符 1 つ (60 BPM) で MIDI クロックを送信",
+        ],
+    ),
+    ("Select Ensemble", ["Elegir conjunto", "Ensemble wählen", "アンサンブルを選択"]),
+    ("★ Favorites", ["★ Favoritos", "★ Favoriten", "★ お気に入り"]),
+    (
+        "📍 Use System Timezone",
+        ["📍 Usar zona horaria del sistema", "📍 Systemzeitzone verwenden", "📍 システムのタイムゾーンを使用"],
+    ),
+    ("{0} results", ["{0} resultados", "{0} Ergebnisse", "{0} 件の結果"]),
+    ("Close (Esc)", ["Cerrar (Esc)", "Schließen (Esc)", "閉じる (Esc)"]),
+    (
+        "Timezone data may be stale or missing",
+        [
+            "Los datos de zona horaria pueden faltar o estar desactualizados",
+            "Zeitzonendaten fehlen oder sind veraltet",
+            "タイムゾーンデータが古いか欠けている可能性があります",
+        ],
+    ),
+    (
+        "Invalid timezone: {0}. Reverting.",
+        [
+            "Zona horaria no válida: {0}. Se revierte.",
+            "Ungültige Zeitzone: {0}. Wird zurückgesetzt.",
+            "無効なタイムゾーン: {0}。元に戻します。",
+        ],
+    ),
+    (
+        "Hosting duel {0} (offline: this computer only)",
+        [
+            "Organizando el duelo {0} (sin conexión: solo este equipo)",
+            "Duell {0} wird ausgerichtet (offline: nur dieser Computer)",
+            "対決 {0} をホスト中 (オフライン: このコンピューターのみ)",
+        ],
+    ),
+    (
+        "Hosting duel {0}",
+        ["Organizando el duelo {0}", "Duell {0} wird ausgerichtet", "対決 {0} をホスト中"],
+    ),
+    (
+        "Couldn't host duel: {0}",
+        [
+            "No se pudo organizar el duelo: {0}",
+            "Duell konnte nicht ausgerichtet werden: {0}",
+            "対決をホストできませんでした: {0}",
+        ],
+    ),
+    ("Joining duel {0}", ["Uniéndose al duelo {0}", "Trete Duell {0} bei", "対決 {0} に参加中"]),
+    (
+        "Couldn't join duel: {0}",
+        [
+            "No se pudo unir al duelo: {0}",
+            "Duell konnte nicht beigetreten werden: {0}",
+            "対決に参加できませんでした: {0}",
+        ],
+    ),
+    (
+        "Opponent joined the duel",
+        ["El rival se unió al duelo", "Der Gegner ist dem Duell beigetreten", "相手が対決に参加しました"],
+    ),
+    (
+        "Lost the opponent; conducting solo",
+        [
+            "Rival perdido; dirigiendo en solitario",
+            "Gegner verloren; dirigiere allein",
+            "相手を見失いました。一人で指揮中",
+        ],
+    ),
+    ("Recording session", ["Grabando la sesión", "Sitzung wird aufgenommen", "セッションを録画中"]),
+    (
+        "Saved session to {0}",
+        ["Sesión guardada en {0}", "Sitzung unter {0} gespeichert", "セッションを {0} に保存しました"],
+    ),
+    (
+        "Session save failed: {0}",
+        [
+            "No se pudo guardar la sesión: {0}",
+            "Speichern der Sitzung fehlgeschlagen: {0}",
+            "セッションの保存に失敗しました: {0}",
+        ],
+    ),
+    (
+        "Couldn't load session: {0}",
+        [
+            "No se pudo cargar la sesión: {0}",
+            "Sitzung konnte nicht geladen werden: {0}",
+            "セッションを読み込めませんでした: {0}",
+        ],
+    ),
+    (
+        "Breathing session started",
+        ["Sesión de respiración iniciada", "Atemsitzung gestartet", "呼吸セッションを開始しました"],
+    ),
+    (
+        "Breathing session: {0}, {1} cycles",
+        [
+            "Sesión de respiración: {0}, {1} ciclos",
+            "Atemsitzung: {0}, {1} Zyklen",
+            "呼吸セッション: {0}、{1} サイクル",
+        ],
+    ),
+    ("Left the duel", ["Has salido del duelo", "Duell verlassen", "対決から退出しました"]),
+    ("Breathe in", ["Inspira", "Einatmen", "息を吸って"]),
+    ("Hold", ["Mantén", "Halten", "止めて"]),
+    ("Breathe out", ["Espira", "Ausatmen", "息を吐いて"]),
+    ("{0} · cycle {1}", ["{0} · ciclo {1}", "{0} · Zyklus {1}", "{0} · サイクル {1}"]),
+    ("Hour {0}", ["Hora {0}", "Stunde {0}", "{0}時"]),
+    ("Hour {0} · {1}", ["Hora {0} · {1}", "Stunde {0} · {1}", "{0}時 · {1}"]),
+    ("Hour {0} highlighted", ["Hora {0} resaltada", "Stunde {0} hervorgehoben", "{0}時を強調表示"]),
+    ("● REC", ["● GRAB", "● AUFN", "● 録画"]),
+    ("SYNC {0}%", ["SINC {0}%", "SYNC {0}%", "同期 {0}%"]),
+    ("Beat", ["Pulso", "Schlag", "拍"]),
+    ("{0} · {1}s", ["{0} · {1} s", "{0} · {1} s", "{0} · {1}秒"]),
+    ("Now: {0}", ["Ahora: {0}", "Jetzt: {0}", "現在: {0}"]),
+    (
+        "⚠ Timezone data may be missing or stale. Showing UTC as fallback.",
+        [
+            "⚠ Los datos de zona horaria pueden faltar o estar desactualizados. Se muestra UTC.",
+            "⚠ Zeitzonendaten fehlen oder sind veraltet. Stattdessen wird UTC angezeigt.",
+            "⚠ タイムゾーンデータが見つからないか古い可能性があります。UTCで表示しています。",
+        ],
+    ),
+    ("YOU", ["TÚ", "DU", "あなた"]),
+    ("OPPONENT", ["RIVAL", "GEGNER", "相手"]),
+    ("Duel socket: {0}", ["Socket del duelo: {0}", "Duell-Socket: {0}", "対決ソケット: {0}"]),
+    (
+        "{0} is not a session code",
+        ["{0} no es un código de sesión", "{0} ist kein Sitzungscode", "{0} はセッションコードではありません"],
+    ),
+    // Audit ledger
+    ("╔══ CONTROLS ══╗", ["╔══ CONTROLES ══╗", "╔══ STEUERUNG ══╗", "╔══ 操作 ══╗"]),
+    ("▸ TIMEZONE", ["▸ ZONA HORARIA", "▸ ZEITZONE", "▸ タイムゾーン"]),
+    ("Change Zone (T)", ["Cambiar zona (T)", "Zone wechseln (T)", "ゾーンを変更 (T)"]),
+    ("Use System TZ", ["Usar zona del sistema", "System-TZ verwenden", "システムの TZ を使用"]),
+    ("▸ DST INSIGHTS", ["▸ HORARIO DE VERANO", "▸ SOMMERZEIT", "▸ 夏時間の情報"]),
+    (
+        "● Daylight Saving Time ACTIVE",
+        ["● Horario de verano ACTIVO", "● Sommerzeit AKTIV", "● 夏時間 有効"],
+    ),
+    ("▸ TIME RANGE", ["▸ INTERVALO", "▸ ZEITRAUM", "▸ 時間範囲"]),
+    ("5 min", ["5 min", "5 Min.", "5 分"]),
+    ("10 min", ["10 min", "10 Min.", "10 分"]),
+    ("30 min", ["30 min", "30 Min.", "30 分"]),
+    ("60 min", ["60 min", "60 Min.", "60 分"]),
+    (
+        "{0} entries in buffer",
+        ["{0} entradas en el búfer", "{0} Einträge im Puffer", "バッファ内に {0} 件"],
+    ),
+    ("▸ COLUMNS", ["▸ COLUMNAS", "▸ SPALTEN", "▸ 列"]),
+    ("Local time", ["Hora local", "Ortszeit", "現地時刻"]),
+    ("Second zone", ["Segunda zona", "Zweite Zone", "2 つ目のゾーン"]),
+    ("Epoch", ["Época", "Epoche", "エポック"]),
+    ("Offset", ["Desfase", "Versatz", "オフセット"]),
+    ("DST flag", ["Marca de horario de verano", "Sommerzeit-Kennzeichen", "夏時間フラグ"]),
+    ("Hash", ["Hash", "Hash", "ハッシュ"]),
+    (
+        "Star a zone to compare against it",
+        [
+            "Marca una zona con estrella para compararla",
+            "Markiere eine Zone mit Stern, um mit ihr zu vergleichen",
+            "比較するゾーンに星を付けてください",
+        ],
+    ),
+    ("▸ SEARCH", ["▸ BUSCAR", "▸ SUCHE", "▸ 検索"]),
+    (
+        "time, offset, DST, hash…",
+        [
+            "hora, desfase, horario de verano, hash…",
+            "Zeit, Versatz, Sommerzeit, Hash…",
+            "時刻、オフセット、夏時間、ハッシュ…",
+        ],
+    ),
+    ("Regex", ["Regex", "Regex", "正規表現"]),
+    ("Only matches", ["Solo coincidencias", "Nur Treffer", "一致のみ"]),
+    ("{0} / {1} matches", ["{0} / {1} coincidencias", "{0} / {1} Treffer", "{0} / {1} 件一致"]),
+    ("{0} matches", ["{0} coincidencias", "{0} Treffer", "{0} 件一致"]),
+    ("▲ Prev", ["▲ Anterior", "▲ Zurück", "▲ 前へ"]),
+    ("▼ Next (N)", ["▼ Siguiente (N)", "▼ Weiter (N)", "▼ 次へ (N)"]),
+    ("▸ STATS", ["▸ ESTADÍSTICAS", "▸ STATISTIK", "▸ 統計"]),
+    ("Uptime", ["Tiempo activo", "Laufzeit", "稼働時間"]),
+    ("Entries", ["Entradas", "Einträge", "エントリ"]),
+    ("Blocks", ["Bloques", "Blöcke", "ブロック"]),
+    ("Chapters", ["Capítulos", "Kapitel", "チャプター"]),
+    ("Per block", ["Por bloque", "Pro Block", "ブロックあたり"]),
+    ("Gaps", ["Huecos", "Lücken", "欠落"]),
+    ("Avg hash", ["Hash medio", "Hash im Schnitt", "平均ハッシュ時間"]),
+    ("Verify rate", ["Ritmo de verificación", "Prüfrate", "検証速度"]),
+    ("{0} ({1} retained)", ["{0} ({1} retenidas)", "{0} ({1} behalten)", "{0} ({1} 件保持)"]),
+    (
+        "{0} ({1}s unrecorded)",
+        ["{0} ({1} s sin registrar)", "{0} ({1} s nicht aufgezeichnet)", "{0} ({1} 秒未記録)"],
+    ),
+    ("▸ CHAIN INTEGRITY", ["▸ INTEGRIDAD DE LA CADENA", "▸ KETTENINTEGRITÄT", "▸ チェーンの整合性"]),
+    ("Verify Chain (V)", ["Verificar cadena (V)", "Kette prüfen (V)", "チェーンを検証 (V)"]),
+    (
+        "✓ Chain intact ({0} entries)",
+        ["✓ Cadena intacta ({0} entradas)", "✓ Kette intakt ({0} Einträge)", "✓ チェーンは正常です ({0} 件)"],
+    ),
+    (
+        "✗ Chain hash mismatch at {0}",
+        [
+            "✗ Hash de la cadena no coincide a las {0}",
+            "✗ Ketten-Hash stimmt um {0} nicht überein",
+            "✗ {0} にチェーンのハッシュ不一致",
+        ],
+    ),
+    (
+        "✗ Chain broken link at {0}",
+        [
+            "✗ Enlace de la cadena roto a las {0}",
+            "✗ Kettenglied um {0} unterbrochen",
+            "✗ {0} にチェーンのリンク切れ",
+        ],
+    ),
+    (
+        "✗ Chain head mismatch at {0}",
+        [
+            "✗ Cabeza de la cadena no coincide a las {0}",
+            "✗ Kettenkopf stimmt um {0} nicht überein",
+            "✗ {0} にチェーン先頭の不一致",
+        ],
+    ),
+    (
+        "No gaps since launch",
+        ["Sin huecos desde el inicio", "Keine Lücken seit dem Start", "起動以来欠落はありません"],
+    ),
+    (
+        "1 gap, {0} unrecorded since launch",
+        [
+            "1 hueco, {0} sin registrar desde el inicio",
+            "1 Lücke, {0} seit dem Start nicht aufgezeichnet",
+            "欠落 1 件、起動以来 {0} 未記録",
+        ],
+    ),
+    (
+        "{0} gaps, {1} unrecorded since launch",
+        [
+            "{0} huecos, {1} sin registrar desde el inicio",
+            "{0} Lücken, {1} seit dem Start nicht aufgezeichnet",
+            "欠落 {0} 件、起動以来 {1} 未記録",
+        ],
+    ),
+    (
+        "Sign minute blocks (ed25519)",
+        [
+            "Firmar bloques de minuto (ed25519)",
+            "Minutenblöcke signieren (ed25519)",
+            "分ブロックに署名 (ed25519)",
+        ],
+    ),
+    (
+        "Built without signing (enable the `signing` feature)",
+        [
+            "Compilado sin firma (activa la función `signing`)",
+            "Ohne Signierung gebaut (Feature `signing` aktivieren)",
+            "署名なしでビルドされています (`signing` 機能を有効にしてください)",
+        ],
+    ),
+    ("{0} blocks signed", ["{0} bloques firmados", "{0} Blöcke signiert", "{0} ブロックに署名済み"]),
+    (
+        "Verify Signatures (Shift+V)",
+        ["Verificar firmas (Mayús+V)", "Signaturen prüfen (Umschalt+V)", "署名を検証 (Shift+V)"],
+    ),
+    (
+        "✓ {0} block signatures valid",
+        ["✓ {0} firmas de bloque válidas", "✓ {0} Blocksignaturen gültig", "✓ {0} 件のブロック署名が有効"],
+    ),
+    (
+        "✗ Block {0} bad signature",
+        ["✗ Bloque {0}: firma no válida", "✗ Block {0}: ungültige Signatur", "✗ ブロック {0}: 署名が不正"],
+    ),
+    (
+        "✗ Block {0} root changed",
+        ["✗ Bloque {0}: la raíz cambió", "✗ Block {0}: Wurzel geändert", "✗ ブロック {0}: ルートが変更されました"],
+    ),
+    ("▸ FOLLOW", ["▸ SEGUIR", "▸ FOLGEN", "▸ フォロー"]),
+    (
+        "path, - for stdin, tcp:9000",
+        ["ruta, - para stdin, tcp:9000", "Pfad, - für stdin, tcp:9000", "パス、stdin は -、tcp:9000"],
+    ),
+    ("Stop (F)", ["Detener (F)", "Stopp (F)", "停止 (F)"]),
+    ("Follow (F)", ["Seguir (F)", "Folgen (F)", "フォロー (F)"]),
+    (
+        "Following {0}: {1} events, {2} skipped",
+        [
+            "Siguiendo {0}: {1} eventos, {2} omitidos",
+            "Folge {0}: {1} Ereignisse, {2} übersprungen",
+            "{0} をフォロー中: {1} 件のイベント、{2} 件をスキップ",
+        ],
+    ),
+    ("Stopped: {0}", ["Detenido: {0}", "Gestoppt: {0}", "停止しました: {0}"]),
+    ("▸ EXPORT", ["▸ EXPORTAR", "▸ EXPORT", "▸ エクスポート"]),
+    ("Visible", ["Visibles", "Sichtbare", "表示中"]),
+    ("All retained", ["Todas las retenidas", "Alle behaltenen", "保持中のすべて"]),
+    ("Output directory", ["Directorio de salida", "Ausgabeverzeichnis", "出力ディレクトリ"]),
+    ("Export (E)", ["Exportar (E)", "Exportieren (E)", "エクスポート (E)"]),
+    (
+        "Auto-rotate logs",
+        ["Rotar registros automáticamente", "Protokolle automatisch rotieren", "ログを自動ローテーション"],
+    ),
+    (
+        "Write each completed UTC hour or day to its own JSONL file",
+        [
+            "Escribir cada hora o día UTC completado en su propio archivo JSONL",
+            "Jede abgeschlossene UTC-Stunde oder jeden Tag in eine eigene JSONL-Datei schreiben",
+            "完了した UTC の各時間または各日を個別の JSONL ファイルに書き出します",
+        ],
+    ),
+    ("Hourly", ["Cada hora", "Stündlich", "毎時"]),
+    ("Daily", ["Diaria", "Täglich", "毎日"]),
+    ("Keep", ["Conservar", "Behalten", "保持"]),
+    (
+        "0 keeps every log",
+        ["0 conserva todos los registros", "0 behält alle Protokolle", "0 ですべてのログを保持"],
+    ),
+    ("logs (all)", ["registros (todos)", "Protokolle (alle)", "件のログ (すべて)"]),
+    ("logs", ["registros", "Protokolle", "件のログ"]),
+    (
+        "Gzip closed logs",
+        [
+            "Comprimir con gzip los registros cerrados",
+            "Geschlossene Protokolle mit gzip packen",
+            "閉じたログを gzip 圧縮",
+        ],
+    ),
+    ("▸ ACCESSIBILITY", ["▸ ACCESIBILIDAD", "▸ BARRIEREFREIHEIT", "▸ アクセシビリティ"]),
+    ("Density:", ["Densidad:", "Dichte:", "密度:"]),
+    ("Compact", ["Compacta", "Kompakt", "コンパクト"]),
+    ("Normal", ["Normal", "Normal", "標準"]),
+    ("Large", ["Grande", "Groß", "大"]),
+    ("▸ SHORTCUTS", ["▸ ATAJOS", "▸ TASTENKÜRZEL", "▸ ショートカット"]),
+    ("● LIVE", ["● EN VIVO", "● LIVE", "● ライブ"]),
+    ("○ PAUSED", ["○ EN PAUSA", "○ PAUSIERT", "○ 一時停止中"]),
+    (
+        "╔══ SELECT TIMEZONE ══╗",
+        ["╔══ ELEGIR ZONA HORARIA ══╗", "╔══ ZEITZONE WÄHLEN ══╗", "╔══ タイムゾーンを選択 ══╗"],
+    ),
+    (
+        "╔══ ENTRY DETAIL ══╗",
+        ["╔══ DETALLE DE LA ENTRADA ══╗", "╔══ EINTRAGSDETAILS ══╗", "╔══ エントリの詳細 ══╗"],
+    ),
+    ("▸ HASH CHAIN", ["▸ CADENA DE HASH", "▸ HASH-KETTE", "▸ ハッシュチェーン"]),
+    ("Previous", ["Anterior", "Vorheriger", "前"]),
+    ("▸ TIMESTAMPS", ["▸ MARCAS DE TIEMPO", "▸ ZEITSTEMPEL", "▸ タイムスタンプ"]),
+    (
+        "Show in linked clocks",
+        ["Mostrar en los relojes vinculados", "In verknüpften Uhren zeigen", "リンクした時計で表示"],
+    ),
+    ("Copy", ["Copiar", "Kopieren", "コピー"]),
+    (
+        "No linked clocks are open",
+        [
+            "No hay relojes vinculados abiertos",
+            "Keine verknüpften Uhren geöffnet",
+            "リンクした時計は開いていません",
+        ],
+    ),
+    (
+        "Sent {0} to 1 clock",
+        ["{0} enviado a 1 reloj", "{0} an 1 Uhr gesendet", "{0} を 1 つの時計に送信しました"],
+    ),
+    (
+        "Sent {0} to {1} clocks",
+        ["{0} enviado a {1} relojes", "{0} an {1} Uhren gesendet", "{0} を {1} つの時計に送信しました"],
+    ),
+    (
+        "Could not reach linked clocks: {0}",
+        [
+            "No se pudo contactar con los relojes vinculados: {0}",
+            "Verknüpfte Uhren nicht erreichbar: {0}",
+            "リンクした時計に接続できませんでした: {0}",
+        ],
+    ),
+    (
+        "Block signing is off",
+        ["La firma de bloques está desactivada", "Blocksignierung ist aus", "ブロック署名はオフです"],
+    ),
+    (
+        "No matching entries",
+        ["No hay entradas coincidentes", "Keine passenden Einträge", "一致するエントリはありません"],
+    ),
+    ("Following {0}", ["Siguiendo {0}", "Folge {0}", "{0} をフォロー中"]),
+    ("Stopped following", ["Se dejó de seguir", "Folgen beendet", "フォローを停止しました"]),
+    (
+        "Event stream closed: {0}",
+        ["Flujo de eventos cerrado: {0}", "Ereignisstrom geschlossen: {0}", "イベントストリームが閉じました: {0}"],
+    ),
+    (
+        "Exported {0} to {1}",
+        ["{0} exportado a {1}", "{0} nach {1} exportiert", "{0} を {1} にエクスポートしました"],
+    ),
+    (
+        "No directory to write logs to",
+        [
+            "No hay directorio donde escribir los registros",
+            "Kein Verzeichnis für Protokolle",
+            "ログを書き込むディレクトリがありません",
+        ],
+    ),
+    (
+        "Log rotation stopped: {0}",
+        [
+            "Rotación de registros detenida: {0}",
+            "Protokollrotation gestoppt: {0}",
+            "ログのローテーションを停止しました: {0}",
+        ],
+    ),
+    ("Copied {0}", ["{0} copiado", "{0} kopiert", "{0} をコピーしました"]),
+    (
+        "DST is active. Clocks will fall back\n\
+         1 hour in autumn {0}.",
+        [
+            "Horario de verano activo. Los relojes se\n\
+             atrasarán 1 hora en otoño de {0}.",
+            "Sommerzeit aktiv. Die Uhren werden im\n\
+             Herbst {0} um 1 Stunde zurückgestellt.",
+            "夏時間が有効です。{0} 年の秋に\n\
+             時計が 1 時間戻ります。",
+        ],
+    ),
+    (
+        "Standard time. Clocks will spring\n\
+         forward 1 hour in March {0}.",
+        [
+            "Horario estándar. Los relojes se\n\
+             adelantarán 1 hora en marzo de {0}.",
+            "Normalzeit. Die Uhren werden im\n\
+             März {0} um 1 Stunde vorgestellt.",
+            "標準時です。{0} 年 3 月に\n\
+             時計が 1 時間進みます。",
+        ],
+    ),
+    (
+        "Standard time. DST ended for {0}.\n\
+         Next DST starts March {1}.",
+        [
+            "Horario estándar. El horario de verano terminó en {0}.\n\
+             El próximo empieza en marzo de {1}.",
+            "Normalzeit. Die Sommerzeit {0} ist vorbei.\n\
+             Die nächste beginnt im März {1}.",
+            "標準時です。{0} 年の夏時間は終了しました。\n\
+             次の夏時間は {1} 年 3 月に始まります。",
+        ],
+    ),
+    (
+        "╔══ AUDIT LEDGER CLOCK ══╗",
+        ["╔══ RELOJ LIBRO DE AUDITORÍA ══╗", "╔══ PRÜFPROTOKOLL-UHR ══╗", "╔══ 監査台帳クロック ══╗"],
+    ),
+    ("HASH: {0}", ["HASH: {0}", "HASH: {0}", "ハッシュ: {0}"]),
+    (
+        "{0} ═══ CHAPTER {1} ({2} {3}) ═══ {4} blocks │ {5} entries │ ROOT {6}",
+        [
+            "{0} ═══ CAPÍTULO {1} ({2} {3}) ═══ {4} bloques │ {5} entradas │ RAÍZ {6}",
+            "{0} ═══ KAPITEL {1} ({2} {3}) ═══ {4} Blöcke │ {5} Einträge │ WURZEL {6}",
+            "{0} ═══ 章 {1} ({2} {3}) ═══ {4} ブロック │ {5} 件 │ ルート {6}",
+        ],
+    ),
+    (
+        "{0} BLOCK {1} │ {2} entries │ ROOT {3}",
+        [
+            "{0} BLOQUE {1} │ {2} entradas │ RAÍZ {3}",
+            "{0} BLOCK {1} │ {2} Einträge │ WURZEL {3}",
+            "{0} ブロック {1} │ {2} 件 │ ルート {3}",
+        ],
+    ),
+    ("SIGNED", ["FIRMADO", "SIGNIERT", "署名済み"]),
+    (
+        "│ ══ DST GAP: {0} → {1} ══ │",
+        [
+            "│ ══ SALTO DE HORARIO DE VERANO: {0} → {1} ══ │",
+            "│ ══ SOMMERZEIT-LÜCKE: {0} → {1} ══ │",
+            "│ ══ 夏時間の欠落: {0} → {1} ══ │",
+        ],
+    ),
+    (
+        "│ ══ LEAP SECOND: {0} ══ │",
+        [
+            "│ ══ SEGUNDO INTERCALAR: {0} ══ │",
+            "│ ══ SCHALTSEKUNDE: {0} ══ │",
+            "│ ══ うるう秒: {0} ══ │",
+        ],
+    ),
+    (
+        "│ ░░ GAP: {0} seconds unrecorded (suspend suspected) ░░ │",
+        [
+            "│ ░░ HUECO: {0} segundos sin registrar (posible suspensión) ░░ │",
+            "│ ░░ LÜCKE: {0} Sekunden nicht aufgezeichnet (Ruhezustand vermutet) ░░ │",
+            "│ ░░ 欠落: {0} 秒間未記録(スリープの可能性) ░░ │",
+        ],
+    ),
+    (
+        "▲ RETURN TO LIVE ({0})",
+        ["▲ VOLVER A EN VIVO ({0})", "▲ ZURÜCK ZU LIVE ({0})", "▲ ライブに戻る ({0})"],
+    ),
+    ("Last closed {0}", ["Último cerrado: {0}", "Zuletzt geschlossen: {0}", "最後に閉じたログ: {0}"]),
+    ("Writing {0}", ["Escribiendo {0}", "Schreibe {0}", "{0} を書き込み中"]),
+    (
+        "Waiting for the next entry",
+        ["Esperando la siguiente entrada", "Warte auf den nächsten Eintrag", "次のエントリーを待っています"],
+    ),
+    // Temporal grammar
+    ("Temporal Grammar", ["Gramática temporal", "Zeitgrammatik", "時間の文法"]),
+    ("Change Zone (Z)", ["Cambiar zona (Z)", "Zone wechseln (Z)", "ゾーンを変更 (Z)"]),
+    ("None", ["Ninguna", "Keine", "なし"]),
+    ("Compare (C)", ["Comparar (C)", "Vergleichen (C)", "比較 (C)"]),
+    (
+        "[A] Favorites Atlas: ON",
+        ["[A] Atlas de favoritos: SÍ", "[A] Favoritenatlas: AN", "[A] お気に入りアトラス: オン"],
+    ),
+    (
+        "[A] Favorites Atlas: OFF",
+        ["[A] Atlas de favoritos: NO", "[A] Favoritenatlas: AUS", "[A] お気に入りアトラス: オフ"],
+    ),
+    ("▸ DST STATUS", ["▸ ESTADO DEL HORARIO DE VERANO", "▸ SOMMERZEITSTATUS", "▸ 夏時間の状態"]),
+    ("DST Active", ["Horario de verano activo", "Sommerzeit aktiv", "夏時間中"]),
+    (
+        "⚠ Change in {0}h (spring forward)",
+        ["⚠ Cambio en {0} h (se adelanta)", "⚠ Umstellung in {0} h (vor)", "⚠ {0}時間後に切り替え (進む)"],
+    ),
+    (
+        "⚠ Change in {0}h (fall back)",
+        ["⚠ Cambio en {0} h (se atrasa)", "⚠ Umstellung in {0} h (zurück)", "⚠ {0}時間後に切り替え (戻る)"],
+    ),
+    ("▸ DECODE QUIZ", ["▸ PRUEBA DE LECTURA", "▸ LESE-QUIZ", "▸ 読み取りクイズ"]),
+    ("[Q] Start Quiz", ["[Q] Empezar prueba", "[Q] Quiz starten", "[Q] クイズを開始"]),
+    (
+        "What time does the diagram show?",
+        ["¿Qué hora muestra el diagrama?", "Welche Uhrzeit zeigt das Diagramm?", "図は何時を示していますか?"],
+    ),
+    ("h:mm or h:mm:ss", ["h:mm o h:mm:ss", "h:mm oder h:mm:ss", "h:mm または h:mm:ss"]),
+    ("Submit", ["Enviar", "Absenden", "回答"]),
+    (
+        "12-hour dial, within {0}s counts",
+        [
+            "Esfera de 12 horas; vale con {0} s de margen",
+            "12-Stunden-Zifferblatt, {0} s Toleranz",
+            "12時間表示、{0}秒以内なら正解",
+        ],
+    ),
+    ("✓ Correct", ["✓ Correcto", "✓ Richtig", "✓ 正解"]),
+    ("✗ Not quite", ["✗ No del todo", "✗ Nicht ganz", "✗ 惜しい"]),
+    (
+        "Answer {0} · you said {1} ({2})",
+        [
+            "Respuesta {0} · dijiste {1} ({2})",
+            "Lösung {0} · deine Antwort {1} ({2})",
+            "正解 {0} · あなたの回答 {1} ({2})",
+        ],
+    ),
+    ("exact", ["exacto", "exakt", "ぴったり"]),
+    ("{0}s late", ["{0} s tarde", "{0} s zu spät", "{0}秒遅い"]),
+    ("{0}s early", ["{0} s pronto", "{0} s zu früh", "{0}秒早い"]),
+    ("{0}m {1}s late", ["{0} min {1} s tarde", "{0} min {1} s zu spät", "{0}分{1}秒遅い"]),
+    ("{0}m {1}s early", ["{0} min {1} s pronto", "{0} min {1} s zu früh", "{0}分{1}秒早い"]),
+    ("Next Question (N)", ["Siguiente pregunta (N)", "Nächste Frage (N)", "次の問題 (N)"]),
+    ("[Q] Stop Quiz", ["[Q] Terminar prueba", "[Q] Quiz beenden", "[Q] クイズを終了"]),
+    (
+        "Score {0}/{1} ({2}%) · streak {3} · best {4}",
+        [
+            "Puntuación {0}/{1} ({2} %) · racha {3} · mejor {4}",
+            "Punkte {0}/{1} ({2} %) · Serie {3} · Bestwert {4}",
+            "スコア {0}/{1} ({2}%) · 連続 {3} · 最高 {4}",
+        ],
+    ),
+    ("▸ TIME CONTROL", ["▸ CONTROL DEL TIEMPO", "▸ ZEITSTEUERUNG", "▸ 時間操作"]),
+    ("? QUIZ", ["? PRUEBA", "? QUIZ", "? クイズ"]),
+    ("⏸ PAUSED", ["⏸ EN PAUSA", "⏸ PAUSIERT", "⏸ 一時停止中"]),
+    ("Return to Live (L)", ["Volver a en directo (L)", "Zurück zu live (L)", "ライブに戻る (L)"]),
+    (
+        "[ ] step sec  |  Shift: min  |  Ctrl: hr",
+        [
+            "[ ] paso seg  |  Mayús: min  |  Ctrl: h",
+            "[ ] Schritt s  |  Umschalt: min  |  Strg: h",
+            "[ ] 秒送り  |  Shift: 分  |  Ctrl: 時",
+        ],
+    ),
+    ("▸ VIEW MODES", ["▸ MODOS DE VISTA", "▸ ANSICHTSMODI", "▸ 表示モード"]),
+    ("[D] Decode Mode: ON", ["[D] Modo de lectura: SÍ", "[D] Lesemodus: AN", "[D] 読み取りモード: オン"]),
+    ("[D] Decode Mode: OFF", ["[D] Modo de lectura: NO", "[D] Lesemodus: AUS", "[D] 読み取りモード: オフ"]),
+    ("Explicit Mode: ON", ["Modo explícito: SÍ", "Klartextmodus: AN", "明示モード: オン"]),
+    ("Explicit Mode: OFF", ["Modo explícito: NO", "Klartextmodus: AUS", "明示モード: オフ"]),
+    (
+        "(Standard time display)",
+        ["(Visualización de hora estándar)", "(Normale Zeitanzeige)", "(標準の時刻表示)"],
+    ),
+    (
+        "[G] Geometry Inspector",
+        ["[G] Inspector de geometría", "[G] Geometrie-Inspektor", "[G] ジオメトリ・インスペクター"],
+    ),
+    (
+        "Every geometry parameter as a slider, to try the mapping",
+        [
+            "Cada parámetro de geometría como control deslizante, para probar la correspondencia",
+            "Jeder Geometrieparameter als Regler, um die Zuordnung auszuprobieren",
+            "すべての形状パラメータをスライダーにして対応を試せます",
+        ],
+    ),
+    ("[E] Export SVG", ["[E] Exportar SVG", "[E] SVG exportieren", "[E] SVG を書き出す"]),
+    (
+        "[Shift+E] Time Poster",
+        ["[Mayús+E] Póster de la hora", "[Umschalt+E] Zeitposter", "[Shift+E] 時刻ポスター"],
+    ),
+    (
+        "Render any instant as a print-ready PNG or SVG",
+        [
+            "Renderiza cualquier instante como PNG o SVG listo para imprimir",
+            "Beliebigen Moment als druckfertiges PNG oder SVG rendern",
+            "任意の瞬間を印刷用の PNG または SVG に書き出します",
+        ],
+    ),
+    ("▸ VIEW PRESETS", ["▸ VISTAS GUARDADAS", "▸ ANSICHTSVORLAGEN", "▸ 表示プリセット"]),
+    ("Delete preset", ["Eliminar vista", "Vorlage löschen", "プリセットを削除"]),
+    (
+        "Save a view to recall it with 1–9",
+        [
+            "Guarda una vista para recuperarla con 1–9",
+            "Ansicht speichern und mit 1–9 abrufen",
+            "ビューを保存して 1–9 で呼び出せます",
+        ],
+    ),
+    ("e.g. presentation", ["p. ej. presentación", "z. B. Präsentation", "例: プレゼン"]),
+    ("Save View", ["Guardar vista", "Ansicht speichern", "ビューを保存"]),
+    (
+        "Save zone, pan, zoom and modes under this name",
+        [
+            "Guarda zona, desplazamiento, zoom y modos con este nombre",
+            "Zone, Verschiebung, Zoom und Modi unter diesem Namen speichern",
+            "ゾーン、パン、ズーム、モードをこの名前で保存します",
+        ],
+    ),
+    ("decode", ["lectura", "Lesen", "読み取り"]),
+    ("explicit", ["explícito", "Klartext", "明示"]),
+    (
+        "[?] How to read this clock",
+        ["[?] Cómo leer este reloj", "[?] So liest man diese Uhr", "[?] この時計の読み方"],
+    ),
+    ("▸ DIAGRAM STATE", ["▸ ESTADO DEL DIAGRAMA", "▸ DIAGRAMMZUSTAND", "▸ 図の状態"]),
+    (
+        "Hidden until the quiz answer is in.",
+        [
+            "Oculto hasta que respondas la prueba.",
+            "Verborgen, bis die Quizantwort abgegeben ist.",
+            "クイズに回答するまで非表示です。",
+        ],
+    ),
+    (
+        "Hold Space or click to reveal exact time",
+        [
+            "Mantén Espacio o haz clic para ver la hora exacta",
+            "Leertaste halten oder klicken für die genaue Zeit",
+            "スペース長押しかクリックで正確な時刻を表示",
+        ],
+    ),
+    ("Select Timezone", ["Seleccionar zona horaria", "Zeitzone wählen", "タイムゾーンを選択"]),
+    ("Seconds", ["Segundos", "Sekunden", "秒"]),
+    ("Date", ["Fecha", "Datum", "日付"]),
+    ("DST note", ["Nota de horario de verano", "Sommerzeithinweis", "夏時間の注記"]),
+    ("text size", ["tamaño del texto", "Textgröße", "文字サイズ"]),
+    (
+        "Larger for a wall display; lines shrink to fit the window",
+        [
+            "Más grande para una pantalla de pared; las líneas se reducen para caber en la ventana",
+            "Größer für Wandanzeigen; Zeilen schrumpfen auf Fenstergröße",
+            "壁掛け表示向けに拡大。行はウィンドウに合わせて縮みます",
+        ],
+    ),
+    ("Left", ["Izquierda", "Links", "左"]),
+    ("Center", ["Centro", "Mitte", "中央"]),
+    ("Right", ["Derecha", "Rechts", "右"]),
+    ("ms transition", ["ms de transición", "ms Übergang", "ms の遷移"]),
+    ("Easing", ["Suavizado", "Dynamik", "イージング"]),
+    ("Linear", ["Lineal", "Linear", "リニア"]),
+    ("Ease in-out", ["Suave al entrar y salir", "Sanft ein und aus", "イーズインアウト"]),
+    ("Ease out", ["Suave al salir", "Sanft aus", "イーズアウト"]),
+    ("Sonification", ["Sonificación", "Sonifikation", "音響化"]),
+    (
+        "Hour sets the pitch, minute sweeps the timbre, seconds tick",
+        [
+            "La hora fija el tono, el minuto recorre el timbre, los segundos hacen tic",
+            "Die Stunde bestimmt die Tonhöhe, die Minute die Klangfarbe, Sekunden ticken",
+            "時が音の高さ、分が音色を変え、秒が刻みます",
+        ],
+    ),
+    ("volume", ["volumen", "Lautstärke", "音量"]),
+    ("Second ticks", ["Tic de segundos", "Sekundenticks", "秒の刻み"]),
+    (
+        "Silent during quiet hours",
+        ["En silencio durante las horas de silencio", "Stumm während der Ruhezeiten", "おやすみ時間中は無音"],
+    ),
+    ("Geometry Inspector", ["Inspector de geometría", "Geometrie-Inspektor", "ジオメトリ・インスペクター"]),
+    (
+        "Detached from live time",
+        ["Desligado de la hora en directo", "Von der Live-Zeit gelöst", "ライブ時刻から切り離し中"],
+    ),
+    ("Following live time", ["Siguiendo la hora en directo", "Folgt der Live-Zeit", "ライブ時刻に追従中"]),
+    ("hour", ["hora", "Stunde", "時"]),
+    (
+        "Sets vertices = 3 + hour",
+        ["Fija vértices = 3 + hora", "Setzt Ecken = 3 + Stunde", "頂点数 = 3 + 時 に設定"],
+    ),
+    ("vertices", ["vértices", "Ecken", "頂点数"]),
+    ("minute", ["minuto", "Minute", "分"]),
+    (
+        "Sets e = 1.2 + 2.8 × m/59 and rotation = -30° - 6° × m",
+        [
+            "Fija e = 1.2 + 2.8 × m/59 y rotación = -30° - 6° × m",
+            "Setzt e = 1.2 + 2.8 × m/59 und Drehung = -30° - 6° × m",
+            "e = 1.2 + 2.8 × m/59、回転 = -30° - 6° × m に設定",
+        ],
+    ),
+    ("exponent e", ["exponente e", "Exponent e", "指数 e"]),
+    ("minute rotation °", ["rotación del minuto °", "Minutendrehung °", "分の回転 °"]),
+    ("second", ["segundo", "Sekunde", "秒"]),
+    (
+        "Sets phase = 6° × second",
+        ["Fija fase = 6° × segundo", "Setzt Phase = 6° × Sekunde", "位相 = 6° × 秒 に設定"],
+    ),
+    ("phase °", ["fase °", "Phase °", "位相 °"]),
+    ("zone rotation °", ["rotación de zona °", "Zonendrehung °", "ゾーンの回転 °"]),
+    (
+        "Live: 7.5° per hour of UTC offset, +5° under DST",
+        [
+            "En directo: 7.5° por hora de desfase UTC, +5° en horario de verano",
+            "Live: 7.5° pro Stunde UTC-Versatz, +5° bei Sommerzeit",
+            "ライブ: UTC オフセット1時間ごとに 7.5°、夏時間は +5°",
+        ],
+    ),
+    ("zone skew x", ["inclinación de zona x", "Zonenscherung x", "ゾーンの傾き x"]),
+    (
+        "Live: 0.10 × the offset's leftover minutes / 60",
+        [
+            "En directo: 0.10 × los minutos sobrantes del desfase / 60",
+            "Live: 0.10 × restliche Minuten des Versatzes / 60",
+            "ライブ: 0.10 × オフセットの端数の分 / 60",
+        ],
+    ),
+    ("DST", ["Horario de verano", "Sommerzeit", "夏時間"]),
+    (
+        "Adds 5° of rotation and shears the minute layer",
+        [
+            "Añade 5° de rotación e inclina la capa de minutos",
+            "Fügt 5° Drehung hinzu und schert die Minutenebene",
+            "回転を 5° 加え、分のレイヤーを傾けます",
+        ],
+    ),
+    (
+        "Return to live mapping",
+        ["Volver a la correspondencia en directo", "Zurück zur Live-Zuordnung", "ライブの対応に戻る"],
+    ),
+    ("Time Poster", ["Póster de la hora", "Zeitposter", "時刻ポスター"]),
+    ("Instant ({0})", ["Instante ({0})", "Zeitpunkt ({0})", "日時 ({0})"]),
+    ("Caption", ["Leyenda", "Beschriftung", "キャプション"]),
+    ("Optional", ["Opcional", "Optional", "任意"]),
+    (
+        "{0} × {1} px at 300 dpi",
+        ["{0} × {1} px a 300 ppp", "{0} × {1} px bei 300 dpi", "{0} × {1} px (300 dpi)"],
+    ),
+    (
+        "Swap portrait and landscape",
+        ["Alternar vertical y horizontal", "Hoch- und Querformat tauschen", "縦向きと横向きを入れ替え"],
+    ),
+    (
+        "Name what each layer encodes under the diagram",
+        [
+            "Nombra bajo el diagrama lo que codifica cada capa",
+            "Unter dem Diagramm benennen, was jede Ebene darstellt",
+            "各レイヤーが表すものを図の下に記します",
+        ],
+    ),
+    ("Render {0}", ["Renderizar {0}", "{0} rendern", "{0} を書き出す"]),
+    ("Sonification off", ["Sonificación desactivada", "Sonifikation aus", "音響化オフ"]),
+    ("Sonification on", ["Sonificación activada", "Sonifikation an", "音響化オン"]),
+    (
+        "Sonification unavailable: {0}",
+        [
+            "Sonificación no disponible: {0}",
+            "Sonifikation nicht verfügbar: {0}",
+            "音響化は使用できません: {0}",
+        ],
+    ),
+    (
+        "Add a favorite zone to compare with",
+        [
+            "Añade una zona favorita para comparar",
+            "Füge eine Favoritenzone zum Vergleichen hinzu",
+            "比較するお気に入りゾーンを追加してください",
+        ],
+    ),
+    (
+        "Add a favorite zone to see the atlas",
+        [
+            "Añade una zona favorita para ver el atlas",
+            "Füge eine Favoritenzone hinzu, um den Atlas zu sehen",
+            "アトラスを見るにはお気に入りゾーンを追加してください",
+        ],
+    ),
+    (
+        "Exported SVG to {0}",
+        ["SVG exportado a {0}", "SVG nach {0} exportiert", "SVG を {0} に書き出しました"],
+    ),
+    (
+        "SVG export failed: {0}",
+        ["Error al exportar SVG: {0}", "SVG-Export fehlgeschlagen: {0}", "SVG の書き出しに失敗しました: {0}"],
+    ),
+    (
+        "Saved {0} poster to {1}",
+        ["Póster {0} guardado en {1}", "{0}-Poster unter {1} gespeichert", "{0} ポスターを {1} に保存しました"],
+    ),
+    (
+        "Poster failed: {0}",
+        ["Error en el póster: {0}", "Poster fehlgeschlagen: {0}", "ポスターの作成に失敗しました: {0}"],
+    ),
+    (
+        "Name the preset first",
+        [
+            "Primero ponle nombre a la vista",
+            "Gib der Vorlage zuerst einen Namen",
+            "先にプリセット名を入力してください",
+        ],
+    ),
+    (
+        "Saved view \"{0}\" to key {1}",
+        [
+            "Vista \"{0}\" guardada en la tecla {1}",
+            "Ansicht „{0}“ auf Taste {1} gespeichert",
+            "ビュー「{0}」をキー {1} に保存しました",
+        ],
+    ),
+    (
+        "No view preset on key {0}",
+        [
+            "No hay vista guardada en la tecla {0}",
+            "Keine Ansichtsvorlage auf Taste {0}",
+            "キー {0} にプリセットはありません",
+        ],
+    ),
+    (
+        "Unknown zone {0} in preset",
+        [
+            "Zona desconocida {0} en la vista",
+            "Unbekannte Zone {0} in der Vorlage",
+            "プリセットに不明なゾーン {0} があります",
+        ],
+    ),
+    ("View: {0}", ["Vista: {0}", "Ansicht: {0}", "ビュー: {0}"]),
+    (
+        "Enter the time as h:mm or h:mm:ss",
+        [
+            "Escribe la hora como h:mm o h:mm:ss",
+            "Gib die Zeit als h:mm oder h:mm:ss ein",
+            "時刻を h:mm または h:mm:ss で入力してください",
+        ],
+    ),
+    (
+        "Hour must be 0-{0}",
+        ["La hora debe estar entre 0 y {0}", "Stunde muss 0-{0} sein", "時は 0-{0} で入力してください"],
+    ),
+    (
+        "Minute must be 0-{0}",
+        ["El minuto debe estar entre 0 y {0}", "Minute muss 0-{0} sein", "分は 0-{0} で入力してください"],
+    ),
+    (
+        "Second must be 0-{0}",
+        ["El segundo debe estar entre 0 y {0}", "Sekunde muss 0-{0} sein", "秒は 0-{0} で入力してください"],
+    ),
+    (
+        "All {0} presets are in use; delete one first",
+        [
+            "Las {0} vistas están en uso; elimina una primero",
+            "Alle {0} Vorlagen sind belegt; lösche zuerst eine",
+            "{0} 個のプリセットはすべて使用中です。先に1つ削除してください",
+        ],
+    ),
+    ("DST On", ["Horario de verano activo", "Sommerzeit an", "夏時間 オン"]),
+    ("DST Off", ["Horario de verano inactivo", "Sommerzeit aus", "夏時間 オフ"]),
+    ("DECODE LEGEND", ["LEYENDA DE DECODIFICACIÓN", "ENTSCHLÜSSELUNGSLEGENDE", "解読の凡例"]),
+    ("Hour polygon", ["Polígono de la hora", "Stundenpolygon", "時の多角形"]),
+    ("Minute superellipse", ["Superelipse del minuto", "Minuten-Superellipse", "分の超楕円"]),
+    ("Second ring", ["Anillo del segundo", "Sekundenring", "秒のリング"]),
+    ("Timezone frame", ["Marco de zona horaria", "Zeitzonenrahmen", "タイムゾーンの枠"]),
+    ("{0} sides · hour {1}", ["{0} lados · hora {1}", "{0} Seiten · Stunde {1}", "{0} 辺 · {1} 時"]),
+    ("Same offset", ["Mismo desfase", "Gleicher Versatz", "同じオフセット"]),
+    ("{0} ahead", ["{0} por delante", "{0} voraus", "{0} 進んでいる"]),
+    ("{0} behind", ["{0} por detrás", "{0} zurück", "{0} 遅れている"]),
+    ("Favorites Atlas", ["Atlas de favoritos", "Favoritenatlas", "お気に入りアトラス"]),
+    ("How to Read This Clock", ["Cómo leer este reloj", "So liest man diese Uhr", "この時計の読み方"]),
+    ("HOUR (Inner Polygon)", ["HORA (polígono interior)", "STUNDE (inneres Polygon)", "時(内側の多角形)"]),
+    (
+        "  Number of sides = 3 + hour",
+        ["  Número de lados = 3 + hora", "  Anzahl der Seiten = 3 + Stunde", "  辺の数 = 3 + 時"],
+    ),
+    (
+        "  4 sides = 1:00, 15 sides = 12:00",
+        [
+            "  4 lados = 1:00, 15 lados = 12:00",
+            "  4 Seiten = 1:00, 15 Seiten = 12:00",
+            "  4 辺 = 1:00、15 辺 = 12:00",
+        ],
+    ),
+    ("MINUTE (Middle Shape)", ["MINUTO (forma central)", "MINUTE (mittlere Form)", "分(中央の形)"]),
+    (
+        "  Shape morphs from round to square",
+        [
+            "  La forma pasa de redonda a cuadrada",
+            "  Die Form wandelt sich von rund zu eckig",
+            "  形が丸から四角へ変化",
+        ],
+    ),
+    (
+        "  Round (e≈1.2) = :00, Square (e≈4) = :59",
+        [
+            "  Redonda (e≈1.2) = :00, cuadrada (e≈4) = :59",
+            "  Rund (e≈1.2) = :00, eckig (e≈4) = :59",
+            "  丸 (e≈1.2) = :00、四角 (e≈4) = :59",
+        ],
+    ),
+    (
+        "  Rotates 360° clockwise through the hour",
+        [
+            "  Gira 360° en sentido horario durante la hora",
+            "  Dreht sich im Lauf der Stunde um 360° im Uhrzeigersinn",
+            "  1 時間で時計回りに 360° 回転",
+        ],
+    ),
+    ("SECOND (Outer Ring)", ["SEGUNDO (anillo exterior)", "SEKUNDE (äußerer Ring)", "秒(外側のリング)"]),
+    (
+        "  60 marks around the ring",
+        [
+            "  60 marcas alrededor del anillo",
+            "  60 Markierungen rund um den Ring",
+            "  リングの周りに 60 個の目盛り",
+        ],
+    ),
+    (
+        "  Highlighted mark = current second",
+        [
+            "  Marca resaltada = segundo actual",
+            "  Hervorgehobene Markierung = aktuelle Sekunde",
+            "  強調された目盛り = 現在の秒",
+        ],
+    ),
+    ("TIMEZONE", ["ZONA HORARIA", "ZEITZONE", "タイムゾーン"]),
+    (
+        "  Offset rotates/skews all layers",
+        [
+            "  El desfase gira e inclina todas las capas",
+            "  Der Versatz dreht und schert alle Ebenen",
+            "  オフセットで全レイヤーが回転・傾斜",
+        ],
+    ),
+    (
+        "  DST adds extra rotation + shear",
+        [
+            "  El horario de verano añade giro e inclinación",
+            "  Sommerzeit fügt zusätzliche Drehung und Scherung hinzu",
+            "  夏時間で回転と傾斜が追加",
+        ],
+    ),
+    ("DST KNOT", ["NUDO DE HORARIO DE VERANO", "SOMMERZEIT-KNOTEN", "夏時間の結び目"]),
+    (
+        "  Appears 24h before DST change",
+        [
+            "  Aparece 24 h antes del cambio de horario",
+            "  Erscheint 24 h vor der Zeitumstellung",
+            "  夏時間切替の 24 時間前に出現",
+        ],
+    ),
+    (
+        "  Grows larger as transition approaches",
+        [
+            "  Crece a medida que se acerca el cambio",
+            "  Wächst, je näher die Umstellung rückt",
+            "  切替が近づくほど大きくなる",
+        ],
+    ),
+    ("DECODE QUIZ", ["PRUEBA DE DECODIFICACIÓN", "ENTSCHLÜSSELUNGSQUIZ", "解読クイズ"]),
+    (
+        "  Read a random instant, type h:mm to answer",
+        [
+            "  Lee un instante al azar y responde con h:mm",
+            "  Lies einen zufälligen Zeitpunkt und antworte mit h:mm",
+            "  ランダムな時刻を読み、h:mm で回答",
+        ],
+    ),
+    ("COMPARE", ["COMPARAR", "VERGLEICHEN", "比較"]),
+    (
+        "  A favorite zone side by side; the shapes differ by offset/DST",
+        [
+            "  Una zona favorita al lado; las formas difieren por desfase/horario",
+            "  Eine Favoritenzone daneben; die Formen unterscheiden sich nach Versatz/Sommerzeit",
+            "  お気に入りゾーンを並べて表示。形はオフセット/夏時間で異なる",
+        ],
+    ),
+    ("FAVORITES ATLAS", ["ATLAS DE FAVORITOS", "FAVORITENATLAS", "お気に入りアトラス"]),
+    (
+        "  Every favorite at the same instant; click one to switch to it",
+        [
+            "  Todos los favoritos en el mismo instante; haz clic en uno para cambiar",
+            "  Alle Favoriten zum selben Zeitpunkt; klicke einen an, um zu wechseln",
+            "  全お気に入りを同じ時刻で表示。クリックで切り替え",
+        ],
+    ),
+    (
+        "GEOMETRY INSPECTOR",
+        ["INSPECTOR DE GEOMETRÍA", "GEOMETRIE-INSPEKTOR", "ジオメトリインスペクター"],
+    ),
+    (
+        "  Drag any parameter to see its effect; return to live to reset",
+        [
+            "  Arrastra un parámetro para ver su efecto; vuelve a en vivo para restablecer",
+            "  Ziehe einen Parameter, um seine Wirkung zu sehen; zurück zu live setzt zurück",
+            "  パラメーターをドラッグして効果を確認。ライブに戻るとリセット",
+        ],
+    ),
+    ("TIME POSTER", ["PÓSTER DE HORA", "ZEITPOSTER", "時刻ポスター"]),
+    (
+        "  Any instant as a print-ready PNG or SVG, with caption and legend",
+        [
+            "  Cualquier instante como PNG o SVG listo para imprimir, con leyenda",
+            "  Jeder Zeitpunkt als druckfertiges PNG oder SVG, mit Beschriftung und Legende",
+            "  任意の時刻を印刷用 PNG/SVG に。キャプションと凡例付き",
+        ],
+    ),
+    ("DST Soon", ["Cambio de horario pronto", "Zeitumstellung bald", "まもなく夏時間切替"]),
+    ("DST Changed", ["Horario cambiado", "Zeit umgestellt", "夏時間切替済み"]),
+    (
+        "DECODE QUIZ · What time is it? Answer in the sidebar",
+        [
+            "PRUEBA · ¿Qué hora es? Responde en la barra lateral",
+            "QUIZ · Wie spät ist es? Antworte in der Seitenleiste",
+            "解読クイズ · 何時でしょう?サイドバーで回答",
+        ],
+    ),
+    (
+        "{0} It was {1} · {2} for the next question",
+        [
+            "{0} Era {1} · {2} para la siguiente pregunta",
+            "{0} Es war {1} · {2} für die nächste Frage",
+            "{0} 正解は {1} · {2} で次の問題",
+        ],
+    ),
+    ("Timezone", ["Zona horaria", "Zeitzone", "タイムゾーン"]),
+    ("Decode", ["Decodificar", "Entschlüsseln", "解読"]),
+    ("Focus", ["Foco", "Fokus", "フォーカス"]),
+    (
+        "Hold Space: Reveal Time",
+        ["Mantén Espacio: mostrar la hora", "Leertaste halten: Zeit anzeigen", "Space 長押し: 時刻を表示"],
+    ),
+    // World daylight
+    ("World Daylight", ["Luz diurna mundial", "Tageslicht der Welt", "世界の昼と夜"]),
+    ("▸ SELECTED ZONE", ["▸ ZONA SELECCIONADA", "▸ AUSGEWÄHLTE ZONE", "▸ 選択中のゾーン"]),
+    ("Change (Z)", ["Cambiar (Z)", "Wechseln (Z)", "変更 (Z)"]),
+    ("System", ["Sistema", "System", "システム"]),
+    ("▸ SUN", ["▸ SOL", "▸ SONNE", "▸ 太陽"]),
+    ("Daylight", ["Día", "Tageslicht", "昼"]),
+    ("Civil twilight", ["Crepúsculo civil", "Bürgerliche Dämmerung", "常用薄明"]),
+    ("Night", ["Noche", "Nacht", "夜"]),
+    (
+        "Midnight sun: no sunset today",
+        [
+            "Sol de medianoche: hoy no se pone el sol",
+            "Mitternachtssonne: heute kein Sonnenuntergang",
+            "白夜: 今日は日が沈みません",
+        ],
+    ),
+    (
+        "Polar night: no sunrise today",
+        [
+            "Noche polar: hoy no sale el sol",
+            "Polarnacht: heute kein Sonnenaufgang",
+            "極夜: 今日は日が昇りません",
+        ],
+    ),
+    (
+        "Sunrise {0}  ·  Sunset {1}",
+        ["Amanecer {0}  ·  Atardecer {1}", "Aufgang {0}  ·  Untergang {1}", "日の出 {0}  ·  日の入り {1}"],
+    ),
+    (
+        "Day length {0}h {1}m",
+        ["Duración del día {0} h {1} min", "Tageslänge {0} h {1} min", "昼の長さ {0}時間{1}分"],
+    ),
+    ("▸ FAVORITES", ["▸ FAVORITOS", "▸ FAVORITEN", "▸ お気に入り"]),
+    (
+        "Press F to add the selected zone",
+        [
+            "Pulsa F para añadir la zona seleccionada",
+            "Drücke F, um die gewählte Zone hinzuzufügen",
+            "F キーで選択中のゾーンを追加",
+        ],
+    ),
+    ("▸ DISPLAY", ["▸ PANTALLA", "▸ ANZEIGE", "▸ 表示"]),
+    ("Graticule (G)", ["Retícula (G)", "Gradnetz (G)", "経緯線 (G)"]),
+    (
+        "Label every marker (L)",
+        [
+            "Etiquetar todos los marcadores (L)",
+            "Alle Markierungen beschriften (L)",
+            "すべてのマーカーに名前を表示 (L)",
+        ],
+    ),
+    ("[?] Reading the map", ["[?] Cómo leer el mapa", "[?] So liest man die Karte", "[?] 地図の読み方"]),
+    ("Selected {0}", ["Seleccionada {0}", "{0} ausgewählt", "{0} を選択しました"]),
+    ("Sun overhead at {0}", ["Sol en el cénit en {0}", "Sonne im Zenit bei {0}", "太陽の直下点 {0}"]),
+    ("{0} · sun {1}°", ["{0} · sol {1}°", "{0} · Sonne {1}°", "{0} · 太陽 {1}°"]),
+    (
+        "Reading the Daylight Map",
+        ["Cómo leer el mapa de luz diurna", "So liest man die Tageslichtkarte", "昼夜マップの読み方"],
+    ),
+    ("SHADING", ["SOMBREADO", "SCHATTIERUNG", "陰影"]),
+    (
+        "  Bright: sun above the horizon",
+        ["  Claro: sol sobre el horizonte", "  Hell: Sonne über dem Horizont", "  明るい: 太陽が地平線の上"],
+    ),
+    (
+        "  Fading: twilight, down to 18° below",
+        [
+            "  Atenuado: crepúsculo, hasta 18° bajo el horizonte",
+            "  Verblassend: Dämmerung, bis 18° darunter",
+            "  薄れる: 薄明、地平線下 18° まで",
+        ],
+    ),
+    ("TERMINATOR", ["TERMINADOR", "TERMINATOR", "明暗境界線"]),
+    (
+        "  Yellow line: sunrise or sunset right now",
+        [
+            "  Línea amarilla: amanecer o atardecer ahora mismo",
+            "  Gelbe Linie: Sonnenaufgang oder -untergang genau jetzt",
+            "  黄色の線: 今まさに日の出か日の入り",
+        ],
+    ),
+    (
+        "  Sun marker: where it is directly overhead",
+        [
+            "  Marcador del sol: donde está justo en el cénit",
+            "  Sonnenmarke: wo sie genau im Zenit steht",
+            "  太陽マーカー: 真上にある地点",
+        ],
+    ),
+    ("MARKERS", ["MARCADORES", "MARKIERUNGEN", "マーカー"]),
+    (
+        "  Yellow day, orange twilight, blue night",
+        [
+            "  Amarillo día, naranja crepúsculo, azul noche",
+            "  Gelb Tag, Orange Dämmerung, Blau Nacht",
+            "  黄は昼、橙は薄明、青は夜",
+        ],
+    ),
+    (
+        "  Ringed marker: the selected zone",
+        [
+            "  Marcador con anillo: la zona seleccionada",
+            "  Umrandete Markierung: die gewählte Zone",
+            "  輪付きマーカー: 選択中のゾーン",
+        ],
+    ),
+    ("SELECTING", ["SELECCIÓN", "AUSWAHL", "選択"]),
+    (
+        "  Click a marker or anywhere on the map",
+        [
+            "  Haz clic en un marcador o en cualquier punto del mapa",
+            "  Klicke eine Markierung oder eine Stelle auf der Karte an",
+            "  マーカーまたは地図上の任意の場所をクリック",
+        ],
+    ),
+    (
+        "  Open ocean picks the nautical zone",
+        [
+            "  En mar abierto se elige la zona náutica",
+            "  Offenes Meer wählt die nautische Zone",
+            "  外洋では航海用ゾーンを選択",
+        ],
+    ),
+    // Flipboard
+    ("Flipboard", ["Tablero de paletas", "Fallblattanzeige", "フラップ式表示板"]),
+    ("▸ HOME", ["▸ ZONA PRINCIPAL", "▸ HEIMATZONE", "▸ ホーム"]),
+    ("▸ ROWS", ["▸ FILAS", "▸ ZEILEN", "▸ 行"]),
+    (
+        "Press F or star zones in the picker to add rows",
+        [
+            "Pulsa F o marca zonas con estrella en el selector para añadir filas",
+            "Drücke F oder markiere Zonen in der Auswahl, um Zeilen hinzuzufügen",
+            "F キーか選択画面の星印で行を追加します",
+        ],
+    ),
+    (
+        "Make this the home row",
+        ["Convertir en la fila principal", "Zur Heimatzeile machen", "この行をホームにする"],
+    ),
+    ("Remove row", ["Quitar fila", "Zeile entfernen", "行を削除"]),
+    (
+        "The board shows the first {0} rows",
+        [
+            "El tablero muestra las primeras {0} filas",
+            "Die Tafel zeigt die ersten {0} Zeilen",
+            "表示板には最初の {0} 行が表示されます",
+        ],
+    ),
+    ("Seconds (S)", ["Segundos (S)", "Sekunden (S)", "秒 (S)"]),
+    ("Per flap", ["Por paleta", "Pro Blatt", "1枚あたり"]),
+    (
+        "[?] Reading the board",
+        ["[?] Cómo leer el tablero", "[?] So liest man die Tafel", "[?] 表示板の読み方"],
+    ),
+    (
+        "Only the first {0} rows fit the board",
+        [
+            "Solo caben las primeras {0} filas en el tablero",
+            "Nur die ersten {0} Zeilen passen auf die Tafel",
+            "表示板に収まるのは最初の {0} 行だけです",
+        ],
+    ),
+    (
+        "Home is now {0}",
+        ["La zona principal es ahora {0}", "Heimatzone ist jetzt {0}", "ホームを {0} にしました"],
+    ),
+    ("WORLD DEPARTURES", ["SALIDAS MUNDIALES", "WELTABFAHRTEN", "世界の出発案内"]),
+    ("DESTINATION", ["DESTINO", "ZIEL", "行先"]),
+    ("TIME", ["HORA", "ZEIT", "時刻"]),
+    ("REMARKS", ["OBSERVACIONES", "HINWEISE", "備考"]),
+    ("Reading the Board", ["Cómo leer el tablero", "So liest man die Tafel", "案内板の読み方"]),
+    ("ROWS", ["FILAS", "ZEILEN", "行"]),
+    (
+        "  First row (marked): the home zone",
+        [
+            "  Primera fila (marcada): la zona de origen",
+            "  Erste Zeile (markiert): die Heimatzone",
+            "  最初の行(印付き): ホームゾーン",
+        ],
+    ),
+    (
+        "  Below it: your favorite zones",
+        ["  Debajo: tus zonas favoritas", "  Darunter: deine Favoritenzonen", "  その下: お気に入りのゾーン"],
+    ),
+    ("COLUMNS", ["COLUMNAS", "SPALTEN", "列"]),
+    (
+        "  Local time (24-hour) and offset from UTC",
+        [
+            "  Hora local (24 horas) y desfase respecto a UTC",
+            "  Ortszeit (24 Stunden) und Versatz zu UTC",
+            "  現地時刻(24 時間制)と UTC からのオフセット",
+        ],
+    ),
+    (
+        "  +1 DAY / -1 DAY: a different date from home",
+        [
+            "  +1 DAY / -1 DAY: una fecha distinta a la de origen",
+            "  +1 DAY / -1 DAY: ein anderes Datum als zu Hause",
+            "  +1 DAY / -1 DAY: ホームと日付が異なる",
+        ],
+    ),
+    (
+        "  DST: daylight saving in effect",
+        ["  DST: horario de verano en vigor", "  DST: Sommerzeit in Kraft", "  DST: 夏時間が有効"],
+    ),
+    (
+        "  DST SOON: clocks change within 24 hours",
+        [
+            "  DST SOON: los relojes cambian en menos de 24 horas",
+            "  DST SOON: Zeitumstellung innerhalb von 24 Stunden",
+            "  DST SOON: 24 時間以内に時刻が変わる",
+        ],
+    ),
+    ("FLAPS", ["PALETAS", "KLAPPEN", "フラップ"]),
+    (
+        "  Each cell turns through its drum in order",
+        [
+            "  Cada celda gira por su tambor en orden",
+            "  Jede Zelle dreht ihre Trommel der Reihe nach durch",
+            "  各セルはドラムを順番にめくる",
+        ],
+    ),
+    ("Conventional time", ["Hora convencional", "Herkömmliche Zeit", "通常の時刻"]),
+    (
+        "Click the map to pick a zone",
+        [
+            "Haz clic en el mapa para elegir una zona",
+            "Klicke auf die Karte, um eine Zone zu wählen",
+            "地図をクリックしてゾーンを選択",
+        ],
+    ),
+    (
+        "Click a row to make it home",
+        [
+            "Haz clic en una fila para hacerla tu origen",
+            "Klicke eine Zeile an, um sie zur Heimat zu machen",
+            "行をクリックしてホームに設定",
+        ],
+    ),
+    // Decimal time
+    ("Decimal Time", ["Hora decimal", "Dezimalzeit", "十進時間"]),
+    ("▸ ZONE", ["▸ ZONA", "▸ ZONE", "▸ ゾーン"]),
+    ("▸ CONVERT", ["▸ CONVERTIR", "▸ UMRECHNEN", "▸ 変換"]),
+    ("Standard", ["Estándar", "Normal", "標準"]),
+    ("Decimal", ["Decimal", "Dezimal", "十進"]),
+    (
+        "Decimal time is h:mm:ss, up to 9:99:99",
+        [
+            "La hora decimal es h:mm:ss, hasta 9:99:99",
+            "Dezimalzeit ist h:mm:ss, bis 9:99:99",
+            "十進時間は h:mm:ss (最大 9:99:99)",
+        ],
+    ),
+    (
+        "Standard time is hh:mm:ss, 24-hour",
+        [
+            "La hora estándar es hh:mm:ss, en 24 horas",
+            "Normalzeit ist hh:mm:ss im 24-Stunden-Format",
+            "標準時刻は 24 時間制の hh:mm:ss",
+        ],
+    ),
+    ("Now", ["Ahora", "Jetzt", "現在"]),
+    (
+        "The faint hand marks the decimal entry",
+        [
+            "La manecilla tenue marca el valor decimal",
+            "Der blasse Zeiger zeigt die Dezimaleingabe",
+            "薄い針が入力した十進時刻を示します",
+        ],
+    ),
+    (
+        "Press F or star zones in the picker to add them",
+        [
+            "Pulsa F o marca zonas con estrella en el selector para añadirlas",
+            "Drücke F oder markiere Zonen in der Auswahl, um sie hinzuzufügen",
+            "F キーか選択画面の星印で追加します",
+        ],
+    ),
+    ("Conventional time (C)", ["Hora convencional (C)", "Herkömmliche Zeit (C)", "通常の時刻 (C)"]),
+    (
+        "[?] Reading decimal time",
+        ["[?] Cómo leer la hora decimal", "[?] So liest man Dezimalzeit", "[?] 十進時間の読み方"],
+    ),
+    ("{0}  standard", ["{0}  estándar", "{0}  Normalzeit", "{0}  標準時刻"]),
+    ("Reading Decimal Time", ["Cómo leer la hora decimal", "So liest man Dezimalzeit", "十進時間の読み方"]),
+    ("THE DAY", ["EL DÍA", "DER TAG", "一日"]),
+    (
+        "  10 hours of 100 minutes of 100 seconds",
+        [
+            "  10 horas de 100 minutos de 100 segundos",
+            "  10 Stunden zu 100 Minuten zu 100 Sekunden",
+            "  1 日 = 10 時間、1 時間 = 100 分、1 分 = 100 秒",
+        ],
+    ),
+    (
+        "  A decimal second is 0.864 standard seconds",
+        [
+            "  Un segundo decimal son 0,864 segundos estándar",
+            "  Eine Dezimalsekunde sind 0,864 Normalsekunden",
+            "  十進の 1 秒は標準の 0.864 秒",
+        ],
+    ),
+    ("THE DIAL", ["LA ESFERA", "DAS ZIFFERBLATT", "文字盤"]),
+    (
+        "  Short hand: hours, once around a day",
+        [
+            "  Aguja corta: horas, una vuelta al día",
+            "  Kurzer Zeiger: Stunden, eine Runde pro Tag",
+            "  短針: 時、一日で一周",
+        ],
+    ),
+    (
+        "  Long hand: minutes, once around a decimal hour",
+        [
+            "  Aguja larga: minutos, una vuelta por hora decimal",
+            "  Langer Zeiger: Minuten, eine Runde pro Dezimalstunde",
+            "  長針: 分、十進の 1 時間で一周",
+        ],
+    ),
+    (
+        "  Thin hand: seconds, once around 86.4 seconds",
+        [
+            "  Aguja fina: segundos, una vuelta cada 86,4 segundos",
+            "  Dünner Zeiger: Sekunden, eine Runde in 86,4 Sekunden",
+            "  細い針: 秒、86.4 秒で一周",
+        ],
+    ),
+    ("LANDMARKS", ["REFERENCIAS", "ORIENTIERUNGSPUNKTE", "目安"]),
+    (
+        "  5:00:00 is noon, 7:50:00 is 18:00",
+        [
+            "  5:00:00 es mediodía, 7:50:00 son las 18:00",
+            "  5:00:00 ist Mittag, 7:50:00 ist 18:00",
+            "  5:00:00 は正午、7:50:00 は 18:00",
+        ],
+    ),
+    (
+        "  The digits read as a fraction of the day",
+        [
+            "  Las cifras se leen como fracción del día",
+            "  Die Ziffern lesen sich als Bruchteil des Tages",
+            "  数字は一日の割合として読む",
+        ],
+    ),
+    (
+        "  Shown in local wall-clock time",
+        ["  Se muestra en la hora local", "  In lokaler Ortszeit angezeigt", "  現地の時刻で表示"],
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translate_falls_back_to_english() {
+        assert_eq!(Language::En.translate("Zoom in"), "Zoom in");
+        assert_eq!(Language::De.translate("Zoom in"), "Vergrößern");
+        assert_eq!(Language::Ja.translate("Not in the catalog"), "Not in the catalog");
+        assert_eq!(
+            fill(Language::Es.translate("Saved clip to {0}"), &[&"/tmp/a.gif"]),
+            "Clip guardado en /tmp/a.gif"
+        );
+    }
+
+    #[test]
+    fn test_long_date_conventions() {
+        let date = |language: Language| language.long_date(Weekday::Mon, 5, 1, 2026);
+        assert_eq!(date(Language::En), "Mon, January 5, 2026");
+        assert_eq!(date(Language::Es), "lun, 5 de enero de 2026");
+        assert_eq!(date(Language::De), "Mo, 5. Januar 2026");
+        assert_eq!(date(Language::Ja), "2026年1月5日(月)");
+        assert_eq!(Language::Ja.meridiem(Meridiem::PM), "午後");
+    }
+
+    #[test]
+    fn test_catalog_entries_are_unique_and_complete() {
+        for (index, (english, translations)) in CATALOG.iter().enumerate() {
+            assert!(
+                CATALOG[..index].iter().all(|(other, _)| other != english),
+                "duplicate entry {:?}",
+                english
+            );
+            // Placeholders survive translation
+            for placeholder in ["{0}", "{1}"] {
+                let in_english = english.contains(placeholder);
+                assert!(translations
+                    .iter()
+                    .all(|t| !t.is_empty() && t.contains(placeholder) == in_english));
+            }
+        }
+    }
+}
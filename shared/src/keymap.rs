@@ -0,0 +1,463 @@
+//! Keymap module - user-remappable keyboard shortcuts
+//!
+//! Each clock registers its actions with their default chords in a
+//! `CommandRegistry`, as it does for the command palette. A `Keymap` wraps
+//! that registry and lets the user bind any action to another chord. Clocks
+//! keep matching the default chords in their key handlers: a pressed chord
+//! is translated back to the default chord of the action it is bound to, the
+//! same way the palette replays a chosen command. A default chord whose
+//! action was moved elsewhere does nothing, unless another action took it.
+//!
+//! Bindings that differ from the defaults are saved per clock, in
+//! `<clock>_keymap.toml`. Two actions may not share a chord: rebinding onto
+//! a chord in use is refused, and a hand-edited file that does so is
+//! reported as a conflict in the settings.
+
+use std::collections::BTreeMap;
+
+use nannou_egui::egui;
+use serde::{Deserialize, Serialize};
+use winit::event::{ModifiersState, VirtualKeyCode};
+
+use crate::command_palette::{key_name, CommandRegistry, KeyChord};
+use crate::config::{load_config, save_config, ConfigError, VersionedConfig};
+use crate::i18n::{tr, tr_args};
+
+/// Keys an action can be bound to; others can't be saved by name
+pub(crate) const BINDABLE_KEYS: &[VirtualKeyCode] = {
+    use VirtualKeyCode as K;
+    &[
+        K::A, K::B, K::C, K::D, K::E, K::F, K::G, K::H, K::I, K::J, K::K, K::L, K::M,
+        K::N, K::O, K::P, K::Q, K::R, K::S, K::T, K::U, K::V, K::W, K::X, K::Y, K::Z,
+        K::Key0, K::Key1, K::Key2, K::Key3, K::Key4,
+        K::Key5, K::Key6, K::Key7, K::Key8, K::Key9,
+        K::F1, K::F2, K::F3, K::F4, K::F5, K::F6,
+        K::F7, K::F8, K::F9, K::F10, K::F11, K::F12,
+        K::Left, K::Right, K::Up, K::Down,
+        K::Home, K::End, K::PageUp, K::PageDown, K::Insert, K::Delete,
+        K::Return, K::Back, K::Space, K::Tab,
+        K::Slash, K::Backslash, K::Equals, K::Plus, K::Minus, K::Comma, K::Period,
+        K::LBracket, K::RBracket, K::Apostrophe, K::Semicolon, K::Grave,
+    ]
+};
+
+/// Keys pressed on their own while holding a chord; they never end a capture
+const MODIFIER_KEYS: &[VirtualKeyCode] = {
+    use VirtualKeyCode as K;
+    &[
+        K::LShift, K::RShift, K::LControl, K::RControl, K::LAlt, K::RAlt, K::LWin, K::RWin,
+    ]
+};
+
+/// Persisted bindings that differ from the defaults, by action name
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+struct KeymapConfig {
+    /// Action name to chord label, e.g. "Toggle help" = "Shift+H"
+    bindings: BTreeMap<String, String>,
+}
+
+impl VersionedConfig for KeymapConfig {
+    const VERSION: u32 = 1;
+}
+
+/// Config file of `clock_name`'s keymap
+fn keymap_config_name(clock_name: &str) -> String {
+    format!("{}_keymap", clock_name)
+}
+
+impl KeyChord {
+    /// The chord for a key press, with Cmd counted as Ctrl and other
+    /// modifiers dropped
+    pub fn pressed(key: VirtualKeyCode, mods: ModifiersState) -> Self {
+        let mut kept = mods & (ModifiersState::SHIFT | ModifiersState::CTRL | ModifiersState::ALT);
+        if mods.logo() {
+            kept |= ModifiersState::CTRL;
+        }
+        Self { key, mods: kept }
+    }
+
+    /// Read a chord back from its label, e.g. "Ctrl+Shift+←"
+    pub fn parse(label: &str) -> Option<Self> {
+        let mut mods = ModifiersState::empty();
+        let mut rest = label.trim();
+        loop {
+            // The whole rest first, so "Ctrl++" ends on the + key
+            if let Some(key) = parse_key(rest) {
+                return Some(Self { key, mods });
+            }
+            let (prefix, modifier) = [
+                ("Ctrl+", ModifiersState::CTRL),
+                ("Alt+", ModifiersState::ALT),
+                ("Shift+", ModifiersState::SHIFT),
+            ]
+            .into_iter()
+            .find(|(prefix, _)| rest.starts_with(prefix))?;
+            mods |= modifier;
+            rest = &rest[prefix.len()..];
+        }
+    }
+}
+
+/// Look up a bare key by its label
+fn parse_key(label: &str) -> Option<VirtualKeyCode> {
+    BINDABLE_KEYS.iter().copied().find(|&key| key_name(key) == label)
+}
+
+/// A change asked for in the keyboard shortcut settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeymapEdit {
+    /// Bind the next chord pressed to this action
+    Capture(&'static str),
+    /// Stop waiting for a chord
+    CancelCapture,
+    /// Go back to this action's default chord
+    Reset(&'static str),
+    /// Go back to every default chord
+    ResetAll,
+}
+
+/// A clock's actions and the chords the user has bound them to
+pub struct Keymap {
+    /// Config file the bindings are saved to
+    config_name: String,
+    registry: CommandRegistry,
+    /// Action waiting for its new chord
+    capturing: Option<&'static str>,
+    /// Why the last rebinding was refused
+    notice: Option<String>,
+}
+
+impl Keymap {
+    /// Wrap `registry` with the bindings saved for `clock_name`
+    pub fn load(clock_name: &str, registry: CommandRegistry) -> Self {
+        let config_name = keymap_config_name(clock_name);
+        let config = match load_config::<KeymapConfig>(&config_name) {
+            Ok(config) => config.unwrap_or_default(),
+            Err(e) => {
+                eprintln!("Failed to load keyboard shortcuts: {}", e);
+                KeymapConfig::default()
+            }
+        };
+        let mut keymap = Self::new(config_name, registry);
+        keymap.apply_config(&config);
+        keymap
+    }
+
+    fn new(config_name: String, registry: CommandRegistry) -> Self {
+        Self {
+            config_name,
+            registry,
+            capturing: None,
+            notice: None,
+        }
+    }
+
+    /// Take saved bindings, skipping unknown actions and unreadable chords
+    fn apply_config(&mut self, config: &KeymapConfig) {
+        for (name, label) in &config.bindings {
+            match KeyChord::parse(label) {
+                Some(chord) => {
+                    if !self.registry.bind(name, chord) {
+                        eprintln!("Ignoring shortcut for unknown action {:?}", name);
+                    }
+                }
+                None => eprintln!("Ignoring unreadable shortcut {:?} for {:?}", label, name),
+            }
+        }
+    }
+
+    fn config(&self) -> KeymapConfig {
+        let bindings = self
+            .registry
+            .commands()
+            .iter()
+            .filter(|command| command.binding != command.chord)
+            .map(|command| (command.name.to_string(), command.binding.label()))
+            .collect();
+        KeymapConfig { bindings }
+    }
+
+    pub fn save(&self) -> Result<(), ConfigError> {
+        save_config(&self.config_name, &self.config())
+    }
+
+    fn save_or_report(&self) {
+        if let Err(e) = self.save() {
+            eprintln!("Failed to save keyboard shortcuts: {}", e);
+        }
+    }
+
+    /// The registered actions, with their current bindings
+    pub fn registry(&self) -> &CommandRegistry {
+        &self.registry
+    }
+
+    /// The action waiting for its new chord, if any
+    pub fn capturing(&self) -> Option<&'static str> {
+        self.capturing
+    }
+
+    pub fn notice(&self) -> Option<&str> {
+        self.notice.as_deref()
+    }
+
+    /// Apply a change from the settings, saving any new bindings
+    pub fn apply(&mut self, edit: KeymapEdit) {
+        self.notice = None;
+        match edit {
+            KeymapEdit::Capture(name) => self.capturing = Some(name),
+            KeymapEdit::CancelCapture => self.capturing = None,
+            KeymapEdit::Reset(name) => {
+                self.capturing = None;
+                let default = self.registry.find(name).map(|command| command.chord);
+                if let Some(default) = default {
+                    if self.rebind(name, default).is_ok() {
+                        self.save_or_report();
+                    }
+                }
+            }
+            KeymapEdit::ResetAll => {
+                self.capturing = None;
+                self.registry.reset_bindings();
+                self.save_or_report();
+            }
+        }
+    }
+
+    /// Bind `name` to `chord`, unless another action already uses it
+    pub fn rebind(&mut self, name: &'static str, chord: KeyChord) -> Result<(), String> {
+        let chord = KeyChord::pressed(chord.key, chord.mods);
+        let result = if KeyChord::is_palette_toggle(chord.key, chord.mods) {
+            Err(tr_args("{0} opens the command palette", &[&chord.label()]))
+        } else if let Some(other) = self
+            .registry
+            .commands()
+            .iter()
+            .find(|command| command.binding == chord && command.name != name)
+        {
+            Err(tr_args("{0} is already used by {1}", &[&chord.label(), &tr(other.name)]))
+        } else {
+            self.registry.bind(name, chord);
+            Ok(())
+        };
+        if let Err(notice) = &result {
+            self.notice = Some(notice.clone());
+        }
+        result
+    }
+
+    /// Offer a key press to a running capture
+    ///
+    /// Returns true when the press was taken: it became the action's new
+    /// binding (saved), cancelled the capture (Esc), or was refused. Presses
+    /// of modifier keys alone are taken while waiting for the rest of the
+    /// chord.
+    pub fn capture(&mut self, key: VirtualKeyCode, mods: ModifiersState) -> bool {
+        let Some(name) = self.capturing else {
+            return false;
+        };
+        if MODIFIER_KEYS.contains(&key) {
+            return true;
+        }
+        if key == VirtualKeyCode::Escape && mods.is_empty() {
+            self.capturing = None;
+            return true;
+        }
+        if !BINDABLE_KEYS.contains(&key) {
+            self.notice = Some(tr_args("{0} can't be used as a shortcut", &[&key_name(key)]));
+            return true;
+        }
+        if self.rebind(name, KeyChord { key, mods }).is_ok() {
+            self.capturing = None;
+            self.notice = None;
+            self.save_or_report();
+        }
+        true
+    }
+
+    /// The chord the clock's key handler should see for a key press
+    ///
+    /// A bound chord becomes its action's default chord. A default chord
+    /// whose action now lives elsewhere gives `None`. Anything else passes
+    /// through untouched.
+    pub fn translate(&self, key: VirtualKeyCode, mods: ModifiersState) -> Option<KeyChord> {
+        let pressed = KeyChord::pressed(key, mods);
+        let commands = self.registry.commands();
+        if let Some(command) = commands.iter().find(|command| command.binding == pressed) {
+            Some(command.chord)
+        } else if commands.iter().any(|command| command.chord == pressed) {
+            None
+        } else {
+            Some(KeyChord { key, mods })
+        }
+    }
+
+    /// Pairs of actions bound to the same chord
+    pub fn conflicts(&self) -> Vec<(&'static str, &'static str)> {
+        let commands = self.registry.commands();
+        let mut conflicts = Vec::new();
+        for (index, first) in commands.iter().enumerate() {
+            for second in &commands[index + 1..] {
+                if first.binding == second.binding {
+                    conflicts.push((first.name, second.name));
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+/// Keyboard shortcut settings: every action with its chord, which can be
+/// clicked to rebind it
+///
+/// Returns the change asked for, to hand to `Keymap::apply`.
+pub fn keymap_settings(ui: &mut egui::Ui, keymap: &Keymap) -> Option<KeymapEdit> {
+    let mut edit = None;
+    egui::CollapsingHeader::new(tr("Keyboard shortcuts"))
+        .id_source("keyboard_shortcuts")
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(tr("Click a shortcut, then press the new keys; Esc cancels"))
+                    .size(10.0),
+            );
+            egui::Grid::new("keymap_grid")
+                .num_columns(3)
+                .striped(true)
+                .show(ui, |ui| {
+                    for command in keymap.registry().commands() {
+                        ui.label(tr(command.name));
+                        if keymap.capturing() == Some(command.name) {
+                            if ui.button(tr("Press keys…")).clicked() {
+                                edit = Some(KeymapEdit::CancelCapture);
+                            }
+                        } else {
+                            let mut label = egui::RichText::new(command.binding.label());
+                            if command.binding != command.chord {
+                                label = label.strong();
+                            }
+                            if ui.button(label).clicked() {
+                                edit = Some(KeymapEdit::Capture(command.name));
+                            }
+                        }
+                        let changed = command.binding != command.chord;
+                        if ui
+                            .add_enabled(changed, egui::Button::new("↺").small())
+                            .on_hover_text(tr_args("Default: {0}", &[&command.chord.label()]))
+                            .clicked()
+                        {
+                            edit = Some(KeymapEdit::Reset(command.name));
+                        }
+                        ui.end_row();
+                    }
+                });
+
+            let warning = ui.visuals().warn_fg_color;
+            for (first, second) in keymap.conflicts() {
+                let text = tr_args("{0} and {1} share a shortcut", &[&tr(first), &tr(second)]);
+                ui.colored_label(warning, text);
+            }
+            if let Some(notice) = keymap.notice() {
+                ui.colored_label(warning, notice);
+            }
+
+            let customized = keymap
+                .registry()
+                .commands()
+                .iter()
+                .any(|command| command.binding != command.chord);
+            if ui.add_enabled(customized, egui::Button::new(tr("Reset all"))).clicked() {
+                edit = Some(KeymapEdit::ResetAll);
+            }
+        });
+    edit
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use VirtualKeyCode as K;
+
+    fn keymap() -> Keymap {
+        let registry = CommandRegistry::new()
+            .register("Open timezone picker", KeyChord::new(K::T))
+            .register("Toggle help", KeyChord::shift(K::Slash))
+            .register("Export ledger", KeyChord::ctrl(K::E));
+        Keymap::new("test_keymap".to_string(), registry)
+    }
+
+    #[test]
+    fn test_chord_labels_round_trip() {
+        for chord in [
+            KeyChord::new(K::T),
+            KeyChord::shift(K::Slash),
+            KeyChord::ctrl_shift(K::Left),
+            KeyChord::ctrl(K::Plus),
+            KeyChord::new(K::Space),
+            KeyChord::new(K::F5),
+        ] {
+            assert_eq!(KeyChord::parse(&chord.label()), Some(chord));
+        }
+        assert_eq!(KeyChord::parse("Shift+"), None);
+        assert_eq!(KeyChord::parse("Hyper+T"), None);
+        assert_eq!(parse_key("Numpad1"), None);
+        // Cmd counts as Ctrl, and unrelated modifiers are dropped
+        let cmd_e = KeyChord::pressed(K::E, ModifiersState::LOGO);
+        assert_eq!(cmd_e, KeyChord::ctrl(K::E));
+    }
+
+    #[test]
+    fn test_rebound_chords_replay_the_default() {
+        let mut keymap = keymap();
+        keymap.rebind("Open timezone picker", KeyChord::new(K::Z)).unwrap();
+
+        let none = ModifiersState::empty();
+        assert_eq!(keymap.translate(K::Z, none), Some(KeyChord::new(K::T)));
+        // The old default now does nothing; unregistered keys pass through
+        assert_eq!(keymap.translate(K::T, none), None);
+        assert_eq!(keymap.translate(K::Escape, none), Some(KeyChord::new(K::Escape)));
+        assert_eq!(keymap.translate(K::E, ModifiersState::LOGO), Some(KeyChord::ctrl(K::E)));
+
+        // Another action may take the freed default
+        keymap.rebind("Toggle help", KeyChord::new(K::T)).unwrap();
+        assert_eq!(keymap.translate(K::T, none), Some(KeyChord::shift(K::Slash)));
+    }
+
+    #[test]
+    fn test_conflicting_rebinds_are_refused() {
+        let mut keymap = keymap();
+        assert!(keymap.rebind("Toggle help", KeyChord::ctrl(K::E)).is_err());
+        assert!(keymap.notice().unwrap().contains("Export ledger"));
+        assert!(keymap.rebind("Toggle help", KeyChord::ctrl(K::K)).is_err());
+        assert!(keymap.conflicts().is_empty());
+
+        // Rebinding an action onto its own chord is fine
+        assert!(keymap.rebind("Export ledger", KeyChord::ctrl(K::E)).is_ok());
+
+        // Hand-edited files can still collide; those are reported
+        let mut config = KeymapConfig::default();
+        config.bindings.insert("Toggle help".to_string(), "T".to_string());
+        config.bindings.insert("Unknown action".to_string(), "Q".to_string());
+        keymap.apply_config(&config);
+        assert_eq!(keymap.conflicts(), [("Open timezone picker", "Toggle help")]);
+    }
+
+    #[test]
+    fn test_capture_and_saved_config() {
+        let mut keymap = keymap();
+        assert!(!keymap.capture(K::Q, ModifiersState::empty()));
+
+        keymap.capturing = Some("Toggle help");
+        assert!(keymap.capture(K::LShift, ModifiersState::SHIFT));
+        assert!(keymap.capture(K::Escape, ModifiersState::empty()));
+        assert_eq!(keymap.capturing(), None);
+
+        keymap.rebind("Toggle help", KeyChord::shift(K::H)).unwrap();
+        let config = keymap.config();
+        assert_eq!(config.bindings.len(), 1);
+        assert_eq!(config.bindings["Toggle help"], "Shift+H");
+
+        keymap.registry.reset_bindings();
+        assert!(keymap.config().bindings.is_empty());
+    }
+}
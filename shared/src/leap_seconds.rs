@@ -0,0 +1,254 @@
+//! Leap seconds - the bundled IERS table and the TAI and GPS time scales
+//!
+//! UTC has had 27 leap seconds inserted since 1972, each as an extra
+//! 23:59:60 at the end of June or December. TAI counts straight through
+//! them, so TAI−UTC steps up by one after each; GPS time runs a fixed 19
+//! seconds behind TAI. The IERS announces a new leap second in Bulletin C
+//! about six months ahead; it belongs at the end of `INSERTIONS`.
+//!
+//! chrono represents an instant inside a leap second as 23:59:59 with a
+//! nanosecond count of a second or more, and formats it as "23:59:60".
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Timelike, Utc};
+
+/// TAI−UTC when the table starts, 1972-01-01
+const INITIAL_TAI_MINUS_UTC: i32 = 10;
+
+/// TAI − GPS, fixed since the GPS epoch in 1980
+pub const TAI_MINUS_GPS: i32 = 19;
+
+/// Months that ended with an inserted leap second, as (year, month)
+const INSERTIONS: [(i32, u32); 27] = [
+    (1972, 6),
+    (1972, 12),
+    (1973, 12),
+    (1974, 12),
+    (1975, 12),
+    (1976, 12),
+    (1977, 12),
+    (1978, 12),
+    (1979, 12),
+    (1981, 6),
+    (1982, 6),
+    (1983, 6),
+    (1985, 6),
+    (1987, 12),
+    (1989, 12),
+    (1990, 12),
+    (1992, 6),
+    (1993, 6),
+    (1994, 6),
+    (1995, 12),
+    (1997, 6),
+    (1998, 12),
+    (2005, 12),
+    (2008, 12),
+    (2012, 6),
+    (2015, 6),
+    (2016, 12),
+];
+
+/// Smears spread the leap second over the 24 hours around it, noon to noon
+const SMEAR_HALF_WINDOW_HOURS: i64 = 12;
+
+/// Detection window for upcoming and recent leap seconds, matching DST warnings
+const DETECTION_WINDOW_HOURS: i64 = 24;
+
+/// One inserted leap second
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeapSecond {
+    /// Start of the inserted second, 23:59:60 UTC
+    pub instant: DateTime<Utc>,
+    /// TAI−UTC once the leap second has passed
+    pub tai_minus_utc: i32,
+}
+
+impl LeapSecond {
+    /// Midnight UTC at the end of the leap second
+    pub fn ends(&self) -> DateTime<Utc> {
+        self.instant + Duration::seconds(1)
+    }
+}
+
+/// Leap second status relative to now
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeapSecondStatus {
+    /// No leap second within the detection window
+    None,
+    /// A leap second is inserted within 24 hours
+    Upcoming { instant: DateTime<Utc> },
+    /// Now is the leap second itself, 23:59:60 UTC
+    InProgress,
+    /// A leap second was inserted within the last 24 hours
+    JustOccurred { instant: DateTime<Utc> },
+}
+
+/// Every leap second in the bundled table, oldest first
+pub fn leap_seconds() -> impl Iterator<Item = LeapSecond> {
+    INSERTIONS.iter().enumerate().map(|(index, &(year, month))| {
+        let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+        let last_day = NaiveDate::from_ymd_opt(next_year, next_month, 1)
+            .and_then(|first| first.pred_opt())
+            .expect("table months are valid");
+        let instant = last_day
+            .and_hms_nano_opt(23, 59, 59, 1_000_000_000)
+            .expect("23:59:60 is a valid leap time")
+            .and_utc();
+        LeapSecond {
+            instant,
+            tai_minus_utc: INITIAL_TAI_MINUS_UTC + index as i32 + 1,
+        }
+    })
+}
+
+/// Whether `instant` falls inside a leap second
+pub fn is_leap_second(instant: DateTime<Utc>) -> bool {
+    instant.nanosecond() >= 1_000_000_000
+}
+
+/// TAI−UTC in seconds at `instant`
+///
+/// Steps up at the end of each leap second; before 1972 the table's
+/// starting value is used.
+pub fn tai_minus_utc(instant: DateTime<Utc>) -> i32 {
+    leap_seconds()
+        .take_while(|leap| leap.ends() <= instant)
+        .last()
+        .map_or(INITIAL_TAI_MINUS_UTC, |leap| leap.tai_minus_utc)
+}
+
+/// TAI reading at `instant`
+pub fn tai_time(instant: DateTime<Utc>) -> NaiveDateTime {
+    // chrono counts a leap second as a repeat of 23:59:59; TAI doesn't
+    let extra = i64::from(is_leap_second(instant));
+    (instant + Duration::seconds(i64::from(tai_minus_utc(instant)) + extra)).naive_utc()
+}
+
+/// GPS time reading at `instant`
+pub fn gps_time(instant: DateTime<Utc>) -> NaiveDateTime {
+    tai_time(instant) - Duration::seconds(i64::from(TAI_MINUS_GPS))
+}
+
+/// Leap seconds inserted after `from`, up to and including `to`
+pub fn leap_seconds_between(
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> impl Iterator<Item = LeapSecond> {
+    leap_seconds().filter(move |leap| leap.instant > from && leap.instant <= to)
+}
+
+/// Whether a leap second is near `instant`, in the manner of DST warnings
+pub fn leap_second_status(instant: DateTime<Utc>) -> LeapSecondStatus {
+    if is_leap_second(instant) {
+        return LeapSecondStatus::InProgress;
+    }
+    let window = Duration::hours(DETECTION_WINDOW_HOURS);
+    for leap in leap_seconds() {
+        if leap.instant > instant && leap.instant - instant <= window {
+            return LeapSecondStatus::Upcoming { instant: leap.instant };
+        }
+        if leap.ends() <= instant && instant - leap.ends() <= window {
+            return LeapSecondStatus::JustOccurred { instant: leap.instant };
+        }
+    }
+    LeapSecondStatus::None
+}
+
+/// The reading of a clock that smears leap seconds, at `instant`
+///
+/// Over the 24 hours from noon to noon around a leap second the smeared
+/// clock runs slow by one part in 86 401, absorbing the extra second
+/// instead of showing 23:59:60. It is half a second behind UTC when the
+/// leap second starts and agrees with UTC again at the window's edges.
+pub fn smeared_time(instant: DateTime<Utc>) -> DateTime<Utc> {
+    let half_window = Duration::hours(SMEAR_HALF_WINDOW_HOURS);
+    let Some(leap) = leap_seconds()
+        .find(|leap| instant >= leap.ends() - half_window && instant < leap.ends() + half_window)
+    else {
+        return instant;
+    };
+
+    // Seconds actually elapsed since the window opened, counting the leap
+    let start = leap.ends() - half_window;
+    let elapsed = if is_leap_second(instant) {
+        let into_leap = instant.nanosecond() - 1_000_000_000;
+        (leap.ends() - start).num_seconds() as f64 + into_leap as f64 / 1e9
+    } else {
+        let posix = (instant - start).num_nanoseconds().unwrap_or(0) as f64 / 1e9;
+        if instant >= leap.ends() {
+            posix + 1.0
+        } else {
+            posix
+        }
+    };
+    let window_secs = (2 * half_window.num_seconds()) as f64;
+    let smeared = elapsed * window_secs / (window_secs + 1.0);
+    start + Duration::nanoseconds((smeared * 1e9).round() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn leap_2016() -> DateTime<Utc> {
+        NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+            .unwrap()
+            .and_utc()
+    }
+
+    #[test]
+    fn test_table_and_offsets() {
+        let last = leap_seconds().last().unwrap();
+        assert_eq!(last.tai_minus_utc, 37);
+        assert_eq!(last.instant.format("%Y-%m-%d %H:%M:%S").to_string(), "2016-12-31 23:59:60");
+        assert_eq!(last.ends(), Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap());
+
+        let before = Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 59).unwrap();
+        assert_eq!(tai_minus_utc(before), 36);
+        assert_eq!(tai_minus_utc(leap_2016()), 36);
+        assert_eq!(tai_minus_utc(last.ends()), 37);
+        assert_eq!(tai_minus_utc(Utc.with_ymd_and_hms(1960, 1, 1, 0, 0, 0).unwrap()), 10);
+    }
+
+    #[test]
+    fn test_tai_and_gps_count_through_the_leap() {
+        let before = Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 59).unwrap();
+        let after = Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
+        let format = |t: NaiveDateTime| t.format("%H:%M:%S%.3f").to_string();
+        assert_eq!(format(tai_time(before)), "00:00:35.000");
+        assert_eq!(format(tai_time(leap_2016())), "00:00:36.500");
+        assert_eq!(format(tai_time(after)), "00:00:37.000");
+        assert_eq!(format(gps_time(after)), "00:00:18.000");
+
+        assert_eq!(leap_seconds_between(before, after).count(), 1);
+        assert_eq!(leap_seconds_between(after, after + Duration::days(365)).count(), 0);
+    }
+
+    #[test]
+    fn test_status_and_smear() {
+        let leap = leap_seconds().last().unwrap();
+        assert_eq!(leap_second_status(leap_2016()), LeapSecondStatus::InProgress);
+        assert_eq!(
+            leap_second_status(leap.instant - Duration::hours(3)),
+            LeapSecondStatus::Upcoming { instant: leap.instant }
+        );
+        assert_eq!(
+            leap_second_status(leap.ends() + Duration::hours(3)),
+            LeapSecondStatus::JustOccurred { instant: leap.instant }
+        );
+        assert_eq!(
+            leap_second_status(Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap()),
+            LeapSecondStatus::None
+        );
+
+        // Half a second behind as the leap second starts, caught up a day later
+        let smeared = smeared_time(leap.instant);
+        assert_eq!(smeared.format("%H:%M:%S%.3f").to_string(), "23:59:59.500");
+        assert!(!is_leap_second(smeared));
+        let edge = leap.ends() + Duration::hours(12);
+        assert_eq!(smeared_time(edge), edge);
+    }
+}
@@ -1,6 +1,60 @@
+pub mod accessibility;
+pub mod cities;
+pub mod cli;
+pub mod clock_app;
+pub mod command_palette;
 pub mod config;
+pub mod config_saver;
+pub mod decimal_time;
+pub mod dst_history;
+pub mod favorites;
+pub mod frame_pacing;
+pub mod i18n;
+pub mod keymap;
+pub mod leap_seconds;
+pub mod link;
+pub mod motion;
+pub mod quiet_hours;
+pub mod recording;
+pub mod reveal_hotkey;
+pub mod safe_palettes;
+pub mod solar;
+pub mod system_zone;
+pub mod theme;
+pub mod ticker;
 pub mod time_engine;
+pub mod tz_search;
+pub mod week_date;
+pub mod window_geometry;
+pub mod window_title;
 
+pub use accessibility::*;
+pub use cities::*;
+pub use cli::*;
+pub use clock_app::*;
+pub use command_palette::*;
 pub use config::*;
+pub use config_saver::*;
+pub use decimal_time::*;
+pub use dst_history::*;
+pub use favorites::*;
+pub use frame_pacing::*;
+pub use i18n::*;
+pub use keymap::*;
+pub use leap_seconds::*;
+pub use link::*;
+pub use motion::*;
+pub use quiet_hours::*;
+pub use recording::*;
+pub use reveal_hotkey::*;
+pub use safe_palettes::*;
+pub use solar::*;
+pub use system_zone::*;
+pub use theme::*;
+pub use ticker::*;
 pub use time_engine::*;
+pub use tz_search::*;
+pub use week_date::*;
+pub use window_geometry::*;
+pub use window_title::*;
 
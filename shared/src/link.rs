@@ -0,0 +1,239 @@
+//! Link module - a tiny local bus for pointing other clocks at an instant
+//!
+//! Each clock that can show arbitrary instants listens on its own Unix
+//! datagram socket in a shared bus directory. A clock that wants others to
+//! look at a moment (the audit ledger, when an entry is clicked) sends one
+//! line to every socket it finds there: `inspect <RFC 3339 instant>`.
+//! Sockets left behind by clocks that exited are removed as they're found.
+//!
+//! The standard library has no named pipes, so on non-Unix platforms the bus
+//! is a no-op: listeners never hear anything and broadcasts reach no one.
+
+use std::io;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use directories::ProjectDirs;
+
+/// A message sent between clocks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkMessage {
+    /// Show this instant
+    Inspect(DateTime<Utc>),
+}
+
+impl LinkMessage {
+    /// The line sent over the bus
+    pub fn encode(&self) -> String {
+        match self {
+            LinkMessage::Inspect(instant) => format!("inspect {}", instant.to_rfc3339()),
+        }
+    }
+
+    /// Parse a received line; unknown or malformed messages are `None`
+    pub fn decode(text: &str) -> Option<Self> {
+        let (verb, argument) = text.trim().split_once(' ')?;
+        match verb {
+            "inspect" => DateTime::parse_from_rfc3339(argument.trim())
+                .ok()
+                .map(|instant| LinkMessage::Inspect(instant.with_timezone(&Utc))),
+            _ => None,
+        }
+    }
+}
+
+/// Directory holding every listener's socket
+///
+/// Prefers the per-session runtime directory, where it exists, so sockets
+/// don't outlive the login.
+pub fn link_dir() -> Option<PathBuf> {
+    ProjectDirs::from("com", "clock-series", "clocks").map(|dirs| {
+        dirs.runtime_dir()
+            .unwrap_or_else(|| dirs.cache_dir())
+            .join("link")
+    })
+}
+
+/// Send `message` to every listening clock; returns how many it reached
+pub fn broadcast_link(message: &LinkMessage) -> io::Result<usize> {
+    match link_dir() {
+        Some(dir) => bus::broadcast(&dir, message),
+        None => Ok(0),
+    }
+}
+
+/// Receives link messages sent to this clock
+///
+/// The socket is removed when the listener is dropped.
+pub struct LinkListener {
+    socket: Option<bus::Socket>,
+}
+
+impl LinkListener {
+    /// Listen as `clock_name`; on failure the listener just stays silent
+    pub fn listen(clock_name: &str) -> Self {
+        let socket = link_dir().and_then(|dir| match bus::Socket::bind(&dir, clock_name) {
+            Ok(socket) => Some(socket),
+            Err(e) => {
+                eprintln!("Failed to listen for linked clocks: {}", e);
+                None
+            }
+        });
+        Self { socket }
+    }
+
+    /// The latest message waiting, dropping any older ones
+    pub fn poll(&self) -> Option<LinkMessage> {
+        let socket = self.socket.as_ref()?;
+        std::iter::from_fn(|| socket.recv())
+            .filter_map(|text| LinkMessage::decode(&text))
+            .last()
+    }
+}
+
+#[cfg(unix)]
+mod bus {
+    use std::fs;
+    use std::io::{self, ErrorKind};
+    use std::os::unix::net::UnixDatagram;
+    use std::path::{Path, PathBuf};
+
+    use super::LinkMessage;
+
+    /// Longest message accepted
+    const MAX_MESSAGE: usize = 256;
+
+    /// A bound listener socket, unlinked on drop
+    pub struct Socket {
+        socket: UnixDatagram,
+        path: PathBuf,
+    }
+
+    impl Socket {
+        pub fn bind(dir: &Path, clock_name: &str) -> io::Result<Self> {
+            fs::create_dir_all(dir)?;
+            let path = dir.join(format!("{}-{}.sock", clock_name, std::process::id()));
+            // A socket left by an earlier process with the same id
+            let _ = fs::remove_file(&path);
+            let socket = UnixDatagram::bind(&path)?;
+            socket.set_nonblocking(true)?;
+            Ok(Self { socket, path })
+        }
+
+        /// The next waiting message, if any
+        pub fn recv(&self) -> Option<String> {
+            let mut buffer = [0u8; MAX_MESSAGE];
+            let len = self.socket.recv(&mut buffer).ok()?;
+            Some(String::from_utf8_lossy(&buffer[..len]).into_owned())
+        }
+    }
+
+    impl Drop for Socket {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+
+    pub fn broadcast(dir: &Path, message: &LinkMessage) -> io::Result<usize> {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            // Nobody has listened yet
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e),
+        };
+        let sender = UnixDatagram::unbound()?;
+        let text = message.encode();
+        let mut reached = 0;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().is_none_or(|ext| ext != "sock") {
+                continue;
+            }
+            match sender.send_to(text.as_bytes(), &path) {
+                Ok(_) => reached += 1,
+                // Nobody bound to it any more
+                Err(e) if e.kind() == ErrorKind::ConnectionRefused => {
+                    let _ = fs::remove_file(&path);
+                }
+                Err(e) => eprintln!("Failed to reach {}: {}", path.display(), e),
+            }
+        }
+        Ok(reached)
+    }
+}
+
+#[cfg(not(unix))]
+mod bus {
+    use std::io;
+    use std::path::Path;
+
+    use super::LinkMessage;
+
+    /// Stand-in listener on platforms without Unix sockets
+    pub struct Socket;
+
+    impl Socket {
+        pub fn bind(_dir: &Path, _clock_name: &str) -> io::Result<Self> {
+            Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "linked clocks need Unix sockets",
+            ))
+        }
+
+        pub fn recv(&self) -> Option<String> {
+            None
+        }
+    }
+
+    pub fn broadcast(_dir: &Path, _message: &LinkMessage) -> io::Result<usize> {
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn instant() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 9, 7, 30, 15).unwrap()
+    }
+
+    #[test]
+    fn test_message_round_trip() {
+        let message = LinkMessage::Inspect(instant());
+        assert_eq!(message.encode(), "inspect 2025-03-09T07:30:15+00:00");
+        assert_eq!(LinkMessage::decode(&message.encode()), Some(message));
+        // Offsets are normalized to UTC
+        assert_eq!(
+            LinkMessage::decode("inspect 2025-03-09T02:30:15-05:00"),
+            Some(message)
+        );
+        assert_eq!(LinkMessage::decode("inspect yesterday"), None);
+        assert_eq!(LinkMessage::decode("jump 2025-03-09T07:30:15Z"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_broadcast_reaches_listeners() {
+        let dir = std::env::temp_dir().join(format!("clock-link-test-{}", std::process::id()));
+        let listener = LinkListener {
+            socket: Some(bus::Socket::bind(&dir, "ribbon").unwrap()),
+        };
+        // A socket file nobody is bound to any more
+        let stale_path = dir.join("stale-1.sock");
+        drop(std::os::unix::net::UnixDatagram::bind(&stale_path).unwrap());
+
+        let earlier = LinkMessage::Inspect(instant() - chrono::Duration::hours(1));
+        let latest = LinkMessage::Inspect(instant());
+        assert_eq!(bus::broadcast(&dir, &earlier).unwrap(), 1);
+        assert_eq!(bus::broadcast(&dir, &latest).unwrap(), 1);
+        assert!(!stale_path.exists());
+
+        assert_eq!(listener.poll(), Some(latest));
+        assert_eq!(listener.poll(), None);
+
+        drop(listener);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,317 @@
+//! Motion module - following the OS "reduce motion" accessibility setting
+//!
+//! Each clock's reduced-motion setting is a three-way choice: Auto follows
+//! the operating system's preference, On and Off override it. The system
+//! preference is read once at startup and then every few seconds on a
+//! background thread, so turning it on in system settings calms every open
+//! clock without touching them.
+//!
+//! The preference is read from GNOME's `enable-animations` key on Linux and
+//! the BSDs, the Accessibility "Reduce motion" default on macOS, and the
+//! "Animate windows" metric on Windows. Where none of these can be read the
+//! system is taken not to ask for reduced motion.
+
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use nannou_egui::egui;
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::{tr, tr_args};
+
+/// How often the watcher thread reads the system preference
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A clock's reduced-motion setting
+///
+/// Configs written before the setting had an Auto choice stored a plain
+/// bool; each clock's config migration converts it with
+/// [`migrate_reduced_motion`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MotionPreference {
+    /// Follow the operating system
+    #[default]
+    Auto,
+    /// Always reduce motion
+    On,
+    /// Never reduce motion
+    Off,
+}
+
+impl MotionPreference {
+    pub const ALL: [MotionPreference; 3] =
+        [MotionPreference::Auto, MotionPreference::On, MotionPreference::Off];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MotionPreference::Auto => "Auto",
+            MotionPreference::On => "On",
+            MotionPreference::Off => "Off",
+        }
+    }
+
+    /// Whether motion is reduced, given the system preference
+    pub fn resolve(&self, system_reduced: bool) -> bool {
+        match self {
+            MotionPreference::Auto => system_reduced,
+            MotionPreference::On => true,
+            MotionPreference::Off => false,
+        }
+    }
+
+    /// The override that flips the current effective setting, for keyboard
+    /// toggles
+    pub fn toggled(&self, system_reduced: bool) -> Self {
+        if self.resolve(system_reduced) {
+            MotionPreference::Off
+        } else {
+            MotionPreference::On
+        }
+    }
+}
+
+/// Convert a config payload's legacy `reduced_motion` bool, for a clock's
+/// `VersionedConfig::migrate`
+///
+/// `true` still means On and `false` now means Auto. A payload that already
+/// names its choice is left alone, so this is safe to run at every step.
+pub fn migrate_reduced_motion(payload: &mut toml::Table) {
+    let Some(&toml::Value::Boolean(reduced)) = payload.get("reduced_motion") else {
+        return;
+    };
+    let preference = if reduced {
+        MotionPreference::On
+    } else {
+        MotionPreference::Auto
+    };
+    if let Ok(value) = toml::Value::try_from(preference) {
+        payload.insert("reduced_motion".to_string(), value);
+    }
+}
+
+/// Where a platform keeps its reduce-motion preference
+struct Source {
+    program: &'static str,
+    args: &'static [&'static str],
+    /// Value printed when motion should be reduced
+    reduced: &'static str,
+    /// Value printed when it shouldn't
+    normal: &'static str,
+}
+
+#[cfg(target_os = "macos")]
+const SOURCE: Source = Source {
+    program: "defaults",
+    args: &["read", "com.apple.universalaccess", "reduceMotion"],
+    reduced: "1",
+    normal: "0",
+};
+
+#[cfg(windows)]
+const SOURCE: Source = Source {
+    program: "reg",
+    args: &["query", r"HKCU\Control Panel\Desktop\WindowMetrics", "/v", "MinAnimate"],
+    reduced: "0",
+    normal: "1",
+};
+
+#[cfg(not(any(target_os = "macos", windows)))]
+const SOURCE: Source = Source {
+    program: "gsettings",
+    args: &["get", "org.gnome.desktop.interface", "enable-animations"],
+    reduced: "false",
+    normal: "true",
+};
+
+impl Source {
+    /// Interpret the command's output; the value is its last word
+    fn parse(&self, output: &str) -> Option<bool> {
+        let value = output.split_whitespace().last()?.trim_matches(|c| c == '\'' || c == '"');
+        if value == self.reduced {
+            Some(true)
+        } else if value == self.normal {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    fn read(&self) -> Option<bool> {
+        let output = Command::new(self.program).args(self.args).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        self.parse(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// Whether the operating system asks for reduced motion
+pub fn system_prefers_reduced_motion() -> bool {
+    SOURCE.read().unwrap_or(false)
+}
+
+/// Handle to a thread watching the system reduce-motion preference
+///
+/// The thread is stopped when the handle is dropped.
+pub struct SystemMotionWatcher {
+    receiver: Receiver<bool>,
+    stop: Arc<AtomicBool>,
+    /// The latest reading
+    reduced: bool,
+}
+
+impl SystemMotionWatcher {
+    /// Read the preference now and keep watching it
+    pub fn spawn() -> Self {
+        let reduced = system_prefers_reduced_motion();
+        let (sender, receiver) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+
+        thread::spawn(move || {
+            let mut last = reduced;
+            while !thread_stop.load(Ordering::Relaxed) {
+                thread::sleep(POLL_INTERVAL);
+                let current = system_prefers_reduced_motion();
+                if current != last {
+                    last = current;
+                    // Receiver dropped - nobody left to tell
+                    if sender.send(current).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        Self {
+            receiver,
+            stop,
+            reduced,
+        }
+    }
+
+    /// Whether the system asks for reduced motion, as last read
+    pub fn reduced(&self) -> bool {
+        self.reduced
+    }
+
+    /// Take pending readings; returns whether the preference changed
+    pub fn poll(&mut self) -> bool {
+        let before = self.reduced;
+        while let Ok(reduced) = self.receiver.try_recv() {
+            self.reduced = reduced;
+        }
+        self.reduced != before
+    }
+}
+
+impl Drop for SystemMotionWatcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Settings control for reduced motion; returns whether it changed
+pub fn reduced_motion_settings(
+    ui: &mut egui::Ui,
+    preference: &mut MotionPreference,
+    system_reduced: bool,
+) -> bool {
+    let mut changed = false;
+    ui.label(tr("Reduced Motion"));
+    ui.horizontal(|ui| {
+        for option in MotionPreference::ALL {
+            changed |= ui.radio_value(preference, option, tr(option.label())).changed();
+        }
+    });
+    let system = tr(if system_reduced { "on" } else { "off" });
+    ui.label(
+        egui::RichText::new(tr_args("Auto follows the system setting (now {0})", &[&system]))
+            .size(10.0)
+            .color(egui::Color32::from_rgb(120, 125, 135)),
+    );
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{decode_config, VersionedConfig};
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Stored {
+        reduced_motion: MotionPreference,
+    }
+
+    impl VersionedConfig for Stored {
+        const VERSION: u32 = 2;
+
+        fn migrate(_from_version: u32, payload: &mut toml::Table) {
+            migrate_reduced_motion(payload);
+        }
+    }
+
+    fn load(text: &str) -> MotionPreference {
+        decode_config::<Stored>(text).unwrap().reduced_motion
+    }
+
+    #[test]
+    fn test_resolve_and_toggle() {
+        assert!(MotionPreference::Auto.resolve(true));
+        assert!(!MotionPreference::Auto.resolve(false));
+        assert!(MotionPreference::On.resolve(false));
+        assert!(!MotionPreference::Off.resolve(true));
+        // Toggling always lands on an explicit override
+        assert_eq!(MotionPreference::Auto.toggled(true), MotionPreference::Off);
+        assert_eq!(MotionPreference::Auto.toggled(false), MotionPreference::On);
+        assert_eq!(MotionPreference::On.toggled(false), MotionPreference::Off);
+    }
+
+    #[test]
+    fn test_migrates_legacy_bool() {
+        // Version 1 files, and unversioned ones, stored a bool
+        let v1 = |value: &str| format!("version = 1\n\n[payload]\nreduced_motion = {}\n", value);
+        assert_eq!(load(&v1("true")), MotionPreference::On);
+        assert_eq!(load(&v1("false")), MotionPreference::Auto);
+        assert_eq!(load("reduced_motion = true"), MotionPreference::On);
+        // Named choices pass through, whatever version wrote them
+        assert_eq!(load(&v1("\"off\"")), MotionPreference::Off);
+
+        let text = crate::config::encode_config(&Stored {
+            reduced_motion: MotionPreference::On,
+        })
+        .unwrap();
+        assert!(text.starts_with("version = 2"));
+        assert_eq!(load(&text), MotionPreference::On);
+        // The bool is no longer accepted outside a migration
+        assert!(toml::from_str::<Stored>("reduced_motion = true").is_err());
+    }
+
+    #[test]
+    fn test_parse_system_output() {
+        let gnome = Source {
+            program: "gsettings",
+            args: &[],
+            reduced: "false",
+            normal: "true",
+        };
+        assert_eq!(gnome.parse("false\n"), Some(true));
+        assert_eq!(gnome.parse("true\n"), Some(false));
+        assert_eq!(gnome.parse(""), None);
+
+        let windows = Source {
+            program: "reg",
+            args: &[],
+            reduced: "0",
+            normal: "1",
+        };
+        let output = "\nHKEY_CURRENT_USER\\Control Panel\\Desktop\\WindowMetrics\n    \
+                      MinAnimate    REG_SZ    0\n";
+        assert_eq!(windows.parse(output), Some(true));
+    }
+}
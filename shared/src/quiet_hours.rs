@@ -0,0 +1,224 @@
+//! Quiet hours module - night mode shared by every clock
+//!
+//! During the configured hours (22:00–07:00 by default, read in the zone
+//! each clock has selected) clocks dim, stop their animation and pulse
+//! effects, and keep chimes silent. The schedule lives in one config file
+//! so setting it in any clock applies to the whole series.
+//!
+//! Each clock turns the schedule into a `VisualIntensity` once per update
+//! and draws from that rather than checking the clock time itself.
+
+use chrono::{DateTime, Timelike, Utc};
+use chrono_tz::Tz;
+use nannou_egui::egui;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{load_config, save_config, ConfigError, VersionedConfig};
+use crate::i18n::tr;
+use crate::time_engine::TimeData;
+
+/// Config file shared by all clocks
+pub const QUIET_HOURS_CONFIG: &str = "quiet_hours";
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// When quiet hours run and how far they dim
+///
+/// `end_minute < start_minute` describes an overnight window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuietHours {
+    pub enabled: bool,
+    pub start_minute: u32,
+    pub end_minute: u32,
+    /// Brightness kept during quiet hours, 0.0–1.0
+    pub brightness: f32,
+}
+
+impl Default for QuietHours {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            start_minute: 22 * 60,
+            end_minute: 7 * 60,
+            brightness: 0.4,
+        }
+    }
+}
+
+impl VersionedConfig for QuietHours {
+    const VERSION: u32 = 1;
+}
+
+impl QuietHours {
+    /// Load the shared schedule, falling back to the default (off)
+    pub fn load() -> Self {
+        match load_config(QUIET_HOURS_CONFIG) {
+            Ok(quiet_hours) => quiet_hours.unwrap_or_default(),
+            Err(e) => {
+                eprintln!("Failed to load quiet hours: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<(), ConfigError> {
+        save_config(QUIET_HOURS_CONFIG, self)
+    }
+
+    /// Whether a local minute-of-day falls inside the window
+    ///
+    /// An empty window (start == end) never matches.
+    pub fn contains_minute(&self, minute_of_day: u32) -> bool {
+        let minute_of_day = minute_of_day % MINUTES_PER_DAY;
+        if self.start_minute <= self.end_minute {
+            minute_of_day >= self.start_minute && minute_of_day < self.end_minute
+        } else {
+            minute_of_day >= self.start_minute || minute_of_day < self.end_minute
+        }
+    }
+
+    /// How strongly to draw at `time_data`'s local time
+    pub fn intensity(&self, time_data: &TimeData) -> VisualIntensity {
+        self.intensity_at_minute(time_data.hour24 * 60 + time_data.minute)
+    }
+
+    /// How strongly to draw at `now_utc` in `tz`, for clocks whose time data
+    /// can be scrubbed away from the present
+    pub fn intensity_at(&self, tz: Tz, now_utc: DateTime<Utc>) -> VisualIntensity {
+        let local = now_utc.with_timezone(&tz);
+        self.intensity_at_minute(local.hour() * 60 + local.minute())
+    }
+
+    fn intensity_at_minute(&self, minute_of_day: u32) -> VisualIntensity {
+        if !self.enabled || !self.contains_minute(minute_of_day) {
+            return VisualIntensity::FULL;
+        }
+        VisualIntensity {
+            brightness: self.brightness.clamp(0.0, 1.0),
+            animations: false,
+            sounds: false,
+        }
+    }
+
+    /// "22:00–07:00"
+    pub fn window_label(&self) -> String {
+        format!(
+            "{}–{}",
+            format_minute_of_day(self.start_minute),
+            format_minute_of_day(self.end_minute)
+        )
+    }
+}
+
+/// What a clock may do this frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VisualIntensity {
+    /// Overall brightness, 1.0 for normal
+    pub brightness: f32,
+    /// Whether animation and pulse effects run
+    pub animations: bool,
+    /// Whether chimes and other sounds play
+    pub sounds: bool,
+}
+
+impl Default for VisualIntensity {
+    fn default() -> Self {
+        Self::FULL
+    }
+}
+
+impl VisualIntensity {
+    /// Outside quiet hours: everything on
+    pub const FULL: Self = Self {
+        brightness: 1.0,
+        animations: true,
+        sounds: true,
+    };
+
+    pub fn is_quiet(&self) -> bool {
+        *self != Self::FULL
+    }
+
+    /// Alpha of the black overlay that dims a frame to `brightness`
+    pub fn dim_alpha(&self) -> f32 {
+        1.0 - self.brightness.clamp(0.0, 1.0)
+    }
+}
+
+/// "HH:MM", 24-hour
+fn format_minute_of_day(minute_of_day: u32) -> String {
+    let minute_of_day = minute_of_day % MINUTES_PER_DAY;
+    format!("{:02}:{:02}", minute_of_day / 60, minute_of_day % 60)
+}
+
+/// Settings controls for the schedule; returns whether anything changed
+pub fn quiet_hours_settings(ui: &mut egui::Ui, quiet_hours: &mut QuietHours) -> bool {
+    let mut changed = ui
+        .checkbox(&mut quiet_hours.enabled, tr("Quiet hours"))
+        .on_hover_text(tr("Dim, stop animations and mute chimes overnight"))
+        .changed();
+    ui.add_enabled_ui(quiet_hours.enabled, |ui| {
+        ui.horizontal(|ui| {
+            changed |= ui.add(minute_of_day_drag(&mut quiet_hours.start_minute)).changed();
+            ui.label("–");
+            changed |= ui.add(minute_of_day_drag(&mut quiet_hours.end_minute)).changed();
+        });
+        changed |= ui
+            .add(egui::Slider::new(&mut quiet_hours.brightness, 0.1..=1.0).text(tr("Brightness")))
+            .changed();
+    });
+    changed
+}
+
+/// Drag value editing a minute-of-day in 15-minute steps
+fn minute_of_day_drag(value: &mut u32) -> egui::DragValue<'_> {
+    egui::DragValue::new(value)
+        .clamp_range(0..=(MINUTES_PER_DAY - 15))
+        .speed(15.0)
+        .custom_formatter(|n, _| format_minute_of_day(n as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time_engine::compute_time_data_at;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_overnight_window() {
+        let quiet_hours = QuietHours::default();
+        assert!(quiet_hours.contains_minute(22 * 60));
+        assert!(quiet_hours.contains_minute(3 * 60));
+        assert!(!quiet_hours.contains_minute(7 * 60));
+        assert!(!quiet_hours.contains_minute(12 * 60));
+        assert_eq!(quiet_hours.window_label(), "22:00–07:00");
+
+        let empty = QuietHours {
+            start_minute: 60,
+            end_minute: 60,
+            ..Default::default()
+        };
+        assert!(!empty.contains_minute(60));
+    }
+
+    #[test]
+    fn test_intensity_follows_zone_time() {
+        let tz: Tz = "Asia/Tokyo".parse().unwrap();
+        // 23:30 in Tokyo, 14:30 UTC
+        let night = compute_time_data_at(tz, Utc.with_ymd_and_hms(2024, 3, 1, 14, 30, 0).unwrap());
+        let day = compute_time_data_at(tz, Utc.with_ymd_and_hms(2024, 3, 1, 3, 0, 0).unwrap());
+
+        let mut quiet_hours = QuietHours::default();
+        assert_eq!(quiet_hours.intensity(&night), VisualIntensity::FULL);
+
+        quiet_hours.enabled = true;
+        let intensity = quiet_hours.intensity(&night);
+        assert!(intensity.is_quiet());
+        assert!(!intensity.animations && !intensity.sounds);
+        assert!((intensity.dim_alpha() - 0.6).abs() < 1e-6);
+        assert_eq!(quiet_hours.intensity(&day), VisualIntensity::FULL);
+        let night_utc = night.local_datetime.with_timezone(&Utc);
+        assert_eq!(quiet_hours.intensity_at(tz, night_utc), intensity);
+    }
+}
@@ -0,0 +1,465 @@
+//! Recording module - shareable clips of a clock's canvas (Ctrl+Shift+R)
+//!
+//! `ClipRecorder` paces frame captures over a fixed duration: each update it
+//! tells the clock whether to capture the coming frame, and where, or that
+//! the clip is complete. Frames are written as PNGs into a temporary folder;
+//! once the clock has waited for them they are encoded on a background
+//! thread - to an animated GIF directly, or to WebM by handing them to
+//! `ffmpeg` - and the clip is saved to the export directory.
+//!
+//! While recording, the indicator goes in the window title rather than on
+//! the canvas, so it doesn't end up in the clip. Like quiet hours, the clip
+//! settings live in one config file for the whole series.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chrono::Local;
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::imageops::{self, FilterType};
+use image::{Delay, Frame};
+use nannou_egui::egui;
+use serde::{Deserialize, Serialize};
+use winit::event::{ModifiersState, VirtualKeyCode};
+
+use crate::config::{export_dir, load_config, save_config, ConfigError, VersionedConfig};
+use crate::i18n::{tr, tr_args};
+
+/// Config file shared by all clocks
+pub const RECORDING_CONFIG: &str = "recording";
+
+/// Wider frames are scaled down before GIF encoding to keep clips shareable
+const GIF_MAX_WIDTH: u32 = 720;
+
+/// GIF quantizer speed, 1 (best) to 30 (fastest)
+const GIF_SPEED: i32 = 10;
+
+/// File type a clip is saved as
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClipFormat {
+    #[default]
+    Gif,
+    /// Encoded by `ffmpeg`, which must be on the PATH
+    Webm,
+}
+
+impl ClipFormat {
+    pub const ALL: [ClipFormat; 2] = [ClipFormat::Gif, ClipFormat::Webm];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ClipFormat::Gif => "GIF",
+            ClipFormat::Webm => "WebM",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ClipFormat::Gif => "gif",
+            ClipFormat::Webm => "webm",
+        }
+    }
+}
+
+/// What a recording captures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClipSettings {
+    pub format: ClipFormat,
+    pub duration_secs: u32,
+    /// Frames captured per second
+    pub fps: u32,
+}
+
+impl Default for ClipSettings {
+    fn default() -> Self {
+        Self {
+            format: ClipFormat::Gif,
+            duration_secs: 5,
+            fps: 15,
+        }
+    }
+}
+
+impl VersionedConfig for ClipSettings {
+    const VERSION: u32 = 1;
+}
+
+impl ClipSettings {
+    /// Load the shared settings, falling back to a five second GIF
+    pub fn load() -> Self {
+        match load_config(RECORDING_CONFIG) {
+            Ok(settings) => settings.unwrap_or_default(),
+            Err(e) => {
+                eprintln!("Failed to load recording settings: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<(), ConfigError> {
+        save_config(RECORDING_CONFIG, self)
+    }
+
+    fn duration(&self) -> Duration {
+        Duration::from_secs(u64::from(self.duration_secs.max(1)))
+    }
+
+    fn frame_interval(&self) -> Duration {
+        Duration::from_secs(1) / self.fps.max(1)
+    }
+}
+
+/// Whether `mods` hold Ctrl (or Cmd on macOS) and Shift, and `key` is R
+pub fn is_clip_toggle(key: VirtualKeyCode, mods: ModifiersState) -> bool {
+    key == VirtualKeyCode::R && (mods.ctrl() || mods.logo()) && mods.shift()
+}
+
+/// What a clock should do on this frame of a recording
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClipStep {
+    /// Nothing to capture this frame
+    Idle,
+    /// Capture the coming frame to this path
+    Capture(PathBuf),
+    /// The clip is complete: wait for captures, then call `ClipRecorder::encode`
+    Finish,
+}
+
+/// Frames captured so far and how to encode them
+#[derive(Debug)]
+struct ClipJob {
+    settings: ClipSettings,
+    frame_dir: PathBuf,
+    started: Instant,
+    frames: u32,
+    /// Time from the first capture to the end of the clip
+    elapsed: Duration,
+}
+
+#[derive(Debug)]
+enum ClipState {
+    Idle,
+    Capturing { job: ClipJob, stop_requested: bool },
+    /// Captures done; waiting for the clock to call `encode`
+    Finished(ClipJob),
+    Encoding,
+}
+
+/// Records a clock's canvas into a clip
+pub struct ClipRecorder {
+    clock_name: &'static str,
+    state: ClipState,
+    receiver: Option<Receiver<Result<PathBuf, String>>>,
+}
+
+impl ClipRecorder {
+    pub fn new(clock_name: &'static str) -> Self {
+        Self {
+            clock_name,
+            state: ClipState::Idle,
+            receiver: None,
+        }
+    }
+
+    /// Whether frames are being captured
+    pub fn is_recording(&self) -> bool {
+        matches!(self.state, ClipState::Capturing { .. })
+    }
+
+    /// Start a clip, or stop the one being captured early
+    ///
+    /// Returns a message to show the user.
+    pub fn toggle(&mut self, settings: ClipSettings, now: Instant) -> String {
+        match &mut self.state {
+            ClipState::Idle => {
+                let frame_dir = std::env::temp_dir().join(format!(
+                    "{}-clip-{}",
+                    self.clock_name,
+                    std::process::id()
+                ));
+                // Frames left by an earlier clip would be encoded into this one
+                let _ = fs::remove_dir_all(&frame_dir);
+                if let Err(e) = fs::create_dir_all(&frame_dir) {
+                    return tr_args("Couldn't start recording: {0}", &[&e]);
+                }
+                self.state = ClipState::Capturing {
+                    job: ClipJob {
+                        settings,
+                        frame_dir,
+                        started: now,
+                        frames: 0,
+                        elapsed: Duration::ZERO,
+                    },
+                    stop_requested: false,
+                };
+                tr_args(
+                    "Recording a {0} s {1} clip (Ctrl+Shift+R to stop)",
+                    &[&settings.duration_secs.max(1), &settings.format.label()],
+                )
+            }
+            ClipState::Capturing { stop_requested, .. } => {
+                *stop_requested = true;
+                tr("Stopping recording").to_string()
+            }
+            ClipState::Finished(_) | ClipState::Encoding => {
+                tr("Still saving the last clip").to_string()
+            }
+        }
+    }
+
+    /// Decide what to do on this frame
+    pub fn advance(&mut self, now: Instant) -> ClipStep {
+        let ClipState::Capturing {
+            job,
+            stop_requested,
+        } = &mut self.state
+        else {
+            return ClipStep::Idle;
+        };
+
+        let elapsed = now.saturating_duration_since(job.started);
+        if *stop_requested || elapsed >= job.settings.duration() {
+            job.elapsed = elapsed;
+            let state = std::mem::replace(&mut self.state, ClipState::Idle);
+            if let ClipState::Capturing { job, .. } = state {
+                self.state = ClipState::Finished(job);
+            }
+            return ClipStep::Finish;
+        }
+
+        // Capture whenever the clip has fallen behind its frame rate; a slow
+        // clock simply gets fewer frames, each shown for longer
+        let due = elapsed.as_nanos() / job.settings.frame_interval().as_nanos().max(1) + 1;
+        if u128::from(job.frames) < due {
+            let path = frame_path(&job.frame_dir, job.frames);
+            job.frames += 1;
+            return ClipStep::Capture(path);
+        }
+        ClipStep::Idle
+    }
+
+    /// Encode the finished clip on a background thread
+    ///
+    /// `frames_written` is false if waiting for the captures timed out.
+    pub fn encode(&mut self, frames_written: bool) {
+        let ClipState::Finished(job) = std::mem::replace(&mut self.state, ClipState::Encoding)
+        else {
+            self.state = ClipState::Idle;
+            return;
+        };
+
+        let (sender, receiver) = mpsc::channel();
+        self.receiver = Some(receiver);
+        let clock_name = self.clock_name;
+        thread::spawn(move || {
+            let result = if frames_written {
+                save_clip(clock_name, &job)
+            } else {
+                Err("timed out writing frames".to_string())
+            };
+            let _ = fs::remove_dir_all(&job.frame_dir);
+            // Receiver dropped - the clock has closed
+            let _ = sender.send(result);
+        });
+    }
+
+    /// Take the outcome of the last encode, as a message to show the user
+    pub fn poll(&mut self) -> Option<String> {
+        let result = self.receiver.as_ref()?.try_recv().ok()?;
+        self.receiver = None;
+        self.state = ClipState::Idle;
+        Some(match result {
+            Ok(path) => tr_args("Saved clip to {0}", &[&path.display()]),
+            Err(e) => tr_args("Couldn't save clip: {0}", &[&e]),
+        })
+    }
+
+    /// Badge for the window title while a clip is recording or saving
+    pub fn indicator(&self, now: Instant) -> Option<String> {
+        match &self.state {
+            ClipState::Idle => None,
+            ClipState::Capturing { job, .. } => {
+                let remaining = job
+                    .settings
+                    .duration()
+                    .saturating_sub(now.saturating_duration_since(job.started));
+                Some(format!("● REC {}s", remaining.as_secs_f32().ceil() as u32))
+            }
+            ClipState::Finished(_) | ClipState::Encoding => Some(tr("Saving clip…").to_string()),
+        }
+    }
+}
+
+fn frame_path(frame_dir: &Path, index: u32) -> PathBuf {
+    frame_dir.join(format!("frame_{:05}.png", index))
+}
+
+/// Encode a job's frames and write the clip into the export directory
+fn save_clip(clock_name: &str, job: &ClipJob) -> Result<PathBuf, String> {
+    if job.frames == 0 {
+        return Err("no frames were captured".to_string());
+    }
+    let dir = export_dir().ok_or_else(|| ConfigError::NoConfigDir.to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let label = format!("clip_{}", Local::now().format("%Y%m%dT%H%M%S"));
+    let path = dir.join(format!(
+        "{}_{}.{}",
+        clock_name,
+        label,
+        job.settings.format.extension()
+    ));
+
+    match job.settings.format {
+        ClipFormat::Gif => encode_gif(job, &path),
+        ClipFormat::Webm => encode_webm(job, &path),
+    }?;
+    Ok(path)
+}
+
+/// Frame delay in milliseconds that plays `frames` back over `elapsed`
+fn frame_delay_ms(frames: u32, elapsed: Duration) -> u32 {
+    let ms = elapsed.as_millis() / u128::from(frames.max(1));
+    // GIF delays are in hundredths of a second, and most viewers treat
+    // anything shorter than two as "as fast as possible"
+    (ms as u32).max(20)
+}
+
+fn encode_gif(job: &ClipJob, path: &Path) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| e.to_string())?;
+    let mut encoder = GifEncoder::new_with_speed(BufWriter::new(file), GIF_SPEED);
+    encoder.set_repeat(Repeat::Infinite).map_err(|e| e.to_string())?;
+    let delay = Delay::from_numer_denom_ms(frame_delay_ms(job.frames, job.elapsed), 1);
+
+    for index in 0..job.frames {
+        let mut image = image::open(frame_path(&job.frame_dir, index))
+            .map_err(|e| e.to_string())?
+            .to_rgba8();
+        if image.width() > GIF_MAX_WIDTH {
+            let height = image.height() * GIF_MAX_WIDTH / image.width();
+            image = imageops::resize(&image, GIF_MAX_WIDTH, height.max(1), FilterType::Triangle);
+        }
+        encoder
+            .encode_frame(Frame::from_parts(image, 0, 0, delay))
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+fn encode_webm(job: &ClipJob, path: &Path) -> Result<(), String> {
+    let fps = 1000.0 / frame_delay_ms(job.frames, job.elapsed) as f32;
+    let output = Command::new("ffmpeg")
+        .args(["-y", "-loglevel", "error", "-framerate"])
+        .arg(format!("{:.3}", fps))
+        .arg("-i")
+        .arg(job.frame_dir.join("frame_%05d.png"))
+        .args(["-c:v", "libvpx-vp9", "-b:v", "0", "-crf", "32", "-pix_fmt", "yuv420p"])
+        .arg(path)
+        .output()
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => "WebM clips need ffmpeg on the PATH".to_string(),
+            _ => e.to_string(),
+        })?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("ffmpeg failed: {}", stderr.trim()));
+    }
+    Ok(())
+}
+
+/// Settings controls for clip recording; returns whether anything changed
+pub fn clip_recording_settings(ui: &mut egui::Ui, settings: &mut ClipSettings) -> bool {
+    let mut changed = false;
+    ui.label(tr("Clip Recording (Ctrl+Shift+R)"));
+    ui.horizontal(|ui| {
+        for format in ClipFormat::ALL {
+            changed |= ui
+                .selectable_value(&mut settings.format, format, format.label())
+                .changed();
+        }
+    });
+    changed |= ui
+        .add(egui::Slider::new(&mut settings.duration_secs, 1..=30).suffix(" s"))
+        .changed();
+    changed |= ui
+        .add(egui::Slider::new(&mut settings.fps, 5..=30).suffix(" fps"))
+        .changed();
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recording(recorder: &mut ClipRecorder, start: Instant) {
+        let settings = ClipSettings {
+            format: ClipFormat::Gif,
+            duration_secs: 1,
+            fps: 10,
+        };
+        recorder.toggle(settings, start);
+        assert!(recorder.is_recording());
+    }
+
+    #[test]
+    fn test_captures_follow_the_frame_rate() {
+        let mut recorder = ClipRecorder::new("recording_test_rate");
+        let start = Instant::now();
+        recording(&mut recorder, start);
+
+        let at = |ms| start + Duration::from_millis(ms);
+        assert!(matches!(recorder.advance(at(0)), ClipStep::Capture(_)));
+        assert_eq!(recorder.advance(at(50)), ClipStep::Idle);
+        let ClipStep::Capture(path) = recorder.advance(at(120)) else {
+            panic!("second frame not captured");
+        };
+        assert!(path.ends_with("frame_00001.png"));
+        assert_eq!(recorder.indicator(at(120)).as_deref(), Some("● REC 1s"));
+
+        assert_eq!(recorder.advance(at(1000)), ClipStep::Finish);
+        assert!(!recorder.is_recording());
+        assert_eq!(recorder.indicator(at(1000)).as_deref(), Some("Saving clip…"));
+        assert_eq!(recorder.advance(at(1100)), ClipStep::Idle);
+    }
+
+    #[test]
+    fn test_toggle_stops_early() {
+        let mut recorder = ClipRecorder::new("recording_test_stop");
+        let start = Instant::now();
+        recording(&mut recorder, start);
+        assert!(matches!(recorder.advance(start), ClipStep::Capture(_)));
+
+        assert_eq!(recorder.toggle(ClipSettings::default(), start), "Stopping recording");
+        assert_eq!(recorder.advance(start), ClipStep::Finish);
+        assert_eq!(
+            recorder.toggle(ClipSettings::default(), start),
+            "Still saving the last clip"
+        );
+
+        // A timed-out capture is reported, not encoded
+        recorder.encode(false);
+        let message = loop {
+            if let Some(message) = recorder.poll() {
+                break message;
+            }
+            thread::sleep(Duration::from_millis(5));
+        };
+        assert_eq!(message, "Couldn't save clip: timed out writing frames");
+        assert_eq!(recorder.indicator(start), None);
+    }
+
+    #[test]
+    fn test_frame_delay() {
+        assert_eq!(frame_delay_ms(10, Duration::from_secs(1)), 100);
+        // Slow captures stretch each frame; impossibly fast ones are clamped
+        assert_eq!(frame_delay_ms(4, Duration::from_secs(1)), 250);
+        assert_eq!(frame_delay_ms(100, Duration::from_millis(500)), 20);
+        assert!(is_clip_toggle(VirtualKeyCode::R, ModifiersState::CTRL | ModifiersState::SHIFT));
+        assert!(!is_clip_toggle(VirtualKeyCode::R, ModifiersState::CTRL));
+    }
+}
@@ -0,0 +1,752 @@
+//! Reveal hotkey module - showing the time from a clock in the background
+//!
+//! An opt-in, system-wide shortcut (Ctrl+Alt+C by default) reaches a clock
+//! that's behind other apps or minimized. It either raises and focuses the
+//! clock's window, or flashes it above other apps for a few seconds with a
+//! large time card over it, like temporal_grammar's Truth Anchor, before
+//! sending it back. Pressing the shortcut again ends a flash early. Like
+//! quiet hours, the setting is one config file for the whole series.
+//!
+//! On Linux the shortcut is grabbed from the X server through Xlib, loaded at
+//! runtime as winit does. Only one clock can hold the shortcut at a time, so
+//! a second clock reports it as taken. Under Wayland, XWayland only passes a
+//! grabbed key on while one of its own windows has focus, so there, as on
+//! other platforms, the option is hidden and the shortcut reported as not
+//! available.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use nannou_egui::egui;
+use serde::{Deserialize, Serialize};
+use winit::event::{ModifiersState, VirtualKeyCode};
+use winit::window::{Window, WindowLevel};
+
+use crate::command_palette::{key_name, KeyChord};
+use crate::config::{load_config, save_config, ConfigError, VersionedConfig};
+use crate::i18n::tr;
+use crate::keymap::BINDABLE_KEYS;
+use crate::time_engine::TimeData;
+
+/// Config file shared by all clocks
+pub const REVEAL_HOTKEY_CONFIG: &str = "reveal_hotkey";
+
+/// Range of the flash duration slider, in seconds
+pub const MIN_FLASH_SECS: f32 = 1.0;
+pub const MAX_FLASH_SECS: f32 = 15.0;
+
+/// What the shortcut does to the clock's window
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RevealAction {
+    /// Show the window above other apps for a few seconds, then send it back
+    #[default]
+    Flash,
+    /// Bring the window to the front and focus it
+    Raise,
+}
+
+impl RevealAction {
+    pub const ALL: [RevealAction; 2] = [RevealAction::Flash, RevealAction::Raise];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RevealAction::Flash => tr("Flash on top"),
+            RevealAction::Raise => tr("Raise window"),
+        }
+    }
+}
+
+/// The system-wide shortcut that reveals the time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RevealHotkey {
+    pub enabled: bool,
+    /// Chord label, e.g. "Ctrl+Alt+C"
+    pub chord: String,
+    pub action: RevealAction,
+    /// How long a flash stays on top
+    pub flash_secs: f32,
+}
+
+impl Default for RevealHotkey {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            chord: "Ctrl+Alt+C".to_string(),
+            action: RevealAction::Flash,
+            flash_secs: 4.0,
+        }
+    }
+}
+
+impl VersionedConfig for RevealHotkey {
+    const VERSION: u32 = 1;
+}
+
+impl RevealHotkey {
+    /// Load the shared setting, falling back to the default (off)
+    pub fn load() -> Self {
+        match load_config(REVEAL_HOTKEY_CONFIG) {
+            Ok(setting) => setting.unwrap_or_default(),
+            Err(e) => {
+                eprintln!("Failed to load reveal hotkey setting: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<(), ConfigError> {
+        save_config(REVEAL_HOTKEY_CONFIG, self)
+    }
+
+    /// The shortcut, if the label names one safe to take from every app:
+    /// it needs Ctrl or Alt, unless it's a function key
+    pub fn parsed_chord(&self) -> Result<KeyChord, &'static str> {
+        let chord = KeyChord::parse(&self.chord).ok_or(tr("Unknown shortcut"))?;
+        let function_key = (VirtualKeyCode::F1..=VirtualKeyCode::F12).contains(&chord.key);
+        if chord.mods.ctrl() || chord.mods.alt() || function_key {
+            Ok(chord)
+        } else {
+            Err(tr("Add Ctrl or Alt, or pick a function key"))
+        }
+    }
+
+    fn flash_duration(&self) -> Duration {
+        let secs = if self.flash_secs.is_finite() {
+            self.flash_secs.clamp(MIN_FLASH_SECS, MAX_FLASH_SECS)
+        } else {
+            Self::default().flash_secs
+        };
+        Duration::from_secs_f32(secs)
+    }
+}
+
+/// Background thread holding the shortcut and reporting its presses
+///
+/// The shortcut is released when the listener is dropped.
+struct HotkeyListener {
+    presses: Receiver<()>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl HotkeyListener {
+    fn spawn(chord: KeyChord) -> Result<Self, String> {
+        let (sender, presses) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread = platform::listen(chord, sender, Arc::clone(&stop))?;
+        Ok(Self {
+            presses,
+            stop,
+            thread: Some(thread),
+        })
+    }
+
+    /// Whether the shortcut was pressed since the last call
+    fn pressed(&self) -> bool {
+        self.presses.try_iter().count() > 0
+    }
+}
+
+impl Drop for HotkeyListener {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        // Wait for the grab to be released, so it can be taken again at once
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// A flash in progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Flash {
+    until: Instant,
+    /// Minimize the window again when the flash ends
+    was_minimized: bool,
+}
+
+/// What a press or the passing time asks of the window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WindowChange {
+    Raise,
+    StartFlash,
+    EndFlash(Flash),
+}
+
+/// The reveal shortcut of one running clock
+pub struct TimeReveal {
+    /// Edited in the settings; call `apply` after a change
+    pub setting: RevealHotkey,
+    listener: Option<HotkeyListener>,
+    /// Why the shortcut isn't listening while enabled
+    status: Option<String>,
+    flash: Option<Flash>,
+    /// The clock keeps its window on top by itself
+    pinned: bool,
+}
+
+impl TimeReveal {
+    /// Load the shared setting and take the shortcut if it's enabled
+    pub fn load() -> Self {
+        let mut reveal = Self {
+            setting: RevealHotkey::load(),
+            listener: None,
+            status: None,
+            flash: None,
+            pinned: false,
+        };
+        reveal.listen();
+        reveal
+    }
+
+    /// Save the edited setting and take its shortcut again
+    pub fn apply(&mut self) {
+        if let Err(e) = self.setting.save() {
+            eprintln!("Failed to save reveal hotkey setting: {}", e);
+        }
+        self.listen();
+    }
+
+    fn listen(&mut self) {
+        // Release the old shortcut first; it may be the one asked for again
+        self.listener = None;
+        self.status = None;
+        if !self.setting.enabled {
+            return;
+        }
+        if let Some(reason) = platform::unavailable() {
+            self.status = Some(reason.to_string());
+            return;
+        }
+        let listener = self
+            .setting
+            .parsed_chord()
+            .map_err(str::to_string)
+            .and_then(HotkeyListener::spawn);
+        match listener {
+            Ok(listener) => self.listener = Some(listener),
+            Err(e) => self.status = Some(e),
+        }
+    }
+
+    /// Why the shortcut isn't working, if it's enabled and isn't
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    /// Whether the time card is showing
+    pub fn is_flashing(&self) -> bool {
+        self.flash.is_some()
+    }
+
+    /// Tell the reveal the clock keeps its window on top, so a flash ending
+    /// leaves it there
+    pub fn set_pinned(&mut self, pinned: bool) {
+        self.pinned = pinned;
+    }
+
+    /// Act on shortcut presses and end a flash once its time is up; call on
+    /// every update
+    pub fn update(&mut self, window: &Window, now: Instant) {
+        let pressed = self.listener.as_ref().is_some_and(HotkeyListener::pressed);
+        let minimized = window.is_minimized().unwrap_or(false);
+        let Some(change) = self.next_change(pressed, now, minimized) else {
+            return;
+        };
+        match change {
+            WindowChange::Raise => {
+                window.set_minimized(false);
+                window.focus_window();
+            }
+            WindowChange::StartFlash => {
+                window.set_minimized(false);
+                window.set_window_level(WindowLevel::AlwaysOnTop);
+            }
+            WindowChange::EndFlash(flash) => {
+                if !self.pinned {
+                    window.set_window_level(WindowLevel::Normal);
+                }
+                if flash.was_minimized {
+                    window.set_minimized(true);
+                }
+            }
+        }
+    }
+
+    fn next_change(&mut self, pressed: bool, now: Instant, minimized: bool) -> Option<WindowChange> {
+        if let Some(flash) = self.flash {
+            // A second press ends the flash early
+            if pressed || now >= flash.until {
+                self.flash = None;
+                return Some(WindowChange::EndFlash(flash));
+            }
+            return None;
+        }
+        if !pressed {
+            return None;
+        }
+        match self.setting.action {
+            RevealAction::Raise => Some(WindowChange::Raise),
+            RevealAction::Flash => {
+                self.flash = Some(Flash {
+                    until: now + self.setting.flash_duration(),
+                    was_minimized: minimized,
+                });
+                Some(WindowChange::StartFlash)
+            }
+        }
+    }
+
+    /// Draw the time card over the clock while flashing; call inside the
+    /// clock's egui frame
+    pub fn show(&self, ctx: &egui::Context, time_data: &TimeData) {
+        if self.flash.is_none() {
+            return;
+        }
+        egui::Area::new("time_reveal")
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .order(egui::Order::Foreground)
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style())
+                    .inner_margin(egui::Margin::symmetric(32.0, 20.0))
+                    .show(ui, |ui| {
+                        ui.vertical_centered(|ui| {
+                            let time = format!("{} {}", time_data.format_time(), time_data.meridiem);
+                            ui.label(egui::RichText::new(time).size(64.0).monospace().strong());
+                            let detail = format!("{} · {}", time_data.format_date(), time_data.tz_abbrev);
+                            ui.label(egui::RichText::new(detail).size(18.0));
+                        });
+                    });
+            });
+        ctx.request_repaint();
+    }
+}
+
+/// Settings control for the reveal shortcut; returns whether it changed
+///
+/// `status` is `TimeReveal::status`, shown when the shortcut can't be used.
+pub fn reveal_hotkey_settings(
+    ui: &mut egui::Ui,
+    setting: &mut RevealHotkey,
+    status: Option<&str>,
+) -> bool {
+    // Hidden where it can't work, rather than offered and failing
+    if let Some(reason) = platform::unavailable() {
+        ui.label(egui::RichText::new(reason).small().weak());
+        return false;
+    }
+    let mut changed = ui
+        .checkbox(&mut setting.enabled, tr("Global shortcut to show the time (X11)"))
+        .on_hover_text(tr("Reach this clock from any app, even when it's hidden"))
+        .changed();
+
+    ui.add_enabled_ui(setting.enabled, |ui| {
+        let mut chord = KeyChord::parse(&setting.chord)
+            .unwrap_or_else(|| KeyChord::parse(&RevealHotkey::default().chord).unwrap());
+        ui.horizontal(|ui| {
+            let mut ctrl = chord.mods.ctrl();
+            let mut alt = chord.mods.alt();
+            let mut shift = chord.mods.shift();
+            ui.checkbox(&mut ctrl, "Ctrl");
+            ui.checkbox(&mut alt, "Alt");
+            ui.checkbox(&mut shift, "Shift");
+            chord.mods = ModifiersState::empty();
+            chord.mods.set(ModifiersState::CTRL, ctrl);
+            chord.mods.set(ModifiersState::ALT, alt);
+            chord.mods.set(ModifiersState::SHIFT, shift);
+            egui::ComboBox::from_id_source("reveal_hotkey_key")
+                .selected_text(key_name(chord.key))
+                .width(60.0)
+                .show_ui(ui, |ui| {
+                    for &key in BINDABLE_KEYS {
+                        ui.selectable_value(&mut chord.key, key, key_name(key));
+                    }
+                });
+        });
+        if chord.label() != setting.chord {
+            setting.chord = chord.label();
+            changed = true;
+        }
+
+        ui.horizontal(|ui| {
+            for action in RevealAction::ALL {
+                changed |= ui
+                    .radio_value(&mut setting.action, action, action.label())
+                    .changed();
+            }
+        });
+        ui.add_enabled_ui(setting.action == RevealAction::Flash, |ui| {
+            let slider = egui::Slider::new(&mut setting.flash_secs, MIN_FLASH_SECS..=MAX_FLASH_SECS)
+                .text(tr("seconds"));
+            changed |= ui.add(slider).changed();
+        });
+    });
+
+    if setting.enabled {
+        if let Some(status) = status {
+            ui.colored_label(ui.visuals().warn_fg_color, status);
+        }
+    }
+    changed
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    //! Grabbing the shortcut from the X server
+
+    use std::env;
+    use std::ffi::CString;
+    use std::os::raw::{c_int, c_uint};
+    use std::ptr;
+    use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+    use std::sync::mpsc::{self, Sender, SyncSender};
+    use std::sync::{Arc, Mutex};
+    use std::thread::{self, JoinHandle};
+    use std::time::{Duration, Instant};
+
+    use x11_dl::xlib;
+
+    use super::{is_wayland_session, keysym_name};
+    use crate::command_palette::KeyChord;
+    use crate::i18n::tr;
+
+    /// How often the thread checks for presses and for being stopped
+    const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+    /// Presses closer together than this are the key repeating
+    const REPEAT_GAP: Duration = Duration::from_millis(400);
+
+    type ErrorHandler = unsafe extern "C" fn(*mut xlib::Display, *mut xlib::XErrorEvent) -> c_int;
+
+    /// Set by the error handler when the X server refuses a grab
+    static GRAB_REFUSED: AtomicBool = AtomicBool::new(false);
+
+    /// The connection the grab is made on, while it's being made
+    static GRAB_DISPLAY: AtomicPtr<xlib::Display> = AtomicPtr::new(ptr::null_mut());
+
+    /// The handler ours replaced, which still gets every other error
+    static PREVIOUS_HANDLER: Mutex<Option<ErrorHandler>> = Mutex::new(None);
+
+    unsafe extern "C" fn note_refusal(
+        display: *mut xlib::Display,
+        event: *mut xlib::XErrorEvent,
+    ) -> c_int {
+        if display == GRAB_DISPLAY.load(Ordering::Relaxed) {
+            if (*event).error_code == xlib::BadAccess {
+                GRAB_REFUSED.store(true, Ordering::Relaxed);
+            }
+            return 0;
+        }
+        // An error on another connection, such as winit's, isn't ours to swallow
+        let previous = PREVIOUS_HANDLER.lock().ok().and_then(|handler| *handler);
+        previous.map_or(0, |handler| handler(display, event))
+    }
+
+    /// Why this session can't hold a global shortcut, if it can't
+    pub(super) fn unavailable() -> Option<&'static str> {
+        let wayland_display = env::var("WAYLAND_DISPLAY").ok();
+        let session_type = env::var("XDG_SESSION_TYPE").ok();
+        is_wayland_session(wayland_display.as_deref(), session_type.as_deref())
+            .then(|| tr("Global shortcuts aren't available under Wayland"))
+    }
+
+    pub(super) fn listen(
+        chord: KeyChord,
+        presses: Sender<()>,
+        stop: Arc<AtomicBool>,
+    ) -> Result<JoinHandle<()>, String> {
+        let name = keysym_name(chord.key).ok_or(tr("Unknown shortcut"))?;
+        let mut mask = 0;
+        if chord.mods.ctrl() {
+            mask |= xlib::ControlMask;
+        }
+        if chord.mods.alt() {
+            mask |= xlib::Mod1Mask;
+        }
+        if chord.mods.shift() {
+            mask |= xlib::ShiftMask;
+        }
+
+        // The display connection can't leave its thread, so the thread
+        // reports whether the grab worked before it starts listening
+        let (ready, started) = mpsc::sync_channel(1);
+        let thread = thread::spawn(move || unsafe { run(name, mask, presses, stop, ready) });
+        match started.recv() {
+            Ok(Ok(())) => Ok(thread),
+            Ok(Err(e)) => Err(e),
+            Err(_) => Err("Shortcut listener stopped".to_string()),
+        }
+    }
+
+    unsafe fn run(
+        name: &str,
+        mask: c_uint,
+        presses: Sender<()>,
+        stop: Arc<AtomicBool>,
+        ready: SyncSender<Result<(), String>>,
+    ) {
+        let xlib = match xlib::Xlib::open() {
+            Ok(xlib) => xlib,
+            Err(e) => {
+                let _ = ready.send(Err(e.to_string()));
+                return;
+            }
+        };
+        // Xlib must be made thread-safe before its first call. winit's X11
+        // backend has done so already; under any other this thread is first.
+        if (xlib.XInitThreads)() == 0 {
+            let _ = ready.send(Err(tr("Xlib can't be used from another thread").into()));
+            return;
+        }
+        let display = (xlib.XOpenDisplay)(ptr::null());
+        if display.is_null() {
+            let _ = ready.send(Err(tr("No X11 display; the shortcut needs an X11 session").into()));
+            return;
+        }
+        let root = (xlib.XDefaultRootWindow)(display);
+        let name = CString::new(name).expect("keysym names have no nul");
+        let keycode = c_int::from((xlib.XKeysymToKeycode)(
+            display,
+            (xlib.XStringToKeysym)(name.as_ptr()),
+        ));
+        if keycode == 0 {
+            (xlib.XCloseDisplay)(display);
+            let _ = ready.send(Err(tr("Unknown shortcut").into()));
+            return;
+        }
+
+        // The grab has to ignore Caps Lock and Num Lock
+        let masks = [0, xlib::LockMask, xlib::Mod2Mask, xlib::LockMask | xlib::Mod2Mask]
+            .map(|lock| mask | lock);
+        // The error handler is process-wide: errors on other connections go on
+        // to the previous one, which is put back right after
+        GRAB_REFUSED.store(false, Ordering::Relaxed);
+        GRAB_DISPLAY.store(display, Ordering::Relaxed);
+        let previous = (xlib.XSetErrorHandler)(Some(note_refusal));
+        if let Ok(mut handler) = PREVIOUS_HANDLER.lock() {
+            *handler = previous;
+        }
+        for mask in masks {
+            (xlib.XGrabKey)(
+                display,
+                keycode,
+                mask,
+                root,
+                xlib::False,
+                xlib::GrabModeAsync,
+                xlib::GrabModeAsync,
+            );
+        }
+        (xlib.XSync)(display, xlib::False);
+        (xlib.XSetErrorHandler)(previous);
+        GRAB_DISPLAY.store(ptr::null_mut(), Ordering::Relaxed);
+
+        let ungrab = || {
+            for mask in masks {
+                (xlib.XUngrabKey)(display, keycode, mask, root);
+            }
+            (xlib.XCloseDisplay)(display);
+        };
+        if GRAB_REFUSED.load(Ordering::Relaxed) {
+            ungrab();
+            let _ = ready.send(Err(tr("Another app or clock already uses this shortcut").into()));
+            return;
+        }
+        let _ = ready.send(Ok(()));
+
+        let mut event: xlib::XEvent = std::mem::zeroed();
+        let mut last_press: Option<Instant> = None;
+        'listen: while !stop.load(Ordering::Relaxed) {
+            while (xlib.XPending)(display) > 0 {
+                (xlib.XNextEvent)(display, &mut event);
+                if event.get_type() != xlib::KeyPress {
+                    continue;
+                }
+                let now = Instant::now();
+                let repeat = last_press.is_some_and(|last| now - last < REPEAT_GAP);
+                last_press = Some(now);
+                // Receiver dropped - nobody left to tell
+                if !repeat && presses.send(()).is_err() {
+                    break 'listen;
+                }
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+        ungrab();
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod platform {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::mpsc::Sender;
+    use std::sync::Arc;
+    use std::thread::JoinHandle;
+
+    use crate::command_palette::KeyChord;
+    use crate::i18n::tr;
+
+    /// Why this platform can't hold a global shortcut
+    pub(super) fn unavailable() -> Option<&'static str> {
+        Some(tr("Global shortcuts are only available on Linux with X11"))
+    }
+
+    pub(super) fn listen(
+        _chord: KeyChord,
+        _presses: Sender<()>,
+        _stop: Arc<AtomicBool>,
+    ) -> Result<JoinHandle<()>, String> {
+        Err(tr("Global shortcuts are only available on Linux with X11").to_string())
+    }
+}
+
+/// Whether the session runs Wayland, from `WAYLAND_DISPLAY` and
+/// `XDG_SESSION_TYPE`
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn is_wayland_session(wayland_display: Option<&str>, session_type: Option<&str>) -> bool {
+    wayland_display.is_some_and(|display| !display.is_empty()) || session_type == Some("wayland")
+}
+
+/// X keysym name of a bindable key
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn keysym_name(key: VirtualKeyCode) -> Option<&'static str> {
+    use VirtualKeyCode as K;
+    const LETTERS: [&str; 26] = [
+        "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q",
+        "r", "s", "t", "u", "v", "w", "x", "y", "z",
+    ];
+    const DIGITS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+    const FUNCTION_KEYS: [&str; 12] = [
+        "F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10", "F11", "F12",
+    ];
+
+    let name = match key {
+        key if (K::A..=K::Z).contains(&key) => LETTERS[key as usize - K::A as usize],
+        key if (K::Key1..=K::Key9).contains(&key) => DIGITS[key as usize - K::Key1 as usize + 1],
+        K::Key0 => DIGITS[0],
+        key if (K::F1..=K::F12).contains(&key) => FUNCTION_KEYS[key as usize - K::F1 as usize],
+        K::Left => "Left",
+        K::Right => "Right",
+        K::Up => "Up",
+        K::Down => "Down",
+        K::Home => "Home",
+        K::End => "End",
+        K::PageUp => "Prior",
+        K::PageDown => "Next",
+        K::Insert => "Insert",
+        K::Delete => "Delete",
+        K::Return => "Return",
+        K::Back => "BackSpace",
+        K::Space => "space",
+        K::Tab => "Tab",
+        K::Slash => "slash",
+        K::Backslash => "backslash",
+        K::Equals => "equal",
+        K::Plus => "plus",
+        K::Minus => "minus",
+        K::Comma => "comma",
+        K::Period => "period",
+        K::LBracket => "bracketleft",
+        K::RBracket => "bracketright",
+        K::Apostrophe => "apostrophe",
+        K::Semicolon => "semicolon",
+        K::Grave => "grave",
+        _ => return None,
+    };
+    Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reveal(action: RevealAction) -> TimeReveal {
+        TimeReveal {
+            setting: RevealHotkey {
+                enabled: true,
+                action,
+                ..RevealHotkey::default()
+            },
+            listener: None,
+            status: None,
+            flash: None,
+            pinned: false,
+        }
+    }
+
+    #[test]
+    fn test_chord_must_be_safe_to_take_globally() {
+        let chord = |label: &str| {
+            RevealHotkey {
+                chord: label.to_string(),
+                ..RevealHotkey::default()
+            }
+            .parsed_chord()
+        };
+        assert_eq!(
+            chord("Ctrl+Alt+C").map(|c| c.label()),
+            Ok("Ctrl+Alt+C".to_string())
+        );
+        assert!(chord("Alt+Shift+T").is_ok());
+        assert!(chord("F9").is_ok());
+        assert!(chord("C").is_err());
+        assert!(chord("Shift+C").is_err());
+        assert!(chord("Ctrl+Nope").is_err());
+    }
+
+    #[test]
+    fn test_every_bindable_key_has_a_keysym() {
+        for &key in BINDABLE_KEYS {
+            assert!(keysym_name(key).is_some(), "{:?}", key);
+        }
+        assert_eq!(keysym_name(VirtualKeyCode::C), Some("c"));
+        assert_eq!(keysym_name(VirtualKeyCode::Key7), Some("7"));
+        assert_eq!(keysym_name(VirtualKeyCode::F11), Some("F11"));
+    }
+
+    #[test]
+    fn test_wayland_session_detection() {
+        assert!(is_wayland_session(Some("wayland-0"), None));
+        assert!(is_wayland_session(None, Some("wayland")));
+        assert!(!is_wayland_session(None, Some("x11")));
+        assert!(!is_wayland_session(Some(""), None));
+        assert!(!is_wayland_session(None, None));
+    }
+
+    #[test]
+    fn test_flash_ends_on_time_or_second_press() {
+        let start = Instant::now();
+        let mut flash = reveal(RevealAction::Flash);
+        assert_eq!(flash.next_change(false, start, false), None);
+        assert_eq!(flash.next_change(true, start, true), Some(WindowChange::StartFlash));
+        assert!(flash.is_flashing());
+        assert_eq!(flash.next_change(false, start + Duration::from_secs(1), false), None);
+
+        let end = start + Duration::from_secs(4);
+        let expected = Flash {
+            until: end,
+            was_minimized: true,
+        };
+        assert_eq!(
+            flash.next_change(false, end, false),
+            Some(WindowChange::EndFlash(expected))
+        );
+        assert!(!flash.is_flashing());
+
+        // Pressed again while flashing
+        flash.next_change(true, start, false);
+        assert!(matches!(
+            flash.next_change(true, start, false),
+            Some(WindowChange::EndFlash(_))
+        ));
+
+        let mut raise = reveal(RevealAction::Raise);
+        assert_eq!(raise.next_change(true, start, true), Some(WindowChange::Raise));
+        assert!(!raise.is_flashing());
+    }
+}
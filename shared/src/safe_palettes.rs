@@ -0,0 +1,384 @@
+//! Safe palettes - high-contrast and color-blind safe colors for the series
+//!
+//! Built-in palettes for the same roles a theme assigns, picked once for the
+//! whole series and saved like the language. While one is chosen it wins
+//! over each clock's own theme, so every clock's text and chrome follow it.
+//!
+//! Each palette is checked rather than eyeballed: every text role keeps a
+//! WCAG 2.x contrast ratio against the background (7:1 for high contrast,
+//! 4.5:1 otherwise), and the color-blind palettes keep accent and warning
+//! apart when simulated for the deficiency they target (Machado et al.,
+//! 2009, full severity). Text a clock keeps in a fixed color on a fixed
+//! surface is held to the high-contrast ratio instead, since no palette
+//! restyles it.
+
+use nannou_egui::egui;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{load_config, save_config, ConfigError, VersionedConfig};
+use crate::i18n::tr;
+use crate::theme::{set_override_palette, Palette, Rgb, ThemeRole};
+
+/// Config file shared by all clocks
+pub const SAFE_PALETTE_CONFIG: &str = "safe_palette";
+
+/// WCAG AAA contrast for normal text
+pub const CONTRAST_AAA: f32 = 7.0;
+
+/// WCAG AA contrast for normal text
+pub const CONTRAST_AA: f32 = 4.5;
+
+/// How far apart (in sRGB units) accent and warning must stay once simulated
+pub const MIN_SIMULATED_DISTANCE: f32 = 90.0;
+
+/// The roles drawn as text, and so held to a contrast ratio
+pub const TEXT_ROLES: [ThemeRole; 4] = [
+    ThemeRole::PrimaryText,
+    ThemeRole::SecondaryText,
+    ThemeRole::Accent,
+    ThemeRole::Warning,
+];
+
+/// White on black, with a yellow accent and a light red warning
+pub const HIGH_CONTRAST: Palette = Palette {
+    background: Rgb::new(0, 0, 0),
+    primary_text: Rgb::new(255, 255, 255),
+    secondary_text: Rgb::new(210, 210, 210),
+    accent: Rgb::new(255, 255, 0),
+    warning: Rgb::new(255, 120, 120),
+};
+
+/// Sky blue against orange: no red-green pair for deuteranopes
+const DEUTERANOPIA: Palette = Palette {
+    background: Rgb::new(18, 18, 24),
+    primary_text: Rgb::new(240, 240, 240),
+    secondary_text: Rgb::new(170, 170, 180),
+    accent: Rgb::new(86, 180, 233),
+    warning: Rgb::new(230, 159, 0),
+};
+
+/// Blue against yellow: reds read too dark for protanopes to warn with
+const PROTANOPIA: Palette = Palette {
+    background: Rgb::new(18, 18, 24),
+    primary_text: Rgb::new(240, 240, 240),
+    secondary_text: Rgb::new(170, 170, 180),
+    accent: Rgb::new(86, 180, 233),
+    warning: Rgb::new(240, 228, 66),
+};
+
+/// Cyan against vermilion: no blue-yellow pair for tritanopes
+const TRITANOPIA: Palette = Palette {
+    background: Rgb::new(18, 18, 24),
+    primary_text: Rgb::new(240, 240, 240),
+    secondary_text: Rgb::new(170, 170, 180),
+    accent: Rgb::new(0, 200, 210),
+    warning: Rgb::new(255, 110, 90),
+};
+
+/// A color vision deficiency a palette can be checked against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Deficiency {
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+}
+
+impl Deficiency {
+    /// How `color` looks with this deficiency
+    pub fn simulate(self, color: Rgb) -> Rgb {
+        let matrix = match self {
+            Deficiency::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            Deficiency::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            Deficiency::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        };
+        let linear = [color.red, color.green, color.blue].map(to_linear);
+        let [red, green, blue] = matrix.map(|row| {
+            let mixed: f32 = row.iter().zip(linear).map(|(m, c)| m * c).sum();
+            to_srgb(mixed)
+        });
+        Rgb::new(red, green, blue)
+    }
+}
+
+/// A series-wide palette choice
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SafePalette {
+    /// Each clock's own theme
+    #[default]
+    Off,
+    HighContrast,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+}
+
+impl SafePalette {
+    pub const ALL: [SafePalette; 5] = [
+        SafePalette::Off,
+        SafePalette::HighContrast,
+        SafePalette::Deuteranopia,
+        SafePalette::Protanopia,
+        SafePalette::Tritanopia,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SafePalette::Off => "Clock theme",
+            SafePalette::HighContrast => "High contrast",
+            SafePalette::Deuteranopia => "Deuteranopia",
+            SafePalette::Protanopia => "Protanopia",
+            SafePalette::Tritanopia => "Tritanopia",
+        }
+    }
+
+    /// The palette's colors, or `None` to leave each clock's theme alone
+    pub fn palette(&self) -> Option<Palette> {
+        match self {
+            SafePalette::Off => None,
+            SafePalette::HighContrast => Some(HIGH_CONTRAST),
+            SafePalette::Deuteranopia => Some(DEUTERANOPIA),
+            SafePalette::Protanopia => Some(PROTANOPIA),
+            SafePalette::Tritanopia => Some(TRITANOPIA),
+        }
+    }
+
+    /// The deficiency the palette is designed for, if any
+    pub fn deficiency(&self) -> Option<Deficiency> {
+        match self {
+            SafePalette::Deuteranopia => Some(Deficiency::Deuteranopia),
+            SafePalette::Protanopia => Some(Deficiency::Protanopia),
+            SafePalette::Tritanopia => Some(Deficiency::Tritanopia),
+            SafePalette::Off | SafePalette::HighContrast => None,
+        }
+    }
+
+    /// The contrast every text role must keep against the background
+    pub fn required_contrast(&self) -> f32 {
+        match self {
+            SafePalette::HighContrast => CONTRAST_AAA,
+            _ => CONTRAST_AA,
+        }
+    }
+}
+
+/// WCAG relative luminance in [0, 1]
+pub fn relative_luminance(color: Rgb) -> f32 {
+    let [red, green, blue] = [color.red, color.green, color.blue].map(to_linear);
+    0.2126 * red + 0.7152 * green + 0.0722 * blue
+}
+
+/// WCAG contrast ratio between two colors, from 1 (none) to 21 (black on white)
+pub fn contrast_ratio(a: Rgb, b: Rgb) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// The lowest contrast any text role in `palette` has against its background
+pub fn min_text_contrast(palette: &Palette) -> f32 {
+    TEXT_ROLES
+        .iter()
+        .map(|&role| contrast_ratio(palette.get(role), palette.background))
+        .fold(f32::INFINITY, f32::min)
+}
+
+/// Whether accent and warning stay apart for someone with `deficiency`
+pub fn keeps_roles_apart(palette: &Palette, deficiency: Deficiency) -> bool {
+    let accent = deficiency.simulate(palette.accent);
+    let warning = deficiency.simulate(palette.warning);
+    let channels = [
+        accent.red as f32 - warning.red as f32,
+        accent.green as f32 - warning.green as f32,
+        accent.blue as f32 - warning.blue as f32,
+    ];
+    channels.iter().map(|c| c * c).sum::<f32>().sqrt() >= MIN_SIMULATED_DISTANCE
+}
+
+/// Whether text in a fixed color stays readable on a fixed surface under any palette
+pub fn fixed_text_is_legible(text: Rgb, surface: Rgb) -> bool {
+    contrast_ratio(text, surface) >= CONTRAST_AAA
+}
+
+fn to_linear(channel: u8) -> f32 {
+    let c = channel as f32 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn to_srgb(linear: f32) -> u8 {
+    let linear = linear.clamp(0.0, 1.0);
+    let c = if linear <= 0.0031308 {
+        linear * 12.92
+    } else {
+        1.055 * linear.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
+
+/// The series-wide safe palette choice
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SafePaletteSetting {
+    pub palette: SafePalette,
+}
+
+impl VersionedConfig for SafePaletteSetting {
+    const VERSION: u32 = 1;
+}
+
+impl SafePaletteSetting {
+    /// Load the shared setting and put its palette in force
+    pub fn load() -> Self {
+        let setting = match load_config(SAFE_PALETTE_CONFIG) {
+            Ok(setting) => setting.unwrap_or_default(),
+            Err(e) => {
+                eprintln!("Failed to load palette setting: {}", e);
+                Self::default()
+            }
+        };
+        set_override_palette(setting.palette.palette());
+        setting
+    }
+
+    pub fn save(&self) -> Result<(), ConfigError> {
+        save_config(SAFE_PALETTE_CONFIG, self)
+    }
+}
+
+/// Settings control for the safe palette; returns whether it changed
+///
+/// A change applies at once; the caller saves it.
+pub fn safe_palette_settings(ui: &mut egui::Ui, setting: &mut SafePaletteSetting) -> bool {
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.label(tr("Colors"));
+        egui::ComboBox::from_id_source("safe_palette")
+            .selected_text(tr(setting.palette.label()))
+            .show_ui(ui, |ui| {
+                for palette in SafePalette::ALL {
+                    changed |= ui
+                        .selectable_value(&mut setting.palette, palette, tr(palette.label()))
+                        .changed();
+                }
+            });
+    })
+    .response
+    .on_hover_text(tr(
+        "High-contrast and color-blind safe colors for every clock",
+    ));
+    if let Some(palette) = setting.palette.palette() {
+        ui.label(
+            egui::RichText::new(format!(
+                "{} {:.1}:1",
+                tr("Text contrast"),
+                min_text_contrast(&palette)
+            ))
+            .small(),
+        );
+    }
+    if changed {
+        set_override_palette(setting.palette.palette());
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contrast_ratio() {
+        let black = Rgb::new(0, 0, 0);
+        let white = Rgb::new(255, 255, 255);
+        assert!((contrast_ratio(black, white) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio(white, white) - 1.0).abs() < 0.01);
+        // #767676 is the lightest gray that passes AA on white
+        assert!(contrast_ratio(Rgb::new(118, 118, 118), white) >= CONTRAST_AA);
+        assert!(contrast_ratio(Rgb::new(119, 119, 119), white) < CONTRAST_AA);
+    }
+
+    #[test]
+    fn test_fixed_text_needs_high_contrast() {
+        let surface = Rgb::new(24, 24, 28);
+        assert!(fixed_text_is_legible(Rgb::new(240, 236, 220), surface));
+        // Passes AA, but a high-contrast palette couldn't lift it any further
+        let gray = Rgb::new(140, 150, 165);
+        assert!(contrast_ratio(gray, surface) >= CONTRAST_AA);
+        assert!(!fixed_text_is_legible(gray, surface));
+    }
+
+    #[test]
+    fn test_palettes_meet_wcag() {
+        for safe in SafePalette::ALL {
+            let Some(palette) = safe.palette() else {
+                continue;
+            };
+            let contrast = min_text_contrast(&palette);
+            assert!(
+                contrast >= safe.required_contrast(),
+                "{:?} text contrast {:.2}",
+                safe,
+                contrast
+            );
+        }
+    }
+
+    #[test]
+    fn test_color_blind_palettes_survive_simulation() {
+        for safe in SafePalette::ALL {
+            let (Some(palette), Some(deficiency)) = (safe.palette(), safe.deficiency()) else {
+                continue;
+            };
+            assert!(keeps_roles_apart(&palette, deficiency), "{:?}", safe);
+            // Text stays readable as the viewer actually sees it
+            let seen = |role| deficiency.simulate(palette.get(role));
+            for role in TEXT_ROLES {
+                let contrast = contrast_ratio(seen(role), seen(ThemeRole::Background));
+                assert!(
+                    contrast >= CONTRAST_AA,
+                    "{:?} {:?} {:.2}",
+                    safe,
+                    role,
+                    contrast
+                );
+            }
+        }
+        // A red-green pair is exactly what these palettes avoid
+        let red_green = Palette {
+            accent: Rgb::new(0, 170, 0),
+            warning: Rgb::new(200, 90, 0),
+            ..DEUTERANOPIA
+        };
+        assert!(!keeps_roles_apart(&red_green, Deficiency::Deuteranopia));
+    }
+
+    #[test]
+    fn test_simulation_keeps_grays() {
+        for deficiency in [
+            Deficiency::Deuteranopia,
+            Deficiency::Protanopia,
+            Deficiency::Tritanopia,
+        ] {
+            for gray in [0, 128, 255] {
+                let seen = deficiency.simulate(Rgb::new(gray, gray, gray));
+                for channel in [seen.red, seen.green, seen.blue] {
+                    assert!((channel as i32 - gray as i32).abs() <= 2);
+                }
+            }
+        }
+    }
+}
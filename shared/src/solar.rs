@@ -0,0 +1,496 @@
+//! Solar module - sunrise, sunset, civil twilight and solar elevation
+//!
+//! Uses the NOAA solar position equations, which are accurate to about a
+//! minute for event times between the polar circles. Positions come from a
+//! configurable latitude/longitude, or from the reference city of a timezone
+//! when none is given.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// Sun altitude at sunrise/sunset: the disc's upper limb plus refraction
+pub const SUNRISE_ALTITUDE: f64 = -0.833;
+/// Sun altitude at the start of civil dawn / end of civil dusk
+pub const CIVIL_TWILIGHT_ALTITUDE: f64 = -6.0;
+
+/// Mean radius of the Earth
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// A position on Earth in degrees (north and east positive)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Coordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl Coordinates {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude: latitude.clamp(-90.0, 90.0),
+            longitude: longitude.clamp(-180.0, 180.0),
+        }
+    }
+
+    /// Great-circle distance to `other`
+    pub fn distance_km(self, other: Coordinates) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let dlat = lat2 - lat1;
+        let dlon = (other.longitude - self.longitude).to_radians();
+        let h = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * h.sqrt().min(1.0).asin()
+    }
+}
+
+/// Reference city (latitude, longitude) for common zones
+const REFERENCE_CITIES: &[(&str, f64, f64)] = &[
+    // North America
+    ("America/New_York", 40.71, -74.01),
+    ("America/Chicago", 41.88, -87.63),
+    ("America/Denver", 39.74, -104.99),
+    ("America/Phoenix", 33.45, -112.07),
+    ("America/Los_Angeles", 34.05, -118.24),
+    ("America/Anchorage", 61.22, -149.90),
+    ("Pacific/Honolulu", 21.31, -157.86),
+    ("America/Toronto", 43.65, -79.38),
+    ("America/Vancouver", 49.28, -123.12),
+    ("America/Edmonton", 53.55, -113.49),
+    ("America/Winnipeg", 49.90, -97.14),
+    ("America/Halifax", 44.65, -63.57),
+    ("America/St_Johns", 47.56, -52.71),
+    // Latin America
+    ("America/Mexico_City", 19.43, -99.13),
+    ("America/Bogota", 4.71, -74.07),
+    ("America/Caracas", 10.48, -66.90),
+    ("America/Lima", -12.05, -77.04),
+    ("America/Santiago", -33.45, -70.67),
+    ("America/Sao_Paulo", -23.55, -46.63),
+    ("America/Argentina/Buenos_Aires", -34.60, -58.38),
+    // Europe
+    ("Europe/London", 51.51, -0.13),
+    ("Europe/Dublin", 53.35, -6.26),
+    ("Europe/Lisbon", 38.72, -9.14),
+    ("Europe/Paris", 48.86, 2.35),
+    ("Europe/Berlin", 52.52, 13.40),
+    ("Europe/Madrid", 40.42, -3.70),
+    ("Europe/Rome", 41.90, 12.50),
+    ("Europe/Zurich", 47.38, 8.54),
+    ("Europe/Amsterdam", 52.37, 4.90),
+    ("Europe/Stockholm", 59.33, 18.07),
+    ("Europe/Oslo", 59.91, 10.75),
+    ("Europe/Helsinki", 60.17, 24.94),
+    ("Europe/Athens", 37.98, 23.73),
+    ("Europe/Kyiv", 50.45, 30.52),
+    ("Europe/Moscow", 55.76, 37.62),
+    ("Europe/Istanbul", 41.01, 28.98),
+    ("Atlantic/Reykjavik", 64.15, -21.94),
+    // Africa & Middle East
+    ("Africa/Cairo", 30.04, 31.24),
+    ("Africa/Lagos", 6.52, 3.38),
+    ("Africa/Kinshasa", -4.44, 15.27),
+    ("Africa/Nairobi", -1.29, 36.82),
+    ("Africa/Johannesburg", -26.20, 28.05),
+    ("Asia/Dubai", 25.20, 55.27),
+    ("Asia/Riyadh", 24.71, 46.68),
+    ("Asia/Baghdad", 33.31, 44.36),
+    ("Asia/Tehran", 35.69, 51.39),
+    ("Asia/Jerusalem", 31.77, 35.21),
+    // South & Southeast Asia
+    ("Asia/Karachi", 24.86, 67.01),
+    ("Asia/Kolkata", 22.57, 88.36),
+    ("Asia/Kathmandu", 27.72, 85.32),
+    ("Asia/Dhaka", 23.81, 90.41),
+    ("Asia/Yangon", 16.87, 96.20),
+    ("Asia/Bangkok", 13.76, 100.50),
+    ("Asia/Ho_Chi_Minh", 10.82, 106.63),
+    ("Asia/Jakarta", -6.21, 106.85),
+    ("Asia/Singapore", 1.35, 103.82),
+    ("Asia/Kuala_Lumpur", 3.139, 101.687),
+    ("Asia/Manila", 14.60, 120.98),
+    // East Asia & Oceania
+    ("Asia/Shanghai", 31.23, 121.47),
+    ("Asia/Hong_Kong", 22.32, 114.17),
+    ("Asia/Taipei", 25.03, 121.57),
+    ("Asia/Seoul", 37.57, 126.98),
+    ("Asia/Tokyo", 35.68, 139.69),
+    ("Australia/Perth", -31.95, 115.86),
+    ("Australia/Adelaide", -34.93, 138.60),
+    ("Australia/Brisbane", -27.47, 153.03),
+    ("Australia/Sydney", -33.87, 151.21),
+    ("Australia/Melbourne", -37.81, 144.96),
+    ("Pacific/Auckland", -36.85, 174.76),
+    // Universal (Greenwich)
+    ("UTC", 51.48, 0.0),
+];
+
+/// Rough latitude for zones outside the reference table, by region
+fn region_latitude(tz_name: &str) -> f64 {
+    match tz_name.split('/').next().unwrap_or("") {
+        "Europe" => 50.0,
+        "America" | "US" | "Canada" => 35.0,
+        "Asia" => 30.0,
+        "Atlantic" => 30.0,
+        "Arctic" => 78.0,
+        "Africa" => 0.0,
+        "Indian" => -10.0,
+        "Pacific" => -15.0,
+        "Australia" => -30.0,
+        "Antarctica" => -75.0,
+        _ => 0.0,
+    }
+}
+
+/// Reference coordinates for a timezone
+///
+/// Zones in the reference table use their main city. Others are
+/// approximated: longitude from the standard UTC offset (15° per hour),
+/// latitude from the zone's region.
+pub fn reference_coordinates(tz: Tz) -> Coordinates {
+    let name = tz.name();
+    if let Some((_, lat, lon)) = REFERENCE_CITIES.iter().find(|(id, _, _)| *id == name) {
+        return Coordinates::new(*lat, *lon);
+    }
+
+    // The smaller of the January and July offsets is standard time
+    let year = Utc::now().year();
+    let offset_at = |month| {
+        tz.with_ymd_and_hms(year, month, 15, 12, 0, 0)
+            .single()
+            .map(|dt| dt.offset().fix().local_minus_utc())
+            .unwrap_or(0)
+    };
+    let standard_seconds = offset_at(1).min(offset_at(7));
+    let longitude = standard_seconds as f64 / 3600.0 * 15.0;
+
+    Coordinates::new(region_latitude(name), longitude)
+}
+
+/// Where the sun is relative to the horizon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DaylightPhase {
+    /// Sun above the horizon
+    Day,
+    /// Sun less than 6° below the horizon
+    CivilTwilight,
+    /// Sun more than 6° below the horizon
+    Night,
+}
+
+impl DaylightPhase {
+    pub fn from_elevation(elevation_degrees: f64) -> Self {
+        if elevation_degrees > SUNRISE_ALTITUDE {
+            DaylightPhase::Day
+        } else if elevation_degrees > CIVIL_TWILIGHT_ALTITUDE {
+            DaylightPhase::CivilTwilight
+        } else {
+            DaylightPhase::Night
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DaylightPhase::Day => "Daylight",
+            DaylightPhase::CivilTwilight => "Civil twilight",
+            DaylightPhase::Night => "Night",
+        }
+    }
+}
+
+/// Sun events for one local calendar day
+///
+/// An event is `None` when the sun never crosses that altitude that day
+/// (polar day or polar night).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolarEvents {
+    /// Sun at its highest point
+    pub solar_noon: DateTime<Utc>,
+    /// Sun elevation at solar noon, in degrees
+    pub noon_elevation_degrees: f64,
+    pub civil_dawn: Option<DateTime<Utc>>,
+    pub sunrise: Option<DateTime<Utc>>,
+    pub sunset: Option<DateTime<Utc>>,
+    pub civil_dusk: Option<DateTime<Utc>>,
+}
+
+impl SolarEvents {
+    /// The sun never sets this day
+    pub fn is_polar_day(&self) -> bool {
+        self.sunrise.is_none() && self.noon_elevation_degrees > SUNRISE_ALTITUDE
+    }
+
+    /// The sun never rises this day
+    pub fn is_polar_night(&self) -> bool {
+        self.sunrise.is_none() && self.noon_elevation_degrees <= SUNRISE_ALTITUDE
+    }
+
+    /// Time between sunrise and sunset (a full day or nothing at the poles)
+    pub fn day_length(&self) -> Duration {
+        match (self.sunrise, self.sunset) {
+            (Some(rise), Some(set)) => set - rise,
+            _ if self.is_polar_day() => Duration::hours(24),
+            _ => Duration::zero(),
+        }
+    }
+}
+
+/// Solar state at one instant, carried in `TimeData`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolarData {
+    /// Where the sun was computed for
+    pub coordinates: Coordinates,
+    /// Sun elevation above the horizon, in degrees (negative below)
+    pub elevation_degrees: f64,
+    /// Events for the local day containing the instant
+    pub events: SolarEvents,
+}
+
+impl SolarData {
+    pub fn phase(&self) -> DaylightPhase {
+        DaylightPhase::from_elevation(self.elevation_degrees)
+    }
+
+    pub fn is_daylight(&self) -> bool {
+        self.phase() == DaylightPhase::Day
+    }
+}
+
+/// Compute the solar state at an instant
+pub fn compute_solar_data(tz: Tz, instant: DateTime<Utc>, coordinates: Coordinates) -> SolarData {
+    let local_date = instant.with_timezone(&tz).date_naive();
+    SolarData {
+        coordinates,
+        elevation_degrees: solar_elevation(instant, coordinates),
+        events: solar_events(local_date, tz, coordinates),
+    }
+}
+
+/// Sun position terms for an instant
+struct SunPosition {
+    /// Declination in radians
+    declination: f64,
+    /// Equation of time in minutes
+    equation_of_time: f64,
+}
+
+fn sun_position(instant: DateTime<Utc>) -> SunPosition {
+    let julian_day = instant.timestamp_millis() as f64 / 86_400_000.0 + 2_440_587.5;
+    let t = (julian_day - 2_451_545.0) / 36_525.0;
+
+    let mean_longitude = (280.46646 + t * (36_000.769_83 + t * 0.000_303_2)).rem_euclid(360.0);
+    let mean_anomaly = 357.52911 + t * (35_999.050_29 - 0.000_153_7 * t);
+    let eccentricity = 0.016_708_634 - t * (0.000_042_037 + 0.000_000_126_7 * t);
+
+    let m = mean_anomaly.to_radians();
+    let center = m.sin() * (1.914_602 - t * (0.004_817 + 0.000_014 * t))
+        + (2.0 * m).sin() * (0.019_993 - 0.000_101 * t)
+        + (3.0 * m).sin() * 0.000_289;
+    let true_longitude = mean_longitude + center;
+
+    let omega = (125.04 - 1_934.136 * t).to_radians();
+    let apparent_longitude = (true_longitude - 0.005_69 - 0.004_78 * omega.sin()).to_radians();
+
+    let mean_obliquity =
+        23.0 + (26.0 + (21.448 - t * (46.815 + t * (0.000_59 - t * 0.001_813))) / 60.0) / 60.0;
+    let obliquity = (mean_obliquity + 0.002_56 * omega.cos()).to_radians();
+
+    let declination = (obliquity.sin() * apparent_longitude.sin()).asin();
+
+    let y = (obliquity / 2.0).tan().powi(2);
+    let l0 = mean_longitude.to_radians();
+    let equation_of_time = 4.0
+        * (y * (2.0 * l0).sin() - 2.0 * eccentricity * m.sin()
+            + 4.0 * eccentricity * y * m.sin() * (2.0 * l0).cos()
+            - 0.5 * y * y * (4.0 * l0).sin()
+            - 1.25 * eccentricity * eccentricity * (2.0 * m).sin())
+        .to_degrees();
+
+    SunPosition {
+        declination,
+        equation_of_time,
+    }
+}
+
+/// Hour angle of the sun in degrees, in [-180, 180) (0 at solar noon)
+fn hour_angle(instant: DateTime<Utc>, longitude: f64, equation_of_time: f64) -> f64 {
+    let midnight = instant.date_naive().and_time(NaiveTime::MIN).and_utc();
+    let utc_minutes = (instant - midnight).num_milliseconds() as f64 / 60_000.0;
+    let true_solar_minutes = utc_minutes + equation_of_time + 4.0 * longitude;
+    (true_solar_minutes / 4.0).rem_euclid(360.0) - 180.0
+}
+
+/// Sun elevation above the horizon at an instant, in degrees
+///
+/// Geometric elevation; atmospheric refraction is accounted for only in the
+/// sunrise/sunset altitude.
+pub fn solar_elevation(instant: DateTime<Utc>, coordinates: Coordinates) -> f64 {
+    let sun = sun_position(instant);
+    let ha = hour_angle(instant, coordinates.longitude, sun.equation_of_time).to_radians();
+    let lat = coordinates.latitude.to_radians();
+
+    let cos_zenith = lat.sin() * sun.declination.sin() + lat.cos() * sun.declination.cos() * ha.cos();
+    90.0 - cos_zenith.clamp(-1.0, 1.0).acos().to_degrees()
+}
+
+/// Point on Earth where the sun is directly overhead at an instant
+pub fn subsolar_point(instant: DateTime<Utc>) -> Coordinates {
+    let sun = sun_position(instant);
+    // Solar noon is where the hour angle is zero
+    let longitude = -hour_angle(instant, 0.0, sun.equation_of_time);
+    let longitude = if longitude >= 180.0 { longitude - 360.0 } else { longitude };
+    Coordinates::new(sun.declination.to_degrees(), longitude)
+}
+
+/// Zones in the reference table with their city coordinates
+pub fn reference_zones() -> impl Iterator<Item = (Tz, Coordinates)> {
+    REFERENCE_CITIES.iter().filter_map(|(id, lat, lon)| {
+        id.parse::<Tz>().ok().map(|tz| (tz, Coordinates::new(*lat, *lon)))
+    })
+}
+
+/// Sun events for a local calendar date
+pub fn solar_events(local_date: NaiveDate, tz: Tz, coordinates: Coordinates) -> SolarEvents {
+    // Start from local clock noon and walk to where the hour angle is zero
+    let noon_local = local_date.and_time(NaiveTime::from_hms_opt(12, 0, 0).unwrap());
+    let mut solar_noon = tz
+        .from_local_datetime(&noon_local)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|| noon_local.and_utc());
+    for _ in 0..3 {
+        let sun = sun_position(solar_noon);
+        let ha = hour_angle(solar_noon, coordinates.longitude, sun.equation_of_time);
+        solar_noon -= minutes(ha * 4.0);
+    }
+
+    let event = |altitude: f64, rising: bool| {
+        // Estimate from the noon declination, then refine at the estimate
+        let mut estimate = solar_noon;
+        for _ in 0..2 {
+            let half_day = half_day_degrees(estimate, coordinates.latitude, altitude)?;
+            let offset = minutes(half_day * 4.0);
+            estimate = if rising {
+                solar_noon - offset
+            } else {
+                solar_noon + offset
+            };
+        }
+        Some(estimate)
+    };
+
+    SolarEvents {
+        solar_noon,
+        noon_elevation_degrees: solar_elevation(solar_noon, coordinates),
+        civil_dawn: event(CIVIL_TWILIGHT_ALTITUDE, true),
+        sunrise: event(SUNRISE_ALTITUDE, true),
+        sunset: event(SUNRISE_ALTITUDE, false),
+        civil_dusk: event(CIVIL_TWILIGHT_ALTITUDE, false),
+    }
+}
+
+/// Hour angle (degrees) at which the sun crosses `altitude`, or `None` when
+/// it stays above or below it all day
+fn half_day_degrees(instant: DateTime<Utc>, latitude: f64, altitude: f64) -> Option<f64> {
+    let declination = sun_position(instant).declination;
+    let lat = latitude.to_radians();
+    let cos_h = (altitude.to_radians().sin() - lat.sin() * declination.sin())
+        / (lat.cos() * declination.cos());
+
+    (-1.0..=1.0).contains(&cos_h).then(|| cos_h.acos().to_degrees())
+}
+
+fn minutes(value: f64) -> Duration {
+    Duration::milliseconds((value * 60_000.0).round() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(actual: DateTime<Utc>, expected: DateTime<Utc>, tolerance_minutes: i64) {
+        let diff = (actual - expected).num_minutes().abs();
+        assert!(
+            diff <= tolerance_minutes,
+            "expected {} ± {}m, got {}",
+            expected,
+            tolerance_minutes,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_london_midsummer_events() {
+        // 21 June 2025, London: sunrise 04:43 BST, sunset 21:21 BST (NOAA)
+        let tz: Tz = "Europe/London".parse().unwrap();
+        let date = NaiveDate::from_ymd_opt(2025, 6, 21).unwrap();
+        let events = solar_events(date, tz, reference_coordinates(tz));
+
+        assert_near(events.sunrise.unwrap(), Utc.with_ymd_and_hms(2025, 6, 21, 3, 43, 0).unwrap(), 3);
+        assert_near(events.sunset.unwrap(), Utc.with_ymd_and_hms(2025, 6, 21, 20, 21, 0).unwrap(), 3);
+        assert_near(events.solar_noon, Utc.with_ymd_and_hms(2025, 6, 21, 12, 2, 0).unwrap(), 2);
+        assert!(events.civil_dawn.unwrap() < events.sunrise.unwrap());
+        assert!(events.civil_dusk.unwrap() > events.sunset.unwrap());
+        assert!((events.noon_elevation_degrees - 62.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_elevation_and_phase() {
+        let nyc = Coordinates::new(40.71, -74.01);
+        let noon = Utc.with_ymd_and_hms(2025, 3, 20, 17, 7, 0).unwrap();
+        let midnight = Utc.with_ymd_and_hms(2025, 3, 21, 5, 0, 0).unwrap();
+
+        // Equinox noon: elevation ≈ 90° − latitude
+        assert!((solar_elevation(noon, nyc) - 49.3).abs() < 1.0);
+        assert_eq!(DaylightPhase::from_elevation(solar_elevation(noon, nyc)), DaylightPhase::Day);
+        assert_eq!(DaylightPhase::from_elevation(solar_elevation(midnight, nyc)), DaylightPhase::Night);
+        assert_eq!(DaylightPhase::from_elevation(-3.0), DaylightPhase::CivilTwilight);
+    }
+
+    #[test]
+    fn test_polar_day_and_night() {
+        let tz: Tz = "Europe/Oslo".parse().unwrap();
+        let svalbard = Coordinates::new(78.22, 15.65);
+
+        let summer = solar_events(NaiveDate::from_ymd_opt(2025, 6, 21).unwrap(), tz, svalbard);
+        assert!(summer.is_polar_day());
+        assert_eq!(summer.day_length(), Duration::hours(24));
+
+        let winter = solar_events(NaiveDate::from_ymd_opt(2025, 12, 21).unwrap(), tz, svalbard);
+        assert!(winter.is_polar_night());
+        assert!(winter.civil_dawn.is_none());
+        assert_eq!(winter.day_length(), Duration::zero());
+    }
+
+    #[test]
+    fn test_subsolar_point() {
+        // June solstice: overhead on the Tropic of Cancer, near Greenwich at 12:00 UTC
+        let instant = Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap();
+        let point = subsolar_point(instant);
+        assert!((point.latitude - 23.44).abs() < 0.1);
+        assert!(point.longitude.abs() < 1.0);
+        assert!(solar_elevation(instant, point) > 89.9);
+
+        // Six hours later the sun is a quarter turn further west
+        let later = subsolar_point(instant + Duration::hours(6));
+        assert!((later.longitude - -90.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_reference_coordinates() {
+        let tokyo: Tz = "Asia/Tokyo".parse().unwrap();
+        assert_eq!(reference_coordinates(tokyo), Coordinates::new(35.68, 139.69));
+
+        // Not in the table: longitude from the standard offset (UTC-3)
+        let araguaina: Tz = "America/Araguaina".parse().unwrap();
+        let approx = reference_coordinates(araguaina);
+        assert!((approx.longitude - -45.0).abs() < 1e-9);
+        assert_eq!(approx.latitude, region_latitude("America/Araguaina"));
+    }
+
+    #[test]
+    fn test_distance() {
+        let paris = Coordinates::new(48.86, 2.35);
+        let london = Coordinates::new(51.51, -0.13);
+        assert!((paris.distance_km(london) - 344.0).abs() < 5.0);
+        assert_eq!(paris.distance_km(paris), 0.0);
+    }
+}
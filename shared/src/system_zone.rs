@@ -0,0 +1,227 @@
+//! System zone module - noticing when the computer's time zone changes
+//!
+//! Travelling with a laptop, or changing the zone in system settings, moves
+//! the OS time zone out from under a running clock. `SystemZoneWatcher`
+//! checks the system zone every few seconds on a background thread and
+//! reports each change; clocks show a toast and, if the user opted in,
+//! switch to the new zone. Like quiet hours, the opt-in lives in one config
+//! file for the whole series.
+//!
+//! The zone database itself is compiled into the clocks, so an OS tzdata
+//! update doesn't change any offsets shown; only the zone name is watched.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use chrono_tz::Tz;
+use nannou_egui::egui;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{load_config, save_config, ConfigError, VersionedConfig};
+use crate::i18n::{tr, tr_args};
+use crate::time_engine::system_timezone;
+
+/// Config file shared by all clocks
+pub const SYSTEM_ZONE_CONFIG: &str = "system_zone";
+
+/// How often the watcher thread reads the system zone
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Whether clocks switch to the system zone when it changes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FollowSystemZone {
+    pub enabled: bool,
+}
+
+impl VersionedConfig for FollowSystemZone {
+    const VERSION: u32 = 1;
+}
+
+impl FollowSystemZone {
+    /// Load the shared setting, falling back to the default (off)
+    pub fn load() -> Self {
+        match load_config(SYSTEM_ZONE_CONFIG) {
+            Ok(setting) => setting.unwrap_or_default(),
+            Err(e) => {
+                eprintln!("Failed to load system zone setting: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<(), ConfigError> {
+        save_config(SYSTEM_ZONE_CONFIG, self)
+    }
+}
+
+/// The system zone changed while the clock was running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SystemZoneChange {
+    /// Zone before the change, if it could be read
+    pub previous: Option<Tz>,
+    pub current: Tz,
+}
+
+impl SystemZoneChange {
+    /// Toast text, saying whether the clock `switched` to the new zone
+    pub fn message(&self, switched: bool) -> String {
+        let change = match self.previous {
+            Some(previous) => format!("{} → {}", previous.name(), self.current.name()),
+            None => self.current.name().to_string(),
+        };
+        if switched {
+            tr_args("System time zone changed ({0}); switched", &[&change])
+        } else {
+            tr_args("System time zone changed ({0})", &[&change])
+        }
+    }
+}
+
+/// Remembers the last system zone seen and spots changes
+#[derive(Debug, Clone, Copy, Default)]
+struct ZoneTracker {
+    last: Option<Tz>,
+}
+
+impl ZoneTracker {
+    /// Record a reading; a failed read (`None`) never counts as a change
+    fn observe(&mut self, current: Option<Tz>) -> Option<SystemZoneChange> {
+        let current = current?;
+        if self.last == Some(current) {
+            return None;
+        }
+        let previous = self.last.replace(current);
+        Some(SystemZoneChange { previous, current })
+    }
+}
+
+/// Handle to a running system zone watcher thread
+///
+/// The thread is stopped when the handle is dropped.
+pub struct SystemZoneWatcher {
+    receiver: Receiver<SystemZoneChange>,
+    stop: Arc<AtomicBool>,
+}
+
+impl SystemZoneWatcher {
+    /// Start watching from the zone the system has now
+    pub fn spawn() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+
+        thread::spawn(move || {
+            let mut tracker = ZoneTracker {
+                last: system_timezone(),
+            };
+            while !thread_stop.load(Ordering::Relaxed) {
+                thread::sleep(POLL_INTERVAL);
+                if let Some(change) = tracker.observe(system_timezone()) {
+                    // Receiver dropped - nobody left to tell
+                    if sender.send(change).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        Self { receiver, stop }
+    }
+
+    /// Drain pending changes, merging several into one from the first zone
+    /// to the latest (or none, if the zone ended up where it started)
+    pub fn poll(&self) -> Option<SystemZoneChange> {
+        let mut merged: Option<SystemZoneChange> = None;
+        while let Ok(change) = self.receiver.try_recv() {
+            merged = Some(SystemZoneChange {
+                previous: merged.map_or(change.previous, |m| m.previous),
+                current: change.current,
+            });
+        }
+        merged.filter(|change| change.previous != Some(change.current))
+    }
+}
+
+impl Drop for SystemZoneWatcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Settings control for following the system zone; returns whether it changed
+pub fn system_zone_settings(ui: &mut egui::Ui, setting: &mut FollowSystemZone) -> bool {
+    ui.checkbox(&mut setting.enabled, tr("Follow system time zone"))
+        .on_hover_text(tr("Switch to the computer's zone when it changes, e.g. after travel"))
+        .changed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tz(name: &str) -> Tz {
+        name.parse().unwrap()
+    }
+
+    #[test]
+    fn test_tracker_reports_only_changes() {
+        let mut tracker = ZoneTracker {
+            last: Some(tz("America/Los_Angeles")),
+        };
+        assert_eq!(tracker.observe(Some(tz("America/Los_Angeles"))), None);
+        // A failed read isn't a change, and doesn't forget the zone
+        assert_eq!(tracker.observe(None), None);
+
+        let change = tracker.observe(Some(tz("Europe/Paris"))).unwrap();
+        assert_eq!(change.previous, Some(tz("America/Los_Angeles")));
+        assert_eq!(change.current, tz("Europe/Paris"));
+        assert_eq!(tracker.observe(Some(tz("Europe/Paris"))), None);
+    }
+
+    #[test]
+    fn test_poll_merges_queued_changes() {
+        let (sender, receiver) = mpsc::channel();
+        let watcher = SystemZoneWatcher {
+            receiver,
+            stop: Arc::new(AtomicBool::new(false)),
+        };
+        let la = tz("America/Los_Angeles");
+        let paris = tz("Europe/Paris");
+        let tokyo = tz("Asia/Tokyo");
+
+        let hop = |previous, current| SystemZoneChange {
+            previous: Some(previous),
+            current,
+        };
+        sender.send(hop(la, paris)).unwrap();
+        sender.send(hop(paris, tokyo)).unwrap();
+        assert_eq!(watcher.poll(), Some(hop(la, tokyo)));
+        assert_eq!(watcher.poll(), None);
+
+        // There and back again before the clock looked
+        sender.send(hop(la, paris)).unwrap();
+        sender.send(hop(paris, la)).unwrap();
+        assert_eq!(watcher.poll(), None);
+    }
+
+    #[test]
+    fn test_change_message() {
+        let change = SystemZoneChange {
+            previous: Some(tz("America/Los_Angeles")),
+            current: tz("Europe/Paris"),
+        };
+        assert_eq!(
+            change.message(true),
+            "System time zone changed (America/Los_Angeles → Europe/Paris); switched"
+        );
+        let first = SystemZoneChange {
+            previous: None,
+            ..change
+        };
+        assert_eq!(first.message(false), "System time zone changed (Europe/Paris)");
+    }
+}
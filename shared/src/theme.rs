@@ -0,0 +1,346 @@
+//! Theme module - named color palettes shared by every clock
+//!
+//! A palette only describes a handful of roles (background, primary and
+//! secondary text, accent, warning). Each clock decides which of its own
+//! colors play each role, so one palette restyles the whole series while
+//! every clock keeps its decorative colors.
+//!
+//! Besides the built-in palettes, users can define their own in
+//! `themes.toml` in the config directory:
+//!
+//! ```toml
+//! [[theme]]
+//! name = "paper"
+//! background = "#f4f1ea"
+//! primary_text = "#1e1e24"
+//! secondary_text = "#6b6b75"
+//! accent = "#0069a8"
+//! warning = "#c05a00"
+//! ```
+//!
+//! A user theme with the same name as a built-in one replaces it. With no
+//! theme selected, each clock draws in its own native colors. A series-wide
+//! safe palette (see `safe_palettes`) overrides whichever theme is selected.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use crate::config::{config_dir, ConfigError};
+use crate::safe_palettes::HIGH_CONTRAST;
+
+/// An 8-bit sRGB color, written as `"#rrggbb"` in theme files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Rgb {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl Rgb {
+    pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue }
+    }
+
+    /// Parse `#rrggbb` (the leading `#` is optional)
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if digits.len() != 6 || !digits.is_ascii() {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).ok();
+        Some(Self::new(channel(0)?, channel(2)?, channel(4)?))
+    }
+
+    pub fn to_hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+
+    /// Relative luminance in [0, 1] (Rec. 709 weights, gamma ignored)
+    pub fn luminance(self) -> f32 {
+        (0.2126 * self.red as f32 + 0.7152 * self.green as f32 + 0.0722 * self.blue as f32)
+            / 255.0
+    }
+}
+
+impl TryFrom<String> for Rgb {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Rgb::from_hex(&value).ok_or_else(|| format!("invalid color {:?}, expected #rrggbb", value))
+    }
+}
+
+impl From<Rgb> for String {
+    fn from(color: Rgb) -> Self {
+        color.to_hex()
+    }
+}
+
+/// A color role a palette assigns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeRole {
+    Background,
+    PrimaryText,
+    SecondaryText,
+    Accent,
+    Warning,
+}
+
+/// Colors for each theme role
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Palette {
+    pub background: Rgb,
+    pub primary_text: Rgb,
+    pub secondary_text: Rgb,
+    pub accent: Rgb,
+    pub warning: Rgb,
+}
+
+impl Palette {
+    /// The color this palette gives a role
+    pub fn get(&self, role: ThemeRole) -> Rgb {
+        match role {
+            ThemeRole::Background => self.background,
+            ThemeRole::PrimaryText => self.primary_text,
+            ThemeRole::SecondaryText => self.secondary_text,
+            ThemeRole::Accent => self.accent,
+            ThemeRole::Warning => self.warning,
+        }
+    }
+
+    /// Whether the palette has a light background (UI chrome should be light)
+    pub fn is_light(&self) -> bool {
+        self.background.luminance() > 0.5
+    }
+}
+
+/// A named palette
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Theme {
+    pub name: String,
+    #[serde(flatten)]
+    pub palette: Palette,
+}
+
+impl Theme {
+    fn builtin(name: &str, palette: Palette) -> Self {
+        Self {
+            name: name.to_string(),
+            palette,
+        }
+    }
+}
+
+/// The palettes that ship with the series
+pub fn builtin_themes() -> Vec<Theme> {
+    vec![
+        Theme::builtin(
+            "dark",
+            Palette {
+                background: Rgb::new(22, 24, 29),
+                primary_text: Rgb::new(235, 235, 240),
+                secondary_text: Rgb::new(140, 145, 155),
+                accent: Rgb::new(0, 212, 255),
+                warning: Rgb::new(255, 179, 71),
+            },
+        ),
+        Theme::builtin(
+            "light",
+            Palette {
+                background: Rgb::new(244, 241, 234),
+                primary_text: Rgb::new(30, 30, 36),
+                secondary_text: Rgb::new(107, 107, 117),
+                accent: Rgb::new(0, 105, 168),
+                warning: Rgb::new(192, 90, 0),
+            },
+        ),
+        Theme::builtin("high-contrast", HIGH_CONTRAST),
+        Theme::builtin(
+            "solarized",
+            Palette {
+                background: Rgb::new(0, 43, 54),
+                primary_text: Rgb::new(238, 232, 213),
+                secondary_text: Rgb::new(131, 148, 150),
+                accent: Rgb::new(38, 139, 210),
+                warning: Rgb::new(203, 75, 22),
+            },
+        ),
+    ]
+}
+
+/// On-disk layout of `themes.toml`
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ThemeFile {
+    #[serde(default)]
+    theme: Vec<Theme>,
+}
+
+/// Parse the contents of a themes file
+pub fn parse_themes(contents: &str) -> Result<Vec<Theme>, ConfigError> {
+    let file: ThemeFile = toml::from_str(contents)?;
+    Ok(file.theme)
+}
+
+/// Get the path of the user's themes file
+pub fn themes_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("themes.toml"))
+}
+
+/// Load the user's themes
+///
+/// Returns an empty list if the themes file doesn't exist.
+pub fn load_user_themes() -> Result<Vec<Theme>, ConfigError> {
+    let path = themes_path().ok_or(ConfigError::NoConfigDir)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    parse_themes(&fs::read_to_string(&path)?)
+}
+
+/// Every theme a clock can offer: built-ins followed by the user's own
+#[derive(Debug, Clone)]
+pub struct ThemeLibrary {
+    pub themes: Vec<Theme>,
+    /// Why the user's themes file couldn't be read, if it couldn't
+    pub error: Option<String>,
+}
+
+impl ThemeLibrary {
+    /// Built-in themes plus the user's themes file
+    pub fn load() -> Self {
+        match load_user_themes() {
+            Ok(user) => Self::with_user_themes(user),
+            Err(e) => Self {
+                themes: builtin_themes(),
+                error: Some(format!("themes.toml: {}", e)),
+            },
+        }
+    }
+
+    /// Built-in themes merged with `user`; a user theme replaces a built-in
+    /// of the same name
+    pub fn with_user_themes(user: Vec<Theme>) -> Self {
+        let mut themes = builtin_themes();
+        for theme in user {
+            match themes.iter_mut().find(|t| t.name == theme.name) {
+                Some(existing) => *existing = theme,
+                None => themes.push(theme),
+            }
+        }
+        Self {
+            themes,
+            error: None,
+        }
+    }
+
+    pub fn find(&self, name: &str) -> Option<&Theme> {
+        self.themes.iter().find(|theme| theme.name == name)
+    }
+
+    /// Make the named theme active, or the clock's native colors for `None`
+    ///
+    /// Returns the name actually activated: an unknown name falls back to
+    /// native colors and returns `None`.
+    pub fn activate(&self, name: Option<&str>) -> Option<String> {
+        let theme = name.and_then(|name| self.find(name));
+        set_active_palette(theme.map(|theme| theme.palette));
+        theme.map(|theme| theme.name.clone())
+    }
+}
+
+static ACTIVE_PALETTE: RwLock<Option<Palette>> = RwLock::new(None);
+static OVERRIDE_PALETTE: RwLock<Option<Palette>> = RwLock::new(None);
+
+/// Set the palette every `themed` lookup uses; `None` restores native colors
+pub fn set_active_palette(palette: Option<Palette>) {
+    if let Ok(mut active) = ACTIVE_PALETTE.write() {
+        *active = palette;
+    }
+}
+
+/// Set a palette that wins over the selected theme; `None` lifts it
+pub fn set_override_palette(palette: Option<Palette>) {
+    if let Ok(mut active) = OVERRIDE_PALETTE.write() {
+        *active = palette;
+    }
+}
+
+/// The palette in force: the override if one is set, else the selected theme's
+pub fn active_palette() -> Option<Palette> {
+    let read = |lock: &RwLock<Option<Palette>>| lock.read().ok().and_then(|palette| *palette);
+    read(&OVERRIDE_PALETTE).or_else(|| read(&ACTIVE_PALETTE))
+}
+
+/// The active palette's color for `role`, or `native` when no theme is selected
+pub fn themed(role: ThemeRole, native: Rgb) -> Rgb {
+    active_palette().map_or(native, |palette| palette.get(role))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rgb_hex() {
+        assert_eq!(Rgb::from_hex("#00d4ff"), Some(Rgb::new(0, 212, 255)));
+        assert_eq!(Rgb::from_hex("FFB347"), Some(Rgb::new(255, 179, 71)));
+        assert_eq!(Rgb::from_hex("#12345"), None);
+        assert_eq!(Rgb::from_hex("#gg0000"), None);
+        assert_eq!(Rgb::new(0, 43, 54).to_hex(), "#002b36");
+    }
+
+    #[test]
+    fn test_builtin_themes() {
+        let themes = builtin_themes();
+        let names: Vec<&str> = themes.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["dark", "light", "high-contrast", "solarized"]);
+
+        let light = themes.iter().find(|t| t.name == "light").unwrap();
+        assert!(light.palette.is_light());
+        assert!(themes.iter().filter(|t| t.name != "light").all(|t| !t.palette.is_light()));
+    }
+
+    #[test]
+    fn test_parse_themes() {
+        let contents = r##"
+            [[theme]]
+            name = "paper"
+            background = "#f4f1ea"
+            primary_text = "#1e1e24"
+            secondary_text = "#6b6b75"
+            accent = "#0069a8"
+            warning = "#c05a00"
+
+            [[theme]]
+            name = "dark"
+            background = "#000000"
+            primary_text = "#ffffff"
+            secondary_text = "#888888"
+            accent = "#ff00ff"
+            warning = "#ff0000"
+        "##;
+        let user = parse_themes(contents).unwrap();
+        assert_eq!(user.len(), 2);
+        assert_eq!(user[0].palette.accent, Rgb::new(0, 105, 168));
+
+        // "dark" replaces the built-in in place, "paper" is appended
+        let library = ThemeLibrary::with_user_themes(user);
+        assert_eq!(library.themes.len(), 5);
+        assert_eq!(library.themes[0].name, "dark");
+        assert_eq!(library.themes[0].palette.accent, Rgb::new(255, 0, 255));
+        assert_eq!(library.themes[4].name, "paper");
+
+        assert!(parse_themes("").unwrap().is_empty());
+        assert!(parse_themes("[[theme]]\nname = \"x\"\nbackground = \"red\"").is_err());
+    }
+
+    #[test]
+    fn test_palette_roles() {
+        let palette = builtin_themes()[3].palette;
+        assert_eq!(palette.get(ThemeRole::Background), palette.background);
+        assert_eq!(palette.get(ThemeRole::Warning), palette.warning);
+    }
+}
@@ -0,0 +1,134 @@
+//! Wall-clock ticker
+//!
+//! Runs a background thread that sleeps until the next second (or minute)
+//! boundary and sends a tick over a channel. Clocks poll the ticker each frame
+//! and only recompute time data when a tick has arrived, instead of calling
+//! `compute_time_data()` at the full frame rate.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, Timelike, Utc};
+
+/// How often the ticker fires
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TickGranularity {
+    /// Fire on every whole second
+    #[default]
+    Second,
+    /// Fire on every whole minute
+    Minute,
+}
+
+/// A single tick sent by the ticker thread
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockTick {
+    /// The boundary instant this tick represents (UTC, whole second)
+    pub instant: DateTime<Utc>,
+    /// Whether this tick lands on a minute boundary
+    pub is_minute_boundary: bool,
+}
+
+/// Handle to a running ticker thread
+///
+/// The thread is stopped when the handle is dropped.
+pub struct Ticker {
+    receiver: Receiver<ClockTick>,
+    stop: Arc<AtomicBool>,
+}
+
+impl Ticker {
+    /// Spawn a ticker thread with the given granularity
+    pub fn spawn(granularity: TickGranularity) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+
+        thread::spawn(move || {
+            while !thread_stop.load(Ordering::Relaxed) {
+                let now = Utc::now();
+                thread::sleep(duration_until_next_boundary(now, granularity));
+
+                let instant = truncate_to_second(Utc::now());
+                let tick = ClockTick {
+                    instant,
+                    is_minute_boundary: instant.second() == 0,
+                };
+
+                // Receiver dropped - nothing left to tick for
+                if sender.send(tick).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self { receiver, stop }
+    }
+
+    /// Drain pending ticks, returning the most recent one (if any)
+    ///
+    /// Multiple ticks can queue up while the window is occluded or the
+    /// event loop is stalled; only the latest matters for rendering, but
+    /// the minute flag is preserved if any drained tick crossed a minute.
+    pub fn poll(&self) -> Option<ClockTick> {
+        let mut latest: Option<ClockTick> = None;
+        while let Ok(tick) = self.receiver.try_recv() {
+            let crossed_minute = latest.map(|t| t.is_minute_boundary).unwrap_or(false);
+            latest = Some(ClockTick {
+                is_minute_boundary: tick.is_minute_boundary || crossed_minute,
+                ..tick
+            });
+        }
+        latest
+    }
+}
+
+impl Drop for Ticker {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Time remaining until the next boundary of the given granularity
+pub fn duration_until_next_boundary(now: DateTime<Utc>, granularity: TickGranularity) -> StdDuration {
+    let nanos_into_second = now.nanosecond().min(999_999_999) as u64;
+    let nanos_to_second = 1_000_000_000 - nanos_into_second;
+
+    match granularity {
+        TickGranularity::Second => StdDuration::from_nanos(nanos_to_second),
+        TickGranularity::Minute => {
+            let whole_seconds_left = 59 - now.second() as u64;
+            StdDuration::from_nanos(nanos_to_second + whole_seconds_left * 1_000_000_000)
+        }
+    }
+}
+
+/// Drop the sub-second part of an instant
+fn truncate_to_second(instant: DateTime<Utc>) -> DateTime<Utc> {
+    instant.with_nanosecond(0).unwrap_or(instant)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_duration_until_next_second() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 9, 12, 0, 30).unwrap()
+            + chrono::Duration::milliseconds(250);
+        let wait = duration_until_next_boundary(now, TickGranularity::Second);
+        assert_eq!(wait.as_millis(), 750);
+    }
+
+    #[test]
+    fn test_duration_until_next_minute() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 9, 12, 0, 30).unwrap()
+            + chrono::Duration::milliseconds(250);
+        let wait = duration_until_next_boundary(now, TickGranularity::Minute);
+        assert_eq!(wait.as_millis(), 29_750);
+    }
+}
@@ -2,10 +2,14 @@
 //!
 //! Provides timezone-aware time data, DST detection, and transition warnings.
 
-use chrono::{DateTime, Datelike, Duration, Local, Offset, TimeZone, Timelike, Utc, Weekday};
-use chrono_tz::Tz;
+use chrono::{DateTime, Datelike, Duration, Offset, TimeZone, Timelike, Utc, Weekday};
+use chrono_tz::{OffsetComponents, Tz};
 use serde::{Deserialize, Serialize};
 
+use crate::i18n::language;
+use crate::leap_seconds::{leap_second_status, tai_minus_utc, LeapSecondStatus};
+use crate::solar::{compute_solar_data, reference_coordinates, Coordinates, SolarData};
+
 /// AM/PM indicator
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Meridiem {
@@ -13,15 +17,23 @@ pub enum Meridiem {
     PM,
 }
 
-impl std::fmt::Display for Meridiem {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl Meridiem {
+    /// "AM" or "PM" whatever the language, for records and exports
+    pub fn as_str(&self) -> &'static str {
         match self {
-            Meridiem::AM => write!(f, "AM"),
-            Meridiem::PM => write!(f, "PM"),
+            Meridiem::AM => "AM",
+            Meridiem::PM => "PM",
         }
     }
 }
 
+/// Shown in the current language ("AM", "a. m.", "午前", ...)
+impl std::fmt::Display for Meridiem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(language().meridiem(*self))
+    }
+}
+
 /// DST transition information
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DstChange {
@@ -73,7 +85,7 @@ pub struct TimeData {
     pub hour24: u32,
     /// Minute (0-59)
     pub minute: u32,
-    /// Second (0-59)
+    /// Second (0-59); during a leap second this stays 59, see `leap_second`
     pub second: u32,
     /// Fractional seconds (0.0-1.0) for smooth animations
     pub second_fraction: f64,
@@ -85,12 +97,18 @@ pub struct TimeData {
     pub is_dst: bool,
     /// DST transition information
     pub dst_change: DstChange,
+    /// TAI−UTC in seconds
+    pub tai_minus_utc: i32,
+    /// Leap second upcoming, in progress or just inserted
+    pub leap_second: LeapSecondStatus,
     /// Time zone abbreviation (e.g., "PST", "PDT")
     pub tz_abbrev: String,
     /// Validity of the time zone data
     pub validity: Validity,
     /// The raw DateTime for additional formatting needs
     pub local_datetime: DateTime<Tz>,
+    /// Sun elevation and the day's sunrise/sunset/twilight
+    pub solar: SolarData,
 }
 
 impl TimeData {
@@ -102,30 +120,10 @@ impl TimeData {
         )
     }
 
-    /// Format the date as "Weekday, Month Day, Year"
+    /// Format the date as "Weekday, Month Day, Year", or the current
+    /// language's equivalent
     pub fn format_date(&self) -> String {
-        let month_name = match self.month {
-            1 => "January",
-            2 => "February",
-            3 => "March",
-            4 => "April",
-            5 => "May",
-            6 => "June",
-            7 => "July",
-            8 => "August",
-            9 => "September",
-            10 => "October",
-            11 => "November",
-            12 => "December",
-            _ => "Unknown",
-        };
-        format!(
-            "{}, {} {}, {}",
-            self.weekday,
-            month_name,
-            self.day,
-            self.year
-        )
+        language().long_date(self.weekday, self.day, self.month, self.year)
     }
 
     /// Format the UTC offset as "UTC±hh:mm"
@@ -137,6 +135,25 @@ impl TimeData {
         format!("UTC{}{:02}:{:02}", sign, hours, mins)
     }
 
+    /// Refresh `second_fraction` from the wall clock without recomputing the rest
+    ///
+    /// Used between ticker updates to keep smooth animations moving. The value
+    /// is clamped just below 1.0 so an animation never runs ahead of the
+    /// displayed second while the next tick is in flight.
+    pub fn refresh_second_fraction(&mut self, now_utc: DateTime<Utc>) {
+        let base_millis = self.local_datetime.timestamp() * 1000;
+        let elapsed_millis = now_utc.timestamp_millis() - base_millis;
+        self.second_fraction = (elapsed_millis as f64 / 1000.0).clamp(0.0, 0.999);
+    }
+
+    /// Second as a clock should read it: 60 during a leap second
+    pub fn display_second(&self) -> u32 {
+        match self.leap_second {
+            LeapSecondStatus::InProgress => 60,
+            _ => self.second,
+        }
+    }
+
     /// Get an accessible description of the time
     pub fn accessible_description(&self) -> String {
         format!(
@@ -151,13 +168,26 @@ impl TimeData {
 }
 
 /// Compute the current time data for a given timezone
+///
+/// "Current" honours a snapshot's pinned `--at` instant.
 pub fn compute_time_data(tz: Tz) -> TimeData {
-    let now_utc = Utc::now();
+    let now_utc = crate::cli::now_utc();
     compute_time_data_at(tz, now_utc)
 }
 
 /// Compute time data for a given timezone at a specific instant
+///
+/// Solar data uses the timezone's reference city.
 pub fn compute_time_data_at(tz: Tz, now_utc: DateTime<Utc>) -> TimeData {
+    compute_time_data_at_location(tz, now_utc, reference_coordinates(tz))
+}
+
+/// Compute time data at a specific instant, with solar data for `location`
+pub fn compute_time_data_at_location(
+    tz: Tz,
+    now_utc: DateTime<Utc>,
+    location: Coordinates,
+) -> TimeData {
     let local = now_utc.with_timezone(&tz);
     
     // Calculate 12-hour format
@@ -169,8 +199,9 @@ pub fn compute_time_data_at(tz: Tz, now_utc: DateTime<Utc>) -> TimeData {
     };
     let meridiem = if hour24 < 12 { Meridiem::AM } else { Meridiem::PM };
     
-    // Calculate fractional seconds for smooth animations
-    let nanos = local.nanosecond();
+    // Calculate fractional seconds for smooth animations; a leap second
+    // carries an extra second in its nanoseconds
+    let nanos = local.nanosecond() % 1_000_000_000;
     let second_fraction = nanos as f64 / 1_000_000_000.0;
     
     // Get UTC offset in minutes
@@ -197,9 +228,12 @@ pub fn compute_time_data_at(tz: Tz, now_utc: DateTime<Utc>) -> TimeData {
         utc_offset_minutes,
         is_dst,
         dst_change,
+        tai_minus_utc: tai_minus_utc(now_utc),
+        leap_second: leap_second_status(now_utc),
         tz_abbrev,
         validity: Validity::Ok,
         local_datetime: local,
+        solar: compute_solar_data(tz, now_utc, location),
     }
 }
 
@@ -290,14 +324,12 @@ fn find_transition_time(
 }
 
 /// Get the system's local timezone as a chrono-tz Tz
+///
+/// Reads the IANA name the OS is configured with (the `TZ` variable, or
+/// `/etc/localtime` and the platform equivalents), so it follows changes
+/// made while a clock is running.
 pub fn system_timezone() -> Option<Tz> {
-    // Try to get the system timezone from the Local type
-    let local_now = Local::now();
-    let tz_name = local_now.format("%Z").to_string();
-    
-    // Try to parse as IANA timezone - this often doesn't work directly
-    // Fall back to checking common patterns
-    tz_name.parse::<Tz>().ok()
+    iana_time_zone::get_timezone().ok()?.parse::<Tz>().ok()
 }
 
 /// Parse a timezone string into a Tz, with fallback
@@ -312,14 +344,33 @@ pub fn all_timezones() -> Vec<Tz> {
     chrono_tz::TZ_VARIANTS.to_vec()
 }
 
-/// Search timezones by name (case-insensitive partial match)
-pub fn search_timezones(query: &str) -> Vec<Tz> {
-    let query_lower = query.to_lowercase();
-    chrono_tz::TZ_VARIANTS
-        .iter()
-        .filter(|tz| tz.name().to_lowercase().contains(&query_lower))
-        .copied()
-        .collect()
+/// The offset in force on one side of a transition
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffsetState {
+    /// Standard-time offset from UTC, in minutes
+    pub standard_minutes: i32,
+    /// Daylight saving on top of standard time, in minutes (0 outside DST)
+    pub saving_minutes: i32,
+    /// Zone abbreviation (e.g., "PST", "PDT", "+0630", or "LMT" before standard time)
+    pub abbreviation: String,
+}
+
+impl OffsetState {
+    /// The offset in force in `tz` at `instant`
+    pub fn at(tz: Tz, instant: DateTime<Utc>) -> Self {
+        let local = instant.with_timezone(&tz);
+        let offset = *local.offset();
+        Self {
+            standard_minutes: offset.base_utc_offset().num_minutes() as i32,
+            saving_minutes: offset.dst_offset().num_minutes() as i32,
+            abbreviation: local.format("%Z").to_string(),
+        }
+    }
+
+    /// Whether daylight saving is in effect
+    pub fn is_dst(&self) -> bool {
+        self.saving_minutes != 0
+    }
 }
 
 /// A DST transition with detailed information for ribbon visualization
@@ -333,6 +384,10 @@ pub struct DstTransition {
     pub local_wall_time_before: String,
     /// Local wall time just after the transition
     pub local_wall_time_after: String,
+    /// Offset in force just before the transition
+    pub before: OffsetState,
+    /// Offset in force from the transition on
+    pub after: OffsetState,
 }
 
 /// Query DST transitions within a time range around a center instant
@@ -340,11 +395,23 @@ pub struct DstTransition {
 /// Returns all DST transitions that occur within `±range_days` of `center`.
 /// Useful for ribbon visualizations that need to show DST seams.
 pub fn query_dst_transitions(tz: Tz, center: DateTime<Utc>, range_days: i64) -> Vec<DstTransition> {
-    let mut transitions = Vec::new();
     let range_duration = Duration::days(range_days);
-    let start = center - range_duration;
-    let end = center + range_duration;
-    
+    query_transitions_between(tz, center - range_duration, center + range_duration)
+}
+
+/// Query every offset change between two instants, in the order walked
+///
+/// Walking backward (`from` after `to`) returns the newest transition first,
+/// so callers looking for the previous change in the past can take the
+/// first one. Any range works, including years before standard time.
+pub fn query_transitions_between(
+    tz: Tz,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<DstTransition> {
+    let (start, end) = if from <= to { (from, to) } else { (to, from) };
+    let mut transitions = Vec::new();
+
     // Sample at hourly intervals to find offset changes
     let mut current = start;
     let mut prev_offset = current.with_timezone(&tz).offset().fix().local_minus_utc();
@@ -370,6 +437,8 @@ pub fn query_dst_transitions(tz: Tz, center: DateTime<Utc>, range_days: i64) ->
                 delta_minutes,
                 local_wall_time_before: before_local.format("%Y-%m-%d %H:%M:%S").to_string(),
                 local_wall_time_after: after_local.format("%Y-%m-%d %H:%M:%S").to_string(),
+                before: OffsetState::at(tz, before_instant),
+                after: OffsetState::at(tz, transition_instant),
             });
             
             prev_offset = next_offset;
@@ -377,7 +446,10 @@ pub fn query_dst_transitions(tz: Tz, center: DateTime<Utc>, range_days: i64) ->
         
         current = next;
     }
-    
+
+    if from > to {
+        transitions.reverse();
+    }
     transitions
 }
 
@@ -403,10 +475,17 @@ mod tests {
     }
 
     #[test]
-    fn test_search_timezones() {
-        let results = search_timezones("New_York");
-        assert!(!results.is_empty());
-        assert!(results.iter().any(|tz| tz.name() == "America/New_York"));
+    fn test_refresh_second_fraction() {
+        let tz: Tz = "Europe/London".parse().unwrap();
+        let instant = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+        let mut data = compute_time_data_at(tz, instant);
+
+        data.refresh_second_fraction(instant + Duration::milliseconds(400));
+        assert!((data.second_fraction - 0.4).abs() < 1e-9);
+
+        // Never runs past the displayed second while waiting for a tick
+        data.refresh_second_fraction(instant + Duration::milliseconds(1500));
+        assert!(data.second_fraction < 1.0);
     }
 }
 
@@ -0,0 +1,394 @@
+//! Timezone search module
+//!
+//! Fuzzy, ranked search over IANA timezone IDs. Queries are matched against
+//! the full ID, the city part of the ID, an alias table of common names and
+//! abbreviations ("NYC", "CET", "Bombay"), and the cities and countries of
+//! the world cities dataset. Results are ranked by match quality, then by
+//! the population of the zone's cities.
+
+use std::collections::HashMap;
+
+use chrono_tz::Tz;
+
+use crate::cities::{fold_accents, zone_population, CITIES};
+
+/// Alias → IANA ID. An alias may appear more than once when it covers
+/// several zones (e.g. abbreviations shared by a region).
+const ALIASES: &[(&str, &str)] = &[
+    // North America
+    ("NYC", "America/New_York"),
+    ("New York City", "America/New_York"),
+    ("Boston", "America/New_York"),
+    ("Washington DC", "America/New_York"),
+    ("Miami", "America/New_York"),
+    ("Atlanta", "America/New_York"),
+    ("Philadelphia", "America/New_York"),
+    ("EST", "America/New_York"),
+    ("EDT", "America/New_York"),
+    ("Eastern", "America/New_York"),
+    ("Chicago", "America/Chicago"),
+    ("Dallas", "America/Chicago"),
+    ("Houston", "America/Chicago"),
+    ("CST", "America/Chicago"),
+    ("CDT", "America/Chicago"),
+    ("Central", "America/Chicago"),
+    ("Denver", "America/Denver"),
+    ("MST", "America/Denver"),
+    ("MDT", "America/Denver"),
+    ("MST", "America/Phoenix"),
+    ("Mountain", "America/Denver"),
+    ("LA", "America/Los_Angeles"),
+    ("San Francisco", "America/Los_Angeles"),
+    ("SF", "America/Los_Angeles"),
+    ("Seattle", "America/Los_Angeles"),
+    ("San Diego", "America/Los_Angeles"),
+    ("Las Vegas", "America/Los_Angeles"),
+    ("PST", "America/Los_Angeles"),
+    ("PDT", "America/Los_Angeles"),
+    ("Pacific", "America/Los_Angeles"),
+    ("AKST", "America/Anchorage"),
+    ("HST", "Pacific/Honolulu"),
+    ("Hawaii", "Pacific/Honolulu"),
+    ("Montreal", "America/Toronto"),
+    ("Ottawa", "America/Toronto"),
+    ("Calgary", "America/Edmonton"),
+    ("NST", "America/St_Johns"),
+    ("Newfoundland", "America/St_Johns"),
+    ("AST", "America/Halifax"),
+    // Latin America
+    ("CDMX", "America/Mexico_City"),
+    ("Rio", "America/Sao_Paulo"),
+    ("Rio de Janeiro", "America/Sao_Paulo"),
+    ("Brasilia", "America/Sao_Paulo"),
+    ("BRT", "America/Sao_Paulo"),
+    ("ART", "America/Argentina/Buenos_Aires"),
+    // Europe
+    ("UK", "Europe/London"),
+    ("GMT", "Europe/London"),
+    ("BST", "Europe/London"),
+    ("Manchester", "Europe/London"),
+    ("Edinburgh", "Europe/London"),
+    ("IST", "Europe/Dublin"),
+    ("WET", "Europe/Lisbon"),
+    ("CET", "Europe/Paris"),
+    ("CET", "Europe/Berlin"),
+    ("CEST", "Europe/Paris"),
+    ("CEST", "Europe/Berlin"),
+    ("Frankfurt", "Europe/Berlin"),
+    ("Munich", "Europe/Berlin"),
+    ("Hamburg", "Europe/Berlin"),
+    ("Milan", "Europe/Rome"),
+    ("Barcelona", "Europe/Madrid"),
+    ("Geneva", "Europe/Zurich"),
+    ("EET", "Europe/Athens"),
+    ("EET", "Europe/Helsinki"),
+    ("EEST", "Europe/Athens"),
+    ("Kiev", "Europe/Kyiv"),
+    ("Saint Petersburg", "Europe/Moscow"),
+    ("MSK", "Europe/Moscow"),
+    ("Constantinople", "Europe/Istanbul"),
+    // Africa & Middle East
+    ("Johannesburg", "Africa/Johannesburg"),
+    ("Cape Town", "Africa/Johannesburg"),
+    ("SAST", "Africa/Johannesburg"),
+    ("Lagos", "Africa/Lagos"),
+    ("WAT", "Africa/Lagos"),
+    ("EAT", "Africa/Nairobi"),
+    ("Abu Dhabi", "Asia/Dubai"),
+    ("GST", "Asia/Dubai"),
+    ("Tel Aviv", "Asia/Jerusalem"),
+    ("Persia", "Asia/Tehran"),
+    // South & Southeast Asia
+    ("Bombay", "Asia/Kolkata"),
+    ("Mumbai", "Asia/Kolkata"),
+    ("Delhi", "Asia/Kolkata"),
+    ("New Delhi", "Asia/Kolkata"),
+    ("Calcutta", "Asia/Kolkata"),
+    ("Bangalore", "Asia/Kolkata"),
+    ("Bengaluru", "Asia/Kolkata"),
+    ("Chennai", "Asia/Kolkata"),
+    ("Madras", "Asia/Kolkata"),
+    ("IST", "Asia/Kolkata"),
+    ("India", "Asia/Kolkata"),
+    ("PKT", "Asia/Karachi"),
+    ("Lahore", "Asia/Karachi"),
+    ("Nepal", "Asia/Kathmandu"),
+    ("Dacca", "Asia/Dhaka"),
+    ("Rangoon", "Asia/Yangon"),
+    ("Saigon", "Asia/Ho_Chi_Minh"),
+    ("Hanoi", "Asia/Bangkok"),
+    ("ICT", "Asia/Bangkok"),
+    ("WIB", "Asia/Jakarta"),
+    ("SGT", "Asia/Singapore"),
+    ("KL", "Asia/Kuala_Lumpur"),
+    ("Kuala Lumpur", "Asia/Kuala_Lumpur"),
+    // East Asia & Oceania
+    ("Beijing", "Asia/Shanghai"),
+    ("Peking", "Asia/Shanghai"),
+    ("Shenzhen", "Asia/Shanghai"),
+    ("Guangzhou", "Asia/Shanghai"),
+    ("China", "Asia/Shanghai"),
+    ("CST", "Asia/Shanghai"),
+    ("HKT", "Asia/Hong_Kong"),
+    ("Taipei", "Asia/Taipei"),
+    ("Osaka", "Asia/Tokyo"),
+    ("Kyoto", "Asia/Tokyo"),
+    ("JST", "Asia/Tokyo"),
+    ("Japan", "Asia/Tokyo"),
+    ("KST", "Asia/Seoul"),
+    ("Korea", "Asia/Seoul"),
+    ("AEST", "Australia/Sydney"),
+    ("AEDT", "Australia/Sydney"),
+    ("Canberra", "Australia/Sydney"),
+    ("ACST", "Australia/Adelaide"),
+    ("AWST", "Australia/Perth"),
+    ("NZST", "Pacific/Auckland"),
+    ("NZDT", "Pacific/Auckland"),
+    ("Wellington", "Pacific/Auckland"),
+    // Universal
+    ("Zulu", "UTC"),
+    ("Coordinated Universal Time", "UTC"),
+];
+
+/// Match quality tiers (higher is better)
+const SCORE_EXACT: u32 = 1000;
+const SCORE_PREFIX: u32 = 800;
+const SCORE_WORD_PREFIX: u32 = 600;
+const SCORE_SUBSTRING: u32 = 400;
+const SCORE_TYPO: u32 = 300;
+/// Subsequence matches score up to this, minus a penalty for gaps
+const SCORE_SUBSEQUENCE: u32 = 200;
+
+/// Search timezones by name, city, or alias (case-insensitive, fuzzy)
+///
+/// An empty query returns every zone in IANA order. Otherwise only matching
+/// zones are returned, best match first.
+pub fn search_timezones(query: &str) -> Vec<Tz> {
+    let query = normalize(query.trim());
+    if query.is_empty() {
+        return chrono_tz::TZ_VARIANTS.to_vec();
+    }
+
+    // Best alias score per zone
+    let mut alias_scores: HashMap<&str, u32> = HashMap::new();
+    for &(alias, id) in ALIASES {
+        if let Some(score) = match_score(&query, &normalize(alias)) {
+            let best = alias_scores.entry(id).or_insert(0);
+            *best = (*best).max(score);
+        }
+    }
+    // Dataset cities and countries count like aliases, but loose subsequence
+    // matches against hundreds of names would only add noise
+    for city in CITIES {
+        let score = [city.name, city.country]
+            .into_iter()
+            .filter_map(|name| match_score(&query, &fold_accents(name)))
+            .filter(|&score| score >= SCORE_TYPO)
+            .max();
+        if let Some(score) = score {
+            let best = alias_scores.entry(city.zone_id).or_insert(0);
+            *best = (*best).max(score);
+        }
+    }
+
+    let mut matches: Vec<(Tz, u32, u32)> = chrono_tz::TZ_VARIANTS
+        .iter()
+        .filter_map(|tz| {
+            let id = tz.name();
+            let normalized_id = normalize(id);
+            let city = normalize(city_name(id));
+
+            let score = [
+                match_score(&query, &normalized_id),
+                match_score(&query, &city),
+                alias_scores.get(id).copied(),
+            ]
+            .into_iter()
+            .flatten()
+            .max()?;
+
+            Some((*tz, score, zone_population(*tz)))
+        })
+        .collect();
+
+    matches.sort_by(|a, b| {
+        b.1.cmp(&a.1)
+            .then(b.2.cmp(&a.2))
+            .then_with(|| a.0.name().cmp(b.0.name()))
+    });
+
+    matches.into_iter().map(|(tz, _, _)| tz).collect()
+}
+
+/// Aliases (city names, abbreviations) known for a zone
+pub fn timezone_aliases(tz: Tz) -> Vec<&'static str> {
+    ALIASES
+        .iter()
+        .filter(|(_, id)| *id == tz.name())
+        .map(|(alias, _)| *alias)
+        .collect()
+}
+
+/// Score how well `query` matches `candidate` (both normalized)
+fn match_score(query: &str, candidate: &str) -> Option<u32> {
+    if candidate == query {
+        return Some(SCORE_EXACT);
+    }
+    if candidate.starts_with(query) {
+        return Some(SCORE_PREFIX);
+    }
+    if candidate
+        .match_indices(query)
+        .any(|(i, _)| is_word_start(candidate, i))
+    {
+        return Some(SCORE_WORD_PREFIX);
+    }
+    if candidate.contains(query) {
+        return Some(SCORE_SUBSTRING);
+    }
+
+    // Typos only count against whole names of similar length
+    let query_len = query.chars().count();
+    if query_len >= 4 {
+        let max_edits = if query_len >= 8 { 2 } else { 1 };
+        if levenshtein(query, candidate) <= max_edits {
+            return Some(SCORE_TYPO);
+        }
+    }
+
+    subsequence_gaps(query, candidate)
+        .map(|gaps| SCORE_SUBSEQUENCE.saturating_sub(gaps * 10).max(1))
+}
+
+/// Lowercase, drop accents and treat `_` as a space so "new york" matches
+/// "New_York" and "bogota" matches "Bogotá"
+fn normalize(text: &str) -> String {
+    fold_accents(text).replace('_', " ")
+}
+
+/// Last path segment of an IANA ID ("America/Argentina/Buenos_Aires" → "Buenos_Aires")
+fn city_name(id: &str) -> &str {
+    id.rsplit('/').next().unwrap_or(id)
+}
+
+fn is_word_start(text: &str, byte_index: usize) -> bool {
+    byte_index == 0
+        || text[..byte_index]
+            .chars()
+            .next_back()
+            .map(|c| c == ' ' || c == '/' || c == '-')
+            .unwrap_or(true)
+}
+
+/// If every query char appears in order in the candidate, the number of
+/// skipped candidate chars between the first and last matched char
+fn subsequence_gaps(query: &str, candidate: &str) -> Option<u32> {
+    let mut query_chars = query.chars().filter(|c| *c != ' ').peekable();
+    let mut gaps = 0;
+    let mut started = false;
+
+    for c in candidate.chars() {
+        match query_chars.peek() {
+            Some(&q) if q == c => {
+                query_chars.next();
+                started = true;
+            }
+            Some(_) if started => gaps += 1,
+            Some(_) => {}
+            None => break,
+        }
+    }
+
+    if query_chars.peek().is_none() {
+        Some(gaps)
+    } else {
+        None
+    }
+}
+
+/// Edit distance between two strings (insert, delete, substitute)
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn top(query: &str) -> &'static str {
+        search_timezones(query)
+            .first()
+            .map(|tz| tz.name())
+            .unwrap_or("")
+    }
+
+    #[test]
+    fn test_search_timezones() {
+        let results = search_timezones("New_York");
+        assert!(!results.is_empty());
+        assert!(results.iter().any(|tz| tz.name() == "America/New_York"));
+    }
+
+    #[test]
+    fn test_aliases_find_zones() {
+        assert_eq!(top("NYC"), "America/New_York");
+        assert_eq!(top("bombay"), "Asia/Kolkata");
+        assert!(search_timezones("CET")
+            .iter()
+            .take(3)
+            .any(|tz| tz.name() == "Europe/Paris"));
+    }
+
+    #[test]
+    fn test_city_and_id_matching() {
+        assert_eq!(top("new york"), "America/New_York");
+        assert_eq!(top("tokyo"), "Asia/Tokyo");
+        // Typo tolerance
+        assert_eq!(top("tokio"), "Asia/Tokyo");
+        // Plain substring of the IANA ID still works
+        assert!(search_timezones("america/").len() > 100);
+    }
+
+    #[test]
+    fn test_ranking_and_empty_query() {
+        // Exact city beats prefix matches of other zones
+        let results = search_timezones("london");
+        assert_eq!(results[0].name(), "Europe/London");
+
+        // Empty query returns everything
+        assert_eq!(search_timezones("").len(), chrono_tz::TZ_VARIANTS.len());
+        assert!(search_timezones("zzzzqqq").is_empty());
+    }
+
+    #[test]
+    fn test_match_helpers() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(subsequence_gaps("ny", "new york"), Some(3));
+        assert_eq!(subsequence_gaps("yn", "new york"), None);
+        assert!(timezone_aliases("Asia/Kolkata".parse().unwrap()).contains(&"Bombay"));
+    }
+
+    #[test]
+    fn test_dataset_cities_and_countries() {
+        // City with no alias and no zone of its own
+        assert_eq!(top("nagoya"), "Asia/Tokyo");
+        assert_eq!(top("medellin"), "America/Bogota");
+        // Country names find the country's most populous zone
+        assert_eq!(top("brazil"), "America/Sao_Paulo");
+        assert_eq!(top("kenya"), "Africa/Nairobi");
+    }
+}
@@ -0,0 +1,111 @@
+//! Week date module - ISO-8601 week numbers and day of year
+//!
+//! ISO weeks start on Monday, and week 1 is the week holding the year's first
+//! Thursday. The first days of January can therefore fall in the last week of
+//! the year before, and the last days of December in week 1 of the next.
+//! Week labels name the week-based year whenever it isn't the calendar year,
+//! so "2026-W01" on 29 December 2025 doesn't read as a typo.
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use chrono_tz::Tz;
+
+/// A date's place in the ISO week calendar and in its own year
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekDate {
+    /// Calendar year of the date
+    pub year: i32,
+    /// Year the ISO week belongs to
+    pub iso_year: i32,
+    /// ISO week, 1 to 52 or 53
+    pub week: u32,
+    /// ISO weekday, Monday = 1 to Sunday = 7
+    pub weekday: u32,
+    /// Day of the year, 1 to 365 or 366
+    pub ordinal: u32,
+}
+
+impl WeekDate {
+    pub fn of(date: NaiveDate) -> Self {
+        let iso = date.iso_week();
+        Self {
+            year: date.year(),
+            iso_year: iso.year(),
+            week: iso.week(),
+            weekday: date.weekday().number_from_monday(),
+            ordinal: date.ordinal(),
+        }
+    }
+
+    /// The week date of the local day in `tz` at `instant`
+    pub fn at(instant: DateTime<Utc>, tz: Tz) -> Self {
+        Self::of(instant.with_timezone(&tz).date_naive())
+    }
+
+    /// "W07", or "2026-W01" when the week belongs to another year
+    pub fn week_label(&self) -> String {
+        if self.iso_year == self.year {
+            format!("W{:02}", self.week)
+        } else {
+            format!("{}-W{:02}", self.iso_year, self.week)
+        }
+    }
+
+    /// "D045"
+    pub fn day_label(&self) -> String {
+        format!("D{:03}", self.ordinal)
+    }
+
+    /// Full ISO-8601 week date, e.g. "2025-W07-3"
+    pub fn iso_string(&self) -> String {
+        format!("{}-W{:02}-{}", self.iso_year, self.week, self.weekday)
+    }
+}
+
+/// Number of ISO weeks (52 or 53) in a week-based year
+pub fn weeks_in_year(iso_year: i32) -> u32 {
+    // 28 December always falls in the year's last week
+    NaiveDate::from_ymd_opt(iso_year, 12, 28)
+        .map(|date| date.iso_week().week())
+        .unwrap_or(52)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_week_dates_across_year_ends() {
+        let mid = WeekDate::of(date(2025, 2, 12));
+        assert_eq!(mid.iso_string(), "2025-W07-3");
+        assert_eq!(mid.week_label(), "W07");
+        assert_eq!(mid.day_label(), "D043");
+
+        // Monday 29 December 2025 starts the first week of 2026
+        let early = WeekDate::of(date(2025, 12, 29));
+        assert_eq!(early.iso_string(), "2026-W01-1");
+        assert_eq!(early.week_label(), "2026-W01");
+        assert_eq!(early.ordinal, 363);
+
+        // Sunday 3 January 2021 closes the 53rd week of 2020
+        let late = WeekDate::of(date(2021, 1, 3));
+        assert_eq!(late.iso_string(), "2020-W53-7");
+        assert_eq!(late.day_label(), "D003");
+
+        assert_eq!(WeekDate::of(date(2024, 12, 31)).ordinal, 366);
+        assert_eq!(weeks_in_year(2020), 53);
+        assert_eq!(weeks_in_year(2025), 52);
+    }
+
+    #[test]
+    fn test_week_date_uses_local_day() {
+        // Sunday evening in New York is already Monday in Tokyo
+        let instant = Utc.with_ymd_and_hms(2025, 3, 2, 23, 0, 0).unwrap();
+        assert_eq!(WeekDate::at(instant, chrono_tz::America::New_York).week, 9);
+        assert_eq!(WeekDate::at(instant, chrono_tz::Asia::Tokyo).week, 10);
+    }
+}
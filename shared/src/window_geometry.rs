@@ -0,0 +1,294 @@
+//! Window geometry module - clocks reopen where they were left
+//!
+//! Each clock remembers its window's size, position and monitor in a small
+//! config file of its own (`<clock>_window.toml`), saved once the window has
+//! stopped moving or resizing for a moment. On the next launch the window
+//! goes back there. If the monitor it was on has gone, the window keeps its
+//! size and the OS picks the place, so it never opens off screen.
+//!
+//! `--monitor <N>` and `--fullscreen` (see `cli`) override the remembered
+//! place. Nothing is saved while the window is fullscreen or minimized, so a
+//! kiosk run doesn't change where the window opens next time.
+
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use winit::dpi::{LogicalSize, PhysicalPosition};
+use winit::monitor::MonitorHandle;
+use winit::window::{Fullscreen, Window};
+
+use crate::cli::{placement_request, snapshot_request, PlacementRequest};
+use crate::config::{load_config, save_config, VersionedConfig};
+
+/// How long the geometry must hold still before it is saved
+const SETTLE: Duration = Duration::from_millis(750);
+
+/// How far into a monitor a window's top-left corner must be, in pixels, for
+/// its title bar to be reachable
+const GRAB_MARGIN: i32 = 40;
+
+/// Config file name for a clock's window geometry
+fn window_config_name(clock_name: &str) -> String {
+    format!("{}_window", clock_name)
+}
+
+/// Where a window was and how big it was
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowGeometry {
+    /// Inner size in points, so it survives a change of display scale
+    pub size: Option<(f32, f32)>,
+    /// Outer top-left corner in physical pixels on the desktop
+    pub position: Option<(i32, i32)>,
+    /// Name of the monitor the window was on
+    pub monitor: Option<String>,
+}
+
+impl VersionedConfig for WindowGeometry {
+    const VERSION: u32 = 1;
+}
+
+impl WindowGeometry {
+    /// The geometry `window` has now
+    pub fn of(window: &Window) -> Self {
+        let size: LogicalSize<f32> = window.inner_size().to_logical(window.scale_factor());
+        Self {
+            size: Some((size.width, size.height)),
+            position: window.outer_position().ok().map(|p| (p.x, p.y)),
+            monitor: window.current_monitor().and_then(|m| m.name()),
+        }
+    }
+}
+
+/// A monitor's name and desktop bounds in physical pixels
+#[derive(Debug, Clone, PartialEq)]
+struct MonitorBounds {
+    name: Option<String>,
+    position: (i32, i32),
+    size: (u32, u32),
+}
+
+impl MonitorBounds {
+    fn of(monitor: &MonitorHandle) -> Self {
+        let (position, size) = (monitor.position(), monitor.size());
+        Self {
+            name: monitor.name(),
+            position: (position.x, position.y),
+            size: (size.width, size.height),
+        }
+    }
+
+    fn contains(&self, (x, y): (i32, i32)) -> bool {
+        let (left, top) = self.position;
+        let (right, bottom) = (left + self.size.0 as i32, top + self.size.1 as i32);
+        (left..right).contains(&x) && (top..bottom).contains(&y)
+    }
+
+    /// Top-left corner that centers a window of `outer` pixels on this monitor
+    fn centered(&self, outer: (u32, u32)) -> (i32, i32) {
+        let offset = |monitor: u32, window: u32| (monitor.saturating_sub(window) / 2) as i32;
+        (
+            self.position.0 + offset(self.size.0, outer.0),
+            self.position.1 + offset(self.size.1, outer.1),
+        )
+    }
+}
+
+/// The saved position, if the monitor it was on is still there and the
+/// window's title bar would land on it
+fn restorable_position(saved: &WindowGeometry, monitors: &[MonitorBounds]) -> Option<(i32, i32)> {
+    let (x, y) = saved.position?;
+    let grab = (x + GRAB_MARGIN, y + GRAB_MARGIN / 2);
+    monitors
+        .iter()
+        .find(|m| m.name.is_some() && m.name == saved.monitor)
+        .filter(|m| m.contains(grab))
+        .map(|_| (x, y))
+}
+
+/// Restores a clock's window on launch and remembers where it goes
+///
+/// Call `restore` once after building the window and `track` every frame.
+pub struct WindowPlacement {
+    config_name: String,
+    /// Geometry as last saved
+    saved: WindowGeometry,
+    /// Geometry that differs from `saved`, and when it was first seen
+    pending: Option<(WindowGeometry, Instant)>,
+    /// Don't save: a snapshot run, or fullscreen from the command line
+    frozen: bool,
+}
+
+impl WindowPlacement {
+    /// Load a clock's remembered geometry
+    pub fn load(clock_name: &str) -> Self {
+        let config_name = window_config_name(clock_name);
+        let saved = match load_config(&config_name) {
+            Ok(saved) => saved.unwrap_or_default(),
+            Err(e) => {
+                eprintln!("Failed to load window geometry: {}", e);
+                WindowGeometry::default()
+            }
+        };
+        Self {
+            config_name,
+            saved,
+            pending: None,
+            frozen: false,
+        }
+    }
+
+    /// Put `window` back where it was, or where the command line asks
+    ///
+    /// Snapshot runs keep the clock's default size, so snapshots don't
+    /// depend on how the window was last left.
+    pub fn restore(&mut self, window: &Window) {
+        if snapshot_request().is_some() {
+            self.frozen = true;
+            return;
+        }
+        let request = placement_request();
+        self.frozen = request.fullscreen;
+
+        if let Some((width, height)) = self.saved.size {
+            window.set_inner_size(LogicalSize::new(width, height));
+        }
+
+        let monitors: Vec<MonitorHandle> = window.available_monitors().collect();
+        match request {
+            PlacementRequest {
+                monitor: Some(n),
+                fullscreen,
+            } => match monitors.get(n - 1) {
+                Some(monitor) if fullscreen => {
+                    window.set_fullscreen(Some(Fullscreen::Borderless(Some(monitor.clone()))));
+                }
+                Some(monitor) => {
+                    let outer = window.outer_size();
+                    let (x, y) = MonitorBounds::of(monitor).centered((outer.width, outer.height));
+                    window.set_outer_position(PhysicalPosition::new(x, y));
+                }
+                None => {
+                    eprintln!("No monitor {} ({} connected)", n, monitors.len());
+                    if fullscreen {
+                        window.set_fullscreen(Some(Fullscreen::Borderless(None)));
+                    }
+                }
+            },
+            PlacementRequest {
+                monitor: None,
+                fullscreen: true,
+            } => window.set_fullscreen(Some(Fullscreen::Borderless(None))),
+            PlacementRequest {
+                monitor: None,
+                fullscreen: false,
+            } => {
+                let bounds: Vec<MonitorBounds> = monitors.iter().map(MonitorBounds::of).collect();
+                if let Some((x, y)) = restorable_position(&self.saved, &bounds) {
+                    window.set_outer_position(PhysicalPosition::new(x, y));
+                }
+            }
+        }
+    }
+
+    /// Save `window`'s geometry once it has settled after a move or resize
+    pub fn track(&mut self, window: &Window, now: Instant) {
+        if self.frozen || window.fullscreen().is_some() || window.is_minimized() == Some(true) {
+            self.pending = None;
+            return;
+        }
+        if let Some(geometry) = self.settle(WindowGeometry::of(window), now) {
+            if let Err(e) = save_config(&self.config_name, &geometry) {
+                eprintln!("Failed to save window geometry: {}", e);
+            }
+        }
+    }
+
+    /// The geometry to save, once `current` has held for `SETTLE`
+    fn settle(&mut self, current: WindowGeometry, now: Instant) -> Option<WindowGeometry> {
+        if current == self.saved {
+            self.pending = None;
+            return None;
+        }
+        match &self.pending {
+            Some((pending, since)) if *pending == current => {
+                if now.duration_since(*since) < SETTLE {
+                    return None;
+                }
+            }
+            _ => {
+                self.pending = Some((current, now));
+                return None;
+            }
+        }
+        self.pending = None;
+        self.saved = current.clone();
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(name: &str, x: i32, width: u32) -> MonitorBounds {
+        MonitorBounds {
+            name: Some(name.to_string()),
+            position: (x, 0),
+            size: (width, 1080),
+        }
+    }
+
+    #[test]
+    fn test_restorable_position() {
+        let monitors = [monitor("DP-1", 0, 1920), monitor("HDMI-1", 1920, 1920)];
+        let saved = WindowGeometry {
+            size: Some((900.0, 600.0)),
+            position: Some((2100, 80)),
+            monitor: Some("HDMI-1".to_string()),
+        };
+        assert_eq!(restorable_position(&saved, &monitors), Some((2100, 80)));
+
+        // The second monitor has been unplugged
+        assert_eq!(restorable_position(&saved, &monitors[..1]), None);
+
+        // The monitor is there, but has moved so the window would be lost
+        let moved = [monitor("DP-1", 0, 1920), monitor("HDMI-1", -1920, 1920)];
+        assert_eq!(restorable_position(&saved, &moved), None);
+
+        // Never saved
+        assert_eq!(restorable_position(&WindowGeometry::default(), &monitors), None);
+    }
+
+    #[test]
+    fn test_centered_on_monitor() {
+        assert_eq!(monitor("HDMI-1", 1920, 1920).centered((1000, 800)), (2380, 140));
+        // Larger than the monitor: pinned to its corner
+        assert_eq!(monitor("DP-1", 0, 1920).centered((2500, 1200)), (0, 0));
+    }
+
+    #[test]
+    fn test_saves_once_settled() {
+        let mut placement = WindowPlacement {
+            config_name: window_config_name("test_clock"),
+            saved: WindowGeometry::default(),
+            pending: None,
+            frozen: false,
+        };
+        let start = Instant::now();
+        let at = |x: i32| WindowGeometry {
+            size: Some((900.0, 600.0)),
+            position: Some((x, 50)),
+            monitor: None,
+        };
+
+        // Still being dragged: each new position restarts the wait
+        assert_eq!(placement.settle(at(10), start), None);
+        assert_eq!(placement.settle(at(20), start + SETTLE), None);
+        assert_eq!(placement.settle(at(20), start + SETTLE * 3 / 2), None);
+
+        // Held still long enough
+        assert_eq!(placement.settle(at(20), start + SETTLE * 2), Some(at(20)));
+        assert_eq!(placement.settle(at(20), start + SETTLE * 4), None);
+    }
+}
@@ -0,0 +1,164 @@
+//! Window title module - the current time in the OS window title
+//!
+//! When enabled, each clock writes the time and zone abbreviation into its
+//! window title once a second ("Audit Ledger — 14:03:27 PDT"), so the time
+//! stays readable in the taskbar or dock while the window is minimized. Like
+//! quiet hours, the setting lives in one config file for the whole series.
+//!
+//! The title always shows the real current time in the clock's selected
+//! zone, never a scrubbed or inspected one.
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use nannou_egui::egui;
+use serde::{Deserialize, Serialize};
+use winit::window::Window;
+
+use crate::config::{load_config, save_config, ConfigError, VersionedConfig};
+use crate::i18n::tr;
+
+/// Config file shared by all clocks
+pub const WINDOW_TITLE_CONFIG: &str = "window_title";
+
+/// Whether and how the time appears in window titles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowTitleClock {
+    pub enabled: bool,
+    /// "14:03:27" rather than "2:03:27 PM"
+    pub twenty_four_hour: bool,
+}
+
+impl Default for WindowTitleClock {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            twenty_four_hour: true,
+        }
+    }
+}
+
+impl VersionedConfig for WindowTitleClock {
+    const VERSION: u32 = 1;
+}
+
+impl WindowTitleClock {
+    /// Load the shared setting, falling back to the default (off)
+    pub fn load() -> Self {
+        match load_config(WINDOW_TITLE_CONFIG) {
+            Ok(setting) => setting.unwrap_or_default(),
+            Err(e) => {
+                eprintln!("Failed to load window title setting: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<(), ConfigError> {
+        save_config(WINDOW_TITLE_CONFIG, self)
+    }
+
+    /// The window title for a clock called `base` at `now` in `tz`
+    pub fn title(&self, base: &str, tz: Tz, now: DateTime<Utc>) -> String {
+        if !self.enabled {
+            return base.to_string();
+        }
+        let format = if self.twenty_four_hour {
+            "%H:%M:%S %Z"
+        } else {
+            "%-I:%M:%S %p %Z"
+        };
+        format!("{} — {}", base, now.with_timezone(&tz).format(format))
+    }
+}
+
+/// Keeps a window's title in step with the clock
+///
+/// Call `update` every frame; the title is only rewritten when its text
+/// changes, which is once a second while the setting is on.
+pub struct WindowTitle {
+    /// The clock's own title, e.g. "Audit Ledger Clock"
+    base: String,
+    /// Shown ahead of the title, e.g. while a clip is recording
+    badge: Option<String>,
+    /// Text last written to the window
+    shown: Option<String>,
+}
+
+impl WindowTitle {
+    pub fn new(base: &str) -> Self {
+        Self {
+            base: base.to_string(),
+            badge: None,
+            shown: None,
+        }
+    }
+
+    /// Set or clear the badge shown ahead of the title
+    pub fn set_badge(&mut self, badge: Option<String>) {
+        self.badge = badge;
+    }
+
+    /// Write the title for `now` in `tz`, if it differs from the last one
+    pub fn update(
+        &mut self,
+        window: &Window,
+        setting: &WindowTitleClock,
+        tz: Tz,
+        now: DateTime<Utc>,
+    ) {
+        let mut title = setting.title(&self.base, tz, now);
+        if let Some(badge) = &self.badge {
+            title = format!("{} {}", badge, title);
+        }
+        if self.shown.as_ref() != Some(&title) {
+            window.set_title(&title);
+            self.shown = Some(title);
+        }
+    }
+}
+
+/// Settings controls for the window title; returns whether anything changed
+pub fn window_title_settings(ui: &mut egui::Ui, setting: &mut WindowTitleClock) -> bool {
+    let mut changed = ui
+        .checkbox(&mut setting.enabled, tr("Time in window title"))
+        .on_hover_text(tr("Keep the time visible in the taskbar or dock"))
+        .changed();
+    ui.add_enabled_ui(setting.enabled, |ui| {
+        changed |= ui.checkbox(&mut setting.twenty_four_hour, tr("24-hour")).changed();
+    });
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_title_formats() {
+        let tz: Tz = "America/Los_Angeles".parse().unwrap();
+        // 14:03:27 PDT
+        let now = Utc.with_ymd_and_hms(2025, 6, 1, 21, 3, 27).unwrap();
+        let mut setting = WindowTitleClock::default();
+        assert_eq!(setting.title("Flipboard", tz, now), "Flipboard");
+
+        setting.enabled = true;
+        assert_eq!(setting.title("Flipboard", tz, now), "Flipboard — 14:03:27 PDT");
+        setting.twenty_four_hour = false;
+        assert_eq!(setting.title("Flipboard", tz, now), "Flipboard — 2:03:27 PM PDT");
+    }
+
+    #[test]
+    fn test_title_follows_the_zone() {
+        let tz: Tz = "Europe/London".parse().unwrap();
+        let setting = WindowTitleClock {
+            enabled: true,
+            twenty_four_hour: true,
+        };
+        let winter = Utc.with_ymd_and_hms(2025, 1, 15, 9, 0, 0).unwrap();
+        assert_eq!(setting.title("Ledger", tz, winter), "Ledger — 09:00:00 GMT");
+        let summer = Utc.with_ymd_and_hms(2025, 7, 15, 9, 0, 0).unwrap();
+        assert_eq!(setting.title("Ledger", tz, summer), "Ledger — 10:00:00 BST");
+    }
+}